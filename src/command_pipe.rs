@@ -0,0 +1,192 @@
+//! Command sources for external automation that doesn't need the control
+//! socket or the web API: a named pipe (`[command_pipe] path`) and, in
+//! headless mode, stdin (`[command_pipe] stdin`).
+//!
+//! Every line read is one command, run exactly like a typed one —
+//! dot-commands included. `echo "look" > ~/.vellum-fe/profiles/<name>/commands`
+//! is enough for a shell script to drive the client.
+//!
+//! Both sources are read on plain threads, like the control socket, and
+//! hand their lines to the runtime over an unbounded channel that is
+//! drained next to network I/O. A FIFO reads end-of-file whenever its
+//! writer closes, so the reader reopens it and waits for the next writer;
+//! stdin ends for good at end-of-file.
+
+use anyhow::Result;
+use std::io::BufRead;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+
+use crate::config::CommandPipeConfig;
+
+/// Where `[command_pipe] path` points: absolute paths as given, relative
+/// ones under the profile directory
+fn resolve_path(profile: Option<&str>, file: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(file);
+    if path.is_absolute() {
+        Ok(path)
+    } else {
+        Ok(crate::config::Config::profile_dir(profile)?.join(path))
+    }
+}
+
+/// Start the sources `config` turns on. Stdin is only read when
+/// `allow_stdin` is set (the headless runtime; the TUI owns the terminal).
+/// Returns `None` when no source is running.
+pub fn start(
+    config: &CommandPipeConfig,
+    profile: Option<&str>,
+    allow_stdin: bool,
+) -> Option<mpsc::UnboundedReceiver<String>> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let mut running = false;
+
+    if let Some(file) = config.path.as_deref() {
+        match resolve_path(profile, file).and_then(|path| spawn_pipe_reader(path, tx.clone())) {
+            Ok(()) => running = true,
+            Err(e) => tracing::warn!("Command pipe unavailable: {e:#}"),
+        }
+    }
+
+    if config.stdin {
+        if allow_stdin {
+            match spawn_stdin_reader(tx) {
+                Ok(()) => running = true,
+                Err(e) => tracing::warn!("Stdin command source unavailable: {e:#}"),
+            }
+        } else {
+            tracing::info!("[command_pipe] stdin is only read in headless mode");
+        }
+    }
+
+    running.then_some(rx)
+}
+
+/// Queue each non-blank line of `reader` as a command. Returns false once
+/// the receiver is gone (the runtime is shutting down).
+fn forward_lines(reader: impl BufRead, tx: &mpsc::UnboundedSender<String>) -> bool {
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let command = line.trim_end_matches('\r');
+        if command.trim().is_empty() {
+            continue;
+        }
+        tracing::info!("command pipe: '{}'", command);
+        if tx.send(command.to_string()).is_err() {
+            return false;
+        }
+    }
+    !tx.is_closed()
+}
+
+#[cfg(unix)]
+fn spawn_pipe_reader(path: PathBuf, tx: mpsc::UnboundedSender<String>) -> Result<()> {
+    ensure_fifo(&path)?;
+    tracing::info!("Reading commands from pipe {}", path.display());
+    std::thread::Builder::new()
+        .name("command-pipe".to_string())
+        .spawn(move || loop {
+            // Blocks until a writer opens the pipe
+            let file = match std::fs::File::open(&path) {
+                Ok(file) => file,
+                Err(e) => {
+                    tracing::warn!("Command pipe {:?} closed: {}", path, e);
+                    break;
+                }
+            };
+            if !forward_lines(std::io::BufReader::new(file), &tx) {
+                break;
+            }
+        })?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn spawn_pipe_reader(path: PathBuf, _tx: mpsc::UnboundedSender<String>) -> Result<()> {
+    anyhow::bail!(
+        "named pipes are only supported on Unix (use `vellum-fe send` instead of {})",
+        path.display()
+    )
+}
+
+/// Create the FIFO at `path` (user-only) unless it exists; refuse anything
+/// that isn't one, since a regular file would be re-read forever
+#[cfg(unix)]
+fn ensure_fifo(path: &std::path::Path) -> Result<()> {
+    use anyhow::Context;
+    use std::os::unix::fs::FileTypeExt;
+
+    if !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create {}", parent.display()))?;
+        }
+        let status = std::process::Command::new("mkfifo")
+            .arg("-m")
+            .arg("600")
+            .arg(path)
+            .status()
+            .context("Could not run mkfifo")?;
+        if !status.success() {
+            anyhow::bail!("mkfifo {} failed ({})", path.display(), status);
+        }
+    }
+    let metadata =
+        std::fs::metadata(path).with_context(|| format!("Could not read {}", path.display()))?;
+    if !metadata.file_type().is_fifo() {
+        anyhow::bail!("{} is not a named pipe", path.display());
+    }
+    Ok(())
+}
+
+fn spawn_stdin_reader(tx: mpsc::UnboundedSender<String>) -> Result<()> {
+    std::thread::Builder::new()
+        .name("command-stdin".to_string())
+        .spawn(move || {
+            forward_lines(std::io::stdin().lock(), &tx);
+            tracing::info!("Stdin command source reached end of input");
+        })?;
+    tracing::info!("Reading commands from stdin");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwards_non_blank_lines() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let input = "look\r\n\n   \n.go bank\n";
+        assert!(forward_lines(std::io::Cursor::new(input), &tx));
+        assert_eq!(rx.try_recv().as_deref(), Ok("look"));
+        assert_eq!(rx.try_recv().as_deref(), Ok(".go bank"));
+        assert!(rx.try_recv().is_err());
+
+        drop(rx);
+        assert!(!forward_lines(std::io::Cursor::new("look\n"), &tx));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reads_commands_from_fifo_across_writers() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("commands");
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        spawn_pipe_reader(path.clone(), tx).expect("start");
+
+        for command in ["look", "inventory"] {
+            let mut writer = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+            writeln!(writer, "{}", command).unwrap();
+            drop(writer);
+            assert_eq!(rx.blocking_recv().as_deref(), Some(command));
+        }
+
+        std::fs::write(dir.path().join("plain"), "look\n").unwrap();
+        assert!(ensure_fifo(&dir.path().join("plain")).is_err());
+    }
+}
@@ -13,6 +13,8 @@ use std::fs;
 use std::path::PathBuf;
 
 pub mod menu_keybind_validator;
+pub mod script_import;
+pub mod theme_import;
 pub mod wrayth_import;
 mod colors;
 mod highlights;
@@ -20,44 +22,55 @@ mod hotbars;
 mod io;
 mod macros;
 mod paths;
+pub mod schema;
 pub mod profiles;
 pub mod skins;
 mod keybinds;
 mod layout;
 mod settings;
 mod templates;
+mod variables;
 mod widgets;
 mod window_def;
 
-pub use colors::{ColorConfig, PaletteColor, SpellColorRange, SpellColorStyle};
+pub use colors::{ColorConfig, PaletteColor, PresetColor, SpellColorRange, SpellColorStyle};
 pub use highlights::{EventAction, EventPattern, HighlightPattern, RedirectMode};
 pub use hotbars::{
     EffectCategory, HotbarButton, HotbarButtonState, HotbarCmp, HotbarCondition,
     HotbarCountdownSource, HotbarDef, HotbarStyle, HotbarsConfig, NameMatch, VitalKind, VitalUnit,
 };
 pub use keybinds::{
-    parse_key_string, AppKeybinds, KeyAction, KeyBindAction, MacroAction, MenuKeybinds,
+    format_key_string, parse_key_string, AppKeybinds, CallAction, KeyAction, KeyBindAction,
+    MacroAction, MenuKeybinds, SequenceAction, SequenceStep,
 };
-pub use layout::{ContentAlign, Layout, LayoutConfig, LayoutMapping};
+pub use layout::{ContentAlign, Layout, LayoutConfig, LayoutMapping, LayoutMeta};
 pub use macros::{MacroButton, MacroGroup, MacroOption, MacrosConfig};
 pub use paths::{DialogPosition, SavedDialogPositions};
+pub use schema::{take_upgrade_notices, SCHEMA_VERSION};
 pub use settings::{
-    ConnectionConfig, FocusConfig, Go2Config, HighlightsConfig, LoggingConfig, MapConfig,
-    SoundConfig, StreamsConfig, TargetListConfig, TtsConfig, UiConfig, WebConfig,
+    AlarmConfig, AwayConfig, CombatModeConfig, CommandPipeConfig, ConnectionConfig,
+    ContainersConfig, FocusConfig, FocusRule, Go2Config, HighlightsConfig, LockoutBypass,
+    LockoutConfig, LockoutPattern, LoggingConfig, LootConfig, MapConfig, PathsConfig, ProxyConfig,
+    ProxyKind, QuitConfig, RebuffConfig, RemindersConfig, ReplyConfig, RoutesConfig, SoundConfig,
+    SoundCue, SpellcheckConfig, StreamerConfig, StreamsConfig, TargetListConfig, TtsConfig,
+    UiConfig, UrlsConfig, WebConfig,
 };
 pub use templates::{IndicatorTemplateEntry, IndicatorTemplateStore};
+pub use variables::VariableStore;
 pub use widgets::{
     apply_compiled_text_replacements, compile_text_replacements, default_minivitals_bar_order,
-    ActiveEffectsWidgetData, BetrayerWidgetData, BorderSides, CommandInputWidgetData,
-    CompassWidgetData, CompiledTextReplacement, ContainerWidgetData, CountdownWidgetData,
-    DashboardIndicatorDef, DashboardWidgetData, EncumbranceWidgetData, ExperienceWidgetData,
-    GS4ExperienceWidgetData, HandWidgetData, HotkeybarWidgetData, IndicatorWidgetData,
-    InjuryDollWidgetData, InventoryWidgetData, ItemsWidgetData, MapWidgetData,
-    MiniVitalsWidgetData, PerceptionWidgetData,
-    PerformanceWidgetData, PlayersWidgetData, ProgressWidgetData, QuickbarDefinition,
-    QuickbarEntryConfig, QuickbarWidgetData, QuickbarsConfig, RoomWidgetData, SortDirection,
-    SpacerWidgetData, SpellsWidgetData, TabbedTextTab, TabbedTextWidgetData, TargetsWidgetData,
-    TextReplacement, TextWidgetData, WebUiWidgetData, WindowBase,
+    ActiveEffectsWidgetData, BetrayerWidgetData, BorderSides, CalendarWidgetData,
+    CombatStatsWidgetData, CommandInputWidgetData, CompassWidgetData, CompiledTextReplacement,
+    ContainerWidgetData, CountdownWidgetData, DashboardIndicatorDef, DashboardWidgetData,
+    EncumbranceWidgetData, ExperienceWidgetData, GS4ExperienceWidgetData, HandWidgetData,
+    HotkeybarWidgetData, IndicatorWidgetData, InjuryDollWidgetData, InventoryWidgetData,
+    ItemsWidgetData, MapWidgetData, MiniVitalsWidgetData, NotesWidgetData, PerceptionWidgetData,
+    PerformanceWidgetData, PlayedWidgetData, PlayersWidgetData, ProgressWidgetData,
+    QuickbarDefinition, QuickbarEntryConfig, QuickbarWidgetData, QuickbarsConfig,
+    RemindersWidgetData, RoomWidgetData, SocietyTasksWidgetData, SortDirection, SpacerWidgetData,
+    SpellsWidgetData, StanceWidgetData, TabbedTextTab, TabbedTextWidgetData, TargetsWidgetData,
+    TaskTrackerWidgetData, TextReplacement, TextWidgetData, TrafficWidgetData, WatchEntry,
+    WatchWidgetData, WealthWidgetData, WebUiWidgetData, WindowBase,
 };
 pub use window_def::WindowDef;
 
@@ -129,7 +142,7 @@ impl WidgetCategory {
             "countdown" => Self::Countdown,
             "hand" => Self::Hand,
             "active_effects" => Self::ActiveEffects,
-            "indicator" | "dashboard" => Self::Status,
+            "indicator" | "dashboard" | "stance" => Self::Status,
             "progress" => Self::ProgressBar,
             "text" | "tabbedtext" => Self::TextWindow,
             "targets" | "players" | "items" => Self::Entity,
@@ -213,6 +226,86 @@ impl std::fmt::Display for TimestampPosition {
     }
 }
 
+/// How the terminal draws emoji written with the emoji presentation
+/// selector (U+FE0F), e.g. "⚔️"
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmojiWidth {
+    /// Two columns, as Unicode specifies (default)
+    #[default]
+    Wide,
+    /// One column; for terminals that ignore the selector
+    Narrow,
+}
+
+impl EmojiWidth {
+    pub const ALL: [EmojiWidth; 2] = [EmojiWidth::Wide, EmojiWidth::Narrow];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|width| width.to_string() == name)
+    }
+}
+
+impl std::fmt::Display for EmojiWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmojiWidth::Wide => write!(f, "wide"),
+            EmojiWidth::Narrow => write!(f, "narrow"),
+        }
+    }
+}
+
+/// Number and clock conventions for rendered stats and timestamps
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Locale {
+    /// "1,234" and "7:08 PM" (default)
+    #[default]
+    #[serde(rename = "en-US")]
+    EnUs,
+    /// "1,234" and "19:08"
+    #[serde(rename = "en-GB")]
+    EnGb,
+    /// "1.234" and "19:08"
+    #[serde(rename = "de-DE")]
+    DeDe,
+    /// "1 234" and "19:08"
+    #[serde(rename = "fr-FR")]
+    FrFr,
+    /// "1'234" and "19:08"
+    #[serde(rename = "de-CH")]
+    DeCh,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 5] = [
+        Locale::EnUs,
+        Locale::EnGb,
+        Locale::DeDe,
+        Locale::FrFr,
+        Locale::DeCh,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.to_string().eq_ignore_ascii_case(name))
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Locale::EnUs => write!(f, "en-US"),
+            Locale::EnGb => write!(f, "en-GB"),
+            Locale::DeDe => write!(f, "de-DE"),
+            Locale::FrFr => write!(f, "fr-FR"),
+            Locale::DeCh => write!(f, "de-CH"),
+        }
+    }
+}
+
 /// Top-level configuration object aggregated from multiple TOML files.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -240,6 +333,8 @@ pub struct Config {
     pub layout_mappings: Vec<LayoutMapping>,
     #[serde(skip)] // Don't serialize/deserialize this - it's set at runtime
     pub character: Option<String>, // Character name for character-specific saving
+    #[serde(skip)] // Set at runtime by --demo
+    pub demo_script: Option<String>, // Scripted session to play instead of connecting (network::demo)
     #[serde(skip)] // Loaded from separate colors.toml file (includes color_palette)
     pub colors: ColorConfig, // All color configuration (presets, prompt_colors, ui colors, spell colors, color_palette)
     #[serde(default)] // Use defaults for menu keybinds
@@ -260,10 +355,46 @@ pub struct Config {
     pub map: MapConfig, // Mapdb discovery for the mini map / map explorer
     #[serde(default)]
     pub go2: Go2Config, // Native travel: saved targets, travel options
+    #[serde(default)]
+    pub loot: LootConfig, // Loot filter: ranked item highlights, drop sound, .loot tally
+    #[serde(default)]
+    pub spellcheck: SpellcheckConfig, // Underline misspellings in says/whispers
+    #[serde(default)]
+    pub alarm: AlarmConfig, // Low-health / death alerts (thresholds per character)
+    #[serde(default)]
+    pub proxy: ProxyConfig, // SOCKS5 / HTTP CONNECT proxy for outgoing connections
+    #[serde(default)]
+    pub rebuff: RebuffConfig, // `.rebuff` cast command templates and pacing
+    #[serde(default)]
+    pub reply: ReplyConfig, // `.r` / `.rt` whisper and thought reply templates
+    #[serde(default)]
+    pub streamer: StreamerConfig, // Name aliases for streaming and screenshots
+    #[serde(default)]
+    pub urls: UrlsConfig, // Clickable URLs: allow list and open confirmation
+    #[serde(default)]
+    pub reminders: RemindersConfig, // `.remind` alert sound, speech and notification
+    #[serde(default)]
+    pub away: AwayConfig, // Away mode: idle timeout, whisper auto-reply and log stream
+    #[serde(default)]
+    pub combat_mode: CombatModeConfig, // Windows opened only while fighting
+    #[serde(default)]
+    pub quit: QuitConfig, // `.quit` confirmation and logout before exiting
+    #[serde(default)]
+    pub lockout: LockoutConfig, // Commands that ask y/n before they're sent
+    #[serde(default)]
+    pub containers: ContainersConfig, // Container window drag moves and confirmation
+    #[serde(default)]
+    pub routes: RoutesConfig, // Saved `.go` routes and playback pacing
+    #[serde(default)]
+    pub command_pipe: CommandPipeConfig, // Named pipe / headless stdin command sources
+    #[serde(default)]
+    pub paths: PathsConfig, // Per-profile sounds/logs directories and environment
     #[serde(skip)] // Merged view of macros.toml + macros-local.toml
     pub macros: MacrosConfig, // Macro buttons for the web frontend
     #[serde(skip)] // Phone-edited overlay, persisted to macros-local.toml
     pub macros_local: MacrosConfig,
+    #[serde(skip)] // Loaded from global + character variables.toml
+    pub variables: VariableStore, // `.set` variables expanded as %name% in commands
 }
 
 
@@ -302,6 +433,17 @@ fn default_focus_exclude() -> Vec<String> {
         "experience".to_string(),
         "gs4_experience".to_string(),
         "encum".to_string(),
+        "stance".to_string(),
+        "wealth".to_string(),
+        "task_tracker".to_string(),
+        "society_tasks".to_string(),
+        "combat_stats".to_string(),
+        "calendar".to_string(),
+        "reminders".to_string(),
+        "notes".to_string(),
+        "watch".to_string(),
+        "traffic".to_string(),
+        "played".to_string(),
         "minivitals".to_string(),
         "betrayer".to_string(),
     ]
@@ -447,10 +589,42 @@ fn default_min_command_length() -> usize {
     3
 }
 
+fn default_history_expansion() -> bool {
+    true
+}
+
+fn default_history_prefix_search() -> bool {
+    true
+}
+
+fn default_paste_confirm_lines() -> usize {
+    2
+}
+
+fn default_keyboard_layout() -> String {
+    "qwerty".to_string()
+}
+
 fn default_command_echo() -> bool {
     true
 }
 
+fn default_view_hint_lines() -> usize {
+    40
+}
+
+fn default_max_fps() -> u32 {
+    60
+}
+
+fn default_idle_fps() -> u32 {
+    5
+}
+
+fn default_boss_key_prompt() -> String {
+    "$ ".to_string()
+}
+
 fn default_perf_stats_x() -> u16 {
     0 // Calculated dynamically: terminal_width - 35
 }
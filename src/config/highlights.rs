@@ -50,7 +50,7 @@ pub struct HighlightPattern {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<String>, // If set, only apply this highlight to lines from this stream (e.g., "death", "thoughts")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub window: Option<String>, // If set with replace, only apply replacement in this window (colors apply everywhere)
+    pub window: Option<String>, // If set, only apply in these windows/tabs (comma-separated): colors, sounds, replace and squelch
 
     // Performance optimization: cache compiled regex (not serialized)
     #[serde(skip)]
@@ -111,7 +111,7 @@ impl Config {
             .with_context(|| format!("Failed to read common highlights: {:?}", path))?;
 
         let highlights: HashMap<String, HighlightPattern> =
-            toml::from_str(&contents).context("Failed to parse common highlights TOML")?;
+            schema::parse(&contents).context("Failed to parse common highlights TOML")?;
 
         Ok(highlights)
     }
@@ -129,7 +129,7 @@ impl Config {
             let contents =
                 fs::read_to_string(&highlights_path).context("Failed to read highlights.toml")?;
             let character_highlights: HashMap<String, HighlightPattern> =
-                toml::from_str(&contents).context("Failed to parse highlights.toml")?;
+                schema::parse(&contents).context("Failed to parse highlights.toml")?;
 
             // Character highlights override global (HashMap::extend)
             highlights.extend(character_highlights);
@@ -144,6 +144,15 @@ impl Config {
         Ok(highlights)
     }
 
+    /// The user's highlights plus the generated loot-filter patterns.
+    /// Engines are built from this; `self.highlights` alone is what gets
+    /// saved, so the loot patterns never land in highlights.toml.
+    pub fn active_highlights(&self) -> Vec<HighlightPattern> {
+        let mut highlights: Vec<_> = self.highlights.values().cloned().collect();
+        highlights.extend(loot_highlight_patterns(&self.loot));
+        highlights
+    }
+
     /// Compile regex patterns for all highlights (performance optimization)
     pub fn compile_highlight_patterns(highlights: &mut HashMap<String, HighlightPattern>) {
         for (name, pattern) in highlights.iter_mut() {
@@ -167,7 +176,8 @@ impl Config {
         let highlights_path = Self::highlights_path(character)?;
         let contents =
             toml::to_string_pretty(&self.highlights).context("Failed to serialize highlights")?;
-        fs::write(&highlights_path, contents).context("Failed to write highlights.toml")?;
+        fs::write(&highlights_path, schema::stamp(&contents))
+            .context("Failed to write highlights.toml")?;
         Ok(())
     }
 
@@ -190,7 +200,7 @@ impl Config {
         let toml =
             toml::to_string_pretty(&highlights).context("Failed to serialize common highlights")?;
 
-        fs::write(&path, toml)
+        fs::write(&path, schema::stamp(&toml))
             .with_context(|| format!("Failed to write common highlights: {:?}", path))?;
 
         Ok(())
@@ -205,7 +215,7 @@ impl Config {
         let toml =
             toml::to_string_pretty(&highlights).context("Failed to serialize common highlights")?;
 
-        fs::write(&path, toml)
+        fs::write(&path, schema::stamp(&toml))
             .with_context(|| format!("Failed to write common highlights: {:?}", path))?;
 
         Ok(())
@@ -225,7 +235,7 @@ impl Config {
         let contents =
             fs::read_to_string(&highlights_path).context("Failed to read highlights.toml")?;
         let highlights: HashMap<String, HighlightPattern> =
-            toml::from_str(&contents).context("Failed to parse highlights.toml")?;
+            schema::parse(&contents).context("Failed to parse highlights.toml")?;
 
         Ok(highlights)
     }
@@ -270,7 +280,7 @@ impl Config {
         let toml = toml::to_string_pretty(&highlights)
             .context("Failed to serialize character highlights")?;
 
-        fs::write(&highlights_path, toml)
+        fs::write(&highlights_path, schema::stamp(&toml))
             .with_context(|| format!("Failed to write highlights: {:?}", highlights_path))?;
 
         tracing::info!(
@@ -316,7 +326,7 @@ impl Config {
             let toml = toml::to_string_pretty(&highlights)
                 .context("Failed to serialize character highlights")?;
 
-            fs::write(&highlights_path, toml)
+            fs::write(&highlights_path, schema::stamp(&toml))
                 .with_context(|| format!("Failed to write highlights: {:?}", highlights_path))?;
 
             tracing::info!(
@@ -384,6 +394,56 @@ impl Config {
     }
 }
 
+/// One highlight per ranked tier, lowest first so a longer top-tier name
+/// ("star ruby") paints over the shorter valuable one ("ruby") it contains.
+fn loot_highlight_patterns(loot: &LootConfig) -> Vec<HighlightPattern> {
+    use crate::data::loot::{LootTable, LootTier};
+    if !loot.enabled {
+        return Vec::new();
+    }
+    let table = LootTable::new(&loot.items);
+    [
+        (LootTier::Common, &loot.common_color),
+        (LootTier::Valuable, &loot.valuable_color),
+        (LootTier::Top, &loot.top_color),
+    ]
+    .into_iter()
+    .filter_map(|(tier, color)| {
+        let color = color.clone()?;
+        let names: Vec<String> = table
+            .items()
+            .iter()
+            .filter(|item| item.tier == tier)
+            .map(|item| regex::escape(&item.name))
+            .collect();
+        if names.is_empty() {
+            return None;
+        }
+        Some(HighlightPattern {
+            pattern: format!(r"(?i)\b(?:{})(?:e?s)?\b", names.join("|")),
+            fg: Some(color),
+            bg: None,
+            bold: tier == LootTier::Top,
+            color_entire_line: false,
+            fast_parse: false,
+            sound: None,
+            sound_volume: None,
+            category: Some("Loot".to_string()),
+            squelch: false,
+            silent_prompt: false,
+            redirect_to: None,
+            redirect_mode: RedirectMode::default(),
+            replace: None,
+            // Drops show up among the room objects; the same names in
+            // speech, inventory or shop lists stay uncolored
+            stream: Some("room".to_string()),
+            window: None,
+            compiled_regex: None,
+        })
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -815,6 +875,22 @@ mod tests {
         assert!(debug_str.contains("test"));
     }
 
+    #[test]
+    fn test_loot_patterns_rank_longer_names_last() {
+        let mut loot = LootConfig::default();
+        let patterns = loot_highlight_patterns(&loot);
+        // Common tier has no color by default
+        assert_eq!(patterns.len(), 2);
+        let top = regex::Regex::new(&patterns[1].pattern).unwrap();
+        assert!(top.is_match("You also see a Star Ruby."));
+        assert!(!top.is_match("You also see a ruby."));
+        assert!(patterns[1].bold);
+        assert!(patterns.iter().all(|p| p.stream.as_deref() == Some("room")));
+
+        loot.enabled = false;
+        assert!(loot_highlight_patterns(&loot).is_empty());
+    }
+
     #[test]
     fn test_event_action_debug() {
         let action = EventAction::Increment;
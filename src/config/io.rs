@@ -20,6 +20,8 @@ impl Config {
     ) -> Result<Self> {
         // Ensure defaults are extracted
         Self::extract_defaults(character)?;
+        // Bring old files up to the current schema (config::schema)
+        Self::upgrade_config_files(character);
 
         // Load config from custom path
         let contents =
@@ -43,6 +45,10 @@ impl Config {
         config.app_keybinds = Self::load_app_keybinds(character)?;
         config.macros = MacrosConfig::load(character).unwrap_or_default();
         config.macros_local = MacrosConfig::load_local(character).unwrap_or_default();
+        config.variables = VariableStore::load(character).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load variables: {:#}", e);
+            VariableStore::default()
+        });
 
         // Validate and auto-fix menu keybinds
         let validation = menu_keybind_validator::validate_menu_keybinds(&config.menu_keybinds);
@@ -338,11 +344,29 @@ impl Config {
         self.web = character_config.web;
         self.map = character_config.map;
         self.go2 = character_config.go2;
+        self.loot = character_config.loot;
+        self.spellcheck = character_config.spellcheck;
+        self.alarm = character_config.alarm;
+        self.proxy = character_config.proxy;
+        self.rebuff = character_config.rebuff;
+        self.reply = character_config.reply;
+        self.streamer = character_config.streamer;
+        self.urls = character_config.urls;
+        self.reminders = character_config.reminders;
+        self.away = character_config.away;
+        self.combat_mode = character_config.combat_mode;
+        self.quit = character_config.quit;
+        self.containers = character_config.containers;
+        self.routes = character_config.routes;
+        self.command_pipe = character_config.command_pipe;
+        self.paths = character_config.paths;
     }
 
     pub fn load_with_options(character: Option<&str>, port_override: Option<u16>) -> Result<Self> {
         // Extract defaults on first run (idempotent - only creates missing files)
         Self::extract_defaults(character)?;
+        // Bring old files up to the current schema (config::schema)
+        Self::upgrade_config_files(character);
 
         // Load global config first (defaults for all characters)
         let mut config = Self::load_common_config()?;
@@ -370,6 +394,10 @@ impl Config {
         config.menu_keybinds = Self::load_menu_keybinds(character)?;
         config.macros = MacrosConfig::load(character).unwrap_or_default();
         config.macros_local = MacrosConfig::load_local(character).unwrap_or_default();
+        config.variables = VariableStore::load(character).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load variables: {:#}", e);
+            VariableStore::default()
+        });
 
         // Validate and auto-fix menu keybinds
         let validation = menu_keybind_validator::validate_menu_keybinds(&config.menu_keybinds);
@@ -412,7 +440,7 @@ impl Config {
 
         // Save main config (without highlights, keybinds, colors, color_palette - those are skipped)
         let contents = toml::to_string_pretty(self).context("Failed to serialize config")?;
-        fs::write(&config_path, contents).context("Failed to write config file")?;
+        fs::write(&config_path, schema::stamp(&contents)).context("Failed to write config file")?;
 
         // Save to separate files
         self.colors.save(char_name)?;
@@ -434,7 +462,8 @@ impl Config {
 
         // Save main config
         let contents = toml::to_string_pretty(self).context("Failed to serialize config")?;
-        fs::write(&config_path, contents).context("Failed to write global config file")?;
+        fs::write(&config_path, schema::stamp(&contents))
+            .context("Failed to write global config file")?;
         tracing::info!("Saved config to global file: {:?}", config_path);
         Ok(())
     }
@@ -488,7 +517,8 @@ impl Config {
             fs::create_dir_all(parent)?;
         }
         let contents = toml::to_string_pretty(&char_config).context("Failed to serialize config")?;
-        fs::write(&config_path, contents).context("Failed to write character config file")?;
+        fs::write(&config_path, schema::stamp(&contents))
+            .context("Failed to write character config file")?;
         tracing::info!("Saved setting '{}' to character config: {:?}", key, config_path);
         Ok(())
     }
@@ -513,8 +543,28 @@ impl Config {
                 dest.ui.selection_respect_window_boundaries = src.ui.selection_respect_window_boundaries
             }
             "ui.selection_auto_copy" => dest.ui.selection_auto_copy = src.ui.selection_auto_copy,
+            "ui.selection_copy_ansi" => dest.ui.selection_copy_ansi = src.ui.selection_copy_ansi,
+            "ui.reduced_motion" => dest.ui.reduced_motion = src.ui.reduced_motion,
+            "ui.screen_reader" => dest.ui.screen_reader = src.ui.screen_reader,
+            "ui.emoji_width" => dest.ui.emoji_width = src.ui.emoji_width,
+            "ui.locale" => dest.ui.locale = src.ui.locale,
+            "ui.max_fps" => dest.ui.max_fps = src.ui.max_fps,
+            "ui.idle_fps" => dest.ui.idle_fps = src.ui.idle_fps,
             "ui.drag_modifier_key" => dest.ui.drag_modifier_key = src.ui.drag_modifier_key.clone(),
             "ui.min_command_length" => dest.ui.min_command_length = src.ui.min_command_length,
+            "ui.history_expansion" => dest.ui.history_expansion = src.ui.history_expansion,
+            "ui.history_prefix_search" => {
+                dest.ui.history_prefix_search = src.ui.history_prefix_search
+            }
+            "ui.paste_confirm_lines" => dest.ui.paste_confirm_lines = src.ui.paste_confirm_lines,
+            "ui.hide_prompt" => dest.ui.hide_prompt = src.ui.hide_prompt,
+            "ui.command_echo" => dest.ui.command_echo = src.ui.command_echo,
+            "ui.command_echo_prefix" => {
+                dest.ui.command_echo_prefix = src.ui.command_echo_prefix.clone()
+            }
+            "ui.mask_secrets" => dest.ui.mask_secrets = src.ui.mask_secrets,
+            "ui.keyboard_layout" => dest.ui.keyboard_layout = src.ui.keyboard_layout.clone(),
+            "spellcheck.enabled" => dest.spellcheck.enabled = src.spellcheck.enabled,
 
             // Sound settings
             "sound.enabled" => dest.sound.enabled = src.sound.enabled,
@@ -545,6 +595,10 @@ impl Default for Config {
                 account: None,
                 password: None,
                 game: None,
+                compression: false,
+                tls: false,
+                tls_port: None,
+                tls_verify: true,
             },
             ui: UiConfig {
                 buffer_size: default_buffer_size(),
@@ -554,8 +608,13 @@ impl Default for Config {
                 selection_enabled: default_selection_enabled(),
                 selection_respect_window_boundaries: default_selection_respect_window_boundaries(),
                 selection_auto_copy: default_selection_auto_copy(),
+                selection_copy_ansi: false,
                 drag_modifier_key: default_drag_modifier_key(),
                 min_command_length: default_min_command_length(),
+                history_expansion: default_history_expansion(),
+                history_prefix_search: default_history_prefix_search(),
+                paste_confirm_lines: default_paste_confirm_lines(),
+                keyboard_layout: default_keyboard_layout(),
                 performance_stats_enabled: default_performance_stats_enabled(),
                 perf_stats_x: default_perf_stats_x(),
                 perf_stats_y: default_perf_stats_y(),
@@ -579,10 +638,22 @@ impl Default for Config {
                 color_mode: ColorMode::default(),
                 timestamp_position: TimestampPosition::default(),
                 command_echo: default_command_echo(),
+                command_echo_prefix: String::new(),
+                mask_secrets: true,
+                hide_prompt: false,
+                view_hint_lines: default_view_hint_lines(),
+                art_panel: false,
                 betrayer_active_color: default_betrayer_active_color(),
                 open_dialog_blocklist: default_open_dialog_blocklist(),
                 focus: FocusConfig::default(),
                 terminal_title: String::new(),
+                boss_key_prompt: default_boss_key_prompt(),
+                reduced_motion: false,
+                screen_reader: false,
+                emoji_width: EmojiWidth::default(),
+                locale: Locale::default(),
+                max_fps: default_max_fps(),
+                idle_fps: default_idle_fps(),
             },
             highlights: HashMap::new(),     // Loaded from highlights.toml
             keybinds: HashMap::new(),       // Loaded from keybinds.toml
@@ -599,11 +670,30 @@ impl Default for Config {
             web: WebConfig::default(), // Web server off by default
             map: MapConfig::default(),
             go2: Go2Config::default(),
+            loot: LootConfig::default(),
+            spellcheck: SpellcheckConfig::default(),
+            alarm: AlarmConfig::default(),
+            proxy: ProxyConfig::default(),
+            rebuff: RebuffConfig::default(),
+            reply: ReplyConfig::default(),
+            streamer: StreamerConfig::default(),
+            urls: UrlsConfig::default(),
+            reminders: RemindersConfig::default(),
+            away: AwayConfig::default(),
+            combat_mode: CombatModeConfig::default(),
+            quit: QuitConfig::default(),
+            lockout: LockoutConfig::default(),
+            containers: ContainersConfig::default(),
+            routes: RoutesConfig::default(),
+            command_pipe: CommandPipeConfig::default(),
+            paths: PathsConfig::default(),
             macros: MacrosConfig::default(), // Loaded from macros.toml
             macros_local: MacrosConfig::default(),
+            variables: VariableStore::default(), // Loaded from variables.toml
             event_patterns: HashMap::new(), // Empty by default - user adds via config
             layout_mappings: Vec::new(),    // Empty by default - user adds via config
             character: None,                // Set at runtime via load_with_options
+            demo_script: None,              // Set at runtime by --demo
             menu_keybinds: MenuKeybinds::default(),
             active_theme: default_theme_name(),
             active_skin: None,
@@ -654,4 +744,45 @@ mod tests {
         Config::copy_setting(&mut dest, &src, "active_skin");
         assert_eq!(dest.active_skin.as_deref(), Some("parchment"));
     }
+
+    #[test]
+    fn focus_rules_load_from_toml() {
+        let sound: SoundConfig =
+            toml::from_str("play_when = \"unfocused\"\nunfocused_volume = 0.3").unwrap();
+        assert_eq!(sound.play_when, FocusRule::Unfocused);
+        assert!(sound.play_when.allows(false));
+        assert!(!sound.play_when.allows(true));
+        assert_eq!(sound.unfocused_volume, 0.3);
+
+        let sound: SoundConfig = toml::from_str("").unwrap();
+        assert!(sound.play_when.allows(true) && sound.play_when.allows(false));
+    }
+
+    #[test]
+    fn profile_paths_resolve_and_override_logging_dir() {
+        let profile = Config::profile_dir(Some("Bob")).unwrap();
+        assert_eq!(
+            PathsConfig::resolve("sounds", Some("Bob")).unwrap(),
+            profile.join("sounds")
+        );
+        let absolute = std::env::temp_dir().join("bob-logs");
+        assert_eq!(
+            PathsConfig::resolve(absolute.to_str().unwrap(), Some("Bob")).unwrap(),
+            absolute
+        );
+
+        let paths: PathsConfig =
+            toml::from_str("logs_dir = \"alt-logs\"\n[env]\nTERMINAL = \"kitty\"").unwrap();
+        assert_eq!(paths.env.get("TERMINAL").map(String::as_str), Some("kitty"));
+        let config = Config {
+            character: Some("Bob".to_string()),
+            paths,
+            ..Config::default()
+        };
+        assert_eq!(config.logs_dir().unwrap(), profile.join("alt-logs"));
+        assert_eq!(
+            Config::default().logs_dir().unwrap(),
+            Config::profile_dir(None).unwrap().join("logs")
+        );
+    }
 }
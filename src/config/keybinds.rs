@@ -4,8 +4,10 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum KeyBindAction {
-    Action(String),     // Just an action: "cursor_word_left"
-    Macro(MacroAction), // A macro with text
+    Action(String),           // Just an action: "cursor_word_left"
+    Macro(MacroAction),       // A macro with text
+    Sequence(SequenceAction), // Several commands with pauses between them
+    Call(CallAction),         // An internal function with arguments
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,12 +15,162 @@ pub struct MacroAction {
     pub macro_text: String, // e.g., "sw\r" for southwest movement
 }
 
+/// Commands sent one after another, each after an optional pause; for
+/// walking the menus of a direct connection or a startup routine.
+///
+/// ```toml
+/// f12 = { sequence = [{ send = "1" }, { send = "A", delay_ms = 2000 }] }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SequenceAction {
+    pub sequence: Vec<SequenceStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SequenceStep {
+    pub send: String,
+    /// Pause before sending, after the previous step
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub delay_ms: u64,
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+impl SequenceAction {
+    /// Parse the one-line form used by the keybind editors:
+    /// "1; wait 2; A; wait 1.5; look". `;` separates steps and
+    /// "wait <seconds>" pauses before the next command.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut sequence = Vec::new();
+        let mut delay_ms = 0;
+        for part in text
+            .split(';')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            let wait = part
+                .get(..5)
+                .filter(|head| head.eq_ignore_ascii_case("wait "))
+                .map(|_| &part[5..]);
+            if let Some(seconds) = wait {
+                let seconds: f64 = seconds
+                    .trim()
+                    .trim_end_matches('s')
+                    .parse()
+                    .map_err(|_| format!("can't read '{}' (wait 2, wait 0.5)", part))?;
+                if !(0.0..=600.0).contains(&seconds) {
+                    return Err(format!("'{}' must be 0-600 seconds", part));
+                }
+                delay_ms += (seconds * 1000.0).round() as u64;
+            } else {
+                sequence.push(SequenceStep {
+                    send: part.to_string(),
+                    delay_ms,
+                });
+                delay_ms = 0;
+            }
+        }
+        if sequence.is_empty() {
+            return Err("a sequence needs at least one command".to_string());
+        }
+        Ok(Self { sequence })
+    }
+
+    /// The one-line form `parse` reads
+    pub fn to_text(&self) -> String {
+        let mut parts = Vec::new();
+        for step in &self.sequence {
+            if step.delay_ms > 0 {
+                let seconds = step.delay_ms as f64 / 1000.0;
+                parts.push(format!("wait {}", seconds));
+            }
+            parts.push(step.send.clone());
+        }
+        parts.join("; ")
+    }
+}
+
+/// A registered client function called with arguments, e.g.
+/// `scroll_window("main", -10)`. The names and what they take live in
+/// `core::action_registry`.
+///
+/// ```toml
+/// "alt+up" = { call = "scroll_window", args = ["main", "10"] }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CallAction {
+    pub call: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+}
+
+impl CallAction {
+    /// Parse `name(arg, "quoted, arg", -10)`. Parentheses are optional when
+    /// there are no arguments; quotes are needed only around commas.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let (call, rest) = match text.split_once('(') {
+            Some((name, rest)) => {
+                let inner = rest
+                    .trim_end()
+                    .strip_suffix(')')
+                    .ok_or_else(|| format!("missing ')' in '{}'", text))?;
+                (name.trim(), Some(inner))
+            }
+            None => (text, None),
+        };
+        if call.is_empty() || !call.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!("'{}' isn't a function name", call));
+        }
+        let mut args = Vec::new();
+        if let Some(inner) = rest.filter(|inner| !inner.trim().is_empty()) {
+            let mut current = String::new();
+            let mut quoted = false;
+            for c in inner.chars() {
+                match c {
+                    '"' => quoted = !quoted,
+                    ',' if !quoted => args.push(std::mem::take(&mut current).trim().to_string()),
+                    _ => current.push(c),
+                }
+            }
+            if quoted {
+                return Err(format!("unclosed quote in '{}'", text));
+            }
+            args.push(current.trim().to_string());
+        }
+        Ok(Self {
+            call: call.to_string(),
+            args,
+        })
+    }
+
+    /// The form `parse` reads; text arguments are quoted
+    pub fn to_text(&self) -> String {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| {
+                if arg.parse::<i64>().is_ok() {
+                    arg.clone()
+                } else {
+                    format!("\"{}\"", arg)
+                }
+            })
+            .collect();
+        format!("{}({})", self.call, args.join(", "))
+    }
+}
+
 impl KeyBindAction {
     /// Returns the type name of this keybind action
     pub fn type_name(&self) -> &'static str {
         match self {
             KeyBindAction::Action(_) => "Action",
             KeyBindAction::Macro(_) => "Macro",
+            KeyBindAction::Sequence(_) => "Sequence",
+            KeyBindAction::Call(_) => "Function",
         }
     }
 
@@ -27,6 +179,8 @@ impl KeyBindAction {
         match self {
             KeyBindAction::Action(a) => a.clone(),
             KeyBindAction::Macro(m) => m.macro_text.clone(),
+            KeyBindAction::Sequence(s) => s.to_text(),
+            KeyBindAction::Call(c) => c.to_text(),
         }
     }
 }
@@ -136,9 +290,22 @@ pub enum KeyAction {
     Paste,     // Paste from clipboard
     SelectAll, // Select all text in command input
 
+    // Input
+    OpenCompose,  // Multi-line compose popup for long commands
+    SpellSuggest, // Corrections for the misspelled word at the cursor
+    CommandLog,   // Fuzzy-search sent commands and resend one
+    ReplyWhisper, // Pre-fill "whisper <last whisperer> " in the input
+    ListUrls,     // List recent URLs from game text as links
+
+    // Layout editing
+    LayoutUndo, // Undo the last window move/resize/add/hide
+    LayoutRedo, // Redo the last undone layout edit
+
     // System toggles
     TogglePerformanceStats, // Show/hide performance overlay
     ToggleSounds,           // Enable/disable sound system
+    ToggleFloatingWindows,  // Show/hide all floating windows
+    ToggleBossKey,          // Blank the screen and mute sounds/TTS (press again to restore)
 
     // Travel
     StopTravel, // Cancel the active .go2 trip (Esc does this by default)
@@ -148,11 +315,14 @@ pub enum KeyAction {
     TtsPrevious,       // Previous message (sequential, includes read)
     TtsNextUnread,     // Skip to next unread message
     TtsStop,           // Stop current speech (keeps position)
+    TtsSkip,           // Skip current utterance, play next unread
+    TtsFlush,          // Stop speech and clear the queue
     TtsMuteToggle,     // Toggle TTS mute on/off
     TtsIncreaseRate,   // Increase speech rate by 0.1
     TtsDecreaseRate,   // Decrease speech rate by 0.1
     TtsIncreaseVolume, // Increase volume by 0.1
     TtsDecreaseVolume, // Decrease volume by 0.1
+    ReadScreen,        // Read visible windows in reading order
 
     // Macro - send literal text
     SendMacro(String),
@@ -195,6 +365,8 @@ pub struct MenuKeybinds {
     pub save: String,
     #[serde(default = "default_delete")]
     pub delete: String,
+    #[serde(default = "default_undo")]
+    pub undo: String,
 
     // Text Editing (Clipboard)
     #[serde(default = "default_select_all")]
@@ -272,6 +444,9 @@ fn default_save() -> String {
 fn default_delete() -> String {
     "Delete".to_string()
 }
+fn default_undo() -> String {
+    "Ctrl+Z".to_string()
+}
 fn default_select_all() -> String {
     "Ctrl+A".to_string()
 }
@@ -326,6 +501,7 @@ impl Default for MenuKeybinds {
             cancel: default_cancel(),
             save: default_save(),
             delete: default_delete(),
+            undo: default_undo(),
             select_all: default_select_all(),
             copy: default_copy(),
             cut: default_cut(),
@@ -472,6 +648,9 @@ impl MenuKeybinds {
             if key_lower == self.toggle_filter.to_lowercase() {
                 return MenuAction::ToggleFilter;
             }
+            if key_lower == self.undo.to_lowercase() {
+                return MenuAction::Undo;
+            }
         }
 
         if key_lower == self.cycle_forward.to_lowercase() {
@@ -521,19 +700,31 @@ impl KeyAction {
             "copy" => Some(Self::Copy),
             "paste" => Some(Self::Paste),
             "select_all" => Some(Self::SelectAll),
+            "open_compose" => Some(Self::OpenCompose),
+            "spell_suggest" => Some(Self::SpellSuggest),
+            "command_log" => Some(Self::CommandLog),
+            "reply_whisper" => Some(Self::ReplyWhisper),
+            "list_urls" => Some(Self::ListUrls),
+            "layout_undo" => Some(Self::LayoutUndo),
+            "layout_redo" => Some(Self::LayoutRedo),
             "toggle_performance_stats" => Some(Self::TogglePerformanceStats),
             "toggle_sounds" => Some(Self::ToggleSounds),
+            "toggle_floating_windows" => Some(Self::ToggleFloatingWindows),
+            "toggle_boss_key" => Some(Self::ToggleBossKey),
             "stop_travel" => Some(Self::StopTravel),
             "tts_next" => Some(Self::TtsNext),
             "tts_previous" => Some(Self::TtsPrevious),
             "tts_next_unread" => Some(Self::TtsNextUnread),
             "tts_stop" => Some(Self::TtsStop),
             "tts_pause_resume" => Some(Self::TtsStop), // Legacy support
+            "tts_skip" => Some(Self::TtsSkip),
+            "tts_flush" => Some(Self::TtsFlush),
             "tts_mute_toggle" => Some(Self::TtsMuteToggle),
             "tts_increase_rate" => Some(Self::TtsIncreaseRate),
             "tts_decrease_rate" => Some(Self::TtsDecreaseRate),
             "tts_increase_volume" => Some(Self::TtsIncreaseVolume),
             "tts_decrease_volume" => Some(Self::TtsDecreaseVolume),
+            "read_screen" => Some(Self::ReadScreen),
             _ => None,
         }
     }
@@ -574,22 +765,35 @@ pub fn parse_key_string(key_str: &str) -> Option<(KeyCode, KeyModifiers)> {
     }
 
     // For keys with modifiers, we need to carefully parse
-    // Split by + but be aware that num_+ contains a literal +
-    let parts: Vec<&str> = key_str.split('+').collect();
+    // Split by + but be aware that num_+ contains a literal +, and that the
+    // '+' key itself is written "+" or "ctrl++"
+    let (modifier_str, mut key_part) = if key_str == "+" {
+        ("", "+")
+    } else if let Some(prefix) = key_str.strip_suffix("++") {
+        (prefix, "+")
+    } else {
+        ("", key_str)
+    };
     let mut modifiers = KeyModifiers::NONE;
-    let mut key_part = key_str;
+    let mut parts: Vec<&str> = Vec::new();
+    if !modifier_str.is_empty() {
+        parts = modifier_str.split('+').collect();
+    } else if key_part != "+" {
+        let split: Vec<&str> = key_str.split('+').collect();
+        if split.len() > 1 {
+            key_part = split[split.len() - 1];
+            parts = split[..split.len() - 1].to_vec();
+        }
+    }
 
     // Parse modifiers
-    if parts.len() > 1 {
-        for part in &parts[..parts.len() - 1] {
-            match part.to_lowercase().as_str() {
-                "ctrl" | "control" => modifiers.ctrl = true,
-                "alt" => modifiers.alt = true,
-                "shift" => modifiers.shift = true,
-                _ => return None,
-            }
+    for part in parts {
+        match part {
+            "ctrl" | "control" => modifiers.ctrl = true,
+            "alt" => modifiers.alt = true,
+            "shift" => modifiers.shift = true,
+            _ => return None,
         }
-        key_part = parts[parts.len() - 1];
     }
 
     // Parse the actual key
@@ -642,8 +846,8 @@ pub fn parse_key_string(key_str: &str) -> Option<(KeyCode, KeyModifiers)> {
         "f11" => KeyCode::F(11),
         "f12" => KeyCode::F(12),
 
-        // Single character
-        s if s.len() == 1 => {
+        // Single character (any script: é, ß, ñ from international layouts)
+        s if s.chars().count() == 1 => {
             let ch = s.chars().next().unwrap();
             KeyCode::Char(ch)
         }
@@ -654,6 +858,71 @@ pub fn parse_key_string(key_str: &str) -> Option<(KeyCode, KeyModifiers)> {
     Some((key_code, modifiers))
 }
 
+/// Inverse of [`parse_key_string`]: the keybinds.toml spelling of a key as
+/// the terminal reported it, or None when the key has no config name.
+pub fn format_key_string(code: KeyCode, modifiers: KeyModifiers) -> Option<String> {
+    let mut modifiers = modifiers;
+    let key = match code {
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) if c.is_uppercase() => {
+            // Terminals report shift+letter as the uppercase letter
+            modifiers.shift = true;
+            c.to_lowercase().collect()
+        }
+        KeyCode::Char(c) if c.is_control() => return None,
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Delete => "delete".to_string(),
+        KeyCode::Insert => "insert".to_string(),
+        KeyCode::Tab => "tab".to_string(),
+        KeyCode::BackTab => {
+            modifiers.shift = true;
+            "tab".to_string()
+        }
+        KeyCode::Esc => "esc".to_string(),
+        KeyCode::Left => "left".to_string(),
+        KeyCode::Right => "right".to_string(),
+        KeyCode::Up => "up".to_string(),
+        KeyCode::Down => "down".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::PageUp => "page_up".to_string(),
+        KeyCode::PageDown => "page_down".to_string(),
+        KeyCode::F(n) if (1..=12).contains(&n) => format!("f{}", n),
+        KeyCode::Keypad0 => "num_0".to_string(),
+        KeyCode::Keypad1 => "num_1".to_string(),
+        KeyCode::Keypad2 => "num_2".to_string(),
+        KeyCode::Keypad3 => "num_3".to_string(),
+        KeyCode::Keypad4 => "num_4".to_string(),
+        KeyCode::Keypad5 => "num_5".to_string(),
+        KeyCode::Keypad6 => "num_6".to_string(),
+        KeyCode::Keypad7 => "num_7".to_string(),
+        KeyCode::Keypad8 => "num_8".to_string(),
+        KeyCode::Keypad9 => "num_9".to_string(),
+        // Symbol keypad keys only parse without modifiers ("num_+" splits)
+        KeyCode::KeypadPeriod if modifiers == KeyModifiers::NONE => "num_.".to_string(),
+        KeyCode::KeypadPlus if modifiers == KeyModifiers::NONE => "num_+".to_string(),
+        KeyCode::KeypadMinus if modifiers == KeyModifiers::NONE => "num_-".to_string(),
+        KeyCode::KeypadMultiply if modifiers == KeyModifiers::NONE => "num_*".to_string(),
+        KeyCode::KeypadDivide if modifiers == KeyModifiers::NONE => "num_/".to_string(),
+        _ => return None,
+    };
+
+    let mut out = String::new();
+    if modifiers.ctrl {
+        out.push_str("ctrl+");
+    }
+    if modifiers.alt {
+        out.push_str("alt+");
+    }
+    if modifiers.shift {
+        out.push_str("shift+");
+    }
+    out.push_str(&key);
+    Some(out)
+}
+
 impl Config {
     /// Load common (global) keybinds that apply to all characters
     /// Returns: HashMap of global keybinds, or empty if file doesn't exist
@@ -752,7 +1021,8 @@ impl Config {
         let keybinds_path = Self::keybinds_path(character)?;
         let contents =
             toml::to_string_pretty(&self.keybinds).context("Failed to serialize keybinds")?;
-        fs::write(&keybinds_path, contents).context("Failed to write keybinds.toml")?;
+        fs::write(&keybinds_path, schema::stamp(&contents))
+            .context("Failed to write keybinds.toml")?;
         Ok(())
     }
 
@@ -806,6 +1076,12 @@ impl Config {
                     );
                     toml::Value::Table(macro_table)
                 }
+                KeyBindAction::Sequence(s) => {
+                    toml::Value::try_from(s).context("Failed to serialize sequence keybind")?
+                }
+                KeyBindAction::Call(c) => {
+                    toml::Value::try_from(c).context("Failed to serialize function keybind")?
+                }
             };
             user_table.insert(key.to_string(), action_value);
         }
@@ -1503,6 +1779,56 @@ mod tests {
         assert!(parse_key_string("ctrl+").is_none());
     }
 
+    #[test]
+    fn test_parse_key_string_plus_and_non_ascii() {
+        assert_eq!(
+            parse_key_string("+"),
+            Some((KeyCode::Char('+'), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_key_string("ctrl++"),
+            Some((KeyCode::Char('+'), KeyModifiers::CTRL))
+        );
+        assert_eq!(
+            parse_key_string("alt+é"),
+            Some((KeyCode::Char('é'), KeyModifiers::ALT))
+        );
+    }
+
+    #[test]
+    fn test_format_key_string_round_trips() {
+        let keys = [
+            (KeyCode::Char('a'), KeyModifiers::CTRL),
+            (KeyCode::Char('+'), KeyModifiers::CTRL),
+            (KeyCode::Char('é'), KeyModifiers::ALT),
+            (KeyCode::Char(' '), KeyModifiers::NONE),
+            (KeyCode::F(5), KeyModifiers::SHIFT),
+            (KeyCode::Keypad7, KeyModifiers::NONE),
+            (KeyCode::KeypadPlus, KeyModifiers::NONE),
+            (
+                KeyCode::PageUp,
+                KeyModifiers {
+                    ctrl: true,
+                    shift: false,
+                    alt: true,
+                },
+            ),
+        ];
+        for (code, mods) in keys {
+            let text = format_key_string(code, mods).unwrap();
+            assert_eq!(parse_key_string(&text), Some((code, mods)), "{}", text);
+        }
+        assert_eq!(
+            format_key_string(KeyCode::Char('Q'), KeyModifiers::SHIFT).as_deref(),
+            Some("shift+q")
+        );
+        assert_eq!(
+            format_key_string(KeyCode::BackTab, KeyModifiers::NONE).as_deref(),
+            Some("shift+tab")
+        );
+        assert!(format_key_string(KeyCode::Null, KeyModifiers::NONE).is_none());
+    }
+
     #[test]
     fn test_parse_key_string_invalid_modifier() {
         assert!(parse_key_string("meta+a").is_none());
@@ -1667,6 +1993,14 @@ mod tests {
             KeyAction::from_str("toggle_sounds"),
             Some(KeyAction::ToggleSounds)
         );
+        assert_eq!(
+            KeyAction::from_str("toggle_floating_windows"),
+            Some(KeyAction::ToggleFloatingWindows)
+        );
+        assert_eq!(
+            KeyAction::from_str("toggle_boss_key"),
+            Some(KeyAction::ToggleBossKey)
+        );
     }
 
     #[test]
@@ -1705,6 +2039,37 @@ mod tests {
             KeyAction::from_str("tts_decrease_volume"),
             Some(KeyAction::TtsDecreaseVolume)
         );
+        assert_eq!(KeyAction::from_str("tts_skip"), Some(KeyAction::TtsSkip));
+        assert_eq!(KeyAction::from_str("tts_flush"), Some(KeyAction::TtsFlush));
+        assert_eq!(
+            KeyAction::from_str("read_screen"),
+            Some(KeyAction::ReadScreen)
+        );
+        assert_eq!(
+            KeyAction::from_str("open_compose"),
+            Some(KeyAction::OpenCompose)
+        );
+        assert_eq!(
+            KeyAction::from_str("spell_suggest"),
+            Some(KeyAction::SpellSuggest)
+        );
+        assert_eq!(
+            KeyAction::from_str("command_log"),
+            Some(KeyAction::CommandLog)
+        );
+        assert_eq!(
+            KeyAction::from_str("reply_whisper"),
+            Some(KeyAction::ReplyWhisper)
+        );
+        assert_eq!(KeyAction::from_str("list_urls"), Some(KeyAction::ListUrls));
+        assert_eq!(
+            KeyAction::from_str("layout_undo"),
+            Some(KeyAction::LayoutUndo)
+        );
+        assert_eq!(
+            KeyAction::from_str("layout_redo"),
+            Some(KeyAction::LayoutRedo)
+        );
     }
 
     #[test]
@@ -1776,6 +2141,7 @@ mod tests {
         assert_eq!(keybinds.cancel, "Esc");
         assert_eq!(keybinds.save, "Ctrl+s");
         assert_eq!(keybinds.delete, "Delete");
+        assert_eq!(keybinds.undo, "Ctrl+Z");
     }
 
     #[test]
@@ -1890,6 +2256,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sequence_text_round_trips() {
+        let action = SequenceAction::parse("1; wait 2; A;; Wait 0.5s; look").unwrap();
+        assert_eq!(
+            action.sequence,
+            vec![
+                SequenceStep {
+                    send: "1".to_string(),
+                    delay_ms: 0,
+                },
+                SequenceStep {
+                    send: "A".to_string(),
+                    delay_ms: 2000,
+                },
+                SequenceStep {
+                    send: "look".to_string(),
+                    delay_ms: 500,
+                },
+            ]
+        );
+        assert_eq!(action.to_text(), "1; wait 2; A; wait 0.5; look");
+        assert!(SequenceAction::parse("wait 2").is_err());
+        assert!(SequenceAction::parse("look; wait soon; north").is_err());
+    }
+
+    #[test]
+    fn call_text_round_trips() {
+        let call = CallAction::parse(r#"scroll_window("main", -10)"#).unwrap();
+        assert_eq!(call.call, "scroll_window");
+        assert_eq!(call.args, ["main", "-10"]);
+        assert_eq!(call.to_text(), r#"scroll_window("main", -10)"#);
+        let call = CallAction::parse(r#"switch_tab( "a, b" )"#).unwrap();
+        assert_eq!(call.args, ["a, b"]);
+        assert_eq!(CallAction::parse("read_screen").unwrap().args.len(), 0);
+        assert_eq!(
+            CallAction::parse("read_screen()").unwrap().to_text(),
+            "read_screen()"
+        );
+        assert!(CallAction::parse("scroll_window(\"main\"").is_err());
+        assert!(CallAction::parse("say hi(").is_err());
+    }
+
+    #[test]
+    fn sequence_keybinds_load_from_toml() {
+        let keybinds: HashMap<String, KeyBindAction> = toml::from_str(
+            r#"
+            f1 = "next_tab"
+            f2 = { macro_text = "look\r" }
+            f12 = { sequence = [{ send = "1" }, { send = "A", delay_ms = 2000 }] }
+            f9 = { call = "switch_tab", args = ["deaths"] }
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(keybinds.get("f2"), Some(KeyBindAction::Macro(_))));
+        match keybinds.get("f12") {
+            Some(KeyBindAction::Sequence(s)) => assert_eq!(s.to_text(), "1; wait 2; A"),
+            other => panic!("expected a sequence, got {:?}", other),
+        }
+        match keybinds.get("f9") {
+            Some(KeyBindAction::Call(c)) => assert_eq!(c.to_text(), r#"switch_tab("deaths")"#),
+            other => panic!("expected a call, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_macro_action_clone() {
         let macro_action = MacroAction {
@@ -26,6 +26,32 @@ impl LayoutMapping {
     }
 }
 
+/// Optional `[meta]` table describing a layout in the gallery
+/// (`.layouts browse`). `Layout` ignores the table when parsing, so any
+/// layout file can carry one and older builds still load it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutMeta {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    /// URL the layout was downloaded from (set by `.layouts get`)
+    pub source: Option<String>,
+}
+
+impl LayoutMeta {
+    /// The `[meta]` table of a layout file; empty when absent or malformed
+    pub fn from_toml(contents: &str) -> Self {
+        #[derive(Deserialize)]
+        struct MetaOnly {
+            #[serde(default)]
+            meta: LayoutMeta,
+        }
+        toml::from_str::<MetaOnly>(contents)
+            .map(|file| file.meta)
+            .unwrap_or_default()
+    }
+}
+
 // CommandInputConfig removed - command_input is now a regular window in the windows array
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -46,6 +72,10 @@ pub struct Layout {
     pub base_layout: Option<String>, // Reference to base layout (for auto layouts)
     #[serde(default)]
     pub theme: Option<String>, // Theme applied when this layout was saved
+    /// Saved keybind profile (`.savekeybinds <name>`) overlaid on the normal
+    /// bindings while this layout is loaded
+    #[serde(default)]
+    pub keybind_profile: Option<String>,
     /// Windows whose widget_type this build can't deserialize (e.g. a layout
     /// saved by a build from another branch/version). Skipped at runtime but
     /// carried through saves so switching builds doesn't destroy them.
@@ -204,8 +234,13 @@ impl Layout {
         Ok((layout, Some("layout".to_string())))
     }
 
-    /// Scale all windows proportionally to fit new terminal size
-    pub fn scale_to_terminal_size(&mut self, new_width: u16, new_height: u16) {
+    /// Scale all windows proportionally to fit new terminal size.
+    ///
+    /// Edges are rounded rather than sizes, so windows that touched before
+    /// still touch afterwards. Sizes are then held to each window's min/max
+    /// constraints and the screen. Returns the names of windows whose size
+    /// a constraint changed.
+    pub fn scale_to_terminal_size(&mut self, new_width: u16, new_height: u16) -> Vec<String> {
         let base_width = self.terminal_width.unwrap_or(new_width);
         let base_height = self.terminal_height.unwrap_or(new_height);
 
@@ -215,7 +250,7 @@ impl Layout {
                 base_width,
                 base_height
             );
-            return;
+            return Vec::new();
         }
 
         let scale_x = new_width as f32 / base_width as f32;
@@ -231,6 +266,8 @@ impl Layout {
             scale_y
         );
 
+        let scale_edge = |edge: u16, scale: f32| (edge as f32 * scale).round() as u16;
+        let mut constrained = Vec::new();
         for window in &mut self.windows {
             // Capture name and type before mutable borrow
             let window_name = window.name().to_string();
@@ -242,18 +279,14 @@ impl Layout {
             let old_cols = base.cols;
             let old_rows = base.rows;
 
-            base.col = (base.col as f32 * scale_x).round() as u16;
-            base.row = (base.row as f32 * scale_y).round() as u16;
-            base.cols = (base.cols as f32 * scale_x).round() as u16;
-            base.rows = (base.rows as f32 * scale_y).round() as u16;
-
+            let right = scale_edge(base.col.saturating_add(base.cols), scale_x);
+            let bottom = scale_edge(base.row.saturating_add(base.rows), scale_y);
+            base.col = scale_edge(base.col, scale_x);
+            base.row = scale_edge(base.row, scale_y);
             // Ensure minimum sizes
-            if base.cols < 1 {
-                base.cols = 1;
-            }
-            if base.rows < 1 {
-                base.rows = 1;
-            }
+            base.cols = right.saturating_sub(base.col).max(1);
+            base.rows = bottom.saturating_sub(base.row).max(1);
+            let (scaled_cols, scaled_rows) = (base.cols, base.rows);
 
             // Respect min/max constraints if set
             if let Some(min_cols) = base.min_cols {
@@ -276,6 +309,17 @@ impl Layout {
                     base.rows = max_rows;
                 }
             }
+            if (base.cols, base.rows) != (scaled_cols, scaled_rows) {
+                constrained.push(window_name.clone());
+            }
+
+            // Keep the window on screen (a min size can push it past an edge)
+            if new_width > 0 && new_height > 0 {
+                base.cols = base.cols.min(new_width);
+                base.rows = base.rows.min(new_height);
+                base.col = base.col.min(new_width - base.cols);
+                base.row = base.row.min(new_height - base.rows);
+            }
 
             tracing::debug!(
                 "  {} [{}]: pos {}x{} -> {}x{}, size {}x{} -> {}x{}",
@@ -295,6 +339,7 @@ impl Layout {
         // Update terminal size to new size
         self.terminal_width = Some(new_width);
         self.terminal_height = Some(new_height);
+        constrained
     }
 
     /// Parse a layout, tolerating window entries this build can't
@@ -704,6 +749,64 @@ impl Layout {
         Ok(())
     }
 
+    /// A name no window in the layout uses, derived from `name`:
+    /// "loot" becomes "loot_2", "loot_2" becomes "loot_3", and so on.
+    pub fn unique_window_name(&self, name: &str) -> String {
+        let stem = match name.rsplit_once('_') {
+            Some((stem, num)) if !stem.is_empty() && num.parse::<u32>().is_ok() => stem,
+            _ => name,
+        };
+        (2..)
+            .map(|n| format!("{}_{}", stem, n))
+            .find(|candidate| self.get_window(candidate).is_none())
+            .expect("unbounded name search")
+    }
+
+    /// Clone window `name` under a fresh name, nudged down and right so it
+    /// doesn't hide the original. Returns the new window's name.
+    pub fn duplicate_window(&mut self, name: &str) -> Result<String> {
+        let mut copy = self
+            .get_window(name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Window not found: {}", name))?;
+        let new_name = self.unique_window_name(name);
+        let base = copy.base_mut();
+        base.name = new_name.clone();
+        base.visible = true;
+        base.locked = false;
+        base.col = base.col.saturating_add(2);
+        base.row = base.row.saturating_add(1);
+        if let Some(width) = self.terminal_width {
+            base.col = base.col.min(width.saturating_sub(base.cols));
+        }
+        if let Some(height) = self.terminal_height {
+            base.row = base.row.min(height.saturating_sub(base.rows));
+        }
+        self.windows.push(copy);
+        tracing::info!("Duplicated window '{}' as '{}'", name, new_name);
+        Ok(new_name)
+    }
+
+    /// Serialize window definitions as a `[[windows]]` TOML snippet, the
+    /// same shape they have in a layout file, for the clipboard
+    pub fn windows_to_toml(windows: &[WindowDef]) -> Result<String> {
+        #[derive(Serialize)]
+        struct Snippet<'a> {
+            windows: &'a [WindowDef],
+        }
+        toml::to_string_pretty(&Snippet { windows }).context("Failed to serialize window")
+    }
+
+    /// Parse window definitions from a `[[windows]]` TOML snippet (or a
+    /// whole layout file). Entries of unknown widget types are skipped.
+    pub fn windows_from_toml(contents: &str) -> Result<Vec<WindowDef>> {
+        let layout = Self::parse_tolerant(contents, "clipboard")?;
+        if layout.windows.is_empty() {
+            anyhow::bail!("No [[windows]] entries found");
+        }
+        Ok(layout.windows)
+    }
+
     /// Hide a window (set visible = false)
     pub fn hide_window(&mut self, name: &str) -> Result<()> {
         let window = self
@@ -818,6 +921,62 @@ zoom = 3
         assert_eq!(reparsed.unknown_windows.len(), 1);
     }
 
+    #[test]
+    fn scaling_keeps_shared_edges_and_limits() {
+        let mut layout = Layout::parse_tolerant(
+            r#"
+terminal_width = 90
+terminal_height = 30
+
+[[windows]]
+widget_type = "text"
+name = "left"
+row = 0
+col = 0
+rows = 30
+cols = 31
+
+[[windows]]
+widget_type = "text"
+name = "right"
+row = 0
+col = 31
+rows = 30
+cols = 59
+max_cols = 60
+"#,
+            "test",
+        )
+        .expect("parse");
+        let held = layout.scale_to_terminal_size(140, 45);
+        let left = layout.windows[0].base();
+        let right = layout.windows[1].base();
+        // The shared edge at 31 scales to 48.2 and both windows round it to 48
+        assert_eq!((left.cols, left.rows), (48, 45));
+        assert_eq!(right.col, left.col + left.cols);
+        assert_eq!(right.cols, 60);
+        assert_eq!(held, vec!["right".to_string()]);
+        assert_eq!(layout.terminal_width, Some(140));
+    }
+
+    #[test]
+    fn duplicated_windows_get_new_names_and_round_trip_as_toml() {
+        let mut layout = Layout::parse_tolerant(MIXED_LAYOUT, "test").expect("parse");
+        assert_eq!(layout.duplicate_window("main").unwrap(), "main_2");
+        assert_eq!(layout.duplicate_window("main_2").unwrap(), "main_3");
+        assert!(layout.duplicate_window("missing").is_err());
+        // Nudged right, but a full-width window can't move off screen
+        let copy = layout.get_window("main_2").unwrap().base();
+        assert_eq!((copy.col, copy.row, copy.cols), (0, 1, 120));
+
+        let snippet = Layout::windows_to_toml(&layout.windows[1..]).unwrap();
+        assert!(snippet.starts_with("[[windows]]"));
+        let pasted = Layout::windows_from_toml(&snippet).unwrap();
+        assert_eq!(pasted.len(), 2);
+        assert_eq!(pasted[0], layout.windows[1]);
+        assert!(Layout::windows_from_toml("terminal_width = 80").is_err());
+    }
+
     #[test]
     fn tolerant_parse_still_fails_on_real_corruption() {
         assert!(Layout::parse_tolerant("windows = 5", "test").is_err());
@@ -175,6 +175,7 @@ fn check_duplicates(issues: &mut Vec<ValidationIssue>, keybinds: &MenuKeybinds)
     add_binding(&mut keybind_map, &keybinds.cancel, "cancel");
     add_binding(&mut keybind_map, &keybinds.save, "save");
     add_binding(&mut keybind_map, &keybinds.delete, "delete");
+    add_binding(&mut keybind_map, &keybinds.undo, "undo");
     add_binding(&mut keybind_map, &keybinds.select_all, "select_all");
     add_binding(&mut keybind_map, &keybinds.copy, "copy");
     add_binding(&mut keybind_map, &keybinds.cut, "cut");
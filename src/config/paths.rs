@@ -4,6 +4,10 @@
 //! profile paths, and dialog-position persistence (widget_state.toml).
 
 use super::*;
+use std::sync::RwLock;
+
+/// Sounds directory from the loaded profile's `[paths] sounds_dir`
+static SOUNDS_DIR_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
 
 /// Saved dialog position for persistence across sessions
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,7 +70,7 @@ impl Config {
 
     /// Get the shared layouts directory (where .savelayout saves to)
     /// Returns: ~/.vellum-fe/layouts/
-    pub(super) fn layouts_dir() -> Result<PathBuf> {
+    pub fn layouts_dir() -> Result<PathBuf> {
         Ok(Self::config_dir()?.join("layouts"))
     }
 
@@ -88,12 +92,49 @@ impl Config {
         Ok(Self::config_dir()?.join("global"))
     }
 
-    /// Get the shared sounds directory
+    /// Get the sounds directory: the loaded profile's `[paths] sounds_dir`,
+    /// else the shared one
     /// Returns: ~/.vellum-fe/global/sounds/
     pub fn sounds_dir() -> Result<PathBuf> {
+        if let Some(dir) = SOUNDS_DIR_OVERRIDE.read().ok().and_then(|dir| dir.clone()) {
+            return Ok(dir);
+        }
         Ok(Self::global_dir()?.join("sounds"))
     }
 
+    /// Get the log directory: `[paths] logs_dir`, else `[logging] dir`
+    /// Returns: ~/.vellum-fe/{character}/logs/
+    pub fn logs_dir(&self) -> Result<PathBuf> {
+        match &self.paths.logs_dir {
+            Some(dir) => PathsConfig::resolve(dir, self.character.as_deref()),
+            None => self.logging.resolve_dir(self.character.as_deref()),
+        }
+    }
+
+    /// Put the profile's `[paths]` into effect: the sounds directory every
+    /// sound lookup uses, and the environment variables. Process-wide, so
+    /// startup calls it once for the session's profile; loading a config
+    /// (the web doll, editors) leaves both alone.
+    pub fn apply_profile_paths(&self) {
+        let character = self.character.as_deref();
+        let sounds = self.paths.sounds_dir.as_deref().and_then(|dir| {
+            PathsConfig::resolve(dir, character)
+                .map_err(|e| tracing::warn!("Ignoring [paths] sounds_dir '{}': {:#}", dir, e))
+                .ok()
+        });
+        if let Ok(mut slot) = SOUNDS_DIR_OVERRIDE.write() {
+            *slot = sounds;
+        }
+        for (key, value) in &self.paths.env {
+            // The data directory is fixed before any profile is read
+            if key.is_empty() || key.contains('=') || key == "VELLUM_FE_DIR" {
+                tracing::warn!("Ignoring [paths.env] {}", key);
+                continue;
+            }
+            std::env::set_var(key, value);
+        }
+    }
+
     /// Get the shared skins directory (one subdirectory per skin, each with a
     /// skin.toml manifest plus its image assets)
     /// Returns: ~/.vellum-fe/skins/
@@ -101,6 +142,37 @@ impl Config {
         Ok(Self::config_dir()?.join("skins"))
     }
 
+    /// Get the art pack directory (images named after creatures/items,
+    /// e.g. `troll.png`, `vultite-greatsword.webp`; subfolders allowed)
+    /// Returns: ~/.vellum-fe/art/
+    pub fn art_dir() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("art"))
+    }
+
+    /// Get the directory `.export` writes relative file names to
+    /// Returns: ~/.vellum-fe/exports/
+    pub fn exports_dir() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("exports"))
+    }
+
+    /// Get the directory window tee files go to, in the profile's logs
+    /// Returns: ~/.vellum-fe/{character}/logs/tee/
+    pub fn tee_dir(&self) -> Result<PathBuf> {
+        Ok(self.logs_dir()?.join("tee"))
+    }
+
+    /// Get path to the remembered item values file (see data::itemdb)
+    /// Returns: ~/.vellum-fe/global/itemdb.toml
+    pub fn item_db_path() -> Result<PathBuf> {
+        Ok(Self::global_dir()?.join("itemdb.toml"))
+    }
+
+    /// Get path to the personal spellcheck dictionary (see core::spellcheck)
+    /// Returns: ~/.vellum-fe/global/dictionary.txt
+    pub fn dictionary_path() -> Result<PathBuf> {
+        Ok(Self::global_dir()?.join("dictionary.txt"))
+    }
+
     /// Get path to common (global) highlights file
     /// Returns: ~/.vellum-fe/global/highlights.toml
     pub fn common_highlights_path() -> Result<PathBuf> {
@@ -211,12 +283,47 @@ impl Config {
         Ok(Self::profile_dir(character)?.join("hotbars.toml"))
     }
 
+    /// Get path to spell_sets.toml (`.rebuff` sets) for a character
+    /// Returns: ~/.vellum-fe/{character}/spell_sets.toml
+    pub fn spell_sets_path(character: Option<&str>) -> Result<PathBuf> {
+        Ok(Self::profile_dir(character)?.join("spell_sets.toml"))
+    }
+
+    /// Get path to reminders.toml (`.remind`) for a character
+    /// Returns: ~/.vellum-fe/{character}/reminders.toml
+    pub fn reminders_path(character: Option<&str>) -> Result<PathBuf> {
+        Ok(Self::profile_dir(character)?.join("reminders.toml"))
+    }
+
+    /// Get path to played.toml (`.played`) for a character
+    /// Returns: ~/.vellum-fe/{character}/played.toml
+    pub fn played_path(character: Option<&str>) -> Result<PathBuf> {
+        Ok(Self::profile_dir(character)?.join("played.toml"))
+    }
+
+    /// Get path to notes.md (notes widget / `.notes`) for a character
+    /// Returns: ~/.vellum-fe/{character}/notes.md
+    pub fn notes_path(character: Option<&str>) -> Result<PathBuf> {
+        Ok(Self::profile_dir(character)?.join("notes.md"))
+    }
+
     /// Get path to auto-saved layout.toml for a character
     /// Returns: ~/.vellum-fe/{character}/layout.toml
     pub fn auto_layout_path(character: Option<&str>) -> Result<PathBuf> {
         Ok(Self::profile_dir(character)?.join("layout.toml"))
     }
 
+    /// Names of the layouts shipped with the client (extracted into the
+    /// shared layouts directory on first run)
+    pub fn bundled_layout_names() -> Vec<String> {
+        let mut names: Vec<String> = LAYOUTS_DIR
+            .files()
+            .filter_map(|file| file.path().file_stem()?.to_str().map(str::to_string))
+            .collect();
+        names.sort();
+        names
+    }
+
     /// List all saved layouts
     pub fn list_layouts() -> Result<Vec<String>> {
         let layouts_dir = Self::config_dir()?.join("layouts");
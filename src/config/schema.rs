@@ -0,0 +1,318 @@
+//! Versioned upgrades for the TOML config files.
+//!
+//! config.toml, colors.toml, highlights.toml, keybinds.toml, hotbars.toml,
+//! macros.toml, variables.toml and custom themes carry a top-level
+//! `schema_version` (missing = 0). Layouts aren't covered: they fix
+//! themselves up as they load (config/layout.rs). On load, a file older than
+//! `SCHEMA_VERSION` gets each later migration applied in order and is
+//! rewritten in place, with the original kept next to it as
+//! `<file>.v<old>.bak`. When a release renames a field, add a `Migration`
+//! here and bump `SCHEMA_VERSION` instead of carrying serde aliases
+//! forever. Migrations must leave an already-current table untouched: a
+//! file saved by an older build loses its version and goes through them
+//! again.
+
+use super::*;
+use std::path::Path;
+use std::sync::Mutex;
+use toml::{Table, Value};
+
+/// Schema version this build writes
+pub const SCHEMA_VERSION: u32 = 1;
+
+const VERSION_KEY: &str = "schema_version";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileKind {
+    Config,
+    Colors,
+    Highlights,
+    Keybinds,
+    Hotbars,
+    Macros,
+    Variables,
+    Theme,
+}
+
+/// One upgrade step for one kind of file
+struct Migration {
+    kind: FileKind,
+    /// Version the file is at afterwards
+    to: u32,
+    /// Edits the table, returning a line per change made
+    apply: fn(&mut Table) -> Vec<String>,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        kind: FileKind::Config,
+        to: 1,
+        apply: rename_speak_whispers,
+    },
+    Migration {
+        kind: FileKind::Keybinds,
+        to: 1,
+        apply: rename_global_keybinds,
+    },
+    Migration {
+        kind: FileKind::Colors,
+        to: 1,
+        apply: prompt_color_to_fg,
+    },
+];
+
+/// What was upgraded while loading, shown in the main window once the app
+/// is up
+static NOTICES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Summaries of the files upgraded since the last call
+pub fn take_upgrade_notices() -> Vec<String> {
+    NOTICES
+        .lock()
+        .map(|mut notices| std::mem::take(&mut *notices))
+        .unwrap_or_default()
+}
+
+/// Parse a versioned file as `T` without its `schema_version`, which
+/// would otherwise read as an entry in name-keyed files like highlights
+pub(crate) fn parse<T: serde::de::DeserializeOwned>(contents: &str) -> Result<T> {
+    let mut table: Table = toml::from_str(contents)?;
+    table.remove(VERSION_KEY);
+    Ok(Value::Table(table).try_into()?)
+}
+
+/// `contents` with the current `schema_version` on top
+pub(crate) fn stamp(contents: &str) -> String {
+    format!("{} = {}\n\n{}", VERSION_KEY, SCHEMA_VERSION, contents)
+}
+
+fn version_of(table: &Table) -> u32 {
+    table
+        .get(VERSION_KEY)
+        .and_then(Value::as_integer)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+/// Run the migrations a `kind` file at version `from` still needs
+fn migrate(kind: FileKind, from: u32, table: &mut Table) -> Vec<String> {
+    MIGRATIONS
+        .iter()
+        .filter(|m| m.kind == kind && m.to > from && m.to <= SCHEMA_VERSION)
+        .flat_map(|m| (m.apply)(table))
+        .collect()
+}
+
+/// Bring the file at `path` up to `SCHEMA_VERSION`. Missing, current and
+/// unparsable files are left alone; the loader reports parse errors.
+pub(crate) fn upgrade_file(path: &Path, kind: FileKind) -> Result<()> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Ok(());
+    };
+    let Ok(mut table) = toml::from_str::<Table>(&contents) else {
+        return Ok(());
+    };
+    let from = version_of(&table);
+    if from == SCHEMA_VERSION {
+        return Ok(());
+    }
+    if from > SCHEMA_VERSION {
+        tracing::warn!(
+            "{:?} is schema_version {} but this build knows {}; loading it as-is",
+            path,
+            from,
+            SCHEMA_VERSION
+        );
+        return Ok(());
+    }
+
+    let changes = migrate(kind, from, &mut table);
+    if changes.is_empty() && !table.contains_key(VERSION_KEY) {
+        // Nothing to convert: record the version and keep the comments
+        fs::write(path, stamp(&contents)).with_context(|| format!("Failed to write {:?}", path))?;
+        return Ok(());
+    }
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let backup = path.with_file_name(format!("{}.v{}.bak", name, from));
+    fs::copy(path, &backup).with_context(|| format!("Failed to back up {:?}", path))?;
+    table.remove(VERSION_KEY);
+    let body = toml::to_string_pretty(&table).context("Failed to serialize upgraded file")?;
+    fs::write(path, stamp(&body)).with_context(|| format!("Failed to write {:?}", path))?;
+
+    let mut summary = format!(
+        "Upgraded {} to schema {} (backup: {})",
+        path.display(),
+        SCHEMA_VERSION,
+        backup.display()
+    );
+    for change in &changes {
+        summary.push_str(&format!("\n  - {}", change));
+    }
+    tracing::info!("{}", summary);
+    if let Ok(mut notices) = NOTICES.lock() {
+        notices.push(summary);
+    }
+    Ok(())
+}
+
+impl Config {
+    /// Upgrade the global and profile config files before they're read
+    pub(super) fn upgrade_config_files(character: Option<&str>) {
+        let files = [
+            (Self::common_config_path(), FileKind::Config),
+            (Self::config_path(character), FileKind::Config),
+            (Self::common_colors_path(), FileKind::Colors),
+            (Self::colors_path(character), FileKind::Colors),
+            (Self::common_highlights_path(), FileKind::Highlights),
+            (Self::highlights_path(character), FileKind::Highlights),
+            (Self::common_keybinds_path(), FileKind::Keybinds),
+            (Self::keybinds_path(character), FileKind::Keybinds),
+            (Self::common_hotbars_path(), FileKind::Hotbars),
+            (Self::hotbars_path(character), FileKind::Hotbars),
+            (
+                Self::global_dir().map(|dir| dir.join("macros.toml")),
+                FileKind::Macros,
+            ),
+            (
+                Self::profile_dir(character).map(|dir| dir.join("macros.toml")),
+                FileKind::Macros,
+            ),
+            (
+                Self::profile_dir(character).map(|dir| dir.join("macros-local.toml")),
+                FileKind::Macros,
+            ),
+            (VariableStore::path(true, character), FileKind::Variables),
+            (VariableStore::path(false, character), FileKind::Variables),
+        ];
+        for (path, kind) in files {
+            let Ok(path) = path else { continue };
+            if let Err(e) = upgrade_file(&path, kind) {
+                tracing::warn!("Could not upgrade {:?}: {:#}", path, e);
+            }
+        }
+    }
+}
+
+/// `[tts] speak_whispers` became `speak_speech`
+fn rename_speak_whispers(table: &mut Table) -> Vec<String> {
+    let Some(Value::Table(tts)) = table.get_mut("tts") else {
+        return Vec::new();
+    };
+    let Some(value) = tts.remove("speak_whispers") else {
+        return Vec::new();
+    };
+    if tts.contains_key("speak_speech") {
+        return vec!["[tts] dropped speak_whispers (speak_speech is set)".to_string()];
+    }
+    tts.insert("speak_speech".to_string(), value);
+    vec!["[tts] speak_whispers renamed to speak_speech".to_string()]
+}
+
+/// The `[global]` keybind section became `[app]`
+fn rename_global_keybinds(table: &mut Table) -> Vec<String> {
+    if table.contains_key("app") {
+        return Vec::new();
+    }
+    let Some(global) = table.remove("global") else {
+        return Vec::new();
+    };
+    table.insert("app".to_string(), global);
+    vec!["[global] keybinds renamed to [app]".to_string()]
+}
+
+/// Prompt colors' `color` became `fg`
+fn prompt_color_to_fg(table: &mut Table) -> Vec<String> {
+    let Some(Value::Array(prompts)) = table.get_mut("prompt_colors") else {
+        return Vec::new();
+    };
+    let mut changes = Vec::new();
+    for prompt in prompts.iter_mut().filter_map(Value::as_table_mut) {
+        let Some(color) = prompt.remove("color") else {
+            continue;
+        };
+        let character = prompt
+            .get("character")
+            .and_then(Value::as_str)
+            .unwrap_or("?")
+            .to_string();
+        if !prompt.contains_key("fg") {
+            prompt.insert("fg".to_string(), color);
+        }
+        changes.push(format!("prompt color '{}': color renamed to fg", character));
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrations_rename_old_fields_once() {
+        let mut keybinds: Table =
+            toml::from_str("[global]\nquit = \"ctrl+q\"\n[user]\nf1 = \"look\"").unwrap();
+        let changes = migrate(FileKind::Keybinds, 0, &mut keybinds);
+        assert_eq!(changes.len(), 1);
+        assert!(keybinds.contains_key("app") && !keybinds.contains_key("global"));
+        assert!(migrate(FileKind::Keybinds, 0, &mut keybinds).is_empty());
+
+        let mut config: Table = toml::from_str("[tts]\nspeak_whispers = true").unwrap();
+        migrate(FileKind::Config, 0, &mut config);
+        assert_eq!(config["tts"]["speak_speech"].as_bool(), Some(true));
+
+        let mut colors: Table =
+            toml::from_str("[[prompt_colors]]\ncharacter = \"R\"\ncolor = \"#ff0000\"").unwrap();
+        migrate(FileKind::Colors, 0, &mut colors);
+        let prompt = &colors["prompt_colors"][0];
+        assert_eq!(prompt["fg"].as_str(), Some("#ff0000"));
+        assert!(prompt.get("color").is_none());
+
+        let mut current = colors.clone();
+        assert!(migrate(FileKind::Colors, SCHEMA_VERSION, &mut current).is_empty());
+    }
+
+    #[test]
+    fn stamped_name_keyed_files_still_parse() {
+        let text = stamp("[monsters]\npattern = \"orc\"\n");
+        let table: Table = toml::from_str(&text).unwrap();
+        assert_eq!(version_of(&table), SCHEMA_VERSION);
+        let parsed: HashMap<String, Table> = parse(&text).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert!(parsed.contains_key("monsters"));
+    }
+
+    #[test]
+    fn upgrade_file_backs_up_and_stamps() {
+        let dir = std::env::temp_dir().join(format!("vellum-schema-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let plain = dir.join("highlights.toml");
+        fs::write(&plain, "# mine\n[a]\npattern = \"x\"\n").unwrap();
+        upgrade_file(&plain, FileKind::Highlights).unwrap();
+        let text = fs::read_to_string(&plain).unwrap();
+        assert!(text.starts_with("schema_version = 1") && text.contains("# mine"));
+        assert!(!dir.join("highlights.toml.v0.bak").exists());
+
+        let keys = dir.join("keybinds.toml");
+        fs::write(&keys, "[global]\nquit = \"ctrl+q\"\n").unwrap();
+        upgrade_file(&keys, FileKind::Keybinds).unwrap();
+        let upgraded: Table = toml::from_str(&fs::read_to_string(&keys).unwrap()).unwrap();
+        assert!(upgraded.contains_key("app"));
+        assert_eq!(version_of(&upgraded), SCHEMA_VERSION);
+        assert!(dir.join("keybinds.toml.v0.bak").exists());
+
+        // Flat name = "value" files read back without the version entry
+        let vars = dir.join("variables.toml");
+        fs::write(&vars, "target = \"kobold\"\n").unwrap();
+        upgrade_file(&vars, FileKind::Variables).unwrap();
+        let parsed: std::collections::BTreeMap<String, String> =
+            parse(&fs::read_to_string(&vars).unwrap()).unwrap();
+        assert_eq!(parsed.len(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,603 @@
+//! Import triggers, aliases, macros and variables from other front ends.
+//!
+//! - **Genie**: the `.cfg` files (`triggers.cfg`, `aliases.cfg`,
+//!   `macros.cfg`, `variables.cfg`, `highlights.cfg`, `gags.cfg`,
+//!   `substitutes.cfg`), all lines of `#command {arg} {arg}`. One parser
+//!   reads any of them, or all of them pasted into one file.
+//! - **Wizard**: the `[Macros]` section of the settings file, `key=text`
+//!   with `\r` (enter), `\x` (clear the line) and `\p` (pause a second).
+//!
+//! VellumFE has no scripted triggers or argument-taking aliases, so only
+//! what has a direct equivalent comes across:
+//!
+//! | From | To |
+//! |------|----|
+//! | `#highlight`, `#gag`, `#subs` | highlights (colors, `squelch`, `replace`) |
+//! | `#trigger` whose action is only `#play <file>` | a highlight with a `sound` |
+//! | `#macro`, Wizard macros | keybind macros, or sequences for several commands |
+//! | `#var` | variables (`%name%`) |
+//! | `#alias` without arguments, one command | a variable of the same name |
+//!
+//! Genie's `$name` variable references become `%name%`. An alias turned
+//! into a variable is also listed in `converted`, since it's typed as
+//! `%name%` rather than `name` afterwards. Everything else is listed in
+//! `unmapped` with the reason, for the user to redo by hand.
+
+use std::collections::HashMap;
+
+use super::theme_import::literal_highlight;
+use super::wrayth_import::{slug, unique_key};
+use super::{
+    format_key_string, parse_key_string, HighlightPattern, KeyBindAction, MacroAction,
+    SequenceAction, SequenceStep, VariableStore,
+};
+
+/// Triggers, macros and variables converted from another client's files
+#[derive(Debug, Default)]
+pub struct ScriptImport {
+    /// Converted highlights, keyed `<source>_<slug of pattern>`
+    pub highlights: Vec<(String, HighlightPattern)>,
+    /// Keybinds, keyed by VellumFE key string (`ctrl+f1`, `num_8`)
+    pub keybinds: Vec<(String, KeyBindAction)>,
+    /// Variables, by lowercase name
+    pub variables: Vec<(String, String)>,
+    /// Entries brought over as something else, and what they became
+    pub converted: Vec<String>,
+    /// Entries with no VellumFE equivalent, with the reason
+    pub unmapped: Vec<String>,
+}
+
+/// Convert Genie `#command {arg} ...` lines
+pub fn import_genie(text: &str) -> ScriptImport {
+    let mut result = ScriptImport::default();
+    let mut used_keys: HashMap<String, u32> = HashMap::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix('#') else {
+            continue;
+        };
+        let (command, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let args = genie_args(rest);
+        let arg = |i: usize| args.get(i).map(String::as_str).unwrap_or("");
+        let at = format!("line {}", number + 1);
+
+        match genie_command(command) {
+            Some("highlight") => {
+                // `{type} {color} {text}`; very old files leave the type out
+                let (kind, color, text) = if args.len() >= 3 {
+                    (arg(0).to_ascii_lowercase(), arg(1), arg(2))
+                } else {
+                    ("string".to_string(), arg(0), arg(1))
+                };
+                if text.is_empty() {
+                    continue;
+                }
+                let (fg, bg) = genie_colors(color);
+                let Some(fg) = fg else {
+                    result
+                        .unmapped
+                        .push(format!("{}: highlight '{}' has no color", at, text));
+                    continue;
+                };
+                let mut pattern = match kind.as_str() {
+                    "string" => literal_highlight(text, fg, false, "genie"),
+                    "line" => literal_highlight(text, fg, true, "genie"),
+                    "beginswith" => {
+                        let regex = format!("^{}", regex::escape(text));
+                        regex_highlight(&regex, Some(fg), true)
+                    }
+                    "regexp" | "regex" => {
+                        if let Err(e) = regex::Regex::new(text) {
+                            result
+                                .unmapped
+                                .push(format!("{}: highlight '{}': {}", at, text, e));
+                            continue;
+                        }
+                        regex_highlight(text, Some(fg), false)
+                    }
+                    other => {
+                        result.unmapped.push(format!(
+                            "{}: highlight type '{}' isn't supported",
+                            at, other
+                        ));
+                        continue;
+                    }
+                };
+                pattern.bg = bg;
+                push_highlight(&mut result, &mut used_keys, text, pattern);
+            }
+            Some("gag") => {
+                let pattern = arg(0);
+                if let Err(reason) = check_regex(pattern) {
+                    result
+                        .unmapped
+                        .push(format!("{}: gag '{}': {}", at, pattern, reason));
+                    continue;
+                }
+                let mut highlight = regex_highlight(pattern, None, false);
+                highlight.squelch = true;
+                push_highlight(&mut result, &mut used_keys, pattern, highlight);
+            }
+            Some("subs") => {
+                let pattern = arg(0);
+                if let Err(reason) = check_regex(pattern) {
+                    result
+                        .unmapped
+                        .push(format!("{}: substitute '{}': {}", at, pattern, reason));
+                    continue;
+                }
+                let mut highlight = regex_highlight(pattern, None, false);
+                highlight.replace = Some(arg(1).to_string());
+                push_highlight(&mut result, &mut used_keys, pattern, highlight);
+            }
+            Some("trigger") => {
+                let pattern = arg(0);
+                let action = arg(1).trim();
+                let sound = genie_play(action);
+                let reason = if let Err(reason) = check_regex(pattern) {
+                    reason
+                } else if let Some(file) = sound {
+                    let mut highlight = regex_highlight(pattern, None, false);
+                    highlight.sound = Some(file.to_string());
+                    push_highlight(&mut result, &mut used_keys, pattern, highlight);
+                    continue;
+                } else {
+                    "VellumFE highlights can play a sound but not send commands".to_string()
+                };
+                result.unmapped.push(format!(
+                    "{}: trigger '{}' -> '{}': {}",
+                    at, pattern, action, reason
+                ));
+            }
+            Some("macro") => {
+                let key = arg(0);
+                let Some(vellum_key) = convert_key(key) else {
+                    result
+                        .unmapped
+                        .push(format!("{}: macro key '{}' has no VellumFE name", at, key));
+                    continue;
+                };
+                match genie_steps(arg(1)) {
+                    Ok(commands) => {
+                        let steps = commands
+                            .into_iter()
+                            .map(|send| SequenceStep { send, delay_ms: 0 })
+                            .collect();
+                        result.keybinds.push((vellum_key, keybind_action(steps)));
+                    }
+                    Err(reason) => result
+                        .unmapped
+                        .push(format!("{}: macro '{}': {}", at, key, reason)),
+                }
+            }
+            Some("var") => {
+                let name = arg(0);
+                if !VariableStore::is_valid_name(name) {
+                    result
+                        .unmapped
+                        .push(format!("{}: variable name '{}' isn't valid here", at, name));
+                    continue;
+                }
+                result
+                    .variables
+                    .push((name.to_ascii_lowercase(), genie_vars(arg(1))));
+            }
+            Some("alias") => {
+                let name = arg(0);
+                let action = arg(1);
+                let reason = if !VariableStore::is_valid_name(name) {
+                    "name isn't a valid variable name".to_string()
+                } else if action.contains('$')
+                    && (0..10).any(|n| action.contains(&format!("${}", n)))
+                {
+                    "takes arguments, which VellumFE can't pass".to_string()
+                } else {
+                    match genie_steps(action) {
+                        Ok(commands) if commands.len() == 1 => {
+                            let name = name.to_ascii_lowercase();
+                            result.converted.push(format!(
+                                "{}: alias '{}' became the variable %{}%",
+                                at, name, name
+                            ));
+                            result.variables.push((name, commands[0].clone()));
+                            continue;
+                        }
+                        Ok(_) => "sends several commands; bind them to a key instead".to_string(),
+                        Err(reason) => reason,
+                    }
+                };
+                result
+                    .unmapped
+                    .push(format!("{}: alias '{}': {}", at, name, reason));
+            }
+            Some(other) => result
+                .unmapped
+                .push(format!("{}: #{} has no VellumFE equivalent", at, other)),
+            None => result
+                .unmapped
+                .push(format!("{}: unknown command #{}", at, command)),
+        }
+    }
+    result
+}
+
+/// Convert the `[Macros]` section of a Wizard settings file
+pub fn import_wizard_macros(text: &str) -> ScriptImport {
+    let mut result = ScriptImport::default();
+    let mut in_macros = false;
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_macros = name.trim().eq_ignore_ascii_case("macros");
+            continue;
+        }
+        if !in_macros {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let at = format!("line {}", number + 1);
+        let Some(vellum_key) = convert_key(key) else {
+            result.unmapped.push(format!(
+                "{}: macro key '{}' has no VellumFE name",
+                at,
+                key.trim()
+            ));
+            continue;
+        };
+        if value.trim().is_empty() {
+            continue;
+        }
+        match wizard_steps(value) {
+            Ok(steps) => result.keybinds.push((vellum_key, keybind_action(steps))),
+            Err(reason) => {
+                result
+                    .unmapped
+                    .push(format!("{}: macro '{}': {}", at, key.trim(), reason))
+            }
+        }
+    }
+    result
+}
+
+/// Genie accepts any unambiguous abbreviation; these are the usual ones
+fn genie_command(command: &str) -> Option<&'static str> {
+    let command = command.to_ascii_lowercase();
+    let name = match command.as_str() {
+        "highlight" | "highlights" | "high" | "hi" => "highlight",
+        "gag" | "gags" => "gag",
+        "subs" | "sub" | "substitute" => "subs",
+        "trigger" | "triggers" | "trig" | "action" => "trigger",
+        "macro" | "macros" | "mac" => "macro",
+        "var" | "variable" | "setvariable" => "var",
+        "alias" | "aliases" | "al" => "alias",
+        "class" => "class",
+        "name" | "names" => "name",
+        "preset" | "presets" => "preset",
+        "config" => "config",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// `{a} {b c} d` -> `["a", "b c", "d"]`. Braces nest, so a trigger's
+/// action can hold `#play {file}`.
+fn genie_args(text: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(first) = chars.next() else {
+            break;
+        };
+        let mut arg = String::new();
+        if first == '{' {
+            let mut depth = 1;
+            for c in chars.by_ref() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+                arg.push(c);
+            }
+        } else {
+            arg.push(first);
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                arg.push(c);
+            }
+        }
+        args.push(arg);
+    }
+    args
+}
+
+/// A Genie color: a name or `#rrggbb`, optionally `fg,bg`
+fn genie_colors(value: &str) -> (Option<String>, Option<String>) {
+    // Names are resolved like any other highlight color's
+    let color = |part: &str| {
+        let part = part.trim();
+        (!part.is_empty()).then(|| part.to_ascii_lowercase())
+    };
+    match value.split_once(',') {
+        Some((fg, bg)) => (color(fg), color(bg)),
+        None => (color(value), None),
+    }
+}
+
+/// The file of an action that's only `#play <file>`
+fn genie_play(action: &str) -> Option<&str> {
+    let rest = action.strip_prefix('#')?;
+    let (command, file) = rest.split_once(char::is_whitespace)?;
+    if !command.eq_ignore_ascii_case("play") || file.contains(';') {
+        return None;
+    }
+    let file = file
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .trim();
+    (!file.is_empty()).then_some(file)
+}
+
+/// The commands of a Genie macro or alias, split on `;`
+fn genie_steps(action: &str) -> Result<Vec<String>, String> {
+    let commands: Vec<String> = action
+        .split(';')
+        .map(str::trim)
+        .filter(|command| !command.is_empty())
+        .map(genie_vars)
+        .collect();
+    if commands.is_empty() {
+        return Err("does nothing".to_string());
+    }
+    if let Some(command) = commands.iter().find(|command| command.starts_with('#')) {
+        return Err(format!("uses the Genie command '{}'", command));
+    }
+    if commands.iter().any(|command| command.contains('@')) {
+        return Err("places the cursor with @, which keybinds can't".to_string());
+    }
+    Ok(commands)
+}
+
+/// Genie's `$name` variable references as `%name%`
+fn genie_vars(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('$') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let len = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        let name = &after[..len];
+        if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            out.push('%');
+            out.push_str(&name.to_ascii_lowercase());
+            out.push('%');
+        } else {
+            out.push('$');
+            out.push_str(name);
+        }
+        rest = &after[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Split a Wizard macro on its `\r`, `\x` and `\p` codes
+fn wizard_steps(value: &str) -> Result<Vec<SequenceStep>, String> {
+    let mut steps = Vec::new();
+    let mut current = String::new();
+    let mut delay_ms = 0;
+    let mut chars = value.trim().chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            current.push(c);
+            continue;
+        }
+        match chars.next().map(|c| c.to_ascii_lowercase()) {
+            Some('r') => {
+                let send = current.trim().to_string();
+                if !send.is_empty() {
+                    steps.push(SequenceStep { send, delay_ms });
+                    delay_ms = 0;
+                }
+                current.clear();
+            }
+            Some('x') => current.clear(),
+            Some('p') => delay_ms += 1000,
+            Some('\\') => current.push('\\'),
+            Some(code) => return Err(format!("the \\{} code has no VellumFE equivalent", code)),
+            None => current.push('\\'),
+        }
+    }
+    let typed = current.trim();
+    match (steps.is_empty(), typed.is_empty()) {
+        (true, true) => Err("does nothing".to_string()),
+        (false, true) => Ok(steps),
+        // Wizard leaves that text in the input; a VellumFE macro sends it
+        (_, false) => Err("types text without sending it, which keybinds can't".to_string()),
+    }
+}
+
+/// One command without pauses is a plain macro; anything else a sequence
+fn keybind_action(mut steps: Vec<SequenceStep>) -> KeyBindAction {
+    if steps.len() == 1 && steps[0].delay_ms == 0 {
+        let step = steps.remove(0);
+        return KeyBindAction::Macro(MacroAction {
+            macro_text: format!("{}\r", step.send),
+        });
+    }
+    KeyBindAction::Sequence(SequenceAction { sequence: steps })
+}
+
+/// A Genie/Wizard key name (`F1`, `Ctrl+F1`, `Shift, NumPad8`, `Prior`) as
+/// a VellumFE key string
+fn convert_key(name: &str) -> Option<String> {
+    let mut parts = Vec::new();
+    for token in name.split(['+', ',']).map(str::trim) {
+        let token = token.to_ascii_lowercase();
+        let part = match token.as_str() {
+            "" => continue,
+            "control" | "ctrl" => "ctrl".to_string(),
+            "alt" | "menu" => "alt".to_string(),
+            "shift" => "shift".to_string(),
+            "add" => "num_+".to_string(),
+            "subtract" => "num_-".to_string(),
+            "multiply" => "num_*".to_string(),
+            "divide" => "num_/".to_string(),
+            "decimal" => "num_.".to_string(),
+            "prior" => "page_up".to_string(),
+            "next" => "page_down".to_string(),
+            "return" => "enter".to_string(),
+            key => match ["numpad", "keypad", "num"]
+                .iter()
+                .find_map(|prefix| key.strip_prefix(prefix))
+            {
+                Some(digit) if digit.len() == 1 && digit.chars().all(|c| c.is_ascii_digit()) => {
+                    format!("num_{}", digit)
+                }
+                _ => key.to_string(),
+            },
+        };
+        parts.push(part);
+    }
+    // Modifiers first, whichever order the source wrote them in
+    parts.sort_by_key(|part| !matches!(part.as_str(), "ctrl" | "alt" | "shift"));
+    let (code, modifiers) = parse_key_string(&parts.join("+"))?;
+    format_key_string(code, modifiers)
+}
+
+fn check_regex(pattern: &str) -> Result<(), String> {
+    if pattern.is_empty() {
+        return Err("no pattern".to_string());
+    }
+    regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|e| format!("invalid pattern ({})", e))
+}
+
+fn regex_highlight(pattern: &str, fg: Option<String>, whole_line: bool) -> HighlightPattern {
+    let mut highlight = literal_highlight(pattern, String::new(), whole_line, "genie");
+    highlight.pattern = pattern.to_string();
+    highlight.fast_parse = false;
+    highlight.fg = fg;
+    highlight
+}
+
+fn push_highlight(
+    result: &mut ScriptImport,
+    used_keys: &mut HashMap<String, u32>,
+    text: &str,
+    pattern: HighlightPattern,
+) {
+    let key = unique_key(&format!("genie_{}", slug(text)), used_keys);
+    result.highlights.push((key, pattern));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENIE_SAMPLE: &str = include_str!("../../tests/data/genie-sample.cfg");
+    const WIZARD_SAMPLE: &str = include_str!("../../tests/data/wizard-settings-sample.ini");
+
+    fn keybind<'a>(result: &'a ScriptImport, key: &str) -> &'a KeyBindAction {
+        &result
+            .keybinds
+            .iter()
+            .find(|(k, _)| k == key)
+            .unwrap_or_else(|| panic!("missing keybind {key}"))
+            .1
+    }
+
+    #[test]
+    fn genie_files_map_what_vellum_can_do() {
+        let result = import_genie(GENIE_SAMPLE);
+
+        let patterns: Vec<(&str, &str)> = result
+            .highlights
+            .iter()
+            .map(|(key, h)| (key.as_str(), h.pattern.as_str()))
+            .collect();
+        assert_eq!(
+            patterns,
+            [
+                ("genie_nisugi", "Nisugi"),
+                ("genie_you_are_stunned", "^You are stunned"),
+                ("genie_the_shadows_shift", "The shadows shift"),
+                ("genie_you_feel_fully_rested", "You feel fully rested"),
+                ("genie_w_smiles_at_you", "(\\w+) smiles at you"),
+            ]
+        );
+        let stunned = &result.highlights[1].1;
+        assert!(stunned.color_entire_line && !stunned.fast_parse);
+        assert_eq!(stunned.bg.as_deref(), Some("black"));
+        assert!(result.highlights[2].1.squelch);
+        assert_eq!(result.highlights[3].1.sound.as_deref(), Some("rested.wav"));
+        assert_eq!(
+            result.highlights[4].1.replace.as_deref(),
+            Some("$1 grins at you")
+        );
+
+        match keybind(&result, "num_8") {
+            KeyBindAction::Macro(m) => assert_eq!(m.macro_text, "north\r"),
+            other => panic!("unexpected {other:?}"),
+        }
+        match keybind(&result, "ctrl+f1") {
+            KeyBindAction::Sequence(s) => {
+                let sends: Vec<&str> = s.sequence.iter().map(|s| s.send.as_str()).collect();
+                assert_eq!(sends, ["stance offensive", "attack %target%"]);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+
+        assert_eq!(
+            result.variables,
+            [
+                ("target".to_string(), "kobold".to_string()),
+                ("hh".to_string(), "hide".to_string()),
+            ]
+        );
+        // The command trigger, the argument alias and #echo can't come across
+        assert_eq!(result.unmapped.len(), 3, "{:?}", result.unmapped);
+        assert!(result.unmapped.iter().any(|u| u.contains("send commands")));
+        assert!(result.unmapped.iter().any(|u| u.contains("alias 'kk'")));
+        // The argument-less alias is reported as a variable now
+        assert_eq!(result.converted.len(), 1, "{:?}", result.converted);
+        assert!(result.converted[0].contains("%hh%"));
+    }
+
+    #[test]
+    fn wizard_macros_become_keybinds() {
+        let result = import_wizard_macros(WIZARD_SAMPLE);
+        match keybind(&result, "f2") {
+            KeyBindAction::Sequence(s) => {
+                assert_eq!(s.sequence.len(), 2);
+                assert_eq!(s.sequence[0].send, "stand");
+                assert_eq!(s.sequence[1].send, "attack");
+                assert_eq!(s.sequence[1].delay_ms, 1000);
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+        match keybind(&result, "f1") {
+            KeyBindAction::Macro(m) => assert_eq!(m.macro_text, "stance offensive\r"),
+            other => panic!("unexpected {other:?}"),
+        }
+        // Ctrl+A only types, and F3 places the cursor
+        assert_eq!(result.keybinds.len(), 2);
+        assert_eq!(result.unmapped.len(), 2, "{:?}", result.unmapped);
+    }
+}
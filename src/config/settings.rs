@@ -62,6 +62,19 @@ pub struct ConnectionConfig {
     /// Game instance: GS4: "prime", "platinum", "shattered", "test"; DR: "dr", "drplatinum", "drfallen", "drtest"
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub game: Option<String>,
+    /// Accept stream compression (MCCP2) on direct connections when the
+    /// game server offers it
+    #[serde(default)]
+    pub compression: bool,
+    /// Wrap the direct game connection in TLS (servers that offer it)
+    #[serde(default)]
+    pub tls: bool,
+    /// Port for the TLS connection when it differs from the one eAccess hands out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_port: Option<u16>,
+    /// Verify the game server's certificate and host name
+    #[serde(default = "default_true")]
+    pub tls_verify: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,7 +84,7 @@ pub struct UiConfig {
     #[serde(default)]
     pub layout: LayoutConfig,
     #[serde(default = "default_border_style")]
-    pub border_style: String, // Default border style: "single", "double", "rounded", "thick", "none"
+    pub border_style: String, // Default border style: "single", "double", "rounded", "thick", "ascii", "none"
     #[serde(default = "default_countdown_icon")]
     pub countdown_icon: String, // Unicode character for countdown blocks (e.g., "\u{f0c8}")
     // Text selection settings
@@ -82,15 +95,38 @@ pub struct UiConfig {
     /// Automatically copy mouse selection to clipboard on mouse-up
     #[serde(default = "default_selection_auto_copy")]
     pub selection_auto_copy: bool,
+    /// Copy selections with their colors as ANSI escapes (holding Alt on
+    /// release flips this for one copy)
+    #[serde(default)]
+    pub selection_copy_ansi: bool,
     // Drag and drop settings
     #[serde(default = "default_drag_modifier_key")]
     pub drag_modifier_key: String, // Modifier key required for drag and drop (e.g., "ctrl", "alt", "shift")
     // Command history settings
     #[serde(default = "default_min_command_length")]
     pub min_command_length: usize, // Minimum command length to save to history (commands shorter than this are not saved)
+    #[serde(default = "default_history_expansion")]
+    pub history_expansion: bool, // Expand `!!` / `!prefix` to history entries on submit
+    #[serde(default = "default_history_prefix_search")]
+    pub history_prefix_search: bool, // Ctrl+P/N only recall history entries starting with the typed text
+    #[serde(default = "default_paste_confirm_lines")]
+    pub paste_confirm_lines: usize, // Pastes with at least this many lines ask before sending (0 = never ask)
+    #[serde(default = "default_keyboard_layout")]
+    pub keyboard_layout: String, // Physical layout for positional keybind fallback: qwerty, azerty, qwertz, dvorak, colemak
     // Command echo settings
     #[serde(default = "default_command_echo")]
     pub command_echo: bool, // Echo sent commands into main window
+    #[serde(default)]
+    pub command_echo_prefix: String, // Text between the prompt and an echoed command (e.g. "» ")
+    #[serde(default = "default_true")]
+    pub mask_secrets: bool, // Echo, log and capture a reply to a game password prompt as ********
+    #[serde(default)]
+    pub hide_prompt: bool, // Keep the game prompt out of the main window (a command input's prompt_template shows it instead)
+    // Long output viewer
+    #[serde(default = "default_view_hint_lines")]
+    pub view_hint_lines: usize, // Offer a ".view last" link after output blocks this long (0 = never)
+    #[serde(default)]
+    pub art_panel: bool, // GUI: show art-pack images (~/.vellum-fe/art/) when clicking nouns
     // Performance stats settings
     #[serde(default = "default_performance_stats_enabled")]
     pub performance_stats_enabled: bool, // Global toggle for performance overlay
@@ -149,6 +185,39 @@ pub struct UiConfig {
     /// Empty string = don't modify terminal title
     #[serde(default)]
     pub terminal_title: String,
+    /// Text shown on the otherwise blank screen while the boss key is engaged
+    #[serde(default = "default_boss_key_prompt")]
+    pub boss_key_prompt: String,
+    /// Reduced motion: no glides, spinners, or blinking cursor. Renderers
+    /// read this through `animations_enabled()`.
+    #[serde(default)]
+    pub reduced_motion: bool,
+    /// Screen-reader mode: plain borders and ASCII markers, focus changes
+    /// announced through TTS, and the `read_screen` read-out.
+    #[serde(default)]
+    pub screen_reader: bool,
+    /// How the terminal draws emoji with the presentation selector (TUI):
+    /// "wide" (default) or "narrow" for terminals that draw them one column
+    #[serde(default)]
+    pub emoji_width: EmojiWidth,
+    /// Thousands separator and 12/24-hour clock for stats and timestamps:
+    /// "en-US" (default), "en-GB", "de-DE", "fr-FR" or "de-CH"
+    #[serde(default)]
+    pub locale: Locale,
+    /// Render rate ceiling while output or input is active (TUI)
+    #[serde(default = "default_max_fps")]
+    pub max_fps: u32,
+    /// Render rate once nothing has changed for a moment (TUI); lower saves
+    /// CPU and battery at the cost of a slower first frame after idling
+    #[serde(default = "default_idle_fps")]
+    pub idle_fps: u32,
+}
+
+impl UiConfig {
+    /// Central animation switch every renderer consults before animating
+    pub fn animations_enabled(&self) -> bool {
+        !self.reduced_motion
+    }
 }
 
 impl Default for UiConfig {
@@ -161,9 +230,19 @@ impl Default for UiConfig {
             selection_enabled: default_selection_enabled(),
             selection_respect_window_boundaries: default_selection_respect_window_boundaries(),
             selection_auto_copy: default_selection_auto_copy(),
+            selection_copy_ansi: false,
             drag_modifier_key: default_drag_modifier_key(),
             min_command_length: default_min_command_length(),
+            history_expansion: default_history_expansion(),
+            history_prefix_search: default_history_prefix_search(),
+            paste_confirm_lines: default_paste_confirm_lines(),
+            keyboard_layout: default_keyboard_layout(),
             command_echo: default_command_echo(),
+            command_echo_prefix: String::new(),
+            mask_secrets: true,
+            hide_prompt: false,
+            view_hint_lines: default_view_hint_lines(),
+            art_panel: false,
             performance_stats_enabled: default_performance_stats_enabled(),
             perf_stats_x: default_perf_stats_x(),
             perf_stats_y: default_perf_stats_y(),
@@ -190,6 +269,13 @@ impl Default for UiConfig {
             open_dialog_blocklist: default_open_dialog_blocklist(),
             focus: FocusConfig::default(),
             terminal_title: String::new(),
+            boss_key_prompt: default_boss_key_prompt(),
+            reduced_motion: false,
+            screen_reader: false,
+            emoji_width: EmojiWidth::default(),
+            locale: Locale::default(),
+            max_fps: default_max_fps(),
+            idle_fps: default_idle_fps(),
         }
     }
 }
@@ -230,6 +316,53 @@ pub struct SoundConfig {
     pub startup_music: bool, // Play music on startup
     #[serde(default = "default_startup_music_delay")]
     pub startup_music_delay_ms: u64, // Delay before startup music (0 = immediate)
+    #[serde(default)]
+    pub play_when: FocusRule, // Play only while the window is focused / unfocused
+    #[serde(default = "default_unfocused_volume")]
+    pub unfocused_volume: f32, // Volume scale while unfocused (1.0 = no ducking)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roundtime_cue: Option<SoundCue>, // Played the moment roundtime runs out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub casttime_cue: Option<SoundCue>, // Played the moment casttime runs out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stun_cue: Option<SoundCue>, // Played when a stun wears off
+}
+
+/// A short sound for a timer event (`[sound.roundtime_cue]` and friends,
+/// see core::countdown_cues)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoundCue {
+    /// File in the sounds directory
+    pub file: String,
+    /// 0.0 to 1.0; the master volume when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f32>,
+}
+
+/// When an alert sink (sounds, desktop notifications) is active, relative
+/// to the terminal or GUI window having focus. Terminals that never report
+/// focus count as always focused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FocusRule {
+    #[default]
+    Always,
+    Focused,
+    Unfocused,
+}
+
+impl FocusRule {
+    pub fn allows(self, focused: bool) -> bool {
+        match self {
+            FocusRule::Always => true,
+            FocusRule::Focused => focused,
+            FocusRule::Unfocused => !focused,
+        }
+    }
+}
+
+fn default_unfocused_volume() -> f32 {
+    1.0
 }
 
 fn default_sound_enabled() -> bool {
@@ -260,6 +393,11 @@ impl Default for SoundConfig {
             cooldown_ms: default_sound_cooldown(),
             startup_music: default_startup_music(),
             startup_music_delay_ms: default_startup_music_delay(),
+            play_when: FocusRule::Always,
+            unfocused_volume: default_unfocused_volume(),
+            roundtime_cue: None,
+            casttime_cue: None,
+            stun_cue: None,
         }
     }
 }
@@ -283,6 +421,8 @@ pub struct TtsConfig {
     pub speak_speech: bool, // Automatically speak speech window (renamed from speak_whispers)
     #[serde(default = "default_tts_speak_main")]
     pub speak_main: bool, // Automatically speak main window
+    #[serde(default = "default_tts_max_queue")]
+    pub max_queue: usize, // Queue depth before low-priority entries are dropped
 }
 
 fn default_tts_enabled() -> bool {
@@ -309,6 +449,10 @@ fn default_tts_speak_main() -> bool {
     false // Main window can be overwhelming, off by default
 }
 
+fn default_tts_max_queue() -> usize {
+    100 // Backed-up speech past this drops normal-priority lines first
+}
+
 impl Default for TtsConfig {
     fn default() -> Self {
         Self {
@@ -318,6 +462,7 @@ impl Default for TtsConfig {
             speak_thoughts: default_tts_speak_thoughts(),
             speak_speech: default_tts_speak_speech(),
             speak_main: default_tts_speak_main(),
+            max_queue: default_tts_max_queue(),
         }
     }
 }
@@ -465,6 +610,38 @@ impl LoggingConfig {
     }
 }
 
+/// Outgoing proxy for the eAccess login, the game connection and Lich.
+/// Loopback targets (a Lich on this machine) always connect directly.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    #[serde(default)]
+    pub kind: ProxyKind,
+    #[serde(default)]
+    pub host: String,
+    #[serde(default)]
+    pub port: u16,
+    /// SOCKS5 username/password auth, or HTTP Basic auth
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxyKind {
+    #[default]
+    None,
+    Socks5,
+    Http,
+}
+
+impl ProxyConfig {
+    pub fn enabled(&self) -> bool {
+        self.kind != ProxyKind::None
+    }
+}
+
 /// Configuration for text stream routing behavior.
 /// Controls how orphaned streams (no widget subscriber) are handled.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -588,6 +765,563 @@ impl Default for Go2Config {
     }
 }
 
+fn default_loot_top_color() -> Option<String> {
+    Some("#ff5fd7".to_string())
+}
+
+fn default_loot_valuable_color() -> Option<String> {
+    Some("#ffd700".to_string())
+}
+
+fn default_loot_sound_tier() -> crate::data::loot::LootTier {
+    crate::data::loot::LootTier::Top
+}
+
+/// Loot filter: ranked item highlighting, drop sounds and the `.loot` tally
+/// (see data::loot for the built-in ranking).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Highlight color for top-tier items (None = no highlight)
+    #[serde(default = "default_loot_top_color")]
+    pub top_color: Option<String>,
+    /// Highlight color for valuable items
+    #[serde(default = "default_loot_valuable_color")]
+    pub valuable_color: Option<String>,
+    /// Highlight color for common items (off by default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub common_color: Option<String>,
+    /// Sound played when a drop at or above `sound_tier` appears
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+    #[serde(default = "default_loot_sound_tier")]
+    pub sound_tier: crate::data::loot::LootTier,
+    /// Show remembered appraisal values after items on the ground and in
+    /// containers (see data::itemdb)
+    #[serde(default = "default_true")]
+    pub show_values: bool,
+    /// Extra or re-ranked items; same name replaces the built-in entry
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<crate::data::loot::LootItem>,
+}
+
+impl Default for LootConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            top_color: default_loot_top_color(),
+            valuable_color: default_loot_valuable_color(),
+            common_color: None,
+            sound: None,
+            sound_tier: default_loot_sound_tier(),
+            show_values: true,
+            items: Vec::new(),
+        }
+    }
+}
+
+fn default_spellcheck_verbs() -> Vec<String> {
+    ["say", "whisper", "think", "yell", "recite", "sing"]
+        .iter()
+        .map(|verb| verb.to_string())
+        .collect()
+}
+
+/// Spellcheck in the command input for spoken commands (see core::spellcheck)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellcheckConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Commands whose text is checked (`'` is always checked as `say`)
+    #[serde(default = "default_spellcheck_verbs")]
+    pub verbs: Vec<String>,
+}
+
+impl Default for SpellcheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            verbs: default_spellcheck_verbs(),
+        }
+    }
+}
+
+fn default_alarm_warn_health() -> u8 {
+    50
+}
+
+fn default_alarm_critical_health() -> u8 {
+    25
+}
+
+fn default_alarm_stunned_health() -> u8 {
+    50
+}
+
+/// Death and near-death alarm thresholds and alerts (see core::alarm).
+/// Health thresholds are percentages; an alert fires at or below them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlarmConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Warning: border flash and a system line
+    #[serde(default = "default_alarm_warn_health")]
+    pub warn_health: u8,
+    /// Critical: adds the sound, speech and desktop notification
+    #[serde(default = "default_alarm_critical_health")]
+    pub critical_health: u8,
+    /// Stunned at or below this health is critical (0 = off)
+    #[serde(default = "default_alarm_stunned_health")]
+    pub stunned_health: u8,
+    /// Bleeding at any health is a warning
+    #[serde(default = "default_true")]
+    pub bleeding: bool,
+    /// Flash the screen border on every alert
+    #[serde(default = "default_true")]
+    pub flash: bool,
+    /// Sound played on critical alerts (file in the sounds directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+    /// Speak critical alerts ("LOW HEALTH") when TTS is enabled
+    #[serde(default = "default_true")]
+    pub speak: bool,
+    /// Desktop notification on critical alerts
+    #[serde(default)]
+    pub desktop_notify: bool,
+    /// Limit desktop notifications to when the window is (un)focused
+    #[serde(default)]
+    pub notify_when: FocusRule,
+}
+
+impl Default for AlarmConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            warn_health: default_alarm_warn_health(),
+            critical_health: default_alarm_critical_health(),
+            stunned_health: default_alarm_stunned_health(),
+            bleeding: true,
+            flash: true,
+            sound: None,
+            speak: true,
+            desktop_notify: false,
+            notify_when: FocusRule::Always,
+        }
+    }
+}
+
+fn default_rebuff_commands() -> Vec<String> {
+    vec!["prep {spell}".to_string(), "cast".to_string()]
+}
+
+fn default_rebuff_step_delay_ms() -> u64 {
+    1500
+}
+
+/// `.rebuff` command templates and pacing (see core::rebuff)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RebuffConfig {
+    /// Sent once per dropped spell, in order; `{spell}` is the spell
+    /// number (or name when there is none), `{name}` the spell name
+    #[serde(default = "default_rebuff_commands")]
+    pub commands: Vec<String>,
+    /// Minimum gap between queued commands, on top of waiting out RT
+    #[serde(default = "default_rebuff_step_delay_ms")]
+    pub step_delay_ms: u64,
+}
+
+impl Default for RebuffConfig {
+    fn default() -> Self {
+        Self {
+            commands: default_rebuff_commands(),
+            step_delay_ms: default_rebuff_step_delay_ms(),
+        }
+    }
+}
+
+/// `.r` / `.rt` command templates (see core::reply)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplyConfig {
+    /// Sent by `.r <text>`; `{name}` is the last person to whisper to you
+    #[serde(default = "default_reply_whisper")]
+    pub whisper: String,
+    /// Sent by `.rt <text>`; `{name}` is the last person whose thought you
+    /// heard
+    #[serde(default = "default_reply_thought")]
+    pub thought: String,
+}
+
+impl Default for ReplyConfig {
+    fn default() -> Self {
+        Self {
+            whisper: default_reply_whisper(),
+            thought: default_reply_thought(),
+        }
+    }
+}
+
+fn default_reply_whisper() -> String {
+    "whisper {name} {text}".to_string()
+}
+
+fn default_reply_thought() -> String {
+    "think to {name} {text}".to_string()
+}
+
+/// Streamer mode: names swapped for aliases in displayed text (see
+/// core::streamer)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Shown in place of your character's name
+    #[serde(default = "default_streamer_character_alias")]
+    pub character_alias: String,
+    /// Shown in place of your account name
+    #[serde(default = "default_streamer_account_alias")]
+    pub account_alias: String,
+    /// Further names to hide, e.g. friends: real name -> alias
+    #[serde(default)]
+    pub names: std::collections::HashMap<String, String>,
+}
+
+impl Default for StreamerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            character_alias: default_streamer_character_alias(),
+            account_alias: default_streamer_account_alias(),
+            names: std::collections::HashMap::new(),
+        }
+    }
+}
+
+fn default_streamer_character_alias() -> String {
+    "Adventurer".to_string()
+}
+
+fn default_streamer_account_alias() -> String {
+    "Player".to_string()
+}
+
+/// Clickable URLs in game text (see core::urls)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlsConfig {
+    /// Turn http(s) URLs in incoming text into links
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Ask (`.url yes`) before opening a URL whose host isn't allowed
+    #[serde(default = "default_true")]
+    pub confirm: bool,
+    /// Hosts that open without asking; subdomains count
+    #[serde(default = "default_urls_allow")]
+    pub allow: Vec<String>,
+}
+
+impl Default for UrlsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            confirm: true,
+            allow: default_urls_allow(),
+        }
+    }
+}
+
+fn default_urls_allow() -> Vec<String> {
+    vec!["play.net".to_string(), "elanthia.online".to_string()]
+}
+
+/// How `.remind` reminders alert when they come due (see core::reminders)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemindersConfig {
+    /// Sound played with each reminder (file in the sounds directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+    /// Speak the reminder when TTS is enabled
+    #[serde(default = "default_true")]
+    pub speak: bool,
+    /// Desktop notification with each reminder
+    #[serde(default = "default_true")]
+    pub desktop_notify: bool,
+    /// Limit desktop notifications to when the window is (un)focused
+    #[serde(default)]
+    pub notify_when: FocusRule,
+}
+
+impl Default for RemindersConfig {
+    fn default() -> Self {
+        Self {
+            sound: None,
+            speak: true,
+            desktop_notify: true,
+            notify_when: FocusRule::Always,
+        }
+    }
+}
+
+/// Away mode configuration (`[away]` section)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwayConfig {
+    /// Go away after this many minutes without keyboard or mouse input
+    /// (0 = only `.away`)
+    #[serde(default)]
+    pub idle_minutes: u64,
+    /// Answer the first whisper from each sender while away
+    #[serde(default = "default_true")]
+    pub auto_reply: bool,
+    /// The auto-reply, sent through the `[reply] whisper` template
+    #[serde(default = "default_away_message")]
+    pub message: String,
+    /// Stream whispers received while away are copied to
+    #[serde(default = "default_away_stream")]
+    pub stream: String,
+}
+
+impl Default for AwayConfig {
+    fn default() -> Self {
+        Self {
+            idle_minutes: 0,
+            auto_reply: true,
+            message: default_away_message(),
+            stream: default_away_stream(),
+        }
+    }
+}
+
+fn default_away_message() -> String {
+    "I'm away from the keyboard right now.".to_string()
+}
+
+fn default_away_stream() -> String {
+    "away".to_string()
+}
+
+/// Combat mode configuration (`[combat_mode]` section, see core::combat_mode)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatModeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Windows opened while fighting: layout window names or templates
+    #[serde(default = "default_combat_windows")]
+    pub windows: Vec<String>,
+    /// Keep them open this long after the last sign of combat
+    #[serde(default = "default_combat_linger")]
+    pub linger_seconds: u64,
+}
+
+impl Default for CombatModeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            windows: default_combat_windows(),
+            linger_seconds: default_combat_linger(),
+        }
+    }
+}
+
+fn default_combat_windows() -> Vec<String> {
+    ["targets", "injuries", "roundtime", "casttime"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+fn default_combat_linger() -> u64 {
+    20
+}
+
+/// Commands that need a y/n confirmation before they go to the game
+/// (`[lockout]` section, see core::lockout)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockoutConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Held with Enter, sends the typed command without asking
+    #[serde(default)]
+    pub bypass_modifier: LockoutBypass,
+    /// Case-insensitive regexes matched against the whole command
+    #[serde(default = "default_lockout_patterns")]
+    pub patterns: Vec<LockoutPattern>,
+}
+
+impl Default for LockoutConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bypass_modifier: LockoutBypass::default(),
+            patterns: default_lockout_patterns(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockoutPattern {
+    pub pattern: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_lockout_patterns() -> Vec<LockoutPattern> {
+    ["^unwield\\b", "^drop all\\b", "^quit$"]
+        .into_iter()
+        .map(|pattern| LockoutPattern {
+            pattern: pattern.to_string(),
+            enabled: true,
+        })
+        .collect()
+}
+
+/// Modifier that skips the lockout confirmation when held with Enter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LockoutBypass {
+    /// Always ask
+    None,
+    #[default]
+    Alt,
+    Ctrl,
+    Shift,
+}
+
+/// `.quit` while connected (`[quit]` section, see core::quit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuitConfig {
+    /// Ask before quitting while connected (`.quit` again or `.quit yes`)
+    #[serde(default = "default_true")]
+    pub confirm: bool,
+    /// Sent to the game before exiting so the character logs out cleanly
+    /// ("" = exit without logging out). Off by default: behind Lich or
+    /// another proxy, the game's QUIT would end the proxy's session too
+    #[serde(default)]
+    pub logout_command: String,
+    /// Seconds to wait for the server to close the connection after the
+    /// logout command
+    #[serde(default = "default_quit_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for QuitConfig {
+    fn default() -> Self {
+        Self {
+            confirm: true,
+            logout_command: String::new(),
+            timeout_secs: default_quit_timeout_secs(),
+        }
+    }
+}
+
+fn default_quit_timeout_secs() -> u64 {
+    5
+}
+
+/// Container window configuration (`[containers]` section)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainersConfig {
+    /// Dragging an item between container windows (GUI) sends `get` and
+    /// `put` commands instead of the game's `_drag`
+    #[serde(default = "default_true")]
+    pub drag_moves: bool,
+    /// Ask (`.move yes`) before moving a dragged item
+    #[serde(default)]
+    pub confirm_moves: bool,
+}
+
+impl Default for ContainersConfig {
+    fn default() -> Self {
+        Self {
+            drag_moves: true,
+            confirm_moves: false,
+        }
+    }
+}
+
+/// Commands from outside the client without the control socket
+/// (`[command_pipe]` section; see crate::command_pipe). Both sources are
+/// off by default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommandPipeConfig {
+    /// Named pipe to read commands from, one per line. Relative paths are
+    /// in the profile directory; a missing pipe is created (Unix).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Headless mode only: read commands from stdin as well
+    #[serde(default)]
+    pub stdin: bool,
+}
+
+/// Per-profile data locations and environment (`[paths]` section), so
+/// several characters on one machine can keep their own sound packs and
+/// logs. Paths may start with `~`; relative ones are in the profile
+/// directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PathsConfig {
+    /// Sounds directory to use instead of the shared `global/sounds`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sounds_dir: Option<String>,
+    /// Log directory; takes precedence over `[logging] dir`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs_dir: Option<String>,
+    /// Environment variables set for the session when the profile loads
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub env: std::collections::BTreeMap<String, String>,
+}
+
+impl PathsConfig {
+    /// Where a `[paths]` entry points: `~/` under the home directory,
+    /// absolute paths as given, anything else under the profile directory
+    pub fn resolve(value: &str, character: Option<&str>) -> Result<PathBuf> {
+        if let Some(rest) = value
+            .strip_prefix("~/")
+            .or_else(|| value.strip_prefix("~\\"))
+        {
+            let home = dirs::home_dir().context("Could not find home directory")?;
+            return Ok(home.join(rest));
+        }
+        let path = PathBuf::from(value);
+        if path.is_absolute() {
+            Ok(path)
+        } else {
+            Ok(Config::profile_dir(character)?.join(path))
+        }
+    }
+}
+
+fn default_route_step_delay_ms() -> u64 {
+    500
+}
+
+fn default_route_step_timeout_ms() -> u64 {
+    8000
+}
+
+/// Saved routes for `.go` (`[routes]` section)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutesConfig {
+    /// Wait at least this long between steps, so the game can report the
+    /// roundtime a step caused before the next one goes out
+    #[serde(default = "default_route_step_delay_ms")]
+    pub step_delay_ms: u64,
+    /// Pause the route when a movement step hasn't changed the room after
+    /// this long
+    #[serde(default = "default_route_step_timeout_ms")]
+    pub step_timeout_ms: u64,
+    /// Routes by lowercase name (`.go save <name> <step; step; ...>`)
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub saved: std::collections::BTreeMap<String, Vec<String>>,
+}
+
+impl Default for RoutesConfig {
+    fn default() -> Self {
+        Self {
+            step_delay_ms: default_route_step_delay_ms(),
+            step_timeout_ms: default_route_step_timeout_ms(),
+            saved: Default::default(),
+        }
+    }
+}
+
 /// Testing-phase default for `MapConfig::mapdb_repo`; flip to
 /// `elanthia-online/mapdb` when the Cartographer pipeline launches upstream.
 pub const DEFAULT_MAPDB_REPO: &str = "Nisugi/mapdb";
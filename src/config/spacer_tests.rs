@@ -135,6 +135,10 @@ use super::*;
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -146,6 +150,7 @@ use super::*;
             terminal_height: Some(50),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -207,6 +212,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -218,6 +227,7 @@ visible = true
             terminal_height: Some(60),
             base_layout: Some("default".to_string()),
             theme: Some("classic".to_string()),
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -274,6 +284,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -302,6 +316,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -313,6 +331,7 @@ visible = true
             terminal_height: Some(50),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -354,6 +373,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -382,6 +405,10 @@ visible = true
                 max_cols: None,
                 visible: false,
                 content_align: None,  // Hidden!
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -393,6 +420,7 @@ visible = true
             terminal_height: Some(50),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -437,6 +465,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: TextWidgetData {
@@ -446,6 +478,9 @@ visible = true
                 show_timestamps: false,
                 timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
             },
         };
 
@@ -472,6 +507,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -500,6 +539,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: TextWidgetData {
@@ -509,6 +552,9 @@ visible = true
                 show_timestamps: false,
                 timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
             },
         };
 
@@ -518,6 +564,7 @@ visible = true
             terminal_height: Some(20),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -559,6 +606,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: TextWidgetData {
@@ -568,6 +619,9 @@ visible = true
                 show_timestamps: false,
                 timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
             },
         };
 
@@ -594,6 +648,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -622,6 +680,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: TextWidgetData {
@@ -631,6 +693,9 @@ visible = true
                 show_timestamps: false,
                 timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
             },
         };
 
@@ -640,6 +705,7 @@ visible = true
             terminal_height: Some(20),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -694,6 +760,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: TextWidgetData {
@@ -703,6 +773,9 @@ visible = true
                 show_timestamps: false,
                 timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
             },
         };
 
@@ -729,6 +802,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -757,6 +834,10 @@ visible = true
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: TextWidgetData {
@@ -766,6 +847,9 @@ visible = true
                 show_timestamps: false,
                 timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
             },
         };
 
@@ -775,6 +859,7 @@ visible = true
             terminal_height: Some(25),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -62,6 +62,19 @@ pub struct IndicatorTemplateStore {
     pub indicators: Vec<IndicatorTemplateEntry>,
 }
 
+/// Indicator templates making up the standard status-effect pack, in
+/// dashboard order
+const STATUS_EFFECT_TEMPLATES: &[&str] = &[
+    "stunned",
+    "webbed",
+    "prone",
+    "hidden",
+    "invisible",
+    "bleeding",
+    "poisoned",
+    "diseased",
+];
+
 /// Generic window template definition stored globally
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindowTemplateEntry {
@@ -115,6 +128,10 @@ impl Config {
             max_cols: None,
             visible: true,
             content_align: None,
+            floating: false,
+            z_index: 0,
+            tee_file: None,
+            show_when: None,
         };
         // Prefer user-defined window templates (global store)
         if let Some(custom) = Self::get_custom_window_template(name) {
@@ -143,6 +160,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -428,7 +448,7 @@ impl Config {
                     layout: default_dashboard_layout(),
                     spacing: default_dashboard_spacing(),
                     hide_inactive: default_dashboard_hide_inactive(),
-                    indicators: Vec::new(),
+                    indicators: Self::status_effect_indicators(),
                 },
             }),
 
@@ -553,6 +573,78 @@ impl Config {
                     default_color: Some("#cccccc".to_string()),
                 },
             }),
+            "prone" => Some(WindowDef::Indicator {
+                base: WindowBase {
+                    name: "prone".to_string(),
+                    title: Some("Prone".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 2,
+                    cols: 1,
+                    min_rows: Some(2),
+                    max_rows: Some(2),
+                    min_cols: Some(1),
+                    max_cols: Some(1),
+                    show_border: false,
+                    ..base_defaults.clone()
+                },
+                data: IndicatorWidgetData {
+                    icon: Some("󰞒".to_string()), // Nerdfont arrow-collapse-down icon
+                    indicator_id: Some("PRONE".to_string()),
+                    inactive_color: None,
+                    active_color: Some("#d2b48c".to_string()),
+                    default_status: None,
+                    default_color: Some("#d2b48c".to_string()),
+                },
+            }),
+            "hidden" => Some(WindowDef::Indicator {
+                base: WindowBase {
+                    name: "hidden".to_string(),
+                    title: Some("Hidden".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 2,
+                    cols: 1,
+                    min_rows: Some(2),
+                    max_rows: Some(2),
+                    min_cols: Some(1),
+                    max_cols: Some(1),
+                    show_border: false,
+                    ..base_defaults.clone()
+                },
+                data: IndicatorWidgetData {
+                    icon: Some("󰈉".to_string()), // Nerdfont eye-off icon
+                    indicator_id: Some("HIDDEN".to_string()),
+                    inactive_color: None,
+                    active_color: Some("#9370db".to_string()),
+                    default_status: None,
+                    default_color: Some("#9370db".to_string()),
+                },
+            }),
+            "invisible" => Some(WindowDef::Indicator {
+                base: WindowBase {
+                    name: "invisible".to_string(),
+                    title: Some("Invisible".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 2,
+                    cols: 1,
+                    min_rows: Some(2),
+                    max_rows: Some(2),
+                    min_cols: Some(1),
+                    max_cols: Some(1),
+                    show_border: false,
+                    ..base_defaults.clone()
+                },
+                data: IndicatorWidgetData {
+                    icon: Some("󰊠".to_string()), // Nerdfont ghost icon
+                    indicator_id: Some("INVISIBLE".to_string()),
+                    inactive_color: None,
+                    active_color: Some("#87ceeb".to_string()),
+                    default_status: None,
+                    default_color: Some("#87ceeb".to_string()),
+                },
+            }),
 
             "spirit" => Some(WindowDef::Progress {
                 base: WindowBase {
@@ -934,6 +1026,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -953,6 +1048,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -972,6 +1070,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -991,6 +1092,31 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
+                },
+            }),
+
+            "away" => Some(WindowDef::Text {
+                base: WindowBase {
+                    name: "away".to_string(),
+                    title: Some("Away".to_string()),
+                    rows: 10,
+                    cols: 50,
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: TextWidgetData {
+                    streams: vec!["away".to_string()],
+                    buffer_size: 500,
+                    wordwrap: true,
+                    show_timestamps: true,
+                    timestamp_position: None,
+                    compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1010,6 +1136,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1029,6 +1158,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1048,6 +1180,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1067,6 +1202,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1086,6 +1224,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1105,6 +1246,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1124,6 +1268,9 @@ impl Config {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             }),
 
@@ -1136,7 +1283,10 @@ impl Config {
                     show_border: true,
                     ..base_defaults.clone()
                 },
-                data: SpellsWidgetData {},
+                data: SpellsWidgetData {
+                    group_by_circle: true,
+                    collapsed_groups: Vec::new(),
+                },
             }),
 
             "chat" => Some(WindowDef::TabbedText {
@@ -1157,6 +1307,8 @@ impl Config {
                             show_timestamps: None,
                             ignore_activity: Some(false),
                             timestamp_position: None,
+                            alert_sound: None,
+                            alert_flash: None,
                         },
                         TabbedTextTab {
                             name: "Speech".to_string(),
@@ -1165,6 +1317,8 @@ impl Config {
                             show_timestamps: None,
                             ignore_activity: Some(false),
                             timestamp_position: None,
+                            alert_sound: None,
+                            alert_flash: None,
                         },
                         TabbedTextTab {
                             name: "Announcements".to_string(),
@@ -1173,6 +1327,8 @@ impl Config {
                             show_timestamps: None,
                             ignore_activity: Some(false),
                             timestamp_position: None,
+                            alert_sound: None,
+                            alert_flash: None,
                         },
                         TabbedTextTab {
                             name: "Loot".to_string(),
@@ -1181,6 +1337,8 @@ impl Config {
                             show_timestamps: None,
                             ignore_activity: Some(false),
                             timestamp_position: None,
+                            alert_sound: None,
+                            alert_flash: None,
                         },
                         TabbedTextTab {
                             name: "Ambients".to_string(),
@@ -1189,10 +1347,13 @@ impl Config {
                             show_timestamps: None,
                             ignore_activity: Some(false),
                             timestamp_position: None,
+                            alert_sound: None,
+                            alert_flash: None,
                         },
                     ],
                     buffer_size: 5000,
                     tab_bar_position: "top".to_string(),
+                    tab_bar_width: 12,
                     tab_separator: true,
                     tab_active_color: None,
                     tab_inactive_color: None,
@@ -1217,9 +1378,12 @@ impl Config {
                         show_timestamps: None, // Per-tab setting, no global default
                         ignore_activity: Some(false),
                         timestamp_position: None,
+                        alert_sound: None,
+                        alert_flash: None,
                     }],
                     buffer_size: 5000,
                     tab_bar_position: "top".to_string(),
+                    tab_bar_width: 12,
                     tab_separator: true,
                     tab_active_color: None,
                     tab_inactive_color: None,
@@ -1290,7 +1454,7 @@ impl Config {
                     rows: 5,           // 3 default content rows (level, mind, exp) + 2 borders
                     cols: 30,
                     min_rows: Some(3), // 1 content row + borders (fields are toggleable)
-                    max_rows: Some(7), // 5 content rows (level, mind, exp, total, ascension) + borders
+                    max_rows: Some(8), // 6 content rows (level, mind, eta, exp, total, ascension) + borders
                     min_cols: Some(20),
                     show_border: true,
                     ..base_defaults.clone()
@@ -1302,6 +1466,7 @@ impl Config {
                     show_mind_bar: true,
                     show_total_exp: false,
                     show_ascension_exp: false,
+                    show_eta: true,
                     mind_bar_color: None,
                     exp_bar_color: None,
                 },
@@ -1332,6 +1497,184 @@ impl Config {
                 },
             }),
 
+            "stance_posture" => Some(WindowDef::Stance {
+                base: WindowBase {
+                    name: "stance_posture".to_string(),
+                    title: Some("Stance".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 4, // bar + posture + 2 borders
+                    cols: 22,
+                    min_rows: Some(3),
+                    max_rows: Some(4),
+                    min_cols: Some(12),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: StanceWidgetData::default(),
+            }),
+
+            "wealth" => Some(WindowDef::Wealth {
+                base: WindowBase {
+                    name: "wealth".to_string(),
+                    title: Some("Silver".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 4, // silver + session + 2 borders
+                    cols: 22,
+                    min_rows: Some(3),
+                    max_rows: Some(4),
+                    min_cols: Some(12),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: WealthWidgetData::default(),
+            }),
+
+            "task_tracker" => Some(WindowDef::TaskTracker {
+                base: WindowBase {
+                    name: "task_tracker".to_string(),
+                    title: Some("Bounty".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 7, // title + 3 detail lines + progress + 2 borders
+                    cols: 30,
+                    min_rows: Some(3),
+                    min_cols: Some(16),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: TaskTrackerWidgetData::default(),
+            }),
+
+            "society_tasks" => Some(WindowDef::SocietyTasks {
+                base: WindowBase {
+                    name: "society_tasks".to_string(),
+                    title: Some("Society".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 7, // status + a few wrapped tasks + 2 borders
+                    cols: 36,
+                    min_rows: Some(3),
+                    min_cols: Some(16),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: SocietyTasksWidgetData::default(),
+            }),
+
+            "combat_stats" => Some(WindowDef::CombatStats {
+                base: WindowBase {
+                    name: "combat_stats".to_string(),
+                    title: Some("Combat".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 10, // 3 totals + 5 creatures + 2 borders
+                    cols: 34,
+                    min_rows: Some(5),
+                    min_cols: Some(20),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: CombatStatsWidgetData::default(),
+            }),
+
+            "calendar" => Some(WindowDef::Calendar {
+                base: WindowBase {
+                    name: "calendar".to_string(),
+                    title: Some("Calendar".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 6, // date + time + local + moon + 2 borders
+                    cols: 30,
+                    min_rows: Some(3),
+                    min_cols: Some(16),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: CalendarWidgetData::default(),
+            }),
+
+            "reminders" => Some(WindowDef::Reminders {
+                base: WindowBase {
+                    name: "reminders".to_string(),
+                    title: Some("Reminders".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 7, // 5 reminders + 2 borders
+                    cols: 36,
+                    min_rows: Some(3),
+                    min_cols: Some(16),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: RemindersWidgetData::default(),
+            }),
+
+            "notes" => Some(WindowDef::Notes {
+                base: WindowBase {
+                    name: "notes".to_string(),
+                    title: Some("Notes".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 12,
+                    cols: 40,
+                    min_rows: Some(3),
+                    min_cols: Some(16),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: NotesWidgetData::default(),
+            }),
+
+            "watch" => Some(WindowDef::Watch {
+                base: WindowBase {
+                    name: "watch".to_string(),
+                    title: Some("Watch".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 7, // 5 rows + 2 borders
+                    cols: 30,
+                    min_rows: Some(3),
+                    min_cols: Some(12),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: WatchWidgetData::default(),
+            }),
+
+            "traffic" => Some(WindowDef::Traffic {
+                base: WindowBase {
+                    name: "traffic".to_string(),
+                    title: Some("Traffic".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 12,
+                    cols: 80,
+                    min_rows: Some(4),
+                    min_cols: Some(30),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: TrafficWidgetData::default(),
+            }),
+
+            "played" => Some(WindowDef::Played {
+                base: WindowBase {
+                    name: "played".to_string(),
+                    title: Some("Played".to_string()),
+                    row: 0,
+                    col: 0,
+                    rows: 5,
+                    cols: 22,
+                    min_rows: Some(3),
+                    min_cols: Some(16),
+                    show_border: true,
+                    ..base_defaults.clone()
+                },
+                data: PlayedWidgetData::default(),
+            }),
+
             "minivitals" => Some(WindowDef::MiniVitals {
                 base: WindowBase {
                     name: "minivitals".to_string(),
@@ -1440,6 +1783,7 @@ impl Config {
             "spirit".to_string(),
             "concentration".to_string(), // DR-specific
             "stance".to_string(),
+            "stance_posture".to_string(),
             "progress_custom".to_string(),
             "dashboard".to_string(),
             "poisoned".to_string(),
@@ -1447,12 +1791,16 @@ impl Config {
             "diseased".to_string(),
             "stunned".to_string(),
             "webbed".to_string(),
+            "prone".to_string(),
+            "hidden".to_string(),
+            "invisible".to_string(),
             // Text windows
             "main".to_string(),
             "thoughts".to_string(),
             "speech".to_string(),
             "announcements".to_string(),
             "loot".to_string(),
+            "away".to_string(),
             "death".to_string(),
             "logons".to_string(),
             "familiar".to_string(),
@@ -1499,6 +1847,16 @@ impl Config {
             "experience".to_string(),     // DR-specific
             "gs4_experience".to_string(), // GS4-specific
             "encum".to_string(),          // Available for both games
+            "wealth".to_string(),
+            "task_tracker".to_string(),
+            "society_tasks".to_string(),
+            "combat_stats".to_string(),
+            "calendar".to_string(), // GS4-specific
+            "reminders".to_string(),
+            "notes".to_string(),
+            "watch".to_string(),
+            "traffic".to_string(),
+            "played".to_string(),
             "minivitals".to_string(),     // GS4-specific
             "betrayer".to_string(),       // GS4-specific
             // command_input is NOT in this list - it's always present and can't be added/removed
@@ -1567,6 +1925,26 @@ impl Config {
             .collect()
     }
 
+    /// The standard status-effect pack: one dashboard entry per condition
+    /// in `core::state::StatusEffect`, with the icon and active color of
+    /// its indicator template and a dim inactive color
+    pub fn status_effect_indicators() -> Vec<DashboardIndicatorDef> {
+        STATUS_EFFECT_TEMPLATES
+            .iter()
+            .filter_map(|name| match Self::get_window_template(name) {
+                Some(WindowDef::Indicator { data, .. }) => Some(DashboardIndicatorDef {
+                    id: data.indicator_id.unwrap_or_else(|| name.to_uppercase()),
+                    icon: data.icon.unwrap_or_default(),
+                    colors: vec![
+                        data.inactive_color.unwrap_or_else(|| "#555555".to_string()),
+                        data.active_color.unwrap_or_else(|| "#00ff00".to_string()),
+                    ],
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Return all indicator templates (built-in + user-defined), deduplicated by id
     pub fn list_indicator_templates() -> Vec<IndicatorTemplateEntry> {
         let mut templates = Vec::new();
@@ -0,0 +1,369 @@
+//! Import colors from other front ends and older VellumFE releases.
+//!
+//! - **Wizard**: the Wizard front end's INI-style settings file. `[Colors]`
+//!   maps stream names (`Speech`, `Whisper`, `Thought`, `RoomName`, `Bold`,
+//!   `Link`, ...) to a color, optionally `fg,bg`; `[Highlights]` holds
+//!   `text=color` lines, with `,line` to color the whole line. A color is
+//!   `#rrggbb`, `r,g,b`, or an index into Wizard's 16-color palette.
+//! - **StormFront/Wrayth**: the XML settings file, through wrayth_import.
+//! - **VellumFE**: a theme file from an older release, which is missing
+//!   fields added since. They're derived from the colors the file does set
+//!   (menus and browsers follow the window colors, and so on), and anything
+//!   still missing comes from the built-in dark theme.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use toml::{Table, Value};
+
+use super::wrayth_import::{slug, unique_key};
+use super::{HighlightPattern, PresetColor, RedirectMode};
+use crate::theme::loader::ThemeData;
+
+/// Colors and highlights converted from another client's settings
+#[derive(Debug, Default)]
+pub struct ColorImport {
+    /// Converted highlights, keyed `<source>_<slug of text>`
+    pub highlights: Vec<(String, HighlightPattern)>,
+    /// Stream colors, keyed by colors.toml preset name
+    pub presets: Vec<(String, PresetColor)>,
+    /// Entries that couldn't be converted, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// Wizard's 16-color palette, in the order its settings number them
+const WIZARD_PALETTE: [&str; 16] = [
+    "#000000", "#000080", "#008000", "#008080", "#800000", "#800080", "#808000", "#c0c0c0",
+    "#808080", "#0000ff", "#00ff00", "#00ffff", "#ff0000", "#ff00ff", "#ffff00", "#ffffff",
+];
+
+/// Wizard `[Colors]` names and the colors.toml presets they become
+const WIZARD_PRESETS: &[(&str, &str)] = &[
+    ("speech", "speech"),
+    ("whisper", "whisper"),
+    ("whispers", "whisper"),
+    ("thought", "thought"),
+    ("thoughts", "thought"),
+    ("roomname", "roomName"),
+    ("bold", "monsterbold"),
+    ("monsterbold", "monsterbold"),
+    ("link", "links"),
+    ("links", "links"),
+    ("command", "commands"),
+    ("commands", "commands"),
+    ("familiar", "familiar"),
+    ("voln", "voln"),
+];
+
+/// Convert a Wizard settings file
+pub fn import_wizard(text: &str) -> ColorImport {
+    let mut result = ColorImport::default();
+    let mut used_keys: HashMap<String, u32> = HashMap::new();
+    let mut section = String::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with([';', '#']) {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name.trim().to_ascii_lowercase();
+            continue;
+        }
+        match section.as_str() {
+            "colors" => {
+                let Some((name, value)) = line.split_once('=') else {
+                    continue;
+                };
+                let key = name.trim().to_ascii_lowercase();
+                let Some(&(_, preset)) = WIZARD_PRESETS.iter().find(|(wizard, _)| *wizard == key)
+                else {
+                    result.skipped.push(format!(
+                        "line {}: no VellumFE color for '{}'",
+                        number + 1,
+                        name.trim()
+                    ));
+                    continue;
+                };
+                match wizard_color_pair(value) {
+                    Some((fg, bg)) => result
+                        .presets
+                        .push((preset.to_string(), PresetColor { fg: Some(fg), bg })),
+                    None => result.skipped.push(format!(
+                        "line {}: unreadable color '{}'",
+                        number + 1,
+                        value.trim()
+                    )),
+                }
+            }
+            "highlights" | "highlight strings" => {
+                // The color never holds '=', so the last one splits the text off
+                let Some((text, value)) = line.rsplit_once('=') else {
+                    continue;
+                };
+                let (value, whole_line) = match value.trim().strip_suffix(",line") {
+                    Some(color) => (color, true),
+                    None => (value.trim(), false),
+                };
+                let Some(fg) = wizard_color(value) else {
+                    result.skipped.push(format!(
+                        "line {}: unreadable color '{}'",
+                        number + 1,
+                        value
+                    ));
+                    continue;
+                };
+                let key = unique_key(&format!("wizard_{}", slug(text)), &mut used_keys);
+                result
+                    .highlights
+                    .push((key, literal_highlight(text, fg, whole_line, "wizard")));
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// A `fg` or `fg,bg` Wizard color value. An `r,g,b` triple is one color.
+fn wizard_color_pair(value: &str) -> Option<(String, Option<String>)> {
+    let value = value.trim();
+    if let Some(color) = wizard_color(value) {
+        return Some((color, None));
+    }
+    let (fg, bg) = value.split_once(',')?;
+    Some((wizard_color(fg)?, Some(wizard_color(bg)?)))
+}
+
+fn wizard_color(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.starts_with('#') {
+        return ThemeData::parse_color(value).map(|_| value.to_ascii_lowercase());
+    }
+    if let Ok(index) = value.parse::<usize>() {
+        return WIZARD_PALETTE.get(index).map(|hex| hex.to_string());
+    }
+    let channels: Vec<u8> = value
+        .split(',')
+        .map(|part| part.trim().parse::<u8>())
+        .collect::<std::result::Result<_, _>>()
+        .ok()?;
+    match channels[..] {
+        [r, g, b] => Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+        _ => None,
+    }
+}
+
+pub(super) fn literal_highlight(
+    text: &str,
+    fg: String,
+    whole_line: bool,
+    category: &str,
+) -> HighlightPattern {
+    // Same literal mapping as wrayth_import: fast_parse unless a '|' would
+    // split the text into alternatives
+    let (pattern, fast_parse) = if text.contains('|') {
+        (regex::escape(text), false)
+    } else {
+        (text.to_string(), true)
+    };
+    HighlightPattern {
+        pattern,
+        fg: Some(fg),
+        bg: None,
+        bold: false,
+        color_entire_line: whole_line,
+        fast_parse,
+        sound: None,
+        sound_volume: None,
+        category: Some(category.to_string()),
+        squelch: false,
+        silent_prompt: false,
+        redirect_to: None,
+        redirect_mode: RedirectMode::default(),
+        replace: None,
+        stream: None,
+        window: None,
+        compiled_regex: None,
+    }
+}
+
+/// A theme converted from an older VellumFE theme file
+pub struct ThemeImport {
+    pub theme: ThemeData,
+    /// Fields derived from other colors in the file
+    pub derived: Vec<String>,
+    /// Fields taken from the built-in dark theme
+    pub defaulted: Vec<String>,
+}
+
+/// Fields newer than some theme files, and the fields (in order of
+/// preference) whose color they take instead
+const THEME_FALLBACKS: &[(&str, &[&str])] = &[
+    ("window_border", &["border_color"]),
+    ("text_primary", &["text_color"]),
+    ("background_primary", &["window_background"]),
+    ("browser_border", &["border_color", "window_border"]),
+    ("browser_title", &["window_title"]),
+    ("browser_item_normal", &["text_color", "text_primary"]),
+    (
+        "browser_background",
+        &["background_primary", "window_background"],
+    ),
+    ("form_border", &["border_color", "window_border"]),
+    ("form_label", &["label_color", "text_secondary"]),
+    (
+        "form_label_focused",
+        &["focused_label_color", "window_border_focused"],
+    ),
+    (
+        "form_field_background",
+        &["background_primary", "window_background"],
+    ),
+    ("form_field_text", &["text_color", "text_primary"]),
+    ("editor_border", &["border_color", "window_border"]),
+    ("editor_label", &["label_color", "text_secondary"]),
+    (
+        "editor_label_focused",
+        &["focused_label_color", "window_border_focused"],
+    ),
+    ("editor_text", &["text_color", "text_primary"]),
+    (
+        "editor_background",
+        &["background_primary", "window_background"],
+    ),
+    ("menu_border", &["border_color", "window_border"]),
+    (
+        "menu_background",
+        &["background_primary", "window_background"],
+    ),
+    ("menu_item_normal", &["text_color", "text_primary"]),
+    (
+        "status_background",
+        &["background_primary", "window_background"],
+    ),
+    ("border_color", &["window_border"]),
+    ("label_color", &["text_secondary"]),
+    ("focused_label_color", &["window_border_focused"]),
+    ("text_color", &["text_primary"]),
+];
+
+/// Convert an older VellumFE theme file. `fallback_name` names it when the
+/// file doesn't.
+pub fn import_vellumfe_theme(text: &str, fallback_name: &str) -> Result<ThemeImport> {
+    let mut table: Table = toml::from_str(text).context("Failed to parse theme file")?;
+    let mut derived = Vec::new();
+    if !table.contains_key("name") {
+        table.insert("name".to_string(), Value::String(fallback_name.to_string()));
+    }
+    if !table.contains_key("description") {
+        let description = "Imported from an older VellumFE theme".to_string();
+        table.insert("description".to_string(), Value::String(description));
+    }
+    for (field, sources) in THEME_FALLBACKS {
+        if table.contains_key(*field) {
+            continue;
+        }
+        if let Some(value) = sources
+            .iter()
+            .find_map(|source| table.get(*source))
+            .cloned()
+        {
+            table.insert(field.to_string(), value);
+            derived.push(field.to_string());
+        }
+    }
+
+    let Value::Table(defaults) =
+        Value::try_from(ThemeData::default()).context("Failed to serialize default theme")?
+    else {
+        anyhow::bail!("Default theme did not serialize as a table");
+    };
+    let mut defaulted = Vec::new();
+    for (field, value) in defaults {
+        if !table.contains_key(&field) {
+            defaulted.push(field.clone());
+            table.insert(field, value);
+        }
+    }
+
+    let theme: ThemeData = Value::Table(table)
+        .try_into()
+        .context("Theme file has a field of the wrong type")?;
+    if theme.to_app_theme().is_none() {
+        anyhow::bail!("Theme file has a color that isn't #rrggbb");
+    }
+    Ok(ThemeImport {
+        theme,
+        derived,
+        defaulted,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIZARD_SAMPLE: &str = include_str!("../../tests/data/wizard-settings-sample.ini");
+    const OLD_THEME_SAMPLE: &str = include_str!("../../tests/data/vellumfe-theme-old.toml");
+
+    fn preset<'a>(result: &'a ColorImport, name: &str) -> &'a PresetColor {
+        &result
+            .presets
+            .iter()
+            .find(|(key, _)| key == name)
+            .unwrap_or_else(|| panic!("missing preset {name}"))
+            .1
+    }
+
+    #[test]
+    fn wizard_colors_map_to_presets() {
+        let result = import_wizard(WIZARD_SAMPLE);
+        assert_eq!(preset(&result, "speech").fg.as_deref(), Some("#00ffff"));
+        assert_eq!(preset(&result, "thought").fg.as_deref(), Some("#80ff80"));
+        let room = preset(&result, "roomName");
+        assert_eq!(room.fg.as_deref(), Some("#ffff00"));
+        assert_eq!(room.bg.as_deref(), Some("#000080"));
+        assert_eq!(preset(&result, "links").fg.as_deref(), Some("#0080ff"));
+        assert!(result.skipped.iter().any(|s| s.contains("Sparkles")));
+    }
+
+    #[test]
+    fn wizard_highlights_convert_literally() {
+        let result = import_wizard(WIZARD_SAMPLE);
+        let keys: Vec<&str> = result.highlights.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(
+            keys,
+            ["wizard_nisugi", "wizard_you_are_stunned", "wizard_swing_a"]
+        );
+        let stunned = &result.highlights[1].1;
+        assert!(stunned.color_entire_line && stunned.fast_parse);
+        assert_eq!(stunned.fg.as_deref(), Some("#ff0000"));
+        assert_eq!(result.highlights[2].1.pattern, "swing=a");
+        // "nothing here=" and "broken=purple" have no usable color
+        assert_eq!(
+            result
+                .skipped
+                .iter()
+                .filter(|s| s.contains("unreadable"))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn old_theme_fills_new_fields_from_its_own_colors() {
+        let result = import_vellumfe_theme(OLD_THEME_SAMPLE, "fallback").unwrap();
+        let theme = &result.theme;
+        assert_eq!(theme.name, "Ember");
+        assert_eq!(theme.menu_border, "#803020");
+        assert_eq!(theme.editor_label_focused, "#ff6040");
+        assert_eq!(theme.browser_background, "#1a1414");
+        assert!(result.derived.contains(&"menu_border".to_string()));
+        // Nothing in the file says what a warning looks like
+        assert!(result.defaulted.contains(&"status_warning".to_string()));
+        assert!(theme.to_app_theme().is_some());
+
+        let unnamed = import_vellumfe_theme("text_primary = \"#ffffff\"", "mine").unwrap();
+        assert_eq!(unnamed.theme.name, "mine");
+        assert!(import_vellumfe_theme("text_primary = \"white\"", "bad").is_err());
+    }
+}
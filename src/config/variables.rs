@@ -0,0 +1,188 @@
+//! User variables for commands, keybind macros, and hotbar buttons.
+//!
+//! Two flat `name = "value"` files: `global/variables.toml` shared by every
+//! character and `profiles/<character>/variables.toml`. A character value
+//! shadows a global one with the same name. `%name%` in an outgoing command
+//! expands to the value (see [`VariableStore::expand`]); `.set` / `.unset`
+//! edit the store and write the file straight away.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Global and per-character variables, keyed by lowercase name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VariableStore {
+    pub global: BTreeMap<String, String>,
+    pub character: BTreeMap<String, String>,
+}
+
+impl VariableStore {
+    /// Variable names are letters, digits, and underscores.
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    /// Load both scopes; a missing file is an empty scope.
+    pub fn load(character: Option<&str>) -> Result<Self> {
+        Ok(Self {
+            global: load_file(&Self::path(true, character)?)?,
+            character: load_file(&Self::path(false, character)?)?,
+        })
+    }
+
+    /// Write one scope back to its file.
+    pub fn save(&self, global: bool, character: Option<&str>) -> Result<()> {
+        let path = Self::path(global, character)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let vars = if global {
+            &self.global
+        } else {
+            &self.character
+        };
+        let text = toml::to_string_pretty(vars).context("Failed to serialize variables")?;
+        fs::write(&path, super::schema::stamp(&text))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub(super) fn path(global: bool, character: Option<&str>) -> Result<PathBuf> {
+        let dir = if global {
+            super::Config::global_dir()?
+        } else {
+            super::Config::profile_dir(character)?
+        };
+        Ok(dir.join("variables.toml"))
+    }
+
+    /// Character value first, then global.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let key = name.to_ascii_lowercase();
+        self.character
+            .get(&key)
+            .or_else(|| self.global.get(&key))
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, name: &str, value: &str, global: bool) {
+        let scope = if global {
+            &mut self.global
+        } else {
+            &mut self.character
+        };
+        scope.insert(name.to_ascii_lowercase(), value.to_string());
+    }
+
+    /// Returns true if the variable existed in that scope.
+    pub fn unset(&mut self, name: &str, global: bool) -> bool {
+        let scope = if global {
+            &mut self.global
+        } else {
+            &mut self.character
+        };
+        scope.remove(&name.to_ascii_lowercase()).is_some()
+    }
+
+    /// Replace `%name%` with the variable's value. Unknown names and stray
+    /// `%` signs ("50% off") are left as typed; `%%` is a literal `%`.
+    /// Values are inserted as-is, not expanded again.
+    pub fn expand(&self, text: &str) -> String {
+        if !text.contains('%') {
+            return text.to_string();
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('%') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            if let Some(tail) = after.strip_prefix('%') {
+                out.push('%');
+                rest = tail;
+                continue;
+            }
+            match after.find('%') {
+                Some(end) if Self::is_valid_name(&after[..end]) => {
+                    match self.get(&after[..end]) {
+                        Some(value) => out.push_str(value),
+                        None => out.push_str(&rest[start..start + end + 2]),
+                    }
+                    rest = &after[end + 1..];
+                }
+                _ => {
+                    out.push('%');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+fn load_file(path: &PathBuf) -> Result<BTreeMap<String, String>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let vars: BTreeMap<String, String> = super::schema::parse(&text)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(vars
+        .into_iter()
+        .map(|(name, value)| (name.to_ascii_lowercase(), value))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> VariableStore {
+        let mut store = VariableStore::default();
+        store.set("target", "kobold", true);
+        store.set("Container", "cloak", true);
+        store.set("container", "backpack", false);
+        store
+    }
+
+    #[test]
+    fn character_values_shadow_global() {
+        let store = store();
+        assert_eq!(store.get("CONTAINER"), Some("backpack"));
+        assert_eq!(store.get("target"), Some("kobold"));
+        assert_eq!(store.get("missing"), None);
+    }
+
+    #[test]
+    fn expands_known_names_and_leaves_the_rest() {
+        let store = store();
+        assert_eq!(
+            store.expand("put my gem in my %container%"),
+            "put my gem in my backpack"
+        );
+        assert_eq!(store.expand("attack %Target%"), "attack kobold");
+        assert_eq!(store.expand("say %unknown% stays"), "say %unknown% stays");
+        assert_eq!(store.expand("say 50% off, %target%"), "say 50% off, kobold");
+        assert_eq!(store.expand("say 100%% sure"), "say 100% sure");
+        assert_eq!(store.expand("say 5% or 10%"), "say 5% or 10%");
+    }
+
+    #[test]
+    fn values_are_not_expanded_twice() {
+        let mut store = VariableStore::default();
+        store.set("a", "%b%", false);
+        store.set("b", "loop", false);
+        assert_eq!(store.expand("%a%"), "%b%");
+    }
+
+    #[test]
+    fn unset_reports_whether_the_scope_had_it() {
+        let mut store = store();
+        assert!(store.unset("container", false));
+        assert_eq!(store.get("container"), Some("cloak"));
+        assert!(!store.unset("container", false));
+    }
+}
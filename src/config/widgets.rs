@@ -225,7 +225,7 @@ pub struct WindowBase {
     #[serde(default = "default_show_border")]
     pub show_border: bool,
     #[serde(default = "default_border_style")]
-    pub border_style: String, // "single", "double", "rounded", "thick", "plain"
+    pub border_style: String, // "single", "double", "rounded", "thick", "ascii", or a theme border set
     #[serde(default)]
     pub border_sides: BorderSides,
     #[serde(default)]
@@ -258,6 +258,28 @@ pub struct WindowBase {
     /// Content alignment within widget area
     #[serde(default)]
     pub content_align: Option<String>,
+    /// Floating windows overlap tiled windows and are drawn above them
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub floating: bool,
+    /// Stacking order among floating windows (higher draws on top)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub z_index: i32,
+    /// Append a plain-text copy of every line this window receives to this
+    /// file (text and tabbed windows; see core::tee)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tee_file: Option<String>,
+    /// Draw the window only while this condition holds, e.g. `stunned` or
+    /// `spells > 0` (same language as `.if`; see core::condition)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_when: Option<String>,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+fn is_zero(n: &i32) -> bool {
+    *n == 0
 }
 
 /// Text widget specific data
@@ -277,6 +299,18 @@ pub struct TextWidgetData {
     /// Enable compact display mode (transforms verbose bounty text to 1-4 lines)
     #[serde(default)]
     pub compact: bool,
+    /// Flood control: collapse identical consecutive lines into one line
+    /// with a "(×N)" counter
+    #[serde(default)]
+    pub collapse_repeats: bool,
+    /// Coalesce a line repeating within this many seconds into its earlier
+    /// copy, annotated with count and time range (0 = off)
+    #[serde(default)]
+    pub coalesce_seconds: u64,
+    /// Conversation view: group consecutive lines from one speaker under a
+    /// dim name header, with the lines indented beneath it
+    #[serde(default)]
+    pub conversation: bool,
 }
 
 /// Room widget specific data
@@ -316,6 +350,10 @@ pub struct CommandInputWidgetData {
     pub prompt_icon: Option<String>,
     #[serde(default)]
     pub prompt_icon_color: Option<String>,
+    /// Rendered prompt shown in place of the icon: `{prompt}`, `{flags}`,
+    /// `{status}`, `{rt}`, `{ct}` (see core::prompt)
+    #[serde(default)]
+    pub prompt_template: Option<String>,
 }
 
 /// Inventory widget specific data
@@ -340,6 +378,9 @@ pub struct TabbedTextWidgetData {
     pub buffer_size: usize,
     #[serde(default = "default_tab_bar_position")]
     pub tab_bar_position: String,
+    /// Label column width when the tab bar is on the left or right
+    #[serde(default = "default_tab_bar_width")]
+    pub tab_bar_width: u16,
     #[serde(default)]
     pub tab_separator: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -356,6 +397,10 @@ fn default_tab_bar_position() -> String {
     "top".to_string()
 }
 
+fn default_tab_bar_width() -> u16 {
+    12
+}
+
 /// Tab configuration for TabbedText widget
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct TabbedTextTab {
@@ -375,6 +420,13 @@ pub struct TabbedTextTab {
     /// Timestamp position (overrides ui.timestamp_position if Some)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timestamp_position: Option<TimestampPosition>,
+    /// Sound played when a line arrives while this tab isn't the active one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_sound: Option<String>,
+    /// Flash the tab label when a line arrives in the background, until
+    /// the tab is viewed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_flash: Option<bool>,
 }
 
 impl TabbedTextTab {
@@ -679,7 +731,12 @@ pub struct QuickbarsConfig {
 /// Spells window widget specific data
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpellsWidgetData {
-    // No extra fields currently - uses "spells" stream
+    /// Group the listing by circle/realm under collapsible headers
+    #[serde(default = "default_true")]
+    pub group_by_circle: bool,
+    /// Groups the user collapsed (kept with the layout)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collapsed_groups: Vec<String>,
 }
 
 /// Text replacement rule for perception widget
@@ -846,6 +903,10 @@ pub struct GS4ExperienceWidgetData {
     /// Show total ascension experience line - default false
     #[serde(default)]
     pub show_ascension_exp: bool,
+    /// Show the time-until-clear readout (absorption rate ETA) - default
+    /// false in existing layouts; the template turns it on
+    #[serde(default)]
+    pub show_eta: bool,
     /// Mind bar fill color (default: cyan)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mind_bar_color: Option<String>,
@@ -880,6 +941,203 @@ pub struct EncumbranceWidgetData {
     pub color_critical: Option<String>,
 }
 
+/// Stance widget data (stance bar + posture, click to change stance)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StanceWidgetData {
+    /// Show the offensive-to-defensive bar - default true
+    #[serde(default = "default_true")]
+    pub show_bar: bool,
+    /// Show posture (standing/kneeling/sitting/prone) - default true
+    #[serde(default = "default_true")]
+    pub show_posture: bool,
+    /// Stances to step through, offensive first (empty = GS4 default:
+    /// offensive, advance, forward, neutral, guarded, defensive)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stances: Vec<String>,
+}
+
+impl Default for StanceWidgetData {
+    fn default() -> Self {
+        Self {
+            show_bar: true,
+            show_posture: true,
+            stances: Vec::new(),
+        }
+    }
+}
+
+/// Wealth widget data (silver estimate + session delta)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WealthWidgetData {
+    /// Show the session gain/loss line - default true
+    #[serde(default = "default_true")]
+    pub show_session: bool,
+}
+
+impl Default for WealthWidgetData {
+    fn default() -> Self {
+        Self { show_session: true }
+    }
+}
+
+/// Task tracker widget data (structured bounty with progress)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskTrackerWidgetData {
+    /// Show the "6/12 culled" progress bar for counted tasks - default true
+    #[serde(default = "default_true")]
+    pub show_progress: bool,
+    /// Post a system line when the task completes - default true
+    #[serde(default = "default_true")]
+    pub notify: bool,
+    /// Sound file to play when the task completes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+}
+
+impl Default for TaskTrackerWidgetData {
+    fn default() -> Self {
+        Self {
+            show_progress: true,
+            notify: true,
+            sound: None,
+        }
+    }
+}
+
+/// Society task checklist data (society stream tasks with progress)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocietyTasksWidgetData {
+    /// Show counts ("6/12") on counted tasks - default true
+    #[serde(default = "default_true")]
+    pub show_progress: bool,
+    /// Post a system line when a task completes - default true
+    #[serde(default = "default_true")]
+    pub notify: bool,
+    /// Sound file to play when a task completes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+}
+
+impl Default for SocietyTasksWidgetData {
+    fn default() -> Self {
+        Self {
+            show_progress: true,
+            notify: true,
+            sound: None,
+        }
+    }
+}
+
+/// Combat stats widget data (session combat analysis)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CombatStatsWidgetData {
+    /// Creatures listed under the totals, most damaged first (0 = totals only)
+    #[serde(default = "default_combat_stats_creatures")]
+    pub creatures: usize,
+}
+
+fn default_combat_stats_creatures() -> usize {
+    5
+}
+
+impl Default for CombatStatsWidgetData {
+    fn default() -> Self {
+        Self {
+            creatures: default_combat_stats_creatures(),
+        }
+    }
+}
+
+/// Calendar widget data (Elanthian date/time + moon phases)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarWidgetData {
+    /// Show the day of the week - default true
+    #[serde(default = "default_true")]
+    pub show_weekday: bool,
+    /// Show real-world local time under the game time - default false
+    #[serde(default)]
+    pub show_local_time: bool,
+    /// Moons to show a phase for. Default: Liabo
+    #[serde(default = "default_calendar_moons")]
+    pub moons: Vec<crate::data::game_time::MoonDef>,
+}
+
+fn default_calendar_moons() -> Vec<crate::data::game_time::MoonDef> {
+    vec![crate::data::game_time::MoonDef::liabo()]
+}
+
+impl Default for CalendarWidgetData {
+    fn default() -> Self {
+        Self {
+            show_weekday: true,
+            show_local_time: false,
+            moons: default_calendar_moons(),
+        }
+    }
+}
+
+/// Reminders widget data (upcoming `.remind` reminders)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemindersWidgetData {
+    /// Show "(every 2h)" / "(daily 9:00pm)" after repeating reminders - default true
+    #[serde(default = "default_true")]
+    pub show_repeat: bool,
+}
+
+impl Default for RemindersWidgetData {
+    fn default() -> Self {
+        Self { show_repeat: true }
+    }
+}
+
+/// Notes widget data (the character notepad, `.notes`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct NotesWidgetData {
+    /// Only show the `# Heading` section with this title - default "" (whole file)
+    #[serde(default)]
+    pub section: String,
+}
+
+/// One watch widget row
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchEntry {
+    /// Row label - default "" (show the expression)
+    #[serde(default)]
+    pub label: String,
+    /// Expression in the `.if` condition language (`health.pct`, `rt`, a `.set` variable)
+    pub expr: String,
+}
+
+/// Watch widget data (live values of state expressions, `.watch`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct WatchWidgetData {
+    /// Rows in display order - default none
+    #[serde(default)]
+    pub watches: Vec<WatchEntry>,
+}
+
+/// Traffic console data (raw socket lines, `.traffic`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct TrafficWidgetData {
+    /// Show lines as a hex dump instead of text - default false
+    #[serde(default)]
+    pub hex: bool,
+}
+
+/// Played widget data (session timer and played time, `.played`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayedWidgetData {
+    /// Show the lifetime total row - default true
+    #[serde(default = "default_true")]
+    pub show_total: bool,
+}
+
+impl Default for PlayedWidgetData {
+    fn default() -> Self {
+        Self { show_total: true }
+    }
+}
+
 /// MiniVitals widget data (horizontal 4-bar layout)
 /// Works with both GS4 (mana) and DR (concentration)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
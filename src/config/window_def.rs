@@ -240,6 +240,105 @@ pub enum WindowDef {
         data: EncumbranceWidgetData,
     },
 
+    /// Stance window (stance bar + posture; clicking changes stance)
+    #[serde(rename = "stance")]
+    Stance {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: StanceWidgetData,
+    },
+
+    /// Wealth window (silver on hand + session delta)
+    #[serde(rename = "wealth")]
+    Wealth {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: WealthWidgetData,
+    },
+
+    /// Task tracker window (structured bounty with progress)
+    #[serde(rename = "task_tracker")]
+    TaskTracker {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: TaskTrackerWidgetData,
+    },
+
+    /// Society task checklist (society stream tasks with progress)
+    #[serde(rename = "society_tasks")]
+    SocietyTasks {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: SocietyTasksWidgetData,
+    },
+
+    /// Combat stats window (hit %, damage rate, per-creature breakdown)
+    #[serde(rename = "combat_stats")]
+    CombatStats {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: CombatStatsWidgetData,
+    },
+
+    /// Calendar window (Elanthian date/time + moon phases)
+    #[serde(rename = "calendar")]
+    Calendar {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: CalendarWidgetData,
+    },
+
+    /// Reminders window (upcoming `.remind` reminders)
+    #[serde(rename = "reminders")]
+    Reminders {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: RemindersWidgetData,
+    },
+
+    /// Notes window (the character notepad, `.notes`)
+    #[serde(rename = "notes")]
+    Notes {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: NotesWidgetData,
+    },
+
+    /// Watch window (live values of state expressions, `.watch`)
+    #[serde(rename = "watch")]
+    Watch {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: WatchWidgetData,
+    },
+
+    /// Traffic console (raw socket lines, `.traffic`)
+    #[serde(rename = "traffic")]
+    Traffic {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: TrafficWidgetData,
+    },
+
+    /// Played time (session timer, today, total)
+    #[serde(rename = "played")]
+    Played {
+        #[serde(flatten)]
+        base: WindowBase,
+        #[serde(flatten)]
+        data: PlayedWidgetData,
+    },
+
     /// MiniVitals window (horizontal 4-bar layout) - GS4 only
     #[serde(rename = "minivitals")]
     MiniVitals {
@@ -300,6 +399,17 @@ impl WindowDef {
             WindowDef::Experience { base, .. } => &base.name,
             WindowDef::GS4Experience { base, .. } => &base.name,
             WindowDef::Encumbrance { base, .. } => &base.name,
+            WindowDef::Stance { base, .. } => &base.name,
+            WindowDef::Wealth { base, .. } => &base.name,
+            WindowDef::TaskTracker { base, .. } => &base.name,
+            WindowDef::SocietyTasks { base, .. } => &base.name,
+            WindowDef::CombatStats { base, .. } => &base.name,
+            WindowDef::Calendar { base, .. } => &base.name,
+            WindowDef::Reminders { base, .. } => &base.name,
+            WindowDef::Notes { base, .. } => &base.name,
+            WindowDef::Watch { base, .. } => &base.name,
+            WindowDef::Traffic { base, .. } => &base.name,
+            WindowDef::Played { base, .. } => &base.name,
             WindowDef::MiniVitals { base, .. } => &base.name,
             WindowDef::Betrayer { base, .. } => &base.name,
             WindowDef::WebUi { base, .. } => &base.name,
@@ -337,6 +447,17 @@ impl WindowDef {
             WindowDef::Experience { .. } => "experience",
             WindowDef::GS4Experience { .. } => "gs4_experience",
             WindowDef::Encumbrance { .. } => "encum",
+            WindowDef::Stance { .. } => "stance",
+            WindowDef::Wealth { .. } => "wealth",
+            WindowDef::TaskTracker { .. } => "task_tracker",
+            WindowDef::SocietyTasks { .. } => "society_tasks",
+            WindowDef::CombatStats { .. } => "combat_stats",
+            WindowDef::Calendar { .. } => "calendar",
+            WindowDef::Reminders { .. } => "reminders",
+            WindowDef::Notes { .. } => "notes",
+            WindowDef::Watch { .. } => "watch",
+            WindowDef::Traffic { .. } => "traffic",
+            WindowDef::Played { .. } => "played",
             WindowDef::MiniVitals { .. } => "minivitals",
             WindowDef::Betrayer { .. } => "betrayer",
             WindowDef::WebUi { .. } => "webui",
@@ -374,6 +495,17 @@ impl WindowDef {
             WindowDef::Experience { base, .. } => base,
             WindowDef::GS4Experience { base, .. } => base,
             WindowDef::Encumbrance { base, .. } => base,
+            WindowDef::Stance { base, .. } => base,
+            WindowDef::Wealth { base, .. } => base,
+            WindowDef::TaskTracker { base, .. } => base,
+            WindowDef::SocietyTasks { base, .. } => base,
+            WindowDef::CombatStats { base, .. } => base,
+            WindowDef::Calendar { base, .. } => base,
+            WindowDef::Reminders { base, .. } => base,
+            WindowDef::Notes { base, .. } => base,
+            WindowDef::Watch { base, .. } => base,
+            WindowDef::Traffic { base, .. } => base,
+            WindowDef::Played { base, .. } => base,
             WindowDef::MiniVitals { base, .. } => base,
             WindowDef::Betrayer { base, .. } => base,
             WindowDef::WebUi { base, .. } => base,
@@ -411,6 +543,17 @@ impl WindowDef {
             WindowDef::Experience { base, .. } => base,
             WindowDef::GS4Experience { base, .. } => base,
             WindowDef::Encumbrance { base, .. } => base,
+            WindowDef::Stance { base, .. } => base,
+            WindowDef::Wealth { base, .. } => base,
+            WindowDef::TaskTracker { base, .. } => base,
+            WindowDef::SocietyTasks { base, .. } => base,
+            WindowDef::CombatStats { base, .. } => base,
+            WindowDef::Calendar { base, .. } => base,
+            WindowDef::Reminders { base, .. } => base,
+            WindowDef::Notes { base, .. } => base,
+            WindowDef::Watch { base, .. } => base,
+            WindowDef::Traffic { base, .. } => base,
+            WindowDef::Played { base, .. } => base,
             WindowDef::MiniVitals { base, .. } => base,
             WindowDef::Betrayer { base, .. } => base,
             WindowDef::WebUi { base, .. } => base,
@@ -301,7 +301,7 @@ fn sound_basename(path: &str) -> String {
 }
 
 /// Reduce highlight text to a short TOML-key-safe slug.
-fn slug(text: &str) -> String {
+pub(super) fn slug(text: &str) -> String {
     let mut out = String::new();
     let mut last_was_sep = true;
     for c in text.chars() {
@@ -324,7 +324,7 @@ fn slug(text: &str) -> String {
     }
 }
 
-fn unique_key(base: &str, used: &mut HashMap<String, u32>) -> String {
+pub(super) fn unique_key(base: &str, used: &mut HashMap<String, u32>) -> String {
     let count = used.entry(base.to_string()).or_insert(0);
     *count += 1;
     if *count == 1 {
@@ -0,0 +1,232 @@
+//! Local control socket for `vellum-fe send`.
+//!
+//! Each running instance listens on a loopback TCP port and records the
+//! port, a random token and its pid in `control.json` in the profile
+//! directory. `vellum-fe send "<command>"` (run with the same `--profile`
+//! or `--character`) reads that file, connects, and hands the command to
+//! the instance, which runs it exactly like a typed command — dot-commands
+//! included. Loopback TCP instead of a Unix socket so Windows works the
+//! same way; the token (readable only by the user on Unix) keeps other
+//! local users out.
+//!
+//! Wire format: one JSON request line, one JSON reply line.
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Longest accepted request line
+const MAX_REQUEST_BYTES: u64 = 16 * 1024;
+/// How long either side waits on the other
+const IO_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Contents of `control.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ControlInfo {
+    port: u16,
+    token: String,
+    pid: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Request {
+    token: String,
+    command: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Reply {
+    ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn control_path(profile: Option<&str>) -> Result<PathBuf> {
+    Ok(crate::config::Config::profile_dir(profile)?.join("control.json"))
+}
+
+/// The listening side. Dropping it removes `control.json`; the accept
+/// thread itself lives until the process exits.
+pub struct ControlServer {
+    path: PathBuf,
+}
+
+impl ControlServer {
+    /// Bind the socket, publish `control.json` for `profile`, and start
+    /// accepting. Received commands arrive on the returned receiver.
+    pub fn start(profile: Option<&str>) -> Result<(Self, mpsc::UnboundedReceiver<String>)> {
+        Self::start_at(control_path(profile)?)
+    }
+
+    fn start_at(path: PathBuf) -> Result<(Self, mpsc::UnboundedReceiver<String>)> {
+        let listener =
+            TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).context("Failed to bind control socket")?;
+        let port = listener.local_addr()?.port();
+
+        let mut bytes = [0u8; 16];
+        getrandom::fill(&mut bytes).context("Failed to generate control token")?;
+        let token: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+
+        let info = ControlInfo {
+            port,
+            token: token.clone(),
+            pid: std::process::id(),
+        };
+        write_private(&path, &serde_json::to_string(&info)?)?;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        std::thread::Builder::new()
+            .name("control-socket".to_string())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else {
+                        continue;
+                    };
+                    if let Err(e) = serve(stream, &token, &tx) {
+                        tracing::debug!("control request failed: {e}");
+                    }
+                    if tx.is_closed() {
+                        break;
+                    }
+                }
+            })?;
+        tracing::info!("Control socket listening on 127.0.0.1:{port}");
+        Ok((Self { path }, rx))
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        // Another instance on the same profile may have taken the file over
+        let ours = std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|text| serde_json::from_str::<ControlInfo>(&text).ok())
+            .is_some_and(|info| info.pid == std::process::id());
+        if ours {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Write `contents` to `path`, readable only by the user on Unix
+fn write_private(path: &std::path::Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    file.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+/// Handle one connection: read the request, check the token, queue the
+/// command, reply
+fn serve(stream: TcpStream, token: &str, tx: &mpsc::UnboundedSender<String>) -> Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut line = String::new();
+    BufReader::new((&stream).take(MAX_REQUEST_BYTES)).read_line(&mut line)?;
+
+    let reply = match serde_json::from_str::<Request>(&line) {
+        Err(e) => Reply {
+            ok: false,
+            error: Some(format!("bad request: {e}")),
+        },
+        Ok(request) if request.token != token => Reply {
+            ok: false,
+            error: Some("bad token".to_string()),
+        },
+        Ok(request) if request.command.trim().is_empty() => Reply {
+            ok: false,
+            error: Some("empty command".to_string()),
+        },
+        Ok(request) => {
+            tracing::info!("control socket command: '{}'", request.command);
+            match tx.send(request.command) {
+                Ok(()) => Reply {
+                    ok: true,
+                    error: None,
+                },
+                Err(_) => Reply {
+                    ok: false,
+                    error: Some("instance is shutting down".to_string()),
+                },
+            }
+        }
+    };
+    let mut stream = stream;
+    writeln!(stream, "{}", serde_json::to_string(&reply)?)?;
+    Ok(())
+}
+
+/// Send `command` to the instance running on `profile` (`vellum-fe send`)
+pub fn send(profile: Option<&str>, command: &str) -> Result<()> {
+    send_via(&control_path(profile)?, command)
+        .with_context(|| format!("profile '{}'", profile.unwrap_or("default")))
+}
+
+fn send_via(path: &std::path::Path, command: &str) -> Result<()> {
+    let text = std::fs::read_to_string(path).map_err(|_| anyhow!("no running instance"))?;
+    let info: ControlInfo = serde_json::from_str(&text)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    let address = (Ipv4Addr::LOCALHOST, info.port).into();
+    let mut stream = TcpStream::connect_timeout(&address, IO_TIMEOUT)
+        .map_err(|_| anyhow!("instance isn't running (stale {})", path.display()))?;
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let request = Request {
+        token: info.token,
+        command: command.to_string(),
+    };
+    writeln!(stream, "{}", serde_json::to_string(&request)?)?;
+
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let reply: Reply = serde_json::from_str(&line).context("Bad reply from instance")?;
+    if !reply.ok {
+        let error = reply
+            .error
+            .unwrap_or_else(|| "command rejected".to_string());
+        bail!(error);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sends_commands_and_rejects_bad_tokens() {
+        let dir = std::env::temp_dir().join(format!("vellum-control-{}", std::process::id()));
+        let path = dir.join("control.json");
+
+        let (server, mut rx) = ControlServer::start_at(path.clone()).expect("start");
+        send_via(&path, "look").expect("send");
+        assert_eq!(rx.blocking_recv().as_deref(), Some("look"));
+
+        let mut info: ControlInfo =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        info.token = "wrong".to_string();
+        std::fs::write(&path, serde_json::to_string(&info).unwrap()).unwrap();
+        assert!(send_via(&path, "look").is_err());
+
+        drop(server);
+        assert!(!path.exists());
+        assert!(send_via(&path, "look").is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,197 @@
+//! Internal functions a keybind can call with arguments.
+//!
+//! This is the one list both keybind editors offer in their dropdowns and
+//! that `execute_keybind_action` checks calls against, so a function only
+//! has to be added here (and handled by the frontends' `action:` handlers)
+//! to become bindable. Calls resolve to the same `action:` strings dot
+//! commands use, except focus changes, which live in core.
+
+use crate::config::CallAction;
+
+/// What an argument is, for prompting and validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// A window name from the layout
+    Window,
+    /// A tab name in a tabbed window
+    Tab,
+    /// A signed line count
+    Lines,
+}
+
+pub struct ActionArg {
+    pub name: &'static str,
+    pub kind: ArgKind,
+}
+
+pub struct RegisteredAction {
+    pub name: &'static str,
+    pub args: &'static [ActionArg],
+    pub summary: &'static str,
+}
+
+const fn arg(name: &'static str, kind: ArgKind) -> ActionArg {
+    ActionArg { name, kind }
+}
+
+/// Most arguments any registered function takes
+pub const MAX_ARGS: usize = 2;
+
+pub const ACTIONS: &[RegisteredAction] = &[
+    RegisteredAction {
+        name: "scroll_window",
+        args: &[arg("window", ArgKind::Window), arg("lines", ArgKind::Lines)],
+        summary: "Scroll a window; positive lines go back, negative forward",
+    },
+    RegisteredAction {
+        name: "switch_tab",
+        args: &[arg("tab", ArgKind::Tab)],
+        summary: "Show a tab in whichever tabbed window has it",
+    },
+    RegisteredAction {
+        name: "focus_window",
+        args: &[arg("window", ArgKind::Window)],
+        summary: "Make a window the target of scroll and search keys",
+    },
+    RegisteredAction {
+        name: "show_window",
+        args: &[arg("window", ArgKind::Window)],
+        summary: "Show a window from the layout",
+    },
+    RegisteredAction {
+        name: "hide_window",
+        args: &[arg("window", ArgKind::Window)],
+        summary: "Hide a window",
+    },
+];
+
+pub fn find(name: &str) -> Option<&'static RegisteredAction> {
+    ACTIONS.iter().find(|action| action.name == name)
+}
+
+/// A call checked against the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InternalCall {
+    ScrollWindow { window: String, lines: i32 },
+    SwitchTab(String),
+    FocusWindow(String),
+    ShowWindow(String),
+    HideWindow(String),
+}
+
+impl InternalCall {
+    /// The `action:` string the frontends handle; focus changes have none
+    pub fn action_string(&self) -> Option<String> {
+        match self {
+            InternalCall::ScrollWindow { window, lines } => {
+                Some(format!("action:scrollwindow:{}:{}", window, lines))
+            }
+            InternalCall::SwitchTab(tab) => Some(format!("action:switchtab:{}", tab)),
+            InternalCall::ShowWindow(window) => Some(format!("action:showwindow:{}", window)),
+            InternalCall::HideWindow(window) => Some(format!("action:hidewindow:{}", window)),
+            InternalCall::FocusWindow(_) => None,
+        }
+    }
+}
+
+/// Check a call's name, argument count and argument types
+pub fn resolve(call: &CallAction) -> Result<InternalCall, String> {
+    let action = find(&call.call).ok_or_else(|| format!("no function named '{}'", call.call))?;
+    if call.args.len() != action.args.len() {
+        let names: Vec<&str> = action.args.iter().map(|arg| arg.name).collect();
+        return Err(format!(
+            "{}({}) takes {} argument{}",
+            action.name,
+            names.join(", "),
+            names.len(),
+            if names.len() == 1 { "" } else { "s" }
+        ));
+    }
+    for (value, arg) in call.args.iter().zip(action.args) {
+        if value.trim().is_empty() {
+            return Err(format!("{} is empty", arg.name));
+        }
+        if arg.kind == ArgKind::Lines && value.trim().parse::<i32>().is_err() {
+            return Err(format!(
+                "{} must be a whole number, not '{}'",
+                arg.name, value
+            ));
+        }
+    }
+    let text = |i: usize| call.args[i].trim().to_string();
+    Ok(match action.name {
+        "scroll_window" => InternalCall::ScrollWindow {
+            window: text(0),
+            lines: text(1).parse().unwrap_or_default(),
+        },
+        "switch_tab" => InternalCall::SwitchTab(text(0)),
+        "focus_window" => InternalCall::FocusWindow(text(0)),
+        "show_window" => InternalCall::ShowWindow(text(0)),
+        "hide_window" => InternalCall::HideWindow(text(0)),
+        other => unreachable!("{} is registered but not resolved", other),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(text: &str) -> Result<InternalCall, String> {
+        resolve(&CallAction::parse(text).unwrap())
+    }
+
+    #[test]
+    fn every_registered_action_resolves() {
+        for action in ACTIONS {
+            assert!(
+                action.args.len() <= MAX_ARGS,
+                "{} takes too many args",
+                action.name
+            );
+            let args: Vec<String> = action
+                .args
+                .iter()
+                .map(|arg| match arg.kind {
+                    ArgKind::Lines => "5".to_string(),
+                    _ => "main".to_string(),
+                })
+                .collect();
+            let call = CallAction {
+                call: action.name.to_string(),
+                args,
+            };
+            assert!(resolve(&call).is_ok(), "{} doesn't resolve", action.name);
+        }
+    }
+
+    #[test]
+    fn checks_names_counts_and_numbers() {
+        assert_eq!(
+            call(r#"scroll_window("main", -10)"#),
+            Ok(InternalCall::ScrollWindow {
+                window: "main".to_string(),
+                lines: -10
+            })
+        );
+        assert_eq!(
+            call(r#"switch_tab("deaths")"#)
+                .unwrap()
+                .action_string()
+                .as_deref(),
+            Some("action:switchtab:deaths")
+        );
+        assert_eq!(
+            call(r#"focus_window("thoughts")"#).unwrap().action_string(),
+            None
+        );
+        assert!(call(r#"scroll_window("main", lots)"#)
+            .unwrap_err()
+            .contains("whole number"));
+        assert!(call(r#"switch_tab()"#)
+            .unwrap_err()
+            .contains("takes 1 argument"));
+        assert!(call(r#"explode("main")"#)
+            .unwrap_err()
+            .contains("no function"));
+    }
+}
@@ -0,0 +1,206 @@
+//! Death and near-death alarm.
+//!
+//! AppCore re-evaluates the alarm whenever health, bleeding, stunned or dead
+//! change (see `AppCore::check_alarm`), against the `[alarm]` thresholds in
+//! config.toml — set them in a character's profile config to give each
+//! character their own. Alerts escalate with the level: a warning flashes
+//! the screen border and prints a line; critical adds the alarm sound, a
+//! spoken "LOW HEALTH" and a desktop notification. Each level alerts once
+//! when it's reached and re-arms after things improve.
+
+use crate::config::AlarmConfig;
+use crate::core::state::StatusInfo;
+use std::time::{Duration, Instant};
+
+/// How long the screen border flashes after an alert
+pub const FLASH_DURATION: Duration = Duration::from_secs(3);
+/// On/off period of the flash
+const FLASH_PERIOD: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlarmLevel {
+    #[default]
+    Clear,
+    Warning,
+    Critical,
+}
+
+/// The vitals the alarm looks at; evaluation only runs when these change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Inputs {
+    health: u8,
+    bleeding: bool,
+    stunned: bool,
+    dead: bool,
+}
+
+/// Level and reason for the current vitals
+pub fn evaluate(config: &AlarmConfig, health: u8, status: &StatusInfo) -> (AlarmLevel, String) {
+    if status.dead {
+        return (AlarmLevel::Critical, "DEAD".to_string());
+    }
+    if health <= config.critical_health {
+        return (AlarmLevel::Critical, format!("LOW HEALTH ({}%)", health));
+    }
+    if status.stunned && health <= config.stunned_health {
+        return (
+            AlarmLevel::Critical,
+            format!("STUNNED, LOW HEALTH ({}%)", health),
+        );
+    }
+    if health <= config.warn_health {
+        return (AlarmLevel::Warning, format!("Health at {}%", health));
+    }
+    if status.bleeding && config.bleeding {
+        return (AlarmLevel::Warning, "Bleeding".to_string());
+    }
+    (AlarmLevel::Clear, String::new())
+}
+
+/// Current alarm level, plus the flash the frontends draw
+#[derive(Debug, Clone, Default)]
+pub struct AlarmState {
+    pub level: AlarmLevel,
+    pub reason: String,
+    /// When the latest alert went off and at what level; the border
+    /// flashes for `FLASH_DURATION` from here
+    pub raised_at: Option<(Instant, AlarmLevel)>,
+    last_inputs: Option<Inputs>,
+}
+
+impl AlarmState {
+    /// Re-evaluate after a vitals update. Returns the new level when it
+    /// went up, i.e. when alerts should fire.
+    pub fn update(
+        &mut self,
+        config: &AlarmConfig,
+        health: u8,
+        status: &StatusInfo,
+        now: Instant,
+    ) -> Option<AlarmLevel> {
+        let inputs = Inputs {
+            health,
+            bleeding: status.bleeding,
+            stunned: status.stunned,
+            dead: status.dead,
+        };
+        if self.last_inputs == Some(inputs) {
+            return None;
+        }
+        self.last_inputs = Some(inputs);
+
+        let (level, reason) = if config.enabled {
+            evaluate(config, health, status)
+        } else {
+            (AlarmLevel::Clear, String::new())
+        };
+        let escalated = level > self.level;
+        self.level = level;
+        self.reason = reason;
+        if !escalated {
+            return None;
+        }
+        if config.flash {
+            self.raised_at = Some((now, level));
+        }
+        Some(level)
+    }
+
+    /// Flash for a test alert (`.alarm test`) without touching the
+    /// evaluated level
+    pub fn test(&mut self, config: &AlarmConfig, now: Instant) {
+        if config.flash {
+            self.raised_at = Some((now, AlarmLevel::Critical));
+        }
+    }
+
+    /// Level to draw the border in at `now`, or None between flashes and
+    /// once the flash is over. Without `animate` (`ui.reduced_motion`) the
+    /// border stays on, unblinking, for the whole duration.
+    pub fn flash(&self, now: Instant, animate: bool) -> Option<AlarmLevel> {
+        let (at, level) = self.raised_at?;
+        let elapsed = now.checked_duration_since(at)?;
+        if elapsed >= FLASH_DURATION {
+            return None;
+        }
+        if !animate {
+            return Some(level);
+        }
+        let phase = elapsed.as_millis() / FLASH_PERIOD.as_millis();
+        phase.is_multiple_of(2).then_some(level)
+    }
+
+    /// Whether the frontends need to keep redrawing for the flash
+    pub fn flashing(&self, now: Instant) -> bool {
+        self.raised_at.is_some_and(|(at, _)| {
+            now.checked_duration_since(at)
+                .is_some_and(|elapsed| elapsed < FLASH_DURATION)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds_pick_the_level() {
+        let config = AlarmConfig::default();
+        let mut status = StatusInfo::default();
+        assert_eq!(evaluate(&config, 90, &status).0, AlarmLevel::Clear);
+        assert_eq!(evaluate(&config, 50, &status).0, AlarmLevel::Warning);
+        assert_eq!(evaluate(&config, 20, &status).0, AlarmLevel::Critical);
+
+        status.bleeding = true;
+        assert_eq!(evaluate(&config, 90, &status).0, AlarmLevel::Warning);
+        status.stunned = true;
+        assert_eq!(evaluate(&config, 45, &status).0, AlarmLevel::Critical);
+        status.dead = true;
+        assert_eq!(evaluate(&config, 90, &status).1, "DEAD");
+    }
+
+    #[test]
+    fn alerts_once_per_escalation_and_rearms() {
+        let config = AlarmConfig::default();
+        let status = StatusInfo::default();
+        let now = Instant::now();
+        let mut alarm = AlarmState::default();
+
+        assert_eq!(
+            alarm.update(&config, 40, &status, now),
+            Some(AlarmLevel::Warning)
+        );
+        assert_eq!(alarm.update(&config, 35, &status, now), None);
+        assert_eq!(
+            alarm.update(&config, 10, &status, now),
+            Some(AlarmLevel::Critical)
+        );
+        // Healing back to a warning doesn't alert; dropping again does
+        assert_eq!(alarm.update(&config, 40, &status, now), None);
+        assert_eq!(
+            alarm.update(&config, 10, &status, now),
+            Some(AlarmLevel::Critical)
+        );
+    }
+
+    #[test]
+    fn border_flashes_then_stops() {
+        let config = AlarmConfig::default();
+        let start = Instant::now();
+        let mut alarm = AlarmState::default();
+        alarm.update(&config, 10, &StatusInfo::default(), start);
+
+        assert_eq!(alarm.flash(start, true), Some(AlarmLevel::Critical));
+        assert_eq!(alarm.flash(start + FLASH_PERIOD, true), None);
+        assert!(alarm.flashing(start + FLASH_PERIOD));
+        assert!(!alarm.flashing(start + FLASH_DURATION));
+        assert_eq!(alarm.flash(start + FLASH_DURATION, true), None);
+
+        // Reduced motion: a steady border, gone at the same time
+        assert_eq!(
+            alarm.flash(start + FLASH_PERIOD, false),
+            Some(AlarmLevel::Critical)
+        );
+        assert_eq!(alarm.flash(start + FLASH_DURATION, false), None);
+    }
+}
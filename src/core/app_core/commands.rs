@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 use super::AppCore;
 
@@ -7,126 +7,1726 @@ impl AppCore {
     pub fn send_command(&mut self, command: String) -> Result<String> {
         use crate::data::{SpanType, StyledLine, TextSegment, WindowContent};
 
+        let bypass_lockout = self.command_lockout.take_bypass();
+
+        // %name% variables expand first, so typed commands, macros, and
+        // hotbar buttons all see the value. The variable commands
+        // themselves keep the text literal (`.set a %b%` stores "%b%"), and
+        // `.if` expands only its inner command, once, when it is sent.
+        let command = if Self::is_variable_command(&command) {
+            command
+        } else {
+            self.config.variables.expand(&command)
+        };
+
         // Check for dot commands (local client commands)
         if command.starts_with('.') {
             return self.handle_dot_command(&command);
         }
 
-        // If the next room turns out to be unmapped, this command is the
-        // edge label on its ghost-room sketch ("go shop").
-        self.map.note_command(&command);
+        // Dangerous commands wait for a y/n answer (core::lockout)
+        if let Some(pattern) =
+            self.command_lockout
+                .check(&self.config.lockout, &command, bypass_lockout)
+        {
+            self.hold_for_confirmation(command, pattern);
+            return Ok(String::new());
+        }
+
+        // A reply to a game password prompt goes to the game as typed but
+        // is echoed, logged and captured masked (core::secrets)
+        let secret = std::mem::take(&mut self.message_processor.password_prompt)
+            && self.config.ui.mask_secrets;
+        if secret {
+            crate::network::traffic::mask_next(&command);
+        } else {
+            // If the next room turns out to be unmapped, this command is the
+            // edge label on its ghost-room sketch ("go shop").
+            self.map.note_command(&command);
+            // APPRAISE/SELL: lets a price-only shop reply be remembered
+            self.message_processor.note_command(&command);
+        }
+        let echoed = if secret {
+            crate::core::secrets::MASK
+        } else {
+            command.as_str()
+        };
+
+        // Intercept game "quit" command - save settings before disconnecting
+        // This handles the case where users close terminal after game disconnect
+        // (.quit's own logout saves when it exits)
+        if command.trim().eq_ignore_ascii_case("quit") && !self.quit_state.is_logging_out() {
+            self.save_on_quit();
+            // Don't set self.running = false - let VellumFE stay open
+            // Fall through to send command to server
+        }
+
+        // Echo command to windows subscribed to "main" stream
+        if self.config.ui.command_echo && !command.is_empty() {
+            // Get windows subscribed to "main" stream
+            let subscribers: Vec<String> = self
+                .message_processor
+                .get_stream_subscribers("main")
+                .to_vec();
+
+            tracing::info!(
+                "[SEND_COMMAND] Echoing command to {} windows subscribed to 'main': {:?}",
+                subscribers.len(),
+                subscribers
+            );
+
+            // Build the styled line once
+            let mut segments = Vec::new();
+
+            // Add prompt with per-character coloring (same as prompt rendering)
+            tracing::debug!(
+                "[SEND_COMMAND] Building styled line with prompt: '{}'",
+                self.game_state.last_prompt
+            );
+            for ch in self.game_state.last_prompt.chars() {
+                // Prebuilt prompt color map (see MessageProcessor::build_prompt_color_map)
+                let color = self
+                    .message_processor
+                    .prompt_char_color(ch)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| "#808080".to_string()); // Default dark gray
+
+                segments.push(TextSegment {
+                    text: ch.to_string(),
+                    fg: Some(crate::data::intern::intern(&color)),
+                    bg: None,
+                    bold: false,
+                    mono: false,
+                    span_type: SpanType::Normal,
+                    link_data: None,
+                });
+            }
+
+            // Add the command text, after the configured prefix
+            segments.push(TextSegment {
+                text: format!("{}{}", self.config.ui.command_echo_prefix, echoed),
+                fg: Some(crate::data::intern::intern(
+                    &self.config.colors.ui.command_echo_color,
+                )),
+                bg: None,
+                bold: false,
+                mono: false,
+                span_type: SpanType::Normal,
+                link_data: None,
+            });
+
+            let styled_line = StyledLine {
+                segments,
+                stream: crate::data::intern::intern("main"),
+                timestamp: None,
+            };
+
+            // Echo bypasses the message pipeline, so mirror it to remote
+            // clients explicitly (they see the same echo as local windows)
+            if let Some(remote) = self.message_processor.remote.as_mut() {
+                remote.push_text("main", std::sync::Arc::new(styled_line.clone()));
+            }
+
+            // Add the styled line to each subscriber window
+            for window_name in subscribers {
+                if let Some(window) = self.ui_state.windows.get_mut(&window_name) {
+                    match &mut window.content {
+                        WindowContent::Text(ref mut content) => {
+                            content.add_line(styled_line.clone());
+                            tracing::info!(
+                                "[SEND_COMMAND] Added command echo to text window '{}'",
+                                window_name
+                            );
+                        }
+                        WindowContent::TabbedText(ref mut tabbed_content) => {
+                            // Find tab(s) subscribed to "main" stream and add the line
+                            for tab in tabbed_content.tabs.iter_mut() {
+                                if tab
+                                    .definition
+                                    .streams
+                                    .iter()
+                                    .any(|s| s.eq_ignore_ascii_case("main"))
+                                {
+                                    tab.content.add_line(styled_line.clone());
+                                    tracing::info!(
+                                        "[SEND_COMMAND] Added command echo to tabbed window '{}' tab '{}'",
+                                        window_name,
+                                        tab.definition.name
+                                    );
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Command history is now managed by the CommandInput widget; this is
+        // the searchable log behind the command log palette
+        if !secret {
+            self.command_log.record(&command);
+        }
+
+        // Return command for network layer to send (network layer adds newline)
+        Ok(command)
+    }
+
+    fn is_variable_command(command: &str) -> bool {
+        let Some(rest) = command.strip_prefix('.') else {
+            return false;
+        };
+        let name = rest.split_whitespace().next().unwrap_or_default();
+        ["set", "unset", "vars", "if"]
+            .iter()
+            .any(|c| name.eq_ignore_ascii_case(c))
+    }
+
+    /// `.if <condition> then <command>`: run the command only when the
+    /// condition holds against current game state (see core::condition).
+    fn handle_conditional_send(&mut self, args: &str) -> Result<String> {
+        use crate::core::condition::{evaluate, split_then, ConditionContext};
+
+        let Some((condition, inner)) = split_then(args) else {
+            self.add_system_message("Usage: .if <condition> then <command>");
+            return Ok(String::new());
+        };
+        let ctx = ConditionContext {
+            game_state: &self.game_state,
+            now_server: chrono::Utc::now().timestamp() + self.message_processor.server_time_offset,
+            variables: &self.config.variables,
+        };
+        match evaluate(condition, &ctx) {
+            Ok(true) => self.send_command(inner.to_string()),
+            Ok(false) => {
+                self.add_system_message(&format!("Not sent ({} is false): {}", condition, inner));
+                Ok(String::new())
+            }
+            Err(e) => {
+                self.add_system_message(&format!("Bad condition '{}': {}", condition, e));
+                Ok(String::new())
+            }
+        }
+    }
+
+    /// `.stance next|prev`: the game command one step toward defensive
+    /// or offensive in the stance widget's cycle order. With no argument,
+    /// report the current stance and posture.
+    fn stance_command(&mut self, arg: Option<&str>) -> String {
+        let forward = match arg.map(str::to_ascii_lowercase).as_deref() {
+            Some("next" | "def" | "defensive") => true,
+            Some("prev" | "off" | "offensive") => false,
+            Some(_) => {
+                self.add_system_message("Usage: .stance [next|prev]");
+                return String::new();
+            }
+            None => {
+                let stance = &self.game_state.stance;
+                let posture = crate::core::stance::posture(&self.game_state.status);
+                let message = if stance.name.is_empty() {
+                    "Stance: unknown (no stance data yet)".to_string()
+                } else {
+                    format!("Stance: {} ({}%)", stance.name, stance.percent)
+                };
+                let message = match posture {
+                    Some(posture) => format!("{}, {}", message, posture),
+                    None => message,
+                };
+                self.add_system_message(&message);
+                return String::new();
+            }
+        };
+        let configured = self
+            .layout
+            .windows
+            .iter()
+            .find_map(|w| match w {
+                crate::config::WindowDef::Stance { data, .. } => Some(data.stances.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let order = crate::core::stance::order_or_default(configured);
+        match crate::core::stance::step(&self.game_state.stance.name, &order, forward) {
+            Some(stance) => format!("stance {}", stance),
+            None => String::new(),
+        }
+    }
+
+    /// `.silver [amount|reset]`: show the silver estimate, correct it by
+    /// hand, or zero the session gain/loss.
+    fn silver_command(&mut self, arg: Option<&str>) {
+        use crate::core::wealth::format_silver;
+        match arg {
+            None => {
+                let wealth = &self.game_state.wealth;
+                let silver = wealth
+                    .silver
+                    .map(format_silver)
+                    .unwrap_or_else(|| "unknown (type WEALTH)".to_string());
+                let sign = if wealth.session_delta > 0 { "+" } else { "" };
+                let message = format!(
+                    "Silver: {}, session {}{}",
+                    silver,
+                    sign,
+                    format_silver(wealth.session_delta)
+                );
+                self.add_system_message(&message);
+            }
+            Some(arg) if arg.eq_ignore_ascii_case("reset") => {
+                self.game_state.wealth.reset_session();
+                self.add_system_message("Silver session gain/loss reset");
+            }
+            Some(arg) => match arg.replace(',', "").parse::<i64>() {
+                Ok(silver) => {
+                    self.game_state.wealth.set(silver);
+                    self.add_system_message(&format!("Silver set to {}", format_silver(silver)));
+                }
+                Err(_) => self.add_system_message("Usage: .silver [amount|reset]"),
+            },
+        }
+    }
+
+    /// `.loot [reset]`: print or clear the session loot tally.
+    fn loot_command(&mut self, arg: Option<&str>) {
+        match arg {
+            None => {
+                if !self.config.loot.enabled {
+                    self.add_system_message("Loot filter is off ([loot] enabled = false)");
+                }
+                for line in self.game_state.loot.summary_lines() {
+                    self.add_system_message(&line);
+                }
+            }
+            Some(arg) if arg.eq_ignore_ascii_case("reset") => {
+                self.game_state.loot.reset();
+                self.add_system_message("Loot tally reset");
+            }
+            Some(_) => self.add_system_message("Usage: .loot [reset]"),
+        }
+    }
+
+    /// `.combat [reset]`: print or clear the session combat analysis.
+    fn combat_command(&mut self, arg: Option<&str>) {
+        match arg {
+            None => {
+                let stats = &self.game_state.combat;
+                let mut lines = stats.summary_lines();
+                lines.extend(
+                    stats
+                        .creatures_by_damage()
+                        .into_iter()
+                        .map(crate::core::combat::CombatStats::creature_line),
+                );
+                for line in lines {
+                    self.add_system_message(&line);
+                }
+            }
+            Some(arg) if arg.eq_ignore_ascii_case("reset") => {
+                self.game_state.combat.reset();
+                self.add_system_message("Combat stats reset");
+            }
+            Some(_) => self.add_system_message("Usage: .combat [reset]"),
+        }
+    }
+
+    /// `.freeze <window> [hold|drop|off]`: without a mode, toggles between
+    /// unfrozen and holding new lines.
+    fn freeze_command(&mut self, name: Option<&str>, mode: Option<&str>) {
+        use crate::data::FreezeMode;
+
+        let Some(name) = name else {
+            self.add_system_message("Usage: .freeze <window> [hold|drop|off]");
+            return;
+        };
+        let frozen = self.is_window_frozen(name);
+        match mode.map(str::to_ascii_lowercase).as_deref() {
+            None | Some("off") if frozen => self.unfreeze_window(name),
+            Some("off") => self.add_system_message(&format!("'{}' isn't frozen", name)),
+            None | Some("hold") => self.freeze_window(name, FreezeMode::Hold),
+            Some("drop") => self.freeze_window(name, FreezeMode::Drop),
+            Some(_) => self.add_system_message("Usage: .freeze <window> [hold|drop|off]"),
+        }
+    }
+
+    /// `.alarm [test]`: show the alarm level and thresholds, or fire the
+    /// critical alerts once so the sound and notification can be checked.
+    fn alarm_command(&mut self, arg: Option<&str>) {
+        match arg {
+            None => {
+                let config = &self.config.alarm;
+                let alarm = &self.game_state.alarm;
+                let state = if !config.enabled {
+                    "off".to_string()
+                } else if alarm.reason.is_empty() {
+                    "clear".to_string()
+                } else {
+                    format!("{:?}: {}", alarm.level, alarm.reason)
+                };
+                let message = format!(
+                    "Alarm {} - warn at {}%, critical at {}%, stunned at {}%{}",
+                    state,
+                    config.warn_health,
+                    config.critical_health,
+                    config.stunned_health,
+                    if config.bleeding {
+                        ", bleeding warns"
+                    } else {
+                        ""
+                    }
+                );
+                self.add_system_message(&message);
+            }
+            Some(arg) if arg.eq_ignore_ascii_case("test") => {
+                self.game_state
+                    .alarm
+                    .test(&self.config.alarm, std::time::Instant::now());
+                self.raise_alarm(
+                    crate::core::alarm::AlarmLevel::Critical,
+                    "LOW HEALTH (test)",
+                );
+            }
+            Some(_) => self.add_system_message("Usage: .alarm [test]"),
+        }
+    }
+
+    /// `.export <window> <file>`: write a text window's scrollback (or the
+    /// last output block) to an ANSI or HTML file
+    fn export_window(&mut self, window: &str, file: &str) {
+        let Some((title, lines)) = self.viewer_content(window) else {
+            self.add_system_message(&format!("No text to export in '{}'", window));
+            return;
+        };
+        let link_fg = self
+            .config
+            .colors
+            .presets
+            .get("links")
+            .and_then(|preset| preset.fg.as_ref())
+            .map(|color| self.config.resolve_palette_color(color));
+        let result = crate::core::export::resolve_path(file).and_then(|path| {
+            crate::core::export::export_lines(&path, &title, &lines, link_fg.as_deref())
+                .map(|_| path)
+        });
+        match result {
+            Ok(path) => self.add_system_message(&format!(
+                "Exported {} lines from '{}' to {}",
+                lines.len(),
+                window,
+                path.display()
+            )),
+            Err(e) => self.add_system_message(&format!("Export failed: {:#}", e)),
+        }
+    }
+
+    /// `.import <wizard|genie|stormfront|vellumfe> <file>`: bring colors,
+    /// macros and triggers over from another client (see
+    /// config::theme_import and config::script_import). Everything goes
+    /// into this profile's files; a theme joins the theme list. `.import
+    /// yes` goes ahead with macros and triggers that replace existing ones.
+    fn import_command(&mut self, args: &str) {
+        use crate::config::theme_import::{self, ColorImport};
+
+        const USAGE: &str = "Usage: .import <wizard|genie|stormfront|vellumfe> <file>";
+        if args.trim().eq_ignore_ascii_case("yes") {
+            match self.pending_import.take() {
+                Some((import, source)) => self.save_and_report_script_import(import, &source),
+                None => self.add_system_message("No import is waiting to replace anything"),
+            }
+            return;
+        }
+        let Some((format, file)) = args.split_once(char::is_whitespace) else {
+            self.add_system_message(USAGE);
+            return;
+        };
+        let character = self.config.character.clone();
+        let text = match crate::config::PathsConfig::resolve(file.trim(), character.as_deref())
+            .and_then(|path| {
+                std::fs::read_to_string(&path)
+                    .with_context(|| format!("Could not read {}", path.display()))
+            }) {
+            Ok(text) => text,
+            Err(e) => {
+                self.add_system_message(&format!("Import failed: {:#}", e));
+                return;
+            }
+        };
+
+        match format.to_ascii_lowercase().as_str() {
+            "wizard" => {
+                let import = theme_import::import_wizard(&text);
+                self.apply_color_import(import, "Wizard");
+                let macros = crate::config::script_import::import_wizard_macros(&text);
+                if !macros.keybinds.is_empty() || !macros.unmapped.is_empty() {
+                    self.apply_script_import(macros, "Wizard");
+                }
+            }
+            "genie" => {
+                let import = crate::config::script_import::import_genie(&text);
+                self.apply_script_import(import, "Genie");
+            }
+            "stormfront" | "wrayth" => {
+                match crate::config::wrayth_import::import_wrayth_settings(&text) {
+                    Ok(result) => {
+                        let skipped = result
+                            .palette_misses
+                            .iter()
+                            .map(|miss| format!("palette reference {} (color dropped)", miss))
+                            .collect();
+                        let import = ColorImport {
+                            highlights: result.highlights,
+                            presets: Vec::new(),
+                            skipped,
+                        };
+                        self.apply_color_import(import, "StormFront");
+                    }
+                    Err(e) => self.add_system_message(&format!("Import failed: {:#}", e)),
+                }
+            }
+            "vellumfe" | "theme" => {
+                let stem = std::path::Path::new(file.trim())
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or("imported");
+                let result = theme_import::import_vellumfe_theme(&text, stem).and_then(|import| {
+                    let path = import.theme.save_to_file(character.as_deref())?;
+                    Ok((import, path))
+                });
+                match result {
+                    Ok((import, path)) => {
+                        self.add_system_message(&format!(
+                            "Imported theme '{}' to {} ({} colors derived from the file, {} from the dark theme). Use .theme {} to switch.",
+                            import.theme.name,
+                            path.display(),
+                            import.derived.len(),
+                            import.defaulted.len(),
+                            import.theme.name
+                        ));
+                    }
+                    Err(e) => self.add_system_message(&format!("Import failed: {:#}", e)),
+                }
+            }
+            _ => self.add_system_message(USAGE),
+        }
+    }
+
+    /// Save imported highlights and stream colors to this profile's files,
+    /// then reload both and report what came across
+    fn apply_color_import(
+        &mut self,
+        import: crate::config::theme_import::ColorImport,
+        source: &str,
+    ) {
+        let character = self.config.character.clone();
+        let mut failed = None;
+        for (name, pattern) in &import.highlights {
+            if let Err(e) = crate::config::Config::save_single_highlight(
+                name,
+                pattern,
+                false,
+                character.as_deref(),
+            ) {
+                failed = Some(e);
+                break;
+            }
+        }
+        if failed.is_none() && !import.presets.is_empty() {
+            let result =
+                crate::config::ColorConfig::load_character_colors_only(character.as_deref())
+                    .and_then(|mut colors| {
+                        colors.presets.extend(import.presets.iter().cloned());
+                        colors.save(character.as_deref())
+                    });
+            failed = result.err();
+        }
+        if let Some(e) = failed {
+            self.add_system_message(&format!("Import failed: {:#}", e));
+            return;
+        }
+
+        if !import.highlights.is_empty() {
+            self.reload_highlights();
+        }
+        if !import.presets.is_empty() {
+            self.reload_colors();
+        }
+        self.add_system_message(&format!(
+            "Imported {} highlights and {} stream colors from {} ({} skipped)",
+            import.highlights.len(),
+            import.presets.len(),
+            source,
+            import.skipped.len()
+        ));
+        for skipped in &import.skipped {
+            self.add_system_message(&format!("  skipped: {}", skipped));
+        }
+    }
+
+    /// Save imported highlights, keybinds and variables to this profile,
+    /// then list what had no VellumFE equivalent. If that would replace
+    /// keybinds or highlights already set, list those and wait for
+    /// `.import yes` instead.
+    fn apply_script_import(
+        &mut self,
+        import: crate::config::script_import::ScriptImport,
+        source: &str,
+    ) {
+        let mut replaced: Vec<String> = import
+            .keybinds
+            .iter()
+            .filter(|(key, _)| self.config.keybinds.contains_key(key))
+            .map(|(key, _)| format!("keybind {}", key))
+            .collect();
+        replaced.extend(
+            import
+                .highlights
+                .iter()
+                .filter(|(name, _)| self.config.highlights.contains_key(name))
+                .map(|(name, _)| format!("highlight {}", name)),
+        );
+        if replaced.is_empty() {
+            self.save_and_report_script_import(import, source);
+            return;
+        }
+        self.add_system_message(&format!(
+            "Importing from {} would replace {} existing entries:",
+            source,
+            replaced.len()
+        ));
+        for entry in &replaced {
+            self.add_system_message(&format!("  {}", entry));
+        }
+        self.add_system_message(
+            "Type .import yes to replace them, or fix the file and import again",
+        );
+        self.pending_import = Some((import, source.to_string()));
+    }
+
+    fn save_and_report_script_import(
+        &mut self,
+        import: crate::config::script_import::ScriptImport,
+        source: &str,
+    ) {
+        if let Err(e) = self.save_script_import(&import) {
+            self.add_system_message(&format!("Import failed: {:#}", e));
+            return;
+        }
+
+        if !import.highlights.is_empty() {
+            self.reload_highlights();
+        }
+        if !import.keybinds.is_empty() {
+            for (key, action) in &import.keybinds {
+                self.config.keybinds.insert(key.clone(), action.clone());
+            }
+            self.rebuild_keybind_map();
+        }
+        self.add_system_message(&format!(
+            "Imported {} highlights, {} keybinds and {} variables from {} ({} unmapped)",
+            import.highlights.len(),
+            import.keybinds.len(),
+            import.variables.len(),
+            source,
+            import.unmapped.len()
+        ));
+        for converted in &import.converted {
+            self.add_system_message(&format!("  converted: {}", converted));
+        }
+        for unmapped in &import.unmapped {
+            self.add_system_message(&format!("  unmapped: {}", unmapped));
+        }
+    }
+
+    fn save_script_import(
+        &mut self,
+        import: &crate::config::script_import::ScriptImport,
+    ) -> Result<()> {
+        let character = self.config.character.clone();
+        for (name, pattern) in &import.highlights {
+            crate::config::Config::save_single_highlight(
+                name,
+                pattern,
+                false,
+                character.as_deref(),
+            )?;
+        }
+        for (key, action) in &import.keybinds {
+            crate::config::Config::save_single_keybind(key, action, false, character.as_deref())?;
+        }
+        if !import.variables.is_empty() {
+            for (name, value) in &import.variables {
+                self.config.variables.set(name, value, false);
+            }
+            self.config.variables.save(false, character.as_deref())?;
+        }
+        Ok(())
+    }
+
+    /// `.url [<n>|open <url>|yes]` / `.urls`: list recent URLs, open one,
+    /// or confirm the one waiting for `.url yes`
+    fn url_command(&mut self, args: &str) {
+        let (sub, rest) = args
+            .split_once(char::is_whitespace)
+            .map(|(sub, rest)| (sub, rest.trim()))
+            .unwrap_or((args, ""));
+        match sub.to_lowercase().as_str() {
+            "" | "list" => self.list_urls(),
+            "open" if !rest.is_empty() => self.open_url(rest),
+            "yes" => match self.game_state.urls.pending.take() {
+                Some(url) => self.launch_url(&url),
+                None => self.add_system_message("No URL is waiting to open"),
+            },
+            n => match n.parse().ok().and_then(|n| self.game_state.urls.get(n)) {
+                Some(url) => {
+                    let url = url.to_string();
+                    self.open_url(&url)
+                }
+                None => self.add_system_message("Usage: .url [<n>|open <url>|yes]"),
+            },
+        }
+    }
+
+    /// List recent URLs as clickable links, newest first
+    pub fn list_urls(&mut self) {
+        use crate::data::{LinkData, SpanType, StyledLine, TextSegment};
+
+        if self.game_state.urls.is_empty() {
+            self.add_system_message("No URLs seen yet");
+            return;
+        }
+        self.add_system_message("Recent URLs (click one, or .url <n>):");
+        let urls: Vec<String> = self.game_state.urls.iter().map(str::to_string).collect();
+        for (i, url) in urls.into_iter().enumerate() {
+            self.add_system_line(StyledLine {
+                segments: vec![
+                    TextSegment {
+                        text: format!("  {:>2}. ", i + 1),
+                        fg: Some(crate::data::intern::intern("#00ff00")),
+                        span_type: SpanType::System,
+                        ..Default::default()
+                    },
+                    TextSegment {
+                        text: url.clone(),
+                        span_type: SpanType::Link,
+                        link_data: Some(LinkData {
+                            exist_id: "_direct_".to_string(),
+                            noun: format!(".url open {}", url),
+                            text: url,
+                            coord: None,
+                        }),
+                        ..Default::default()
+                    },
+                ],
+                stream: crate::data::intern::intern("main"),
+                timestamp: None,
+            });
+        }
+    }
+
+    /// Open a URL now if `[urls]` allows it, otherwise hold it for `.url yes`
+    fn open_url(&mut self, url: &str) {
+        let lower = url.to_ascii_lowercase();
+        if !(lower.starts_with("http://") || lower.starts_with("https://")) {
+            self.add_system_message("Only http(s) URLs can be opened");
+            return;
+        }
+        if crate::core::urls::opens_without_confirm(url, &self.config.urls) {
+            self.launch_url(url);
+        } else {
+            self.game_state.urls.pending = Some(url.to_string());
+            self.add_system_message(&format!(
+                "Open {}? Its host isn't in the [urls] allow list; type .url yes to open it",
+                url
+            ));
+        }
+    }
+
+    fn launch_url(&mut self, url: &str) {
+        match crate::platform::open_url(url) {
+            Ok(()) => self.add_system_message(&format!("Opened {}", url)),
+            Err(e) => self.add_system_message(&format!("Could not open {}: {}", url, e)),
+        }
+    }
+
+    /// Move an item dropped on a container window into that container with
+    /// `get` and `put`, or hold the move for `.move yes` when
+    /// `[containers] confirm_moves` is on. Returns false when the item
+    /// isn't in a known container (or drag moves are off), so the caller
+    /// can fall back to the game's `_drag`.
+    pub fn move_item(&mut self, item_id: &str, to_container_id: &str) -> bool {
+        if !self.config.containers.drag_moves {
+            return false;
+        }
+        let cache = &self.game_state.container_cache;
+        let (Some((from, link)), Some(to)) = (cache.find_item(item_id), cache.get(to_container_id))
+        else {
+            return false;
+        };
+        if from.id == to.id {
+            return true;
+        }
+        let item_move = crate::core::containers::ItemMove {
+            item_id: item_id.to_string(),
+            item_name: link.text,
+            from_id: from.id.clone(),
+            from_title: from.title.clone(),
+            to_id: to.id.clone(),
+            to_title: to.title.clone(),
+        };
+        if self.config.containers.confirm_moves {
+            self.add_system_message(&format!(
+                "Move {} from {} to {}? Type .move yes to confirm",
+                item_move.item_name, item_move.from_title, item_move.to_title
+            ));
+            self.pending_item_move = Some(item_move);
+        } else {
+            self.queued_commands.extend(item_move.commands());
+        }
+        true
+    }
+
+    /// `.move yes|no`: confirm or cancel a dragged item move
+    fn move_command(&mut self, args: &str) {
+        match args.to_lowercase().as_str() {
+            "yes" | "y" => match self.pending_item_move.take() {
+                Some(item_move) => self.queued_commands.extend(item_move.commands()),
+                None => self.add_system_message("No item move is waiting"),
+            },
+            "no" | "n" => match self.pending_item_move.take() {
+                Some(item_move) => {
+                    self.add_system_message(&format!("Cancelled moving {}", item_move.item_name))
+                }
+                None => self.add_system_message("No item move is waiting"),
+            },
+            _ => self.add_system_message("Usage: .move yes|no"),
+        }
+    }
+
+    /// `.repeat <n|forever> <delay> <command>` starts repeating, `.repeat
+    /// stop` ends it, and a bare `.repeat` shows what's running
+    fn repeat_command(&mut self, args: &str) {
+        if args.is_empty() {
+            match self.repeat.label() {
+                Some(label) => self.add_system_message(&format!("[repeat] {}", label)),
+                None => self.add_system_message(
+                    "Usage: .repeat <n|forever> <delay> <command> | stop (Esc also stops it)",
+                ),
+            }
+            return;
+        }
+        if args.eq_ignore_ascii_case("stop") || args.eq_ignore_ascii_case("off") {
+            self.stop_repeat();
+            return;
+        }
+        if !self.game_state.connected {
+            self.add_system_message("[repeat] not connected");
+            return;
+        }
+        match crate::core::repeat::RepeatSpec::parse(args) {
+            Ok(spec) => {
+                let times = match spec.count {
+                    Some(count) => format!("{} times", count),
+                    None => "until stopped".to_string(),
+                };
+                self.add_system_message(&format!(
+                    "[repeat] {} every {:.1}s, {} (.repeat stop or Esc to end)",
+                    spec.command,
+                    spec.delay.as_secs_f64(),
+                    times
+                ));
+                self.repeat.start(spec);
+                self.needs_render = true;
+            }
+            Err(e) => self.add_system_message(&format!("[repeat] {}", e)),
+        }
+    }
+
+    /// `.go <route> [reverse]` walks a saved route; `pause`, `resume` and
+    /// `stop` control playback; `save`, `delete`, `show` and `list` manage
+    /// `[routes] saved`.
+    fn go_command(&mut self, args: &str) {
+        const KEYWORDS: &[&str] = &[
+            "pause", "resume", "stop", "abort", "save", "delete", "show", "list",
+        ];
+        let (first, rest) = args
+            .split_once(char::is_whitespace)
+            .map(|(first, rest)| (first, rest.trim()))
+            .unwrap_or((args, ""));
+        let first = first.to_lowercase();
+        match first.as_str() {
+            "" => match self.route.label() {
+                Some(label) => self.add_system_message(&format!("[go] {}", label)),
+                None => self.add_system_message(
+                    "Usage: .go <route> [reverse] | pause | resume | stop | save <name> <step; step; ...> | delete <name> | show <name> | list",
+                ),
+            },
+            "pause" => {
+                if self.route.pause() {
+                    self.add_system_message("[go] paused (.go resume to continue)");
+                } else {
+                    self.add_system_message("[go] no route playing");
+                }
+            }
+            "resume" => {
+                if !self.route.resume() {
+                    self.add_system_message("[go] no paused route");
+                }
+            }
+            "stop" | "abort" => self.stop_route(),
+            "list" => {
+                if self.config.routes.saved.is_empty() {
+                    self.add_system_message("[go] no saved routes (.go save <name> <step; step; ...>)");
+                    return;
+                }
+                let routes: Vec<String> = self
+                    .config
+                    .routes
+                    .saved
+                    .iter()
+                    .map(|(name, steps)| format!("{} ({} steps)", name, steps.len()))
+                    .collect();
+                self.add_system_message(&format!("[go] routes: {}", routes.join(", ")));
+            }
+            "show" => match self.config.routes.saved.get(&rest.to_lowercase()) {
+                Some(steps) => {
+                    let message = format!("[go] {}: {}", rest.to_lowercase(), steps.join("; "));
+                    self.add_system_message(&message);
+                }
+                None => self.add_system_message(&format!("[go] no route '{}'", rest)),
+            },
+            "save" => {
+                let (name, steps) = rest
+                    .split_once(char::is_whitespace)
+                    .map(|(name, steps)| (name.to_lowercase(), crate::core::routes::parse_steps(steps)))
+                    .unwrap_or_default();
+                if steps.is_empty() {
+                    self.add_system_message("Usage: .go save <name> <step; step; ...>");
+                    return;
+                }
+                if !crate::core::rebuff::SpellSets::is_valid_name(&name)
+                    || KEYWORDS.contains(&name.as_str())
+                {
+                    self.add_system_message(
+                        "[go] route names are letters, digits, - and _, and not a .go keyword",
+                    );
+                    return;
+                }
+                let count = steps.len();
+                self.config.routes.saved.insert(name.clone(), steps);
+                match self.save_config() {
+                    Ok(()) => self.add_system_message(&format!(
+                        "[go] saved '{}' ({} steps) - walk it with .go {}",
+                        name, count, name
+                    )),
+                    Err(e) => self.add_system_message(&format!("[go] save failed: {}", e)),
+                }
+            }
+            "delete" => {
+                let name = rest.to_lowercase();
+                if self.config.routes.saved.remove(&name).is_none() {
+                    self.add_system_message(&format!("[go] no route '{}'", rest));
+                    return;
+                }
+                match self.save_config() {
+                    Ok(()) => self.add_system_message(&format!("[go] deleted '{}'", name)),
+                    Err(e) => self.add_system_message(&format!("[go] save failed: {}", e)),
+                }
+            }
+            name => {
+                let reverse = match rest.to_lowercase().as_str() {
+                    "" => false,
+                    "reverse" | "back" => true,
+                    _ => {
+                        self.add_system_message("Usage: .go <route> [reverse]");
+                        return;
+                    }
+                };
+                let Some(steps) = self.config.routes.saved.get(name).cloned() else {
+                    self.add_system_message(&format!("[go] no route '{}' (.go list)", name));
+                    return;
+                };
+                let steps = if reverse {
+                    match crate::core::routes::reverse_route(&steps) {
+                        Ok(steps) => steps,
+                        Err(step) => {
+                            self.add_system_message(&format!(
+                                "[go] '{}' can't be walked backwards - save a return route instead",
+                                step
+                            ));
+                            return;
+                        }
+                    }
+                } else {
+                    steps
+                };
+                if self.travel.is_traveling() {
+                    self.add_system_message("[go] .go2 is traveling - .go2 stop first");
+                    return;
+                }
+                let count = steps.len();
+                self.route.start(name, steps);
+                self.add_system_message(&format!(
+                    "[go] walking {}{} ({} steps) - .go pause / .go stop",
+                    name,
+                    if reverse { " in reverse" } else { "" },
+                    count
+                ));
+            }
+        }
+    }
+
+    /// `.notes` opens the notes editor; `.notes add <text>` appends a line
+    fn notes_command(&mut self, args: &str) -> String {
+        if args.is_empty() {
+            return "action:notes".to_string();
+        }
+        let Some(line) = args
+            .strip_prefix("add ")
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        else {
+            self.add_system_message("Usage: .notes [add <text>]");
+            return String::new();
+        };
+        let mut text = self.notes.text().to_string();
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        text.push_str(line);
+        text.push('\n');
+        self.set_notes(text);
+        self.add_system_message("[notes] added");
+        String::new()
+    }
+
+    /// `.r <text>` / `.rt <text>`: answer the last whisper or thought
+    /// through the `[reply]` template. With no text, say who that would be.
+    fn reply_command(&mut self, thought: bool, text: &str) -> Result<String> {
+        let (target, template, kind) = if thought {
+            let reply = &self.game_state.reply.thought;
+            (reply.clone(), self.config.reply.thought.clone(), "thought")
+        } else {
+            let reply = &self.game_state.reply.whisper;
+            (reply.clone(), self.config.reply.whisper.clone(), "whisper")
+        };
+        let Some(name) = target else {
+            self.add_system_message(&format!("No {} to reply to yet", kind));
+            return Ok(String::new());
+        };
+        if text.is_empty() {
+            self.add_system_message(&format!("Replying to {} from {}", kind, name));
+            return Ok(String::new());
+        }
+        let command = crate::core::reply::expand(&template, &name, text);
+        self.send_command(command.trim_end().to_string())
+    }
+
+    /// Input text for the `reply_whisper` keybind: the whisper template up
+    /// to what you'd type, e.g. "whisper Bob "
+    pub fn reply_prefill(&self) -> Option<String> {
+        let name = self.game_state.reply.whisper.as_deref()?;
+        let template = &self.config.reply.whisper;
+        let head = template.split("{text}").next().unwrap_or(template);
+        Some(crate::core::reply::expand(head, name, ""))
+    }
+
+    /// `.rebuff [list|stop]`, `.rebuff save|delete <set>`, and
+    /// `.rebuff <set> [cast]`: manage spell sets, show what has dropped
+    /// from one, or queue the casts that restore it.
+    fn rebuff_command(&mut self, args: &[&str]) {
+        const USAGE: &str =
+            "Usage: .rebuff [list|stop] | .rebuff save|delete <set> | .rebuff <set> [cast]";
+        let sub = args.first().map(|s| s.to_ascii_lowercase());
+        match (sub.as_deref(), args.get(1).copied(), args.len()) {
+            (None, ..) | (Some("list"), None, _) => {
+                if self.spell_sets.sets.is_empty() {
+                    self.add_system_message(
+                        "[rebuff] no spell sets - .rebuff save <set> records your active spells",
+                    );
+                    return;
+                }
+                let lines: Vec<String> = self
+                    .spell_sets
+                    .sets
+                    .iter()
+                    .map(|(name, spells)| format!("[rebuff] {}: {} spells", name, spells.len()))
+                    .collect();
+                for line in lines {
+                    self.add_system_message(&line);
+                }
+            }
+            (Some("stop"), None, _) => {
+                let remaining = self.rebuff.remaining();
+                if self.rebuff.stop() {
+                    self.add_system_message(&format!(
+                        "[rebuff] stopped - {} commands not sent",
+                        remaining
+                    ));
+                } else {
+                    self.add_system_message("[rebuff] nothing queued.");
+                }
+            }
+            (Some("save"), Some(name), 2) => self.save_spell_set(name),
+            (Some("delete"), Some(name), 2) => {
+                if !self.spell_sets.remove(name) {
+                    self.add_system_message(&format!("[rebuff] no spell set named '{}'", name));
+                    return;
+                }
+                self.write_spell_sets();
+                self.add_system_message(&format!("[rebuff] deleted spell set '{}'", name));
+            }
+            (Some(_), None, 1) => self.plan_rebuff(args[0], false),
+            (Some(_), Some(cast), 2) if cast.eq_ignore_ascii_case("cast") => {
+                self.plan_rebuff(args[0], true)
+            }
+            _ => self.add_system_message(USAGE),
+        }
+    }
+
+    /// `.remind [list] | <delay> <message> | at|daily <time> <message> |
+    /// every <interval> <message> | delete <n> | clear`
+    fn remind_command(&mut self, args: &str) {
+        use crate::core::reminders::{
+            format_duration, next_local, parse_clock, parse_duration, Reminder,
+        };
+        const USAGE: &str = "Usage: .remind <45m|1h30m> <message> | .remind at|daily <9pm|21:00> <message> | .remind every <2h> <message> | .remind [list] | .remind delete <n> | .remind clear";
+
+        let (sub, rest) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+        let rest = rest.trim();
+        let now = chrono::Utc::now().timestamp();
+        let reminder = |due: i64, message: &str| Reminder {
+            id: 0,
+            message: message.to_string(),
+            due,
+            every: None,
+            daily: None,
+        };
+
+        let new = match sub.to_ascii_lowercase().as_str() {
+            "" | "list" => {
+                if self.reminders.is_empty() {
+                    self.add_system_message(
+                        "[remind] no reminders - .remind 45m <message> sets one",
+                    );
+                    return;
+                }
+                let lines: Vec<String> = self
+                    .reminders
+                    .upcoming()
+                    .into_iter()
+                    .map(|r| {
+                        let repeat = r
+                            .repeat_label()
+                            .map(|l| format!(" ({})", l))
+                            .unwrap_or_default();
+                        format!(
+                            "[remind] {}. in {} - {}{}",
+                            r.id,
+                            format_duration(r.due - now),
+                            r.message,
+                            repeat
+                        )
+                    })
+                    .collect();
+                for line in lines {
+                    self.add_system_message(&line);
+                }
+                return;
+            }
+            "delete" | "del" | "rm" => {
+                match rest.trim_start_matches('#').parse::<u32>() {
+                    Ok(id) if self.reminders.remove(id) => {
+                        self.write_reminders();
+                        self.add_system_message(&format!("[remind] deleted reminder {}", id));
+                    }
+                    Ok(id) => self.add_system_message(&format!("[remind] no reminder {}", id)),
+                    Err(_) => self.add_system_message(USAGE),
+                }
+                return;
+            }
+            "clear" => {
+                self.reminders.clear();
+                self.write_reminders();
+                self.add_system_message("[remind] all reminders cleared");
+                return;
+            }
+            "at" | "daily" | "every" => {
+                let (when, message) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let message = message.trim();
+                if message.is_empty() {
+                    self.add_system_message(USAGE);
+                    return;
+                }
+                if sub.eq_ignore_ascii_case("every") {
+                    match parse_duration(when) {
+                        Some(every) => Reminder {
+                            every: Some(every),
+                            ..reminder(now + every as i64, message)
+                        },
+                        None => {
+                            self.add_system_message(&format!(
+                                "[remind] can't read interval '{}'",
+                                when
+                            ));
+                            return;
+                        }
+                    }
+                } else {
+                    let Some(time) = parse_clock(when) else {
+                        self.add_system_message(&format!(
+                            "[remind] can't read time '{}' (9pm, 9:30pm, 21:00)",
+                            when
+                        ));
+                        return;
+                    };
+                    let daily = sub
+                        .eq_ignore_ascii_case("daily")
+                        .then(|| time.format("%H:%M").to_string());
+                    Reminder {
+                        daily,
+                        ..reminder(next_local(now, time), message)
+                    }
+                }
+            }
+            _ => match parse_duration(sub) {
+                Some(delay) if !rest.is_empty() => reminder(now + delay as i64, rest),
+                _ => {
+                    self.add_system_message(USAGE);
+                    return;
+                }
+            },
+        };
+
+        let when = match new.repeat_label() {
+            Some(repeat) => format!("{}, next in {}", repeat, format_duration(new.due - now)),
+            None => format!("in {}", format_duration(new.due - now)),
+        };
+        let message = new.message.clone();
+        let id = self.reminders.add(new);
+        self.write_reminders();
+        self.add_system_message(&format!("[remind] {}. {} - {}", id, when, message));
+    }
+
+    /// `.watch [list] | add <expr> [as <label>] | remove <n> | clear`: edit
+    /// the rows of the layout's first watch window, adding one on first use
+    fn watch_command(&mut self, args: &str) {
+        use crate::core::watch::{parse_entry, USAGE};
+
+        let (sub, rest) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+        let rest = rest.trim();
+        let window = self
+            .layout
+            .windows
+            .iter()
+            .find(|w| matches!(w, crate::config::WindowDef::Watch { .. }))
+            .map(|w| w.name().to_string());
+
+        match sub.to_ascii_lowercase().as_str() {
+            "" | "list" => {
+                let rows = window
+                    .as_deref()
+                    .map(|w| self.watch_rows(w))
+                    .unwrap_or_default();
+                if rows.is_empty() {
+                    self.add_system_message(
+                        "[watch] nothing watched - .watch add <expr> [as <label>]",
+                    );
+                    return;
+                }
+                for (i, row) in rows.iter().enumerate() {
+                    let value = match &row.value {
+                        Ok(value) => value.clone(),
+                        Err(e) => format!("? ({})", e),
+                    };
+                    self.add_system_message(&format!(
+                        "[watch] {}. {} = {}",
+                        i + 1,
+                        row.label,
+                        value
+                    ));
+                }
+            }
+            "add" => {
+                let Some(entry) = parse_entry(rest) else {
+                    self.add_system_message(USAGE);
+                    return;
+                };
+                let window = match window {
+                    Some(window) => window,
+                    None => {
+                        let width = self.layout.terminal_width.unwrap_or(80);
+                        let height = self.layout.terminal_height.unwrap_or(24);
+                        self.show_window("watch", width, height);
+                        "watch".to_string()
+                    }
+                };
+                let Some(watches) = self.watch_entries_mut(&window) else {
+                    return;
+                };
+                watches.push(entry);
+                self.mark_layout_modified();
+                self.needs_render = true;
+                if let Some(row) = self.watch_rows(&window).pop() {
+                    let value = row.value.unwrap_or_else(|e| format!("? ({})", e));
+                    self.add_system_message(&format!("[watch] added {} = {}", row.label, value));
+                }
+            }
+            "remove" | "delete" => {
+                let index = rest.parse::<usize>().ok().filter(|&n| n > 0);
+                let removed = match (window.as_deref(), index) {
+                    (Some(window), Some(n)) => self
+                        .watch_entries_mut(window)
+                        .filter(|watches| n <= watches.len())
+                        .map(|watches| watches.remove(n - 1)),
+                    _ => None,
+                };
+                match removed {
+                    Some(entry) => {
+                        self.mark_layout_modified();
+                        self.needs_render = true;
+                        self.add_system_message(&format!("[watch] removed {}", entry.expr));
+                    }
+                    None => self.add_system_message(&format!(
+                        "[watch] no row '{}' - .watch lists them",
+                        rest
+                    )),
+                }
+            }
+            "clear" => {
+                if let Some(watches) = window.as_deref().and_then(|w| self.watch_entries_mut(w)) {
+                    watches.clear();
+                    self.mark_layout_modified();
+                    self.needs_render = true;
+                }
+                self.add_system_message("[watch] all rows cleared");
+            }
+            _ => self.add_system_message(USAGE),
+        }
+    }
+
+    fn watch_entries_mut(&mut self, window: &str) -> Option<&mut Vec<crate::config::WatchEntry>> {
+        match self.layout.windows.iter_mut().find(|w| w.name() == window) {
+            Some(crate::config::WindowDef::Watch { data, .. }) => Some(&mut data.watches),
+            _ => None,
+        }
+    }
+
+    /// `.inspect [on|off|clear|<filter>]`: record recent server lines for the
+    /// parser inspector, or open it (filtered by tag type). Returns the
+    /// action string that opens the popup.
+    fn inspect_command(&mut self, args: &str) -> String {
+        use crate::core::inspector::CAPACITY;
+        match args.to_ascii_lowercase().as_str() {
+            "on" => {
+                self.inspector.set_enabled(true);
+                self.add_system_message(&format!(
+                    "[inspect] recording the last {} server lines - .inspect [tag] to view, .inspect off to stop",
+                    CAPACITY
+                ));
+            }
+            "off" => {
+                self.inspector.set_enabled(false);
+                self.add_system_message("[inspect] stopped and cleared");
+            }
+            "clear" => {
+                self.inspector.clear();
+                self.add_system_message("[inspect] cleared");
+            }
+            _ if !self.inspector.is_enabled() => {
+                self.inspector.set_enabled(true);
+                self.add_system_message(
+                    "[inspect] recording from now - reproduce the problem, then .inspect again",
+                );
+            }
+            _ if self.inspector.is_empty() => {
+                self.add_system_message("[inspect] nothing recorded yet");
+            }
+            _ => return format!("action:inspect:{}", args),
+        }
+        String::new()
+    }
+
+    /// `.quit [yes|now]` and the quit keybind: exit, first asking and
+    /// logging the character out while connected, per `[quit]`
+    /// (see core::quit)
+    pub fn request_quit(&mut self, args: &str) {
+        use crate::core::quit::QuitStep;
+        let now = std::time::Instant::now();
+        let step = self
+            .quit_state
+            .request(args, self.game_state.connected, &self.config.quit, now);
+        match step {
+            QuitStep::Exit => self.quit(),
+            QuitStep::Confirm => self.add_system_message(
+                "[quit] still connected - quit again to log out and exit (.quit now skips the logout)",
+            ),
+            QuitStep::Logout { command, wait } => {
+                // Asked for already; a `^quit$` lockout mustn't hold it
+                self.command_lockout.approve(command.clone());
+                self.queued_commands.push(command.clone());
+                self.add_system_message(&format!(
+                    "[quit] sent '{}' - exiting when the server closes the connection (at most {}s; quit again to stop waiting)",
+                    command,
+                    wait.as_secs()
+                ));
+            }
+        }
+        self.needs_render = true;
+    }
 
-        // Intercept game "quit" command - save settings before disconnecting
-        // This handles the case where users close terminal after game disconnect
-        if command.trim().eq_ignore_ascii_case("quit") {
-            self.save_on_quit();
-            // Don't set self.running = false - let VellumFE stay open
-            // Fall through to send command to server
+    /// `.traffic [on|off|hex|clear]`: capture raw socket lines into the
+    /// traffic console window. Bare `.traffic` toggles capture.
+    fn traffic_command(&mut self, args: &str) {
+        use crate::network::traffic;
+
+        let window = self
+            .layout
+            .windows
+            .iter()
+            .find(|w| matches!(w, crate::config::WindowDef::Traffic { .. }))
+            .map(|w| w.name().to_string());
+        let sub = match args.to_ascii_lowercase() {
+            sub if sub.is_empty() && traffic::is_enabled() => "off".to_string(),
+            sub if sub.is_empty() => "on".to_string(),
+            sub => sub,
+        };
+        match sub.as_str() {
+            "on" => {
+                traffic::set_enabled(true);
+                let width = self.layout.terminal_width.unwrap_or(80);
+                let height = self.layout.terminal_height.unwrap_or(24);
+                self.show_window(window.as_deref().unwrap_or("traffic"), width, height);
+                self.add_system_message(&format!(
+                    "[traffic] capturing raw socket lines (last {} kept) - .traffic off to pause",
+                    traffic::CAPACITY
+                ));
+            }
+            "off" => {
+                traffic::set_enabled(false);
+                self.add_system_message(&format!(
+                    "[traffic] paused with {} lines - .traffic clear drops them",
+                    traffic::len()
+                ));
+            }
+            "hex" => {
+                let mut hex = None;
+                for window in self.layout.windows.iter_mut() {
+                    if let crate::config::WindowDef::Traffic { data, .. } = window {
+                        data.hex = !data.hex;
+                        hex = Some(data.hex);
+                    }
+                }
+                match hex {
+                    Some(hex) => {
+                        self.mark_layout_modified();
+                        self.needs_render = true;
+                        self.add_system_message(if hex {
+                            "[traffic] hex view on"
+                        } else {
+                            "[traffic] hex view off"
+                        });
+                    }
+                    None => self
+                        .add_system_message("[traffic] no traffic window - .traffic on opens one"),
+                }
+            }
+            "clear" => {
+                traffic::clear();
+                self.needs_render = true;
+                self.add_system_message("[traffic] cleared");
+            }
+            _ => self.add_system_message("Usage: .traffic [on|off|hex|clear]"),
         }
+    }
 
-        // Echo command to windows subscribed to "main" stream
-        if self.config.ui.command_echo && !command.is_empty() {
-            // Get windows subscribed to "main" stream
-            let subscribers: Vec<String> = self
-                .message_processor
-                .get_stream_subscribers("main")
-                .to_vec();
+    /// `.played`: this session, today, the last week and month, and the
+    /// lifetime total
+    fn played_command(&mut self) {
+        // Credit the session up to now so the numbers agree
+        self.tick_played();
+        for line in self.played.summary(chrono::Local::now()) {
+            self.add_system_message(&format!("[played] {}", line));
+        }
+    }
 
-            tracing::info!(
-                "[SEND_COMMAND] Echoing command to {} windows subscribed to 'main': {:?}",
-                subscribers.len(),
-                subscribers
-            );
+    /// `.away` toggles away mode, `.away <message>` goes away with that
+    /// auto-reply, `.away off` comes back
+    fn away_command(&mut self, args: &str) {
+        use crate::core::away::AwayReason;
 
-            // Build the styled line once
-            let mut segments = Vec::new();
+        let going = match args {
+            "" => !self.away.is_away(),
+            "on" => true,
+            "off" => false,
+            message => {
+                self.away.message = Some(message.to_string());
+                true
+            }
+        };
+        if !going {
+            match self.away.end() {
+                Some(missed) => self.announce_back(missed),
+                None => self.add_system_message("[away] you're not away"),
+            }
+            return;
+        }
+        self.away.start(AwayReason::Manual);
+        let message = self
+            .away
+            .message
+            .clone()
+            .unwrap_or_else(|| self.config.away.message.clone());
+        if self.config.away.auto_reply {
+            self.add_system_message(&format!(
+                "[away] you are now away - whispers get \"{}\"",
+                message
+            ));
+        } else {
+            self.add_system_message("[away] you are now away");
+        }
+    }
 
-            // Add prompt with per-character coloring (same as prompt rendering)
-            tracing::debug!(
-                "[SEND_COMMAND] Building styled line with prompt: '{}'",
-                self.game_state.last_prompt
-            );
-            for ch in self.game_state.last_prompt.chars() {
-                // Prebuilt prompt color map (see MessageProcessor::build_prompt_color_map)
-                let color = self
-                    .message_processor
-                    .prompt_char_color(ch)
-                    .map(str::to_string)
-                    .unwrap_or_else(|| "#808080".to_string()); // Default dark gray
+    /// Record the spells active right now as set `name`.
+    fn save_spell_set(&mut self, name: &str) {
+        if !crate::core::rebuff::SpellSets::is_valid_name(name) {
+            self.add_system_message("[rebuff] set names are letters, digits, - and _");
+            return;
+        }
+        let spells = self
+            .game_state
+            .effects
+            .get("ActiveSpells")
+            .map(|content| crate::core::rebuff::snapshot(&content.effects))
+            .unwrap_or_default();
+        if spells.is_empty() {
+            self.add_system_message("[rebuff] no active spells to record");
+            return;
+        }
+        let count = spells.len();
+        self.spell_sets.insert(name, spells);
+        self.write_spell_sets();
+        self.add_system_message(&format!(
+            "[rebuff] saved {} active spells as '{}'",
+            count,
+            name.to_ascii_lowercase()
+        ));
+    }
 
-                segments.push(TextSegment {
-                    text: ch.to_string(),
-                    fg: Some(color),
-                    bg: None,
-                    bold: false,
-                    mono: false,
-                    span_type: SpanType::Normal,
-                    link_data: None,
-                });
-            }
+    fn write_spell_sets(&mut self) {
+        let result = crate::config::Config::spell_sets_path(self.config.character.as_deref())
+            .and_then(|path| self.spell_sets.save(&path));
+        if let Err(e) = result {
+            self.add_system_message(&format!("[rebuff] failed to save spell sets: {}", e));
+        }
+    }
 
-            // Add the command text (in default color)
-            segments.push(TextSegment {
-                text: command.clone(),
-                fg: Some("#ffffff".to_string()), // White text for command
-                bg: None,
-                bold: false,
-                mono: false,
-                span_type: SpanType::Normal,
-                link_data: None,
-            });
+    /// Compare set `name` with the active spells; list what dropped and
+    /// the commands that recast it, and queue them when `cast` is set.
+    fn plan_rebuff(&mut self, name: &str, cast: bool) {
+        use crate::core::rebuff;
+        let Some(set) = self.spell_sets.get(name) else {
+            self.add_system_message(&format!(
+                "[rebuff] no spell set named '{}' (.rebuff list)",
+                name
+            ));
+            return;
+        };
+        let active = self
+            .game_state
+            .effects
+            .get("ActiveSpells")
+            .map(|content| content.effects.as_slice())
+            .unwrap_or_default();
+        let down = rebuff::missing(set, active);
+        let name = name.to_ascii_lowercase();
+        if down.is_empty() {
+            let message = format!("[rebuff] {}: all {} spells active", name, set.len());
+            self.add_system_message(&message);
+            return;
+        }
+        let listed = down
+            .iter()
+            .map(|spell| match spell.number {
+                Some(number) => format!("{} ({})", spell.name, number),
+                None => spell.name.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let header = format!(
+            "[rebuff] {}: {} of {} down - {}",
+            name,
+            down.len(),
+            set.len(),
+            listed
+        );
+        let commands = rebuff::commands(&down, &self.config.rebuff.commands);
+        let count = down.len();
+        self.add_system_message(&header);
+        if commands.is_empty() {
+            self.add_system_message("[rebuff] no [rebuff] commands configured - nothing to send");
+        } else if cast {
+            self.add_system_message(&format!(
+                "[rebuff] casting {} spells ({} commands) - .rebuff stop to cancel",
+                count,
+                commands.len()
+            ));
+            self.rebuff.start(&name, commands);
+            self.tick_rebuff();
+        } else {
+            self.add_system_message(&format!("[rebuff] would send: {}", commands.join("; ")));
+            self.add_system_message(&format!("[rebuff] .rebuff {} cast to send them", name));
+        }
+    }
 
-            let styled_line = StyledLine {
-                segments,
-                stream: String::from("main"),
-                timestamp: None,
+    /// `.set [-g] <name> <value>`: store a variable (character scope unless
+    /// -g), or show one / list all when the value / name is left off.
+    fn handle_set_variable(&mut self, args: &str) {
+        let (global, args) = match args.strip_prefix("-g") {
+            Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+                (true, rest.trim_start())
+            }
+            _ => (false, args),
+        };
+        let (name, value) = match args.split_once(char::is_whitespace) {
+            Some((name, value)) => (name, value.trim()),
+            None => (args, ""),
+        };
+        if name.is_empty() {
+            self.list_variables();
+            return;
+        }
+        if !crate::config::VariableStore::is_valid_name(name) {
+            self.add_system_message("Variable names use letters, digits, and _ only");
+            return;
+        }
+        if value.is_empty() {
+            let message = match self.config.variables.get(name) {
+                Some(value) => format!("%{}% = {}", name.to_ascii_lowercase(), value),
+                None => format!("%{}% is not set", name.to_ascii_lowercase()),
             };
+            self.add_system_message(&message);
+            return;
+        }
+        self.config.variables.set(name, value, global);
+        let scope = if global { "global" } else { "character" };
+        match self
+            .config
+            .variables
+            .save(global, self.config.character.as_deref())
+        {
+            Ok(()) => self.add_system_message(&format!(
+                "Set %{}% = {} ({})",
+                name.to_ascii_lowercase(),
+                value,
+                scope
+            )),
+            Err(e) => self.add_system_message(&format!("Set for this session only: {e:#}")),
+        }
+    }
 
-            // Echo bypasses the message pipeline, so mirror it to remote
-            // clients explicitly (they see the same echo as local windows)
-            if let Some(remote) = self.message_processor.remote.as_mut() {
-                remote.push_text("main", std::sync::Arc::new(styled_line.clone()));
+    /// `.unset [-g] <name>`
+    fn handle_unset_variable(&mut self, args: &[&str]) {
+        let (global, name) = match args {
+            ["-g", name] => (true, *name),
+            [name] => (false, *name),
+            _ => {
+                self.add_system_message("Usage: .unset [-g] <name>");
+                return;
             }
+        };
+        if !self.config.variables.unset(name, global) {
+            let scope = if global { "global" } else { "character" };
+            self.add_system_message(&format!("No {} variable '{}'", scope, name));
+            return;
+        }
+        if let Err(e) = self
+            .config
+            .variables
+            .save(global, self.config.character.as_deref())
+        {
+            self.add_system_message(&format!("Removed for this session only: {e:#}"));
+            return;
+        }
+        self.add_system_message(&format!("Unset %{}%", name.to_ascii_lowercase()));
+    }
 
-            // Add the styled line to each subscriber window
-            for window_name in subscribers {
-                if let Some(window) = self.ui_state.windows.get_mut(&window_name) {
-                    match &mut window.content {
-                        WindowContent::Text(ref mut content) => {
-                            content.add_line(styled_line.clone());
-                            tracing::info!(
-                                "[SEND_COMMAND] Added command echo to text window '{}'",
-                                window_name
-                            );
-                        }
-                        WindowContent::TabbedText(ref mut tabbed_content) => {
-                            // Find tab(s) subscribed to "main" stream and add the line
-                            for tab in tabbed_content.tabs.iter_mut() {
-                                if tab
-                                    .definition
-                                    .streams
-                                    .iter()
-                                    .any(|s| s.eq_ignore_ascii_case("main"))
-                                {
-                                    tab.content.add_line(styled_line.clone());
-                                    tracing::info!(
-                                        "[SEND_COMMAND] Added command echo to tabbed window '{}' tab '{}'",
-                                        window_name,
-                                        tab.definition.name
-                                    );
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
+    /// `.vars`: every variable, marking where character values shadow global
+    fn list_variables(&mut self) {
+        let vars = &self.config.variables;
+        if vars.global.is_empty() && vars.character.is_empty() {
+            self.add_system_message("No variables set (.set <name> <value>)");
+            return;
+        }
+        let mut lines = vec!["=== Variables ===".to_string()];
+        for (name, value) in &vars.character {
+            lines.push(format!("  %{}% = {}", name, value));
+        }
+        for (name, value) in &vars.global {
+            let shadowed = if vars.character.contains_key(name) {
+                " (shadowed)"
+            } else {
+                ""
+            };
+            lines.push(format!("  %{}% = {} [global]{}", name, value, shadowed));
+        }
+        for line in lines {
+            self.add_system_message(&line);
+        }
+    }
+
+    /// `.netinfo`: which address the latest connection used, and why any
+    /// others failed.
+    fn show_netinfo(&mut self) {
+        let Some(info) = crate::network::dial::last() else {
+            self.add_system_message("No connection attempted yet");
+            return;
+        };
+        let mut lines = Vec::new();
+        let when = info.at.format("%H:%M:%S");
+        match info.addr {
+            Some(addr) => {
+                let family = if addr.is_ipv6() { "IPv6" } else { "IPv4" };
+                lines.push(format!(
+                    "Connected to {}:{} at {}",
+                    info.host, info.port, when
+                ));
+                match &info.proxy {
+                    Some(proxy) => lines.push(format!("  Via proxy:  {} ({})", proxy, addr)),
+                    None => lines.push(format!("  Address:    {} ({})", addr, family)),
                 }
             }
+            None => lines.push(format!(
+                "Could not connect to {}:{} at {}",
+                info.host, info.port, when
+            )),
+        }
+        if info.proxy.is_none() {
+            let v6 = info.resolved.iter().filter(|a| a.is_ipv6()).count();
+            lines.push(format!(
+                "  Resolved:   {} address(es) ({} IPv6, {} IPv4)",
+                info.resolved.len(),
+                v6,
+                info.resolved.len() - v6
+            ));
+        }
+        for (addr, error) in &info.failures {
+            lines.push(format!("  Failed:     {} - {}", addr, error));
+        }
+        if info.addr.is_some() {
+            lines.push(format!(
+                "  TLS:        {}",
+                if info.tls { "on" } else { "off" }
+            ));
+            if let Some((wire, inflated)) = crate::network::compression::totals() {
+                lines.push(format!(
+                    "  Compressed: {} KB -> {} KB",
+                    wire / 1024,
+                    inflated / 1024
+                ));
+            }
+        }
+        for line in lines {
+            self.add_system_message(&line);
         }
-
-        // Command history is now managed by the CommandInput widget
-
-        // Return command for network layer to send (network layer adds newline)
-        Ok(command)
     }
 
     /// `.webinfo`: the phone-onboarding pairing URL and QR code.
@@ -497,10 +2097,12 @@ impl AppCore {
         match cmd.as_str() {
             // Application commands
             "quit" | "q" => {
-                self.quit();
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.request_quit(args);
             }
             "help" | "h" | "?" => {
-                self.show_help();
+                let topic = command[1..].trim_start()[parts[0].len()..].trim();
+                return Ok(format!("action:help:{}", topic));
             }
             "version" | "ver" => {
                 self.show_version();
@@ -555,7 +2157,10 @@ impl AppCore {
                 self.show_webinfo();
             }
 
-            // Layout commands. The TUI intercepts both in
+            // Address the latest connection used (IPv6/IPv4 fallback)
+            "netinfo" => self.show_netinfo(),
+
+            // Layout commands. The TUI intercepts these in
             // handle_command_submission with the real terminal size, so these
             // fallbacks only run in frontends without a cell grid (GUI,
             // headless), where TOML cell layouts don't apply.
@@ -575,9 +2180,30 @@ impl AppCore {
                     "TOML layouts are a TUI feature. The GUI manages its own window layout and saves it automatically.",
                 );
             }
-            "layouts" => {
-                self.list_layouts();
-            }
+            "layout" => match (
+                parts.get(1).map(|s| s.to_lowercase()).as_deref(),
+                parts.get(2).map(|s| s.to_lowercase()).as_deref(),
+            ) {
+                (Some("undo"), None) => self.undo_layout_edit(),
+                (Some("redo"), None) => self.redo_layout_edit(),
+                (Some("keys"), _) => self.set_layout_keybind_profile(parts.get(2).copied()),
+                (Some("scale"), Some("cancel")) => self.cancel_layout_scale(),
+                (Some("scale"), None | Some("apply")) => self.add_system_message(
+                    "Layout scaling works on TOML cell layouts, which are a TUI feature.",
+                ),
+                _ => self.add_system_message(
+                    "Usage: .layout undo|redo|scale [apply|cancel]|keys [profile|off]",
+                ),
+            },
+            "layouts" => match parts.get(1).map(|s| s.to_lowercase()).as_deref() {
+                None => self.list_layouts(),
+                Some("browse") => return Ok("action:layoutgallery".to_string()),
+                Some("get") => match parts.get(2) {
+                    Some(url) => self.download_layout(url),
+                    None => self.add_system_message("Usage: .layouts get <url>"),
+                },
+                Some(_) => self.add_system_message("Usage: .layouts [browse|get <url>]"),
+            },
             "resize" => {
                 self.resize_to_current_terminal();
             }
@@ -867,6 +2493,42 @@ impl AppCore {
                 }
             }
 
+            "dupwindow" | "dupwin" => match parts.get(1) {
+                Some(name) => self.duplicate_window(name),
+                None => self.add_system_message("Usage: .dupwindow <name>"),
+            },
+            "copywindow" | "copywin" => match parts.get(1) {
+                Some(name) => self.copy_window(name),
+                None => self.add_system_message("Usage: .copywindow <name>"),
+            },
+            "pastewindow" | "pastewin" => self.paste_windows(),
+            // Runtime windows for scripts and aliases: .window create/close
+            "window" => match parts.get(1).map(|sub| sub.to_ascii_lowercase()).as_deref() {
+                Some("create") => {
+                    match crate::core::window_spec::WindowSpec::parse(&parts[2..]) {
+                        Ok(spec) => self.create_window(&spec),
+                        Err(e) => self.add_system_message(&e),
+                    }
+                }
+                Some("close") => match parts.get(2) {
+                    Some(name) => self.close_window(name),
+                    None => self.add_system_message("Usage: .window close <name>"),
+                },
+                _ => {
+                    self.add_system_message(crate::core::window_spec::USAGE);
+                    self.add_system_message("       .window close <name>");
+                }
+            },
+            "clear" => match parts.get(1) {
+                Some(name) => self.clear_window(name),
+                None => self.add_system_message("Usage: .clear <window>"),
+            },
+            "freeze" => self.freeze_command(parts.get(1).copied(), parts.get(2).copied()),
+            "unfreeze" => match parts.get(1) {
+                Some(name) => self.freeze_command(Some(name), Some("off")),
+                None => self.add_system_message("Usage: .unfreeze <window>"),
+            },
+
             // Reload config from disk
             "reload" => {
                 tracing::debug!("handle_dot_command: reload args {:?}", parts.get(1));
@@ -916,6 +2578,209 @@ impl AppCore {
                 self.needs_render = true;
             }
 
+            // Floating windows
+            "float" => {
+                let Some(name) = parts.get(1) else {
+                    self.add_system_message("Usage: .float <window> [on|off]");
+                    return Ok(String::new());
+                };
+                let floating = match parts.get(2).map(|s| s.to_lowercase()).as_deref() {
+                    None => None,
+                    Some("on") | Some("true") => Some(true),
+                    Some("off") | Some("false") => Some(false),
+                    Some(other) => {
+                        self.add_system_message(&format!("Invalid value '{}' (use on/off)", other));
+                        return Ok(String::new());
+                    }
+                };
+                self.set_window_floating(name, floating);
+            }
+            "raise" => {
+                if let Some(name) = parts.get(1) {
+                    self.raise_window(name);
+                } else {
+                    self.add_system_message("Usage: .raise <window>");
+                }
+            }
+
+            // Compact mode (single tabbed view for small screens)
+            "compact" => match parts.get(1).map(|s| s.to_lowercase()).as_deref() {
+                None => self.set_compact_mode(None),
+                Some("on") | Some("true") => self.set_compact_mode(Some(true)),
+                Some("off") | Some("false") => self.set_compact_mode(Some(false)),
+                Some("next") => self.cycle_compact_tab(1),
+                Some("prev") => self.cycle_compact_tab(-1),
+                Some(_) => {
+                    let name = parts[1];
+                    if !self.select_compact_tab(name) {
+                        self.add_system_message(&format!(
+                            "'{}' is not a visible text window (tabs: {})",
+                            name,
+                            self.compact_tabs().join(", ")
+                        ));
+                    } else if !self.ui_state.compact_mode {
+                        self.set_compact_mode(Some(true));
+                    }
+                }
+            },
+
+            // Popup viewer for long output
+            "view" => {
+                let source = parts.get(1).copied().unwrap_or("last");
+                if self.viewer_content(source).is_some() {
+                    return Ok(format!("action:view:{}", source));
+                } else if source.eq_ignore_ascii_case("last") {
+                    self.add_system_message("Nothing to view yet");
+                } else {
+                    self.add_system_message(&format!("No text to view in '{}'", source));
+                }
+            }
+
+            // Window scrollback to an ANSI or HTML file
+            "export" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                match args.split_once(char::is_whitespace) {
+                    Some((window, file)) => self.export_window(window, file.trim()),
+                    None => self.add_system_message("Usage: .export <window|last> <file>"),
+                }
+            }
+
+            // Colors and highlights from other clients' settings files
+            "import" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.import_command(args);
+            }
+
+            // Plain-text copy of a window's lines to a file, as they arrive
+            "tee" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.tee_command(args);
+            }
+
+            // Recent URLs from game text, and opening them
+            "url" | "urls" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.url_command(args);
+            }
+
+            // Popup viewer over a session log (or the diagnostics log)
+            "viewlog" => {
+                let date = parts.get(1).copied();
+                match crate::core::log_viewer::find_log(&self.config, date) {
+                    Ok(_) => return Ok(format!("action:viewlog:{}", date.unwrap_or_default())),
+                    Err(e) => self.add_system_message(&e.to_string()),
+                }
+            }
+
+            // Sent-command palette, optionally pre-filtered
+            "cmdlog" => {
+                let query = command[1..].trim_start()[parts[0].len()..].trim();
+                if self.command_log.is_empty() {
+                    self.add_system_message("No commands sent yet");
+                } else {
+                    return Ok(format!("action:cmdlog:{}", query));
+                }
+            }
+
+            // Linearized screen read-out (screen-reader mode)
+            "readscreen" => self.read_screen(),
+
+            // Variables (%name% expansion in commands)
+            "set" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.handle_set_variable(args);
+            }
+            "unset" => self.handle_unset_variable(&parts[1..]),
+            "if" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                return self.handle_conditional_send(args);
+            }
+            "vars" => self.list_variables(),
+
+            // Stance widget stepping (also bound from keybind macros)
+            "stance" => return Ok(self.stance_command(parts.get(1).copied())),
+
+            // Silver tracker: show / correct / reset session
+            "silver" => self.silver_command(parts.get(1).copied()),
+
+            // Loot filter session tally
+            "loot" => self.loot_command(parts.get(1).copied()),
+
+            // Combat analyzer session totals
+            "combat" => self.combat_command(parts.get(1).copied()),
+
+            // Low-health alarm status / test alert
+            "alarm" => self.alarm_command(parts.get(1).copied()),
+
+            // Spell sets: record, compare against active spells, recast
+            "rebuff" => self.rebuff_command(&parts[1..]),
+
+            // Reminders and recurring schedules
+            "remind" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.remind_command(args);
+            }
+
+            // Parser event inspector
+            "inspect" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                let action = self.inspect_command(args);
+                if !action.is_empty() {
+                    return Ok(action);
+                }
+            }
+
+            // Raw traffic console
+            "traffic" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.traffic_command(args);
+            }
+
+            // Watch widget rows
+            "watch" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.watch_command(args);
+            }
+
+            // Played time summary
+            "played" => self.played_command(),
+
+            // Away mode: whisper auto-reply and missed-whisper log
+            "away" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.away_command(args);
+            }
+
+            // Confirm a dragged item move between containers
+            "move" => self.move_command(parts.get(1).copied().unwrap_or("")),
+
+            // Re-send a command on an interval
+            "repeat" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.repeat_command(args);
+            }
+
+            // Saved routes: walk, pause/resume/stop, save/delete/list
+            "go" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                self.go_command(args);
+            }
+
+            // Per-character notepad: open the editor, or append a line
+            "notes" | "note" => {
+                let args = command[1..].trim_start()[parts[0].len()..].trim();
+                return Ok(self.notes_command(args));
+            }
+
+            // Answer the last whisper / thought
+            "r" | "reply" | "rt" => {
+                let text = command[1..].trim_start()[parts[0].len()..].trim();
+                return self.reply_command(cmd == "rt", text);
+            }
+
+            // Text-to-speech queue control
+            "tts" => self.tts_command(parts.get(1).copied(), parts.get(2).copied()),
+
             // Container discovery mode
             "containers" => {
                 self.ui_state.container_discovery_mode = !self.ui_state.container_discovery_mode;
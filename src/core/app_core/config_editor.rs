@@ -27,7 +27,7 @@ impl AppCore {
     /// Parse into the real config type so a bad save can't wedge startup.
     fn validate_config_content(file: &str, content: &str) -> Result<(), String> {
         let parse_error = match file {
-            "highlights" | "highlights-global" => toml::from_str::<
+            "highlights" | "highlights-global" => crate::config::schema::parse::<
                 std::collections::HashMap<String, crate::config::HighlightPattern>,
             >(content)
             .err()
@@ -84,7 +84,8 @@ impl AppCore {
         }
         let content =
             std::fs::read_to_string(path).map_err(|e| format!("Read failed: {e}"))?;
-        toml::from_str(&content).map_err(|e| format!("Existing file is invalid TOML: {e}"))
+        crate::config::schema::parse(&content)
+            .map_err(|e| format!("Existing file is invalid TOML: {e}"))
     }
 
     fn save_highlights_map(
@@ -96,7 +97,8 @@ impl AppCore {
         }
         let content =
             toml::to_string_pretty(map).map_err(|e| format!("Serialize failed: {e}"))?;
-        std::fs::write(path, content).map_err(|e| format!("Write failed: {e}"))
+        std::fs::write(path, crate::config::schema::stamp(&content))
+            .map_err(|e| format!("Write failed: {e}"))
     }
 
     /// Sound files available for highlight rules (the form's dropdown).
@@ -267,7 +269,8 @@ impl AppCore {
             }
             let content =
                 toml::to_string_pretty(&config).map_err(|e| format!("Serialize failed: {e}"))?;
-            std::fs::write(&path, content).map_err(|e| format!("Write failed: {e}"))
+            std::fs::write(&path, crate::config::schema::stamp(&content))
+                .map_err(|e| format!("Write failed: {e}"))
         })();
         let (saved, error) = match result {
             Ok(()) => (true, None),
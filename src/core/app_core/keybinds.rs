@@ -6,6 +6,7 @@ use crate::config::{
     BorderSides, Config, HotbarsConfig, KeyAction, KeyBindAction, MacroAction,
     PerformanceWidgetData, WindowBase, WindowDef,
 };
+use crate::core::key_layout::KeyboardLayout;
 use crate::data::input::KeyEvent;
 
 use super::AppCore;
@@ -26,8 +27,17 @@ impl AppCore {
     /// Converts string-based keybinds (e.g., "num_0", "Ctrl+s") to KeyEvent structs
     pub(super) fn build_keybind_map(config: &Config) -> HashMap<KeyEvent, KeyBindAction> {
         let mut map = HashMap::new();
+        Self::insert_keybinds(&mut map, &config.keybinds);
+        tracing::debug!("Built keybind map with {} entries", map.len());
+        map
+    }
 
-        for (key_string, action) in &config.keybinds {
+    /// Parse string-keyed bindings into `map`, replacing existing entries
+    pub(super) fn insert_keybinds(
+        map: &mut HashMap<KeyEvent, KeyBindAction>,
+        keybinds: &HashMap<String, KeyBindAction>,
+    ) {
+        for (key_string, action) in keybinds {
             // Parse the key string into a (KeyCode, KeyModifiers) tuple
             if let Some((code, modifiers)) = crate::config::parse_key_string(key_string) {
                 // Create a KeyEvent from the parsed code and modifiers
@@ -37,9 +47,15 @@ impl AppCore {
                 tracing::warn!("Failed to parse keybind string: '{}'", key_string);
             }
         }
+    }
 
-        tracing::debug!("Built keybind map with {} entries", map.len());
-        map
+    /// Binding for a key: the exact key first, then the QWERTY key at the
+    /// same physical position on `ui.keyboard_layout`.
+    pub fn lookup_keybind(&self, key_event: KeyEvent) -> Option<&KeyBindAction> {
+        self.keybind_map.get(&key_event).or_else(|| {
+            let layout = KeyboardLayout::from_name(&self.config.ui.keyboard_layout);
+            self.keybind_map.get(&layout.normalize(key_event)?)
+        })
     }
 
     /// Merge hotbar button hotkeys into the runtime keybind map as Macro
@@ -102,10 +118,68 @@ impl AppCore {
     /// Re-merges hotbar hotkeys and refreshes the conflict list.
     pub fn rebuild_keybind_map(&mut self) {
         let mut map = Self::build_keybind_map(&self.config);
+        // The layout's keybind profile overlays the normal bindings; on a
+        // shared key the overlay wins
+        if let Some((_, overlay)) = &self.keybind_overlay {
+            Self::insert_keybinds(&mut map, overlay);
+        }
         self.hotbar_key_conflicts = Self::merge_hotbar_hotkeys(&mut map, &self.config.hotbars);
         self.keybind_map = map;
     }
 
+    /// Switch the keybind overlay to the current layout's
+    /// `keybind_profile`, dropping the previous layout's, and rebuild the
+    /// map. Called whenever a layout is loaded.
+    pub(super) fn sync_keybind_profile(&mut self) {
+        let previous = self.keybind_overlay.take().map(|(name, _)| name);
+        let message = match self.layout.keybind_profile.clone() {
+            Some(name) => match Config::load_keybinds_from(&name) {
+                Ok(bindings) => {
+                    let message = (previous.as_ref() != Some(&name)).then(|| {
+                        format!(
+                            "Keybind profile '{}' active ({} bindings)",
+                            name,
+                            bindings.len()
+                        )
+                    });
+                    self.keybind_overlay = Some((name, bindings));
+                    message
+                }
+                Err(e) => Some(format!("Layout keybind profile not loaded: {}", e)),
+            },
+            None => previous.map(|name| format!("Keybind profile '{}' off", name)),
+        };
+        self.rebuild_keybind_map();
+        if let Some(message) = message {
+            self.add_system_message(&message);
+        }
+    }
+
+    /// `.layout keys [profile|off]`: show or set the current layout's
+    /// keybind profile. The choice is part of the layout, so it is saved
+    /// with it.
+    pub(super) fn set_layout_keybind_profile(&mut self, profile: Option<&str>) {
+        let Some(profile) = profile else {
+            let available = match Config::list_saved_keybinds() {
+                Ok(names) if !names.is_empty() => names.join(", "),
+                _ => "none - save one with .savekeybinds <name>".to_string(),
+            };
+            self.add_system_message(&format!(
+                "Layout keybind profile: {} (available: {})",
+                self.layout.keybind_profile.as_deref().unwrap_or("none"),
+                available
+            ));
+            return;
+        };
+        let profile = (!profile.eq_ignore_ascii_case("off")).then(|| profile.to_string());
+        if profile == self.layout.keybind_profile {
+            return;
+        }
+        self.layout.keybind_profile = profile;
+        self.mark_layout_modified();
+        self.sync_keybind_profile();
+    }
+
     /// Execute a keybind action (called when a bound key is pressed)
     /// Returns a list of commands to send to the server (for macros)
     pub fn execute_keybind_action(&mut self, action: &KeyBindAction) -> Result<Vec<String>> {
@@ -136,6 +210,48 @@ impl AppCore {
                 tracing::info!("[MACRO] send_command returned: '{}'", command);
                 Ok(vec![command]) // Return command for network layer to send
             }
+            KeyBindAction::Sequence(sequence) => {
+                // A second press of the same key cancels it
+                if self.sequence.is_playing(&sequence.sequence) {
+                    let left = self.sequence.remaining();
+                    self.sequence.stop();
+                    self.add_system_message(&format!(
+                        "[sequence] stopped ({} step{} not sent)",
+                        left,
+                        if left == 1 { "" } else { "s" }
+                    ));
+                    return Ok(vec![]);
+                }
+                tracing::info!("[SEQUENCE] Starting: '{}'", sequence.to_text());
+                self.sequence
+                    .start(&sequence.sequence, std::time::Instant::now());
+                // Steps without a pause go out with the next outbound drain
+                self.tick_sequence();
+                Ok(vec![])
+            }
+            KeyBindAction::Call(call) => {
+                use crate::core::action_registry::{self, InternalCall};
+                match action_registry::resolve(call) {
+                    Ok(InternalCall::FocusWindow(name)) => {
+                        if self.ui_state.windows.contains_key(&name) {
+                            self.ui_state.set_focus(Some(name));
+                            self.needs_render = true;
+                        } else {
+                            self.add_system_message(&format!(
+                                "[keybind] no window named '{}'",
+                                name
+                            ));
+                        }
+                        Ok(vec![])
+                    }
+                    // UI actions the frontend handles, like dot commands do
+                    Ok(internal) => Ok(internal.action_string().into_iter().collect()),
+                    Err(e) => {
+                        self.add_system_message(&format!("[keybind] {}: {}", call.to_text(), e));
+                        Ok(vec![])
+                    }
+                }
+            }
         }
     }
 
@@ -205,6 +321,21 @@ impl AppCore {
             KeyAction::StartSearch => {
                 tracing::debug!("StartSearch handled in frontend layer");
             }
+            KeyAction::OpenCompose => {
+                tracing::debug!("OpenCompose handled in frontend layer");
+            }
+            KeyAction::SpellSuggest => {
+                tracing::debug!("SpellSuggest handled in frontend layer");
+            }
+            KeyAction::CommandLog => {
+                tracing::debug!("CommandLog handled in frontend layer");
+            }
+            KeyAction::ReplyWhisper => {
+                tracing::debug!("ReplyWhisper handled in frontend layer");
+            }
+            KeyAction::ListUrls => {
+                self.list_urls();
+            }
             KeyAction::NextSearchMatch => {
                 tracing::debug!("NextSearchMatch handled in frontend layer");
             }
@@ -242,6 +373,20 @@ impl AppCore {
                 self.add_system_message(&format!("Sound system {}", status));
                 tracing::info!("Sound system toggled: {}", status);
             }
+            KeyAction::ToggleFloatingWindows => {
+                self.toggle_floating_windows();
+            }
+            KeyAction::ToggleBossKey => {
+                self.toggle_boss_mode();
+            }
+
+            // Layout editing
+            KeyAction::LayoutUndo => {
+                self.undo_layout_edit();
+            }
+            KeyAction::LayoutRedo => {
+                self.redo_layout_edit();
+            }
 
             // Travel
             KeyAction::StopTravel => {
@@ -269,6 +414,16 @@ impl AppCore {
                     tracing::warn!("TTS stop failed: {}", e);
                 }
             }
+            KeyAction::TtsSkip => {
+                if let Err(e) = self.tts_manager.skip() {
+                    tracing::warn!("TTS skip failed: {}", e);
+                }
+            }
+            KeyAction::TtsFlush => {
+                if let Err(e) = self.tts_manager.flush() {
+                    tracing::warn!("TTS flush failed: {}", e);
+                }
+            }
             KeyAction::TtsMuteToggle => {
                 self.tts_manager.toggle_mute();
                 let status = if self.tts_manager.is_muted() { "muted" } else { "unmuted" };
@@ -302,6 +457,7 @@ impl AppCore {
                     self.add_system_message("TTS volume decreased");
                 }
             }
+            KeyAction::ReadScreen => self.read_screen(),
 
             // Macro actions (should not reach here - handled by execute_keybind_action)
             KeyAction::SendMacro(text) => {
@@ -403,6 +559,10 @@ impl AppCore {
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
             }
         };
 
@@ -417,8 +577,11 @@ impl AppCore {
 #[cfg(test)]
 mod tests {
     use super::AppCore;
-    use crate::config::{Config, HotbarButton, HotbarDef, HotbarsConfig, KeyBindAction};
+    use crate::config::{
+        Config, HotbarButton, HotbarDef, HotbarsConfig, KeyBindAction, MacroAction,
+    };
     use crate::data::input::{KeyCode, KeyEvent, KeyModifiers};
+    use std::collections::HashMap;
 
     #[test]
     fn build_keybind_map_parses_valid_entries() {
@@ -446,6 +609,30 @@ mod tests {
         assert!(map.contains_key(&alt_x), "Expected alt+x entry");
     }
 
+    #[test]
+    fn keybind_profile_overlay_wins() {
+        let mut config = Config::default();
+        config.keybinds.insert(
+            "f1".to_string(),
+            KeyBindAction::Action("start_search".to_string()),
+        );
+        let mut profile = HashMap::new();
+        profile.insert(
+            "f1".to_string(),
+            KeyBindAction::Macro(MacroAction {
+                macro_text: "attack\r".to_string(),
+            }),
+        );
+        let mut map = AppCore::build_keybind_map(&config);
+        AppCore::insert_keybinds(&mut map, &profile);
+
+        let (code, modifiers) = crate::config::parse_key_string("f1").unwrap();
+        assert!(matches!(
+            map.get(&KeyEvent { code, modifiers }),
+            Some(KeyBindAction::Macro(m)) if m.macro_text == "attack\r"
+        ));
+    }
+
     #[test]
     fn build_keybind_map_skips_invalid_keys() {
         let mut config = Config::default();
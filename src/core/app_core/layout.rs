@@ -5,6 +5,10 @@ use crate::data::{WindowContent, WindowPosition};
 
 use super::AppCore;
 
+/// Largest `.layout scale` preview, in cells
+const LAYOUT_SCALE_PREVIEW_WIDTH: u16 = 60;
+const LAYOUT_SCALE_PREVIEW_HEIGHT: u16 = 20;
+
 impl AppCore {
     /// Apply a layout-provided theme, returning the applied theme (if changed)
     pub(super) fn apply_layout_theme(
@@ -70,93 +74,223 @@ impl AppCore {
 
         match Layout::load_from_file(&layout_path) {
             Ok(new_layout) => {
-                let theme_update = self.apply_layout_theme(new_layout.theme.as_deref());
-                tracing::info!("Layout file loaded successfully");
-                tracing::info!("Loaded layout has {} windows", new_layout.windows.len());
-                tracing::info!(
-                    "Loaded layout terminal size: {}x{}",
-                    new_layout.terminal_width.unwrap_or(0),
-                    new_layout.terminal_height.unwrap_or(0)
+                self.install_layout(name, new_layout, terminal_width, terminal_height)
+            }
+            Err(e) => {
+                tracing::error!("Failed to load layout file '{}': {}", name, e);
+                tracing::info!("========== LOAD LAYOUT: '{}' FAILED ==========", name);
+                self.add_system_message(&format!("Failed to load layout: {}", e));
+                None
+            }
+        }
+    }
+
+    /// Make `new_layout` (loaded as `name`) the current layout: apply its
+    /// theme, refuse it if the terminal is too small, then sync windows.
+    /// Returns the applied theme for the frontend's cache.
+    fn install_layout(
+        &mut self,
+        name: &str,
+        new_layout: Layout,
+        terminal_width: u16,
+        terminal_height: u16,
+    ) -> Option<(String, crate::theme::AppTheme)> {
+        let theme_update = self.apply_layout_theme(new_layout.theme.as_deref());
+        tracing::info!("Layout file loaded successfully");
+        tracing::info!("Loaded layout has {} windows", new_layout.windows.len());
+        tracing::info!(
+            "Loaded layout terminal size: {}x{}",
+            new_layout.terminal_width.unwrap_or(0),
+            new_layout.terminal_height.unwrap_or(0)
+        );
+
+        // Log all windows in the loaded layout
+        for (idx, window_def) in new_layout.windows.iter().enumerate() {
+            let base = window_def.base();
+            tracing::info!(
+                "  [{}] Window '{}' ({}): pos=({},{}) size={}x{}",
+                idx,
+                window_def.name(),
+                window_def.widget_type(),
+                base.col,
+                base.row,
+                base.cols,
+                base.rows
+            );
+        }
+
+        // Check if terminal is too small for any window
+        let mut terminal_too_small = false;
+        for window_def in &new_layout.windows {
+            let base = window_def.base();
+            let required_width = base.col.saturating_add(base.cols);
+            let required_height = base.row.saturating_add(base.rows);
+            if terminal_width < required_width || terminal_height < required_height {
+                terminal_too_small = true;
+                tracing::error!(
+                    "Window '{}' ({}) requires {}x{} at position ({},{}), but terminal is {}x{}",
+                    window_def.name(),
+                    window_def.widget_type(),
+                    required_width,
+                    required_height,
+                    base.col,
+                    base.row,
+                    terminal_width,
+                    terminal_height
                 );
+            }
+        }
 
-                // Log all windows in the loaded layout
-                for (idx, window_def) in new_layout.windows.iter().enumerate() {
-                    let base = window_def.base();
-                    tracing::info!(
-                        "  [{}] Window '{}' ({}): pos=({},{}) size={}x{}",
-                        idx,
-                        window_def.name(),
-                        window_def.widget_type(),
-                        base.col,
-                        base.row,
-                        base.cols,
-                        base.rows
-                    );
-                }
+        if terminal_too_small {
+            tracing::error!("Terminal too small to load layout '{}'", name);
+            self.add_system_message(&format!(
+                "Cannot load layout '{}': terminal too small",
+                name
+            ));
+            self.add_system_message("Increase terminal size or use a different layout");
+            return None;
+        }
 
-                // Check if terminal is too small for any window
-                let mut terminal_too_small = false;
-                for window_def in &new_layout.windows {
-                    let base = window_def.base();
-                    let required_width = base.col.saturating_add(base.cols);
-                    let required_height = base.row.saturating_add(base.rows);
-                    if terminal_width < required_width || terminal_height < required_height {
-                        terminal_too_small = true;
-                        tracing::error!(
-                            "Window '{}' ({}) requires {}x{} at position ({},{}), but terminal is {}x{}",
-                            window_def.name(),
-                            window_def.widget_type(),
-                            required_width,
-                            required_height,
-                            base.col,
-                            base.row,
-                            terminal_width,
-                            terminal_height
-                        );
-                    }
-                }
+        // Store new layout
+        let old_layout = std::mem::replace(&mut self.layout, new_layout.clone());
+        self.layout_history.reset(new_layout.clone());
+        self.baseline_layout = Some(new_layout);
+        self.sync_keybind_profile();
 
-                if terminal_too_small {
-                    tracing::error!("Terminal too small to load layout '{}'", name);
-                    self.add_system_message(&format!(
-                        "Cannot load layout '{}': terminal too small",
-                        name
-                    ));
-                    self.add_system_message("Increase terminal size or use a different layout");
-                    return None;
-                }
+        tracing::info!("Calling sync_layout_to_ui_state to apply changes...");
 
-                // Store new layout
-                let old_layout = std::mem::replace(&mut self.layout, new_layout.clone());
-                self.baseline_layout = Some(new_layout);
+        // Update positions for existing windows, create new ones, remove old ones
+        self.sync_layout_to_ui_state(terminal_width, terminal_height, &old_layout);
 
-                tracing::info!("Calling sync_layout_to_ui_state to apply changes...");
+        tracing::info!(
+            "After sync: UI state now has {} windows",
+            self.ui_state.windows.len()
+        );
+        tracing::info!("========== LOAD LAYOUT: '{}' SUCCESS ==========", name);
 
-                // Update positions for existing windows, create new ones, remove old ones
-                self.sync_layout_to_ui_state(terminal_width, terminal_height, &old_layout);
+        self.add_system_message(&format!("Layout '{}' loaded", name));
 
-                tracing::info!(
-                    "After sync: UI state now has {} windows",
-                    self.ui_state.windows.len()
-                );
-                tracing::info!("========== LOAD LAYOUT: '{}' SUCCESS ==========", name);
+        // Clear modified flag and update base layout name
+        self.layout_modified_since_save = false;
+        self.base_layout_name = Some(name.to_string());
+        self.needs_render = true;
+        theme_update
+    }
 
-                self.add_system_message(&format!("Layout '{}' loaded", name));
+    /// Apply a layout from the gallery (`.layouts browse`), scaled from the
+    /// size it was designed for to the current terminal first, so layouts
+    /// made on other screens fit.
+    pub fn apply_gallery_layout(
+        &mut self,
+        name: &str,
+        terminal_width: u16,
+        terminal_height: u16,
+    ) -> Option<(String, crate::theme::AppTheme)> {
+        let mut layout =
+            match Config::layout_path(name).and_then(|path| Layout::load_from_file(&path)) {
+                Ok(layout) => layout,
+                Err(e) => {
+                    self.add_system_message(&format!("Failed to load layout: {}", e));
+                    return None;
+                }
+            };
+        let (design_width, design_height) = crate::core::layout_gallery::design_size(&layout);
+        if (design_width, design_height) != (terminal_width, terminal_height) {
+            layout.terminal_width = Some(design_width);
+            layout.terminal_height = Some(design_height);
+            layout.scale_to_terminal_size(terminal_width, terminal_height);
+            self.add_system_message(&format!(
+                "Scaled layout '{}' from {}x{} to {}x{}",
+                name, design_width, design_height, terminal_width, terminal_height
+            ));
+        }
+        self.install_layout(name, layout, terminal_width, terminal_height)
+    }
 
-                // Clear modified flag and update base layout name
-                self.layout_modified_since_save = false;
-                self.base_layout_name = Some(name.to_string());
-                self.needs_render = true;
-                return theme_update;
-            }
-            Err(e) => {
-                tracing::error!("Failed to load layout file '{}': {}", name, e);
-                tracing::info!("========== LOAD LAYOUT: '{}' FAILED ==========", name);
-                self.add_system_message(&format!("Failed to load layout: {}", e));
-            }
+    /// `.layout scale`: rescale the layout proportionally from the size it
+    /// was designed for to the terminal and show a preview. Nothing changes
+    /// until `.layout scale apply`.
+    pub fn preview_layout_scale(&mut self, terminal_width: u16, terminal_height: u16) {
+        self.needs_render = true;
+        let mut layout = self
+            .baseline_layout
+            .as_ref()
+            .unwrap_or(&self.layout)
+            .clone();
+        let (design_width, design_height) = crate::core::layout_gallery::design_size(&layout);
+        if (design_width, design_height) == (terminal_width, terminal_height) {
+            self.pending_layout_scale = None;
+            self.add_system_message(&format!(
+                "Layout is already sized for {}x{} - nothing to scale",
+                terminal_width, terminal_height
+            ));
+            return;
         }
+        layout.terminal_width = Some(design_width);
+        layout.terminal_height = Some(design_height);
+        let constrained = layout.scale_to_terminal_size(terminal_width, terminal_height);
 
-        None
+        self.add_system_message(&format!(
+            "Layout scaled from {}x{} to {}x{} (preview):",
+            design_width, design_height, terminal_width, terminal_height
+        ));
+        let preview_width = terminal_width.min(LAYOUT_SCALE_PREVIEW_WIDTH);
+        let preview_height = ((preview_width as u32 * terminal_height as u32
+            / terminal_width as u32) as u16)
+            .clamp(4, LAYOUT_SCALE_PREVIEW_HEIGHT);
+        for row in
+            crate::core::layout_gallery::render_preview(&layout, preview_width, preview_height)
+        {
+            self.add_system_message(&row);
+        }
+        if !constrained.is_empty() {
+            self.add_system_message(&format!(
+                "Held to their min/max size: {}",
+                constrained.join(", ")
+            ));
+        }
+        self.add_system_message("Use .layout scale apply to use it, or .layout scale cancel");
+        self.pending_layout_scale = Some(layout);
+    }
+
+    /// `.layout scale apply`: switch to the layout previewed by
+    /// `.layout scale`. It isn't saved until `.savelayout`.
+    pub fn apply_layout_scale(
+        &mut self,
+        terminal_width: u16,
+        terminal_height: u16,
+    ) -> Option<(String, crate::theme::AppTheme)> {
+        let Some(layout) = self.pending_layout_scale.take() else {
+            self.add_system_message("Nothing to apply - preview with .layout scale first");
+            return None;
+        };
+        if (layout.terminal_width, layout.terminal_height)
+            != (Some(terminal_width), Some(terminal_height))
+        {
+            self.add_system_message("Terminal resized since the preview - run .layout scale again");
+            return None;
+        }
+        let name = self
+            .base_layout_name
+            .clone()
+            .unwrap_or_else(|| "layout".to_string());
+        let unscaled = self.layout.clone();
+        let theme_update = self.install_layout(&name, layout, terminal_width, terminal_height);
+        // Loading starts a fresh history; keep one step back to the unscaled layout
+        self.layout_history.reset(unscaled);
+        self.record_layout_edit();
+        self.layout_modified_since_save = true;
+        self.add_system_message("Scaled layout applied - use .savelayout <name> to keep it");
+        theme_update
+    }
+
+    /// `.layout scale cancel`: drop the previewed layout
+    pub(super) fn cancel_layout_scale(&mut self) {
+        if self.pending_layout_scale.take().is_some() {
+            self.add_system_message("Layout scale preview discarded");
+        } else {
+            self.add_system_message("No layout scale preview to discard");
+        }
     }
 
     /// Resize all windows proportionally based on current terminal size (VellumFE algorithm)
@@ -287,6 +421,7 @@ impl AppCore {
             }
         }
 
+        self.record_layout_edit();
         self.needs_render = true;
         self.add_system_message(&format!(
             "Resized to {}x{} - use .savelayout to save",
@@ -1097,7 +1232,11 @@ impl AppCore {
             .ui_state
             .windows
             .keys()
-            .filter(|name| !new_window_names.contains(*name))
+            .filter(|name| {
+                // Ephemeral windows were never in any layout
+                !new_window_names.contains(*name)
+                    && !self.ui_state.ephemeral_windows.contains(*name)
+            })
             .cloned()
             .collect();
 
@@ -1115,6 +1254,8 @@ impl AppCore {
             tracing::info!("No windows to remove");
         }
 
+        self.sync_tees();
+
         tracing::info!("--- sync_layout_to_ui_state COMPLETE ---");
     }
 
@@ -1132,8 +1273,10 @@ impl AppCore {
             Ok(new_layout) => {
                 self.apply_layout_theme(new_layout.theme.as_deref());
                 self.layout = new_layout.clone();
+                self.layout_history.reset(new_layout.clone());
                 self.baseline_layout = Some(new_layout);
                 self.add_system_message(&format!("Layout '{}' loaded", name));
+                self.sync_keybind_profile();
 
                 // Clear modified flag and update base layout name
                 self.layout_modified_since_save = false;
@@ -1150,6 +1293,367 @@ impl AppCore {
         }
     }
 
+    /// Start downloading a community layout into the shared layouts
+    /// directory; `poll_map` reports the result
+    pub(super) fn download_layout(&mut self, url: &str) {
+        if self.layout_download.is_some() {
+            self.add_system_message("A layout download is already running");
+            return;
+        }
+        match Config::layouts_dir() {
+            Ok(dir) => {
+                self.layout_download = Some(crate::core::layout_gallery::LayoutDownload::start(
+                    url.to_string(),
+                    dir,
+                ));
+                self.add_system_message(&format!("Downloading layout from {}", url));
+            }
+            Err(e) => self.add_system_message(&format!("Failed to get layouts directory: {}", e)),
+        }
+    }
+
+    /// Copy a window's on-screen geometry (it may have been moved or resized
+    /// with the mouse) back into its layout definition
+    fn capture_window_geometry(&mut self, name: &str) {
+        let Some(position) = self.ui_state.windows.get(name).map(|w| w.position.clone()) else {
+            return;
+        };
+        if let Some(window_def) = self.layout.windows.iter_mut().find(|w| w.name() == name) {
+            let base = window_def.base_mut();
+            base.col = position.x;
+            base.row = position.y;
+            base.cols = position.width;
+            base.rows = position.height;
+        }
+    }
+
+    /// `.dupwindow <name>`: add a copy of a window next to it
+    pub(super) fn duplicate_window(&mut self, name: &str) {
+        self.capture_window_geometry(name);
+        match self.layout.duplicate_window(name) {
+            Ok(new_name) => {
+                if let Some(window_def) = self.layout.get_window(&new_name).cloned() {
+                    self.add_new_window(&window_def, 0, 0);
+                }
+                self.mark_layout_modified();
+                self.needs_render = true;
+                self.add_system_message(&format!("Window '{}' duplicated as '{}'", name, new_name));
+            }
+            Err(e) => self.add_system_message(&format!("Failed to duplicate window: {}", e)),
+        }
+    }
+
+    /// `.copywindow <name>`: put a window's definition on the clipboard as a
+    /// `[[windows]]` TOML snippet, to paste into another layout or a file
+    pub(super) fn copy_window(&mut self, name: &str) {
+        self.capture_window_geometry(name);
+        let Some(window_def) = self.layout.get_window(name).cloned() else {
+            self.add_system_message(&format!("Window not found: {}", name));
+            return;
+        };
+        let result = Layout::windows_to_toml(std::slice::from_ref(&window_def))
+            .and_then(|toml| crate::clipboard::copy(&toml));
+        match result {
+            Ok(()) => self.add_system_message(&format!(
+                "Copied window '{}' to the clipboard - .pastewindow adds it to a layout",
+                name
+            )),
+            Err(e) => self.add_system_message(&format!("Failed to copy window: {}", e)),
+        }
+    }
+
+    /// `.pastewindow`: add the window definitions on the clipboard to the
+    /// layout, renaming any whose name is taken
+    pub(super) fn paste_windows(&mut self) {
+        let windows =
+            match crate::clipboard::paste().and_then(|text| Layout::windows_from_toml(&text)) {
+                Ok(windows) => windows,
+                Err(e) => {
+                    self.add_system_message(&format!("Nothing to paste: {}", e));
+                    return;
+                }
+            };
+        let mut pasted = Vec::new();
+        for mut window_def in windows {
+            let wanted = match window_def.name() {
+                "" => window_def.widget_type().to_string(),
+                name => name.to_string(),
+            };
+            let name = if self.layout.get_window(&wanted).is_some()
+                || self.ui_state.windows.contains_key(&wanted)
+            {
+                self.layout.unique_window_name(&wanted)
+            } else {
+                wanted
+            };
+            let base = window_def.base_mut();
+            base.name = name.clone();
+            base.visible = true;
+            if let (Some(width), Some(height)) =
+                (self.layout.terminal_width, self.layout.terminal_height)
+            {
+                base.cols = base.cols.clamp(1, width.max(1));
+                base.rows = base.rows.clamp(1, height.max(1));
+                base.col = base.col.min(width.saturating_sub(base.cols));
+                base.row = base.row.min(height.saturating_sub(base.rows));
+            }
+            self.layout.windows.push(window_def.clone());
+            self.add_new_window(&window_def, 0, 0);
+            pasted.push(name);
+        }
+        self.mark_layout_modified();
+        self.needs_render = true;
+        self.add_system_message(&format!("Pasted window(s): {}", pasted.join(", ")));
+    }
+
+    /// `.window create`: open a text window from a one-line spec. It is
+    /// ephemeral (gone when the session ends) unless the spec says
+    /// `persist`, which adds it to the layout like any other window.
+    pub fn create_window(&mut self, spec: &crate::core::window_spec::WindowSpec) {
+        if self.ui_state.windows.contains_key(&spec.name)
+            || self.layout.get_window(&spec.name).is_some()
+        {
+            self.add_system_message(&format!("Window '{}' already exists", spec.name));
+            return;
+        }
+        let Some(mut window_def) = Config::get_window_template("text_custom") else {
+            self.add_system_message("No text window template to create from");
+            return;
+        };
+        let (col, row, cols, rows) = spec.placement(
+            self.layout.terminal_width.unwrap_or(80),
+            self.layout.terminal_height.unwrap_or(24),
+        );
+        let base = window_def.base_mut();
+        base.name = spec.name.clone();
+        base.title = Some(spec.name.clone());
+        base.visible = true;
+        base.col = col;
+        base.row = row;
+        base.cols = cols;
+        base.rows = rows;
+        if let crate::config::WindowDef::Text { data, .. } = &mut window_def {
+            data.streams = spec.streams.clone();
+        }
+
+        self.add_new_window(&window_def, 0, 0);
+        if spec.persist {
+            self.layout.windows.push(window_def);
+            self.mark_layout_modified();
+        } else {
+            if let Some(window) = self.ui_state.windows.get_mut(&spec.name) {
+                window.ephemeral = true;
+            }
+            self.ui_state.ephemeral_windows.insert(spec.name.clone());
+        }
+        self.needs_render = true;
+        self.add_system_message(&format!(
+            "Window '{}' created{} (streams: {})",
+            spec.name,
+            if spec.persist {
+                " and added to the layout"
+            } else {
+                ""
+            },
+            spec.streams.join(", ")
+        ));
+    }
+
+    /// `.window close`: remove a window made by `.window create` (or any
+    /// ephemeral window). Layout windows are hidden, as with `.hidewindow`.
+    pub fn close_window(&mut self, name: &str) {
+        if !self.ui_state.ephemeral_windows.remove(name) {
+            self.hide_window(name);
+            return;
+        }
+        self.ui_state.remove_window(name);
+        self.message_processor
+            .update_text_stream_subscribers(&self.ui_state);
+        self.needs_render = true;
+        self.add_system_message(&format!("Window '{}' closed", name));
+    }
+
+    /// Open the `[combat_mode]` windows that aren't already showing, as
+    /// ephemeral windows. A window the layout defines (hidden) keeps its
+    /// layout position; the rest come from their templates.
+    pub(super) fn apply_combat_overlay(&mut self) {
+        let mut unknown = Vec::new();
+        for name in self.config.combat_mode.windows.clone() {
+            if self.ui_state.windows.contains_key(&name) {
+                continue;
+            }
+            let window_def = match self.layout.get_window(&name) {
+                Some(window_def) => window_def.clone(),
+                None => match Config::get_window_template(&name) {
+                    Some(window_def) if window_def.name() == name => window_def,
+                    _ => {
+                        unknown.push(name);
+                        continue;
+                    }
+                },
+            };
+            self.add_new_window(&window_def, 0, 0);
+            if let Some(window) = self.ui_state.windows.get_mut(&name) {
+                window.ephemeral = true;
+            }
+            self.ui_state.ephemeral_windows.insert(name.clone());
+            self.combat_mode.opened.push(name);
+        }
+        if !unknown.is_empty() {
+            self.add_system_message(&format!(
+                "[combat_mode] no window or template named {}",
+                unknown.join(", ")
+            ));
+        }
+        if !self.combat_mode.opened.is_empty() {
+            self.message_processor
+                .update_text_stream_subscribers(&self.ui_state);
+            self.ui_state.needs_widget_reset = true;
+            self.needs_render = true;
+        }
+    }
+
+    /// Close the windows combat mode opened. One the layout has since
+    /// shown for real (`.showwindow` mid-fight) stays.
+    pub(super) fn remove_combat_overlay(&mut self) {
+        let opened = std::mem::take(&mut self.combat_mode.opened);
+        if opened.is_empty() {
+            return;
+        }
+        for name in opened {
+            let shown_by_layout = self
+                .layout
+                .get_window(&name)
+                .is_some_and(|window_def| window_def.base().visible);
+            if !self.ui_state.ephemeral_windows.remove(&name) {
+                continue;
+            }
+            if shown_by_layout {
+                if let Some(window) = self.ui_state.windows.get_mut(&name) {
+                    window.ephemeral = false;
+                }
+            } else {
+                self.ui_state.remove_window(&name);
+            }
+        }
+        self.message_processor
+            .update_text_stream_subscribers(&self.ui_state);
+        self.ui_state.needs_widget_reset = true;
+        self.needs_render = true;
+    }
+
+    /// Record the layout as it is now as an undoable edit (no-op if no
+    /// window changed since the last record)
+    pub fn record_layout_edit(&mut self) {
+        self.layout_history.record(&self.layout);
+    }
+
+    /// Collapse or expand a group in a spells window. The state lives in
+    /// the window's layout entry, so it is saved with the layout.
+    pub fn toggle_spell_group(&mut self, window_name: &str, group: &str) {
+        let Some(crate::config::WindowDef::Spells { data, .. }) = self
+            .layout
+            .windows
+            .iter_mut()
+            .find(|w| w.name() == window_name)
+        else {
+            return;
+        };
+        match data.collapsed_groups.iter().position(|g| g == group) {
+            Some(index) => {
+                data.collapsed_groups.remove(index);
+            }
+            None => data.collapsed_groups.push(group.to_string()),
+        }
+        self.layout_modified_since_save = true;
+        self.needs_render = true;
+    }
+
+    /// Undo the last layout edit (Ctrl+Z, `.layout undo`)
+    pub fn undo_layout_edit(&mut self) {
+        match self.layout_history.undo() {
+            Some(layout) => {
+                self.restore_layout(layout);
+                self.add_system_message(&format!(
+                    "Undid layout edit ({} more to undo)",
+                    self.layout_history.undo_steps()
+                ));
+            }
+            None => self.add_system_message("Nothing to undo"),
+        }
+    }
+
+    /// Redo the last undone layout edit (Ctrl+Y, `.layout redo`)
+    pub fn redo_layout_edit(&mut self) {
+        match self.layout_history.redo() {
+            Some(layout) => {
+                self.restore_layout(layout);
+                self.add_system_message(&format!(
+                    "Redid layout edit ({} more to redo)",
+                    self.layout_history.redo_steps()
+                ));
+            }
+            None => self.add_system_message("Nothing to redo"),
+        }
+    }
+
+    /// Switch to a layout from the undo history, touching only the windows
+    /// that differ. Windows outside the layout (container popups, the
+    /// performance overlay) are left alone.
+    fn restore_layout(&mut self, restored: Layout) {
+        let previous = std::mem::replace(&mut self.layout, restored);
+        let was_visible = |name: &str| {
+            previous
+                .windows
+                .iter()
+                .any(|w| w.name() == name && w.base().visible)
+        };
+        let mut changed = Vec::new();
+        for window_def in self.layout.windows.iter().filter(|w| w.base().visible) {
+            let before = previous
+                .windows
+                .iter()
+                .find(|w| w.name() == window_def.name());
+            if before != Some(window_def) || !self.ui_state.windows.contains_key(window_def.name())
+            {
+                changed.push(window_def.clone());
+            }
+        }
+        let removed: Vec<String> = previous
+            .windows
+            .iter()
+            .filter(|w| {
+                w.base().visible
+                    && self
+                        .layout
+                        .get_window(w.name())
+                        .is_none_or(|d| !d.base().visible)
+            })
+            .map(|w| w.name().to_string())
+            .collect();
+
+        for name in removed {
+            self.ui_state.remove_window(&name);
+        }
+        for window_def in changed {
+            let name = window_def.name().to_string();
+            if self.ui_state.windows.contains_key(&name) && was_visible(&name) {
+                self.ui_state.widgets_to_reset.push(name.clone());
+                self.update_window_position(&window_def, 0, 0);
+                if matches!(window_def, crate::config::WindowDef::TabbedText { .. })
+                    && self.sync_tabbed_window_tabs(&name)
+                {
+                    self.ui_state.needs_widget_reset = true;
+                }
+            } else {
+                self.add_new_window(&window_def, 0, 0);
+            }
+        }
+        self.sync_tees();
+        self.layout_modified_since_save = true;
+        self.needs_render = true;
+    }
+
     /// List all saved layouts
     pub(super) fn list_layouts(&mut self) {
         match Config::list_layouts() {
@@ -1379,6 +1883,10 @@ mod tests {
             max_cols: None,
             visible: true,
             content_align: None,
+            floating: false,
+            z_index: 0,
+            tee_file: None,
+            show_when: None,
             title_position: "top-left".to_string(),
         }
     }
@@ -1390,6 +1898,7 @@ mod tests {
             terminal_height: Some(24),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         }
     }
@@ -1401,6 +1910,7 @@ mod tests {
             terminal_height: Some(24),
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         }
     }
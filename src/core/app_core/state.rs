@@ -8,6 +8,7 @@ use crate::cmdlist::CmdList;
 use crate::config::{Config, Layout, SavedDialogPositions};
 use crate::core::{GameState, MessageProcessor};
 use crate::data::*;
+use crate::parse_worker::{ParseWorker, ParsedChunk, ParserUpdate};
 use crate::parser::{ParsedElement, XmlParser};
 use crate::performance::PerformanceStats;
 use anyhow::Result;
@@ -23,6 +24,17 @@ pub struct PendingMenuRequest {
     pub origin: crate::core::remote::MenuOrigin,
 }
 
+/// What a frontend should do with text pasted into the command input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PasteAction {
+    /// Single line: insert it at the cursor like typed text
+    Insert(String),
+    /// Several lines below the confirm threshold: queued for sending
+    Sent(usize),
+    /// Held in `ui_state.paste_guard` until the user picks a `PasteChoice`
+    Confirm,
+}
+
 /// Core application state - frontend-agnostic
 pub struct AppCore {
     // === Configuration ===
@@ -46,6 +58,10 @@ pub struct AppCore {
     /// XML parser for GemStone IV protocol
     pub parser: XmlParser,
 
+    /// Parse thread fed from the network, once a runtime starts one; its
+    /// parser gets the same config updates as `parser`
+    parse_worker: Option<ParseWorker>,
+
     /// Message processor (routes parsed elements to state updates)
     pub message_processor: MessageProcessor,
 
@@ -91,6 +107,35 @@ pub struct AppCore {
     /// Text-to-Speech manager for accessibility
     pub tts_manager: crate::tts::TtsManager,
 
+    /// Whether the terminal/GUI window has focus, from frontend focus
+    /// events; assumed true until one arrives
+    pub app_focused: bool,
+
+    /// TTS mute state from before the boss key, restored when it's released
+    boss_tts_was_muted: bool,
+
+    /// Window whose focus was last announced in screen-reader mode
+    announced_focus: Option<String>,
+
+    /// Bundled word list + personal dictionary for `[spellcheck]`
+    pub spellchecker: crate::core::spellcheck::SpellChecker,
+
+    /// Community layout download in flight (`.layouts get`)
+    pub(super) layout_download: Option<crate::core::layout_gallery::LayoutDownload>,
+
+    /// Rescaled layout previewed by `.layout scale`, waiting for `apply`
+    pub(super) pending_layout_scale: Option<Layout>,
+
+    /// Undo/redo steps for layout edits
+    pub(super) layout_history: crate::core::layout_history::LayoutHistory,
+
+    /// Game commands sent this session, for the command log palette
+    pub command_log: crate::core::command_log::CommandLog,
+
+    /// Commands queued by the core itself (confirmed pastes), drained with
+    /// travel's queue by `take_outbound`
+    pub(super) queued_commands: Vec<String>,
+
     // === Navigation State ===
     /// Navigation room ID from <nav rm='...'/>
     /// Live map state: mapdb, generated layouts, current-room tracking.
@@ -99,6 +144,43 @@ pub struct AppCore {
     pub map_updater: crate::core::mapdb_update::MapDbUpdater,
     /// Native go2: the walk executor and its outbound command queue.
     pub travel: crate::core::travel::TravelService,
+    /// Named spell sets for `.rebuff`, from the profile's spell_sets.toml
+    pub spell_sets: crate::core::rebuff::SpellSets,
+    /// Casts queued by `.rebuff <set> cast`
+    pub rebuff: crate::core::rebuff::RebuffQueue,
+    /// `.go <route>` playback
+    pub route: crate::core::routes::RoutePlayer,
+    /// Sequence keybind playback (login walks, startup routines)
+    pub sequence: crate::core::sequence::SequencePlayer,
+    /// `.repeat` command on an interval
+    pub repeat: crate::core::repeat::Repeater,
+    /// `.remind` reminders, from the profile's reminders.toml
+    pub reminders: crate::core::reminders::Reminders,
+    /// Away mode: `.away` / idle state and who has been auto-replied to
+    pub away: crate::core::away::AwayState,
+    /// `[combat_mode]`: whether we're fighting and the windows opened for it
+    pub combat_mode: crate::core::combat_mode::CombatMode,
+    /// Roundtime / casttime / stun endings for the `[sound]` cues
+    pub countdown_cues: crate::core::countdown_cues::CountdownCues,
+    /// Per-character notepad, from the profile's notes.md
+    pub notes: crate::core::notes::Notes,
+    /// Time connected this session, per day and in total, from the
+    /// profile's played.toml
+    pub played: crate::core::played::Played,
+    /// `.quit` confirmation and logout wait
+    pub(super) quit_state: crate::core::quit::QuitState,
+    /// A script import that would replace existing keybinds or highlights,
+    /// with its source name, waiting for `.import yes`
+    pub(super) pending_import: Option<(crate::config::script_import::ScriptImport, String)>,
+    /// `.tee` targets outside the tee folder, by window name; session only,
+    /// since a layout can't hold them (core::tee)
+    tee_overrides: HashMap<String, String>,
+    /// `[lockout]` checks; frontends set the bypass when its modifier is held
+    pub command_lockout: crate::core::lockout::CommandLockout,
+    /// `.inspect`: recent server lines, their parsed elements and state changes
+    pub inspector: crate::core::inspector::Inspector,
+    /// A dragged item move waiting for `.move yes` (`[containers] confirm_moves`)
+    pub(super) pending_item_move: Option<crate::core::containers::ItemMove>,
     /// Cache for the wire-format map scene sent to web clients, keyed by
     /// (scene Arc pointer, sheet, building cluster) so a rebuild only
     /// happens when the drawn view actually changes.
@@ -167,6 +249,10 @@ pub struct AppCore {
     /// (keybinds.toml or an earlier hotbar button). Editors surface these.
     pub hotbar_key_conflicts: Vec<crate::core::app_core::keybinds::HotbarKeyConflict>,
 
+    /// Saved keybind profile overlaid on the map while the current layout
+    /// names it (`keybind_profile`): its name and bindings
+    pub keybind_overlay: Option<(String, HashMap<String, crate::config::KeyBindAction>)>,
+
     // === Dialog Position Persistence ===
     /// Saved dialog positions loaded from widget_state.toml
     /// Updated when dialogs with save='t' are dragged/resized
@@ -176,6 +262,8 @@ pub struct AppCore {
 impl AppCore {
     /// Create a new AppCore instance
     pub fn new(config: Config) -> Result<Self> {
+        crate::core::locale::set_locale(config.ui.locale);
+
         // Load layout from file system
         let layout = Layout::load(config.character.as_deref())?;
 
@@ -189,7 +277,11 @@ impl AppCore {
             .unwrap_or_default();
 
         // Create message processor (shares saved_dialog_positions reference)
-        let message_processor = MessageProcessor::new(config.clone(), saved_dialog_positions.clone());
+        let mut message_processor =
+            MessageProcessor::new(config.clone(), saved_dialog_positions.clone());
+        if let Ok(path) = Config::item_db_path() {
+            message_processor.item_db = crate::data::itemdb::ItemDb::load(&path);
+        }
 
         // Convert presets from config to parser format, resolving palette names to hex values
         let preset_list: Vec<(String, Option<String>, Option<String>)> = config
@@ -232,7 +324,8 @@ impl AppCore {
         let tts_manager = crate::tts::TtsManager::new(
             config.tts.enabled,
             config.tts.rate,
-            config.tts.volume
+            config.tts.volume,
+            config.tts.max_queue,
         );
         if config.tts.enabled {
             tracing::info!("TTS enabled - accessibility features active");
@@ -243,6 +336,31 @@ impl AppCore {
         let mut keybind_map = Self::build_keybind_map(&config);
         let hotbar_key_conflicts = Self::merge_hotbar_hotkeys(&mut keybind_map, &config.hotbars);
 
+        let spell_sets = Config::spell_sets_path(config.character.as_deref())
+            .and_then(|path| crate::core::rebuff::SpellSets::load(&path))
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load spell sets: {}", e);
+                Default::default()
+            });
+        let reminders = Config::reminders_path(config.character.as_deref())
+            .and_then(|path| crate::core::reminders::Reminders::load(&path))
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load reminders: {}", e);
+                Default::default()
+            });
+        let notes = Config::notes_path(config.character.as_deref())
+            .and_then(|path| crate::core::notes::Notes::load(&path))
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load notes: {}", e);
+                Default::default()
+            });
+        let played = Config::played_path(config.character.as_deref())
+            .and_then(|path| crate::core::played::Played::load(&path))
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load played time: {}", e);
+                Default::default()
+            });
+
         let layout_theme = layout.theme.clone();
         let map_base = Config::base_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
         let map_cache_dir = map_base.join("cache").join("layouts");
@@ -255,14 +373,33 @@ impl AppCore {
                 crate::core::mapdb_update::download_dir(&map_base),
             ),
             travel: Default::default(),
+            spell_sets,
+            rebuff: Default::default(),
+            route: Default::default(),
+            sequence: Default::default(),
+            repeat: Default::default(),
+            reminders,
+            away: Default::default(),
+            combat_mode: Default::default(),
+            countdown_cues: Default::default(),
+            notes,
+            played,
+            quit_state: Default::default(),
+            pending_import: None,
+            tee_overrides: HashMap::new(),
+            command_lockout: Default::default(),
+            inspector: Default::default(),
+            pending_item_move: None,
             remote_map_cache: None,
             last_remote_map_revision: 0,
             pending_map_views: Vec::new(),
             layout: layout.clone(),
+            layout_history: crate::core::layout_history::LayoutHistory::new(layout.clone()),
             baseline_layout: Some(layout),
             game_state: GameState::new(),
             ui_state: UiState::new(),
             parser,
+            parse_worker: None,
             message_processor,
             current_stream: String::from("main"),
             discard_current_stream: false,
@@ -277,6 +414,16 @@ impl AppCore {
             show_perf_stats: false,
             sound_player,
             tts_manager,
+            app_focused: true,
+            boss_tts_was_muted: false,
+            announced_focus: None,
+            spellchecker: Config::dictionary_path()
+                .map(|path| crate::core::spellcheck::SpellChecker::load(&path))
+                .unwrap_or_default(),
+            layout_download: None,
+            pending_layout_scale: None,
+            command_log: crate::core::command_log::CommandLog::new(),
+            queued_commands: Vec::new(),
             evidence: crate::core::evidence::EvidenceStore::default(),
             nav_room_id: None,
             lich_room_id: None,
@@ -293,9 +440,17 @@ impl AppCore {
             base_layout_name: None,
             keybind_map,
             hotbar_key_conflicts,
+            keybind_overlay: None,
             saved_dialog_positions,
         };
 
+        // Layer the layout's keybind profile (if any) over the map built above
+        app.sync_keybind_profile();
+
+        for notice in crate::config::take_upgrade_notices() {
+            app.add_system_message(&notice);
+        }
+
         for conflict in &app.hotbar_key_conflicts.clone() {
             app.add_system_message(&format!(
                 "Hotbar key '{}' ({}:{}) not registered - already bound by {}",
@@ -317,6 +472,7 @@ impl AppCore {
 
         app.apply_session_cache();
         app.apply_custom_quickbars();
+        app.apply_focus_audio();
 
         if let Some((theme_id, _)) = app.apply_layout_theme(layout_theme.as_deref()) {
             app.add_system_message(&format!("Theme switched to: {}", theme_id));
@@ -343,8 +499,9 @@ impl AppCore {
         self.map.ensure_db(source);
     }
 
-    /// Drain the map worker and the mapdb updater; a freshly installed
-    /// download is picked up immediately. Frontends call this once per frame.
+    /// Drain the map worker, the mapdb updater, and any layout download; a
+    /// freshly installed mapdb is picked up immediately. Frontends call this
+    /// once per frame.
     pub fn poll_map(&mut self) {
         self.map.poll();
         if self.map_updater.poll() {
@@ -362,7 +519,28 @@ impl AppCore {
             };
             self.add_system_message(&format!("[map] {text}"));
         }
+        if let Some(result) = self.layout_download.as_ref().and_then(|d| d.poll()) {
+            self.layout_download = None;
+            match result {
+                Ok(name) => self.add_system_message(&format!(
+                    "Layout '{}' downloaded - .layouts browse to preview and apply it",
+                    name
+                )),
+                Err(e) => self.add_system_message(&format!("Layout download failed: {}", e)),
+            }
+        }
         self.tick_travel();
+        self.tick_rebuff();
+        self.tick_route();
+        self.tick_sequence();
+        self.tick_repeat();
+        self.tick_reminders();
+        self.tick_away();
+        self.tick_played();
+        self.tick_quit();
+        self.tick_combat_mode();
+        self.tick_countdown_cues();
+        self.tick_window_conditions();
         // Browse replies waiting on the layout worker.
         self.service_pending_map_views();
         // A layout that finished generating between game lines still needs
@@ -435,10 +613,386 @@ impl AppCore {
         }
     }
 
+    /// Release the next `.rebuff` cast once roundtime and casttime are clear.
+    pub fn tick_rebuff(&mut self) {
+        if !self.rebuff.is_running() {
+            return;
+        }
+        let busy = self.game_state.in_roundtime() || self.game_state.in_casttime();
+        let step_delay = std::time::Duration::from_millis(self.config.rebuff.step_delay_ms);
+        if let Some(command) = self
+            .rebuff
+            .tick(busy, std::time::Instant::now(), step_delay)
+        {
+            self.queued_commands.push(command);
+            if !self.rebuff.is_running() {
+                let message = format!("[rebuff] {} done", self.rebuff.set());
+                self.add_system_message(&message);
+            }
+        }
+    }
+
+    /// Send the next `.go` route step once roundtime is clear and the last
+    /// move has landed.
+    pub fn tick_route(&mut self) {
+        if !self.route.is_running() {
+            return;
+        }
+        let room = self
+            .nav_room_id
+            .clone()
+            .or_else(|| self.map.current_room_id.map(|id| id.to_string()));
+        let name = self.route.name().to_string();
+        let config = &self.config.routes;
+        let event = self.route.tick(
+            room.as_deref(),
+            self.game_state.in_roundtime(),
+            std::time::Instant::now(),
+            std::time::Duration::from_millis(config.step_delay_ms),
+            std::time::Duration::from_millis(config.step_timeout_ms),
+        );
+        match event {
+            Some(crate::core::routes::RouteEvent::Send(step)) => self.queued_commands.push(step),
+            Some(crate::core::routes::RouteEvent::Stuck(step)) => {
+                self.add_system_message(&format!(
+                    "[go] '{}' didn't move you - paused (.go resume to retry, .go stop to give up)",
+                    step
+                ));
+            }
+            Some(crate::core::routes::RouteEvent::Done) => {
+                self.add_system_message(&format!("[go] {} done", name));
+            }
+            None => return,
+        }
+        self.needs_render = true;
+    }
+
+    /// Send the `.repeat` command when its delay is up and roundtime and
+    /// casttime are clear. A dropped connection ends it.
+    pub fn tick_repeat(&mut self) {
+        if !self.repeat.is_running() {
+            return;
+        }
+        if !self.game_state.connected {
+            self.stop_repeat();
+            return;
+        }
+        let busy = self.game_state.in_roundtime() || self.game_state.in_casttime();
+        if let Some(command) = self.repeat.tick(busy, std::time::Instant::now()) {
+            self.queued_commands.push(command.clone());
+            if !self.repeat.is_running() {
+                self.add_system_message(&format!("[repeat] {} done", command));
+            }
+            self.needs_render = true;
+        }
+    }
+
+    /// Stop `.repeat`
+    pub fn stop_repeat(&mut self) {
+        match self.repeat.stop() {
+            Some(command) => self.add_system_message(&format!("[repeat] {} stopped", command)),
+            None => self.add_system_message("[repeat] nothing repeating"),
+        }
+        self.needs_render = true;
+    }
+
+    /// Stop `.go` route playback
+    pub fn stop_route(&mut self) {
+        let name = self.route.name().to_string();
+        if self.route.stop() {
+            self.add_system_message(&format!("[go] {} stopped", name));
+        } else {
+            self.add_system_message("[go] no route playing");
+        }
+    }
+
+    /// Queue the sequence keybind steps whose pause has run out.
+    pub fn tick_sequence(&mut self) {
+        let due = self.sequence.tick(std::time::Instant::now());
+        if !due.is_empty() {
+            self.queued_commands.extend(due);
+            self.needs_render = true;
+        }
+    }
+
+    /// Fire the reminders that have come due and save the rescheduled list.
+    pub fn tick_reminders(&mut self) {
+        let fired = self.reminders.take_due(chrono::Utc::now().timestamp());
+        if fired.is_empty() {
+            return;
+        }
+        for reminder in &fired {
+            self.raise_reminder(&reminder.message);
+        }
+        self.write_reminders();
+        self.needs_render = true;
+    }
+
+    /// Alerts for a reminder: a system line plus the sound, speech and
+    /// desktop notification from `[reminders]`.
+    fn raise_reminder(&mut self, message: &str) {
+        self.add_system_message(&format!("[remind] {}", message));
+        let config = self.config.reminders.clone();
+        if let Some(file) = config.sound {
+            self.game_state
+                .queue_sound(crate::core::highlight_engine::SoundTrigger { file, volume: None });
+        }
+        if config.speak {
+            if let Err(e) = self.tts_manager.announce(message, "reminder") {
+                tracing::warn!("TTS reminder failed: {}", e);
+            }
+        }
+        if config.desktop_notify {
+            let title = match &self.config.character {
+                Some(character) => format!("{} - Reminder", character),
+                None => "VellumFE - Reminder".to_string(),
+            };
+            self.notify_desktop(config.notify_when, &title, message);
+        }
+    }
+
+    /// Desktop notification for an alarm or reminder. Skipped in boss mode
+    /// (a popup would give the hidden client away) and when `when` rules
+    /// out the current focus.
+    fn notify_desktop(&self, when: crate::config::FocusRule, title: &str, body: &str) {
+        if self.ui_state.boss_mode || !when.allows(self.app_focused) {
+            return;
+        }
+        if let Err(e) = crate::platform::notify(title, body) {
+            tracing::warn!("Desktop notification failed: {}", e);
+        }
+    }
+
+    /// Keyboard or mouse input from the user; frontends call this for
+    /// every input event. Ends idle away mode.
+    pub fn note_user_input(&mut self) {
+        if let Some(missed) = self.away.note_input(std::time::Instant::now()) {
+            self.announce_back(missed);
+        }
+    }
+
+    /// Go away once `[away] idle_minutes` pass without input.
+    pub fn tick_away(&mut self) {
+        let idle_after = std::time::Duration::from_secs(self.config.away.idle_minutes * 60);
+        if self.away.check_idle(std::time::Instant::now(), idle_after) {
+            self.add_system_message("[away] idle - you are now away");
+        }
+    }
+
+    /// Credit connected time and save played.toml every minute and on
+    /// disconnect.
+    pub fn tick_played(&mut self) {
+        if self
+            .played
+            .tick(self.game_state.connected, chrono::Local::now())
+        {
+            self.write_played();
+        }
+    }
+
+    /// Re-evaluate the layout's `show_when` conditions. A window whose
+    /// condition is false isn't drawn; one that can't be evaluated stays
+    /// shown, with the error reported once.
+    pub fn tick_window_conditions(&mut self) {
+        let ctx = crate::core::condition::ConditionContext {
+            game_state: &self.game_state,
+            now_server: chrono::Utc::now().timestamp() + self.message_processor.server_time_offset,
+            variables: &self.config.variables,
+        };
+        let (hidden, messages) = evaluate_window_conditions(
+            &self.layout.windows,
+            &ctx,
+            &mut self.ui_state.condition_errors,
+        );
+        for message in messages {
+            self.add_system_message(&message);
+        }
+        if hidden != self.ui_state.hidden_by_condition {
+            self.ui_state.hidden_by_condition = hidden;
+            self.needs_render = true;
+        }
+    }
+
+    /// Open or close the `[combat_mode]` windows as fighting starts and
+    /// winds down (core::combat_mode).
+    pub fn tick_combat_mode(&mut self) {
+        use crate::core::combat_mode::{looks_like_combat, CombatChange};
+        if !self.config.combat_mode.enabled {
+            if self.combat_mode.is_active() {
+                // The overlay closes the windows listed in `opened`
+                self.remove_combat_overlay();
+                self.combat_mode = Default::default();
+            }
+            return;
+        }
+        let now_server = chrono::Utc::now().timestamp() + self.message_processor.server_time_offset;
+        let fighting = looks_like_combat(&self.game_state, now_server);
+        let linger = std::time::Duration::from_secs(self.config.combat_mode.linger_seconds);
+        match self
+            .combat_mode
+            .update(fighting, std::time::Instant::now(), linger)
+        {
+            Some(CombatChange::Started) => self.apply_combat_overlay(),
+            Some(CombatChange::Ended) => self.remove_combat_overlay(),
+            None => {}
+        }
+    }
+
+    /// Queue the `[sound]` cue for each timer that ran out since the last
+    /// frame (core::countdown_cues).
+    pub fn tick_countdown_cues(&mut self) {
+        use crate::core::countdown_cues::CountdownCue;
+        let now_ms = chrono::Utc::now().timestamp_millis()
+            + self.message_processor.server_time_offset * 1000;
+        for cue in self.countdown_cues.update(&self.game_state, now_ms) {
+            let sound = match cue {
+                CountdownCue::Roundtime => &self.config.sound.roundtime_cue,
+                CountdownCue::Casttime => &self.config.sound.casttime_cue,
+                CountdownCue::Stun => &self.config.sound.stun_cue,
+            };
+            if let Some(sound) = sound.clone() {
+                self.game_state
+                    .queue_sound(crate::core::highlight_engine::SoundTrigger {
+                        file: sound.file,
+                        volume: sound.volume,
+                    });
+            }
+        }
+    }
+
+    /// Exit once a `.quit` logout wait ends (core::quit).
+    pub fn tick_quit(&mut self) {
+        use crate::core::quit::LogoutEnd;
+        let now = std::time::Instant::now();
+        match self.quit_state.poll(self.game_state.connected, now) {
+            Some(LogoutEnd::Closed) => self.quit(),
+            Some(LogoutEnd::TimedOut) => {
+                tracing::warn!("Server didn't close the connection after logout; exiting anyway");
+                self.quit();
+            }
+            None => {}
+        }
+    }
+
+    pub(super) fn announce_back(&mut self, missed: usize) {
+        let message = match missed {
+            0 => "[away] welcome back".to_string(),
+            1 => "[away] welcome back - 1 whisper while away".to_string(),
+            n => format!("[away] welcome back - {} whispers while away", n),
+        };
+        self.add_system_message(&message);
+    }
+
+    /// A whisper arrived: auto-reply once per sender and copy the line to
+    /// the away stream while away.
+    fn away_whisper(&mut self, sender: &str, line: &str) {
+        let own = self.game_state.character_name.as_deref();
+        if own.is_some_and(|own| own.eq_ignore_ascii_case(sender)) {
+            return;
+        }
+        let Some(action) = self.away.whisper(sender, line) else {
+            return;
+        };
+        if action.log {
+            self.log_away_line(line);
+        }
+        if action.reply && self.config.away.auto_reply {
+            let message = self
+                .away
+                .message
+                .clone()
+                .unwrap_or_else(|| self.config.away.message.clone());
+            let command = crate::core::reply::expand(&self.config.reply.whisper, sender, &message);
+            self.queued_commands.push(command);
+        }
+    }
+
+    /// Add a line to the windows on the `[away] stream`. Without one the
+    /// whisper is only counted; it's already in main.
+    fn log_away_line(&mut self, text: &str) {
+        use crate::data::{SpanType, StyledLine, TextSegment, WindowContent};
+
+        let stream = self.config.away.stream.clone();
+        let line = StyledLine {
+            segments: vec![TextSegment {
+                text: text.to_string(),
+                fg: None,
+                bg: None,
+                bold: false,
+                mono: false,
+                span_type: SpanType::Normal,
+                link_data: None,
+            }],
+            stream: crate::data::intern::intern(&stream),
+            timestamp: None,
+        };
+        let subscribers = self
+            .message_processor
+            .get_stream_subscribers(&stream)
+            .to_vec();
+        if let Some(remote) = self.message_processor.remote.as_mut() {
+            remote.push_text(&stream, std::sync::Arc::new(line.clone()));
+        }
+        for name in subscribers {
+            let Some(window) = self.ui_state.windows.get_mut(&name) else {
+                continue;
+            };
+            match &mut window.content {
+                WindowContent::Text(content) => content.add_line(line.clone()),
+                WindowContent::TabbedText(content) => {
+                    for tab in content.tabs.iter_mut() {
+                        if tab
+                            .definition
+                            .streams
+                            .iter()
+                            .any(|s| s.eq_ignore_ascii_case(&stream))
+                        {
+                            tab.content.add_line(line.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.needs_render = true;
+    }
+
+    pub(super) fn write_played(&mut self) {
+        let result = Config::played_path(self.config.character.as_deref())
+            .and_then(|path| self.played.save(&path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to save played time: {}", e);
+        }
+    }
+
+    pub(super) fn write_reminders(&mut self) {
+        let result = Config::reminders_path(self.config.character.as_deref())
+            .and_then(|path| self.reminders.save(&path));
+        if let Err(e) = result {
+            self.add_system_message(&format!("[remind] failed to save reminders: {}", e));
+        }
+    }
+
+    /// Replace the notes with text from an editor and save them to
+    /// notes.md. Unchanged text isn't written.
+    pub fn set_notes(&mut self, text: String) {
+        if !self.notes.set_text(text) {
+            return;
+        }
+        self.needs_render = true;
+        let result = Config::notes_path(self.config.character.as_deref())
+            .and_then(|path| self.notes.save(&path));
+        if let Err(e) = result {
+            self.add_system_message(&format!("[notes] failed to save notes: {}", e));
+        }
+    }
+
     /// Commands automation wants sent to the game; frontends drain this
     /// through the same path as typed commands.
     pub fn take_outbound(&mut self) -> Vec<String> {
-        self.travel.take_outbound()
+        let mut commands = std::mem::take(&mut self.queued_commands);
+        commands.extend(self.travel.take_outbound());
+        commands
     }
 
     /// Plan and begin a trip to a mapdb room id.
@@ -995,6 +1549,17 @@ impl AppCore {
             crate::data::WidgetType::Experience => "experience",
             crate::data::WidgetType::GS4Experience => "gs4_experience",
             crate::data::WidgetType::Encumbrance => "encum",
+            crate::data::WidgetType::Stance => "stance",
+            crate::data::WidgetType::Wealth => "wealth",
+            crate::data::WidgetType::TaskTracker => "task_tracker",
+            crate::data::WidgetType::SocietyTasks => "society_tasks",
+            crate::data::WidgetType::CombatStats => "combat_stats",
+            crate::data::WidgetType::Calendar => "calendar",
+            crate::data::WidgetType::Reminders => "reminders",
+            crate::data::WidgetType::Notes => "notes",
+            crate::data::WidgetType::Watch => "watch",
+            crate::data::WidgetType::Traffic => "traffic",
+            crate::data::WidgetType::Played => "played",
             crate::data::WidgetType::Quickbar => "quickbar",
             crate::data::WidgetType::Hotkeybar => "hotkeybar",
             crate::data::WidgetType::MiniVitals => "minivitals",
@@ -1348,6 +1913,241 @@ impl AppCore {
                 }
             }
         }
+
+        self.announce_focus_change();
+    }
+
+    /// Speak an interface announcement in screen-reader mode
+    pub fn announce(&mut self, text: &str) {
+        if !self.config.ui.screen_reader {
+            return;
+        }
+        if let Err(e) = self.tts_manager.announce(text, "screen_reader") {
+            tracing::warn!("TTS announce failed: {}", e);
+        }
+    }
+
+    /// Task tracker completion notice: a system line, the optional sound,
+    /// and a spoken announcement in screen-reader mode. Configured on the
+    /// first task_tracker window; without one, nothing is emitted.
+    fn notify_bounty_complete(&mut self) {
+        let Some((notify, sound)) = self.layout.windows.iter().find_map(|w| match w {
+            crate::config::WindowDef::TaskTracker { data, .. } => {
+                Some((data.notify, data.sound.clone()))
+            }
+            _ => None,
+        }) else {
+            return;
+        };
+        if !notify {
+            return;
+        }
+        let detail = self
+            .game_state
+            .bounty
+            .task
+            .as_ref()
+            .map(|t| t.lines.join(", "))
+            .unwrap_or_default();
+        let message = format!("[bounty] Task complete: {}", detail);
+        self.add_system_message(&message);
+        if let Some(file) = sound {
+            self.game_state
+                .queue_sound(crate::core::highlight_engine::SoundTrigger { file, volume: None });
+        }
+        self.announce(&message);
+    }
+
+    /// Society task completion notice, one per finished task, configured on
+    /// the first society_tasks window like the bounty notice.
+    fn notify_society_tasks_complete(
+        &mut self,
+        finished: &[crate::core::society_tasks::SocietyTask],
+    ) {
+        let Some((notify, sound)) = self.layout.windows.iter().find_map(|w| match w {
+            crate::config::WindowDef::SocietyTasks { data, .. } => {
+                Some((data.notify, data.sound.clone()))
+            }
+            _ => None,
+        }) else {
+            return;
+        };
+        if !notify || finished.is_empty() {
+            return;
+        }
+        for task in finished {
+            let message = format!("[society] Task complete: {}", task.text);
+            self.add_system_message(&message);
+            self.announce(&message);
+        }
+        if let Some(file) = sound {
+            self.game_state
+                .queue_sound(crate::core::highlight_engine::SoundTrigger { file, volume: None });
+        }
+    }
+
+    /// Re-evaluate the death/near-death alarm (core::alarm) against the
+    /// current vitals and fire the alerts for a new, higher level.
+    fn check_alarm(&mut self) {
+        let escalated = self.game_state.alarm.update(
+            &self.config.alarm,
+            self.game_state.vitals.health,
+            &self.game_state.status,
+            std::time::Instant::now(),
+        );
+        if let Some(level) = escalated {
+            let reason = self.game_state.alarm.reason.clone();
+            self.raise_alarm(level, &reason);
+        }
+    }
+
+    /// Alerts for an alarm level: a system line for a warning; critical
+    /// adds the sound, speech and desktop notification.
+    pub(super) fn raise_alarm(&mut self, level: crate::core::alarm::AlarmLevel, reason: &str) {
+        self.add_system_message(&format!("[alarm] {}", reason));
+        self.needs_render = true;
+        if level < crate::core::alarm::AlarmLevel::Critical {
+            return;
+        }
+        let config = self.config.alarm.clone();
+        if let Some(file) = config.sound {
+            self.game_state
+                .queue_sound(crate::core::highlight_engine::SoundTrigger { file, volume: None });
+        }
+        if config.speak {
+            if let Err(e) = self.tts_manager.announce(reason, "alarm") {
+                tracing::warn!("TTS alarm failed: {}", e);
+            }
+        }
+        if config.desktop_notify {
+            let title = match &self.config.character {
+                Some(character) => format!("{} - VellumFE", character),
+                None => "VellumFE".to_string(),
+            };
+            self.notify_desktop(config.notify_when, &title, reason);
+        }
+    }
+
+    /// Announce the focused window when it changed since the last poll.
+    /// Checked from the event loop so every focus path (cycling, clicks,
+    /// compact tabs) is covered without hooking each one.
+    fn announce_focus_change(&mut self) {
+        if !self.config.ui.screen_reader || self.ui_state.focused_window == self.announced_focus {
+            return;
+        }
+        self.announced_focus = self.ui_state.focused_window.clone();
+        let Some(name) = self.announced_focus.clone() else {
+            return;
+        };
+        let now = chrono::Utc::now().timestamp() + self.message_processor.server_time_offset;
+        let text = match self
+            .ui_state
+            .get_window(&name)
+            .and_then(|window| crate::core::reading_order::describe(window, now))
+        {
+            Some(summary) => format!("{} window. {}", name, summary),
+            None => format!("{} window", name),
+        };
+        self.announce(&text);
+    }
+
+    /// `.tts stop|skip|mute|status|queue [n]`
+    pub(super) fn tts_command(&mut self, sub: Option<&str>, arg: Option<&str>) {
+        let result = match sub.map(|s| s.to_lowercase()).as_deref() {
+            Some("stop") | Some("flush") => self.tts_manager.flush(),
+            Some("skip") => self.tts_manager.skip(),
+            Some("mute") => {
+                self.tts_manager.toggle_mute();
+                let status = if self.tts_manager.is_muted() {
+                    "muted"
+                } else {
+                    "unmuted"
+                };
+                self.add_system_message(&format!("TTS {}", status));
+                Ok(())
+            }
+            Some("queue") => {
+                match arg.map(str::parse::<usize>) {
+                    Some(Ok(depth)) if depth > 0 => {
+                        self.tts_manager.set_max_queue_size(depth);
+                        self.add_system_message(&format!("TTS queue depth set to {}", depth));
+                    }
+                    Some(_) => self.add_system_message("Usage: .tts queue <depth> (1 or more)"),
+                    None => self.add_system_message(&format!(
+                        "TTS queue depth: {}",
+                        self.tts_manager.max_queue_size()
+                    )),
+                }
+                Ok(())
+            }
+            None | Some("status") => {
+                let state = if !self.tts_manager.is_enabled() {
+                    "disabled"
+                } else if self.tts_manager.is_muted() {
+                    "muted"
+                } else {
+                    "on"
+                };
+                self.add_system_message(&format!(
+                    "TTS {}: {} queued, {} unread (max {})",
+                    state,
+                    self.tts_manager.queue_size(),
+                    self.tts_manager.unread_count(),
+                    self.tts_manager.max_queue_size()
+                ));
+                Ok(())
+            }
+            Some(_) => {
+                self.add_system_message("Usage: .tts stop|skip|mute|status|queue [depth]");
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            tracing::warn!("TTS command failed: {}", e);
+            self.add_system_message(&format!("TTS error: {}", e));
+        }
+    }
+
+    /// Read every visible window in reading order: spoken through TTS and
+    /// echoed as system lines for terminal screen readers
+    pub fn read_screen(&mut self) {
+        let now = chrono::Utc::now().timestamp() + self.message_processor.server_time_offset;
+        let lines = crate::core::reading_order::reading_order(&self.ui_state, now);
+        if lines.is_empty() {
+            self.add_system_message("Nothing on screen to read");
+            return;
+        }
+        if let Err(e) = self
+            .tts_manager
+            .announce(&lines.join(". "), "screen_reader")
+        {
+            tracing::warn!("TTS announce failed: {}", e);
+        }
+        self.add_system_message("=== Screen ===");
+        for line in &lines {
+            self.add_system_message(line);
+        }
+    }
+
+    /// Text window content with the settings from its layout entry, or
+    /// the defaults when `window_def` isn't a text window.
+    fn text_content_for(&self, window_def: &crate::config::WindowDef, title: &str) -> TextContent {
+        let crate::config::WindowDef::Text { data, .. } = window_def else {
+            let mut content = TextContent::new(title, 1000);
+            content.timestamp_position = self.config.ui.timestamp_position;
+            return content;
+        };
+        let mut content = TextContent::new(title, data.buffer_size);
+        content.streams = data.streams.clone();
+        content.compact = data.compact;
+        content.show_timestamps = data.show_timestamps;
+        content.timestamp_position = data
+            .timestamp_position
+            .unwrap_or(self.config.ui.timestamp_position);
+        content.collapse_repeats = data.collapse_repeats;
+        content.coalesce_seconds = data.coalesce_seconds;
+        content.conversation = data.conversation;
+        content
     }
 
     /// Initialize windows based on current layout
@@ -1402,24 +2202,9 @@ impl AppCore {
 
             let content = match widget_type {
                 WidgetType::Text => {
-                    let (buffer_size, streams, compact, show_ts, ts_pos) =
-                        if let crate::config::WindowDef::Text { data, .. } = window_def {
-                            (
-                                data.buffer_size,
-                                data.streams.clone(),
-                                data.compact,
-                                data.show_timestamps,
-                                data.timestamp_position
-                                    .unwrap_or(self.config.ui.timestamp_position),
-                            )
-                        } else {
-                            (1000, vec![], false, false, self.config.ui.timestamp_position)
-                        };
-                    let mut text_content = TextContent::new(title, buffer_size);
-                    text_content.streams = streams.clone();
-                    text_content.compact = compact;
-                    text_content.show_timestamps = show_ts;
-                    text_content.timestamp_position = ts_pos;
+                    let mut text_content = self.text_content_for(window_def, title);
+                    let compact = text_content.compact;
+                    let streams = text_content.streams.clone();
 
                     // Pre-populate bounty window with cached data on reload
                     if window_def.name().eq_ignore_ascii_case("bounty") && self.game_state.bounty.has_data() {
@@ -1465,10 +2250,10 @@ impl AppCore {
                                 (tab.name.clone(), tab.get_streams(), show_ts, ignore, ts_pos)
                             })
                             .collect();
-                        WindowContent::TabbedText(crate::data::TabbedTextContent::new(
-                            tabs,
-                            data.buffer_size,
-                        ))
+                        let mut tabbed =
+                            crate::data::TabbedTextContent::new(tabs, data.buffer_size);
+                        tabbed.apply_alerts(&data.tabs);
+                        WindowContent::TabbedText(tabbed)
                     } else {
                         // Fallback, though this path should ideally not be taken if config is valid
                         WindowContent::TabbedText(crate::data::TabbedTextContent::new(
@@ -1560,9 +2345,15 @@ impl AppCore {
                         } else {
                             (window_def.name().to_string(), None)
                         };
+                    // Start out matching the current status, not inactive
+                    let active = self
+                        .game_state
+                        .status
+                        .indicator(&indicator_id)
+                        .unwrap_or(false);
                     WindowContent::Indicator(IndicatorData {
                         indicator_id,
-                        active: false,
+                        active,
                         color: active_color,
                     })
                 }
@@ -1626,7 +2417,7 @@ impl AppCore {
                     WindowContent::Container { container_title }
                 }
                 WidgetType::Dashboard => WindowContent::Dashboard {
-                    indicators: Vec::new(),
+                    indicators: self.game_state.status.dashboard_values(),
                 },
                 WidgetType::Perception => WindowContent::Perception(PerceptionData {
                     entries: Vec::new(),
@@ -1636,6 +2427,17 @@ impl AppCore {
                 WidgetType::Experience => WindowContent::Experience,
                 WidgetType::GS4Experience => WindowContent::GS4Experience,
                 WidgetType::Encumbrance => WindowContent::Encumbrance,
+                WidgetType::Stance => WindowContent::Stance,
+                WidgetType::Wealth => WindowContent::Wealth,
+                WidgetType::TaskTracker => WindowContent::TaskTracker,
+                WidgetType::SocietyTasks => WindowContent::SocietyTasks,
+                WidgetType::CombatStats => WindowContent::CombatStats,
+                WidgetType::Calendar => WindowContent::Calendar,
+                WidgetType::Reminders => WindowContent::Reminders,
+                WidgetType::Notes => WindowContent::Notes,
+                WidgetType::Watch => WindowContent::Watch,
+                WidgetType::Traffic => WindowContent::Traffic,
+                WidgetType::Played => WindowContent::Played,
                 WidgetType::Quickbar => WindowContent::Quickbar,
                 WidgetType::Hotkeybar => {
                     let bar = if let crate::config::WindowDef::Hotkeybar { data, .. } = window_def {
@@ -1673,10 +2475,21 @@ impl AppCore {
                 .set_window(window_def.name().to_string(), window);
         }
 
-        // Set default focused window to "main" if it exists (enables scrolling with PageUp/PageDown)
-        if self.ui_state.focused_window.is_none() {
-            if self.ui_state.windows.contains_key("main") {
-                self.ui_state.set_focus(Some("main".to_string()));
+        // Rebuild floating window stack from layout (stable sort keeps layout order for ties)
+        let mut floating: Vec<(i32, String)> = self
+            .layout
+            .windows
+            .iter()
+            .filter(|w| w.base().visible && w.base().floating)
+            .map(|w| (w.base().z_index, w.name().to_string()))
+            .collect();
+        floating.sort_by_key(|(z, _)| *z);
+        self.ui_state.floating_windows = floating.into_iter().map(|(_, name)| name).collect();
+
+        // Set default focused window to "main" if it exists (enables scrolling with PageUp/PageDown)
+        if self.ui_state.focused_window.is_none() {
+            if self.ui_state.windows.contains_key("main") {
+                self.ui_state.set_focus(Some("main".to_string()));
                 tracing::debug!("Set default focused window to 'main'");
             } else if let Some(first_name) = self.ui_state.windows.keys().next().cloned() {
                 // Fall back to first window if main doesn't exist
@@ -1708,6 +2521,7 @@ impl AppCore {
             }
         }
 
+        self.sync_tees();
         self.needs_render = true;
     }
 
@@ -1756,24 +2570,8 @@ impl AppCore {
 
         let content = match widget_type {
             WidgetType::Text => {
-                let (buffer_size, streams, compact, show_ts, ts_pos) =
-                    if let crate::config::WindowDef::Text { data, .. } = window_def {
-                        (
-                            data.buffer_size,
-                            data.streams.clone(),
-                            data.compact,
-                            data.show_timestamps,
-                            data.timestamp_position
-                                .unwrap_or(self.config.ui.timestamp_position),
-                        )
-                    } else {
-                        (1000, vec![], false, false, self.config.ui.timestamp_position)
-                    };
-                let mut text_content = TextContent::new(title, buffer_size);
-                text_content.streams = streams;
-                text_content.compact = compact;
-                text_content.show_timestamps = show_ts;
-                text_content.timestamp_position = ts_pos;
+                let mut text_content = self.text_content_for(window_def, title);
+                let compact = text_content.compact;
 
                 // For bounty windows: pre-populate with buffered bounty data if available
                 if window_def.name().eq_ignore_ascii_case("bounty") && self.game_state.bounty.has_data() {
@@ -1814,10 +2612,9 @@ impl AppCore {
                             (tab.name.clone(), tab.get_streams(), show_ts, ignore, ts_pos)
                         })
                         .collect();
-                    WindowContent::TabbedText(crate::data::TabbedTextContent::new(
-                        tabs,
-                        data.buffer_size,
-                    ))
+                    let mut tabbed = crate::data::TabbedTextContent::new(tabs, data.buffer_size);
+                    tabbed.apply_alerts(&data.tabs);
+                    WindowContent::TabbedText(tabbed)
                 } else {
                     // Fallback if window_def is wrong type
                     WindowContent::TabbedText(crate::data::TabbedTextContent::new(
@@ -1906,9 +2703,14 @@ impl AppCore {
                     } else {
                         (window_def.name().to_string(), None)
                     };
+                let active = self
+                    .game_state
+                    .status
+                    .indicator(&indicator_id)
+                    .unwrap_or(false);
                 WindowContent::Indicator(IndicatorData {
                     indicator_id,
-                    active: false,
+                    active,
                     color: active_color,
                 })
             }
@@ -1976,11 +2778,22 @@ impl AppCore {
                 WindowContent::Container { container_title }
             }
             WidgetType::Dashboard => WindowContent::Dashboard {
-                indicators: Vec::new(),
+                indicators: self.game_state.status.dashboard_values(),
             },
             WidgetType::Experience => WindowContent::Experience,
             WidgetType::GS4Experience => WindowContent::GS4Experience,
             WidgetType::Encumbrance => WindowContent::Encumbrance,
+            WidgetType::Stance => WindowContent::Stance,
+            WidgetType::Wealth => WindowContent::Wealth,
+            WidgetType::TaskTracker => WindowContent::TaskTracker,
+            WidgetType::SocietyTasks => WindowContent::SocietyTasks,
+            WidgetType::CombatStats => WindowContent::CombatStats,
+            WidgetType::Calendar => WindowContent::Calendar,
+            WidgetType::Reminders => WindowContent::Reminders,
+            WidgetType::Notes => WindowContent::Notes,
+            WidgetType::Watch => WindowContent::Watch,
+            WidgetType::Traffic => WindowContent::Traffic,
+            WidgetType::Played => WindowContent::Played,
             WidgetType::Quickbar => WindowContent::Quickbar,
             WidgetType::Hotkeybar => {
                 let bar = if let crate::config::WindowDef::Hotkeybar { data, .. } = window_def {
@@ -2123,6 +2936,7 @@ impl AppCore {
 
         // Update and return whether structural change occurred
         let changed = tabbed_content.update_tabs(new_tabs, data.buffer_size);
+        tabbed_content.apply_alerts(&data.tabs);
         if changed {
             tracing::info!("Updated tabs for window '{}'", window_name);
             // Tab streams changed - keep the routing index in sync
@@ -2145,162 +2959,180 @@ impl AppCore {
 
     /// Process incoming XML data from server
     pub fn process_server_data(&mut self, data: &str) -> Result<()> {
-        // Handle empty input (blank line from server) - "".lines() yields nothing!
-        // Network reads line-by-line, so blank lines arrive as empty strings.
-        // We must handle this explicitly since Rust's lines() returns an empty iterator for "".
-        if data.is_empty() {
-            // Parser already handles empty input: returns vec![Text { content: "" }]
-            let elements = self.parser.parse_line(data);
-            for element in elements {
-                self.process_element(&element)?;
-            }
-            self.message_processor
-                .flush_current_stream_with_tts(&mut self.ui_state, Some(&mut self.tts_manager));
+        let chunk = ParsedChunk::parse(&mut self.parser, data);
+        self.process_parsed_chunk(&chunk)
+    }
 
-            // Transfer pending sounds from MessageProcessor to GameState
-            for sound in self.message_processor.pending_sounds.drain(..) {
-                self.game_state.queue_sound(sound);
+    /// Apply a network read that has already been parsed, on this thread
+    /// (`process_server_data`) or on the parse worker.
+    pub fn process_parsed_chunk(&mut self, chunk: &ParsedChunk) -> Result<()> {
+        for (index, elements) in chunk.lines.iter().enumerate() {
+            if !chunk.blank && !elements.is_empty() {
+                self.perf_stats
+                    .record_elements_parsed(elements.len() as u64);
             }
-
-            // Attribute mapping observations to the current room uid
-            if !self.message_processor.pending_evidence.is_empty() {
-                let uid = self
-                    .nav_room_id
-                    .as_deref()
-                    .and_then(|s| s.trim().parse::<i64>().ok())
-                    .filter(|&u| u != 0);
-                for obs in self.message_processor.pending_evidence.drain(..) {
-                    if let Some(uid) = uid {
-                        self.evidence.record(
-                            uid,
-                            self.game_state.room_name.clone(),
-                            obs,
-                            self.game_state.game_time,
-                        );
-                    }
-                }
+            if self.inspector.is_enabled() {
+                use crate::core::inspector::StateSnapshot;
+                let before = StateSnapshot::capture(&self.game_state);
+                self.apply_parsed_line(elements)?;
+                let changes = before.changes(&StateSnapshot::capture(&self.game_state));
+                let raw = chunk.raw.as_ref().and_then(|raw| raw.get(index).cloned());
+                self.inspector.record(raw, elements, changes);
+            } else {
+                self.apply_parsed_line(elements)?;
             }
+        }
 
-            // A pathcode NPC spoke a route: persist it for the maze whose
-            // entrance we're standing at (works mid-.go2 or asked by hand).
-            if let Some(route) = self.message_processor.pending_pathcode.take() {
-                let maze = self
-                    .map
-                    .current_room_id
-                    .and_then(crate::core::travel::mazes::maze_at_entrance);
-                if let Some(maze) = maze {
-                    let steps = route.len();
-                    self.config.go2.pathcodes.insert(maze.name.clone(), route);
-                    if let Err(e) = self.save_config() {
-                        tracing::warn!("pathcode save failed: {e}");
-                    }
-                    self.add_system_message(&format!(
-                        "[go2] pathcode for {} captured ({steps} steps)",
-                        maze.name
-                    ));
-                } else {
-                    tracing::debug!("pathcode heard away from any maze entrance; ignored");
-                }
-            }
+        // A blank line from the server can't move the room or the walk
+        if chunk.blank {
+            return Ok(());
+        }
 
-            // Transfer bounty buffer to GameState if any
-            if let Some((raw_text, compact_lines)) = self.message_processor.take_bounty_buffer() {
-                self.game_state.bounty.update(raw_text, compact_lines);
-            }
+        self.sync_map_room();
+        // Walk executor reacts to whatever this line changed (room, RT,
+        // status); the per-frame tick covers pure time-based waits.
+        self.tick_travel();
+        self.tick_rebuff();
 
-            // Transfer society buffer to GameState if any
-            let society_lines = self.message_processor.take_society_buffer();
-            if !society_lines.is_empty() {
-                self.game_state.society.update(society_lines);
-            }
+        Ok(())
+    }
 
-            return Ok(());
+    fn apply_parsed_line(&mut self, elements: &[ParsedElement]) -> Result<()> {
+        for element in elements {
+            self.process_element(element)?;
         }
 
-        // Parse XML line by line
-        for line in data.lines() {
-            let elements = self.parser.parse_line(line);
-            if !elements.is_empty() {
-                self.perf_stats
-                    .record_elements_parsed(elements.len() as u64);
+        // Finish the current line after processing all elements from this network line
+        // This ensures newlines from the game are preserved (like VellumFE does)
+        self.message_processor
+            .flush_current_stream_with_tts(&mut self.ui_state, Some(&mut self.tts_manager));
+
+        // Transfer pending sounds from MessageProcessor to GameState
+        for sound in self.message_processor.pending_sounds.drain(..) {
+            self.game_state.queue_sound(sound);
+        }
+
+        // Attribute mapping observations to the current room uid
+        if !self.message_processor.pending_evidence.is_empty() {
+            let uid = self
+                .nav_room_id
+                .as_deref()
+                .and_then(|s| s.trim().parse::<i64>().ok())
+                .filter(|&u| u != 0);
+            for obs in self.message_processor.pending_evidence.drain(..) {
+                if let Some(uid) = uid {
+                    self.evidence.record(
+                        uid,
+                        self.game_state.room_name.clone(),
+                        obs,
+                        self.game_state.game_time,
+                    );
+                }
             }
+        }
 
-            // Process each element
-            for element in elements {
-                self.process_element(&element)?;
+        // A pathcode NPC spoke a route: persist it for the maze whose
+        // entrance we're standing at (works mid-.go2 or asked by hand).
+        if let Some(route) = self.message_processor.pending_pathcode.take() {
+            let maze = self
+                .map
+                .current_room_id
+                .and_then(crate::core::travel::mazes::maze_at_entrance);
+            if let Some(maze) = maze {
+                let steps = route.len();
+                self.config.go2.pathcodes.insert(maze.name.clone(), route);
+                if let Err(e) = self.save_config() {
+                    tracing::warn!("pathcode save failed: {e}");
+                }
+                self.add_system_message(&format!(
+                    "[go2] pathcode for {} captured ({steps} steps)",
+                    maze.name
+                ));
+            } else {
+                tracing::debug!("pathcode heard away from any maze entrance; ignored");
             }
+        }
 
-            // Finish the current line after processing all elements from this network line
-            // This ensures newlines from the game are preserved (like VellumFE does)
-            self.message_processor
-                .flush_current_stream_with_tts(&mut self.ui_state, Some(&mut self.tts_manager));
+        for event in self.message_processor.pending_silver.drain(..) {
+            self.game_state.wealth.apply(event);
+        }
 
-            // Transfer pending sounds from MessageProcessor to GameState
-            for sound in self.message_processor.pending_sounds.drain(..) {
-                self.game_state.queue_sound(sound);
+        for event in self.message_processor.pending_capacity.drain(..) {
+            if self.game_state.container_cache.apply_capacity(event) {
+                self.needs_render = true;
             }
+        }
 
-            // Attribute mapping observations to the current room uid
-            if !self.message_processor.pending_evidence.is_empty() {
-                let uid = self
-                    .nav_room_id
-                    .as_deref()
-                    .and_then(|s| s.trim().parse::<i64>().ok())
-                    .filter(|&u| u != 0);
-                for obs in self.message_processor.pending_evidence.drain(..) {
-                    if let Some(uid) = uid {
-                        self.evidence.record(
-                            uid,
-                            self.game_state.room_name.clone(),
-                            obs,
-                            self.game_state.game_time,
-                        );
-                    }
-                }
+        let now = self.game_state.game_time;
+        for event in self.message_processor.pending_combat.drain(..) {
+            use crate::core::combat::CombatEvent;
+            if matches!(event, CombatEvent::Attack(_) | CombatEvent::Incoming) {
+                self.combat_mode.note_activity(std::time::Instant::now());
             }
+            self.game_state.combat.apply(event, now);
+        }
 
-            // A pathcode NPC spoke a route: persist it for the maze whose
-            // entrance we're standing at (works mid-.go2 or asked by hand).
-            if let Some(route) = self.message_processor.pending_pathcode.take() {
-                let maze = self
-                    .map
-                    .current_room_id
-                    .and_then(crate::core::travel::mazes::maze_at_entrance);
-                if let Some(maze) = maze {
-                    let steps = route.len();
-                    self.config.go2.pathcodes.insert(maze.name.clone(), route);
-                    if let Err(e) = self.save_config() {
-                        tracing::warn!("pathcode save failed: {e}");
-                    }
-                    self.add_system_message(&format!(
-                        "[go2] pathcode for {} captured ({steps} steps)",
-                        maze.name
-                    ));
-                } else {
-                    tracing::debug!("pathcode heard away from any maze entrance; ignored");
-                }
+        let replies = std::mem::take(&mut self.message_processor.pending_reply);
+        for (event, line) in replies {
+            if let crate::core::reply::ReplyEvent::Whisper(sender) = &event {
+                self.away_whisper(sender, &line);
             }
+            self.game_state
+                .reply
+                .apply(event, self.game_state.character_name.as_deref());
+        }
 
-            // Transfer bounty buffer to GameState if any
-            if let Some((raw_text, compact_lines)) = self.message_processor.take_bounty_buffer() {
-                self.game_state.bounty.update(raw_text, compact_lines);
-            }
+        for url in self.message_processor.pending_urls.drain(..) {
+            self.game_state.urls.push(url);
+        }
+
+        if let Some(reading) = self.message_processor.pending_calendar.take() {
+            let now_server =
+                chrono::Utc::now().timestamp() + self.message_processor.server_time_offset;
+            self.game_state.calendar.sync(reading, now_server);
+        }
 
-            // Transfer society buffer to GameState if any
-            let society_lines = self.message_processor.take_society_buffer();
-            if !society_lines.is_empty() {
-                self.game_state.society.update(society_lines);
+        self.check_alarm();
+
+        // Transfer bounty buffer to GameState if any
+        if let Some((raw_text, compact_lines)) = self.message_processor.take_bounty_buffer() {
+            if self.game_state.bounty.update(raw_text, compact_lines) {
+                self.notify_bounty_complete();
             }
         }
 
-        self.sync_map_room();
-        // Walk executor reacts to whatever this line changed (room, RT,
-        // status); the per-frame tick covers pure time-based waits.
-        self.tick_travel();
+        // Transfer society buffer to GameState if any
+        let society_lines = self.message_processor.take_society_buffer();
+        if !society_lines.is_empty() {
+            let finished = self.game_state.society.update(society_lines);
+            self.notify_society_tasks_complete(&finished);
+        }
 
         Ok(())
     }
 
+    /// Move parsing of `server_rx` onto a worker thread seeded with the
+    /// current parser. The runtime drains the returned receiver and hands
+    /// each chunk to `process_parsed_chunk`.
+    pub fn start_parse_worker(
+        &mut self,
+        server_rx: tokio::sync::mpsc::Receiver<crate::network::ServerMessage>,
+    ) -> Result<tokio::sync::mpsc::Receiver<crate::parse_worker::ParsedMessage>> {
+        let (worker, parsed_rx) = ParseWorker::spawn(self.parser.clone(), server_rx)?;
+        self.parse_worker = Some(worker);
+        Ok(parsed_rx)
+    }
+
+    fn update_parser(&mut self, update: ParserUpdate) {
+        if let Some(worker) = &self.parse_worker {
+            worker.update(update.clone());
+        }
+        match update {
+            ParserUpdate::Presets(presets) => self.parser.update_presets(presets),
+            ParserUpdate::EventPatterns(patterns) => self.parser.update_event_patterns(patterns),
+        }
+    }
+
     /// Seed default quickbars when attaching without login bursts.
     /// Intended for non-direct connections where login-only data is missing.
     pub fn seed_default_quickbars_if_empty(&mut self) {
@@ -2372,96 +3204,7 @@ impl AppCore {
 
     /// Get list of available dot commands for tab completion
     pub fn get_available_commands(&self) -> Vec<String> {
-        vec![
-            // Application commands
-            ".quit".to_string(),
-            ".q".to_string(),
-            ".help".to_string(),
-            ".h".to_string(),
-            ".?".to_string(),
-            ".reload".to_string(),
-            // Layout commands
-            ".savelayout".to_string(),
-            ".loadlayout".to_string(),
-            ".layouts".to_string(),
-            ".resize".to_string(),
-            // Window management
-            ".windows".to_string(),
-            ".deletewindow".to_string(),
-            ".delwindow".to_string(),
-            ".addwindow".to_string(),
-            ".rename".to_string(),
-            ".border".to_string(),
-            ".editwindow".to_string(),
-            ".editwin".to_string(),
-            ".hidewindow".to_string(),
-            ".hidewin".to_string(),
-            // Highlight commands
-            ".highlights".to_string(),
-            ".hl".to_string(),
-            ".addhighlight".to_string(),
-            ".addhl".to_string(),
-            ".edithighlight".to_string(),
-            ".edithl".to_string(),
-            ".testline".to_string(),
-            ".savehighlights".to_string(),
-            ".savehl".to_string(),
-            ".loadhighlights".to_string(),
-            ".loadhl".to_string(),
-            ".highlightprofiles".to_string(),
-            ".hlprofiles".to_string(),
-            // Keybind commands
-            ".keybinds".to_string(),
-            ".kb".to_string(),
-            ".addkeybind".to_string(),
-            ".addkey".to_string(),
-            ".savekeybinds".to_string(),
-            ".savekb".to_string(),
-            ".loadkeybinds".to_string(),
-            ".loadkb".to_string(),
-            ".keybindprofiles".to_string(),
-            ".kbprofiles".to_string(),
-            // Color commands
-            ".colors".to_string(),
-            ".colorpalette".to_string(),
-            ".addcolor".to_string(),
-            ".createcolor".to_string(),
-            ".uicolors".to_string(),
-            ".spellcolors".to_string(),
-            ".addspellcolor".to_string(),
-            ".newspellcolor".to_string(),
-            ".setpalette".to_string(),
-            ".resetpalette".to_string(),
-            // Theme commands
-            ".themes".to_string(),
-            ".settheme".to_string(),
-            ".theme".to_string(),
-            ".edittheme".to_string(),
-            // Skin commands (GUI)
-            ".skins".to_string(),
-            ".setskin".to_string(),
-            ".skin".to_string(),
-            ".makeskin".to_string(),
-            ".reloadskin".to_string(),
-            // Tab navigation
-            ".nexttab".to_string(),
-            ".prevtab".to_string(),
-            ".gonew".to_string(),
-            ".nextunread".to_string(),
-            // Settings
-            ".settings".to_string(),
-            // Toggles
-            ".toggletransparency".to_string(),
-            ".transparency".to_string(),
-            // Window locking (toggle)
-            ".lockwindows".to_string(),
-            ".lockall".to_string(),
-            // Containers
-            ".containers".to_string(),
-            ".hidecontainers".to_string(),
-            // Menu system
-            ".menu".to_string(),
-        ]
+        crate::core::help::command_names()
     }
 
     /// Get list of window names for tab completion
@@ -2473,6 +3216,12 @@ impl AppCore {
             .collect()
     }
 
+    /// Completion words for non-dot input: common verbs plus the nouns and
+    /// player names linked in the current room.
+    pub fn get_game_completions(&self) -> Vec<String> {
+        crate::core::completion::game_words(&self.room_components)
+    }
+
     /// Get the current game type from config
     pub fn game_type(&self) -> Option<crate::config::GameType> {
         crate::config::GameType::from_game_string(self.config.connection.game.as_deref())
@@ -2508,21 +3257,26 @@ impl AppCore {
     /// Add a system message to a window that receives the "main" stream.
     /// First tries window named "main", then looks for any window subscribed to "main" stream.
     pub fn add_system_message(&mut self, message: &str) {
-        use crate::data::{SpanType, StyledLine, TextSegment, WindowContent};
+        use crate::data::{SpanType, StyledLine, TextSegment};
 
-        let line = StyledLine {
+        self.add_system_line(StyledLine {
             segments: vec![TextSegment {
                 text: message.to_string(),
-                fg: Some("#00ff00".to_string()),
+                fg: Some(crate::data::intern::intern("#00ff00")),
                 bg: None,
                 bold: true,
                 mono: false,
                 span_type: SpanType::System, // system echo; skip highlight transforms
                 link_data: None,
             }],
-            stream: String::from("main"),
+            stream: crate::data::intern::intern("main"),
             timestamp: None,
-        };
+        });
+    }
+
+    /// Add a prebuilt line (e.g. one with links) where system messages go
+    pub fn add_system_line(&mut self, line: crate::data::StyledLine) {
+        use crate::data::WindowContent;
 
         // System messages bypass the message pipeline, so mirror them to
         // remote clients explicitly (dot-command feedback, errors, ...)
@@ -2565,7 +3319,11 @@ impl AppCore {
         }
 
         // No window found - log warning
-        tracing::warn!("No window found subscribed to 'main' stream for system message: {}", message);
+        let text: String = line.segments.iter().map(|s| s.text.as_str()).collect();
+        tracing::warn!(
+            "No window found subscribed to 'main' stream for system message: {}",
+            text
+        );
     }
 
     /// Inject a test line through the complete pipeline (parser → message processor → UI)
@@ -2591,123 +3349,61 @@ impl AppCore {
         self.needs_render = true;
     }
 
-    /// Show help for dot commands
-    pub(super) fn show_help(&mut self) {
-        self.add_system_message("=== VellumFE Dot Commands ===");
-        self.add_system_message("");
-
-        // Application commands
-        self.add_system_message("APPLICATION:");
-        self.add_system_message("  .quit / .q              - Exit VellumFE");
-        self.add_system_message("  .help / .h / .?         - Show this help");
-        self.add_system_message("  .version / .ver         - Show version info");
-        self.add_system_message("  .menu                   - Open main menu");
-        self.add_system_message("  .settings               - Open settings editor");
-        self.add_system_message("  .reload [category]      - Reload config from disk (highlights|keybinds|hotbars|settings|colors)");
-        self.add_system_message("  .room                   - Show how the current room resolved against the mapdb");
-        self.add_system_message("  .mapdb [download|remove|repo <r>] - Manage downloaded map data (status by default)");
-        self.add_system_message("  .go2 <target>           - Travel there (room id, uid, tag, saved name, or text search)");
-        self.add_system_message("  .go2 stop|status        - Cancel / show the active trip");
-        self.add_system_message("  .go2 save <name> [id]   - Save a target (.go2 targets lists, .go2 back returns)");
-        self.add_system_message("");
-
-        // Layout commands
-        self.add_system_message("LAYOUTS:");
-        self.add_system_message("  .savelayout [name]      - Save current layout (default: 'default')");
-        self.add_system_message("  .loadlayout [name]      - Load a saved layout");
-        self.add_system_message("  .layouts                - List available layouts");
-        self.add_system_message("  .resize                 - Resize layout to current terminal");
-        self.add_system_message("");
-
-        // Window management
-        self.add_system_message("WINDOWS:");
-        self.add_system_message("  .windows                - List all windows");
-        self.add_system_message("  .addwindow              - Open widget type picker");
-        self.add_system_message("  .addwindow <name> <type> <x> <y> <w> [h] - Add window manually");
-        self.add_system_message("  .deletewindow <name>    - Delete a window");
-        self.add_system_message("  .delwindow <name>       - Alias for .deletewindow");
-        self.add_system_message("  .hidewindow [name]      - Hide window (or open picker)");
-        self.add_system_message("  .hidewin [name]         - Alias for .hidewindow");
-        self.add_system_message("  .editwindow [name]      - Edit window (or open picker)");
-        self.add_system_message("  .editwin [name]         - Alias for .editwindow");
-        self.add_system_message("  .rename <win> <title>   - Rename window title");
-        self.add_system_message("  .border <win> <style> [color] - Set window border");
-        self.add_system_message("    Styles: all, none, top, bottom, left, right");
-        self.add_system_message("");
-
-        // Highlights
-        self.add_system_message("HIGHLIGHTS:");
-        self.add_system_message("  .highlights / .hl       - Open highlights browser");
-        self.add_system_message("  .addhighlight / .addhl  - Create new highlight");
-        self.add_system_message("  .edithighlight <name>   - Edit existing highlight");
-        self.add_system_message("  .edithl <name>          - Alias for .edithighlight");
-        self.add_system_message("  .savehighlights [name]  - Save highlights as profile (default: 'default')");
-        self.add_system_message("  .loadhighlights [name]  - Load highlights from profile");
-        self.add_system_message("  .highlightprofiles      - List saved highlight profiles");
-        self.add_system_message("");
-
-        // Testing
-        self.add_system_message("TESTING:");
-        self.add_system_message("  .testline <text>        - Test highlights/squelch with fake game line");
-        self.add_system_message("");
-
-        // Keybinds
-        self.add_system_message("KEYBINDS:");
-        self.add_system_message("  .keybinds / .kb         - Open keybinds browser");
-        self.add_system_message("  .addkeybind / .addkey   - Create new keybind");
-        self.add_system_message("  .savekeybinds [name]    - Save keybinds as profile (default: 'default')");
-        self.add_system_message("  .loadkeybinds <name>    - Load keybinds from profile");
-        self.add_system_message("  .keybindprofiles        - List saved keybind profiles");
-        self.add_system_message("");
-
-        // Hotbars
-        self.add_system_message("HOTBARS:");
-        self.add_system_message("  .hotbars / .hotbar      - Open hotbar editor (bars of command buttons)");
-        self.add_system_message("    Add a bar to a layout with a 'hotkeybar' window (.addwindow)");
-        self.add_system_message("");
-
-        // Colors
-        self.add_system_message("COLORS:");
-        self.add_system_message("  .colors / .colorpalette - Open color palette browser");
-        self.add_system_message("  .addcolor / .createcolor - Create new palette color");
-        self.add_system_message("  .uicolors               - Open UI colors browser");
-        self.add_system_message("  .spellcolors            - Open spell colors browser");
-        self.add_system_message("  .addspellcolor          - Create new spell color");
-        self.add_system_message("  .newspellcolor          - Alias for .addspellcolor");
-        self.add_system_message("  .setpalette             - Load palette colors into terminal");
-        self.add_system_message("  .resetpalette           - Reset terminal palette to defaults");
-        self.add_system_message("");
-
-        // Themes
-        self.add_system_message("THEMES:");
-        self.add_system_message("  .themes                 - Open themes browser");
-        self.add_system_message("  .settheme <name>        - Switch to a theme");
-        self.add_system_message("  .theme <name>           - Alias for .settheme");
-        self.add_system_message("  .edittheme              - Edit current theme");
-        self.add_system_message("  .skins                  - List installed GUI skins");
-        self.add_system_message("  .setskin <name>         - Activate a GUI skin (.setskin none to disable)");
-        self.add_system_message("  .skin <name>            - Alias for .setskin");
-        self.add_system_message("  .makeskin <name>        - Create a starter skin to edit");
-        self.add_system_message("  .reloadskin             - Reload the active skin's images");
-        self.add_system_message("");
-
-        // Tab navigation
-        self.add_system_message("TAB NAVIGATION:");
-        self.add_system_message("  .nexttab                - Switch to next tab");
-        self.add_system_message("  .prevtab                - Switch to previous tab");
-        self.add_system_message("  .gonew / .nextunread    - Jump to next tab with unread messages");
-        self.add_system_message("");
-
-        // Toggles
-        self.add_system_message("TOGGLES:");
-        self.add_system_message("");
-
-        // Window locking
-        self.add_system_message("WINDOW LOCKING:");
-        self.add_system_message("  .lockwindows / .lockall - Toggle lock on all windows (prevent move/resize)");
-        self.add_system_message("");
-
-        self.add_system_message("Type the command name for more details. Example: .help windows");
+    /// The `.help [topic]` page, for the popup viewer
+    pub fn help_page(&self, topic: &str) -> crate::core::help::HelpPage {
+        crate::core::help::page(topic, &self.config)
+    }
+
+    /// Print the `.help [topic]` page as system messages, for frontends
+    /// without the popup viewer. An unmatched topic prints just the lines
+    /// that mention it.
+    pub fn print_help(&mut self, topic: &str) {
+        let page = self.help_page(topic);
+        let needle = page.search.as_deref().map(str::to_lowercase);
+        let lines: Vec<String> = page
+            .lines
+            .iter()
+            .map(|line| {
+                line.segments
+                    .iter()
+                    .map(|seg| seg.text.as_str())
+                    .collect::<String>()
+            })
+            .filter(|text| {
+                needle
+                    .as_deref()
+                    .is_none_or(|needle| text.to_lowercase().contains(needle))
+            })
+            .collect();
+        if lines.is_empty() {
+            self.add_system_message(&format!(
+                "No help for '{}'. Type .help for everything.",
+                topic
+            ));
+            return;
+        }
+        self.add_system_message(&format!("=== VellumFE {} ===", page.title));
+        for line in lines {
+            self.add_system_message(&line);
+        }
+    }
+
+    /// The `.inspect [filter]` page, for the popup viewer
+    pub fn inspect_page(&self, filter: &str) -> (String, Vec<crate::data::StyledLine>) {
+        let filter = Some(filter.trim()).filter(|f| !f.is_empty());
+        self.inspector.page(filter, None)
+    }
+
+    /// Print the newest `.inspect [filter]` entries as system messages, for
+    /// frontends without the popup viewer
+    pub fn print_inspector(&mut self, filter: &str) {
+        let filter = Some(filter.trim()).filter(|f| !f.is_empty());
+        let (title, lines) = self.inspector.page(filter, Some(10));
+        self.add_system_message(&format!("=== {} (newest 10) ===", title));
+        for line in lines {
+            let text: String = line.segments.iter().map(|seg| seg.text.as_str()).collect();
+            self.add_system_message(&text);
+        }
     }
 
     /// Show version information
@@ -2799,6 +3495,11 @@ impl AppCore {
                 base.rows = clamped_height;
                 base.cols = clamped_width;
 
+                // Capture floating state and stacking order
+                let z_index = self.ui_state.floating_z_index(&window_name);
+                base.floating = z_index.is_some();
+                base.z_index = z_index.unwrap_or(0);
+
                 tracing::debug!(
                     "Window '{}' AFTER capture: pos=({},{}) size={}x{}",
                     window_name,
@@ -2824,96 +3525,528 @@ impl AppCore {
             }
         };
 
-        tracing::info!("Saving layout to: {}", layout_path.display());
+        tracing::info!("Saving layout to: {}", layout_path.display());
+
+        // Pass actual terminal size with force=true so it always updates to current terminal size
+        self.layout.theme = Some(self.config.active_theme.clone());
+        match self
+            .layout
+            .save(name, Some((terminal_width, terminal_height)), true)
+        {
+            Ok(_) => {
+                tracing::info!(
+                    "Layout '{}' saved successfully to {}",
+                    name,
+                    layout_path.display()
+                );
+                tracing::info!("========== SAVE LAYOUT: '{}' SUCCESS ==========", name);
+                self.add_system_message(&format!("Layout saved as '{}'", name));
+                // Clear modified flag and update base layout name
+                self.layout_modified_since_save = false;
+                self.base_layout_name = Some(name.to_string());
+            }
+            Err(e) => {
+                tracing::error!("Failed to save layout '{}': {}", name, e);
+                tracing::info!("========== SAVE LAYOUT: '{}' FAILED ==========", name);
+                self.add_system_message(&format!("Failed to save layout: {}", e));
+            }
+        }
+    }
+
+    /// Load a saved layout and update window positions/configs
+    ///
+    /// Loads layout at exact positions specified in file.
+    /// Use .resize command for delta-based proportional scaling after loading.
+
+    /// Resize all windows proportionally based on current terminal size (VellumFE algorithm)
+    ///
+    /// This command resets to the baseline layout and applies delta-based proportional distribution.
+    /// This is the ONLY place (besides initial load) that should perform scaling operations.
+
+    /// Helper to get minimum widget size based on widget type (from VellumFE)
+
+
+    /// Apply proportional height resize (from VellumFE apply_height_resize)
+    /// Adapted for WindowDef enum structure
+
+    /// Apply proportional width resize (from VellumFE apply_width_resize)
+    /// Adapted for WindowDef enum structure
+    /// baseline_rows: Vec of (name, baseline_row, baseline_rows) for grouping windows by original row
+
+    /// Sync layout WindowDefs to ui_state WindowStates without destroying content
+    ///
+    /// Uses exact positions from layout file.
+    /// Use .resize command for delta-based proportional scaling.
+
+    /// Load a saved layout with terminal size for immediate reinitialization
+
+    /// List all saved layouts
+
+    /// Resize layout using delta-based proportional distribution
+    /// This method is called by the .resize command and requires manual invocation
+
+    /// Wrapper for resize command - gets terminal size from layout
+
+    /// List all windows
+    pub(super) fn list_windows(&mut self) {
+        let window_count = self.ui_state.windows.len();
+
+        // Collect window info first to avoid borrow checker issues
+        let mut window_info = Vec::new();
+        for (name, window) in &self.ui_state.windows {
+            let pos = &window.position;
+            let visible = if window.visible { "visible" } else { "hidden" };
+            window_info.push(format!(
+                "  {} - {}x{} at ({},{}) - {} - {}",
+                name,
+                pos.width,
+                pos.height,
+                pos.x,
+                pos.y,
+                visible,
+                format!("{:?}", window.widget_type)
+            ));
+        }
+
+        // Now add all messages
+        self.add_system_message(&format!("=== Windows ({}) ===", window_count));
+        for info in window_info {
+            self.add_system_message(&info);
+        }
+    }
+
+    /// Lines for the popup viewer: "last" is the most recent output block,
+    /// anything else names a text window whose buffer is snapshotted.
+    /// Returns (title, lines), or None if there is nothing to show.
+    pub fn viewer_content(&self, source: &str) -> Option<(String, Vec<crate::data::StyledLine>)> {
+        if source.eq_ignore_ascii_case("last") {
+            let lines = self.message_processor.last_output_block();
+            if lines.is_empty() {
+                return None;
+            }
+            return Some(("Last output".to_string(), lines.to_vec()));
+        }
+
+        let window = self.ui_state.get_window(source)?;
+        match &window.content {
+            WindowContent::Text(content) if !content.lines.is_empty() => Some((
+                content.title.clone(),
+                content.lines.iter().cloned().collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Open the log `.viewlog [date]` names for the popup viewer
+    pub fn open_log_viewer(
+        &self,
+        date: Option<&str>,
+    ) -> anyhow::Result<(String, crate::core::log_viewer::LogPager)> {
+        use crate::core::log_viewer::{find_log, LogKind, LogPager};
+        let (kind, path) = find_log(&self.config, date)?;
+        let title = match kind {
+            LogKind::Diagnostics => "Diagnostics log".to_string(),
+            LogKind::Session => format!(
+                "Session log {}",
+                path.file_stem().unwrap_or_default().to_string_lossy()
+            ),
+        };
+        let highlights = crate::core::highlight_engine::CoreHighlightEngine::new(
+            self.config.active_highlights(),
+        );
+        Ok((title, LogPager::open(kind, &path, highlights)?))
+    }
+
+    /// Make a window float above the tiled layout (or return it to the layout).
+    /// `floating` of None toggles the current state.
+    pub fn set_window_floating(&mut self, name: &str, floating: Option<bool>) {
+        if !self.ui_state.windows.contains_key(name) {
+            self.add_system_message(&format!("Window '{}' not found", name));
+            return;
+        }
+
+        let floating = floating.unwrap_or(!self.ui_state.is_floating(name));
+        self.ui_state.set_floating(name, floating);
+        if floating {
+            // Bring hidden floating windows back so the new one is actually visible
+            self.ui_state.floating_hidden = false;
+        }
+
+        let z_index = self.ui_state.floating_z_index(name);
+        if let Some(window_def) = self.layout.windows.iter_mut().find(|w| w.name() == name) {
+            let base = window_def.base_mut();
+            base.floating = floating;
+            base.z_index = z_index.unwrap_or(0);
+        }
+
+        if floating {
+            self.add_system_message(&format!("Window '{}' is now floating", name));
+        } else {
+            self.add_system_message(&format!("Window '{}' returned to layout", name));
+        }
+        self.mark_layout_modified();
+        self.needs_render = true;
+    }
+
+    /// Bring a floating window to the top of the stack
+    pub fn raise_window(&mut self, name: &str) {
+        if self.ui_state.raise_window(name) {
+            self.needs_render = true;
+        } else {
+            self.add_system_message(&format!("Window '{}' is not floating", name));
+        }
+    }
+
+    /// Re-open the per-window tee files from the layout's `tee_file`
+    /// settings. Files already open for the same window stay open.
+    pub fn sync_tees(&mut self) {
+        let tee_dir = match self.config.tee_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                tracing::warn!("No tee directory: {:#}", e);
+                return;
+            }
+        };
+        let mut targets = Vec::new();
+        let mut failures = Vec::new();
+        for window_def in &self.layout.windows {
+            let resolved = match self.tee_overrides.get(window_def.name()) {
+                Some(file) => Ok(crate::core::tee::resolve_path(file, &tee_dir)),
+                None => match window_def.base().tee_file.as_deref() {
+                    Some(file) => crate::core::tee::resolve_layout_path(file, &tee_dir),
+                    None => continue,
+                },
+            };
+            match resolved {
+                Ok(path) => targets.push((window_def.name().to_string(), path)),
+                Err(e) => failures.push((window_def.name().to_string(), format!("{:#}", e))),
+            }
+        }
+        failures.extend(self.message_processor.set_tees(targets));
+        for (window, error) in failures {
+            self.add_system_message(&format!("Tee for '{}' is off: {}", window, error));
+        }
+    }
+
+    /// `.tee [<window> [<file>|off]]`: list the teed windows, show one, or
+    /// start/stop teeing a window's lines to a file
+    pub fn tee_command(&mut self, args: &str) {
+        let (window, file) = args
+            .split_once(char::is_whitespace)
+            .map(|(window, file)| (window, file.trim()))
+            .unwrap_or((args, ""));
+        if window.is_empty() {
+            let teed: Vec<(String, String)> = self
+                .layout
+                .windows
+                .iter()
+                .filter_map(|w| {
+                    let path = self.message_processor.tee_path(w.name())?;
+                    Some((w.name().to_string(), path.display().to_string()))
+                })
+                .collect();
+            if teed.is_empty() {
+                self.add_system_message("No windows are teed (use .tee <window> <file>)");
+            } else {
+                self.add_system_message(&format!("=== Tees ({}) ===", teed.len()));
+                for (name, path) in teed {
+                    self.add_system_message(&format!("  {} -> {}", name, path));
+                }
+            }
+            return;
+        }
+
+        let Some(window_def) = self.layout.get_window(window) else {
+            self.add_system_message(&format!("Window '{}' not found", window));
+            return;
+        };
+        if !matches!(
+            window_def,
+            crate::config::WindowDef::Text { .. } | crate::config::WindowDef::TabbedText { .. }
+        ) {
+            let message = format!(
+                "Only text and tabbed text windows can be teed ('{}' is {})",
+                window,
+                window_def.widget_type()
+            );
+            self.add_system_message(&message);
+            return;
+        }
+        if file.is_empty() {
+            let status = match self.message_processor.tee_path(window) {
+                Some(path) => format!("Window '{}' tees to {}", window, path.display()),
+                None => format!("Window '{}' is not teed", window),
+            };
+            self.add_system_message(&status);
+            return;
+        }
+
+        let off = matches!(file.to_lowercase().as_str(), "off" | "none");
+        // Paths outside the tee folder can't go in the layout; they last
+        // for this session
+        let session_only = !off && !crate::core::tee::is_contained(file);
+        if session_only {
+            self.tee_overrides
+                .insert(window.to_string(), file.to_string());
+        } else {
+            self.tee_overrides.remove(window);
+            if let Some(window_def) = self.layout.windows.iter_mut().find(|w| w.name() == window) {
+                window_def.base_mut().tee_file = (!off).then(|| file.to_string());
+            }
+            self.mark_layout_modified();
+        }
+        self.sync_tees();
+        match self.message_processor.tee_path(window) {
+            Some(path) => {
+                let path = path.display().to_string();
+                let note = if session_only {
+                    " (this session only; layouts can only tee into the tee folder)"
+                } else {
+                    ""
+                };
+                self.add_system_message(&format!("Teeing window '{}' to {}{}", window, path, note));
+            }
+            None if off => self.add_system_message(&format!("Stopped teeing window '{}'", window)),
+            // sync_tees already reported why the file could not be opened
+            None => {}
+        }
+    }
+
+    /// Show or hide all floating windows at once
+    pub fn toggle_floating_windows(&mut self) {
+        if self.ui_state.floating_windows.is_empty() {
+            self.add_system_message("No floating windows (use .float <window>)");
+            return;
+        }
+        self.ui_state.floating_hidden = !self.ui_state.floating_hidden;
+        let status = if self.ui_state.floating_hidden {
+            "hidden"
+        } else {
+            "shown"
+        };
+        self.add_system_message(&format!("Floating windows {}", status));
+        self.needs_render = true;
+    }
+
+    /// Text windows available as compact-mode tabs, in layout order
+    pub fn compact_tabs(&self) -> Vec<String> {
+        let order: Vec<&str> = self.layout.windows.iter().map(|w| w.name()).collect();
+        self.ui_state.compact_tab_names(&order)
+    }
 
-        // Pass actual terminal size with force=true so it always updates to current terminal size
-        self.layout.theme = Some(self.config.active_theme.clone());
-        match self
-            .layout
-            .save(name, Some((terminal_width, terminal_height)), true)
-        {
-            Ok(_) => {
-                tracing::info!(
-                    "Layout '{}' saved successfully to {}",
-                    name,
-                    layout_path.display()
-                );
-                tracing::info!("========== SAVE LAYOUT: '{}' SUCCESS ==========", name);
-                self.add_system_message(&format!("Layout saved as '{}'", name));
-                // Clear modified flag and update base layout name
-                self.layout_modified_since_save = false;
-                self.base_layout_name = Some(name.to_string());
-            }
-            Err(e) => {
-                tracing::error!("Failed to save layout '{}': {}", name, e);
-                tracing::info!("========== SAVE LAYOUT: '{}' FAILED ==========", name);
-                self.add_system_message(&format!("Failed to save layout: {}", e));
+    /// Switch compact mode on or off (None toggles)
+    pub fn set_compact_mode(&mut self, enabled: Option<bool>) {
+        let enabled = enabled.unwrap_or(!self.ui_state.compact_mode);
+        self.ui_state.compact_mode = enabled;
+        if enabled {
+            let tabs = self.compact_tabs();
+            if let Some(active) = self.ui_state.compact_active_tab(&tabs).cloned() {
+                self.ui_state.set_focus(Some(active));
             }
+            self.add_system_message(
+                "Compact mode on (.compact <window> or next_tab/prev_tab to switch, .compact off to leave)",
+            );
+        } else {
+            self.add_system_message("Compact mode off");
         }
+        self.needs_render = true;
     }
 
-    /// Load a saved layout and update window positions/configs
-    ///
-    /// Loads layout at exact positions specified in file.
-    /// Use .resize command for delta-based proportional scaling after loading.
-
-    /// Resize all windows proportionally based on current terminal size (VellumFE algorithm)
-    ///
-    /// This command resets to the baseline layout and applies delta-based proportional distribution.
-    /// This is the ONLY place (besides initial load) that should perform scaling operations.
+    /// Show a text window as the compact tab. Returns false if it isn't one.
+    pub fn select_compact_tab(&mut self, name: &str) -> bool {
+        if !self.compact_tabs().iter().any(|tab| tab == name) {
+            return false;
+        }
+        self.ui_state.compact_tab = Some(name.to_string());
+        self.ui_state.set_focus(Some(name.to_string()));
+        self.needs_render = true;
+        true
+    }
 
-    /// Helper to get minimum widget size based on widget type (from VellumFE)
+    /// Step through compact tabs (wrapping); `delta` is +1/-1
+    pub fn cycle_compact_tab(&mut self, delta: isize) {
+        let tabs = self.compact_tabs();
+        if tabs.is_empty() {
+            return;
+        }
+        let current = self
+            .ui_state
+            .compact_active_tab(&tabs)
+            .and_then(|active| tabs.iter().position(|tab| tab == active))
+            .unwrap_or(0);
+        let next = (current as isize + delta).rem_euclid(tabs.len() as isize) as usize;
+        let name = tabs[next].clone();
+        self.select_compact_tab(&name);
+    }
 
+    /// Title line for compact mode: character, vitals, and RT/CT countdowns
+    pub fn compact_title(&self) -> String {
+        let vitals = &self.game_state.vitals;
+        let mut title = format!(
+            "HP {}%  MP {}%  SP {}%  ST {}%",
+            vitals.health, vitals.mana, vitals.spirit, vitals.stamina
+        );
+        if let Some(name) = self.game_state.character_name.as_deref() {
+            title = format!("{}  {}", name, title);
+        }
+        let now = chrono::Utc::now().timestamp() + self.message_processor.server_time_offset;
+        if let Some(end) = self.game_state.roundtime_end.filter(|end| *end > now) {
+            title.push_str(&format!("  RT {}", end - now));
+        }
+        if let Some(end) = self.game_state.casttime_end.filter(|end| *end > now) {
+            title.push_str(&format!("  CT {}", end - now));
+        }
+        title
+    }
 
-    /// Apply proportional height resize (from VellumFE apply_height_resize)
-    /// Adapted for WindowDef enum structure
+    /// Record a window focus change from the frontend and re-scale sound
+    /// playback for it (`sound.play_when`, `sound.unfocused_volume`).
+    pub fn set_app_focused(&mut self, focused: bool) {
+        if self.app_focused == focused {
+            return;
+        }
+        self.app_focused = focused;
+        tracing::debug!("Window focus {}", if focused { "gained" } else { "lost" });
+        self.apply_focus_audio();
+    }
 
-    /// Apply proportional width resize (from VellumFE apply_width_resize)
-    /// Adapted for WindowDef enum structure
-    /// baseline_rows: Vec of (name, baseline_row, baseline_rows) for grouping windows by original row
+    fn apply_focus_audio(&mut self) {
+        let sound = &self.config.sound;
+        let gain = if !sound.play_when.allows(self.app_focused) {
+            0.0
+        } else if self.app_focused {
+            1.0
+        } else {
+            sound.unfocused_volume
+        };
+        if let Some(player) = self.sound_player.as_mut() {
+            player.set_focus_gain(gain);
+        }
+    }
 
-    /// Sync layout WindowDefs to ui_state WindowStates without destroying content
-    ///
-    /// Uses exact positions from layout file.
-    /// Use .resize command for delta-based proportional scaling.
+    /// Boss key: blank the screen and silence sounds/TTS, or restore.
+    /// Deliberately posts no system message - nothing should appear on screen.
+    pub fn toggle_boss_mode(&mut self) {
+        let enable = !self.ui_state.boss_mode;
+        self.ui_state.boss_mode = enable;
+        if let Some(player) = self.sound_player.as_mut() {
+            player.set_muted(enable);
+        }
+        if enable {
+            self.boss_tts_was_muted = self.tts_manager.is_muted();
+            self.tts_manager.set_muted(true);
+        } else {
+            self.tts_manager.set_muted(self.boss_tts_was_muted);
+        }
+        self.needs_render = true;
+        tracing::info!("Boss key {}", if enable { "engaged" } else { "released" });
+    }
 
-    /// Load a saved layout with terminal size for immediate reinitialization
+    /// Route a bracketed paste into the command input. Multi-line pastes of
+    /// `ui.paste_confirm_lines` or more are held for confirmation instead of
+    /// flooding the game with every line.
+    pub fn route_paste(&mut self, text: &str) -> PasteAction {
+        let lines: Vec<String> = text
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect();
+        if lines.len() <= 1 {
+            return PasteAction::Insert(lines.into_iter().next().unwrap_or_default());
+        }
+        let threshold = self.config.ui.paste_confirm_lines;
+        if threshold == 0 || lines.len() < threshold {
+            let count = lines.len();
+            self.queued_commands.extend(lines);
+            return PasteAction::Sent(count);
+        }
+        self.ui_state.paste_guard = Some(PasteGuard::new(lines));
+        self.needs_render = true;
+        PasteAction::Confirm
+    }
 
-    /// List all saved layouts
+    /// Close the paste confirmation, queueing whatever `choice` sends.
+    pub fn resolve_paste_guard(&mut self, choice: PasteChoice) {
+        let Some(guard) = self.ui_state.paste_guard.take() else {
+            return;
+        };
+        match choice {
+            PasteChoice::SendAll => self.queued_commands.extend(guard.lines),
+            PasteChoice::SendFirst => self.queued_commands.extend(guard.lines.into_iter().take(1)),
+            PasteChoice::Cancel => {
+                self.add_system_message(&format!("Paste of {} lines cancelled", guard.lines.len()))
+            }
+        }
+        self.needs_render = true;
+    }
 
-    /// Resize layout using delta-based proportional distribution
-    /// This method is called by the .resize command and requires manual invocation
+    /// Hold a command that matched a lockout pattern until the user answers
+    /// the y/n popup. A command already waiting is dropped.
+    pub(super) fn hold_for_confirmation(&mut self, command: String, pattern: String) {
+        if let Some(previous) = self.ui_state.lockout_prompt.take() {
+            self.add_system_message(&format!("[lockout] not sent: {}", previous.command));
+        }
+        self.ui_state.lockout_prompt = Some(LockoutPrompt { command, pattern });
+        self.needs_render = true;
+    }
 
-    /// Wrapper for resize command - gets terminal size from layout
+    /// Close the lockout popup, queueing the held command on yes.
+    pub fn resolve_lockout(&mut self, send: bool) {
+        let Some(prompt) = self.ui_state.lockout_prompt.take() else {
+            return;
+        };
+        if send {
+            self.command_lockout.approve(prompt.command.clone());
+            self.queued_commands.push(prompt.command);
+        } else {
+            self.add_system_message(&format!("[lockout] not sent: {}", prompt.command));
+        }
+        self.needs_render = true;
+    }
 
-    /// List all windows
-    pub(super) fn list_windows(&mut self) {
-        let window_count = self.ui_state.windows.len();
+    /// Whether the next command answers a game password prompt and will be
+    /// masked (core::secrets). Frontends keep it out of input history.
+    pub fn expects_secret(&self) -> bool {
+        self.config.ui.mask_secrets && self.message_processor.password_prompt
+    }
 
-        // Collect window info first to avoid borrow checker issues
-        let mut window_info = Vec::new();
-        for (name, window) in &self.ui_state.windows {
-            let pos = &window.position;
-            let visible = if window.visible { "visible" } else { "hidden" };
-            window_info.push(format!(
-                "  {} - {}x{} at ({},{}) - {} - {}",
-                name,
-                pos.width,
-                pos.height,
-                pos.x,
-                pos.y,
-                visible,
-                format!("{:?}", window.widget_type)
-            ));
+    /// Misspelled words in command input text; empty unless `[spellcheck]`
+    /// is enabled and the text is a spoken command.
+    pub fn spell_errors(&self, input: &str) -> Vec<crate::core::spellcheck::Misspelling> {
+        if !self.config.spellcheck.enabled {
+            return Vec::new();
         }
+        self.spellchecker
+            .check(input, &self.config.spellcheck.verbs)
+    }
 
-        // Now add all messages
-        self.add_system_message(&format!("=== Windows ({}) ===", window_count));
-        for info in window_info {
-            self.add_system_message(&info);
+    /// Add a word to the personal spellcheck dictionary.
+    pub fn add_to_dictionary(&mut self, word: &str) {
+        match self.spellchecker.add_word(word) {
+            Ok(true) => self.add_system_message(&format!("Added '{}' to your dictionary", word)),
+            Ok(false) => {}
+            Err(e) => self.add_system_message(&format!("Failed to save dictionary: {}", e)),
         }
     }
 
+    /// Send a command picked from the command log palette. It goes out
+    /// through `send_command` like anything typed, so it is logged again.
+    pub fn resend_command(&mut self, command: &str) {
+        self.queued_commands.push(command.to_string());
+        self.needs_render = true;
+    }
+
+    /// Queue the text from the compose popup as commands (see
+    /// `compose_commands`). Returns how many commands were queued.
+    pub fn submit_compose(&mut self, text: &str) -> usize {
+        let commands = crate::data::compose_commands(text);
+        let count = commands.len();
+        self.queued_commands.extend(commands);
+        self.needs_render = true;
+        count
+    }
+
     /// Hide a window (keep in layout for persistence, remove from UI)
     pub fn hide_window(&mut self, name: &str) {
         if name == "main" {
@@ -3264,6 +4397,17 @@ impl AppCore {
             WidgetType::Experience => WindowContent::Experience,
             WidgetType::GS4Experience => WindowContent::GS4Experience,
             WidgetType::Encumbrance => WindowContent::Encumbrance,
+            WidgetType::Stance => WindowContent::Stance,
+            WidgetType::Wealth => WindowContent::Wealth,
+            WidgetType::TaskTracker => WindowContent::TaskTracker,
+            WidgetType::SocietyTasks => WindowContent::SocietyTasks,
+            WidgetType::CombatStats => WindowContent::CombatStats,
+            WidgetType::Calendar => WindowContent::Calendar,
+            WidgetType::Reminders => WindowContent::Reminders,
+            WidgetType::Notes => WindowContent::Notes,
+            WidgetType::Watch => WindowContent::Watch,
+            WidgetType::Traffic => WindowContent::Traffic,
+            WidgetType::Played => WindowContent::Played,
             WidgetType::MiniVitals => WindowContent::MiniVitals,
             WidgetType::Betrayer => WindowContent::Betrayer,
             // A dot-command-created hotkeybar binds to the bar with the
@@ -3347,6 +4491,10 @@ impl AppCore {
             max_cols: None,
             visible: true,
             content_align: None,
+            floating: false,
+            z_index: 0,
+            tee_file: None,
+            show_when: None,
         };
 
         let window_def = match widget_type_str.to_lowercase().as_str() {
@@ -3359,6 +4507,9 @@ impl AppCore {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             },
             "room" => WindowDef::Room {
@@ -3393,6 +4544,9 @@ impl AppCore {
                         show_timestamps: false,
                         timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                     },
                 }
             }
@@ -3496,6 +4650,10 @@ impl AppCore {
             max_cols: None,
             visible: true,
             content_align: None,
+            floating: false,
+            z_index: 0,
+            tee_file: None,
+            show_when: None,
         };
         self.layout.windows.insert(
             0,
@@ -3869,6 +5027,11 @@ impl AppCore {
                 command: "menu:hidewindow".to_string(),
                 disabled: false,
             },
+            crate::data::ui_state::PopupMenuItem {
+                text: "Paste window".to_string(),
+                command: ".pastewindow".to_string(),
+                disabled: false,
+            },
             crate::data::ui_state::PopupMenuItem {
                 text: "List windows >".to_string(),
                 command: ".windows".to_string(),
@@ -4277,6 +5440,7 @@ impl AppCore {
     /// Mark layout as modified and show reminder (once per session)
     pub fn mark_layout_modified(&mut self) {
         self.layout_modified_since_save = true;
+        self.record_layout_edit();
 
         // Show reminder once per session
         if !self.save_reminder_shown {
@@ -4403,6 +5567,11 @@ impl AppCore {
             }
         }
 
+        // Credit the session up to now
+        self.played
+            .tick(self.game_state.connected, chrono::Local::now());
+        self.write_played();
+
         let allowed_ids = self.allowed_quickbar_ids();
         let quickbars: HashMap<String, QuickbarData> = self
             .ui_state
@@ -4520,8 +5689,9 @@ impl AppCore {
         match crate::config::Config::load_keybinds(self.config.character.as_deref()) {
             Ok(keybinds) => {
                 self.config.keybinds = keybinds;
-                // Rebuild keybind map for O(1) lookups (re-merges hotbar keys)
-                self.rebuild_keybind_map();
+                // Rebuild keybind map for O(1) lookups (re-merges hotbar
+                // keys), re-reading the layout's keybind profile too
+                self.sync_keybind_profile();
                 self.add_system_message("Keybinds reloaded");
             }
             Err(e) => {
@@ -4569,10 +5739,12 @@ impl AppCore {
                         self.config.connection = new_config.connection;
                         self.config.ui = new_config.ui;
                         self.config.sound = new_config.sound;
+                        self.apply_focus_audio();
                         self.config.event_patterns = new_config.event_patterns;
                         self.config.layout_mappings = new_config.layout_mappings;
-                        self.parser
-                            .update_event_patterns(self.config.event_patterns.clone());
+                        self.update_parser(ParserUpdate::EventPatterns(
+                            self.config.event_patterns.clone(),
+                        ));
                         self.message_processor.apply_config(self.config.clone());
                         self.add_system_message("Settings reloaded");
                     }
@@ -4610,7 +5782,7 @@ impl AppCore {
                         (id.clone(), resolved_fg, resolved_bg)
                     })
                     .collect();
-                self.parser.update_presets(presets);
+                self.update_parser(ParserUpdate::Presets(presets));
                 self.message_processor.apply_config(self.config.clone());
                 self.add_system_message("Colors reloaded");
             }
@@ -4620,6 +5792,46 @@ impl AppCore {
         }
     }
 
+    /// Reverse a browser delete: write the entry back to the file it came
+    /// from and refresh the live config to match.
+    pub fn undo_browser_edit(&mut self, edit: &crate::core::undo::BrowserEdit) -> Result<()> {
+        use crate::core::undo::BrowserEdit;
+        let character = self.config.character.clone();
+        match edit {
+            BrowserEdit::DeletedHighlight {
+                name,
+                pattern,
+                is_global,
+            } => {
+                Config::save_single_highlight(name, pattern, *is_global, character.as_deref())?;
+                self.config
+                    .highlights
+                    .insert(name.clone(), pattern.as_ref().clone());
+                Config::compile_highlight_patterns(&mut self.config.highlights);
+                self.message_processor.apply_config(self.config.clone());
+            }
+            BrowserEdit::DeletedKeybind {
+                key,
+                action,
+                is_global,
+            } => {
+                Config::save_single_keybind(key, action, *is_global, character.as_deref())?;
+                self.config.keybinds.insert(key.clone(), action.clone());
+                self.rebuild_keybind_map();
+            }
+            BrowserEdit::DeletedColor { color, is_global } => {
+                crate::config::ColorConfig::save_single_palette_color(
+                    color,
+                    *is_global,
+                    character.as_deref(),
+                )?;
+                self.config.colors =
+                    crate::config::ColorConfig::load_with_merge(character.as_deref())?;
+            }
+        }
+        Ok(())
+    }
+
     /// Reload layout from the auto-saved layout.toml file
     ///
     /// This reloads the character's layout from ~/.vellum-fe/{character}/layout.toml
@@ -4651,7 +5863,9 @@ impl AppCore {
 
                 // Update layout and baseline
                 self.layout = new_layout.clone();
+                self.layout_history.reset(new_layout.clone());
                 self.baseline_layout = Some(new_layout);
+                self.sync_keybind_profile();
 
                 // Clear modified flag
                 self.layout_modified_since_save = false;
@@ -4754,6 +5968,268 @@ impl AppCore {
             .collect()
     }
 
+    /// Right-click menu for a window: actions on its text for text and
+    /// tabbed windows (the active tab of a tabbed one), then the layout
+    /// actions every window has
+    pub fn build_window_context_menu(
+        &self,
+        name: &str,
+    ) -> Vec<crate::data::ui_state::PopupMenuItem> {
+        let Some(window) = self.ui_state.windows.get(name) else {
+            return Vec::new();
+        };
+        let item = |text: &str, command: String, disabled: bool| {
+            crate::data::ui_state::PopupMenuItem {
+                text: text.to_string(),
+                command,
+                disabled,
+            }
+        };
+        let timestamps = |shown: bool| {
+            if shown {
+                "Hide Timestamps"
+            } else {
+                "Show Timestamps"
+            }
+        };
+        let freeze = if self.is_window_frozen(name) {
+            "Unfreeze"
+        } else {
+            "Freeze"
+        };
+        let essential = name == "main" || name == "command_input";
+
+        let mut items = Vec::new();
+        match &window.content {
+            WindowContent::Text(content) => {
+                let empty = content.lines.is_empty();
+                items.push(item(
+                    "Search Here",
+                    format!("action:searchwindow:{}", name),
+                    empty,
+                ));
+                items.push(item(
+                    "View in Popup",
+                    format!("action:view:{}", name),
+                    empty,
+                ));
+                items.push(item(
+                    "Export to HTML",
+                    format!(
+                        ".export {} {}-{}.html",
+                        name,
+                        name,
+                        chrono::Local::now().format("%Y%m%d-%H%M%S")
+                    ),
+                    empty,
+                ));
+                items.push(item(
+                    timestamps(content.show_timestamps),
+                    format!("action:timestamps:{}", name),
+                    false,
+                ));
+                items.push(item(
+                    "Clear Buffer",
+                    format!("action:clearwindow:{}", name),
+                    empty,
+                ));
+                items.push(item(freeze, format!(".freeze {}", name), false));
+            }
+            WindowContent::TabbedText(tabbed) => {
+                if let Some(tab) = tabbed.tabs.get(tabbed.active_tab_index) {
+                    let empty = tab.content.lines.is_empty();
+                    items.push(item(
+                        "Search Here",
+                        format!("action:searchwindow:{}", name),
+                        empty,
+                    ));
+                    items.push(item(
+                        timestamps(tab.definition.show_timestamps),
+                        format!("action:timestamps:{}", name),
+                        false,
+                    ));
+                    items.push(item(
+                        "Clear Tab",
+                        format!("action:clearwindow:{}", name),
+                        empty,
+                    ));
+                    items.push(item(freeze, format!(".freeze {}", name), false));
+                }
+            }
+            _ => {}
+        }
+
+        items.push(item(
+            "Edit Window...",
+            format!("action:editwindow:{}", name),
+            false,
+        ));
+        if !essential {
+            items.push(item(
+                "Duplicate Window",
+                format!(".dupwindow {}", name),
+                false,
+            ));
+        }
+        items.push(item("Copy Window", format!(".copywindow {}", name), false));
+        if !essential {
+            items.push(item(
+                "Close Window",
+                format!("__CLOSE_WINDOW__{}", name),
+                false,
+            ));
+        }
+        items.push(item("Open Menu", ".menu".to_string(), false));
+        items
+    }
+
+    /// Empty a text window's buffer (the active tab of a tabbed one)
+    pub fn clear_window(&mut self, name: &str) {
+        let content = match self.ui_state.windows.get_mut(name).map(|w| &mut w.content) {
+            Some(WindowContent::Text(content)) => Some(content),
+            Some(WindowContent::TabbedText(tabbed)) => {
+                let active = tabbed.active_tab_index;
+                tabbed.tabs.get_mut(active).map(|tab| &mut tab.content)
+            }
+            _ => None,
+        };
+        match content {
+            Some(content) => {
+                content.lines.clear();
+                content.scroll_offset = 0;
+                // Fewer lines than the generation moved makes the frontend
+                // rebuild the widget from the (now empty) buffer
+                content.generation = content.generation.wrapping_add(1);
+                self.needs_render = true;
+            }
+            None => self.add_system_message(&format!("'{}' has no text to clear", name)),
+        }
+    }
+
+    /// Flip timestamps on a text window (the active tab of a tabbed one).
+    /// Lines that arrive from now on follow the new setting.
+    pub fn toggle_window_timestamps(&mut self, name: &str) {
+        let shown = match self.ui_state.windows.get_mut(name).map(|w| &mut w.content) {
+            Some(WindowContent::Text(content)) => {
+                content.show_timestamps = !content.show_timestamps;
+                Some((content.show_timestamps, None))
+            }
+            Some(WindowContent::TabbedText(tabbed)) => {
+                let active = tabbed.active_tab_index;
+                tabbed.tabs.get_mut(active).map(|tab| {
+                    tab.definition.show_timestamps = !tab.definition.show_timestamps;
+                    tab.content.show_timestamps = tab.definition.show_timestamps;
+                    (
+                        tab.definition.show_timestamps,
+                        Some(tab.definition.name.clone()),
+                    )
+                })
+            }
+            _ => None,
+        };
+        let Some((shown, tab)) = shown else {
+            self.add_system_message(&format!("'{}' has no timestamps to toggle", name));
+            return;
+        };
+
+        // The layout is what the frontend and the next save read
+        match self.layout.windows.iter_mut().find(|w| w.name() == name) {
+            Some(crate::config::WindowDef::Text { data, .. }) => data.show_timestamps = shown,
+            Some(crate::config::WindowDef::TabbedText { data, .. }) => {
+                if let Some(def) = data.tabs.iter_mut().find(|t| Some(&t.name) == tab.as_ref()) {
+                    def.show_timestamps = Some(shown);
+                }
+            }
+            _ => {}
+        }
+        self.mark_layout_modified();
+        self.add_system_message(&format!(
+            "Timestamps {} for '{}'",
+            if shown { "on" } else { "off" },
+            tab.as_deref().unwrap_or(name)
+        ));
+        self.needs_render = true;
+    }
+
+    /// Text buffers of a window: its content, or every tab of a tabbed one
+    fn window_text_buffers(&mut self, name: &str) -> Vec<&mut TextContent> {
+        match self.ui_state.windows.get_mut(name).map(|w| &mut w.content) {
+            Some(WindowContent::Text(content)) => vec![content],
+            Some(WindowContent::TabbedText(tabbed)) => {
+                tabbed.tabs.iter_mut().map(|tab| &mut tab.content).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Current rows of watch window `name`, evaluated against live state
+    /// (empty if it isn't a watch window)
+    pub fn watch_rows(&self, name: &str) -> Vec<crate::core::watch::WatchRow> {
+        let Some(crate::config::WindowDef::Watch { data, .. }) = self.layout.get_window(name)
+        else {
+            return Vec::new();
+        };
+        let ctx = crate::core::condition::ConditionContext {
+            game_state: &self.game_state,
+            now_server: chrono::Utc::now().timestamp() + self.message_processor.server_time_offset,
+            variables: &self.config.variables,
+        };
+        crate::core::watch::evaluate_rows(&data.watches, &ctx)
+    }
+
+    pub fn is_window_frozen(&self, name: &str) -> bool {
+        match self.ui_state.windows.get(name).map(|w| &w.content) {
+            Some(WindowContent::Text(content)) => content.freeze.is_some(),
+            Some(WindowContent::TabbedText(tabbed)) => {
+                tabbed.tabs.iter().any(|tab| tab.content.freeze.is_some())
+            }
+            _ => false,
+        }
+    }
+
+    /// Stop a text window from showing new lines until `unfreeze_window`;
+    /// they are held for later or dropped depending on `mode`
+    pub fn freeze_window(&mut self, name: &str, mode: FreezeMode) {
+        if self.window_text_buffers(name).is_empty() {
+            self.add_system_message(&format!("'{}' has no text to freeze", name));
+            return;
+        }
+        // Announce first: with main frozen the message would be held too
+        self.add_system_message(&match mode {
+            FreezeMode::Hold => format!(
+                "Froze '{}': new lines are held until .unfreeze {}",
+                name, name
+            ),
+            FreezeMode::Drop => format!(
+                "Froze '{}': new lines are dropped until .unfreeze {}",
+                name, name
+            ),
+        });
+        for buffer in self.window_text_buffers(name) {
+            buffer.freeze(mode);
+        }
+        self.needs_render = true;
+    }
+
+    pub fn unfreeze_window(&mut self, name: &str) {
+        let mut released = 0;
+        let mut dropped = 0;
+        for buffer in self.window_text_buffers(name) {
+            dropped += buffer.freeze.as_ref().map_or(0, |freeze| freeze.dropped);
+            released += buffer.unfreeze();
+        }
+        let message = if dropped > 0 {
+            format!(
+                "Unfroze '{}' ({} held lines added, {} dropped)",
+                name, released, dropped
+            )
+        } else {
+            format!("Unfroze '{}' ({} held lines added)", name, released)
+        };
+        self.add_system_message(&message);
+        self.needs_render = true;
+    }
+
     /// Build category submenu showing available windows of that type
     pub fn build_add_window_category_menu(
         &self,
@@ -5220,6 +6696,40 @@ fn wire_map_scene(
     }
 }
 
+/// Evaluate each window's `show_when`. Returns the windows to hide and a
+/// message for each newly broken condition; `reported` holds the windows
+/// already reported, so an error is shown once until it's fixed.
+fn evaluate_window_conditions(
+    windows: &[crate::config::WindowDef],
+    ctx: &crate::core::condition::ConditionContext,
+    reported: &mut std::collections::HashSet<String>,
+) -> (std::collections::HashSet<String>, Vec<String>) {
+    let mut hidden = std::collections::HashSet::new();
+    let mut errors = Vec::new();
+    for window_def in windows {
+        let base = window_def.base();
+        let Some(condition) = base.show_when.as_deref().filter(|c| !c.trim().is_empty()) else {
+            continue;
+        };
+        match crate::core::condition::evaluate(condition, ctx) {
+            Ok(true) => {}
+            Ok(false) => {
+                hidden.insert(base.name.clone());
+            }
+            Err(e) => errors.push((base.name.clone(), e)),
+        }
+    }
+
+    // A fixed condition can be reported again if it breaks later
+    reported.retain(|name| errors.iter().any(|(errored, _)| errored == name));
+    let messages = errors
+        .into_iter()
+        .filter(|(name, _)| reported.insert(name.clone()))
+        .map(|(name, e)| format!("[layout] window '{}' show_when: {:#} (showing it)", name, e))
+        .collect();
+    (hidden, messages)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5249,10 +6759,57 @@ mod tests {
             max_cols: None,
             visible: true,
             content_align: None,
+            floating: false,
+            z_index: 0,
+            tee_file: None,
+            show_when: None,
             title_position: "top-left".to_string(),
         }
     }
 
+    #[test]
+    fn test_window_conditions_hide_and_report_once() {
+        let spacer = |name: &str, show_when: Option<&str>| {
+            let mut base = test_window_base(name);
+            base.show_when = show_when.map(str::to_string);
+            WindowDef::Spacer {
+                base,
+                data: SpacerWidgetData {},
+            }
+        };
+        let mut windows = vec![
+            spacer("always", None),
+            spacer("shown", Some("true")),
+            spacer("hidden", Some("false")),
+            spacer("broken", Some("nosuch == 1")),
+        ];
+        let game_state = crate::core::state::GameState::new();
+        let variables = crate::config::VariableStore::default();
+        let ctx = crate::core::condition::ConditionContext {
+            game_state: &game_state,
+            now_server: 0,
+            variables: &variables,
+        };
+        let mut reported = std::collections::HashSet::new();
+
+        let (hidden, messages) = evaluate_window_conditions(&windows, &ctx, &mut reported);
+        assert_eq!(hidden, ["hidden".to_string()].into_iter().collect());
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains("'broken'"));
+
+        // Still broken: not reported again
+        let (_, messages) = evaluate_window_conditions(&windows, &ctx, &mut reported);
+        assert!(messages.is_empty());
+
+        // Fixed, then broken again: reported again
+        windows[3] = spacer("broken", Some("true"));
+        let (_, messages) = evaluate_window_conditions(&windows, &ctx, &mut reported);
+        assert!(messages.is_empty() && reported.is_empty());
+        windows[3] = spacer("broken", Some("nosuch == 1"));
+        let (_, messages) = evaluate_window_conditions(&windows, &ctx, &mut reported);
+        assert_eq!(messages.len(), 1);
+    }
+
     #[test]
     fn test_edit_picker_reaches_hidden_windows() {
         // A hidden spacer must appear in the edit picker's template map when
@@ -5268,6 +6825,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5293,6 +6851,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5313,6 +6872,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5341,6 +6901,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5365,6 +6926,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5399,6 +6961,9 @@ mod tests {
                 show_timestamps: false,
                 timestamp_position: None,
                 compact: false,
+                collapse_repeats: false,
+                coalesce_seconds: 0,
+                conversation: false,
             },
         };
         let spacer1 = WindowDef::Spacer {
@@ -5411,6 +6976,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5441,6 +7007,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5465,6 +7032,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -5485,6 +7053,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
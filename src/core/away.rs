@@ -0,0 +1,168 @@
+//! Away mode: auto-reply to whispers and keep the ones you missed.
+//!
+//! `.away` turns it on by hand; `[away] idle_minutes` turns it on after
+//! that long without keyboard or mouse input, and the next input turns
+//! that back off. While away, the first whisper from each sender gets the
+//! away message back (through the `[reply] whisper` template) and every
+//! whisper is copied to the `away` stream, so an "away" window holds what
+//! you missed. Coming back prints how many whispers arrived.
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Why away mode is on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AwayReason {
+    /// `.away`
+    Manual,
+    /// No input for `[away] idle_minutes`
+    Idle,
+}
+
+/// What a whisper received while away should trigger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhileAway {
+    /// Send the auto-reply to this sender
+    pub reply: bool,
+    /// Log the line to the away stream (false for an echo of the same
+    /// whisper on another stream)
+    pub log: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct AwayState {
+    reason: Option<AwayReason>,
+    /// Overrides `[away] message` until away mode ends (`.away <message>`)
+    pub message: Option<String>,
+    last_input: Instant,
+    /// Senders already answered this time away, lowercase
+    replied: HashSet<String>,
+    last_logged: Option<String>,
+    /// Whispers logged this time away
+    missed: usize,
+}
+
+impl Default for AwayState {
+    fn default() -> Self {
+        Self {
+            reason: None,
+            message: None,
+            last_input: Instant::now(),
+            replied: HashSet::new(),
+            last_logged: None,
+            missed: 0,
+        }
+    }
+}
+
+impl AwayState {
+    pub fn reason(&self) -> Option<AwayReason> {
+        self.reason
+    }
+
+    pub fn is_away(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    /// Start away mode. Returns false if it was already on.
+    pub fn start(&mut self, reason: AwayReason) -> bool {
+        if self.reason.is_some() {
+            // Going away by hand keeps it on through input
+            if reason == AwayReason::Manual {
+                self.reason = Some(reason);
+            }
+            return false;
+        }
+        self.reason = Some(reason);
+        self.replied.clear();
+        self.last_logged = None;
+        self.missed = 0;
+        true
+    }
+
+    /// End away mode. Returns the number of whispers missed, or None if
+    /// it wasn't on.
+    pub fn end(&mut self) -> Option<usize> {
+        self.reason.take()?;
+        self.message = None;
+        Some(self.missed)
+    }
+
+    /// Keyboard or mouse input. Ends idle away mode; returns the missed
+    /// count when it did.
+    pub fn note_input(&mut self, now: Instant) -> Option<usize> {
+        self.last_input = now;
+        if self.reason == Some(AwayReason::Idle) {
+            self.end()
+        } else {
+            None
+        }
+    }
+
+    /// Go idle-away once `idle_after` has passed without input. Returns
+    /// true when that just happened.
+    pub fn check_idle(&mut self, now: Instant, idle_after: Duration) -> bool {
+        !idle_after.is_zero()
+            && self.reason.is_none()
+            && now.duration_since(self.last_input) >= idle_after
+            && self.start(AwayReason::Idle)
+    }
+
+    /// A whisper from `sender` (the full line in `line`) arrived
+    pub fn whisper(&mut self, sender: &str, line: &str) -> Option<WhileAway> {
+        if !self.is_away() {
+            return None;
+        }
+        let log = self.last_logged.as_deref() != Some(line);
+        if log {
+            self.last_logged = Some(line.to_string());
+            self.missed += 1;
+        }
+        let reply = self.replied.insert(sender.to_ascii_lowercase());
+        Some(WhileAway { reply, log })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replies_once_per_sender_and_logs_each_whisper() {
+        let mut away = AwayState::default();
+        assert_eq!(away.whisper("Bob", "Bob whispers, \"hi\""), None);
+        assert!(away.start(AwayReason::Manual));
+        let first = away.whisper("Bob", "Bob whispers, \"hi\"").unwrap();
+        assert!(first.reply && first.log);
+        // The same whisper echoed on another stream
+        let echo = away.whisper("Bob", "Bob whispers, \"hi\"").unwrap();
+        assert!(!echo.reply && !echo.log);
+        let again = away.whisper("bob", "Bob whispers, \"you there?\"").unwrap();
+        assert!(!again.reply && again.log);
+        assert!(
+            away.whisper("Alice", "Alice whispers, \"hey\"")
+                .unwrap()
+                .reply
+        );
+        // Manual away stays on through input
+        assert_eq!(away.note_input(Instant::now()), None);
+        assert_eq!(away.end(), Some(3));
+        assert!(!away.is_away());
+    }
+
+    #[test]
+    fn idle_away_ends_on_input() {
+        let mut away = AwayState::default();
+        let start = Instant::now();
+        away.note_input(start);
+        let idle = Duration::from_secs(600);
+        assert!(!away.check_idle(start + Duration::from_secs(599), idle));
+        assert!(away.check_idle(start + idle, idle));
+        assert_eq!(away.reason(), Some(AwayReason::Idle));
+        assert!(!away.check_idle(start + idle * 2, idle));
+        away.whisper("Bob", "Bob whispers, \"hi\"");
+        assert_eq!(away.note_input(start + idle * 2), Some(1));
+        // Zero disables idle detection
+        assert!(!away.check_idle(start + idle * 10, Duration::ZERO));
+    }
+}
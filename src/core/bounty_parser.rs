@@ -1,6 +1,7 @@
 //! Bounty text parser for compact display mode.
 //!
-//! Transforms verbose bounty task text into 1-4 line compact format.
+//! Transforms verbose bounty task text into 1-4 line compact format, and
+//! into a structured task (phase + remaining count) for the task tracker.
 //! Based on minibounty.lic by Demandred.
 
 use regex::Regex;
@@ -171,13 +172,20 @@ static RE_FAILED: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"The child you were tasked to rescue is gone and your task is failed\.|You have failed in your task\.  Return to the Adventurer's Guild for further instructions\.").unwrap()
 });
 
+/// Normalize whitespace for matching (bounty text may span multiple lines)
+fn normalize(text: &str) -> String {
+    text.replace('\n', " ")
+        .replace("  ", " ")
+        .trim()
+        .to_string()
+}
+
 /// Parse bounty text and return compact representation if it matches a known pattern.
 ///
 /// Returns `None` if the text doesn't match any bounty pattern.
 pub fn parse_bounty(text: &str) -> Option<CompactBounty> {
-    // Normalize whitespace for matching (bounty text may span multiple lines)
-    let normalized = text.replace('\n', " ").replace("  ", " ");
-    let text = normalized.trim();
+    let normalized = normalize(text);
+    let text = normalized.as_str();
 
     // Try each pattern in order (more specific patterns first)
 
@@ -401,6 +409,105 @@ pub fn parse_bounty(text: &str) -> Option<CompactBounty> {
     None
 }
 
+/// Where the current bounty stands, for the task tracker widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BountyPhase {
+    /// No task assigned
+    #[default]
+    None,
+    /// Assigned, but the details come from an NPC ("Visit Guard")
+    Visit,
+    /// Out working on it
+    Active,
+    /// Done; report back or turn in at the guild
+    Complete,
+    Failed,
+}
+
+/// Structured view of the bounty text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BountyTask {
+    pub phase: BountyPhase,
+    /// Compact lines, title first (same as compact text windows)
+    pub lines: Vec<String>,
+    /// How many are still needed, for counted tasks (culling, bandits,
+    /// skins, gems, herbs)
+    pub remaining: Option<u32>,
+    /// The text gives the full count ("kill 12 of them") rather than
+    /// what's left ("kill 6 more of them")
+    pub count_is_total: bool,
+    /// Word for the progress line: "6/12 culled"
+    pub unit: &'static str,
+}
+
+impl BountyTask {
+    /// Identifies the task across count updates: the compact lines with
+    /// the digits stripped.
+    pub fn key(&self) -> String {
+        self.lines
+            .iter()
+            .flat_map(|line| line.chars().filter(|c| !c.is_ascii_digit()))
+            .collect()
+    }
+}
+
+/// Count, whether it's the full count, and progress unit for counted tasks.
+/// Checked in the same order as `parse_bounty`.
+fn task_count(text: &str) -> Option<(u32, bool, &'static str)> {
+    let counted: [(&Regex, &'static str); 5] = [
+        (&RE_SKINNING, "skinned"),
+        (&RE_GEM, "found"),
+        (&RE_FORAGING, "foraged"),
+        (&RE_BANDIT, "killed"),
+        (&RE_CULLING, "culled"),
+    ];
+    counted.into_iter().find_map(|(re, unit)| {
+        let caps = re.captures(text)?;
+        let count = caps.name("count")?;
+        let is_total = !text[count.end()..].starts_with(" more");
+        Some((count.as_str().parse().ok()?, is_total, unit))
+    })
+}
+
+/// Parse bounty text into phase, compact lines and remaining count.
+///
+/// Returns `None` if the text doesn't match any bounty pattern.
+pub fn parse_task(text: &str) -> Option<BountyTask> {
+    let compact = parse_bounty(text)?;
+    let normalized = normalize(text);
+    let text = normalized.as_str();
+
+    let phase = if RE_NO_TASK.is_match(text) {
+        BountyPhase::None
+    } else if RE_FAILED.is_match(text) {
+        BountyPhase::Failed
+    } else if RE_TURN_IN.is_match(text) || RE_REPORT_SUCCESS.is_match(text) {
+        BountyPhase::Complete
+    } else if compact
+        .lines
+        .first()
+        .is_some_and(|l| l.starts_with("Visit "))
+    {
+        BountyPhase::Visit
+    } else {
+        BountyPhase::Active
+    };
+
+    let mut task = BountyTask {
+        phase,
+        lines: compact.lines,
+        ..Default::default()
+    };
+    if phase == BountyPhase::Active {
+        if let Some((count, is_total, unit)) = task_count(text) {
+            task.remaining = Some(count);
+            task.count_is_total = is_total;
+            task.unit = unit;
+        }
+    }
+    Some(task)
+}
+
 fn capitalize_first(s: &str) -> String {
     let mut chars = s.chars();
     match chars.next() {
@@ -467,6 +574,29 @@ mod tests {
         assert!(parse_bounty(text).is_none());
     }
 
+    #[test]
+    fn test_parse_task_phase_and_count() {
+        let text = "You have been tasked to suppress triton fanatic activity in the Atoll near Kraken's Fall. You need to kill 12 of them to complete your task.";
+        let task = parse_task(text).unwrap();
+        assert_eq!(task.phase, BountyPhase::Active);
+        assert_eq!(task.remaining, Some(12));
+        assert!(task.count_is_total);
+        assert_eq!(task.unit, "culled");
+
+        let text = "You have been tasked to suppress triton fanatic activity in the Atoll near Kraken's Fall. You need to kill 6 more of them to complete your task.";
+        let later = parse_task(text).unwrap();
+        assert_eq!(later.remaining, Some(6));
+        assert!(!later.count_is_total);
+        assert_eq!(later.key(), task.key());
+
+        let done = parse_task("You have succeeded in your task and can return to the Adventurer's Guild to receive your reward.").unwrap();
+        assert_eq!(done.phase, BountyPhase::Complete);
+        assert_eq!(done.remaining, None);
+
+        let visit = parse_task("The local furrier Jarvis has an order to fill and wants our help.  Head over there and see what you can do.").unwrap();
+        assert_eq!(visit.phase, BountyPhase::Visit);
+    }
+
     #[test]
     fn test_visit_furrier() {
         let text = "The local furrier Jarvis has an order to fill and wants our help.  Head over there and see what you can do.";
@@ -0,0 +1,309 @@
+//! Combat analyzer for the combat stats widget.
+//!
+//! GemStone IV prints every one of your attacks as an attack line ("You
+//! swing a broadsword at a kobold!"), a roll line (`AS: ... = +128` or
+//! `CS: ... == +113`), and, on a hit, a damage line ("... and hit for 38
+//! points of damage!"). Endrolls over 100 hit. Those lines are parsed in the
+//! message pipeline into `CombatEvent`s and applied to GameState by AppCore,
+//! like the silver tracker.
+//!
+//! A roll only counts when it follows one of your own attack lines, so
+//! creatures attacking you (whose rolls you also see) don't skew the numbers.
+//! The game doesn't print critical ranks, so "best hit" is the most damage
+//! one attack did.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// One combat-related line from the game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CombatEvent {
+    /// You attacked this creature ("kobold", "greater ghoul")
+    Attack(String),
+    /// Something attacked you; the next roll isn't yours
+    Incoming,
+    /// Your attack's roll line
+    Roll { hit: bool },
+    /// Points of damage from the last roll
+    Damage(u32),
+    /// A creature died ("The kobold dies.")
+    Death(String),
+}
+
+static RE_ATTACK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^You (?:[a-z]+ )+?at (?:an? |the |some )?(.+?)[.!]$").unwrap());
+static RE_ENDROLL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"=+ ?([+-]?\d+)\s*$").unwrap());
+static RE_DAMAGE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(\d+) points? of damage\b").unwrap());
+static RE_DEATH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:The |An? )?(.+?) (?:dies|falls .*\bdead|collapses .*\bdies)\b").unwrap()
+});
+
+/// Parse one main- or combat-stream line.
+pub fn parse_combat_line(line: &str) -> Option<CombatEvent> {
+    let line = line.trim();
+    if line.starts_with("AS:") || line.starts_with("CS:") || line.starts_with("UAF:") {
+        let endroll: i64 = RE_ENDROLL.captures(line)?[1]
+            .trim_start_matches('+')
+            .parse()
+            .ok()?;
+        return Some(CombatEvent::Roll { hit: endroll > 100 });
+    }
+    if let Some(caps) = RE_DAMAGE.captures(line) {
+        return caps[1].parse().ok().map(CombatEvent::Damage);
+    }
+    // The remaining patterns are all "... at ..." or a death; most lines
+    // are neither, so skip the regexes for them
+    if line.contains(" at ") {
+        if line.starts_with("You ") {
+            return RE_ATTACK
+                .captures(line)
+                .map(|caps| CombatEvent::Attack(caps[1].to_string()));
+        }
+        if line.ends_with(" at you!") || line.ends_with(" at you.") {
+            return Some(CombatEvent::Incoming);
+        }
+    }
+    if !(line.contains("dies") || line.contains("dead")) {
+        return None;
+    }
+    RE_DEATH
+        .captures(line)
+        .map(|caps| CombatEvent::Death(caps[1].to_string()))
+}
+
+/// Per-creature totals
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreatureStats {
+    pub name: String,
+    pub swings: u32,
+    pub hits: u32,
+    pub damage: u64,
+    pub kills: u32,
+}
+
+/// Session combat totals
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CombatStats {
+    pub swings: u32,
+    pub hits: u32,
+    pub damage: u64,
+    /// Most damage one attack did, and to what
+    pub best_hit: Option<(u32, String)>,
+    /// Creatures in the order they were first attacked
+    pub creatures: Vec<CreatureStats>,
+    /// Game time of the first and latest roll, for the damage rate
+    pub first_roll_at: Option<i64>,
+    pub last_roll_at: Option<i64>,
+    /// Creature your latest attack line named, until something else attacks
+    target: Option<String>,
+    /// Whether a roll for `target` is still expected
+    roll_pending: bool,
+    /// Bumped on every change so widgets can skip redraws
+    pub generation: u64,
+}
+
+impl CombatStats {
+    /// Apply one event seen at game time `now`
+    pub fn apply(&mut self, event: CombatEvent, now: i64) {
+        match event {
+            CombatEvent::Attack(target) => {
+                self.target = Some(target);
+                self.roll_pending = true;
+                return;
+            }
+            CombatEvent::Incoming => {
+                self.roll_pending = false;
+                return;
+            }
+            CombatEvent::Roll { hit } => {
+                // Multi-strike prints several rolls after one attack line
+                let Some(target) = self.target.clone().filter(|_| self.roll_pending) else {
+                    return;
+                };
+                self.swings += 1;
+                self.first_roll_at.get_or_insert(now);
+                self.last_roll_at = Some(now);
+                let creature = self.creature_mut(&target);
+                creature.swings += 1;
+                if hit {
+                    creature.hits += 1;
+                    self.hits += 1;
+                }
+            }
+            CombatEvent::Damage(points) => {
+                let Some(target) = self.target.clone().filter(|_| self.roll_pending) else {
+                    return;
+                };
+                self.damage += points as u64;
+                self.creature_mut(&target).damage += points as u64;
+                if self
+                    .best_hit
+                    .as_ref()
+                    .is_none_or(|(best, _)| points > *best)
+                {
+                    self.best_hit = Some((points, target));
+                }
+            }
+            CombatEvent::Death(name) => {
+                let Some(target) = self.target.as_deref() else {
+                    return;
+                };
+                // "The kobold dies." after attacking "a greater kobold"
+                let noun = target.rsplit(' ').next().unwrap_or(target);
+                if !name.ends_with(noun) {
+                    return;
+                }
+                let target = target.to_string();
+                self.creature_mut(&target).kills += 1;
+                self.roll_pending = false;
+            }
+        }
+        self.generation += 1;
+    }
+
+    fn creature_mut(&mut self, name: &str) -> &mut CreatureStats {
+        let index = match self.creatures.iter().position(|c| c.name == name) {
+            Some(index) => index,
+            None => {
+                self.creatures.push(CreatureStats {
+                    name: name.to_string(),
+                    ..Default::default()
+                });
+                self.creatures.len() - 1
+            }
+        };
+        &mut self.creatures[index]
+    }
+
+    /// Share of rolls that hit, 0-100
+    pub fn hit_percent(&self) -> Option<f64> {
+        (self.swings > 0).then(|| self.hits as f64 * 100.0 / self.swings as f64)
+    }
+
+    /// Damage per second between the first and latest roll
+    pub fn damage_per_second(&self) -> Option<f64> {
+        let elapsed = self.last_roll_at? - self.first_roll_at?;
+        (elapsed > 0).then(|| self.damage as f64 / elapsed as f64)
+    }
+
+    /// Creatures ordered by damage dealt, most first
+    pub fn creatures_by_damage(&self) -> Vec<&CreatureStats> {
+        let mut creatures: Vec<&CreatureStats> = self.creatures.iter().collect();
+        creatures.sort_by_key(|creature| std::cmp::Reverse(creature.damage));
+        creatures
+    }
+
+    /// Totals as display lines: hits, damage and rate, best hit
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = vec![match self.hit_percent() {
+            Some(percent) => format!("Hits: {}/{} ({:.0}%)", self.hits, self.swings, percent),
+            None => "Hits: no attacks yet".to_string(),
+        }];
+        lines.push(match self.damage_per_second() {
+            Some(rate) => format!("Damage: {} ({:.1}/s)", self.damage, rate),
+            None => format!("Damage: {}", self.damage),
+        });
+        if let Some((points, target)) = &self.best_hit {
+            lines.push(format!("Best hit: {} on {}", points, target));
+        }
+        lines
+    }
+
+    /// "kobold: 5/8, 210 dmg, 2 killed"
+    pub fn creature_line(creature: &CreatureStats) -> String {
+        format!(
+            "{}: {}/{}, {} dmg, {} killed",
+            creature.name, creature.hits, creature.swings, creature.damage, creature.kills
+        )
+    }
+
+    pub fn reset(&mut self) {
+        let generation = self.generation + 1;
+        *self = Self {
+            generation,
+            ..Default::default()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(stats: &mut CombatStats, lines: &[&str], now: i64) {
+        for line in lines {
+            if let Some(event) = parse_combat_line(line) {
+                stats.apply(event, now);
+            }
+        }
+    }
+
+    #[test]
+    fn parses_gs4_combat_lines() {
+        assert_eq!(
+            parse_combat_line("You swing a vultite broadsword at a kobold!"),
+            Some(CombatEvent::Attack("kobold".to_string()))
+        );
+        assert_eq!(
+            parse_combat_line("AS: +250 vs DS: +180 with AvD: +35 + d100 roll: +47 = +152"),
+            Some(CombatEvent::Roll { hit: true })
+        );
+        assert_eq!(
+            parse_combat_line("CS: +320 - TD: +290 + CvA: +25 + d100: +31 == +86"),
+            Some(CombatEvent::Roll { hit: false })
+        );
+        assert_eq!(
+            parse_combat_line("   ... and hit for 38 points of damage!"),
+            Some(CombatEvent::Damage(38))
+        );
+        assert_eq!(
+            parse_combat_line("A kobold swings a club at you!"),
+            Some(CombatEvent::Incoming)
+        );
+        assert_eq!(
+            parse_combat_line("The kobold dies."),
+            Some(CombatEvent::Death("kobold".to_string()))
+        );
+    }
+
+    #[test]
+    fn aggregates_only_your_own_rolls() {
+        let mut stats = CombatStats::default();
+        feed(
+            &mut stats,
+            &[
+                "You swing a vultite broadsword at a greater kobold!",
+                "AS: +250 vs DS: +180 with AvD: +35 + d100 roll: +47 = +152",
+                "   ... and hit for 38 points of damage!",
+            ],
+            1000,
+        );
+        // The kobold's swing at you and its roll don't count
+        feed(
+            &mut stats,
+            &[
+                "A greater kobold swings a club at you!",
+                "AS: +150 vs DS: +200 with AvD: +30 + d100 roll: +90 = +70",
+            ],
+            1003,
+        );
+        feed(
+            &mut stats,
+            &[
+                "You swing a vultite broadsword at a greater kobold!",
+                "AS: +250 vs DS: +180 with AvD: +35 + d100 roll: +80 = +185",
+                "   ... and hit for 61 points of damage!",
+                "The greater kobold dies.",
+            ],
+            1010,
+        );
+
+        assert_eq!((stats.swings, stats.hits, stats.damage), (2, 2, 99));
+        assert_eq!(stats.best_hit, Some((61, "greater kobold".to_string())));
+        assert_eq!(stats.hit_percent(), Some(100.0));
+        assert_eq!(stats.damage_per_second(), Some(9.9));
+        let kobold = &stats.creatures[0];
+        assert_eq!((kobold.swings, kobold.kills), (2, 1));
+    }
+}
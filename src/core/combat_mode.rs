@@ -0,0 +1,143 @@
+//! Combat mode: extra windows that only show up while you're fighting.
+//!
+//! With `[combat_mode] enabled`, the windows named in `[combat_mode]
+//! windows` (targets, injuries, roundtime, ...) are opened when combat
+//! starts and closed again `linger_seconds` after it ends. There's no
+//! "combat" flag in the feed, so it's guessed: a living creature in the
+//! room while roundtime or casttime is running, or an attack line to or
+//! from you, counts as fighting. Each sign of it pushes the end back.
+//!
+//! The overlay windows are ephemeral; the layout file never sees them. A
+//! window the layout already defines (usually with `visible = false`)
+//! opens where the layout puts it, anything else from its template.
+
+use crate::core::state::GameState;
+use std::time::{Duration, Instant};
+
+/// Combat mode turning on or off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatChange {
+    Started,
+    Ended,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CombatMode {
+    active: bool,
+    /// Last time anything looked like fighting
+    last_seen: Option<Instant>,
+    /// Overlay windows opened for this fight, closed when it ends
+    pub opened: Vec<String>,
+}
+
+/// Whether the game state looks like a fight right now: something alive
+/// in the room and roundtime or casttime running
+pub fn looks_like_combat(game_state: &GameState, now_server: i64) -> bool {
+    let busy = [game_state.roundtime_end, game_state.casttime_end]
+        .into_iter()
+        .flatten()
+        .any(|end| end > now_server);
+    busy && game_state.room_creatures.iter().any(|creature| {
+        !creature.is_body_part() && !creature.flags.as_ref().is_some_and(|flags| flags.dead)
+    })
+}
+
+impl CombatMode {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Something that only happens in a fight (an attack line) was seen
+    pub fn note_activity(&mut self, now: Instant) {
+        self.last_seen = Some(now);
+    }
+
+    /// Advance with this frame's view of the game. Returns the change, if
+    /// combat started or has been over for `linger`.
+    pub fn update(
+        &mut self,
+        fighting: bool,
+        now: Instant,
+        linger: Duration,
+    ) -> Option<CombatChange> {
+        if fighting {
+            self.last_seen = Some(now);
+        }
+        let recent = self
+            .last_seen
+            .is_some_and(|seen| now.duration_since(seen) < linger.max(Duration::from_secs(1)));
+        match (self.active, recent) {
+            (false, true) => {
+                self.active = true;
+                Some(CombatChange::Started)
+            }
+            (true, false) => {
+                self.active = false;
+                self.last_seen = None;
+                Some(CombatChange::Ended)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::{Creature, CreatureFlags};
+
+    fn creature(name: &str, dead: bool) -> Creature {
+        Creature {
+            name: name.to_string(),
+            noun: None,
+            id: "#1".to_string(),
+            status: None,
+            flags: Some(CreatureFlags {
+                dead,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn needs_a_living_creature_and_roundtime() {
+        let mut gs = GameState::new();
+        gs.roundtime_end = Some(1_000);
+        assert!(!looks_like_combat(&gs, 995));
+        gs.room_creatures.push(creature("kobold", true));
+        assert!(!looks_like_combat(&gs, 995));
+        gs.room_creatures.push(creature("kobold", false));
+        assert!(looks_like_combat(&gs, 995));
+        assert!(!looks_like_combat(&gs, 1_000));
+        gs.casttime_end = Some(1_003);
+        assert!(looks_like_combat(&gs, 1_000));
+    }
+
+    #[test]
+    fn ends_after_linger_without_fighting() {
+        let mut mode = CombatMode::default();
+        let start = Instant::now();
+        let linger = Duration::from_secs(20);
+        assert_eq!(mode.update(false, start, linger), None);
+        assert_eq!(
+            mode.update(true, start, linger),
+            Some(CombatChange::Started)
+        );
+        assert!(mode.is_active());
+        // An attack line keeps it going between roundtimes
+        mode.note_activity(start + Duration::from_secs(15));
+        assert_eq!(
+            mode.update(false, start + Duration::from_secs(30), linger),
+            None
+        );
+        assert_eq!(
+            mode.update(false, start + Duration::from_secs(35), linger),
+            Some(CombatChange::Ended)
+        );
+        assert!(!mode.is_active());
+        assert_eq!(
+            mode.update(false, start + Duration::from_secs(36), linger),
+            None
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Log of commands sent to the game, with fuzzy search for resending.
+//!
+//! Every game command that leaves `AppCore::send_command` is recorded with
+//! the time it was sent (dot commands stay local and aren't logged). Unlike
+//! the command input's history, which is a linear list for Up/Down recall,
+//! the log is searched as a whole: `search` folds repeats of the same
+//! command into one row and ranks rows by how well they match a fuzzy
+//! query, then by how recently they were sent. The frontends show it as the
+//! command log palette.
+
+use chrono::{DateTime, Local};
+use std::collections::{HashMap, VecDeque};
+
+/// Oldest entries are dropped past this many
+const MAX_ENTRIES: usize = 5000;
+
+/// One command as it was sent
+#[derive(Debug, Clone)]
+pub struct SentCommand {
+    pub text: String,
+    pub sent_at: DateTime<Local>,
+}
+
+/// A distinct command matching a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandMatch {
+    pub text: String,
+    /// When it was last sent
+    pub last_sent: DateTime<Local>,
+    /// How many times it was sent this session
+    pub count: usize,
+    /// Char indices of `text` matched by the query (for highlighting)
+    pub positions: Vec<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct CommandLog {
+    entries: VecDeque<SentCommand>,
+}
+
+impl CommandLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(SentCommand {
+            text: text.to_string(),
+            sent_at: Local::now(),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Distinct commands matching `query`, best match first. An empty query
+    /// matches everything, most recently sent first.
+    pub fn search(&self, query: &str) -> Vec<CommandMatch> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for entry in &self.entries {
+            *counts.entry(entry.text.as_str()).or_default() += 1;
+        }
+
+        // Newest first, so the first time a text is seen is its last send
+        let mut scored: Vec<(i64, usize, CommandMatch)> = Vec::new();
+        for (recency, entry) in self.entries.iter().rev().enumerate() {
+            let Some(count) = counts.remove(entry.text.as_str()) else {
+                continue;
+            };
+            let Some((score, positions)) = fuzzy_match(query, &entry.text) else {
+                continue;
+            };
+            scored.push((
+                score,
+                recency,
+                CommandMatch {
+                    text: entry.text.clone(),
+                    last_sent: entry.sent_at,
+                    count,
+                    positions,
+                },
+            ));
+        }
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, _, found)| found).collect()
+    }
+}
+
+/// Case-insensitive subsequence match of `query` (spaces ignored) against
+/// `text`. Returns a score, higher is better, and the matched char indices.
+/// Runs of consecutive chars and matches at the start of a word score extra,
+/// so "sw" prefers "wield sword" over "answer".
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let needle: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let hay: Vec<char> = text.chars().collect();
+    if needle.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let mut positions = Vec::with_capacity(needle.len());
+    let mut score = 0i64;
+    let mut next = 0;
+    for &wanted in &needle {
+        let idx = (next..hay.len()).find(|&i| hay[i].to_lowercase().eq(std::iter::once(wanted)))?;
+        score += 1;
+        if positions.last().is_some_and(|&prev| prev + 1 == idx) {
+            score += 5;
+        }
+        if idx == 0 || !hay[idx - 1].is_alphanumeric() {
+            score += 8;
+        }
+        positions.push(idx);
+        next = idx + 1;
+    }
+    // Tighter matches win among otherwise equal scores
+    score -= (positions[positions.len() - 1] - positions[0]) as i64 / 4;
+    Some((score, positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_prefers_word_starts() {
+        assert!(fuzzy_match("xyz", "get sword").is_none());
+        let (_, positions) = fuzzy_match("GS", "get sword").unwrap();
+        assert_eq!(positions, vec![0, 4]);
+        let (sword, _) = fuzzy_match("sw", "wield sword").unwrap();
+        let (answer, _) = fuzzy_match("sw", "answer").unwrap();
+        assert!(sword > answer);
+    }
+
+    #[test]
+    fn search_folds_repeats_and_ranks() {
+        let mut log = CommandLog::new();
+        for text in [
+            "look",
+            "get sword",
+            "attack troll",
+            "look",
+            "  ",
+            "say hello",
+        ] {
+            log.record(text);
+        }
+        assert_eq!(log.len(), 5);
+
+        let all = log.search("");
+        let texts: Vec<&str> = all.iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(texts, ["say hello", "look", "attack troll", "get sword"]);
+        assert_eq!(all[1].count, 2);
+
+        let found = log.search("at tr");
+        assert_eq!(found[0].text, "attack troll");
+        assert!(log.search("qqq").is_empty());
+    }
+}
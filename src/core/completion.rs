@@ -0,0 +1,192 @@
+//! Tab-completion words for game commands typed in the command input.
+//!
+//! Dot-commands complete from `AppCore::get_available_commands`; everything
+//! else completes from here: a fixed list of common game verbs plus the
+//! nouns of whatever is linked in the current room (creatures, items,
+//! players). Room words come straight from the parsed `room objs` /
+//! `room players` components, so they follow the room as you move.
+
+use crate::data::TextSegment;
+use std::collections::{BTreeSet, HashMap};
+
+/// Room components whose links feed completion.
+const ROOM_COMPONENTS: &[&str] = &["room objs", "room players"];
+
+/// Common verbs shared by GemStone IV and DragonRealms.
+pub const GAME_VERBS: &[&str] = &[
+    "advance",
+    "analyze",
+    "appraise",
+    "ask",
+    "attack",
+    "bow",
+    "buy",
+    "cast",
+    "close",
+    "drag",
+    "drop",
+    "eat",
+    "experience",
+    "fire",
+    "get",
+    "give",
+    "glance",
+    "go",
+    "health",
+    "hide",
+    "incant",
+    "info",
+    "inventory",
+    "kneel",
+    "lean",
+    "look",
+    "loot",
+    "nod",
+    "open",
+    "order",
+    "peer",
+    "prepare",
+    "put",
+    "quit",
+    "read",
+    "release",
+    "remove",
+    "retreat",
+    "search",
+    "sell",
+    "shake",
+    "sit",
+    "skin",
+    "smile",
+    "spells",
+    "stance",
+    "stand",
+    "steal",
+    "stow",
+    "target",
+    "tell",
+    "think",
+    "touch",
+    "unhide",
+    "wave",
+    "wear",
+    "whisper",
+    "wield",
+    "yell",
+];
+
+/// Nouns of the links currently shown in the room components, deduplicated
+/// case-insensitively. Player links carry the player's name as their noun.
+pub fn room_words(room_components: &HashMap<String, Vec<Vec<TextSegment>>>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    let mut words = Vec::new();
+    let links = ROOM_COMPONENTS
+        .iter()
+        .filter_map(|id| room_components.get(*id))
+        .flatten()
+        .flatten()
+        .filter_map(|segment| segment.link_data.as_ref());
+    for link in links {
+        // Synthetic links (`_direct_` etc.) are commands, not objects
+        if link.exist_id.starts_with('_') {
+            continue;
+        }
+        let noun = link.noun.trim();
+        if !noun.is_empty() && seen.insert(noun.to_lowercase()) {
+            words.push(noun.to_string());
+        }
+    }
+    words
+}
+
+/// Verbs followed by room words, first spelling wins on duplicates.
+pub fn game_words(room_components: &HashMap<String, Vec<Vec<TextSegment>>>) -> Vec<String> {
+    let room = room_words(room_components);
+    let mut words: Vec<String> = GAME_VERBS.iter().map(|verb| verb.to_string()).collect();
+    for word in room {
+        if !GAME_VERBS.contains(&word.to_lowercase().as_str()) {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// Words from `words` starting with `partial` (case-insensitive), sorted
+/// and deduplicated ignoring case.
+pub fn candidates(words: &[String], partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+    let mut matches: Vec<String> = words
+        .iter()
+        .filter(|word| word.to_lowercase().starts_with(&partial))
+        .cloned()
+        .collect();
+    matches.sort_by_key(|word| word.to_lowercase());
+    matches.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{LinkData, SpanType};
+
+    fn link_segment(noun: &str, exist_id: &str) -> TextSegment {
+        TextSegment {
+            text: noun.to_string(),
+            fg: None,
+            bg: None,
+            bold: false,
+            mono: false,
+            span_type: SpanType::Link,
+            link_data: Some(LinkData {
+                exist_id: exist_id.to_string(),
+                noun: noun.to_string(),
+                text: noun.to_string(),
+                coord: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_room_words_collects_objects_and_players() {
+        let mut components = HashMap::new();
+        components.insert(
+            "room objs".to_string(),
+            vec![vec![link_segment("troll", "1"), link_segment("Troll", "2")]],
+        );
+        components.insert(
+            "room players".to_string(),
+            vec![vec![
+                link_segment("Trevor", "-3"),
+                link_segment("look", "_direct_"),
+            ]],
+        );
+        components.insert(
+            "room desc".to_string(),
+            vec![vec![link_segment("fountain", "4")]],
+        );
+        assert_eq!(room_words(&components), vec!["troll", "Trevor"]);
+    }
+
+    #[test]
+    fn test_game_words_skip_nouns_that_are_verbs() {
+        let mut components = HashMap::new();
+        components.insert(
+            "room objs".to_string(),
+            vec![vec![link_segment("skin", "1")]],
+        );
+        let words = game_words(&components);
+        assert_eq!(words.iter().filter(|w| w.as_str() == "skin").count(), 1);
+        assert!(words.iter().any(|w| w == "attack"));
+    }
+
+    #[test]
+    fn test_candidates_ignore_case_and_dedup() {
+        let words: Vec<String> = ["troll", "Trevor", "TROLL", "look"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        assert_eq!(candidates(&words, "TR"), vec!["Trevor", "troll"]);
+        assert!(candidates(&words, "x").is_empty());
+    }
+}
@@ -0,0 +1,560 @@
+//! Tiny condition language for conditional sends (`.if <cond> then <cmd>`).
+//!
+//! Expressions read game state so macros and hotbar buttons can refuse to
+//! fire at the wrong moment:
+//!
+//! ```text
+//! rt == 0 && health% > 50
+//! hands_empty or right ~ "sword"
+//! !(stunned || webbed) and target == "kobold"
+//! ```
+//!
+//! Names: `rt`/`ct` (seconds left), `health`/`mana`/`stamina`/`spirit`
+//! (percent, an optional trailing `%` is allowed), status indicators
+//! (`standing`, `kneeling`, `sitting`, `prone`, `stunned`, `bleeding`,
+//! `hidden`, `invisible`, `webbed`, `joined`, `dead`), `stance`/`posture`
+//! (text), `silver` (tracked estimate), `left`/`right` (held item text),
+//! `hands_empty`/`left_empty`/`right_empty`, and `spells`/`buffs`/`debuffs`/
+//! `cooldowns` (how many are active). Any other name reads a `.set`
+//! variable. Operators: `== != < <= > >=`, `~`
+//! (case-insensitive contains), `&&`/`and`, `||`/`or`, `!`/`not`, parens.
+//!
+//! Dotted fields pick a detail: `health.pct`, `health.cur` and `health.max`
+//! (the last two from minivitals, GS4), `rt.remaining`/`ct.remaining`, and
+//! `var.<name>` for a variable whose name clashes with a built-in. The watch
+//! widget shows expressions' values with [`evaluate_value`].
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::config::{EffectCategory, VariableStore, VitalKind, VitalUnit};
+use crate::core::hotbar::{active_effect_count, indicator_value, vital_value};
+use crate::core::state::GameState;
+
+/// What a condition can see.
+pub struct ConditionContext<'a> {
+    pub game_state: &'a GameState,
+    /// Local unix time + server offset (same clock as the countdowns)
+    pub now_server: i64,
+    pub variables: &'a VariableStore,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Num(i64),
+    Bool(bool),
+    Str(String),
+}
+
+impl Value {
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Num(n) => *n != 0,
+            Value::Bool(b) => *b,
+            Value::Str(s) => !s.is_empty(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Num(i64),
+    Str(String),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+}
+
+/// Split `<cond> then <command>` at the first top-level `then` keyword.
+/// Only the condition is tokenized; the command after `then` is passed
+/// through as typed, punctuation and all.
+pub fn split_then(text: &str) -> Option<(&str, &str)> {
+    let mut depth = 0i32;
+    for token in Lexer::new(text) {
+        let (token, start, end) = token.ok()?;
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            Token::Ident(word) if depth == 0 && word == "then" => {
+                let command = text[end..].trim();
+                return (!command.is_empty()).then(|| (text[..start].trim(), command));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Evaluate a condition expression.
+pub fn evaluate(expr: &str, ctx: &ConditionContext) -> Result<bool> {
+    Ok(parse(expr, ctx)?.truthy())
+}
+
+/// Evaluate an expression for display: numbers and text as they are,
+/// true/false as `yes`/`no`.
+pub fn evaluate_value(expr: &str, ctx: &ConditionContext) -> Result<String> {
+    Ok(match parse(expr, ctx)? {
+        Value::Num(n) => n.to_string(),
+        Value::Bool(b) => String::from(if b { "yes" } else { "no" }),
+        Value::Str(s) => s,
+    })
+}
+
+fn parse(expr: &str, ctx: &ConditionContext) -> Result<Value> {
+    let tokens: Vec<Token> = tokenize(expr)?.into_iter().map(|(t, _, _)| t).collect();
+    if tokens.is_empty() {
+        bail!("empty condition");
+    }
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        ctx,
+    };
+    let value = parser.or()?;
+    if let Some(token) = parser.tokens.get(parser.pos) {
+        bail!("unexpected {:?}", token);
+    }
+    Ok(value)
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize, usize)>> {
+    Lexer::new(text).collect()
+}
+
+/// Tokens with their byte spans, read one at a time so `split_then` can
+/// stop at `then` without lexing the command behind it
+struct Lexer<'a> {
+    text: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            chars: text.char_indices().peekable(),
+        }
+    }
+
+    fn token(&mut self) -> Result<Option<(Token, usize, usize)>> {
+        let text = self.text;
+        let chars = &mut self.chars;
+        while chars.peek().is_some_and(|&(_, c)| c.is_whitespace()) {
+            chars.next();
+        }
+        let Some(&(start, c)) = chars.peek() else {
+            return Ok(None);
+        };
+        let token = if c.is_ascii_digit() {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                if !d.is_ascii_digit() {
+                    break;
+                }
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            Token::Num(text[start..end].parse()?)
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                // A dot joins name parts ("health.pct") when a letter follows
+                let field = d == '.' && text[i + 1..].starts_with(|n: char| n.is_alphabetic());
+                if !(d.is_alphanumeric() || d == '_' || field) {
+                    break;
+                }
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            // "health%" reads naturally; the % is decoration
+            if chars.peek().is_some_and(|&(_, d)| d == '%') {
+                chars.next();
+            }
+            Token::Ident(text[start..end].to_ascii_lowercase())
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut value = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, d)) if d == c => break,
+                    Some((_, d)) => value.push(d),
+                    None => bail!("unterminated string"),
+                }
+            }
+            Token::Str(value)
+        } else {
+            chars.next();
+            let next = chars.peek().map(|&(_, d)| d);
+            let two = |chars: &mut std::iter::Peekable<std::str::CharIndices>, op| {
+                chars.next();
+                Token::Op(op)
+            };
+            match (c, next) {
+                ('(', _) => Token::LParen,
+                (')', _) => Token::RParen,
+                ('=', Some('=')) => two(chars, "=="),
+                ('=', _) => Token::Op("=="),
+                ('!', Some('=')) => two(chars, "!="),
+                ('!', _) => Token::Op("!"),
+                ('<', Some('=')) => two(chars, "<="),
+                ('<', _) => Token::Op("<"),
+                ('>', Some('=')) => two(chars, ">="),
+                ('>', _) => Token::Op(">"),
+                ('&', Some('&')) => two(chars, "&&"),
+                ('|', Some('|')) => two(chars, "||"),
+                ('~', _) => Token::Op("~"),
+                _ => bail!("unexpected '{}'", c),
+            }
+        };
+        let end = chars.peek().map_or(text.len(), |&(i, _)| i);
+        Ok(Some((token, start, end)))
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<(Token, usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.token().transpose()
+    }
+}
+
+struct Parser<'a, 'c> {
+    tokens: Vec<Token>,
+    pos: usize,
+    ctx: &'a ConditionContext<'c>,
+}
+
+impl Parser<'_, '_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat_keyword(&mut self, op: &str, word: &str) -> bool {
+        let hit = match self.peek() {
+            Some(Token::Op(o)) => *o == op,
+            Some(Token::Ident(w)) => w == word,
+            _ => false,
+        };
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    fn or(&mut self) -> Result<Value> {
+        let mut value = self.and()?;
+        while self.eat_keyword("||", "or") {
+            let rhs = self.and()?;
+            value = Value::Bool(value.truthy() || rhs.truthy());
+        }
+        Ok(value)
+    }
+
+    fn and(&mut self) -> Result<Value> {
+        let mut value = self.unary()?;
+        while self.eat_keyword("&&", "and") {
+            let rhs = self.unary()?;
+            value = Value::Bool(value.truthy() && rhs.truthy());
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<Value> {
+        if self.eat_keyword("!", "not") {
+            return Ok(Value::Bool(!self.unary()?.truthy()));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Value> {
+        let lhs = self.atom()?;
+        let op = match self.peek() {
+            Some(Token::Op(op)) if !matches!(*op, "&&" | "||" | "!") => *op,
+            _ => return Ok(lhs),
+        };
+        self.pos += 1;
+        let rhs = self.atom()?;
+        compare(&lhs, op, &rhs).map(Value::Bool)
+    }
+
+    fn atom(&mut self) -> Result<Value> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| anyhow!("condition ends early"))?;
+        self.pos += 1;
+        match token {
+            Token::Num(n) => Ok(Value::Num(n)),
+            Token::Str(s) => Ok(Value::Str(s)),
+            Token::Ident(name) => self.lookup(&name),
+            Token::LParen => {
+                let value = self.or()?;
+                match self.tokens.get(self.pos) {
+                    Some(Token::RParen) => {
+                        self.pos += 1;
+                        Ok(value)
+                    }
+                    _ => bail!("missing ')'"),
+                }
+            }
+            other => bail!("unexpected {:?}", other),
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Result<Value> {
+        let gs = self.ctx.game_state;
+        let now = self.ctx.now_server;
+        let remaining = |end: Option<i64>| Value::Num(end.map_or(0, |end| (end - now).max(0)));
+        let hand = |item: &Option<String>| {
+            item.as_deref()
+                .filter(|i| !i.is_empty() && !i.eq_ignore_ascii_case("empty"))
+                .map(str::to_string)
+        };
+        let vital = |kind| Value::Num(vital_value(gs, kind, VitalUnit::Percent).unwrap_or(0));
+        let effects = |category| Value::Num(active_effect_count(gs, &category, now) as i64);
+        if let Some((head, field)) = name.split_once('.') {
+            return self.lookup_field(head, field);
+        }
+        Ok(match name {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            "rt" | "roundtime" => remaining(gs.roundtime_end),
+            "ct" | "casttime" => remaining(gs.casttime_end),
+            "health" => vital(VitalKind::Health),
+            "mana" => vital(VitalKind::Mana),
+            "stamina" => vital(VitalKind::Stamina),
+            "spirit" => vital(VitalKind::Spirit),
+            "stance" => Value::Str(gs.stance.name.clone()),
+            "silver" => Value::Num(gs.wealth.silver.unwrap_or(0)),
+            "posture" => Value::Str(
+                crate::core::stance::posture(&gs.status)
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            "left" => Value::Str(hand(&gs.left_hand).unwrap_or_default()),
+            "right" => Value::Str(hand(&gs.right_hand).unwrap_or_default()),
+            "left_empty" => Value::Bool(hand(&gs.left_hand).is_none()),
+            "right_empty" => Value::Bool(hand(&gs.right_hand).is_none()),
+            "hands_empty" => {
+                Value::Bool(hand(&gs.left_hand).is_none() && hand(&gs.right_hand).is_none())
+            }
+            "spells" => effects(EffectCategory::ActiveSpells),
+            "buffs" => effects(EffectCategory::Buffs),
+            "debuffs" => effects(EffectCategory::Debuffs),
+            "cooldowns" => effects(EffectCategory::Cooldowns),
+            _ => {
+                if let Some(active) = indicator_value(gs, name) {
+                    Value::Bool(active)
+                } else {
+                    self.variable(name)?
+                }
+            }
+        })
+    }
+
+    /// `head.field` names: vital details, remaining timers, `var.<name>`
+    fn lookup_field(&self, head: &str, field: &str) -> Result<Value> {
+        let gs = self.ctx.game_state;
+        let kind = match head {
+            "health" => Some(VitalKind::Health),
+            "mana" => Some(VitalKind::Mana),
+            "stamina" => Some(VitalKind::Stamina),
+            "spirit" => Some(VitalKind::Spirit),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            let absolute = || vital_value(gs, kind, VitalUnit::Absolute);
+            return match field {
+                "pct" | "percent" => self.lookup(head),
+                "cur" | "current" | "value" => Ok(Value::Num(absolute().unwrap_or(0))),
+                "max" => {
+                    let entry = match kind {
+                        VitalKind::Health => &gs.minivitals.health,
+                        VitalKind::Mana => &gs.minivitals.mana,
+                        VitalKind::Stamina => &gs.minivitals.stamina,
+                        VitalKind::Spirit => &gs.minivitals.spirit,
+                    };
+                    Ok(Value::Num(entry.max as i64))
+                }
+                _ => bail!("unknown field '{}.{}' (pct, cur, max)", head, field),
+            };
+        }
+        match (head, field) {
+            ("rt" | "roundtime" | "ct" | "casttime", "remaining" | "left") => self.lookup(head),
+            ("var", name) => self.variable(name),
+            _ => bail!("unknown name '{}.{}'", head, field),
+        }
+    }
+
+    fn variable(&self, name: &str) -> Result<Value> {
+        match self.ctx.variables.get(name) {
+            Some(value) => Ok(value
+                .parse()
+                .map(Value::Num)
+                .unwrap_or_else(|_| Value::Str(value.to_string()))),
+            None => bail!("unknown name '{}'", name),
+        }
+    }
+}
+
+fn compare(lhs: &Value, op: &str, rhs: &Value) -> Result<bool> {
+    use std::cmp::Ordering;
+    if op == "~" {
+        let (Value::Str(hay), Value::Str(needle)) = (lhs, rhs) else {
+            bail!("'~' compares text");
+        };
+        return Ok(hay.to_lowercase().contains(&needle.to_lowercase()));
+    }
+    let ordering = match (lhs, rhs) {
+        (Value::Num(a), Value::Num(b)) => a.cmp(b),
+        (Value::Str(a), Value::Str(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => bail!("can't compare {:?} with {:?}", lhs, rhs),
+    };
+    Ok(match op {
+        "==" => ordering == Ordering::Equal,
+        "!=" => ordering != Ordering::Equal,
+        "<" => ordering == Ordering::Less,
+        "<=" => ordering != Ordering::Greater,
+        ">" => ordering == Ordering::Greater,
+        ">=" => ordering != Ordering::Less,
+        _ => bail!("unknown operator '{}'", op),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_000_000;
+
+    fn eval(expr: &str, gs: &GameState, vars: &VariableStore) -> Result<bool> {
+        let ctx = ConditionContext {
+            game_state: gs,
+            now_server: NOW,
+            variables: vars,
+        };
+        evaluate(expr, &ctx)
+    }
+
+    fn state() -> GameState {
+        let mut gs = GameState::new();
+        gs.roundtime_end = Some(NOW + 3);
+        gs.vitals.health = 80;
+        gs.vitals.mana = 40;
+        gs.right_hand = Some("broadsword".to_string());
+        gs.status.kneeling = true;
+        gs
+    }
+
+    #[test]
+    fn reads_roundtime_vitals_hands_and_status() {
+        let gs = state();
+        let vars = VariableStore::default();
+        assert!(!eval("rt == 0", &gs, &vars).unwrap());
+        assert!(eval("rt > 0 and rt <= 3", &gs, &vars).unwrap());
+        assert!(eval("health% > 50 && mana < 50", &gs, &vars).unwrap());
+        assert!(eval("left_empty && !hands_empty", &gs, &vars).unwrap());
+        assert!(eval("right ~ \"SWORD\"", &gs, &vars).unwrap());
+        assert!(eval("kneeling and not standing", &gs, &vars).unwrap());
+        assert!(eval("(stunned || kneeling) && ct == 0", &gs, &vars).unwrap());
+    }
+
+    #[test]
+    fn unknown_names_read_variables() {
+        let gs = state();
+        let mut vars = VariableStore::default();
+        vars.set("target", "Kobold", false);
+        vars.set("min_health", "75", false);
+        assert!(eval("target == 'kobold'", &gs, &vars).unwrap());
+        assert!(eval("health >= min_health", &gs, &vars).unwrap());
+        assert!(eval("nosuch == 1", &gs, &vars).is_err());
+    }
+
+    #[test]
+    fn counts_active_effects() {
+        let mut gs = state();
+        let vars = VariableStore::default();
+        assert!(eval("spells == 0 && buffs == 0", &gs, &vars).unwrap());
+        let effect = |id: &str, expires_at| crate::data::ActiveEffect {
+            id: id.to_string(),
+            text: format!("Spell {}", id),
+            value: 50,
+            time: String::new(),
+            expires_at,
+            bar_color: None,
+            text_color: None,
+        };
+        gs.effects.insert(
+            "ActiveSpells".to_string(),
+            crate::data::ActiveEffectsContent {
+                category: "ActiveSpells".to_string(),
+                // The second has run out but the game hasn't cleared it yet
+                effects: vec![effect("401", Some(NOW + 60)), effect("406", Some(NOW - 5))],
+                generation: 1,
+            },
+        );
+        assert!(eval("spells == 1 and debuffs == 0", &gs, &vars).unwrap());
+    }
+
+    #[test]
+    fn dotted_fields_and_display_values() {
+        let mut gs = state();
+        gs.minivitals.health.value = 180;
+        gs.minivitals.health.max = 225;
+        let mut vars = VariableStore::default();
+        vars.set("rt", "shadowed", false);
+        let value = |expr| {
+            let ctx = ConditionContext {
+                game_state: &gs,
+                now_server: NOW,
+                variables: &vars,
+            };
+            evaluate_value(expr, &ctx)
+        };
+        assert_eq!(value("health.pct").unwrap(), "80");
+        assert_eq!(value("health.cur").unwrap(), "180");
+        assert_eq!(value("health.max").unwrap(), "225");
+        assert_eq!(value("rt.remaining").unwrap(), "3");
+        assert_eq!(value("var.rt").unwrap(), "shadowed");
+        assert_eq!(value("right").unwrap(), "broadsword");
+        assert_eq!(value("mana.pct < 50").unwrap(), "yes");
+        assert!(value("health.bogus").is_err());
+        assert!(value("rt.").is_err());
+    }
+
+    #[test]
+    fn malformed_conditions_are_errors() {
+        let gs = state();
+        let vars = VariableStore::default();
+        assert!(eval("", &gs, &vars).is_err());
+        assert!(eval("(rt == 0", &gs, &vars).is_err());
+        assert!(eval("rt == 0 extra", &gs, &vars).is_err());
+        assert!(eval("health > 'x'", &gs, &vars).is_err());
+    }
+
+    #[test]
+    fn then_splits_at_top_level_only() {
+        assert_eq!(
+            split_then("rt == 0 then attack kobold"),
+            Some(("rt == 0", "attack kobold"))
+        );
+        assert_eq!(
+            split_then("right == 'then' then say then"),
+            Some(("right == 'then'", "say then"))
+        );
+        // The command is never tokenized, so anything can follow `then`
+        assert_eq!(
+            split_then("rt == 0 then say I'm ready."),
+            Some(("rt == 0", "say I'm ready."))
+        );
+        assert_eq!(
+            split_then("(stunned or webbed) then say Help! @#$"),
+            Some(("(stunned or webbed)", "say Help! @#$"))
+        );
+        assert_eq!(split_then("rt == 0"), None);
+        assert_eq!(split_then("rt == 0 then"), None);
+    }
+}
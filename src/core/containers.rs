@@ -0,0 +1,233 @@
+//! Container helpers: capacity learned from game text, item links, and
+//! the commands that move an item between containers.
+//!
+//! The container feed only lists what's inside, so how much a container
+//! holds comes from what the game says about it: an explicit "can hold 20
+//! items", or a refusal ("There isn't any more room in your backpack")
+//! that tells us it's full at its current count. The message pipeline
+//! parses these on the main stream and AppCore applies them to
+//! GameState.container_cache, like the silver tracker.
+//!
+//! Dragging an item from one container window to another (GUI) becomes
+//! `get #item from #source` then `put #item in #target`; see
+//! AppCore::move_item and `[containers]`.
+
+use crate::data::{LinkData, SpanType, TextSegment};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// What a line says about a container's capacity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapacityEvent {
+    /// The container holds this many items
+    Holds { container: String, capacity: usize },
+    /// The container just refused an item
+    Full { container: String },
+}
+
+/// `Your backpack can hold 20 items.` / `The bandolier can only hold up to
+/// 12 items.`
+static RE_HOLDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:Your|The) (.+?) (?:can|could) (?:only )?(?:hold|contain) (?:up to )?(\d+) items?\b",
+    )
+    .unwrap()
+});
+/// `There isn't any more room in your backpack for that.` / `Your
+/// backpack is too full.` ("won't fit" is about size, not count, so it
+/// doesn't count)
+static RE_FULL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:^There isn't any more room in (?:your |the )?(.+?)(?: for that)?\.|^(?:Your|The) (.+?) is too full\b)",
+    )
+    .unwrap()
+});
+
+/// Parse one main-stream line
+pub fn parse_capacity_line(line: &str) -> Option<CapacityEvent> {
+    let line = line.trim();
+    if let Some(caps) = RE_HOLDS.captures(line) {
+        return Some(CapacityEvent::Holds {
+            container: caps[1].to_string(),
+            capacity: caps[2].parse().ok()?,
+        });
+    }
+    if !(line.contains("room in") || line.contains("too full")) {
+        return None;
+    }
+    let caps = RE_FULL.captures(line)?;
+    let container = (1..=2).find_map(|i| caps.get(i))?.as_str().to_string();
+    Some(CapacityEvent::Full { container })
+}
+
+/// An item dragged from one container to another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemMove {
+    pub item_id: String,
+    /// Link text, for the confirmation prompt ("a blue gem")
+    pub item_name: String,
+    pub from_id: String,
+    pub from_title: String,
+    pub to_id: String,
+    pub to_title: String,
+}
+
+impl ItemMove {
+    /// The game commands that perform the move
+    pub fn commands(&self) -> Vec<String> {
+        vec![
+            format!("get #{} from #{}", self.item_id, self.from_id),
+            format!("put #{} in #{}", self.item_id, self.to_id),
+        ]
+    }
+}
+
+/// Split a container item line into text and link segments. Links keep
+/// their exist id and noun; colors are left to the frontend.
+pub fn parse_item_line(content: &str) -> Vec<TextSegment> {
+    let text_segment = |text: &str, link_data: Option<LinkData>| TextSegment {
+        text: text.to_string(),
+        span_type: if link_data.is_some() {
+            SpanType::Link
+        } else {
+            SpanType::Normal
+        },
+        link_data,
+        ..Default::default()
+    };
+    let mut segments = Vec::new();
+    let mut rest = content;
+    while !rest.is_empty() {
+        let Some(a_start) = rest.find("<a ") else {
+            segments.push(text_segment(rest, None));
+            break;
+        };
+        if a_start > 0 {
+            segments.push(text_segment(&rest[..a_start], None));
+        }
+        let tag_and_rest = &rest[a_start..];
+        let parsed = tag_and_rest.find('>').and_then(|tag_end| {
+            let tag = &tag_and_rest[..=tag_end];
+            let after = &tag_and_rest[tag_end + 1..];
+            let close = after.find("</a>")?;
+            let link_data = LinkData {
+                exist_id: extract_attribute(tag, "exist").unwrap_or_default(),
+                noun: extract_attribute(tag, "noun").unwrap_or_default(),
+                text: after[..close].to_string(),
+                coord: None,
+            };
+            Some((link_data, &after[close + 4..]))
+        });
+        match parsed {
+            Some((link_data, after)) => {
+                segments.push(text_segment(&link_data.text.clone(), Some(link_data)));
+                rest = after;
+            }
+            // Unparseable link: skip the "<a" and keep going
+            None => rest = &tag_and_rest[2..],
+        }
+    }
+    segments
+}
+
+/// An attribute value from an XML tag: `attr="value"` or `attr='value'`
+pub fn extract_attribute(tag: &str, attr_name: &str) -> Option<String> {
+    for quote in ['"', '\''] {
+        let pattern = format!("{}={}", attr_name, quote);
+        if let Some(start) = tag.find(&pattern) {
+            let value_start = start + pattern.len();
+            if let Some(end) = tag[value_start..].find(quote) {
+                return Some(tag[value_start..value_start + end].to_string());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_capacity_and_full_lines() {
+        assert_eq!(
+            parse_capacity_line("Your black leather backpack can hold 20 items."),
+            Some(CapacityEvent::Holds {
+                container: "black leather backpack".into(),
+                capacity: 20
+            })
+        );
+        assert_eq!(
+            parse_capacity_line("The bandolier can only hold up to 12 items."),
+            Some(CapacityEvent::Holds {
+                container: "bandolier".into(),
+                capacity: 12
+            })
+        );
+        assert_eq!(
+            parse_capacity_line("There isn't any more room in the backpack for that."),
+            Some(CapacityEvent::Full {
+                container: "backpack".into()
+            })
+        );
+        assert_eq!(
+            parse_capacity_line("The longsword won't fit in your cloak."),
+            None
+        );
+        assert_eq!(
+            parse_capacity_line("Your haversack is too full to hold that."),
+            Some(CapacityEvent::Full {
+                container: "haversack".into()
+            })
+        );
+        assert_eq!(parse_capacity_line("You are too full to eat that."), None);
+        assert_eq!(parse_capacity_line("You put a gem in your backpack."), None);
+    }
+
+    #[test]
+    fn parses_item_links_and_move_commands() {
+        let segments = parse_item_line(r#"a <a exist="123" noun="gem">blue gem</a> (cut)"#);
+        let texts: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["a ", "blue gem", " (cut)"]);
+        let link = segments[1].link_data.as_ref().unwrap();
+        assert_eq!((link.exist_id.as_str(), link.noun.as_str()), ("123", "gem"));
+
+        let item_move = ItemMove {
+            item_id: "123".into(),
+            item_name: "blue gem".into(),
+            from_id: "1".into(),
+            from_title: "Backpack".into(),
+            to_id: "2".into(),
+            to_title: "Cloak".into(),
+        };
+        assert_eq!(item_move.commands(), ["get #123 from #1", "put #123 in #2"]);
+    }
+
+    #[test]
+    fn extract_attribute_double_quotes() {
+        let tag = r#"<a exist="12345" noun="sword">"#;
+        let result = extract_attribute(tag, "exist");
+        assert_eq!(result, Some("12345".to_string()));
+    }
+
+    #[test]
+    fn extract_attribute_single_quotes() {
+        let tag = r#"<a exist='67890' noun='dagger'>"#;
+        let result = extract_attribute(tag, "noun");
+        assert_eq!(result, Some("dagger".to_string()));
+    }
+
+    #[test]
+    fn extract_attribute_missing() {
+        let tag = r#"<a exist="12345">"#;
+        let result = extract_attribute(tag, "noun");
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn extract_attribute_empty_value() {
+        let tag = r#"<a exist="" noun="test">"#;
+        let result = extract_attribute(tag, "exist");
+        assert_eq!(result, Some("".to_string()));
+    }
+}
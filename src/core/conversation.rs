@@ -0,0 +1,242 @@
+//! Conversation view for chat windows (`conversation = true` on a text
+//! window).
+//!
+//! Busy thought channels are hard to follow when every line repeats the
+//! channel and sender. With the view on, a line that starts with a
+//! recognizable speaker (`[General]-GSIV:Bob: "..."`, `Bob says, "..."`)
+//! opens a group under a dim header naming the speaker, and the speaker's
+//! following lines are added indented beneath it without the prefix. Lines
+//! with no speaker end the group and are added unchanged.
+//!
+//! The restructuring happens as lines enter the window's buffer, so every
+//! frontend shows the same groups; renderers only need to keep the indent
+//! as a hanging indent when a line wraps (see `INDENT`).
+
+use crate::data::{SpanType, StyledLine, TextContent, TextSegment};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Leading segment of an indented conversation line: an `Annotation`
+/// segment of exactly this text. Renderers repeat it on wrapped rows.
+pub const INDENT: &str = "  ";
+
+/// GemStone ESP: `[General]-GSIV:Bob: "..."`
+static RE_CHANNEL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[([^\]]+)\]-[^:]*:([A-Z][A-Za-z'-]+): ").unwrap());
+/// DragonRealms: `Your mind hears Bob thinking, "..."`
+static RE_HEARD: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Your mind hears ([A-Z][A-Za-z'-]+) thinking, ").unwrap());
+/// Speech: `Bob says, "..."`, `You ask, "..."`, `Bob whispers to you, "..."`
+static RE_SPEECH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"^([A-Z][A-Za-z'-]+) (?:says?|asks?|exclaims?|whispers?|yells?|sings?|recites?|thinks?)\b[^,"]*, ""#,
+    )
+    .unwrap()
+});
+
+/// Who opened a line, and where the text after the speaker prefix starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Speaker {
+    pub name: String,
+    /// Thought channel, when the line names one
+    pub channel: Option<String>,
+    /// Byte offset of the line's text after the prefix
+    pub body_start: usize,
+}
+
+impl Speaker {
+    /// Groups break when either the speaker or the channel changes
+    fn key(&self) -> String {
+        match &self.channel {
+            Some(channel) => format!("{}@{}", self.name, channel),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// The speaker of `text`, if it starts like a chat line. For speech the
+/// verb stays in the body ("says, ..."), since it tells says from
+/// whispers; thought prefixes are dropped entirely.
+pub fn speaker(text: &str) -> Option<Speaker> {
+    if let Some(caps) = RE_CHANNEL.captures(text) {
+        return Some(Speaker {
+            name: caps[2].to_string(),
+            channel: Some(caps[1].to_string()),
+            body_start: caps.get(0)?.end(),
+        });
+    }
+    if let Some(caps) = RE_HEARD.captures(text) {
+        return Some(Speaker {
+            name: caps[1].to_string(),
+            channel: None,
+            body_start: caps.get(0)?.end(),
+        });
+    }
+    let caps = RE_SPEECH.captures(text)?;
+    let name = caps.get(1)?;
+    Some(Speaker {
+        name: name.as_str().to_string(),
+        channel: None,
+        body_start: name.end() + 1,
+    })
+}
+
+/// Add `line` to `content`, grouped by speaker when the conversation view
+/// is on (otherwise exactly `add_or_collapse`)
+pub fn add_line(content: &mut TextContent, line: StyledLine) {
+    if !content.conversation {
+        content.add_or_collapse(line);
+        return;
+    }
+    // A cleared buffer lost its header; the next line starts a new group
+    if content.lines.is_empty() {
+        content.conversation_speaker = None;
+    }
+    for line in group(line, &mut content.conversation_speaker) {
+        content.add_or_collapse(line);
+    }
+}
+
+/// Restructure one line against the current group's speaker `last`: a
+/// header plus the indented body for a new speaker, the indented body alone
+/// for the same one, the line unchanged when it has no speaker.
+pub fn group(line: StyledLine, last: &mut Option<String>) -> Vec<StyledLine> {
+    let text: String = line.segments.iter().map(|s| s.text.as_str()).collect();
+    let Some(speaker) = speaker(&text) else {
+        *last = None;
+        return vec![line];
+    };
+
+    let key = speaker.key();
+    let mut body = vec![TextSegment {
+        text: INDENT.to_string(),
+        span_type: SpanType::Annotation,
+        ..Default::default()
+    }];
+    body.extend(split_off(&line.segments, speaker.body_start));
+    let body = StyledLine {
+        segments: body,
+        stream: line.stream.clone(),
+        timestamp: line.timestamp,
+    };
+    if last.as_deref() == Some(key.as_str()) {
+        return vec![body];
+    }
+    *last = Some(key);
+
+    let mut header = vec![TextSegment {
+        text: speaker.name,
+        bold: true,
+        span_type: SpanType::Annotation,
+        ..Default::default()
+    }];
+    if let Some(channel) = speaker.channel {
+        header.push(TextSegment {
+            text: format!(" · {}", channel),
+            span_type: SpanType::Annotation,
+            ..Default::default()
+        });
+    }
+    vec![
+        StyledLine {
+            segments: header,
+            stream: line.stream,
+            timestamp: line.timestamp,
+        },
+        body,
+    ]
+}
+
+/// The segments covering text from byte `start` on, styling kept
+fn split_off(segments: &[TextSegment], start: usize) -> Vec<TextSegment> {
+    let mut offset = 0;
+    let mut out = Vec::new();
+    for segment in segments {
+        let end = offset + segment.text.len();
+        if end > start {
+            let mut segment = segment.clone();
+            if offset < start {
+                segment.text = segment.text[start - offset..].to_string();
+            }
+            out.push(segment);
+        }
+        offset = end;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(lines: &[StyledLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.segments.iter().map(|s| s.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn recognizes_thought_and_speech_prefixes() {
+        let thought = speaker(r#"[General]-GSIV:Bob: "Anyone hunting?""#).unwrap();
+        assert_eq!(thought.name, "Bob");
+        assert_eq!(thought.channel.as_deref(), Some("General"));
+        assert_eq!(thought.body_start, "[General]-GSIV:Bob: ".len());
+
+        let said = speaker(r#"Alice asks, "Where?""#).unwrap();
+        assert_eq!((said.name.as_str(), said.body_start), ("Alice", 6));
+        assert_eq!(speaker(r#"You say, "Hi.""#).unwrap().name, "You");
+        assert!(speaker("Bob just arrived.").is_none());
+    }
+
+    #[test]
+    fn groups_consecutive_lines_by_speaker() {
+        let mut content = TextContent::new("thoughts", 100);
+        content.conversation = true;
+        for text in [
+            r#"[General]-GSIV:Bob: "Anyone hunting?""#,
+            r#"[General]-GSIV:Bob: "Need a group.""#,
+            r#"[General]-GSIV:Carol: "Sure.""#,
+            r#"[Merchant]-GSIV:Carol: "Selling ora.""#,
+        ] {
+            add_line(
+                &mut content,
+                StyledLine::from_text_with_stream(text, "thoughts"),
+            );
+        }
+        let lines: Vec<StyledLine> = content.lines.iter().cloned().collect();
+        assert_eq!(
+            texts(&lines),
+            [
+                "Bob · General",
+                r#"  "Anyone hunting?""#,
+                r#"  "Need a group.""#,
+                "Carol · General",
+                r#"  "Sure.""#,
+                "Carol · Merchant",
+                r#"  "Selling ora.""#,
+            ]
+        );
+        assert_eq!(lines[0].segments[0].span_type, SpanType::Annotation);
+    }
+
+    #[test]
+    fn keeps_styling_and_breaks_on_plain_lines() {
+        let line = StyledLine {
+            segments: vec![
+                TextSegment::plain("Bob"),
+                TextSegment::styled(r#" says, "Hi.""#, Some("#ffffff".into()), false),
+            ],
+            stream: "speech".into(),
+            timestamp: None,
+        };
+        let mut last = None;
+        let grouped = group(line.clone(), &mut last);
+        assert_eq!(texts(&grouped), ["Bob", r#"  says, "Hi.""#]);
+        assert_eq!(grouped[1].segments[1].fg.as_deref(), Some("#ffffff"));
+
+        let plain = group(StyledLine::from_text("Bob waves."), &mut last);
+        assert_eq!(texts(&plain), ["Bob waves."]);
+        assert_eq!(group(line, &mut last).len(), 2);
+    }
+}
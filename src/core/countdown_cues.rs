@@ -0,0 +1,103 @@
+//! Sound cues for timers running out: roundtime and casttime reaching
+//! zero, and a stun wearing off (`[sound] roundtime_cue`, `casttime_cue`,
+//! `stun_cue`).
+//!
+//! Roundtime and casttime arrive as the server time they end, so the cue
+//! comes from that end time passing on the server clock, checked every
+//! frame, not from any text. A timer only cues if it was seen running:
+//! the stale end time from before a reconnect stays quiet. Stuns have no
+//! end time in the feed; the cue is the stunned indicator clearing.
+
+use crate::core::state::GameState;
+
+/// A timer event with a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountdownCue {
+    Roundtime,
+    Casttime,
+    Stun,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CountdownCues {
+    /// End times (server seconds) seen in the future and not yet cued
+    roundtime: Option<i64>,
+    casttime: Option<i64>,
+    stunned: bool,
+}
+
+impl CountdownCues {
+    /// Compare the game state with what was seen last frame. `now_ms` is
+    /// server time in milliseconds.
+    pub fn update(&mut self, game_state: &GameState, now_ms: i64) -> Vec<CountdownCue> {
+        let mut cues = Vec::new();
+        if expired(&mut self.roundtime, game_state.roundtime_end, now_ms) {
+            cues.push(CountdownCue::Roundtime);
+        }
+        if expired(&mut self.casttime, game_state.casttime_end, now_ms) {
+            cues.push(CountdownCue::Casttime);
+        }
+        let stunned = game_state.status.stunned;
+        if self.stunned && !stunned {
+            cues.push(CountdownCue::Stun);
+        }
+        self.stunned = stunned;
+        cues
+    }
+}
+
+/// Track one timer: arm on an end time still ahead, fire once it passes
+fn expired(armed: &mut Option<i64>, end: Option<i64>, now_ms: i64) -> bool {
+    if let Some(end) = end.filter(|&end| end * 1000 > now_ms) {
+        // New, extended or shortened timer
+        *armed = Some(end);
+        return false;
+    }
+    armed.take_if(|end| *end * 1000 <= now_ms).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn cues_once_when_a_running_timer_ends() {
+        let mut cues = CountdownCues::default();
+        let mut gs = GameState::new();
+        // Left over from before we were watching
+        gs.roundtime_end = Some(NOW - 10);
+        assert!(cues.update(&gs, NOW * 1000).is_empty());
+
+        gs.roundtime_end = Some(NOW + 3);
+        assert!(cues.update(&gs, NOW * 1000).is_empty());
+        assert!(cues.update(&gs, (NOW + 3) * 1000 - 1).is_empty());
+        assert_eq!(
+            cues.update(&gs, (NOW + 3) * 1000),
+            vec![CountdownCue::Roundtime]
+        );
+        assert!(cues.update(&gs, (NOW + 4) * 1000).is_empty());
+
+        // Extended before it ran out: only the later end cues
+        gs.casttime_end = Some(NOW + 6);
+        cues.update(&gs, (NOW + 4) * 1000);
+        gs.casttime_end = Some(NOW + 8);
+        assert!(cues.update(&gs, (NOW + 7) * 1000).is_empty());
+        assert_eq!(
+            cues.update(&gs, (NOW + 8) * 1000),
+            vec![CountdownCue::Casttime]
+        );
+    }
+
+    #[test]
+    fn cues_when_stun_clears() {
+        let mut cues = CountdownCues::default();
+        let mut gs = GameState::new();
+        gs.status.stunned = true;
+        assert!(cues.update(&gs, NOW * 1000).is_empty());
+        gs.status.stunned = false;
+        assert_eq!(cues.update(&gs, NOW * 1000), vec![CountdownCue::Stun]);
+        assert!(cues.update(&gs, NOW * 1000).is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+//! Experience absorption rate and "clear as a bell" ETA (GS4).
+//!
+//! Field experience drains in pulses as the mind absorbs it. Every drop in
+//! field experience is recorded as a pulse; the absorption rate is what the
+//! pulses of the last `WINDOW` absorbed over the time they span, and the
+//! ETA is the field experience left at that rate. Gains from hunting raise
+//! the amount left but never count toward the rate.
+//!
+//! The exact field experience from the mindState bar attributes is used
+//! when the game sends it; otherwise the mind state percentage is sampled,
+//! which gives the same ETA more coarsely.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How far back pulses count toward the rate
+pub const WINDOW: Duration = Duration::from_secs(15 * 60);
+
+/// One drain of field experience
+#[derive(Debug, Clone, Copy)]
+struct Pulse {
+    at: Instant,
+    absorbed: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AbsorbTracker {
+    pulses: VecDeque<Pulse>,
+    /// Latest amount left and when it was seen
+    last: Option<(u64, Instant)>,
+    /// Samples are exact field experience rather than mind state percent
+    exact: bool,
+}
+
+impl AbsorbTracker {
+    /// Note the field experience (or mind state percent when `exact` is
+    /// false) now. Switching between the two starts over.
+    pub fn observe(&mut self, amount: u64, exact: bool, now: Instant) {
+        if exact != self.exact {
+            *self = Self {
+                exact,
+                ..Self::default()
+            };
+        }
+        if let Some((previous, _)) = self.last {
+            if amount < previous {
+                self.pulses.push_back(Pulse {
+                    at: now,
+                    absorbed: previous - amount,
+                });
+            }
+        }
+        self.last = Some((amount, now));
+        while self
+            .pulses
+            .front()
+            .is_some_and(|pulse| now.duration_since(pulse.at) > WINDOW)
+        {
+            self.pulses.pop_front();
+        }
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Absorbed per minute over the recent pulses; None until two pulses
+    /// have been seen
+    pub fn rate_per_minute(&self) -> Option<f64> {
+        let first = self.pulses.front()?;
+        let last = self.pulses.back()?;
+        let span = last.at.duration_since(first.at).as_secs_f64();
+        if span <= 0.0 {
+            return None;
+        }
+        // The first pulse's drain happened before the span began
+        let absorbed: u64 = self.pulses.iter().skip(1).map(|p| p.absorbed).sum();
+        Some(absorbed as f64 * 60.0 / span)
+    }
+
+    /// Time until the mind is clear, counting down from the last sample.
+    /// Some(ZERO) once clear; None while the rate is unknown.
+    pub fn eta(&self, now: Instant) -> Option<Duration> {
+        let (left, seen) = self.last?;
+        if left == 0 {
+            return Some(Duration::ZERO);
+        }
+        let rate = self.rate_per_minute().filter(|rate| *rate > 0.0)?;
+        let total = Duration::from_secs_f64(left as f64 / rate * 60.0);
+        Some(total.saturating_sub(now.duration_since(seen)))
+    }
+
+    /// Widget readout: `Clear in ~12m (45/min)`; None while clear or the
+    /// rate is unknown
+    pub fn readout(&self, now: Instant) -> Option<String> {
+        let eta = self.eta(now).filter(|eta| !eta.is_zero())?;
+        let mut text = format!(
+            "Clear in ~{}",
+            crate::core::reminders::format_duration(eta.as_secs() as i64)
+        );
+        if self.exact {
+            if let Some(rate) = self.rate_per_minute() {
+                text.push_str(&format!(" ({:.0}/min)", rate));
+            }
+        }
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(n: u64) -> Duration {
+        Duration::from_secs(n)
+    }
+
+    #[test]
+    fn rate_and_eta_from_pulses() {
+        let start = Instant::now();
+        let mut tracker = AbsorbTracker::default();
+        tracker.observe(1000, true, start);
+        assert_eq!(tracker.eta(start), None);
+        tracker.observe(950, true, start + secs(60));
+        assert_eq!(tracker.rate_per_minute(), None);
+        tracker.observe(900, true, start + secs(120));
+        tracker.observe(850, true, start + secs(180));
+        assert_eq!(tracker.rate_per_minute(), Some(50.0));
+        // 850 left at 50/min is 17 minutes, 1 of which has passed
+        assert_eq!(tracker.eta(start + secs(240)), Some(secs(16 * 60)));
+        assert_eq!(
+            tracker.readout(start + secs(240)).as_deref(),
+            Some("Clear in ~16m (50/min)")
+        );
+    }
+
+    #[test]
+    fn gains_do_not_count_as_absorption() {
+        let start = Instant::now();
+        let mut tracker = AbsorbTracker::default();
+        tracker.observe(100, true, start);
+        tracker.observe(90, true, start + secs(60));
+        tracker.observe(200, true, start + secs(90));
+        tracker.observe(190, true, start + secs(120));
+        assert_eq!(tracker.rate_per_minute(), Some(10.0));
+        assert_eq!(tracker.eta(start + secs(120)), Some(secs(19 * 60)));
+    }
+
+    #[test]
+    fn clear_mind_and_old_pulses() {
+        let start = Instant::now();
+        let mut tracker = AbsorbTracker::default();
+        tracker.observe(20, false, start);
+        tracker.observe(10, false, start + secs(60));
+        tracker.observe(0, false, start + secs(120));
+        assert_eq!(tracker.eta(start + secs(120)), Some(Duration::ZERO));
+        assert_eq!(tracker.readout(start + secs(120)), None);
+
+        // Pulses older than the window no longer count
+        tracker.observe(50, false, start + WINDOW + secs(121));
+        assert_eq!(tracker.rate_per_minute(), None);
+    }
+
+    #[test]
+    fn switching_to_exact_samples_starts_over() {
+        let start = Instant::now();
+        let mut tracker = AbsorbTracker::default();
+        tracker.observe(40, false, start);
+        tracker.observe(30, false, start + secs(60));
+        tracker.observe(5000, true, start + secs(61));
+        assert!(tracker.is_exact());
+        assert_eq!(tracker.eta(start + secs(61)), None);
+    }
+}
@@ -0,0 +1,220 @@
+//! `.export <window> <file>`: write a window's scrollback to a file with
+//! its colors, as ANSI text or a standalone HTML page.
+//!
+//! The format follows the file extension: `.html`/`.htm` gets HTML with
+//! inline styles, anything else gets 24-bit ANSI escapes (view it with
+//! `cat` or `less -R`). Colors are the ones on the stored lines, so
+//! highlights come through; unstyled links take the `links` preset like
+//! the windows do. Relative file names land in `~/.vellum-fe/exports/`.
+
+use crate::data::{SpanType, StyledLine, TextSegment};
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Page colors for HTML exports (the ANSI export leaves the terminal's own)
+const HTML_FG: &str = "#c0c0c0";
+const HTML_BG: &str = "#000000";
+/// Repeat counters and other client annotations render dim
+const ANNOTATION_FG: &str = "#808080";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Ansi,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") => {
+                Self::Html
+            }
+            _ => Self::Ansi,
+        }
+    }
+}
+
+/// Where `.export` writes `file`: absolute paths as given, relative ones
+/// under the exports directory
+pub fn resolve_path(file: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(file);
+    if path.is_absolute() {
+        Ok(path)
+    } else {
+        Ok(crate::config::Config::exports_dir()?.join(path))
+    }
+}
+
+/// Write `lines` to `path` in the format its extension names
+pub fn export_lines(
+    path: &Path,
+    title: &str,
+    lines: &[StyledLine],
+    link_fg: Option<&str>,
+) -> Result<()> {
+    let body = match ExportFormat::from_path(path) {
+        ExportFormat::Ansi => to_ansi(lines, link_fg),
+        ExportFormat::Html => to_html(title, lines, link_fg),
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Could not create {}", parent.display()))?;
+    }
+    std::fs::write(path, body).with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// The color a segment is drawn in, if any
+fn segment_fg<'a>(segment: &'a TextSegment, link_fg: Option<&'a str>) -> Option<&'a str> {
+    match (segment.fg.as_deref(), segment.span_type) {
+        (Some(fg), _) => Some(fg),
+        (None, SpanType::Link) => link_fg,
+        (None, SpanType::Annotation) => Some(ANNOTATION_FG),
+        (None, _) => None,
+    }
+}
+
+fn hex_rgb(hex: &str) -> Option<(u8, u8, u8)> {
+    let hex = hex.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Lines as text with 24-bit ANSI color escapes, one line per row
+pub fn to_ansi(lines: &[StyledLine], link_fg: Option<&str>) -> String {
+    let mut out = String::new();
+    for line in lines {
+        for segment in &line.segments {
+            let mut codes = Vec::new();
+            if segment.bold {
+                codes.push("1".to_string());
+            }
+            if let Some((r, g, b)) = segment_fg(segment, link_fg).and_then(hex_rgb) {
+                codes.push(format!("38;2;{};{};{}", r, g, b));
+            }
+            if let Some((r, g, b)) = segment.bg.as_deref().and_then(hex_rgb) {
+                codes.push(format!("48;2;{};{};{}", r, g, b));
+            }
+            if codes.is_empty() {
+                out.push_str(&segment.text);
+            } else {
+                out.push_str(&format!("\x1b[{}m{}\x1b[0m", codes.join(";"), segment.text));
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Lines as a standalone HTML page with inline styles
+pub fn to_html(title: &str, lines: &[StyledLine], link_fg: Option<&str>) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n\
+         <body style=\"margin:0;background:{bg}\">\n\
+         <pre style=\"margin:0;padding:8px;background:{bg};color:{fg};\
+         font-family:monospace;white-space:pre-wrap\">",
+        escape_html(title),
+        bg = HTML_BG,
+        fg = HTML_FG,
+    );
+    for line in lines {
+        for segment in &line.segments {
+            let mut style = Vec::new();
+            // Colors are only ever emitted from validated hex
+            if let Some(fg) = segment_fg(segment, link_fg).filter(|fg| hex_rgb(fg).is_some()) {
+                style.push(format!("color:{}", fg));
+            }
+            if let Some(bg) = segment.bg.as_deref().filter(|bg| hex_rgb(bg).is_some()) {
+                style.push(format!("background:{}", bg));
+            }
+            if segment.bold {
+                style.push("font-weight:bold".to_string());
+            }
+            let text = escape_html(&segment.text);
+            if style.is_empty() {
+                out.push_str(&text);
+            } else {
+                out.push_str(&format!(
+                    "<span style=\"{}\">{}</span>",
+                    style.join(";"),
+                    text
+                ));
+            }
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>\n</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines() -> Vec<StyledLine> {
+        vec![StyledLine {
+            segments: vec![
+                TextSegment::plain("A "),
+                TextSegment::styled("troll", Some("#ff0000".into()), true),
+                TextSegment {
+                    text: " <x2>".to_string(),
+                    span_type: SpanType::Annotation,
+                    ..Default::default()
+                },
+            ],
+            stream: "main".into(),
+            timestamp: None,
+        }]
+    }
+
+    #[test]
+    fn ansi_keeps_colors_and_bold() {
+        assert_eq!(
+            to_ansi(&lines(), None),
+            "A \x1b[1;38;2;255;0;0mtroll\x1b[0m\x1b[38;2;128;128;128m <x2>\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn html_escapes_text_and_inlines_styles() {
+        let html = to_html("Main & Co", &lines(), None);
+        assert!(html.contains("<title>Main &amp; Co</title>"));
+        assert!(html.contains(
+            "A <span style=\"color:#ff0000;font-weight:bold\">troll</span>\
+             <span style=\"color:#808080\"> &lt;x2&gt;</span>\n"
+        ));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn format_follows_extension() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("hunt.HTML")),
+            ExportFormat::Html
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("hunt.ans")),
+            ExportFormat::Ansi
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("hunt")),
+            ExportFormat::Ansi
+        );
+    }
+}
@@ -0,0 +1,843 @@
+//! Content behind `.help [topic]`.
+//!
+//! The dot-command registry here is the single list of commands: `.help`
+//! renders it, tab completion offers its names, and a test checks that every
+//! name is dispatched in `app_core/commands.rs`, so a new command can't be
+//! forgotten in one place. The keybind and widget pages are built from the
+//! live config and the window templates rather than written by hand.
+
+use crate::config::{Config, KeyBindAction};
+use crate::data::{StyledLine, TextSegment};
+
+/// Color of section headings
+const HEADING_COLOR: &str = "#00ffff";
+/// Column the command summaries line up at
+const SUMMARY_COLUMN: usize = 26;
+
+/// One row of the command reference
+pub struct CommandHelp {
+    /// The command and its aliases, without the dot
+    pub names: &'static [&'static str],
+    pub args: &'static str,
+    pub summary: &'static str,
+}
+
+pub struct HelpSection {
+    pub title: &'static str,
+    pub commands: &'static [CommandHelp],
+}
+
+const fn cmd(
+    names: &'static [&'static str],
+    args: &'static str,
+    summary: &'static str,
+) -> CommandHelp {
+    CommandHelp {
+        names,
+        args,
+        summary,
+    }
+}
+
+/// Every dot-command, grouped as `.help` lists them. A command may have
+/// several rows when its forms do different things.
+pub const SECTIONS: &[HelpSection] = &[
+    HelpSection {
+        title: "Application",
+        commands: &[
+            cmd(
+                &["quit", "q"],
+                "[yes|now]",
+                "Exit VellumFE (logs out first while connected)",
+            ),
+            cmd(
+                &["help", "h", "?"],
+                "[topic]",
+                "Show this help (a command, section, widget type, 'keys' or 'widgets')",
+            ),
+            cmd(&["version", "ver"], "", "Show version info"),
+            cmd(&["menu"], "", "Open main menu"),
+            cmd(&["settings"], "", "Open settings editor"),
+            cmd(
+                &["reload"],
+                "[category]",
+                "Reload config from disk (highlights|keybinds|hotbars|settings|colors|layout)",
+            ),
+            cmd(
+                &["set"],
+                "[-g] <name> <value>",
+                "Set a variable; %name% expands in commands and macros",
+            ),
+            cmd(&["unset"], "[-g] <name>", "Remove a variable"),
+            cmd(&["vars"], "", "List variables"),
+            cmd(
+                &["if"],
+                "<cond> then <cmd>",
+                "Send only if true (e.g. .if rt == 0 && health > 50 then attack)",
+            ),
+            cmd(
+                &["r", "reply", "rt"],
+                "<text>",
+                "Reply to the last whisper (.r) / thought (.rt)",
+            ),
+            cmd(
+                &["cmdlog"],
+                "[search]",
+                "Search sent commands and resend one",
+            ),
+            cmd(
+                &["netinfo"],
+                "",
+                "Show the address the connection used and any that failed",
+            ),
+            cmd(
+                &["played"],
+                "",
+                "Time played: this session, today, last 7/30 days, total",
+            ),
+            cmd(
+                &["away"],
+                "[message|off]",
+                "Toggle away mode (auto-reply to whispers)",
+            ),
+            cmd(
+                &["remind"],
+                "<45m> <message>",
+                "Reminder (also at|daily <9pm>, every <2h>, delete <n>)",
+            ),
+            cmd(
+                &["watch"],
+                "add <expr> [as <label>]",
+                "Watch a live value (also list, remove <n>, clear)",
+            ),
+            cmd(
+                &["notes", "note"],
+                "[add <text>]",
+                "Edit the character notepad / append a line",
+            ),
+            cmd(
+                &["inspect"],
+                "[on|off|clear|<tag>]",
+                "Parser inspector: raw XML, parsed elements, state changes",
+            ),
+            cmd(
+                &["traffic"],
+                "[on|off|hex|clear]",
+                "Raw traffic console: socket lines in and out",
+            ),
+            cmd(
+                &["testline"],
+                "<text>",
+                "Test highlights/squelch with a fake game line",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Map",
+        commands: &[
+            cmd(
+                &["room"],
+                "",
+                "Show how the current room resolved against the mapdb",
+            ),
+            cmd(
+                &["mapdb"],
+                "[download|remove|repo <r>]",
+                "Manage downloaded map data (status by default)",
+            ),
+            cmd(
+                &["go2"],
+                "<target>",
+                "Travel there (room id, uid, tag, saved name, or text search)",
+            ),
+            cmd(&["go2"], "stop|status", "Cancel / show the active trip"),
+            cmd(
+                &["go2"],
+                "save <name> [id]",
+                "Save a target (.go2 targets lists, .go2 back returns)",
+            ),
+            cmd(
+                &["go"],
+                "<route> [reverse]",
+                "Walk a saved route (also pause, resume, stop, save, list)",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Character",
+        commands: &[
+            cmd(
+                &["stance"],
+                "[next|prev]",
+                "Show stance, or step toward defensive/offensive",
+            ),
+            cmd(
+                &["silver"],
+                "[amount|reset]",
+                "Show, correct, or reset the silver tracker",
+            ),
+            cmd(
+                &["loot"],
+                "[reset]",
+                "Show or clear this session's loot tally",
+            ),
+            cmd(
+                &["combat"],
+                "[reset]",
+                "Show or clear this session's combat stats",
+            ),
+            cmd(
+                &["alarm"],
+                "[test]",
+                "Show alarm thresholds, or fire a test alert",
+            ),
+            cmd(
+                &["rebuff"],
+                "save|delete <set>",
+                "Record active spells as a set / forget one",
+            ),
+            cmd(
+                &["rebuff"],
+                "<set> [cast]",
+                "Show dropped spells in a set / recast them",
+            ),
+            cmd(
+                &["repeat"],
+                "<n|forever> <delay> <cmd>",
+                "Re-send a command every <delay>s after RT (stop or Esc ends)",
+            ),
+            cmd(
+                &["move"],
+                "yes|no",
+                "Confirm or cancel a dragged container item move",
+            ),
+            cmd(
+                &["containers"],
+                "",
+                "Toggle container discovery (LOOK IN a container to open a window)",
+            ),
+            cmd(
+                &["hidecontainers"],
+                "[title]",
+                "Close container windows (all, or the one titled)",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Layouts",
+        commands: &[
+            cmd(
+                &["savelayout"],
+                "[name]",
+                "Save current layout (default: 'default')",
+            ),
+            cmd(&["loadlayout"], "[name]", "Load a saved layout"),
+            cmd(&["layouts"], "", "List available layouts"),
+            cmd(
+                &["layouts"],
+                "browse",
+                "Preview layouts and apply one scaled to this terminal",
+            ),
+            cmd(&["layouts"], "get <url>", "Download a community layout"),
+            cmd(
+                &["layout"],
+                "undo|redo",
+                "Undo or redo a window move, resize, add, or hide (Ctrl+Z / Ctrl+Y)",
+            ),
+            cmd(
+                &["layout"],
+                "scale [apply|cancel]",
+                "Preview the layout scaled to this terminal, then apply it",
+            ),
+            cmd(
+                &["layout"],
+                "keys [profile|off]",
+                "Show or set the keybind profile this layout turns on",
+            ),
+            cmd(&["resize"], "", "Resize layout to current terminal"),
+        ],
+    },
+    HelpSection {
+        title: "Windows",
+        commands: &[
+            cmd(&["windows"], "", "List all windows"),
+            cmd(&["addwindow"], "", "Open widget type picker"),
+            cmd(
+                &["addwindow"],
+                "<name> <type> <x> <y> <w> [h]",
+                "Add window manually",
+            ),
+            cmd(&["deletewindow", "delwindow"], "<name>", "Delete a window"),
+            cmd(
+                &["hidewindow", "hidewin"],
+                "[name]",
+                "Hide window (or open picker)",
+            ),
+            cmd(
+                &["editwindow", "editwin"],
+                "[name]",
+                "Edit window (or open picker)",
+            ),
+            cmd(
+                &["dupwindow", "dupwin"],
+                "<name>",
+                "Add a copy of a window beside it",
+            ),
+            cmd(
+                &["copywindow", "copywin"],
+                "<name>",
+                "Copy a window definition to the clipboard as TOML",
+            ),
+            cmd(
+                &["pastewindow", "pastewin"],
+                "",
+                "Add window definitions from the clipboard",
+            ),
+            cmd(
+                &["window"],
+                "create <name> [streams=a,b] [rows=N] [cols=N] [at=col,row] [persist]",
+                "Open a text window for this session (persist adds it to the layout)",
+            ),
+            cmd(
+                &["window"],
+                "close <name>",
+                "Close a created window (layout windows are hidden)",
+            ),
+            cmd(&["rename"], "<win> <title>", "Rename window title"),
+            cmd(&["clear"], "<win>", "Wipe a window's scrollback"),
+            cmd(
+                &["freeze"],
+                "<win> [hold|drop|off]",
+                "Stop showing new lines (held for later, or dropped)",
+            ),
+            cmd(&["unfreeze"], "<win>", "Resume a frozen window"),
+            cmd(
+                &["border"],
+                "<win> <style> [color]",
+                "Set window border (all, none, top, bottom, left, right)",
+            ),
+            cmd(
+                &["float"],
+                "<win> [on|off]",
+                "Make window float above the layout",
+            ),
+            cmd(&["raise"], "<win>", "Bring floating window to front"),
+            cmd(
+                &["view"],
+                "[last|window]",
+                "Open output in a scrollable popup",
+            ),
+            cmd(
+                &["viewlog"],
+                "[date|diag]",
+                "Browse a session log (or vellum-fe.log) in the popup",
+            ),
+            cmd(
+                &["export"],
+                "<window> <file>",
+                "Save a window's text with colors (.html or ANSI)",
+            ),
+            cmd(
+                &["import"],
+                "<wizard|genie|stormfront|vellumfe> <file>",
+                "Bring colors, macros and triggers over from another client",
+            ),
+            cmd(
+                &["tee"],
+                "[window] [file|off]",
+                "Copy a window's lines to a text file as they arrive",
+            ),
+            cmd(
+                &["urls", "url"],
+                "[n]",
+                "List URLs seen in game text / open one",
+            ),
+            cmd(
+                &["compact"],
+                "[on|off|next|prev|win]",
+                "Single tabbed view for small screens",
+            ),
+            cmd(
+                &["readscreen"],
+                "",
+                "Read visible windows top-to-bottom (spoken with TTS)",
+            ),
+            cmd(
+                &["tts"],
+                "stop|skip|mute",
+                "Flush the speech queue / skip the current line / mute",
+            ),
+            cmd(
+                &["tts"],
+                "status|queue [n]",
+                "Show queue state / set max queue depth",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Tabs",
+        commands: &[
+            cmd(&["nexttab"], "", "Switch to next tab"),
+            cmd(&["prevtab"], "", "Switch to previous tab"),
+            cmd(
+                &["gonew", "nextunread"],
+                "",
+                "Jump to next tab with unread messages",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Toggles",
+        commands: &[
+            cmd(
+                &["transparent"],
+                "",
+                "Toggle transparent backgrounds on all windows",
+            ),
+            cmd(
+                &["lockwindows", "lockall", "unlockwindows", "unlockall"],
+                "",
+                "Toggle lock on all windows (prevent move/resize)",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Highlights",
+        commands: &[
+            cmd(&["highlights", "hl"], "", "Open highlights browser"),
+            cmd(&["addhighlight", "addhl"], "", "Create new highlight"),
+            cmd(
+                &["edithighlight", "edithl"],
+                "<name>",
+                "Edit existing highlight",
+            ),
+            cmd(
+                &["savehighlights", "savehl"],
+                "[name]",
+                "Save highlights as profile (default: 'default')",
+            ),
+            cmd(
+                &["loadhighlights", "loadhl"],
+                "[name]",
+                "Load highlights from profile",
+            ),
+            cmd(
+                &["highlightprofiles", "hlprofiles"],
+                "",
+                "List saved highlight profiles",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Keybinds",
+        commands: &[
+            cmd(&["keybinds", "kb"], "", "Open keybinds browser"),
+            cmd(&["addkeybind", "addkey"], "", "Create new keybind"),
+            cmd(
+                &["savekeybinds", "savekb"],
+                "[name]",
+                "Save keybinds as profile (default: 'default')",
+            ),
+            cmd(
+                &["loadkeybinds", "loadkb"],
+                "<name>",
+                "Load keybinds from profile",
+            ),
+            cmd(
+                &["keybindprofiles", "kbprofiles"],
+                "",
+                "List saved keybind profiles",
+            ),
+            cmd(
+                &["hotbars", "hotbar"],
+                "",
+                "Open hotbar editor (add a bar with a 'hotkeybar' window)",
+            ),
+        ],
+    },
+    HelpSection {
+        title: "Colors",
+        commands: &[
+            cmd(
+                &["colors", "colorpalette"],
+                "",
+                "Open color palette browser",
+            ),
+            cmd(&["addcolor", "createcolor"], "", "Create new palette color"),
+            cmd(&["uicolors"], "", "Open UI colors browser"),
+            cmd(&["spellcolors"], "", "Open spell colors browser"),
+            cmd(
+                &["addspellcolor", "newspellcolor"],
+                "",
+                "Create new spell color",
+            ),
+            cmd(&["setpalette"], "", "Load palette colors into terminal"),
+            cmd(&["resetpalette"], "", "Reset terminal palette to defaults"),
+        ],
+    },
+    HelpSection {
+        title: "Themes",
+        commands: &[
+            cmd(&["themes"], "", "Open themes browser"),
+            cmd(&["settheme", "theme"], "<name>", "Switch to a theme"),
+            cmd(&["edittheme"], "", "Edit current theme"),
+            cmd(&["skins"], "", "List installed GUI skins"),
+            cmd(
+                &["setskin", "skin"],
+                "<name>",
+                "Activate a GUI skin (.setskin none to disable)",
+            ),
+            cmd(&["makeskin"], "<name>", "Create a starter skin to edit"),
+            cmd(&["reloadskin"], "", "Reload the active skin's images"),
+        ],
+    },
+    HelpSection {
+        title: "Web",
+        commands: &[
+            cmd(
+                &["webinfo"],
+                "",
+                "Show the pairing URL and QR code for the web frontend",
+            ),
+            cmd(
+                &["reloadmacros"],
+                "",
+                "Reload macros.toml and push it to connected phones",
+            ),
+            cmd(
+                &["webui"],
+                "[page|off]",
+                "Open the Lich WebUI page picker, or a page as a panel",
+            ),
+        ],
+    },
+];
+
+/// Every command name with its dot, in registry order, for tab completion
+pub fn command_names() -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for command in SECTIONS.iter().flat_map(|section| section.commands) {
+        for name in command.names {
+            let name = format!(".{}", name);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// A rendered help page for the popup viewer
+pub struct HelpPage {
+    pub title: String,
+    pub lines: Vec<StyledLine>,
+    /// Query to search for on open, when the topic matched nothing directly
+    pub search: Option<String>,
+}
+
+fn line(segments: Vec<TextSegment>) -> StyledLine {
+    StyledLine {
+        segments,
+        stream: "main".into(),
+        timestamp: None,
+    }
+}
+
+fn text(text: impl Into<String>) -> StyledLine {
+    line(vec![TextSegment::plain(text)])
+}
+
+fn heading(lines: &mut Vec<StyledLine>, title: &str) {
+    if !lines.is_empty() {
+        lines.push(text(""));
+    }
+    lines.push(line(vec![TextSegment::styled(
+        title.to_uppercase(),
+        Some(HEADING_COLOR.to_string()),
+        true,
+    )]));
+}
+
+fn command_row(command: &CommandHelp) -> StyledLine {
+    let mut usage = command
+        .names
+        .iter()
+        .map(|name| format!(".{}", name))
+        .collect::<Vec<_>>()
+        .join(" / ");
+    if !command.args.is_empty() {
+        usage.push(' ');
+        usage.push_str(command.args);
+    }
+    text(format!(
+        "  {:<width$} - {}",
+        usage,
+        command.summary,
+        width = SUMMARY_COLUMN - 2
+    ))
+}
+
+fn push_section(lines: &mut Vec<StyledLine>, section: &HelpSection) {
+    heading(lines, section.title);
+    lines.extend(section.commands.iter().map(command_row));
+}
+
+fn push_keybinds(lines: &mut Vec<StyledLine>, config: &Config) {
+    heading(lines, "Bound keys");
+    let app = &config.app_keybinds;
+    for (key, action) in [
+        (&app.quit, "quit"),
+        (&app.start_search, "start_search"),
+        (&app.next_search_match, "next_search_match"),
+        (&app.prev_search_match, "prev_search_match"),
+        (&app.close_window, "close_window"),
+    ] {
+        lines.push(text(format!("  {:<20} {}", key, action)));
+    }
+    let mut keys: Vec<(&String, &KeyBindAction)> = config.keybinds.iter().collect();
+    keys.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, action) in keys {
+        let value = match action {
+            KeyBindAction::Action(action) => action.clone(),
+            KeyBindAction::Macro(m) => format!("send \"{}\"", m.macro_text.escape_debug()),
+            KeyBindAction::Sequence(s) => format!("sequence \"{}\"", s.to_text()),
+            KeyBindAction::Call(c) => c.to_text(),
+        };
+        lines.push(text(format!("  {:<20} {}", key, value)));
+    }
+}
+
+/// A widget type with the templates that use it and the options its
+/// window definitions take (beyond the options every window has)
+struct WidgetHelp {
+    widget_type: String,
+    templates: Vec<String>,
+    options: Vec<String>,
+}
+
+/// Option names of a serialized window definition, minus `except`
+fn option_names<T: serde::Serialize>(value: &T, except: &[String]) -> Vec<String> {
+    match toml::Value::try_from(value) {
+        Ok(toml::Value::Table(table)) => table
+            .keys()
+            .filter(|key| *key != "widget_type" && !except.contains(key))
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Widget types from the window templates, plus the options all windows share
+fn widget_types() -> (Vec<String>, Vec<WidgetHelp>) {
+    let mut common = Vec::new();
+    let mut widgets: Vec<WidgetHelp> = Vec::new();
+    for name in Config::list_window_templates() {
+        let Some(template) = Config::get_window_template(&name) else {
+            continue;
+        };
+        if common.is_empty() {
+            common = option_names(template.base(), &[]);
+        }
+        match widgets
+            .iter_mut()
+            .find(|widget| widget.widget_type == template.widget_type())
+        {
+            Some(widget) => widget.templates.push(name),
+            None => widgets.push(WidgetHelp {
+                widget_type: template.widget_type().to_string(),
+                options: option_names(&template, &common),
+                templates: vec![name],
+            }),
+        }
+    }
+    widgets.sort_by(|a, b| a.widget_type.cmp(&b.widget_type));
+    (common, widgets)
+}
+
+fn push_widget(lines: &mut Vec<StyledLine>, widget: &WidgetHelp) {
+    lines.push(line(vec![TextSegment::styled(
+        format!("  {}", widget.widget_type),
+        None,
+        true,
+    )]));
+    lines.push(text(format!(
+        "    templates: {}",
+        widget.templates.join(", ")
+    )));
+    if !widget.options.is_empty() {
+        lines.push(text(format!(
+            "    options:   {}",
+            widget.options.join(", ")
+        )));
+    }
+}
+
+fn push_widgets(lines: &mut Vec<StyledLine>, only: Option<&str>) {
+    let (common, widgets) = widget_types();
+    heading(lines, "Widget types");
+    lines.push(text(format!("  every window: {}", common.join(", "))));
+    for widget in widgets
+        .iter()
+        .filter(|widget| only.is_none_or(|only| widget.widget_type.eq_ignore_ascii_case(only)))
+    {
+        push_widget(lines, widget);
+    }
+}
+
+fn is_widget_type(topic: &str) -> bool {
+    Config::list_window_templates().iter().any(|name| {
+        Config::get_window_template(name)
+            .is_some_and(|template| template.widget_type().eq_ignore_ascii_case(topic))
+    })
+}
+
+/// Build the page for `.help [topic]`. The topic may be a section
+/// ("windows"), a command with or without its dot, a widget type, "keys",
+/// "widgets" or "commands"; anything else opens the whole reference
+/// searched for it.
+pub fn page(topic: &str, config: &Config) -> HelpPage {
+    let topic = topic.trim();
+    let name = topic.trim_start_matches('.').to_lowercase();
+    let mut lines = Vec::new();
+
+    let title = match name.as_str() {
+        "" => {
+            SECTIONS
+                .iter()
+                .for_each(|section| push_section(&mut lines, section));
+            push_keybinds(&mut lines, config);
+            push_widgets(&mut lines, None);
+            "Help".to_string()
+        }
+        "commands" | "cmds" => {
+            SECTIONS
+                .iter()
+                .for_each(|section| push_section(&mut lines, section));
+            "Help: commands".to_string()
+        }
+        "keys" | "keybinds" | "bindings" => {
+            if let Some(section) = SECTIONS.iter().find(|s| s.title == "Keybinds") {
+                push_section(&mut lines, section);
+            }
+            push_keybinds(&mut lines, config);
+            "Help: keybinds".to_string()
+        }
+        "widgets" | "widget" => {
+            push_widgets(&mut lines, None);
+            "Help: widgets".to_string()
+        }
+        _ => {
+            if let Some(section) = SECTIONS
+                .iter()
+                .find(|s| s.title.eq_ignore_ascii_case(&name))
+            {
+                push_section(&mut lines, section);
+                format!("Help: {}", section.title.to_lowercase())
+            } else if let Some(section) = SECTIONS
+                .iter()
+                .find(|s| s.commands.iter().any(|c| c.names.contains(&name.as_str())))
+            {
+                heading(&mut lines, section.title);
+                lines.extend(
+                    section
+                        .commands
+                        .iter()
+                        .filter(|c| c.names.contains(&name.as_str()))
+                        .map(command_row),
+                );
+                format!("Help: .{}", name)
+            } else if is_widget_type(&name) {
+                push_widgets(&mut lines, Some(&name));
+                format!("Help: {} widget", name)
+            } else {
+                let mut page = page("", config);
+                page.search = Some(topic.to_string());
+                return page;
+            }
+        }
+    };
+
+    HelpPage {
+        title,
+        lines,
+        search: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(page: &HelpPage) -> Vec<String> {
+        page.lines
+            .iter()
+            .map(|line| line.segments.iter().map(|s| s.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn every_registered_command_is_dispatched() {
+        let dispatch = include_str!("app_core/commands.rs");
+        for name in command_names() {
+            let quoted = format!("\"{}\"", &name[1..]);
+            assert!(
+                dispatch.contains(&quoted),
+                "{} is in the help registry but not handled in commands.rs",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn every_dispatched_command_is_registered() {
+        let dispatch = include_str!("app_core/commands.rs");
+        let start = dispatch
+            .find("match cmd.as_str() {")
+            .expect("dot-command dispatch");
+        let names = command_names();
+        // Arms of the dispatch match sit at one indent: `"name" | "alias" =>`
+        for line in dispatch[start..]
+            .lines()
+            .skip(1)
+            .take_while(|line| !line.starts_with("            _ =>"))
+            .filter(|line| line.starts_with("            \""))
+        {
+            let arm = line.split("=>").next().unwrap_or_default();
+            for name in arm.split('"').skip(1).step_by(2) {
+                assert!(
+                    names.contains(&format!(".{}", name)),
+                    ".{} is handled in commands.rs but missing from the help registry",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn command_names_are_unique_and_dotted() {
+        let names = command_names();
+        assert!(names.contains(&".help".to_string()));
+        assert!(names.contains(&".?".to_string()));
+        assert_eq!(names.iter().filter(|n| *n == ".go2").count(), 1);
+        assert!(names.iter().all(|n| n.starts_with('.')));
+    }
+
+    #[test]
+    fn topics_narrow_the_page() {
+        let config = Config::default();
+
+        let section = page("windows", &config);
+        assert_eq!(section.title, "Help: windows");
+        assert!(plain(&section).iter().any(|l| l.contains(".float")));
+        assert!(!plain(&section).iter().any(|l| l.contains(".quit")));
+
+        let command = page(".hl", &config);
+        let text = plain(&command);
+        assert_eq!(text.len(), 2, "{:?}", text);
+        assert!(text[1].contains(".highlights / .hl"));
+
+        let unknown = page("sword", &config);
+        assert_eq!(unknown.title, "Help");
+        assert_eq!(unknown.search.as_deref(), Some("sword"));
+    }
+}
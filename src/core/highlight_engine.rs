@@ -6,9 +6,11 @@
 //! NO frontend imports - works directly with TextSegment from the data layer.
 
 use crate::config::HighlightPattern;
+use crate::data::intern::intern;
 use crate::data::{LinkData, SpanType, TextSegment};
 use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
 use regex::Regex;
+use std::sync::Arc;
 
 /// Sound trigger from a highlight match
 #[derive(Clone, Debug)]
@@ -17,20 +19,6 @@ pub struct SoundTrigger {
     pub volume: Option<f32>,
 }
 
-/// A replacement that was deferred because it targets a specific window
-#[derive(Clone, Debug)]
-pub struct DeferredReplacement {
-    /// Character range in the original text (before any replacements)
-    pub start_char: usize,
-    pub end_char: usize,
-    /// The replacement text (with capture groups expanded)
-    pub replacement: String,
-    /// Window name this replacement targets
-    pub target_window: String,
-    /// Original matched text (for applying replacement)
-    pub original_text: String,
-}
-
 /// Result of applying highlights to text segments
 #[derive(Clone, Debug)]
 pub struct HighlightResult {
@@ -38,8 +26,6 @@ pub struct HighlightResult {
     pub segments: Vec<TextSegment>,
     /// Any sounds that should be triggered
     pub sounds: Vec<SoundTrigger>,
-    /// Replacements that target specific windows (applied during routing)
-    pub deferred_replacements: Vec<DeferredReplacement>,
     /// True if the ENTIRE line was covered by silent_prompt patterns (suppress prompt)
     pub line_is_silent: bool,
 }
@@ -48,8 +34,8 @@ pub struct HighlightResult {
 #[derive(Clone)]
 #[derive(Default)]
 struct CharStyle {
-    fg: Option<String>,
-    bg: Option<String>,
+    fg: Option<Arc<str>>,
+    bg: Option<Arc<str>>,
     bold: bool,
     mono: bool,
     span_type: SpanType,
@@ -65,8 +51,6 @@ struct MatchInfo {
     bold: bool,
     color_entire_line: bool,
     replace: Option<String>,
-    /// Window name for window-specific replacements (None = apply everywhere)
-    target_window: Option<String>,
     /// If true, this match contributes to silent prompt detection
     silent_prompt: bool,
 }
@@ -80,10 +64,36 @@ pub struct CoreHighlightEngine {
     fast_matcher: Option<AhoCorasick>,
     fast_pattern_map: Vec<usize>,
     replace_enabled: bool,
+    /// True if any pattern is scoped to windows (see apply_scoped_highlights)
+    has_scoped: bool,
     /// Hash of the highlights for change detection (see update_if_changed)
     highlights_hash: u64,
 }
 
+/// Window/tab names a pattern's `window` field scopes it to. The field takes
+/// a comma-separated list; blank entries are ignored, so an all-blank value
+/// leaves the pattern global.
+pub fn window_scope(highlight: &HighlightPattern) -> impl Iterator<Item = &str> {
+    highlight
+        .window
+        .as_deref()
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
+/// Does `highlight` apply in this pass? The global pass (`targets` None)
+/// takes unscoped patterns only; a window pass takes only the scoped
+/// patterns naming one of `targets` (case-insensitive).
+fn in_scope(highlight: &HighlightPattern, targets: Option<&[&str]>) -> bool {
+    let mut scope = window_scope(highlight).peekable();
+    match targets {
+        None => scope.peek().is_none(),
+        Some(names) => scope.any(|w| names.iter().any(|n| n.eq_ignore_ascii_case(w))),
+    }
+}
+
 impl CoreHighlightEngine {
     /// Compute a hash of highlight patterns for change detection
     pub fn compute_hash(highlights: &[HighlightPattern]) -> u64 {
@@ -99,6 +109,8 @@ impl CoreHighlightEngine {
             h.fast_parse.hash(&mut hasher);
             h.color_entire_line.hash(&mut hasher);
             h.replace.hash(&mut hasher);
+            h.stream.hash(&mut hasher);
+            h.window.hash(&mut hasher);
         }
         hasher.finish()
     }
@@ -161,6 +173,7 @@ impl CoreHighlightEngine {
             (None, Vec::new())
         };
 
+        let has_scoped = highlights.iter().any(|h| window_scope(h).next().is_some());
         let highlights_hash = Self::compute_hash(&highlights);
         Self {
             highlights,
@@ -168,6 +181,7 @@ impl CoreHighlightEngine {
             fast_matcher,
             fast_pattern_map,
             replace_enabled: true,
+            has_scoped,
             highlights_hash,
         }
     }
@@ -180,6 +194,7 @@ impl CoreHighlightEngine {
             fast_matcher: None,
             fast_pattern_map: Vec::new(),
             replace_enabled: true,
+            has_scoped: false,
             highlights_hash: Self::compute_hash(&[]),
         }
     }
@@ -198,23 +213,43 @@ impl CoreHighlightEngine {
     ///
     /// This is the main entry point called from MessageProcessor.
     /// Returns the segments with colors applied and any sounds to trigger.
+    /// Window-scoped patterns are skipped here; see apply_scoped_highlights.
     pub fn apply_highlights(
         &self,
         segments: &[TextSegment],
         stream: &str,
     ) -> HighlightResult {
-        self.apply_highlights_impl(segments, stream)
+        self.apply_highlights_impl(segments, stream, None)
             .unwrap_or_else(|| HighlightResult {
                 segments: segments.to_vec(),
                 sounds: Vec::new(),
-                deferred_replacements: Vec::new(),
                 line_is_silent: false,
             })
     }
 
+    /// True if any pattern is scoped to specific windows or tabs
+    pub fn has_scoped(&self) -> bool {
+        self.has_scoped
+    }
+
+    /// Apply only the window-scoped patterns naming one of `targets` (a
+    /// window and, for tabbed windows, the tab). Called while routing a line
+    /// into a window, after the global pass. `None` means nothing matched.
+    pub fn apply_scoped_highlights(
+        &self,
+        segments: &[TextSegment],
+        stream: &str,
+        targets: &[&str],
+    ) -> Option<HighlightResult> {
+        if !self.has_scoped {
+            return None;
+        }
+        self.apply_highlights_impl(segments, stream, Some(targets))
+    }
+
     /// Segment-only variant for frontend widgets: returns `None` when the
     /// line is untouched (callers keep their original segments, no clone).
-    /// Sounds, deferred replacements, and silent-prompt state are handled by
+    /// Sounds, window-scoped patterns, and silent-prompt state are handled by
     /// the message pipeline, not here - widget lines already passed through
     /// it, so acting on them again would double-fire.
     pub fn apply_highlights_to_segments(
@@ -222,16 +257,18 @@ impl CoreHighlightEngine {
         segments: &[TextSegment],
         stream: &str,
     ) -> Option<Vec<TextSegment>> {
-        self.apply_highlights_impl(segments, stream)
+        self.apply_highlights_impl(segments, stream, None)
             .map(|result| result.segments)
     }
 
     /// Shared implementation; `None` means "nothing matched, line unchanged"
-    /// so callers can skip cloning the input.
+    /// so callers can skip cloning the input. `targets` selects the pass
+    /// (see in_scope).
     fn apply_highlights_impl(
         &self,
         segments: &[TextSegment],
         stream: &str,
+        targets: Option<&[&str]>,
     ) -> Option<HighlightResult> {
         // Skip if no highlights or empty input
         if self.highlights.is_empty() || segments.is_empty() {
@@ -282,6 +319,10 @@ impl CoreHighlightEngine {
                     if let Some(&highlight_idx) = self.fast_pattern_map.get(mat.pattern().as_usize())
                     {
                         if let Some(highlight) = self.highlights.get(highlight_idx) {
+                            if !in_scope(highlight, targets) {
+                                continue;
+                            }
+
                             // Check stream filter
                             if let Some(ref required_stream) = highlight.stream {
                                 if !stream.eq_ignore_ascii_case(required_stream) {
@@ -305,7 +346,6 @@ impl CoreHighlightEngine {
                                 bold: highlight.bold,
                                 color_entire_line: highlight.color_entire_line,
                                 replace: highlight.replace.clone(),
-                                target_window: highlight.window.clone(),
                                 silent_prompt: highlight.silent_prompt,
                             });
                         }
@@ -320,6 +360,10 @@ impl CoreHighlightEngine {
                 continue; // Already handled by Aho-Corasick
             }
 
+            if !in_scope(highlight, targets) {
+                continue;
+            }
+
             // Check stream filter
             if let Some(ref required_stream) = highlight.stream {
                 if !stream.eq_ignore_ascii_case(required_stream) {
@@ -353,7 +397,6 @@ impl CoreHighlightEngine {
                                     bold: highlight.bold,
                                     color_entire_line: highlight.color_entire_line,
                                     replace: Some(expanded),
-                                    target_window: highlight.window.clone(),
                                     silent_prompt: highlight.silent_prompt,
                                 });
                             }
@@ -377,7 +420,6 @@ impl CoreHighlightEngine {
                             bold: highlight.bold,
                             color_entire_line: highlight.color_entire_line,
                             replace: None,
-                            target_window: highlight.window.clone(),
                             silent_prompt: highlight.silent_prompt,
                         });
                     }
@@ -410,7 +452,6 @@ impl CoreHighlightEngine {
             }
         }
 
-        let mut deferred_replacements: Vec<DeferredReplacement> = Vec::new();
         let mut line_is_silent = false;
 
         if !matches.is_empty() {
@@ -479,28 +520,7 @@ impl CoreHighlightEngine {
 
                 let new_start = new_styles.len();
 
-                // Check if this is a window-specific replacement (deferred)
-                let should_defer = m.target_window.is_some() && m.replace.is_some();
-
-                if should_defer {
-                    // Defer the replacement - collect original text for later
-                    let original_text: String =
-                        full_text_chars[start_char..end_char].iter().collect();
-                    deferred_replacements.push(DeferredReplacement {
-                        start_char: new_styles.len(), // Position in new text
-                        end_char: new_styles.len() + (end_char - start_char),
-                        replacement: m.replace.clone().unwrap(),
-                        target_window: m.target_window.clone().unwrap(),
-                        original_text: original_text.clone(),
-                    });
-                    // Use original text (colors will still be applied below)
-                    for i in start_char..end_char {
-                        new_text.push(full_text_chars[i]);
-                        new_styles.push(char_styles.get(i).cloned().unwrap_or_default());
-                        new_links.push(char_links.get(i).cloned().unwrap_or(None));
-                    }
-                } else if let Some(ref repl) = m.replace {
-                    // Immediate replacement (no window filter)
+                if let Some(ref repl) = m.replace {
                     let base_style = char_styles.get(start_char).cloned().unwrap_or_default();
                     for ch in repl.chars() {
                         new_text.push(ch);
@@ -531,17 +551,19 @@ impl CoreHighlightEngine {
 
             // Apply highlight styling
             for (start, end, info) in &new_match_ranges {
+                let fg = info.fg.as_deref().map(intern);
+                let bg = info.bg.as_deref().map(intern);
                 if info.color_entire_line {
                     for cs in new_styles.iter_mut() {
                         if cs.span_type == SpanType::Link || cs.span_type == SpanType::Monsterbold {
-                            if let Some(ref color) = info.bg {
+                            if let Some(ref color) = bg {
                                 cs.bg = Some(color.clone());
                             }
                         } else {
-                            if let Some(ref color) = info.fg {
+                            if let Some(ref color) = fg {
                                 cs.fg = Some(color.clone());
                             }
-                            if let Some(ref color) = info.bg {
+                            if let Some(ref color) = bg {
                                 cs.bg = Some(color.clone());
                             }
                             if info.bold {
@@ -552,10 +574,10 @@ impl CoreHighlightEngine {
                     break; // only first whole-line match applies
                 } else {
                     for idx in *start..(*end).min(new_styles.len()) {
-                        if let Some(ref color) = info.fg {
+                        if let Some(ref color) = fg {
                             new_styles[idx].fg = Some(color.clone());
                         }
-                        if let Some(ref color) = info.bg {
+                        if let Some(ref color) = bg {
                             new_styles[idx].bg = Some(color.clone());
                         }
                         if info.bold {
@@ -616,7 +638,6 @@ impl CoreHighlightEngine {
         Some(HighlightResult {
             segments: result_segments,
             sounds,
-            deferred_replacements,
             line_is_silent,
         })
     }
@@ -688,76 +709,6 @@ impl Default for CoreHighlightEngine {
     }
 }
 
-/// Apply deferred replacements for a specific window.
-///
-/// This is called during message routing to apply window-specific replacements
-/// that were deferred during the main highlight processing.
-///
-/// Returns new segments with the replacements applied, or the original segments
-/// if no replacements apply to this window.
-pub fn apply_deferred_for_window(
-    segments: &[TextSegment],
-    deferred: &[DeferredReplacement],
-    window_name: &str,
-) -> Vec<TextSegment> {
-    // Filter to replacements targeting this window
-    let applicable: Vec<_> = deferred
-        .iter()
-        .filter(|d| d.target_window.eq_ignore_ascii_case(window_name))
-        .collect();
-
-    if applicable.is_empty() {
-        return segments.to_vec(); // No changes needed
-    }
-
-    // Build full text from segments
-    let full_text: String = segments.iter().map(|s| s.text.as_str()).collect();
-    let mut result = full_text.clone();
-
-    // Apply replacements in reverse order (to preserve character positions)
-    let mut sorted_applicable: Vec<_> = applicable.clone();
-    sorted_applicable.sort_by(|a, b| b.start_char.cmp(&a.start_char));
-
-    for repl in sorted_applicable {
-        // Find the original text in the result and replace it
-        if let Some(pos) = result.find(&repl.original_text) {
-            result = format!(
-                "{}{}{}",
-                &result[..pos],
-                repl.replacement,
-                &result[pos + repl.original_text.len()..]
-            );
-        }
-    }
-
-    // If text didn't change, return original
-    if result == full_text {
-        return segments.to_vec();
-    }
-
-    // Rebuild segments with replaced text
-    // For simplicity, preserve first segment's style for the entire replaced text
-    let first_style = segments.first().cloned().unwrap_or_else(|| TextSegment {
-        text: String::new(),
-        fg: None,
-        bg: None,
-        bold: false,
-        mono: false,
-        span_type: SpanType::Normal,
-        link_data: None,
-    });
-
-    vec![TextSegment {
-        text: result,
-        fg: first_style.fg,
-        bg: first_style.bg,
-        bold: first_style.bold,
-        mono: first_style.mono,
-        span_type: first_style.span_type,
-        link_data: None,
-    }]
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -888,7 +839,6 @@ mod tests {
         assert_eq!(result.segments.len(), 1);
         assert_eq!(result.segments[0].text, "Hello world");
         assert!(result.sounds.is_empty());
-        assert!(result.deferred_replacements.is_empty());
     }
 
     #[test]
@@ -941,7 +891,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text.contains("world") && s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.text.contains("world") && s.fg.as_deref() == Some("#FF0000")));
     }
 
     #[test]
@@ -966,11 +916,11 @@ mod tests {
         let has_hello_color = result
             .segments
             .iter()
-            .any(|s| s.text.contains("Hello") && s.fg == Some("#00FF00".to_string()));
+            .any(|s| s.text.contains("Hello") && s.fg.as_deref() == Some("#00FF00"));
         let has_world_color = result
             .segments
             .iter()
-            .any(|s| s.text.contains("world") && s.fg == Some("#FF0000".to_string()));
+            .any(|s| s.text.contains("world") && s.fg.as_deref() == Some("#FF0000"));
 
         assert!(has_hello_color, "Hello should be green");
         assert!(has_world_color, "world should be red");
@@ -990,7 +940,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text == "test" && s.bg == Some("#330000".to_string())));
+            .any(|s| s.text == "test" && s.bg.as_deref() == Some("#330000")));
     }
 
     #[test]
@@ -1026,7 +976,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text == "damage" && s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.text == "damage" && s.fg.as_deref() == Some("#FF0000")));
     }
 
     #[test]
@@ -1044,14 +994,14 @@ mod tests {
         assert!(result1
             .segments
             .iter()
-            .any(|s| s.text == "hit" && s.fg == Some("#00FF00".to_string())));
+            .any(|s| s.text == "hit" && s.fg.as_deref() == Some("#00FF00")));
 
         // Test "miss"
         let result2 = engine.apply_highlights(&[make_segment("You miss!")], "main");
         assert!(result2
             .segments
             .iter()
-            .any(|s| s.text == "miss" && s.fg == Some("#00FF00".to_string())));
+            .any(|s| s.text == "miss" && s.fg.as_deref() == Some("#00FF00")));
     }
 
     #[test]
@@ -1090,7 +1040,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text == "dam" && s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.text == "dam" && s.fg.as_deref() == Some("#FF0000")));
     }
 
     // ===========================================
@@ -1111,7 +1061,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text == "50 damage" && s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.text == "50 damage" && s.fg.as_deref() == Some("#FF0000")));
     }
 
     #[test]
@@ -1206,86 +1156,87 @@ mod tests {
     }
 
     // ===========================================
-    // Deferred replacement tests
+    // Window scope tests
     // ===========================================
 
     #[test]
-    fn test_deferred_replacement_for_window() {
+    fn test_scoped_pattern_skipped_in_global_pass() {
         let patterns = vec![{
             let mut p = make_pattern("xxx");
+            p.fg = Some("#ff0000".to_string());
             p.replace = Some("yyy".to_string());
             p.window = Some("deaths".to_string());
             p
         }];
         let engine = CoreHighlightEngine::new(patterns);
+        assert!(engine.has_scoped());
         let segments = vec![make_segment("Replace xxx here")];
         let result = engine.apply_highlights(&segments, "main");
 
-        // Replacement should be DEFERRED, not applied
         let full_text = segments_to_text(&result.segments);
-        assert!(full_text.contains("xxx"), "Original should be preserved");
-        assert!(
-            !result.deferred_replacements.is_empty(),
-            "Should have deferred replacement"
-        );
-        assert_eq!(result.deferred_replacements[0].target_window, "deaths");
-        assert_eq!(result.deferred_replacements[0].replacement, "yyy");
+        assert_eq!(full_text, "Replace xxx here");
+        assert!(result.segments.iter().all(|s| s.fg.is_none()));
     }
 
     #[test]
-    fn test_apply_deferred_for_window_matches() {
-        let segments = vec![make_segment("Replace xxx here")];
-        let deferred = vec![DeferredReplacement {
-            start_char: 8,
-            end_char: 11,
-            replacement: "yyy".to_string(),
-            target_window: "deaths".to_string(),
-            original_text: "xxx".to_string(),
+    fn test_scoped_pattern_applies_to_named_window() {
+        let patterns = vec![{
+            let mut p = make_pattern("xxx");
+            p.fg = Some("#ff0000".to_string());
+            p.replace = Some("yyy".to_string());
+            p.window = Some("deaths".to_string());
+            p
         }];
+        let engine = CoreHighlightEngine::new(patterns);
+        let segments = vec![make_segment("Replace xxx here")];
 
-        // Apply to deaths window - should replace
-        let result = apply_deferred_for_window(&segments, &deferred, "deaths");
-        let full_text = segments_to_text(&result);
-        assert!(full_text.contains("yyy"), "Should apply replacement");
-        assert!(!full_text.contains("xxx"), "Should replace original");
+        let result = engine
+            .apply_scoped_highlights(&segments, "main", &["deaths"])
+            .expect("scoped pattern should match");
+        assert_eq!(segments_to_text(&result.segments), "Replace yyy here");
+        assert!(result
+            .segments
+            .iter()
+            .any(|s| s.text == "yyy" && s.fg.as_deref() == Some("#ff0000")));
+
+        // Other windows are untouched
+        assert!(engine
+            .apply_scoped_highlights(&segments, "main", &["main"])
+            .is_none());
     }
 
     #[test]
-    fn test_apply_deferred_for_window_no_match() {
-        let segments = vec![make_segment("Replace xxx here")];
-        let deferred = vec![DeferredReplacement {
-            start_char: 8,
-            end_char: 11,
-            replacement: "yyy".to_string(),
-            target_window: "deaths".to_string(),
-            original_text: "xxx".to_string(),
+    fn test_scope_list_matches_any_name_case_insensitive() {
+        let patterns = vec![{
+            let mut p = make_pattern("xxx");
+            p.fg = Some("#ff0000".to_string());
+            p.window = Some("Deaths, Combat".to_string());
+            p
         }];
+        let engine = CoreHighlightEngine::new(patterns);
+        let segments = vec![make_segment("Replace xxx here")];
 
-        // Apply to main window - should NOT replace
-        let result = apply_deferred_for_window(&segments, &deferred, "main");
-        let full_text = segments_to_text(&result);
-        assert!(full_text.contains("xxx"), "Should preserve original");
-        assert!(!full_text.contains("yyy"), "Should not apply replacement");
+        // Window name, then tab name - either may match
+        assert!(engine
+            .apply_scoped_highlights(&segments, "main", &["chat", "combat"])
+            .is_some());
+        assert!(engine
+            .apply_scoped_highlights(&segments, "main", &["deaths"])
+            .is_some());
     }
 
     #[test]
-    fn test_apply_deferred_case_insensitive_window_match() {
-        let segments = vec![make_segment("Replace xxx here")];
-        let deferred = vec![DeferredReplacement {
-            start_char: 8,
-            end_char: 11,
-            replacement: "yyy".to_string(),
-            target_window: "Deaths".to_string(),
-            original_text: "xxx".to_string(),
+    fn test_blank_scope_is_global() {
+        let patterns = vec![{
+            let mut p = make_pattern("xxx");
+            p.fg = Some("#ff0000".to_string());
+            p.window = Some(" , ".to_string());
+            p
         }];
-
-        // Apply with different case - should still match
-        let result = apply_deferred_for_window(&segments, &deferred, "deaths");
-        let full_text = segments_to_text(&result);
-        assert!(
-            full_text.contains("yyy"),
-            "Case-insensitive match should work"
-        );
+        let engine = CoreHighlightEngine::new(patterns);
+        assert!(!engine.has_scoped());
+        let result = engine.apply_highlights(&[make_segment("xxx")], "main");
+        assert_eq!(result.segments[0].fg.as_deref(), Some("#ff0000"));
     }
 
     // ===========================================
@@ -1316,7 +1267,7 @@ mod tests {
             result_death
                 .segments
                 .iter()
-                .any(|s| s.fg == Some("#FF0000".to_string())),
+                .any(|s| s.fg.as_deref() == Some("#FF0000")),
             "Should color in death stream"
         );
     }
@@ -1337,7 +1288,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.fg.as_deref() == Some("#FF0000")));
     }
 
     // ===========================================
@@ -1361,7 +1312,7 @@ mod tests {
             result
                 .segments
                 .iter()
-                .all(|s| s.fg == Some("#FF0000".to_string())),
+                .all(|s| s.fg.as_deref() == Some("#FF0000")),
             "Entire line should be colored"
         );
     }
@@ -1380,7 +1331,7 @@ mod tests {
         // Create segment with Link span type
         let segments = vec![TextSegment {
             text: "click here".to_string(),
-            fg: Some("#0000FF".to_string()), // Blue link
+            fg: Some("#0000FF".into()), // Blue link
             bg: None,
             bold: false,
             mono: false,
@@ -1399,7 +1350,7 @@ mod tests {
         let link = link_seg.unwrap();
         assert_eq!(
             link.bg,
-            Some("#330000".to_string()),
+            Some("#330000".into()),
             "Link should get bg color"
         );
         // fg should be applied but the span_type check might preserve original
@@ -1564,7 +1515,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.fg.as_deref() == Some("#FF0000")));
     }
 
     #[test]
@@ -1605,7 +1556,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text == "abc" && s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.text == "abc" && s.fg.as_deref() == Some("#FF0000")));
     }
 
     #[test]
@@ -1661,7 +1612,7 @@ mod tests {
         assert!(result
             .segments
             .iter()
-            .any(|s| s.text == "red" && s.fg == Some("#FF0000".to_string())));
+            .any(|s| s.text == "red" && s.fg.as_deref() == Some("#FF0000")));
     }
 
     #[test]
@@ -1676,7 +1627,7 @@ mod tests {
         // Input with pre-existing color
         let segments = vec![TextSegment {
             text: "Hello world".to_string(),
-            fg: Some("#0000FF".to_string()), // Pre-existing blue
+            fg: Some("#0000FF".into()), // Pre-existing blue
             bg: None,
             bold: false,
             mono: false,
@@ -1693,13 +1644,13 @@ mod tests {
         assert!(hello_seg.is_some());
         assert_eq!(
             hello_seg.unwrap().fg,
-            Some("#0000FF".to_string()),
+            Some("#0000FF".into()),
             "Hello should keep original color"
         );
         assert!(world_seg.is_some());
         assert_eq!(
             world_seg.unwrap().fg,
-            Some("#FF0000".to_string()),
+            Some("#FF0000".into()),
             "world should get highlight color"
         );
     }
@@ -153,6 +153,21 @@ fn effect_lookup<'a>(
     })
 }
 
+/// How many effects in a category are active (`spells > 0` conditions)
+pub(crate) fn active_effect_count(
+    gs: &GameState,
+    category: &EffectCategory,
+    now_server: i64,
+) -> usize {
+    gs.effects.get(category.state_key()).map_or(0, |store| {
+        store
+            .effects
+            .iter()
+            .filter(|e| effect_is_active(e, now_server))
+            .count()
+    })
+}
+
 /// An effect entry is active unless its derived expiry has already passed.
 /// Effects without a parseable expiry (e.g. "Indefinite") count as active
 /// while present — the game removes them via dialog clears.
@@ -160,7 +175,7 @@ fn effect_is_active(effect: &ActiveEffect, now_server: i64) -> bool {
     effect.expires_at.map(|end| end > now_server).unwrap_or(true)
 }
 
-fn indicator_value(gs: &GameState, id: &str) -> Option<bool> {
+pub(crate) fn indicator_value(gs: &GameState, id: &str) -> Option<bool> {
     let s = &gs.status;
     Some(match id {
         "standing" => s.standing,
@@ -180,7 +195,7 @@ fn indicator_value(gs: &GameState, id: &str) -> Option<bool> {
 
 /// Percent comes from the vitals bars; absolute from minivitals (GS4).
 /// Absolute returns None until minivitals data has arrived (max == 0).
-fn vital_value(gs: &GameState, vital: VitalKind, unit: VitalUnit) -> Option<i64> {
+pub(crate) fn vital_value(gs: &GameState, vital: VitalKind, unit: VitalUnit) -> Option<i64> {
     match unit {
         VitalUnit::Percent => Some(match vital {
             VitalKind::Health => gs.vitals.health as i64,
@@ -32,14 +32,20 @@ fn get_action_context(mode: &InputMode) -> ActionContext {
         | InputMode::SpellColorsBrowser
         | InputMode::UIColorsBrowser
         | InputMode::ThemeBrowser
-        | InputMode::IndicatorTemplateEditor => ActionContext::Browser,
+        | InputMode::IndicatorTemplateEditor
+        | InputMode::TextViewer
+        | InputMode::SpellSuggest
+        | InputMode::LayoutGallery => ActionContext::Browser,
 
         // Form widgets
         InputMode::HighlightForm
         | InputMode::KeybindForm
         | InputMode::ColorForm
         | InputMode::SpellColorForm
-        | InputMode::ThemeEditor => ActionContext::Form,
+        | InputMode::ThemeEditor
+        | InputMode::Compose
+        | InputMode::NotesEditor
+        | InputMode::CommandLog => ActionContext::Form,
 
         // Settings editor (hybrid - has both navigation and inline editing)
         InputMode::SettingsEditor => ActionContext::SettingsEditor,
@@ -0,0 +1,326 @@
+//! Parser event inspector (`.inspect`): the last few hundred server lines
+//! with their raw XML, the elements the parser made of them, and the game
+//! state each one changed.
+//!
+//! Built for "widget X didn't update" reports: find the tag, check that it
+//! parsed into the element you expect, then check that state moved. Off by
+//! default; while off nothing is recorded and the parse worker doesn't keep
+//! raw text (parse_worker::set_capture_raw).
+
+use std::collections::VecDeque;
+
+use crate::core::state::GameState;
+use crate::data::{StyledLine, TextSegment};
+use crate::parser::ParsedElement;
+
+/// Lines kept; the oldest drop off
+pub const CAPACITY: usize = 500;
+
+const RAW_COLOR: &str = "#808080";
+const ELEMENT_COLOR: &str = "#87afd7";
+const CHANGE_COLOR: &str = "#afd787";
+
+/// One server line as the inspector saw it
+#[derive(Debug, Clone)]
+pub struct InspectEvent {
+    pub seq: u64,
+    /// The line as received; None when capture started mid-chunk
+    pub raw: Option<String>,
+    /// `{:?}` of each parsed element
+    pub elements: Vec<String>,
+    /// `name: old -> new` for each watched state field the line changed
+    pub changes: Vec<String>,
+}
+
+/// Element variant name from its `{:?}` text (`RoundTime { value: 3 }`)
+fn kind(element: &str) -> &str {
+    let end = element
+        .find(|c: char| !c.is_alphanumeric())
+        .unwrap_or(element.len());
+    &element[..end]
+}
+
+impl InspectEvent {
+    /// Element kinds on this line, e.g. `["ProgressBar", "Text"]`
+    pub fn kinds(&self) -> impl Iterator<Item = &str> {
+        self.elements.iter().map(|e| kind(e))
+    }
+
+    /// Filter by tag type: an element kind containing `filter`
+    /// (`progress`, `roundtime`) or a raw `<filter` tag (`<dialogData`)
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.trim_start_matches('<').to_lowercase();
+        self.kinds().any(|k| k.to_lowercase().contains(&filter))
+            || self
+                .raw
+                .as_deref()
+                .is_some_and(|raw| raw.to_lowercase().contains(&format!("<{}", filter)))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Inspector {
+    enabled: bool,
+    events: VecDeque<InspectEvent>,
+    next_seq: u64,
+}
+
+impl Inspector {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Start or stop recording. Stopping drops what was recorded.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        crate::parse_worker::set_capture_raw(enabled);
+        if !enabled {
+            self.events.clear();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn record(
+        &mut self,
+        raw: Option<String>,
+        elements: &[ParsedElement],
+        changes: Vec<String>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        self.next_seq += 1;
+        self.events.push_back(InspectEvent {
+            seq: self.next_seq,
+            raw,
+            elements: elements.iter().map(|e| format!("{:?}", e)).collect(),
+            changes,
+        });
+        while self.events.len() > CAPACITY {
+            self.events.pop_front();
+        }
+    }
+
+    /// Recorded lines, oldest first, optionally only those matching `filter`
+    pub fn events<'a>(&'a self, filter: Option<&'a str>) -> impl Iterator<Item = &'a InspectEvent> {
+        self.events
+            .iter()
+            .filter(move |event| filter.is_none_or(|filter| event.matches(filter)))
+    }
+
+    /// The `.inspect [filter]` popup: title and lines, newest last. `last`
+    /// keeps only that many of the newest matching server lines.
+    pub fn page(&self, filter: Option<&str>, last: Option<usize>) -> (String, Vec<StyledLine>) {
+        let line = |text: String, color: Option<&str>, bold: bool| StyledLine {
+            segments: vec![TextSegment::styled(text, color.map(str::to_string), bold)],
+            stream: "main".into(),
+            timestamp: None,
+        };
+        let matching: Vec<&InspectEvent> = self.events(filter).collect();
+        let shown = matching.len();
+        let skip = last.map_or(0, |last| shown.saturating_sub(last));
+        let mut lines = Vec::new();
+        for event in &matching[skip..] {
+            let kinds: Vec<&str> = event.kinds().collect();
+            lines.push(line(
+                format!("#{} {}", event.seq, kinds.join(" ")),
+                None,
+                true,
+            ));
+            if let Some(raw) = &event.raw {
+                lines.push(line(format!("  raw  {}", raw), Some(RAW_COLOR), false));
+            }
+            for element in &event.elements {
+                lines.push(line(
+                    format!("  el   {}", element),
+                    Some(ELEMENT_COLOR),
+                    false,
+                ));
+            }
+            for change in &event.changes {
+                lines.push(line(
+                    format!("  set  {}", change),
+                    Some(CHANGE_COLOR),
+                    false,
+                ));
+            }
+        }
+        let title = match filter {
+            Some(filter) => format!(
+                "Inspector: {} of {} lines match '{}'",
+                shown,
+                self.len(),
+                filter
+            ),
+            None => format!("Inspector: last {} lines", self.len()),
+        };
+        (title, lines)
+    }
+}
+
+/// The slice of game state the inspector compares before and after each
+/// line. Big structures are tracked by their generation counter and
+/// reported as "updated" rather than dumped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateSnapshot(Vec<(&'static str, Field)>);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Value(String),
+    Generation(u64),
+}
+
+impl StateSnapshot {
+    pub fn capture(gs: &GameState) -> Self {
+        use Field::{Generation, Value};
+        let status = &gs.status;
+        let flags: Vec<&str> = [
+            (status.standing, "standing"),
+            (status.kneeling, "kneeling"),
+            (status.sitting, "sitting"),
+            (status.prone, "prone"),
+            (status.stunned, "stunned"),
+            (status.bleeding, "bleeding"),
+            (status.hidden, "hidden"),
+            (status.invisible, "invisible"),
+            (status.webbed, "webbed"),
+            (status.poisoned, "poisoned"),
+            (status.diseased, "diseased"),
+            (status.joined, "joined"),
+            (status.dead, "dead"),
+        ]
+        .into_iter()
+        .filter_map(|(on, name)| on.then_some(name))
+        .collect();
+        let mut injuries: Vec<String> = gs
+            .injuries
+            .iter()
+            .filter(|(_, level)| **level > 0)
+            .map(|(part, level)| format!("{}={}", part, level))
+            .collect();
+        injuries.sort();
+        let v = &gs.vitals;
+        Self(vec![
+            (
+                "room",
+                Value(format!("{:?} {:?}", gs.room_id, gs.room_name)),
+            ),
+            ("compass", Value(gs.compass_dirs.join(","))),
+            ("roundtime_end", Value(format!("{:?}", gs.roundtime_end))),
+            ("casttime_end", Value(format!("{:?}", gs.casttime_end))),
+            ("spell", Value(format!("{:?}", gs.spell))),
+            (
+                "vitals",
+                Value(format!(
+                    "health {} mana {} stamina {} spirit {}",
+                    v.health, v.mana, v.stamina, v.spirit
+                )),
+            ),
+            ("left_hand", Value(format!("{:?}", gs.left_hand))),
+            ("right_hand", Value(format!("{:?}", gs.right_hand))),
+            ("status", Value(flags.join(","))),
+            (
+                "stance",
+                Value(format!("{} {}%", gs.stance.name, gs.stance.percent)),
+            ),
+            ("injuries", Value(injuries.join(","))),
+            ("creatures", Generation(gs.room_creatures_generation)),
+            ("room_objects", Generation(gs.room_objects_generation)),
+            ("room_players", Generation(gs.room_players_generation)),
+            ("minivitals", Generation(gs.minivitals.generation)),
+            ("encumbrance", Generation(gs.encumbrance.generation)),
+            ("gs4_experience", Generation(gs.gs4_experience.generation)),
+            ("dr_experience", Generation(gs.dr_experience.generation)),
+            ("betrayer", Generation(gs.betrayer.generation)),
+        ])
+    }
+
+    /// What changed between `self` (before a line) and `after`
+    pub fn changes(&self, after: &Self) -> Vec<String> {
+        self.0
+            .iter()
+            .zip(&after.0)
+            .filter(|(before, after)| before.1 != after.1)
+            .map(|((name, before), (_, after))| match (before, after) {
+                (Field::Value(before), Field::Value(after)) => {
+                    format!("{}: {} -> {}", name, before, after)
+                }
+                _ => format!("{} updated", name),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> Inspector {
+        Inspector {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn records_up_to_capacity_and_filters_by_tag() {
+        let mut inspector = enabled();
+        for n in 0..CAPACITY as u32 + 5 {
+            inspector.record(
+                Some(format!("<roundTime value='{}'/>", n)),
+                &[ParsedElement::RoundTime { value: n }],
+                Vec::new(),
+            );
+        }
+        inspector.record(
+            Some("<castTime value='5'/>".into()),
+            &[ParsedElement::CastTime { value: 5 }],
+            Vec::new(),
+        );
+        assert_eq!(inspector.len(), CAPACITY);
+        assert_eq!(inspector.events(Some("casttime")).count(), 1);
+        assert_eq!(inspector.events(Some("<roundTime")).count(), CAPACITY - 1);
+        assert_eq!(inspector.events(Some("compass")).count(), 0);
+        let (title, lines) = inspector.page(Some("casttime"), None);
+        assert!(title.contains("1 of 500"));
+        let text: Vec<String> = lines.iter().map(|l| l.segments[0].text.clone()).collect();
+        assert_eq!(text[0], format!("#{} CastTime", CAPACITY + 6));
+        assert_eq!(text[1], "  raw  <castTime value='5'/>");
+        assert_eq!(text[2], "  el   CastTime { value: 5 }");
+        let (_, lines) = inspector.page(None, Some(2));
+        assert_eq!(lines.iter().filter(|l| l.segments[0].bold).count(), 2);
+    }
+
+    #[test]
+    fn disabled_inspector_records_nothing() {
+        let mut inspector = Inspector::default();
+        inspector.record(None, &[ParsedElement::RoundTime { value: 1 }], Vec::new());
+        assert!(inspector.is_empty());
+    }
+
+    #[test]
+    fn snapshot_reports_changed_fields() {
+        let mut gs = GameState::new();
+        let before = StateSnapshot::capture(&gs);
+        gs.vitals.health = 55;
+        gs.status.stunned = true;
+        gs.room_creatures_generation += 1;
+        let changes = before.changes(&StateSnapshot::capture(&gs));
+        assert_eq!(changes.len(), 3);
+        assert!(changes[0].starts_with("vitals: health 100"));
+        assert!(changes[0].ends_with("-> health 55 mana 100 stamina 100 spirit 100"));
+        assert_eq!(changes[1], "status:  -> stunned");
+        assert_eq!(changes[2], "creatures updated");
+    }
+}
@@ -0,0 +1,175 @@
+//! Keyboard layout normalization for keybind lookup.
+//!
+//! Keybinds are written against US QWERTY key names ("ctrl+1", "alt+;").
+//! On other layouts the terminal reports the character the key produces,
+//! so the key labelled `1` on AZERTY arrives as `&` and never matches.
+//! When the exact key has no binding, the character is translated to the
+//! QWERTY key at the same physical position and looked up again.
+//!
+//! Terminals (and crossterm) don't report scancodes, so this table is the
+//! only positional information the TUI has; the GUI also falls back to
+//! the physical key its windowing layer reports.
+
+use crate::data::input::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Key positions in order: backtick, number row, top row, backslash,
+/// home row, bottom row (47 keys). A space marks a position that
+/// produces nothing useful on that layout.
+const QWERTY: (&str, &str) = (
+    "`1234567890-=qwertyuiop[]\\asdfghjkl;'zxcvbnm,./",
+    "~!@#$%^&*()_+QWERTYUIOP{}|ASDFGHJKL:\"ZXCVBNM<>?",
+);
+const AZERTY: (&str, &str) = (
+    "²&é\"'(-è_çà)=azertyuiop^$*qsdfghjklmùwxcvbn,;:!",
+    " 1234567890°+AZERTYUIOP¨£µQSDFGHJKLM%WXCVBN?./§",
+);
+const QWERTZ: (&str, &str) = (
+    "^1234567890ß´qwertzuiopü+#asdfghjklöäyxcvbnm,.-",
+    "°!\"§$%&/()=?`QWERTZUIOPÜ*'ASDFGHJKLÖÄYXCVBNM;:_",
+);
+const DVORAK: (&str, &str) = (
+    "`1234567890[]',.pyfgcrl/=\\aoeuidhtns-;qjkxbmwvz",
+    "~!@#$%^&*(){}\"<>PYFGCRL?+|AOEUIDHTNS_:QJKXBMWVZ",
+);
+const COLEMAK: (&str, &str) = (
+    "`1234567890-=qwfpgjluy;[]\\arstdhneio'zxcvbkm,./",
+    "~!@#$%^&*()_+QWFPGJLUY:{}|ARSTDHNEIO\"ZXCVBKM<>?",
+);
+
+/// Physical keyboard layout the user types on (`ui.keyboard_layout`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Azerty,
+    Qwertz,
+    Dvorak,
+    Colemak,
+}
+
+impl KeyboardLayout {
+    pub const ALL: [KeyboardLayout; 5] = [
+        KeyboardLayout::Qwerty,
+        KeyboardLayout::Azerty,
+        KeyboardLayout::Qwertz,
+        KeyboardLayout::Dvorak,
+        KeyboardLayout::Colemak,
+    ];
+
+    /// Parse a config name; unknown names fall back to QWERTY
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "azerty" => KeyboardLayout::Azerty,
+            "qwertz" => KeyboardLayout::Qwertz,
+            "dvorak" => KeyboardLayout::Dvorak,
+            "colemak" => KeyboardLayout::Colemak,
+            _ => KeyboardLayout::Qwerty,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwerty => "qwerty",
+            KeyboardLayout::Azerty => "azerty",
+            KeyboardLayout::Qwertz => "qwertz",
+            KeyboardLayout::Dvorak => "dvorak",
+            KeyboardLayout::Colemak => "colemak",
+        }
+    }
+
+    fn rows(self) -> (&'static str, &'static str) {
+        match self {
+            KeyboardLayout::Qwerty => QWERTY,
+            KeyboardLayout::Azerty => AZERTY,
+            KeyboardLayout::Qwertz => QWERTZ,
+            KeyboardLayout::Dvorak => DVORAK,
+            KeyboardLayout::Colemak => COLEMAK,
+        }
+    }
+
+    /// The QWERTY key at the physical position that produced `c`, and
+    /// whether shift was needed to produce it.
+    pub fn qwerty_position(self, c: char) -> Option<(char, bool)> {
+        if c == ' ' {
+            return None;
+        }
+        let (plain, shifted) = self.rows();
+        if let Some(idx) = plain.chars().position(|p| p == c) {
+            return QWERTY.0.chars().nth(idx).map(|q| (q, false));
+        }
+        let idx = shifted.chars().position(|s| s == c)?;
+        QWERTY.0.chars().nth(idx).map(|q| (q, true))
+    }
+
+    /// Layout-normalized form of a key event, or None when the key has no
+    /// positional translation or translates to itself.
+    pub fn normalize(self, event: KeyEvent) -> Option<KeyEvent> {
+        let KeyCode::Char(c) = event.code else {
+            return None;
+        };
+        let (q, shifted) = self.qwerty_position(c)?;
+        let modifiers = KeyModifiers {
+            shift: event.modifiers.shift || shifted,
+            ..event.modifiers
+        };
+        let normalized = KeyEvent::new(KeyCode::Char(q), modifiers);
+        (normalized != event).then_some(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_cover_every_position() {
+        for layout in KeyboardLayout::ALL {
+            let (plain, shifted) = layout.rows();
+            assert_eq!(plain.chars().count(), 47, "{}", layout.name());
+            assert_eq!(shifted.chars().count(), 47, "{}", layout.name());
+        }
+    }
+
+    #[test]
+    fn azerty_number_row_maps_to_digits() {
+        let ctrl_amp = KeyEvent::new(KeyCode::Char('&'), KeyModifiers::CTRL);
+        assert_eq!(
+            KeyboardLayout::Azerty.normalize(ctrl_amp),
+            Some(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::CTRL))
+        );
+        // Shift+& produces '1', which already reads as "shift+1"
+        let one = KeyEvent::new(KeyCode::Char('1'), KeyModifiers::SHIFT);
+        assert_eq!(KeyboardLayout::Azerty.normalize(one), None);
+        let e_acute = KeyEvent::new(KeyCode::Char('é'), KeyModifiers::ALT);
+        assert_eq!(
+            KeyboardLayout::Azerty.normalize(e_acute),
+            Some(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::ALT))
+        );
+    }
+
+    #[test]
+    fn dvorak_letters_map_by_position() {
+        let alt_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT);
+        assert_eq!(
+            KeyboardLayout::Dvorak.normalize(alt_s),
+            Some(KeyEvent::new(KeyCode::Char(';'), KeyModifiers::ALT))
+        );
+    }
+
+    #[test]
+    fn qwerty_shifted_symbols_become_shift_binds() {
+        let bang = KeyEvent::new(KeyCode::Char('!'), KeyModifiers::NONE);
+        assert_eq!(
+            KeyboardLayout::Qwerty.normalize(bang),
+            Some(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::SHIFT))
+        );
+        let a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CTRL);
+        assert_eq!(KeyboardLayout::Qwerty.normalize(a), None);
+    }
+
+    #[test]
+    fn unknown_layout_names_fall_back_to_qwerty() {
+        assert_eq!(KeyboardLayout::from_name("AZERTY"), KeyboardLayout::Azerty);
+        assert_eq!(KeyboardLayout::from_name("bepo"), KeyboardLayout::Qwerty);
+    }
+}
@@ -0,0 +1,391 @@
+//! Layout gallery: the layouts in the shared layouts directory, with
+//! metadata and ASCII previews, plus downloads of community layouts.
+//!
+//! Every `*.toml` in `~/.vellum-fe/layouts/` is an entry. Entries are
+//! tagged by where they came from: shipped with the client, downloaded with
+//! `.layouts get <url>` (their `[meta]` records the source URL), or saved
+//! with `.savelayout`. The frontends show the list with `.layouts browse`
+//! and apply a pick scaled to the current terminal size
+//! (`AppCore::apply_gallery_layout`).
+//!
+//! Downloads follow the `MapDbUpdater` pattern: a worker thread fetches and
+//! validates the file, the core polls for the result once per frame.
+
+use crate::config::{Layout, LayoutMeta};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+/// Layout files are small; anything bigger isn't a layout
+const MAX_DOWNLOAD_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LayoutOrigin {
+    Bundled,
+    Downloaded,
+    Saved,
+}
+
+impl LayoutOrigin {
+    pub fn label(self) -> &'static str {
+        match self {
+            LayoutOrigin::Bundled => "bundled",
+            LayoutOrigin::Downloaded => "community",
+            LayoutOrigin::Saved => "saved",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GalleryEntry {
+    /// File stem, as `.loadlayout` takes it
+    pub name: String,
+    pub origin: LayoutOrigin,
+    pub meta: LayoutMeta,
+    pub layout: Layout,
+}
+
+impl GalleryEntry {
+    pub fn title(&self) -> &str {
+        self.meta.title.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Size the layout was designed for (its bounding box when unrecorded)
+    pub fn design_size(&self) -> (u16, u16) {
+        design_size(&self.layout)
+    }
+}
+
+/// Every readable layout in `dir`: bundled first, then community, then
+/// saved, each alphabetical. Unparseable files are skipped.
+pub fn scan(dir: &Path, bundled: &[String]) -> Vec<GalleryEntry> {
+    let Ok(files) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut entries = Vec::new();
+    for path in files.flatten().map(|file| file.path()) {
+        if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let layout = match Layout::load_from_file(&path) {
+            Ok(layout) => layout,
+            Err(e) => {
+                tracing::warn!("Layout gallery: skipping {:?}: {:#}", path, e);
+                continue;
+            }
+        };
+        let meta = std::fs::read_to_string(&path)
+            .map(|contents| LayoutMeta::from_toml(&contents))
+            .unwrap_or_default();
+        let origin = if bundled.iter().any(|b| b == name) {
+            LayoutOrigin::Bundled
+        } else if meta.source.is_some() {
+            LayoutOrigin::Downloaded
+        } else {
+            LayoutOrigin::Saved
+        };
+        entries.push(GalleryEntry {
+            name: name.to_string(),
+            origin,
+            meta,
+            layout,
+        });
+    }
+    entries.sort_by(|a, b| (a.origin, &a.name).cmp(&(b.origin, &b.name)));
+    entries
+}
+
+/// Terminal size a layout was made for: the recorded one, else the
+/// bounding box of its visible windows
+pub fn design_size(layout: &Layout) -> (u16, u16) {
+    let visible = || {
+        layout
+            .windows
+            .iter()
+            .map(|w| w.base())
+            .filter(|b| b.visible)
+    };
+    let width = layout.terminal_width.unwrap_or_else(|| {
+        visible()
+            .map(|b| b.col.saturating_add(b.cols))
+            .max()
+            .unwrap_or(80)
+    });
+    let height = layout.terminal_height.unwrap_or_else(|| {
+        visible()
+            .map(|b| b.row.saturating_add(b.rows))
+            .max()
+            .unwrap_or(24)
+    });
+    (width.max(1), height.max(1))
+}
+
+/// ASCII mock of the layout's visible windows shrunk into `width` x
+/// `height` cells: each window is a `+--+` box with its name in the top-left
+/// corner. Windows too thin for a box become a line of `=`.
+pub fn render_preview(layout: &Layout, width: u16, height: u16) -> Vec<String> {
+    let (width, height) = (width as usize, height as usize);
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    let mut grid = vec![vec![' '; width]; height];
+    let (design_w, design_h) = design_size(layout);
+    let scale_x = |col: u16| (col as usize * width / design_w as usize).min(width);
+    let scale_y = |row: u16| (row as usize * height / design_h as usize).min(height);
+
+    for window in layout.windows.iter().filter(|w| w.base().visible) {
+        let base = window.base();
+        let (x0, y0) = (scale_x(base.col), scale_y(base.row));
+        let x1 = scale_x(base.col.saturating_add(base.cols))
+            .max(x0 + 1)
+            .min(width)
+            - 1;
+        let y1 = scale_y(base.row.saturating_add(base.rows))
+            .max(y0 + 1)
+            .min(height)
+            - 1;
+        if x0 >= width || y0 >= height {
+            continue;
+        }
+
+        if y1 == y0 || x1 == x0 {
+            for row in grid.iter_mut().take(y1 + 1).skip(y0) {
+                for cell in row.iter_mut().take(x1 + 1).skip(x0) {
+                    *cell = '=';
+                }
+            }
+        } else {
+            for (y, row) in grid.iter_mut().enumerate().take(y1 + 1).skip(y0) {
+                for (x, cell) in row.iter_mut().enumerate().take(x1 + 1).skip(x0) {
+                    let edge_x = x == x0 || x == x1;
+                    let edge_y = y == y0 || y == y1;
+                    *cell = match (edge_x, edge_y) {
+                        (true, true) => '+',
+                        (false, true) => '-',
+                        (true, false) => '|',
+                        (false, false) => ' ',
+                    };
+                }
+            }
+        }
+
+        // Label inside the box when there is room, else over its top edge
+        let label_y = if y1 > y0 + 1 { y0 + 1 } else { y0 };
+        let room = x1.saturating_sub(x0 + 1);
+        for (offset, ch) in window.name().chars().take(room).enumerate() {
+            grid[label_y][x0 + 1 + offset] = ch;
+        }
+    }
+    grid.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
+
+/// A community layout download running on a worker thread
+pub struct LayoutDownload {
+    rx: mpsc::Receiver<Result<String, String>>,
+}
+
+impl LayoutDownload {
+    /// Fetch `url` into `dir` in the background
+    pub fn start(url: String, dir: PathBuf) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let _ = std::thread::Builder::new()
+            .name("layout-download".into())
+            .spawn(move || {
+                let _ = tx.send(download(&url, &dir));
+            });
+        Self { rx }
+    }
+
+    /// The installed layout name (or the error) once the worker finishes
+    pub fn poll(&self) -> Option<Result<String, String>> {
+        match self.rx.try_recv() {
+            Ok(result) => Some(result),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                Some(Err("download worker exited unexpectedly".to_string()))
+            }
+        }
+    }
+}
+
+/// GitHub file pages serve HTML; fetch the raw file instead
+fn raw_url(url: &str) -> String {
+    match url
+        .strip_prefix("https://github.com/")
+        .and_then(|rest| rest.split_once("/blob/"))
+    {
+        Some((repo, path)) => format!("https://raw.githubusercontent.com/{}/{}", repo, path),
+        None => url.to_string(),
+    }
+}
+
+fn download(url: &str, dir: &Path) -> Result<String, String> {
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        return Err("expected an http(s) URL".to_string());
+    }
+    let resp = crate::core::mapdb_update::agent()?
+        .get(&raw_url(url))
+        .call()
+        .map_err(|e| format!("download failed: {e}"))?;
+    let mut contents = String::new();
+    resp.into_reader()
+        .take(MAX_DOWNLOAD_BYTES)
+        .read_to_string(&mut contents)
+        .map_err(|e| format!("read failed: {e}"))?;
+    install(&contents, url, dir)
+}
+
+/// Validate a downloaded layout, stamp its source URL into `[meta]`, and
+/// write it to `dir` under a name derived from its title or the URL.
+/// Existing layouts are never overwritten unless they came from the same
+/// URL. Returns the layout name.
+fn install(contents: &str, url: &str, dir: &Path) -> Result<String, String> {
+    let layout = Layout::parse_tolerant(contents, url).map_err(|e| format!("{e:#}"))?;
+    if layout.windows.is_empty() {
+        return Err("layout has no windows".to_string());
+    }
+    let mut meta = LayoutMeta::from_toml(contents);
+    let stem = url
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(".toml");
+    let base = safe_name(meta.title.as_deref().unwrap_or(stem));
+    let base = if base.is_empty() {
+        "community".to_string()
+    } else {
+        base
+    };
+
+    let mut name = base.clone();
+    for n in 2.. {
+        let path = dir.join(format!("{}.toml", name));
+        let same_source = std::fs::read_to_string(&path)
+            .map(|existing| LayoutMeta::from_toml(&existing).source.as_deref() == Some(url))
+            .unwrap_or(false);
+        if !path.exists() || same_source {
+            break;
+        }
+        name = format!("{}-{}", base, n);
+    }
+
+    meta.source = Some(url.to_string());
+    let mut value: toml::Value =
+        toml::from_str(contents).map_err(|e| format!("invalid TOML: {e}"))?;
+    let table = value
+        .as_table_mut()
+        .ok_or_else(|| "invalid layout file".to_string())?;
+    // A stranger's layout doesn't get to write files (core::tee)
+    if let Some(toml::Value::Array(windows)) = table.get_mut("windows") {
+        for window in windows.iter_mut().filter_map(toml::Value::as_table_mut) {
+            window.remove("tee_file");
+        }
+    }
+    table.insert(
+        "meta".to_string(),
+        toml::Value::try_from(&meta).map_err(|e| e.to_string())?,
+    );
+    let text = toml::to_string_pretty(&value).map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!("{}.toml", name));
+    std::fs::write(&path, text).map_err(|e| format!("write {} failed: {e}", path.display()))?;
+    Ok(name)
+}
+
+/// Lowercase, with anything but letters, digits, `-` and `_` as `-`
+fn safe_name(title: &str) -> String {
+    let name: String = title
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    name.trim_matches('-').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NONE_LAYOUT: &str = include_str!("../../defaults/globals/layouts/none.toml");
+
+    #[test]
+    fn preview_draws_scaled_boxes() {
+        let layout = Layout::parse_tolerant(NONE_LAYOUT, "none.toml").unwrap();
+        let preview = render_preview(&layout, 20, 6);
+        assert_eq!(
+            preview,
+            vec![
+                "+------------------+",
+                "|main              |",
+                "|                  |",
+                "|                  |",
+                "+------------------+",
+                "=command_input======",
+            ]
+        );
+    }
+
+    #[test]
+    fn install_names_and_stamps_source() {
+        let dir =
+            std::env::temp_dir().join(format!("vellum-fe-gallery-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let url = "https://example.com/layouts/Hunting Setup.toml";
+        let contents = NONE_LAYOUT.replace("title = \"Bare\"", "title = \"Hunter's Grid\"");
+
+        assert_eq!(install(&contents, url, &dir).unwrap(), "hunter-s-grid");
+        // Same URL again replaces; a different URL gets a fresh name
+        assert_eq!(install(&contents, url, &dir).unwrap(), "hunter-s-grid");
+        let other = "https://example.com/other.toml";
+        assert_eq!(install(&contents, other, &dir).unwrap(), "hunter-s-grid-2");
+
+        let entries = scan(&dir, &[]);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].origin, LayoutOrigin::Downloaded);
+        assert_eq!(entries[0].meta.source.as_deref(), Some(url));
+        assert_eq!(entries[0].title(), "Hunter's Grid");
+        assert!(install("windows = []", url, &dir).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn install_drops_tee_files() {
+        let dir =
+            std::env::temp_dir().join(format!("vellum-fe-gallery-tee-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let contents = NONE_LAYOUT.replacen(
+            "name = \"main\"",
+            "name = \"main\"\ntee_file = \"/home/me/.bashrc\"",
+            1,
+        );
+        assert!(contents.contains("tee_file"));
+        let name = install(&contents, "https://example.com/tee.toml", &dir).unwrap();
+        let installed = std::fs::read_to_string(dir.join(format!("{}.toml", name))).unwrap();
+        assert!(!installed.contains("tee_file"));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn github_pages_map_to_raw_files() {
+        assert_eq!(
+            raw_url("https://github.com/me/layouts/blob/main/grid.toml"),
+            "https://raw.githubusercontent.com/me/layouts/main/grid.toml"
+        );
+        assert_eq!(
+            raw_url("https://example.com/a.toml"),
+            "https://example.com/a.toml"
+        );
+    }
+}
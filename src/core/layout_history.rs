@@ -0,0 +1,116 @@
+//! Undo/redo for layout edits.
+//!
+//! Every edit that changes the layout's windows (mouse moves and resizes,
+//! the window editor, adding, hiding, duplicating or pasting windows) ends
+//! with `AppCore::record_layout_edit`, which hands the edited layout to
+//! `LayoutHistory::record`. The history keeps the layout as of the last
+//! record, so the state *before* an edit is always at hand without every
+//! edit path having to snapshot ahead of time. Loading a layout starts a
+//! fresh history.
+
+use crate::config::Layout;
+
+/// Oldest steps are dropped past this many
+const MAX_STEPS: usize = 50;
+
+#[derive(Debug)]
+pub struct LayoutHistory {
+    /// The layout as of the last record (or undo/redo)
+    current: Layout,
+    undo: Vec<Layout>,
+    redo: Vec<Layout>,
+}
+
+impl LayoutHistory {
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            current: layout,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Forget all steps and start over from `layout`
+    pub fn reset(&mut self, layout: Layout) {
+        *self = Self::new(layout);
+    }
+
+    /// Record `layout` as the result of an edit. Returns false (and records
+    /// nothing) if its windows didn't actually change.
+    pub fn record(&mut self, layout: &Layout) -> bool {
+        if self.current.windows == layout.windows {
+            return false;
+        }
+        let before = std::mem::replace(&mut self.current, layout.clone());
+        self.undo.push(before);
+        if self.undo.len() > MAX_STEPS {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+        true
+    }
+
+    /// Step back: the layout to restore, if there's an edit to undo
+    pub fn undo(&mut self) -> Option<Layout> {
+        let previous = self.undo.pop()?;
+        let edited = std::mem::replace(&mut self.current, previous.clone());
+        self.redo.push(edited);
+        Some(previous)
+    }
+
+    /// Step forward again after an undo
+    pub fn redo(&mut self) -> Option<Layout> {
+        let next = self.redo.pop()?;
+        let undone = std::mem::replace(&mut self.current, next.clone());
+        self.undo.push(undone);
+        Some(next)
+    }
+
+    pub fn undo_steps(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn redo_steps(&self) -> usize {
+        self.redo.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout_with_main_at(col: u16) -> Layout {
+        Layout::parse_tolerant(
+            &format!(
+                "[[windows]]\nwidget_type = \"text\"\nname = \"main\"\nrow = 0\ncol = {}\nrows = 10\ncols = 40\n",
+                col
+            ),
+            "test",
+        )
+        .expect("parse")
+    }
+
+    fn main_col(layout: &Layout) -> u16 {
+        layout.get_window("main").unwrap().base().col
+    }
+
+    #[test]
+    fn undo_and_redo_walk_recorded_edits() {
+        let mut history = LayoutHistory::new(layout_with_main_at(0));
+        assert!(history.undo().is_none());
+        assert!(!history.record(&layout_with_main_at(0)));
+        assert!(history.record(&layout_with_main_at(5)));
+        assert!(history.record(&layout_with_main_at(9)));
+
+        assert_eq!(main_col(&history.undo().unwrap()), 5);
+        assert_eq!(main_col(&history.undo().unwrap()), 0);
+        assert!(history.undo().is_none());
+        assert_eq!(main_col(&history.redo().unwrap()), 5);
+        assert_eq!((history.undo_steps(), history.redo_steps()), (1, 1));
+
+        // A new edit after an undo drops the redo branch
+        assert!(history.record(&layout_with_main_at(7)));
+        assert!(history.redo().is_none());
+        assert_eq!(main_col(&history.undo().unwrap()), 5);
+    }
+}
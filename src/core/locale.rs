@@ -0,0 +1,171 @@
+//! Locale-aware number and clock formatting
+//!
+//! `ui.locale` picks the thousands separator and whether clocks read
+//! "7:08 PM" or "19:08". Stats, wealth, experience, line timestamps and
+//! reminder times all format through here instead of hardcoding US style.
+//! The frontend sets the active locale from config each frame, so a change
+//! in the settings editor shows on the next redraw; core formatting read
+//! before any frame (startup messages) uses the same global.
+
+use crate::config::Locale;
+use chrono::Timelike;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+/// Set from `ui.locale`; cheap enough to call every frame
+pub fn set_locale(locale: Locale) {
+    let index = Locale::ALL.iter().position(|l| *l == locale).unwrap_or(0);
+    ACTIVE.store(index as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    Locale::ALL
+        .get(ACTIVE.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn thousands_separator(locale: Locale) -> char {
+    match locale {
+        Locale::EnUs | Locale::EnGb => ',',
+        Locale::DeDe => '.',
+        Locale::FrFr => ' ',
+        Locale::DeCh => '\'',
+    }
+}
+
+fn uses_24_hour(locale: Locale) -> bool {
+    locale != Locale::EnUs
+}
+
+fn group_digits_in(locale: Locale, digits: &str) -> String {
+    let separator = thousands_separator(locale);
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(separator);
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn format_int_in(locale: Locale, value: i64) -> String {
+    let grouped = group_digits_in(locale, &value.unsigned_abs().to_string());
+    if value < 0 {
+        format!("-{}", grouped)
+    } else {
+        grouped
+    }
+}
+
+/// "12,345" / "12.345" / "12 345" / "12'345"
+pub fn format_int(value: i64) -> String {
+    format_int_in(locale(), value)
+}
+
+pub fn format_uint(value: u64) -> String {
+    group_digits_in(locale(), &value.to_string())
+}
+
+/// Group a string of ASCII digits; anything else comes back unchanged
+pub fn group_digits(value: &str) -> String {
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+        return value.to_string();
+    }
+    group_digits_in(locale(), value)
+}
+
+fn format_time_in(locale: Locale, time: &impl Timelike, seconds: bool, compact: bool) -> String {
+    let secs = if seconds {
+        format!(":{:02}", time.second())
+    } else {
+        String::new()
+    };
+    if uses_24_hour(locale) {
+        return format!("{:02}:{:02}{}", time.hour(), time.minute(), secs);
+    }
+    let (pm, hour) = time.hour12();
+    let suffix = match (pm, compact) {
+        (true, true) => "pm",
+        (false, true) => "am",
+        (true, false) => " PM",
+        (false, false) => " AM",
+    };
+    format!("{}:{:02}{}{}", hour, time.minute(), secs, suffix)
+}
+
+/// Line timestamps and clocks: "7:08 PM" / "19:08"
+pub fn format_time(time: &impl Timelike) -> String {
+    format_time_in(locale(), time, false, false)
+}
+
+/// Log and diagnostics times: "7:08:05 PM" / "19:08:05"
+pub fn format_time_seconds(time: &impl Timelike) -> String {
+    format_time_in(locale(), time, true, false)
+}
+
+/// Tight spaces (reminders, calendar): "9:00pm" / "21:00"
+pub fn format_clock_compact(time: &impl Timelike) -> String {
+    format_time_in(locale(), time, false, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    #[test]
+    fn groups_digits_per_locale() {
+        assert_eq!(format_int_in(Locale::EnUs, 0), "0");
+        assert_eq!(format_int_in(Locale::EnUs, 999), "999");
+        assert_eq!(format_int_in(Locale::EnUs, 1234567), "1,234,567");
+        assert_eq!(format_int_in(Locale::DeDe, -4500), "-4.500");
+        assert_eq!(format_int_in(Locale::FrFr, 12345), "12 345");
+        assert_eq!(format_int_in(Locale::DeCh, 1000), "1'000");
+        assert_eq!(
+            format_int_in(Locale::EnUs, i64::MIN),
+            "-9,223,372,036,854,775,808"
+        );
+    }
+
+    #[test]
+    fn clocks_follow_the_locale() {
+        let evening = NaiveTime::from_hms_opt(19, 8, 5).unwrap();
+        let morning = NaiveTime::from_hms_opt(0, 30, 0).unwrap();
+        assert_eq!(
+            format_time_in(Locale::EnUs, &evening, false, false),
+            "7:08 PM"
+        );
+        assert_eq!(
+            format_time_in(Locale::EnUs, &morning, false, false),
+            "12:30 AM"
+        );
+        assert_eq!(
+            format_time_in(Locale::EnUs, &evening, true, false),
+            "7:08:05 PM"
+        );
+        assert_eq!(
+            format_time_in(Locale::EnUs, &evening, false, true),
+            "7:08pm"
+        );
+        assert_eq!(
+            format_time_in(Locale::EnGb, &evening, false, false),
+            "19:08"
+        );
+        assert_eq!(
+            format_time_in(Locale::DeDe, &morning, true, false),
+            "00:30:00"
+        );
+    }
+
+    #[test]
+    fn locale_names_round_trip() {
+        for locale in Locale::ALL {
+            assert_eq!(Locale::from_name(&locale.to_string()), Some(locale));
+        }
+        assert_eq!(Locale::from_name("de-de"), Some(Locale::DeDe));
+        assert_eq!(Locale::from_name("xx"), None);
+    }
+}
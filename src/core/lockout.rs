@@ -0,0 +1,152 @@
+//! Safety lockout for dangerous commands.
+//!
+//! Commands matching an enabled `[lockout] patterns` regex don't go to the
+//! game straight away: `send_command` holds them in `ui_state.lockout_prompt`
+//! and the frontend asks y/n. Yes approves that exact command once and
+//! queues it, so it comes back through the send path and is let through;
+//! no drops it. Holding `bypass_modifier` with Enter sends a typed command
+//! without asking. Everything that reaches the game through `send_command`
+//! is checked, including macros, sequences and pasted lines. The headless
+//! runtime has no popup and sends everything unchecked.
+
+use crate::config::{LockoutBypass, LockoutConfig, LockoutPattern};
+use crate::data::input::KeyModifiers;
+use regex::{Regex, RegexBuilder};
+
+#[derive(Debug, Default)]
+pub struct CommandLockout {
+    /// Patterns `compiled` was built from, to notice config edits
+    source: Vec<LockoutPattern>,
+    compiled: Vec<(String, Regex)>,
+    /// A confirmed command, let through once
+    approved: Option<String>,
+    /// The next command skips the check (bypass modifier held)
+    bypass_next: bool,
+}
+
+impl CommandLockout {
+    /// Let the next command through unchecked
+    pub fn bypass_next(&mut self) {
+        self.bypass_next = true;
+    }
+
+    /// Clear the bypass flag, returning whether it was set. Taken at the
+    /// start of every send so it can't outlive the command it was set for.
+    pub fn take_bypass(&mut self) -> bool {
+        std::mem::take(&mut self.bypass_next)
+    }
+
+    /// Let `command` through the next time it's sent
+    pub fn approve(&mut self, command: String) {
+        self.approved = Some(command);
+    }
+
+    /// The pattern `command` matches, when it has to be confirmed first
+    pub fn check(&mut self, config: &LockoutConfig, command: &str, bypass: bool) -> Option<String> {
+        if self.approved.as_deref() == Some(command) {
+            self.approved = None;
+            return None;
+        }
+        if bypass || !config.enabled {
+            return None;
+        }
+        self.refresh(&config.patterns);
+        let command = command.trim();
+        self.compiled
+            .iter()
+            .find(|(_, regex)| regex.is_match(command))
+            .map(|(pattern, _)| pattern.clone())
+    }
+
+    fn refresh(&mut self, patterns: &[LockoutPattern]) {
+        if self.source == patterns {
+            return;
+        }
+        self.source = patterns.to_vec();
+        self.compiled = patterns
+            .iter()
+            .filter(|p| p.enabled)
+            .filter_map(
+                |p| match RegexBuilder::new(&p.pattern).case_insensitive(true).build() {
+                    Ok(regex) => Some((p.pattern.clone(), regex)),
+                    Err(e) => {
+                        tracing::warn!("Skipping lockout pattern '{}': {}", p.pattern, e);
+                        None
+                    }
+                },
+            )
+            .collect();
+    }
+}
+
+/// Whether `modifiers` include the configured bypass modifier
+pub fn bypass_held(bypass: LockoutBypass, modifiers: KeyModifiers) -> bool {
+    match bypass {
+        LockoutBypass::None => false,
+        LockoutBypass::Alt => modifiers.alt,
+        LockoutBypass::Ctrl => modifiers.ctrl,
+        LockoutBypass::Shift => modifiers.shift,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holds_matching_commands_until_approved() {
+        let config = LockoutConfig::default();
+        let mut lockout = CommandLockout::default();
+        assert_eq!(
+            lockout.check(&config, "UNWIELD sword", false).as_deref(),
+            Some("^unwield\\b")
+        );
+        assert_eq!(lockout.check(&config, "wield sword", false), None);
+        assert_eq!(lockout.check(&config, "quit now", false), None);
+        assert_eq!(lockout.check(&config, "unwield sword", true), None);
+
+        lockout.approve("drop all".to_string());
+        assert_eq!(lockout.check(&config, "drop all", false), None);
+        assert!(lockout.check(&config, "drop all", false).is_some());
+    }
+
+    #[test]
+    fn disabled_and_invalid_patterns_are_skipped() {
+        let mut config = LockoutConfig {
+            patterns: vec![
+                LockoutPattern {
+                    pattern: "^drop".to_string(),
+                    enabled: false,
+                },
+                LockoutPattern {
+                    pattern: "(".to_string(),
+                    enabled: true,
+                },
+                LockoutPattern {
+                    pattern: "^sell".to_string(),
+                    enabled: true,
+                },
+            ],
+            ..LockoutConfig::default()
+        };
+        let mut lockout = CommandLockout::default();
+        assert_eq!(lockout.check(&config, "drop gem", false), None);
+        assert_eq!(
+            lockout.check(&config, "sell bag", false).as_deref(),
+            Some("^sell")
+        );
+        config.enabled = false;
+        assert_eq!(lockout.check(&config, "sell bag", false), None);
+    }
+
+    #[test]
+    fn bypass_modifier_matches() {
+        let alt = KeyModifiers {
+            alt: true,
+            ..KeyModifiers::NONE
+        };
+        assert!(bypass_held(LockoutBypass::Alt, alt));
+        assert!(!bypass_held(LockoutBypass::Ctrl, alt));
+        assert!(!bypass_held(LockoutBypass::None, alt));
+    }
+}
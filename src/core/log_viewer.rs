@@ -0,0 +1,361 @@
+//! Paged log reader behind `.viewlog`.
+//!
+//! Session logs (`[logging]`: the raw XML from the server, one file per
+//! session) and the diagnostics log (`vellum-fe.log`) run to tens of
+//! megabytes, so the viewer never holds a whole file. Opening one indexes
+//! where each line starts in a single pass; lines are then read a page at a
+//! time as the viewer scrolls, and search streams through the file. Session
+//! lines are shown as the text the game displayed — tags stripped, entities
+//! decoded, tag-only lines skipped — with the highlight rules applied on
+//! request.
+
+use crate::config::Config;
+use crate::core::highlight_engine::CoreHighlightEngine;
+use crate::data::{StyledLine, TextSegment};
+use crate::parser::XmlParser;
+use anyhow::{bail, Context, Result};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Lines read from disk at a time
+const PAGE_LINES: usize = 256;
+/// Pages kept in memory; enough for a screen plus scrolling back and forth
+const CACHED_PAGES: usize = 8;
+/// Color of the `HH:MM:SS` prefix on session-log lines
+const TIMESTAMP_COLOR: &str = "#808080";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogKind {
+    /// Raw XML written by `[logging]`
+    Session,
+    /// `vellum-fe.log`
+    Diagnostics,
+}
+
+/// Find the log `.viewlog [date]` opens: `diag` for vellum-fe.log, otherwise
+/// the newest session log whose name starts with `date` (`2026-10-17`,
+/// `today`), or the newest overall.
+pub fn find_log(config: &Config, date: Option<&str>) -> Result<(LogKind, PathBuf)> {
+    if matches!(date, Some("diag") | Some("debug")) {
+        let path = Config::base_dir()?.join("vellum-fe.log");
+        if !path.exists() {
+            bail!("No diagnostics log at {}", path.display());
+        }
+        return Ok((LogKind::Diagnostics, path));
+    }
+
+    let prefix = match date {
+        Some("today") => chrono::Local::now().format("%Y-%m-%d").to_string(),
+        Some(date) => date.to_string(),
+        None => String::new(),
+    };
+    let dir = config.logs_dir()?;
+    let newest = std::fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "xml"))
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.starts_with(&prefix))
+        })
+        // Names are timestamps, so the greatest is the newest
+        .max();
+    match newest {
+        Some(path) => Ok((LogKind::Session, path)),
+        None if date.is_some() => bail!("No session log from '{}' in {}", prefix, dir.display()),
+        None if !config.logging.enabled => {
+            bail!("No session logs yet; enable [logging] in config.toml to record them")
+        }
+        None => bail!("No session logs in {}", dir.display()),
+    }
+}
+
+/// Split a session-log line into its timestamp (if `[logging] timestamps`
+/// wrote one) and the text the game showed. None for tag-only lines.
+fn session_text(raw: &str) -> Option<(Option<&str>, String)> {
+    let bytes = raw.as_bytes();
+    let stamped = bytes.len() > 9
+        && bytes[2] == b':'
+        && bytes[5] == b':'
+        && bytes[8] == b' '
+        && [0, 1, 3, 4, 6, 7]
+            .iter()
+            .all(|&i| bytes[i].is_ascii_digit());
+    let (timestamp, xml) = if stamped {
+        (Some(&raw[..8]), &raw[9..])
+    } else {
+        (None, raw)
+    };
+
+    let mut text = String::with_capacity(xml.len());
+    let mut in_tag = false;
+    for c in xml.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    if text.trim().is_empty() {
+        return None;
+    }
+    Some((timestamp, XmlParser::decode_entities(text)))
+}
+
+/// Displayed text of a raw line, or None if the viewer skips it
+fn visible_text(kind: LogKind, raw: &str) -> Option<String> {
+    match kind {
+        LogKind::Session => session_text(raw).map(|(_, text)| text),
+        LogKind::Diagnostics => Some(raw.to_string()),
+    }
+}
+
+/// A log file read a page at a time
+pub struct LogPager {
+    kind: LogKind,
+    path: PathBuf,
+    reader: BufReader<File>,
+    /// Byte offset of each shown line
+    offsets: Vec<u64>,
+    /// Cached pages by page number, most recently used last
+    pages: Vec<(usize, Vec<StyledLine>)>,
+    highlights: CoreHighlightEngine,
+    highlighting: bool,
+}
+
+impl LogPager {
+    /// Index `path`; `highlights` are applied once `toggle_highlights` turns
+    /// them on
+    pub fn open(kind: LogKind, path: &Path, highlights: CoreHighlightEngine) -> Result<Self> {
+        let file =
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut reader = BufReader::new(file);
+        let mut offsets = Vec::new();
+        let mut offset = 0u64;
+        let mut raw = Vec::new();
+        loop {
+            raw.clear();
+            let read = reader.read_until(b'\n', &mut raw)?;
+            if read == 0 {
+                break;
+            }
+            let line = String::from_utf8_lossy(&raw);
+            if visible_text(kind, line.trim_end_matches(['\r', '\n'])).is_some() {
+                offsets.push(offset);
+            }
+            offset += read as u64;
+        }
+        Ok(Self {
+            kind,
+            path: path.to_path_buf(),
+            reader,
+            offsets,
+            pages: Vec::new(),
+            highlights,
+            highlighting: false,
+        })
+    }
+
+    pub fn kind(&self) -> LogKind {
+        self.kind
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    pub fn highlighting(&self) -> bool {
+        self.highlighting
+    }
+
+    /// Turn the highlight rules on or off for session-log lines
+    pub fn toggle_highlights(&mut self) {
+        self.highlighting = !self.highlighting;
+        self.pages.clear();
+    }
+
+    /// Line `index` as displayed, reading its page from disk if needed
+    pub fn line(&mut self, index: usize) -> Option<StyledLine> {
+        if index >= self.offsets.len() {
+            return None;
+        }
+        let page = index / PAGE_LINES;
+        let slot = match self.pages.iter().position(|(number, _)| *number == page) {
+            Some(slot) => slot,
+            None => {
+                let lines = self
+                    .read_page(page)
+                    .map_err(|e| tracing::warn!("Failed to read {}: {}", self.path.display(), e))
+                    .ok()?;
+                if self.pages.len() >= CACHED_PAGES {
+                    self.pages.remove(0);
+                }
+                self.pages.push((page, lines));
+                self.pages.len() - 1
+            }
+        };
+        let entry = self.pages.remove(slot);
+        let line = entry.1.get(index % PAGE_LINES).cloned();
+        self.pages.push(entry);
+        line
+    }
+
+    fn read_page(&mut self, page: usize) -> Result<Vec<StyledLine>> {
+        let first = page * PAGE_LINES;
+        let last = (first + PAGE_LINES).min(self.offsets.len());
+        let mut lines = Vec::with_capacity(last - first);
+        // One seek, then read on, skipping the lines the index left out
+        self.reader.seek(SeekFrom::Start(self.offsets[first]))?;
+        let mut raw = Vec::new();
+        while lines.len() < last - first {
+            raw.clear();
+            if self.reader.read_until(b'\n', &mut raw)? == 0 {
+                break;
+            }
+            let text = String::from_utf8_lossy(&raw);
+            let text = text.trim_end_matches(['\r', '\n']);
+            if visible_text(self.kind, text).is_some() {
+                lines.push(self.styled(text));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn styled(&self, raw: &str) -> StyledLine {
+        let mut segments = match self.kind {
+            LogKind::Diagnostics => vec![TextSegment::plain(raw)],
+            LogKind::Session => {
+                let (timestamp, text) = session_text(raw).unwrap_or((None, String::new()));
+                let mut segments = vec![TextSegment::plain(text)];
+                if self.highlighting {
+                    if let Some(highlighted) = self
+                        .highlights
+                        .apply_highlights_to_segments(&segments, "main")
+                    {
+                        segments = highlighted;
+                    }
+                }
+                if let Some(timestamp) = timestamp {
+                    segments.insert(
+                        0,
+                        TextSegment::styled(
+                            format!("{} ", timestamp),
+                            Some(TIMESTAMP_COLOR.to_string()),
+                            false,
+                        ),
+                    );
+                }
+                segments
+            }
+        };
+        segments.retain(|seg| !seg.text.is_empty());
+        StyledLine {
+            segments,
+            stream: "main".into(),
+            timestamp: None,
+        }
+    }
+
+    /// Indices of lines containing `query` (case-insensitive), scanning the
+    /// file from the start
+    pub fn search(&mut self, query: &str) -> Vec<usize> {
+        let needle = query.to_lowercase();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        let mut matches = Vec::new();
+        if self.reader.seek(SeekFrom::Start(0)).is_err() {
+            return matches;
+        }
+        let mut index = 0;
+        let mut raw = Vec::new();
+        while let Ok(read) = self.reader.read_until(b'\n', &mut raw) {
+            if read == 0 {
+                break;
+            }
+            let line = String::from_utf8_lossy(&raw);
+            if let Some(text) = visible_text(self.kind, line.trim_end_matches(['\r', '\n'])) {
+                if text.to_lowercase().contains(&needle) {
+                    matches.push(index);
+                }
+                index += 1;
+            }
+            raw.clear();
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_log(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "vellum-log-viewer-{}-{}.xml",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn text(line: &StyledLine) -> String {
+        line.segments.iter().map(|seg| seg.text.as_str()).collect()
+    }
+
+    #[test]
+    fn session_lines_show_game_text_only() {
+        let path = write_log(
+            "text",
+            "12:00:01 <prompt time=\"1\">&gt;</prompt>\n\
+             12:00:02 <streamWindow id='room'/>\n\
+             12:00:03 You see a <a exist=\"1\" noun=\"kobold\">kobold</a> &amp; a rat.\n",
+        );
+        let mut pager =
+            LogPager::open(LogKind::Session, &path, CoreHighlightEngine::empty()).unwrap();
+        assert_eq!(pager.len(), 2);
+        assert_eq!(text(&pager.line(0).unwrap()), "12:00:01 >");
+        assert_eq!(
+            text(&pager.line(1).unwrap()),
+            "12:00:03 You see a kobold & a rat."
+        );
+        assert!(pager.line(2).is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn pages_and_search_cover_the_whole_file() {
+        let contents: String = (0..PAGE_LINES * 3)
+            .map(|i| format!("line {}\n", i))
+            .collect();
+        let path = write_log("pages", &contents);
+        let mut pager =
+            LogPager::open(LogKind::Diagnostics, &path, CoreHighlightEngine::empty()).unwrap();
+        assert_eq!(pager.len(), PAGE_LINES * 3);
+
+        let last = PAGE_LINES * 3 - 1;
+        assert_eq!(text(&pager.line(last).unwrap()), format!("line {}", last));
+        assert_eq!(text(&pager.line(5).unwrap()), "line 5");
+        assert_eq!(
+            pager.search("LINE 51"),
+            vec![51, 510, 511, 512, 513, 514, 515, 516, 517, 518, 519]
+        );
+        // Search moved the reader; paging still seeks to the right line
+        assert_eq!(text(&pager.line(700).unwrap()), "line 700");
+        let _ = std::fs::remove_file(&path);
+    }
+}
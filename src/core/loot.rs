@@ -0,0 +1,130 @@
+//! Session loot tally for the loot filter.
+//!
+//! A drop is a ranked item (see data::loot) that shows up in the room
+//! objects while you stay in the same room - what a search or a kill
+//! leaves on the ground. Objects already present when you walk in are
+//! the baseline and never count. The message processor feeds room object
+//! updates in; `.loot` prints the tally.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::core::state::RoomObject;
+use crate::data::loot::{LootCategory, LootItem, LootTable, LootTier};
+
+/// Drops counted this session
+#[derive(Debug, Clone, Default)]
+pub struct LootTally {
+    /// (category, item name) → (tier, count)
+    counts: BTreeMap<(LootCategory, String), (LootTier, u32)>,
+    /// Object ids seen in the current room
+    room_ids: HashSet<String>,
+    /// Bumped on every counted drop
+    pub generation: u64,
+}
+
+impl LootTally {
+    /// Walked into a new room: everything here is scenery, not a drop.
+    pub fn rebase(&mut self, objects: &[RoomObject]) {
+        self.room_ids = objects.iter().map(|o| o.id.clone()).collect();
+    }
+
+    /// Count ranked objects that weren't in the room before. Returns the
+    /// newly counted items.
+    pub fn record_new<'t>(
+        &mut self,
+        objects: &[RoomObject],
+        table: &'t LootTable,
+    ) -> Vec<&'t LootItem> {
+        let mut dropped = Vec::new();
+        for object in objects {
+            if !self.room_ids.insert(object.id.clone()) {
+                continue;
+            }
+            if let Some(item) = table.classify(&object.name) {
+                let entry = self
+                    .counts
+                    .entry((item.category, item.name.clone()))
+                    .or_insert((item.tier, 0));
+                entry.1 += 1;
+                dropped.push(item);
+            }
+        }
+        if !dropped.is_empty() {
+            self.generation += 1;
+        }
+        dropped
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.values().map(|(_, count)| count).sum()
+    }
+
+    pub fn reset(&mut self) {
+        self.counts.clear();
+        self.generation += 1;
+    }
+
+    /// `.loot` output: one line per category, best tier first.
+    pub fn summary_lines(&self) -> Vec<String> {
+        if self.counts.is_empty() {
+            return vec!["No loot counted this session".to_string()];
+        }
+        let mut lines = vec![format!("Loot this session: {} drops", self.total())];
+        let mut category: Option<LootCategory> = None;
+        let mut entries: Vec<_> = self.counts.iter().collect();
+        entries.sort_by(|((ca, na), (ta, _)), ((cb, nb), (tb, _))| {
+            ca.cmp(cb).then(tb.cmp(ta)).then(na.cmp(nb))
+        });
+        for ((cat, name), (tier, count)) in entries {
+            if category != Some(*cat) {
+                category = Some(*cat);
+                lines.push(format!("  {}:", cat.label()));
+            }
+            let marker = match tier {
+                LootTier::Top => " *",
+                _ => "",
+            };
+            lines.push(format!("    {} x{}{}", name, count, marker));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(id: &str, name: &str) -> RoomObject {
+        RoomObject {
+            name: name.to_string(),
+            noun: None,
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn counts_only_new_ranked_objects() {
+        let table = LootTable::new(&[]);
+        let mut tally = LootTally::default();
+        tally.rebase(&[object("1", "a blue sapphire"), object("2", "a rock")]);
+
+        let room = [
+            object("1", "a blue sapphire"),
+            object("2", "a rock"),
+            object("3", "a star ruby"),
+            object("4", "an iron box"),
+            object("5", "a bent nail"),
+        ];
+        let dropped = tally.record_new(&room, &table);
+        assert_eq!(dropped.len(), 2);
+        assert_eq!(dropped[0].tier, LootTier::Top);
+        // Same objects again: nothing new
+        assert!(tally.record_new(&room, &table).is_empty());
+        assert_eq!(tally.total(), 2);
+
+        let lines = tally.summary_lines();
+        assert_eq!(lines[0], "Loot this session: 2 drops");
+        assert!(lines.contains(&"    star ruby x1 *".to_string()));
+        assert!(lines.contains(&"    box x1".to_string()));
+    }
+}
@@ -221,7 +221,7 @@ impl MapDbUpdater {
     }
 }
 
-fn agent() -> Result<ureq::Agent, String> {
+pub(crate) fn agent() -> Result<ureq::Agent, String> {
     let connector =
         native_tls::TlsConnector::new().map_err(|e| format!("TLS init failed: {e}"))?;
     Ok(ureq::AgentBuilder::new()
@@ -35,6 +35,7 @@ pub enum MenuAction {
     // Editing
     Save,   // Ctrl+s
     Delete, // Delete key or Ctrl+D
+    Undo,   // Ctrl+Z - undo the last delete in browsers
 
     // Text Editing (always available in TextAreas)
     SelectAll, // Ctrl+A
@@ -6,10 +6,14 @@
 use crate::config::{Config, SavedDialogPositions, SpellColorStyle};
 use crate::core::bounty_parser;
 use crate::core::GameState;
+use crate::data::intern::intern;
 use crate::data::*;
 use crate::parser::ParsedElement;
 // std::time unused here
 
+/// Cap on lines kept for a single output block (popup viewer capture)
+const MAX_OUTPUT_BLOCK_LINES: usize = 5000;
+
 /// Processes incoming game messages and updates state
 pub struct MessageProcessor {
     /// Configuration (for presets, highlights, etc.)
@@ -76,6 +80,11 @@ pub struct MessageProcessor {
 
     squelch_matcher: Option<aho_corasick::AhoCorasick>,
     squelch_regexes: Vec<regex::Regex>,
+    /// Gags scoped to windows/tabs: (scope names, matcher). Checked as a
+    /// line is added to each window instead of dropping it everywhere.
+    scoped_squelch: Vec<(Vec<String>, regex::Regex)>,
+    /// Per-window tee files (see core::tee), synced from the layout by AppCore
+    tees: super::tee::TeeSet,
 
     /// Redirect cache: true if any highlights have redirect_to configured (lazy check optimization)
     has_redirect_highlights: bool,
@@ -119,6 +128,47 @@ pub struct MessageProcessor {
     /// and persists it under that maze's name.
     pub pending_pathcode: Option<Vec<String>>,
 
+    /// Silver pickups, sales, bank moves and wealth checks seen on the main
+    /// stream. AppCore applies them to GameState.wealth.
+    pub pending_silver: Vec<super::wealth::SilverEvent>,
+
+    /// Container capacity lines seen on the main stream. AppCore applies
+    /// them to GameState.container_cache.
+    pub pending_capacity: Vec<super::containers::CapacityEvent>,
+
+    /// Attack, roll, damage and death lines seen on the main or combat
+    /// stream. AppCore applies them to GameState.combat.
+    pub pending_combat: Vec<super::combat::CombatEvent>,
+
+    /// Whisper and thought senders seen on any stream, with the line they
+    /// came from. AppCore applies them to GameState.reply and away mode.
+    pub pending_reply: Vec<(super::reply::ReplyEvent, String)>,
+
+    /// URLs linkified in game text. AppCore adds them to GameState.urls.
+    pub pending_urls: Vec<String>,
+
+    /// The last `TIME` check seen on the main stream. AppCore corrects
+    /// GameState.calendar with it.
+    pub pending_calendar: Option<crate::data::game_time::CalendarReading>,
+
+    /// Ranked item list for the loot filter, rebuilt from config.loot
+    loot_table: crate::data::loot::LootTable,
+    /// Streamer-mode name aliases, rebuilt from config.streamer and when
+    /// the character name arrives
+    streamer: super::streamer::Anonymizer,
+    /// Set by <nav>: the next room objects update is the new room's
+    /// baseline rather than drops
+    loot_room_changed: bool,
+
+    /// Remembered appraisal/sale values; AppCore loads it from itemdb.toml
+    pub item_db: crate::data::itemdb::ItemDb,
+    /// Item from the last APPRAISE/SELL command, for shop lines that only
+    /// quote a price
+    appraisal_item: Option<String>,
+    /// The last main-stream line asked for a password (core::secrets);
+    /// AppCore masks the next command sent
+    pub password_prompt: bool,
+
     /// Saved dialog positions for persistence across sessions
     pub saved_dialog_positions: SavedDialogPositions,
 
@@ -130,6 +180,12 @@ pub struct MessageProcessor {
     /// Updated whenever society stream text arrives
     society_buffer: Vec<String>,
 
+    /// Main-stream lines received since the last prompt (the output block in progress)
+    output_block: Vec<StyledLine>,
+
+    /// Most recent completed output block, for the popup viewer (.view last)
+    last_output_block: Vec<StyledLine>,
+
     /// Remote client sink for the web frontend sidecar.
     /// None unless `[web] enabled = true` — see core/remote.rs.
     pub remote: Option<super::remote::RemoteSink>,
@@ -173,7 +229,7 @@ impl MessageProcessor {
         let parser = crate::parser::XmlParser::with_presets(preset_list, event_patterns);
 
         // Build highlight engine from config
-        let highlights: Vec<_> = config.highlights.values().cloned().collect();
+        let highlights = config.active_highlights();
         let mut highlight_engine = super::highlight_engine::CoreHighlightEngine::new(highlights);
         highlight_engine.set_replace_enabled(config.highlight_settings.replace_enabled);
 
@@ -203,6 +259,8 @@ impl MessageProcessor {
             previous_room_components: std::collections::HashMap::new(),
             squelch_matcher: None,
             squelch_regexes: Vec::new(),
+            scoped_squelch: Vec::new(),
+            tees: super::tee::TeeSet::default(),
             has_redirect_highlights: false,
             redirect_matcher: None,
             redirect_literal_meta: Vec::new(),
@@ -214,18 +272,44 @@ impl MessageProcessor {
             pending_sounds: Vec::new(),
             pending_evidence: Vec::new(),
             pending_pathcode: None,
+            pending_silver: Vec::new(),
+            pending_capacity: Vec::new(),
+            pending_combat: Vec::new(),
+            pending_reply: Vec::new(),
+            pending_urls: Vec::new(),
+            pending_calendar: None,
+            loot_table: crate::data::loot::LootTable::default(),
+            streamer: super::streamer::Anonymizer::default(),
+            loot_room_changed: true,
+            item_db: crate::data::itemdb::ItemDb::default(),
+            appraisal_item: None,
+            password_prompt: false,
             saved_dialog_positions,
             bounty_buffer: None,
             society_buffer: Vec::new(),
+            output_block: Vec::new(),
+            last_output_block: Vec::new(),
         };
 
         // Initialize squelch patterns from config
         processor.update_squelch_patterns();
         // Initialize redirect cache from config
         processor.update_redirect_cache();
+        processor.loot_table = crate::data::loot::LootTable::new(&processor.config.loot.items);
+        let character = processor.config.connection.character.clone();
+        processor.rebuild_streamer(character.as_deref());
         processor
     }
 
+    /// Rebuild the streamer-mode anonymizer for `character`
+    fn rebuild_streamer(&mut self, character: Option<&str>) {
+        self.streamer = super::streamer::Anonymizer::new(
+            &self.config.streamer,
+            self.config.connection.account.as_deref(),
+            character,
+        );
+    }
+
     /// Build the prompt character color map from config.
     /// Only single-character entries can ever match (the renderer compares
     /// one char at a time); first entry wins for duplicate characters.
@@ -260,6 +344,75 @@ impl MessageProcessor {
         std::mem::take(&mut self.society_buffer)
     }
 
+    /// Most recent completed main-stream output block (between two prompts)
+    pub fn last_output_block(&self) -> &[StyledLine] {
+        &self.last_output_block
+    }
+
+    /// Ranked item list for the loot filter (`[loot] items` included)
+    pub fn loot_table(&self) -> &crate::data::loot::LootTable {
+        &self.loot_table
+    }
+
+    /// Close the output block in progress. Blocks at or above
+    /// `ui.view_hint_lines` get a clickable "view" link appended to main.
+    fn finish_output_block(&mut self, ui_state: &mut UiState) {
+        if self.output_block.is_empty() {
+            return;
+        }
+        self.last_output_block = std::mem::take(&mut self.output_block);
+
+        let threshold = self.config.ui.view_hint_lines;
+        let count = self.last_output_block.len();
+        if threshold == 0 || count < threshold {
+            return;
+        }
+
+        let hint = StyledLine {
+            segments: vec![
+                TextSegment {
+                    text: format!("[{} lines - ", count),
+                    fg: Some(intern("#808080")),
+                    bg: None,
+                    bold: false,
+                    mono: false,
+                    span_type: SpanType::System,
+                    link_data: None,
+                },
+                TextSegment {
+                    text: "view".to_string(),
+                    fg: Some(intern("#00ffff")),
+                    bg: None,
+                    bold: false,
+                    mono: false,
+                    span_type: SpanType::Link,
+                    link_data: Some(LinkData {
+                        exist_id: "_direct_".to_string(),
+                        noun: ".view last".to_string(),
+                        text: "view".to_string(),
+                        coord: None,
+                    }),
+                },
+                TextSegment {
+                    text: "]".to_string(),
+                    fg: Some(intern("#808080")),
+                    bg: None,
+                    bold: false,
+                    mono: false,
+                    span_type: SpanType::System,
+                    link_data: None,
+                },
+            ],
+            stream: intern("main"),
+            timestamp: None,
+        };
+        if let Some(window) = ui_state.get_window_mut("main") {
+            if let WindowContent::Text(ref mut content) = window.content {
+                content.add_line(hint);
+            }
+        }
+    }
+
     /// Refresh internal config, parser presets, and caches after a reload.
     pub fn apply_config(&mut self, mut config: Config) {
         let apply_start = std::time::Instant::now();
@@ -270,6 +423,9 @@ impl MessageProcessor {
         );
         self.config = config;
         self.prompt_color_map = Self::build_prompt_color_map(&self.config);
+        self.loot_table = crate::data::loot::LootTable::new(&self.config.loot.items);
+        let character = self.streamer.character().map(str::to_string);
+        self.rebuild_streamer(character.as_deref());
 
         // Log loaded presets for debugging
         for (id, preset) in &self.config.colors.presets {
@@ -317,7 +473,7 @@ impl MessageProcessor {
     /// Called on startup and when highlights are reloaded.
     pub fn update_highlights(&mut self) {
         let start = std::time::Instant::now();
-        let highlights: Vec<_> = self.config.highlights.values().cloned().collect();
+        let highlights = self.config.active_highlights();
         self.highlight_engine.update_patterns(highlights);
         self.highlight_engine
             .set_replace_enabled(self.config.highlight_settings.replace_enabled);
@@ -410,9 +566,13 @@ impl MessageProcessor {
                 // login-derived write-back is the fallback).
                 game_state.character_name = Some(character.clone());
                 tracing::debug!("Character name from <app>: {}", character);
+                if self.streamer.character() != Some(character.as_str()) {
+                    self.rebuild_streamer(Some(character.as_str()));
+                }
             }
             ParsedElement::RoomId { id } => {
                 *nav_room_id = Some(id.clone());
+                self.loot_room_changed = true;
                 *room_window_dirty = true;
                 tracing::debug!("Room ID updated: {}", id);
             }
@@ -603,6 +763,13 @@ impl MessageProcessor {
                 // Finish current stream before prompt
                 self.flush_current_stream_with_tts(ui_state, tts_manager.as_deref_mut());
 
+                // The prompt closes the current output block
+                self.finish_output_block(ui_state);
+
+                // An APPRAISE or SELL is answered before its prompt; a price
+                // line after that is about something else
+                self.appraisal_item = None;
+
                 // Flush perception buffer on prompt (after all entries have accumulated)
                 if !self.perception_buffer.is_empty() {
                     self.flush_perception_buffer(ui_state);
@@ -630,27 +797,31 @@ impl MessageProcessor {
                     // Store the prompt in game state for command echoes
                     game_state.last_prompt = text.clone();
 
-                    // Render prompt with per-character coloring
-                    for ch in text.chars() {
-                        let color = self
-                            .prompt_color_map
-                            .get(&ch)
-                            .cloned()
-                            .unwrap_or_else(|| "#808080".to_string()); // Default dark gray
-
-                        self.current_segments.push(TextSegment {
-                            text: ch.to_string(),
-                            fg: Some(color),
-                            bg: None,
-                            bold: false,
-                            mono: false,
-                            span_type: SpanType::Normal,
-                            link_data: None,
-                        });
-                    }
+                    // With hide_prompt, a command input's prompt_template
+                    // shows the prompt instead of main
+                    if !self.config.ui.hide_prompt {
+                        // Render prompt with per-character coloring
+                        for ch in text.chars() {
+                            let color = self
+                                .prompt_color_map
+                                .get(&ch)
+                                .cloned()
+                                .unwrap_or_else(|| "#808080".to_string()); // Default dark gray
+
+                            self.current_segments.push(TextSegment {
+                                text: ch.to_string(),
+                                fg: Some(intern(&color)),
+                                bg: None,
+                                bold: false,
+                                mono: false,
+                                span_type: SpanType::Normal,
+                                link_data: None,
+                            });
+                        }
 
-                    // Finish prompt line
-                    self.flush_current_stream_with_tts(ui_state, tts_manager);
+                        // Finish prompt line
+                        self.flush_current_stream_with_tts(ui_state, tts_manager);
+                    }
                 }
 
                 // Extract server time offset for countdown synchronization
@@ -670,6 +841,8 @@ impl MessageProcessor {
                 // Reset chunk tracking for next prompt
                 self.chunk_has_main_text = false;
                 self.chunk_has_silent_updates = false;
+                // The prompt line itself isn't part of the next block
+                self.output_block.clear();
 
                 // Reset discard flag - prompts always return to main stream
                 self.discard_current_stream = false;
@@ -718,8 +891,8 @@ impl MessageProcessor {
                     // Create the text segment
                     let segment = TextSegment {
                         text: content.clone(),
-                        fg: fg_color.clone(),
-                        bg: bg_color.clone(),
+                        fg: fg_color.as_deref().map(intern),
+                        bg: bg_color.as_deref().map(intern),
                         bold: *bold,
                         mono: *mono,
                         span_type: data_span_type,
@@ -788,8 +961,8 @@ impl MessageProcessor {
 
                 self.current_segments.push(TextSegment {
                     text: content.clone(),
-                    fg: fg_color.clone(),
-                    bg: bg_color.clone(),
+                    fg: fg_color.as_deref().map(intern),
+                    bg: bg_color.as_deref().map(intern),
                     bold: *bold,
                     mono: *mono,
                     span_type: data_span_type,
@@ -1044,6 +1217,7 @@ impl MessageProcessor {
                     "encumlevel" => {
                         game_state.encumbrance.update_level(*value, text.clone());
                     }
+                    "pbarStance" => game_state.stance.update(*value, *max, text),
                     _ => {}
                 }
             }
@@ -1091,17 +1265,15 @@ impl MessageProcessor {
                 // Update game state. The parser strips the "Icon" prefix
                 // but preserves casing (e.g. "BLEEDING"), so match
                 // case-insensitively like the indicator widgets below do.
+                if let Some(effect) = crate::core::state::StatusEffect::from_id(id) {
+                    game_state.status.set(effect, *active);
+                }
                 match id.to_ascii_lowercase().as_str() {
-                    "stunned" => game_state.status.stunned = *active,
-                    "bleeding" => game_state.status.bleeding = *active,
-                    "hidden" => game_state.status.hidden = *active,
-                    "invisible" => game_state.status.invisible = *active,
-                    "webbed" => game_state.status.webbed = *active,
                     "dead" => game_state.status.dead = *active,
                     "standing" => game_state.status.standing = *active,
                     "kneeling" => game_state.status.kneeling = *active,
                     "sitting" => game_state.status.sitting = *active,
-                    "prone" => game_state.status.prone = *active,
+                    "joined" => game_state.status.joined = *active,
                     _ => {}
                 }
 
@@ -1556,7 +1728,7 @@ impl MessageProcessor {
                                     let mut segments = Vec::new();
                                     segments.push(TextSegment {
                                         text: "!".to_string(),
-                                        fg: active_color.clone(),
+                                        fg: active_color.as_deref().map(intern),
                                         bg: None,
                                         bold: false,
                                         mono: false,
@@ -1577,7 +1749,7 @@ impl MessageProcessor {
                                     }
                                     content.add_line(StyledLine {
                                         segments,
-                                        stream: window_name.clone(),
+                                        stream: intern(&window_name),
                                         timestamp: None,
                                     });
                                 } else {
@@ -1808,8 +1980,11 @@ impl MessageProcessor {
             ParsedElement::ContainerItem { container_id, content } => {
                 self.chunk_has_silent_updates = true; // Mark as silent update
 
-                // Add item to container
-                game_state.container_cache.add_item(container_id, content.clone());
+                // Add item to container, with its remembered value if any
+                let note = Self::link_text(content).and_then(|name| self.item_value_note(name));
+                game_state
+                    .container_cache
+                    .add_item(container_id, content.clone(), note);
 
                 tracing::trace!("Added item to container '{}': {}", container_id,
                     if content.len() > 50 { format!("{}...", &content[..50]) } else { content.clone() });
@@ -1913,6 +2088,14 @@ impl MessageProcessor {
     /// Scan raw component content for `<crtrStatus exist="..." .../>` tags,
     /// keyed by exist id. Component values are captured with embedded tags
     /// intact, so this runs over the same string the creature scan uses.
+    /// Text of the first `<a>` link in a line of markup.
+    fn link_text(markup: &str) -> Option<&str> {
+        let start = markup.find("<a ")?;
+        let text_start = start + markup[start..].find('>')? + 1;
+        let text_end = text_start + markup[text_start..].find("</a>")?;
+        Some(&markup[text_start..text_end])
+    }
+
     fn parse_crtr_status_tags(
         value: &str,
     ) -> std::collections::HashMap<String, crate::core::state::CreatureFlags> {
@@ -1942,6 +2125,69 @@ impl MessageProcessor {
         map
     }
 
+    /// An outgoing command. APPRAISE and SELL name the item a following
+    /// price-only shop line is about.
+    pub fn note_command(&mut self, command: &str) {
+        let mut words = command.split_whitespace();
+        let verb = words.next().unwrap_or("").to_ascii_lowercase();
+        let is_shop_verb = (verb.len() >= 4 && "appraise".starts_with(&verb)) || verb == "sell";
+        let item: Vec<&str> = words.collect();
+        self.appraisal_item = (is_shop_verb && !item.is_empty()).then(|| item.join(" "));
+    }
+
+    /// Store an appraised or sold value and persist the database.
+    fn remember_item_value(&mut self, appraisal: crate::data::itemdb::Appraisal) {
+        let pending = self.appraisal_item.take();
+        let Some(item) = appraisal.item.or(pending) else {
+            return;
+        };
+        if self.item_db.record(&item, appraisal.value) {
+            tracing::debug!("Remembered item value: {} = {}", item, appraisal.value);
+            if let Err(e) = self.item_db.save() {
+                tracing::warn!("Failed to save item values: {:#}", e);
+            }
+        }
+    }
+
+    /// Dim " (~1,250)" annotation for an item with a remembered value.
+    fn item_value_note(&self, name: &str) -> Option<String> {
+        if !self.config.loot.show_values {
+            return None;
+        }
+        let value = self.item_db.value(name)?;
+        Some(format!(
+            " (~{})",
+            super::wealth::format_silver(value.min(i64::MAX as u64) as i64)
+        ))
+    }
+
+    /// Loot filter: count ranked items that appeared in the room since the
+    /// last update, and queue the drop sound for the configured tier.
+    fn track_loot_drops(&mut self, game_state: &mut GameState) {
+        if !self.config.loot.enabled {
+            return;
+        }
+        if std::mem::take(&mut self.loot_room_changed) {
+            game_state.loot.rebase(&game_state.room_objects);
+            return;
+        }
+        let dropped = game_state
+            .loot
+            .record_new(&game_state.room_objects, &self.loot_table);
+        let loud = dropped
+            .iter()
+            .any(|item| item.tier >= self.config.loot.sound_tier);
+        if loud {
+            if let Some(sound) = &self.config.loot.sound {
+                self.pending_sounds
+                    .push(super::highlight_engine::SoundTrigger {
+                        file: sound.clone(),
+                        volume: None,
+                    });
+            }
+        }
+    }
+
     /// Handle component data for room window and exp window (DR)
     fn handle_component(
         &mut self,
@@ -2234,6 +2480,8 @@ impl MessageProcessor {
                 "Extracted {} room objects from room objs",
                 game_state.room_objects.len()
             );
+
+            self.track_loot_drops(game_state);
         }
 
         // Extract players from room players component
@@ -2278,7 +2526,7 @@ impl MessageProcessor {
 
                                         let player = crate::core::state::Player {
                                             id: exist_id.to_string(),
-                                            name: player_name.to_string(),
+                                            name: self.streamer.apply(player_name).into_owned(),
                                             primary_status,
                                             secondary_status,
                                         };
@@ -2377,8 +2625,8 @@ impl MessageProcessor {
 
                         let segment = TextSegment {
                             text: content.clone(),
-                            fg: fg_color.clone(),
-                            bg: bg_color.clone(),
+                            fg: fg_color.as_deref().map(intern),
+                            bg: bg_color.as_deref().map(intern),
                             bold,
                             mono: false,
                             span_type: data_span_type,
@@ -2397,6 +2645,21 @@ impl MessageProcessor {
                         }
 
                         current_line_segments.push(segment);
+
+                        // Remembered value after items on the ground, dimmed
+                        if id == "room objs" && data_span_type == DataSpanType::Link {
+                            if let Some(note) = self.item_value_note(&content) {
+                                current_line_segments.push(TextSegment {
+                                    text: note,
+                                    fg: Some(intern("#808080")),
+                                    bg: None,
+                                    bold: false,
+                                    mono: false,
+                                    span_type: DataSpanType::Normal,
+                                    link_data: None,
+                                });
+                            }
+                        }
                     }
                     _ => {
                         // Ignore other parsed elements (we only care about Text)
@@ -2406,6 +2669,7 @@ impl MessageProcessor {
 
             // Add the line if we got any segments
             if !current_line_segments.is_empty() {
+                self.streamer.apply_segments(&mut current_line_segments);
                 if let Some(buffer) = room_components.get_mut(id) {
                     buffer.push(current_line_segments);
                     *room_window_dirty = true;
@@ -2465,9 +2729,14 @@ impl MessageProcessor {
             return; // Discard line completely
         }
 
-        // Mapping evidence capture (forage sense / ranger sense responses on
-        // the main stream). Cheap: a few substring checks per line.
+        // Main-stream lines AppCore acts on: password prompts, mapping
+        // evidence (forage / ranger sense), maze pathcodes, silver changes,
+        // game time and container capacity. Cheap: a few substring checks
+        // per line.
         if self.current_stream == "main" {
+            if !is_blank_line {
+                self.password_prompt = crate::core::secrets::is_password_prompt(&full_text);
+            }
             if let Some(items) = crate::core::evidence::parse_forage_line(&full_text) {
                 self.pending_evidence
                     .push(crate::core::evidence::Observation::Forage(items));
@@ -2478,8 +2747,27 @@ impl MessageProcessor {
                 crate::core::travel::mazes::parse_pathcode_line(&full_text)
             {
                 self.pending_pathcode = Some(route);
+            } else if let Some(event) = crate::core::wealth::parse_silver_line(&full_text) {
+                self.pending_silver.push(event);
+            } else if let Some(reading) = crate::data::game_time::parse_time_line(&full_text) {
+                self.pending_calendar = Some(reading);
+            } else if let Some(event) = crate::core::containers::parse_capacity_line(&full_text) {
+                self.pending_capacity.push(event);
+            }
+            if let Some(appraisal) = crate::data::itemdb::parse_appraisal_line(&full_text) {
+                self.remember_item_value(appraisal);
+            }
+        }
+        if self.current_stream == "main" || self.current_stream == "combat" {
+            if let Some(event) = crate::core::combat::parse_combat_line(&full_text) {
+                self.pending_combat.push(event);
             }
         }
+        if let Some(event) = crate::core::reply::parse_reply_line(&self.current_stream, &full_text)
+        {
+            self.pending_reply
+                .push((event, full_text.trim().to_string()));
+        }
 
         // Check for redirect match (after squelch, as squelch takes precedence)
         let redirect_match = self.check_redirect_match(&full_text);
@@ -2510,14 +2798,22 @@ impl MessageProcessor {
             .highlight_engine
             .apply_highlights(&self.current_segments, &self.current_stream);
         self.current_segments = highlight_result.segments;
-        let deferred_replacements = highlight_result.deferred_replacements;
+        // Streamer mode after highlights, so name highlights still match
+        self.streamer.apply_segments(&mut self.current_segments);
+        if self.config.urls.enabled {
+            let urls = super::urls::linkify(&mut self.current_segments);
+            self.pending_urls.extend(urls);
+        }
+        // Window-scoped highlights and gags are applied per window as the
+        // line is added (see line_for_window)
+        let window_scoped = self.highlight_engine.has_scoped() || !self.scoped_squelch.is_empty();
 
         // Queue sounds from highlight processing
         self.pending_sounds.extend(highlight_result.sounds);
 
         let mut line = StyledLine {
             segments: std::mem::take(&mut self.current_segments),
-            stream: self.current_stream.clone(),
+            stream: intern(&self.current_stream),
             timestamp: None,
         };
 
@@ -2606,6 +2902,13 @@ impl MessageProcessor {
             }
         }
 
+        // Capture main-stream output for the popup viewer
+        if (self.current_stream == "main" || (should_send_to_original && original_stream == "main"))
+            && self.output_block.len() < MAX_OUTPUT_BLOCK_LINES
+        {
+            self.output_block.push(line.clone());
+        }
+
         // Buffer bounty stream data for later use (e.g., when adding a bounty window later)
         // This happens regardless of whether a bounty window exists
         if self.current_stream.eq_ignore_ascii_case("bounty") {
@@ -2727,6 +3030,9 @@ impl MessageProcessor {
         }
 
         let mut text_added_to_any_window = false;
+        // A scoped gag dropping the line counts as handled: it must not fall
+        // back to main
+        let mut gagged_in_window = false;
         let mut tts_handled = false;
 
         // Route via the prebuilt subscriber index (one O(1) lookup per line)
@@ -2777,7 +3083,7 @@ impl MessageProcessor {
                         // depend on the line surviving)
                         let move_line = is_last
                             && !needed_later
-                            && deferred_replacements.is_empty()
+                            && !window_scoped
                             && !is_compact_bounty;
 
                         if move_line {
@@ -2792,25 +3098,27 @@ impl MessageProcessor {
                                 }
                                 tts_handled = true;
                             }
-                            content.add_line(line_slot.take().expect("line moved at most once"));
+                            self.tee(
+                                window_name,
+                                line_slot.as_ref().expect("line present until moved"),
+                            );
+                            super::conversation::add_line(
+                                content,
+                                line_slot.take().expect("line moved at most once"),
+                            );
                             text_added_to_any_window = true;
                             continue;
                         }
 
                         let src = line_slot.as_ref().expect("line present until moved");
-                        // Apply window-specific replacements if any
-                        let final_line = if deferred_replacements.is_empty() {
+                        // Apply window-scoped highlights and gags if any
+                        let final_line = if !window_scoped {
                             src.clone()
+                        } else if let Some(scoped) = self.line_for_window(src, &[window_name]) {
+                            scoped
                         } else {
-                            StyledLine {
-                                segments: super::highlight_engine::apply_deferred_for_window(
-                                    &src.segments,
-                                    &deferred_replacements,
-                                    window_name,
-                                ),
-                                stream: src.stream.clone(),
-                                timestamp: src.timestamp,
-                            }
+                            gagged_in_window = true;
+                            continue;
                         };
 
                         // Check for compact bounty mode
@@ -2830,7 +3138,8 @@ impl MessageProcessor {
                             }
                         }
 
-                        content.add_line(final_line);
+                        self.tee(window_name, &final_line);
+                        super::conversation::add_line(content, final_line);
                         added_here = true;
                     }
                 }
@@ -2876,6 +3185,8 @@ impl MessageProcessor {
                     // Tabs may match multiple times, so this arm always clones
                     let src = line_slot.as_ref().expect("line present until moved");
                     let active_tab_index = tab_content.active_tab_index;
+                    // The window tees each line once, however many tabs take it
+                    let mut teed = false;
                     for (tab_index, tab) in tab_content.tabs.iter_mut().enumerate() {
                         if tab
                             .definition
@@ -2883,35 +3194,43 @@ impl MessageProcessor {
                             .iter()
                             .any(|s| s.trim().eq_ignore_ascii_case(&self.current_stream))
                         {
-                            // Apply window-specific replacements if any
-                            // Check both parent window name and tab name
-                            let final_line = if deferred_replacements.is_empty() {
+                            // Apply window-scoped highlights and gags if any;
+                            // a scope may name the parent window or this tab
+                            let final_line = if !window_scoped {
                                 src.clone()
+                            } else if let Some(scoped) =
+                                self.line_for_window(src, &[window_name, &tab.definition.name])
+                            {
+                                scoped
                             } else {
-                                // Try window name first, then tab name
-                                let mut segments = super::highlight_engine::apply_deferred_for_window(
-                                    &src.segments,
-                                    &deferred_replacements,
-                                    window_name,
-                                );
-                                // Also check tab name (allows targeting specific tabs)
-                                segments = super::highlight_engine::apply_deferred_for_window(
-                                    &segments,
-                                    &deferred_replacements,
-                                    &tab.definition.name,
-                                );
-                                StyledLine {
-                                    segments,
-                                    stream: src.stream.clone(),
-                                    timestamp: src.timestamp,
-                                }
+                                gagged_in_window = true;
+                                continue;
                             };
+                            if !teed {
+                                self.tee(window_name, &final_line);
+                                teed = true;
+                            }
                             tab.content.add_line(final_line);
                             added_here = true;
                             // Mark tab as unread if it's not the active tab and activity tracking is enabled
                             if tab_index != active_tab_index && !tab.definition.ignore_activity {
                                 tab.has_unread = true;
                             }
+                            // Alerts fire for background tabs only; the sound
+                            // player's cooldown keeps a burst to one ping
+                            if tab_index != active_tab_index {
+                                if tab.definition.alert_flash {
+                                    tab.alerting = true;
+                                }
+                                if let Some(file) = &tab.definition.alert_sound {
+                                    self.pending_sounds.push(
+                                        super::highlight_engine::SoundTrigger {
+                                            file: file.clone(),
+                                            volume: None,
+                                        },
+                                    );
+                                }
+                            }
                         }
                     }
                 }
@@ -2938,7 +3257,7 @@ impl MessageProcessor {
 
         // Fallback routing if no window handled the stream
         // Uses config.streams settings: drop_unsubscribed list and fallback window
-        if !text_added_to_any_window {
+        if !text_added_to_any_window && !gagged_in_window {
             // A move implies text was added, so the line is always present here
             let line = line_slot.as_ref().expect("line present when nothing was added");
             match self.resolve_orphaned_stream(&self.current_stream) {
@@ -2959,21 +3278,13 @@ impl MessageProcessor {
                     );
                     if let Some(fallback) = ui_state.get_window_mut(&fallback_window) {
                         if let WindowContent::Text(ref mut content) = fallback.content {
-                            // Apply window-specific replacements if any
-                            let final_line = if deferred_replacements.is_empty() {
-                                line.clone()
-                            } else {
-                                StyledLine {
-                                    segments: super::highlight_engine::apply_deferred_for_window(
-                                        &line.segments,
-                                        &deferred_replacements,
-                                        &fallback_window,
-                                    ),
-                                    stream: line.stream.clone(),
-                                    timestamp: line.timestamp,
-                                }
-                            };
-                            content.add_line(final_line);
+                            // Apply window-scoped highlights and gags if any
+                            if let Some(final_line) =
+                                self.line_for_window(line, &[&fallback_window])
+                            {
+                                self.tee(&fallback_window, &final_line);
+                                content.add_line(final_line);
+                            }
                             if let Some(tts_mgr) = tts_manager.as_deref_mut() {
                                 self.enqueue_tts(tts_mgr, &fallback_window, &line);
                             }
@@ -2986,21 +3297,11 @@ impl MessageProcessor {
                         );
                         if let Some(main_window) = ui_state.get_window_mut("main") {
                             if let WindowContent::Text(ref mut content) = main_window.content {
-                                // Apply window-specific replacements if any
-                                let final_line = if deferred_replacements.is_empty() {
-                                    line.clone()
-                                } else {
-                                    StyledLine {
-                                        segments: super::highlight_engine::apply_deferred_for_window(
-                                            &line.segments,
-                                            &deferred_replacements,
-                                            "main",
-                                        ),
-                                        stream: line.stream.clone(),
-                                        timestamp: line.timestamp,
-                                    }
-                                };
-                                content.add_line(final_line);
+                                // Apply window-scoped highlights and gags if any
+                                if let Some(final_line) = self.line_for_window(line, &["main"]) {
+                                    self.tee("main", &final_line);
+                                    content.add_line(final_line);
+                                }
                                 if let Some(tts_mgr) = tts_manager.as_deref_mut() {
                                     self.enqueue_tts(tts_mgr, "main", &line);
                                 }
@@ -3028,21 +3329,13 @@ impl MessageProcessor {
             if let Some(window) = ui_state.get_window_mut(&original_window_name) {
                 match window.content {
                     WindowContent::Text(ref mut content) => {
-                        // Apply window-specific replacements if any
-                        let final_line = if deferred_replacements.is_empty() {
-                            line.clone()
-                        } else {
-                            StyledLine {
-                                segments: super::highlight_engine::apply_deferred_for_window(
-                                    &line.segments,
-                                    &deferred_replacements,
-                                    &original_window_name,
-                                ),
-                                stream: line.stream.clone(),
-                                timestamp: line.timestamp,
-                            }
-                        };
-                        content.add_line(final_line);
+                        // Apply window-scoped highlights and gags if any
+                        if let Some(final_line) =
+                            self.line_for_window(line, &[&original_window_name])
+                        {
+                            self.tee(&original_window_name, &final_line);
+                            content.add_line(final_line);
+                        }
                     }
                     WindowContent::Inventory(ref mut content)
                     | WindowContent::Reserve(ref mut content) => {
@@ -3057,21 +3350,11 @@ impl MessageProcessor {
                 // Fallback to main for original stream too
                 if let Some(main_window) = ui_state.get_window_mut("main") {
                     if let WindowContent::Text(ref mut content) = main_window.content {
-                        // Apply window-specific replacements if any
-                        let final_line = if deferred_replacements.is_empty() {
-                            line.clone()
-                        } else {
-                            StyledLine {
-                                segments: super::highlight_engine::apply_deferred_for_window(
-                                    &line.segments,
-                                    &deferred_replacements,
-                                    "main",
-                                ),
-                                stream: line.stream.clone(),
-                                timestamp: line.timestamp,
-                            }
-                        };
-                        content.add_line(final_line);
+                        // Apply window-scoped highlights and gags if any
+                        if let Some(final_line) = self.line_for_window(line, &["main"]) {
+                            self.tee("main", &final_line);
+                            content.add_line(final_line);
+                        }
                     }
                 }
             }
@@ -3108,7 +3391,7 @@ impl MessageProcessor {
                     for line_segments in &self.inventory_buffer {
                         content.add_line(StyledLine {
                             segments: line_segments.clone(),
-                            stream: String::from("inv"),
+                            stream: intern("inv"),
                             timestamp: None,
                         });
                     }
@@ -3168,7 +3451,7 @@ impl MessageProcessor {
                     for line_segments in &self.reserve_buffer {
                         content.add_line(StyledLine {
                             segments: line_segments.clone(),
-                            stream: String::from("reserve"),
+                            stream: intern("reserve"),
                             timestamp: None,
                         });
                     }
@@ -3230,7 +3513,7 @@ impl MessageProcessor {
                     for line_segments in &self.spells_buffer {
                         content.add_line(StyledLine {
                             segments: line_segments.clone(),
-                            stream: String::from("Spells"),
+                            stream: intern("Spells"),
                             timestamp: None,
                         });
                     }
@@ -3647,7 +3930,7 @@ impl MessageProcessor {
         for line_segments in &self.spells_buffer {
             window_content.add_line(StyledLine {
                 segments: line_segments.clone(),
-                stream: String::from("Spells"),
+                stream: intern("Spells"),
                 timestamp: None,
             });
         }
@@ -3660,12 +3943,37 @@ impl MessageProcessor {
 
     /// Update squelch pattern matching infrastructure from config
     pub fn update_squelch_patterns(&mut self) {
-        // Collect all squelch patterns
-        let squelch_patterns: Vec<_> = self
+        // Collect all squelch patterns; window-scoped ones are checked per
+        // window during routing
+        let (scoped, squelch_patterns): (Vec<_>, Vec<_>) = self
             .config
             .highlights
             .values()
             .filter(|pattern| pattern.squelch)
+            .partition(|p| super::highlight_engine::window_scope(p).next().is_some());
+
+        // Fast-parse literals are plain substrings, so they fold into one
+        // escaped alternation per scoped pattern
+        self.scoped_squelch = scoped
+            .iter()
+            .filter_map(|p| {
+                let source = if p.fast_parse {
+                    p.pattern
+                        .split('|')
+                        .map(str::trim)
+                        .filter(|l| !l.is_empty())
+                        .map(regex::escape)
+                        .collect::<Vec<_>>()
+                        .join("|")
+                } else {
+                    p.pattern.clone()
+                };
+                let regex = regex::Regex::new(&source).ok()?;
+                let names = super::highlight_engine::window_scope(p)
+                    .map(str::to_string)
+                    .collect();
+                Some((names, regex))
+            })
             .collect();
 
         // Build Aho-Corasick for fast_parse patterns
@@ -3697,9 +4005,10 @@ impl MessageProcessor {
             .collect();
 
         tracing::debug!(
-            "Updated squelch patterns: {} fast patterns, {} regex patterns",
+            "Updated squelch patterns: {} fast patterns, {} regex patterns, {} window-scoped",
             fast_patterns.len(),
-            self.squelch_regexes.len()
+            self.squelch_regexes.len(),
+            self.scoped_squelch.len()
         );
     }
 
@@ -3993,6 +4302,69 @@ impl MessageProcessor {
         best.map(|(window, mode, len)| (window.to_string(), mode.clone(), len))
     }
 
+    /// Retarget the per-window tee files: (window name, file path) pairs.
+    /// Returns the windows whose file could not be opened, with the error.
+    pub fn set_tees(
+        &mut self,
+        targets: Vec<(String, std::path::PathBuf)>,
+    ) -> Vec<(String, String)> {
+        self.tees.sync(targets)
+    }
+
+    /// The file `window` is teed to, if any
+    pub fn tee_path(&self, window: &str) -> Option<&std::path::Path> {
+        self.tees.path_for(window)
+    }
+
+    /// Append the line's plain text to `window`'s tee file, if it has one
+    fn tee(&mut self, window: &str, line: &StyledLine) {
+        if self.tees.is_empty() {
+            return;
+        }
+        let text: String = line.segments.iter().map(|s| s.text.as_str()).collect();
+        self.tees.write(window, &text);
+    }
+
+    /// The line as it should appear in a window (and tab): `None` if a gag
+    /// scoped to one of `targets` drops it there, otherwise the line with
+    /// the window-scoped highlights for `targets` applied.
+    fn line_for_window(&mut self, line: &StyledLine, targets: &[&str]) -> Option<StyledLine> {
+        let names_target = |scope: &[String]| {
+            scope
+                .iter()
+                .any(|w| targets.iter().any(|t| t.eq_ignore_ascii_case(w)))
+        };
+        if self
+            .scoped_squelch
+            .iter()
+            .any(|(scope, _)| names_target(scope))
+        {
+            let text: String = line.segments.iter().map(|s| s.text.as_str()).collect();
+            if self
+                .scoped_squelch
+                .iter()
+                .any(|(scope, regex)| names_target(scope) && regex.is_match(&text))
+            {
+                return None;
+            }
+        }
+
+        match self
+            .highlight_engine
+            .apply_scoped_highlights(&line.segments, &line.stream, targets)
+        {
+            Some(result) => {
+                self.pending_sounds.extend(result.sounds);
+                Some(StyledLine {
+                    segments: result.segments,
+                    stream: line.stream.clone(),
+                    timestamp: line.timestamp,
+                })
+            }
+            None => Some(line.clone()),
+        }
+    }
+
     /// Check if a line should be squelched (ignored/filtered)
     fn should_squelch_line(&self, text: &str) -> bool {
         // Check Aho-Corasick fast patterns
@@ -4268,6 +4640,68 @@ mod tests {
         assert_eq!(game_state.room_objects_generation, 1);
     }
 
+    #[test]
+    fn test_remembered_item_value_annotates_room_objs() {
+        let mut processor = create_test_processor();
+        let mut game_state = GameState::new();
+
+        // Price-only reply: the item comes from the APPRAISE command
+        processor.note_command("appr my blue sapphire");
+        processor.remember_item_value(crate::data::itemdb::Appraisal {
+            item: None,
+            value: 1250,
+        });
+        assert_eq!(processor.item_db.value("blue sapphire"), Some(1250));
+
+        let mut room_components = std::collections::HashMap::new();
+        let mut current_room_component = None;
+        let mut room_dirty = false;
+        processor.handle_component(
+            "room objs",
+            "You also see a <a exist='42' noun='sapphire'>blue sapphire</a>.",
+            &mut game_state,
+            &mut room_components,
+            &mut current_room_component,
+            &mut room_dirty,
+        );
+        let line: String = room_components["room objs"][0]
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect();
+        assert_eq!(line, "You also see a blue sapphire (~1,250).");
+    }
+
+    #[test]
+    fn test_unanswered_appraisal_ends_at_the_prompt() {
+        let mut processor = create_test_processor();
+        let mut game_state = GameState::new();
+        let mut ui_state = UiState::default();
+
+        // The shopkeeper won't look at it; the prompt closes the exchange
+        processor.note_command("appraise my blue sapphire");
+        processor.process_element(
+            &ParsedElement::Prompt {
+                time: "1700000000".to_string(),
+                text: ">".to_string(),
+            },
+            &mut game_state,
+            &mut ui_state,
+            &mut std::collections::HashMap::new(),
+            &mut None,
+            &mut false,
+            &mut None,
+            &mut None,
+            &mut None,
+            None,
+        );
+        // A later price-only line (a trade, a bounty payout) isn't its value
+        processor.remember_item_value(crate::data::itemdb::Appraisal {
+            item: None,
+            value: 300,
+        });
+        assert_eq!(processor.item_db.value("blue sapphire"), None);
+    }
+
     // ===========================================
     // <crtrStatus> tests (fixtures captured from a live GST session,
     // via lich-5 PR #1425's spec suite)
@@ -4507,6 +4941,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_output_block_captured_and_hint_added() {
+        let mut processor = create_test_processor();
+        processor.config.ui.view_hint_lines = 3;
+        let mut ui_state = UiState::new();
+        ui_state
+            .windows
+            .insert("main".to_string(), make_text_window("main", &["main"]));
+        processor.update_text_stream_subscribers(&ui_state);
+
+        for text in ["line one", "line two", "line three"] {
+            push_test_segment(&mut processor, text);
+            processor.flush_current_stream(&mut ui_state);
+        }
+        assert!(processor.last_output_block().is_empty());
+
+        processor.finish_output_block(&mut ui_state);
+        assert_eq!(processor.last_output_block().len(), 3);
+        // Three lines of output plus the "view" hint
+        assert_eq!(text_line_count(&ui_state, "main"), 4);
+
+        // Short blocks replace the last block without a hint
+        push_test_segment(&mut processor, "short");
+        processor.flush_current_stream(&mut ui_state);
+        processor.finish_output_block(&mut ui_state);
+        assert_eq!(processor.last_output_block().len(), 1);
+        assert_eq!(text_line_count(&ui_state, "main"), 5);
+    }
+
     #[test]
     fn test_multi_subscriber_delivery() {
         // Two windows subscribe the same stream: both must receive the line
@@ -4529,6 +4992,54 @@ mod tests {
         assert_eq!(text_line_count(&ui_state, "beta"), 1);
     }
 
+    #[test]
+    fn test_window_scoped_highlight_and_gag_apply_per_window() {
+        let mut config = Config::default();
+        let mut gag = make_redirect_pattern("faint");
+        gag.redirect_to = None;
+        gag.squelch = true;
+        gag.window = Some("alpha".to_string());
+        config.highlights.insert("alpha_gag".to_string(), gag);
+        let mut color = make_redirect_pattern("thoughts");
+        color.redirect_to = None;
+        color.fg = Some("#00ff00".to_string());
+        color.replace = Some("THOUGHTS".to_string());
+        color.window = Some("Beta".to_string());
+        config.highlights.insert("beta_color".to_string(), color);
+
+        let mut processor = MessageProcessor::new(config, SavedDialogPositions::default());
+        let mut ui_state = UiState::new();
+        for name in ["main", "alpha", "beta"] {
+            let streams: &[&str] = if name == "main" {
+                &["main"]
+            } else {
+                &["thoughts"]
+            };
+            ui_state
+                .windows
+                .insert(name.to_string(), make_text_window(name, streams));
+        }
+        processor.update_text_stream_subscribers(&ui_state);
+
+        processor.current_stream = "thoughts".to_string();
+        push_test_segment(&mut processor, "You hear the faint thoughts of someone.");
+        processor.flush_current_stream(&mut ui_state);
+
+        // Gagged in alpha only, and the gag doesn't push it to main
+        assert_eq!(text_line_count(&ui_state, "alpha"), 0);
+        assert_eq!(text_line_count(&ui_state, "main"), 0);
+        let WindowContent::Text(beta) = &ui_state.windows["beta"].content else {
+            panic!("not a text window");
+        };
+        let line = beta.lines.back().expect("beta got the line");
+        let text: String = line.segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(text, "You hear the faint THOUGHTS of someone.");
+        assert!(line
+            .segments
+            .iter()
+            .any(|s| s.text == "THOUGHTS" && s.fg.as_deref() == Some("#00ff00")));
+    }
+
     #[test]
     fn test_redirect_copy_delivers_to_target_and_original() {
         let mut config = Config::default();
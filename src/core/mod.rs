@@ -4,27 +4,70 @@
 //! NO imports from frontend/ or rendering code.
 //! Core updates data structures in the data layer, frontends read and render.
 
+pub mod action_registry;
+pub mod alarm;
 pub mod app_core;
+pub mod away;
 pub mod bounty_parser;
+pub mod combat;
+pub mod combat_mode;
+pub mod command_log;
+pub mod completion;
+pub mod condition;
+pub mod containers;
+pub mod conversation;
+pub mod countdown_cues;
 pub mod evidence;
+pub mod exp_eta;
+pub mod export;
 pub mod ghost_rooms;
+pub mod help;
 pub mod highlight_engine;
 pub mod hotbar;
 pub mod input_router;
+pub mod inspector;
+pub mod key_layout;
 pub mod layout_engine;
+pub mod layout_gallery;
+pub mod layout_history;
+pub mod locale;
+pub mod lockout;
+pub mod log_viewer;
+pub mod loot;
 pub mod map_service;
 pub mod mapdb;
 pub mod mapdb_update;
 pub mod menu_actions;
 pub mod messages;
+pub mod notes;
 pub mod pathing;
+pub mod played;
+pub mod prompt;
+pub mod quit;
+pub mod reading_order;
+pub mod rebuff;
+pub mod reminders;
 pub mod remote;
-pub mod travel;
+pub mod repeat;
+pub mod reply;
+pub mod routes;
+pub mod secrets;
+pub mod sequence;
+pub mod society_tasks;
+pub mod spell_groups;
+pub mod spellcheck;
+pub mod stance;
 pub mod state;
+pub mod streamer;
+pub mod tee;
+pub mod travel;
+pub mod undo;
+pub mod urls;
+pub mod watch;
+pub mod wealth;
+pub mod window_spec;
 
-pub use app_core::AppCore;
-pub use highlight_engine::{
-    apply_deferred_for_window, CoreHighlightEngine, DeferredReplacement, HighlightResult,
-};
+pub use app_core::{AppCore, PasteAction};
+pub use highlight_engine::{CoreHighlightEngine, HighlightResult};
 pub use messages::MessageProcessor;
 pub use state::GameState;
@@ -0,0 +1,133 @@
+//! Per-character notepad behind the notes widget and `.notes`.
+//!
+//! One plain-text (usually markdown) file per character, `notes.md` in the
+//! profile folder, for hunting checklists, directions and the like. The
+//! frontends edit it in a popup and hand the text back through
+//! AppCore::set_notes, which saves it. A notes window shows the whole file,
+//! or just one `# Heading` section of it.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default)]
+pub struct Notes {
+    text: String,
+    /// Bumped on every change, for widget caches
+    pub generation: u64,
+}
+
+impl Notes {
+    /// Load the notes file; a missing file is empty notes.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(Self {
+            text,
+            generation: 0,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(path, &self.text).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text. Returns false if nothing changed.
+    pub fn set_text(&mut self, text: String) -> bool {
+        if text == self.text {
+            return false;
+        }
+        self.text = text;
+        self.generation += 1;
+        true
+    }
+
+    /// The lines to show: everything, or only the section under the
+    /// heading named `section` (matched case-insensitively, any level),
+    /// up to the next heading of the same or a higher level.
+    pub fn lines(&self, section: Option<&str>) -> Vec<&str> {
+        let section = section.map(str::trim).filter(|s| !s.is_empty());
+        let Some(section) = section else {
+            return self.text.lines().collect();
+        };
+        let mut lines = self.text.lines();
+        let Some(level) = lines.by_ref().find_map(|line| {
+            heading(line)
+                .and_then(|(level, title)| title.eq_ignore_ascii_case(section).then_some(level))
+        }) else {
+            return Vec::new();
+        };
+        lines
+            .take_while(|line| heading(line).is_none_or(|(next, _)| next > level))
+            .collect()
+    }
+
+    /// `# Heading` titles in the file, in order
+    pub fn sections(&self) -> Vec<&str> {
+        self.text
+            .lines()
+            .filter_map(|line| heading(line).map(|(_, title)| title))
+            .collect()
+    }
+}
+
+/// A markdown heading's level and title
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let title = line[level..].strip_prefix(' ')?;
+    (level > 0).then_some((level, title.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "\
+# Hunting
+- check bounty
+## Route
+go2 bank
+# Shopping
+buy arrows
+";
+
+    #[test]
+    fn shows_one_section() {
+        let mut notes = Notes::default();
+        assert!(notes.set_text(NOTES.to_string()));
+        assert!(!notes.set_text(NOTES.to_string()));
+        assert_eq!(
+            notes.lines(Some("hunting")),
+            ["- check bounty", "## Route", "go2 bank"]
+        );
+        assert_eq!(notes.lines(Some("Route")), ["go2 bank"]);
+        assert_eq!(notes.lines(Some("shopping")), ["buy arrows"]);
+        assert!(notes.lines(Some("missing")).is_empty());
+        assert_eq!(notes.lines(None).len(), 6);
+        assert_eq!(notes.sections(), ["Hunting", "Route", "Shopping"]);
+        // "#hashtag" isn't a heading
+        assert_eq!(heading("#tag"), None);
+    }
+
+    #[test]
+    fn saves_and_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sub").join("notes.md");
+        assert_eq!(Notes::load(&path).unwrap().text(), "");
+        let mut notes = Notes::default();
+        notes.set_text(NOTES.to_string());
+        notes.save(&path).unwrap();
+        assert_eq!(Notes::load(&path).unwrap().text(), NOTES);
+    }
+}
@@ -0,0 +1,277 @@
+//! Played time: how long the character has been connected, this session,
+//! per day and in total.
+//!
+//! AppCore ticks this once per frame with the connection state; connected
+//! time is credited to the local day it falls on and saved per character
+//! in `played.toml` every minute, on disconnect and on quit, so a crash
+//! loses at most a minute. `.played` prints a summary and the played
+//! widget shows the live session timer.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Credited seconds that trigger a save while connected
+const SAVE_EVERY_SECS: u64 = 60;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PlayedFile {
+    /// Seconds connected per local day, keyed "2026-10-17"
+    #[serde(default)]
+    days: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Played {
+    days: BTreeMap<NaiveDate, u64>,
+    /// When the current connection started; None while disconnected
+    session_start: Option<DateTime<Local>>,
+    /// Time up to which the session has been credited to `days`
+    credited_to: Option<DateTime<Local>>,
+    /// Milliseconds credited but not yet a whole second
+    carry_ms: i64,
+    /// Seconds credited since the last save
+    unsaved: u64,
+}
+
+impl Played {
+    /// Load the played file; a missing file is no time played.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file: PlayedFile =
+            toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))?;
+        let days = file
+            .days
+            .into_iter()
+            .filter_map(|(day, secs)| Some((day.parse::<NaiveDate>().ok()?, secs)))
+            .collect();
+        Ok(Self {
+            days,
+            ..Self::default()
+        })
+    }
+
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let file = PlayedFile {
+            days: self
+                .days
+                .iter()
+                .map(|(day, secs)| (day.to_string(), *secs))
+                .collect(),
+        };
+        let text = toml::to_string_pretty(&file).context("Failed to serialize played time")?;
+        fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+        self.unsaved = 0;
+        Ok(())
+    }
+
+    /// Credit connected time up to `now`. Returns true when the file
+    /// should be saved: a minute's worth is unsaved, or the session ended.
+    pub fn tick(&mut self, connected: bool, now: DateTime<Local>) -> bool {
+        match (connected, self.credited_to) {
+            (true, None) => {
+                self.session_start = Some(now);
+                self.credited_to = Some(now);
+                false
+            }
+            (true, Some(_)) => {
+                self.credit(now);
+                self.unsaved >= SAVE_EVERY_SECS
+            }
+            (false, Some(_)) => {
+                self.credit(now);
+                self.session_start = None;
+                self.credited_to = None;
+                self.carry_ms = 0;
+                self.unsaved > 0
+            }
+            (false, None) => false,
+        }
+    }
+
+    fn credit(&mut self, now: DateTime<Local>) {
+        let Some(from) = self.credited_to else {
+            return;
+        };
+        // A clock set backwards credits nothing rather than going negative
+        let ms = (now - from).num_milliseconds().max(0) + self.carry_ms;
+        let seconds = (ms / 1000) as u64;
+        self.carry_ms = ms % 1000;
+        self.credited_to = Some(now);
+        if seconds > 0 {
+            *self.days.entry(now.date_naive()).or_insert(0) += seconds;
+            self.unsaved += seconds;
+        }
+    }
+
+    /// Length of the current connection in seconds; None while disconnected
+    pub fn session(&self, now: DateTime<Local>) -> Option<u64> {
+        self.session_start
+            .map(|start| (now - start).num_seconds().max(0) as u64)
+    }
+
+    /// Seconds played on `day`
+    pub fn day(&self, day: NaiveDate) -> u64 {
+        self.days.get(&day).copied().unwrap_or(0)
+    }
+
+    /// Seconds played in the `count` days up to and including `day`
+    pub fn days_ending(&self, day: NaiveDate, count: u64) -> u64 {
+        let first = day - chrono::Duration::days(count.saturating_sub(1) as i64);
+        self.days.range(first..=day).map(|(_, secs)| secs).sum()
+    }
+
+    /// Seconds played since tracking began
+    pub fn total(&self) -> u64 {
+        self.days.values().sum()
+    }
+
+    /// The first day with time played
+    pub fn since(&self) -> Option<NaiveDate> {
+        self.days.keys().next().copied()
+    }
+
+    /// Widget rows: session, today and (optionally) total
+    pub fn rows(&self, now: DateTime<Local>, show_total: bool) -> Vec<(&'static str, String)> {
+        let mut rows = vec![
+            (
+                "Session",
+                self.session(now)
+                    .map(format_hms)
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            ("Today", format_hms(self.day(now.date_naive()))),
+        ];
+        if show_total {
+            rows.push(("Total", format_hms(self.total())));
+        }
+        rows
+    }
+
+    /// `.played` lines
+    pub fn summary(&self, now: DateTime<Local>) -> Vec<String> {
+        let today = now.date_naive();
+        let session = match (self.session(now), self.session_start) {
+            (Some(secs), Some(start)) => format!(
+                "this session {} (connected at {})",
+                format_hms(secs),
+                super::locale::format_time(&start)
+            ),
+            _ => "not connected".to_string(),
+        };
+        let since = self
+            .since()
+            .map(|day| format!(" since {}", day))
+            .unwrap_or_default();
+        vec![
+            session,
+            format!(
+                "today {}, last 7 days {}, last 30 days {}",
+                format_hms(self.day(today)),
+                format_hms(self.days_ending(today, 7)),
+                format_hms(self.days_ending(today, 30))
+            ),
+            format!("total {}{}", format_hms(self.total()), since),
+        ]
+    }
+}
+
+/// `1:02:03`; hours keep counting past a day (`123:45:00`)
+pub fn format_hms(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32, minute: u32, second: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2026, 10, day, hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn credits_connected_time_per_day() {
+        let mut played = Played::default();
+        assert!(!played.tick(false, at(17, 22, 0, 0)));
+        played.tick(true, at(17, 23, 59, 0));
+        assert!(!played.tick(true, at(17, 23, 59, 59)));
+        assert!(played.tick(true, at(18, 0, 1, 0)));
+        assert_eq!(played.session(at(18, 0, 1, 0)), Some(120));
+
+        let date = |day| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+        assert_eq!(played.day(date(17)), 59);
+        assert_eq!(played.day(date(18)), 61);
+        assert_eq!(played.days_ending(date(18), 7), 120);
+        assert_eq!(played.since(), Some(date(17)));
+    }
+
+    #[test]
+    fn disconnect_ends_the_session_and_asks_for_a_save() {
+        let mut played = Played::default();
+        played.tick(true, at(17, 12, 0, 0));
+        played.tick(true, at(17, 12, 0, 10));
+        assert!(played.tick(false, at(17, 12, 0, 20)));
+        assert_eq!(played.session(at(17, 12, 0, 30)), None);
+        // Time while disconnected isn't counted
+        played.tick(true, at(17, 13, 0, 0));
+        played.tick(true, at(17, 13, 0, 5));
+        assert_eq!(played.total(), 25);
+    }
+
+    #[test]
+    fn round_trips_through_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("played.toml");
+        let mut played = Played::default();
+        played.tick(true, at(17, 12, 0, 0));
+        played.tick(true, at(17, 12, 30, 0));
+        played.save(&path).unwrap();
+        let loaded = Played::load(&path).unwrap();
+        assert_eq!(loaded.total(), 1800);
+        assert_eq!(loaded.session(at(17, 12, 30, 0)), None);
+    }
+
+    #[test]
+    fn summary_and_rows_show_session_today_and_total() {
+        let mut played = Played::default();
+        played.tick(true, at(17, 20, 15, 0));
+        played.tick(true, at(17, 21, 15, 0));
+        let now = at(17, 21, 15, 0);
+        let lines = played.summary(now);
+        assert_eq!(lines[0], "this session 1:00:00 (connected at 8:15 PM)");
+        assert_eq!(
+            lines[1],
+            "today 1:00:00, last 7 days 1:00:00, last 30 days 1:00:00"
+        );
+        assert_eq!(lines[2], "total 1:00:00 since 2026-10-17");
+        let rows = played.rows(now, false);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], ("Session", "1:00:00".to_string()));
+    }
+
+    #[test]
+    fn formats_hours_minutes_seconds() {
+        assert_eq!(format_hms(0), "0:00:00");
+        assert_eq!(format_hms(3723), "1:02:03");
+        assert_eq!(format_hms(445_500), "123:45:00");
+    }
+}
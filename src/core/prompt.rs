@@ -0,0 +1,59 @@
+//! Custom prompt rendering for the command input.
+//!
+//! The game prompt ("RK>") carries state as letters. A command input with a
+//! `prompt_template` shows the template instead of (or as well as) the raw
+//! prompt line in the main window. Variables:
+//!
+//! - `{prompt}` - the raw prompt ("RK>")
+//! - `{flags}` - the status letters only ("RK")
+//! - `{status}` - the letters as words ("roundtime kneeling")
+//! - `{rt}` / `{ct}` - seconds of roundtime / cast time left (empty at 0)
+//! - `{route}` - `.go` route progress ("bank 3/7"; empty when none)
+
+use crate::core::state::GameState;
+
+/// Expand a prompt template. `now_server` is the current server time
+/// (local clock plus the prompt offset) so RT counts down between prompts.
+/// `route` is the `.go` progress label, or "".
+pub fn render_prompt(
+    template: &str,
+    game_state: &GameState,
+    now_server: i64,
+    route: &str,
+) -> String {
+    let prompt = game_state.last_prompt.trim();
+    let flags: String = prompt.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+    let seconds = |end: Option<i64>| match end.map(|end| end - now_server) {
+        Some(left) if left > 0 => left.to_string(),
+        _ => String::new(),
+    };
+    template
+        .replace("{prompt}", prompt)
+        .replace("{flags}", &flags)
+        .replace("{status}", &crate::parser::prompt_status(prompt).join(" "))
+        .replace("{rt}", &seconds(game_state.roundtime_end))
+        .replace("{ct}", &seconds(game_state.casttime_end))
+        .replace("{route}", route)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_prompt_variables() {
+        let mut game_state = GameState::new();
+        game_state.last_prompt = "RK>".to_string();
+        game_state.roundtime_end = Some(105);
+        assert_eq!(
+            render_prompt("[{rt}] {status} {flags}{prompt}", &game_state, 100, ""),
+            "[5] roundtime kneeling RKRK>"
+        );
+        // RT over: the variable is empty rather than "0"
+        assert_eq!(render_prompt("{rt}>", &game_state, 110, ""), ">");
+        assert_eq!(
+            render_prompt("{route}>", &game_state, 110, "bank 3/7"),
+            "bank 3/7>"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Graceful `.quit`: confirm while connected, log the character out, then
+//! exit.
+//!
+//! With `[quit] confirm` on, the first `.quit` while connected only asks;
+//! a second one within `CONFIRM_WINDOW` (or `.quit yes`) goes ahead. Going
+//! ahead sends `[quit] logout_command` (if set) to the game and waits up to
+//! `timeout_secs` for the server to close the connection, so the character
+//! is out of the game before the client exits. `.quit` during that wait,
+//! or `.quit now` at any time, exits at once. Layout and session caches
+//! are saved on the way out either way (AppCore::quit).
+
+use crate::config::QuitConfig;
+use std::time::{Duration, Instant};
+
+/// How long a first `.quit` waits for the second that confirms it
+pub const CONFIRM_WINDOW: Duration = Duration::from_secs(10);
+
+/// What a `.quit` should do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuitStep {
+    /// Save and exit now
+    Exit,
+    /// Still connected: ask first
+    Confirm,
+    /// Send this to the game and wait for the connection to close
+    Logout { command: String, wait: Duration },
+}
+
+/// Why a logout wait ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogoutEnd {
+    /// The server closed the connection
+    Closed,
+    /// `timeout_secs` passed first
+    TimedOut,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct QuitState {
+    confirm_until: Option<Instant>,
+    logout_until: Option<Instant>,
+}
+
+impl QuitState {
+    /// Waiting for the server to close after the logout command
+    pub fn is_logging_out(&self) -> bool {
+        self.logout_until.is_some()
+    }
+
+    /// `.quit [yes|now]` was entered
+    pub fn request(
+        &mut self,
+        args: &str,
+        connected: bool,
+        config: &QuitConfig,
+        now: Instant,
+    ) -> QuitStep {
+        let args = args.trim().to_ascii_lowercase();
+        if !connected || args == "now" || self.logout_until.is_some() {
+            return QuitStep::Exit;
+        }
+        let confirmed =
+            args == "yes" || !config.confirm || self.confirm_until.is_some_and(|until| now < until);
+        if !confirmed {
+            self.confirm_until = Some(now + CONFIRM_WINDOW);
+            return QuitStep::Confirm;
+        }
+        self.confirm_until = None;
+        let command = config.logout_command.trim();
+        if command.is_empty() || config.timeout_secs == 0 {
+            return QuitStep::Exit;
+        }
+        let wait = Duration::from_secs(config.timeout_secs);
+        self.logout_until = Some(now + wait);
+        QuitStep::Logout {
+            command: command.to_string(),
+            wait,
+        }
+    }
+
+    /// Check a logout wait; Some once it's over
+    pub fn poll(&mut self, connected: bool, now: Instant) -> Option<LogoutEnd> {
+        let until = self.logout_until?;
+        let end = if !connected {
+            LogoutEnd::Closed
+        } else if now >= until {
+            LogoutEnd::TimedOut
+        } else {
+            return None;
+        };
+        self.logout_until = None;
+        Some(end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> QuitConfig {
+        QuitConfig {
+            logout_command: "quit".to_string(),
+            ..QuitConfig::default()
+        }
+    }
+
+    #[test]
+    fn disconnected_quit_exits_at_once() {
+        let mut state = QuitState::default();
+        let step = state.request("", false, &config(), Instant::now());
+        assert_eq!(step, QuitStep::Exit);
+    }
+
+    #[test]
+    fn second_quit_confirms_then_logs_out() {
+        let mut state = QuitState::default();
+        let now = Instant::now();
+        assert_eq!(state.request("", true, &config(), now), QuitStep::Confirm);
+        let step = state.request("", true, &config(), now + Duration::from_secs(2));
+        assert_eq!(
+            step,
+            QuitStep::Logout {
+                command: "quit".into(),
+                wait: Duration::from_secs(5),
+            }
+        );
+        assert!(state.is_logging_out());
+        // Asked again while waiting: stop waiting
+        assert_eq!(state.request("", true, &config(), now), QuitStep::Exit);
+    }
+
+    #[test]
+    fn confirmation_expires() {
+        let mut state = QuitState::default();
+        let now = Instant::now();
+        state.request("", true, &config(), now);
+        let later = now + CONFIRM_WINDOW + Duration::from_secs(1);
+        assert_eq!(state.request("", true, &config(), later), QuitStep::Confirm);
+    }
+
+    #[test]
+    fn yes_now_and_settings_skip_steps() {
+        let now = Instant::now();
+        let mut state = QuitState::default();
+        assert!(matches!(
+            state.request("yes", true, &config(), now),
+            QuitStep::Logout { .. }
+        ));
+        let mut state = QuitState::default();
+        assert_eq!(state.request("now", true, &config(), now), QuitStep::Exit);
+
+        let quick = QuitConfig {
+            confirm: false,
+            logout_command: String::new(),
+            ..config()
+        };
+        let mut state = QuitState::default();
+        assert_eq!(state.request("", true, &quick, now), QuitStep::Exit);
+
+        // No logout command unless one is configured
+        let mut state = QuitState::default();
+        assert_eq!(
+            state.request("yes", true, &QuitConfig::default(), now),
+            QuitStep::Exit
+        );
+    }
+
+    #[test]
+    fn logout_ends_on_close_or_timeout() {
+        let now = Instant::now();
+        let mut state = QuitState::default();
+        state.request("yes", true, &config(), now);
+        assert_eq!(state.poll(true, now + Duration::from_secs(1)), None);
+        assert_eq!(
+            state.poll(false, now + Duration::from_secs(1)),
+            Some(LogoutEnd::Closed)
+        );
+        assert!(!state.is_logging_out());
+
+        state.request("yes", true, &config(), now);
+        assert_eq!(
+            state.poll(true, now + Duration::from_secs(5)),
+            Some(LogoutEnd::TimedOut)
+        );
+        assert_eq!(state.poll(true, now + Duration::from_secs(6)), None);
+    }
+}
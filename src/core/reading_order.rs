@@ -0,0 +1,182 @@
+//! Linearized reading order for screen-reader mode.
+//!
+//! Flattens the visible windows into one line each, top-to-bottom then
+//! left-to-right, so a speech engine can read the screen the way a
+//! sighted player scans it. Windows with nothing to say (spacers, empty
+//! text windows, inactive indicators) are left out.
+
+use crate::data::{StyledLine, TextContent, UiState, WindowContent, WindowState};
+
+/// One line per visible window, in reading order.
+pub fn reading_order(ui_state: &UiState, now: i64) -> Vec<String> {
+    let mut windows: Vec<&WindowState> = ui_state.windows.values().filter(|w| w.visible).collect();
+    windows.sort_by(|a, b| {
+        (a.position.y, a.position.x, &a.name).cmp(&(b.position.y, b.position.x, &b.name))
+    });
+    windows
+        .into_iter()
+        .filter_map(|window| describe(window, now))
+        .collect()
+}
+
+/// Short spoken summary of a window, or None when there is nothing to read.
+pub fn describe(window: &WindowState, now: i64) -> Option<String> {
+    let name = window.name.as_str();
+    match &window.content {
+        WindowContent::Text(text)
+        | WindowContent::Inventory(text)
+        | WindowContent::Reserve(text)
+        | WindowContent::Spells(text) => {
+            let title = if text.title.is_empty() {
+                name
+            } else {
+                text.title.as_str()
+            };
+            last_line(text).map(|line| format!("{}: {}", title, line))
+        }
+        WindowContent::TabbedText(tabbed) => {
+            let tab = tabbed.tabs.get(tabbed.active_tab_index)?;
+            let unread = tabbed.tabs.iter().filter(|t| t.has_unread).count();
+            let mut summary = format!("{}, tab {}", name, tab.definition.name);
+            if unread > 0 {
+                summary.push_str(&format!(", {} unread", unread));
+            }
+            if let Some(line) = last_line(&tab.content) {
+                summary.push_str(&format!(": {}", line));
+            }
+            Some(summary)
+        }
+        WindowContent::Progress(data) => {
+            let label = if data.label.is_empty() {
+                name
+            } else {
+                data.label.as_str()
+            };
+            Some(format!("{} {} of {}", label, data.value, data.max))
+        }
+        WindowContent::Countdown(data) => {
+            let remaining = data.end_time - now;
+            (remaining > 0).then(|| {
+                let label = if data.label.is_empty() {
+                    name
+                } else {
+                    data.label.as_str()
+                };
+                format!("{} {} seconds", label, remaining)
+            })
+        }
+        WindowContent::Compass(data) => Some(if data.directions.is_empty() {
+            "No obvious exits".to_string()
+        } else {
+            format!("Exits: {}", data.directions.join(", "))
+        }),
+        WindowContent::Indicator(data) => data.active.then(|| data.indicator_id.clone()),
+        WindowContent::Dashboard { indicators } => {
+            let active: Vec<&str> = indicators
+                .iter()
+                .filter(|(_, value)| *value > 0)
+                .map(|(id, _)| id.as_str())
+                .collect();
+            (!active.is_empty()).then(|| format!("{}: {}", name, active.join(", ")))
+        }
+        WindowContent::InjuryDoll(data) => {
+            let mut hurt: Vec<(&String, &u8)> = data
+                .injuries
+                .iter()
+                .filter(|(_, level)| **level > 0)
+                .collect();
+            if hurt.is_empty() {
+                return None;
+            }
+            hurt.sort();
+            let parts: Vec<String> = hurt
+                .into_iter()
+                .map(|(part, level)| {
+                    if *level > 3 {
+                        format!("{} scar {}", part, level - 3)
+                    } else {
+                        format!("{} wound {}", part, level)
+                    }
+                })
+                .collect();
+            Some(format!("Injuries: {}", parts.join(", ")))
+        }
+        WindowContent::Room(room) => {
+            let mut summary = room.name.clone();
+            if !room.exits.is_empty() {
+                summary.push_str(&format!(". Exits: {}", room.exits.join(", ")));
+            }
+            (!summary.is_empty()).then_some(summary)
+        }
+        WindowContent::Hand { item, .. } => Some(format!(
+            "{}: {}",
+            name,
+            item.as_deref().filter(|i| !i.is_empty()).unwrap_or("empty")
+        )),
+        WindowContent::CommandInput { text, .. } => Some(if text.is_empty() {
+            "Command input, empty".to_string()
+        } else {
+            format!("Command input: {}", text)
+        }),
+        _ => None,
+    }
+}
+
+fn last_line(text: &TextContent) -> Option<String> {
+    text.lines
+        .iter()
+        .rev()
+        .map(line_text)
+        .find(|line| !line.trim().is_empty())
+}
+
+fn line_text(line: &StyledLine) -> String {
+    line.segments.iter().map(|s| s.text.as_str()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{CompassData, WindowPosition};
+
+    fn at(mut window: WindowState, x: u16, y: u16) -> WindowState {
+        window.position = WindowPosition {
+            x,
+            y,
+            width: 10,
+            height: 5,
+        };
+        window
+    }
+
+    #[test]
+    fn windows_read_top_to_bottom_then_left_to_right() {
+        let mut ui_state = UiState::new();
+        let mut main = WindowState::new_text("main", 100);
+        if let WindowContent::Text(text) = &mut main.content {
+            text.add_line(StyledLine::from_text("You swing at the kobold."));
+            text.add_line(StyledLine::from_text(""));
+        }
+        let mut compass = WindowState::new_text("compass", 1);
+        compass.content = WindowContent::Compass(CompassData {
+            directions: vec!["n".to_string(), "out".to_string()],
+        });
+        let empty = WindowState::new_text("thoughts", 100);
+
+        ui_state.windows.insert("main".to_string(), at(main, 0, 3));
+        ui_state
+            .windows
+            .insert("compass".to_string(), at(compass, 40, 0));
+        ui_state
+            .windows
+            .insert("thoughts".to_string(), at(empty, 0, 0));
+
+        assert_eq!(
+            reading_order(&ui_state, 0),
+            vec![
+                "Exits: n, out".to_string(),
+                "main: You swing at the kobold.".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Rebuff planner: named spell sets and the cast queue behind `.rebuff`.
+//!
+//! `.rebuff save <set>` records the spells active right now (the
+//! `ActiveSpells` effects) under a name, persisted per character in
+//! `spell_sets.toml`. `.rebuff <set>` compares the set with what is active
+//! and shows the commands that would recast whatever has dropped, built
+//! from the `[rebuff] commands` templates; `.rebuff <set> cast` queues them.
+//!
+//! The queue hands out one command at a time, and only while neither
+//! roundtime nor casttime is running and `step_delay` has passed since the
+//! previous command — the gap gives the game time to report the RT a
+//! command caused before the next one goes out.
+
+use crate::core::spell_groups::KnownSpell;
+use crate::data::ActiveEffect;
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Named spell sets, keyed by lowercase name
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpellSets {
+    pub sets: BTreeMap<String, Vec<KnownSpell>>,
+}
+
+impl SpellSets {
+    /// Set names are letters, digits, `-` and `_`
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Load the sets file; a missing file is no sets.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let sets =
+            toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Self { sets })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let text = toml::to_string_pretty(&self.sets).context("Failed to serialize spell sets")?;
+        fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&[KnownSpell]> {
+        self.sets.get(&name.to_ascii_lowercase()).map(Vec::as_slice)
+    }
+
+    pub fn insert(&mut self, name: &str, spells: Vec<KnownSpell>) {
+        self.sets.insert(name.to_ascii_lowercase(), spells);
+    }
+
+    /// Returns true if the set existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.sets.remove(&name.to_ascii_lowercase()).is_some()
+    }
+}
+
+/// The spells in `active`, as they'd be recorded in a set
+pub fn snapshot(active: &[ActiveEffect]) -> Vec<KnownSpell> {
+    let mut spells: Vec<KnownSpell> = Vec::new();
+    for effect in active {
+        let spell = KnownSpell {
+            number: effect.id.trim().parse().ok(),
+            name: effect.text.trim().to_string(),
+        };
+        if (spell.number.is_some() || !spell.name.is_empty()) && !spells.contains(&spell) {
+            spells.push(spell);
+        }
+    }
+    spells
+}
+
+/// Spells of `set` that are not in `active`, in set order
+pub fn missing<'a>(set: &'a [KnownSpell], active: &[ActiveEffect]) -> Vec<&'a KnownSpell> {
+    set.iter()
+        .filter(|spell| !spell.is_active(active))
+        .collect()
+}
+
+/// The commands that cast `spells`: every template once per spell, with
+/// `{spell}` replaced by the spell number (or the name when the number is
+/// unknown) and `{name}` by the name
+pub fn commands(spells: &[&KnownSpell], templates: &[String]) -> Vec<String> {
+    let mut out = Vec::new();
+    for spell in spells {
+        let id = spell
+            .number
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| spell.name.clone());
+        for template in templates {
+            let command = template
+                .replace("{spell}", &id)
+                .replace("{name}", &spell.name);
+            if !command.trim().is_empty() {
+                out.push(command);
+            }
+        }
+    }
+    out
+}
+
+/// Commands waiting to be sent for a `.rebuff <set> cast`
+#[derive(Debug, Default)]
+pub struct RebuffQueue {
+    set: String,
+    pending: VecDeque<String>,
+    last_sent: Option<Instant>,
+}
+
+impl RebuffQueue {
+    /// Replace whatever is queued with `commands` for `set`
+    pub fn start(&mut self, set: &str, commands: Vec<String>) {
+        self.set = set.to_string();
+        self.pending = commands.into();
+        self.last_sent = None;
+    }
+
+    /// Drop the queue. Returns whether anything was queued.
+    pub fn stop(&mut self) -> bool {
+        let running = self.is_running();
+        self.pending.clear();
+        running
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    pub fn set(&self) -> &str {
+        &self.set
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// The next command to send, if the character is free (`busy` is false)
+    /// and `step_delay` has passed since the last one
+    pub fn tick(&mut self, busy: bool, now: Instant, step_delay: Duration) -> Option<String> {
+        if busy || self.pending.is_empty() {
+            return None;
+        }
+        if self
+            .last_sent
+            .is_some_and(|sent| now.duration_since(sent) < step_delay)
+        {
+            return None;
+        }
+        self.last_sent = Some(now);
+        self.pending.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effect(id: &str, text: &str) -> ActiveEffect {
+        ActiveEffect {
+            id: id.to_string(),
+            text: text.to_string(),
+            value: 100,
+            time: "10:00".to_string(),
+            expires_at: None,
+            bar_color: None,
+            text_color: None,
+        }
+    }
+
+    fn spell(number: Option<u32>, name: &str) -> KnownSpell {
+        KnownSpell {
+            number,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn plans_commands_for_dropped_spells() {
+        let set = snapshot(&[
+            effect("101", "Spirit Warding I"),
+            effect("107", "Spirit Warding II"),
+            effect("Dark Hunger", "Dark Hunger"),
+        ]);
+        assert_eq!(set[2], spell(None, "Dark Hunger"));
+
+        let active = [effect("107", "Spirit Warding II")];
+        let down = missing(&set, &active);
+        let templates = vec!["prep {spell}".to_string(), "cast".to_string()];
+        assert_eq!(
+            commands(&down, &templates),
+            ["prep 101", "cast", "prep Dark Hunger", "cast"]
+        );
+    }
+
+    #[test]
+    fn sets_round_trip_through_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spell_sets.toml");
+        let mut sets = SpellSets::default();
+        sets.insert(
+            "Hunting",
+            vec![
+                spell(Some(101), "Spirit Warding I"),
+                spell(None, "Dark Hunger"),
+            ],
+        );
+        sets.save(&path).unwrap();
+        let loaded = SpellSets::load(&path).unwrap();
+        assert_eq!(loaded, sets);
+        assert_eq!(loaded.get("hunting").map(<[_]>::len), Some(2));
+    }
+
+    #[test]
+    fn queue_waits_for_roundtime_and_step_delay() {
+        let mut queue = RebuffQueue::default();
+        queue.start("hunting", vec!["prep 101".into(), "cast".into()]);
+        let delay = Duration::from_millis(1500);
+        let t0 = Instant::now();
+
+        assert_eq!(queue.tick(true, t0, delay), None);
+        assert_eq!(queue.tick(false, t0, delay).as_deref(), Some("prep 101"));
+        assert_eq!(
+            queue.tick(false, t0 + Duration::from_millis(500), delay),
+            None
+        );
+        assert_eq!(
+            queue.tick(false, t0 + delay, delay).as_deref(),
+            Some("cast")
+        );
+        assert!(!queue.is_running());
+        assert!(!queue.stop());
+    }
+}
@@ -0,0 +1,331 @@
+//! Reminders and recurring schedules behind `.remind`.
+//!
+//! `.remind 45m check the pawnshop` fires once after a delay, `.remind at
+//! 9pm ...` at the next 9pm, `.remind every 2h ...` on an interval and
+//! `.remind daily 9pm merchant opens` at the same local time every day.
+//! Reminders run on the computer's clock, not game time, and are persisted
+//! per character in `reminders.toml`, so they survive a restart; anything
+//! that came due while the client was closed fires on the next tick.
+//!
+//! AppCore polls `take_due` once per frame and raises the alerts from
+//! `[reminders]`. The reminders widget lists what's coming up.
+
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveDateTime, NaiveTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// One scheduled reminder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: u32,
+    pub message: String,
+    /// Unix time of the next alert
+    pub due: i64,
+    /// Repeat this many seconds after each alert
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<u64>,
+    /// Repeat every day at this local time ("21:00")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily: Option<String>,
+}
+
+impl Reminder {
+    /// "every 2h", "daily 9:00pm", or None for a one-shot
+    pub fn repeat_label(&self) -> Option<String> {
+        if let Some(every) = self.every {
+            return Some(format!("every {}", format_duration(every as i64)));
+        }
+        self.daily
+            .as_deref()
+            .and_then(parse_clock)
+            .map(|time| format!("daily {}", format_clock(time)))
+    }
+
+    /// The next due time after `now`, or None if it doesn't repeat
+    fn next_after(&self, now: i64) -> Option<i64> {
+        if let Some(every) = self.every.filter(|every| *every > 0) {
+            let every = every as i64;
+            let missed = (now - self.due).max(0) / every + 1;
+            return Some(self.due + missed * every);
+        }
+        let time = parse_clock(self.daily.as_deref()?)?;
+        Some(next_local(now, time))
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RemindersFile {
+    #[serde(default)]
+    reminders: Vec<Reminder>,
+}
+
+/// All reminders for the current character
+#[derive(Debug, Clone, Default)]
+pub struct Reminders {
+    items: Vec<Reminder>,
+    /// Bumped on every change, for widget caches
+    pub generation: u64,
+}
+
+impl Reminders {
+    /// Load the reminders file; a missing file is no reminders.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file: RemindersFile =
+            toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Self {
+            items: file.reminders,
+            generation: 0,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let file = RemindersFile {
+            reminders: self.items.clone(),
+        };
+        let text = toml::to_string_pretty(&file).context("Failed to serialize reminders")?;
+        fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Add a reminder and return its id
+    pub fn add(&mut self, mut reminder: Reminder) -> u32 {
+        reminder.id = self.items.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+        let id = reminder.id;
+        self.items.push(reminder);
+        self.generation += 1;
+        id
+    }
+
+    /// Returns true if the reminder existed.
+    pub fn remove(&mut self, id: u32) -> bool {
+        let before = self.items.len();
+        self.items.retain(|r| r.id != id);
+        self.generation += 1;
+        self.items.len() != before
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.generation += 1;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Reminders soonest first
+    pub fn upcoming(&self) -> Vec<&Reminder> {
+        let mut items: Vec<&Reminder> = self.items.iter().collect();
+        items.sort_by_key(|r| (r.due, r.id));
+        items
+    }
+
+    /// Remove the reminders due at `now` and return them, soonest first.
+    /// Repeating ones are rescheduled instead of removed; one that missed
+    /// several alerts while the client was closed fires once.
+    pub fn take_due(&mut self, now: i64) -> Vec<Reminder> {
+        if !self.items.iter().any(|r| r.due <= now) {
+            return Vec::new();
+        }
+        let mut fired = Vec::new();
+        self.items.retain_mut(|reminder| {
+            if reminder.due > now {
+                return true;
+            }
+            fired.push(reminder.clone());
+            match reminder.next_after(now) {
+                Some(next) => {
+                    reminder.due = next;
+                    true
+                }
+                None => false,
+            }
+        });
+        fired.sort_by_key(|r| (r.due, r.id));
+        self.generation += 1;
+        fired
+    }
+}
+
+/// Parse "45m", "1h30m", "90s", "2d" or a bare number of minutes
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let text = text.trim().to_ascii_lowercase();
+    if let Ok(minutes) = text.parse::<u64>() {
+        return Some(minutes * 60);
+    }
+    let mut total = 0u64;
+    let mut number = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            'd' => 86_400,
+            'h' => 3_600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        total += number.parse::<u64>().ok()? * unit;
+        number.clear();
+    }
+    (number.is_empty() && total > 0).then_some(total)
+}
+
+/// Parse a clock time: "9pm", "9:30pm", "21:00"
+pub fn parse_clock(text: &str) -> Option<NaiveTime> {
+    let text = text.trim().to_ascii_lowercase();
+    let (digits, pm) = if let Some(rest) = text.strip_suffix("pm") {
+        (rest, Some(true))
+    } else if let Some(rest) = text.strip_suffix("am") {
+        (rest, Some(false))
+    } else {
+        (text.as_str(), None)
+    };
+    let (hour, minute) = match digits.split_once(':') {
+        Some((h, m)) if m.len() == 2 => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        None if pm.is_some() => (digits.parse::<u32>().ok()?, 0),
+        _ => return None,
+    };
+    let hour = match pm {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// "9:00pm" or "21:00", per `ui.locale`
+pub fn format_clock(time: NaiveTime) -> String {
+    super::locale::format_clock_compact(&time)
+}
+
+/// "1h 05m", "2h", "44m", "30s", "2d 3h"
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (days, hours, minutes) = (seconds / 86_400, seconds / 3_600 % 24, seconds / 60 % 60);
+    match (days, hours, minutes) {
+        (d, h, _) if d > 0 => format!("{}d {}h", d, h),
+        (_, h, 0) if h > 0 => format!("{}h", h),
+        (_, h, m) if h > 0 => format!("{}h {:02}m", h, m),
+        (_, _, m) if m > 0 => format!("{}m", m),
+        _ => format!("{}s", seconds),
+    }
+}
+
+/// The first local `time` after `after` (naive local datetimes)
+pub fn next_at(after: NaiveDateTime, time: NaiveTime) -> NaiveDateTime {
+    let today = after.date().and_time(time);
+    if today > after {
+        today
+    } else {
+        today + chrono::Duration::days(1)
+    }
+}
+
+/// The first local `time` after the Unix time `now`, as Unix time
+pub fn next_local(now: i64, time: NaiveTime) -> i64 {
+    let local_now = Local
+        .timestamp_opt(now, 0)
+        .single()
+        .map(|t| t.naive_local())
+        .unwrap_or_default();
+    let next = next_at(local_now, time);
+    Local
+        .from_local_datetime(&next)
+        .earliest()
+        // Skipped by a DST change: an hour later is the same wall time
+        .or_else(|| {
+            Local
+                .from_local_datetime(&(next + chrono::Duration::hours(1)))
+                .earliest()
+        })
+        .map(|t| t.timestamp())
+        .unwrap_or(now + 86_400)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reminder(message: &str, due: i64) -> Reminder {
+        Reminder {
+            id: 0,
+            message: message.to_string(),
+            due,
+            every: None,
+            daily: None,
+        }
+    }
+
+    #[test]
+    fn parses_durations_and_clock_times() {
+        assert_eq!(parse_duration("45m"), Some(2_700));
+        assert_eq!(parse_duration("1h30m"), Some(5_400));
+        assert_eq!(parse_duration("90"), Some(5_400));
+        assert_eq!(parse_duration("2d"), Some(172_800));
+        assert_eq!(parse_duration("5x"), None);
+        assert_eq!(parse_duration("1h30"), None);
+
+        let clock = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+        assert_eq!(parse_clock("9pm"), clock(21, 0));
+        assert_eq!(parse_clock("12:15am"), clock(0, 15));
+        assert_eq!(parse_clock("21:00"), clock(21, 0));
+        assert_eq!(parse_clock("13pm"), None);
+        assert_eq!(parse_clock("21"), None);
+        assert_eq!(format_clock(clock(21, 5).unwrap()), "9:05pm");
+    }
+
+    #[test]
+    fn next_at_rolls_over_to_tomorrow() {
+        let day = chrono::NaiveDate::from_ymd_opt(2025, 3, 4).unwrap();
+        let nine_pm = NaiveTime::from_hms_opt(21, 0, 0).unwrap();
+        let morning = day.and_hms_opt(8, 0, 0).unwrap();
+        assert_eq!(next_at(morning, nine_pm), day.and_time(nine_pm));
+        let at_nine = day.and_time(nine_pm);
+        assert_eq!(next_at(at_nine, nine_pm).date(), day.succ_opt().unwrap());
+    }
+
+    #[test]
+    fn take_due_fires_and_reschedules() {
+        let mut reminders = Reminders::default();
+        let once = reminders.add(reminder("pawnshop", 100));
+        reminders.add(Reminder {
+            every: Some(60),
+            ..reminder("drink", 90)
+        });
+        reminders.add(reminder("later", 1_000));
+        assert!(reminders.take_due(50).is_empty());
+
+        // Missed three "drink" alerts; it fires once and moves past now
+        let fired = reminders.take_due(250);
+        let messages: Vec<&str> = fired.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages, ["drink", "pawnshop"]);
+        assert!(!reminders.remove(once));
+        let upcoming = reminders.upcoming();
+        assert_eq!(upcoming[0].message, "drink");
+        assert_eq!(upcoming[0].due, 270);
+        assert_eq!(upcoming[0].repeat_label().as_deref(), Some("every 1m"));
+        assert_eq!(upcoming[1].message, "later");
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(30), "30s");
+        assert_eq!(format_duration(2_700), "45m");
+        assert_eq!(format_duration(3_900), "1h 05m");
+        assert_eq!(format_duration(7_200), "2h");
+        assert_eq!(format_duration(183_600), "2d 3h");
+    }
+}
@@ -1072,7 +1072,7 @@ mod tests {
     fn styled(text: &str) -> Arc<StyledLine> {
         Arc::new(StyledLine {
             segments: vec![TextSegment::plain(text)],
-            stream: "main".to_string(),
+            stream: "main".into(),
             timestamp: None,
         })
     }
@@ -0,0 +1,186 @@
+//! `.repeat <n|forever> <delay> <command>`: send one command over and over
+//! on an interval, for the likes of foraging or practicing a skill.
+//!
+//! The delay is the least time between sends; each send also waits for
+//! roundtime and casttime to clear, so a command that takes longer than
+//! the delay just goes out as soon as it can. It only ever runs because
+//! the user typed it: `.repeat stop`, Esc, or a dropped connection ends it,
+//! and delays under a second aren't accepted.
+
+use std::time::{Duration, Instant};
+
+/// Shortest delay `.repeat` takes
+pub const MIN_DELAY: Duration = Duration::from_secs(1);
+
+/// A parsed `.repeat` request
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepeatSpec {
+    /// Times to send; None is forever
+    pub count: Option<u32>,
+    pub delay: Duration,
+    pub command: String,
+}
+
+impl RepeatSpec {
+    /// Parse `<n|forever> <delay> <command>`. The delay is seconds ("5",
+    /// "2.5", "5s") or milliseconds ("500ms").
+    pub fn parse(args: &str) -> Result<Self, String> {
+        let mut parts = args.trim().splitn(3, char::is_whitespace);
+        let (Some(count), Some(delay), Some(command)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Usage: .repeat <n|forever> <delay> <command>".to_string());
+        };
+        let count = if count.eq_ignore_ascii_case("forever") {
+            None
+        } else {
+            match count.parse::<u32>() {
+                Ok(n) if n > 0 => Some(n),
+                _ => return Err(format!("'{}' isn't a count or 'forever'", count)),
+            }
+        };
+        let delay = parse_delay(delay)
+            .ok_or_else(|| format!("'{}' isn't a delay (e.g. 5, 2.5s, 500ms)", delay))?;
+        if delay < MIN_DELAY {
+            return Err("the delay has to be at least a second".to_string());
+        }
+        let command = command.trim();
+        if command.is_empty() {
+            return Err("nothing to repeat".to_string());
+        }
+        Ok(Self {
+            count,
+            delay,
+            command: command.to_string(),
+        })
+    }
+}
+
+fn parse_delay(text: &str) -> Option<Duration> {
+    let text = text.to_ascii_lowercase();
+    let seconds = match text.strip_suffix("ms") {
+        Some(ms) => ms.parse::<f64>().ok()? / 1000.0,
+        None => text
+            .strip_suffix('s')
+            .unwrap_or(&text)
+            .parse::<f64>()
+            .ok()?,
+    };
+    (seconds.is_finite() && seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+#[derive(Debug, Default)]
+pub struct Repeater {
+    spec: Option<RepeatSpec>,
+    sent: u32,
+    last_sent: Option<Instant>,
+}
+
+impl Repeater {
+    /// Replace whatever is repeating with `spec`; the first send goes out
+    /// on the next tick that isn't in roundtime
+    pub fn start(&mut self, spec: RepeatSpec) {
+        *self = Self {
+            spec: Some(spec),
+            sent: 0,
+            last_sent: None,
+        };
+    }
+
+    /// Stop repeating. Returns the command that was repeating, if any.
+    pub fn stop(&mut self) -> Option<String> {
+        std::mem::take(self).spec.map(|spec| spec.command)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.spec.is_some()
+    }
+
+    /// Progress for the command input and `.repeat`: "forage 3/10" or
+    /// "forage 3/forever", or None when nothing is repeating
+    pub fn label(&self) -> Option<String> {
+        let spec = self.spec.as_ref()?;
+        let total = match spec.count {
+            Some(count) => count.to_string(),
+            None => "forever".to_string(),
+        };
+        Some(format!("{} {}/{}", spec.command, self.sent, total))
+    }
+
+    /// The command to send now, if the delay has passed and `busy`
+    /// (roundtime or casttime) is false. The last send ends the repeat.
+    pub fn tick(&mut self, busy: bool, now: Instant) -> Option<String> {
+        let spec = self.spec.as_ref()?;
+        if busy
+            || self
+                .last_sent
+                .is_some_and(|sent| now.duration_since(sent) < spec.delay)
+        {
+            return None;
+        }
+        let command = spec.command.clone();
+        self.sent += 1;
+        self.last_sent = Some(now);
+        if spec.count.is_some_and(|count| self.sent >= count) {
+            self.spec = None;
+        }
+        Some(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_count_delay_and_command() {
+        assert_eq!(
+            RepeatSpec::parse("10 5 forage for acantha leaf"),
+            Ok(RepeatSpec {
+                count: Some(10),
+                delay: Duration::from_secs(5),
+                command: "forage for acantha leaf".to_string(),
+            })
+        );
+        let spec = RepeatSpec::parse("forever 1500ms search").unwrap();
+        assert_eq!(spec.count, None);
+        assert_eq!(spec.delay, Duration::from_millis(1500));
+        assert_eq!(
+            RepeatSpec::parse("FOREVER 2.5s look").unwrap().delay,
+            Duration::from_millis(2500)
+        );
+        assert!(RepeatSpec::parse("0 5 look").is_err());
+        assert!(RepeatSpec::parse("3 0.5 look").is_err());
+        assert!(RepeatSpec::parse("3 soon look").is_err());
+        assert!(RepeatSpec::parse("3 5").is_err());
+    }
+
+    #[test]
+    fn waits_for_delay_and_roundtime() {
+        let mut repeater = Repeater::default();
+        repeater.start(RepeatSpec::parse("3 5 forage").unwrap());
+        let t0 = Instant::now();
+        let at = |secs: u64| t0 + Duration::from_secs(secs);
+        assert_eq!(repeater.tick(true, t0), None);
+        assert_eq!(repeater.tick(false, at(1)).as_deref(), Some("forage"));
+        assert_eq!(repeater.label().as_deref(), Some("forage 1/3"));
+        assert_eq!(repeater.tick(false, at(5)), None);
+        assert_eq!(repeater.tick(false, at(6)).as_deref(), Some("forage"));
+        // Roundtime past the delay holds the send until it clears
+        assert_eq!(repeater.tick(true, at(12)), None);
+        assert_eq!(repeater.tick(false, at(14)).as_deref(), Some("forage"));
+        assert!(!repeater.is_running());
+        assert_eq!(repeater.tick(false, at(30)), None);
+    }
+
+    #[test]
+    fn stop_ends_a_forever_repeat() {
+        let mut repeater = Repeater::default();
+        repeater.start(RepeatSpec::parse("forever 2 search").unwrap());
+        let t0 = Instant::now();
+        assert!(repeater.tick(false, t0).is_some());
+        assert_eq!(repeater.label().as_deref(), Some("search 1/forever"));
+        assert_eq!(repeater.stop().as_deref(), Some("search"));
+        assert_eq!(repeater.tick(false, t0 + Duration::from_secs(10)), None);
+        assert_eq!(repeater.stop(), None);
+    }
+}
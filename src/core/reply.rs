@@ -0,0 +1,140 @@
+//! Reply targets for `.r` and `.rt`.
+//!
+//! The message pipeline watches for whispers and thoughts aimed at you and
+//! remembers who sent the last of each; AppCore applies the senders to
+//! GameState.reply, like the silver tracker. `.r <text>` and `.rt <text>`
+//! expand the `[reply]` templates with that name, and the `reply_whisper`
+//! keybind pre-fills the input with the whisper command so you can type the
+//! rest.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Who spoke to you, from one line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplyEvent {
+    Whisper(String),
+    Thought(String),
+}
+
+/// `Bob whispers, "..."` / `Bob whispers to you, "..."`; group whispers
+/// (`to the group`) and your own (`You whisper`) don't match
+static RE_WHISPER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^([A-Z][A-Za-z'-]+) whispers(?: to you)?(?: \w+)?, ""#).unwrap()
+});
+/// GemStone ESP: `[General]-GSIV:Bob: "..."`
+static RE_THOUGHT_CHANNEL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^\[[^\]]+\]-[^:]*:([A-Z][A-Za-z'-]+): ""#).unwrap());
+/// DragonRealms gweth/ESP: `Your mind hears Bob thinking, "..."`
+static RE_THOUGHT_HEARD: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Your mind hears ([A-Z][A-Za-z'-]+) thinking").unwrap());
+
+/// Names that look like a sender but aren't anyone you can answer
+const NOT_A_NAME: &[&str] = &["You", "Someone", "Somebody"];
+
+/// Parse one line. Whispers are looked for on every stream (they show up
+/// in main as well as the speech/whisper windows), thoughts only on the
+/// thoughts stream.
+pub fn parse_reply_line(stream: &str, line: &str) -> Option<ReplyEvent> {
+    let line = line.trim();
+    let event = if stream == "thoughts" {
+        RE_THOUGHT_CHANNEL
+            .captures(line)
+            .or_else(|| RE_THOUGHT_HEARD.captures(line))
+            .map(|caps| ReplyEvent::Thought(caps[1].to_string()))
+    } else if line.contains(" whispers") {
+        RE_WHISPER
+            .captures(line)
+            .map(|caps| ReplyEvent::Whisper(caps[1].to_string()))
+    } else {
+        None
+    };
+    event.filter(|event| {
+        let (ReplyEvent::Whisper(name) | ReplyEvent::Thought(name)) = event;
+        !NOT_A_NAME.contains(&name.as_str())
+    })
+}
+
+/// The last whisper and thought senders
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplyTargets {
+    pub whisper: Option<String>,
+    pub thought: Option<String>,
+}
+
+impl ReplyTargets {
+    /// Remember a sender, unless it's `own_name` (your thoughts echo back
+    /// on the channel with your own name)
+    pub fn apply(&mut self, event: ReplyEvent, own_name: Option<&str>) {
+        let is_own = |name: &str| own_name.is_some_and(|own| own.eq_ignore_ascii_case(name));
+        match event {
+            ReplyEvent::Whisper(name) if !is_own(&name) => self.whisper = Some(name),
+            ReplyEvent::Thought(name) if !is_own(&name) => self.thought = Some(name),
+            _ => {}
+        }
+    }
+}
+
+/// Fill a `[reply]` template: `{name}` is the sender, `{text}` what you
+/// typed
+pub fn expand(template: &str, name: &str, text: &str) -> String {
+    template.replace("{name}", name).replace("{text}", text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_whisper_and_thought_senders() {
+        assert_eq!(
+            parse_reply_line("main", r#"Bob whispers, "Meet me at the bank.""#),
+            Some(ReplyEvent::Whisper("Bob".into()))
+        );
+        assert_eq!(
+            parse_reply_line("whisper", r#"Alice whispers to you, "Thanks!""#),
+            Some(ReplyEvent::Whisper("Alice".into()))
+        );
+        assert_eq!(
+            parse_reply_line("main", r#"Bob whispers to the group, "Go north.""#),
+            None
+        );
+        assert_eq!(
+            parse_reply_line("main", r#"You whisper to Bob, "Sure.""#),
+            None
+        );
+        assert_eq!(
+            parse_reply_line("main", r#"Someone whispers, "Boo.""#),
+            None
+        );
+        assert_eq!(
+            parse_reply_line("thoughts", r#"[General]-GSIV:Carol: "Anyone selling ora?""#),
+            Some(ReplyEvent::Thought("Carol".into()))
+        );
+        assert_eq!(
+            parse_reply_line("thoughts", r#"Your mind hears Dave thinking, "Hunting?""#),
+            Some(ReplyEvent::Thought("Dave".into()))
+        );
+        // Thought-shaped text outside the thoughts stream is just text
+        assert_eq!(
+            parse_reply_line("main", r#"[General]-GSIV:Carol: "hi""#),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_own_thoughts_and_expands_templates() {
+        let mut targets = ReplyTargets::default();
+        targets.apply(ReplyEvent::Thought("Carol".into()), Some("Me"));
+        targets.apply(ReplyEvent::Thought("Me".into()), Some("me"));
+        targets.apply(ReplyEvent::Whisper("Bob".into()), Some("Me"));
+        assert_eq!(targets.thought.as_deref(), Some("Carol"));
+        assert_eq!(targets.whisper.as_deref(), Some("Bob"));
+
+        assert_eq!(
+            expand("whisper {name} {text}", "Bob", "on my way"),
+            "whisper Bob on my way"
+        );
+        assert_eq!(expand("whisper {name} {text}", "Bob", ""), "whisper Bob ");
+    }
+}
@@ -0,0 +1,280 @@
+//! Saved routes: named lists of movement commands played back by `.go`.
+//!
+//! A route is whatever you'd type to walk somewhere ("north", "go door",
+//! "climb stair"), saved in `[routes] saved`. Playback sends one step at a
+//! time, only while no roundtime is running and `step_delay_ms` after the
+//! previous step. After a movement step it also waits for the room to
+//! change; a step that doesn't move you within `step_timeout_ms` pauses the
+//! route so `.go resume` can retry it. Plain directions can be walked
+//! backwards (`.go <route> reverse`).
+
+use std::time::{Duration, Instant};
+
+/// Compass and vertical directions with their opposites
+const DIRECTIONS: &[(&str, &str)] = &[
+    ("north", "south"),
+    ("northeast", "southwest"),
+    ("east", "west"),
+    ("southeast", "northwest"),
+    ("up", "down"),
+    ("n", "s"),
+    ("ne", "sw"),
+    ("e", "w"),
+    ("se", "nw"),
+    ("u", "d"),
+];
+
+/// Verbs that take you somewhere when followed by a destination
+const MOVE_VERBS: &[&str] = &["go", "climb", "swim", "crawl", "walk", "run"];
+
+/// Split "north; east; go door" into steps. `;` separates steps.
+pub fn parse_steps(text: &str) -> Vec<String> {
+    text.split(';')
+        .map(str::trim)
+        .filter(|step| !step.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The opposite of a plain direction step ("ne" -> "sw", "out" has none)
+pub fn reverse_step(step: &str) -> Option<String> {
+    let step = step.trim().to_ascii_lowercase();
+    DIRECTIONS.iter().find_map(|&(a, b)| {
+        if step == a {
+            Some(b.to_string())
+        } else if step == b {
+            Some(a.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+/// The route walked backwards, or the first step that has no opposite
+pub fn reverse_route(steps: &[String]) -> Result<Vec<String>, String> {
+    steps
+        .iter()
+        .rev()
+        .map(|step| reverse_step(step).ok_or_else(|| step.clone()))
+        .collect()
+}
+
+/// Whether a step should change the room: a direction, "out", or a
+/// movement verb with a destination ("go door")
+pub fn is_movement(step: &str) -> bool {
+    let step = step.trim().to_ascii_lowercase();
+    if step == "out" || reverse_step(&step).is_some() {
+        return true;
+    }
+    let mut words = step.split_whitespace();
+    matches!(
+        (words.next(), words.next()),
+        (Some(verb), Some(_)) if MOVE_VERBS.contains(&verb)
+    )
+}
+
+/// What a playback tick produced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteEvent {
+    /// Send this step to the game
+    Send(String),
+    /// A movement step didn't change the room; the route is paused on it
+    Stuck(String),
+    /// Every step has been sent (and the last move landed)
+    Done,
+}
+
+/// Playback of one route
+#[derive(Debug, Default)]
+pub struct RoutePlayer {
+    name: String,
+    steps: Vec<String>,
+    /// Index of the next step to send
+    next: usize,
+    paused: bool,
+    last_sent: Option<Instant>,
+    /// A movement step is out: the room it left from and when it was sent
+    awaiting: Option<(String, Instant)>,
+}
+
+impl RoutePlayer {
+    /// Replace whatever is playing with `steps` of route `name`
+    pub fn start(&mut self, name: &str, steps: Vec<String>) {
+        *self = Self {
+            name: name.to_string(),
+            steps,
+            ..Self::default()
+        };
+    }
+
+    /// Drop the route. Returns whether one was playing.
+    pub fn stop(&mut self) -> bool {
+        let running = self.is_running();
+        *self = Self::default();
+        running
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.steps.is_empty()
+    }
+
+    /// Pause before the next step. Returns false if nothing is playing or
+    /// it was already paused.
+    pub fn pause(&mut self) -> bool {
+        if !self.is_running() || self.paused {
+            return false;
+        }
+        self.paused = true;
+        // A move already sent will land while paused
+        self.awaiting = None;
+        true
+    }
+
+    /// Returns false if nothing is playing or it wasn't paused
+    pub fn resume(&mut self) -> bool {
+        if !self.is_running() || !self.paused {
+            return false;
+        }
+        self.paused = false;
+        true
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Progress for the prompt and `.go`: "bank 3/7", "bank 3/7 paused",
+    /// or None when nothing is playing
+    pub fn label(&self) -> Option<String> {
+        if !self.is_running() {
+            return None;
+        }
+        Some(format!(
+            "{} {}/{}{}",
+            self.name,
+            self.next,
+            self.steps.len(),
+            if self.paused { " paused" } else { "" }
+        ))
+    }
+
+    /// Advance playback. `room` is the current room id if known, `busy`
+    /// is true while roundtime is running.
+    pub fn tick(
+        &mut self,
+        room: Option<&str>,
+        busy: bool,
+        now: Instant,
+        step_delay: Duration,
+        step_timeout: Duration,
+    ) -> Option<RouteEvent> {
+        if !self.is_running() || self.paused {
+            return None;
+        }
+        if let Some((from, sent)) = &self.awaiting {
+            if room.is_some_and(|room| room != from) {
+                self.awaiting = None;
+            } else if now.duration_since(*sent) >= step_timeout {
+                // Pause on the step so resume sends it again
+                self.awaiting = None;
+                self.paused = true;
+                self.next -= 1;
+                return Some(RouteEvent::Stuck(self.steps[self.next].clone()));
+            } else {
+                return None;
+            }
+        }
+        if self.next >= self.steps.len() {
+            self.stop();
+            return Some(RouteEvent::Done);
+        }
+        if busy
+            || self
+                .last_sent
+                .is_some_and(|sent| now.duration_since(sent) < step_delay)
+        {
+            return None;
+        }
+        let step = self.steps[self.next].clone();
+        self.next += 1;
+        self.last_sent = Some(now);
+        // Without a room id there's nothing to confirm the move against
+        if let Some(room) = room.filter(|_| is_movement(&step)) {
+            self.awaiting = Some((room.to_string(), now));
+        }
+        Some(RouteEvent::Send(step))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_reverses_routes() {
+        let steps = parse_steps("north; ne ;go door;; up");
+        assert_eq!(steps, ["north", "ne", "go door", "up"]);
+        assert!(steps.iter().all(|step| is_movement(step)));
+        assert!(!is_movement("open door"));
+        assert!(!is_movement("go"));
+        assert_eq!(reverse_route(&steps), Err("go door".to_string()));
+        assert_eq!(
+            reverse_route(&parse_steps("north; ne; up")).unwrap(),
+            ["down", "sw", "south"]
+        );
+    }
+
+    #[test]
+    fn waits_for_rt_and_room_changes() {
+        let mut player = RoutePlayer::default();
+        player.start("bank", parse_steps("north; open door; east"));
+        let delay = Duration::from_millis(500);
+        let timeout = Duration::from_secs(8);
+        let t0 = Instant::now();
+        let tick = |player: &mut RoutePlayer, room: &str, busy: bool, ms: u64| {
+            player.tick(
+                Some(room),
+                busy,
+                t0 + Duration::from_millis(ms),
+                delay,
+                timeout,
+            )
+        };
+
+        assert_eq!(tick(&mut player, "1", true, 0), None);
+        assert_eq!(
+            tick(&mut player, "1", false, 0),
+            Some(RouteEvent::Send("north".into()))
+        );
+        // Still in the old room: waiting for the move
+        assert_eq!(tick(&mut player, "1", false, 1_000), None);
+        assert_eq!(player.label().as_deref(), Some("bank 1/3"));
+        assert_eq!(
+            tick(&mut player, "2", false, 1_000),
+            Some(RouteEvent::Send("open door".into()))
+        );
+        // Not a move: only the step delay holds the next one
+        assert_eq!(tick(&mut player, "2", false, 1_200), None);
+        assert_eq!(
+            tick(&mut player, "2", false, 1_500),
+            Some(RouteEvent::Send("east".into()))
+        );
+        // The move fails: paused on it, and resume retries it
+        assert_eq!(
+            tick(&mut player, "2", false, 9_500),
+            Some(RouteEvent::Stuck("east".into()))
+        );
+        assert_eq!(player.label().as_deref(), Some("bank 2/3 paused"));
+        assert_eq!(tick(&mut player, "2", false, 10_000), None);
+        assert!(player.resume());
+        assert_eq!(
+            tick(&mut player, "2", false, 10_000),
+            Some(RouteEvent::Send("east".into()))
+        );
+        assert_eq!(
+            tick(&mut player, "3", false, 11_000),
+            Some(RouteEvent::Done)
+        );
+        assert!(!player.is_running());
+    }
+}
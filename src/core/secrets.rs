@@ -0,0 +1,44 @@
+//! Masking for replies to the game's own password prompts.
+//!
+//! Direct-connect menus and some account screens ask for a password in the
+//! game text ("Please enter your password:"). With `ui.mask_secrets` on,
+//! the next command after such a line is echoed, logged and captured as
+//! `MASK`; the game still gets the real text. Detection is deliberately
+//! narrow (a short line ending in ':' or '?' that names a password), so
+//! ordinary speech about passwords isn't masked.
+
+/// What a masked command shows as. Fixed length, so it doesn't tell how
+/// long the secret was.
+pub const MASK: &str = "********";
+
+/// Longest line treated as a prompt
+const MAX_PROMPT_LEN: usize = 80;
+
+/// Whether a line of game text asks for a password
+pub fn is_password_prompt(line: &str) -> bool {
+    let line = line.trim();
+    if line.is_empty() || line.len() > MAX_PROMPT_LEN || !line.ends_with([':', '?']) {
+        return false;
+    }
+    let lower = line.to_ascii_lowercase();
+    ["password", "passphrase", "passcode"]
+        .iter()
+        .any(|word| lower.contains(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spots_password_prompts_only() {
+        assert!(is_password_prompt("Please enter your password:"));
+        assert!(is_password_prompt("  Password: "));
+        assert!(is_password_prompt("What is your account passphrase?"));
+        assert!(!is_password_prompt(
+            "Bob says, \"I forgot my password again.\""
+        ));
+        assert!(!is_password_prompt("Enter your account name:"));
+        assert!(!is_password_prompt(&format!("{}password:", "x".repeat(90))));
+    }
+}
@@ -0,0 +1,106 @@
+//! Playback for sequence keybinds: a list of commands sent in order, each
+//! after its own pause (`delay_ms`, measured from the previous step). Meant
+//! for login walks through direct-connect menus and startup routines, so
+//! unlike `.go` routes it doesn't wait on roundtime or room changes.
+//! Pressing the same key again while it plays stops it.
+
+use crate::config::SequenceStep;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+pub struct SequencePlayer {
+    /// The sequence as bound, to recognise a second press of the same key
+    steps: Vec<SequenceStep>,
+    pending: VecDeque<SequenceStep>,
+    last_sent: Option<Instant>,
+}
+
+impl SequencePlayer {
+    /// Replace whatever is playing with `steps`, starting at `now`
+    pub fn start(&mut self, steps: &[SequenceStep], now: Instant) {
+        *self = Self {
+            steps: steps.to_vec(),
+            pending: steps.iter().cloned().collect(),
+            last_sent: Some(now),
+        };
+    }
+
+    /// Drop the sequence. Returns whether one was playing.
+    pub fn stop(&mut self) -> bool {
+        let running = self.is_running();
+        *self = Self::default();
+        running
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Whether `steps` is the sequence currently playing
+    pub fn is_playing(&self, steps: &[SequenceStep]) -> bool {
+        self.is_running() && self.steps == steps
+    }
+
+    /// Steps left, for status messages
+    pub fn remaining(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Commands whose pause has run out by `now`, in order
+    pub fn tick(&mut self, now: Instant) -> Vec<String> {
+        let mut due = Vec::new();
+        while let Some(step) = self.pending.front() {
+            let ready_at = self
+                .last_sent
+                .map(|sent| sent + Duration::from_millis(step.delay_ms));
+            if ready_at.is_some_and(|at| now < at) {
+                break;
+            }
+            // Later pauses count from when this step was due, not from a
+            // late tick, so a slow frame doesn't stretch the whole sequence
+            self.last_sent = ready_at.or(Some(now));
+            due.extend(self.pending.pop_front().map(|step| step.send));
+        }
+        if self.pending.is_empty() {
+            self.steps.clear();
+        }
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SequenceAction;
+
+    #[test]
+    fn sends_each_step_after_its_pause() {
+        let action = SequenceAction::parse("1; A; wait 2; Eliandra; wait 0.5; look").unwrap();
+        let mut player = SequencePlayer::default();
+        let t0 = Instant::now();
+        let at = |ms: u64| t0 + Duration::from_millis(ms);
+        player.start(&action.sequence, t0);
+        assert!(player.is_playing(&action.sequence));
+        assert_eq!(player.tick(at(0)), ["1", "A"]);
+        assert!(player.tick(at(1999)).is_empty());
+        assert_eq!(player.remaining(), 2);
+        // A late tick sends the step; the next pause still counts from 2000
+        assert_eq!(player.tick(at(2400)), ["Eliandra"]);
+        assert_eq!(player.tick(at(2500)), ["look"]);
+        assert!(!player.is_running());
+        assert!(!player.is_playing(&action.sequence));
+    }
+
+    #[test]
+    fn stop_drops_the_rest() {
+        let action = SequenceAction::parse("1; wait 5; 2").unwrap();
+        let mut player = SequencePlayer::default();
+        let t0 = Instant::now();
+        player.start(&action.sequence, t0);
+        assert_eq!(player.tick(t0), ["1"]);
+        assert!(player.stop());
+        assert!(player.tick(t0 + Duration::from_secs(10)).is_empty());
+        assert!(!player.stop());
+    }
+}
@@ -0,0 +1,183 @@
+//! Society task parser for the society task checklist.
+//!
+//! The society stream carries the status and current task text for the
+//! Order of Voln, the Council of Light and the Guardians of Sunfist. Each
+//! line that asks for something ("release 12 undead", "you need 3 more
+//! favors") becomes a task; other lines ("You are a Master in the Order of
+//! Voln") are kept as status. A count in a task line becomes progress —
+//! SocietyState remembers the full count as the text counts down, like the
+//! bounty tracker — and a line saying the work is done, or a count reaching
+//! zero, marks the task complete.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A line that asks for something
+static RE_TASK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:task|instructed|need(?:s|ed)? to|need \d|must|asked you to|have been (?:asked|tasked|instructed)|favors?|symbols?)\b").unwrap()
+});
+
+/// "3 of 10", "3 out of 10"
+static RE_OF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(\d+) (?:of|out of) (\d+)\b").unwrap());
+
+/// "3/10"
+static RE_SLASH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(\d+)\s*/\s*(\d+)\b").unwrap());
+
+/// "6 more"
+static RE_MORE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(\d+) more\b").unwrap());
+
+/// Any other count: "release 12 undead", "need 5 favors"
+static RE_COUNT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(\d+)\b").unwrap());
+
+/// The work is done and waiting to be turned in
+static RE_DONE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:you have (?:completed|finished|fulfilled)|(?:task|duty) is (?:complete|done|fulfilled)|ready to (?:advance|be tested|report)|return to (?:the |your )?(?:master|monk|guildmaster|council|society))").unwrap()
+});
+
+/// Society named in a line, by its short name
+fn society_of(line: &str) -> Option<&'static str> {
+    let lower = line.to_ascii_lowercase();
+    [
+        ("voln", "Voln"),
+        ("council of light", "Council of Light"),
+        ("sunfist", "Sunfist"),
+    ]
+    .into_iter()
+    .find(|(needle, _)| lower.contains(needle))
+    .map(|(_, name)| name)
+}
+
+/// One task line from the society stream
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SocietyTask {
+    /// The line as the game sent it
+    pub text: String,
+    /// How many are still needed, for counted tasks
+    pub remaining: Option<u32>,
+    /// Full count, when the text gives it ("3 of 10", or a count without
+    /// "more")
+    pub total: Option<u32>,
+    /// The work is done
+    pub complete: bool,
+}
+
+impl SocietyTask {
+    /// Identifies the task across count updates: the text with the digits
+    /// and the word "more" removed
+    pub fn key(&self) -> String {
+        self.text
+            .split_whitespace()
+            .filter(|word| !word.eq_ignore_ascii_case("more"))
+            .flat_map(|word| word.chars().filter(|c| !c.is_ascii_digit()))
+            .collect()
+    }
+}
+
+/// Society stream split into status lines and tasks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SocietyReport {
+    /// Short name of the society, if any line names it
+    pub society: Option<&'static str>,
+    /// Lines that aren't tasks: rank, step, standing
+    pub status: Vec<String>,
+    pub tasks: Vec<SocietyTask>,
+}
+
+pub fn parse(lines: &[String]) -> SocietyReport {
+    let mut report = SocietyReport::default();
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if report.society.is_none() {
+            report.society = society_of(line);
+        }
+        if !RE_TASK.is_match(line) && !RE_DONE.is_match(line) {
+            report.status.push(line.to_string());
+            continue;
+        }
+        report.tasks.push(parse_task(line));
+    }
+    report
+}
+
+fn parse_task(line: &str) -> SocietyTask {
+    let number = |caps: &regex::Captures, i: usize| caps[i].parse::<u32>().ok();
+    let (remaining, total) =
+        if let Some(caps) = RE_OF.captures(line).or_else(|| RE_SLASH.captures(line)) {
+            match (number(&caps, 1), number(&caps, 2)) {
+                (Some(done), Some(total)) => (Some(total.saturating_sub(done)), Some(total)),
+                _ => (None, None),
+            }
+        } else if let Some(caps) = RE_MORE.captures(line) {
+            (number(&caps, 1), None)
+        } else if let Some(caps) = RE_COUNT.captures(line) {
+            let count = number(&caps, 1);
+            (count, count)
+        } else {
+            (None, None)
+        };
+    let complete = remaining == Some(0) || (remaining.is_none() && RE_DONE.is_match(line));
+    SocietyTask {
+        text: line.to_string(),
+        remaining,
+        total,
+        complete,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &[&str]) -> Vec<String> {
+        text.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn splits_status_from_tasks() {
+        let report = parse(&lines(&[
+            "You are a Master in the Order of Voln at step 12.",
+            "",
+            "The Voln Master has instructed you to release 12 undead souls.",
+        ]));
+        assert_eq!(report.society, Some("Voln"));
+        assert_eq!(
+            report.status,
+            vec!["You are a Master in the Order of Voln at step 12."]
+        );
+        assert_eq!(report.tasks.len(), 1);
+        assert_eq!(report.tasks[0].remaining, Some(12));
+        assert_eq!(report.tasks[0].total, Some(12));
+        assert!(!report.tasks[0].complete);
+    }
+
+    #[test]
+    fn reads_counts_in_each_form() {
+        let task = parse_task("You need 6 more favors to advance.");
+        assert_eq!((task.remaining, task.total), (Some(6), None));
+        let task = parse_task("Rift favors earned: 3 of 10.");
+        assert_eq!((task.remaining, task.total), (Some(7), Some(10)));
+        let task = parse_task("Symbols learned: 4/4");
+        assert!(task.complete);
+    }
+
+    #[test]
+    fn done_lines_complete_without_a_count() {
+        let report = parse(&lines(&[
+            "You have completed your task. Return to the Voln Master to advance.",
+        ]));
+        assert!(report.tasks[0].complete);
+        let report = parse(&lines(&["You must complete 5 more tasks."]));
+        assert!(!report.tasks[0].complete);
+    }
+
+    #[test]
+    fn key_ignores_counts() {
+        let a = parse_task("You need to release 12 undead souls.");
+        let b = parse_task("You need to release 6 more undead souls.");
+        assert_eq!(a.key(), b.key());
+    }
+}
@@ -0,0 +1,280 @@
+//! Spell circle grouping for the spells window.
+//!
+//! The `Spells` stream is one flat listing. When the game labels its
+//! sections (a `Minor Spiritual:` line, or a DragonRealms realm), those
+//! labels become the groups; otherwise spells are grouped by the circle
+//! their number falls in (101 → Minor Spiritual, 401 → Minor Elemental).
+//! Lines before the first group — the listing's preamble — stay in an
+//! unnamed group that is always shown.
+//!
+//! Each group knows its spells (number and name), so the window can count
+//! how many of them are currently active.
+
+use crate::data::{ActiveEffect, StyledLine};
+use serde::{Deserialize, Serialize};
+
+/// One known spell parsed from a listing line
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownSpell {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    #[serde(default)]
+    pub name: String,
+}
+
+impl KnownSpell {
+    /// Whether `active` has this spell, matched by spell number (the
+    /// effect id) or by name
+    pub fn is_active(&self, active: &[ActiveEffect]) -> bool {
+        active.iter().any(|effect| {
+            let by_number = self
+                .number
+                .is_some_and(|n| effect.id.trim().parse::<u32>() == Ok(n));
+            by_number
+                || (!self.name.is_empty() && effect.text.trim().eq_ignore_ascii_case(&self.name))
+        })
+    }
+}
+
+/// A section of the spell listing
+#[derive(Debug, Clone)]
+pub struct SpellGroup {
+    /// Header shown for the group; empty for the preamble
+    pub name: String,
+    pub lines: Vec<StyledLine>,
+    pub spells: Vec<KnownSpell>,
+}
+
+impl SpellGroup {
+    fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            lines: Vec::new(),
+            spells: Vec::new(),
+        }
+    }
+
+    /// How many of this group's spells appear in `active`
+    pub fn active_count(&self, active: &[ActiveEffect]) -> usize {
+        self.spells
+            .iter()
+            .filter(|spell| spell.is_active(active))
+            .count()
+    }
+}
+
+/// GemStone IV spell circle for a spell number
+pub fn circle_name(number: u32) -> Option<&'static str> {
+    Some(match number / 100 {
+        1 => "Minor Spiritual",
+        2 => "Major Spiritual",
+        3 => "Cleric",
+        4 => "Minor Elemental",
+        5 => "Major Elemental",
+        6 => "Ranger",
+        7 => "Sorcerer",
+        9 => "Wizard",
+        10 => "Bard",
+        11 => "Empath",
+        12 => "Minor Mental",
+        13 => "Major Mental",
+        14 => "Savant",
+        16 => "Paladin",
+        17 => "Arcane",
+        _ => return None,
+    })
+}
+
+fn line_text(line: &StyledLine) -> String {
+    line.segments.iter().map(|seg| seg.text.as_str()).collect()
+}
+
+/// A section label: short, ends with ':', no link and no spell number
+fn header_name(line: &StyledLine) -> Option<String> {
+    if line.segments.iter().any(|seg| seg.link_data.is_some()) {
+        return None;
+    }
+    let text = line_text(line);
+    let label = text.trim().strip_suffix(':')?.trim();
+    (!label.is_empty() && label.len() <= 40 && !label.chars().any(|c| c.is_ascii_digit()))
+        .then(|| label.to_string())
+}
+
+/// First standalone run of 3-4 digits
+fn spell_number(text: &str) -> Option<u32> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if (3..=4).contains(&(i - start)) {
+                return text[start..i].parse().ok();
+            }
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Spell name from a listing line: parenthesized abbreviations, numbers
+/// and separators dropped
+fn spell_name(text: &str) -> String {
+    let mut bare = String::with_capacity(text.len());
+    let mut depth = 0usize;
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 => bare.push(c),
+            _ => {}
+        }
+    }
+    bare.split(|c: char| c.is_ascii_digit() || matches!(c, ':' | '-' | '.'))
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The spell a listing line names, if it names one. Under a labeled
+/// section every non-blank line is a spell (`labeled`); otherwise a line
+/// needs a spell number or a link.
+fn parse_spell(line: &StyledLine, labeled: bool) -> Option<KnownSpell> {
+    let text = line_text(line);
+    let link = line.segments.iter().find_map(|seg| seg.link_data.as_ref());
+    let number = spell_number(&text).or_else(|| link.and_then(|l| spell_number(&l.exist_id)));
+    let name = spell_name(&text);
+    if number.is_none() && link.is_none() && (!labeled || name.is_empty()) {
+        return None;
+    }
+    Some(KnownSpell { number, name })
+}
+
+/// Split a spell listing into groups, in the order they first appear
+pub fn group(lines: &[StyledLine]) -> Vec<SpellGroup> {
+    let mut groups = vec![SpellGroup::new("")];
+    if lines.iter().any(|line| header_name(line).is_some()) {
+        for line in lines {
+            if let Some(name) = header_name(line) {
+                groups.push(SpellGroup::new(name));
+                continue;
+            }
+            let labeled = groups.len() > 1;
+            let current = groups.last_mut().expect("preamble group");
+            if let Some(spell) = parse_spell(line, labeled) {
+                current.spells.push(spell);
+            }
+            current.lines.push(line.clone());
+        }
+    } else {
+        for line in lines {
+            let Some(spell) = parse_spell(line, false) else {
+                if !line_text(line).trim().is_empty() {
+                    groups[0].lines.push(line.clone());
+                }
+                continue;
+            };
+            let name = match spell.number {
+                Some(n) => circle_name(n)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("{}s", n / 100 * 100)),
+                None => "Other".to_string(),
+            };
+            let index = match groups.iter().position(|g| g.name == name) {
+                Some(index) => index,
+                None => {
+                    groups.push(SpellGroup::new(name));
+                    groups.len() - 1
+                }
+            };
+            groups[index].spells.push(spell);
+            groups[index].lines.push(line.clone());
+        }
+    }
+    groups.retain(|g| !g.name.is_empty() || !g.lines.is_empty());
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::TextSegment;
+
+    fn line(text: &str) -> StyledLine {
+        StyledLine {
+            segments: vec![TextSegment::plain(text)],
+            stream: "Spells".into(),
+            timestamp: None,
+        }
+    }
+
+    fn effect(id: &str, text: &str) -> ActiveEffect {
+        ActiveEffect {
+            id: id.to_string(),
+            text: text.to_string(),
+            value: 100,
+            time: "10:00".to_string(),
+            expires_at: None,
+            bar_color: None,
+            text_color: None,
+        }
+    }
+
+    fn names(groups: &[SpellGroup]) -> Vec<&str> {
+        groups.iter().map(|g| g.name.as_str()).collect()
+    }
+
+    #[test]
+    fn groups_by_circle_when_unlabeled() {
+        let lines = [
+            line("Spell listing"),
+            line("101 Spirit Warding I"),
+            line("401 Elemental Defense I"),
+            line("103 Spirit Defense"),
+        ];
+        let groups = group(&lines);
+        assert_eq!(names(&groups), ["", "Minor Spiritual", "Minor Elemental"]);
+        assert_eq!(groups[1].lines.len(), 2);
+        assert_eq!(
+            groups[1].spells[1],
+            KnownSpell {
+                number: Some(103),
+                name: "Spirit Defense".into()
+            }
+        );
+    }
+
+    #[test]
+    fn uses_the_listings_own_headers() {
+        let lines = [
+            line("Lunar Magic:"),
+            line("  Dark Hunger (DH)"),
+            line("Arcane:"),
+            line("  Arcane Decoy (1701)"),
+        ];
+        let groups = group(&lines);
+        assert_eq!(names(&groups), ["Lunar Magic", "Arcane"]);
+        assert_eq!(groups[0].spells[0].name, "Dark Hunger");
+        assert_eq!(groups[1].spells[0].number, Some(1701));
+    }
+
+    #[test]
+    fn counts_active_spells_by_number_or_name() {
+        let lines = [
+            line("101 Spirit Warding I"),
+            line("102 Spirit Barrier"),
+            line("103 Spirit Defense"),
+        ];
+        let groups = group(&lines);
+        let active = [
+            effect("101", "Spirit Warding I"),
+            effect("x", "spirit defense"),
+        ];
+        assert_eq!(groups[0].active_count(&active), 2);
+        assert_eq!(groups[0].active_count(&[]), 0);
+    }
+}
@@ -0,0 +1,432 @@
+//! Spellcheck for spoken commands (`say`, `whisper`, `think`, ...).
+//!
+//! Words are checked against the bundled list (`defaults/globals/words.txt`)
+//! plus a personal dictionary (`global/dictionary.txt`, one word per line,
+//! grown from the suggestion popup). Simple inflections of a known word
+//! ("dragons", "smiled", "hunting", "quickly") count as known, so the list
+//! only needs base forms.
+//!
+//! Only the spoken part of the input is checked: the verb, a whisper's
+//! target, and capitalized words mid-sentence (names) are skipped.
+
+use anyhow::{Context, Result};
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+const BUNDLED_WORDS: &str = include_str!("../../defaults/globals/words.txt");
+
+/// Verbs whose first argument is a target, not speech
+/// ("whisper Bob hello", "whisper ooc Bob hello")
+const TARGETED_VERBS: &[&str] = &["whisper"];
+
+/// Shortest verb abbreviation accepted ("whis" for "whisper")
+const MIN_VERB_ABBREV: usize = 3;
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// A word not found in the dictionary, as char offsets into the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    pub start: usize,
+    pub end: usize,
+    pub word: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpellChecker {
+    words: HashSet<String>,
+    personal: BTreeSet<String>,
+    personal_path: Option<PathBuf>,
+}
+
+impl Default for SpellChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpellChecker {
+    /// Bundled word list only
+    pub fn new() -> Self {
+        Self {
+            words: parse_word_list(BUNDLED_WORDS).collect(),
+            personal: BTreeSet::new(),
+            personal_path: None,
+        }
+    }
+
+    /// Bundled list plus the personal dictionary at `path` (missing file =
+    /// empty dictionary; `add_word` creates it).
+    pub fn load(path: &Path) -> Self {
+        let mut checker = Self::new();
+        if let Ok(text) = fs::read_to_string(path) {
+            checker.personal = parse_word_list(&text).collect();
+        }
+        checker.personal_path = Some(path.to_path_buf());
+        checker
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(word) || self.personal.contains(word)
+    }
+
+    /// Whether `word` (any case) or a simple inflection of it is known.
+    pub fn is_known(&self, word: &str) -> bool {
+        let lower = word.to_lowercase();
+        let lower = lower
+            .strip_suffix("'s")
+            .or_else(|| lower.strip_suffix('\''))
+            .unwrap_or(&lower);
+        if lower.is_empty() || self.contains(lower) {
+            return true;
+        }
+        base_forms(lower).iter().any(|base| self.contains(base))
+    }
+
+    /// Misspelled words in `input`, if it starts with one of `verbs` (or
+    /// `'`, the say shorthand). Anything else is a game command and is
+    /// never checked.
+    pub fn check(&self, input: &str, verbs: &[String]) -> Vec<Misspelling> {
+        let chars: Vec<char> = input.chars().collect();
+        let Some(speech_start) = speech_start(&chars, verbs) else {
+            return Vec::new();
+        };
+
+        let mut errors = Vec::new();
+        let mut sentence_start = true;
+        let mut i = speech_start;
+        while i < chars.len() {
+            // Whitespace-separated token
+            if chars[i].is_whitespace() {
+                i += 1;
+                continue;
+            }
+            let token_start = i;
+            while i < chars.len() && !chars[i].is_whitespace() {
+                i += 1;
+            }
+            let token = &chars[token_start..i];
+            let at_sentence_start = sentence_start;
+            sentence_start = matches!(token.last(), Some('.' | '!' | '?'));
+            // Links, emails, numbers, markup: not prose
+            if token
+                .iter()
+                .any(|c| c.is_ascii_digit() || "/:@#<>=_\\".contains(*c))
+            {
+                continue;
+            }
+            for (start, end) in word_runs(token) {
+                let word: String = token[start..end].iter().collect();
+                if word.chars().count() < 2 {
+                    continue;
+                }
+                let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+                let shouting = word.chars().all(|c| !c.is_lowercase());
+                // Mid-sentence capitals are names; all-caps are acronyms
+                if (capitalized && !(at_sentence_start && start == 0)) || shouting {
+                    continue;
+                }
+                if !self.is_known(&word) {
+                    errors.push(Misspelling {
+                        start: token_start + start,
+                        end: token_start + end,
+                        word,
+                    });
+                }
+            }
+        }
+        errors
+    }
+
+    /// Up to `limit` known words one or two edits away from `word`, closest
+    /// first. Among equally close words, swapped letters ("teh" -> "the")
+    /// beat a changed first letter, which beats a change in length.
+    /// Capitalization of the first letter is kept.
+    pub fn suggest(&self, word: &str, limit: usize) -> Vec<String> {
+        let lower = word.to_lowercase();
+        let first = lower.chars().next();
+        let letters = sorted_chars(&lower);
+        let mut seen = HashSet::new();
+        let mut ranked: Vec<(usize, bool, bool, usize, String)> = Vec::new();
+        let near = edits(&lower);
+        for distance in 1..=2 {
+            let candidates: Vec<String> = if distance == 1 {
+                near.clone()
+            } else if ranked.len() < limit {
+                near.iter().flat_map(|candidate| edits(candidate)).collect()
+            } else {
+                break;
+            };
+            for candidate in candidates {
+                // Inflections only at distance 1; two edits away there are
+                // far too many candidates to stem each one
+                let known = if distance == 1 {
+                    self.is_known(&candidate)
+                } else {
+                    self.contains(&candidate)
+                };
+                if candidate == lower || !known || seen.contains(&candidate) {
+                    continue;
+                }
+                seen.insert(candidate.clone());
+                ranked.push((
+                    distance,
+                    sorted_chars(&candidate) != letters,
+                    candidate.chars().next() != first,
+                    candidate.chars().count().abs_diff(lower.chars().count()),
+                    candidate,
+                ));
+            }
+        }
+        ranked.sort();
+        let capitalize = word.chars().next().is_some_and(char::is_uppercase);
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, _, _, candidate)| {
+                if capitalize {
+                    let mut chars = candidate.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                } else {
+                    candidate
+                }
+            })
+            .collect()
+    }
+
+    /// Add a word to the personal dictionary and append it to the file.
+    /// Returns false if it was already known.
+    pub fn add_word(&mut self, word: &str) -> Result<bool> {
+        let lower = word.trim().to_lowercase();
+        if lower.is_empty() || self.contains(&lower) {
+            return Ok(false);
+        }
+        if let Some(path) = &self.personal_path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open {}", path.display()))?;
+            writeln!(file, "{}", lower)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        self.personal.insert(lower);
+        Ok(true)
+    }
+
+    pub fn personal_len(&self) -> usize {
+        self.personal.len()
+    }
+}
+
+/// Lowercased words from a one-per-line list; `#` starts a comment line
+fn parse_word_list(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_lowercase)
+}
+
+/// Char offset where the spoken text begins, or None if `chars` isn't speech
+fn speech_start(chars: &[char], verbs: &[String]) -> Option<usize> {
+    let lead = chars.iter().take_while(|c| c.is_whitespace()).count();
+    if chars.get(lead) == Some(&'\'') {
+        return Some(lead + 1);
+    }
+    let mut tokens = Vec::new();
+    let mut i = lead;
+    while i < chars.len() && tokens.len() < 3 {
+        let start = i;
+        while i < chars.len() && !chars[i].is_whitespace() {
+            i += 1;
+        }
+        tokens.push((chars[start..i].iter().collect::<String>().to_lowercase(), i));
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+    }
+    let (verb_token, verb_end) = tokens.first()?;
+    let verb = verbs.iter().find(|verb| {
+        let verb = verb.to_lowercase();
+        verb == *verb_token
+            || (verb_token.len() >= MIN_VERB_ABBREV && verb.starts_with(verb_token.as_str()))
+    })?;
+    if !TARGETED_VERBS.contains(&verb.to_lowercase().as_str()) {
+        return Some(*verb_end);
+    }
+    // Skip the target (and an "ooc" marker before it)
+    match tokens.get(1) {
+        Some((second, _)) if second == "ooc" => tokens.get(2).map(|(_, end)| *end),
+        Some((_, end)) => Some(*end),
+        None => None,
+    }
+}
+
+/// Runs of letters (with inner apostrophes, "don't") inside a token
+fn word_runs(token: &[char]) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut i = 0;
+    while i < token.len() {
+        if !token[i].is_alphabetic() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < token.len()
+            && (token[i].is_alphabetic()
+                || (token[i] == '\'' && token.get(i + 1).is_some_and(|c| c.is_alphabetic())))
+        {
+            i += 1;
+        }
+        runs.push((start, i));
+    }
+    runs
+}
+
+/// Candidate base forms for an inflected word ("smiled" -> "smile",
+/// "hunting" -> "hunt", "ponies" -> "pony", "quickly" -> "quick")
+fn base_forms(word: &str) -> Vec<String> {
+    let mut forms = Vec::new();
+    let mut stem = |suffix: &str, replacements: &[&str]| {
+        if let Some(stem) = word.strip_suffix(suffix) {
+            if stem.chars().count() < 2 {
+                return;
+            }
+            for replacement in replacements {
+                forms.push(format!("{}{}", stem, replacement));
+            }
+            // Doubled final consonant: "stopped" -> "stop", "bigger" -> "big"
+            let chars: Vec<char> = stem.chars().collect();
+            if let [.., a, b] = chars[..] {
+                if a == b && !"aeiou".contains(a) {
+                    forms.push(chars[..chars.len() - 1].iter().collect());
+                }
+            }
+        }
+    };
+    stem("s", &[""]);
+    stem("es", &[""]);
+    stem("ies", &["y"]);
+    stem("ed", &["", "e"]);
+    stem("ied", &["y"]);
+    stem("ing", &["", "e"]);
+    stem("er", &["", "e"]);
+    stem("ier", &["y"]);
+    stem("est", &["", "e"]);
+    stem("iest", &["y"]);
+    stem("ly", &[""]);
+    stem("ily", &["y"]);
+    stem("ness", &[""]);
+    stem("iness", &["y"]);
+    stem("ful", &[""]);
+    stem("less", &[""]);
+    stem("ment", &[""]);
+    stem("n't", &[""]);
+    for prefix in ["un", "re"] {
+        if let Some(rest) = word.strip_prefix(prefix) {
+            if rest.chars().count() >= 3 {
+                forms.push(rest.to_string());
+            }
+        }
+    }
+    forms
+}
+
+fn sorted_chars(word: &str) -> Vec<char> {
+    let mut chars: Vec<char> = word.chars().collect();
+    chars.sort_unstable();
+    chars
+}
+
+/// Every string one deletion, transposition, substitution or insertion away
+fn edits(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut out = Vec::new();
+    for i in 0..=chars.len() {
+        let (left, right) = chars.split_at(i);
+        let left: String = left.iter().collect();
+        if let Some((first, rest)) = right.split_first() {
+            let rest: String = rest.iter().collect();
+            out.push(format!("{left}{rest}"));
+            if let Some((second, tail)) = rest.chars().next().map(|c| (c, &rest[c.len_utf8()..])) {
+                out.push(format!("{left}{second}{first}{tail}"));
+            }
+            for c in ALPHABET.chars().filter(|c| c != first) {
+                out.push(format!("{left}{c}{rest}"));
+            }
+        }
+        let right: String = right.iter().collect();
+        for c in ALPHABET.chars() {
+            out.push(format!("{left}{c}{right}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verbs() -> Vec<String> {
+        ["say", "whisper", "think"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn flags_only_spoken_words() {
+        let checker = SpellChecker::new();
+        let errors = checker.check("say Hello Bob, teh dragon smiled!", &verbs());
+        assert_eq!(
+            errors,
+            vec![Misspelling {
+                start: 15,
+                end: 18,
+                word: "teh".to_string()
+            }]
+        );
+        // Game commands are never checked
+        assert!(checker.check("get teh sword", &verbs()).is_empty());
+        // Whisper target and the ' shorthand
+        assert!(checker
+            .check("whisper ooc Xyzzy hunting wolves", &verbs())
+            .is_empty());
+        assert_eq!(checker.check("'recieve", &verbs())[0].word, "recieve");
+        assert_eq!(checker.check("whis Bob wnat", &verbs())[0].start, 9);
+    }
+
+    #[test]
+    fn suggests_close_words() {
+        let checker = SpellChecker::new();
+        assert_eq!(
+            checker.suggest("teh", 3).first().map(String::as_str),
+            Some("the")
+        );
+        assert!(checker.suggest("Dragno", 5).contains(&"Dragon".to_string()));
+    }
+
+    #[test]
+    fn personal_words_persist() {
+        let path = std::env::temp_dir().join(format!(
+            "vellum-fe-dictionary-test-{}.txt",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let mut checker = SpellChecker::load(&path);
+        assert!(!checker.is_known("zzyzx"));
+        assert!(checker.add_word("Zzyzx").unwrap());
+        assert!(!checker.add_word("zzyzx").unwrap());
+        assert!(SpellChecker::load(&path).is_known("zzyzx"));
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,124 @@
+//! Combat stance and posture for the stance widget.
+//!
+//! The stance comes from the `pbarStance` bar in the stance dialog
+//! ("defensive (100%)"); posture comes from the status indicators. The
+//! widget and `.stance next|prev` step through a configurable cycle order
+//! and send the game's `stance <name>` command.
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::StatusInfo;
+
+/// GS4 stances, offensive to defensive. Used when the widget has no
+/// `stances` list of its own.
+pub const DEFAULT_STANCES: [&str; 6] = [
+    "offensive",
+    "advance",
+    "forward",
+    "neutral",
+    "guarded",
+    "defensive",
+];
+
+/// Current combat stance
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StanceInfo {
+    /// Stance name as the game reports it; empty until the first update
+    pub name: String,
+    /// 0 (fully offensive) to 100 (fully defensive)
+    pub percent: u8,
+}
+
+impl StanceInfo {
+    /// Update from a `pbarStance` progress bar ("guarded (80%)").
+    pub fn update(&mut self, value: u32, max: u32, text: &str) {
+        let name = text.split('(').next().unwrap_or_default().trim();
+        self.name = name.to_ascii_lowercase();
+        self.percent = (value.min(max) * 100)
+            .checked_div(max)
+            .unwrap_or_else(|| value.min(100)) as u8;
+    }
+}
+
+/// The stance one step from `current` toward defensive (`forward`) or
+/// offensive, clamped at the ends of `order`. An unknown current stance
+/// starts from the matching end. None when `order` is empty.
+pub fn step(current: &str, order: &[String], forward: bool) -> Option<String> {
+    let last = order.len().checked_sub(1)?;
+    let index = match order.iter().position(|s| s.eq_ignore_ascii_case(current)) {
+        Some(i) if forward => (i + 1).min(last),
+        Some(i) => i.saturating_sub(1),
+        None if forward => last,
+        None => 0,
+    };
+    Some(order[index].clone())
+}
+
+/// The cycle order, falling back to [`DEFAULT_STANCES`].
+pub fn order_or_default(stances: &[String]) -> Vec<String> {
+    if stances.is_empty() {
+        DEFAULT_STANCES.iter().map(|s| s.to_string()).collect()
+    } else {
+        stances.to_vec()
+    }
+}
+
+/// Body posture from the status indicators, if the game has sent one.
+pub fn posture(status: &StatusInfo) -> Option<&'static str> {
+    if status.dead {
+        Some("dead")
+    } else if status.prone {
+        Some("prone")
+    } else if status.sitting {
+        Some("sitting")
+    } else if status.kneeling {
+        Some("kneeling")
+    } else if status.standing {
+        Some("standing")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_stance_bar_text() {
+        let mut stance = StanceInfo::default();
+        stance.update(80, 100, "Guarded (80%)");
+        assert_eq!(stance.name, "guarded");
+        assert_eq!(stance.percent, 80);
+        stance.update(0, 100, "offensive");
+        assert_eq!(stance.name, "offensive");
+        assert_eq!(stance.percent, 0);
+    }
+
+    #[test]
+    fn steps_clamp_at_the_ends() {
+        let order = order_or_default(&[]);
+        assert_eq!(step("neutral", &order, true).as_deref(), Some("guarded"));
+        assert_eq!(step("neutral", &order, false).as_deref(), Some("forward"));
+        assert_eq!(
+            step("defensive", &order, true).as_deref(),
+            Some("defensive")
+        );
+        assert_eq!(
+            step("offensive", &order, false).as_deref(),
+            Some("offensive")
+        );
+        assert_eq!(step("", &order, true).as_deref(), Some("defensive"));
+        assert_eq!(step("neutral", &[], true), None);
+    }
+
+    #[test]
+    fn posture_prefers_the_most_specific_indicator() {
+        let mut status = StatusInfo::default();
+        assert_eq!(posture(&status), None);
+        status.standing = true;
+        assert_eq!(posture(&status), Some("standing"));
+        status.prone = true;
+        assert_eq!(posture(&status), Some("prone"));
+    }
+}
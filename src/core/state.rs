@@ -6,6 +6,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 
+use super::bounty_parser::{self, BountyPhase, BountyTask};
+use super::society_tasks::{self, SocietyTask};
 use super::highlight_engine::SoundTrigger;
 
 /// How often to recalculate lag estimate (in seconds of game time)
@@ -70,6 +72,30 @@ pub struct GameState {
     /// Player status indicators
     pub status: StatusInfo,
 
+    /// Combat stance (from the stance dialog's pbarStance bar)
+    pub stance: crate::core::stance::StanceInfo,
+
+    /// Running silver estimate (wealth checks, pickups, sales, bank)
+    pub wealth: crate::core::wealth::WealthState,
+
+    /// Session combat analysis (your attacks, rolls, damage, kills)
+    pub combat: crate::core::combat::CombatStats,
+
+    /// Low-health / death alarm level and border flash
+    pub alarm: crate::core::alarm::AlarmState,
+
+    /// Ranked drops counted by the loot filter (`.loot`)
+    pub loot: crate::core::loot::LootTally,
+
+    /// Last whisper and thought senders, for `.r` / `.rt`
+    pub reply: crate::core::reply::ReplyTargets,
+
+    /// URLs seen in game text, for `.urls`
+    pub urls: crate::core::urls::RecentUrls,
+
+    /// Elanthian date/time, corrected by `TIME` checks
+    pub calendar: crate::data::game_time::GameCalendar,
+
     /// Vitals (health, mana, etc.)
     pub vitals: Vitals,
 
@@ -176,10 +202,129 @@ pub struct StatusInfo {
     pub hidden: bool,
     pub invisible: bool,
     pub webbed: bool,
+    pub poisoned: bool,
+    pub diseased: bool,
     pub joined: bool,
     pub dead: bool,
 }
 
+/// The status conditions shared by indicators, dashboards and the alarm.
+/// Ids are the parser's indicator ids (`IconSTUNNED` -> "STUNNED").
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatusEffect {
+    Stunned,
+    Webbed,
+    Prone,
+    Hidden,
+    Invisible,
+    Bleeding,
+    Poisoned,
+    Diseased,
+}
+
+impl StatusEffect {
+    pub const ALL: [StatusEffect; 8] = [
+        StatusEffect::Stunned,
+        StatusEffect::Webbed,
+        StatusEffect::Prone,
+        StatusEffect::Hidden,
+        StatusEffect::Invisible,
+        StatusEffect::Bleeding,
+        StatusEffect::Poisoned,
+        StatusEffect::Diseased,
+    ];
+
+    /// Indicator id as the game sends it (without the `Icon` prefix)
+    pub fn id(self) -> &'static str {
+        match self {
+            StatusEffect::Stunned => "STUNNED",
+            StatusEffect::Webbed => "WEBBED",
+            StatusEffect::Prone => "PRONE",
+            StatusEffect::Hidden => "HIDDEN",
+            StatusEffect::Invisible => "INVISIBLE",
+            StatusEffect::Bleeding => "BLEEDING",
+            StatusEffect::Poisoned => "POISONED",
+            StatusEffect::Diseased => "DISEASED",
+        }
+    }
+
+    /// Case-insensitive lookup by indicator id
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|effect| effect.id().eq_ignore_ascii_case(id))
+    }
+}
+
+impl StatusInfo {
+    pub fn has(&self, effect: StatusEffect) -> bool {
+        match effect {
+            StatusEffect::Stunned => self.stunned,
+            StatusEffect::Webbed => self.webbed,
+            StatusEffect::Prone => self.prone,
+            StatusEffect::Hidden => self.hidden,
+            StatusEffect::Invisible => self.invisible,
+            StatusEffect::Bleeding => self.bleeding,
+            StatusEffect::Poisoned => self.poisoned,
+            StatusEffect::Diseased => self.diseased,
+        }
+    }
+
+    pub fn set(&mut self, effect: StatusEffect, active: bool) {
+        let flag = match effect {
+            StatusEffect::Stunned => &mut self.stunned,
+            StatusEffect::Webbed => &mut self.webbed,
+            StatusEffect::Prone => &mut self.prone,
+            StatusEffect::Hidden => &mut self.hidden,
+            StatusEffect::Invisible => &mut self.invisible,
+            StatusEffect::Bleeding => &mut self.bleeding,
+            StatusEffect::Poisoned => &mut self.poisoned,
+            StatusEffect::Diseased => &mut self.diseased,
+        };
+        *flag = active;
+    }
+
+    /// Conditions currently in effect, in `StatusEffect::ALL` order
+    pub fn active_effects(&self) -> Vec<StatusEffect> {
+        StatusEffect::ALL
+            .into_iter()
+            .filter(|effect| self.has(*effect))
+            .collect()
+    }
+
+    /// Current state of any indicator id the status tracks, conditions and
+    /// posture alike; None for ids it doesn't know
+    pub fn indicator(&self, id: &str) -> Option<bool> {
+        if let Some(effect) = StatusEffect::from_id(id) {
+            return Some(self.has(effect));
+        }
+        Some(match id.to_ascii_uppercase().as_str() {
+            "STANDING" => self.standing,
+            "KNEELING" => self.kneeling,
+            "SITTING" => self.sitting,
+            "DEAD" => self.dead,
+            "JOINED" => self.joined,
+            _ => return None,
+        })
+    }
+
+    /// Dashboard values (0/1) for every indicator currently on, so a
+    /// dashboard created mid-session starts out matching the game
+    pub fn dashboard_values(&self) -> Vec<(String, u8)> {
+        let mut values: Vec<(String, u8)> = self
+            .active_effects()
+            .into_iter()
+            .map(|effect| (effect.id().to_string(), 1))
+            .collect();
+        for id in ["STANDING", "KNEELING", "SITTING", "DEAD", "JOINED"] {
+            if self.indicator(id) == Some(true) {
+                values.push((id.to_string(), 1));
+            }
+        }
+        values
+    }
+}
+
 /// Player vitals (percentages only)
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Vitals {
@@ -239,16 +384,57 @@ pub struct BountyState {
     pub raw_text: String,
     /// Parsed compact bounty lines (task, creature, location, etc.)
     pub compact_lines: Vec<String>,
+    /// Structured task for the task tracker; None if the text didn't parse
+    pub task: Option<BountyTask>,
+    /// Full count of the current counted task, remembered as it counts down
+    pub task_total: Option<u32>,
     /// Generation counter for change detection
     pub generation: u64,
 }
 
 impl BountyState {
     /// Update bounty state with new text. Always parses both raw and compact.
-    pub fn update(&mut self, raw_text: String, compact_lines: Vec<String>) {
+    ///
+    /// Returns true when this update finished the task (active → complete).
+    pub fn update(&mut self, raw_text: String, compact_lines: Vec<String>) -> bool {
+        let mut completed = false;
+        // Blank lines in the bounty stream leave the tracked task alone
+        if !raw_text.trim().is_empty() {
+            let task = bounty_parser::parse_task(&raw_text);
+            let prev = self.task.take();
+            self.task_total = match &task {
+                Some(t) => match t.remaining {
+                    Some(r) if t.count_is_total => Some(r),
+                    Some(r) => match &prev {
+                        Some(p) if p.key() == t.key() => Some(self.task_total.unwrap_or(r).max(r)),
+                        _ => Some(r),
+                    },
+                    // Keep the count around so a finished task reads "12/12"
+                    None if t.phase == BountyPhase::Complete => self.task_total,
+                    None => None,
+                },
+                None => None,
+            };
+            completed = prev.is_some_and(|p| p.phase == BountyPhase::Active)
+                && task
+                    .as_ref()
+                    .is_some_and(|t| t.phase == BountyPhase::Complete);
+            self.task = task;
+        }
         self.raw_text = raw_text;
         self.compact_lines = compact_lines;
         self.generation += 1;
+        completed
+    }
+
+    /// (done, total) for counted tasks, e.g. (6, 12) for "6/12 culled".
+    pub fn progress(&self) -> Option<(u32, u32)> {
+        let total = self.task_total?;
+        let remaining = match self.task.as_ref()? {
+            t if t.phase == BountyPhase::Complete => 0,
+            t => t.remaining?,
+        };
+        Some((total.saturating_sub(remaining), total))
     }
 
     /// Check if there's any bounty data
@@ -260,25 +446,80 @@ impl BountyState {
     pub fn clear(&mut self) {
         self.raw_text.clear();
         self.compact_lines.clear();
+        self.task = None;
+        self.task_total = None;
         self.generation += 1;
     }
 }
 
-/// Society state - stores society stream text for reload
-/// Similar to bounty caching but without parsing (just stores lines)
+/// Society state - stores society stream text for reload, split into
+/// status lines and tasks for the society task checklist
 #[derive(Clone, Debug, Default)]
 pub struct SocietyState {
     /// Lines from society stream
     pub lines: Vec<String>,
+    /// Short name of the society ("Voln"), if the text names it
+    pub society: Option<&'static str>,
+    /// Lines that aren't tasks (rank, step)
+    pub status: Vec<String>,
+    /// Task lines, in stream order
+    pub tasks: Vec<SocietyTask>,
+    /// Full count per task key, remembered as the text counts down
+    totals: HashMap<String, u32>,
     /// Generation counter for change detection
     pub generation: u64,
 }
 
 impl SocietyState {
-    /// Update society state with new lines
-    pub fn update(&mut self, lines: Vec<String>) {
+    /// Update society state with new lines.
+    ///
+    /// Returns the tasks this update finished (not complete before, complete
+    /// now). The first report after login never counts as finishing one.
+    pub fn update(&mut self, lines: Vec<String>) -> Vec<SocietyTask> {
+        let report = society_tasks::parse(&lines);
+        let was_done: HashMap<String, bool> = self
+            .tasks
+            .iter()
+            .map(|task| (task.key(), task.complete))
+            .collect();
+        let mut totals = HashMap::new();
+        for task in &report.tasks {
+            let key = task.key();
+            let previous = self.totals.get(&key).copied();
+            let total = match (task.total, task.remaining) {
+                (Some(total), _) => Some(total),
+                (None, Some(remaining)) => Some(previous.unwrap_or(remaining).max(remaining)),
+                // Keep the count so a finished task reads "12/12"
+                (None, None) => previous,
+            };
+            if let Some(total) = total {
+                totals.insert(key, total);
+            }
+        }
+        let finished = if self.tasks.is_empty() {
+            Vec::new()
+        } else {
+            report
+                .tasks
+                .iter()
+                .filter(|task| task.complete && was_done.get(&task.key()) != Some(&true))
+                .cloned()
+                .collect()
+        };
+        self.totals = totals;
+        self.society = report.society.or(self.society);
+        self.status = report.status;
+        self.tasks = report.tasks;
         self.lines = lines;
         self.generation += 1;
+        finished
+    }
+
+    /// (done, total) for a counted task, e.g. (6, 12)
+    pub fn progress(&self, task: &SocietyTask) -> Option<(u32, u32)> {
+        let total = *self.totals.get(&task.key())?;
+        let remaining = if task.complete { 0 } else { task.remaining? };
+        Some((total.saturating_sub(remaining), total))
     }
 
     /// Add a single line
@@ -295,6 +536,10 @@ impl SocietyState {
     /// Clear society data
     pub fn clear(&mut self) {
         self.lines.clear();
+        self.society = None;
+        self.status.clear();
+        self.tasks.clear();
+        self.totals.clear();
         self.generation += 1;
     }
 }
@@ -518,10 +763,41 @@ pub struct ContainerData {
     pub title_lower: String,
     /// Items in the container (raw content lines with links preserved)
     pub items: Vec<String>,
+    /// Remembered value note per item, parallel to `items` (see data::itemdb)
+    pub notes: Vec<Option<String>>,
+    /// Most items it holds, when game text has told us (see core::containers)
+    pub capacity: Option<usize>,
     /// Generation counter for change detection
     pub generation: u64,
 }
 
+impl ContainerData {
+    /// Whether an item line is a real item rather than a header like
+    /// "In the bandolier:" or "nothing"
+    pub fn is_item_line(line: &str) -> bool {
+        let trimmed = line.trim().to_lowercase();
+        !(trimmed.starts_with("in the ") || trimmed == "nothing" || trimmed.is_empty())
+    }
+
+    /// Number of items, not counting header lines
+    pub fn item_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|line| Self::is_item_line(line))
+            .count()
+    }
+
+    /// Title suffix: "(empty)", "[12]", or "[12/20]" once the capacity is
+    /// known
+    pub fn count_label(&self) -> String {
+        match (self.item_count(), self.capacity) {
+            (count, Some(capacity)) => format!("[{}/{}]", count, capacity),
+            (0, None) => "(empty)".to_string(),
+            (count, None) => format!("[{}]", count),
+        }
+    }
+}
+
 impl TargetListState {
     /// Clear the current target
     pub fn clear(&mut self) {
@@ -674,6 +950,8 @@ pub struct GS4ExperienceState {
     pub lumnis: Option<u8>,
     /// RPA bonus multiplier (can be fractional); only present while active
     pub rpa: Option<f32>,
+    /// Field experience drain, for the time-until-clear readout
+    pub absorb: crate::core::exp_eta::AbsorbTracker,
     /// Generation counter for change detection
     pub generation: u64,
 }
@@ -692,6 +970,11 @@ impl GS4ExperienceState {
 
     /// Update mind state, returns true if changed
     pub fn update_mind_state(&mut self, value: u32, text: String) -> bool {
+        // Without the exact feed the percentage is the best measure of drain
+        if self.field_exp.is_none() {
+            self.absorb
+                .observe(value as u64, false, std::time::Instant::now());
+        }
         if self.mind_state_value != value || self.mind_state_text != text {
             self.mind_state_value = value;
             self.mind_state_text = text;
@@ -730,6 +1013,9 @@ impl GS4ExperienceState {
         lumnis: Option<u8>,
         rpa: Option<f32>,
     ) -> bool {
+        if let Some(field) = field_exp {
+            self.absorb.observe(field, true, std::time::Instant::now());
+        }
         let mut changed = false;
         for (field, incoming) in [
             (&mut self.field_exp, field_exp),
@@ -776,6 +1062,7 @@ impl GS4ExperienceState {
         self.fashlonae = None;
         self.lumnis = None;
         self.rpa = None;
+        self.absorb = Default::default();
         self.generation += 1;
     }
 }
@@ -902,6 +1189,8 @@ impl ContainerCache {
                     title_lower: title.to_lowercase(),
                     title,
                     items: Vec::new(),
+                    notes: Vec::new(),
+                    capacity: None,
                     generation: 0,
                 },
             );
@@ -924,14 +1213,16 @@ impl ContainerCache {
     pub fn clear_container(&mut self, id: &str) {
         if let Some(container) = self.containers.get_mut(id) {
             container.items.clear();
+            container.notes.clear();
             container.generation += 1;
         }
     }
 
     /// Add an item to a container
-    pub fn add_item(&mut self, container_id: &str, content: String) {
+    pub fn add_item(&mut self, container_id: &str, content: String, note: Option<String>) {
         if let Some(container) = self.containers.get_mut(container_id) {
             container.items.push(content);
+            container.notes.push(note);
             container.generation += 1;
         } else {
             // Container not registered yet - create it with unknown title
@@ -942,6 +1233,8 @@ impl ContainerCache {
                 title: String::new(),
                 title_lower: String::new(),
                 items: vec![content],
+                notes: vec![note],
+                capacity: None,
                 generation: 1,
             };
             self.containers.insert(container_id.to_string(), container);
@@ -977,6 +1270,64 @@ impl ContainerCache {
         None
     }
 
+    /// The container holding the item with this exist id, and the item's
+    /// link
+    pub fn find_item(&self, exist_id: &str) -> Option<(&ContainerData, crate::data::LinkData)> {
+        if exist_id.is_empty() {
+            return None;
+        }
+        self.containers.values().find_map(|container| {
+            container
+                .items
+                .iter()
+                .filter(|line| line.contains(exist_id))
+                .flat_map(|line| crate::core::containers::parse_item_line(line))
+                .filter_map(|segment| segment.link_data)
+                .find(|link| link.exist_id == exist_id)
+                .map(|link| (container, link))
+        })
+    }
+
+    /// Record what game text said about a container's capacity. The
+    /// container is matched by the name in the text, then by its last word
+    /// (the noun). Returns true if a known container changed.
+    pub fn apply_capacity(&mut self, event: crate::core::containers::CapacityEvent) -> bool {
+        use crate::core::containers::CapacityEvent;
+
+        let (name, capacity) = match &event {
+            CapacityEvent::Holds {
+                container,
+                capacity,
+            } => (container, Some(*capacity)),
+            CapacityEvent::Full { container } => (container, None),
+        };
+        let noun = name.rsplit(' ').next().unwrap_or(name);
+        let Some(id) = self
+            .find_by_title(name)
+            .or_else(|| self.find_by_title(noun))
+            .map(|container| container.id.clone())
+        else {
+            return false;
+        };
+        let Some(container) = self.containers.get_mut(&id) else {
+            return false;
+        };
+        // Refused at its current count: that's as many as it holds
+        let capacity = match capacity {
+            Some(capacity) => capacity,
+            None => match container.item_count() {
+                0 => return false,
+                count => count,
+            },
+        };
+        if container.capacity == Some(capacity) {
+            return false;
+        }
+        container.capacity = Some(capacity);
+        container.generation += 1;
+        true
+    }
+
     /// Get all known containers sorted by title
     pub fn list_containers(&self) -> Vec<&ContainerData> {
         let mut containers: Vec<_> = self.containers.values().collect();
@@ -999,6 +1350,14 @@ impl GameState {
             spell: None,
             active_streams: HashMap::new(),
             status: StatusInfo::default(),
+            stance: Default::default(),
+            wealth: Default::default(),
+            combat: Default::default(),
+            alarm: Default::default(),
+            loot: Default::default(),
+            reply: Default::default(),
+            urls: Default::default(),
+            calendar: Default::default(),
             vitals: Vitals::default(),
             inventory: Vec::new(),
             left_hand: None,
@@ -1130,6 +1489,63 @@ impl Default for Vitals {
 mod tests {
     use super::*;
 
+    // ========== BountyState task tracking ==========
+
+    #[test]
+    fn test_bounty_progress_counts_down_and_flags_completion() {
+        let culling = |count: &str| {
+            format!("You have been tasked to suppress triton fanatic activity in the Atoll near Kraken's Fall. You need to kill {count} of them to complete your task.")
+        };
+        let mut bounty = BountyState::default();
+        assert!(!bounty.update(culling("12"), Vec::new()));
+        assert_eq!(bounty.progress(), Some((0, 12)));
+        assert!(!bounty.update(culling("6 more"), Vec::new()));
+        assert!(!bounty.update(String::new(), Vec::new()));
+        assert_eq!(bounty.progress(), Some((6, 12)));
+
+        let done = "You have succeeded in your task and can return to the Adventurer's Guild to receive your reward.";
+        assert!(bounty.update(done.to_string(), Vec::new()));
+        assert_eq!(bounty.progress(), Some((12, 12)));
+        assert!(
+            !bounty.update(done.to_string(), Vec::new()),
+            "only the transition notifies"
+        );
+    }
+
+    // ========== SocietyState task tracking ==========
+
+    #[test]
+    fn test_society_task_counts_down_and_flags_completion() {
+        let report = |task: &str| {
+            vec![
+                "You are a Master in the Order of Voln at step 12.".to_string(),
+                task.to_string(),
+            ]
+        };
+        let mut society = SocietyState::default();
+        let first = society.update(report(
+            "You have been instructed to release 12 undead souls.",
+        ));
+        assert!(first.is_empty());
+        assert_eq!(society.society, Some("Voln"));
+        assert_eq!(society.progress(&society.tasks[0]), Some((0, 12)));
+
+        assert!(society
+            .update(report(
+                "You have been instructed to release 4 more undead souls."
+            ))
+            .is_empty());
+        assert_eq!(society.progress(&society.tasks[0]), Some((8, 12)));
+
+        let done = "You have been instructed to release 0 more undead souls.";
+        assert_eq!(society.update(report(done)).len(), 1);
+        assert_eq!(society.progress(&society.tasks[0]), Some((12, 12)));
+        assert!(
+            society.update(report(done)).is_empty(),
+            "only the transition notifies"
+        );
+    }
+
     // ========== Creature::is_body_part tests ==========
 
     fn body_part_creature(name: &str, noun: Option<&str>) -> Creature {
@@ -1188,7 +1604,7 @@ mod tests {
             cache.register_container(format!("id{}", i), String::new());
         }
         // add_item to an unregistered container also creates an entry
-        cache.add_item("overflow", "a coin".to_string());
+        cache.add_item("overflow", "a coin".to_string(), None);
         assert_eq!(cache.containers.len(), MAX_CONTAINERS);
         assert!(cache.get("id0").is_none());
         assert!(cache.get("overflow").is_some());
@@ -1220,6 +1636,52 @@ mod tests {
         assert_eq!(cache.get("id0").unwrap().title, "new title");
     }
 
+    #[test]
+    fn test_container_cache_capacity_from_game_text() {
+        use crate::core::containers::CapacityEvent;
+
+        let mut cache = ContainerCache::default();
+        cache.register_container("1".to_string(), "Backpack".to_string());
+        cache.add_item("1", "In the backpack:".to_string(), None);
+        cache.add_item("1", "a gem".to_string(), None);
+        cache.add_item("1", "a box".to_string(), None);
+        assert_eq!(cache.get("1").unwrap().count_label(), "[2]");
+
+        // Refused with two items: it holds two
+        let full = CapacityEvent::Full {
+            container: "black leather backpack".to_string(),
+        };
+        assert!(cache.apply_capacity(full.clone()));
+        assert!(!cache.apply_capacity(full));
+        assert_eq!(cache.get("1").unwrap().count_label(), "[2/2]");
+
+        assert!(cache.apply_capacity(CapacityEvent::Holds {
+            container: "backpack".to_string(),
+            capacity: 20,
+        }));
+        cache.clear_container("1");
+        assert_eq!(cache.get("1").unwrap().count_label(), "[0/20]");
+        assert!(!cache.apply_capacity(CapacityEvent::Holds {
+            container: "cloak".to_string(),
+            capacity: 5,
+        }));
+    }
+
+    #[test]
+    fn test_container_cache_find_item() {
+        let mut cache = ContainerCache::default();
+        cache.register_container("1".to_string(), "Backpack".to_string());
+        cache.add_item(
+            "1",
+            r#"a <a exist="123" noun="gem">blue gem</a>"#.to_string(),
+            None,
+        );
+        let (container, link) = cache.find_item("123").unwrap();
+        assert_eq!(container.id, "1");
+        assert_eq!(link.text, "blue gem");
+        assert!(cache.find_item("12").is_none());
+    }
+
     // ========== GameState tests ==========
 
     #[test]
@@ -1645,4 +2107,42 @@ mod tests {
         assert_eq!(exp.field_exp, None);
         assert_eq!(exp.exp, None);
     }
+
+    // ========== StatusInfo / StatusEffect ==========
+
+    #[test]
+    fn test_status_effects_set_by_indicator_id() {
+        let mut status = StatusInfo::default();
+        for id in ["poisoned", "DISEASED", "Hidden"] {
+            status.set(StatusEffect::from_id(id).unwrap(), true);
+        }
+        assert!(status.poisoned && status.diseased && status.hidden);
+        assert_eq!(
+            status.active_effects(),
+            [
+                StatusEffect::Hidden,
+                StatusEffect::Poisoned,
+                StatusEffect::Diseased
+            ]
+        );
+        assert_eq!(StatusEffect::from_id("KNEELING"), None);
+
+        status.kneeling = true;
+        assert_eq!(status.indicator("kneeling"), Some(true));
+        assert_eq!(status.indicator("STUNNED"), Some(false));
+        assert_eq!(status.indicator("FOO"), None);
+        let values = status.dashboard_values();
+        assert_eq!(values.len(), 4);
+        assert!(values.contains(&("KNEELING".to_string(), 1)));
+    }
+
+    #[test]
+    fn test_status_effect_pack_covers_every_effect() {
+        let ids: Vec<String> = crate::config::Config::status_effect_indicators()
+            .into_iter()
+            .map(|def| def.id)
+            .collect();
+        let expected: Vec<&str> = StatusEffect::ALL.iter().map(|e| e.id()).collect();
+        assert_eq!(ids, expected);
+    }
 }
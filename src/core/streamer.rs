@@ -0,0 +1,151 @@
+//! Streamer mode: swap real names for aliases in displayed text.
+//!
+//! With `[streamer] enabled = true`, the message pipeline runs every line,
+//! room description and player name through an `Anonymizer` after
+//! highlights are applied, so highlights keyed on your name still fire.
+//! Only display text changes: link data keeps the real nouns and ids, and
+//! commands you send are never rewritten.
+//!
+//! Names match as whole words. Character and friend names match
+//! case-sensitively (the game capitalizes them, and a friend called "Will"
+//! shouldn't hide every "will"); the account name matches in any case.
+
+use crate::config::StreamerConfig;
+use crate::data::TextSegment;
+use regex::Regex;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default)]
+pub struct Anonymizer {
+    character: Option<String>,
+    /// One alternation over every hidden name, longest first
+    pattern: Option<Regex>,
+    /// Exact name -> alias
+    aliases: HashMap<String, String>,
+    /// Lowercased account name and its alias
+    account: Option<(String, String)>,
+}
+
+impl Anonymizer {
+    /// Build from config; a disabled config hides nothing. `character` is
+    /// kept so the anonymizer can be rebuilt on config reload.
+    pub fn new(config: &StreamerConfig, account: Option<&str>, character: Option<&str>) -> Self {
+        let character = character.map(str::to_string);
+        if !config.enabled {
+            return Self {
+                character,
+                ..Default::default()
+            };
+        }
+
+        let mut aliases: HashMap<String, String> = config
+            .names
+            .iter()
+            .filter(|(name, _)| name.len() >= 2)
+            .map(|(name, alias)| (name.clone(), alias.clone()))
+            .collect();
+        if let Some(name) = character.as_deref().filter(|name| name.len() >= 2) {
+            aliases.insert(name.to_string(), config.character_alias.clone());
+        }
+        let account = account
+            .filter(|name| name.len() >= 2)
+            .map(|name| (name.to_lowercase(), config.account_alias.clone()));
+
+        let mut names: Vec<String> = aliases.keys().map(|name| regex::escape(name)).collect();
+        if let Some((name, _)) = &account {
+            names.push(format!("(?i:{})", regex::escape(name)));
+        }
+        // Longest first so "Bobby" isn't matched as "Bob" + "by"
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+        let pattern = if names.is_empty() {
+            None
+        } else {
+            Regex::new(&format!(r"\b(?:{})\b", names.join("|"))).ok()
+        };
+
+        Self {
+            character,
+            pattern,
+            aliases,
+            account,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.pattern.is_some()
+    }
+
+    /// The character name this anonymizer was built with
+    pub fn character(&self) -> Option<&str> {
+        self.character.as_deref()
+    }
+
+    /// `text` with every hidden name replaced by its alias
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let Some(pattern) = &self.pattern else {
+            return Cow::Borrowed(text);
+        };
+        pattern.replace_all(text, |caps: &regex::Captures| {
+            let name = &caps[0];
+            match self.aliases.get(name) {
+                Some(alias) => alias.clone(),
+                None => match &self.account {
+                    Some((account, alias)) if name.to_lowercase() == *account => alias.clone(),
+                    _ => name.to_string(),
+                },
+            }
+        })
+    }
+
+    /// Anonymize segment text in place; styling and link data are kept
+    pub fn apply_segments(&self, segments: &mut [TextSegment]) {
+        if !self.is_enabled() {
+            return;
+        }
+        for segment in segments {
+            if let Cow::Owned(text) = self.apply(&segment.text) {
+                segment.text = text;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> StreamerConfig {
+        StreamerConfig {
+            enabled: true,
+            names: HashMap::from([("Carol".to_string(), "Friend".to_string())]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn replaces_whole_names_only() {
+        let anonymizer = Anonymizer::new(&config(), Some("bobsacct"), Some("Bob"));
+        assert_eq!(
+            anonymizer.apply(r#"Carol says, "Hi Bob, Bobby's here.""#),
+            r#"Friend says, "Hi Adventurer, Bobby's here.""#
+        );
+        assert_eq!(
+            anonymizer.apply("Account BOBSACCT logged in as Bob's alt."),
+            "Account Player logged in as Adventurer's alt."
+        );
+        assert!(matches!(anonymizer.apply("carol waves."), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn disabled_hides_nothing_but_keeps_character() {
+        let anonymizer = Anonymizer::new(&StreamerConfig::default(), None, Some("Bob"));
+        assert!(!anonymizer.is_enabled());
+        assert_eq!(anonymizer.character(), Some("Bob"));
+        assert_eq!(anonymizer.apply("Bob waves."), "Bob waves.");
+
+        let mut segments = vec![TextSegment::plain("Carol"), TextSegment::plain(" nods.")];
+        Anonymizer::new(&config(), None, None).apply_segments(&mut segments);
+        assert_eq!(segments[0].text, "Friend");
+    }
+}
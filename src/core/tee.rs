@@ -0,0 +1,184 @@
+//! Tee: append a plain-text copy of every line a window receives to a file,
+//! as it arrives, so external tools can tail it (`tail -f`, screen readers,
+//! bots).
+//!
+//! A window tees when its `tee_file` is set (window editor or `.tee`).
+//! AppCore re-syncs the targets from the layout whenever it changes; the
+//! message pipeline calls `TeeSet::write` as it adds a line to a window.
+//! Relative file names land in the `tee/` folder of the profile's log
+//! directory (`Config::tee_dir`).
+//!
+//! Layouts get shared and downloaded, so a layout's `tee_file` has to stay
+//! inside the tee directory: an absolute path or `..` there would let a
+//! layout append game text (other players' speech included) to any file.
+//! Only a `.tee` typed locally can point anywhere, and that lasts for the
+//! session without being saved to the layout.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Whether `file` stays under the tee directory: relative, with no `..`
+pub fn is_contained(file: &str) -> bool {
+    use std::path::Component;
+    let path = Path::new(file);
+    !path.has_root()
+        && path
+            .components()
+            .all(|part| matches!(part, Component::Normal(_) | Component::CurDir))
+}
+
+/// Where a layout's `tee_file` writes, under `tee_dir`. Absolute paths
+/// and `..` are refused (see the module docs).
+pub fn resolve_layout_path(file: &str, tee_dir: &Path) -> Result<PathBuf> {
+    if !is_contained(file) {
+        anyhow::bail!(
+            "layouts can only tee into the tee folder ('{}' leaves it); use .tee for other paths",
+            file
+        );
+    }
+    Ok(tee_dir.join(file))
+}
+
+/// Where a `.tee` typed by the user writes: absolute paths as given,
+/// relative ones under `tee_dir`
+pub fn resolve_path(file: &str, tee_dir: &Path) -> PathBuf {
+    tee_dir.join(file)
+}
+
+fn open_append(path: &Path) -> Result<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Could not create {}", parent.display()))?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Could not open {}", path.display()))
+}
+
+struct Tee {
+    path: PathBuf,
+    /// None once a write failed; the tee stays off until re-synced
+    file: Option<File>,
+}
+
+/// Open tee files, keyed by lowercased window name
+#[derive(Default)]
+pub struct TeeSet {
+    tees: HashMap<String, Tee>,
+}
+
+impl TeeSet {
+    pub fn is_empty(&self) -> bool {
+        self.tees.is_empty()
+    }
+
+    /// Replace the targets with `targets` (window name, file path). Files
+    /// already open for the same window and path stay open; the rest are
+    /// closed or opened. Returns the targets that could not be opened.
+    pub fn sync(
+        &mut self,
+        targets: impl IntoIterator<Item = (String, PathBuf)>,
+    ) -> Vec<(String, String)> {
+        let mut old = std::mem::take(&mut self.tees);
+        let mut failures = Vec::new();
+        for (window, path) in targets {
+            let key = window.to_lowercase();
+            match old.remove(&key) {
+                Some(tee) if tee.path == path && tee.file.is_some() => {
+                    self.tees.insert(key, tee);
+                }
+                _ => match open_append(&path) {
+                    Ok(file) => {
+                        tracing::info!("Teeing window '{}' to {:?}", window, path);
+                        self.tees.insert(
+                            key,
+                            Tee {
+                                path,
+                                file: Some(file),
+                            },
+                        );
+                    }
+                    Err(e) => failures.push((window, format!("{:#}", e))),
+                },
+            }
+        }
+        failures
+    }
+
+    /// The file `window` tees to, if any
+    pub fn path_for(&self, window: &str) -> Option<&Path> {
+        self.tees
+            .get(&window.to_lowercase())
+            .map(|tee| tee.path.as_path())
+    }
+
+    /// Append `text` as one line to `window`'s tee file, if it has one.
+    /// Written straight through (no buffering) so tailing tools see each
+    /// line as it arrives.
+    pub fn write(&mut self, window: &str, text: &str) {
+        let Some(tee) = self.tees.get_mut(&window.to_lowercase()) else {
+            return;
+        };
+        let Some(file) = tee.file.as_mut() else {
+            return;
+        };
+        if let Err(e) = writeln!(file, "{}", text) {
+            tracing::warn!("Tee to {:?} failed, stopping it: {}", tee.path, e);
+            tee.file = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_and_write_appends_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sub").join("thoughts.txt");
+        let mut tees = TeeSet::default();
+        assert!(tees
+            .sync([("Thoughts".to_string(), path.clone())])
+            .is_empty());
+
+        tees.write("thoughts", "[General] Hello there");
+        tees.write("main", "not teed");
+        // Re-syncing the same target keeps the file open and appending
+        tees.sync([("thoughts".to_string(), path.clone())]);
+        tees.write("THOUGHTS", "second line");
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "[General] Hello there\nsecond line\n"
+        );
+
+        tees.sync(Vec::new());
+        assert!(tees.is_empty());
+        assert!(tees.path_for("thoughts").is_none());
+    }
+
+    #[test]
+    fn test_layout_paths_stay_in_the_tee_dir() {
+        assert!(is_contained("thoughts.txt"));
+        assert!(is_contained("./logs/thoughts.txt"));
+        assert!(!is_contained("../.bashrc"));
+        assert!(!is_contained("logs/../../.bashrc"));
+        assert!(!is_contained("/home/me/.bashrc"));
+        let dir = Path::new("/profile/logs/tee");
+        assert_eq!(
+            resolve_layout_path("thoughts.txt", dir).unwrap(),
+            dir.join("thoughts.txt")
+        );
+        assert!(resolve_layout_path("/home/me/.bashrc", dir).is_err());
+        assert!(resolve_layout_path("../x", dir).is_err());
+        assert_eq!(
+            resolve_path("/home/me/thoughts.txt", dir),
+            PathBuf::from("/home/me/thoughts.txt")
+        );
+    }
+}
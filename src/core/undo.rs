@@ -0,0 +1,120 @@
+//! Undo for destructive actions in the config browsers.
+//!
+//! Each browser (highlights, keybinds, palette colors) keeps its own
+//! `UndoStack` of `BrowserEdit`s. An edit carries everything needed to put
+//! the entry back exactly where it was — the full definition and which file
+//! (global or character) it came from; `AppCore::undo_browser_edit` writes
+//! it back and refreshes the live config.
+
+use crate::config::{HighlightPattern, KeyBindAction, PaletteColor};
+
+/// Oldest entries are dropped past this many
+const MAX_STEPS: usize = 50;
+
+/// An action that can be undone, described for the "Undid ..." toast
+pub trait Undoable {
+    fn describe(&self) -> String;
+}
+
+/// Bounded last-in-first-out stack of undoable actions
+#[derive(Debug)]
+pub struct UndoStack<T> {
+    entries: Vec<T>,
+}
+
+impl<T> Default for UndoStack<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T: Undoable> UndoStack<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, action: T) {
+        self.entries.push(action);
+        if self.entries.len() > MAX_STEPS {
+            self.entries.remove(0);
+        }
+    }
+
+    /// Most recent action, removed from the stack
+    pub fn pop(&mut self) -> Option<T> {
+        self.entries.pop()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// A destructive browser action, with what's needed to reverse it
+#[derive(Debug, Clone)]
+pub enum BrowserEdit {
+    DeletedHighlight {
+        name: String,
+        pattern: Box<HighlightPattern>,
+        is_global: bool,
+    },
+    DeletedKeybind {
+        key: String,
+        action: KeyBindAction,
+        is_global: bool,
+    },
+    DeletedColor {
+        color: PaletteColor,
+        is_global: bool,
+    },
+}
+
+impl Undoable for BrowserEdit {
+    fn describe(&self) -> String {
+        match self {
+            BrowserEdit::DeletedHighlight { name, .. } => {
+                format!("delete of highlight '{}'", name)
+            }
+            BrowserEdit::DeletedKeybind { key, .. } => format!("delete of keybind '{}'", key),
+            BrowserEdit::DeletedColor { color, .. } => {
+                format!("delete of color '{}'", color.name)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Step(usize);
+
+    impl Undoable for Step {
+        fn describe(&self) -> String {
+            format!("step {}", self.0)
+        }
+    }
+
+    #[test]
+    fn stack_pops_newest_first_and_drops_oldest_past_limit() {
+        let mut stack = UndoStack::new();
+        assert!(stack.pop().is_none());
+        for i in 0..MAX_STEPS + 5 {
+            stack.push(Step(i));
+        }
+        assert_eq!(stack.len(), MAX_STEPS);
+        assert_eq!(stack.pop().unwrap().describe(), "step 54");
+        let mut oldest = None;
+        while let Some(step) = stack.pop() {
+            oldest = Some(step.0);
+        }
+        assert_eq!(oldest, Some(5));
+        assert!(stack.is_empty());
+    }
+}
@@ -0,0 +1,245 @@
+//! Clickable URLs in game text.
+//!
+//! The message pipeline turns every http(s) URL in a line into a link span
+//! whose click runs `.url open <url>`, and reports the URLs so AppCore can
+//! keep the recent ones in GameState.urls for `.urls` and the `list_urls`
+//! keybind. Opening honors `[urls]`: hosts on the allow list open right
+//! away, anything else waits for `.url yes` while `confirm` is on.
+
+use crate::config::UrlsConfig;
+use crate::data::{LinkData, SpanType, TextSegment};
+use regex::Regex;
+use std::collections::VecDeque;
+use std::sync::LazyLock;
+
+/// How many URLs `.urls` remembers
+const MAX_RECENT: usize = 20;
+
+static RE_URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\bhttps?://[^\s<>"'`]+"#).unwrap());
+
+/// Byte ranges of the URLs in `text`. Trailing sentence punctuation and an
+/// unbalanced closing paren ("(see https://x.y/z)") stay out of the URL.
+pub fn find_urls(text: &str) -> Vec<std::ops::Range<usize>> {
+    RE_URL
+        .find_iter(text)
+        .map(|found| {
+            let url = found.as_str();
+            let mut end = url.len();
+            loop {
+                let trimmed = &url[..end];
+                match trimmed.chars().last() {
+                    Some('.' | ',' | ';' | ':' | '!' | '?') => end -= 1,
+                    Some(')') if trimmed.matches('(').count() < trimmed.matches(')').count() => {
+                        end -= 1
+                    }
+                    _ => break,
+                }
+            }
+            found.start()..found.start() + end
+        })
+        .filter(|range| host(&text[range.clone()]).is_some())
+        .collect()
+}
+
+/// Split URLs in non-link segments out into link segments. Returns the
+/// URLs found, in order.
+pub fn linkify(segments: &mut Vec<TextSegment>) -> Vec<String> {
+    if !segments
+        .iter()
+        .any(|segment| segment.link_data.is_none() && segment.text.contains("://"))
+    {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    let mut out = Vec::with_capacity(segments.len());
+    for segment in segments.drain(..) {
+        let ranges = if segment.link_data.is_none() {
+            find_urls(&segment.text)
+        } else {
+            Vec::new()
+        };
+        if ranges.is_empty() {
+            out.push(segment);
+            continue;
+        }
+
+        let mut pos = 0;
+        for range in ranges {
+            if range.start > pos {
+                out.push(TextSegment {
+                    text: segment.text[pos..range.start].to_string(),
+                    ..segment.clone()
+                });
+            }
+            let url = segment.text[range.clone()].to_string();
+            out.push(TextSegment {
+                text: url.clone(),
+                // Drawn in the link color, like game links
+                fg: None,
+                span_type: SpanType::Link,
+                link_data: Some(LinkData {
+                    exist_id: "_direct_".to_string(),
+                    noun: format!(".url open {}", url),
+                    text: url.clone(),
+                    coord: None,
+                }),
+                ..segment.clone()
+            });
+            found.push(url);
+            pos = range.end;
+        }
+        if pos < segment.text.len() {
+            out.push(TextSegment {
+                text: segment.text[pos..].to_string(),
+                ..segment
+            });
+        }
+    }
+    *segments = out;
+    found
+}
+
+/// The lowercased host of `url`, without port or credentials. Browsers
+/// read `\` as `/`, so it ends the authority too; a host with anything but
+/// letters, digits, `-` and `.` left over is None rather than a guess.
+pub fn host(url: &str) -> Option<String> {
+    let rest = url.split_once("://")?.1;
+    let authority = rest.split(['/', '\\', '?', '#']).next()?;
+    let authority = authority.rsplit('@').next()?;
+    let host = authority.split(':').next()?.trim_end_matches('.');
+    let valid = host
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '.');
+    (!host.is_empty() && valid).then(|| host.to_ascii_lowercase())
+}
+
+/// Whether `url` opens without asking: its host (or a parent domain) is on
+/// the allow list, or confirmation is off
+pub fn opens_without_confirm(url: &str, config: &UrlsConfig) -> bool {
+    if !config.confirm {
+        return true;
+    }
+    let Some(host) = host(url) else {
+        return false;
+    };
+    config.allow.iter().any(|allowed| {
+        let allowed = allowed.trim().trim_start_matches("*.").to_ascii_lowercase();
+        !allowed.is_empty()
+            && (host == allowed
+                || host
+                    .strip_suffix(&allowed)
+                    .is_some_and(|sub| sub.ends_with('.')))
+    })
+}
+
+/// Recently seen URLs, newest first, and one awaiting `.url yes`
+#[derive(Debug, Clone, Default)]
+pub struct RecentUrls {
+    urls: VecDeque<String>,
+    pub pending: Option<String>,
+}
+
+impl RecentUrls {
+    /// Remember a URL; seeing it again moves it back to the front
+    pub fn push(&mut self, url: String) {
+        self.urls.retain(|known| *known != url);
+        self.urls.push_front(url);
+        self.urls.truncate(MAX_RECENT);
+    }
+
+    /// The `n`th most recent URL, counting from 1
+    pub fn get(&self, n: usize) -> Option<&str> {
+        n.checked_sub(1)
+            .and_then(|i| self.urls.get(i))
+            .map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.urls.iter().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(text: &str) -> Vec<&str> {
+        find_urls(text).into_iter().map(|r| &text[r]).collect()
+    }
+
+    #[test]
+    fn finds_urls_without_trailing_punctuation() {
+        assert_eq!(
+            urls("See https://gswiki.play.net/Rings_of_Lumnis. Or (http://x.io/a_(b))!"),
+            [
+                "https://gswiki.play.net/Rings_of_Lumnis",
+                "http://x.io/a_(b)"
+            ]
+        );
+        assert!(urls("ftp://nope and https:// alone").is_empty());
+    }
+
+    #[test]
+    fn linkify_splits_segments_into_links() {
+        let mut segments = vec![TextSegment::styled(
+            "Vote at https://play.net/vote today",
+            Some("#ffffff".into()),
+            true,
+        )];
+        let found = linkify(&mut segments);
+        assert_eq!(found, ["https://play.net/vote"]);
+        let texts: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["Vote at ", "https://play.net/vote", " today"]);
+        assert_eq!(segments[1].span_type, SpanType::Link);
+        assert!(segments[1].fg.is_none() && segments[1].bold);
+        assert_eq!(
+            segments[1].link_data.as_ref().unwrap().noun,
+            ".url open https://play.net/vote"
+        );
+        assert_eq!(segments[2].fg.as_deref(), Some("#ffffff"));
+    }
+
+    #[test]
+    fn allow_list_matches_host_and_subdomains() {
+        let config = UrlsConfig {
+            allow: vec!["play.net".into()],
+            ..Default::default()
+        };
+        assert!(opens_without_confirm("https://gswiki.play.net/x", &config));
+        assert!(opens_without_confirm("https://user@PLAY.net:443/", &config));
+        assert!(!opens_without_confirm("https://notplay.net/", &config));
+        assert!(!opens_without_confirm("https://play.net.evil.io/", &config));
+        // Browsers go to evil.example: `\` ends the authority there
+        assert_eq!(
+            host("https://evil.example\\@gswiki.play.net/").as_deref(),
+            Some("evil.example")
+        );
+        assert!(!opens_without_confirm(
+            "https://evil.example\\@gswiki.play.net/",
+            &config
+        ));
+        let open = UrlsConfig {
+            confirm: false,
+            ..config
+        };
+        assert!(opens_without_confirm("https://anything.example/", &open));
+    }
+
+    #[test]
+    fn recent_urls_dedupe_newest_first() {
+        let mut recent = RecentUrls::default();
+        recent.push("https://a.io".into());
+        recent.push("https://b.io".into());
+        recent.push("https://a.io".into());
+        assert_eq!(recent.get(1), Some("https://a.io"));
+        assert_eq!(recent.get(2), Some("https://b.io"));
+        assert_eq!(recent.get(0), None);
+        assert_eq!(recent.iter().count(), 2);
+    }
+}
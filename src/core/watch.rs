@@ -0,0 +1,113 @@
+//! Watch widget rows: labeled live values of state expressions.
+//!
+//! A row is any expression in the `.if` condition language
+//! (core::condition): `health.pct`, `rt.remaining`, `stance`, a `.set`
+//! variable, or a comparison such as `mana < 30`. Rows are evaluated on
+//! every sync, so the widget follows state as it changes. An expression
+//! that can't be read shows why instead of a value.
+//!
+//! ```text
+//! .watch add health.cur as HP
+//! .watch add rt > 0 as Busy
+//! ```
+
+use crate::config::WatchEntry;
+use crate::core::condition::{evaluate_value, ConditionContext};
+
+pub const USAGE: &str =
+    "Usage: .watch [list] | .watch add <expr> [as <label>] | .watch remove <n> | .watch clear";
+
+/// A watch row ready to draw
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchRow {
+    pub label: String,
+    /// The value, or why it couldn't be read
+    pub value: Result<String, String>,
+}
+
+/// Parse `.watch add` arguments: `<expr> [as <label>]`. A trailing
+/// ` as ` inside a quoted string is left to the expression.
+pub fn parse_entry(args: &str) -> Option<WatchEntry> {
+    let args = args.trim();
+    let (expr, label) = match args.rsplit_once(" as ") {
+        Some((expr, label)) if !label.contains(['"', '\'']) => (expr.trim(), label.trim()),
+        _ => (args, ""),
+    };
+    (!expr.is_empty()).then(|| WatchEntry {
+        label: label.to_string(),
+        expr: expr.to_string(),
+    })
+}
+
+/// Evaluate every row; an unlabeled row is labeled with its expression
+pub fn evaluate_rows(watches: &[WatchEntry], ctx: &ConditionContext) -> Vec<WatchRow> {
+    watches
+        .iter()
+        .map(|watch| WatchRow {
+            label: if watch.label.is_empty() {
+                watch.expr.clone()
+            } else {
+                watch.label.clone()
+            },
+            value: evaluate_value(&watch.expr, ctx).map_err(|e| e.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::VariableStore;
+    use crate::core::state::GameState;
+
+    #[test]
+    fn parses_expression_and_optional_label() {
+        let entry = parse_entry("health.cur as HP").unwrap();
+        assert_eq!(
+            (entry.expr.as_str(), entry.label.as_str()),
+            ("health.cur", "HP")
+        );
+        let entry = parse_entry("rt > 0").unwrap();
+        assert_eq!((entry.expr.as_str(), entry.label.as_str()), ("rt > 0", ""));
+        let entry = parse_entry("right ~ 'sword as shield'").unwrap();
+        assert_eq!(entry.expr, "right ~ 'sword as shield'");
+        assert!(parse_entry("  ").is_none());
+    }
+
+    #[test]
+    fn rows_show_values_or_errors() {
+        let mut gs = GameState::new();
+        gs.vitals.mana = 25;
+        let mut vars = VariableStore::default();
+        vars.set("target", "kobold", false);
+        let ctx = ConditionContext {
+            game_state: &gs,
+            now_server: 0,
+            variables: &vars,
+        };
+        let watches = vec![
+            WatchEntry {
+                label: "Mana".into(),
+                expr: "mana.pct".into(),
+            },
+            WatchEntry {
+                label: String::new(),
+                expr: "mana < 30".into(),
+            },
+            WatchEntry {
+                label: "Target".into(),
+                expr: "target".into(),
+            },
+            WatchEntry {
+                label: "Typo".into(),
+                expr: "healht".into(),
+            },
+        ];
+        let rows = evaluate_rows(&watches, &ctx);
+        assert_eq!(rows[0].value.as_deref(), Ok("25"));
+        assert_eq!(rows[1].label, "mana < 30");
+        assert_eq!(rows[1].value.as_deref(), Ok("yes"));
+        assert_eq!(rows[2].value.as_deref(), Ok("kobold"));
+        assert!(rows[3].value.is_err());
+    }
+}
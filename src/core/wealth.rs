@@ -0,0 +1,202 @@
+//! Silver tracker for the wealth widget.
+//!
+//! A running estimate of the silver on hand, built from the lines the game
+//! prints anyway: `WEALTH` checks set it outright, pickups and sales add,
+//! purchases subtract, and bank deposits/withdrawals move silver between
+//! hand and bank. The session delta counts only real gains and spending, so
+//! a trip to the bank doesn't show up as income or loss.
+//!
+//! Lines are parsed in the message pipeline (main stream only) and applied
+//! to GameState by AppCore, like the sound queue. `.silver <amount>` fixes
+//! the estimate by hand when a pattern misses.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// One silver-related line from the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SilverEvent {
+    /// WEALTH check: the exact amount on hand
+    Balance(i64),
+    /// Picked up or was paid
+    Gained(i64),
+    /// Paid for something
+    Spent(i64),
+    /// Moved to the bank (None = "all")
+    Deposited(Option<i64>),
+    /// Taken out of the bank
+    Withdrew(i64),
+}
+
+static RE_BALANCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^You have (no|[\d,]+) (?:silver|coins?)(?: coins?)? with you").unwrap()
+});
+static RE_PICKUP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^You (?:pick up|gather(?: up)?(?: the remaining)?) ([\d,]+) (?:silvers?|coins?)\b")
+        .unwrap()
+});
+static RE_HANDS_YOU: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"hands you ([\d,]+) (?:silvers?|coins?)\b").unwrap());
+static RE_SPENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^You (?:hand over|pay)(?: \w+)*? ([\d,]+) (?:silvers?|coins?)\b").unwrap()
+});
+static RE_DEPOSIT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^You deposit (all of your silver|all your silver|[\d,]+ (?:silvers?|coins?))")
+        .unwrap()
+});
+
+fn amount(text: &str) -> Option<i64> {
+    text.replace(',', "").parse().ok()
+}
+
+/// Parse one main-stream line.
+pub fn parse_silver_line(line: &str) -> Option<SilverEvent> {
+    let line = line.trim();
+    // Every pattern mentions silver or coins; skip the regexes otherwise
+    if !(line.contains("silver") || line.contains("coin")) {
+        return None;
+    }
+    if let Some(caps) = RE_BALANCE.captures(line) {
+        return Some(SilverEvent::Balance(match &caps[1] {
+            "no" => 0,
+            n => amount(n)?,
+        }));
+    }
+    if let Some(caps) = RE_PICKUP.captures(line) {
+        return amount(&caps[1]).map(SilverEvent::Gained);
+    }
+    if let Some(caps) = RE_DEPOSIT.captures(line) {
+        let text = &caps[1];
+        return Some(SilverEvent::Deposited(if text.starts_with("all") {
+            None
+        } else {
+            Some(amount(text.split_whitespace().next()?)?)
+        }));
+    }
+    if let Some(caps) = RE_HANDS_YOU.captures(line) {
+        let n = amount(&caps[1])?;
+        return Some(if line.contains("teller") {
+            SilverEvent::Withdrew(n)
+        } else {
+            SilverEvent::Gained(n)
+        });
+    }
+    if let Some(caps) = RE_SPENT.captures(line) {
+        return amount(&caps[1]).map(SilverEvent::Spent);
+    }
+    None
+}
+
+/// Running silver estimate
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WealthState {
+    /// Silver on hand; None until a WEALTH check or `.silver <amount>`
+    pub silver: Option<i64>,
+    /// Gains minus spending since the session started (or `.silver reset`)
+    pub session_delta: i64,
+    /// Bumped on every change so widgets can skip redraws
+    pub generation: u64,
+}
+
+impl WealthState {
+    pub fn apply(&mut self, event: SilverEvent) {
+        let on_hand = |delta: i64, silver: Option<i64>| silver.map(|s| (s + delta).max(0));
+        match event {
+            SilverEvent::Balance(n) => self.silver = Some(n),
+            SilverEvent::Gained(n) => {
+                self.silver = on_hand(n, self.silver);
+                self.session_delta += n;
+            }
+            SilverEvent::Spent(n) => {
+                self.silver = on_hand(-n, self.silver);
+                self.session_delta -= n;
+            }
+            SilverEvent::Deposited(Some(n)) => self.silver = on_hand(-n, self.silver),
+            SilverEvent::Deposited(None) => self.silver = Some(0),
+            SilverEvent::Withdrew(n) => self.silver = on_hand(n, self.silver),
+        }
+        self.generation += 1;
+    }
+
+    /// Manual correction (`.silver <amount>`); not counted as a gain.
+    pub fn set(&mut self, silver: i64) {
+        self.silver = Some(silver.max(0));
+        self.generation += 1;
+    }
+
+    pub fn reset_session(&mut self) {
+        self.session_delta = 0;
+        self.generation += 1;
+    }
+}
+
+/// "12,345", grouped per `ui.locale`
+pub fn format_silver(value: i64) -> String {
+    super::locale::format_int(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_wealth_checks() {
+        assert_eq!(
+            parse_silver_line("You have 12,345 silver with you."),
+            Some(SilverEvent::Balance(12345))
+        );
+        assert_eq!(
+            parse_silver_line("You have no silver coins with you."),
+            Some(SilverEvent::Balance(0))
+        );
+    }
+
+    #[test]
+    fn parses_gains_spending_and_bank_moves() {
+        assert_eq!(
+            parse_silver_line("You gather the remaining 250 coins."),
+            Some(SilverEvent::Gained(250))
+        );
+        assert_eq!(
+            parse_silver_line("The pawnbroker hands you 1,200 silvers."),
+            Some(SilverEvent::Gained(1200))
+        );
+        assert_eq!(
+            parse_silver_line(
+                "The teller carefully records the transaction, and then hands you 500 silvers."
+            ),
+            Some(SilverEvent::Withdrew(500))
+        );
+        assert_eq!(
+            parse_silver_line("You deposit 2,000 silvers into your account."),
+            Some(SilverEvent::Deposited(Some(2000)))
+        );
+        assert_eq!(
+            parse_silver_line("You hand over 75 silvers."),
+            Some(SilverEvent::Spent(75))
+        );
+        assert_eq!(parse_silver_line("A silver wolf snarls at you."), None);
+    }
+
+    #[test]
+    fn bank_moves_do_not_count_toward_the_session() {
+        let mut wealth = WealthState::default();
+        wealth.apply(SilverEvent::Gained(100));
+        assert_eq!(wealth.silver, None, "unknown until a wealth check");
+        wealth.apply(SilverEvent::Balance(1000));
+        wealth.apply(SilverEvent::Gained(250));
+        wealth.apply(SilverEvent::Deposited(Some(500)));
+        wealth.apply(SilverEvent::Spent(50));
+        assert_eq!(wealth.silver, Some(700));
+        assert_eq!(wealth.session_delta, 300);
+        wealth.apply(SilverEvent::Deposited(None));
+        assert_eq!(wealth.silver, Some(0));
+    }
+
+    #[test]
+    fn formats_with_thousands_separators() {
+        assert_eq!(format_silver(0), "0");
+        assert_eq!(format_silver(1234567), "1,234,567");
+        assert_eq!(format_silver(-4500), "-4,500");
+    }
+}
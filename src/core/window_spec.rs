@@ -0,0 +1,152 @@
+//! `.window create` arguments: a text window described on one line, so
+//! scripts and aliases can open one at runtime without the window editor.
+//!
+//! ```text
+//! .window create loot streams=loot,treasure rows=10 cols=40 at=5,5 persist
+//! ```
+//!
+//! Everything after the name is optional. Without `streams=` the window
+//! listens to a stream of its own name; without `at=` it opens centered.
+//! Unknown keys are errors, so a typo doesn't quietly give a default window.
+
+/// A text window to create, parsed from `.window create` arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowSpec {
+    pub name: String,
+    pub streams: Vec<String>,
+    pub rows: u16,
+    pub cols: u16,
+    /// Top-left corner as (col, row); None centers the window
+    pub at: Option<(u16, u16)>,
+    /// Add the window to the layout instead of keeping it for the session
+    pub persist: bool,
+}
+
+pub const USAGE: &str =
+    "Usage: .window create <name> [streams=a,b] [rows=N] [cols=N] [at=col,row] [persist]";
+
+fn size(key: &str, value: &str) -> Result<u16, String> {
+    match value.parse::<u16>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("{}= needs a positive number, got '{}'", key, value)),
+    }
+}
+
+impl WindowSpec {
+    /// Parse the arguments after `.window create`
+    pub fn parse(args: &[&str]) -> Result<Self, String> {
+        let Some((&name, options)) = args.split_first() else {
+            return Err(USAGE.to_string());
+        };
+        if name.contains('=') {
+            return Err(format!("Window name first: {}", USAGE));
+        }
+        let mut spec = WindowSpec {
+            name: name.to_string(),
+            streams: vec![name.to_string()],
+            rows: 10,
+            cols: 40,
+            at: None,
+            persist: false,
+        };
+        for option in options {
+            if option.eq_ignore_ascii_case("persist") {
+                spec.persist = true;
+                continue;
+            }
+            let Some((key, value)) = option.split_once('=') else {
+                return Err(format!("Unknown option '{}'", option));
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "streams" | "stream" => {
+                    spec.streams = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                }
+                "rows" | "height" => spec.rows = size(key, value)?,
+                "cols" | "width" => spec.cols = size(key, value)?,
+                "at" => {
+                    let corner = value
+                        .split_once(',')
+                        .and_then(|(col, row)| Some((col.parse().ok()?, row.parse().ok()?)));
+                    match corner {
+                        Some(corner) => spec.at = Some(corner),
+                        None => return Err(format!("at= needs col,row, got '{}'", value)),
+                    }
+                }
+                _ => return Err(format!("Unknown option '{}'", key)),
+            }
+        }
+        Ok(spec)
+    }
+
+    /// Where the window goes on a `width`x`height` screen: `at=` (pulled in
+    /// so the window fits) or centered. Returns (col, row, cols, rows).
+    pub fn placement(&self, width: u16, height: u16) -> (u16, u16, u16, u16) {
+        let cols = self.cols.min(width.max(1));
+        let rows = self.rows.min(height.max(1));
+        let (col, row) = self.at.unwrap_or((
+            width.saturating_sub(cols) / 2,
+            height.saturating_sub(rows) / 2,
+        ));
+        (
+            col.min(width.saturating_sub(cols)),
+            row.min(height.saturating_sub(rows)),
+            cols,
+            rows,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_spec() {
+        let spec = WindowSpec::parse(&[
+            "loot",
+            "streams=loot,treasure",
+            "rows=8",
+            "cols=30",
+            "at=5,6",
+            "persist",
+        ])
+        .unwrap();
+        assert_eq!(spec.name, "loot");
+        assert_eq!(spec.streams, vec!["loot", "treasure"]);
+        assert_eq!((spec.rows, spec.cols), (8, 30));
+        assert_eq!(spec.at, Some((5, 6)));
+        assert!(spec.persist);
+    }
+
+    #[test]
+    fn defaults_listen_to_own_stream() {
+        let spec = WindowSpec::parse(&["scratch"]).unwrap();
+        assert_eq!(spec.streams, vec!["scratch"]);
+        assert_eq!((spec.rows, spec.cols, spec.at), (10, 40, None));
+        assert!(!spec.persist);
+    }
+
+    #[test]
+    fn rejects_typos_and_bad_values() {
+        assert!(WindowSpec::parse(&[]).is_err());
+        assert!(WindowSpec::parse(&["rows=5"]).is_err());
+        assert!(WindowSpec::parse(&["loot", "row=5"]).is_err());
+        assert!(WindowSpec::parse(&["loot", "rows=0"]).is_err());
+        assert!(WindowSpec::parse(&["loot", "at=5"]).is_err());
+        assert!(WindowSpec::parse(&["loot", "persistent"]).is_err());
+    }
+
+    #[test]
+    fn placement_centers_or_fits_on_screen() {
+        let mut spec = WindowSpec::parse(&["loot", "rows=10", "cols=40"]).unwrap();
+        assert_eq!(spec.placement(100, 30), (30, 10, 40, 10));
+        spec.at = Some((90, 25));
+        assert_eq!(spec.placement(100, 30), (60, 20, 40, 10));
+        assert_eq!(spec.placement(20, 5), (0, 0, 20, 5));
+    }
+}
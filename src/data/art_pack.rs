@@ -0,0 +1,167 @@
+//! Local art packs for creature/item images.
+//!
+//! An art pack is just a directory of image files named after the thing
+//! they depict (`troll.png`, `vultite greatsword.webp`, `krolvin_warrior.jpg`).
+//! Subdirectories are scanned too, so packs can be organised however the
+//! author likes. Names are normalised (lowercase, `_`/`-`/spaces unified) so
+//! a clicked "a massive Krolvin warrior" finds `krolvin-warrior.png`.
+//!
+//! Lookup is pure path resolution; decoding the image is the frontend's job.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File extensions recognised as art (matches the GUI's `image` features).
+const ART_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp"];
+
+/// Leading words dropped before matching ("a troll" -> "troll").
+const ARTICLES: &[&str] = &["a", "an", "the", "some"];
+
+/// Index of art files keyed by normalised name.
+#[derive(Debug, Default, Clone)]
+pub struct ArtPack {
+    root: PathBuf,
+    entries: HashMap<String, PathBuf>,
+}
+
+impl ArtPack {
+    /// Scan `root` (recursively) for image files. A missing directory yields
+    /// an empty pack rather than an error - art is optional.
+    pub fn load(root: &Path) -> Self {
+        let mut pack = Self {
+            root: root.to_path_buf(),
+            entries: HashMap::new(),
+        };
+        pack.scan_dir(root);
+        pack
+    }
+
+    fn scan_dir(&mut self, dir: &Path) {
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut paths: Vec<PathBuf> = read_dir.flatten().map(|entry| entry.path()).collect();
+        // Sorted so duplicate names resolve deterministically (first wins)
+        paths.sort();
+        for path in paths {
+            if path.is_dir() {
+                self.scan_dir(&path);
+                continue;
+            }
+            let is_art = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ART_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+                .unwrap_or(false);
+            if !is_art {
+                continue;
+            }
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                let key = normalize_art_name(stem);
+                if !key.is_empty() {
+                    self.entries.entry(key).or_insert(path);
+                }
+            }
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find art for a game name. Tries the full name (articles stripped),
+    /// then progressively shorter tails ("massive krolvin warrior" ->
+    /// "krolvin warrior" -> "warrior"), so adjectives don't defeat a match.
+    pub fn lookup(&self, name: &str) -> Option<&Path> {
+        let normalized = normalize_art_name(name);
+        let words: Vec<&str> = normalized.split(' ').filter(|w| !w.is_empty()).collect();
+        let start = words
+            .iter()
+            .position(|word| !ARTICLES.contains(word))
+            .unwrap_or(words.len());
+        (start..words.len())
+            .map(|i| words[i..].join(" "))
+            .find_map(|key| self.entries.get(&key))
+            .map(PathBuf::as_path)
+    }
+}
+
+/// Lowercase, treat `_` and `-` as spaces, drop other punctuation, and
+/// collapse whitespace.
+pub fn normalize_art_name(name: &str) -> String {
+    let mapped: String = name
+        .chars()
+        .map(|c| match c {
+            '_' | '-' => ' ',
+            c if c.is_alphanumeric() || c.is_whitespace() || c == '\'' => c.to_ascii_lowercase(),
+            _ => ' ',
+        })
+        .collect();
+    mapped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack_with(files: &[&str]) -> (tempfile::TempDir, ArtPack) {
+        let dir = tempfile::tempdir().unwrap();
+        for file in files {
+            let path = dir.path().join(file);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).unwrap();
+            }
+            std::fs::write(&path, b"").unwrap();
+        }
+        let pack = ArtPack::load(dir.path());
+        (dir, pack)
+    }
+
+    #[test]
+    fn test_normalize_art_name() {
+        assert_eq!(normalize_art_name("Krolvin_Warrior"), "krolvin warrior");
+        assert_eq!(
+            normalize_art_name("vultite-greatsword"),
+            "vultite greatsword"
+        );
+        assert_eq!(normalize_art_name("  a  troll, "), "a troll");
+    }
+
+    #[test]
+    fn test_load_indexes_images_recursively_and_skips_other_files() {
+        let (_dir, pack) = pack_with(&["troll.png", "items/vultite-greatsword.WEBP", "readme.txt"]);
+        assert_eq!(pack.len(), 2);
+        assert!(pack.lookup("troll").is_some());
+        assert!(pack.lookup("vultite greatsword").is_some());
+        assert!(pack.lookup("readme").is_none());
+    }
+
+    #[test]
+    fn test_lookup_strips_articles_and_falls_back_to_shorter_names() {
+        let (_dir, pack) = pack_with(&["krolvin_warrior.png", "warrior.png", "greatsword.jpg"]);
+        let exact = pack.lookup("a massive Krolvin warrior").unwrap();
+        assert!(exact.ends_with("krolvin_warrior.png"));
+        let tail = pack.lookup("an elven warrior").unwrap();
+        assert!(tail.ends_with("warrior.png"));
+        assert!(pack
+            .lookup("some vultite greatsword")
+            .unwrap()
+            .ends_with("greatsword.jpg"));
+        assert!(pack.lookup("a pebble").is_none());
+    }
+
+    #[test]
+    fn test_missing_directory_is_empty_pack() {
+        let pack = ArtPack::load(Path::new("/nonexistent/vellum-art"));
+        assert!(pack.is_empty());
+        assert!(pack.lookup("troll").is_none());
+    }
+}
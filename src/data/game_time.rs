@@ -0,0 +1,306 @@
+//! Elanthian calendar and moons for the calendar widget.
+//!
+//! GemStone IV keeps the Elanthian date in step with the real one: the
+//! Modern Era year is the real year plus 3100, the twelve months line up
+//! with January..December, and the "elven time standard" is the game
+//! server's local time (US Eastern). The clock is computed from server time
+//! and corrected whenever a `TIME` check is seen, so a drifting estimate
+//! snaps back to what the game says.
+//!
+//! Moon phases are computed from each moon's cycle length and a known new
+//! moon; the calendar widget carries the list of moons to show.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Weekday};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// Modern Era year = real year + this
+const ERA_OFFSET: i32 = 3100;
+
+pub const MONTHS: [&str; 12] = [
+    "Lormesta",
+    "Fashanos",
+    "Charlatos",
+    "Olaesta",
+    "Ivastaen",
+    "Lumnea",
+    "Koaratos",
+    "Phoenatos",
+    "Imaerasta",
+    "Jastatos",
+    "Eoantos",
+    "Eorgaen",
+];
+
+/// Days of the week, Restday first
+pub const WEEKDAYS: [&str; 7] = [
+    "Restday",
+    "Volnes",
+    "Tilamaires",
+    "Leyan",
+    "Niiman",
+    "Day of the Huntress",
+    "Feastday",
+];
+
+static RE_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bday (\d{1,2}) of the month (\w+) in the year (\d{4})").unwrap()
+});
+static RE_CLOCK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bIt is (\d{1,2}):(\d{2}) ?([ap])\.?m\.? by the elven time standard").unwrap()
+});
+
+/// A moment on the Elanthian calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElanthianTime {
+    pub year: i32,
+    /// 1..=12
+    pub month: u32,
+    /// 1..=31
+    pub day: u32,
+    /// 0 = Restday
+    pub weekday: usize,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl ElanthianTime {
+    fn from_naive(local: NaiveDateTime) -> Self {
+        Self {
+            year: local.year() + ERA_OFFSET,
+            month: local.month(),
+            day: local.day(),
+            weekday: local.weekday().num_days_from_sunday() as usize,
+            hour: local.hour(),
+            minute: local.minute(),
+        }
+    }
+
+    pub fn month_name(&self) -> &'static str {
+        MONTHS[(self.month as usize).saturating_sub(1) % 12]
+    }
+
+    pub fn weekday_name(&self) -> &'static str {
+        WEEKDAYS[self.weekday % 7]
+    }
+
+    /// "14 Ivastaen 5125"
+    pub fn date_string(&self) -> String {
+        format!("{} {} {}", self.day, self.month_name(), self.year)
+    }
+
+    /// "3:12pm", the way `TIME` prints it
+    pub fn clock_string(&self) -> String {
+        let hour = match self.hour % 12 {
+            0 => 12,
+            h => h,
+        };
+        let suffix = if self.hour < 12 { "am" } else { "pm" };
+        format!("{}:{:02}{}", hour, self.minute, suffix)
+    }
+}
+
+/// The date and clock read from a `TIME` check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarReading {
+    local: NaiveDateTime,
+}
+
+/// Parse the game's `TIME` output ("Today is Volnes, day 14 of the month
+/// Ivastaen in the year 5125 of the Modern Era.  It is 3:12pm by the elven
+/// time standard."). Both halves must be on the line.
+pub fn parse_time_line(line: &str) -> Option<CalendarReading> {
+    let date = RE_DATE.captures(line)?;
+    let clock = RE_CLOCK.captures(line)?;
+
+    let day: u32 = date[1].parse().ok()?;
+    let month = MONTHS
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(&date[2]))? as u32
+        + 1;
+    let year: i32 = date[3].parse().ok()?;
+    let hour12: u32 = clock[1].parse().ok()?;
+    let minute: u32 = clock[2].parse().ok()?;
+    if !(1..=12).contains(&hour12) {
+        return None;
+    }
+    let pm = clock[3].eq_ignore_ascii_case("p");
+    let hour = hour12 % 12 + if pm { 12 } else { 0 };
+
+    let local =
+        NaiveDate::from_ymd_opt(year - ERA_OFFSET, month, day)?.and_hms_opt(hour, minute, 0)?;
+    Some(CalendarReading { local })
+}
+
+/// US Eastern wall-clock time for a Unix timestamp (EST/EDT, current rules)
+fn eastern(unix: i64) -> NaiveDateTime {
+    let utc = chrono::DateTime::from_timestamp(unix, 0)
+        .unwrap_or_default()
+        .naive_utc();
+    let year = utc.year();
+    // DST runs from 2am EST on the second Sunday of March to 2am EDT on
+    // the first Sunday of November, i.e. 07:00 and 06:00 UTC
+    let starts = NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2)
+        .and_then(|d| d.and_hms_opt(7, 0, 0));
+    let ends = NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Sun, 1)
+        .and_then(|d| d.and_hms_opt(6, 0, 0));
+    let dst = matches!((starts, ends), (Some(s), Some(e)) if utc >= s && utc < e);
+    utc - Duration::hours(if dst { 4 } else { 5 })
+}
+
+/// The game clock: server time mapped onto the Elanthian calendar, plus
+/// the correction from the last `TIME` check
+#[derive(Debug, Clone, Default)]
+pub struct GameCalendar {
+    /// Seconds to add to the computed clock, from the last `TIME` check
+    offset: i64,
+    /// Bumped on every correction
+    pub generation: u64,
+}
+
+impl GameCalendar {
+    /// Line the clock up with a `TIME` reading taken at `server_time`.
+    /// The reading only has minutes, so the correction is whole minutes.
+    pub fn sync(&mut self, reading: CalendarReading, server_time: i64) {
+        let diff = (reading.local - eastern(server_time)).num_seconds();
+        self.offset = (diff as f64 / 60.0).round() as i64 * 60;
+        self.generation += 1;
+    }
+
+    /// The Elanthian date and time at `server_time`
+    pub fn at(&self, server_time: i64) -> ElanthianTime {
+        ElanthianTime::from_naive(eastern(server_time) + Duration::seconds(self.offset))
+    }
+}
+
+/// A moon to track: its cycle and one known new moon
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoonDef {
+    pub name: String,
+    /// Days from new moon to new moon
+    pub period_days: f64,
+    /// Unix time of any new moon
+    pub new_moon: i64,
+}
+
+impl MoonDef {
+    /// Liabo, the white moon, which follows the real moon's cycle
+    pub fn liabo() -> Self {
+        Self {
+            name: "Liabo".to_string(),
+            period_days: 29.530_588,
+            // 2000-01-06 18:14 UTC
+            new_moon: 947_182_440,
+        }
+    }
+
+    /// Fraction of the way through the cycle at `unix`, 0.0..1.0
+    pub fn age(&self, unix: i64) -> f64 {
+        if self.period_days <= 0.0 {
+            return 0.0;
+        }
+        let days = (unix - self.new_moon) as f64 / 86_400.0;
+        (days / self.period_days).rem_euclid(1.0)
+    }
+
+    pub fn phase(&self, unix: i64) -> MoonPhase {
+        MoonPhase::from_age(self.age(unix))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhase {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Eight equal slices of the cycle, each centered on its phase
+    pub fn from_age(age: f64) -> Self {
+        const PHASES: [MoonPhase; 8] = [
+            MoonPhase::New,
+            MoonPhase::WaxingCrescent,
+            MoonPhase::FirstQuarter,
+            MoonPhase::WaxingGibbous,
+            MoonPhase::Full,
+            MoonPhase::WaningGibbous,
+            MoonPhase::LastQuarter,
+            MoonPhase::WaningCrescent,
+        ];
+        PHASES[((age * 8.0 + 0.5).floor() as usize) % 8]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MoonPhase::New => "new",
+            MoonPhase::WaxingCrescent => "waxing crescent",
+            MoonPhase::FirstQuarter => "first quarter",
+            MoonPhase::WaxingGibbous => "waxing gibbous",
+            MoonPhase::Full => "full",
+            MoonPhase::WaningGibbous => "waning gibbous",
+            MoonPhase::LastQuarter => "last quarter",
+            MoonPhase::WaningCrescent => "waning crescent",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unix(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp()
+    }
+
+    #[test]
+    fn computes_date_in_eastern_time() {
+        let calendar = GameCalendar::default();
+        // 2025-06-14 19:12 UTC = 3:12pm EDT, a Saturday
+        let now = calendar.at(unix(2025, 6, 14, 19, 12));
+        assert_eq!(now.date_string(), "14 Lumnea 5125");
+        assert_eq!(now.clock_string(), "3:12pm");
+        assert_eq!(now.weekday_name(), "Feastday");
+        // 2025-01-01 03:00 UTC is still New Year's Eve in EST
+        let eve = calendar.at(unix(2025, 1, 1, 3, 0));
+        assert_eq!(eve.date_string(), "31 Eorgaen 5124");
+        assert_eq!(eve.clock_string(), "10:00pm");
+    }
+
+    #[test]
+    fn time_check_corrects_the_clock() {
+        let line = "Today is Volnes, day 14 of the month Ivastaen in the year 5125 of the Modern Era.  It is 12:05am by the elven time standard.";
+        let reading = parse_time_line(line).unwrap();
+        let mut calendar = GameCalendar::default();
+        // Server clock reads 5 minutes behind what the game says
+        let server = unix(2025, 5, 14, 4, 0) + 30;
+        calendar.sync(reading, server);
+        assert_eq!(calendar.at(server).clock_string(), "12:05am");
+        assert_eq!(calendar.at(server + 3600).date_string(), "14 Ivastaen 5125");
+        assert!(parse_time_line("It is 3:12pm by the elven time standard.").is_none());
+    }
+
+    #[test]
+    fn moon_phases_follow_the_cycle() {
+        let liabo = MoonDef::liabo();
+        assert_eq!(liabo.phase(liabo.new_moon), MoonPhase::New);
+        let half = liabo.new_moon + (liabo.period_days * 86_400.0 / 2.0) as i64;
+        assert_eq!(liabo.phase(half), MoonPhase::Full);
+        // Before the epoch still lands in the cycle
+        let quarter_before = liabo.new_moon - (liabo.period_days * 86_400.0 / 4.0) as i64;
+        assert_eq!(liabo.phase(quarter_before), MoonPhase::LastQuarter);
+        // A known full moon: 2024-01-25 17:54 UTC
+        assert_eq!(liabo.phase(unix(2024, 1, 25, 17, 54)), MoonPhase::Full);
+    }
+}
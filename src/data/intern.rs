@@ -0,0 +1,96 @@
+//! Process-wide string interning for values repeated on every buffered line.
+//!
+//! A 10k-line buffer repeats the same handful of stream names ("main",
+//! "combat") and color strings ("#FFFFFF") thousands of times. `intern`
+//! hands back a shared `Arc<str>` for each distinct value, so those lines
+//! point at one allocation instead of owning a copy each.
+//!
+//! The table only grows by distinct values (streams, palette colors), which
+//! stay in the low hundreds; `stats` reports what sharing currently saves.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, OnceLock};
+
+fn table() -> &'static Mutex<HashSet<Arc<str>>> {
+    static TABLE: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+    TABLE.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Shared copy of `value`, allocated on first use
+pub fn intern(value: &str) -> Arc<str> {
+    let mut table = table().lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(existing) = table.get(value) {
+        return existing.clone();
+    }
+    let shared: Arc<str> = Arc::from(value);
+    table.insert(shared.clone());
+    shared
+}
+
+/// Live interning figures for the performance widget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InternStats {
+    /// Distinct strings currently referenced outside the table
+    pub unique: usize,
+    /// References to those strings (one per line/segment using one)
+    pub references: usize,
+    /// Bytes the referenced text would take as one owned copy per reference
+    pub bytes_unshared: usize,
+    /// Bytes it actually takes, one copy per distinct string
+    pub bytes_shared: usize,
+}
+
+impl InternStats {
+    pub fn bytes_saved(&self) -> usize {
+        self.bytes_unshared.saturating_sub(self.bytes_shared)
+    }
+}
+
+/// Measure current sharing. Entries nothing references any more are
+/// dropped along the way.
+pub fn stats() -> InternStats {
+    let mut table = table().lock().unwrap_or_else(|e| e.into_inner());
+    table.retain(|value| Arc::strong_count(value) > 1);
+    measure(&table)
+}
+
+fn measure(table: &HashSet<Arc<str>>) -> InternStats {
+    let mut stats = InternStats::default();
+    for value in table {
+        // The table's own handle isn't a use
+        let references = Arc::strong_count(value) - 1;
+        stats.unique += 1;
+        stats.references += references;
+        stats.bytes_unshared += references * value.len();
+        stats.bytes_shared += value.len();
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_strings_share_one_allocation() {
+        let a = intern("intern-test-stream");
+        let b = intern(&String::from("intern-test-stream"));
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &intern("intern-test-other")));
+    }
+
+    #[test]
+    fn stats_count_references_outside_the_table() {
+        let main: Arc<str> = Arc::from("main");
+        let table: HashSet<Arc<str>> = [main.clone()].into_iter().collect();
+        let lines: Vec<Arc<str>> = (0..10).map(|_| main.clone()).collect();
+        drop(main);
+
+        let stats = measure(&table);
+        assert_eq!(stats.unique, 1);
+        assert_eq!(stats.references, lines.len());
+        assert_eq!(stats.bytes_unshared, 40);
+        assert_eq!(stats.bytes_shared, 4);
+        assert_eq!(stats.bytes_saved(), 36);
+    }
+}
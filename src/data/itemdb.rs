@@ -0,0 +1,196 @@
+//! Remembered item values from appraisals and sales.
+//!
+//! Whenever a shopkeeper appraises or buys an item, the item name and the
+//! silver offered are kept in a flat `"name" = value` file
+//! (`global/itemdb.toml`), shared by every character. The message processor
+//! records new values as the lines arrive and annotates room objects and
+//! container contents with what the same item fetched before.
+//!
+//! Names are stored normalised (lowercase, no leading article), so
+//! "the blue sapphire" and "a Blue Sapphire" are the same entry.
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Leading words dropped from item names before storing or matching.
+const ARTICLES: &[&str] = &["a", "an", "the", "some", "your", "my"];
+
+/// "Dealer takes your blue sapphire and gives you 1,250 silvers."
+/// "The gemcutter takes the blue sapphire, looks it over, and says, "I'll give you 1250 for it.""
+static RE_TAKES: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"takes (?:your|the|an?|some) (.+?)(?:,| and | from | to | for )").unwrap()
+});
+/// The offer or payment in a shop line.
+static RE_OFFER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:give|gives|offer|offers|pay|pays) you ([\d,]+)(?: silvers?| coins?| for it)")
+        .unwrap()
+});
+/// "You estimate that the blue sapphire is worth about 1,250 silvers."
+static RE_WORTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:the|your) (.+?) (?:is|would be|appears to be) worth (?:about |roughly |approximately )?([\d,]+) silvers?",
+    )
+    .unwrap()
+});
+
+/// A value seen in a shop line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Appraisal {
+    /// Item named on the line, if the line names it
+    pub item: Option<String>,
+    pub value: u64,
+}
+
+/// Parse one main-stream line for an appraisal or sale.
+pub fn parse_appraisal_line(line: &str) -> Option<Appraisal> {
+    let line = line.trim();
+    if !(line.contains("give")
+        || line.contains("pay")
+        || line.contains("offer")
+        || line.contains("worth"))
+    {
+        return None;
+    }
+    let amount = |text: &str| text.replace(',', "").parse::<u64>().ok();
+    if let Some(caps) = RE_WORTH.captures(line) {
+        return Some(Appraisal {
+            item: Some(normalize_item_name(&caps[1])),
+            value: amount(&caps[2])?,
+        });
+    }
+    let value = amount(&RE_OFFER.captures(line)?[1])?;
+    let item = RE_TAKES
+        .captures(line)
+        .map(|caps| normalize_item_name(&caps[1]))
+        .filter(|name| !name.is_empty());
+    Some(Appraisal { item, value })
+}
+
+/// "The Blue  Sapphire" -> "blue sapphire"
+pub fn normalize_item_name(name: &str) -> String {
+    let lower = name.trim().trim_end_matches(['.', ',', '!']).to_lowercase();
+    let mut words: Vec<&str> = lower.split_whitespace().collect();
+    if words.len() > 1 && ARTICLES.contains(&words[0]) {
+        words.remove(0);
+    }
+    words.join(" ")
+}
+
+/// Item name -> last value seen, backed by a file
+#[derive(Debug, Clone, Default)]
+pub struct ItemDb {
+    values: BTreeMap<String, u64>,
+    path: Option<PathBuf>,
+}
+
+impl ItemDb {
+    /// Load from `path`. A missing or unreadable file is an empty database
+    /// (logged) - remembered values are a convenience, not a requirement.
+    pub fn load(path: &Path) -> Self {
+        let values = match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring malformed {}: {}", path.display(), e);
+                BTreeMap::new()
+            }),
+            Err(_) => BTreeMap::new(),
+        };
+        Self {
+            values,
+            path: Some(path.to_path_buf()),
+        }
+    }
+
+    /// Write back to the file it was loaded from (no-op when unbacked).
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let text =
+            toml::to_string_pretty(&self.values).context("Failed to serialize item values")?;
+        fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Remembered value for an item name (any case, article optional).
+    pub fn value(&self, name: &str) -> Option<u64> {
+        self.values.get(&normalize_item_name(name)).copied()
+    }
+
+    /// Remember a value. Returns true if it changed the database.
+    pub fn record(&mut self, name: &str, value: u64) -> bool {
+        let key = normalize_item_name(name);
+        if key.is_empty() {
+            return false;
+        }
+        self.values.insert(key, value) != Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sales_and_appraisals() {
+        assert_eq!(
+            parse_appraisal_line("Zirconia takes your blue sapphire and gives you 1,250 silvers."),
+            Some(Appraisal {
+                item: Some("blue sapphire".to_string()),
+                value: 1250
+            })
+        );
+        assert_eq!(
+            parse_appraisal_line(
+                "The gemcutter takes the uncut diamond, inspects it, and says, \"I'll give you 2500 for it if you want to sell it.\""
+            ),
+            Some(Appraisal {
+                item: Some("uncut diamond".to_string()),
+                value: 2500
+            })
+        );
+        assert_eq!(
+            parse_appraisal_line("You estimate that the silver wand is worth about 800 silvers."),
+            Some(Appraisal {
+                item: Some("silver wand".to_string()),
+                value: 800
+            })
+        );
+        // Value only: the processor fills in the item from the APPRAISE command
+        assert_eq!(
+            parse_appraisal_line("\"I'd give you 150 for it,\" the pawnbroker says."),
+            Some(Appraisal {
+                item: None,
+                value: 150
+            })
+        );
+        assert_eq!(
+            parse_appraisal_line("You give your ruby a quick polish."),
+            None
+        );
+    }
+
+    #[test]
+    fn records_normalised_names() {
+        let mut db = ItemDb::default();
+        assert!(db.record("The Blue Sapphire", 1250));
+        assert!(!db.record("blue sapphire", 1250));
+        assert_eq!(db.value("a blue sapphire"), Some(1250));
+        assert_eq!(db.value("sapphire"), None);
+        assert_eq!(db.len(), 1);
+    }
+}
@@ -0,0 +1,211 @@
+//! Ranked loot item database.
+//!
+//! A flat list of item names (gems, boxes, magic items) with a category and
+//! a tier. The loot filter uses it to color valuable drops, play a sound for
+//! the top tier, and count drops for `.loot`. Users extend or re-rank
+//! entries through `[[loot.items]]` in config.toml; a user entry with the
+//! same name replaces the built-in one.
+//!
+//! Matching is whole-word and case-insensitive, longest name first, so
+//! "a star ruby" ranks as a star ruby rather than a plain ruby.
+
+use serde::{Deserialize, Serialize};
+
+/// What kind of loot an item is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LootCategory {
+    Gem,
+    Box,
+    Magic,
+    Other,
+}
+
+impl LootCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            LootCategory::Gem => "Gems",
+            LootCategory::Box => "Boxes",
+            LootCategory::Magic => "Magic",
+            LootCategory::Other => "Other",
+        }
+    }
+}
+
+/// How worthwhile an item is, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LootTier {
+    Common,
+    Valuable,
+    Top,
+}
+
+/// One ranked item name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LootItem {
+    /// Item name without article ("star ruby", "strongbox")
+    pub name: String,
+    pub category: LootCategory,
+    pub tier: LootTier,
+}
+
+use LootCategory::{Box as Bx, Gem, Magic};
+use LootTier::{Common, Top, Valuable};
+
+/// Built-in GS4 ranking. Deliberately short; `[[loot.items]]` fills gaps.
+const BUILTIN_LOOT: &[(&str, LootCategory, LootTier)] = &[
+    // Top-tier gems
+    ("star ruby", Gem, Top),
+    ("star sapphire", Gem, Top),
+    ("star emerald", Gem, Top),
+    ("dragonfire emerald", Gem, Top),
+    ("blue diamond", Gem, Top),
+    ("black diamond", Gem, Top),
+    ("black opal", Gem, Top),
+    ("firestone", Gem, Top),
+    ("dragonstone", Gem, Top),
+    // Valuable gems
+    ("diamond", Gem, Valuable),
+    ("emerald", Gem, Valuable),
+    ("ruby", Gem, Valuable),
+    ("sapphire", Gem, Valuable),
+    ("fire opal", Gem, Valuable),
+    ("alexandrite", Gem, Valuable),
+    ("aquamarine", Gem, Valuable),
+    // Common gems
+    ("agate", Gem, Common),
+    ("amethyst", Gem, Common),
+    ("bloodstone", Gem, Common),
+    ("garnet", Gem, Common),
+    ("jasper", Gem, Common),
+    ("moonstone", Gem, Common),
+    ("onyx", Gem, Common),
+    ("opal", Gem, Common),
+    ("pearl", Gem, Common),
+    ("peridot", Gem, Common),
+    ("quartz", Gem, Common),
+    ("sunstone", Gem, Common),
+    ("topaz", Gem, Common),
+    ("tourmaline", Gem, Common),
+    ("turquoise", Gem, Common),
+    ("zircon", Gem, Common),
+    // Boxes
+    ("box", Bx, Valuable),
+    ("casket", Bx, Valuable),
+    ("chest", Bx, Valuable),
+    ("coffer", Bx, Valuable),
+    ("strongbox", Bx, Valuable),
+    ("trunk", Bx, Valuable),
+    // Magic items
+    ("wand", Magic, Valuable),
+    ("rod", Magic, Valuable),
+    ("amulet", Magic, Valuable),
+    ("talisman", Magic, Valuable),
+    ("scroll", Magic, Common),
+];
+
+/// Ranked item list, longest name first
+#[derive(Debug, Clone, Default)]
+pub struct LootTable {
+    items: Vec<LootItem>,
+}
+
+impl LootTable {
+    /// The built-in ranking with `extra` entries added (same name replaces).
+    pub fn new(extra: &[LootItem]) -> Self {
+        let mut items: Vec<LootItem> = extra
+            .iter()
+            .map(|item| LootItem {
+                name: item.name.trim().to_lowercase(),
+                ..item.clone()
+            })
+            .filter(|item| !item.name.is_empty())
+            .collect();
+        for &(name, category, tier) in BUILTIN_LOOT {
+            if !items.iter().any(|item| item.name == name) {
+                items.push(LootItem {
+                    name: name.to_string(),
+                    category,
+                    tier,
+                });
+            }
+        }
+        items.sort_by(|a, b| b.name.len().cmp(&a.name.len()).then(a.name.cmp(&b.name)));
+        Self { items }
+    }
+
+    pub fn items(&self) -> &[LootItem] {
+        &self.items
+    }
+
+    /// The ranked entry an item name matches, if any.
+    pub fn classify(&self, item_name: &str) -> Option<&LootItem> {
+        let lower = item_name.to_lowercase();
+        self.items
+            .iter()
+            .find(|item| contains_word(&lower, &item.name))
+    }
+}
+
+/// `needle` appears in `haystack` on word boundaries (an "s"/"es" plural
+/// suffix is allowed).
+fn contains_word(haystack: &str, needle: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '\'';
+    haystack.match_indices(needle).any(|(start, _)| {
+        let before_ok = haystack[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !is_word(c));
+        let rest = &haystack[start + needle.len()..];
+        let rest = rest
+            .strip_prefix("es")
+            .or_else(|| rest.strip_prefix('s'))
+            .filter(|r| r.chars().next().is_none_or(|c| !is_word(c)))
+            .unwrap_or(rest);
+        before_ok && rest.chars().next().is_none_or(|c| !is_word(c))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn longest_name_wins() {
+        let table = LootTable::new(&[]);
+        let star = table.classify("a star ruby").unwrap();
+        assert_eq!(star.name, "star ruby");
+        assert_eq!(star.tier, LootTier::Top);
+        assert_eq!(
+            table.classify("a small ruby").unwrap().tier,
+            LootTier::Valuable
+        );
+        assert_eq!(
+            table.classify("some blue diamonds").unwrap().name,
+            "blue diamond"
+        );
+        assert_eq!(
+            table.classify("an iron strongbox").unwrap().category,
+            LootCategory::Box
+        );
+    }
+
+    #[test]
+    fn matches_whole_words_only() {
+        let table = LootTable::new(&[]);
+        assert!(table.classify("a boxwood staff").is_none());
+        assert!(table.classify("a sandy rodent").is_none());
+        assert!(table.classify("a carved rod").is_some());
+    }
+
+    #[test]
+    fn user_entries_override_builtins() {
+        let table = LootTable::new(&[LootItem {
+            name: "Pearl".to_string(),
+            category: LootCategory::Gem,
+            tier: LootTier::Top,
+        }]);
+        assert_eq!(table.classify("a black pearl").unwrap().tier, LootTier::Top);
+    }
+}
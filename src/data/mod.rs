@@ -4,14 +4,22 @@
 //! NO imports from frontend/ or any rendering code.
 //! Both TUI and GUI frontends read from these structures to render.
 
+pub mod art_pack;
+pub mod game_time;
 pub mod input;
+pub mod intern;
+pub mod itemdb;
+pub mod loot;
 pub mod remote_buffer;
 pub mod ui_state;
 pub mod webui;
 pub mod widget;
 pub mod window;
 
+pub use art_pack::*;
 pub use input::*;
+pub use itemdb::*;
+pub use loot::*;
 pub use remote_buffer::*;
 pub use ui_state::*;
 pub use webui::*;
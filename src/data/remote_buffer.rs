@@ -133,7 +133,7 @@ mod tests {
     fn line(text: &str, stream: &str) -> Arc<StyledLine> {
         Arc::new(StyledLine {
             segments: vec![crate::data::widget::TextSegment::plain(text)],
-            stream: stream.to_string(),
+            stream: stream.into(),
             timestamp: None,
         })
     }
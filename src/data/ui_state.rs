@@ -3,7 +3,7 @@
 //! This module contains UI state that is independent of rendering.
 //! Both TUI and GUI frontends read from these structures.
 
-use super::window::WindowState;
+use super::window::{WindowContent, WindowState};
 use crate::data::LinkData;
 use crate::selection::SelectionState;
 use std::collections::HashMap;
@@ -21,6 +21,10 @@ pub struct UiState {
     /// Currently focused window name
     pub focused_window: Option<String>,
 
+    /// Window taking keystrokes instead of the command line (a focused
+    /// widget that `captures_input`); None = the command line. Esc clears it.
+    pub input_focus: Option<String>,
+
     /// Current input mode
     pub input_mode: InputMode,
 
@@ -65,12 +69,40 @@ pub struct UiState {
     /// More targeted than needs_widget_reset which clears ALL caches
     pub widgets_to_reset: Vec<String>,
 
+    /// Scroll and unread state of text windows (and tabs, as `window:tab`)
+    /// whose widgets are being rebuilt, restored once they're refilled
+    pub window_views: HashMap<String, WindowView>,
+
     /// Container discovery mode - when ON, auto-creates windows for LOOK IN containers
     pub container_discovery_mode: bool,
 
     /// Set of ephemeral window names (session-only, not saved to layout)
     pub ephemeral_windows: std::collections::HashSet<String>,
 
+    /// Floating window names in stacking order (bottom to top)
+    /// Floating windows overlap the tiled layout and are drawn after it
+    pub floating_windows: Vec<String>,
+
+    /// When true, floating windows are temporarily hidden (toggled by keybind)
+    pub floating_hidden: bool,
+
+    /// Windows whose `show_when` condition is false right now
+    pub hidden_by_condition: std::collections::HashSet<String>,
+
+    /// Windows whose `show_when` failed to evaluate, reported once each
+    pub condition_errors: std::collections::HashSet<String>,
+
+    /// Compact mode: every text window collapses into one full-screen tabbed
+    /// view with vitals in the title line (small terminals, phone SSH)
+    pub compact_mode: bool,
+
+    /// Text window shown in compact mode (None = first available tab)
+    pub compact_tab: Option<String>,
+
+    /// Boss key active: frontends draw a neutral screen and ignore all
+    /// input except the boss key itself
+    pub boss_mode: bool,
+
     /// Quickbar data keyed by id (e.g., "quick", "quick-combat")
     pub quickbars: HashMap<String, crate::data::QuickbarData>,
 
@@ -86,6 +118,12 @@ pub struct UiState {
     /// Active injuries popup (viewing another player's injuries)
     pub injuries_popup: Option<InjuriesPopupState>,
 
+    /// Multi-line paste waiting for send-all / send-first / cancel
+    pub paste_guard: Option<PasteGuard>,
+
+    /// Command matching a `[lockout]` pattern, waiting for y/n
+    pub lockout_prompt: Option<LockoutPrompt>,
+
     /// Dialog drag state for move/resize operations
     pub dialog_drag: Option<DialogDragState>,
 
@@ -195,6 +233,18 @@ pub enum InputMode {
     SettingsEditor,
     /// Indicator template editor is open
     IndicatorTemplateEditor,
+    /// Popup text viewer is open (.view)
+    TextViewer,
+    /// Multi-line compose popup is open (open_compose keybind)
+    Compose,
+    /// Notes editor popup is open (.notes)
+    NotesEditor,
+    /// Spellcheck suggestion popup is open (spell_suggest keybind)
+    SpellSuggest,
+    /// Command log palette is open (.cmdlog)
+    CommandLog,
+    /// Layout gallery is open (.layouts browse)
+    LayoutGallery,
 }
 
 /// Dialog popup state
@@ -348,6 +398,96 @@ impl InjuriesPopupState {
     }
 }
 
+/// A pasted block of lines held back until the user confirms what to send
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PasteGuard {
+    /// Non-empty pasted lines, in order
+    pub lines: Vec<String>,
+    /// Highlighted choice (index into `PasteChoice::ALL`)
+    pub selected: usize,
+}
+
+/// What to do with a guarded paste
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PasteChoice {
+    SendAll,
+    SendFirst,
+    Cancel,
+}
+
+impl PasteChoice {
+    /// Button order in the confirmation popup
+    pub const ALL: [PasteChoice; 3] = [Self::SendAll, Self::SendFirst, Self::Cancel];
+
+    pub fn label(&self, line_count: usize) -> String {
+        match self {
+            Self::SendAll => format!("Send all {} lines (a)", line_count),
+            Self::SendFirst => "Send first line (f)".to_string(),
+            Self::Cancel => "Cancel (Esc)".to_string(),
+        }
+    }
+}
+
+impl PasteGuard {
+    pub fn new(lines: Vec<String>) -> Self {
+        Self { lines, selected: 0 }
+    }
+
+    pub fn selected_choice(&self) -> PasteChoice {
+        PasteChoice::ALL[self.selected.min(PasteChoice::ALL.len() - 1)]
+    }
+
+    /// Move the highlight by `delta` choices, wrapping around
+    pub fn move_selection(&mut self, delta: isize) {
+        let len = PasteChoice::ALL.len() as isize;
+        self.selected = (self.selected as isize + delta).rem_euclid(len) as usize;
+    }
+}
+
+/// A command held back by the safety lockout
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockoutPrompt {
+    pub command: String,
+    /// The `[lockout]` pattern it matched
+    pub pattern: String,
+}
+
+/// Where a text window or tab was left, carried across a widget rebuild
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowView {
+    /// Wrapped lines from the end when scrolled back (None = live)
+    pub scroll_from_end: Option<usize>,
+    /// New lines the user hasn't seen: below a scrolled-back view, or in
+    /// a tab that isn't active
+    pub unread: usize,
+    /// Rows the window last rendered, so a rebuilt widget that hasn't
+    /// rendered yet judges "scrolled back" against the same height
+    pub visible_height: usize,
+}
+
+/// Split text from the compose popup into commands. Blank lines separate
+/// commands; the lines of one paragraph are joined with single spaces, so a
+/// long `say` can be typed across several wrapped lines.
+pub fn compose_commands(text: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !current.is_empty() {
+                commands.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        commands.push(current.join(" "));
+    }
+    commands
+}
+
 /// Popup menu state
 #[derive(Clone, Debug)]
 pub struct PopupMenu {
@@ -370,6 +510,7 @@ impl UiState {
             windows: HashMap::new(),
             widget_type_index: HashMap::new(),
             focused_window: None,
+            input_focus: None,
             input_mode: InputMode::Normal,
             search_input: String::new(),
             search_cursor: 0,
@@ -385,13 +526,23 @@ impl UiState {
             pending_link_click: None,
             needs_widget_reset: false,
             widgets_to_reset: Vec::new(),
+            window_views: HashMap::new(),
             container_discovery_mode: false,
             ephemeral_windows: std::collections::HashSet::new(),
+            floating_windows: Vec::new(),
+            floating_hidden: false,
+            hidden_by_condition: std::collections::HashSet::new(),
+            condition_errors: std::collections::HashSet::new(),
+            compact_mode: false,
+            compact_tab: None,
+            boss_mode: false,
             quickbars: HashMap::new(),
             quickbar_order: Vec::new(),
             active_quickbar_id: None,
             active_dialog: None,
             injuries_popup: None,
+            paste_guard: None,
+            lockout_prompt: None,
             dialog_drag: None,
             pending_window_additions: Vec::new(),
         }
@@ -416,6 +567,10 @@ impl UiState {
     /// Remove a window by name
     pub fn remove_window(&mut self, name: &str) -> Option<WindowState> {
         let result = self.windows.remove(name);
+        self.floating_windows.retain(|n| n != name);
+        if self.input_focus.as_deref() == Some(name) {
+            self.input_focus = None;
+        }
         if result.is_some() {
             self.rebuild_widget_index();
         }
@@ -479,7 +634,9 @@ impl UiState {
         }
     }
 
-    /// Set the focused window
+    /// Set the focused window. Focusing a widget that captures input gives
+    /// it the keyboard too; any other window hands it back to the command
+    /// line.
     pub fn set_focus(&mut self, name: Option<String>) {
         // Clear old focus
         if let Some(old_name) = &self.focused_window {
@@ -495,9 +652,90 @@ impl UiState {
             }
         }
 
+        self.input_focus = name.clone().filter(|name| {
+            self.windows
+                .get(name)
+                .is_some_and(|window| window.widget_type.captures_input())
+        });
         self.focused_window = name;
     }
 
+    /// Give the keyboard back to the command line. Returns whether a
+    /// widget had it.
+    pub fn release_input_focus(&mut self) -> bool {
+        self.input_focus.take().is_some()
+    }
+
+    /// Check whether a window is floating
+    pub fn is_floating(&self, name: &str) -> bool {
+        self.floating_windows.iter().any(|n| n == name)
+    }
+
+    /// Mark a window as floating (placed on top) or return it to the tiled layer
+    pub fn set_floating(&mut self, name: &str, floating: bool) {
+        self.floating_windows.retain(|n| n != name);
+        if floating {
+            self.floating_windows.push(name.to_string());
+        }
+    }
+
+    /// Raise a floating window to the top of the stack
+    /// Returns false if the window is not floating
+    pub fn raise_window(&mut self, name: &str) -> bool {
+        let Some(pos) = self.floating_windows.iter().position(|n| n == name) else {
+            return false;
+        };
+        if pos + 1 != self.floating_windows.len() {
+            let entry = self.floating_windows.remove(pos);
+            self.floating_windows.push(entry);
+        }
+        true
+    }
+
+    /// Z-index of a floating window (position in the stack), None for tiled windows
+    pub fn floating_z_index(&self, name: &str) -> Option<i32> {
+        self.floating_windows
+            .iter()
+            .position(|n| n == name)
+            .map(|pos| pos as i32 + 1)
+    }
+
+    /// Whether a window should be drawn/hit-tested this frame
+    /// (floating windows are skipped while floating_hidden is set, and any
+    /// window while its `show_when` condition is false)
+    pub fn is_window_suppressed(&self, name: &str) -> bool {
+        (self.floating_hidden && self.is_floating(name)) || self.hidden_by_condition.contains(name)
+    }
+
+    /// Tabs for compact mode: visible text windows in `order`, with "main"
+    /// always first
+    pub fn compact_tab_names(&self, order: &[&str]) -> Vec<String> {
+        let is_tab = |name: &str| {
+            self.windows.get(name).is_some_and(|window| {
+                window.visible && matches!(window.content, WindowContent::Text(_))
+            })
+        };
+        let mut tabs: Vec<String> = Vec::new();
+        if is_tab("main") {
+            tabs.push("main".to_string());
+        }
+        for name in order {
+            if *name != "main" && is_tab(name) && !tabs.iter().any(|t| t == name) {
+                tabs.push(name.to_string());
+            }
+        }
+        tabs
+    }
+
+    /// The compact tab to draw, falling back to the first tab when the
+    /// selected one was closed or hidden
+    pub fn compact_active_tab<'a>(&self, tabs: &'a [String]) -> Option<&'a String> {
+        self.compact_tab
+            .as_ref()
+            .and_then(|selected| tabs.iter().find(|tab| *tab == selected))
+            .or_else(|| tabs.first())
+    }
+
     /// Get the currently focused window
     pub fn focused_window(&self) -> Option<&WindowState> {
         self.focused_window
@@ -635,6 +873,98 @@ mod tests {
         assert!(state.focused_window().is_none());
     }
 
+    #[test]
+    fn test_focus_on_capturing_widget_takes_input() {
+        let mut state = UiState::new();
+        state.set_window("main".to_string(), WindowState::new_text("main", 100));
+        let mut notes = WindowState::new_text("notes", 10);
+        notes.widget_type = crate::data::window::WidgetType::Notes;
+        state.set_window("notes".to_string(), notes);
+
+        state.set_focus(Some("notes".to_string()));
+        assert_eq!(state.input_focus.as_deref(), Some("notes"));
+        // Esc: back to the command line, window focus stays
+        assert!(state.release_input_focus());
+        assert!(!state.release_input_focus());
+        assert_eq!(state.focused_window.as_deref(), Some("notes"));
+
+        state.set_focus(Some("notes".to_string()));
+        state.set_focus(Some("main".to_string()));
+        assert!(state.input_focus.is_none());
+        state.set_focus(Some("notes".to_string()));
+        state.remove_window("notes");
+        assert!(state.input_focus.is_none());
+    }
+
+    // ==================== Floating Window Tests ====================
+
+    #[test]
+    fn test_set_floating_appends_to_top() {
+        let mut state = UiState::new();
+        state.set_floating("map", true);
+        state.set_floating("log", true);
+        assert_eq!(state.floating_windows, vec!["map", "log"]);
+        assert!(state.is_floating("map"));
+        assert_eq!(state.floating_z_index("log"), Some(2));
+        assert_eq!(state.floating_z_index("main"), None);
+
+        state.set_floating("map", false);
+        assert_eq!(state.floating_windows, vec!["log"]);
+    }
+
+    #[test]
+    fn test_raise_window_moves_to_top() {
+        let mut state = UiState::new();
+        state.set_floating("a", true);
+        state.set_floating("b", true);
+        state.set_floating("c", true);
+        assert!(state.raise_window("a"));
+        assert_eq!(state.floating_windows, vec!["b", "c", "a"]);
+        assert!(!state.raise_window("main"));
+    }
+
+    #[test]
+    fn test_floating_hidden_suppresses_only_floating() {
+        let mut state = UiState::new();
+        state.set_floating("map", true);
+        assert!(!state.is_window_suppressed("map"));
+        state.floating_hidden = true;
+        assert!(state.is_window_suppressed("map"));
+        assert!(!state.is_window_suppressed("main"));
+    }
+
+    #[test]
+    fn test_compact_tabs_put_main_first_and_skip_non_text() {
+        let mut state = UiState::new();
+        for name in ["thoughts", "main", "speech"] {
+            state.set_window(name.to_string(), WindowState::new_text(name, 100));
+        }
+        state.set_window(
+            "command_input".to_string(),
+            WindowState::new_command_input("command_input"),
+        );
+        if let Some(window) = state.windows.get_mut("speech") {
+            window.visible = false;
+        }
+        let tabs = state.compact_tab_names(&["thoughts", "command_input", "speech", "main"]);
+        assert_eq!(tabs, vec!["main".to_string(), "thoughts".to_string()]);
+
+        assert_eq!(
+            state.compact_active_tab(&tabs).map(String::as_str),
+            Some("main")
+        );
+        state.compact_tab = Some("thoughts".to_string());
+        assert_eq!(
+            state.compact_active_tab(&tabs).map(String::as_str),
+            Some("thoughts")
+        );
+        state.compact_tab = Some("speech".to_string());
+        assert_eq!(
+            state.compact_active_tab(&tabs).map(String::as_str),
+            Some("main")
+        );
+    }
+
     // ==================== InputMode Tests ====================
 
     #[test]
@@ -680,6 +1010,12 @@ mod tests {
             InputMode::ThemeEditor,
             InputMode::SettingsEditor,
             InputMode::IndicatorTemplateEditor,
+            InputMode::TextViewer,
+            InputMode::Compose,
+            InputMode::NotesEditor,
+            InputMode::SpellSuggest,
+            InputMode::CommandLog,
+            InputMode::LayoutGallery,
         ];
 
         // All modes should be distinct
@@ -1053,4 +1389,25 @@ mod tests {
         assert_eq!(cloned.input_mode, state.input_mode);
         assert_eq!(cloned.status_text, state.status_text);
     }
+
+    #[test]
+    fn test_paste_guard_selection_wraps() {
+        let mut guard = PasteGuard::new(vec!["look".to_string(), "smile".to_string()]);
+        assert_eq!(guard.selected_choice(), PasteChoice::SendAll);
+        guard.move_selection(-1);
+        assert_eq!(guard.selected_choice(), PasteChoice::Cancel);
+        guard.move_selection(2);
+        assert_eq!(guard.selected_choice(), PasteChoice::SendFirst);
+        assert_eq!(PasteChoice::SendAll.label(2), "Send all 2 lines (a)");
+    }
+
+    #[test]
+    fn test_compose_commands_split_on_blank_lines() {
+        let text = "say This is a long\n  speech over two lines\n\n\nbow\n";
+        assert_eq!(
+            compose_commands(text),
+            vec!["say This is a long speech over two lines", "bow"]
+        );
+        assert!(compose_commands("\n   \n").is_empty());
+    }
 }
@@ -5,7 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::sync::Arc;
 
+use super::intern::intern;
 use crate::config::TimestampPosition;
 
 /// Styled text content for text-based widgets
@@ -31,15 +33,69 @@ pub struct TextContent {
     pub show_timestamps: bool,
     /// Where the timestamp goes on the line (start or end)
     pub timestamp_position: TimestampPosition,
+    /// Flood control: identical consecutive lines collapse into the newest
+    /// line with a "(×N)" counter (see `add_or_collapse`)
+    pub collapse_repeats: bool,
+    /// How many times the newest line has repeated (1 = not collapsed)
+    pub repeat_count: u32,
+    /// Bumps whenever the newest line is rewritten in place, so frontends
+    /// that sync by generation know to redraw it
+    pub tail_revision: u64,
+    /// Coalesce window in seconds (0 = off): a line repeating within this
+    /// long of its last occurrence replaces it, annotated "(×N, 12:01–12:04)"
+    pub coalesce_seconds: u64,
+    /// Lines seen within the coalesce window, oldest first
+    pub repeat_runs: VecDeque<RepeatRun>,
+    /// Conversation view: speaker headers with indented lines beneath
+    /// (see `core::conversation`)
+    pub conversation: bool,
+    /// Speaker of the newest conversation group
+    pub conversation_speaker: Option<String>,
+    /// Set while the window is frozen (`.freeze`): new lines are held or
+    /// dropped instead of shown
+    pub freeze: Option<Freeze>,
 }
 
+/// What a frozen window does with lines that arrive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FreezeMode {
+    /// Keep them (up to `max_lines`) and append them on unfreeze
+    Hold,
+    /// Discard them, counting how many
+    Drop,
+}
+
+/// Lines a frozen window has held back or dropped
+#[derive(Clone, Debug, PartialEq)]
+pub struct Freeze {
+    pub mode: FreezeMode,
+    pub held: VecDeque<StyledLine>,
+    /// Lines discarded: all of them in drop mode, overflow in hold mode
+    pub dropped: usize,
+}
+
+/// A line tracked for time-window coalescing
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepeatRun {
+    pub stream: Arc<str>,
+    pub text: String,
+    /// Unix seconds of the first and latest occurrence
+    pub first: i64,
+    pub last: i64,
+    pub count: u32,
+}
+
+/// Cap on tracked runs so a window full of unique lines stays cheap
+const MAX_REPEAT_RUNS: usize = 64;
+
 /// A single display line with styled segments
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StyledLine {
     pub segments: Vec<TextSegment>,
     /// The stream this line originated from (e.g., "death", "thoughts", "main")
-    /// Used for stream-filtered highlights
-    pub stream: String,
+    /// Used for stream-filtered highlights. Interned: every line of a
+    /// buffer shares one allocation per stream name.
+    pub stream: Arc<str>,
     /// Arrival time (unix seconds), stamped when the line enters a text
     /// buffer. Rendered when a window enables timestamps; None on lines
     /// recorded before this field existed.
@@ -52,8 +108,8 @@ pub struct StyledLine {
 pub struct TextSegment {
     #[serde(default)]
     pub text: String,
-    pub fg: Option<String>, // Hex color "#RRGGBB"
-    pub bg: Option<String>, // Hex color "#RRGGBB"
+    pub fg: Option<Arc<str>>, // Hex color "#RRGGBB", interned
+    pub bg: Option<Arc<str>>, // Hex color "#RRGGBB", interned
     #[serde(default)]
     pub bold: bool,
     /// Render in monospace font (for GUI dual-font rendering)
@@ -78,7 +134,7 @@ impl TextSegment {
     pub fn styled(text: impl Into<String>, fg: Option<String>, bold: bool) -> Self {
         Self {
             text: text.into(),
-            fg,
+            fg: fg.as_deref().map(intern),
             bold,
             ..Default::default()
         }
@@ -95,8 +151,8 @@ impl TextSegment {
     ) -> Self {
         Self {
             text: text.into(),
-            fg,
-            bg,
+            fg: fg.as_deref().map(intern),
+            bg: bg.as_deref().map(intern),
             bold,
             mono,
             span_type,
@@ -115,6 +171,7 @@ pub enum SpanType {
     Spell,       // <spell> tag from parser (spells)
     Speech,      // <preset id="speech"> from parser (player speech)
     System,      // Client/system messages; skip highlight transforms
+    Annotation,  // Client-added suffix (repeat counters); rendered dim
 }
 
 /// Link metadata for clickable text
@@ -290,6 +347,8 @@ pub struct TabDefinition {
     pub show_timestamps: bool,                 // Whether to render timestamps for this tab
     pub ignore_activity: bool,                 // Skip unread indicators/counts
     pub timestamp_position: TimestampPosition, // Position of timestamps (start or end)
+    pub alert_flash: bool,                     // Flash the label on background activity
+    pub alert_sound: Option<String>,           // Sound to play on background activity
 }
 
 /// Holds the state for a single tab, including its definition and content.
@@ -298,6 +357,7 @@ pub struct TabState {
     pub definition: TabDefinition,
     pub content: TextContent,
     pub has_unread: bool, // Whether tab has unread messages
+    pub alerting: bool,   // Whether the label flashes until the tab is viewed
 }
 
 /// Tabbed text window content
@@ -322,6 +382,8 @@ impl TabbedTextContent {
                         show_timestamps,
                         ignore_activity,
                         timestamp_position,
+                        alert_flash: false,
+                        alert_sound: None,
                     };
                     let mut content = TextContent::new(name, max_lines_per_tab);
                     // Mirror timestamp settings onto the tab's content so the
@@ -332,6 +394,7 @@ impl TabbedTextContent {
                         definition,
                         content,
                         has_unread: false,
+                        alerting: false,
                     }
                 },
             )
@@ -342,6 +405,15 @@ impl TabbedTextContent {
         }
     }
 
+    /// Freeze indicator for the whole window (tabs freeze together)
+    pub fn freeze_label(&self) -> Option<String> {
+        describe_freezes(
+            self.tabs
+                .iter()
+                .filter_map(|tab| tab.content.freeze.as_ref()),
+        )
+    }
+
     /// Mark a specific tab as having unread messages
     pub fn mark_tab_unread(&mut self, tab_index: usize) {
         if let Some(tab) = self.tabs.get_mut(tab_index) {
@@ -349,6 +421,9 @@ impl TabbedTextContent {
             if tab_index != self.active_tab_index && !tab.definition.ignore_activity {
                 tab.has_unread = true;
             }
+            if tab_index != self.active_tab_index && tab.definition.alert_flash {
+                tab.alerting = true;
+            }
         }
     }
 
@@ -356,6 +431,22 @@ impl TabbedTextContent {
     pub fn clear_tab_unread(&mut self, tab_index: usize) {
         if let Some(tab) = self.tabs.get_mut(tab_index) {
             tab.has_unread = false;
+            tab.alerting = false;
+        }
+    }
+
+    /// Copy the alert settings of the configured `tabs` onto the matching
+    /// tabs (by name). A tab that no longer flashes stops alerting.
+    pub fn apply_alerts(&mut self, tabs: &[crate::config::TabbedTextTab]) {
+        for tab in &mut self.tabs {
+            let config = tabs.iter().find(|t| t.name == tab.definition.name);
+            tab.definition.alert_flash = config.and_then(|t| t.alert_flash).unwrap_or(false);
+            tab.definition.alert_sound = config
+                .and_then(|t| t.alert_sound.clone())
+                .filter(|sound| !sound.trim().is_empty());
+            if !tab.definition.alert_flash {
+                tab.alerting = false;
+            }
         }
     }
 
@@ -437,6 +528,8 @@ impl TabbedTextContent {
                         show_timestamps,
                         ignore_activity,
                         timestamp_position,
+                        alert_flash: false,
+                        alert_sound: None,
                     };
 
                     // Reuse existing tab content if available
@@ -454,6 +547,7 @@ impl TabbedTextContent {
                             definition,
                             content,
                             has_unread: false,
+                            alerting: false,
                         }
                     }
                 },
@@ -469,6 +563,66 @@ impl TabbedTextContent {
     }
 }
 
+/// Compare two lines by their visible text, ignoring styling and how the
+/// text is split into segments
+/// "frozen, 12 held, 3 dropped" summed over one or more frozen buffers
+fn describe_freezes<'a>(freezes: impl Iterator<Item = &'a Freeze>) -> Option<String> {
+    let mut totals = None;
+    for freeze in freezes {
+        let (held, dropped) = totals.get_or_insert((0, 0));
+        *held += freeze.held.len();
+        *dropped += freeze.dropped;
+    }
+    let (held, dropped) = totals?;
+    let mut label = String::from("frozen");
+    if held > 0 {
+        label.push_str(&format!(", {} held", held));
+    }
+    if dropped > 0 {
+        label.push_str(&format!(", {} dropped", dropped));
+    }
+    Some(label)
+}
+
+fn same_text(a: &[TextSegment], b: &[TextSegment]) -> bool {
+    a.iter()
+        .flat_map(|segment| segment.text.chars())
+        .eq(b.iter().flat_map(|segment| segment.text.chars()))
+}
+
+/// A line's segments without its trailing repeat annotation, if any
+fn without_annotation(segments: &[TextSegment]) -> &[TextSegment] {
+    match segments.last() {
+        Some(last) if last.span_type == SpanType::Annotation => &segments[..segments.len() - 1],
+        _ => segments,
+    }
+}
+
+fn annotation(text: String) -> TextSegment {
+    TextSegment {
+        text,
+        span_type: SpanType::Annotation,
+        ..Default::default()
+    }
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// Local clock time for a coalesced range
+fn clock(timestamp: i64) -> String {
+    use chrono::TimeZone;
+    chrono::Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| crate::core::locale::format_time(&time))
+        .unwrap_or_default()
+}
+
 impl TextContent {
     pub fn new(title: impl Into<String>, max_lines: usize) -> Self {
         Self {
@@ -481,10 +635,171 @@ impl TextContent {
             compact: false,  // Default to disabled - set during window creation from layout
             show_timestamps: false,
             timestamp_position: TimestampPosition::default(),
+            collapse_repeats: false,
+            repeat_count: 0,
+            tail_revision: 0,
+            coalesce_seconds: 0,
+            repeat_runs: VecDeque::new(),
+            conversation: false,
+            conversation_speaker: None,
+            freeze: None,
         }
     }
 
-    pub fn add_line(&mut self, mut line: StyledLine) {
+    /// Stop showing new lines until `unfreeze`. Re-freezing switches the
+    /// mode but keeps anything already held.
+    pub fn freeze(&mut self, mode: FreezeMode) {
+        match &mut self.freeze {
+            Some(freeze) => freeze.mode = mode,
+            None => {
+                self.freeze = Some(Freeze {
+                    mode,
+                    held: VecDeque::new(),
+                    dropped: 0,
+                })
+            }
+        }
+    }
+
+    /// Resume showing new lines, appending whatever was held meanwhile.
+    /// Returns how many lines were appended.
+    pub fn unfreeze(&mut self) -> usize {
+        let Some(freeze) = self.freeze.take() else {
+            return 0;
+        };
+        let count = freeze.held.len();
+        for line in freeze.held {
+            self.add_or_collapse(line);
+        }
+        count
+    }
+
+    /// Title indicator for a frozen window, e.g. "frozen, 12 held"
+    pub fn freeze_label(&self) -> Option<String> {
+        describe_freezes(self.freeze.iter())
+    }
+
+    /// Divert a line into the freeze state. Returns it back when not frozen.
+    fn hold(&mut self, mut line: StyledLine) -> Option<StyledLine> {
+        let Some(freeze) = &mut self.freeze else {
+            return Some(line);
+        };
+        match freeze.mode {
+            FreezeMode::Drop => freeze.dropped += 1,
+            FreezeMode::Hold => {
+                // Stamp now: on unfreeze the line should read as when it arrived
+                line.timestamp.get_or_insert_with(unix_now);
+                freeze.held.push_back(line);
+                if self.max_lines > 0 && freeze.held.len() > self.max_lines {
+                    freeze.held.pop_front();
+                    freeze.dropped += 1;
+                }
+            }
+        }
+        None
+    }
+
+    /// Add a line, or when repeat collapsing is enabled and it matches the
+    /// newest line, bump that line's "(×N)" counter instead. With a coalesce
+    /// window set, repeats within it are folded as described on
+    /// `coalesce_seconds`.
+    pub fn add_or_collapse(&mut self, line: StyledLine) {
+        let Some(line) = self.hold(line) else {
+            return;
+        };
+        if self.coalesce_seconds > 0 {
+            self.coalesce(line);
+            return;
+        }
+        if self.collapse_repeats && self.repeat_count > 0 {
+            if let Some(last) = self.lines.back_mut() {
+                if last.stream == line.stream
+                    && same_text(without_annotation(&last.segments), &line.segments)
+                {
+                    if self.repeat_count > 1 {
+                        last.segments.pop();
+                    }
+                    self.repeat_count += 1;
+                    last.segments
+                        .push(annotation(format!(" (×{})", self.repeat_count)));
+                    self.tail_revision = self.tail_revision.wrapping_add(1);
+                    return;
+                }
+            }
+        }
+        self.add_line(line);
+    }
+
+    /// Time-window coalescing: the earlier copy of a repeated line is
+    /// replaced by the new one, annotated with the count and time range.
+    /// Rewriting the newest line is a tail rewrite; moving an older line to
+    /// the end forces frontends to resync the buffer.
+    fn coalesce(&mut self, mut line: StyledLine) {
+        let now = *line.timestamp.get_or_insert_with(unix_now);
+        let window = self.coalesce_seconds as i64;
+        self.repeat_runs.retain(|run| now - run.last <= window);
+
+        let text: String = line.segments.iter().map(|s| s.text.as_str()).collect();
+        let run_idx = self
+            .repeat_runs
+            .iter()
+            .position(|run| run.stream == line.stream && run.text == text);
+        // The run's line is the newest copy of its text (any later copy
+        // would have been coalesced into it)
+        let pos = run_idx.and_then(|_| {
+            self.lines.iter().rposition(|existing| {
+                existing.stream == line.stream
+                    && same_text(without_annotation(&existing.segments), &line.segments)
+            })
+        });
+
+        let (Some(run_idx), Some(pos)) = (run_idx, pos) else {
+            // New (or its earlier copy was cleared/pruned): start a run
+            if let Some(idx) = run_idx {
+                self.repeat_runs.remove(idx);
+            }
+            self.repeat_runs.push_back(RepeatRun {
+                stream: line.stream.clone(),
+                text,
+                first: now,
+                last: now,
+                count: 1,
+            });
+            if self.repeat_runs.len() > MAX_REPEAT_RUNS {
+                self.repeat_runs.pop_front();
+            }
+            self.add_line(line);
+            return;
+        };
+
+        let run = &mut self.repeat_runs[run_idx];
+        run.count += 1;
+        run.last = now;
+        let (first, last) = (clock(run.first), clock(run.last));
+        let range = if first == last {
+            first
+        } else {
+            format!("{}–{}", first, last)
+        };
+        line.segments
+            .push(annotation(format!(" (×{}, {})", run.count, range)));
+
+        if pos + 1 == self.lines.len() {
+            self.lines[pos] = line;
+            self.tail_revision = self.tail_revision.wrapping_add(1);
+        } else {
+            self.lines.remove(pos);
+            self.lines.push_back(line);
+            // A generation jump past the line count makes frontends resync
+            // the whole buffer instead of appending
+            self.generation = self.generation.wrapping_add(self.lines.len() as u64 + 1);
+        }
+    }
+
+    pub fn add_line(&mut self, line: StyledLine) {
+        let Some(mut line) = self.hold(line) else {
+            return;
+        };
         // Stamp arrival time once, centrally, so any window that enables
         // timestamps (now or later) can render when each line arrived.
         if line.timestamp.is_none() {
@@ -501,6 +816,7 @@ impl TextContent {
         // Increment generation counter on every add_line call
         // This allows frontend to detect changes even when line count stays constant
         self.generation = self.generation.wrapping_add(1);
+        self.repeat_count = 1;
     }
 
     pub fn scroll_up(&mut self, amount: usize) {
@@ -534,7 +850,7 @@ impl StyledLine {
                 span_type: SpanType::Normal,
                 link_data: None,
             }],
-            stream: String::from("main"),
+            stream: intern("main"),
             timestamp: None,
         }
     }
@@ -551,7 +867,7 @@ impl StyledLine {
                 span_type: SpanType::Normal,
                 link_data: None,
             }],
-            stream: stream.into(),
+            stream: intern(&stream.into()),
             timestamp: None,
         }
     }
@@ -601,14 +917,14 @@ mod tests {
     #[test]
     fn test_styled_line_json_round_trip() {
         let line = StyledLine {
-            stream: "main".to_string(),
+            stream: "main".into(),
             timestamp: Some(1_720_000_000),
             segments: vec![
                 TextSegment::plain("You see "),
                 TextSegment {
                     text: "a kobold".to_string(),
-                    fg: Some("#ff0000".to_string()),
-                    bg: Some("#000000".to_string()),
+                    fg: Some(intern("#ff0000")),
+                    bg: Some(intern("#000000")),
                     bold: true,
                     mono: false,
                     span_type: SpanType::Monsterbold,
@@ -682,6 +998,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_content_freeze_hold_and_drop() {
+        let mut content = TextContent::new("Test", 3);
+        content.add_line(StyledLine::from_text("before"));
+
+        content.freeze(FreezeMode::Hold);
+        for i in 0..4 {
+            content.add_line(StyledLine::from_text(format!("held {}", i)));
+        }
+        assert_eq!(content.lines.len(), 1);
+        assert_eq!(content.generation, 1);
+        assert_eq!(
+            content.freeze_label().as_deref(),
+            Some("frozen, 3 held, 1 dropped")
+        );
+
+        assert_eq!(content.unfreeze(), 3);
+        assert!(content.freeze_label().is_none());
+        let text: Vec<_> = content
+            .lines
+            .iter()
+            .map(|l| l.segments[0].text.as_str())
+            .collect();
+        assert_eq!(text, vec!["held 1", "held 2", "held 3"]);
+
+        content.freeze(FreezeMode::Drop);
+        content.add_or_collapse(StyledLine::from_text("noise"));
+        assert_eq!(content.freeze_label().as_deref(), Some("frozen, 1 dropped"));
+        assert_eq!(content.unfreeze(), 0);
+        assert_eq!(content.lines.back().unwrap().segments[0].text, "held 3");
+    }
+
+    #[test]
+    fn test_text_content_collapse_repeats() {
+        let mut content = TextContent::new("Arrivals", 100);
+        content.collapse_repeats = true;
+
+        for _ in 0..3 {
+            content.add_or_collapse(StyledLine::from_text("Someone just arrived."));
+        }
+        assert_eq!(content.lines.len(), 1);
+        assert_eq!(content.generation, 1);
+        assert_eq!(content.tail_revision, 2);
+        let text: String = content.lines[0]
+            .segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(text, "Someone just arrived. (×3)");
+
+        // A different line (or the same text on another stream) starts over
+        content.add_or_collapse(StyledLine::from_text_with_stream(
+            "Someone just arrived.",
+            "logons",
+        ));
+        content.add_or_collapse(StyledLine::from_text("Bob just arrived."));
+        content.add_or_collapse(StyledLine::from_text("Bob just arrived."));
+        assert_eq!(content.lines.len(), 3);
+        let text: String = content.lines[2]
+            .segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(text, "Bob just arrived. (×2)");
+    }
+
+    #[test]
+    fn test_text_content_coalesce_within_window() {
+        let mut content = TextContent::new("Main", 100);
+        content.coalesce_seconds = 300;
+        let at = |text: &str, ts: i64| StyledLine {
+            timestamp: Some(ts),
+            ..StyledLine::from_text(text)
+        };
+
+        content.add_or_collapse(at("You feel fully rested.", 1_000));
+        content.add_or_collapse(at("A breeze blows.", 1_010));
+        content.add_or_collapse(at("You feel fully rested.", 1_060));
+        // The older copy moved to the end with its count
+        assert_eq!(content.lines.len(), 2);
+        assert_eq!(content.lines[0].segments[0].text, "A breeze blows.");
+        let newest = &content.lines[1];
+        assert_eq!(newest.segments[0].text, "You feel fully rested.");
+        assert_eq!(newest.segments[1].span_type, SpanType::Annotation);
+        assert!(newest.segments[1].text.starts_with(" (×2, "));
+        // Moving a line forces a full resync
+        assert!(content.generation > content.lines.len() as u64 + 2);
+
+        // Immediate repeat rewrites the tail in place
+        let revision = content.tail_revision;
+        content.add_or_collapse(at("You feel fully rested.", 1_120));
+        assert_eq!(content.lines.len(), 2);
+        assert_eq!(content.tail_revision, revision + 1);
+        assert!(content.lines[1].segments[1].text.starts_with(" (×3, "));
+
+        // Outside the window it's a fresh line again
+        content.add_or_collapse(at("You feel fully rested.", 2_000));
+        assert_eq!(content.lines.len(), 3);
+        assert_eq!(content.lines[2].segments.len(), 1);
+    }
+
+    #[test]
+    fn test_text_content_collapse_disabled() {
+        let mut content = TextContent::new("Main", 100);
+        content.add_or_collapse(StyledLine::from_text("Same"));
+        content.add_or_collapse(StyledLine::from_text("Same"));
+        assert_eq!(content.lines.len(), 2);
+        assert_eq!(content.tail_revision, 0);
+    }
+
     #[test]
     fn test_text_content_scroll_up() {
         let mut content = TextContent::new("Test", 100);
@@ -766,7 +1192,7 @@ mod tests {
     fn test_text_segment_with_link() {
         let segment = TextSegment {
             text: "a rusty sword".to_string(),
-            fg: Some("#477ab3".to_string()),
+            fg: Some(intern("#477ab3")),
             bg: None,
             bold: false,
             mono: false,
@@ -789,7 +1215,7 @@ mod tests {
     fn test_text_segment_equality() {
         let seg1 = TextSegment {
             text: "test".to_string(),
-            fg: Some("#FF0000".to_string()),
+            fg: Some(intern("#FF0000")),
             bg: None,
             bold: true,
             mono: false,
@@ -799,7 +1225,7 @@ mod tests {
 
         let seg2 = TextSegment {
             text: "test".to_string(),
-            fg: Some("#FF0000".to_string()),
+            fg: Some(intern("#FF0000")),
             bg: None,
             bold: true,
             mono: false,
@@ -809,7 +1235,7 @@ mod tests {
 
         let seg3 = TextSegment {
             text: "different".to_string(),
-            fg: Some("#FF0000".to_string()),
+            fg: Some(intern("#FF0000")),
             bg: None,
             bold: true,
             mono: false,
@@ -1183,4 +1609,55 @@ mod tests {
         assert_eq!(tabbed.tabs.len(), 1);
         assert_eq!(tabbed.active_tab_index, 0); // Should be clamped
     }
+
+    #[test]
+    fn test_tabbed_text_content_alert_flashes_until_viewed() {
+        use super::TabbedTextContent;
+        use crate::config::{TabbedTextTab, TimestampPosition};
+
+        let mut tabbed = TabbedTextContent::new(
+            vec![
+                (
+                    "Main".to_string(),
+                    vec!["main".to_string()],
+                    false,
+                    false,
+                    TimestampPosition::End,
+                ),
+                (
+                    "Thoughts".to_string(),
+                    vec!["thoughts".to_string()],
+                    false,
+                    true,
+                    TimestampPosition::End,
+                ),
+            ],
+            1000,
+        );
+        tabbed.apply_alerts(&[TabbedTextTab {
+            name: "Thoughts".to_string(),
+            stream: None,
+            streams: vec!["thoughts".to_string()],
+            show_timestamps: None,
+            ignore_activity: Some(true),
+            timestamp_position: None,
+            alert_sound: Some("ping.wav".to_string()),
+            alert_flash: Some(true),
+        }]);
+        assert_eq!(
+            tabbed.tabs[1].definition.alert_sound.as_deref(),
+            Some("ping.wav")
+        );
+
+        // Flashing doesn't depend on the unread indicator
+        tabbed.mark_tab_unread(1);
+        assert!(!tabbed.tabs[1].has_unread);
+        assert!(tabbed.tabs[1].alerting);
+
+        tabbed.mark_tab_unread(0);
+        assert!(!tabbed.tabs[0].alerting);
+
+        tabbed.clear_tab_unread(1);
+        assert!(!tabbed.tabs[1].alerting);
+    }
 }
@@ -46,6 +46,17 @@ pub enum WidgetType {
     Experience,
     GS4Experience,
     Encumbrance,
+    Stance,
+    Wealth,
+    TaskTracker,
+    SocietyTasks,
+    CombatStats,
+    Calendar,
+    Reminders,
+    Notes,
+    Watch,
+    Traffic,
+    Played,
     Quickbar,
     Hotkeybar,
     MiniVitals,
@@ -94,6 +105,17 @@ impl WidgetType {
             "experience" => Some(WidgetType::Experience),
             "gs4_experience" => Some(WidgetType::GS4Experience),
             "encum" => Some(WidgetType::Encumbrance),
+            "stance" => Some(WidgetType::Stance),
+            "wealth" => Some(WidgetType::Wealth),
+            "task_tracker" => Some(WidgetType::TaskTracker),
+            "society_tasks" => Some(WidgetType::SocietyTasks),
+            "combat_stats" => Some(WidgetType::CombatStats),
+            "calendar" => Some(WidgetType::Calendar),
+            "reminders" => Some(WidgetType::Reminders),
+            "notes" => Some(WidgetType::Notes),
+            "watch" => Some(WidgetType::Watch),
+            "traffic" => Some(WidgetType::Traffic),
+            "played" => Some(WidgetType::Played),
             "quickbar" => Some(WidgetType::Quickbar),
             "hotkeybar" => Some(WidgetType::Hotkeybar),
             "minivitals" => Some(WidgetType::MiniVitals),
@@ -130,6 +152,17 @@ impl WidgetType {
         "experience",
         "gs4_experience",
         "encum",
+        "stance",
+        "wealth",
+        "task_tracker",
+        "society_tasks",
+        "combat_stats",
+        "calendar",
+        "reminders",
+        "notes",
+        "watch",
+        "traffic",
+        "played",
         "quickbar",
         "hotkeybar",
         "minivitals",
@@ -137,6 +170,13 @@ impl WidgetType {
         "webui",
         "map",
     ];
+
+    /// Widgets that take keystrokes while focused (the TUI's input focus):
+    /// arrows, Enter and the like go to the widget instead of the command
+    /// line until Esc
+    pub fn captures_input(&self) -> bool {
+        matches!(self, WidgetType::Quickbar | WidgetType::Notes)
+    }
 }
 
 /// Window content - what the window displays
@@ -194,6 +234,39 @@ pub enum WindowContent {
     /// Encumbrance window - displays progress bar + optional label
     /// Reads from GameState.encumbrance (no data stored here)
     Encumbrance,
+    /// Stance window - stance bar + posture
+    /// Reads from GameState.stance and GameState.status (no data stored here)
+    Stance,
+    /// Wealth window - silver on hand + session delta
+    /// Reads from GameState.wealth (no data stored here)
+    Wealth,
+    /// Task tracker window - structured bounty with progress
+    /// Reads from GameState.bounty (no data stored here)
+    TaskTracker,
+    /// Society task checklist - society status, tasks and progress
+    /// Reads from GameState.society (no data stored here)
+    SocietyTasks,
+    /// Combat stats window - hit %, damage rate, best hit, per creature
+    /// Reads from GameState.combat (no data stored here)
+    CombatStats,
+    /// Calendar window - Elanthian date/time and moon phases
+    /// Reads from GameState.calendar (no data stored here)
+    Calendar,
+    /// Reminders window - upcoming `.remind` reminders
+    /// Reads from AppCore.reminders (no data stored here)
+    Reminders,
+    /// Notes window - the character notepad (`.notes`)
+    /// Reads from AppCore.notes (no data stored here)
+    Notes,
+    /// Watch window - labeled values of state expressions (`.watch`)
+    /// Evaluated from GameState and variables each sync (no data stored here)
+    Watch,
+    /// Traffic console - raw socket lines (`.traffic`)
+    /// Reads from network::traffic (no data stored here)
+    Traffic,
+    /// Played time - session timer, today and total (`.played`)
+    /// Reads from AppCore.played (no data stored here)
+    Played,
     Quickbar,
     /// Hotkey bar - buttons resolved each frame from config.hotbars +
     /// GameState by core::hotbar::resolve_bar; carries only its bar binding
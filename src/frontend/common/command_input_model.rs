@@ -1,3 +1,4 @@
+use super::text_width;
 use std::collections::VecDeque;
 
 #[derive(Clone, Debug)]
@@ -7,6 +8,38 @@ struct CommandInputSnapshot {
     selection: Option<(usize, usize)>,
 }
 
+/// Result of shell-style history expansion on a submitted line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HistoryExpansion {
+    /// The line is not a history reference; send it as typed.
+    None,
+    /// The line referenced this history entry.
+    Expanded(String),
+    /// The line was a history reference with no matching entry.
+    NotFound,
+}
+
+/// Expand a whole-line history reference against `history` (newest first):
+/// `!!` recalls the last command, `!att` the last one starting with "att".
+/// A bare `!` or `! text` is not a reference.
+pub fn expand_history<'a>(
+    text: &str,
+    history: impl IntoIterator<Item = &'a String>,
+) -> HistoryExpansion {
+    let Some(reference) = text.trim().strip_prefix('!') else {
+        return HistoryExpansion::None;
+    };
+    if reference.is_empty() || reference.starts_with(char::is_whitespace) {
+        return HistoryExpansion::None;
+    }
+    let prefix = if reference == "!" { "" } else { reference };
+    history
+        .into_iter()
+        .find(|entry| entry.starts_with(prefix))
+        .map(|entry| HistoryExpansion::Expanded(entry.clone()))
+        .unwrap_or(HistoryExpansion::NotFound)
+}
+
 /// Frontend-agnostic command input state and editing logic.
 #[derive(Clone, Debug)]
 pub struct CommandInputModel {
@@ -24,6 +57,10 @@ pub struct CommandInputModel {
     completion_candidates: Vec<String>,
     completion_index: Option<usize>,
     completion_prefix: Option<String>,
+    history_expansion: bool,
+    history_prefix_search: bool,
+    /// Text typed before a Ctrl+P/N prefix search started
+    history_search_prefix: Option<String>,
 }
 
 impl CommandInputModel {
@@ -43,6 +80,9 @@ impl CommandInputModel {
             completion_candidates: Vec::new(),
             completion_index: None,
             completion_prefix: None,
+            history_expansion: true,
+            history_prefix_search: true,
+            history_search_prefix: None,
         }
     }
 
@@ -50,6 +90,14 @@ impl CommandInputModel {
         self.min_command_length = min_length;
     }
 
+    /// Toggle `!!` / `!prefix` expansion on submit and prefix filtering for
+    /// `history_search_previous`/`_next` (`ui.history_expansion`,
+    /// `ui.history_prefix_search`).
+    pub fn set_history_options(&mut self, expansion: bool, prefix_search: bool) {
+        self.history_expansion = expansion;
+        self.history_prefix_search = prefix_search;
+    }
+
     pub fn text(&self) -> &str {
         &self.text
     }
@@ -111,9 +159,12 @@ impl CommandInputModel {
             return;
         }
         self.push_undo_snapshot();
-        let byte_idx = self.char_pos_to_byte_idx(self.cursor_pos - 1);
-        self.text.remove(byte_idx);
-        self.cursor_pos -= 1;
+        // Whole grapheme, so a combining accent goes with its letter
+        let start = text_width::prev_grapheme(&self.text, self.cursor_pos);
+        let start_byte = self.char_pos_to_byte_idx(start);
+        let end_byte = self.char_pos_to_byte_idx(self.cursor_pos);
+        self.text.drain(start_byte..end_byte);
+        self.cursor_pos = start;
         self.reset_completion();
         self.is_user_typed = true;
         self.redo_stack.clear();
@@ -170,18 +221,13 @@ impl CommandInputModel {
 
     pub fn move_cursor_left(&mut self, extend: bool) {
         let anchor_cursor = self.cursor_pos;
-        if self.cursor_pos > 0 {
-            self.cursor_pos -= 1;
-        }
+        self.cursor_pos = text_width::prev_grapheme(&self.text, self.cursor_pos);
         self.update_selection(extend, anchor_cursor);
     }
 
     pub fn move_cursor_right(&mut self, extend: bool) {
         let anchor_cursor = self.cursor_pos;
-        let char_count = self.text.chars().count();
-        if self.cursor_pos < char_count {
-            self.cursor_pos += 1;
-        }
+        self.cursor_pos = text_width::next_grapheme(&self.text, self.cursor_pos);
         self.update_selection(extend, anchor_cursor);
     }
 
@@ -246,6 +292,25 @@ impl CommandInputModel {
         self.redo_stack.clear();
     }
 
+    /// Replace the chars in `start..end` (spellcheck corrections), leaving
+    /// the cursor after the new text. Undoable.
+    pub fn replace_range(&mut self, start: usize, end: usize, text: &str) {
+        let len = self.text.chars().count();
+        let (start, end) = (start.min(len), end.min(len));
+        if start > end {
+            return;
+        }
+        self.push_undo_snapshot();
+        let start_byte = self.char_pos_to_byte_idx(start);
+        let end_byte = self.char_pos_to_byte_idx(end);
+        self.text.replace_range(start_byte..end_byte, text);
+        self.cursor_pos = start + text.chars().count();
+        self.clear_selection();
+        self.reset_completion();
+        self.is_user_typed = true;
+        self.redo_stack.clear();
+    }
+
     pub fn get_input(&self) -> Option<String> {
         if self.text.is_empty() {
             None
@@ -282,7 +347,15 @@ impl CommandInputModel {
         if self.text.is_empty() {
             return None;
         }
-        let command = self.text.clone();
+        let mut command = self.text.clone();
+        if self.history_expansion {
+            match expand_history(&command, &self.history) {
+                HistoryExpansion::Expanded(expanded) => command = expanded,
+                // Leave the reference in the input, like a shell's "event not found"
+                HistoryExpansion::NotFound => return None,
+                HistoryExpansion::None => {}
+            }
+        }
         if command.len() >= self.min_command_length {
             let should_add = self
                 .history
@@ -299,6 +372,7 @@ impl CommandInputModel {
         self.text.clear();
         self.cursor_pos = 0;
         self.history_index = None;
+        self.history_search_prefix = None;
         self.is_user_typed = false;
         self.clear_selection();
         self.reset_completion();
@@ -333,6 +407,14 @@ impl CommandInputModel {
         }
     }
 
+    /// Drop `command` from the top of history, for a just-submitted reply
+    /// to a password prompt.
+    pub fn forget_latest(&mut self, command: &str) {
+        if self.history.front().map(String::as_str) == Some(command) {
+            self.history.pop_front();
+        }
+    }
+
     pub fn get_last_command(&self) -> Option<String> {
         self.history.front().cloned()
     }
@@ -395,6 +477,84 @@ impl CommandInputModel {
         }
     }
 
+    /// Ctrl+P: step to the next older history entry starting with the text
+    /// typed before the search began (plain `history_previous` when prefix
+    /// search is off or nothing was typed).
+    pub fn history_search_previous(&mut self) {
+        let prefix = self.current_search_prefix();
+        if prefix.is_empty() {
+            self.history_previous();
+            return;
+        }
+        let start = self.history_index.map(|idx| idx + 1).unwrap_or(0);
+        let found = self
+            .history
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find(|(_, entry)| entry.starts_with(&prefix))
+            .map(|(idx, _)| idx);
+        if let Some(idx) = found {
+            self.history_search_prefix = Some(prefix);
+            self.show_history_entry(idx);
+        }
+    }
+
+    /// Ctrl+N: step to the next newer matching entry; past the newest, the
+    /// originally typed prefix comes back.
+    pub fn history_search_next(&mut self) {
+        let prefix = self.current_search_prefix();
+        if prefix.is_empty() {
+            self.history_next();
+            return;
+        }
+        let Some(current) = self.history_index else {
+            return;
+        };
+        let found = (0..current)
+            .rev()
+            .find(|idx| self.history[*idx].starts_with(&prefix));
+        match found {
+            Some(idx) => self.show_history_entry(idx),
+            None => {
+                self.history_search_prefix = None;
+                self.history_index = None;
+                self.text = prefix;
+                self.cursor_pos = self.text.chars().count();
+                self.is_user_typed = true;
+                self.clear_selection();
+                self.reset_completion();
+            }
+        }
+    }
+
+    /// Prefix for Ctrl+P/N: the one captured when the search began, or the
+    /// current text when starting fresh. Empty means "no filtering".
+    fn current_search_prefix(&self) -> String {
+        if !self.history_prefix_search {
+            return String::new();
+        }
+        match (&self.history_search_prefix, self.history_index) {
+            (Some(prefix), Some(_)) => prefix.clone(),
+            (_, None) => self.text.clone(),
+            // Browsing started with plain Up/Down: no filter
+            (None, Some(_)) => String::new(),
+        }
+    }
+
+    fn show_history_entry(&mut self, idx: usize) {
+        if let Some(cmd) = self.history.get(idx) {
+            self.text = cmd.clone();
+            self.cursor_pos = self.text.chars().count();
+            self.history_index = Some(idx);
+            self.is_user_typed = false;
+            self.clear_selection();
+            self.reset_completion();
+            self.undo_stack.clear();
+            self.redo_stack.clear();
+        }
+    }
+
     pub fn undo(&mut self) -> bool {
         let snapshot = match self.undo_stack.pop() {
             Some(snapshot) => snapshot,
@@ -417,9 +577,13 @@ impl CommandInputModel {
         true
     }
 
-    pub fn try_complete(&mut self, available_commands: &[String], window_names: &[String]) {
+    /// Complete the last word (case-insensitively): dot-commands from
+    /// `available_commands`, anything else from `names` (window names after
+    /// a dot-command, game words otherwise). Repeated calls cycle through the
+    /// candidates. Returns false when nothing matched.
+    pub fn try_complete(&mut self, available_commands: &[String], names: &[String]) -> bool {
         if self.cursor_pos != self.text.chars().count() {
-            return;
+            return false;
         }
         if self.completion_candidates.is_empty() {
             let input = self.text.trim();
@@ -432,29 +596,20 @@ impl CommandInputModel {
             };
 
             if word_to_complete.is_empty() {
-                return;
+                return false;
             }
 
-            let mut candidates = Vec::new();
-            if word_to_complete.starts_with('.') {
-                for cmd in available_commands {
-                    if cmd.starts_with(word_to_complete) {
-                        candidates.push(cmd.clone());
-                    }
-                }
+            let pool = if word_to_complete.starts_with('.') {
+                available_commands
             } else {
-                for name in window_names {
-                    if name.starts_with(word_to_complete) {
-                        candidates.push(name.clone());
-                    }
-                }
-            }
+                names
+            };
+            let candidates = crate::core::completion::candidates(pool, word_to_complete);
 
             if candidates.is_empty() {
-                return;
+                return false;
             }
 
-            candidates.sort();
             self.completion_candidates = candidates;
             self.completion_prefix = Some(prefix);
             self.completion_index = Some(0);
@@ -473,6 +628,17 @@ impl CommandInputModel {
                 self.redo_stack.clear();
             }
         }
+        true
+    }
+
+    /// Candidates of the completion in progress (empty when not completing).
+    pub fn completion_candidates(&self) -> &[String] {
+        &self.completion_candidates
+    }
+
+    /// Index of the candidate currently shown in the input.
+    pub fn completion_index(&self) -> Option<usize> {
+        self.completion_index
     }
 
     pub fn reset_completion(&mut self) {
@@ -560,7 +726,7 @@ impl CommandInputModel {
 
 #[cfg(test)]
 mod tests {
-    use super::CommandInputModel;
+    use super::{expand_history, CommandInputModel, HistoryExpansion};
 
     #[test]
     fn select_all_and_delete() {
@@ -573,6 +739,37 @@ mod tests {
         assert_eq!(model.cursor_pos(), 0);
     }
 
+    #[test]
+    fn cursor_and_backspace_step_over_graphemes() {
+        let mut model = CommandInputModel::new(10);
+        // "e" + combining acute, then a flag made of two regional indicators
+        model.insert_text("cafe\u{301} \u{1F1EB}\u{1F1F7}");
+        model.move_cursor_left(false);
+        assert_eq!(model.cursor_pos(), 6);
+        model.move_cursor_left(false);
+        model.move_cursor_left(false);
+        assert_eq!(model.cursor_pos(), 3);
+        model.move_cursor_right(true);
+        assert_eq!(model.get_selected_text().as_deref(), Some("e\u{301}"));
+        model.move_cursor_end(false);
+        model.delete_char();
+        assert_eq!(model.text(), "cafe\u{301} ");
+        model.delete_char();
+        model.delete_char();
+        assert_eq!(model.text(), "caf");
+    }
+
+    #[test]
+    fn replace_range_swaps_a_word() {
+        let mut model = CommandInputModel::new(10);
+        model.insert_text("say teh café");
+        model.replace_range(4, 7, "the");
+        assert_eq!(model.text(), "say the café");
+        assert_eq!(model.cursor_pos(), 7);
+        assert!(model.undo());
+        assert_eq!(model.text(), "say teh café");
+    }
+
     #[test]
     fn undo_redo_basic() {
         let mut model = CommandInputModel::new(10);
@@ -631,6 +828,109 @@ mod tests {
         assert_eq!(model.text(), ".window main");
     }
 
+    #[test]
+    fn completion_matches_game_words_case_insensitively() {
+        let mut model = CommandInputModel::new(10);
+        let words = vec![
+            "attack".to_string(),
+            "Trevor".to_string(),
+            "troll".to_string(),
+        ];
+
+        model.insert_text("attack tr");
+        assert!(model.try_complete(&[], &words));
+        assert_eq!(model.text(), "attack Trevor");
+        assert_eq!(model.completion_candidates().len(), 2);
+        model.try_complete(&[], &words);
+        assert_eq!(model.text(), "attack troll");
+        assert_eq!(model.completion_index(), Some(1));
+
+        model.insert_char(' ');
+        assert!(model.completion_candidates().is_empty());
+        model.insert_text("zz");
+        assert!(!model.try_complete(&[], &words));
+    }
+
+    fn model_with_history(commands: &[&str]) -> CommandInputModel {
+        let mut model = CommandInputModel::new(10);
+        for command in commands {
+            model.insert_text(command);
+            model.submit();
+        }
+        model
+    }
+
+    #[test]
+    fn bang_bang_repeats_last_command() {
+        let mut model = model_with_history(&["attack troll", "look"]);
+        model.insert_text("!!");
+        assert_eq!(model.submit(), Some("look".to_string()));
+        // The expanded command (not "!!") is what lands in history
+        assert_eq!(model.get_last_command(), Some("look".to_string()));
+    }
+
+    #[test]
+    fn bang_prefix_recalls_latest_match() {
+        let mut model = model_with_history(&["attack troll", "look", "attack kobold"]);
+        model.insert_text("!att");
+        assert_eq!(model.submit(), Some("attack kobold".to_string()));
+    }
+
+    #[test]
+    fn unmatched_bang_stays_in_input() {
+        let mut model = model_with_history(&["look"]);
+        model.insert_text("!zzz");
+        assert_eq!(model.submit(), None);
+        assert_eq!(model.text(), "!zzz");
+
+        // Leading spaces don't hide the reference
+        let mut model = model_with_history(&["look"]);
+        model.insert_text("  !zzz");
+        assert_eq!(model.submit(), None);
+        assert_eq!(model.text(), "  !zzz");
+    }
+
+    #[test]
+    fn expansion_can_be_disabled() {
+        let mut model = model_with_history(&["look"]);
+        model.set_history_options(false, true);
+        model.insert_text("!!");
+        assert_eq!(model.submit(), Some("!!".to_string()));
+    }
+
+    #[test]
+    fn bare_bang_is_not_a_reference() {
+        let history = vec!["look".to_string()];
+        assert_eq!(expand_history("!", &history), HistoryExpansion::None);
+        assert_eq!(expand_history("! hello", &history), HistoryExpansion::None);
+        assert_eq!(expand_history("look", &history), HistoryExpansion::None);
+    }
+
+    #[test]
+    fn prefix_search_filters_history() {
+        let mut model = model_with_history(&["attack troll", "look", "attack kobold", "stance"]);
+        model.insert_text("att");
+        model.history_search_previous();
+        assert_eq!(model.text(), "attack kobold");
+        model.history_search_previous();
+        assert_eq!(model.text(), "attack troll");
+        model.history_search_previous();
+        assert_eq!(model.text(), "attack troll");
+        model.history_search_next();
+        assert_eq!(model.text(), "attack kobold");
+        model.history_search_next();
+        assert_eq!(model.text(), "att");
+    }
+
+    #[test]
+    fn prefix_search_off_walks_all_history() {
+        let mut model = model_with_history(&["attack troll", "look"]);
+        model.set_history_options(true, false);
+        model.insert_text("att");
+        model.history_search_previous();
+        assert_eq!(model.text(), "look");
+    }
+
     #[test]
     fn preserves_leading_spaces() {
         let mut model = CommandInputModel::new(10);
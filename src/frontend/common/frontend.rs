@@ -0,0 +1,86 @@
+//! The `Frontend` trait and the shared per-frame steps every frontend runs.
+//!
+//! A frontend owns presentation only: it turns native input into
+//! `FrontendEvent`s, applies them against `AppCore`, copies core state into
+//! its own widgets (`sync`), and draws (`render`). Everything else - network
+//! I/O, server parsing, timers - stays in the runtime loop, which drives any
+//! frontend through the helpers below so the TUI, GUI, and future web
+//! frontends share one event/render sequence.
+
+use anyhow::Result;
+use std::time::Instant;
+
+use crate::core::AppCore;
+use crate::frontend::FrontendEvent;
+
+/// Frontend trait - implemented by every local UI (TUI via ratatui, GUI via
+/// egui) so the runtime loop doesn't care which one it is driving.
+pub trait Frontend {
+    /// Return all pending input converted to `FrontendEvent`s (empty when
+    /// idle). May block briefly; the TUI uses this as its frame pacing.
+    fn poll_events(&mut self) -> Result<Vec<FrontendEvent>>;
+
+    /// Apply one event against core state.
+    ///
+    /// Returns `Some(command)` when the event produced text for the game
+    /// server (a submitted line, a clicked link); the runtime sends it.
+    fn handle_event(&mut self, core: &mut AppCore, event: FrontendEvent) -> Result<Option<String>>;
+
+    /// Copy core state into frontend-owned widgets. Called right before
+    /// `render`; implementations should skip work whose inputs are unchanged.
+    fn sync(&mut self, core: &mut AppCore);
+
+    /// Draw the current state. Mutable because some widgets update
+    /// internal state while drawing (countdowns, scroll clamps).
+    fn render(&mut self, core: &mut AppCore) -> Result<()>;
+
+    /// Restore the terminal / close windows before exit.
+    fn cleanup(&mut self) -> Result<()>;
+
+    /// Current drawable size: terminal cells for the TUI, logical units
+    /// for the GUI.
+    fn size(&self) -> (u16, u16);
+}
+
+/// Run a batch of polled events through `handle_event`, recording per-event
+/// timing and materialising any windows the events queued. Returns the
+/// commands to send to the server, in order.
+pub fn dispatch_events<F: Frontend + ?Sized>(
+    frontend: &mut F,
+    core: &mut AppCore,
+    events: Vec<FrontendEvent>,
+) -> Result<Vec<String>> {
+    core.perf_stats
+        .record_event_queue_depth(events.len() as u64);
+    let mut outbound = Vec::new();
+    for event in events {
+        let event_start = Instant::now();
+        if let Some(command) = frontend.handle_event(core, event)? {
+            core.perf_stats
+                .record_bytes_sent((command.len() + 1) as u64);
+            outbound.push(command);
+        }
+        core.perf_stats
+            .record_event_process_time(event_start.elapsed());
+
+        // Windows queued by the event (e.g. .testline, dialog opens)
+        let (width, height) = frontend.size();
+        core.process_pending_window_additions(width, height);
+    }
+    Ok(outbound)
+}
+
+/// Sync and draw one frame unconditionally.
+pub fn render_frame<F: Frontend + ?Sized>(frontend: &mut F, core: &mut AppCore) -> Result<()> {
+    frontend.sync(core);
+    frontend.render(core)
+}
+
+/// Sync and draw only when core state changed since the last frame.
+pub fn present<F: Frontend + ?Sized>(frontend: &mut F, core: &mut AppCore) -> Result<()> {
+    if core.needs_render {
+        render_frame(frontend, core)?;
+        core.needs_render = false;
+    }
+    Ok(())
+}
@@ -5,10 +5,13 @@
 
 pub mod color;
 pub mod command_input_model;
+pub mod frontend;
 pub mod rect;
 pub mod text_input;
+pub mod text_width;
 
 pub use color::{Color, NamedColor};
-pub use command_input_model::CommandInputModel;
+pub use command_input_model::{expand_history, CommandInputModel, HistoryExpansion};
+pub use frontend::Frontend;
 pub use rect::Rect;
 pub use text_input::TextInput;
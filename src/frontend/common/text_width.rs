@@ -0,0 +1,164 @@
+//! Display width of text in terminal columns.
+//!
+//! Renderers that center, pad or truncate text measure it here instead of
+//! counting chars, so titles, labels and icons with wide glyphs (CJK,
+//! emoji) take the columns ratatui actually gives them in the buffer and
+//! don't run over borders. Widths come from unicode-width, the same tables
+//! ratatui lays text out with.
+//!
+//! `ui.emoji_width = "narrow"` is for terminals that ignore the emoji
+//! presentation selector (U+FE0F) and draw "⚔️" one column wide. Layout
+//! still gives such emoji two columns; the TUI drops the selector from the
+//! finished frame (`narrow_symbol`) so the terminal draws the glyph and
+//! then the blank column after it, instead of leaving the row one short.
+//!
+//! Text inputs keep their cursor as a char index; the grapheme helpers here
+//! move it by whole user-perceived characters, so an accented letter built
+//! from combining marks or a multi-codepoint emoji is stepped over, deleted
+//! and drawn as one.
+
+use crate::config::EmojiWidth;
+use std::sync::atomic::{AtomicBool, Ordering};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Emoji presentation selector
+const VS16: char = '\u{FE0F}';
+
+static NARROW_EMOJI: AtomicBool = AtomicBool::new(false);
+
+/// Set from `ui.emoji_width`; cheap enough to call every frame
+pub fn set_emoji_width(width: EmojiWidth) {
+    NARROW_EMOJI.store(width == EmojiWidth::Narrow, Ordering::Relaxed);
+}
+
+pub fn emoji_width() -> EmojiWidth {
+    if NARROW_EMOJI.load(Ordering::Relaxed) {
+        EmojiWidth::Narrow
+    } else {
+        EmojiWidth::Wide
+    }
+}
+
+/// Columns `text` takes on screen
+pub fn width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Columns one char takes; control chars take none
+pub fn char_width(c: char) -> usize {
+    UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Longest prefix of `text` that fits in `max` columns. A wide glyph that
+/// would straddle the limit is left out whole.
+pub fn truncate(text: &str, max: usize) -> &str {
+    let mut used = 0;
+    for (i, c) in text.char_indices() {
+        let w = char_width(c);
+        if used + w > max {
+            return &text[..i];
+        }
+        used += w;
+    }
+    text
+}
+
+/// `text` cut to `max` columns with a trailing "…" when it doesn't fit
+pub fn truncate_ellipsis(text: &str, max: usize) -> String {
+    if width(text) <= max {
+        return text.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+    format!("{}…", truncate(text, max - 1))
+}
+
+/// `text` padded with spaces to `columns` (never truncated)
+pub fn pad_right(text: &str, columns: usize) -> String {
+    let pad = columns.saturating_sub(width(text));
+    format!("{}{}", text, " ".repeat(pad))
+}
+
+/// A cell symbol without the emoji presentation selector, when narrow emoji
+/// are on and the symbol has one
+pub fn narrow_symbol(symbol: &str) -> Option<String> {
+    if !NARROW_EMOJI.load(Ordering::Relaxed) || !symbol.contains(VS16) {
+        return None;
+    }
+    Some(symbol.chars().filter(|c| *c != VS16).collect())
+}
+
+/// Graphemes of `text` with the char index each starts at
+pub fn graphemes(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.graphemes(true).scan(0, |chars, grapheme| {
+        let start = *chars;
+        *chars += grapheme.chars().count();
+        Some((start, grapheme))
+    })
+}
+
+/// Char index where the grapheme before `char_pos` starts (0 at the start)
+pub fn prev_grapheme(text: &str, char_pos: usize) -> usize {
+    graphemes(text)
+        .map(|(start, _)| start)
+        .take_while(|start| *start < char_pos)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Char index just past the grapheme at `char_pos` (the char count at the
+/// end)
+pub fn next_grapheme(text: &str, char_pos: usize) -> usize {
+    graphemes(text)
+        .map(|(start, grapheme)| start + grapheme.chars().count())
+        .find(|end| *end > char_pos)
+        .unwrap_or_else(|| text.chars().count())
+}
+
+/// Whether `char_pos` falls between graphemes rather than inside one
+pub fn is_grapheme_boundary(text: &str, char_pos: usize) -> bool {
+    char_pos == text.chars().count() || graphemes(text).any(|(start, _)| start == char_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_columns_not_chars() {
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("日本"), 4);
+        assert_eq!(truncate("日本語", 5), "日本");
+        assert_eq!(truncate("abc", 10), "abc");
+        assert_eq!(truncate_ellipsis("日本語", 5), "日本…");
+        assert_eq!(truncate_ellipsis("日本", 4), "日本");
+        assert_eq!(pad_right("日", 4), "日  ");
+    }
+
+    #[test]
+    fn steps_over_whole_graphemes() {
+        // "e" + combining acute, then a family emoji joined with ZWJs
+        let text = "ae\u{301}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}b";
+        let chars = text.chars().count();
+        assert_eq!(next_grapheme(text, 1), 3);
+        assert_eq!(next_grapheme(text, 3), 8);
+        assert_eq!(prev_grapheme(text, 8), 3);
+        assert_eq!(prev_grapheme(text, 3), 1);
+        assert_eq!(prev_grapheme(text, 0), 0);
+        assert_eq!(next_grapheme(text, chars), chars);
+        assert!(is_grapheme_boundary(text, 3));
+        assert!(!is_grapheme_boundary(text, 2));
+        assert!(is_grapheme_boundary(text, chars));
+    }
+
+    #[test]
+    fn narrow_mode_drops_the_presentation_selector() {
+        assert_eq!(narrow_symbol("⚔\u{FE0F}"), None);
+        set_emoji_width(EmojiWidth::Narrow);
+        assert_eq!(narrow_symbol("⚔\u{FE0F}").as_deref(), Some("⚔"));
+        assert_eq!(narrow_symbol("a"), None);
+        set_emoji_width(EmojiWidth::Wide);
+    }
+}
@@ -20,6 +20,8 @@ pub enum FrontendEvent {
     Resize { width: u16, height: u16 },
     /// Paste event (text from clipboard)
     Paste { text: String },
+    /// Terminal/window gained or lost focus
+    Focus { focused: bool },
     /// Application quit signal
     Quit,
 }
@@ -14,6 +14,7 @@ use crate::data::{
     WidgetType, WindowContent, WindowState,
 };
 use crate::network::{LichConnection, RawLogger, ServerMessage};
+use crate::parse_worker::ParsedMessage;
 use anyhow::{anyhow, Context, Result};
 use eframe::egui;
 use eframe::egui::{Color32, Pos2, Rect, RichText, Vec2, ViewportBuilder};
@@ -22,12 +23,17 @@ use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+mod art_panel;
+mod completion;
 mod detached;
 mod map_explorer;
 mod dialogs;
 mod dock;
 mod editors;
+mod lockout_prompt;
 mod menus;
+mod notes_editor;
+mod paste_guard;
 mod status_icons;
 mod theme;
 mod webui_panel;
@@ -85,6 +91,8 @@ pub(super) struct WidgetRenderSettings {
     /// Widget sprite art from the active skin (status icons, compass,
     /// injury doll); None = draw the built-in vector graphics.
     skin_art: Option<std::sync::Arc<skin::SkinWidgetArt>>,
+    /// Active theme asks for glyph/shape cues on color-coded widgets.
+    shape_cues: bool,
 }
 
 impl WidgetRenderSettings {
@@ -152,9 +160,11 @@ pub struct VellumGuiApp {
     app_core: AppCore,
     _runtime: tokio::runtime::Runtime,
     command_tx: mpsc::UnboundedSender<String>,
-    server_rx: mpsc::Receiver<ServerMessage>,
+    server_rx: mpsc::Receiver<ParsedMessage>,
     /// Commands typed on remote web clients (empty when web is disabled).
     remote_rx: mpsc::UnboundedReceiver<crate::core::remote::RemoteEvent>,
+    /// Removes control.json (`vellum-fe send`) when the app closes
+    _control_server: Option<crate::control::ControlServer>,
     network_handle: Option<tokio::task::JoinHandle<()>>,
     command_input: String,
     /// Input-bar history, newest first (same file and semantics as the
@@ -191,6 +201,12 @@ pub struct VellumGuiApp {
     current_theme: crate::theme::AppTheme,
     /// Active skin graphics (config.active_skin); reloaded when it changes.
     skin_state: skin::SkinState,
+    /// Art pack index (`~/.vellum-fe/art/`), scanned on first use.
+    art_pack: Option<crate::data::ArtPack>,
+    /// Image shown for the last clicked noun (`ui.art_panel`) or `.art <name>`.
+    art_panel: Option<art_panel::ArtPanelState>,
+    /// Open `.notes` editor window
+    notes_editor: Option<notes_editor::NotesEditorState>,
     ui_font: FontRef,
     fonts_applied: bool,
     /// Named font families actually registered with egui; a per-tab font
@@ -221,6 +237,8 @@ pub struct VellumGuiApp {
     applied_title_font_size: Option<f32>,
     /// Spacing density currently applied to the egui style.
     applied_density: Option<f32>,
+    /// `ui.reduced_motion` currently applied to the egui style.
+    applied_reduced_motion: Option<bool>,
     settings_editor: Option<editors::SettingsEditorState>,
     highlight_editor: Option<editors::HighlightEditorState>,
     keybind_editor: Option<editors::KeybindEditorState>,
@@ -239,6 +257,8 @@ pub struct VellumGuiApp {
     /// skipped while it is unchanged.
     available_tabs_fingerprint: Option<u64>,
     command_input_id: Option<egui::Id>,
+    /// Tab-completion in progress for the input bar
+    completion: Option<completion::GuiCompletion>,
     repaint_ctx: std::sync::Arc<std::sync::Mutex<Option<egui::Context>>>,
     layout_save_tx: Option<std::sync::mpsc::Sender<GuiLayoutFileV1>>,
     layout_save_worker: Option<std::thread::JoinHandle<()>>,
@@ -324,8 +344,10 @@ impl VellumGuiApp {
             None
         };
 
-        let (server_tx, mut network_rx) =
+        let (server_tx, network_rx) =
             mpsc::channel::<ServerMessage>(crate::network::SERVER_CHANNEL_CAPACITY);
+        // Parse on a worker thread; the frame only applies parsed chunks.
+        let mut network_rx = app_core.start_parse_worker(network_rx)?;
         let (command_tx, command_rx) = mpsc::unbounded_channel::<String>();
 
         // Forward server messages through an intermediary that wakes the egui
@@ -334,7 +356,7 @@ impl VellumGuiApp {
         let repaint_ctx: std::sync::Arc<std::sync::Mutex<Option<egui::Context>>> =
             std::sync::Arc::new(std::sync::Mutex::new(None));
         let (forward_tx, server_rx) =
-            mpsc::channel::<ServerMessage>(crate::network::SERVER_CHANNEL_CAPACITY);
+            mpsc::channel::<ParsedMessage>(crate::parse_worker::PARSED_CHANNEL_CAPACITY);
         let waker_ctx = std::sync::Arc::clone(&repaint_ctx);
         runtime.spawn(async move {
             while let Some(message) = network_rx.recv().await {
@@ -353,6 +375,53 @@ impl VellumGuiApp {
         // and the receiver just sits empty.
         let (remote_forward_tx, remote_rx) =
             mpsc::unbounded_channel::<crate::core::remote::RemoteEvent>();
+
+        // Commands from `vellum-fe send` take the same hop as web commands
+        let control_server =
+            match crate::control::ControlServer::start(app_core.config.character.as_deref()) {
+                Ok((server, mut control_rx)) => {
+                    let forward_tx = remote_forward_tx.clone();
+                    let waker_ctx = std::sync::Arc::clone(&repaint_ctx);
+                    runtime.spawn(async move {
+                        while let Some(text) = control_rx.recv().await {
+                            let event = crate::core::remote::RemoteEvent::Command(text);
+                            if forward_tx.send(event).is_err() {
+                                break;
+                            }
+                            if let Some(ctx) = waker_ctx.lock().ok().and_then(|slot| slot.clone()) {
+                                ctx.request_repaint();
+                            }
+                        }
+                    });
+                    Some(server)
+                }
+                Err(e) => {
+                    tracing::warn!("Control socket unavailable: {e:#}");
+                    None
+                }
+            };
+
+        // So do lines from the `[command_pipe]` named pipe
+        if let Some(mut pipe_rx) = crate::command_pipe::start(
+            &app_core.config.command_pipe,
+            app_core.config.character.as_deref(),
+            false,
+        ) {
+            let forward_tx = remote_forward_tx.clone();
+            let waker_ctx = std::sync::Arc::clone(&repaint_ctx);
+            runtime.spawn(async move {
+                while let Some(text) = pipe_rx.recv().await {
+                    let event = crate::core::remote::RemoteEvent::Command(text);
+                    if forward_tx.send(event).is_err() {
+                        break;
+                    }
+                    if let Some(ctx) = waker_ctx.lock().ok().and_then(|slot| slot.clone()) {
+                        ctx.request_repaint();
+                    }
+                }
+            });
+        }
+
         if let Some(mut event_rx) = web_event_rx {
             let waker_ctx = std::sync::Arc::clone(&repaint_ctx);
             runtime.spawn(async move {
@@ -369,6 +438,7 @@ impl VellumGuiApp {
 
         let host = app_core.config.connection.host.clone();
         let port = app_core.config.connection.port;
+        let proxy = app_core.config.proxy.clone();
 
         let raw_logger = match RawLogger::new(&app_core.config) {
             Ok(logger) => logger,
@@ -378,7 +448,18 @@ impl VellumGuiApp {
             }
         };
 
+        // --demo plays a scripted session instead of connecting
+        let demo_script = app_core.config.demo_script.clone();
         let network_handle = match direct {
+            _ if demo_script.is_some() => runtime.spawn(async move {
+                let script = demo_script.unwrap_or_default();
+                if let Err(err) =
+                    crate::network::demo::DemoConnection::start(&script, server_tx, command_rx)
+                        .await
+                {
+                    tracing::error!("GUI demo session error: {}", err);
+                }
+            }),
             Some(cfg) => runtime.spawn(async move {
                 if let Err(err) =
                     crate::network::DirectConnection::start(cfg, server_tx, command_rx, raw_logger)
@@ -388,9 +469,16 @@ impl VellumGuiApp {
                 }
             }),
             None => runtime.spawn(async move {
-                if let Err(err) =
-                    LichConnection::start(&host, port, login_key, server_tx, command_rx, raw_logger)
-                        .await
+                if let Err(err) = LichConnection::start(
+                    &host,
+                    port,
+                    login_key,
+                    server_tx,
+                    command_rx,
+                    raw_logger,
+                    Some(&proxy),
+                )
+                .await
                 {
                     tracing::error!("GUI network connection error: {}", err);
                 }
@@ -412,6 +500,7 @@ impl VellumGuiApp {
 
         let persisted_layout = load_layout(&layout_profile, &layout_character).ok();
         let available_tabs = Self::collect_available_tabs(&app_core);
+        let tui_zones = Self::zones_from_tui_layout(&app_core.layout, &available_tabs);
         let dock::RestoredLayoutState {
             hidden_tabs,
             main_window_rects,
@@ -427,6 +516,7 @@ impl VellumGuiApp {
         } = Self::restore_layout_state(
             persisted_layout.as_ref(),
             &available_tabs,
+            &tui_zones,
             initial_width,
         );
 
@@ -445,6 +535,7 @@ impl VellumGuiApp {
             command_tx,
             server_rx,
             remote_rx,
+            _control_server: control_server,
             network_handle: Some(network_handle),
             command_input: String::new(),
             command_history,
@@ -470,6 +561,9 @@ impl VellumGuiApp {
             applied_theme_id: None,
             current_theme: crate::theme::AppTheme::default(),
             skin_state: skin::SkinState::default(),
+            art_pack: None,
+            art_panel: None,
+            notes_editor: None,
             ui_font,
             fonts_applied: false,
             registered_font_families: HashSet::new(),
@@ -482,6 +576,7 @@ impl VellumGuiApp {
             startup_music_at: None,
             applied_title_font_size: None,
             applied_density: None,
+            applied_reduced_motion: None,
             settings_editor: None,
             highlight_editor: None,
             keybind_editor: None,
@@ -497,6 +592,7 @@ impl VellumGuiApp {
             search_match_cache: None,
             available_tabs_fingerprint: None,
             command_input_id: None,
+            completion: None,
             repaint_ctx,
             layout_save_tx: Some(layout_save_tx),
             layout_save_worker: Some(layout_save_worker),
@@ -738,7 +834,7 @@ impl VellumGuiApp {
                     .iter()
                     .map(|segments| StyledLine {
                         segments: segments.clone(),
-                        stream: "room".to_string(),
+                        stream: crate::data::intern::intern("room"),
                         timestamp: None,
                     })
                     .collect()
@@ -958,6 +1054,7 @@ impl VellumGuiApp {
                 .get(key)
                 .and_then(|tab| self.skin_state.background_for(&tab.window_name)),
             skin_art: self.skin_state.widget_art(),
+            shape_cues: self.current_theme.shape_cues,
         }
     }
 
@@ -969,10 +1066,30 @@ impl VellumGuiApp {
                 .members
                 .iter()
                 .filter_map(|key| self.available_tabs.get(key))
-                .map(|member| member.id.title.as_str())
+                .map(|member| self.tab_title(member))
                 .collect::<Vec<_>>()
                 .join(" + "),
-            _ => tab.id.title.clone(),
+            _ => self.tab_title(tab),
+        }
+    }
+
+    /// A window's own title; container windows add their item count and
+    /// capacity ("Backpack [12/20]").
+    fn tab_title(&self, tab: &GuiTab) -> String {
+        let container = match self.app_core.ui_state.windows.get(&tab.window_name) {
+            Some(window) => match &window.content {
+                WindowContent::Container { container_title } => self
+                    .app_core
+                    .game_state
+                    .container_cache
+                    .find_by_title(container_title),
+                _ => None,
+            },
+            None => None,
+        };
+        match container {
+            Some(container) => format!("{} {}", tab.id.title, container.count_label()),
+            None => tab.id.title.clone(),
         }
     }
 
@@ -1060,10 +1177,25 @@ impl VellumGuiApp {
                             let rows = (show_bar as u32 + show_label as u32).max(1) as f32;
                             Some(bar_height * rows + gap * (rows - 1.0))
                         }
+                        Some(WindowContent::Stance) => {
+                            let (show_bar, show_posture) =
+                                Self::stance_flags(&self.app_core, &member.window_name);
+                            let rows = (show_bar as u32 + show_posture as u32).max(1) as f32;
+                            Some(bar_height * rows + gap * (rows - 1.0))
+                        }
+                        Some(WindowContent::Wealth) => {
+                            let rows =
+                                if Self::wealth_show_session(&self.app_core, &member.window_name) {
+                                    2.0
+                                } else {
+                                    1.0
+                                };
+                            Some(bar_height * rows + gap * (rows - 1.0))
+                        }
                         Some(WindowContent::GS4Experience) => {
-                            let (level, mind, exp_bar, total, ascension) =
+                            let (level, mind, exp_bar, total, ascension, eta) =
                                 Self::gs4_experience_flags(&self.app_core, &member.window_name);
-                            let rows = ([level, mind, exp_bar, total, ascension]
+                            let rows = ([level, mind, exp_bar, total, ascension, eta]
                                 .into_iter()
                                 .filter(|on| *on)
                                 .count()
@@ -1303,6 +1435,24 @@ impl VellumGuiApp {
                 style.spacing.interact_size = defaults.interact_size * density;
             });
         }
+
+        // Reduced motion zeroes egui's own animations (collapsing headers,
+        // smooth scrolling); the GUI renderers read the same zero through
+        // `animations_enabled` below.
+        let reduced_motion = !self.app_core.config.ui.animations_enabled();
+        if self.applied_reduced_motion != Some(reduced_motion) {
+            self.applied_reduced_motion = Some(reduced_motion);
+            ctx.global_style_mut(|style| {
+                let defaults = egui::Style::default();
+                if reduced_motion {
+                    style.animation_time = 0.0;
+                    style.scroll_animation = egui::style::ScrollAnimation::none();
+                } else {
+                    style.animation_time = defaults.animation_time;
+                    style.scroll_animation = defaults.scroll_animation;
+                }
+            });
+        }
     }
 
     /// Assemble the persistable layout snapshot. Returns None when the dock
@@ -1470,8 +1620,13 @@ impl VellumGuiApp {
             .as_ref()
             .map(|state| state.inner_size[0])
             .unwrap_or(1280.0);
-        let restored =
-            Self::restore_layout_state(Some(layout), &self.available_tabs, content_width);
+        let tui_zones = Self::zones_from_tui_layout(&self.app_core.layout, &self.available_tabs);
+        let restored = Self::restore_layout_state(
+            Some(layout),
+            &self.available_tabs,
+            &tui_zones,
+            content_width,
+        );
         self.hidden_tabs = restored.hidden_tabs;
         self.main_window_rects = restored.main_window_rects;
         self.last_center_window_rects.clear();
@@ -1560,6 +1715,14 @@ impl VellumGuiApp {
                 self.list_layout_checkpoints();
                 true
             }
+            "importlayout" => {
+                let count = self.import_tui_layout_zones();
+                self.app_core.add_system_message(&format!(
+                    "Docked {} windows to match the TUI layout.",
+                    count
+                ));
+                true
+            }
             _ => false,
         }
     }
@@ -1582,7 +1745,10 @@ impl VellumGuiApp {
         while let Ok(event) = self.remote_rx.try_recv() {
             match event {
                 crate::core::remote::RemoteEvent::Command(text) => {
-                    tracing::debug!("remote command: '{}'", text);
+                    if !self.app_core.expects_secret() {
+                        tracing::debug!("remote command: '{}'", text);
+                    }
+                    self.app_core.note_user_input();
                     self.record_command_history(&text);
                     self.dispatch_command(text);
                 }
@@ -1753,11 +1919,18 @@ impl VellumGuiApp {
         for command in self.app_core.take_outbound() {
             self.dispatch_command(command);
         }
+        // A `.quit` logout wait can end between commands
+        if !self.app_core.running {
+            self.close_requested = true;
+        }
 
         let mut received_text = false;
+        self.app_core
+            .perf_stats
+            .record_parsed_queue_depth(self.server_rx.len() as u64);
         while let Ok(message) = self.server_rx.try_recv() {
             match message {
-                ServerMessage::Text(line) => {
+                ParsedMessage::Text(chunk) => {
                     // First data from the game = connection established:
                     // time the login music from here.
                     if self.startup_music_pending {
@@ -1771,15 +1944,21 @@ impl VellumGuiApp {
                     }
                     self.app_core
                         .perf_stats
-                        .record_bytes_received((line.len() + 1) as u64);
-                    if let Err(err) = self.app_core.process_server_data(&line) {
+                        .record_bytes_received((chunk.bytes + 1) as u64);
+                    let apply_start = std::time::Instant::now();
+                    if let Err(err) = self.app_core.process_parsed_chunk(&chunk) {
                         self.app_core
                             .add_system_message(&format!("GUI parse error: {}", err));
                     }
+                    self.app_core.perf_stats.record_parse(chunk.parse_time);
+                    self.app_core.perf_stats.record_parse_stages(
+                        apply_start.duration_since(chunk.parsed_at),
+                        apply_start.elapsed(),
+                    );
                     self.app_core.needs_render = true;
                     received_text = true;
                 }
-                ServerMessage::Connected => {
+                ParsedMessage::Connected => {
                     self.app_core.game_state.connected = true;
                     self.app_core.needs_render = true;
                     // Layout has saved WebUI panels: bring them back up
@@ -1792,7 +1971,7 @@ impl VellumGuiApp {
                         self.request_webui_handshake();
                     }
                 }
-                ServerMessage::Disconnected => {
+                ParsedMessage::Disconnected => {
                     self.app_core.game_state.connected = false;
                     self.app_core.needs_render = true;
                 }
@@ -2302,15 +2481,111 @@ impl VellumGuiApp {
         let mut consumed_keyboard_input = false;
 
         for key_press in key_presses {
-            // Esc cancels an active .go2 trip from anywhere in the GUI. Gated
-            // on the same text-capture modes as macro dispatch so an editor
-            // that owns the keyboard keeps its Esc semantics.
+            // Boss key works everywhere (even inside editors); while engaged
+            // the rest of the UI isn't drawn, so nothing else sees keys.
+            let is_boss_key = Self::key_candidates(&key_press)
+                .into_iter()
+                .any(|key_event| {
+                    matches!(
+                        self.app_core.lookup_keybind(key_event),
+                        Some(KeyBindAction::Action(action)) if action == "toggle_boss_key"
+                    )
+                });
+            if is_boss_key {
+                self.app_core.toggle_boss_mode();
+                consumed_keyboard_input = true;
+                ctx.input_mut(|input| {
+                    if let Some(logical_key) = key_press.logical_key {
+                        input.consume_key(key_press.modifiers, logical_key);
+                    }
+                    if let Some(physical_key) = key_press.physical_key {
+                        input.consume_key(key_press.modifiers, physical_key);
+                    }
+                });
+                continue;
+            }
+            if self.app_core.ui_state.boss_mode {
+                continue;
+            }
+
+            // Reply keybind: swap the input for "whisper <name> " and leave
+            // the caret at the end for the reply text
+            let is_reply_key = !suppress_macro_dispatch
+                && Self::key_candidates(&key_press)
+                    .into_iter()
+                    .any(|key_event| {
+                        matches!(
+                            self.app_core.lookup_keybind(key_event),
+                            Some(KeyBindAction::Action(action)) if action == "reply_whisper"
+                        )
+                    });
+            if is_reply_key {
+                match self.app_core.reply_prefill() {
+                    Some(text) => {
+                        self.command_input = text;
+                        self.command_cursor_to_end(ctx);
+                        if let Some(id) = self.command_input_id {
+                            ctx.memory_mut(|memory| memory.request_focus(id));
+                        }
+                    }
+                    None => self
+                        .app_core
+                        .add_system_message("No whisper to reply to yet"),
+                }
+                consumed_keyboard_input = true;
+                ctx.input_mut(|input| {
+                    if let Some(logical_key) = key_press.logical_key {
+                        input.consume_key(key_press.modifiers, logical_key);
+                    }
+                    if let Some(physical_key) = key_press.physical_key {
+                        input.consume_key(key_press.modifiers, physical_key);
+                    }
+                });
+                continue;
+            }
+
+            // Recent URLs keybind
+            let is_list_urls_key = !suppress_macro_dispatch
+                && Self::key_candidates(&key_press)
+                    .into_iter()
+                    .any(|key_event| {
+                        matches!(
+                            self.app_core.lookup_keybind(key_event),
+                            Some(KeyBindAction::Action(action)) if action == "list_urls"
+                        )
+                    });
+            if is_list_urls_key {
+                self.app_core.list_urls();
+                consumed_keyboard_input = true;
+                ctx.input_mut(|input| {
+                    if let Some(logical_key) = key_press.logical_key {
+                        input.consume_key(key_press.modifiers, logical_key);
+                    }
+                    if let Some(physical_key) = key_press.physical_key {
+                        input.consume_key(key_press.modifiers, physical_key);
+                    }
+                });
+                continue;
+            }
+
+            // Esc cancels an active .go2 trip, .go route or .repeat from
+            // anywhere in the GUI. Gated on the same text-capture modes as
+            // macro dispatch so an editor that owns the keyboard keeps its
+            // Esc semantics.
             if key_press.key_event.code == crate::data::input::KeyCode::Esc
                 && key_press.key_event.modifiers == crate::data::input::KeyModifiers::NONE
                 && !suppress_macro_dispatch
-                && self.app_core.travel.is_traveling()
+                && (self.app_core.travel.is_traveling()
+                    || self.app_core.route.is_running()
+                    || self.app_core.repeat.is_running())
             {
-                self.app_core.stop_travel();
+                if self.app_core.repeat.is_running() {
+                    self.app_core.stop_repeat();
+                } else if self.app_core.travel.is_traveling() {
+                    self.app_core.stop_travel();
+                } else {
+                    self.app_core.stop_route();
+                }
                 consumed_keyboard_input = true;
                 ctx.input_mut(|input| {
                     if let Some(logical_key) = key_press.logical_key {
@@ -2323,12 +2598,22 @@ impl VellumGuiApp {
                 continue;
             }
 
-            let target = Self::resolve_global_dispatch_target(
-                key_press.key_event,
-                &self.app_core.keybind_map,
-                &self.app_core.config.app_keybinds,
-                suppress_macro_dispatch,
+            // Layout fallback: the produced key first, then the key at the
+            // same physical (US QWERTY) position
+            let layout = crate::core::key_layout::KeyboardLayout::from_name(
+                &self.app_core.config.ui.keyboard_layout,
             );
+            let target = Self::key_candidates(&key_press)
+                .into_iter()
+                .flat_map(|key_event| std::iter::once(key_event).chain(layout.normalize(key_event)))
+                .find_map(|key_event| {
+                    Self::resolve_global_dispatch_target(
+                        key_event,
+                        &self.app_core.keybind_map,
+                        &self.app_core.config.app_keybinds,
+                        suppress_macro_dispatch,
+                    )
+                });
             let Some(target) = target else {
                 continue;
             };
@@ -2363,6 +2648,22 @@ impl VellumGuiApp {
         }
     }
 
+    /// Keys to try for a press: the logical key, then the physical key
+    /// (egui reports it as the US layout key in that position) when the two
+    /// differ, so binds survive non-QWERTY layouts.
+    fn key_candidates(key_press: &GuiKeyPress) -> Vec<crate::data::input::KeyEvent> {
+        let mut candidates = vec![key_press.key_event];
+        if let Some(physical) = key_press
+            .physical_key
+            .and_then(|key| Self::egui_key_to_frontend_event(key, key_press.modifiers))
+        {
+            if physical != key_press.key_event {
+                candidates.push(physical);
+            }
+        }
+        candidates
+    }
+
     fn collect_pressed_key_events(ctx: &egui::Context) -> Vec<GuiKeyPress> {
         ctx.input(|input| {
             input
@@ -2530,7 +2831,12 @@ impl VellumGuiApp {
         suppress_macro_dispatch: bool,
     ) -> Option<GlobalDispatchTarget> {
         if !suppress_macro_dispatch {
-            if let Some(binding @ KeyBindAction::Macro(_)) = keybind_map.get(&key_event) {
+            if let Some(
+                binding @ (KeyBindAction::Macro(_)
+                | KeyBindAction::Sequence(_)
+                | KeyBindAction::Call(_)),
+            ) = keybind_map.get(&key_event)
+            {
                 return Some(GlobalDispatchTarget::Macro(binding.clone()));
             }
         }
@@ -2581,7 +2887,15 @@ impl VellumGuiApp {
         match self.app_core.execute_keybind_action(action) {
             Ok(commands) => {
                 for outbound in commands {
-                    if Self::should_send_to_network(&outbound) {
+                    // Function keybinds resolve to UI actions
+                    if outbound.starts_with("action:") {
+                        if !self.handle_action_string(&outbound) {
+                            self.app_core.add_system_message(&format!(
+                                "GUI action not implemented yet: {}",
+                                outbound
+                            ));
+                        }
+                    } else if Self::should_send_to_network(&outbound) {
                         self.app_core
                             .perf_stats
                             .record_bytes_sent((outbound.len() + 1) as u64);
@@ -2603,8 +2917,10 @@ impl VellumGuiApp {
     fn execute_app_shortcut(&mut self, shortcut: AppShortcut) {
         match shortcut {
             AppShortcut::Quit => {
-                self.app_core.quit();
-                self.close_requested = true;
+                self.app_core.request_quit("");
+                if !self.app_core.running {
+                    self.close_requested = true;
+                }
             }
             AppShortcut::StartSearch => {
                 self.app_core.start_search_mode();
@@ -2738,12 +3054,34 @@ impl VellumGuiApp {
         Some(code)
     }
 
-    fn submit_command(&mut self) {
-        let input = std::mem::take(&mut self.command_input);
+    /// Send the input bar's text. `modifiers` are those held with Enter,
+    /// for the lockout bypass.
+    fn submit_command(&mut self, modifiers: egui::Modifiers) {
+        let mut input = std::mem::take(&mut self.command_input);
+        if self.app_core.config.ui.history_expansion {
+            use crate::frontend::common::{expand_history, HistoryExpansion};
+            match expand_history(&input, &self.command_history) {
+                HistoryExpansion::Expanded(command) => input = command,
+                HistoryExpansion::NotFound => {
+                    // Leave the reference in the input, like a shell
+                    self.app_core
+                        .add_system_message(&format!("{}: event not found", input.trim()));
+                    self.command_input = input;
+                    return;
+                }
+                HistoryExpansion::None => {}
+            }
+        }
         self.record_command_history(&input);
         self.history_pos = None;
         self.history_draft.clear();
+        let lockout = self.app_core.config.lockout.bypass_modifier;
+        if crate::core::lockout::bypass_held(lockout, Self::egui_modifiers_to_frontend(modifiers)) {
+            self.app_core.command_lockout.bypass_next();
+        }
         self.dispatch_command(input);
+        // Layout and art commands never reach send_command
+        self.app_core.command_lockout.take_bypass();
     }
 
     const MAX_COMMAND_HISTORY: usize = 100;
@@ -2777,6 +3115,10 @@ impl VellumGuiApp {
     /// matching the TUI's input model, then persist.
     fn record_command_history(&mut self, command: &str) {
         let command = command.trim_end();
+        // A password reply isn't kept (core::secrets)
+        if self.app_core.expects_secret() {
+            return;
+        }
         if command.is_empty() || command.len() < self.app_core.config.ui.min_command_length {
             return;
         }
@@ -2832,6 +3174,45 @@ impl VellumGuiApp {
         }
     }
 
+    /// Ctrl+P / Ctrl+N: like Up/Down, but only entries starting with the
+    /// text typed before browsing (`ui.history_prefix_search`). Stepping
+    /// past the newest match brings the typed text back.
+    fn history_search(&mut self, older: bool) {
+        let prefix = if !self.app_core.config.ui.history_prefix_search {
+            String::new()
+        } else if self.history_pos.is_none() {
+            self.command_input.clone()
+        } else {
+            self.history_draft.clone()
+        };
+        let found = if older {
+            let start = self.history_pos.map(|i| i + 1).unwrap_or(0);
+            (start..self.command_history.len())
+                .find(|&i| self.command_history[i].starts_with(&prefix))
+        } else {
+            let Some(pos) = self.history_pos else {
+                return;
+            };
+            (0..pos)
+                .rev()
+                .find(|&i| self.command_history[i].starts_with(&prefix))
+        };
+        match found {
+            Some(i) => {
+                if self.history_pos.is_none() {
+                    self.history_draft = std::mem::take(&mut self.command_input);
+                }
+                self.history_pos = Some(i);
+                self.command_input = self.command_history[i].clone();
+            }
+            None if !older => {
+                self.history_pos = None;
+                self.command_input = std::mem::take(&mut self.history_draft);
+            }
+            None => {}
+        }
+    }
+
     /// Put the caret at the end of the input after programmatic text swaps.
     fn command_cursor_to_end(&self, ctx: &egui::Context) {
         let Some(id) = self.command_input_id else {
@@ -2860,6 +3241,9 @@ impl VellumGuiApp {
         if self.handle_layout_command(&command) {
             return;
         }
+        if self.handle_art_command(&command) {
+            return;
+        }
 
         match self.app_core.send_command(command) {
             Ok(outbound) => {
@@ -2896,6 +3280,30 @@ impl VellumGuiApp {
         }
     }
 
+    /// The layout's command input `prompt_template`, expanded against the
+    /// current game state. The GUI docks a single input, so the first
+    /// command input window with a template supplies it.
+    fn rendered_prompt(&self) -> Option<String> {
+        let template = self
+            .app_core
+            .layout
+            .windows
+            .iter()
+            .find_map(|def| match def {
+                crate::config::WindowDef::CommandInput { data, .. } => data.prompt_template.clone(),
+                _ => None,
+            })?;
+        let now_server =
+            chrono::Utc::now().timestamp() + self.app_core.message_processor.server_time_offset;
+        let route = self.app_core.route.label().unwrap_or_default();
+        Some(crate::core::prompt::render_prompt(
+            &template,
+            &self.app_core.game_state,
+            now_server,
+            &route,
+        ))
+    }
+
     /// True while any countdown window is actively ticking.
     fn any_countdown_running(&self) -> bool {
         let now = std::time::SystemTime::now()
@@ -3110,6 +3518,12 @@ impl VellumGuiApp {
         }
 
         let target = target.unwrap_or_else(|| "drop".to_string());
+        // Container to container: get/put instead of _drag ([containers])
+        if let Some(container_id) = target.strip_prefix('#') {
+            if self.app_core.move_item(&payload.exist_id, container_id) {
+                return;
+            }
+        }
         let command = format!("_drag #{} {}", payload.exist_id, target);
         self.dispatch_raw_command(command);
     }
@@ -3139,6 +3553,31 @@ impl VellumGuiApp {
         }
     }
 
+    /// `scroll_window` keybind function: scroll a text window (a tabbed one
+    /// on its active tab) by whole lines, positive going back.
+    fn scroll_window_by_name(&mut self, name: &str, lines: i32) {
+        let scroll_id = match self.app_core.ui_state.windows.get(name).map(|w| &w.content) {
+            Some(
+                WindowContent::Text(_)
+                | WindowContent::Inventory(_)
+                | WindowContent::Reserve(_)
+                | WindowContent::Spells(_),
+            ) => name.to_string(),
+            Some(WindowContent::TabbedText(tabbed)) => {
+                format!("{}::tab{}", name, tabbed.active_tab_index)
+            }
+            Some(_) => return,
+            None => {
+                self.app_core
+                    .add_system_message(&format!("No window named '{}'", name));
+                return;
+            }
+        };
+        if let Some(ctx) = self.repaint_ctx.lock().ok().and_then(|slot| slot.clone()) {
+            Self::request_text_scroll(&ctx, &scroll_id, lines);
+        }
+    }
+
     /// Add a window from a layout template (menu `__ADD__<template>` path).
     /// The new window is picked up as a dock tab on the next frame by
     /// refresh_available_tabs_if_needed.
@@ -3188,7 +3627,7 @@ impl VellumGuiApp {
             if let WindowContent::TabbedText(tabbed) = &mut window.content {
                 if index < tabbed.tabs.len() {
                     tabbed.active_tab_index = index;
-                    tabbed.tabs[index].has_unread = false;
+                    tabbed.clear_tab_unread(index);
                     self.app_core.needs_render = true;
                 }
             }
@@ -3211,7 +3650,7 @@ impl VellumGuiApp {
                     (tabbed.active_tab_index + count - 1) % count
                 };
                 tabbed.active_tab_index = next;
-                tabbed.tabs[next].has_unread = false;
+                tabbed.clear_tab_unread(next);
                 any = true;
             }
         }
@@ -3228,7 +3667,7 @@ impl VellumGuiApp {
             if let WindowContent::TabbedText(tabbed) = &mut window.content {
                 if let Some(index) = tabbed.tabs.iter().position(|tab| tab.has_unread) {
                     tabbed.active_tab_index = index;
-                    tabbed.tabs[index].has_unread = false;
+                    tabbed.clear_tab_unread(index);
                     self.app_core.needs_render = true;
                     return;
                 }
@@ -3244,6 +3683,14 @@ impl VellumGuiApp {
             let _ = self.app_core.send_command(".windows".to_string());
             return true;
         }
+        if let Some(topic) = action.strip_prefix("action:help:") {
+            self.app_core.print_help(topic);
+            return true;
+        }
+        if let Some(filter) = action.strip_prefix("action:inspect:") {
+            self.app_core.print_inspector(filter);
+            return true;
+        }
         if let Some(name) = action.strip_prefix("action:settheme:") {
             let name = name.to_string();
             self.apply_theme_by_name(&name);
@@ -3350,6 +3797,10 @@ impl VellumGuiApp {
             }
             return true;
         }
+        if action == "action:notes" {
+            self.open_notes_editor();
+            return true;
+        }
         if action == "action:nexttab" {
             self.cycle_tabbed_tabs(true);
             return true;
@@ -3362,6 +3813,50 @@ impl VellumGuiApp {
             self.goto_unread_tab();
             return true;
         }
+        if let Some(tab) = action.strip_prefix("action:switchtab:") {
+            let targets: Vec<(String, usize)> = self
+                .app_core
+                .ui_state
+                .windows
+                .iter()
+                .filter_map(|(name, window)| match &window.content {
+                    WindowContent::TabbedText(tabbed) => tabbed
+                        .tabs
+                        .iter()
+                        .position(|state| state.definition.name.eq_ignore_ascii_case(tab))
+                        .map(|index| (name.clone(), index)),
+                    _ => None,
+                })
+                .collect();
+            if targets.is_empty() {
+                self.app_core
+                    .add_system_message(&format!("No tab named '{}'", tab));
+            }
+            for (window_name, index) in targets {
+                self.switch_tabbed_tab(&window_name, index);
+            }
+            return true;
+        }
+        if let Some(target) = action.strip_prefix("action:scrollwindow:") {
+            if let Some((name, lines)) = target.rsplit_once(':') {
+                self.scroll_window_by_name(name, lines.parse().unwrap_or(0));
+            }
+            return true;
+        }
+        if let Some(name) = action.strip_prefix("action:showwindow:") {
+            let name = name.to_string();
+            let key = self
+                .app_core
+                .ui_state
+                .windows
+                .get(&name)
+                .and_then(|window| Self::tab_key_for_window(&name, window));
+            match key {
+                Some(key) => self.restore_tab(key),
+                None => self.add_window_from_template(&name),
+            }
+            return true;
+        }
         if let Some(name) = action.strip_prefix("action:hidewindow:") {
             let name = name.to_string();
             let key = self
@@ -3511,12 +4006,25 @@ impl VellumGuiApp {
         if click.link_data.exist_id == Self::LINK_DROP_SENTINEL {
             if let Some((dragged, target)) = click.link_data.noun.split_once('|') {
                 if !dragged.is_empty() && !target.is_empty() && dragged != target {
+                    // Dropped on an item in a container: move into that container
+                    let target_container = self
+                        .app_core
+                        .game_state
+                        .container_cache
+                        .find_item(target)
+                        .map(|(container, _)| container.id.clone());
+                    if let Some(container_id) = target_container {
+                        if self.app_core.move_item(dragged, &container_id) {
+                            return;
+                        }
+                    }
                     let command = format!("_drag #{} #{}", dragged, target);
                     self.dispatch_raw_command(command);
                 }
             }
             return;
         }
+        self.show_art_for_link(&click.link_data);
         let dispatch =
             Self::resolve_link_dispatch(&click.link_data, self.app_core.cmdlist.as_ref());
         let Some(dispatch) = dispatch else {
@@ -3550,7 +4058,28 @@ impl eframe::App for VellumGuiApp {
     fn ui(&mut self, ui: &mut egui::Ui, frame: &mut eframe::Frame) {
         let ctx = ui.ctx().clone();
         self.app_core.perf_stats.record_frame();
+        self.app_core.perf_stats.sample_sysinfo();
+        crate::core::locale::set_locale(self.app_core.config.ui.locale);
         self.capture_main_viewport(&ctx);
+        self.handle_lockout_prompt_keys(&ctx);
+        // Window focus drives sound ducking and focus-gated notifications
+        let focused = ctx.input(|input| input.focused);
+        self.app_core.set_app_focused(focused);
+        // Keyboard and mouse input ends idle away mode
+        let active = ctx.input(|input| {
+            input.events.iter().any(|event| {
+                matches!(
+                    event,
+                    egui::Event::Key { .. }
+                        | egui::Event::Text(_)
+                        | egui::Event::PointerButton { .. }
+                        | egui::Event::PointerMoved(_)
+                )
+            })
+        });
+        if active {
+            self.app_core.note_user_input();
+        }
         // Fire delayed startup music once its deadline passes; ask egui for
         // a frame at the deadline so a slow idle repaint can't stretch the
         // configured delay.
@@ -3601,12 +4130,15 @@ impl eframe::App for VellumGuiApp {
             .apply_if_changed(&ctx, self.app_core.config.active_skin.as_deref());
         self.apply_ui_sizing(&ctx);
         self.pump_server_messages();
-        // Keep painting while the map worker, mapdb download, or walk
-        // executor is busy so results and progress appear without waiting
-        // for user input or game text (travel needs ticks for RT waits).
+        // Keep painting while the map worker, mapdb download, walk
+        // executor, a `.go` route or `.repeat` is busy so results and
+        // progress appear without waiting for user input or game text
+        // (travel needs ticks for RT waits).
         if self.app_core.map.has_pending()
             || self.app_core.map_updater.in_flight()
             || self.app_core.travel.is_traveling()
+            || self.app_core.route.is_running()
+            || self.app_core.repeat.is_running()
         {
             ui.ctx()
                 .request_repaint_after(std::time::Duration::from_millis(150));
@@ -3629,6 +4161,24 @@ impl eframe::App for VellumGuiApp {
             return;
         }
 
+        // Boss key: a plain prompt instead of the whole shell
+        if self.app_core.ui_state.boss_mode {
+            egui::CentralPanel::default()
+                .frame(
+                    egui::Frame::default()
+                        .fill(Color32::BLACK)
+                        .inner_margin(8.0),
+                )
+                .show(ui, |ui| {
+                    ui.label(
+                        RichText::new(&self.app_core.config.ui.boss_key_prompt)
+                            .monospace()
+                            .color(Color32::LIGHT_GRAY),
+                    );
+                });
+            return;
+        }
+
         let detached_before_frame = self.detached_tab_keys();
         let mut visibility_toggles: Vec<TabKey> = Vec::new();
         let mut window_additions: Vec<String> = Vec::new();
@@ -3810,16 +4360,51 @@ impl eframe::App for VellumGuiApp {
         }
 
         egui::Panel::bottom("gui_command_input").show(ui, |ui| {
-            let response = ui.add(
-                egui::TextEdit::singleline(&mut self.command_input)
-                    .hint_text("Enter command...")
-                    .desired_width(ui.available_width()),
-            );
+            // Tab completes while the input has focus; taken before the
+            // TextEdit so egui doesn't move focus to the next widget
+            let input_focused = self
+                .command_input_id
+                .is_some_and(|id| ui.memory(|memory| memory.has_focus(id)));
+            let tab = input_focused
+                && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Tab));
+            if tab && self.complete_command_input() {
+                self.command_cursor_to_end(ui.ctx());
+            }
+            if input_focused {
+                self.intercept_multiline_paste(ui);
+            }
+            // `.go` route progress
+            if let Some(label) = self.app_core.route.label() {
+                ui.label(RichText::new(format!("[go] {}", label)).weak().small());
+            }
+            // `.repeat` progress
+            if let Some(label) = self.app_core.repeat.label() {
+                ui.label(
+                    RichText::new(format!("[repeat] {} (Esc stops)", label))
+                        .weak()
+                        .small(),
+                );
+            }
+
+            let prompt = self.rendered_prompt();
+            let response = ui
+                .horizontal(|ui| {
+                    if let Some(prompt) = &prompt {
+                        ui.label(RichText::new(prompt).monospace());
+                    }
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.command_input)
+                            .hint_text("Enter command...")
+                            .desired_width(ui.available_width()),
+                    )
+                })
+                .inner;
             self.command_input_id = Some(response.id);
+            self.render_completion_popup(ui.ctx(), response.rect);
 
             let pressed_enter = ui.input(|i| i.key_pressed(egui::Key::Enter));
             if response.lost_focus() && pressed_enter {
-                self.submit_command();
+                self.submit_command(ui.input(|i| i.modifiers));
                 response.request_focus();
             }
 
@@ -3833,12 +4418,21 @@ impl eframe::App for VellumGuiApp {
                 let down = ui.input_mut(|i| {
                     i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown)
                 });
+                let search_older = ui.input_mut(|i| {
+                    i.consume_key(egui::Modifiers::CTRL, egui::Key::P)
+                });
+                let search_newer = ui.input_mut(|i| {
+                    i.consume_key(egui::Modifiers::CTRL, egui::Key::N)
+                });
                 if up {
                     self.history_previous();
                     self.command_cursor_to_end(ui.ctx());
                 } else if down {
                     self.history_next();
                     self.command_cursor_to_end(ui.ctx());
+                } else if search_older || search_newer {
+                    self.history_search(search_older);
+                    self.command_cursor_to_end(ui.ctx());
                 }
             }
         });
@@ -4094,6 +4688,10 @@ impl eframe::App for VellumGuiApp {
         self.render_window_context_popup(&ctx);
         self.render_popup_menus(&ctx);
         self.render_injuries_popup(&ctx);
+        self.render_art_panel(&ctx);
+        self.render_notes_editor(&ctx);
+        self.render_paste_guard(&ctx);
+        self.render_lockout_prompt(&ctx);
         self.render_editors(&ctx);
         self.render_server_dialog(&ctx);
         self.render_search_bar(&ctx);
@@ -4174,10 +4772,33 @@ impl eframe::App for VellumGuiApp {
             }
         }
 
+        // Low-health alarm (core::alarm): blink a frame around the window
+        // over everything else, or hold it steady under reduced motion
+        let now = Instant::now();
+        let animate = self.app_core.config.ui.animations_enabled();
+        if let Some(level) = self.app_core.game_state.alarm.flash(now, animate) {
+            let color = match level {
+                crate::core::alarm::AlarmLevel::Critical => Color32::from_rgb(0xe0, 0x30, 0x30),
+                _ => Color32::from_rgb(0xe0, 0xb0, 0x30),
+            };
+            ctx.layer_painter(egui::LayerId::new(
+                egui::Order::Foreground,
+                egui::Id::new("alarm_flash"),
+            ))
+            .rect_stroke(
+                ctx.content_rect(),
+                0.0,
+                egui::Stroke::new(6.0, color),
+                egui::StrokeKind::Inside,
+            );
+        }
+
         // Input events and incoming server data (via the forwarder task) wake
         // the loop immediately; the periodic repaint only drives countdown
         // ticks and background polling, so idle CPU stays near zero.
-        let repaint_after = if self.any_countdown_running() {
+        let repaint_after = if self.app_core.game_state.alarm.flashing(now) {
+            Duration::from_millis(50)
+        } else if self.any_countdown_running() {
             Duration::from_millis(100)
         } else {
             Duration::from_millis(500)
@@ -4222,6 +4843,10 @@ pub fn run_native_gui(
         .character
         .as_deref()
         .or(app_core.config.character.as_deref())
+        .map(|character| match &app_core.config.streamer {
+            streamer if streamer.enabled => streamer.character_alias.as_str(),
+            _ => character,
+        })
         .map(|character| format!("VellumFE - {}", character))
         .unwrap_or_else(|| "VellumFE".to_string());
     // Restore the last session's OS window geometry. Opening at a smaller
@@ -4503,7 +5128,7 @@ mod tests {
     fn test_segment_has_clickable_link_for_monsterbold_link_segment() {
         let segment = TextSegment {
             text: "goblin".to_string(),
-            fg: Some("#00ff00".to_string()),
+            fg: Some("#00ff00".into()),
             bg: None,
             bold: true,
             mono: false,
@@ -4586,7 +5211,7 @@ mod tests {
         let lines = VellumGuiApp::room_component_lines(Some(&component));
 
         assert_eq!(lines.len(), 1);
-        assert_eq!(lines[0].stream, "room");
+        assert_eq!(&*lines[0].stream, "room");
         assert_eq!(lines[0].segments[0].text, "Room text");
     }
 
@@ -0,0 +1,162 @@
+//! Art panel: shows local art-pack images for clicked creatures/items.
+//!
+//! Optional extra on top of the text links (`ui.art_panel`). The pack index
+//! (`data::ArtPack`) is built lazily from `~/.vellum-fe/art/` on first use;
+//! the image for the current subject is decoded on the next frame and kept
+//! as a single texture until the next click replaces it.
+
+use super::*;
+use crate::data::ArtPack;
+use std::path::{Path, PathBuf};
+
+/// What the art panel is currently showing.
+pub(super) struct ArtPanelState {
+    subject: String,
+    path: PathBuf,
+    /// None until the render pass decodes `path`
+    image: Option<Result<egui::TextureHandle, String>>,
+}
+
+impl VellumGuiApp {
+    /// Index of the art directory, scanned on first use.
+    fn art_pack(&mut self) -> &ArtPack {
+        self.art_pack
+            .get_or_insert_with(|| match Config::art_dir() {
+                Ok(dir) => ArtPack::load(&dir),
+                Err(err) => {
+                    tracing::warn!("Art pack: cannot resolve art directory: {}", err);
+                    ArtPack::default()
+                }
+            })
+    }
+
+    /// Called for every noun link click; no-op unless `ui.art_panel` is on
+    /// and the pack has art for the clicked text.
+    pub(super) fn show_art_for_link(&mut self, link: &LinkData) {
+        // `_direct_` and other synthetic links are commands, not game objects
+        if !self.app_core.config.ui.art_panel
+            || link.exist_id.is_empty()
+            || link.exist_id.starts_with('_')
+        {
+            return;
+        }
+        let subject = if link.text.trim().is_empty() {
+            link.noun.clone()
+        } else {
+            link.text.clone()
+        };
+        let Some(path) = self.art_pack().lookup(&subject).map(Path::to_path_buf) else {
+            return;
+        };
+        self.open_art_panel(subject, path);
+    }
+
+    fn open_art_panel(&mut self, subject: String, path: PathBuf) {
+        self.art_panel = Some(ArtPanelState {
+            subject,
+            path,
+            image: None,
+        });
+    }
+
+    /// `.art <name>` shows art on demand, `.art on|off` toggles the click
+    /// panel, `.art reload` rescans the art directory.
+    pub(super) fn handle_art_command(&mut self, command: &str) -> bool {
+        let Some(rest) = command.strip_prefix(".art") else {
+            return false;
+        };
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            return false;
+        }
+        let arg = rest.trim();
+        match arg.to_lowercase().as_str() {
+            "" => {
+                let state = if self.app_core.config.ui.art_panel {
+                    "on"
+                } else {
+                    "off"
+                };
+                let count = self.art_pack().len();
+                let root = self.art_pack().root().display().to_string();
+                self.app_core.add_system_message(&format!(
+                    "Art panel is {} ({} images in {}). Usage: .art <name> | on | off | reload",
+                    state, count, root
+                ));
+            }
+            "on" | "off" => {
+                let enabled = arg.eq_ignore_ascii_case("on");
+                self.app_core.config.ui.art_panel = enabled;
+                if !enabled {
+                    self.art_panel = None;
+                }
+                self.app_core.add_system_message(&format!(
+                    "Art panel {}",
+                    if enabled { "enabled" } else { "disabled" }
+                ));
+            }
+            "reload" => {
+                self.art_pack = None;
+                let count = self.art_pack().len();
+                self.app_core
+                    .add_system_message(&format!("Art pack reloaded: {} images", count));
+            }
+            _ => match self.art_pack().lookup(arg).map(Path::to_path_buf) {
+                Some(path) => self.open_art_panel(arg.to_string(), path),
+                None => self
+                    .app_core
+                    .add_system_message(&format!("No art found for '{}'", arg)),
+            },
+        }
+        true
+    }
+
+    pub(super) fn render_art_panel(&mut self, ctx: &egui::Context) {
+        let Some(panel) = self.art_panel.as_mut() else {
+            return;
+        };
+        let image = panel.image.get_or_insert_with(|| {
+            let image = load_art_texture(ctx, &panel.path);
+            if let Err(err) = &image {
+                tracing::warn!("Art pack: {}", err);
+            }
+            image
+        });
+        let mut open = true;
+        egui::Window::new(panel.subject.clone())
+            .id(egui::Id::new("gui_art_panel"))
+            .collapsible(true)
+            .resizable(true)
+            .default_size(Vec2::new(280.0, 280.0))
+            .open(&mut open)
+            .show(ctx, |ui| match image {
+                Ok(texture) => {
+                    ui.add(
+                        egui::Image::new(texture)
+                            .max_size(ui.available_size())
+                            .maintain_aspect_ratio(true),
+                    );
+                }
+                Err(err) => {
+                    ui.label(RichText::new(err).weak());
+                }
+            });
+        if !open {
+            self.art_panel = None;
+        }
+    }
+}
+
+fn load_art_texture(ctx: &egui::Context, path: &Path) -> Result<egui::TextureHandle, String> {
+    let bytes =
+        std::fs::read(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let decoded = image::load_from_memory(&bytes)
+        .map_err(|err| format!("cannot decode {}: {}", path.display(), err))?;
+    let rgba = decoded.to_rgba8();
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    let color_image = egui::ColorImage::from_rgba_unmultiplied(size, rgba.as_raw());
+    Ok(ctx.load_texture(
+        format!("art:{}", path.display()),
+        color_image,
+        egui::TextureOptions::LINEAR,
+    ))
+}
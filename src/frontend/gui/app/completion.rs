@@ -0,0 +1,105 @@
+//! Tab-completion for the GUI input bar.
+//!
+//! Same word sources as the TUI (`AppCore::get_available_commands` /
+//! `get_window_names` for dot-commands, `get_game_completions` otherwise)
+//! and the same matching (`core::completion::candidates`). Repeated Tab
+//! cycles while the input still holds the last completion; any edit starts
+//! over. A small list above the input shows the candidates.
+
+use super::*;
+
+/// Completion in progress for the input bar.
+pub(super) struct GuiCompletion {
+    /// Input text before the word being completed
+    prefix: String,
+    candidates: Vec<String>,
+    index: usize,
+}
+
+impl GuiCompletion {
+    fn text(&self) -> String {
+        format!("{}{}", self.prefix, self.candidates[self.index])
+    }
+}
+
+impl VellumGuiApp {
+    /// Complete (or cycle) the last word of the input. Returns false when
+    /// nothing matched.
+    pub(super) fn complete_command_input(&mut self) -> bool {
+        if let Some(completion) = self.completion.as_mut() {
+            if completion.text() == self.command_input {
+                completion.index = (completion.index + 1) % completion.candidates.len();
+                self.command_input = completion.text();
+                return true;
+            }
+        }
+        self.completion = None;
+
+        let input = self.command_input.trim();
+        let (prefix, word) = match input.rfind(char::is_whitespace) {
+            Some(pos) => (input[..=pos].to_string(), &input[pos + 1..]),
+            None => (String::new(), input),
+        };
+        if word.is_empty() {
+            return false;
+        }
+        let pool = if word.starts_with('.') {
+            self.app_core.get_available_commands()
+        } else if input.starts_with('.') {
+            self.app_core.get_window_names()
+        } else {
+            self.app_core.get_game_completions()
+        };
+        let candidates = crate::core::completion::candidates(&pool, word);
+        if candidates.is_empty() {
+            return false;
+        }
+        let completion = GuiCompletion {
+            prefix,
+            candidates,
+            index: 0,
+        };
+        self.command_input = completion.text();
+        self.completion = Some(completion);
+        true
+    }
+
+    /// Candidate list anchored above the input bar; hidden for a unique
+    /// match or once the input was edited. Clicking a candidate picks it.
+    pub(super) fn render_completion_popup(&mut self, ctx: &egui::Context, input_rect: Rect) {
+        let Some(completion) = self.completion.as_ref() else {
+            return;
+        };
+        if completion.candidates.len() < 2 || completion.text() != self.command_input {
+            return;
+        }
+        let mut picked = None;
+        egui::Area::new(egui::Id::new("gui_completion_popup"))
+            .order(egui::Order::Foreground)
+            .pivot(egui::Align2::LEFT_BOTTOM)
+            .fixed_pos(input_rect.left_top())
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(180.0)
+                        .show(ui, |ui| {
+                            for (idx, candidate) in completion.candidates.iter().enumerate() {
+                                let selected = idx == completion.index;
+                                let label = ui.selectable_label(selected, candidate.as_str());
+                                if selected {
+                                    label.scroll_to_me(None);
+                                }
+                                if label.clicked() {
+                                    picked = Some(idx);
+                                }
+                            }
+                        });
+                });
+            });
+        if let (Some(idx), Some(completion)) = (picked, self.completion.as_mut()) {
+            completion.index = idx;
+            self.command_input = completion.text();
+            self.command_cursor_to_end(ctx);
+        }
+    }
+}
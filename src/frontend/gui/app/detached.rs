@@ -59,6 +59,8 @@ struct DetachedFrameOutput {
     typed_text: String,
     backspaces: usize,
     submit_command: bool,
+    /// Held with that Enter (lockout bypass)
+    submit_modifiers: egui::Modifiers,
     popup_command: Option<GuiMenuCommand>,
     popup_should_close: bool,
     info: Option<egui::ViewportInfo>,
@@ -308,7 +310,7 @@ impl VellumGuiApp {
                 self.command_input.push_str(&out.typed_text);
             }
             if out.submit_command {
-                self.submit_command();
+                self.submit_command(out.submit_modifiers);
             }
             if let Some(pos) = out.open_menu_at {
                 self.close_all_popup_menus();
@@ -511,8 +513,12 @@ impl VellumGuiApp {
                         key: egui::Key::Enter,
                         pressed: true,
                         repeat: false,
+                        modifiers,
                         ..
-                    } => out.submit_command = true,
+                    } => {
+                        out.submit_command = true;
+                        out.submit_modifiers = *modifiers;
+                    }
                     egui::Event::Key {
                         key: egui::Key::Backspace,
                         pressed: true,
@@ -52,10 +52,13 @@ impl VellumGuiApp {
     /// Reconcile a persisted layout against this session's available tabs.
     /// `None` yields the same defaults as a missing layout file. Saved state
     /// referencing tabs that don't exist this session is dropped; tabs the
-    /// file doesn't know get their default zone.
+    /// file doesn't know get their default zone. Without a saved dock
+    /// arrangement, `tui_zones` (converted from the TUI layout) seeds the
+    /// zones so a first GUI run mirrors the TUI arrangement.
     pub(super) fn restore_layout_state(
         persisted_layout: Option<&GuiLayoutFileV1>,
         available_tabs: &HashMap<TabKey, GuiTab>,
+        tui_zones: &HashMap<TabKey, GuiShellZone>,
         content_width: f32,
     ) -> RestoredLayoutState {
         let ui_font = persisted_layout
@@ -111,15 +114,22 @@ impl VellumGuiApp {
             })
             .unwrap_or_default();
         no_title_tabs.retain(|key| available_tabs.contains_key(key));
+        let use_tui_zones = snapshot.is_none() && !tui_zones.is_empty();
         for key in available_tabs.keys() {
-            tab_zones
-                .entry(key.clone())
-                .or_insert_with(|| Self::default_zone_for_tab_key(key));
+            tab_zones.entry(key.clone()).or_insert_with(|| {
+                use_tui_zones
+                    .then(|| tui_zones.get(key).copied())
+                    .flatten()
+                    .unwrap_or_else(|| Self::default_zone_for_tab_key(key))
+            });
         }
         let mut shell_layout = snapshot
             .as_ref()
             .map(|snapshot| snapshot.shell_layout.clone())
             .unwrap_or_default();
+        if use_tui_zones {
+            shell_layout.reveal_zones(tab_zones.values().copied());
+        }
         shell_layout.sanitize(content_width.max(1.0));
 
         let tab_groups = Self::sanitize_tab_groups(
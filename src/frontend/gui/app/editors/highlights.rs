@@ -386,7 +386,7 @@ impl VellumGuiApp {
                                     ui.label("Stream");
                                     ui.text_edit_singleline(&mut form.stream);
                                     ui.end_row();
-                                    ui.label("Window");
+                                    ui.label("Windows/tabs");
                                     ui.text_edit_singleline(&mut form.window);
                                     ui.end_row();
                                 });
@@ -5,7 +5,8 @@
 //! `InputMode::KeybindForm`).
 
 use super::super::VellumGuiApp;
-use crate::config::{Config, KeyAction, KeyBindAction, MacroAction};
+use crate::config::{CallAction, Config, KeyAction, KeyBindAction, MacroAction, SequenceAction};
+use crate::core::action_registry::{self, MAX_ARGS};
 use crate::data::InputMode;
 use eframe::egui;
 
@@ -23,15 +24,28 @@ impl KeybindEditorState {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BindingKind {
+    Macro,
+    Action,
+    Sequence,
+    Function,
+}
+
 struct KeybindFormState {
     /// Some(key) when editing an existing binding; None when adding.
     original_key: Option<String>,
     original_is_global: bool,
     key: String,
     capture_armed: bool,
-    is_macro: bool,
+    kind: BindingKind,
     action: String,
     macro_text: String,
+    /// "1; wait 2; A" form of a sequence
+    sequence_text: String,
+    /// Index into `action_registry::ACTIONS`
+    function: usize,
+    function_args: [String; MAX_ARGS],
     is_global: bool,
     error: Option<String>,
 }
@@ -43,31 +57,48 @@ impl KeybindFormState {
             original_is_global: true,
             key: String::new(),
             capture_armed: false,
-            is_macro: true,
+            kind: BindingKind::Macro,
             action: String::new(),
             macro_text: String::new(),
+            sequence_text: String::new(),
+            function: 0,
+            function_args: Default::default(),
             is_global: true,
             error: None,
         }
     }
 
     fn from_binding(key: &str, action: &KeyBindAction, is_global: bool) -> Self {
-        let (is_macro, action_text, macro_text) = match action {
-            KeyBindAction::Action(name) => (false, name.clone(), String::new()),
+        let mut form = Self::empty();
+        match action {
+            KeyBindAction::Action(name) => {
+                form.kind = BindingKind::Action;
+                form.action = name.clone();
+            }
             KeyBindAction::Macro(macro_action) => {
-                (true, String::new(), macro_action.macro_text.clone())
+                form.macro_text = macro_action.macro_text.clone();
             }
-        };
+            KeyBindAction::Sequence(sequence) => {
+                form.kind = BindingKind::Sequence;
+                form.sequence_text = sequence.to_text();
+            }
+            KeyBindAction::Call(call) => {
+                form.kind = BindingKind::Function;
+                form.function = action_registry::ACTIONS
+                    .iter()
+                    .position(|action| action.name == call.call)
+                    .unwrap_or(0);
+                for (slot, arg) in form.function_args.iter_mut().zip(&call.args) {
+                    slot.clone_from(arg);
+                }
+            }
+        }
         Self {
             original_key: Some(key.to_string()),
             original_is_global: is_global,
             key: key.to_string(),
-            capture_armed: false,
-            is_macro,
-            action: action_text,
-            macro_text,
             is_global,
-            error: None,
+            ..form
         }
     }
 
@@ -79,19 +110,38 @@ impl KeybindFormState {
         if crate::config::parse_key_string(&key).is_none() {
             return Err(format!("Unrecognized key combo '{}'.", key));
         }
-        let action = if self.is_macro {
-            if self.macro_text.is_empty() {
-                return Err("Macro text is required (\\r sends enter).".to_string());
+        let action = match self.kind {
+            BindingKind::Macro => {
+                if self.macro_text.is_empty() {
+                    return Err("Macro text is required (\\r sends enter).".to_string());
+                }
+                // Store literal \r/\n escapes the way keybinds.toml expects.
+                let text = self.macro_text.replace("\\r", "\r").replace("\\n", "\n");
+                KeyBindAction::Macro(MacroAction { macro_text: text })
             }
-            // Store literal \r/\n escapes the way keybinds.toml expects.
-            let text = self.macro_text.replace("\\r", "\r").replace("\\n", "\n");
-            KeyBindAction::Macro(MacroAction { macro_text: text })
-        } else {
-            let name = self.action.trim().to_string();
-            if KeyAction::from_str(&name).is_none() {
-                return Err(format!("Unknown action '{}'.", name));
+            BindingKind::Action => {
+                let name = self.action.trim().to_string();
+                if KeyAction::from_str(&name).is_none() {
+                    return Err(format!("Unknown action '{}'.", name));
+                }
+                KeyBindAction::Action(name)
+            }
+            BindingKind::Sequence => KeyBindAction::Sequence(
+                SequenceAction::parse(&self.sequence_text)
+                    .map_err(|e| format!("Sequence: {}.", e))?,
+            ),
+            BindingKind::Function => {
+                let action = &action_registry::ACTIONS[self.function];
+                let call = CallAction {
+                    call: action.name.to_string(),
+                    args: self.function_args[..action.args.len()]
+                        .iter()
+                        .map(|arg| arg.trim().to_string())
+                        .collect(),
+                };
+                action_registry::resolve(&call).map_err(|e| format!("Function: {}.", e))?;
+                KeyBindAction::Call(call)
             }
-            KeyBindAction::Action(name)
         };
         Ok((key, action))
     }
@@ -104,6 +154,8 @@ fn display_action(action: &KeyBindAction) -> String {
             "macro: {}",
             macro_action.macro_text.replace('\r', "\\r").replace('\n', "\\n")
         ),
+        KeyBindAction::Sequence(sequence) => format!("sequence: {}", sequence.to_text()),
+        KeyBindAction::Call(call) => format!("call: {}", call.to_text()),
     }
 }
 
@@ -288,24 +340,73 @@ impl VellumGuiApp {
                             ui.end_row();
                             ui.label("Type");
                             ui.horizontal(|ui| {
-                                ui.selectable_value(&mut form.is_macro, true, "Macro");
-                                ui.selectable_value(&mut form.is_macro, false, "Action");
+                                ui.selectable_value(&mut form.kind, BindingKind::Macro, "Macro");
+                                ui.selectable_value(&mut form.kind, BindingKind::Action, "Action");
+                                ui.selectable_value(
+                                    &mut form.kind,
+                                    BindingKind::Sequence,
+                                    "Sequence",
+                                );
+                                ui.selectable_value(
+                                    &mut form.kind,
+                                    BindingKind::Function,
+                                    "Function",
+                                );
                             });
                             ui.end_row();
-                            if form.is_macro {
-                                ui.label("Macro text");
-                                ui.text_edit_singleline(&mut form.macro_text);
-                                ui.end_row();
-                            } else {
-                                ui.label("Action");
-                                ui.text_edit_singleline(&mut form.action);
-                                ui.end_row();
+                            match form.kind {
+                                BindingKind::Macro => {
+                                    ui.label("Macro text");
+                                    ui.text_edit_singleline(&mut form.macro_text);
+                                }
+                                BindingKind::Action => {
+                                    ui.label("Action");
+                                    ui.text_edit_singleline(&mut form.action);
+                                }
+                                BindingKind::Sequence => {
+                                    ui.label("Steps");
+                                    ui.text_edit_singleline(&mut form.sequence_text);
+                                }
+                                BindingKind::Function => {
+                                    ui.label("Function");
+                                    egui::ComboBox::from_id_salt("keybind_function")
+                                        .selected_text(action_registry::ACTIONS[form.function].name)
+                                        .show_ui(ui, |ui| {
+                                            for (i, action) in
+                                                action_registry::ACTIONS.iter().enumerate()
+                                            {
+                                                ui.selectable_value(
+                                                    &mut form.function,
+                                                    i,
+                                                    action.name,
+                                                );
+                                            }
+                                        });
+                                    let action = &action_registry::ACTIONS[form.function];
+                                    for (arg, value) in
+                                        action.args.iter().zip(form.function_args.iter_mut())
+                                    {
+                                        ui.end_row();
+                                        ui.label(arg.name);
+                                        ui.text_edit_singleline(value);
+                                    }
+                                }
                             }
+                            ui.end_row();
                         });
-                    if form.is_macro {
-                        ui.weak("Use \\r for enter (e.g. \"sw\\r\" to walk southwest).");
-                    } else {
-                        ui.weak("Action name, e.g. cursor_word_left, next_tab, toggle_sounds.");
+                    match form.kind {
+                        BindingKind::Macro => {
+                            ui.weak("Use \\r for enter (e.g. \"sw\\r\" to walk southwest).");
+                        }
+                        BindingKind::Action => {
+                            ui.weak("Action name, e.g. cursor_word_left, next_tab, toggle_sounds.");
+                        }
+                        BindingKind::Sequence => {
+                            ui.weak("Commands separated by ;, with wait <seconds> between them (e.g. \"1; wait 2; A; look\"). Press the key again to stop.");
+                        }
+                        BindingKind::Function => {
+                            ui.weak(action_registry::ACTIONS[form.function].summary);
+                        }
                     }
                     ui.checkbox(&mut form.is_global, "Global (all characters)");
 
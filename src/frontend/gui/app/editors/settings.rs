@@ -12,6 +12,7 @@ const BORDER_STYLES: &[&str] = &[
     "thick",
     "quadrant_inside",
     "quadrant_outside",
+    "ascii",
 ];
 
 /// Buffered edit state, initialized from Config when the editor opens and
@@ -24,6 +25,8 @@ pub(in super::super) struct SettingsEditorState {
     border_style: String,
     countdown_icon: String,
     min_command_length: usize,
+    history_expansion: bool,
+    history_prefix_search: bool,
     lich_dir: String,
     mapdb_path: String,
     mapdb_repo: String,
@@ -69,6 +72,8 @@ impl SettingsEditorState {
             border_style: config.ui.border_style.clone(),
             countdown_icon: config.ui.countdown_icon.clone(),
             min_command_length: config.ui.min_command_length,
+            history_expansion: config.ui.history_expansion,
+            history_prefix_search: config.ui.history_prefix_search,
             sound_enabled: config.sound.enabled,
             sound_volume: config.sound.volume,
             sound_cooldown_ms: config.sound.cooldown_ms,
@@ -108,6 +113,8 @@ impl SettingsEditorState {
         config.ui.border_style = self.border_style.clone();
         config.ui.countdown_icon = self.countdown_icon.clone();
         config.ui.min_command_length = self.min_command_length;
+        config.ui.history_expansion = self.history_expansion;
+        config.ui.history_prefix_search = self.history_prefix_search;
         config.sound.enabled = self.sound_enabled;
         config.sound.volume = self.sound_volume;
         config.sound.cooldown_ms = self.sound_cooldown_ms;
@@ -333,6 +340,15 @@ impl VellumGuiApp {
                                             .range(0..=10),
                                     );
                                     ui.end_row();
+                                    ui.label("History expansion");
+                                    ui.checkbox(&mut state.history_expansion, "!! and !prefix");
+                                    ui.end_row();
+                                    ui.label("History prefix search");
+                                    ui.checkbox(
+                                        &mut state.history_prefix_search,
+                                        "Ctrl+P/N match typed text",
+                                    );
+                                    ui.end_row();
                                 },
                             );
                         });
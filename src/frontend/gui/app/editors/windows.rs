@@ -60,6 +60,7 @@ struct ExperienceFields {
     show_exp_bar: bool,
     show_total_exp: bool,
     show_ascension_exp: bool,
+    show_eta: bool,
 }
 
 /// Encumbrance display toggles (shared via EncumbranceWidgetData).
@@ -254,6 +255,7 @@ impl VellumGuiApp {
                         show_exp_bar: data.show_exp_bar,
                         show_total_exp: data.show_total_exp,
                         show_ascension_exp: data.show_ascension_exp,
+                        show_eta: data.show_eta,
                     });
                 }
                 crate::config::WindowDef::Encumbrance { data, .. } => {
@@ -551,6 +553,7 @@ impl VellumGuiApp {
                 data.show_exp_bar = experience.show_exp_bar;
                 data.show_total_exp = experience.show_total_exp;
                 data.show_ascension_exp = experience.show_ascension_exp;
+                data.show_eta = experience.show_eta;
                 self.app_core.layout_modified_since_save = true;
             }
         }
@@ -782,6 +785,11 @@ impl VellumGuiApp {
                             ui.vertical(|ui| {
                                 ui.checkbox(&mut experience.show_level, "level");
                                 ui.checkbox(&mut experience.show_mind_bar, "mind state");
+                                ui.checkbox(&mut experience.show_eta, "time until clear")
+                                    .on_hover_text(
+                                        "Estimated time until your mind is clear, from \
+                                         the recent absorption rate.",
+                                    );
                                 ui.checkbox(&mut experience.show_exp_bar, "experience bar");
                                 ui.checkbox(&mut experience.show_total_exp, "total exp")
                                     .on_hover_text(
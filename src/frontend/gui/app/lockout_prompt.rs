@@ -0,0 +1,61 @@
+//! Safety lockout modal for the GUI: a command matching a `[lockout]`
+//! pattern waits here for Send / Cancel (y / n or Esc) before it goes to
+//! the game, the same prompt the TUI shows (see core::lockout).
+
+use super::*;
+
+impl VellumGuiApp {
+    /// Answer keys for an open prompt. Runs at the start of the frame so
+    /// the keys (and any other typing) never reach the command input.
+    pub(super) fn handle_lockout_prompt_keys(&mut self, ctx: &egui::Context) {
+        if self.app_core.ui_state.lockout_prompt.is_none() {
+            return;
+        }
+        let answer = ctx.input_mut(|input| {
+            let answer = if input.consume_key(egui::Modifiers::NONE, egui::Key::Y) {
+                Some(true)
+            } else if input.consume_key(egui::Modifiers::NONE, egui::Key::N)
+                || input.consume_key(egui::Modifiers::NONE, egui::Key::Escape)
+            {
+                Some(false)
+            } else {
+                None
+            };
+            input
+                .events
+                .retain(|event| !matches!(event, egui::Event::Text(_)));
+            answer
+        });
+        if let Some(send) = answer {
+            self.app_core.resolve_lockout(send);
+        }
+    }
+
+    pub(super) fn render_lockout_prompt(&mut self, ctx: &egui::Context) {
+        let Some(prompt) = self.app_core.ui_state.lockout_prompt.as_ref() else {
+            return;
+        };
+        let mut answer = None;
+        egui::Window::new("Send this command?")
+            .id(egui::Id::new("gui_lockout_prompt"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(RichText::new(&prompt.command).monospace().strong());
+                ui.label(RichText::new(format!("matches lockout {}", prompt.pattern)).weak());
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Send (y)").clicked() {
+                        answer = Some(true);
+                    }
+                    if ui.button("Cancel (n)").clicked() {
+                        answer = Some(false);
+                    }
+                });
+            });
+        if let Some(send) = answer {
+            self.app_core.resolve_lockout(send);
+        }
+    }
+}
@@ -292,7 +292,7 @@ impl VellumGuiApp {
                             );
                         }
                         DbState::Loading => {
-                            ui.spinner();
+                            Self::busy_indicator(ui);
                             ui.label("loading mapdb\u{2026}");
                         }
                         DbState::Failed => {
@@ -305,7 +305,7 @@ impl VellumGuiApp {
                         DbState::Loaded => {
                             if let Some(loc) = ex.location.as_deref() {
                                 if map.is_pending(loc) {
-                                    ui.spinner();
+                                    Self::busy_indicator(ui);
                                     ui.label("generating\u{2026}");
                                 } else if let Some(scene) = map.scene_for(loc) {
                                     ui.label(format!(
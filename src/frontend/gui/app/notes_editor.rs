@@ -0,0 +1,80 @@
+//! Notes editor window (`.notes`).
+//!
+//! A multi-line TextEdit over the character notepad. Text goes back to core
+//! (`AppCore::set_notes`) a couple of seconds after typing stops, on
+//! Ctrl+S, and when the window closes, the same as the TUI popup.
+
+use super::*;
+use std::time::{Duration, Instant};
+
+/// How long after the last edit the notes are saved
+const AUTOSAVE_DELAY: Duration = Duration::from_secs(2);
+
+/// The open notes editor
+pub(super) struct NotesEditorState {
+    text: String,
+    /// Last edit not yet saved
+    unsaved_since: Option<Instant>,
+}
+
+impl VellumGuiApp {
+    pub(super) fn open_notes_editor(&mut self) {
+        if self.notes_editor.is_none() {
+            self.notes_editor = Some(NotesEditorState {
+                text: self.app_core.notes.text().to_string(),
+                unsaved_since: None,
+            });
+        }
+    }
+
+    pub(super) fn render_notes_editor(&mut self, ctx: &egui::Context) {
+        let Some(editor) = self.notes_editor.as_mut() else {
+            return;
+        };
+        let mut open = true;
+        let mut save =
+            ctx.input_mut(|input| input.consume_key(egui::Modifiers::COMMAND, egui::Key::S));
+        egui::Window::new("Notes")
+            .id(egui::Id::new("gui_notes_editor"))
+            .collapsible(false)
+            .resizable(true)
+            .default_size(Vec2::new(420.0, 360.0))
+            .open(&mut open)
+            .show(ctx, |ui| {
+                let status = if editor.unsaved_since.is_some() {
+                    "Editing - Ctrl+S to save"
+                } else {
+                    "Saved"
+                };
+                ui.label(RichText::new(status).weak());
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        let response = ui.add(
+                            egui::TextEdit::multiline(&mut editor.text)
+                                .font(egui::TextStyle::Monospace)
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(16),
+                        );
+                        if response.changed() {
+                            editor.unsaved_since = Some(Instant::now());
+                        }
+                    });
+            });
+        if let Some(since) = editor.unsaved_since {
+            if since.elapsed() >= AUTOSAVE_DELAY {
+                save = true;
+            } else {
+                ctx.request_repaint_after(AUTOSAVE_DELAY - since.elapsed());
+            }
+        }
+        if save || !open {
+            editor.unsaved_since = None;
+            let text = editor.text.clone();
+            self.app_core.set_notes(text);
+        }
+        if !open {
+            self.notes_editor = None;
+        }
+    }
+}
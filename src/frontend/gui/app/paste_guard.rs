@@ -0,0 +1,77 @@
+//! Paste guard for the GUI input bar.
+//!
+//! Multi-line pastes are pulled out of egui's event queue before the input's
+//! TextEdit sees them and routed through `AppCore::route_paste`, the same
+//! path the TUI uses for bracketed pastes. Long pastes open a modal with the
+//! line count, a preview, and send-all / send-first-line / cancel.
+
+use super::*;
+use crate::core::PasteAction;
+use crate::data::PasteChoice;
+
+/// Preview rows shown before the "... N more" line
+const PREVIEW_LINES: usize = 6;
+
+impl VellumGuiApp {
+    /// Take multi-line paste events away from the focused input and route
+    /// them through core. Single-line pastes stay with the TextEdit.
+    pub(super) fn intercept_multiline_paste(&mut self, ui: &egui::Ui) {
+        let pastes: Vec<String> = ui.input_mut(|input| {
+            let mut taken = Vec::new();
+            input.events.retain(|event| match event {
+                egui::Event::Paste(text) if text.trim_end().contains('\n') => {
+                    taken.push(text.clone());
+                    false
+                }
+                _ => true,
+            });
+            taken
+        });
+        for text in pastes {
+            match self.app_core.route_paste(&text) {
+                PasteAction::Insert(line) => self.command_input.push_str(&line),
+                PasteAction::Sent(_) | PasteAction::Confirm => {}
+            }
+        }
+    }
+
+    pub(super) fn render_paste_guard(&mut self, ctx: &egui::Context) {
+        let Some(guard) = self.app_core.ui_state.paste_guard.as_ref() else {
+            return;
+        };
+        let count = guard.lines.len();
+        let mut choice = ctx.input_mut(|input| {
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::Escape) {
+                Some(PasteChoice::Cancel)
+            } else {
+                None
+            }
+        });
+        egui::Window::new(format!("Paste {} lines?", count))
+            .id(egui::Id::new("gui_paste_guard"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                for line in guard.lines.iter().take(PREVIEW_LINES) {
+                    ui.label(RichText::new(line).monospace());
+                }
+                if count > PREVIEW_LINES {
+                    ui.label(
+                        RichText::new(format!("... and {} more", count - PREVIEW_LINES)).weak(),
+                    );
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    for option in PasteChoice::ALL {
+                        if ui.button(option.label(count)).clicked() {
+                            choice = Some(option);
+                        }
+                    }
+                });
+            });
+        if let Some(choice) = choice {
+            self.app_core.resolve_paste_guard(choice);
+        }
+    }
+}
@@ -922,7 +922,7 @@ impl VellumGuiApp {
                     }
                     WebUiImageState::Loading => {
                         ui.horizontal(|ui| {
-                            ui.add(egui::Spinner::new());
+                            Self::busy_indicator(ui);
                             ui.label(RichText::new("loading image...").weak());
                         });
                     }
@@ -963,7 +963,7 @@ impl VellumGuiApp {
             WebUiImageState::Ready(texture) => texture,
             WebUiImageState::Loading => {
                 ui.horizontal(|ui| {
-                    ui.add(egui::Spinner::new());
+                    Self::busy_indicator(ui);
                     ui.label(RichText::new("loading image...").weak());
                 });
                 return;
@@ -14,10 +14,28 @@ impl VellumGuiApp {
     /// the target, and egui keeps repainting while the value is moving, so
     /// this composes with repaint-on-demand at zero idle cost.
     fn animated_fraction(ui: &egui::Ui, id_salt: &str, target: f32) -> f32 {
+        if !Self::animations_enabled(ui) {
+            return target;
+        }
         ui.ctx()
             .animate_value_with_time(ui.id().with(id_salt), target, BAR_ANIMATION_SECONDS)
     }
 
+    /// False under `ui.reduced_motion`, which `apply_ui_sizing` applies by
+    /// zeroing the style's animation time.
+    pub(super) fn animations_enabled(ui: &egui::Ui) -> bool {
+        ui.style().animation_time > 0.0
+    }
+
+    /// Busy indicator: a spinner, or a static ellipsis under reduced motion.
+    pub(super) fn busy_indicator(ui: &mut egui::Ui) {
+        if Self::animations_enabled(ui) {
+            ui.spinner();
+        } else {
+            ui.weak("…");
+        }
+    }
+
     pub(super) fn segment_to_rich_text(
         segment: &TextSegment,
         visuals: &egui::Visuals,
@@ -45,6 +63,8 @@ impl VellumGuiApp {
             .unwrap_or_else(|| {
                 if is_link {
                     visuals.hyperlink_color
+                } else if segment.span_type == crate::data::SpanType::Annotation {
+                    visuals.weak_text_color()
                 } else {
                     visuals.text_color()
                 }
@@ -171,6 +191,8 @@ impl VellumGuiApp {
             .unwrap_or_else(|| {
                 if is_link {
                     visuals.hyperlink_color
+                } else if segment.span_type == crate::data::SpanType::Annotation {
+                    visuals.weak_text_color()
                 } else {
                     visuals.text_color()
                 }
@@ -218,8 +240,7 @@ impl VellumGuiApp {
     ) -> Option<String> {
         use chrono::TimeZone;
         let local = chrono::Local.timestamp_opt(timestamp, 0).single()?;
-        let time = local.format("%l:%M %p").to_string();
-        let time = time.trim();
+        let time = crate::core::locale::format_time(&local);
         Some(match position {
             crate::config::TimestampPosition::Start => format!("[{}] ", time),
             crate::config::TimestampPosition::End => format!(" [{}]", time),
@@ -1061,11 +1082,17 @@ impl VellumGuiApp {
 
         // Bar is full at FULL_BAR_SECONDS or more and drains as the timer runs out.
         const FULL_BAR_SECONDS: u32 = 10;
-        let remaining_f = Self::countdown_remaining_seconds_f(
-            countdown.end_time,
-            app_core.server_time_offset,
-            now_f,
-        );
+        // Reduced motion steps the bar once per second instead of draining
+        // continuously.
+        let remaining_f = if Self::animations_enabled(ui) {
+            Self::countdown_remaining_seconds_f(
+                countdown.end_time,
+                app_core.server_time_offset,
+                now_f,
+            )
+        } else {
+            remaining as f32
+        };
         let fraction = remaining_f.min(FULL_BAR_SECONDS as f32) / FULL_BAR_SECONDS as f32;
         // Custom color override from the window config wins; otherwise the
         // fill falls back to the well-known per-timer defaults.
@@ -1134,6 +1161,7 @@ impl VellumGuiApp {
         ui: &mut egui::Ui,
         injuries: &HashMap<String, u8>,
         skin_art: Option<&crate::frontend::gui::skin::SkinWidgetArt>,
+        shape_cues: bool,
     ) {
         // Sprite mode: skin-supplied base body, then per part either a
         // hand-drawn severity overlay (authored on the base's canvas so it
@@ -1272,6 +1300,23 @@ impl VellumGuiApp {
                 }
             };
 
+            // Shape cues: rank numeral on the part ("s" marks scars) so
+            // severity doesn't rest on color alone.
+            if shape_cues && level > 0 {
+                let label = if level <= 3 {
+                    level.to_string()
+                } else {
+                    format!("s{}", level.min(6) - 3)
+                };
+                painter.text(
+                    hover_rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    label,
+                    egui::FontId::proportional((scale * 0.05).clamp(8.0, 14.0)),
+                    Self::readable_text_color(Color32::WHITE, fill, true),
+                );
+            }
+
             ui.interact(
                 hover_rect,
                 ui.id().with(("injury_doll", key)),
@@ -1298,6 +1343,7 @@ impl VellumGuiApp {
                         ui,
                         &popup.injuries,
                         self.skin_state.widget_art().as_deref(),
+                        self.current_theme.shape_cues,
                     );
                 });
             });
@@ -1311,6 +1357,7 @@ impl VellumGuiApp {
         label: &str,
         indicator: &crate::data::IndicatorData,
         skin_art: Option<&crate::frontend::gui::skin::SkinWidgetArt>,
+        shape_cues: bool,
     ) {
         let text = if label.is_empty() {
             &indicator.indicator_id
@@ -1357,12 +1404,23 @@ impl VellumGuiApp {
                         ui.visuals().window_fill(),
                     );
                 }
+                // Shape cue: strike through inactive icons
+                if shape_cues && !indicator.active {
+                    ui.painter().line_segment(
+                        [rect.left_bottom(), rect.right_top()],
+                        egui::Stroke::new(2.0, ui.visuals().strong_text_color()),
+                    );
+                }
                 response.on_hover_text(text.to_string());
             });
             return;
         }
         ui.centered_and_justified(|ui| {
-            ui.label(RichText::new(text).color(color).strong());
+            let mut label = RichText::new(text).color(color).strong();
+            if shape_cues && !indicator.active {
+                label = label.strikethrough();
+            }
+            ui.label(label);
         });
     }
 
@@ -1393,7 +1451,7 @@ impl VellumGuiApp {
                 return None;
             }
             DbState::Loading => {
-                ui.centered_and_justified(|ui| ui.spinner());
+                ui.centered_and_justified(Self::busy_indicator);
                 return None;
             }
             DbState::Failed => {
@@ -1460,16 +1518,22 @@ impl VellumGuiApp {
         if rect.width() < 8.0 || rect.height() < 8.0 {
             return None;
         }
-        // Glide toward the new room instead of jump-cutting.
+        // Glide toward the new room instead of jump-cutting (unless reduced
+        // motion is on).
+        let glide_seconds = if Self::animations_enabled(ui) {
+            0.25
+        } else {
+            0.0
+        };
         let cx = ui.ctx().animate_value_with_time(
             ui.id().with("map_center_x"),
             center.x as f32,
-            0.25,
+            glide_seconds,
         );
         let cy = ui.ctx().animate_value_with_time(
             ui.id().with("map_center_y"),
             center.y as f32,
-            0.25,
+            glide_seconds,
         );
         let camera = map_view::MapCamera {
             center: egui::Pos2::new(cx, cy),
@@ -1931,12 +1995,13 @@ impl VellumGuiApp {
     }
 
     /// Per-window field toggles for the gs4_experience widget, from its
-    /// layout def: (level, mind bar, exp bar, total exp, ascension exp).
+    /// layout def: (level, mind bar, exp bar, total exp, ascension exp,
+    /// time until clear).
     /// Missing def falls back to the widget's classic three-line look.
     pub(super) fn gs4_experience_flags(
         app_core: &AppCore,
         window_name: &str,
-    ) -> (bool, bool, bool, bool, bool) {
+    ) -> (bool, bool, bool, bool, bool, bool) {
         match app_core
             .layout
             .windows
@@ -1949,8 +2014,9 @@ impl VellumGuiApp {
                 data.show_exp_bar,
                 data.show_total_exp,
                 data.show_ascension_exp,
+                data.show_eta,
             ),
-            _ => (true, true, true, false, false),
+            _ => (true, true, true, false, false, false),
         }
     }
 
@@ -1969,19 +2035,6 @@ impl VellumGuiApp {
         }
     }
 
-    /// Group digits in threes: 1234567 -> "1,234,567".
-    fn format_thousands(value: u64) -> String {
-        let digits = value.to_string();
-        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
-        for (i, ch) in digits.chars().enumerate() {
-            if i > 0 && (digits.len() - i) % 3 == 0 {
-                out.push(',');
-            }
-            out.push(ch);
-        }
-        out
-    }
-
     pub(super) fn render_gs4_experience_content(
         app_core: &AppCore,
         ui: &mut egui::Ui,
@@ -1999,7 +2052,7 @@ impl VellumGuiApp {
             return;
         }
 
-        let (show_level, show_mind_bar, show_exp_bar, show_total_exp, show_ascension_exp) =
+        let (show_level, show_mind_bar, show_exp_bar, show_total_exp, show_ascension_exp, show_eta) =
             Self::gs4_experience_flags(app_core, window_name);
         if show_level && !exp.level_text.is_empty() {
             ui.label(RichText::new(&exp.level_text).strong());
@@ -2017,6 +2070,14 @@ impl VellumGuiApp {
             );
             ui.add_sized([ui.available_width().max(40.0), bar_height], bar);
         }
+        if show_eta {
+            if let Some(readout) = exp.absorb.readout(std::time::Instant::now()) {
+                ui.label(readout);
+                // Counts down between pulses without game text to repaint
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs(1));
+            }
+        }
         if show_exp_bar && !exp.next_level_text.is_empty() {
             let fraction =
                 Self::animated_fraction(ui, "gs4_next", exp.next_level_value.min(100) as f32 / 100.0);
@@ -2031,12 +2092,15 @@ impl VellumGuiApp {
         }
         if show_total_exp {
             if let Some(total) = exp.exp {
-                ui.label(format!("Exp: {}", Self::format_thousands(total)));
+                ui.label(format!("Exp: {}", crate::core::locale::format_uint(total)));
             }
         }
         if show_ascension_exp {
             if let Some(ascension) = exp.ascension_exp {
-                ui.label(format!("Ascension: {}", Self::format_thousands(ascension)));
+                ui.label(format!(
+                    "Ascension: {}",
+                    crate::core::locale::format_uint(ascension)
+                ));
             }
         }
     }
@@ -2091,6 +2155,454 @@ impl VellumGuiApp {
         }
     }
 
+    pub(super) fn stance_flags(app_core: &AppCore, window_name: &str) -> (bool, bool) {
+        match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::Stance { data, .. }) => {
+                (data.show_bar, data.show_posture)
+            }
+            _ => (true, true),
+        }
+    }
+
+    /// Stance bar and posture. Clicking the left half of the bar steps
+    /// toward offensive, the right half toward defensive (`.stance`).
+    pub(super) fn render_stance_content(
+        app_core: &AppCore,
+        ui: &mut egui::Ui,
+        window_name: &str,
+        settings: &WidgetRenderSettings,
+    ) -> Option<GuiLinkClick> {
+        let stance = &app_core.game_state.stance;
+        let (show_bar, show_posture) = Self::stance_flags(app_core, window_name);
+        let capitalize = |text: &str| {
+            let mut chars = text.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        };
+        let mut clicked = None;
+        if show_bar {
+            let text = if stance.name.is_empty() {
+                "Stance: unknown".to_string()
+            } else {
+                format!("{} ({}%)", capitalize(&stance.name), stance.percent)
+            };
+            let bar_height = ui.spacing().interact_size.y.max(16.0);
+            let fraction =
+                Self::animated_fraction(ui, "stance", stance.percent.min(100) as f32 / 100.0);
+            let bar = Self::styled_progress_bar(
+                ui,
+                settings,
+                fraction,
+                Color32::from_rgb(0x30, 0x50, 0xa0),
+                text,
+            );
+            let response = ui
+                .add_sized([ui.available_width().max(40.0), bar_height], bar)
+                .interact(egui::Sense::click())
+                .on_hover_text("Click left: more offensive\nClick right: more defensive");
+            if response.clicked() {
+                let forward = response
+                    .interact_pointer_pos()
+                    .is_some_and(|pos| pos.x >= response.rect.center().x);
+                let step = if forward { "next" } else { "prev" };
+                clicked = Some(Self::gui_link_click_from_response(
+                    &response,
+                    ui,
+                    Self::direct_command_link(format!(".stance {}", step)),
+                ));
+            }
+        }
+        if show_posture {
+            if let Some(posture) = crate::core::stance::posture(&app_core.game_state.status) {
+                ui.label(capitalize(posture));
+            }
+        }
+        clicked
+    }
+
+    /// Structured bounty: title colored by phase, detail lines, and a
+    /// "6/12 culled" bar for counted tasks (core::bounty_parser).
+    pub(super) fn render_task_tracker_content(
+        app_core: &AppCore,
+        ui: &mut egui::Ui,
+        window_name: &str,
+        settings: &WidgetRenderSettings,
+    ) {
+        use crate::core::bounty_parser::BountyPhase;
+        let bounty = &app_core.game_state.bounty;
+        let show_progress = match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::TaskTracker { data, .. }) => data.show_progress,
+            _ => true,
+        };
+        let Some(task) = &bounty.task else {
+            if bounty.has_data() {
+                ui.weak(bounty.compact_lines.join(" "));
+            } else {
+                ui.weak("No bounty - type BOUNTY");
+            }
+            return;
+        };
+        if let Some(title) = task.lines.first() {
+            let text = egui::RichText::new(title).strong();
+            let text = match task.phase {
+                BountyPhase::Complete => text.color(Color32::from_rgb(0x55, 0xb8, 0x6c)),
+                BountyPhase::Failed => text.color(Color32::from_rgb(0xcd, 0x4d, 0x4d)),
+                BountyPhase::Visit => text.color(Color32::from_rgb(0xd8, 0xb0, 0x40)),
+                BountyPhase::None => text.weak(),
+                BountyPhase::Active => text,
+            };
+            ui.label(text);
+        }
+        for line in task.lines.iter().skip(1) {
+            ui.label(line);
+        }
+        let progress = bounty
+            .progress()
+            .filter(|_| show_progress && !task.unit.is_empty());
+        if let Some((done, total)) = progress {
+            let bar_height = ui.spacing().interact_size.y.max(16.0);
+            let fraction = if total == 0 {
+                0.0
+            } else {
+                done.min(total) as f32 / total as f32
+            };
+            let bar = Self::styled_progress_bar(
+                ui,
+                settings,
+                fraction,
+                Color32::from_rgb(0x2e, 0x6b, 0x3a),
+                format!("{}/{} {}", done, total, task.unit),
+            );
+            ui.add_sized([ui.available_width().max(40.0), bar_height], bar);
+        }
+    }
+
+    /// Society status lines and the task checklist (core::society_tasks).
+    pub(super) fn render_society_tasks_content(
+        app_core: &AppCore,
+        ui: &mut egui::Ui,
+        window_name: &str,
+        settings: &WidgetRenderSettings,
+    ) {
+        let society = &app_core.game_state.society;
+        let show_progress = match app_core.layout.get_window(window_name) {
+            Some(crate::config::WindowDef::SocietyTasks { data, .. }) => data.show_progress,
+            _ => true,
+        };
+        if !society.has_data() {
+            ui.weak("No society tasks - type SOCIETY");
+            return;
+        }
+        for status in &society.status {
+            ui.weak(status);
+        }
+        let bar_height = ui.spacing().interact_size.y.max(16.0);
+        for task in &society.tasks {
+            let mark = if task.complete { "[x]" } else { "[ ]" };
+            let text = egui::RichText::new(format!("{} {}", mark, task.text));
+            ui.label(if task.complete {
+                text.color(Color32::from_rgb(0x55, 0xb8, 0x6c))
+            } else {
+                text
+            });
+            let progress = society.progress(task).filter(|_| show_progress);
+            if let Some((done, total)) = progress.filter(|_| !task.complete) {
+                let fraction = if total == 0 {
+                    0.0
+                } else {
+                    done.min(total) as f32 / total as f32
+                };
+                let bar = Self::styled_progress_bar(
+                    ui,
+                    settings,
+                    fraction,
+                    Color32::from_rgb(0x2e, 0x6b, 0x3a),
+                    format!("{}/{}", done, total),
+                );
+                ui.add_sized([ui.available_width().max(40.0), bar_height], bar);
+            }
+        }
+    }
+
+    pub(super) fn wealth_show_session(app_core: &AppCore, window_name: &str) -> bool {
+        match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::Wealth { data, .. }) => data.show_session,
+            _ => true,
+        }
+    }
+
+    /// Silver estimate and session gain/loss (core::wealth).
+    pub(super) fn render_wealth_content(app_core: &AppCore, ui: &mut egui::Ui, window_name: &str) {
+        use crate::core::wealth::format_silver;
+        let wealth = &app_core.game_state.wealth;
+        let show_session = Self::wealth_show_session(app_core, window_name);
+        match wealth.silver {
+            Some(silver) => ui.label(format!("Silver: {}", format_silver(silver))),
+            None => ui.weak("Silver: ? (type WEALTH)"),
+        };
+        if show_session {
+            let delta = wealth.session_delta;
+            let text = format!(
+                "Session: {}{}",
+                if delta > 0 { "+" } else { "" },
+                format_silver(delta)
+            );
+            match delta.signum() {
+                1 => ui.colored_label(Color32::from_rgb(0x55, 0xb8, 0x6c), text),
+                -1 => ui.colored_label(Color32::from_rgb(0xcd, 0x4d, 0x4d), text),
+                _ => ui.label(text),
+            };
+        }
+    }
+
+    /// Session hit rate, damage, best hit and top creatures (core::combat).
+    pub(super) fn render_combat_stats_content(
+        app_core: &AppCore,
+        ui: &mut egui::Ui,
+        window_name: &str,
+    ) {
+        use crate::core::combat::CombatStats;
+        let stats = &app_core.game_state.combat;
+        let creatures = match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::CombatStats { data, .. }) => data.creatures,
+            _ => 5,
+        };
+        for line in stats.summary_lines() {
+            ui.label(line);
+        }
+        for creature in stats.creatures_by_damage().into_iter().take(creatures) {
+            ui.weak(CombatStats::creature_line(creature));
+        }
+    }
+
+    /// Elanthian date/time, moon phases and optional local time
+    /// (data::game_time).
+    pub(super) fn render_calendar_content(
+        app_core: &AppCore,
+        ui: &mut egui::Ui,
+        window_name: &str,
+    ) {
+        let data = match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::Calendar { data, .. }) => data.clone(),
+            _ => Default::default(),
+        };
+        let server_time =
+            chrono::Utc::now().timestamp() + app_core.message_processor.server_time_offset;
+        let now = app_core.game_state.calendar.at(server_time);
+        ui.label(now.date_string());
+        if data.show_weekday {
+            ui.label(format!("{}, {}", now.clock_string(), now.weekday_name()));
+        } else {
+            ui.label(now.clock_string());
+        }
+        if data.show_local_time {
+            ui.weak(format!(
+                "Local {}",
+                crate::core::locale::format_clock_compact(&chrono::Local::now())
+            ));
+        }
+        for moon in &data.moons {
+            ui.horizontal(|ui| {
+                ui.weak(format!("{}:", moon.name));
+                ui.label(moon.phase(server_time).label());
+            });
+        }
+        // The clock only changes by the minute
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs(1));
+    }
+
+    /// The character notepad, or one section of it (core::notes).
+    pub(super) fn render_notes_content(app_core: &AppCore, ui: &mut egui::Ui, window_name: &str) {
+        let section = match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::Notes { data, .. }) => data.section.as_str(),
+            _ => "",
+        };
+        let lines = app_core.notes.lines(Some(section));
+        if lines.iter().all(|line| line.trim().is_empty()) {
+            ui.weak("No notes (.notes)");
+            return;
+        }
+        egui::ScrollArea::vertical()
+            .id_salt(("notes_scroll", window_name))
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for line in lines {
+                    if line.starts_with('#') {
+                        ui.strong(line);
+                    } else {
+                        ui.label(line);
+                    }
+                }
+            });
+    }
+
+    /// Labeled live values of `.watch` expressions (core::watch).
+    pub(super) fn render_watch_content(app_core: &AppCore, ui: &mut egui::Ui, window_name: &str) {
+        let rows = app_core.watch_rows(window_name);
+        if rows.is_empty() {
+            ui.weak("Nothing watched (.watch add)");
+            return;
+        }
+        egui::Grid::new(("watch_grid", window_name))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for row in rows {
+                    ui.weak(&row.label);
+                    match &row.value {
+                        Ok(value) => ui.label(value),
+                        Err(e) => ui.colored_label(Color32::LIGHT_RED, "?").on_hover_text(e),
+                    };
+                    ui.end_row();
+                }
+            });
+        // Timers like rt.remaining tick without new game data
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs(1));
+    }
+
+    /// Raw socket lines from network::traffic, newest at the bottom.
+    pub(super) fn render_traffic_content(app_core: &AppCore, ui: &mut egui::Ui, window_name: &str) {
+        use crate::network::traffic::{self, Direction};
+        let hex = matches!(
+            app_core.layout.get_window(window_name),
+            Some(crate::config::WindowDef::Traffic { data, .. }) if data.hex
+        );
+        let lines = traffic::recent(traffic::CAPACITY);
+        if lines.is_empty() {
+            ui.weak(if traffic::is_enabled() {
+                "Waiting for traffic..."
+            } else {
+                "Capture off (.traffic on)"
+            });
+            return;
+        }
+        egui::ScrollArea::both()
+            .id_salt(("traffic_scroll", window_name))
+            .stick_to_bottom(true)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for line in &lines {
+                    let color = match line.direction {
+                        Direction::In => ui.visuals().text_color(),
+                        Direction::Out => Color32::LIGHT_BLUE,
+                    };
+                    let prefix = format!(
+                        "{} {} ",
+                        line.at.format("%H:%M:%S"),
+                        line.direction.marker()
+                    );
+                    let body = if hex {
+                        let mut body = format!("{} bytes", line.bytes.len());
+                        for row in line.hex_rows() {
+                            body.push('\n');
+                            body.push_str(&row);
+                        }
+                        body
+                    } else {
+                        line.text()
+                    };
+                    ui.horizontal_top(|ui| {
+                        ui.label(RichText::new(prefix).monospace().weak());
+                        ui.label(RichText::new(body).monospace().color(color));
+                    });
+                }
+            });
+        // Lines arrive without game text to trigger a repaint (commands sent)
+        if traffic::is_enabled() {
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_millis(250));
+        }
+    }
+
+    /// Session timer, today and total played time (core::played).
+    pub(super) fn render_played_content(app_core: &AppCore, ui: &mut egui::Ui, window_name: &str) {
+        let show_total = !matches!(
+            app_core.layout.get_window(window_name),
+            Some(crate::config::WindowDef::Played { data, .. }) if !data.show_total
+        );
+        let rows = app_core.played.rows(chrono::Local::now(), show_total);
+        egui::Grid::new(("played_grid", window_name))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (label, value) in rows {
+                    ui.weak(label);
+                    ui.label(RichText::new(value).monospace());
+                    ui.end_row();
+                }
+            });
+        // The session timer ticks without game text to trigger a repaint
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs(1));
+    }
+
+    /// Upcoming `.remind` reminders, soonest first (core::reminders).
+    pub(super) fn render_reminders_content(
+        app_core: &AppCore,
+        ui: &mut egui::Ui,
+        window_name: &str,
+    ) {
+        use crate::core::reminders::format_duration;
+        let show_repeat = match app_core
+            .layout
+            .windows
+            .iter()
+            .find(|w| w.name() == window_name)
+        {
+            Some(crate::config::WindowDef::Reminders { data, .. }) => data.show_repeat,
+            _ => true,
+        };
+        let upcoming = app_core.reminders.upcoming();
+        if upcoming.is_empty() {
+            ui.weak("No reminders (.remind)");
+            return;
+        }
+        let now = chrono::Utc::now().timestamp();
+        for reminder in upcoming {
+            ui.horizontal(|ui| {
+                ui.weak(format_duration(reminder.due - now));
+                match reminder.repeat_label().filter(|_| show_repeat) {
+                    Some(repeat) => ui.label(format!("{} ({})", reminder.message, repeat)),
+                    None => ui.label(&reminder.message),
+                };
+            });
+        }
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs(1));
+    }
+
     pub(super) fn render_betrayer_content(
         app_core: &AppCore,
         ui: &mut egui::Ui,
@@ -2217,18 +2729,19 @@ impl VellumGuiApp {
         ui: &mut egui::Ui,
         container_title: &str,
         wrap: bool,
-    ) {
+    ) -> Option<GuiLinkClick> {
         let Some(container) = app_core.game_state.container_cache.find_by_title(container_title)
         else {
             ui.weak(format!("No contents cached for \"{}\".", container_title));
-            return;
+            return None;
         };
 
         if container.items.is_empty() {
             ui.weak("Empty.");
-            return;
+            return None;
         }
 
+        let mut clicked_link = None;
         let max_height = ui.available_height().max(1.0);
         let scroll_area = if wrap {
             egui::ScrollArea::vertical()
@@ -2244,10 +2757,41 @@ impl VellumGuiApp {
                 if !wrap {
                     ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
                 }
-                for item in &container.items {
-                    ui.label(item);
+                for (index, item) in container.items.iter().enumerate() {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.spacing_mut().item_spacing.x = 0.0;
+                        // Item links drag to other container windows (get/put)
+                        for segment in crate::core::containers::parse_item_line(item) {
+                            let Some(link_data) = segment.link_data else {
+                                ui.label(segment.text.as_str());
+                                continue;
+                            };
+                            let response = ui
+                                .add(
+                                    egui::Label::new(
+                                        RichText::new(segment.text.as_str())
+                                            .color(ui.visuals().hyperlink_color),
+                                    )
+                                    .sense(egui::Sense::click_and_drag())
+                                    .selectable(!Self::link_drag_blocks_selection(ui)),
+                                )
+                                .on_hover_cursor(egui::CursorIcon::PointingHand);
+                            if let Some(drop) = Self::handle_link_dnd(ui, &response, &link_data) {
+                                clicked_link.get_or_insert(drop);
+                            }
+                            if response.clicked() && clicked_link.is_none() {
+                                clicked_link = Some(Self::gui_link_click_from_response(
+                                    &response, ui, link_data,
+                                ));
+                            }
+                        }
+                        if let Some(Some(note)) = container.notes.get(index) {
+                            ui.weak(format!(" {}", note.trim()));
+                        }
+                    });
                 }
             });
+        clicked_link
     }
 
     /// Sentinel exist_id used to route quickbar switching through the
@@ -2322,8 +2866,9 @@ impl VellumGuiApp {
     /// noun is "<window_name>|<tab_index>".
     pub(super) const TABBED_SWITCH_SENTINEL: &'static str = "_tabbed_switch_";
 
-    /// Inner tab strip for tabbedtext windows. Unread tabs render bold; clicks
-    /// flow through the link channel since renderers only get `&AppCore`.
+    /// Inner tab strip for tabbedtext windows. Unread tabs render bold and
+    /// alerting tabs flash; clicks flow through the link channel since
+    /// renderers only get `&AppCore`.
     fn render_tabbed_text_tab_strip(
         ui: &mut egui::Ui,
         window_name: &str,
@@ -2340,6 +2885,19 @@ impl VellumGuiApp {
                 if tab_state.has_unread && !is_active {
                     label = label.strong();
                 }
+                if tab_state.alerting && !is_active {
+                    let animate = Self::animations_enabled(ui);
+                    let lit = !animate || (ui.input(|i| i.time) * 2.0) as i64 % 2 == 0;
+                    if lit {
+                        label = label
+                            .color(ui.visuals().extreme_bg_color)
+                            .background_color(ui.visuals().warn_fg_color);
+                    }
+                    if animate {
+                        ui.ctx()
+                            .request_repaint_after(std::time::Duration::from_millis(500));
+                    }
+                }
                 if ui.selectable_label(is_active, label).clicked() && !is_active {
                     clicked = Some(GuiLinkClick {
                         link_data: LinkData {
@@ -2524,7 +3082,13 @@ impl VellumGuiApp {
                     clicked = Some(Self::gui_link_click_from_response(
                         &response,
                         ui,
-                        Self::direct_command_link(button.command.clone()),
+                        // %name% variables expand here; dot commands
+                        // (.if, .set) are expanded by the command funnel
+                        Self::direct_command_link(if button.command.starts_with('.') {
+                            button.command.clone()
+                        } else {
+                            app_core.config.variables.expand(&button.command)
+                        }),
                     ));
                 }
             }
@@ -2575,6 +3139,17 @@ impl VellumGuiApp {
                     stats.bytes_sent_per_sec()
                 ),
             ));
+            if let Some((wire, inflated)) = stats.compression_totals() {
+                rows.push((
+                    "Compressed",
+                    format!(
+                        "{} B -> {} B ({:.1}x)",
+                        wire,
+                        inflated,
+                        inflated as f64 / wire as f64
+                    ),
+                ));
+            }
         }
         if cfg.show_parse {
             rows.push((
@@ -2585,6 +3160,16 @@ impl VellumGuiApp {
                     stats.elements_per_sec()
                 ),
             ));
+            rows.push((
+                "Parse queue",
+                format!(
+                    "wait {:.1} us, apply {:.1} us, backlog {} (max {})",
+                    stats.avg_parse_queue_wait_us(),
+                    stats.avg_parse_apply_time_us(),
+                    stats.last_parsed_queue_depth(),
+                    stats.max_parsed_queue_depth()
+                ),
+            ));
         }
         if cfg.show_events {
             rows.push((
@@ -2605,6 +3190,16 @@ impl VellumGuiApp {
                     stats.estimated_memory_mb()
                 ),
             ));
+            let interned = stats.intern_stats();
+            rows.push((
+                "Shared",
+                format!(
+                    "{:.1} KB as copies, {:.1} KB shared ({} strings)",
+                    interned.bytes_unshared as f64 / 1024.0,
+                    interned.bytes_shared as f64 / 1024.0,
+                    interned.unique
+                ),
+            ));
         }
         if cfg.show_lines {
             rows.push((
@@ -2654,6 +3249,7 @@ impl VellumGuiApp {
         ui: &mut egui::Ui,
         indicators: &[(String, u8)],
         skin_art: Option<&crate::frontend::gui::skin::SkinWidgetArt>,
+        shape_cues: bool,
     ) {
         // Matches the TUI dashboard default of hiding inactive indicators.
         let active: Vec<&(String, u8)> = indicators
@@ -2695,7 +3291,23 @@ impl VellumGuiApp {
                             ui.visuals().window_fill(),
                         );
                     }
+                    // Shape cue: level numeral in the corner
+                    if shape_cues {
+                        ui.painter().text(
+                            rect.right_bottom(),
+                            egui::Align2::RIGHT_BOTTOM,
+                            value.to_string(),
+                            egui::FontId::proportional((icon_side * 0.4).max(8.0)),
+                            ui.visuals().strong_text_color(),
+                        );
+                    }
                     response.on_hover_text(super::status_icons::display_name(id));
+                } else if shape_cues {
+                    ui.label(
+                        RichText::new(format!("{} {}", id, value))
+                            .color(color)
+                            .strong(),
+                    );
                 } else {
                     ui.label(RichText::new(id).color(color).strong());
                 }
@@ -2714,6 +3326,48 @@ impl VellumGuiApp {
         });
     }
 
+    /// The Objects line with `[loot]` tier colors, matching what the loot
+    /// filter's generated highlights paint in the TUI
+    pub(super) fn render_room_objects(app_core: &AppCore, ui: &mut egui::Ui, objects: &[String]) {
+        let loot = &app_core.config.loot;
+        if !loot.enabled {
+            Self::render_room_entities(ui, "Objects", objects);
+            return;
+        }
+        if objects.is_empty() {
+            return;
+        }
+        let table = app_core.message_processor.loot_table();
+        ui.separator();
+        ui.horizontal_wrapped(|ui| {
+            ui.label(RichText::new("Objects:").strong());
+            for (index, object) in objects.iter().enumerate() {
+                let mut text = RichText::new(object.as_str());
+                if let Some(item) = table.classify(object) {
+                    let color = match item.tier {
+                        crate::data::loot::LootTier::Top => &loot.top_color,
+                        crate::data::loot::LootTier::Valuable => &loot.valuable_color,
+                        crate::data::loot::LootTier::Common => &loot.common_color,
+                    };
+                    let color = color
+                        .as_deref()
+                        .map(|color| app_core.config.resolve_palette_color(color))
+                        .and_then(|color| parse_hex_color(&color));
+                    if let Some(color) = color {
+                        text = text.color(color);
+                        if item.tier == crate::data::loot::LootTier::Top {
+                            text = text.strong();
+                        }
+                    }
+                }
+                ui.label(text);
+                if index + 1 < objects.len() {
+                    ui.label(",");
+                }
+            }
+        });
+    }
+
     pub(super) fn render_room_exits(ui: &mut egui::Ui, exits: &[String]) -> Option<GuiLinkClick> {
         if exits.is_empty() {
             return None;
@@ -3148,6 +3802,17 @@ impl VellumGuiApp {
         let mut dropped_px = 0.0f32;
         let mut appended_px = 0.0f32;
         if incremental {
+            // The newest measured line was rewritten in place (repeat
+            // counter bump): re-measure it along with the new lines
+            let mut delta = delta;
+            if cache.tail_revision != content.tail_revision
+                && !cache.heights.is_empty()
+                && delta < rendered_count
+            {
+                let h = cache.heights.pop().unwrap_or_default();
+                appended_px -= h + spacing_y;
+                delta += 1;
+            }
             if delta > 0 {
                 let drop_front = (cache.heights.len() + delta)
                     .saturating_sub(rendered_count)
@@ -3178,10 +3843,29 @@ impl VellumGuiApp {
         cache.wrap_width = wrap_width;
         cache.font_id = font_id.clone();
         cache.generation = content.generation;
+        cache.tail_revision = content.tail_revision;
         debug_assert_eq!(cache.heights.len(), rendered_count);
         (dropped_px, appended_px)
     }
 
+    /// Scroll a text view by whole lines on its next frame (the
+    /// `scroll_window` keybind function; positive lines go back)
+    pub(super) fn request_text_scroll(ctx: &egui::Context, scroll_id: &str, lines: i32) {
+        let cache_id = egui::Id::new(("text_row_heights", scroll_id));
+        let cache_handle = ctx.data_mut(|data| {
+            data.get_temp_mut_or_insert_with::<std::sync::Arc<std::sync::Mutex<RowHeightCache>>>(
+                cache_id,
+                Default::default,
+            )
+            .clone()
+        });
+        cache_handle
+            .lock()
+            .expect("row height cache poisoned")
+            .scroll_lines += lines;
+        ctx.request_repaint();
+    }
+
     pub(super) fn render_text_content(
         ui: &mut egui::Ui,
         content: &TextContent,
@@ -3194,6 +3878,7 @@ impl VellumGuiApp {
         let style = ui.style().clone();
         let visuals = &style.visuals;
         let mut clicked_link = None;
+        let mut unseen_lines = 0usize;
         let rendered_count = content.lines.len().min(MAX_RENDERED_LINES);
         let start = content.lines.len() - rendered_count;
         let max_height = ui.available_height().max(1.0);
@@ -3204,7 +3889,7 @@ impl VellumGuiApp {
         } else {
             egui::ScrollArea::both()
         };
-        scroll_area
+        let output = scroll_area
             .id_salt(format!("text_scroll_{}", scroll_id))
             .stick_to_bottom(true)
             .auto_shrink([false, false])
@@ -3281,6 +3966,30 @@ impl VellumGuiApp {
                     }
                 }
 
+                // Pinned-to-bottom bookkeeping for the "N new lines" pill:
+                // only a reader at the bottom has seen the newest lines.
+                let total_px: f32 = cache.heights.iter().map(|h| h + spacing_y).sum();
+                if std::mem::take(&mut cache.jump_to_bottom) {
+                    ui.scroll_to_rect(
+                        Rect::from_min_size(
+                            Pos2::new(content_left, content_top + total_px - 1.0),
+                            Vec2::splat(1.0),
+                        ),
+                        Some(egui::Align::BOTTOM),
+                    );
+                    cache.seen_generation = Some(content.generation);
+                }
+                let scroll_lines = std::mem::take(&mut cache.scroll_lines);
+                if scroll_lines != 0 {
+                    let row_px = ctx.fonts_mut(|fonts| fonts.row_height(font_id)) + spacing_y;
+                    ui.scroll_with_delta(Vec2::new(0.0, scroll_lines as f32 * row_px));
+                }
+                if viewport.max.y >= total_px - spacing_y - 1.0 {
+                    cache.seen_generation = Some(content.generation);
+                }
+                let seen = *cache.seen_generation.get_or_insert(content.generation);
+                unseen_lines = (content.generation.wrapping_sub(seen) as usize).min(rendered_count);
+
                 // ---- Buffer-anchored selection: window-level updates ----
                 let clip = ui.clip_rect();
                 let mut selection = Self::buffer_selection(&ctx);
@@ -3634,6 +4343,38 @@ impl VellumGuiApp {
                     ui.allocate_space(Vec2::new(1.0, bottom_space - spacing_y));
                 }
             });
+
+        if unseen_lines > 0 {
+            let label = format!(
+                "{} new line{} \u{2193}",
+                unseen_lines,
+                if unseen_lines == 1 { "" } else { "s" }
+            );
+            let text_size = ui
+                .painter()
+                .layout_no_wrap(label.clone(), font_id.clone(), visuals.text_color())
+                .size();
+            let size = text_size + ui.spacing().button_padding * 2.0;
+            let margin = 8.0;
+            let pill = Rect::from_min_size(
+                output.inner_rect.right_bottom() - size - Vec2::splat(margin),
+                size,
+            );
+            let button = egui::Button::new(RichText::new(label).font(font_id.clone()))
+                .fill(visuals.selection.bg_fill)
+                .corner_radius(size.y / 2.0);
+            if ui.put(pill, button).clicked() {
+                if let Some(handle) = ui.ctx().data(|data| {
+                    data.get_temp::<std::sync::Arc<std::sync::Mutex<RowHeightCache>>>(cache_id)
+                }) {
+                    handle
+                        .lock()
+                        .expect("row height cache poisoned")
+                        .jump_to_bottom = true;
+                }
+                ui.ctx().request_repaint();
+            }
+        }
         clicked_link
     }
 
@@ -3677,6 +4418,14 @@ impl VellumGuiApp {
             ui.label("This tab's source window is no longer available.");
             return None;
         };
+        // `show_when` is false: the dock keeps its space, empty
+        if app_core
+            .ui_state
+            .hidden_by_condition
+            .contains(&tab.window_name)
+        {
+            return None;
+        }
 
         if let Some(background) = &settings.background {
             crate::frontend::gui::skin::paint_background(
@@ -3827,7 +4576,7 @@ impl VellumGuiApp {
                     Self::render_room_entities(ui, "Players", &room.players);
                 }
                 if show_objs {
-                    Self::render_room_entities(ui, "Objects", &room.objects);
+                    Self::render_room_objects(app_core, ui, &room.objects);
                 }
                 clicked_link
             }
@@ -3853,15 +4602,26 @@ impl VellumGuiApp {
                     &tab.id.title,
                     indicator,
                     settings.skin_art.as_deref(),
+                    settings.shape_cues,
                 );
                 None
             }
             WindowContent::InjuryDoll(doll) => {
-                Self::render_injury_doll(ui, &doll.injuries, settings.skin_art.as_deref());
+                Self::render_injury_doll(
+                    ui,
+                    &doll.injuries,
+                    settings.skin_art.as_deref(),
+                    settings.shape_cues,
+                );
                 None
             }
             WindowContent::Dashboard { indicators } => {
-                Self::render_dashboard_content(ui, indicators, settings.skin_art.as_deref());
+                Self::render_dashboard_content(
+                    ui,
+                    indicators,
+                    settings.skin_art.as_deref(),
+                    settings.shape_cues,
+                );
                 None
             }
             WindowContent::GS4Experience => {
@@ -3876,6 +4636,49 @@ impl VellumGuiApp {
                 Self::render_encumbrance_content(app_core, ui, &tab.window_name, &settings);
                 None
             }
+            WindowContent::Wealth => {
+                Self::render_wealth_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::TaskTracker => {
+                Self::render_task_tracker_content(app_core, ui, &tab.window_name, &settings);
+                None
+            }
+            WindowContent::SocietyTasks => {
+                Self::render_society_tasks_content(app_core, ui, &tab.window_name, &settings);
+                None
+            }
+            WindowContent::CombatStats => {
+                Self::render_combat_stats_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Calendar => {
+                Self::render_calendar_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Reminders => {
+                Self::render_reminders_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Notes => {
+                Self::render_notes_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Watch => {
+                Self::render_watch_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Traffic => {
+                Self::render_traffic_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Played => {
+                Self::render_played_content(app_core, ui, &tab.window_name);
+                None
+            }
+            WindowContent::Stance => {
+                Self::render_stance_content(app_core, ui, &tab.window_name, &settings)
+            }
             WindowContent::Betrayer => {
                 Self::render_betrayer_content(app_core, ui, &settings);
                 None
@@ -3885,8 +4688,7 @@ impl VellumGuiApp {
             }
             WindowContent::Items => Self::render_items_content(app_core, ui),
             WindowContent::Container { container_title } => {
-                Self::render_container_content(app_core, ui, container_title, settings.wrap_text);
-                None
+                Self::render_container_content(app_core, ui, container_title, settings.wrap_text)
             }
             WindowContent::Quickbar => Self::render_quickbar_content(app_core, ui),
             WindowContent::Hotkeybar { bar } => {
@@ -3939,7 +4741,16 @@ pub(super) struct RowHeightCache {
     wrap_width: f32,
     font_id: egui::FontId,
     generation: u64,
+    tail_revision: u64,
     heights: Vec<f32>,
+    /// Generation last seen at the bottom; anything newer arrived while the
+    /// reader was scrolled up (the "N new lines" pill)
+    seen_generation: Option<u64>,
+    /// Set by the pill; the next frame scrolls to the bottom
+    jump_to_bottom: bool,
+    /// Set by a `scroll_window` keybind; the next frame scrolls this many
+    /// lines (positive goes back)
+    scroll_lines: i32,
 }
 
 pub(super) fn parse_hex_color(input: &str) -> Option<Color32> {
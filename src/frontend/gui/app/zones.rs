@@ -100,6 +100,20 @@ impl ShellLayoutSnapshot {
         self.left_sidebar_width = self.left_sidebar_width.min(max_sidebar_width);
         self.right_sidebar_width = self.right_sidebar_width.min(max_sidebar_width);
     }
+
+    /// Open the shell regions that the given zones need (the default shell
+    /// is center-only, which would leave imported tabs in hidden regions)
+    pub(super) fn reveal_zones(&mut self, zones: impl IntoIterator<Item = GuiShellZone>) {
+        for zone in zones {
+            match zone {
+                GuiShellZone::Header => self.header_visible = true,
+                GuiShellZone::Footer => self.footer_visible = true,
+                GuiShellZone::LeftSidebar => self.left_sidebar_collapsed = false,
+                GuiShellZone::RightSidebar => self.right_sidebar_collapsed = false,
+                GuiShellZone::Center => {}
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -158,6 +172,99 @@ impl VellumGuiApp {
         }
     }
 
+    /// Dock zone for a TUI layout rectangle (cells), judged by where it sits
+    /// on the designed terminal: short full-width strips at the top/bottom
+    /// become header/footer, narrow columns hugging an edge become sidebars,
+    /// everything else docks in the center.
+    pub(super) fn zone_for_tui_rect(
+        row: u16,
+        col: u16,
+        rows: u16,
+        cols: u16,
+        term_cols: u16,
+        term_rows: u16,
+    ) -> GuiShellZone {
+        let term_cols = f32::from(term_cols.max(1));
+        let term_rows = f32::from(term_rows.max(1));
+        let top = f32::from(row) / term_rows;
+        let bottom = f32::from(row.saturating_add(rows)) / term_rows;
+        let left = f32::from(col) / term_cols;
+        let right = f32::from(col.saturating_add(cols)) / term_cols;
+        let width = right - left;
+        let height = bottom - top;
+
+        if width < 0.35 && height >= 0.3 {
+            if right <= 0.4 {
+                return GuiShellZone::LeftSidebar;
+            }
+            if left >= 0.6 {
+                return GuiShellZone::RightSidebar;
+            }
+        }
+        if height <= 0.2 {
+            if bottom <= 0.25 {
+                return GuiShellZone::Header;
+            }
+            if top >= 0.75 {
+                return GuiShellZone::Footer;
+            }
+        }
+        GuiShellZone::Center
+    }
+
+    /// Dock zones converted from the TUI layout, for every tab whose window
+    /// the layout places. Used for first-run GUI layouts and `.importlayout`.
+    pub(super) fn zones_from_tui_layout(
+        layout: &crate::config::Layout,
+        available_tabs: &HashMap<TabKey, GuiTab>,
+    ) -> HashMap<TabKey, GuiShellZone> {
+        // Fall back to the layout's own extent when it wasn't saved with a size
+        let extent = |edge: fn(&crate::config::WindowBase) -> u16| {
+            layout
+                .windows
+                .iter()
+                .map(|w| edge(w.base()))
+                .max()
+                .unwrap_or(1)
+        };
+        let term_cols = layout
+            .terminal_width
+            .unwrap_or_else(|| extent(|b| b.col.saturating_add(b.cols)));
+        let term_rows = layout
+            .terminal_height
+            .unwrap_or_else(|| extent(|b| b.row.saturating_add(b.rows)));
+
+        available_tabs
+            .iter()
+            .filter_map(|(key, tab)| {
+                let base = layout
+                    .windows
+                    .iter()
+                    .find(|w| w.name() == tab.window_name)?
+                    .base();
+                Some((
+                    key.clone(),
+                    Self::zone_for_tui_rect(
+                        base.row, base.col, base.rows, base.cols, term_cols, term_rows,
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    /// Re-dock every tab according to the current TUI layout (`.importlayout`)
+    pub(super) fn import_tui_layout_zones(&mut self) -> usize {
+        let zones = Self::zones_from_tui_layout(&self.app_core.layout, &self.available_tabs);
+        for (key, zone) in &zones {
+            self.tab_zones.insert(key.clone(), *zone);
+        }
+        self.shell_layout.reveal_zones(zones.values().copied());
+        self.main_window_rects.clear();
+        self.last_center_window_rects.clear();
+        self.layout_dirty = true;
+        zones.len()
+    }
+
     pub(super) fn zone_for_tab(&self, key: &TabKey) -> GuiShellZone {
         self.tab_zones
             .get(key)
@@ -783,7 +890,16 @@ impl VellumGuiApp {
                             Self::is_compact_center_widget(&window.widget_type)
                                 || matches!(
                                     window.widget_type,
-                                    WidgetType::Encumbrance | WidgetType::Dashboard
+                                    WidgetType::Encumbrance
+                                        | WidgetType::Stance
+                                        | WidgetType::Wealth
+                                        | WidgetType::Calendar
+                                        | WidgetType::Reminders
+                                        | WidgetType::Notes
+                                        | WidgetType::Watch
+                                        | WidgetType::Traffic
+                                        | WidgetType::Played
+                                        | WidgetType::Dashboard
                                 )
                         })
                         .unwrap_or(false);
@@ -1233,6 +1349,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_zone_for_tui_rect_classifies_edges() {
+        // 200x50 terminal
+        assert_eq!(
+            VellumGuiApp::zone_for_tui_rect(0, 0, 40, 40, 200, 50),
+            super::GuiShellZone::LeftSidebar
+        );
+        assert_eq!(
+            VellumGuiApp::zone_for_tui_rect(0, 160, 45, 40, 200, 50),
+            super::GuiShellZone::RightSidebar
+        );
+        assert_eq!(
+            VellumGuiApp::zone_for_tui_rect(0, 40, 3, 120, 200, 50),
+            super::GuiShellZone::Header
+        );
+        assert_eq!(
+            VellumGuiApp::zone_for_tui_rect(46, 40, 4, 120, 200, 50),
+            super::GuiShellZone::Footer
+        );
+        assert_eq!(
+            VellumGuiApp::zone_for_tui_rect(3, 40, 40, 120, 200, 50),
+            super::GuiShellZone::Center
+        );
+    }
+
+    #[test]
+    fn test_reveal_zones_opens_needed_regions() {
+        let mut shell = ShellLayoutSnapshot::default();
+        shell.reveal_zones([
+            super::GuiShellZone::Footer,
+            super::GuiShellZone::LeftSidebar,
+        ]);
+        assert!(shell.footer_visible);
+        assert!(!shell.left_sidebar_collapsed);
+        assert!(!shell.header_visible);
+        assert!(shell.right_sidebar_collapsed);
+    }
+
     #[test]
     fn test_zone_for_pointer_returns_matching_zone() {
         let zone_rects = vec![
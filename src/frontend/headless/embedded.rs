@@ -48,6 +48,7 @@ pub fn start(data_dir: &str) -> Result<(u16, String), String> {
 
     let config = crate::config::Config::load_with_options(None, None)
         .map_err(|e| format!("config load failed: {e:#}"))?;
+    config.apply_profile_paths();
     let token = crate::config::Config::load_or_create_web_token()
         .map_err(|e| format!("web token unavailable: {e:#}"))?;
     // The runtime forces web.enabled; the port comes from config ([web]
@@ -17,9 +17,11 @@ use tokio::sync::mpsc;
 
 use crate::core::remote::{RemoteSessionInfo, SessionState};
 use crate::network::{
-    AuthFailed, DirectConnectConfig, DirectConnection, LichConnection, RawLogger, ServerMessage,
+    AuthFailed, DirectConnectConfig, DirectConnection, GameTls, LichConnection, RawLogger,
+    ServerMessage, TlsFailed,
 };
 use crate::core::AppCore;
+use crate::parse_worker::ParsedMessage;
 
 /// Windows are layout containers for stream routing; with no terminal we
 /// still initialize them at a nominal size so highlight/stream processing
@@ -128,6 +130,14 @@ impl Supervisor {
             }
         };
         let task = match self.direct.as_ref() {
+            // --demo plays a scripted session instead of connecting
+            _ if app_core.config.demo_script.is_some() => {
+                let script = app_core.config.demo_script.clone().unwrap_or_default();
+                tokio::spawn(async move {
+                    crate::network::demo::DemoConnection::start(&script, server_tx, command_rx)
+                        .await
+                })
+            }
             Some(cfg) => {
                 let cfg = cfg.clone();
                 tokio::spawn(async move {
@@ -143,9 +153,18 @@ impl Supervisor {
                     ),
                 };
                 let login_key = self.login_key.clone();
+                let proxy = app_core.config.proxy.clone();
                 tokio::spawn(async move {
-                    LichConnection::start(&host, port, login_key, server_tx, command_rx, raw_logger)
-                        .await
+                    LichConnection::start(
+                        &host,
+                        port,
+                        login_key,
+                        server_tx,
+                        command_rx,
+                        raw_logger,
+                        Some(&proxy),
+                    )
+                    .await
                 })
             }
         };
@@ -224,6 +243,9 @@ fn resolve_connect(req: &SessionRequest) -> Result<ResolvedConnect, String> {
             character: saved.character.clone(),
             game_code: DirectConnectConfig::game_name_to_code(&saved.game).to_string(),
             data_dir,
+            compression: false,
+            proxy: None,
+            tls: None,
         }));
     }
 
@@ -287,6 +309,9 @@ fn resolve_connect(req: &SessionRequest) -> Result<ResolvedConnect, String> {
         character,
         game_code: DirectConnectConfig::game_name_to_code(&game).to_string(),
         data_dir,
+        compression: false,
+        proxy: None,
+        tls: None,
     }))
 }
 
@@ -310,6 +335,24 @@ pub async fn async_run(
     app_core.enable_remote(sink);
     app_core.set_remote_session_control(true);
 
+    // Control socket for `vellum-fe send`; the guard removes control.json
+    // on exit
+    let (_control_server, mut control_rx) =
+        match crate::control::ControlServer::start(app_core.config.character.as_deref()) {
+            Ok((server, rx)) => (Some(server), Some(rx)),
+            Err(e) => {
+                tracing::warn!("Control socket unavailable: {e:#}");
+                (None, None)
+            }
+        };
+
+    // `[command_pipe]` named pipe and stdin (there is no terminal UI here)
+    let mut pipe_rx = crate::command_pipe::start(
+        &app_core.config.command_pipe,
+        app_core.config.character.as_deref(),
+        true,
+    );
+
     // With no local UI there is no `.webinfo` to surface the pairing token —
     // print the ready-to-open URL instead. (Unpinned instances may port-walk
     // above the base port if it's taken; the log from the server task shows
@@ -334,8 +377,10 @@ pub async fn async_run(
 
     app_core.init_windows(NOMINAL_COLS, NOMINAL_ROWS);
 
-    let (server_tx, mut server_rx) =
+    let (server_tx, server_rx) =
         mpsc::channel::<ServerMessage>(crate::network::SERVER_CHANNEL_CAPACITY);
+    // One parse thread for the whole run; reconnects reuse `server_tx`.
+    let mut parsed_rx = app_core.start_parse_worker(server_rx)?;
 
     let is_direct = direct.is_some();
     let mut supervisor = Supervisor {
@@ -425,9 +470,43 @@ pub async fn async_run(
                     }
                 }
             }
-            maybe_msg = server_rx.recv() => {
+            // `vellum-fe send` and the command pipe: same path as a
+            // command from a web client
+            Some(text) = async {
+                match control_rx.as_mut() {
+                    Some(rx) => rx.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                if handle_remote_event(
+                    &mut app_core,
+                    supervisor.connection.as_ref(),
+                    crate::core::remote::RemoteEvent::Command(text),
+                    &mut session_requests,
+                ) {
+                    supervisor.saw_input_since_connect = true;
+                    supervisor.unattended_losses = 0;
+                }
+            }
+            Some(text) = async {
+                match pipe_rx.as_mut() {
+                    Some(rx) => rx.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                if handle_remote_event(
+                    &mut app_core,
+                    supervisor.connection.as_ref(),
+                    crate::core::remote::RemoteEvent::Command(text),
+                    &mut session_requests,
+                ) {
+                    supervisor.saw_input_since_connect = true;
+                    supervisor.unattended_losses = 0;
+                }
+            }
+            maybe_msg = parsed_rx.recv() => {
                 if let Some(msg) = maybe_msg {
-                    if matches!(msg, ServerMessage::Text(_)) {
+                    if matches!(msg, ParsedMessage::Text(_)) {
                         supervisor.first_text_seen = true;
                     }
                     let newly_connected = handle_server_message(&mut app_core, msg);
@@ -483,6 +562,13 @@ pub async fn async_run(
                             tracing::error!("Auth failure, not retrying: {e:#}");
                             error_text = Some(format!("{e:#}"));
                             true
+                        } else if e.chain().any(|c| c.is::<TlsFailed>()) {
+                            app_core.add_system_message(&format!(
+                                "TLS error (login was fine; check [connection] tls settings): {e:#}"
+                            ));
+                            tracing::error!("TLS failure, not retrying: {e:#}");
+                            error_text = Some(format!("{e:#}"));
+                            true
                         } else {
                             tracing::warn!("Connection error: {e:#}");
                             error_text = Some(format!("{e:#}"));
@@ -579,12 +665,14 @@ pub async fn async_run(
                 app_core.add_system_message("Logged out.");
                 app_core.set_remote_session_state(supervisor.status(SessionState::Idle));
             }
-            // Map/travel work in flight (mapdb download, walk executor RT
-            // waits): wake periodically so the post-select tick below runs
+            // Map/travel work in flight (mapdb download, walk executor, .go route or
+            // .repeat RT waits): wake periodically so the post-select tick below runs
             // even when the game is quiet. Guarded, so idle sessions stay
             // dormant (phone battery).
             _ = tokio::time::sleep(Duration::from_millis(250)),
                 if app_core.travel.is_traveling()
+                    || app_core.route.is_running()
+                    || app_core.repeat.is_running()
                     || app_core.map_updater.in_flight()
                     || app_core.map.has_pending() => {}
             // Reconnect timer fired: start a fresh attempt.
@@ -621,8 +709,11 @@ pub async fn async_run(
                 supervisor.unattended_losses = 0;
             }
         }
-        while let Ok(msg) = server_rx.try_recv() {
-            if matches!(msg, ServerMessage::Text(_)) {
+        app_core
+            .perf_stats
+            .record_parsed_queue_depth(parsed_rx.len() as u64);
+        while let Ok(msg) = parsed_rx.try_recv() {
+            if matches!(msg, ParsedMessage::Text(_)) {
                 supervisor.first_text_seen = true;
             }
             let newly_connected = handle_server_message(&mut app_core, msg);
@@ -643,15 +734,25 @@ pub async fn async_run(
         // Map worker, mapdb updater, and walk executor tick once per batch;
         // travel commands go out through the same path as typed ones.
         app_core.poll_map();
+        // Sounds queued off the clock (timer cues, reminders) play now
+        // rather than with the next server line
+        for sound in app_core.game_state.drain_sound_queue() {
+            app_core.push_remote_sound(&sound.file, sound.volume);
+            if let Some(ref player) = app_core.sound_player {
+                if let Err(e) = player.play_from_sounds_dir(&sound.file, sound.volume) {
+                    tracing::warn!("Failed to play sound '{}': {}", sound.file, e);
+                }
+            }
+        }
         for command in app_core.take_outbound() {
-            match app_core.send_command(command) {
+            match send_unconfirmed(&mut app_core, command) {
                 Ok(out) if !out.is_empty() && !out.starts_with("action:") => {
                     if let Some(conn) = supervisor.connection.as_ref() {
                         let _ = conn.command_tx.send(out);
                     }
                 }
                 Ok(_) => {}
-                Err(e) => tracing::warn!("travel command failed: {e}"),
+                Err(e) => tracing::warn!("queued command failed: {e}"),
             }
         }
 
@@ -690,7 +791,14 @@ pub async fn async_run(
                     match resolve_connect(&connect) {
                         Ok(resolved) => {
                             let state = match resolved {
-                                ResolvedConnect::Direct(cfg) => {
+                                ResolvedConnect::Direct(mut cfg) => {
+                                    cfg.compression = app_core.config.connection.compression;
+                                    cfg.proxy = app_core
+                                        .config
+                                        .proxy
+                                        .enabled()
+                                        .then(|| app_core.config.proxy.clone());
+                                    cfg.tls = GameTls::from_config(&app_core.config.connection);
                                     supervisor.character = Some(cfg.character.clone());
                                     supervisor.game = Some(cfg.game_code.clone());
                                     supervisor.direct = Some(cfg);
@@ -751,11 +859,15 @@ fn dispatch_command(
     if command.is_empty() {
         return false;
     }
-    match app_core.send_command(command) {
+    match send_unconfirmed(app_core, command) {
         Ok(outbound) => {
             if outbound.is_empty() || outbound.starts_with("__") {
                 return false;
             }
+            if let Some(topic) = outbound.strip_prefix("action:help:") {
+                app_core.print_help(topic);
+                return false;
+            }
             if outbound.starts_with("action:") || outbound.starts_with("menu:") {
                 app_core.add_system_message("That action needs the desktop client.");
                 return false;
@@ -782,6 +894,13 @@ fn dispatch_command(
     }
 }
 
+/// `send_command` past the `[lockout]` check: there's no local popup to
+/// answer it here, and a held command would never go out or be dropped
+fn send_unconfirmed(app_core: &mut AppCore, command: String) -> Result<String> {
+    app_core.command_lockout.bypass_next();
+    app_core.send_command(command)
+}
+
 /// Returns true when the event was direct user input (command, macro,
 /// link tap) — the supervisor uses this to tell attended sessions from
 /// abandoned ones.
@@ -794,7 +913,10 @@ fn handle_remote_event(
     use crate::core::remote::RemoteEvent;
     match event {
         RemoteEvent::Command(text) => {
-            tracing::debug!("remote command: '{}'", text);
+            if !app_core.expects_secret() {
+                tracing::debug!("remote command: '{}'", text);
+            }
+            app_core.note_user_input();
             if dispatch_command(app_core, connection, text) {
                 session_requests.push(SessionRequest::UserQuit);
             }
@@ -980,17 +1102,21 @@ fn handle_remote_event(
 }
 
 /// Returns true when this message flipped the session to connected.
-fn handle_server_message(app_core: &mut AppCore, msg: ServerMessage) -> bool {
+fn handle_server_message(app_core: &mut AppCore, msg: ParsedMessage) -> bool {
     match msg {
-        ServerMessage::Text(line) => {
+        ParsedMessage::Text(chunk) => {
             app_core
                 .perf_stats
-                .record_bytes_received((line.len() + 1) as u64);
-            let parse_start = Instant::now();
-            if let Err(e) = app_core.process_server_data(&line) {
+                .record_bytes_received((chunk.bytes + 1) as u64);
+            let apply_start = Instant::now();
+            if let Err(e) = app_core.process_parsed_chunk(&chunk) {
                 tracing::error!("Error processing server data: {}", e);
             }
-            app_core.perf_stats.record_parse(parse_start.elapsed());
+            app_core.perf_stats.record_parse(chunk.parse_time);
+            app_core.perf_stats.record_parse_stages(
+                apply_start.duration_since(chunk.parsed_at),
+                apply_start.elapsed(),
+            );
 
             // Content-driven sizing still runs: it feeds stream routing
             // decisions, not just TUI pane geometry.
@@ -1012,13 +1138,13 @@ fn handle_server_message(app_core: &mut AppCore, msg: ServerMessage) -> bool {
             app_core.process_pending_window_additions(NOMINAL_COLS, NOMINAL_ROWS);
             false
         }
-        ServerMessage::Connected => {
+        ParsedMessage::Connected => {
             tracing::info!("Connected to game server");
             let newly = !app_core.game_state.connected;
             app_core.game_state.connected = true;
             newly
         }
-        ServerMessage::Disconnected => {
+        ParsedMessage::Disconnected => {
             tracing::info!("Disconnected from game server");
             app_core.game_state.connected = false;
             false
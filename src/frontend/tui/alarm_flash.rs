@@ -0,0 +1,49 @@
+//! Screen border flash for the low-health alarm (core::alarm).
+//!
+//! Tints the background of the outermost ring of cells, so whatever is
+//! drawn there stays readable while the border blinks.
+
+use crate::core::alarm::AlarmLevel;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+pub(crate) fn render_alarm_flash(level: AlarmLevel, screen: Rect, buf: &mut Buffer) {
+    let color = match level {
+        AlarmLevel::Critical => Color::Red,
+        AlarmLevel::Warning => Color::Yellow,
+        AlarmLevel::Clear => return,
+    };
+    if screen.width == 0 || screen.height == 0 {
+        return;
+    }
+    let (left, right) = (screen.left(), screen.right() - 1);
+    let (top, bottom) = (screen.top(), screen.bottom() - 1);
+    for x in left..=right {
+        for y in [top, bottom] {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.set_bg(color);
+            }
+        }
+    }
+    for y in top..=bottom {
+        for x in [left, right] {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.set_bg(color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tints_only_the_outer_ring() {
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        render_alarm_flash(AlarmLevel::Critical, area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Red);
+        assert_eq!(buf[(3, 2)].bg, Color::Red);
+        assert_eq!(buf[(1, 1)].bg, Color::Reset);
+    }
+}
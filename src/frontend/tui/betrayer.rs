@@ -31,6 +31,7 @@ pub struct Betrayer {
     generation: u64,
     /// Border color
     border_color: Color,
+    border_style: Option<String>,
     /// Text color
     text_color: Color,
     /// Bar fill color (default: dark red #8b0000)
@@ -52,6 +53,7 @@ impl Betrayer {
             items: Vec::new(),
             generation: 0,
             border_color: Color::White,
+            border_style: None,
             text_color: Color::White,
             bar_color: Color::Rgb(139, 0, 0), // #8b0000 dark red
             background_color: None,
@@ -64,6 +66,11 @@ impl Betrayer {
         self.border_color = color;
     }
 
+    /// Set the border glyph style (see border_glyphs)
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
     /// Set the text color
     pub fn set_text_color(&mut self, color: Color) {
         self.text_color = color;
@@ -181,6 +188,9 @@ impl Betrayer {
             let block = Block::default()
                 .title(self.title.as_str())
                 .borders(Borders::ALL)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
                 .border_style(Style::default().fg(self.border_color));
             let inner = block.inner(area);
             block.render(area, buf);
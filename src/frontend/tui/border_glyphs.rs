@@ -0,0 +1,242 @@
+//! Border glyph sets shared by every bordered widget and popup.
+//!
+//! A window's `border_style` names a set: the built-in "double", "rounded",
+//! "thick", "quadrant_inside", "quadrant_outside" and "ascii", or a custom
+//! set from the theme's `[border_sets.<name>]` tables. "single" and unknown
+//! names use the theme's `border_set`, as do popups and forms, so a theme
+//! that sets `border_set = "ascii"` draws no box-drawing glyphs anywhere
+//! except windows that ask for another set.
+//!
+//! The active theme's sets are installed by `ThemeCache` whenever the theme
+//! changes; renderers only read them.
+
+use crate::theme::{AppTheme, BorderGlyphs};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    symbols::border::{self, Set},
+};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+/// `+-|` for fonts without box-drawing glyphs
+pub const ASCII: Set = Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+#[derive(Default)]
+struct ThemeSets {
+    /// Set for single-line borders and popups; None is `border::PLAIN`
+    default: Option<Set>,
+    /// Custom sets by name
+    custom: HashMap<String, Set>,
+}
+
+// Thread-local like the color caches: set on theme change, read per frame
+thread_local! {
+    static THEME_SETS: RefCell<ThemeSets> = RefCell::new(ThemeSets::default());
+    // Custom glyphs are leaked once so sets can be `Copy` like ratatui's own;
+    // reused across theme switches so re-applying a theme leaks nothing
+    static GLYPHS: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+fn intern(glyph: &str) -> &'static str {
+    GLYPHS.with(|glyphs| {
+        let mut glyphs = glyphs.borrow_mut();
+        if let Some(existing) = glyphs.get(glyph) {
+            return *existing;
+        }
+        let leaked: &'static str = Box::leak(glyph.to_string().into_boxed_str());
+        glyphs.insert(leaked);
+        leaked
+    })
+}
+
+fn builtin(name: &str) -> Option<Set> {
+    match name {
+        "single" | "plain" => Some(border::PLAIN),
+        "double" => Some(border::DOUBLE),
+        "rounded" => Some(border::ROUNDED),
+        "thick" => Some(border::THICK),
+        "quadrant_inside" => Some(border::QUADRANT_INSIDE),
+        "quadrant_outside" => Some(border::QUADRANT_OUTSIDE),
+        "ascii" => Some(ASCII),
+        _ => None,
+    }
+}
+
+/// Convert a theme glyph set; None if any glyph isn't a single character
+fn custom_set(glyphs: &BorderGlyphs) -> Option<Set> {
+    let parts = [
+        &glyphs.top_left,
+        &glyphs.top_right,
+        &glyphs.bottom_left,
+        &glyphs.bottom_right,
+        &glyphs.horizontal,
+        &glyphs.vertical,
+    ];
+    if parts
+        .iter()
+        .any(|glyph| glyph.chars().count() != 1 || glyph.chars().any(char::is_control))
+    {
+        return None;
+    }
+    Some(Set {
+        top_left: intern(&glyphs.top_left),
+        top_right: intern(&glyphs.top_right),
+        bottom_left: intern(&glyphs.bottom_left),
+        bottom_right: intern(&glyphs.bottom_right),
+        vertical_left: intern(&glyphs.vertical),
+        vertical_right: intern(&glyphs.vertical),
+        horizontal_top: intern(&glyphs.horizontal),
+        horizontal_bottom: intern(&glyphs.horizontal),
+    })
+}
+
+/// Install the theme's glyph sets. Invalid custom sets are skipped with a
+/// warning and an unknown `border_set` falls back to single lines.
+pub fn apply_theme(theme: &AppTheme) {
+    let mut custom = HashMap::new();
+    for (name, glyphs) in &theme.border_sets {
+        match custom_set(glyphs) {
+            Some(set) => {
+                custom.insert(name.clone(), set);
+            }
+            None => tracing::warn!(
+                "Theme '{}': border set '{}' needs exactly one character per glyph; ignoring it",
+                theme.name,
+                name
+            ),
+        }
+    }
+    let default = theme.border_set.as_deref().and_then(|name| {
+        let set = custom.get(name).copied().or_else(|| builtin(name));
+        if set.is_none() {
+            tracing::warn!(
+                "Theme '{}': unknown border_set '{}'; using single lines",
+                theme.name,
+                name
+            );
+        }
+        set
+    });
+    THEME_SETS.with(|sets| *sets.borrow_mut() = ThemeSets { default, custom });
+}
+
+/// Glyphs for a window's `border_style`
+pub fn border_set(style: Option<&str>) -> Set {
+    THEME_SETS.with(|sets| {
+        let sets = sets.borrow();
+        let named = style
+            .filter(|name| !matches!(*name, "single" | "plain"))
+            .and_then(|name| sets.custom.get(name).copied().or_else(|| builtin(name)));
+        named.or(sets.default).unwrap_or(border::PLAIN)
+    })
+}
+
+/// Glyphs for popups, forms and browsers: the theme's set
+pub fn popup_set() -> Set {
+    border_set(None)
+}
+
+/// The theme's set if it defines one, else `fallback`; for popups drawn
+/// in their own style (rounded, double) when the theme doesn't say
+pub fn themed(fallback: Set) -> Set {
+    THEME_SETS
+        .with(|sets| sets.borrow().default)
+        .unwrap_or(fallback)
+}
+
+/// Draw a box outline around `area` in the popup set, for popups that
+/// draw their frame cell by cell rather than with a `Block`
+pub fn draw_box(buf: &mut Buffer, area: Rect, style: Style) {
+    if area.width < 2 || area.height < 2 {
+        return;
+    }
+    let set = popup_set();
+    let right = area.x + area.width - 1;
+    let bottom = area.y + area.height - 1;
+    let mut put = |x: u16, y: u16, glyph: &str| {
+        if let Some(cell) = buf.cell_mut((x, y)) {
+            cell.set_symbol(glyph).set_style(style);
+        }
+    };
+    for x in area.x + 1..right {
+        put(x, area.y, set.horizontal_top);
+        put(x, bottom, set.horizontal_bottom);
+    }
+    for y in area.y + 1..bottom {
+        put(area.x, y, set.vertical_left);
+        put(right, y, set.vertical_right);
+    }
+    put(area.x, area.y, set.top_left);
+    put(right, area.y, set.top_right);
+    put(area.x, bottom, set.bottom_left);
+    put(right, bottom, set.bottom_right);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyphs(corner: &str, horizontal: &str, vertical: &str) -> BorderGlyphs {
+        BorderGlyphs {
+            top_left: corner.to_string(),
+            top_right: corner.to_string(),
+            bottom_left: corner.to_string(),
+            bottom_right: corner.to_string(),
+            horizontal: horizontal.to_string(),
+            vertical: vertical.to_string(),
+        }
+    }
+
+    #[test]
+    fn theme_set_replaces_single_lines_and_popups() {
+        let mut theme = crate::theme::ThemePresets::dark();
+        theme.border_set = Some("ascii".to_string());
+        theme
+            .border_sets
+            .insert("dots".to_string(), glyphs("*", ".", ":"));
+        theme
+            .border_sets
+            .insert("broken".to_string(), glyphs("**", ".", ":"));
+        apply_theme(&theme);
+
+        assert_eq!(border_set(Some("single")), ASCII);
+        assert_eq!(popup_set(), ASCII);
+        assert_eq!(border_set(Some("double")), border::DOUBLE);
+        assert_eq!(border_set(Some("dots")).top_left, "*");
+        assert_eq!(border_set(Some("dots")).vertical_right, ":");
+        // Invalid sets are dropped, so the window falls back to the theme set
+        assert_eq!(border_set(Some("broken")), ASCII);
+
+        apply_theme(&crate::theme::ThemePresets::dark());
+        assert_eq!(border_set(Some("single")), border::PLAIN);
+        assert_eq!(border_set(Some("dots")), border::PLAIN);
+    }
+
+    #[test]
+    fn draws_a_box_in_the_popup_set() {
+        let mut theme = crate::theme::ThemePresets::dark();
+        theme.border_set = Some("ascii".to_string());
+        apply_theme(&theme);
+
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        draw_box(&mut buf, area, Style::default());
+        let row = |y: u16| (0..4).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert_eq!(row(0), "+--+");
+        assert_eq!(row(1), "|  |");
+        assert_eq!(row(2), "+--+");
+
+        apply_theme(&crate::theme::ThemePresets::dark());
+    }
+}
@@ -0,0 +1,205 @@
+//! Calendar widget.
+//!
+//! Shows the Elanthian date and elven time, the phase of each configured
+//! moon, and optionally the real-world local time.
+//!
+//! Reads data from GameState.calendar (see data::game_time).
+
+use crate::config::BorderSides;
+use crate::data::game_time::{ElanthianTime, GameCalendar, MoonDef, MoonPhase};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Calendar widget - game date/time, moons and local time
+pub struct Calendar {
+    title: String,
+    show_weekday: bool,
+    show_local_time: bool,
+    moons: Vec<MoonDef>,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached values for rendering
+    now: Option<ElanthianTime>,
+    local_time: String,
+    phases: Vec<(String, MoonPhase)>,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Calendar {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_weekday: true,
+            show_local_time: false,
+            moons: Vec::new(),
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            now: None,
+            local_time: String::new(),
+            phases: Vec::new(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_weekday(&mut self, show: bool) {
+        self.show_weekday = show;
+    }
+
+    pub fn set_show_local_time(&mut self, show: bool) {
+        self.show_local_time = show;
+    }
+
+    pub fn set_moons(&mut self, moons: &[MoonDef]) {
+        if self.moons != moons {
+            self.moons = moons.to_vec();
+        }
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Recompute for `server_time`. Returns true if the display changed.
+    pub fn update_from_state(&mut self, calendar: &GameCalendar, server_time: i64) -> bool {
+        let now = Some(calendar.at(server_time));
+        let local_time = if self.show_local_time {
+            crate::core::locale::format_clock_compact(&chrono::Local::now())
+        } else {
+            String::new()
+        };
+        let phases: Vec<(String, MoonPhase)> = self
+            .moons
+            .iter()
+            .map(|moon| (moon.name.clone(), moon.phase(server_time)))
+            .collect();
+        if now == self.now && local_time == self.local_time && phases == self.phases {
+            return false;
+        }
+        self.now = now;
+        self.local_time = local_time;
+        self.phases = phases;
+        true
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+        let Some(now) = self.now else {
+            return;
+        };
+
+        let text = Style::default().fg(self.text_color);
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines = vec![Line::from(Span::styled(now.date_string(), text))];
+        let mut clock = vec![Span::styled(now.clock_string(), text)];
+        if self.show_weekday {
+            clock.push(Span::styled(format!(", {}", now.weekday_name()), text));
+        }
+        lines.push(Line::from(clock));
+        if self.show_local_time {
+            lines.push(Line::from(Span::styled(
+                format!("Local {}", self.local_time),
+                dim,
+            )));
+        }
+        for (name, phase) in &self.phases {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", name), dim),
+                Span::styled(phase.label(), text),
+            ]));
+        }
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_date_clock_and_moons() {
+        let liabo = MoonDef::liabo();
+        let mut widget = Calendar::new("Calendar");
+        widget.set_show_border(false);
+        widget.set_moons(std::slice::from_ref(&liabo));
+        let calendar = GameCalendar::default();
+        // Liabo's reference new moon: 2000-01-06 1:14pm EST, a Thursday
+        assert!(widget.update_from_state(&calendar, liabo.new_moon));
+        assert!(!widget.update_from_state(&calendar, liabo.new_moon + 10));
+
+        let area = Rect::new(0, 0, 24, 3);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..24).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).starts_with("6 Lormesta 5100"));
+        assert!(row(1).starts_with("1:14pm, Niiman"));
+        assert!(row(2).starts_with("Liabo: new"));
+    }
+}
@@ -3,6 +3,7 @@
 //! Handles text validation, dragable chrome, and `Saveable` trait integration so
 //! color editing feels consistent with other configuration dialogs.
 
+use super::textarea_bridge::GraphemeInput;
 use crate::config::PaletteColor;
 use crate::frontend::tui::crossterm_bridge;
 use crossterm::event::KeyEvent;
@@ -153,13 +154,13 @@ impl ColorForm {
                 let rt_key = crate::frontend::tui::textarea_bridge::to_textarea_event(key_event);
                 match self.focused_field {
                     0 => {
-                        self.name.input(rt_key);
+                        self.name.input_graphemes(rt_key);
                     }
                     1 => {
-                        self.category.input(rt_key);
+                        self.category.input_graphemes(rt_key);
                     }
                     2 => {
-                        self.color.input(rt_key);
+                        self.color.input_graphemes(rt_key);
                     }
                     _ => {}
                 }
@@ -456,9 +457,11 @@ impl ColorForm {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.form_label));
 
-        // Top border
-        let top = format!("┌{}┐", "─".repeat(popup_width as usize - 2));
-        buf.set_string(self.popup_x, self.popup_y, &top, border_style);
+        super::border_glyphs::draw_box(
+            buf,
+            Rect::new(self.popup_x, self.popup_y, popup_width, popup_height),
+            border_style,
+        );
 
         // Title
         let title = match self.mode {
@@ -472,26 +475,6 @@ impl ColorForm {
             border_style.add_modifier(Modifier::BOLD),
         );
 
-        // Side borders
-        for i in 1..popup_height - 1 {
-            buf.set_string(self.popup_x, self.popup_y + i, "│", border_style);
-            buf.set_string(
-                self.popup_x + popup_width - 1,
-                self.popup_y + i,
-                "│",
-                border_style,
-            );
-        }
-
-        // Bottom border
-        let bottom = format!("└{}┘", "─".repeat(popup_width as usize - 2));
-        buf.set_string(
-            self.popup_x,
-            self.popup_y + popup_height - 1,
-            &bottom,
-            border_style,
-        );
-
         // Render fields (single-line rows)
         let mut y = self.popup_y + 2;
         let focused = self.focused_field;
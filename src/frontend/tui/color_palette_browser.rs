@@ -4,6 +4,7 @@
 //! other management popups.
 
 use crate::config::PaletteColor;
+use crate::core::undo::{BrowserEdit, UndoStack};
 use crate::frontend::tui::colors::parse_color_to_ratatui;
 use crate::frontend::tui::crossterm_bridge;
 use ratatui::{
@@ -50,6 +51,11 @@ pub struct ColorPaletteBrowser {
     pub is_dragging: bool,
     pub drag_offset_x: u16,
     pub drag_offset_y: u16,
+
+    /// Deletes made in this browser, newest last (Ctrl+Z restores)
+    pub undo: UndoStack<BrowserEdit>,
+    /// One-line notice shown in place of the footer until the next key
+    pub toast: Option<String>,
 }
 
 impl ColorPaletteBrowser {
@@ -120,6 +126,8 @@ impl ColorPaletteBrowser {
             is_dragging: false,
             drag_offset_x: 0,
             drag_offset_y: 0,
+            undo: UndoStack::new(),
+            toast: None,
         }
     }
 
@@ -479,10 +487,13 @@ impl ColorPaletteBrowser {
         } else {
             (self.selected_index + 1).min(total)
         };
-        let help = format!(
-            " ↑/↓:Nav  Enter:Edit  Del:Del  F:Fav  /:Filter  Esc:Close  ({}/{}) ",
-            current, total
-        );
+        let help = match &self.toast {
+            Some(toast) => format!(" {} ", toast),
+            None => format!(
+                " ↑/↓:Nav  Enter:Edit  Del:Del  F:Fav  /:Filter  Esc:Close  ({}/{}) ",
+                current, total
+            ),
+        };
         let help_x = popup_area.x + popup_area.width.saturating_sub(help.len() as u16 + 1);
         let start_x = if help_x > popup_area.x + 1 {
             help_x
@@ -673,49 +684,7 @@ impl ColorPaletteBrowser {
     }
 
     fn draw_border(&self, area: Rect, buf: &mut Buffer, style: Style) {
-        // Top border
-        for x in area.x..area.x + area.width {
-            if let Some(cell) = buf.cell_mut((x, area.y)) {
-                if x == area.x {
-                    cell.set_char('┌');
-                } else if x == area.x + area.width - 1 {
-                    cell.set_char('┐');
-                } else {
-                    cell.set_char('─');
-                }
-                cell.set_style(style);
-            }
-        }
-
-        // Bottom border
-        for x in area.x..area.x + area.width {
-            if let Some(cell) = buf.cell_mut((x, area.y + area.height - 1)) {
-                if x == area.x {
-                    cell.set_char('└');
-                } else if x == area.x + area.width - 1 {
-                    cell.set_char('┘');
-                } else {
-                    cell.set_char('─');
-                }
-                cell.set_style(style);
-            }
-        }
-
-        // Left border
-        for y in area.y + 1..area.y + area.height - 1 {
-            if let Some(cell) = buf.cell_mut((area.x, y)) {
-                cell.set_char('│');
-                cell.set_style(style);
-            }
-        }
-
-        // Right border
-        for y in area.y + 1..area.y + area.height - 1 {
-            if let Some(cell) = buf.cell_mut((area.x + area.width - 1, y)) {
-                cell.set_char('│');
-                cell.set_style(style);
-            }
-        }
+        super::border_glyphs::draw_box(buf, area, style);
     }
 
     /// Parse a color string to ratatui Color (supports hex and color names)
@@ -296,6 +296,54 @@ pub fn resolve_window_colors(
     }
 }
 
+/// ANSI SGR parameters for a style's foreground, background and bold
+/// (e.g. "1;38;2;255;0;0"), or None when the style sets none of them.
+/// Used to copy selections with their colors.
+pub fn style_to_sgr(style: ratatui::style::Style) -> Option<String> {
+    use ratatui::style::{Color, Modifier};
+
+    fn color_sgr(color: Color, base: u8) -> Option<String> {
+        let named = |slot: u8| {
+            // Slots 0-7 are 30-37 (40-47), bright 8-15 are 90-97 (100-107)
+            let code = if slot < 8 {
+                base + slot
+            } else {
+                base + 60 + slot - 8
+            };
+            Some(code.to_string())
+        };
+        match color {
+            Color::Reset => None,
+            Color::Black => named(0),
+            Color::Red => named(1),
+            Color::Green => named(2),
+            Color::Yellow => named(3),
+            Color::Blue => named(4),
+            Color::Magenta => named(5),
+            Color::Cyan => named(6),
+            Color::Gray => named(7),
+            Color::DarkGray => named(8),
+            Color::LightRed => named(9),
+            Color::LightGreen => named(10),
+            Color::LightYellow => named(11),
+            Color::LightBlue => named(12),
+            Color::LightMagenta => named(13),
+            Color::LightCyan => named(14),
+            Color::White => named(15),
+            Color::Indexed(slot) => Some(format!("{};5;{}", base + 8, slot)),
+            Color::Rgb(r, g, b) => Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+        }
+    }
+
+    let mut codes = Vec::new();
+    if style.add_modifier.contains(Modifier::BOLD) {
+        codes.push("1".to_string());
+    }
+    codes.extend(style.fg.and_then(|fg| color_sgr(fg, 30)));
+    codes.extend(style.bg.and_then(|bg| color_sgr(bg, 40)));
+    (!codes.is_empty()).then(|| codes.join(";"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,4 +441,22 @@ mod tests {
         // Restore for other tests on this thread
         set_global_color_mode(ColorMode::Direct);
     }
+
+    #[test]
+    fn style_to_sgr_covers_rgb_named_and_bold() {
+        use ratatui::style::{Color, Modifier, Style};
+        let style = Style::default()
+            .fg(Color::Rgb(255, 0, 0))
+            .bg(Color::Indexed(17))
+            .add_modifier(Modifier::BOLD);
+        assert_eq!(
+            style_to_sgr(style).as_deref(),
+            Some("1;38;2;255;0;0;48;5;17")
+        );
+        assert_eq!(
+            style_to_sgr(Style::default().fg(Color::DarkGray)).as_deref(),
+            Some("90")
+        );
+        assert_eq!(style_to_sgr(Style::default()), None);
+    }
 }
@@ -0,0 +1,177 @@
+//! Combat stats widget.
+//!
+//! Shows session hit rate, damage and damage rate, the best single hit, and
+//! the creatures you've done the most damage to.
+//!
+//! Reads data from GameState.combat (see core::combat).
+
+use crate::config::BorderSides;
+use crate::core::combat::CombatStats;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Combat stats widget - session totals plus a per-creature breakdown
+pub struct CombatStatsWidget {
+    title: String,
+    /// How many creature rows to show under the totals
+    creatures: usize,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached state for rendering
+    stats: CombatStats,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl CombatStatsWidget {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            creatures: 5,
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            stats: CombatStats::default(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_creatures(&mut self, creatures: usize) {
+        self.creatures = creatures;
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Update from game state. Returns true if the display changed.
+    pub fn update_from_state(&mut self, stats: &CombatStats) -> bool {
+        if self.stats.generation == stats.generation {
+            return false;
+        }
+        self.stats = stats.clone();
+        true
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let text_style = Style::default().fg(self.text_color);
+        let mut lines: Vec<Line> = self
+            .stats
+            .summary_lines()
+            .into_iter()
+            .map(|line| Line::from(Span::styled(line, text_style)))
+            .collect();
+        let creature_style = Style::default().fg(Color::Gray);
+        for creature in self
+            .stats
+            .creatures_by_damage()
+            .into_iter()
+            .take(self.creatures)
+        {
+            lines.push(Line::from(Span::styled(
+                CombatStats::creature_line(creature),
+                creature_style,
+            )));
+        }
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::combat::CombatEvent;
+
+    #[test]
+    fn renders_totals_and_top_creatures() {
+        let mut stats = CombatStats::default();
+        for (target, points) in [("kobold", 20), ("rat", 45)] {
+            stats.apply(CombatEvent::Attack(target.to_string()), 100);
+            stats.apply(CombatEvent::Roll { hit: true }, 100);
+            stats.apply(CombatEvent::Damage(points), 100);
+        }
+        let mut widget = CombatStatsWidget::new("Combat");
+        widget.set_show_border(false);
+        widget.set_creatures(1);
+        assert!(widget.update_from_state(&stats));
+        assert!(!widget.update_from_state(&stats));
+
+        let area = Rect::new(0, 0, 30, 5);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..30).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).starts_with("Hits: 2/2 (100%)"));
+        assert!(row(1).starts_with("Damage: 65"));
+        assert!(row(2).starts_with("Best hit: 45 on rat"));
+        assert!(row(3).starts_with("rat: 1/1, 45 dmg"));
+        assert!(row(4).trim().is_empty());
+    }
+}
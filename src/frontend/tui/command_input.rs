@@ -1,10 +1,11 @@
 //! Stateful command line widget that mimics Profanity's behavior.
 //!
 //! Handles multi-byte cursoring, cut/copy selection, history persistence, and
-//! autocomplete for both dot-commands and window names.
+//! autocomplete for dot-commands, window names, and game words (with a small
+//! candidate popup).
 
 use crate::config::BorderSides;
-use crate::frontend::common::CommandInputModel;
+use crate::frontend::common::{text_width, CommandInputModel};
 use crate::frontend::tui::{
     crossterm_bridge,
     title_position::{self, TitlePosition},
@@ -12,9 +13,9 @@ use crate::frontend::tui::{
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{BorderType, Paragraph, Widget},
+    widgets::{Paragraph, Widget},
 };
 use std::fs;
 use std::io::{BufRead, BufReader, Write as _};
@@ -35,6 +36,8 @@ pub struct CommandInput {
     cursor_bg_color: Option<String>,   // Cursor background color
     prompt_icon: Option<String>,       // Optional prompt icon shown before input
     prompt_icon_color: Option<String>, // Color for prompt icon
+    prompt_text: Option<String>,       // Rendered prompt_template; shown instead of the icon
+    misspelled: Vec<(usize, usize)>,   // Char ranges underlined by spellcheck
 }
 
 impl CommandInput {
@@ -54,6 +57,8 @@ impl CommandInput {
             cursor_bg_color: None, // Default: white
             prompt_icon: None,
             prompt_icon_color: None,
+            prompt_text: None,
+            misspelled: Vec::new(),
         }
     }
 
@@ -105,6 +110,25 @@ impl CommandInput {
         self.prompt_icon_color = color;
     }
 
+    /// Rendered prompt template (None = show the icon, if any)
+    pub fn set_prompt_text(&mut self, text: Option<String>) {
+        self.prompt_text = text;
+    }
+
+    /// Char ranges to underline as misspelled (refreshed every frame)
+    pub fn set_misspelled(&mut self, ranges: Vec<(usize, usize)>) {
+        self.misspelled = ranges;
+    }
+
+    pub fn cursor_pos(&self) -> usize {
+        self.model.cursor_pos()
+    }
+
+    /// Replace the chars in `start..end` (spellcheck corrections)
+    pub fn replace_range(&mut self, start: usize, end: usize, text: &str) {
+        self.model.replace_range(start, end, text);
+    }
+
     pub fn insert_char(&mut self, c: char) {
         self.model.insert_char(c);
         tracing::debug!(
@@ -176,6 +200,10 @@ impl CommandInput {
         self.model.record_external_command(command);
     }
 
+    pub fn forget_latest(&mut self, command: &str) {
+        self.model.forget_latest(command);
+    }
+
     pub fn history_previous(&mut self) {
         self.model.history_previous();
     }
@@ -184,6 +212,18 @@ impl CommandInput {
         self.model.history_next();
     }
 
+    pub fn history_search_previous(&mut self) {
+        self.model.history_search_previous();
+    }
+
+    pub fn history_search_next(&mut self) {
+        self.model.history_search_next();
+    }
+
+    pub fn set_history_options(&mut self, expansion: bool, prefix_search: bool) {
+        self.model.set_history_options(expansion, prefix_search);
+    }
+
     pub fn render(&self, area: Rect, buf: &mut Buffer) {
         self.render_with_status(area, buf, None);
     }
@@ -205,14 +245,7 @@ impl CommandInput {
         };
 
         let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
-        let border_type = match self.border_style.as_deref() {
-            Some("double") => BorderType::Double,
-            Some("rounded") => BorderType::Rounded,
-            Some("thick") => BorderType::Thick,
-            Some("quadrant_inside") => BorderType::QuadrantInside,
-            Some("quadrant_outside") => BorderType::QuadrantOutside,
-            _ => BorderType::Plain,
-        };
+        let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
 
         let mut border_style = Style::default();
         if let Some(color_str) = &self.border_color {
@@ -258,7 +291,7 @@ impl CommandInput {
             show_border,
             borders,
             &self.border_sides,
-            border_type,
+            border_set,
             border_style,
             &title_text,
             self.title_position,
@@ -271,7 +304,7 @@ impl CommandInput {
             .as_ref()
             .and_then(|c| self.parse_color(c))
             .unwrap_or(Color::White);
-        let icon_text = self.prompt_icon.as_ref().and_then(|s| {
+        let icon_text = self.prompt_text.as_ref().or(self.prompt_icon.as_ref()).and_then(|s| {
             let t = s.trim();
             if t.is_empty() {
                 None
@@ -282,8 +315,8 @@ impl CommandInput {
         if let Some(icon) = icon_text {
             let max_icon_width = inner.width as usize;
             if max_icon_width > 0 {
-                let icon_render: String = icon.chars().take(max_icon_width).collect();
-                let icon_render_width = icon_render.chars().count();
+                let icon_render = text_width::truncate(icon, max_icon_width);
+                let icon_render_width = text_width::width(icon_render);
                 let icon_color = self
                     .prompt_icon_color
                     .as_ref()
@@ -292,7 +325,7 @@ impl CommandInput {
                 buf.set_string(
                     inner.x,
                     inner.y,
-                    &icon_render,
+                    icon_render,
                     Style::default().fg(icon_color),
                 );
                 let mut consumed = icon_render_width;
@@ -312,17 +345,35 @@ impl CommandInput {
         }
 
         let available_width = text_area.width as usize;
-        let chars: Vec<char> = self.model.text().chars().collect();
-        let total_chars = chars.len();
+        let text = self.model.text();
+        let cursor_pos = self.model.cursor_pos();
         let selection = self.model.selection_range();
 
+        // Lay the text out by grapheme, in columns: (char index, grapheme,
+        // start column). The cursor sits on a grapheme, never inside one.
+        let mut column = 0;
+        let mut cursor_col = None;
+        let graphemes: Vec<(usize, &str, usize)> = text_width::graphemes(text)
+            .map(|(start, grapheme)| {
+                if start >= cursor_pos && cursor_col.is_none() {
+                    cursor_col = Some(column);
+                }
+                let col = column;
+                column += text_width::width(grapheme);
+                (start, grapheme, col)
+            })
+            .collect();
+        let total_cols = column;
+        // At the end, the cursor block takes one more column
+        let cursor_col = cursor_col.unwrap_or(total_cols);
+
         // We need space for: text before cursor + cursor block + text after cursor
         // The cursor block takes 1 position, so max visible cursor position is (available_width - 1)
         let max_visible_cursor_pos = available_width.saturating_sub(1);
 
         let scroll_offset = if available_width == 0 {
             0
-        } else if total_chars < available_width {
+        } else if total_cols < available_width {
             // Everything fits - no scroll needed
             0
         } else {
@@ -331,35 +382,18 @@ impl CommandInput {
             let target_cursor_pos = (available_width * 3 / 10).min(max_visible_cursor_pos);
 
             // Calculate scroll to position cursor at target_cursor_pos from left
-            if self.model.cursor_pos() < target_cursor_pos {
+            if cursor_col < target_cursor_pos {
                 // Near start - show from beginning
                 0
-            } else if self.model.cursor_pos()
-                >= total_chars.saturating_sub(available_width - target_cursor_pos)
-            {
+            } else if cursor_col >= total_cols.saturating_sub(available_width - target_cursor_pos) {
                 // Near end - anchor to end, ensuring cursor stays within bounds
-                total_chars.saturating_sub(available_width)
+                (total_cols + 1).saturating_sub(available_width)
             } else {
                 // Middle - keep cursor at target position from left
-                self.model.cursor_pos().saturating_sub(target_cursor_pos)
+                cursor_col.saturating_sub(target_cursor_pos)
             }
         };
 
-        // Extract visible portion of text with scroll applied
-        // Take up to available_width chars, which includes the cursor position
-        let visible_chars: Vec<char> = chars
-            .iter()
-            .skip(scroll_offset)
-            .take(available_width)
-            .copied()
-            .collect();
-
-        // Adjust cursor position relative to visible window
-        let visible_cursor_pos = self.model.cursor_pos().saturating_sub(scroll_offset);
-
-        // Ensure cursor position doesn't exceed available space
-        let visible_cursor_pos = visible_cursor_pos.min(available_width.saturating_sub(1));
-
         // Get cursor colors
         let cursor_fg = self
             .cursor_fg_color
@@ -377,27 +411,40 @@ impl CommandInput {
             .as_ref()
             .and_then(|c| self.parse_color(c))
             .unwrap_or(Color::DarkGray);
+        let cursor_style = Style::default().bg(cursor_bg).fg(cursor_fg);
 
+        // Graphemes wholly inside the visible columns, each its own span so
+        // combining marks stay with their base character
         let mut spans = Vec::new();
-        if visible_chars.is_empty() {
-            spans.push(Span::styled(
-                " ",
-                Style::default().bg(cursor_bg).fg(cursor_fg),
-            ));
-        } else {
-            for (i, ch) in visible_chars.iter().enumerate() {
-                let mut style = Style::default().fg(text_color);
-                if let Some((start, end)) = selection {
-                    let global_idx = scroll_offset + i;
-                    if global_idx >= start && global_idx < end {
-                        style = style.bg(selection_bg);
-                    }
-                }
-                if i == visible_cursor_pos {
-                    style = Style::default().bg(cursor_bg).fg(cursor_fg);
+        for &(start, grapheme, col) in &graphemes {
+            if col < scroll_offset {
+                continue;
+            }
+            if col + text_width::width(grapheme) > scroll_offset + available_width {
+                break;
+            }
+            let mut style = Style::default().fg(text_color);
+            if let Some((sel_start, sel_end)) = selection {
+                if start >= sel_start && start < sel_end {
+                    style = style.bg(selection_bg);
                 }
-                spans.push(Span::styled(ch.to_string(), style));
             }
+            if self
+                .misspelled
+                .iter()
+                .any(|&(m_start, m_end)| start >= m_start && start < m_end)
+            {
+                style = style
+                    .add_modifier(Modifier::UNDERLINED)
+                    .underline_color(Color::Red);
+            }
+            if start == cursor_pos {
+                style = cursor_style;
+            }
+            spans.push(Span::styled(grapheme, style));
+        }
+        if cursor_col == total_cols && total_cols < scroll_offset + available_width {
+            spans.push(Span::styled(" ", cursor_style));
         }
 
         let line = Line::from(spans);
@@ -421,14 +468,7 @@ impl CommandInput {
         let show_border = self.show_border && !border_is_none;
 
         let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
-        let border_type = match self.border_style.as_deref() {
-            Some("double") => BorderType::Double,
-            Some("rounded") => BorderType::Rounded,
-            Some("thick") => BorderType::Thick,
-            Some("quadrant_inside") => BorderType::QuadrantInside,
-            Some("quadrant_outside") => BorderType::QuadrantOutside,
-            _ => BorderType::Plain,
-        };
+        let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
 
         let mut border_style = Style::default();
         if let Some(color_str) = &self.border_color {
@@ -472,7 +512,7 @@ impl CommandInput {
             show_border,
             borders,
             &self.border_sides,
-            border_type,
+            border_set,
             border_style,
             "", // No title in search mode
             self.title_position,
@@ -517,15 +557,76 @@ impl CommandInput {
     }
 
     /// Try to complete the current input.
-    /// Returns true if a completion was performed.
+    /// Returns true if there was anything to complete.
     pub fn try_complete(
         &mut self,
         available_commands: &[String],
         available_names: &[String],
     ) -> bool {
-        let before = self.model.text().to_string();
-        self.model.try_complete(available_commands, available_names);
-        before != self.model.text()
+        self.model.try_complete(available_commands, available_names)
+    }
+
+    /// List the completion candidates just above (or, without room, below)
+    /// `input_area`, highlighting the one currently in the input. Nothing is
+    /// drawn for a unique match.
+    pub fn render_completion_popup(
+        &self,
+        input_area: Rect,
+        screen: Rect,
+        buf: &mut Buffer,
+        theme: &crate::theme::AppTheme,
+    ) {
+        const MAX_ROWS: usize = 8;
+        let candidates = self.model.completion_candidates();
+        if candidates.len() < 2 {
+            return;
+        }
+        let selected = self.model.completion_index().unwrap_or(0);
+        let rows = candidates.len().min(MAX_ROWS);
+        // Scroll the visible slice so the selected candidate stays in view
+        let first = selected
+            .saturating_sub(rows - 1)
+            .min(candidates.len() - rows);
+        let longest = candidates
+            .iter()
+            .map(|c| text_width::width(c))
+            .max()
+            .unwrap_or(0);
+
+        let width = ((longest + 4) as u16).max(12).min(screen.width);
+        let height = (rows as u16 + 2).min(screen.height);
+        let y = if input_area.y >= screen.y + height {
+            input_area.y - height
+        } else {
+            (input_area.y + input_area.height).min(screen.bottom().saturating_sub(height))
+        };
+        let x = input_area.x.min(screen.right().saturating_sub(width));
+        let area = Rect::new(x, y, width, height);
+
+        let color = crossterm_bridge::to_ratatui_color;
+        ratatui::widgets::Clear.render(area, buf);
+        let block = ratatui::widgets::Block::default()
+            .borders(ratatui::widgets::Borders::ALL)
+            .title(format!("{}/{}", selected + 1, candidates.len()))
+            .border_style(Style::default().fg(color(theme.window_border)))
+            .style(Style::default().bg(color(theme.background_secondary)));
+        let lines: Vec<Line> = candidates
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .map(|(idx, candidate)| {
+                let style = if idx == selected {
+                    Style::default()
+                        .fg(color(theme.text_selected))
+                        .bg(color(theme.background_selected))
+                } else {
+                    Style::default().fg(color(theme.text_primary))
+                };
+                Line::from(Span::styled(format!(" {} ", candidate), style))
+            })
+            .collect();
+        Paragraph::new(lines).block(block).render(area, buf);
     }
 
     /// Get the history file path (~/.vellum-fe/history/<character>.txt or default.txt)
@@ -66,6 +66,8 @@ impl TuiFrontend {
                                 }
                             }
                             'e' => cmd_input.move_cursor_end(false),
+                            'p' => cmd_input.history_search_previous(),
+                            'n' => cmd_input.history_search_next(),
                             'u' => cmd_input.clear(),
                             'w' => cmd_input.delete_word_backward(),
                             _ => {}
@@ -0,0 +1,237 @@
+//! Command log palette: fuzzy-search sent commands and resend one.
+//!
+//! Opened with `.cmdlog [search]` or the `command_log` keybind. Typing
+//! filters the session's sent commands (`core::command_log`), Enter sends
+//! the highlighted one again and Tab copies it into the command input for
+//! editing instead.
+
+use super::crossterm_bridge;
+use crate::core::command_log::CommandMatch;
+use crate::core::AppCore;
+use crate::data::input::{KeyCode, KeyModifiers};
+use crate::data::InputMode;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+pub struct CommandLogPalette {
+    query: String,
+    matches: Vec<CommandMatch>,
+    selected: usize,
+    /// First visible row
+    scroll: usize,
+    /// Visible row count from the last render (for paging)
+    page_height: usize,
+}
+
+impl CommandLogPalette {
+    fn new(query: &str, app_core: &AppCore) -> Self {
+        let mut palette = Self {
+            query: query.to_string(),
+            matches: Vec::new(),
+            selected: 0,
+            scroll: 0,
+            page_height: 10,
+        };
+        palette.refresh(app_core);
+        palette
+    }
+
+    fn refresh(&mut self, app_core: &AppCore) {
+        self.matches = app_core.command_log.search(&self.query);
+        self.selected = 0;
+        self.scroll = 0;
+    }
+
+    fn select(&mut self, delta: isize) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let last = self.matches.len() - 1;
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    pub fn render(&mut self, screen: Rect, buf: &mut Buffer, theme: &crate::theme::AppTheme) {
+        let color = crossterm_bridge::to_ratatui_color;
+        let width = screen
+            .width
+            .saturating_sub(4)
+            .clamp(30, 90)
+            .min(screen.width);
+        let height = screen
+            .height
+            .saturating_sub(4)
+            .clamp(8, 24)
+            .min(screen.height);
+        let area = Rect::new(
+            screen.x + screen.width.saturating_sub(width) / 2,
+            screen.y + screen.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+
+        Clear.render(area, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(super::border_glyphs::themed(
+                ratatui::symbols::border::ROUNDED,
+            ))
+            .border_style(Style::default().fg(color(theme.browser_border)))
+            .title(format!(" Command Log ({}) ", self.matches.len()))
+            .title_style(
+                Style::default()
+                    .fg(color(theme.browser_title))
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(Line::from(Span::styled(
+                " Enter send  Tab edit  Esc close ",
+                Style::default().fg(color(theme.text_secondary)),
+            )))
+            .style(Style::default().bg(color(theme.browser_background)));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.height < 2 || inner.width < 10 {
+            return;
+        }
+
+        let normal = Style::default().fg(color(theme.browser_item_normal));
+        let mut lines = vec![Line::from(vec![
+            Span::styled("> ", Style::default().fg(color(theme.browser_title))),
+            Span::styled(self.query.clone(), normal),
+            Span::styled(" ", normal.add_modifier(Modifier::REVERSED)),
+        ])];
+
+        self.page_height = inner.height as usize - 1;
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + self.page_height {
+            self.scroll = self.selected + 1 - self.page_height;
+        }
+
+        if self.matches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No matching commands",
+                Style::default().fg(color(theme.text_disabled)),
+            )));
+        }
+        let text_width = (inner.width as usize).saturating_sub(12);
+        for (idx, found) in self
+            .matches
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(self.page_height)
+        {
+            let row = if idx == self.selected {
+                Style::default()
+                    .fg(color(theme.browser_item_selected))
+                    .bg(color(theme.background_selected))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                normal
+            };
+            let matched = row
+                .fg(color(theme.browser_item_focused))
+                .add_modifier(Modifier::UNDERLINED);
+            let mut spans = vec![Span::styled(
+                format!(
+                    " {} ",
+                    crate::core::locale::format_time_seconds(&found.last_sent)
+                ),
+                row.fg(color(theme.text_secondary)),
+            )];
+            let count = if found.count > 1 {
+                format!(" x{}", found.count)
+            } else {
+                String::new()
+            };
+            let budget = text_width.saturating_sub(count.len());
+            for (pos, ch) in found.text.chars().take(budget).enumerate() {
+                let style = if found.positions.contains(&pos) {
+                    matched
+                } else {
+                    row
+                };
+                spans.push(Span::styled(ch.to_string(), style));
+            }
+            spans.push(Span::styled(count, row.fg(color(theme.text_secondary))));
+            lines.push(Line::from(spans));
+        }
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+impl super::TuiFrontend {
+    /// Open the palette, pre-filtered with `query`
+    pub(crate) fn open_command_log(&mut self, query: &str, app_core: &mut AppCore) {
+        app_core.needs_render = true;
+        if app_core.command_log.is_empty() {
+            app_core.add_system_message("No commands sent yet");
+            return;
+        }
+        self.command_log_palette = Some(CommandLogPalette::new(query, app_core));
+        app_core.ui_state.input_mode = InputMode::CommandLog;
+    }
+
+    pub(crate) fn handle_command_log_key(
+        &mut self,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        app_core: &mut AppCore,
+    ) {
+        app_core.needs_render = true;
+        let Some(palette) = self.command_log_palette.as_mut() else {
+            app_core.ui_state.input_mode = InputMode::Normal;
+            return;
+        };
+        let page = palette.page_height.max(1) as isize;
+        match code {
+            KeyCode::Up => palette.select(-1),
+            KeyCode::Down => palette.select(1),
+            KeyCode::PageUp => palette.select(-page),
+            KeyCode::PageDown => palette.select(page),
+            KeyCode::Home => palette.select(isize::MIN),
+            KeyCode::End => palette.select(isize::MAX),
+            KeyCode::Char('u') if modifiers.ctrl => {
+                palette.query.clear();
+                palette.refresh(app_core);
+            }
+            KeyCode::Char(c) if !modifiers.ctrl && !modifiers.alt => {
+                palette.query.push(c);
+                palette.refresh(app_core);
+            }
+            KeyCode::Backspace => {
+                palette.query.pop();
+                palette.refresh(app_core);
+            }
+            KeyCode::Enter | KeyCode::Tab => {
+                let picked = palette
+                    .matches
+                    .get(palette.selected)
+                    .map(|m| m.text.clone());
+                self.command_log_palette = None;
+                app_core.ui_state.input_mode = InputMode::Normal;
+                let Some(text) = picked else {
+                    return;
+                };
+                if code == KeyCode::Enter {
+                    app_core.resend_command(&text);
+                } else if let Some(input) =
+                    self.widget_manager.command_inputs.get_mut("command_input")
+                {
+                    input.clear();
+                    input.insert_text(&text);
+                }
+            }
+            KeyCode::Esc => {
+                self.command_log_palette = None;
+                app_core.ui_state.input_mode = InputMode::Normal;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Compact mode rendering: the whole layout collapsed into one tabbed view.
+//!
+//! Row 0 is a title line with vitals and RT/CT, row 1 a strip of tabs (one
+//! per visible text window), then the active window's text, and the command
+//! input at the bottom. Used for small terminals such as phone SSH; toggled
+//! with `.compact`. The regular layout is untouched and comes back as-is.
+
+use super::command_input::CommandInput;
+use super::crossterm_bridge;
+use super::text_window::TextWindow;
+use crate::core::AppCore;
+use crate::data::input::{MouseButton, MouseEvent, MouseEventKind};
+use crate::data::PendingLinkClick;
+use crate::frontend::common::text_width;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+use std::collections::HashMap;
+
+/// Screen regions of the compact view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CompactRegions {
+    pub title: Rect,
+    pub tabs: Rect,
+    pub body: Rect,
+    pub input: Rect,
+}
+
+impl CompactRegions {
+    /// Split `area` top to bottom; `input_height` is clamped to what fits
+    pub fn new(area: Rect, input_height: u16) -> Self {
+        let row = |y: u16, height: u16| Rect {
+            x: area.x,
+            y,
+            width: area.width,
+            height,
+        };
+        let title = row(area.y, area.height.min(1));
+        let tabs = row(area.y + title.height, area.height.saturating_sub(1).min(1));
+        let top = title.height + tabs.height;
+        let input_height = input_height.min(area.height.saturating_sub(top));
+        let body_height = area.height - top - input_height;
+        Self {
+            title,
+            tabs,
+            body: row(area.y + top, body_height),
+            input: row(area.y + top + body_height, input_height),
+        }
+    }
+}
+
+/// Height of the command input in compact mode (its layout height, 1-3 rows)
+pub(crate) fn compact_input_height(app_core: &AppCore) -> u16 {
+    app_core
+        .ui_state
+        .windows
+        .get("command_input")
+        .map(|window| window.position.height.clamp(1, 3))
+        .unwrap_or(1)
+}
+
+/// Column ranges (start, end exclusive) of each tab label in the strip
+pub(crate) fn tab_columns(tabs: &[String], x: u16) -> Vec<(u16, u16)> {
+    let mut columns = Vec::with_capacity(tabs.len());
+    let mut col = x;
+    for tab in tabs {
+        let width = text_width::width(tab) as u16 + 2;
+        columns.push((col, col + width));
+        col += width + 1; // separator
+    }
+    columns
+}
+
+/// Index of the tab under column `col`, if any
+pub(crate) fn tab_at(tabs: &[String], x: u16, col: u16) -> Option<usize> {
+    tab_columns(tabs, x)
+        .iter()
+        .position(|(start, end)| col >= *start && col < *end)
+}
+
+pub(crate) fn render_compact(
+    area: Rect,
+    buf: &mut Buffer,
+    app_core: &AppCore,
+    text_windows: &mut HashMap<String, TextWindow>,
+    command_inputs: &HashMap<String, CommandInput>,
+    theme: &crate::theme::AppTheme,
+) {
+    let regions = CompactRegions::new(area, compact_input_height(app_core));
+    let color = crossterm_bridge::to_ratatui_color;
+
+    Paragraph::new(Span::styled(
+        app_core.compact_title(),
+        Style::default()
+            .fg(color(theme.window_title))
+            .add_modifier(Modifier::BOLD),
+    ))
+    .style(Style::default().bg(color(theme.background_secondary)))
+    .render(regions.title, buf);
+
+    let tabs = app_core.compact_tabs();
+    let active = app_core.ui_state.compact_active_tab(&tabs).cloned();
+    let mut spans = Vec::with_capacity(tabs.len() * 2);
+    for (idx, tab) in tabs.iter().enumerate() {
+        if idx > 0 {
+            spans.push(Span::styled(
+                super::border_glyphs::popup_set().vertical_left,
+                Style::default().fg(color(theme.window_border)),
+            ));
+        }
+        let style = if Some(tab) == active.as_ref() {
+            Style::default()
+                .fg(color(theme.text_selected))
+                .bg(color(theme.background_selected))
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color(theme.text_secondary))
+        };
+        spans.push(Span::styled(format!(" {} ", tab), style));
+    }
+    Paragraph::new(Line::from(spans)).render(regions.tabs, buf);
+
+    if let Some(text_window) = active.as_ref().and_then(|name| text_windows.get_mut(name)) {
+        if regions.body.height > 0 {
+            text_window.render_with_focus(
+                regions.body,
+                buf,
+                true,
+                app_core.ui_state.selection_state.as_ref(),
+                "#4a4a4a",
+                0,
+                theme,
+            );
+        }
+    }
+
+    if let Some(cmd_input) = command_inputs.get("command_input") {
+        if regions.input.height > 0 {
+            cmd_input.render(regions.input, buf);
+        }
+    }
+}
+
+impl super::TuiFrontend {
+    /// Mouse handling while compact mode is showing. Returns None for events
+    /// the regular handler should still see (button releases complete a
+    /// pending link click there).
+    pub(crate) fn handle_compact_mouse(
+        &mut self,
+        mouse_event: &MouseEvent,
+        app_core: &mut AppCore,
+    ) -> Option<(bool, Option<String>)> {
+        let (width, height) = self.size();
+        let regions = CompactRegions::new(
+            Rect::new(0, 0, width, height),
+            compact_input_height(app_core),
+        );
+        let tabs = app_core.compact_tabs();
+        let active = app_core.ui_state.compact_active_tab(&tabs).cloned();
+        let (x, y) = (mouse_event.column, mouse_event.row);
+
+        match mouse_event.kind {
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                if let Some(name) = active {
+                    let lines = if mouse_event.kind == MouseEventKind::ScrollUp {
+                        10
+                    } else {
+                        -10
+                    };
+                    self.scroll_window(&name, lines);
+                    app_core.needs_render = true;
+                }
+                Some((true, None))
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                if regions.tabs.contains((x, y).into()) {
+                    if let Some(idx) = tab_at(&tabs, regions.tabs.x, x) {
+                        app_core.select_compact_tab(&tabs[idx]);
+                    }
+                } else if regions.body.contains((x, y).into()) {
+                    if let Some(link_data) = active
+                        .as_deref()
+                        .and_then(|name| self.link_at_position(name, x, y, regions.body))
+                    {
+                        app_core.ui_state.pending_link_click = Some(PendingLinkClick {
+                            link_data,
+                            click_pos: (x, y),
+                        });
+                    }
+                }
+                Some((true, None))
+            }
+            // No window moves/resizes in compact mode
+            MouseEventKind::Drag(_) => Some((true, None)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions_stack_title_tabs_body_input() {
+        let regions = CompactRegions::new(Rect::new(0, 0, 40, 20), 3);
+        assert_eq!(regions.title, Rect::new(0, 0, 40, 1));
+        assert_eq!(regions.tabs, Rect::new(0, 1, 40, 1));
+        assert_eq!(regions.body, Rect::new(0, 2, 40, 15));
+        assert_eq!(regions.input, Rect::new(0, 17, 40, 3));
+    }
+
+    #[test]
+    fn test_regions_survive_tiny_screens() {
+        let regions = CompactRegions::new(Rect::new(0, 0, 10, 2), 3);
+        assert_eq!(regions.body.height, 0);
+        assert_eq!(regions.input.height, 0);
+    }
+
+    #[test]
+    fn test_tab_hit_testing_matches_strip_layout() {
+        let tabs = vec!["main".to_string(), "thoughts".to_string()];
+        // " main " = cols 0..6, separator at 6, " thoughts " = 7..17
+        assert_eq!(tab_columns(&tabs, 0), vec![(0, 6), (7, 17)]);
+        assert_eq!(tab_at(&tabs, 0, 3), Some(0));
+        assert_eq!(tab_at(&tabs, 0, 6), None);
+        assert_eq!(tab_at(&tabs, 0, 10), Some(1));
+        assert_eq!(tab_at(&tabs, 0, 30), None);
+    }
+}
@@ -8,7 +8,7 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
-    widgets::{Block, BorderType, Clear, Widget as RatatuiWidget},
+    widgets::{Block, Clear, Widget as RatatuiWidget},
 };
 use std::collections::HashSet;
 
@@ -146,17 +146,9 @@ impl Compass {
             let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
             block = block.borders(borders);
 
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    "quadrant_inside" => BorderType::QuadrantInside,
-                    "quadrant_outside" => BorderType::QuadrantOutside,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             if let Some(color) = self.border_color {
                 block = block.border_style(Style::default().fg(color));
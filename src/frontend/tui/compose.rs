@@ -0,0 +1,202 @@
+//! Multi-line compose popup for long commands.
+//!
+//! Opened with the `open_compose` keybind, seeded with whatever was typed in
+//! the command input. Editing is done by a `tui_textarea::TextArea`; the text
+//! is drawn word-wrapped by us since the textarea only scrolls sideways.
+//! Ctrl+S hands the text to core (`AppCore::submit_compose`), which splits it
+//! into commands on blank lines.
+
+use super::crossterm_bridge;
+use super::textarea_bridge::GraphemeInput;
+use crate::data::input::{KeyCode, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+use tui_textarea::{CursorMove, TextArea};
+
+/// Outcome of a key press in the compose popup
+pub enum ComposeResult {
+    None,
+    Send(String),
+    Cancel,
+}
+
+pub struct ComposeEditor {
+    textarea: TextArea<'static>,
+    /// First visible wrapped row
+    scroll: usize,
+}
+
+impl ComposeEditor {
+    pub fn new(initial: &str) -> Self {
+        let mut textarea = TextArea::new(vec![initial.to_string()]);
+        textarea.set_cursor_line_style(Style::default());
+        textarea.move_cursor(CursorMove::End);
+        Self {
+            textarea,
+            scroll: 0,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.textarea.lines().join("\n")
+    }
+
+    /// Ctrl+S sends, Esc cancels; everything else edits (Enter is a newline).
+    pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> ComposeResult {
+        match code {
+            KeyCode::Esc => return ComposeResult::Cancel,
+            KeyCode::Char('s') | KeyCode::Char('S') if modifiers.ctrl => {
+                return ComposeResult::Send(self.text());
+            }
+            _ => {}
+        }
+        let ct_event = crossterm::event::KeyEvent::new(
+            crossterm_bridge::to_crossterm_keycode(code),
+            crossterm_bridge::to_crossterm_modifiers(modifiers),
+        );
+        self.textarea
+            .input_graphemes(super::textarea_bridge::to_textarea_event(ct_event));
+        ComposeResult::None
+    }
+
+    pub fn render(&mut self, screen: Rect, buf: &mut Buffer, theme: &crate::theme::AppTheme) {
+        let color = crossterm_bridge::to_ratatui_color;
+        let width = screen
+            .width
+            .saturating_sub(4)
+            .clamp(20, 80)
+            .min(screen.width);
+        let height = screen
+            .height
+            .saturating_sub(4)
+            .clamp(6, 14)
+            .min(screen.height);
+        let area = Rect::new(
+            screen.x + screen.width.saturating_sub(width) / 2,
+            screen.y + screen.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+
+        let commands = crate::data::compose_commands(&self.text()).len();
+        Clear.render(area, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(super::border_glyphs::themed(
+                ratatui::symbols::border::ROUNDED,
+            ))
+            .border_style(Style::default().fg(color(theme.window_border)))
+            .title(" Compose ")
+            .title_style(
+                Style::default()
+                    .fg(color(theme.window_title))
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(Line::from(Span::styled(
+                format!(
+                    " {} command{} - Ctrl+S send, Esc cancel ",
+                    commands,
+                    if commands == 1 { "" } else { "s" }
+                ),
+                Style::default().fg(color(theme.text_secondary)),
+            )))
+            .style(Style::default().bg(color(theme.window_background)));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let text_style = Style::default().fg(color(theme.text_primary));
+        render_wrapped(&self.textarea, &mut self.scroll, inner, buf, text_style);
+    }
+}
+
+/// Draw a textarea's lines word-wrapped into `inner`, scrolled to keep the
+/// cursor visible, with the cursor cell reversed.
+pub(super) fn render_wrapped(
+    textarea: &TextArea<'static>,
+    scroll: &mut usize,
+    inner: Rect,
+    buf: &mut Buffer,
+    text_style: Style,
+) {
+    if inner.width < 2 || inner.height == 0 {
+        return;
+    }
+    // Leave the last column free so the cursor fits after a full row
+    let wrap_width = inner.width as usize - 1;
+    let (cursor_line, cursor_col) = textarea.cursor();
+    let mut rows: Vec<String> = Vec::new();
+    let mut cursor = (0, 0);
+    for (idx, line) in textarea.lines().iter().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        let segments = wrap_line(&chars, wrap_width);
+        let last = segments.len() - 1;
+        for (seg_idx, &(start, end)) in segments.iter().enumerate() {
+            if idx == cursor_line && cursor_col >= start && (cursor_col < end || seg_idx == last) {
+                cursor = (rows.len(), cursor_col - start);
+            }
+            rows.push(chars[start..end].iter().collect());
+        }
+    }
+
+    let visible = inner.height as usize;
+    if cursor.0 < *scroll {
+        *scroll = cursor.0;
+    } else if cursor.0 >= *scroll + visible {
+        *scroll = cursor.0 + 1 - visible;
+    }
+
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .skip(*scroll)
+        .take(visible)
+        .map(|row| Line::from(Span::styled(row, text_style)))
+        .collect();
+    Paragraph::new(lines).render(inner, buf);
+
+    let x = inner.x + cursor.1 as u16;
+    let y = inner.y + (cursor.0 - *scroll) as u16;
+    if let Some(cell) = buf.cell_mut((x, y)) {
+        cell.set_style(text_style.add_modifier(Modifier::REVERSED));
+    }
+}
+
+/// Split one line into `[start, end)` char ranges of at most `width` chars,
+/// breaking after the last space that fits. Words longer than a row are cut.
+fn wrap_line(chars: &[char], width: usize) -> Vec<(usize, usize)> {
+    let width = width.max(1);
+    let mut segments = Vec::new();
+    let mut start = 0;
+    while chars.len() - start > width {
+        let end = (start + 1..start + width)
+            .rev()
+            .find(|&i| chars[i] == ' ')
+            .map(|i| i + 1)
+            .unwrap_or(start + width);
+        segments.push((start, end));
+        start = end;
+    }
+    segments.push((start, chars.len()));
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_at_word_boundaries() {
+        let chars: Vec<char> = "say hello there friend".chars().collect();
+        assert_eq!(wrap_line(&chars, 10), vec![(0, 10), (10, 16), (16, 22)]);
+        let long: Vec<char> = "abcdefghij".chars().collect();
+        assert_eq!(wrap_line(&long, 4), vec![(0, 4), (4, 8), (8, 10)]);
+        assert_eq!(wrap_line(&[], 10), vec![(0, 0)]);
+    }
+}
@@ -22,6 +22,9 @@ pub struct ContainerWindow {
 
     /// Generation counter for change detection (matches ContainerData.generation)
     last_generation: u64,
+
+    /// Title suffix from ContainerData::count_label ("[12]", "[12/20]")
+    count_label: String,
 }
 
 impl ContainerWindow {
@@ -33,6 +36,7 @@ impl ContainerWindow {
             widget,
             link_color: None,
             last_generation: 0,
+            count_label: "(empty)".to_string(),
         }
     }
 
@@ -55,6 +59,7 @@ impl ContainerWindow {
         }
 
         self.last_generation = container.generation;
+        self.count_label = container.count_label();
 
         // Update title from container if we don't have a custom title
         let current_title = self.widget.get_title();
@@ -62,141 +67,52 @@ impl ContainerWindow {
             self.widget.set_title(container.title.clone());
         }
 
-        // Clear existing lines
+        // Clear existing lines (keeping a scrolled-back view in place)
+        let saved_scroll = self.widget.scroll_position();
         self.widget.clear();
 
         // Parse each item line from the container
-        for item_content in &container.items {
+        for (index, item_content) in container.items.iter().enumerate() {
             // Skip header lines like "In the bandolier:" and empty/nothing lines
-            let trimmed = item_content.trim().to_lowercase();
-            if trimmed.starts_with("in the ") || trimmed == "nothing" || trimmed.is_empty() {
+            if !ContainerData::is_item_line(item_content) {
                 continue;
             }
 
-            let segments = self.parse_container_item(item_content);
+            let mut segments = self.parse_container_item(item_content);
+            // Remembered value from an earlier appraisal, dimmed
+            if let Some(Some(note)) = container.notes.get(index) {
+                segments.push(TextSegment {
+                    text: note.clone(),
+                    fg: Some(crate::data::intern::intern("#808080")),
+                    bg: None,
+                    bold: false,
+                    mono: false,
+                    span_type: crate::data::SpanType::Normal,
+                    link_data: None,
+                });
+            }
             if !segments.is_empty() {
                 // ListWidget handles highlights internally during add_line
                 self.widget.add_line(segments);
             }
         }
+        self.widget.restore_scroll_position(saved_scroll);
 
         true
     }
 
     /// Parse a container item string (with potential XML/links) into TextSegments
     fn parse_container_item(&mut self, content: &str) -> Vec<TextSegment> {
-        let mut segments = Vec::new();
-        let mut current_pos = 0;
-        let content_len = content.len();
-
-        while current_pos < content_len {
-            // Look for <a tag
-            if let Some(a_start) = content[current_pos..].find("<a ") {
-                let a_start_abs = current_pos + a_start;
-
-                // Add text before the link
-                if a_start > 0 {
-                    let text = &content[current_pos..a_start_abs];
-                    if !text.is_empty() {
-                        segments.push(TextSegment {
-                            text: text.to_string(),
-                            fg: None,
-                            bg: None,
-                            bold: false,
-                            mono: false,
-                            span_type: crate::data::SpanType::Normal,
-                            link_data: None,
-                        });
-                    }
-                }
-
-                // Parse the <a> tag
-                if let Some(tag_end) = content[a_start_abs..].find('>') {
-                    let tag_end_abs = a_start_abs + tag_end;
-                    let tag = &content[a_start_abs..=tag_end_abs];
-
-                    // Extract attributes
-                    let exist_id = Self::extract_attribute(tag, "exist").unwrap_or_default();
-                    let noun = Self::extract_attribute(tag, "noun").unwrap_or_default();
-
-                    // Find closing </a>
-                    if let Some(close_start) = content[tag_end_abs + 1..].find("</a>") {
-                        let close_start_abs = tag_end_abs + 1 + close_start;
-                        let link_text = &content[tag_end_abs + 1..close_start_abs];
-
-                        // Create link data
-                        let link_data = crate::data::LinkData {
-                            exist_id,
-                            noun,
-                            text: link_text.to_string(),
-                            coord: None,
-                        };
-
-                        // Add link segment - use theme link color or default to cyan
-                        segments.push(TextSegment {
-                            text: link_text.to_string(),
-                            fg: self
-                                .link_color
-                                .clone()
-                                .or_else(|| Some("#00FFFF".to_string())),
-                            bg: None,
-                            bold: false,
-                            mono: false,
-                            span_type: crate::data::SpanType::Link,
-                            link_data: Some(link_data),
-                        });
-
-                        current_pos = close_start_abs + 4; // Skip past </a>
-                        continue;
-                    }
-                }
-
-                // If we couldn't parse the link, skip the <a and continue
-                current_pos = a_start_abs + 2;
-            } else {
-                // No more links - add remaining text
-                let text = &content[current_pos..];
-                if !text.is_empty() {
-                    segments.push(TextSegment {
-                        text: text.to_string(),
-                        fg: None,
-                        bg: None,
-                        bold: false,
-                        mono: false,
-                        span_type: crate::data::SpanType::Normal,
-                        link_data: None,
-                    });
-                }
-                break;
-            }
+        let mut segments = crate::core::containers::parse_item_line(content);
+        // Links use the theme link color or default to cyan
+        let link_color =
+            crate::data::intern::intern(self.link_color.as_deref().unwrap_or("#00FFFF"));
+        for segment in segments.iter_mut().filter(|s| s.link_data.is_some()) {
+            segment.fg = Some(link_color.clone());
         }
-
         segments
     }
 
-    /// Extract an attribute value from an XML tag
-    fn extract_attribute(tag: &str, attr_name: &str) -> Option<String> {
-        // Look for attr="value" or attr='value'
-        let pattern1 = format!("{}=\"", attr_name);
-        let pattern2 = format!("{}='", attr_name);
-
-        if let Some(start) = tag.find(&pattern1) {
-            let value_start = start + pattern1.len();
-            if let Some(end) = tag[value_start..].find('"') {
-                return Some(tag[value_start..value_start + end].to_string());
-            }
-        }
-
-        if let Some(start) = tag.find(&pattern2) {
-            let value_start = start + pattern2.len();
-            if let Some(end) = tag[value_start..].find('\'') {
-                return Some(tag[value_start..value_start + end].to_string());
-            }
-        }
-
-        None
-    }
-
     /// Scroll up by N lines
     pub fn scroll_up(&mut self, lines: usize) {
         self.widget.scroll_up(lines);
@@ -259,8 +175,7 @@ impl ContainerWindow {
 
     /// Render the container window
     pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
-        // Update title with item count
-        let line_count = self.widget.get_lines().len();
+        // Update title with item count (and capacity when known)
         let base_title = self.widget.get_title().to_string();
 
         // Extract base title without count if present
@@ -272,12 +187,7 @@ impl ContainerWindow {
             &base_title
         };
 
-        let display_title = if line_count == 0 {
-            format!("{} (empty)", display_base)
-        } else {
-            format!("{} [{}]", display_base, line_count)
-        };
-
+        let display_title = format!("{} {}", display_base, self.count_label);
         self.widget.set_title(display_title);
         self.widget.render(area, buf);
     }
@@ -380,38 +290,6 @@ mod tests {
     // Inner size tests
     // ===========================================
 
-    // ===========================================
-    // Extract attribute tests
-    // ===========================================
-
-    #[test]
-    fn test_extract_attribute_double_quotes() {
-        let tag = r#"<a exist="12345" noun="sword">"#;
-        let result = ContainerWindow::extract_attribute(tag, "exist");
-        assert_eq!(result, Some("12345".to_string()));
-    }
-
-    #[test]
-    fn test_extract_attribute_single_quotes() {
-        let tag = r#"<a exist='67890' noun='dagger'>"#;
-        let result = ContainerWindow::extract_attribute(tag, "noun");
-        assert_eq!(result, Some("dagger".to_string()));
-    }
-
-    #[test]
-    fn test_extract_attribute_missing() {
-        let tag = r#"<a exist="12345">"#;
-        let result = ContainerWindow::extract_attribute(tag, "noun");
-        assert!(result.is_none());
-    }
-
-    #[test]
-    fn test_extract_attribute_empty_value() {
-        let tag = r#"<a exist="" noun="test">"#;
-        let result = ContainerWindow::extract_attribute(tag, "exist");
-        assert_eq!(result, Some("".to_string()));
-    }
-
     // ===========================================
     // Parse container item tests
     // ===========================================
@@ -480,6 +358,8 @@ mod tests {
             title: "Bag".to_string(),
             title_lower: "bag".to_string(),
             items: vec![],
+            notes: vec![],
+            capacity: None,
             generation: 0,
         };
 
@@ -496,12 +376,37 @@ mod tests {
             title: "My Bag".to_string(),
             title_lower: "my bag".to_string(),
             items: vec!["an item".to_string()],
+            notes: vec![None],
+            capacity: None,
             generation: 1,
         };
 
         let changed = cw.update_from_cache(&container);
         assert!(changed);
         assert_eq!(cw.last_generation, 1);
+        assert_eq!(cw.count_label, "[1]");
+    }
+
+    #[test]
+    fn test_render_title_shows_count_and_capacity() {
+        let mut cw = ContainerWindow::new("Backpack".to_string());
+        let container = ContainerData {
+            id: "4".to_string(),
+            title: "Backpack".to_string(),
+            title_lower: "backpack".to_string(),
+            items: vec!["In the backpack:".to_string(), "a gem".to_string()],
+            notes: vec![None, None],
+            capacity: Some(20),
+            generation: 1,
+        };
+        cw.update_from_cache(&container);
+
+        let area = Rect::new(0, 0, 30, 4);
+        let mut buf = Buffer::empty(area);
+        cw.render(area, &mut buf);
+        cw.render(area, &mut buf);
+        let top: String = (0..30).map(|x| buf[(x, 0)].symbol()).collect();
+        assert!(top.contains("Backpack [1/20]"), "{}", top);
     }
 
     #[test]
@@ -512,6 +417,8 @@ mod tests {
             title: "Container Title".to_string(),
             title_lower: "container title".to_string(),
             items: vec![],
+            notes: vec![],
+            capacity: None,
             generation: 1,
         };
 
@@ -148,14 +148,7 @@ impl Countdown {
 
         // Build border parameters
         let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
-        let border_type = match self.border_style.as_deref() {
-            Some("double") => ratatui::widgets::BorderType::Double,
-            Some("rounded") => ratatui::widgets::BorderType::Rounded,
-            Some("thick") => ratatui::widgets::BorderType::Thick,
-            Some("quadrant_inside") => ratatui::widgets::BorderType::QuadrantInside,
-            Some("quadrant_outside") => ratatui::widgets::BorderType::QuadrantOutside,
-            _ => ratatui::widgets::BorderType::Plain,
-        };
+        let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
         let border_style = Style::default()
             .fg(border_color)
             .bg(bg_color.unwrap_or(Color::Reset));
@@ -167,7 +160,7 @@ impl Countdown {
             self.show_border,
             borders,
             &self.border_sides,
-            border_type,
+            border_set,
             border_style,
             &self.label,
             self.title_position,
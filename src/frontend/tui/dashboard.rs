@@ -6,13 +6,14 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, BorderType, Widget as RatatuiWidget},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Widget as RatatuiWidget},
 };
 use std::collections::HashMap;
 
 use super::colors::parse_color_to_ratatui;
 use super::crossterm_bridge;
+use crate::frontend::common::text_width;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DashboardLayout {
@@ -68,6 +69,8 @@ pub struct Dashboard {
     background_color: Option<String>,
     content_align: Option<String>,
     transparent_background: bool,
+    // Theme shape cues: active icons bold+underlined, inactive dim
+    shape_cues: bool,
 }
 
 impl Dashboard {
@@ -86,6 +89,7 @@ impl Dashboard {
             background_color: None,
             content_align: None,
             transparent_background: false,
+            shape_cues: false,
         }
     }
 
@@ -151,10 +155,26 @@ impl Dashboard {
         self.indicator_map.clear();
     }
 
+    pub fn set_shape_cues(&mut self, shape_cues: bool) {
+        self.shape_cues = shape_cues;
+    }
+
     fn parse_color(input: &str) -> Color {
         parse_color_to_ratatui(input).unwrap_or(Color::White)
     }
 
+    /// Icon style for an indicator's current value. With shape cues on,
+    /// on/off no longer depends on color alone.
+    fn indicator_style(&self, indicator: &DashboardIndicator) -> Style {
+        let color_index = (indicator.value as usize).min(indicator.colors.len().saturating_sub(1));
+        let style = Style::default().fg(Self::parse_color(&indicator.colors[color_index]));
+        match (self.shape_cues, indicator.value > 0) {
+            (false, _) => style,
+            (true, true) => style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            (true, false) => style.add_modifier(Modifier::DIM),
+        }
+    }
+
     pub fn render(&self, area: Rect, buf: &mut Buffer) {
         // Clear area first
         ratatui::widgets::Clear.render(area, buf);
@@ -175,16 +195,11 @@ impl Dashboard {
         let inner_area = if self.show_border {
             let mut block = Block::default();
 
-            let border_type = match self.border_style.as_deref() {
-                Some("double") => BorderType::Double,
-                Some("rounded") => BorderType::Rounded,
-                Some("thick") => BorderType::Thick,
-                _ => BorderType::Plain,
-            };
+            let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
 
             let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
 
-            block = block.borders(borders).border_type(border_type);
+            block = block.borders(borders).border_set(border_set);
 
             if let Some(ref color_str) = self.border_color {
                 let color = Self::parse_color(color_str);
@@ -243,7 +258,7 @@ impl Dashboard {
         // Calculate total width needed
         let total_width: usize = indicators
             .iter()
-            .map(|ind| ind.icon.chars().count())
+            .map(|ind| text_width::width(&ind.icon))
             .sum::<usize>()
             + (indicators.len().saturating_sub(1)) * self.spacing as usize;
 
@@ -252,18 +267,9 @@ impl Dashboard {
 
         let mut x = start_x;
         for indicator in indicators {
-            let color_index =
-                (indicator.value as usize).min(indicator.colors.len().saturating_sub(1));
-            let color = Self::parse_color(&indicator.colors[color_index]);
-
-            for ch in indicator.icon.chars() {
-                if x >= area.right() {
-                    break;
-                }
-                buf[(x, area.y)].set_char(ch).set_fg(color);
-                x += 1;
-            }
+            let style = self.indicator_style(indicator);
 
+            x = Self::draw_icon(buf, x, area.y, &indicator.icon, area.right(), style);
             x += self.spacing;
         }
     }
@@ -281,7 +287,7 @@ impl Dashboard {
         let mut current_width: usize = 0;
 
         for ind in indicators {
-            let icon_width = ind.icon.chars().count();
+            let icon_width = text_width::width(&ind.icon);
             let extra_spacing = if current_row.is_empty() { 0 } else { spacing };
             if !current_row.is_empty()
                 && current_width + extra_spacing + icon_width > available_width
@@ -310,20 +316,13 @@ impl Dashboard {
             }
             let row_width: usize = row
                 .iter()
-                .map(|ind| ind.icon.chars().count())
+                .map(|ind| text_width::width(&ind.icon))
                 .sum::<usize>()
                 + (row.len().saturating_sub(1)) * spacing;
             let mut x = self.calculate_horizontal_offset(row_width, area.width as usize, area.x);
             for ind in row {
-                let color_index = (ind.value as usize).min(ind.colors.len().saturating_sub(1));
-                let color = Self::parse_color(&ind.colors[color_index]);
-                for ch in ind.icon.chars() {
-                    if x >= area.right() {
-                        break;
-                    }
-                    buf[(x, y)].set_char(ch).set_fg(color);
-                    x += 1;
-                }
+                let style = self.indicator_style(ind);
+                x = Self::draw_icon(buf, x, y, &ind.icon, area.right(), style);
                 x = x.saturating_add(self.spacing);
             }
             y = y.saturating_add(1 + self.spacing);
@@ -343,20 +342,9 @@ impl Dashboard {
             if y >= area.bottom() {
                 break;
             }
+            let style = self.indicator_style(indicator);
 
-            let color_index =
-                (indicator.value as usize).min(indicator.colors.len().saturating_sub(1));
-            let color = Self::parse_color(&indicator.colors[color_index]);
-
-            let mut x = area.x;
-            for ch in indicator.icon.chars() {
-                if x >= area.right() {
-                    break;
-                }
-                buf[(x, y)].set_char(ch).set_fg(color);
-                x += 1;
-            }
-
+            Self::draw_icon(buf, area.x, y, &indicator.icon, area.right(), style);
             y += 1 + self.spacing;
         }
     }
@@ -382,20 +370,22 @@ impl Dashboard {
 
             let x = area.x + (grid_col * cell_width) as u16;
             let y = area.y + (grid_row * cell_height) as u16;
+            let style = self.indicator_style(indicator);
 
-            let color_index =
-                (indicator.value as usize).min(indicator.colors.len().saturating_sub(1));
-            let color = Self::parse_color(&indicator.colors[color_index]);
+            let max_x = area.right().min(x + cell_width as u16);
+            Self::draw_icon(buf, x, y, &indicator.icon, max_x, style);
+        }
+    }
 
-            let mut curr_x = x;
-            for ch in indicator.icon.chars() {
-                if curr_x >= area.right() || curr_x >= x + cell_width as u16 {
-                    break;
-                }
-                buf[(curr_x, y)].set_char(ch).set_fg(color);
-                curr_x += 1;
-            }
+    /// Draw an icon up to `max_x`, whole graphemes only; returns the column
+    /// after it
+    fn draw_icon(buf: &mut Buffer, x: u16, y: u16, icon: &str, max_x: u16, style: Style) -> u16 {
+        if x >= max_x {
+            return x;
         }
+        let room = (max_x - x) as usize;
+        buf.set_stringn(x, y, text_width::truncate(icon, room), room, style)
+            .0
     }
 
     fn calculate_horizontal_offset(
@@ -48,31 +48,10 @@ fn display_label(button: &crate::data::DialogButton) -> String {
     }
 }
 
-/// Format a number with commas for display (e.g., 1000 → 1,000)
-fn format_number_with_commas(value: &str) -> String {
-    // Only format if it's a pure number (digits only)
-    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
-        return value.to_string();
-    }
-
-    let mut result = String::new();
-    let chars: Vec<char> = value.chars().collect();
-    let len = chars.len();
-
-    for (i, ch) in chars.iter().enumerate() {
-        result.push(*ch);
-        let remaining = len - i - 1;
-        if remaining > 0 && remaining % 3 == 0 {
-            result.push(',');
-        }
-    }
-    result
-}
-
-/// Get formatted value for a dialog field (with commas, no cursor)
+/// Get formatted value for a dialog field (digits grouped, no cursor)
 fn field_formatted_value(dialog: &DialogState, index: usize) -> String {
     let field = &dialog.fields[index];
-    format_number_with_commas(&field.value)
+    crate::core::locale::group_digits(&field.value)
 }
 
 /// Create a fixed-width input box display: "[     value]"
@@ -1033,6 +1012,7 @@ pub fn render_dialog(
 
     let block = Block::default()
         .borders(Borders::ALL)
+        .border_set(super::border_glyphs::popup_set())
         .title(title)
         .border_style(Style::default().fg(crossterm_bridge::to_ratatui_color(theme.menu_border)))
         .style(Style::default().bg(crossterm_bridge::to_ratatui_color(theme.menu_background)));
@@ -30,6 +30,7 @@ pub struct Encumbrance {
     show_border: bool,
     /// Which border sides to show
     border_sides: BorderSides,
+    border_style: Option<String>,
     /// Cached state for rendering
     value: u32,
     text: String,
@@ -68,6 +69,7 @@ impl Encumbrance {
             show_title: true,
             show_border: true,
             border_sides: BorderSides::default(),
+            border_style: None,
             value: 0,
             text: String::new(),
             blurb: String::new(),
@@ -122,6 +124,11 @@ impl Encumbrance {
         self.border_sides = sides;
     }
 
+    /// Set the border glyph style (see border_glyphs)
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
     /// Set the light encumbrance color (0-20)
     pub fn set_color_light(&mut self, color: Color) {
         self.color_light = color;
@@ -243,6 +250,9 @@ impl Encumbrance {
             let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
             let mut block = Block::default()
                 .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
                 .border_style(Style::default().fg(self.border_color));
             if self.show_title {
                 block = block.title(self.title.as_str());
@@ -21,6 +21,7 @@ pub struct Experience {
     generation: u64,
     /// Border color
     border_color: Color,
+    border_style: Option<String>,
     /// Text color
     text_color: Color,
     /// Background color (from theme)
@@ -41,6 +42,7 @@ impl Experience {
             lines: Vec::new(),
             generation: 0,
             border_color: Color::White,
+            border_style: None,
             text_color: Color::White,
             background_color: None,
         }
@@ -51,6 +53,11 @@ impl Experience {
         self.border_color = color;
     }
 
+    /// Set the border glyph style (see border_glyphs)
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
     /// Set the text color
     pub fn set_text_color(&mut self, color: Color) {
         self.text_color = color;
@@ -105,6 +112,9 @@ impl Experience {
         let block = Block::default()
             .title(self.title.as_str())
             .borders(Borders::ALL)
+            .border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ))
             .border_style(Style::default().fg(self.border_color));
 
         let inner = block.inner(area);
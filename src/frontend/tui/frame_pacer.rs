@@ -0,0 +1,120 @@
+use std::time::{Duration, Instant};
+
+/// How long after the last input or server text the loop keeps full rate
+const ACTIVE_HOLD: Duration = Duration::from_secs(1);
+
+/// Adaptive frame pacing for the TUI event loop.
+///
+/// The loop sleeps in the terminal event poll between iterations. While
+/// text or input is arriving the poll wakes at `max_fps`; once nothing has
+/// happened for `ACTIVE_HOLD` it stretches to `idle_fps`, so a quiet session
+/// wakes a handful of times a second instead of sixty. Key presses still end
+/// the poll immediately, so typing never waits on the idle rate.
+pub struct FramePacer {
+    max_fps: u32,
+    idle_fps: u32,
+    last_activity: Instant,
+    last_frame: Option<Instant>,
+}
+
+impl FramePacer {
+    pub fn new(max_fps: u32, idle_fps: u32) -> Self {
+        let mut pacer = Self {
+            max_fps: 60,
+            idle_fps: 5,
+            last_activity: Instant::now(),
+            last_frame: None,
+        };
+        pacer.set_rates(max_fps, idle_fps);
+        pacer
+    }
+
+    /// Apply `ui.max_fps`/`ui.idle_fps`; idle never exceeds max
+    pub fn set_rates(&mut self, max_fps: u32, idle_fps: u32) {
+        self.max_fps = max_fps.max(1);
+        self.idle_fps = idle_fps.clamp(1, self.max_fps);
+    }
+
+    /// Something happened (input, server text): ramp to full rate
+    pub fn note_activity(&mut self, now: Instant) {
+        self.last_activity = now;
+    }
+
+    /// Rate the loop is currently pacing to
+    pub fn target_fps(&self, now: Instant) -> u32 {
+        if now.duration_since(self.last_activity) < ACTIVE_HOLD {
+            self.max_fps
+        } else {
+            self.idle_fps
+        }
+    }
+
+    /// Whether a pending render may go out now without exceeding `max_fps`
+    pub fn frame_due(&self, now: Instant) -> bool {
+        self.last_frame
+            .is_none_or(|last| now.duration_since(last) >= self.min_frame_interval())
+    }
+
+    pub fn frame_rendered(&mut self, now: Instant) {
+        self.last_frame = Some(now);
+    }
+
+    /// How long the next event poll may block. A render held back by the
+    /// rate cap gets woken as soon as it's due.
+    pub fn poll_timeout(&self, now: Instant, render_pending: bool) -> Duration {
+        if render_pending {
+            if let Some(last) = self.last_frame {
+                return self
+                    .min_frame_interval()
+                    .saturating_sub(now.duration_since(last));
+            }
+            return Duration::ZERO;
+        }
+        interval(self.target_fps(now))
+    }
+
+    fn min_frame_interval(&self) -> Duration {
+        interval(self.max_fps)
+    }
+}
+
+fn interval(fps: u32) -> Duration {
+    Duration::from_secs(1) / fps.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_to_idle_rate_after_activity_settles() {
+        let start = Instant::now();
+        let mut pacer = FramePacer::new(60, 5);
+        pacer.note_activity(start);
+        assert_eq!(pacer.target_fps(start), 60);
+        assert_eq!(pacer.poll_timeout(start, false), interval(60));
+
+        let later = start + ACTIVE_HOLD;
+        assert_eq!(pacer.target_fps(later), 5);
+        assert_eq!(pacer.poll_timeout(later, false), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn caps_renders_at_max_fps() {
+        let start = Instant::now();
+        let mut pacer = FramePacer::new(50, 5);
+        assert!(pacer.frame_due(start));
+        pacer.frame_rendered(start);
+
+        let soon = start + Duration::from_millis(5);
+        assert!(!pacer.frame_due(soon));
+        assert_eq!(pacer.poll_timeout(soon, true), Duration::from_millis(15));
+        assert!(pacer.frame_due(start + Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn idle_rate_never_exceeds_max() {
+        let pacer = FramePacer::new(10, 30);
+        assert_eq!(pacer.idle_fps, 10);
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use crate::core::AppCore;
+use crate::frontend::common::text_width;
 use crate::frontend::{Frontend, FrontendEvent};
 use crossterm::{
     event::{self, Event, KeyEventKind},
@@ -18,7 +19,7 @@ impl Frontend for TuiFrontend {
         // full sync/render pass per event. Capped so a pathological flood
         // still lets the main loop breathe.
         const MAX_EVENTS_PER_POLL: usize = 128;
-        let mut wait = std::time::Duration::from_millis(16);
+        let mut wait = self.poll_timeout;
         while events.len() < MAX_EVENTS_PER_POLL && event::poll(wait)? {
             wait = std::time::Duration::ZERO;
             match event::read()? {
@@ -55,7 +56,8 @@ impl Frontend for TuiFrontend {
                 Event::Paste(text) => {
                     events.push(FrontendEvent::Paste { text });
                 }
-                _ => {}
+                Event::FocusGained => events.push(FrontendEvent::Focus { focused: true }),
+                Event::FocusLost => events.push(FrontendEvent::Focus { focused: false }),
             }
         }
 
@@ -67,11 +69,73 @@ impl Frontend for TuiFrontend {
         Ok(events)
     }
 
-    fn render(&mut self, app: &mut dyn std::any::Any) -> Result<()> {
-        // Downcast to AppCore
-        let app_core = app
-            .downcast_mut::<AppCore>()
-            .ok_or_else(|| anyhow::anyhow!("Invalid app type"))?;
+    fn handle_event(
+        &mut self,
+        app_core: &mut AppCore,
+        event: FrontendEvent,
+    ) -> Result<Option<String>> {
+        match event {
+            FrontendEvent::Key { code, modifiers } => self.handle_key_event(
+                code,
+                modifiers,
+                app_core,
+                super::menu_actions::handle_menu_action,
+            ),
+            FrontendEvent::Mouse(mouse_event) => {
+                let (_handled, command) = self.handle_mouse_event(
+                    &mouse_event,
+                    app_core,
+                    super::menu_actions::handle_menu_action,
+                )?;
+                Ok(command)
+            }
+            FrontendEvent::Resize { width, height } => {
+                // Automatic resize on terminal resize is disabled (manual .resize command only)
+                tracing::info!(
+                    "Terminal resized to {}x{} (auto-resize disabled, use .resize command)",
+                    width,
+                    height
+                );
+                Ok(None)
+            }
+            FrontendEvent::Paste { text } => self.handle_paste(app_core, text),
+            FrontendEvent::Focus { focused } => {
+                app_core.set_app_focused(focused);
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn sync(&mut self, app_core: &mut AppCore) {
+        // Reset widget caches if layout was reloaded
+        if app_core.ui_state.needs_widget_reset {
+            // Rebuilt windows pick up where the reader left them
+            let views = self.widget_manager.all_window_views();
+            app_core.ui_state.window_views.extend(views);
+            self.widget_manager.clear();
+            app_core.ui_state.needs_widget_reset = false;
+            tracing::debug!("Widget caches cleared after layout reload");
+        }
+
+        // Save the notes editor's text once typing pauses
+        if let Some(text) = self
+            .notes_editor
+            .as_mut()
+            .and_then(|editor| editor.autosave_due(std::time::Instant::now()))
+        {
+            app_core.set_notes(text);
+        }
+
+        // Reset specific widgets (e.g., when widget type changes)
+        if !app_core.ui_state.widgets_to_reset.is_empty() {
+            for name in app_core.ui_state.widgets_to_reset.drain(..) {
+                let views = self.widget_manager.window_views(&name);
+                app_core.ui_state.window_views.extend(views);
+                self.widget_manager.remove_widget_from_all_caches(&name);
+                tracing::debug!("Reset widget cache for '{}' (type change)", name);
+            }
+        }
 
         // Arc handle so all sync tasks share the same palette without
         // deep-cloning the theme every frame
@@ -123,9 +187,27 @@ impl Frontend for TuiFrontend {
         self.sync_experience_widgets(app_core, &theme);
         self.sync_gs4_experience_widgets(app_core, &theme);
         self.sync_encumbrance_widgets(app_core, &theme);
+        self.sync_stance_widgets(app_core, &theme);
+        self.sync_wealth_widgets(app_core, &theme);
+        self.sync_task_tracker_widgets(app_core, &theme);
+        self.sync_society_tasks_widgets(app_core, &theme);
+        self.sync_combat_stats_widgets(app_core, &theme);
+        self.sync_calendar_widgets(app_core, &theme);
+        self.sync_reminders_widgets(app_core, &theme);
+        self.sync_notes_widgets(app_core, &theme);
+        self.sync_watch_widgets(app_core, &theme);
+        self.sync_traffic_widgets(app_core, &theme);
+        self.sync_played_widgets(app_core, &theme);
         self.sync_minivitals_widgets(app_core, &theme);
         self.sync_betrayer_widgets(app_core, &theme);
 
+        // Rebuilt widgets took their saved views during the sync above
+        app_core.ui_state.window_views.clear();
+    }
+
+    fn render(&mut self, app_core: &mut AppCore) -> Result<()> {
+        let theme = self.theme_cache.get_theme_arc();
+
         // Temporarily take ownership of widgets to use in render
         let mut text_windows = std::mem::take(&mut self.widget_manager.text_windows);
         let command_inputs = std::mem::take(&mut self.widget_manager.command_inputs);
@@ -153,6 +235,20 @@ impl Frontend for TuiFrontend {
         let mut experience_widgets = std::mem::take(&mut self.widget_manager.experience_widgets);
         let mut gs4_experience_widgets = std::mem::take(&mut self.widget_manager.gs4_experience_widgets);
         let mut encumbrance_widgets = std::mem::take(&mut self.widget_manager.encumbrance_widgets);
+        let mut stance_widgets = std::mem::take(&mut self.widget_manager.stance_widgets);
+        let mut wealth_widgets = std::mem::take(&mut self.widget_manager.wealth_widgets);
+        let mut task_tracker_widgets =
+            std::mem::take(&mut self.widget_manager.task_tracker_widgets);
+        let mut society_tasks_widgets =
+            std::mem::take(&mut self.widget_manager.society_tasks_widgets);
+        let mut combat_stats_widgets =
+            std::mem::take(&mut self.widget_manager.combat_stats_widgets);
+        let mut calendar_widgets = std::mem::take(&mut self.widget_manager.calendar_widgets);
+        let mut reminders_widgets = std::mem::take(&mut self.widget_manager.reminders_widgets);
+        let mut notes_widgets = std::mem::take(&mut self.widget_manager.notes_widgets);
+        let mut watch_widgets = std::mem::take(&mut self.widget_manager.watch_widgets);
+        let mut traffic_widgets = std::mem::take(&mut self.widget_manager.traffic_widgets);
+        let mut played_widgets = std::mem::take(&mut self.widget_manager.played_widgets);
         let mut minivitals_widgets = std::mem::take(&mut self.widget_manager.minivitals_widgets);
         let mut betrayer_widgets = std::mem::take(&mut self.widget_manager.betrayer_widgets);
 
@@ -161,6 +257,22 @@ impl Frontend for TuiFrontend {
 
         let render_start = Instant::now();
 
+        // Reduced motion: stop the terminal cursor blinking (restored when
+        // the setting is turned off and on exit)
+        let steady = !app_core.config.ui.animations_enabled();
+        if steady != self.steady_cursor {
+            self.steady_cursor = steady;
+            let style = if steady {
+                crossterm::cursor::SetCursorStyle::SteadyBlock
+            } else {
+                crossterm::cursor::SetCursorStyle::DefaultUserShape
+            };
+            let _ = execute!(self.terminal.backend_mut(), style);
+        }
+
+        text_width::set_emoji_width(app_core.config.ui.emoji_width);
+        crate::core::locale::set_locale(app_core.config.ui.locale);
+
         // Refresh the cached render order (no-op unless the window set changed)
         self.window_order_cache.refresh(&app_core.ui_state);
         let order_cache = &self.window_order_cache;
@@ -173,15 +285,45 @@ impl Frontend for TuiFrontend {
             let theme = theme_for_render.clone();
             let screen_area = f.area();
 
+            // Boss key: nothing but a neutral prompt
+            if app_core.ui_state.boss_mode {
+                let prompt = app_core.config.ui.boss_key_prompt.as_str();
+                f.render_widget(ratatui::widgets::Clear, screen_area);
+                f.render_widget(ratatui::widgets::Paragraph::new(prompt), screen_area);
+                let cursor_x =
+                    (text_width::width(prompt) as u16).min(screen_area.width.saturating_sub(1));
+                f.set_cursor_position((screen_area.x + cursor_x, screen_area.y));
+                return;
+            }
+
             // Cached render order and z-index map (see WindowOrderCache)
             let window_index_map = &order_cache.render_index;
 
+            // Compact mode replaces the whole layout with one tabbed view;
+            // popups, menus, and editors below still draw over it
+            let compact = app_core.ui_state.compact_mode;
+            if compact {
+                super::compact::render_compact(
+                    screen_area,
+                    f.buffer_mut(),
+                    app_core,
+                    &mut text_windows,
+                    &command_inputs,
+                    &theme,
+                );
+            }
+            let render_order: &[String] = if compact {
+                &[]
+            } else {
+                &order_cache.render_order
+            };
+
             // Render each window at its position
-            for name in &order_cache.render_order {
+            for name in render_order {
                 let Some(window) = app_core.ui_state.windows.get(name) else {
                     continue;
                 };
-                if !window.visible {
+                if !window.visible || app_core.ui_state.is_window_suppressed(name) {
                     continue;
                 }
 
@@ -198,6 +340,11 @@ impl Frontend for TuiFrontend {
                     continue;
                 }
 
+                // Floating windows overlap tiled ones - wipe what's underneath first
+                if app_core.ui_state.is_floating(name) {
+                    f.render_widget(ratatui::widgets::Clear, area);
+                }
+
                 match &window.content {
                     WindowContent::Text(_) => {
                         // Use the TextWindow widget for proper text rendering with wrapping, scrolling, etc.
@@ -262,9 +409,18 @@ impl Frontend for TuiFrontend {
                                 );
                             }
                         } else {
-                            // Normal mode - render command input
+                            // Normal mode - render command input, with
+                            // `.repeat` progress in the title
                             if let Some(cmd_input) = command_inputs.get(name) {
-                                cmd_input.render(area, f.buffer_mut());
+                                let repeat = app_core
+                                    .repeat
+                                    .label()
+                                    .map(|label| format!("repeat {}", label));
+                                cmd_input.render_with_status(
+                                    area,
+                                    f.buffer_mut(),
+                                    repeat.as_deref(),
+                                );
                             } else {
                                 tracing::error!(
                                     "CommandInput widget '{}' doesn't exist during render!",
@@ -273,14 +429,15 @@ impl Frontend for TuiFrontend {
                                 // Render error message
                                 let block = Block::default()
                                     .title("Command (ERROR: widget not initialized)")
-                                    .borders(Borders::ALL);
+                                    .borders(Borders::ALL)
+                                    .border_set(super::border_glyphs::popup_set());
                                 f.render_widget(block, area);
                             }
                         }
                     }
                     WindowContent::Quickbar => {
                         if let Some(quickbar_widget) = quickbar_widgets.get_mut(name) {
-                            let focused = app_core.ui_state.focused_window.as_ref() == Some(name);
+                            let focused = app_core.ui_state.input_focus.as_ref() == Some(name);
                             quickbar_widget.render(area, f.buffer_mut(), focused);
                         }
                     }
@@ -446,6 +603,61 @@ impl Frontend for TuiFrontend {
                             enc_widget.render(area, f.buffer_mut());
                         }
                     }
+                    WindowContent::Stance => {
+                        if let Some(stance_widget) = stance_widgets.get_mut(name) {
+                            stance_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Wealth => {
+                        if let Some(wealth_widget) = wealth_widgets.get_mut(name) {
+                            wealth_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::TaskTracker => {
+                        if let Some(tracker_widget) = task_tracker_widgets.get_mut(name) {
+                            tracker_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::SocietyTasks => {
+                        if let Some(society_widget) = society_tasks_widgets.get_mut(name) {
+                            society_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::CombatStats => {
+                        if let Some(combat_widget) = combat_stats_widgets.get_mut(name) {
+                            combat_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Calendar => {
+                        if let Some(calendar_widget) = calendar_widgets.get_mut(name) {
+                            calendar_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Reminders => {
+                        if let Some(reminders_widget) = reminders_widgets.get_mut(name) {
+                            reminders_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Notes => {
+                        if let Some(notes_widget) = notes_widgets.get_mut(name) {
+                            notes_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Watch => {
+                        if let Some(watch_widget) = watch_widgets.get_mut(name) {
+                            watch_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Traffic => {
+                        if let Some(traffic_widget) = traffic_widgets.get_mut(name) {
+                            traffic_widget.render(area, f.buffer_mut());
+                        }
+                    }
+                    WindowContent::Played => {
+                        if let Some(played_widget) = played_widgets.get_mut(name) {
+                            played_widget.render(area, f.buffer_mut());
+                        }
+                    }
                     WindowContent::MiniVitals => {
                         if let Some(mv_widget) = minivitals_widgets.get_mut(name) {
                             mv_widget.render(area, f.buffer_mut());
@@ -469,6 +681,46 @@ impl Frontend for TuiFrontend {
                 }
             }
 
+            // Tab-completion candidates and spelling suggestions float over
+            // whatever sits above the input
+            if app_core.ui_state.input_mode != crate::data::ui_state::InputMode::Search {
+                let input_area = if compact {
+                    Some(
+                        super::compact::CompactRegions::new(
+                            screen_area,
+                            super::compact::compact_input_height(app_core),
+                        )
+                        .input,
+                    )
+                } else {
+                    app_core
+                        .ui_state
+                        .windows
+                        .get("command_input")
+                        .map(|window| Rect {
+                            x: window.position.x,
+                            y: window.position.y,
+                            width: window.position.width,
+                            height: window.position.height,
+                        })
+                };
+                if let (Some(input_area), Some(cmd_input)) =
+                    (input_area, command_inputs.get("command_input"))
+                {
+                    cmd_input.render_completion_popup(
+                        input_area,
+                        screen_area,
+                        f.buffer_mut(),
+                        &theme,
+                    );
+                }
+                if let (Some(input_area), Some(spell_suggest)) =
+                    (input_area, self.spell_suggest.as_ref())
+                {
+                    spell_suggest.render(input_area, screen_area, f.buffer_mut(), &theme);
+                }
+            }
+
             // Render popup menu if active
             if let Some(ref popup_menu) = app_core.ui_state.popup_menu {
                 // Convert from ui_state::PopupMenu to rendering popup_menu::PopupMenu
@@ -591,6 +843,22 @@ impl Frontend for TuiFrontend {
             if let Some(ref mut settings_editor) = self.settings_editor {
                 settings_editor.render(screen_area, f.buffer_mut(), &app_core.config, &theme);
             }
+            if let Some(ref mut text_viewer) = self.text_viewer {
+                text_viewer.render(screen_area, f.buffer_mut(), &theme);
+            }
+            if let Some(ref mut compose) = self.compose {
+                compose.render(screen_area, f.buffer_mut(), &theme);
+            }
+            if let Some(ref mut notes_editor) = self.notes_editor {
+                notes_editor.render(screen_area, f.buffer_mut(), &theme);
+            }
+            if let Some(ref mut palette) = self.command_log_palette {
+                palette.render(screen_area, f.buffer_mut(), &theme);
+            }
+            if let Some(ref mut gallery) = self.layout_gallery {
+                let terminal_size = (screen_area.width, screen_area.height);
+                gallery.render(screen_area, f.buffer_mut(), &theme, terminal_size);
+            }
 
             if let Some(ref mut indicator_template_editor) = self.indicator_template_editor {
                 indicator_template_editor.render(screen_area, f.buffer_mut(), &theme);
@@ -611,6 +879,43 @@ impl Frontend for TuiFrontend {
             if let Some(ref injuries_popup) = app_core.ui_state.injuries_popup {
                 injury_doll::render_injuries_popup(injuries_popup, screen_area, f.buffer_mut(), &theme);
             }
+
+            if let Some(ref paste_guard) = app_core.ui_state.paste_guard {
+                super::paste_guard::render_paste_guard(
+                    paste_guard,
+                    screen_area,
+                    f.buffer_mut(),
+                    &theme,
+                );
+            }
+
+            if let Some(ref prompt) = app_core.ui_state.lockout_prompt {
+                super::lockout_prompt::render_lockout_prompt(
+                    prompt,
+                    screen_area,
+                    f.buffer_mut(),
+                    &theme,
+                );
+            }
+
+            // Low-health alarm: blink the screen edge over everything else
+            // (held steady under reduced motion)
+            if let Some(level) = app_core.game_state.alarm.flash(
+                std::time::Instant::now(),
+                app_core.config.ui.animations_enabled(),
+            ) {
+                super::alarm_flash::render_alarm_flash(level, screen_area, f.buffer_mut());
+            }
+
+            // Narrow emoji: drop U+FE0F so the terminal also draws the blank
+            // cell ratatui reserved after the glyph
+            if app_core.config.ui.emoji_width == crate::config::EmojiWidth::Narrow {
+                for cell in f.buffer_mut().content.iter_mut() {
+                    if let Some(symbol) = text_width::narrow_symbol(cell.symbol()) {
+                        cell.set_symbol(&symbol);
+                    }
+                }
+            }
         })?;
 
         // Feed text wrapping timings into performance stats (drain samples from all text widgets)
@@ -672,6 +977,17 @@ impl Frontend for TuiFrontend {
         self.widget_manager.experience_widgets = experience_widgets;
         self.widget_manager.gs4_experience_widgets = gs4_experience_widgets;
         self.widget_manager.encumbrance_widgets = encumbrance_widgets;
+        self.widget_manager.stance_widgets = stance_widgets;
+        self.widget_manager.wealth_widgets = wealth_widgets;
+        self.widget_manager.task_tracker_widgets = task_tracker_widgets;
+        self.widget_manager.society_tasks_widgets = society_tasks_widgets;
+        self.widget_manager.combat_stats_widgets = combat_stats_widgets;
+        self.widget_manager.calendar_widgets = calendar_widgets;
+        self.widget_manager.reminders_widgets = reminders_widgets;
+        self.widget_manager.notes_widgets = notes_widgets;
+        self.widget_manager.watch_widgets = watch_widgets;
+        self.widget_manager.traffic_widgets = traffic_widgets;
+        self.widget_manager.played_widgets = played_widgets;
         self.widget_manager.minivitals_widgets = minivitals_widgets;
         self.widget_manager.betrayer_widgets = betrayer_widgets;
 
@@ -680,10 +996,18 @@ impl Frontend for TuiFrontend {
 
     fn cleanup(&mut self) -> Result<()> {
         disable_raw_mode()?;
+        if self.steady_cursor {
+            execute!(
+                self.terminal.backend_mut(),
+                crossterm::cursor::SetCursorStyle::DefaultUserShape
+            )?;
+        }
         execute!(
             self.terminal.backend_mut(),
             LeaveAlternateScreen,
-            crossterm::event::DisableMouseCapture
+            crossterm::event::DisableMouseCapture,
+            crossterm::event::DisableBracketedPaste,
+            crossterm::event::DisableFocusChange
         )?;
         Ok(())
     }
@@ -692,8 +1016,4 @@ impl Frontend for TuiFrontend {
         let rect = self.terminal.size().unwrap_or_default();
         (rect.width, rect.height)
     }
-
-    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
-        self
-    }
 }
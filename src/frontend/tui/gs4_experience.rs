@@ -3,6 +3,7 @@
 //! Displays GS4 experience data from the `expr` dialog:
 //! - Level text (yourLvl label)
 //! - Mind state progress bar (mindState)
+//! - Time until clear, from the absorption rate (core::exp_eta)
 //! - Experience progress bar (nextLvlPB)
 //!
 //! Reads data from GameState.gs4_experience (populated from dialogData updates).
@@ -27,6 +28,7 @@ pub struct GS4Experience {
     show_border: bool,
     /// Which border sides to show
     border_sides: BorderSides,
+    border_style: Option<String>,
     /// Cached state for rendering
     level_text: String,
     mind_value: u32,
@@ -49,6 +51,10 @@ pub struct GS4Experience {
     show_exp_bar: bool,
     /// Whether to show the mind state bar
     show_mind_bar: bool,
+    /// Whether to show the time-until-clear line
+    show_eta: bool,
+    /// Time-until-clear readout; None while clear or not yet measured
+    eta: Option<String>,
     /// Whether to show the total absorbed experience line
     show_total_exp: bool,
     /// Whether to show the total ascension experience line
@@ -75,6 +81,7 @@ impl GS4Experience {
             show_title: true,
             show_border: true,
             border_sides: BorderSides::default(),
+            border_style: None,
             level_text: String::new(),
             mind_value: 0,
             mind_text: String::new(),
@@ -88,6 +95,8 @@ impl GS4Experience {
             show_level: true,
             show_exp_bar: true,
             show_mind_bar: true,
+            show_eta: false,
+            eta: None,
             show_total_exp: false,
             show_ascension_exp: false,
             exp_total: None,
@@ -121,6 +130,17 @@ impl GS4Experience {
         self.show_mind_bar = show;
     }
 
+    /// Set whether to show the time-until-clear line
+    pub fn set_show_eta(&mut self, show: bool) {
+        self.show_eta = show;
+    }
+
+    /// Set the time-until-clear readout (refreshed every sync, since it
+    /// counts down between pulses)
+    pub fn set_eta(&mut self, eta: Option<String>) {
+        self.eta = eta;
+    }
+
     /// Set whether to show the total experience line
     pub fn set_show_total_exp(&mut self, show: bool) {
         self.show_total_exp = show;
@@ -146,6 +166,11 @@ impl GS4Experience {
         self.border_sides = sides;
     }
 
+    /// Set the border glyph style (see border_glyphs)
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
     /// Set the background color (from theme)
     pub fn set_background_color(&mut self, color: Option<String>) {
         self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
@@ -181,19 +206,6 @@ impl GS4Experience {
         true
     }
 
-    /// Group digits in threes: 1234567 -> "1,234,567".
-    fn format_thousands(value: u64) -> String {
-        let digits = value.to_string();
-        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
-        for (i, ch) in digits.chars().enumerate() {
-            if i > 0 && (digits.len() - i) % 3 == 0 {
-                out.push(',');
-            }
-            out.push(ch);
-        }
-        out
-    }
-
     /// Render one plain text line at `y`, returning the next row.
     fn render_text_row(&self, inner: Rect, buf: &mut Buffer, y: u16, text: String) -> u16 {
         let line = Line::from(Span::styled(text, Style::default().fg(self.text_color)));
@@ -294,6 +306,9 @@ impl GS4Experience {
             let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
             let mut block = Block::default()
                 .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
                 .border_style(Style::default().fg(self.border_color));
             if self.show_title {
                 block = block.title(self.title.as_str());
@@ -362,6 +377,13 @@ impl GS4Experience {
             current_y += 1;
         }
 
+        // Time until clear (if enabled and the rate is known)
+        if self.show_eta && current_y < inner.y + inner.height {
+            if let Some(eta) = &self.eta {
+                current_y = self.render_text_row(inner, buf, current_y, eta.clone());
+            }
+        }
+
         // Row 3: Exp progress bar (if show_exp_bar enabled)
         if self.show_exp_bar
             && current_y < inner.y + inner.height
@@ -390,7 +412,7 @@ impl GS4Experience {
                     inner,
                     buf,
                     current_y,
-                    format!("Exp: {}", Self::format_thousands(total)),
+                    format!("Exp: {}", crate::core::locale::format_uint(total)),
                 );
             }
         }
@@ -402,7 +424,7 @@ impl GS4Experience {
                     inner,
                     buf,
                     current_y,
-                    format!("Ascension: {}", Self::format_thousands(ascension)),
+                    format!("Ascension: {}", crate::core::locale::format_uint(ascension)),
                 );
             }
         }
@@ -480,6 +502,31 @@ mod tests {
         assert!(!changed);
     }
 
+    #[test]
+    fn test_eta_row_under_mind_bar() {
+        let mut exp = GS4Experience::new("Experience", "left");
+        exp.set_show_border(false);
+        exp.set_show_level(false);
+        exp.set_show_eta(true);
+        exp.mind_text = "becoming numbed".to_string();
+        exp.next_level_text = "1000 experience".to_string();
+        exp.set_eta(Some("Clear in ~16m (50/min)".to_string()));
+
+        let area = Rect::new(0, 0, 30, 3);
+        let mut buf = Buffer::empty(area);
+        exp.render(area, &mut buf);
+        let row = |y| {
+            (0..30)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim()
+                .to_string()
+        };
+        assert_eq!(row(0), "becoming numbed");
+        assert_eq!(row(1), "Clear in ~16m (50/min)");
+        assert_eq!(row(2), "1000 experience");
+    }
+
     #[test]
     fn test_set_border_color() {
         let mut exp = GS4Experience::new("Test", "left");
@@ -7,11 +7,12 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
-    widgets::{Block, BorderType, Borders, Clear, Widget},
+    widgets::{Block, Borders, Clear, Widget},
 };
 
 use super::colors::parse_color_to_ratatui;
 use super::crossterm_bridge;
+use crate::frontend::common::text_width;
 
 /// Individual hand widget for left/right/spell hand
 /// Shows icon + text for a single hand (e.g., "L: item name")
@@ -91,12 +92,8 @@ impl Hand {
     }
 
     pub fn set_content(&mut self, content: String) {
-        // Truncate to 24 characters
-        self.content = if content.chars().count() > 24 {
-            content.chars().take(24).collect()
-        } else {
-            content
-        };
+        // Truncate to 24 columns
+        self.content = text_width::truncate(&content, 24).to_string();
     }
 
     pub fn set_link_data(&mut self, link: Option<crate::data::LinkData>) {
@@ -187,17 +184,9 @@ impl Hand {
             // Use Block widget for all other border combinations
             let mut block = Block::default().borders(borders);
 
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    "quadrant_inside" => BorderType::QuadrantInside,
-                    "quadrant_outside" => BorderType::QuadrantOutside,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             block = block.border_style(Style::default().fg(border_color));
 
@@ -231,29 +220,24 @@ impl Hand {
 
         let y = inner_area.y;
 
-        // Render icon using configurable icon field
-        for (i, ch) in self.icon.chars().enumerate() {
-            let x = inner_area.x + i as u16;
-            if x < inner_area.x + inner_area.width && x < buf.area().width && y < buf.area().height
-            {
-                buf[(x, y)].set_char(ch);
-                buf[(x, y)].set_fg(icon_color);
-                if let Some(bg_color) = fill_bg {
-                    buf[(x, y)].set_bg(bg_color);
-                }
+        // Render icon using configurable icon field, then content after
+        // icon (+ 1 space)
+        let right = (inner_area.x + inner_area.width).min(buf.area().width);
+        if y < buf.area().height {
+            let mut icon_style = Style::default().fg(icon_color);
+            let mut content_style = Style::default().fg(content_color);
+            if let Some(bg_color) = fill_bg {
+                icon_style = icon_style.bg(bg_color);
+                content_style = content_style.bg(bg_color);
             }
-        }
-
-        // Render content after icon (+ 1 space)
-        let start_col = self.icon.chars().count() as u16 + 1;
-        for (i, ch) in self.content.chars().enumerate() {
-            let x = inner_area.x + start_col + i as u16;
-            if x < inner_area.x + inner_area.width && x < buf.area().width && y < buf.area().height
-            {
-                buf[(x, y)].set_char(ch);
-                buf[(x, y)].set_fg(content_color);
-                if let Some(bg_color) = fill_bg {
-                    buf[(x, y)].set_bg(bg_color);
+            let start_col = inner_area.x + text_width::width(&self.icon) as u16 + 1;
+            for (x, text, style) in [
+                (inner_area.x, &self.icon, icon_style),
+                (start_col, &self.content, content_style),
+            ] {
+                if x < right {
+                    let room = (right - x) as usize;
+                    buf.set_stringn(x, y, text_width::truncate(text, room), room, style);
                 }
             }
         }
@@ -262,19 +246,20 @@ impl Hand {
         if only_horizontal_borders {
             let content_y = inner_area.y; // Hand widgets always render at y=0 of inner area
             if content_y < buf.area().height {
+                let set = super::border_glyphs::border_set(self.border_style.as_deref());
                 let has_left = borders.contains(ratatui::widgets::Borders::LEFT);
                 let has_right = borders.contains(ratatui::widgets::Borders::RIGHT);
 
                 // Render left border
                 if has_left && area.x < buf.area().width {
-                    buf[(area.x, content_y)].set_char('│');
+                    buf[(area.x, content_y)].set_symbol(set.vertical_left);
                     buf[(area.x, content_y)].set_fg(border_color);
                 }
                 // Render right border
                 if has_right {
                     let right_x = area.x + area.width.saturating_sub(1);
                     if right_x < buf.area().width {
-                        buf[(right_x, content_y)].set_char('│');
+                        buf[(right_x, content_y)].set_symbol(set.vertical_right);
                         buf[(right_x, content_y)].set_fg(border_color);
                     }
                 }
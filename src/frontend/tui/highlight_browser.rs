@@ -4,11 +4,12 @@
 //! wires into the `Navigable`/`Selectable` widget traits for consistent control
 //! hints.
 
+use crate::core::undo::{BrowserEdit, UndoStack};
 use crate::frontend::tui::crossterm_bridge;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier},
+    style::{Color, Modifier, Style},
     widgets::{Clear, Widget},
 };
 use std::collections::HashMap;
@@ -57,6 +58,11 @@ pub struct HighlightBrowser {
     pub is_dragging: bool,
     pub drag_offset_x: u16,
     pub drag_offset_y: u16,
+
+    /// Deletes made in this browser, newest last (Ctrl+Z restores)
+    pub undo: UndoStack<BrowserEdit>,
+    /// One-line notice shown in place of the footer until the next key
+    pub toast: Option<String>,
 }
 
 impl HighlightBrowser {
@@ -141,6 +147,8 @@ impl HighlightBrowser {
             is_dragging: false,
             drag_offset_x: 0,
             drag_offset_y: 0,
+            undo: UndoStack::new(),
+            toast: None,
         }
     }
 
@@ -667,10 +675,16 @@ impl HighlightBrowser {
         }
 
         // Footer (one line above the bottom border)
-        let footer = " Ctrl+S:Save | A:Add | E:Edit | Del:Delete | Esc:Back ";
+        let footer: String = match &self.toast {
+            Some(toast) => format!(" {} ", toast)
+                .chars()
+                .take(width.saturating_sub(2) as usize)
+                .collect(),
+            None => " Ctrl+S:Save | A:Add | E:Edit | Del:Delete | Esc:Back ".to_string(),
+        };
         // Draw footer inside the bottom border row
         let footer_y = y + height - 1;
-        let footer_x = x + 1 + ((width.saturating_sub(2) - footer.len() as u16) / 2);
+        let footer_x = x + 1 + ((width.saturating_sub(2) - footer.chars().count() as u16) / 2);
         for (i, ch) in footer.chars().enumerate() {
             buf[(footer_x + i as u16, footer_y)]
                 .set_char(ch)
@@ -680,73 +694,7 @@ impl HighlightBrowser {
     }
 
     fn draw_border(&self, area: &Rect, buf: &mut Buffer, color: Color, bg_color: Color) {
-        // Top and bottom borders
-        for x in area.x..area.x + area.width {
-            if x < buf.area.width {
-                if area.y < buf.area.height {
-                    buf[(x, area.y)]
-                        .set_char('─')
-                        .set_fg(color)
-                        .set_bg(bg_color);
-                }
-                let bottom_y = area.y + area.height - 1;
-                if bottom_y < buf.area.height {
-                    buf[(x, bottom_y)]
-                        .set_char('─')
-                        .set_fg(color)
-                        .set_bg(bg_color);
-                }
-            }
-        }
-
-        // Left and right borders
-        for y in area.y..area.y + area.height {
-            if y < buf.area.height {
-                if area.x < buf.area.width {
-                    buf[(area.x, y)]
-                        .set_char('│')
-                        .set_fg(color)
-                        .set_bg(bg_color);
-                }
-                let right_x = area.x + area.width - 1;
-                if right_x < buf.area.width {
-                    buf[(right_x, y)]
-                        .set_char('│')
-                        .set_fg(color)
-                        .set_bg(bg_color);
-                }
-            }
-        }
-
-        // Corners
-        if area.x < buf.area.width && area.y < buf.area.height {
-            buf[(area.x, area.y)]
-                .set_char('┌')
-                .set_fg(color)
-                .set_bg(bg_color);
-        }
-        let top_right_x = area.x + area.width - 1;
-        if top_right_x < buf.area.width && area.y < buf.area.height {
-            buf[(top_right_x, area.y)]
-                .set_char('┐')
-                .set_fg(color)
-                .set_bg(bg_color);
-        }
-        let bottom_left_y = area.y + area.height - 1;
-        if area.x < buf.area.width && bottom_left_y < buf.area.height {
-            buf[(area.x, bottom_left_y)]
-                .set_char('└')
-                .set_fg(color)
-                .set_bg(bg_color);
-        }
-        let bottom_right_x = area.x + area.width - 1;
-        let bottom_right_y = area.y + area.height - 1;
-        if bottom_right_x < buf.area.width && bottom_right_y < buf.area.height {
-            buf[(bottom_right_x, bottom_right_y)]
-                .set_char('┘')
-                .set_fg(color)
-                .set_bg(bg_color);
-        }
+        super::border_glyphs::draw_box(buf, *area, Style::default().fg(color).bg(bg_color));
     }
 
     fn parse_hex_color(hex: &str) -> Option<Color> {
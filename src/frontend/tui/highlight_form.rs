@@ -3,6 +3,7 @@
 //! Mirrors the VellumFE workflow: regex pattern entry, optional colors/sounds,
 //! and checkbox flags for rendering behavior.
 
+use super::textarea_bridge::GraphemeInput;
 use crate::config::{Config, HighlightPattern};
 use crate::frontend::tui::crossterm_bridge;
 use ratatui::{
@@ -12,12 +13,15 @@ use ratatui::{
     widgets::{Clear, Widget},
 };
 use regex::Regex;
-use tui_textarea::TextArea;
+use tui_textarea::{CursorMove, TextArea};
 
 // Keep popup geometry in one place so dragging + rendering stay in sync
 const POPUP_WIDTH: u16 = 70;
 const POPUP_HEIGHT: u16 = 23;
 
+/// Window filter hint: a comma-separated list of window/tab names
+const WINDOW_FILTER_PLACEHOLDER: &str = "windows/tabs, comma-separated (←/→ picks)";
+
 /// Actions that can result from mouse interaction with the highlight form
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum HighlightFormMouseAction {
@@ -84,10 +88,14 @@ pub struct HighlightFormWidget {
     // Redirect dropdown (Off=0, Copy=1, Redirect-only=2)
     redirect_mode_index: usize,
 
-    // Filter fields: restrict the highlight to one stream and/or window
+    // Filter fields: restrict the highlight to one stream and/or windows/tabs
     stream_filter: TextArea<'static>,
     window_filter: TextArea<'static>,
 
+    // Window picker for the window filter (index 0 = "" = every window)
+    window_choices: Vec<String>,
+    window_choice_index: usize,
+
     // Scope (Global vs Character)
     is_global: bool, // true = save to global/, false = save to character profile
 
@@ -175,7 +183,7 @@ impl HighlightFormWidget {
 
         let mut window_filter = TextArea::default();
         window_filter.set_cursor_line_style(Style::default());
-        window_filter.set_placeholder_text("only this window (optional)");
+        window_filter.set_placeholder_text(WINDOW_FILTER_PLACEHOLDER);
 
         Self {
             name,
@@ -201,6 +209,8 @@ impl HighlightFormWidget {
             redirect_mode_index: 0, // Default to "Off"
             stream_filter,
             window_filter,
+            window_choices: vec![String::new()],
+            window_choice_index: 0,
             is_global: true,        // Default to global scope
             popup_x: 0,
             popup_y: 0,
@@ -323,6 +333,55 @@ impl HighlightFormWidget {
         };
     }
 
+    /// Window and tab names a highlight can be scoped to
+    pub fn scope_names(ui_state: &crate::data::UiState) -> Vec<String> {
+        let mut names = Vec::new();
+        for (name, window) in &ui_state.windows {
+            match &window.content {
+                crate::data::WindowContent::Text(_) => names.push(name.clone()),
+                crate::data::WindowContent::TabbedText(tabbed) => {
+                    names.push(name.clone());
+                    names.extend(tabbed.tabs.iter().map(|t| t.definition.name.clone()));
+                }
+                _ => {}
+            }
+        }
+        names
+    }
+
+    /// Offer these window/tab names in the window filter's Left/Right picker
+    pub fn set_window_choices(&mut self, mut names: Vec<String>) {
+        names.sort_by_key(|n| n.to_lowercase());
+        names.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        names.insert(0, String::new());
+        self.window_choices = names;
+        self.window_choice_index = 0;
+    }
+
+    /// Step the window picker and put the choice in the window filter
+    fn cycle_window_choice(&mut self, forward: bool) {
+        let len = self.window_choices.len();
+        if len <= 1 {
+            return;
+        }
+        self.window_choice_index = if forward {
+            (self.window_choice_index + 1) % len
+        } else {
+            (self.window_choice_index + len - 1) % len
+        };
+        let choice = self.window_choices[self.window_choice_index].clone();
+        self.window_filter = if choice.is_empty() {
+            let mut area = TextArea::default();
+            area.set_placeholder_text(WINDOW_FILTER_PLACEHOLDER);
+            area
+        } else {
+            TextArea::from([choice])
+        };
+        self.window_filter.set_cursor_line_style(Style::default());
+        // Cursor at the end so ",<name>" can extend the pick
+        self.window_filter.move_cursor(CursorMove::End);
+    }
+
     /// Update sound field from current sound_file_index
     fn update_sound_from_index(&mut self) {
         if self.sound_files.is_empty() {
@@ -362,19 +421,21 @@ impl HighlightFormWidget {
                 let rt_key = crate::frontend::tui::textarea_bridge::to_textarea_event(key);
 
                 let handled = match self.focused_field {
-                    0 => self.name.input(rt_key),
+                    0 => self.name.input_graphemes(rt_key),
                     1 => {
-                        let result = self.pattern.input(rt_key);
+                        let result = self.pattern.input_graphemes(rt_key);
                         self.validate_pattern();
                         result
                     }
-                    2 => self.category.input(rt_key),
-                    3 => self.fg_color.input(rt_key),
-                    4 => self.bg_color.input(rt_key),
-                    5 => self.sound.input(rt_key),
-                    6 => self.sound_volume.input(rt_key),
-                    7 => self.replace.input(rt_key),
-                    8 => self.redirect_to.input(rt_key),
+                    2 => self.category.input_graphemes(rt_key),
+                    3 => self.fg_color.input_graphemes(rt_key),
+                    4 => self.bg_color.input_graphemes(rt_key),
+                    5 => self.sound.input_graphemes(rt_key),
+                    6 => self.sound_volume.input_graphemes(rt_key),
+                    7 => self.replace.input_graphemes(rt_key),
+                    8 => self.redirect_to.input_graphemes(rt_key),
+                    17 => self.stream_filter.input_graphemes(rt_key),
+                    18 => self.window_filter.input_graphemes(rt_key),
                     _ => false,
                 };
 
@@ -419,6 +480,8 @@ impl HighlightFormWidget {
                     if self.redirect_mode_index > 0 {
                         self.redirect_mode_index -= 1;
                     }
+                } else if self.focused_field == 18 {
+                    self.cycle_window_choice(false);
                 }
                 None
             }
@@ -437,6 +500,8 @@ impl HighlightFormWidget {
                     if self.redirect_mode_index < 2 {
                         self.redirect_mode_index += 1;
                     }
+                } else if self.focused_field == 18 {
+                    self.cycle_window_choice(true);
                 }
                 None
             }
@@ -687,11 +752,16 @@ impl HighlightFormWidget {
         self.render_fields(x, y, width, height, buf, config, theme);
 
         // Footer (matches mockup)
-        let mut footer = "└─[Ctrl+S: Save]─[Esc: Back]".to_string();
+        let set = super::border_glyphs::popup_set();
+        let line = set.horizontal_bottom;
+        let mut footer = format!(
+            "{}{}[Ctrl+S: Save]{}[Esc: Back]",
+            set.bottom_left, line, line
+        );
         let footer_len = footer.chars().count() as u16;
         let fill_len = width.saturating_sub(footer_len + 1); // leave room for closing corner
-        footer.push_str(&"─".repeat(fill_len as usize));
-        footer.push('┘');
+        footer.push_str(&line.repeat(fill_len as usize));
+        footer.push_str(set.bottom_right);
         let footer_y = y + height - 1;
         let footer_x = x;
         for (i, ch) in footer.chars().enumerate() {
@@ -717,54 +787,7 @@ impl HighlightFormWidget {
     ) {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.form_label));
-        let buf_width = buf.area().width;
-        let buf_height = buf.area().height;
-
-        // Top border
-        if x < buf_width && y < buf_height {
-            buf[(x, y)].set_char('┌').set_style(border_style);
-        }
-        for col in 1..width - 1 {
-            if x + col < buf_width && y < buf_height {
-                buf[(x + col, y)].set_char('─').set_style(border_style);
-            }
-        }
-        if x + width - 1 < buf_width && y < buf_height {
-            buf[(x + width - 1, y)]
-                .set_char('┐')
-                .set_style(border_style);
-        }
-
-        // Side borders
-        for row in 1..height - 1 {
-            if x < buf_width && y + row < buf_height {
-                buf[(x, y + row)].set_char('│').set_style(border_style);
-            }
-            if x + width - 1 < buf_width && y + row < buf_height {
-                buf[(x + width - 1, y + row)]
-                    .set_char('│')
-                    .set_style(border_style);
-            }
-        }
-
-        // Bottom border
-        if x < buf_width && y + height - 1 < buf_height {
-            buf[(x, y + height - 1)]
-                .set_char('└')
-                .set_style(border_style);
-        }
-        for col in 1..width - 1 {
-            if x + col < buf_width && y + height - 1 < buf_height {
-                buf[(x + col, y + height - 1)]
-                    .set_char('─')
-                    .set_style(border_style);
-            }
-        }
-        if x + width - 1 < buf_width && y + height - 1 < buf_height {
-            buf[(x + width - 1, y + height - 1)]
-                .set_char('┘')
-                .set_style(border_style);
-        }
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, width, height), border_style);
     }
 
     /// Render all form fields
@@ -1317,7 +1340,7 @@ impl HighlightFormWidget {
         }
         current_y += 1;
 
-        // Fields 17-18: optional stream/window filters
+        // Fields 17-18: optional stream/window filters (Left/Right picks a window)
         Self::render_text_row(
             focused_field,
             17,
@@ -1919,6 +1942,37 @@ mod tests {
         assert_eq!(pattern.window.as_deref(), Some("combat_win"));
     }
 
+    #[test]
+    fn window_picker_cycles_choices_and_typing_reaches_filter() {
+        use crate::core::menu_actions::MenuAction;
+
+        let mut form = HighlightFormWidget::new();
+        form.name = TextArea::from(["combat_hl"]);
+        form.pattern = TextArea::from(["You swing"]);
+        form.set_window_choices(vec!["main".into(), "Combat".into(), "main".into()]);
+        form.focused_field = 18;
+
+        form.handle_action(MenuAction::CycleForward);
+        assert_eq!(form.window_filter.lines()[0], "Combat");
+        form.handle_action(MenuAction::CycleForward);
+        assert_eq!(form.window_filter.lines()[0], "main");
+        // Wraps back to "every window"
+        form.handle_action(MenuAction::CycleForward);
+        assert_eq!(form.window_filter.lines()[0], "");
+
+        form.handle_action(MenuAction::CycleBackward);
+        for c in [',', 'x'] {
+            form.handle_key(crossterm::event::KeyEvent::new(
+                crossterm::event::KeyCode::Char(c),
+                crossterm::event::KeyModifiers::NONE,
+            ));
+        }
+        let Some(FormResult::Save { pattern, .. }) = form.save_internal() else {
+            panic!("expected Save result");
+        };
+        assert_eq!(pattern.window.as_deref(), Some("main,x"));
+    }
+
     #[test]
     fn edit_round_trip_preserves_redirect_mode() {
         // RedirectOnly in, RedirectOnly out (the old index mapping was
@@ -7,6 +7,7 @@
 //! here as a count. Saves are returned to the input layer as results so
 //! it can write via Config::save_hotbar and reload_hotbars().
 
+use super::textarea_bridge::GraphemeInput;
 use crate::config::{
     Config, EffectCategory, HotbarButton, HotbarCountdownSource, HotbarDef, NameMatch,
 };
@@ -300,7 +301,7 @@ impl HotbarEditor {
                 }
                 _ => {
                     let rt_key = crate::frontend::tui::textarea_bridge::to_textarea_event(key);
-                    self.new_bar_name.input(rt_key);
+                    self.new_bar_name.input_graphemes(rt_key);
                 }
             }
             return HotbarEditorResult::None;
@@ -485,7 +486,7 @@ impl HotbarEditor {
             _ => {
                 if let Some(area) = form.focused_textarea() {
                     let rt_key = crate::frontend::tui::textarea_bridge::to_textarea_event(key);
-                    area.input(rt_key);
+                    area.input_graphemes(rt_key);
                     form.error = None;
                 }
             }
@@ -885,27 +886,6 @@ impl HotbarEditor {
     ) {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_border));
-        buf[(x, y)].set_char('┌').set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y)].set_char('─').set_style(border_style);
-            buf[(x + col, y + height - 1)]
-                .set_char('─')
-                .set_style(border_style);
-        }
-        buf[(x + width - 1, y)]
-            .set_char('┐')
-            .set_style(border_style);
-        for row in 1..height - 1 {
-            buf[(x, y + row)].set_char('│').set_style(border_style);
-            buf[(x + width - 1, y + row)]
-                .set_char('│')
-                .set_style(border_style);
-        }
-        buf[(x, y + height - 1)]
-            .set_char('└')
-            .set_style(border_style);
-        buf[(x + width - 1, y + height - 1)]
-            .set_char('┘')
-            .set_style(border_style);
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, width, height), border_style);
     }
 }
@@ -3,11 +3,12 @@
 //! via the same hit-testing scheme as the quickbar.
 
 use crate::core::hotbar::ResolvedHotbarButton;
+use crate::frontend::common::text_width;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Clear, Widget as RatatuiWidget},
+    widgets::{Block, Borders, Clear, Widget as RatatuiWidget},
 };
 
 #[derive(Clone, Debug)]
@@ -175,7 +176,7 @@ impl HotkeyBar {
                 if row >= inner_height {
                     break;
                 }
-                let len = (Self::button_text(button).chars().count() as u16).min(inner_width);
+                let len = (text_width::width(&Self::button_text(button)) as u16).min(inner_width);
                 items.push(RenderedItem {
                     start: 0,
                     end: len,
@@ -189,7 +190,7 @@ impl HotkeyBar {
         let mut cursor = 0u16;
         let mut row = 0u16;
         for (idx, button) in self.buttons.iter().enumerate() {
-            let len = Self::button_text(button).chars().count() as u16;
+            let len = text_width::width(&Self::button_text(button)) as u16;
             if len == 0 || len > inner_width {
                 continue;
             }
@@ -235,18 +236,11 @@ impl HotkeyBar {
     }
 
     fn render_text(&self, buf: &mut Buffer, x: u16, y: u16, text: &str, style: Style, max_x: u16) {
-        let mut cursor = x;
-        for ch in text.chars() {
-            if cursor >= max_x {
-                break;
-            }
-            if cursor < buf.area().width && y < buf.area().height {
-                buf[(cursor, y)]
-                    .set_symbol(ch.encode_utf8(&mut [0; 4]))
-                    .set_style(style);
-            }
-            cursor += 1;
+        if x >= max_x.min(buf.area().width) || y >= buf.area().height {
+            return;
         }
+        let room = (max_x.min(buf.area().width) - x) as usize;
+        buf.set_stringn(x, y, text_width::truncate(text, room), room, style);
     }
 
     fn inner_rect(&self, area: Rect) -> Rect {
@@ -277,15 +271,9 @@ impl HotkeyBar {
             block = block
                 .borders(borders)
                 .border_style(Style::default().fg(border_color));
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
             if !self.title.is_empty() {
                 block = block.title(self.title.as_str());
             }
@@ -6,12 +6,13 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, BorderType, Widget},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Widget},
 };
 
 use super::colors::parse_color_to_ratatui;
 use super::crossterm_bridge;
+use crate::frontend::common::text_width;
 
 /// Indicator widget for displaying boolean status (on/off)
 /// Used for status indicators like "standing", "kneeling", "sitting", etc.
@@ -26,6 +27,8 @@ pub struct Indicator {
     on_color: String,  // Color when active
     background_color: Option<String>,
     transparent_background: bool,
+    shape_cues: bool,   // Theme shape cues: bold label with a leading marker
+    ascii_marker: bool, // Screen-reader mode: "*" instead of "●"
 }
 
 impl Indicator {
@@ -41,6 +44,8 @@ impl Indicator {
             on_color: "#00ff00".to_string(),  // Green when on
             background_color: None,
             transparent_background: false,
+            shape_cues: false,
+            ascii_marker: false,
         }
     }
 
@@ -68,6 +73,14 @@ impl Indicator {
         self.active = active;
     }
 
+    pub fn set_shape_cues(&mut self, shape_cues: bool) {
+        self.shape_cues = shape_cues;
+    }
+
+    pub fn set_ascii_marker(&mut self, ascii_marker: bool) {
+        self.ascii_marker = ascii_marker;
+    }
+
     /// Set custom colors for off and on states
     pub fn set_colors(&mut self, off_color: String, on_color: String) {
         self.off_color = off_color;
@@ -115,17 +128,9 @@ impl Indicator {
             // Use Block widget for borders
             let mut block = Block::default().borders(borders);
 
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    "quadrant_inside" => BorderType::QuadrantInside,
-                    "quadrant_outside" => BorderType::QuadrantOutside,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             block = block.border_style(Style::default().fg(border_color));
 
@@ -169,31 +174,48 @@ impl Indicator {
         // Get color for active state
         let color = Self::parse_color(&self.on_color);
 
-        // Render the label text with appropriate color
-        let display_text = &self.label;
+        // Render the label text with appropriate color. Shape cues add a
+        // marker (when it fits) and bold so "on" isn't signalled by color alone.
+        let marker = if self.ascii_marker { '*' } else { '●' };
+        let marked = format!("{} {}", marker, self.label);
+        let display_text =
+            if self.shape_cues && text_width::width(&marked) <= inner_area.width as usize {
+                &marked
+            } else {
+                &self.label
+            };
+        let modifier = if self.shape_cues {
+            Modifier::BOLD
+        } else {
+            Modifier::empty()
+        };
 
         // Center the text in the available space
-        let text_width = display_text.chars().count() as u16;
-        let start_col = if text_width <= inner_area.width {
-            inner_area.x + (inner_area.width - text_width) / 2
+        let label_width = text_width::width(display_text) as u16;
+        let start_col = if label_width <= inner_area.width {
+            inner_area.x + (inner_area.width - label_width) / 2
         } else {
             inner_area.x
         };
 
-        // Render each character of the label (vertically centered)
+        // Render the label by grapheme so icons keep their full width
         let y = inner_area.y;
         if y < buf.area().height {
-            for (i, c) in display_text.chars().enumerate() {
-                let x = start_col + i as u16;
-                if x < inner_area.x + inner_area.width && x < buf.area().width {
-                    buf[(x, y)].set_char(c);
-                    buf[(x, y)].set_fg(color);
-                    // Set background if not transparent and color is configured
-                    if !self.transparent_background {
-                        if let Some(ref color_hex) = self.background_color {
-                            let bg_color = Self::parse_color(color_hex);
-                            buf[(x, y)].set_bg(bg_color);
-                        }
+            let room = (inner_area.x + inner_area.width).saturating_sub(start_col);
+            let visible = text_width::truncate(display_text, room as usize);
+            let (end_x, _) = buf.set_stringn(
+                start_col,
+                y,
+                visible,
+                room as usize,
+                Style::default().fg(color).add_modifier(modifier),
+            );
+            // Set background if not transparent and color is configured
+            if !self.transparent_background {
+                if let Some(ref color_hex) = self.background_color {
+                    let bg_color = Self::parse_color(color_hex);
+                    for x in start_col..end_x {
+                        buf[(x, y)].set_bg(bg_color);
                     }
                 }
             }
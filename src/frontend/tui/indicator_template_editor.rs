@@ -1,3 +1,4 @@
+use super::textarea_bridge::GraphemeInput;
 use crate::config::{Config, IndicatorTemplateEntry, IndicatorTemplateStore};
 use crate::data::input::{KeyCode, KeyModifiers};
 use crate::theme::AppTheme;
@@ -192,7 +193,7 @@ impl IndicatorTemplateEditor {
                     let ct_mods = crate::frontend::tui::crossterm_bridge::to_crossterm_modifiers(modifiers);
                     let key_event = crossterm::event::KeyEvent::new(ct_code, ct_mods);
                     let input = crate::frontend::tui::textarea_bridge::to_textarea_event(key_event);
-                    let _ = self.current_field().input(input);
+                    let _ = self.current_field().input_graphemes(input);
                 }
             },
         }
@@ -426,13 +427,14 @@ impl IndicatorTemplateEditor {
         let footer_fg = border_color;
         if footer_y < buf.area().height && width >= 2 {
             let content_width = width.saturating_sub(2) as usize;
+            let set = super::border_glyphs::popup_set();
             let mut inner = footer.to_string();
             if inner.len() < content_width {
-                inner.push_str(&"─".repeat(content_width - inner.len()));
+                inner.push_str(&set.horizontal_bottom.repeat(content_width - inner.len()));
             } else {
                 inner.truncate(content_width);
             }
-            let line = format!("└{}┘", inner);
+            let line = format!("{}{}{}", set.bottom_left, inner, set.bottom_right);
             buf.set_string(x, footer_y, line, Style::default().fg(footer_fg));
         }
 
@@ -607,40 +609,7 @@ impl IndicatorTemplateEditor {
     }
 
     fn draw_border(&self, area: &Rect, buf: &mut Buffer, color: ratatui::style::Color, bg: ratatui::style::Color) {
-        let Rect { x, y, width, height } = *area;
-        if width < 2 || height < 2 {
-            return;
-        }
-        for dx in 1..width - 1 {
-            buf[(x + dx, y)]
-                .set_char('─')
-                .set_fg(color)
-                .set_bg(bg);
-            buf[(x + dx, y + height - 1)]
-                .set_char('─')
-                .set_fg(color)
-                .set_bg(bg);
-        }
-        for dy in 1..height - 1 {
-            buf[(x, y + dy)]
-                .set_char('│')
-                .set_fg(color)
-                .set_bg(bg);
-            buf[(x + width - 1, y + dy)]
-                .set_char('│')
-                .set_fg(color)
-                .set_bg(bg);
-        }
-        buf[(x, y)].set_char('┌').set_fg(color).set_bg(bg);
-        buf[(x + width - 1, y)].set_char('┐').set_fg(color).set_bg(bg);
-        buf[(x, y + height - 1)]
-            .set_char('└')
-            .set_fg(color)
-            .set_bg(bg);
-        buf[(x + width - 1, y + height - 1)]
-            .set_char('┘')
-            .set_fg(color)
-            .set_bg(bg);
+        super::border_glyphs::draw_box(buf, *area, Style::default().fg(color).bg(bg));
     }
 
     fn parse_icon_char(value: &str) -> Option<char> {
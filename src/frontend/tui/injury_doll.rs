@@ -6,8 +6,8 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, BorderType, Clear, Widget as RatatuiWidget},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Clear, Widget as RatatuiWidget},
 };
 use std::collections::HashMap;
 
@@ -35,6 +35,8 @@ pub struct InjuryDoll {
     background_color: Option<Color>,
     content_align: Option<String>,
     transparent_background: bool,
+    // Theme shape cues: rank digits/text styles so levels aren't color-only
+    shape_cues: bool,
 }
 
 impl InjuryDoll {
@@ -58,6 +60,7 @@ impl InjuryDoll {
             background_color: None,
             content_align: None,
             transparent_background: false, // Default to transparent
+            shape_cues: false,
         }
     }
 
@@ -122,6 +125,24 @@ impl InjuryDoll {
         }
     }
 
+    pub fn set_shape_cues(&mut self, shape_cues: bool) {
+        self.shape_cues = shape_cues;
+    }
+
+    /// Shape cue for a body part: with cues on, a wound or scar shows its
+    /// rank digit in place of the body glyph, wounds bold and scars
+    /// underlined.
+    fn level_cue(&self, body_part: &str) -> Option<(char, Modifier)> {
+        if !self.shape_cues {
+            return None;
+        }
+        match self.injuries.get(body_part).copied().unwrap_or(0) {
+            level @ 1..=3 => Some((char::from(b'0' + level), Modifier::BOLD)),
+            level @ 4..=6 => Some((char::from(b'0' + level - 3), Modifier::UNDERLINED)),
+            _ => None,
+        }
+    }
+
     fn get_injury_color(&self, body_part: &str) -> Color {
         let level = self.injuries.get(body_part).copied().unwrap_or(0);
         let color_hex = &self.colors[level as usize];
@@ -147,17 +168,9 @@ impl InjuryDoll {
             let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
             block = block.borders(borders);
 
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    "quadrant_inside" => BorderType::QuadrantInside,
-                    "quadrant_outside" => BorderType::QuadrantOutside,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             if let Some(color) = self.border_color {
                 block = block.border_style(Style::default().fg(color));
@@ -227,7 +240,7 @@ impl InjuryDoll {
             content_height = content_height.max(row_val + 1);
         }
         for (start_col, row, text, _) in text_indicators.iter() {
-            let text_width = text.chars().count() as u16;
+            let text_width = crate::frontend::common::text_width::width(text) as u16;
             let start_col_val = *start_col;
             let row_val = *row;
             content_width = content_width.max(start_col_val + text_width);
@@ -260,7 +273,15 @@ impl InjuryDoll {
                 && y < buf.area().height
             {
                 let color = self.get_injury_color(body_part);
-                buf[(x, y)].set_char(*ch);
+                match self.level_cue(body_part) {
+                    Some((digit, modifier)) => {
+                        buf[(x, y)].set_char(digit);
+                        buf[(x, y)].modifier.insert(modifier);
+                    }
+                    None => {
+                        buf[(x, y)].set_char(*ch);
+                    }
+                }
                 buf[(x, y)].set_fg(color);
                 if !self.transparent_background {
                     if let Some(bg) = bg_color {
@@ -272,6 +293,7 @@ impl InjuryDoll {
 
         for (start_col, row, text, body_part) in text_indicators.iter() {
             let color = self.get_injury_color(body_part);
+            let modifier = self.level_cue(body_part).map(|(_, modifier)| modifier);
 
             for (i, ch) in text.chars().enumerate() {
                 let x = inner_area.x + start_col + i as u16 + col_offset;
@@ -287,6 +309,9 @@ impl InjuryDoll {
                 {
                     buf[(x, y)].set_char(ch);
                     buf[(x, y)].set_fg(color);
+                    if let Some(modifier) = modifier {
+                        buf[(x, y)].modifier.insert(modifier);
+                    }
                     if !self.transparent_background {
                         if let Some(bg) = bg_color {
                             buf[(x, y)].set_bg(bg);
@@ -336,7 +361,9 @@ pub fn render_injuries_popup(
     // Draw border
     let block = Block::default()
         .borders(ratatui::widgets::Borders::ALL)
-        .border_type(BorderType::Rounded)
+        .border_set(super::border_glyphs::themed(
+            ratatui::symbols::border::ROUNDED,
+        ))
         .border_style(Style::default().fg(border_color))
         .title(format!(" {}'s Injuries ", popup.player_name))
         .title_style(
@@ -374,6 +401,7 @@ pub fn render_injuries_popup(
     // Center the injury doll content
     doll.set_content_align(Some("center".to_string()));
     doll.set_transparent_background(false);
+    doll.set_shape_cues(theme.shape_cues);
     if let Some((r, g, b)) = color_to_rgb(bg_color) {
         doll.set_background_color(Some(format!("#{:02x}{:02x}{:02x}", r, g, b)));
     }
@@ -466,6 +494,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shape_cues_replace_glyphs_with_rank_digits() {
+        let mut doll = InjuryDoll::new("Test");
+        doll.set_injury("head".to_string(), 2);
+        doll.set_injury("chest".to_string(), 5);
+
+        let area = Rect::new(0, 0, 10, 6);
+        let mut buf = Buffer::empty(area);
+        doll.render(area, &mut buf);
+        assert_eq!(buf[(2, 1)].symbol(), "0");
+
+        doll.set_shape_cues(true);
+        let mut buf = Buffer::empty(area);
+        doll.render(area, &mut buf);
+        assert_eq!(buf[(2, 1)].symbol(), "2");
+        assert!(buf[(2, 1)].modifier.contains(Modifier::BOLD));
+        // Scar rank 2 (level 5), underlined
+        assert_eq!(buf[(2, 2)].symbol(), "2");
+        assert!(buf[(2, 2)].modifier.contains(Modifier::UNDERLINED));
+        // Uninjured parts keep their body glyph
+        assert_eq!(buf[(1, 2)].symbol(), "/");
+    }
+
     #[test]
     fn content_does_not_overflow_into_bottom_border() {
         let mut doll = InjuryDoll::new("Test");
@@ -25,11 +25,44 @@ fn auto_assign_slot(mut color: crate::config::PaletteColor, palette: &[crate::co
     color
 }
 
+/// Restore a browser delete popped off its undo stack; returns the toast text
+fn undo_toast(
+    app_core: &mut crate::core::AppCore,
+    edit: &crate::core::undo::BrowserEdit,
+) -> String {
+    use crate::core::undo::Undoable;
+    match app_core.undo_browser_edit(edit) {
+        Ok(()) => format!("Undid {}", edit.describe()),
+        Err(e) => {
+            tracing::error!("Failed to undo {}: {}", edit.describe(), e);
+            format!("Undo failed: {}", e)
+        }
+    }
+}
+
 /// Find the topmost window at the given screen coordinates.
-/// Ephemeral windows (container discovery) have higher z-order and are checked first.
+/// Floating windows are checked first (top of the stack down), then ephemeral
+/// windows (container discovery), then the tiled layout.
 /// Returns the window name, defaulting to "main" if no window contains the point.
 fn find_topmost_window_at(app_core: &crate::core::AppCore, x: u16, y: u16) -> String {
-    // First check ephemeral windows (they're rendered on top)
+    let ui_state = &app_core.ui_state;
+
+    // Floating windows overlap everything else
+    if !ui_state.floating_hidden {
+        for window_name in ui_state.floating_windows.iter().rev() {
+            if let Some(window) = ui_state.windows.get(window_name) {
+                if !window.visible {
+                    continue;
+                }
+                let pos = &window.position;
+                if x >= pos.x && x < pos.x + pos.width && y >= pos.y && y < pos.y + pos.height {
+                    return window_name.clone();
+                }
+            }
+        }
+    }
+
+    // Then check ephemeral windows (they're rendered above tiled windows)
     for window_name in &app_core.ui_state.ephemeral_windows {
         if let Some(window) = app_core.ui_state.windows.get(window_name) {
             if !window.visible {
@@ -44,7 +77,11 @@ fn find_topmost_window_at(app_core: &crate::core::AppCore, x: u16, y: u16) -> St
 
     // Then check regular windows
     for (name, window) in &app_core.ui_state.windows {
-        if !window.visible || app_core.ui_state.ephemeral_windows.contains(name) {
+        if !window.visible
+            || app_core.ui_state.ephemeral_windows.contains(name)
+            || ui_state.is_floating(name)
+            || ui_state.hidden_by_condition.contains(name)
+        {
             continue;
         }
         let pos = &window.position;
@@ -134,6 +171,43 @@ impl TuiFrontend {
         app_core.ui_state.input_mode = InputMode::Menu;
     }
 
+    /// Popup listing a tabbed window's hidden tabs, with unread counts
+    fn open_tab_overflow_menu(
+        &mut self,
+        app_core: &mut crate::core::AppCore,
+        window_name: &str,
+        window_rect: ratatui::layout::Rect,
+        click_pos: (u16, u16),
+    ) {
+        use crate::data::ui_state::{InputMode, PopupMenu, PopupMenuItem};
+
+        let Some(tabbed) = self.widget_manager.tabbed_text_windows.get(window_name) else {
+            return;
+        };
+        let items: Vec<PopupMenuItem> = tabbed
+            .hidden_tabs(window_rect)
+            .into_iter()
+            .map(|(index, name, unread)| PopupMenuItem {
+                text: if unread > 0 {
+                    format!("{} ({})", name, unread)
+                } else {
+                    name
+                },
+                command: format!("action:showtab:{}:{}", window_name, index),
+                disabled: false,
+            })
+            .collect();
+        if items.is_empty() {
+            return;
+        }
+
+        app_core.ui_state.popup_menu = Some(PopupMenu::new(items, click_pos));
+        app_core.ui_state.submenu = None;
+        app_core.ui_state.nested_submenu = None;
+        app_core.ui_state.deep_submenu = None;
+        app_core.ui_state.input_mode = InputMode::Menu;
+    }
+
     /// Handle mouse events (extracted from main.rs Phase 4.1)
     /// Returns (handled, optional_command)
     pub fn handle_mouse_event(
@@ -162,6 +236,36 @@ impl TuiFrontend {
             }
         }
 
+        // Boss key: the screen is blank, so nothing is clickable
+        if app_core.ui_state.boss_mode {
+            return Ok((true, None));
+        }
+
+        // Compact mode has no window geometry to hit-test against
+        if app_core.ui_state.compact_mode
+            && app_core.ui_state.popup_menu.is_none()
+            && app_core.ui_state.active_dialog.is_none()
+            && app_core.ui_state.input_mode == InputMode::Normal
+        {
+            if let Some(result) = self.handle_compact_mouse(mouse_event, app_core) {
+                return Ok(result);
+            }
+        }
+
+        // Clicking a floating window brings it to the front (menus and dialogs
+        // above have already consumed their clicks)
+        if let MouseEventKind::Down(crate::data::input::MouseButton::Left) = kind {
+            if app_core.ui_state.popup_menu.is_none()
+                && app_core.ui_state.active_dialog.is_none()
+                && !app_core.ui_state.floating_windows.is_empty()
+            {
+                let topmost_window = find_topmost_window_at(app_core, *x, *y);
+                if app_core.ui_state.raise_window(&topmost_window) {
+                    app_core.needs_render = true;
+                }
+            }
+        }
+
         // Stable window index = position in the sorted name list. A binary
         // search at the two use sites replaces the HashMap previously built
         // on every mouse event.
@@ -1073,14 +1177,18 @@ impl TuiFrontend {
             MouseEventKind::ScrollUp => {
                 // Find topmost window at mouse position (ephemeral windows have higher z-order)
                 let target_window = find_topmost_window_at(app_core, *x, *y);
-                self.scroll_window(&target_window, 10);
+                if !self.scroll_tab_bar_at(app_core, &target_window, *x, *y, -1) {
+                    self.scroll_window(&target_window, 10);
+                }
                 app_core.needs_render = true;
                 return Ok((true, None));
             }
             MouseEventKind::ScrollDown => {
                 // Find topmost window at mouse position (ephemeral windows have higher z-order)
                 let target_window = find_topmost_window_at(app_core, *x, *y);
-                self.scroll_window(&target_window, -10);
+                if !self.scroll_tab_bar_at(app_core, &target_window, *x, *y, 1) {
+                    self.scroll_window(&target_window, -10);
+                }
                 app_core.needs_render = true;
                 return Ok((true, None));
             }
@@ -1403,6 +1511,33 @@ impl TuiFrontend {
                     }
                 }
 
+                // Stance widget: left half steps toward offensive, right
+                // half toward defensive. Border cells stay free for drags.
+                if let Some(pos) = app_core
+                    .ui_state
+                    .get_window(&topmost_window)
+                    .filter(|window| window.widget_type == WidgetType::Stance)
+                    .map(|window| window.position.clone())
+                {
+                    let inside = *x > pos.x
+                        && *x + 1 < pos.x + pos.width
+                        && *y > pos.y
+                        && *y + 1 < pos.y + pos.height;
+                    if inside {
+                        let step = if *x >= pos.x + pos.width / 2 {
+                            "next"
+                        } else {
+                            "prev"
+                        };
+                        let command = app_core.send_command(format!(".stance {}", step))?;
+                        app_core.needs_render = true;
+                        if command.is_empty() {
+                            return Ok((true, None));
+                        }
+                        return Ok((true, Some(format!("{}\n", command))));
+                    }
+                }
+
                 let is_hotkeybar = app_core
                     .ui_state
                     .get_window(&topmost_window)
@@ -1432,6 +1567,15 @@ impl TuiFrontend {
                         };
                         if let Some(command) = bar_widget.handle_click(*x, *y, rect) {
                             app_core.needs_render = true;
+                            // Dot commands (.if, .set, ...) run locally
+                            let command = if command.starts_with('.') {
+                                app_core.send_command(command)?
+                            } else {
+                                app_core.config.variables.expand(&command)
+                            };
+                            if command.is_empty() || command.starts_with("action:") {
+                                return Ok((true, None));
+                            }
                             return Ok((true, Some(format!("{}\n", command))));
                         }
                     }
@@ -1440,6 +1584,7 @@ impl TuiFrontend {
                 let mut found_window = None;
                 let mut drag_op = None;
                 let mut handled_tab_click: Option<(String, usize)> = None;
+                let mut tab_overflow_click: Option<(String, Rect)> = None;
 
                 // Use topmost window for click processing (respects z-order for overlapping windows)
                 let clicked_window_name = Some(topmost_window.clone());
@@ -1473,7 +1618,15 @@ impl TuiFrontend {
                             width: pos.width,
                             height: pos.height,
                         };
-                        if let Some(new_index) =
+                        // ‹ › list the tabs scrolled out of view
+                        if self
+                            .widget_manager
+                            .tabbed_text_windows
+                            .get(name)
+                            .is_some_and(|tabbed| tabbed.overflow_indicator_at(rect, *x, *y))
+                        {
+                            tab_overflow_click = Some((name.clone(), rect));
+                        } else if let Some(new_index) =
                             self.handle_tabbed_click(name, rect, *x, *y)
                         {
                             handled_tab_click = Some((name.clone(), new_index));
@@ -1512,6 +1665,12 @@ impl TuiFrontend {
                     }
                 }
 
+                if let Some((win_name, rect)) = tab_overflow_click {
+                    self.open_tab_overflow_menu(app_core, &win_name, rect, (*x, *y));
+                    app_core.needs_render = true;
+                    return Ok((true, None));
+                }
+
                 if let Some((win_name, new_index)) = handled_tab_click {
                     // Focus the tabbed window when clicking its tabs
                     app_core.ui_state.set_focus(Some(win_name.clone()));
@@ -1522,6 +1681,7 @@ impl TuiFrontend {
                         {
                             if new_index < tabbed.tabs.len() {
                                 tabbed.active_tab_index = new_index;
+                                tabbed.clear_active_tab_unread();
                             }
                         }
                     }
@@ -1595,11 +1755,15 @@ impl TuiFrontend {
                         }
                     }
                 } else if let Some(window_name) = clicked_window_name {
-                    // Check if this window should receive focus (text/tabbedtext only)
+                    // Check if this window should receive focus (text/tabbedtext,
+                    // and widgets that take the keyboard)
                     let should_focus = app_core
                         .ui_state
                         .get_window(&window_name)
-                        .map(|w| matches!(w.widget_type, WidgetType::Text | WidgetType::TabbedText))
+                        .map(|w| {
+                            matches!(w.widget_type, WidgetType::Text | WidgetType::TabbedText)
+                                || w.widget_type.captures_input()
+                        })
                         .unwrap_or(false);
 
                     if let Some(window) = app_core.ui_state.get_window(&window_name) {
@@ -1616,6 +1780,19 @@ impl TuiFrontend {
                             window_name, *x, *y, window_rect.y, window_rect.height
                         );
 
+                        // The "N new lines" pill jumps back to the live view
+                        if self.jump_to_live_at(&window_name, *x, *y) {
+                            app_core.needs_render = true;
+                            return Ok((true, None));
+                        }
+
+                        // Spells window group headers collapse/expand
+                        if let Some(group) = self.spell_group_at(&window_name, *x, *y, window_rect)
+                        {
+                            app_core.toggle_spell_group(&window_name, &group);
+                            return Ok((true, None));
+                        }
+
                         if let Some(link_data) =
                             self.link_at_position(&window_name, *x, *y, window_rect)
                         {
@@ -1917,7 +2094,17 @@ impl TuiFrontend {
                                 "Executing <d> direct command: {}",
                                 command.trim()
                             );
-                            command_to_send = Some(command);
+                            if command.starts_with('.') {
+                                // Client-side link (e.g. the ".view last" hint) - handle locally
+                                command_to_send = self
+                                    .handle_command_submission(
+                                        command.trim_end().to_string(),
+                                        app_core,
+                                    )?
+                                    .map(|cmd| format!("{}\n", cmd));
+                            } else {
+                                command_to_send = Some(command);
+                            }
                         } else if let Some(ref coord) = pending_click.link_data.coord {
                             // Link has coord field: Look up command in cmdlist and send directly
                             if let Some(ref cmdlist) = app_core.cmdlist {
@@ -2020,6 +2207,10 @@ impl TuiFrontend {
                     }
                 }
 
+                if app_core.ui_state.mouse_drag.is_some() {
+                    // No-op unless the drag actually moved or resized the window
+                    app_core.record_layout_edit();
+                }
                 app_core.ui_state.mouse_drag = None;
                 app_core.ui_state.selection_drag_start = None;
 
@@ -2031,10 +2222,26 @@ impl TuiFrontend {
                         // Extract text from selection using the stored window name
                         let (start, end) = selection.normalized_range();
                         let window_name = &selection.window_name;
+                        // Alt on release flips the copy-with-colors setting
+                        let text = if app_core.config.ui.selection_copy_ansi != modifiers.alt {
+                            self.extract_selection_ansi(
+                                window_name,
+                                start.line,
+                                start.col,
+                                end.line,
+                                end.col,
+                            )
+                        } else {
+                            self.extract_selection_text(
+                                window_name,
+                                start.line,
+                                start.col,
+                                end.line,
+                                end.col,
+                            )
+                        };
 
-                        if let Some(text) = self.extract_selection_text(
-                            window_name, start.line, start.col, end.line, end.col,
-                        ) {
+                        if let Some(text) = text {
                             // Copy to clipboard
                             match crate::clipboard::copy(&text) {
                                 Ok(()) => {
@@ -2082,43 +2289,21 @@ impl TuiFrontend {
                     }
                 }
 
-                // Right-click: show context menu for window title bars
-                for (name, window) in &app_core.ui_state.windows {
+                // Right-click anywhere in a window: its context menu
+                let target = find_topmost_window_at(app_core, *x, *y);
+                let inside = app_core.ui_state.windows.get(&target).is_some_and(|window| {
                     let pos = &window.position;
-                    // Check if click is on the title bar (top row of window)
-                    if *y == pos.y && *x >= pos.x && *x < pos.x + pos.width {
-                        // Build context menu items
-                        let mut items = Vec::new();
-
-                        // Don't allow closing the main window
-                        if name != "main" && name != "command_input" {
-                            items.push(crate::data::ui_state::PopupMenuItem {
-                                text: "Close Window".to_string(),
-                                command: format!("__CLOSE_WINDOW__{}", name),
-                                disabled: false,
-                            });
-                        }
-
-                        items.push(crate::data::ui_state::PopupMenuItem {
-                            text: "Edit Window...".to_string(),
-                            command: format!("action:editwindow:{}", name),
-                            disabled: false,
-                        });
-
-                        items.push(crate::data::ui_state::PopupMenuItem {
-                            text: "Open Menu".to_string(),
-                            command: ".menu".to_string(),
-                            disabled: false,
-                        });
-
-                        if !items.is_empty() {
-                            // Position menu just below click point
-                            app_core.ui_state.popup_menu =
-                                Some(crate::data::ui_state::PopupMenu::new(items, (*x, *y + 1)));
-                            app_core.ui_state.input_mode = InputMode::Menu;
-                            app_core.needs_render = true;
-                            return Ok((true, None));
-                        }
+                    *x >= pos.x && *x < pos.x + pos.width && *y >= pos.y && *y < pos.y + pos.height
+                });
+                if inside {
+                    let items = app_core.build_window_context_menu(&target);
+                    if !items.is_empty() {
+                        // Position menu just below click point
+                        app_core.ui_state.popup_menu =
+                            Some(crate::data::ui_state::PopupMenu::new(items, (*x, *y + 1)));
+                        app_core.ui_state.input_mode = InputMode::Menu;
+                        app_core.needs_render = true;
+                        return Ok((true, None));
                     }
                 }
             }
@@ -2148,6 +2333,32 @@ impl TuiFrontend {
             app_core.ui_state.input_mode
         );
 
+        // Boss key works in every mode; while engaged it swallows all other keys
+        let key_event = crate::data::input::KeyEvent { code, modifiers };
+        let is_boss_key = matches!(
+            app_core.lookup_keybind(key_event),
+            Some(crate::config::KeyBindAction::Action(action)) if action == "toggle_boss_key"
+        );
+        if is_boss_key {
+            app_core.toggle_boss_mode();
+            return Ok(None);
+        }
+        if app_core.ui_state.boss_mode {
+            return Ok(None);
+        }
+
+        // Paste confirmation is modal
+        if app_core.ui_state.paste_guard.is_some() {
+            self.handle_paste_guard_key(code, app_core);
+            return Ok(None);
+        }
+
+        // So is the dangerous command lockout
+        if app_core.ui_state.lockout_prompt.is_some() {
+            self.handle_lockout_prompt_key(code, app_core);
+            return Ok(None);
+        }
+
         // Handle injuries popup (overlay that closes on Escape or any click outside)
         if app_core.ui_state.injuries_popup.is_some() {
             if code == KeyCode::Esc && modifiers == KeyModifiers::NONE {
@@ -2166,6 +2377,7 @@ impl TuiFrontend {
             }
             InputMode::HighlightBrowser => {
                 if let Some(ref mut browser) = self.highlight_browser {
+                    browser.toast = None;
                     let key_event = crate::data::input::KeyEvent { code, modifiers };
                     let action = input_router::route_input(
                         &key_event,
@@ -2215,6 +2427,9 @@ impl TuiFrontend {
                                         name, pattern,
                                     );
                                     form.set_scope(is_global);
+                                    form.set_window_choices(
+                                        crate::frontend::tui::highlight_form::HighlightFormWidget::scope_names(&app_core.ui_state),
+                                    );
                                     self.highlight_form = Some(form);
                                     app_core.ui_state.input_mode = InputMode::HighlightForm;
                                 }
@@ -2222,15 +2437,19 @@ impl TuiFrontend {
                         }
                         crate::core::menu_actions::MenuAction::New
                         | crate::core::menu_actions::MenuAction::Add => {
-                            self.highlight_form = Some(
-                                crate::frontend::tui::highlight_form::HighlightFormWidget::new(),
+                            let mut form =
+                                crate::frontend::tui::highlight_form::HighlightFormWidget::new();
+                            form.set_window_choices(
+                                crate::frontend::tui::highlight_form::HighlightFormWidget::scope_names(&app_core.ui_state),
                             );
+                            self.highlight_form = Some(form);
                             app_core.ui_state.input_mode = InputMode::HighlightForm;
                         }
                         crate::core::menu_actions::MenuAction::Delete => {
                             if let Some(name) = browser.get_selected() {
                                 // Default to global if unknown
                                 let is_global = browser.get_selected_is_global().unwrap_or(true);
+                                let deleted = app_core.config.highlights.get(&name).cloned();
                                 // Delete from appropriate file based on scope
                                 if let Err(e) = crate::config::Config::delete_single_highlight(
                                     &name,
@@ -2258,10 +2477,38 @@ impl TuiFrontend {
                                         app_core.config.character.as_deref()
                                     ).unwrap_or_default();
                                     browser.update_items_with_source(&global, &character);
+                                    if let Some(pattern) = deleted {
+                                        browser.toast = Some(format!(
+                                            "Deleted highlight '{}' ({} to undo)",
+                                            name, app_core.config.menu_keybinds.undo
+                                        ));
+                                        browser.undo.push(
+                                            crate::core::undo::BrowserEdit::DeletedHighlight {
+                                                name: name.clone(),
+                                                pattern: Box::new(pattern),
+                                                is_global,
+                                            },
+                                        );
+                                    }
                                 }
                                 tracing::info!("Deleted highlight: {} (global={})", name, is_global);
                             }
                         }
+                        crate::core::menu_actions::MenuAction::Undo => {
+                            if let Some(edit) = browser.undo.pop() {
+                                browser.toast = Some(undo_toast(app_core, &edit));
+                                let global = crate::config::Config::load_common_highlights()
+                                    .unwrap_or_default();
+                                let character =
+                                    crate::config::Config::load_character_highlights_only(
+                                        app_core.config.character.as_deref(),
+                                    )
+                                    .unwrap_or_default();
+                                browser.update_items_with_source(&global, &character);
+                            } else {
+                                browser.toast = Some("Nothing to undo".to_string());
+                            }
+                        }
                         crate::core::menu_actions::MenuAction::Cancel => {
                             self.highlight_browser = None;
                             app_core.ui_state.input_mode = InputMode::Normal;
@@ -2274,6 +2521,7 @@ impl TuiFrontend {
             }
             InputMode::KeybindBrowser => {
                 if let Some(ref mut browser) = self.keybind_browser {
+                    browser.toast = None;
                     let key_event = crate::data::input::KeyEvent { code, modifiers };
                     let action = input_router::route_input(
                         &key_event,
@@ -2299,10 +2547,11 @@ impl TuiFrontend {
                         | crate::core::menu_actions::MenuAction::Edit => {
                             if let Some(entry) = browser.get_selected_entry() {
                                 use crate::frontend::tui::keybind_form::KeybindActionType;
-                                let action_type = if entry.action_type == "Action" {
-                                    KeybindActionType::Action
-                                } else {
-                                    KeybindActionType::Macro
+                                let action_type = match entry.action_type.as_str() {
+                                    "Action" => KeybindActionType::Action,
+                                    "Sequence" => KeybindActionType::Sequence,
+                                    "Function" => KeybindActionType::Function,
+                                    _ => KeybindActionType::Macro,
                                 };
                                 self.keybind_form = Some(
                                     crate::frontend::tui::keybind_form::KeybindFormWidget::new_edit(
@@ -2324,14 +2573,27 @@ impl TuiFrontend {
                         crate::core::menu_actions::MenuAction::Delete => {
                             if let Some(entry) = browser.get_selected_entry() {
                                 let key_combo = entry.key_combo.clone();
+                                let is_global = entry.is_global;
                                 // Remove from the file the entry came from, or the
                                 // reload below immediately resurrects it in the list
                                 if let Err(e) = crate::config::Config::delete_single_keybind(
                                     &key_combo,
-                                    entry.is_global,
+                                    is_global,
                                     app_core.config.character.as_deref(),
                                 ) {
                                     tracing::error!("Failed to delete keybind '{}': {}", key_combo, e);
+                                } else if let Some(action) = app_core.config.keybinds.get(&key_combo).cloned() {
+                                    browser.toast = Some(format!(
+                                        "Deleted keybind '{}' ({} to undo)",
+                                        key_combo, app_core.config.menu_keybinds.undo
+                                    ));
+                                    browser.undo.push(
+                                        crate::core::undo::BrowserEdit::DeletedKeybind {
+                                            key: key_combo.clone(),
+                                            action,
+                                            is_global,
+                                        },
+                                    );
                                 }
                                 app_core.config.keybinds.remove(&key_combo);
                                 app_core.rebuild_keybind_map();
@@ -2345,6 +2607,24 @@ impl TuiFrontend {
                                 tracing::info!("Deleted keybind: {}", key_combo);
                             }
                         }
+                        crate::core::menu_actions::MenuAction::Undo => {
+                            if let Some(edit) = browser.undo.pop() {
+                                browser.toast = Some(undo_toast(app_core, &edit));
+                                let global_keybinds = crate::config::Config::load_common_keybinds()
+                                    .unwrap_or_default();
+                                let character_keybinds =
+                                    crate::config::Config::load_character_keybinds_only(
+                                        app_core.config.character.as_deref(),
+                                    )
+                                    .unwrap_or_default();
+                                browser.update_items_with_source(
+                                    &global_keybinds,
+                                    &character_keybinds,
+                                );
+                            } else {
+                                browser.toast = Some("Nothing to undo".to_string());
+                            }
+                        }
                         crate::core::menu_actions::MenuAction::Cancel => {
                             self.keybind_browser = None;
                             app_core.ui_state.input_mode = InputMode::Normal;
@@ -2355,6 +2635,72 @@ impl TuiFrontend {
                 }
                 return Ok(None);
             }
+            InputMode::TextViewer => {
+                if let Some(ref mut viewer) = self.text_viewer {
+                    if viewer.handle_key(code, modifiers) {
+                        self.text_viewer = None;
+                        app_core.ui_state.input_mode = InputMode::Normal;
+                    }
+                } else {
+                    app_core.ui_state.input_mode = InputMode::Normal;
+                }
+                app_core.needs_render = true;
+                return Ok(None);
+            }
+            InputMode::SpellSuggest => {
+                self.handle_spell_suggest_key(code, app_core);
+                return Ok(None);
+            }
+            InputMode::CommandLog => {
+                self.handle_command_log_key(code, modifiers, app_core);
+                return Ok(None);
+            }
+            InputMode::LayoutGallery => {
+                self.handle_layout_gallery_key(code, app_core);
+                return Ok(None);
+            }
+            InputMode::Compose => {
+                let result = match self.compose.as_mut() {
+                    Some(compose) => compose.handle_key(code, modifiers),
+                    None => super::compose::ComposeResult::Cancel,
+                };
+                match result {
+                    super::compose::ComposeResult::None => {}
+                    super::compose::ComposeResult::Send(text) => {
+                        let count = app_core.submit_compose(&text);
+                        tracing::debug!("Compose queued {} commands", count);
+                        self.compose = None;
+                        app_core.ui_state.input_mode = InputMode::Normal;
+                    }
+                    super::compose::ComposeResult::Cancel => {
+                        self.compose = None;
+                        app_core.ui_state.input_mode = InputMode::Normal;
+                    }
+                }
+                app_core.needs_render = true;
+                return Ok(None);
+            }
+            InputMode::NotesEditor => {
+                let result = match self.notes_editor.as_mut() {
+                    Some(editor) => editor.handle_key(code, modifiers),
+                    None => super::notes_editor::NotesEditorResult::Close(
+                        app_core.notes.text().to_string(),
+                    ),
+                };
+                match result {
+                    super::notes_editor::NotesEditorResult::None => {}
+                    super::notes_editor::NotesEditorResult::Save(text) => {
+                        app_core.set_notes(text);
+                    }
+                    super::notes_editor::NotesEditorResult::Close(text) => {
+                        app_core.set_notes(text);
+                        self.notes_editor = None;
+                        app_core.ui_state.input_mode = InputMode::Normal;
+                    }
+                }
+                app_core.needs_render = true;
+                return Ok(None);
+            }
             InputMode::HotbarEditor => {
                 if let Some(mut editor) = self.hotbar_editor.take() {
                     let ct_event = crossterm::event::KeyEvent::new(
@@ -2431,6 +2777,7 @@ impl TuiFrontend {
             }
             InputMode::ColorPaletteBrowser => {
                 if let Some(ref mut browser) = self.color_palette_browser {
+                    browser.toast = None;
                     let key_event = crate::data::input::KeyEvent { code, modifiers };
                     let action = input_router::route_input(
                         &key_event,
@@ -2471,6 +2818,7 @@ impl TuiFrontend {
                         crate::core::menu_actions::MenuAction::Delete => {
                             if let Some(color_name) = browser.get_selected() {
                                 let is_global = browser.get_selected_is_global().unwrap_or(true);
+                                let deleted = browser.get_selected_color().cloned();
 
                                 // Delete from appropriate file based on scope
                                 if let Err(e) = crate::config::ColorConfig::delete_single_palette_color(
@@ -2479,6 +2827,17 @@ impl TuiFrontend {
                                     app_core.config.character.as_deref(),
                                 ) {
                                     tracing::error!("Failed to delete color: {}", e);
+                                } else if let Some(color) = deleted {
+                                    browser.toast = Some(format!(
+                                        "Deleted color '{}' ({} to undo)",
+                                        color_name, app_core.config.menu_keybinds.undo
+                                    ));
+                                    browser.undo.push(
+                                        crate::core::undo::BrowserEdit::DeletedColor {
+                                            color,
+                                            is_global,
+                                        },
+                                    );
                                 }
 
                                 // Reload colors to update in-memory state
@@ -2502,6 +2861,24 @@ impl TuiFrontend {
                                 tracing::info!("Deleted color: {} ({})", color_name, if is_global { "global" } else { "character" });
                             }
                         }
+                        crate::core::menu_actions::MenuAction::Undo => {
+                            if let Some(edit) = browser.undo.pop() {
+                                browser.toast = Some(undo_toast(app_core, &edit));
+                                let global_colors =
+                                    crate::config::ColorConfig::load_common_colors()
+                                        .map(|c| c.color_palette)
+                                        .unwrap_or_default();
+                                let char_colors =
+                                    crate::config::ColorConfig::load_character_colors_only(
+                                        app_core.config.character.as_deref(),
+                                    )
+                                    .map(|c| c.color_palette)
+                                    .unwrap_or_default();
+                                browser.update_items_with_source(&global_colors, &char_colors);
+                            } else {
+                                browser.toast = Some("Nothing to undo".to_string());
+                            }
+                        }
                         crate::core::menu_actions::MenuAction::Cancel => {
                             self.color_palette_browser = None;
                             app_core.ui_state.input_mode = InputMode::Normal;
@@ -3044,6 +3421,14 @@ impl TuiFrontend {
             }
             InputMode::KeybindForm => {
                 if let Some(ref mut form) = self.keybind_form {
+                    // Capture mode records the next key verbatim, even keys
+                    // the form would otherwise treat as navigation
+                    if form.is_capturing() {
+                        form.capture_key(code, modifiers);
+                        app_core.needs_render = true;
+                        return Ok(None);
+                    }
+
                     use crate::frontend::tui::widget_traits::{
                         FieldNavigable, TextEditable, Toggleable,
                     };
@@ -3160,6 +3545,21 @@ impl TuiFrontend {
                                                     crate::config::MacroAction { macro_text: value },
                                                 )
                                             }
+                                            // The form only returns text that parses
+                                            KeybindActionType::Sequence => {
+                                                match crate::config::SequenceAction::parse(&value) {
+                                                    Ok(sequence) => {
+                                                        crate::config::KeyBindAction::Sequence(sequence)
+                                                    }
+                                                    Err(_) => return Ok(None),
+                                                }
+                                            }
+                                            KeybindActionType::Function => {
+                                                match crate::config::CallAction::parse(&value) {
+                                                    Ok(call) => crate::config::KeyBindAction::Call(call),
+                                                    Err(_) => return Ok(None),
+                                                }
+                                            }
                                         };
                                         // Save to correct file based on scope
                                         if let Err(e) = crate::config::Config::save_single_keybind(
@@ -3226,6 +3626,21 @@ impl TuiFrontend {
                                                     crate::config::MacroAction { macro_text: value },
                                                 )
                                             }
+                                            // The form only returns text that parses
+                                            KeybindActionType::Sequence => {
+                                                match crate::config::SequenceAction::parse(&value) {
+                                                    Ok(sequence) => {
+                                                        crate::config::KeyBindAction::Sequence(sequence)
+                                                    }
+                                                    Err(_) => return Ok(None),
+                                                }
+                                            }
+                                            KeybindActionType::Function => {
+                                                match crate::config::CallAction::parse(&value) {
+                                                    Ok(call) => crate::config::KeyBindAction::Call(call),
+                                                    Err(_) => return Ok(None),
+                                                }
+                                            }
                                         };
                                         // Save to correct file based on scope
                                         if let Err(e) = crate::config::Config::save_single_keybind(
@@ -4253,6 +4668,7 @@ impl TuiFrontend {
                         let actual_name = window_def.name().to_string();
                         app_core.add_new_window(&window_def, width, height);
                         app_core.layout_modified_since_save = true;
+                        app_core.record_layout_edit();
                         app_core.add_system_message(&format!("Window '{}' added", actual_name));
                         tracing::info!("Added window: {}", actual_name);
 
@@ -4285,6 +4701,7 @@ impl TuiFrontend {
                     app_core.add_system_message(&format!("Window '{}' hidden", window_name));
                     tracing::info!("Hidden window: {}", window_name);
                     app_core.layout.remove_window_if_default(window_name);
+                    app_core.record_layout_edit();
                 }
                 Err(e) => {
                     app_core.add_system_message(&format!("Failed to hide window: {}", e));
@@ -4737,6 +5154,7 @@ impl TuiFrontend {
                             }
                         }
                         app_core.mark_layout_modified();
+                        app_core.sync_tees();
                         self.window_editor = None;
                         app_core.ui_state.input_mode = InputMode::Normal;
                         app_core.needs_render = true;
@@ -120,51 +120,42 @@ impl super::TuiFrontend {
         app_core: &mut crate::core::AppCore,
     ) -> Result<Option<String>> {
         use crate::data::input::KeyCode;
-        use crate::data::window::WidgetType;
 
-        // Esc cancels an active .go2 trip. Reaching Normal mode means every
-        // higher-priority layer (popups, editors, menus) already declined the
-        // key, and the gate on is_traveling keeps Esc inert otherwise.
+        // Esc always hands the keyboard back to the command line first
         if matches!(code, KeyCode::Esc)
             && modifiers == crate::data::input::KeyModifiers::NONE
-            && app_core.travel.is_traveling()
+            && app_core.ui_state.release_input_focus()
         {
-            app_core.stop_travel();
             app_core.needs_render = true;
             return Ok(None);
         }
 
-        let focused_name = app_core.get_focused_window_name();
-        if let Some(window) = app_core.ui_state.get_window(&focused_name) {
-            if window.widget_type == WidgetType::Quickbar {
-                match code {
-                    KeyCode::Left => {
-                        if let Some(widget) = self.widget_manager.quickbar_widgets.get_mut(&focused_name) {
-                            widget.move_selection(-1);
-                            app_core.needs_render = true;
-                            return Ok(None);
-                        }
-                    }
-                    KeyCode::Right => {
-                        if let Some(widget) = self.widget_manager.quickbar_widgets.get_mut(&focused_name) {
-                            widget.move_selection(1);
-                            app_core.needs_render = true;
-                            return Ok(None);
-                        }
-                    }
-                    KeyCode::Enter => {
-                        if let Some(widget) = self.widget_manager.quickbar_widgets.get_mut(&focused_name) {
-                            if let Some(action) = widget.activate_selected() {
-                                return Ok(self.handle_quickbar_action(action, &focused_name, app_core));
-                            }
-                        }
-                    }
-                    KeyCode::Esc => {
-                        app_core.needs_render = true;
-                        return Ok(None);
-                    }
-                    _ => {}
-                }
+        // Esc cancels an active .go2 trip, .go route or .repeat. Reaching
+        // Normal mode means every higher-priority layer (popups, editors,
+        // menus) already declined the key, and the gate on is_traveling
+        // keeps Esc inert otherwise.
+        if matches!(code, KeyCode::Esc)
+            && modifiers == crate::data::input::KeyModifiers::NONE
+            && (app_core.travel.is_traveling()
+                || app_core.route.is_running()
+                || app_core.repeat.is_running())
+        {
+            if app_core.repeat.is_running() {
+                app_core.stop_repeat();
+            } else if app_core.travel.is_traveling() {
+                app_core.stop_travel();
+            } else {
+                app_core.stop_route();
+            }
+            app_core.needs_render = true;
+            return Ok(None);
+        }
+
+        // A widget with input focus gets first look at the key; whatever it
+        // doesn't use goes on to the command line
+        if let Some(focused_name) = app_core.ui_state.input_focus.clone() {
+            if let Some(result) = self.handle_input_focus_key(&focused_name, code, app_core) {
+                return Ok(result);
             }
         }
 
@@ -177,13 +168,41 @@ impl super::TuiFrontend {
         // Handle Enter key - always submit command
         if matches!(code, KeyCode::Enter) {
             if let Some(command) = self.command_input_submit("command_input") {
-                tracing::debug!(
-                    "Command submitted (len={}, bytes={:?}): '{}'",
-                    command.len(),
-                    command.as_bytes().iter().take(10).collect::<Vec<_>>(),
-                    command
-                );
-                return self.handle_command_submission(command, app_core);
+                if app_core.expects_secret() {
+                    // A password reply stays out of history and the debug log
+                    if let Some(cmd) = self.widget_manager.command_inputs.get_mut("command_input") {
+                        cmd.forget_latest(&command);
+                    }
+                } else {
+                    tracing::debug!(
+                        "Command submitted (len={}, bytes={:?}): '{}'",
+                        command.len(),
+                        command.as_bytes().iter().take(10).collect::<Vec<_>>(),
+                        command
+                    );
+                }
+                // Enter with the lockout bypass modifier sends without asking;
+                // the flag is cleared after in case nothing was sent
+                if crate::core::lockout::bypass_held(
+                    app_core.config.lockout.bypass_modifier,
+                    modifiers,
+                ) {
+                    app_core.command_lockout.bypass_next();
+                }
+                let result = self.handle_command_submission(command, app_core);
+                app_core.command_lockout.take_bypass();
+                return result;
+            }
+            // Text left behind by submit is an unmatched `!prefix` (leading
+            // spaces included); it stays in the input instead of submitting
+            if let Some(text) = self
+                .widget_manager
+                .command_inputs
+                .get("command_input")
+                .and_then(|cmd| cmd.get_input())
+            {
+                app_core.add_system_message(&format!("{}: event not found", text.trim()));
+                app_core.needs_render = true;
             }
         } else {
             // Check for keybinds first - normalize to lowercase for consistent matching
@@ -192,7 +211,7 @@ impl super::TuiFrontend {
                 other => other,
             };
             let key_event = crate::data::input::KeyEvent { code: normalized_code, modifiers };
-            if let Some(action) = app_core.keybind_map.get(&key_event).cloned() {
+            if let Some(action) = app_core.lookup_keybind(key_event).cloned() {
                 // Repeat-from-history actions submit directly; they can't go
                 // through command_input_key, which re-reads the raw key and
                 // would drop it (these actions never worked via that path)
@@ -215,6 +234,46 @@ impl super::TuiFrontend {
                         }
                         return Ok(None);
                     }
+                    // The compose popup takes over whatever was typed so far
+                    if s == "open_compose" {
+                        let typed = self
+                            .widget_manager
+                            .command_inputs
+                            .get_mut("command_input")
+                            .and_then(|input| {
+                                let typed = input.get_input();
+                                input.clear();
+                                typed
+                            })
+                            .unwrap_or_default();
+                        self.compose = Some(super::compose::ComposeEditor::new(&typed));
+                        app_core.ui_state.input_mode = crate::data::InputMode::Compose;
+                        app_core.needs_render = true;
+                        return Ok(None);
+                    }
+                    if s == "spell_suggest" {
+                        self.open_spell_suggest(app_core);
+                        return Ok(None);
+                    }
+                    if s == "command_log" {
+                        self.open_command_log("", app_core);
+                        return Ok(None);
+                    }
+                    if s == "reply_whisper" {
+                        match app_core.reply_prefill() {
+                            Some(text) => {
+                                if let Some(input) =
+                                    self.widget_manager.command_inputs.get_mut("command_input")
+                                {
+                                    input.clear();
+                                    input.insert_text(&text);
+                                }
+                            }
+                            None => app_core.add_system_message("No whisper to reply to yet"),
+                        }
+                        app_core.needs_render = true;
+                        return Ok(None);
+                    }
                 }
 
                 let is_command_input_action = matches!(&action,
@@ -325,33 +384,50 @@ impl super::TuiFrontend {
                     }
                     app_core.needs_render = true;
                 } else if is_switch_window_action {
-                    // Check if command input has text that should trigger tab completion
-                    let should_complete = self
+                    // Typed text: complete it (dot-commands + window names, or
+                    // game verbs + room nouns/players). Empty input, or game
+                    // text with no match, cycles the focused window instead.
+                    let input = self
                         .widget_manager
                         .command_inputs
                         .get("command_input")
-                        .and_then(|cmd| cmd.get_input())
-                        .map(|text| text.starts_with('.'))
-                        .unwrap_or(false);
+                        .and_then(|cmd| cmd.get_input());
 
-                    if should_complete {
-                        // Do tab completion for dot commands
-                        let available_commands = app_core.get_available_commands();
-                        let available_window_names = app_core.get_window_names();
-                        use crate::frontend::tui::crossterm_bridge;
-                        let ct_code = crossterm_bridge::to_crossterm_keycode(code);
-                        let ct_mods = crossterm_bridge::to_crossterm_modifiers(modifiers);
-                        self.command_input_key(
-                            "command_input",
-                            ct_code,
-                            ct_mods,
-                            &available_commands,
-                            &available_window_names,
-                        );
-                    } else {
+                    let completed = match input {
+                        Some(text) if text.starts_with('.') => {
+                            let available_commands = app_core.get_available_commands();
+                            let available_window_names = app_core.get_window_names();
+                            if let Some(cmd) =
+                                self.widget_manager.command_inputs.get_mut("command_input")
+                            {
+                                cmd.try_complete(&available_commands, &available_window_names);
+                            }
+                            true
+                        }
+                        Some(text) if !text.trim().is_empty() => {
+                            let game_words = app_core.get_game_completions();
+                            self.widget_manager
+                                .command_inputs
+                                .get_mut("command_input")
+                                .map(|cmd| cmd.try_complete(&[], &game_words))
+                                .unwrap_or(false)
+                        }
+                        _ => false,
+                    };
+                    if !completed {
                         app_core.cycle_focused_window();
                     }
                     app_core.needs_render = true;
+                } else if is_tab_action && app_core.ui_state.compact_mode {
+                    // Compact mode: tab keys switch the compact view instead
+                    if let crate::config::KeyBindAction::Action(action_str) = &action {
+                        match action_str.as_str() {
+                            "next_tab" => app_core.cycle_compact_tab(1),
+                            "prev_tab" => app_core.cycle_compact_tab(-1),
+                            _ => {}
+                        }
+                    }
+                    app_core.needs_render = true;
                 } else if is_tab_action {
                     if let crate::config::KeyBindAction::Action(action_str) = &action {
                         match action_str.as_str() {
@@ -395,6 +471,11 @@ impl super::TuiFrontend {
                         Ok(commands) => {
                             if let Some(cmd) = commands.into_iter().next() {
                                 app_core.needs_render = true;
+                                // Function keybinds resolve to UI actions
+                                if cmd.starts_with("action:") {
+                                    menu_actions::handle_menu_action(app_core, self, &cmd)?;
+                                    return Ok(None);
+                                }
                                 return Ok(Some(cmd));
                             }
                         }
@@ -430,7 +511,9 @@ impl super::TuiFrontend {
         command: String,
         app_core: &mut crate::core::AppCore,
     ) -> Result<Option<String>> {
-        tracing::debug!("handle_command_submission: start '{}'", command);
+        if !app_core.expects_secret() {
+            tracing::debug!("handle_command_submission: start '{}'", command);
+        }
         if command.starts_with(".savelayout ") || command == ".savelayout" {
             let name = command
                 .strip_prefix(".savelayout ")
@@ -449,13 +532,28 @@ impl super::TuiFrontend {
                 self.update_theme_cache(theme_id, theme);
             }
             app_core.needs_render = true;
+        } else if command.trim() == ".layout scale" {
+            let (width, height) = self.size();
+            app_core.preview_layout_scale(width, height);
+        } else if command.trim() == ".layout scale apply" {
+            let (width, height) = self.size();
+            if let Some((theme_id, theme)) = app_core.apply_layout_scale(width, height) {
+                self.update_theme_cache(theme_id, theme);
+            }
+            app_core.needs_render = true;
         } else if command == ".resize" {
             let (width, height) = self.size();
             app_core.resize_windows(width, height);
             app_core.needs_render = true;
         } else {
+            let secret = app_core.expects_secret();
             let to_send = app_core.send_command(command)?;
-            tracing::debug!("handle_command_submission: send_command returned '{}'", to_send);
+            if !secret {
+                tracing::debug!(
+                    "handle_command_submission: send_command returned '{}'",
+                    to_send
+                );
+            }
             if to_send.starts_with("action:") {
                 // Handle internal UI actions locally instead of sending to the game
                 menu_actions::handle_menu_action(app_core, self, &to_send)?;
@@ -478,6 +576,64 @@ impl super::TuiFrontend {
         Ok(None)
     }
 
+    /// Keys for the widget holding input focus. None = not used, let the
+    /// command line have it.
+    fn handle_input_focus_key(
+        &mut self,
+        name: &str,
+        code: crate::data::input::KeyCode,
+        app_core: &mut crate::core::AppCore,
+    ) -> Option<Option<String>> {
+        use crate::data::input::KeyCode;
+        use crate::data::window::WidgetType;
+
+        let widget_type = app_core.ui_state.get_window(name)?.widget_type.clone();
+        match widget_type {
+            WidgetType::Quickbar => {
+                let widget = self.widget_manager.quickbar_widgets.get_mut(name)?;
+                match code {
+                    KeyCode::Left => widget.move_selection(-1),
+                    KeyCode::Right => widget.move_selection(1),
+                    KeyCode::Enter => {
+                        let action = widget.activate_selected()?;
+                        return Some(self.handle_quickbar_action(action, name, app_core));
+                    }
+                    _ => return None,
+                }
+            }
+            WidgetType::Notes => {
+                let widget = self.widget_manager.notes_widgets.get_mut(name)?;
+                match code {
+                    KeyCode::Up => widget.scroll_by(-1),
+                    KeyCode::Down => widget.scroll_by(1),
+                    KeyCode::PageUp => widget.scroll_by(-10),
+                    KeyCode::PageDown => widget.scroll_by(10),
+                    KeyCode::Home => widget.scroll_to_top(),
+                    KeyCode::End => widget.scroll_to_bottom(),
+                    // Enter on an empty command line opens the editor
+                    KeyCode::Enter
+                        if self
+                            .widget_manager
+                            .command_inputs
+                            .get("command_input")
+                            .and_then(|cmd| cmd.get_input())
+                            .is_none_or(|text| text.is_empty()) =>
+                    {
+                        if let Err(e) =
+                            menu_actions::handle_menu_action(app_core, self, "action:notes")
+                        {
+                            tracing::warn!("Failed to open notes editor: {}", e);
+                        }
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+        app_core.needs_render = true;
+        Some(None)
+    }
+
     fn handle_quickbar_action(
         &mut self,
         action: super::quickbar::QuickbarAction,
@@ -44,8 +44,8 @@ impl InventoryWindow {
         // Delegate to ListWidget's add_text method
         self.widget.add_text(
             segment.text,
-            segment.fg,
-            segment.bg,
+            segment.fg.as_deref().map(str::to_string),
+            segment.bg.as_deref().map(str::to_string),
             segment.bold,
             segment.span_type,
             segment.link_data,
@@ -68,6 +68,14 @@ impl InventoryWindow {
         self.widget.scroll_down(lines);
     }
 
+    pub fn scroll_position(&self) -> Option<usize> {
+        self.widget.scroll_position()
+    }
+
+    pub fn restore_scroll_position(&mut self, start_line: Option<usize>) {
+        self.widget.restore_scroll_position(start_line);
+    }
+
     /// Get wrapped lines for mouse click detection
     pub fn get_wrapped_lines(&self) -> &[Vec<TextSegment>] {
         // For InventoryWindow compatibility, return reference to internal lines
@@ -4,6 +4,7 @@
 //! quickly audit key combos, differentiate actions vs macros, and pick entries
 //! to edit/delete.
 
+use crate::core::undo::{BrowserEdit, UndoStack};
 use crate::frontend::tui::crossterm_bridge;
 use ratatui::{
     buffer::Buffer,
@@ -60,6 +61,11 @@ pub struct KeybindBrowser {
     pub is_dragging: bool,
     pub drag_offset_x: u16,
     pub drag_offset_y: u16,
+
+    /// Deletes made in this browser, newest last (Ctrl+Z restores)
+    pub undo: UndoStack<BrowserEdit>,
+    /// One-line notice shown in place of the footer until the next key
+    pub toast: Option<String>,
 }
 
 impl KeybindBrowser {
@@ -131,6 +137,8 @@ impl KeybindBrowser {
                     .replace('\t', "\\t");
                 ("Macro".to_string(), escaped)
             }
+            crate::config::KeyBindAction::Sequence(s) => ("Sequence".to_string(), s.to_text()),
+            crate::config::KeyBindAction::Call(c) => ("Function".to_string(), c.to_text()),
         }
     }
 
@@ -153,6 +161,8 @@ impl KeybindBrowser {
             is_dragging: false,
             drag_offset_x: 0,
             drag_offset_y: 0,
+            undo: UndoStack::new(),
+            toast: None,
         }
     }
 
@@ -432,7 +442,10 @@ impl KeybindBrowser {
         }
 
         // Footer (off border at row 18)
-        let footer = "↑/↓:Nav PgUp/PgDn:Page Enter:Edit Del:Remove Esc:Close";
+        let footer = self
+            .toast
+            .as_deref()
+            .unwrap_or("↑/↓:Nav PgUp/PgDn:Page Enter:Edit Del:Remove Esc:Close");
         let footer_y = y + 18;
         let footer_x = x + 2;
         for (i, ch) in footer.chars().enumerate() {
@@ -647,36 +660,7 @@ impl KeybindBrowser {
     ) {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_border));
-
-        // Top border
-        buf[(x, y)].set_char('┌').set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y)].set_char('─').set_style(border_style);
-        }
-        buf[(x + width - 1, y)]
-            .set_char('┐')
-            .set_style(border_style);
-
-        // Side borders
-        for row in 1..height - 1 {
-            buf[(x, y + row)].set_char('│').set_style(border_style);
-            buf[(x + width - 1, y + row)]
-                .set_char('│')
-                .set_style(border_style);
-        }
-
-        // Bottom border
-        buf[(x, y + height - 1)]
-            .set_char('└')
-            .set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y + height - 1)]
-                .set_char('─')
-                .set_style(border_style);
-        }
-        buf[(x + width - 1, y + height - 1)]
-            .set_char('┘')
-            .set_style(border_style);
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, width, height), border_style);
     }
 
     /// Move to next page (alias for page_down)
@@ -1,9 +1,12 @@
 //! Popup form for creating or editing keybind definitions from the TUI.
 //!
-//! Handles both action-style bindings (predefined commands) and macro text,
-//! validates combinations, and integrates with the shared widget traits so the
+//! Handles action-style bindings (predefined commands), macro text,
+//! multi-step sequences ("1; wait 2; A") and calls to registered functions
+//! with their arguments, validates combinations, and integrates with the shared widget traits so the
 //! broader UI can drive it uniformly.
 
+use super::textarea_bridge::GraphemeInput;
+use crate::core::action_registry::{self, MAX_ARGS};
 use crate::frontend::tui::crossterm_bridge;
 use ratatui::{
     buffer::Buffer,
@@ -44,8 +47,10 @@ pub enum KeybindFormResult {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum KeybindActionType {
-    Action, // Built-in action
-    Macro,  // Macro text
+    Action,   // Built-in action
+    Macro,    // Macro text
+    Sequence, // Commands with pauses, in SequenceAction's text form
+    Function, // Registered function call, in CallAction's text form
 }
 
 /// Action sections for keybind browser navigation
@@ -68,12 +73,18 @@ pub struct KeybindFormWidget {
     action_type: KeybindActionType,
     action_dropdown_index: usize, // Index in AVAILABLE_ACTIONS
     macro_text: TextArea<'static>,
+    sequence_text: TextArea<'static>,
+    function_index: usize, // Index in action_registry::ACTIONS
+    function_args: [TextArea<'static>; MAX_ARGS],
     is_global: bool, // Scope: true = global, false = character-specific
 
-    // 0=action_type_action, 1=action_type_macro, 2=key_combo, 3=action/macro field, 4=scope_global, 5=scope_char
+    // 0=action_type_action, 1=action_type_macro, 2=action_type_sequence,
+    // 3=action_type_function, 4=key_combo, 5=action/macro/sequence/function field,
+    // 6-7=function arguments (only those the function takes), 8=scope_global, 9=scope_char
     focused_field: usize,
     status_message: String,
     key_combo_error: Option<String>,
+    capturing: bool, // Enter on the key combo field records the next key press
     mode: FormMode,
 
     // Popup position (for dragging)
@@ -121,9 +132,34 @@ const AVAILABLE_ACTIONS: &[&str] = &[
     "prev_search_match",
     "next_search_match",
     "clear_search",
+    "open_compose",
+    "spell_suggest",
+    "command_log",
+    "reply_whisper",
+    "list_urls",
+    "layout_undo",
+    "layout_redo",
     "toggle_performance_stats",
+    "toggle_floating_windows",
+    "toggle_boss_key",
+    "read_screen",
 ];
 
+/// "ctrl+shift" style label for capture status messages
+fn modifier_label(modifiers: crate::data::input::KeyModifiers) -> String {
+    let mut names = Vec::new();
+    if modifiers.ctrl {
+        names.push("ctrl");
+    }
+    if modifiers.alt {
+        names.push("alt");
+    }
+    if modifiers.shift {
+        names.push("shift");
+    }
+    names.join("+")
+}
+
 impl KeybindFormWidget {
     pub fn new() -> Self {
         let mut key_combo = TextArea::default();
@@ -132,22 +168,31 @@ impl KeybindFormWidget {
         let mut macro_text = TextArea::default();
         macro_text.set_placeholder_text("e.g., run left\\r");
 
-        Self {
+        let mut sequence_text = TextArea::default();
+        sequence_text.set_placeholder_text("1; wait 2; A; look");
+
+        let mut form = Self {
             key_combo,
             action_type: KeybindActionType::Action,
             action_dropdown_index: 0,
             macro_text,
+            sequence_text,
+            function_index: 0,
+            function_args: Default::default(),
             is_global: true, // Default to global scope for new keybinds
             focused_field: 0,
             status_message: String::new(),
             key_combo_error: None,
+            capturing: false,
             mode: FormMode::Create,
             popup_x: 0,
             popup_y: 0,
             is_dragging: false,
             drag_offset_x: 0,
             drag_offset_y: 0,
-        }
+        };
+        form.set_function(0);
+        form
     }
 
     pub fn new_edit(
@@ -171,6 +216,22 @@ impl KeybindFormWidget {
             KeybindActionType::Macro => {
                 form.macro_text.insert_str(&value);
             }
+            KeybindActionType::Sequence => {
+                form.sequence_text.insert_str(&value);
+            }
+            KeybindActionType::Function => {
+                if let Ok(call) = crate::config::CallAction::parse(&value) {
+                    if let Some(idx) = action_registry::ACTIONS
+                        .iter()
+                        .position(|a| a.name == call.call)
+                    {
+                        form.set_function(idx);
+                    }
+                    for (area, arg) in form.function_args.iter_mut().zip(&call.args) {
+                        area.insert_str(arg);
+                    }
+                }
+            }
         }
 
         form.mode = FormMode::Edit {
@@ -187,15 +248,22 @@ impl KeybindFormWidget {
         let rt_key = crate::frontend::tui::textarea_bridge::to_textarea_event(key);
 
         let _handled = match self.focused_field {
-            2 => {
-                // Field 2: Key Combo
-                let result = self.key_combo.input(rt_key);
+            4 => {
+                // Field 4: Key Combo
+                let result = self.key_combo.input_graphemes(rt_key);
                 self.validate_key_combo();
                 result
             }
-            3 if self.action_type == KeybindActionType::Macro => {
-                // Field 3: Macro text (only when macro type is selected)
-                self.macro_text.input(rt_key)
+            5 if self.action_type == KeybindActionType::Macro => {
+                // Field 5: Macro text (only when macro type is selected)
+                self.macro_text.input_graphemes(rt_key)
+            }
+            5 if self.action_type == KeybindActionType::Sequence => {
+                self.sequence_text.input_graphemes(rt_key)
+            }
+            6 | 7 if self.field_shown(self.focused_field) => {
+                // Fields 6-7: Function arguments
+                self.function_args[self.focused_field - 6].input_graphemes(rt_key)
             }
             _ => false,
         };
@@ -222,17 +290,12 @@ impl KeybindFormWidget {
             }
             MenuAction::CycleBackward => {
                 // Left arrow - cycle dropdown backward (when on action dropdown)
-                if self.focused_field == 3 && self.action_type == KeybindActionType::Action {
-                    self.action_dropdown_index = self.action_dropdown_index.saturating_sub(1);
-                }
+                self.cycle_backward();
                 None
             }
             MenuAction::CycleForward => {
                 // Right arrow - cycle dropdown forward (when on action dropdown)
-                if self.focused_field == 3 && self.action_type == KeybindActionType::Action {
-                    self.action_dropdown_index =
-                        (self.action_dropdown_index + 1).min(AVAILABLE_ACTIONS.len() - 1);
-                }
+                self.cycle_forward();
                 None
             }
             MenuAction::Select | MenuAction::Toggle => {
@@ -244,10 +307,20 @@ impl KeybindFormWidget {
                     1 => {
                         self.action_type = KeybindActionType::Macro;
                     }
-                    4 => {
+                    2 => {
+                        self.action_type = KeybindActionType::Sequence;
+                    }
+                    3 => {
+                        self.action_type = KeybindActionType::Function;
+                    }
+                    4 if matches!(action, MenuAction::Select) => {
+                        self.capturing = true;
+                        self.status_message = "Press the key to bind...".to_string();
+                    }
+                    8 => {
                         self.is_global = true; // Select Global scope
                     }
-                    5 => {
+                    9 => {
                         self.is_global = false; // Select Character scope
                     }
                     _ => {}
@@ -271,99 +344,47 @@ impl KeybindFormWidget {
     }
 
     fn validate_key_combo(&mut self) {
-        let combo = self.key_combo.lines()[0].as_str();
+        let combo = self.key_combo.lines()[0].trim();
         if combo.is_empty() {
             self.key_combo_error = None;
             return;
         }
 
-        // Basic validation - check if it looks like a valid key combo
-        // Valid formats: "a", "ctrl+a", "alt+shift+f5", etc.
-        let parts: Vec<&str> = combo.split('+').collect();
-        let mut has_key = false;
-
-        for part in &parts {
-            let normalized = part.trim().to_lowercase();
-            if matches!(
-                normalized.as_str(),
-                "a" | "b"
-                    | "c"
-                    | "d"
-                    | "e"
-                    | "f"
-                    | "g"
-                    | "h"
-                    | "i"
-                    | "j"
-                    | "k"
-                    | "l"
-                    | "m"
-                    | "n"
-                    | "o"
-                    | "p"
-                    | "q"
-                    | "r"
-                    | "s"
-                    | "t"
-                    | "u"
-                    | "v"
-                    | "w"
-                    | "x"
-                    | "y"
-                    | "z"
-                    | "f1"
-                    | "f2"
-                    | "f3"
-                    | "f4"
-                    | "f5"
-                    | "f6"
-                    | "f7"
-                    | "f8"
-                    | "f9"
-                    | "f10"
-                    | "f11"
-                    | "f12"
-                    | "enter"
-                    | "space"
-                    | "tab"
-                    | "esc"
-                    | "backspace"
-                    | "delete"
-                    | "home"
-                    | "end"
-                    | "page_up"
-                    | "page_down"
-                    | "up"
-                    | "down"
-                    | "left"
-                    | "right"
-                    | "num_0"
-                    | "num_1"
-                    | "num_2"
-                    | "num_3"
-                    | "num_4"
-                    | "num_5"
-                    | "num_6"
-                    | "num_7"
-                    | "num_8"
-                    | "num_9"
-                    | "num_."
-                    | "num_+"
-                    | "num_-"
-                    | "num_*"
-                    | "num_/"
-            ) {
-                has_key = true;
-            } else if !matches!(normalized.as_str(), "ctrl" | "alt" | "shift") {
-                self.key_combo_error = Some(format!("Invalid key: '{}'", part));
-                return;
-            }
-        }
-
-        if !has_key {
-            self.key_combo_error = Some("Must specify a key (not just modifiers)".to_string());
+        // Same parser the keybind map uses, so anything it accepts (digits,
+        // punctuation, non-ASCII keys from international layouts) is valid
+        self.key_combo_error = if crate::config::parse_key_string(combo).is_some() {
+            None
         } else {
-            self.key_combo_error = None;
+            Some(format!("Invalid key combo: '{}'", combo))
+        };
+    }
+
+    /// Whether the next key press is being recorded into the key combo
+    pub fn is_capturing(&self) -> bool {
+        self.capturing
+    }
+
+    /// Record a key exactly as the terminal reported it. Ends capture mode;
+    /// keys with no keybinds.toml spelling leave the combo unchanged.
+    pub fn capture_key(
+        &mut self,
+        code: crate::data::input::KeyCode,
+        modifiers: crate::data::input::KeyModifiers,
+    ) {
+        self.capturing = false;
+        let raw = format!("{:?} {}", code, modifier_label(modifiers));
+        match crate::config::format_key_string(code, modifiers) {
+            Some(combo) => {
+                self.key_combo = TextArea::default();
+                self.key_combo
+                    .set_placeholder_text("e.g., ctrl+e, f5, alt+shift+a");
+                self.key_combo.insert_str(&combo);
+                self.validate_key_combo();
+                self.status_message = format!("Captured {} as {}", raw.trim_end(), combo);
+            }
+            None => {
+                self.status_message = format!("Can't bind {}", raw.trim_end());
+            }
         }
     }
 
@@ -392,6 +413,29 @@ impl KeybindFormWidget {
                 }
                 text
             }
+            KeybindActionType::Sequence => {
+                let text = self.sequence_text.lines()[0].trim().to_string();
+                if let Err(e) = crate::config::SequenceAction::parse(&text) {
+                    self.status_message = format!("Sequence: {}", e);
+                    return None;
+                }
+                text
+            }
+            KeybindActionType::Function => {
+                let action = &action_registry::ACTIONS[self.function_index];
+                let call = crate::config::CallAction {
+                    call: action.name.to_string(),
+                    args: self.function_args[..action.args.len()]
+                        .iter()
+                        .map(|area| area.lines()[0].trim().to_string())
+                        .collect(),
+                };
+                if let Err(e) = action_registry::resolve(&call) {
+                    self.status_message = format!("Function: {}", e);
+                    return None;
+                }
+                call.to_text()
+            }
         };
 
         Some(KeybindFormResult::Save {
@@ -420,8 +464,9 @@ impl KeybindFormWidget {
         config: &crate::config::Config,
         theme: &crate::theme::AppTheme,
     ) {
-        let width = 52;
-        let height = 10; // Increased by 1 for scope row
+        let width = 60;
+        let extra = self.arg_rows();
+        let height = 10 + extra; // Increased by 1 for scope row, plus argument rows
 
         // Center on first render
         if self.popup_x == 0 && self.popup_y == 0 {
@@ -471,9 +516,30 @@ impl KeybindFormWidget {
         // Render fields
         self.render_fields(x, y, width, buf, config, theme);
 
-        // Footer (centered at row 7)
+        // Status / validation line (row 8, below any argument rows)
+        let status = self
+            .key_combo_error
+            .as_deref()
+            .unwrap_or(self.status_message.as_str());
+        let status_color = if self.key_combo_error.is_some() {
+            theme.form_error
+        } else {
+            theme.form_label_focused
+        };
+        for (i, ch) in status
+            .chars()
+            .take(width.saturating_sub(4) as usize)
+            .enumerate()
+        {
+            buf[(x + 2 + i as u16, y + 8 + extra)]
+                .set_char(ch)
+                .set_fg(crossterm_bridge::to_ratatui_color(status_color))
+                .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
+        }
+
+        // Footer (centered at row 7, below any argument rows)
         let footer = "Ctrl+s:Save Ctrl+D:Delete Esc:Cancel";
-        let footer_y = y + 7;
+        let footer_y = y + 7 + extra;
         let footer_x = x + (width.saturating_sub(footer.len() as u16)) / 2;
         for (i, ch) in footer.chars().enumerate() {
             if (footer_x + i as u16) < (x + width) {
@@ -496,36 +562,7 @@ impl KeybindFormWidget {
     ) {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.form_label));
-
-        // Top border
-        buf[(x, y)].set_char('┌').set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y)].set_char('─').set_style(border_style);
-        }
-        buf[(x + width - 1, y)]
-            .set_char('┐')
-            .set_style(border_style);
-
-        // Side borders
-        for row in 1..height - 1 {
-            buf[(x, y + row)].set_char('│').set_style(border_style);
-            buf[(x + width - 1, y + row)]
-                .set_char('│')
-                .set_style(border_style);
-        }
-
-        // Bottom border
-        buf[(x, y + height - 1)]
-            .set_char('└')
-            .set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y + height - 1)]
-                .set_char('─')
-                .set_style(border_style);
-        }
-        buf[(x + width - 1, y + height - 1)]
-            .set_char('┘')
-            .set_style(border_style);
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, width, height), border_style);
     }
 
     fn render_fields(
@@ -548,9 +585,9 @@ impl KeybindFormWidget {
             Color::Reset // Fallback to terminal default
         };
 
-        // Row 2: Type (radio buttons) - Fields 0 and 1
+        // Row 2: Type (radio buttons) - Fields 0 to 3
         let type_label_color = crossterm_bridge::to_ratatui_color(
-            if self.focused_field == 0 || self.focused_field == 1 {
+            if self.focused_field <= 3 {
                 theme.form_label_focused
             } else {
                 theme.form_label
@@ -596,20 +633,56 @@ impl KeybindFormWidget {
             "[ ] Macro"
         };
         for (i, ch) in macro_text.chars().enumerate() {
-            buf[(x + 23 + i as u16, current_y)]
+            buf[(x + 20 + i as u16, current_y)]
                 .set_char(ch)
                 .set_fg(macro_color)
                 .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
         }
+
+        // Sequence radio button (Field 2)
+        let sequence_color = crossterm_bridge::to_ratatui_color(if self.focused_field == 2 {
+            theme.form_label_focused
+        } else {
+            theme.form_label
+        });
+        let sequence_text = if self.action_type == KeybindActionType::Sequence {
+            "[X] Sequence"
+        } else {
+            "[ ] Sequence"
+        };
+        for (i, ch) in sequence_text.chars().enumerate() {
+            buf[(x + 31 + i as u16, current_y)]
+                .set_char(ch)
+                .set_fg(sequence_color)
+                .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
+        }
+
+        // Function radio button (Field 3)
+        let function_color = crossterm_bridge::to_ratatui_color(if self.focused_field == 3 {
+            theme.form_label_focused
+        } else {
+            theme.form_label
+        });
+        let function_text = if self.action_type == KeybindActionType::Function {
+            "[X] Function"
+        } else {
+            "[ ] Function"
+        };
+        for (i, ch) in function_text.chars().enumerate() {
+            buf[(x + 45 + i as u16, current_y)]
+                .set_char(ch)
+                .set_fg(function_color)
+                .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
+        }
         current_y += 1;
 
         let focused_field = self.focused_field;
 
-        // Row 3: Key Combo (Field 2) - 1 col spacing, 37 col width
+        // Row 3: Key Combo (Field 4) - 1 col spacing, 37 col width
         let key_input_start = x + 2 + 10 + 1; // "Key Combo:" (10) + 1 space
         Self::render_text_row(
             focused_field,
-            2,
+            4,
             "Key Combo:",
             &mut self.key_combo,
             "ctrl+e, f5, alt+shift+a",
@@ -628,6 +701,8 @@ impl KeybindFormWidget {
             KeybindActionType::Action => {
                 let action_input_start = x + 2 + 7 + 4; // "Action:" (7) + 4 spaces
                 self.render_action_dropdown(
+                    "Action:",
+                    AVAILABLE_ACTIONS[self.action_dropdown_index],
                     x + 2,
                     current_y,
                     action_input_start,
@@ -641,7 +716,7 @@ impl KeybindFormWidget {
                 let macro_input_start = x + 2 + 11 + 4; // "Macro Text:" (11) + 4 spaces
                 Self::render_text_row(
                     focused_field,
-                    3,
+                    5,
                     "Macro Text:",
                     &mut self.macro_text,
                     "run left\\r",
@@ -654,12 +729,65 @@ impl KeybindFormWidget {
                     theme,
                 );
             }
+            KeybindActionType::Sequence => {
+                let steps_input_start = x + 2 + 11 + 4; // Same column as the macro text
+                Self::render_text_row(
+                    focused_field,
+                    5,
+                    "Steps:",
+                    &mut self.sequence_text,
+                    "1; wait 2; A; look",
+                    x + 2,
+                    current_y,
+                    steps_input_start,
+                    37,
+                    textarea_bg,
+                    buf,
+                    theme,
+                );
+            }
+            KeybindActionType::Function => {
+                let function_input_start = x + 2 + 11 + 4;
+                let action = &action_registry::ACTIONS[self.function_index];
+                let names: Vec<&str> = action.args.iter().map(|arg| arg.name).collect();
+                let signature = format!("{}({})", action.name, names.join(", "));
+                self.render_action_dropdown(
+                    "Function:",
+                    &signature,
+                    x + 2,
+                    current_y,
+                    function_input_start,
+                    37,
+                    textarea_bg,
+                    buf,
+                    theme,
+                );
+
+                // Rows 6+: one text field per argument (Fields 6-7)
+                for (i, arg) in action.args.iter().enumerate() {
+                    current_y += 1;
+                    Self::render_text_row(
+                        focused_field,
+                        6 + i,
+                        &format!("  {}:", arg.name),
+                        &mut self.function_args[i],
+                        arg.name,
+                        x + 2,
+                        current_y,
+                        function_input_start,
+                        37,
+                        textarea_bg,
+                        buf,
+                        theme,
+                    );
+                }
+            }
         }
         current_y += 1;
 
-        // Row: Scope (radio buttons) - Fields 4 and 5
+        // Row: Scope (radio buttons) - Fields 8 and 9
         let scope_label_color =
-            crossterm_bridge::to_ratatui_color(if focused_field == 4 || focused_field == 5 {
+            crossterm_bridge::to_ratatui_color(if focused_field == 8 || focused_field == 9 {
                 theme.form_label_focused
             } else {
                 theme.form_label
@@ -672,8 +800,8 @@ impl KeybindFormWidget {
                 .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
         }
 
-        // Global radio button (Field 4)
-        let global_color = crossterm_bridge::to_ratatui_color(if focused_field == 4 {
+        // Global radio button (Field 8)
+        let global_color = crossterm_bridge::to_ratatui_color(if focused_field == 8 {
             theme.form_label_focused
         } else {
             theme.form_label
@@ -690,8 +818,8 @@ impl KeybindFormWidget {
                 .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
         }
 
-        // Character radio button (Field 5)
-        let char_color = crossterm_bridge::to_ratatui_color(if focused_field == 5 {
+        // Character radio button (Field 9)
+        let char_color = crossterm_bridge::to_ratatui_color(if focused_field == 9 {
             theme.form_label_focused
         } else {
             theme.form_label
@@ -765,6 +893,8 @@ impl KeybindFormWidget {
 
     fn render_action_dropdown(
         &self,
+        label: &str,
+        current_value: &str,
         x: u16,
         y: u16,
         input_x: u16,
@@ -773,7 +903,7 @@ impl KeybindFormWidget {
         buf: &mut Buffer,
         theme: &crate::theme::AppTheme,
     ) {
-        let focused = self.focused_field == 3;
+        let focused = self.focused_field == 5;
         let label_color = crossterm_bridge::to_ratatui_color(if focused {
             theme.form_label_focused
         } else {
@@ -781,7 +911,6 @@ impl KeybindFormWidget {
         });
 
         // Render label
-        let label = "Action:";
         for (i, ch) in label.chars().enumerate() {
             buf[(x + i as u16, y)]
                 .set_char(ch)
@@ -789,9 +918,6 @@ impl KeybindFormWidget {
                 .set_bg(crossterm_bridge::to_ratatui_color(theme.browser_background));
         }
 
-        // Get current value from dropdown index
-        let current_value = AVAILABLE_ACTIONS[self.action_dropdown_index];
-
         // Render current value (highlight if focused, no background)
         let value_color = crossterm_bridge::to_ratatui_color(if focused {
             theme.form_label_focused
@@ -814,8 +940,9 @@ impl KeybindFormWidget {
         pressed: bool,
         terminal_area: Rect,
     ) -> KeybindFormMouseAction {
-        let popup_width: u16 = 52;
-        let popup_height: u16 = 10;
+        let popup_width: u16 = 60;
+        let extra = self.arg_rows();
+        let popup_height: u16 = 10 + extra;
 
         // Check if click is on title bar (top border, excluding corners)
         let on_title_bar =
@@ -861,66 +988,85 @@ impl KeybindFormWidget {
         let x = self.popup_x;
         let y = self.popup_y;
 
-        // Check footer row (y + 7) for button clicks
-        // Footer: "Ctrl+s:Save Ctrl+D:Delete Esc:Cancel" (centered in 52-width popup)
-        let footer_y = y + 7;
+        // Check footer row (y + 7, below any argument rows) for button clicks
+        // Footer: "Ctrl+s:Save Ctrl+D:Delete Esc:Cancel" (centered in 60-width popup)
+        let footer_y = y + 7 + extra;
         if row == footer_y {
             let rel_x = col.saturating_sub(x);
             // Footer is centered, approximately: "Ctrl+s:Save Ctrl+D:Delete Esc:Cancel"
-            // Rough positions: Save ~8-18, Delete ~20-32, Cancel ~34-44
-            if rel_x >= 8 && rel_x <= 18 {
+            // Rough positions: Save ~12-22, Delete ~24-36, Cancel ~38-48
+            if (12..=22).contains(&rel_x) {
                 return KeybindFormMouseAction::Save;
-            } else if rel_x >= 20 && rel_x <= 32 {
+            } else if (24..=36).contains(&rel_x) {
                 return KeybindFormMouseAction::Delete;
-            } else if rel_x >= 34 && rel_x <= 44 {
+            } else if (38..=48).contains(&rel_x) {
                 return KeybindFormMouseAction::Cancel;
             }
         }
 
         // Check field rows for clicks
-        // Row 2 (y+2): Type radios - Action at x+8, Macro at x+23
+        // Row 2 (y+2): Type radios - Action at x+8, Macro at x+20, Sequence at x+31,
+        // Function at x+45
         if row == y + 2 {
             let rel_x = col.saturating_sub(x);
-            if rel_x >= 8 && rel_x <= 18 {
+            if (8..=18).contains(&rel_x) {
                 // Clicked Action radio
                 self.focused_field = 0;
                 self.action_type = KeybindActionType::Action;
                 return KeybindFormMouseAction::None;
-            } else if rel_x >= 23 && rel_x <= 32 {
+            } else if (20..=28).contains(&rel_x) {
                 // Clicked Macro radio
                 self.focused_field = 1;
                 self.action_type = KeybindActionType::Macro;
                 return KeybindFormMouseAction::None;
+            } else if (31..=42).contains(&rel_x) {
+                // Clicked Sequence radio
+                self.focused_field = 2;
+                self.action_type = KeybindActionType::Sequence;
+                return KeybindFormMouseAction::None;
+            } else if (45..=56).contains(&rel_x) {
+                // Clicked Function radio
+                self.focused_field = 3;
+                self.action_type = KeybindActionType::Function;
+                return KeybindFormMouseAction::None;
             }
         }
 
         // Row 3 (y+3): Key Combo text field
         if row == y + 3 {
-            self.focused_field = 2;
+            self.focused_field = 4;
             return KeybindFormMouseAction::None;
         }
 
-        // Row 5 (y+5): Action dropdown or Macro text
+        // Row 5 (y+5): Action dropdown, Macro text, Sequence steps or Function dropdown
         if row == y + 5 {
-            self.focused_field = 3;
+            self.focused_field = 5;
             // If it's a dropdown and user clicked, cycle the value
-            if self.action_type == KeybindActionType::Action {
-                self.cycle_action_dropdown(false);
+            match self.action_type {
+                KeybindActionType::Action => self.cycle_action_dropdown(false),
+                KeybindActionType::Function => self.cycle_function(false),
+                _ => {}
             }
             return KeybindFormMouseAction::None;
         }
 
-        // Row 6 (y+6): Scope radios - Global at x+9, Character at x+23
-        if row == y + 6 {
+        // Rows 6+ (y+6..): Function arguments
+        if row >= y + 6 && row < y + 6 + extra {
+            self.focused_field = 6 + (row - y - 6) as usize;
+            return KeybindFormMouseAction::None;
+        }
+
+        // Row 6 (y+6, below any argument rows): Scope radios - Global at x+9, Character at x+23
+        if row == y + 6 + extra {
             let rel_x = col.saturating_sub(x);
             if rel_x >= 9 && rel_x <= 19 {
                 // Clicked Global radio
-                self.focused_field = 4;
+                self.focused_field = 8;
                 self.is_global = true;
                 return KeybindFormMouseAction::None;
             } else if rel_x >= 23 && rel_x <= 36 {
                 // Clicked Character radio
-                self.focused_field = 5;
+                self.focused_field = 9;
                 self.is_global = false;
                 return KeybindFormMouseAction::None;
             }
@@ -951,7 +1097,7 @@ impl KeybindFormWidget {
         if let Some(idx) = AVAILABLE_ACTIONS.iter().position(|&a| a == target) {
             self.action_type = KeybindActionType::Action;
             self.action_dropdown_index = idx;
-            self.focused_field = 3; // action dropdown field
+            self.focused_field = 5; // action dropdown field
             self.status_message = format!("Jumped to {} actions", label);
         }
     }
@@ -969,6 +1115,45 @@ impl KeybindFormWidget {
         }
     }
 
+    /// Cycle the function dropdown, wrapping at either end
+    fn cycle_function(&mut self, backward: bool) {
+        let count = action_registry::ACTIONS.len();
+        let index = if backward {
+            (self.function_index + count - 1) % count
+        } else {
+            (self.function_index + 1) % count
+        };
+        self.set_function(index);
+        self.status_message = action_registry::ACTIONS[index].summary.to_string();
+    }
+
+    /// Select a registered function; argument placeholders become its
+    /// argument names. Text already typed into the argument fields is kept.
+    fn set_function(&mut self, index: usize) {
+        let action = &action_registry::ACTIONS[index];
+        self.function_index = index;
+        for (i, area) in self.function_args.iter_mut().enumerate() {
+            area.set_placeholder_text(action.args.get(i).map_or("", |arg| arg.name));
+        }
+    }
+
+    /// Argument rows shown under the function dropdown
+    fn arg_rows(&self) -> u16 {
+        if self.action_type == KeybindActionType::Function {
+            action_registry::ACTIONS[self.function_index].args.len() as u16
+        } else {
+            0
+        }
+    }
+
+    /// Argument fields only exist for the arguments the function takes
+    fn field_shown(&self, field: usize) -> bool {
+        match field {
+            6 | 7 => field - 6 < self.arg_rows() as usize,
+            _ => field < 10,
+        }
+    }
+
     /// Parse hex color string to ratatui Color
     fn parse_hex_color(hex: &str) -> Option<Color> {
         // Use centralized mode-aware color parser
@@ -983,16 +1168,24 @@ use super::widget_traits::{Cyclable, FieldNavigable, TextEditable, Toggleable};
 impl TextEditable for KeybindFormWidget {
     fn get_focused_field(&self) -> Option<&TextArea<'static>> {
         match self.focused_field {
-            2 => Some(&self.key_combo),
-            3 if self.action_type == KeybindActionType::Macro => Some(&self.macro_text),
+            4 => Some(&self.key_combo),
+            5 if self.action_type == KeybindActionType::Macro => Some(&self.macro_text),
+            5 if self.action_type == KeybindActionType::Sequence => Some(&self.sequence_text),
+            6 | 7 if self.field_shown(self.focused_field) => {
+                Some(&self.function_args[self.focused_field - 6])
+            }
             _ => None,
         }
     }
 
     fn get_focused_field_mut(&mut self) -> Option<&mut TextArea<'static>> {
         match self.focused_field {
-            2 => Some(&mut self.key_combo),
-            3 if self.action_type == KeybindActionType::Macro => Some(&mut self.macro_text),
+            4 => Some(&mut self.key_combo),
+            5 if self.action_type == KeybindActionType::Macro => Some(&mut self.macro_text),
+            5 if self.action_type == KeybindActionType::Sequence => Some(&mut self.sequence_text),
+            6 | 7 if self.field_shown(self.focused_field) => {
+                Some(&mut self.function_args[self.focused_field - 6])
+            }
             _ => None,
         }
     }
@@ -1000,20 +1193,32 @@ impl TextEditable for KeybindFormWidget {
 
 impl FieldNavigable for KeybindFormWidget {
     fn next_field(&mut self) {
-        // Fields: 0=Action, 1=Macro, 2=KeyCombo, 3=ActionDropdown/MacroText, 4=GlobalScope, 5=CharScope
-        self.focused_field = (self.focused_field + 1) % 6;
+        // Fields: 0=Action, 1=Macro, 2=Sequence, 3=Function, 4=KeyCombo,
+        // 5=ActionDropdown/MacroText/SequenceSteps/FunctionDropdown,
+        // 6-7=FunctionArgs (skipped unless the function takes them), 8=GlobalScope, 9=CharScope
+        loop {
+            self.focused_field = (self.focused_field + 1) % 10;
+            if self.field_shown(self.focused_field) {
+                break;
+            }
+        }
     }
 
     fn previous_field(&mut self) {
-        self.focused_field = if self.focused_field == 0 {
-            5
-        } else {
-            self.focused_field - 1
-        };
+        loop {
+            self.focused_field = if self.focused_field == 0 {
+                9
+            } else {
+                self.focused_field - 1
+            };
+            if self.field_shown(self.focused_field) {
+                break;
+            }
+        }
     }
 
     fn field_count(&self) -> usize {
-        6
+        10
     }
 
     fn current_field(&self) -> usize {
@@ -1042,6 +1247,14 @@ impl Toggleable for KeybindFormWidget {
                 self.action_type = KeybindActionType::Macro;
                 Some(false)
             }
+            2 => {
+                self.action_type = KeybindActionType::Sequence;
+                Some(false)
+            }
+            3 => {
+                self.action_type = KeybindActionType::Function;
+                Some(false)
+            }
             _ => None,
         }
     }
@@ -1049,15 +1262,29 @@ impl Toggleable for KeybindFormWidget {
 
 impl Cyclable for KeybindFormWidget {
     fn cycle_forward(&mut self) {
-        if self.focused_field == 3 && self.action_type == KeybindActionType::Action {
-            self.action_dropdown_index =
-                (self.action_dropdown_index + 1).min(AVAILABLE_ACTIONS.len() - 1);
+        if self.focused_field != 5 {
+            return;
+        }
+        match self.action_type {
+            KeybindActionType::Action => {
+                self.action_dropdown_index =
+                    (self.action_dropdown_index + 1).min(AVAILABLE_ACTIONS.len() - 1);
+            }
+            KeybindActionType::Function => self.cycle_function(false),
+            _ => {}
         }
     }
 
     fn cycle_backward(&mut self) {
-        if self.focused_field == 3 && self.action_type == KeybindActionType::Action {
-            self.action_dropdown_index = self.action_dropdown_index.saturating_sub(1);
+        if self.focused_field != 5 {
+            return;
+        }
+        match self.action_type {
+            KeybindActionType::Action => {
+                self.action_dropdown_index = self.action_dropdown_index.saturating_sub(1);
+            }
+            KeybindActionType::Function => self.cycle_function(true),
+            _ => {}
         }
     }
 }
@@ -0,0 +1,283 @@
+//! Layout gallery popup (`.layouts browse`).
+//!
+//! Lists the layouts found by `core::layout_gallery::scan` next to an ASCII
+//! preview of the highlighted one. Enter asks for confirmation, then the
+//! layout is applied scaled to the terminal (`AppCore::apply_gallery_layout`).
+
+use super::crossterm_bridge;
+use crate::config::Config;
+use crate::core::layout_gallery::{self, GalleryEntry};
+use crate::core::AppCore;
+use crate::data::input::KeyCode;
+use crate::data::InputMode;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Width of the layout list column
+const LIST_WIDTH: u16 = 28;
+
+pub struct LayoutGallery {
+    entries: Vec<GalleryEntry>,
+    selected: usize,
+    /// First visible list row
+    scroll: usize,
+    /// Waiting for y/n before applying the selected layout
+    confirm: bool,
+}
+
+impl LayoutGallery {
+    fn new(entries: Vec<GalleryEntry>) -> Self {
+        Self {
+            entries,
+            selected: 0,
+            scroll: 0,
+            confirm: false,
+        }
+    }
+
+    pub fn render(
+        &mut self,
+        screen: Rect,
+        buf: &mut Buffer,
+        theme: &crate::theme::AppTheme,
+        terminal_size: (u16, u16),
+    ) {
+        let color = crossterm_bridge::to_ratatui_color;
+        let width = screen
+            .width
+            .saturating_sub(4)
+            .clamp(50, 110)
+            .min(screen.width);
+        let height = screen
+            .height
+            .saturating_sub(4)
+            .clamp(12, 32)
+            .min(screen.height);
+        let area = Rect::new(
+            screen.x + screen.width.saturating_sub(width) / 2,
+            screen.y + screen.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+        let Some(entry) = self.entries.get(self.selected) else {
+            return;
+        };
+
+        let footer = if self.confirm {
+            Span::styled(
+                format!(
+                    " Apply '{}' scaled to {}x{}? y/n ",
+                    entry.name, terminal_size.0, terminal_size.1
+                ),
+                Style::default()
+                    .fg(color(theme.status_info))
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled(
+                " Enter apply  Esc close ",
+                Style::default().fg(color(theme.text_secondary)),
+            )
+        };
+        Clear.render(area, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(super::border_glyphs::themed(
+                ratatui::symbols::border::ROUNDED,
+            ))
+            .border_style(Style::default().fg(color(theme.browser_border)))
+            .title(format!(" Layouts ({}) ", self.entries.len()))
+            .title_style(
+                Style::default()
+                    .fg(color(theme.browser_title))
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(Line::from(footer))
+            .style(Style::default().bg(color(theme.browser_background)));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width <= LIST_WIDTH + 10 || inner.height < 4 {
+            return;
+        }
+
+        // Layout list
+        let list_area = Rect::new(inner.x, inner.y, LIST_WIDTH, inner.height);
+        let visible = list_area.height as usize;
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + visible {
+            self.scroll = self.selected + 1 - visible;
+        }
+        let name_width = LIST_WIDTH as usize - 13;
+        let rows: Vec<Line> = self
+            .entries
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(visible)
+            .map(|(idx, item)| {
+                let style = if idx == self.selected {
+                    Style::default()
+                        .fg(color(theme.browser_item_selected))
+                        .bg(color(theme.background_selected))
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(color(theme.browser_item_normal))
+                };
+                let name: String = item.name.chars().take(name_width).collect();
+                Line::from(vec![
+                    Span::styled(format!(" {:<width$} ", name, width = name_width), style),
+                    Span::styled(
+                        format!("{:<10}", item.origin.label()),
+                        style.fg(color(theme.text_secondary)),
+                    ),
+                ])
+            })
+            .collect();
+        Paragraph::new(rows).render(list_area, buf);
+
+        // Details and preview
+        let detail_area = Rect::new(
+            inner.x + LIST_WIDTH + 1,
+            inner.y,
+            inner.width - LIST_WIDTH - 1,
+            inner.height,
+        );
+        let (design_w, design_h) = entry.design_size();
+        let visible_windows = entry
+            .layout
+            .windows
+            .iter()
+            .filter(|w| w.base().visible)
+            .count();
+        let mut details = vec![Line::from(Span::styled(
+            entry.title().to_string(),
+            Style::default()
+                .fg(color(theme.text_primary))
+                .add_modifier(Modifier::BOLD),
+        ))];
+        let secondary = Style::default().fg(color(theme.text_secondary));
+        if let Some(author) = &entry.meta.author {
+            details.push(Line::from(Span::styled(
+                format!("by {}", author),
+                secondary,
+            )));
+        }
+        details.push(Line::from(Span::styled(
+            format!(
+                "{}x{}, {} window{}",
+                design_w,
+                design_h,
+                visible_windows,
+                if visible_windows == 1 { "" } else { "s" }
+            ),
+            secondary,
+        )));
+        if let Some(description) = &entry.meta.description {
+            details.push(Line::from(Span::styled(
+                description.clone(),
+                Style::default().fg(color(theme.text_primary)),
+            )));
+        }
+        let wrapped_rows: u16 = details
+            .iter()
+            .map(|line| (line.width() as u16).div_ceil(detail_area.width).max(1))
+            .sum();
+        let detail_height = (wrapped_rows + 1).min(detail_area.height / 2);
+        Paragraph::new(details).wrap(Wrap { trim: true }).render(
+            Rect::new(
+                detail_area.x,
+                detail_area.y,
+                detail_area.width,
+                detail_height,
+            ),
+            buf,
+        );
+
+        // Shrink the layout evenly so the preview keeps its proportions
+        let preview_top = detail_area.y + detail_height;
+        let room_h = detail_area.bottom().saturating_sub(preview_top);
+        let mut preview_w = detail_area.width;
+        let mut preview_h = (preview_w as u32 * design_h as u32 / design_w as u32) as u16;
+        if preview_h > room_h {
+            preview_h = room_h;
+            preview_w = (preview_h as u32 * design_w as u32 / design_h as u32)
+                .min(detail_area.width as u32) as u16;
+        }
+        let preview = layout_gallery::render_preview(&entry.layout, preview_w, preview_h);
+        let preview_style = Style::default().fg(color(theme.window_border));
+        let lines: Vec<Line> = preview
+            .into_iter()
+            .map(|row| Line::from(Span::styled(row, preview_style)))
+            .collect();
+        Paragraph::new(lines).render(
+            Rect::new(detail_area.x, preview_top, preview_w, preview_h),
+            buf,
+        );
+    }
+}
+
+impl super::TuiFrontend {
+    pub(crate) fn open_layout_gallery(&mut self, app_core: &mut AppCore) {
+        app_core.needs_render = true;
+        let entries = match Config::layouts_dir() {
+            Ok(dir) => layout_gallery::scan(&dir, &Config::bundled_layout_names()),
+            Err(e) => {
+                app_core.add_system_message(&format!("Failed to get layouts directory: {}", e));
+                return;
+            }
+        };
+        if entries.is_empty() {
+            app_core.add_system_message("No layouts found");
+            return;
+        }
+        self.layout_gallery = Some(LayoutGallery::new(entries));
+        app_core.ui_state.input_mode = InputMode::LayoutGallery;
+    }
+
+    pub(crate) fn handle_layout_gallery_key(&mut self, code: KeyCode, app_core: &mut AppCore) {
+        app_core.needs_render = true;
+        let Some(gallery) = self.layout_gallery.as_mut() else {
+            app_core.ui_state.input_mode = InputMode::Normal;
+            return;
+        };
+        let last = gallery.entries.len().saturating_sub(1);
+        if gallery.confirm {
+            match code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    let name = gallery.entries[gallery.selected].name.clone();
+                    self.layout_gallery = None;
+                    app_core.ui_state.input_mode = InputMode::Normal;
+                    let (width, height) = self.size();
+                    if let Some((theme_id, theme)) =
+                        app_core.apply_gallery_layout(&name, width, height)
+                    {
+                        self.update_theme_cache(theme_id, theme);
+                    }
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => gallery.confirm = false,
+                _ => {}
+            }
+            return;
+        }
+        match code {
+            KeyCode::Up => gallery.selected = gallery.selected.saturating_sub(1),
+            KeyCode::Down => gallery.selected = (gallery.selected + 1).min(last),
+            KeyCode::PageUp => gallery.selected = gallery.selected.saturating_sub(10),
+            KeyCode::PageDown => gallery.selected = (gallery.selected + 10).min(last),
+            KeyCode::Home => gallery.selected = 0,
+            KeyCode::End => gallery.selected = last,
+            KeyCode::Enter => gallery.confirm = true,
+            KeyCode::Esc => {
+                self.layout_gallery = None;
+                app_core.ui_state.input_mode = InputMode::Normal;
+            }
+            _ => {}
+        }
+    }
+}
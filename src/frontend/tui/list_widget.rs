@@ -11,7 +11,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget as RatatuiWidget},
+    widgets::{Block, Borders, Clear, Paragraph, Widget as RatatuiWidget},
 };
 use std::collections::VecDeque;
 
@@ -167,7 +167,7 @@ impl ListWidget {
 
         let segment = TextSegment {
             text,
-            fg: color,
+            fg: color.as_deref().map(crate::data::intern::intern),
             bg: None,
             bold: false,
             mono: false,
@@ -224,8 +224,8 @@ impl ListWidget {
 
         self.current_line.push(TextSegment {
             text,
-            fg,
-            bg,
+            fg: fg.as_deref().map(crate::data::intern::intern),
+            bg: bg.as_deref().map(crate::data::intern::intern),
             bold,
             mono: false,
             span_type,
@@ -274,6 +274,21 @@ impl ListWidget {
         self.scroll_offset = self.scroll_offset.saturating_sub(amount);
     }
 
+    /// Top visible line while scrolled back (None = pinned to the bottom),
+    /// for carrying the view across a clear-and-rebuild
+    pub fn scroll_position(&self) -> Option<usize> {
+        (self.scroll_offset > 0).then(|| self.get_start_line())
+    }
+
+    /// Restore a `scroll_position()` taken before a rebuild, keeping the
+    /// same top line in view. A pinned view stays pinned.
+    pub fn restore_scroll_position(&mut self, start_line: Option<usize>) {
+        if let Some(start) = start_line {
+            let max_start = self.lines.len().saturating_sub(self.inner_height);
+            self.scroll_offset = max_start.saturating_sub(start);
+        }
+    }
+
     /// Get the start line offset (which line is shown at the top of the visible area)
     /// This is needed for click detection to map visual rows to actual line indices
     pub fn get_start_line(&self) -> usize {
@@ -585,15 +600,9 @@ impl ListWidget {
                 .border_style(Style::default().fg(border_color));
 
             // Apply border type
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             if !self.title.is_empty() {
                 block = block.title(self.title.as_str());
@@ -0,0 +1,101 @@
+//! Safety lockout popup: a command matching a `[lockout]` pattern waits
+//! here for y/n before it goes to the game (see core::lockout).
+
+use super::crossterm_bridge;
+use crate::core::AppCore;
+use crate::data::input::KeyCode;
+use crate::data::LockoutPrompt;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+pub(crate) fn render_lockout_prompt(
+    prompt: &LockoutPrompt,
+    screen: Rect,
+    buf: &mut Buffer,
+    theme: &crate::theme::AppTheme,
+) {
+    let color = crossterm_bridge::to_ratatui_color;
+    // command + pattern + blank + buttons, plus borders
+    let height = 6u16;
+    let width = screen
+        .width
+        .saturating_sub(4)
+        .clamp(20, 60)
+        .min(screen.width);
+    let area = Rect::new(
+        screen.x + screen.width.saturating_sub(width) / 2,
+        screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height.min(screen.height),
+    );
+
+    Clear.render(area, buf);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(super::border_glyphs::themed(
+            ratatui::symbols::border::ROUNDED,
+        ))
+        .border_style(Style::default().fg(color(theme.form_error)))
+        .title(" Send this command? ")
+        .title_style(
+            Style::default()
+                .fg(color(theme.window_title))
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(color(theme.window_background)));
+
+    let text_width = width.saturating_sub(4) as usize;
+    let shown = |text: &str| -> String {
+        crate::frontend::common::text_width::truncate_ellipsis(text, text_width)
+    };
+    let lines = vec![
+        Line::from(Span::styled(
+            format!(" {}", shown(&prompt.command)),
+            Style::default()
+                .fg(color(theme.text_primary))
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(" {}", shown(&format!("matches lockout {}", prompt.pattern))),
+            Style::default().fg(color(theme.text_secondary)),
+        )),
+        Line::default(),
+        Line::from(vec![
+            Span::raw(" "),
+            Span::styled(
+                "[Send (y)]",
+                Style::default().fg(color(theme.text_secondary)),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                "[Cancel (n/Esc)]",
+                Style::default()
+                    .fg(color(theme.text_selected))
+                    .bg(color(theme.background_selected))
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    Paragraph::new(lines).block(block).render(area, buf);
+}
+
+impl super::TuiFrontend {
+    /// Keys while the lockout popup is open: y sends, n/Esc/Enter cancel.
+    /// Everything else is swallowed, so a stray keypress can't confirm.
+    pub(crate) fn handle_lockout_prompt_key(&mut self, code: KeyCode, app_core: &mut AppCore) {
+        match code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app_core.resolve_lockout(true),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Enter => {
+                app_core.resolve_lockout(false)
+            }
+            _ => {}
+        }
+        app_core.needs_render = true;
+    }
+}
@@ -166,8 +166,13 @@ pub fn handle_menu_action(
             }
             "action:addhighlight" => {
                 // Open highlight form for creating new highlight
-                frontend.highlight_form =
-                    Some(crate::frontend::tui::highlight_form::HighlightFormWidget::new());
+                let mut form = crate::frontend::tui::highlight_form::HighlightFormWidget::new();
+                form.set_window_choices(
+                    crate::frontend::tui::highlight_form::HighlightFormWidget::scope_names(
+                        &app_core.ui_state,
+                    ),
+                );
+                frontend.highlight_form = Some(form);
                 // Close menus so only the form remains
                 close_all_menus(&mut app_core.ui_state);
                 app_core.ui_state.input_mode = InputMode::HighlightForm;
@@ -293,6 +298,69 @@ pub fn handle_menu_action(
                 close_all_menus(&mut app_core.ui_state);
                 app_core.ui_state.input_mode = InputMode::ThemeBrowser;
             }
+            action if action.starts_with("action:view:") => {
+                let source = action.strip_prefix("action:view:").unwrap_or("last");
+                if let Some((title, lines)) = app_core.viewer_content(source) {
+                    frontend.text_viewer = Some(
+                        crate::frontend::tui::text_viewer::TextViewer::new(title, lines),
+                    );
+                    close_all_menus(&mut app_core.ui_state);
+                    app_core.ui_state.input_mode = InputMode::TextViewer;
+                }
+            }
+            action if action.starts_with("action:help:") => {
+                let topic = action.strip_prefix("action:help:").unwrap_or_default();
+                let page = app_core.help_page(topic);
+                let mut viewer =
+                    crate::frontend::tui::text_viewer::TextViewer::new(page.title, page.lines);
+                if let Some(query) = page.search {
+                    viewer.search(&query);
+                }
+                frontend.text_viewer = Some(viewer);
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.input_mode = InputMode::TextViewer;
+            }
+            action if action.starts_with("action:inspect:") => {
+                let filter = action.strip_prefix("action:inspect:").unwrap_or_default();
+                let (title, lines) = app_core.inspect_page(filter);
+                let mut viewer = crate::frontend::tui::text_viewer::TextViewer::new(title, lines);
+                // Newest lines are at the bottom
+                viewer.scroll_to_bottom();
+                frontend.text_viewer = Some(viewer);
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.input_mode = InputMode::TextViewer;
+            }
+            action if action.starts_with("action:viewlog:") => {
+                let date = action
+                    .strip_prefix("action:viewlog:")
+                    .filter(|date| !date.is_empty());
+                match app_core.open_log_viewer(date) {
+                    Ok((title, pager)) => {
+                        frontend.text_viewer = Some(
+                            crate::frontend::tui::text_viewer::TextViewer::for_log(title, pager),
+                        );
+                        close_all_menus(&mut app_core.ui_state);
+                        app_core.ui_state.input_mode = InputMode::TextViewer;
+                    }
+                    Err(e) => app_core.add_system_message(&format!("{:#}", e)),
+                }
+            }
+            "action:notes" => {
+                frontend.notes_editor = Some(crate::frontend::tui::notes_editor::NotesEditor::new(
+                    app_core.notes.text(),
+                ));
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.input_mode = InputMode::NotesEditor;
+            }
+            "action:layoutgallery" => {
+                close_all_menus(&mut app_core.ui_state);
+                frontend.open_layout_gallery(app_core);
+            }
+            action if action.starts_with("action:cmdlog:") => {
+                let query = action.strip_prefix("action:cmdlog:").unwrap_or_default();
+                close_all_menus(&mut app_core.ui_state);
+                frontend.open_command_log(query, app_core);
+            }
             action if action.starts_with("action:settheme:") => {
                 // Update frontend theme cache when theme changes via .settheme command
                 let theme_id = action.strip_prefix("action:settheme:").unwrap().to_string();
@@ -351,6 +419,76 @@ pub fn handle_menu_action(
                 frontend.sync_tabbed_active_state(app_core);
                 app_core.needs_render = true;
             }
+            action if action.starts_with("action:showtab:") => {
+                // A tab picked from a tabbed window's overflow list
+                let target = action.strip_prefix("action:showtab:").unwrap_or_default();
+                if let Some((window_name, index)) = target.rsplit_once(':') {
+                    if let (Some(tabbed), Ok(index)) = (
+                        frontend
+                            .widget_manager
+                            .tabbed_text_windows
+                            .get_mut(window_name),
+                        index.parse::<usize>(),
+                    ) {
+                        tabbed.switch_to_tab(index);
+                    }
+                }
+                frontend.sync_tabbed_active_state(app_core);
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.input_mode = InputMode::Normal;
+                app_core.needs_render = true;
+            }
+            // Function keybinds (core::action_registry)
+            action if action.starts_with("action:scrollwindow:") => {
+                let target = action
+                    .strip_prefix("action:scrollwindow:")
+                    .unwrap_or_default();
+                if let Some((window_name, lines)) = target.rsplit_once(':') {
+                    if app_core.ui_state.windows.contains_key(window_name) {
+                        frontend.scroll_window(window_name, lines.parse().unwrap_or(0));
+                    } else {
+                        app_core.add_system_message(&format!("No window named '{}'", window_name));
+                    }
+                }
+                app_core.needs_render = true;
+            }
+            action if action.starts_with("action:switchtab:") => {
+                let tab = action.strip_prefix("action:switchtab:").unwrap_or_default();
+                if frontend.switch_tab_all(tab) {
+                    frontend.sync_tabbed_active_state(app_core);
+                } else {
+                    app_core.add_system_message(&format!("No tab named '{}'", tab));
+                }
+                app_core.needs_render = true;
+            }
+            // Window right-click menu
+            action if action.starts_with("action:clearwindow:") => {
+                let name = action
+                    .strip_prefix("action:clearwindow:")
+                    .unwrap_or_default();
+                app_core.clear_window(name);
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.input_mode = InputMode::Normal;
+                app_core.needs_render = true;
+            }
+            action if action.starts_with("action:timestamps:") => {
+                let name = action
+                    .strip_prefix("action:timestamps:")
+                    .unwrap_or_default();
+                app_core.toggle_window_timestamps(name);
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.input_mode = InputMode::Normal;
+                app_core.needs_render = true;
+            }
+            action if action.starts_with("action:searchwindow:") => {
+                // Search runs in the focused window
+                let name = action
+                    .strip_prefix("action:searchwindow:")
+                    .unwrap_or_default();
+                close_all_menus(&mut app_core.ui_state);
+                app_core.ui_state.set_focus(Some(name.to_string()));
+                app_core.start_search_mode();
+            }
             "action:gonew" => {
                 // Navigate to next tab with unread messages
                 if !frontend.go_to_next_unread_tab() {
@@ -101,6 +101,7 @@ pub fn build_settings_items_with_source(
                 "double".to_string(),
                 "rounded".to_string(),
                 "thick".to_string(),
+                "ascii".to_string(),
                 "none".to_string(),
             ],
         ),
@@ -154,6 +155,39 @@ pub fn build_settings_items_with_source(
         is_global: ui_is_global,
     });
 
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.selection_copy_ansi".to_string(),
+        display_name: "Copy With Colors".to_string(),
+        value: SettingValue::Boolean(config.ui.selection_copy_ansi),
+        description: Some("Copy selections as ANSI-colored text (Alt flips it)".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.reduced_motion".to_string(),
+        display_name: "Reduced Motion".to_string(),
+        value: SettingValue::Boolean(config.ui.reduced_motion),
+        description: Some("Disable glides, spinners, and cursor blinking".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.screen_reader".to_string(),
+        display_name: "Screen Reader Mode".to_string(),
+        value: SettingValue::Boolean(config.ui.screen_reader),
+        description: Some("Plain borders, spoken focus changes (needs TTS)".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
     items.push(SettingItem {
         category: "UI".to_string(),
         key: "ui.drag_modifier_key".to_string(),
@@ -179,6 +213,171 @@ pub fn build_settings_items_with_source(
         is_global: ui_is_global,
     });
 
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.history_expansion".to_string(),
+        display_name: "History Expansion".to_string(),
+        value: SettingValue::Boolean(config.ui.history_expansion),
+        description: Some("Expand !! and !prefix to earlier commands".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.history_prefix_search".to_string(),
+        display_name: "History Prefix Search".to_string(),
+        value: SettingValue::Boolean(config.ui.history_prefix_search),
+        description: Some("Ctrl+P/N recall only commands starting with the typed text".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.hide_prompt".to_string(),
+        display_name: "Hide Prompt Line".to_string(),
+        value: SettingValue::Boolean(config.ui.hide_prompt),
+        description: Some("Keep the game prompt out of the main window".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.command_echo".to_string(),
+        display_name: "Command Echo".to_string(),
+        value: SettingValue::Boolean(config.ui.command_echo),
+        description: Some("Show sent commands in the main window".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.command_echo_prefix".to_string(),
+        display_name: "Command Echo Prefix".to_string(),
+        value: SettingValue::String(config.ui.command_echo_prefix.clone()),
+        description: Some("Text between the prompt and an echoed command".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.mask_secrets".to_string(),
+        display_name: "Mask Secrets".to_string(),
+        value: SettingValue::Boolean(config.ui.mask_secrets),
+        description: Some(
+            "Hide replies to game password prompts from echo, logs and traffic capture".to_string(),
+        ),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.paste_confirm_lines".to_string(),
+        display_name: "Paste Confirm Lines".to_string(),
+        value: SettingValue::Number(config.ui.paste_confirm_lines as i64),
+        description: Some("Ask before sending pastes with this many lines (0 = never)".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.max_fps".to_string(),
+        display_name: "Max FPS".to_string(),
+        value: SettingValue::Number(config.ui.max_fps as i64),
+        description: Some("Render rate while text or input is arriving".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.idle_fps".to_string(),
+        display_name: "Idle FPS".to_string(),
+        value: SettingValue::Number(config.ui.idle_fps as i64),
+        description: Some("Render rate once nothing is changing".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "spellcheck.enabled".to_string(),
+        display_name: "Spellcheck Speech".to_string(),
+        value: SettingValue::Boolean(config.spellcheck.enabled),
+        description: Some("Underline misspelled words in says, whispers and thoughts".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.keyboard_layout".to_string(),
+        display_name: "Keyboard Layout".to_string(),
+        value: SettingValue::Enum(
+            config.ui.keyboard_layout.clone(),
+            crate::core::key_layout::KeyboardLayout::ALL
+                .iter()
+                .map(|layout| layout.name().to_string())
+                .collect(),
+        ),
+        description: Some("Unbound keys retry as the QWERTY key in the same position".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.emoji_width".to_string(),
+        display_name: "Emoji Width".to_string(),
+        value: SettingValue::Enum(
+            config.ui.emoji_width.to_string(),
+            crate::config::EmojiWidth::ALL
+                .iter()
+                .map(|width| width.to_string())
+                .collect(),
+        ),
+        description: Some("Use narrow if your terminal draws emoji one column wide".to_string()),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
+    items.push(SettingItem {
+        category: "UI".to_string(),
+        key: "ui.locale".to_string(),
+        display_name: "Locale".to_string(),
+        value: SettingValue::Enum(
+            config.ui.locale.to_string(),
+            crate::config::Locale::ALL
+                .iter()
+                .map(|locale| locale.to_string())
+                .collect(),
+        ),
+        description: Some(
+            "Thousands separator and 12/24-hour clock for stats and timestamps".to_string(),
+        ),
+        editable: true,
+        name_width: None,
+        is_global: ui_is_global,
+    });
+
     // Sound settings - can be global or character override
     let sound_is_global = !character_config_exists;
 
@@ -41,6 +41,7 @@ pub struct MiniVitals {
     show_border: bool,
     /// Which border sides to show
     border_sides: BorderSides,
+    border_style: Option<String>,
     /// Cached vitals for rendering
     health_value: u32,
     health_max: u32,
@@ -80,6 +81,7 @@ impl MiniVitals {
             show_title: true,
             show_border,
             border_sides: BorderSides::default(),
+            border_style: None,
             health_value: 0,
             health_max: 100,
             health_text: String::new(),
@@ -135,6 +137,11 @@ impl MiniVitals {
         self.border_sides = sides;
     }
 
+    /// Set the border glyph style (see border_glyphs)
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
     /// Set display mode (full, numbers_only, current_only)
     pub fn set_display_mode(&mut self, numbers_only: bool, current_only: bool) {
         self.display_mode = if current_only {
@@ -335,6 +342,9 @@ impl MiniVitals {
             let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
             let mut block = Block::default()
                 .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
                 .border_style(Style::default().fg(self.border_color));
             if self.show_title {
                 block = block.title(self.title.as_str());
@@ -3,13 +3,20 @@
 //! This module implements the Frontend trait for terminal rendering.
 
 mod active_effects;
+mod alarm_flash;
 mod betrayer;
+mod border_glyphs;
+mod calendar;
 pub mod color_form;
 pub mod color_palette_browser;
 mod colors;
+mod combat_stats;
 mod command_input;
 mod command_line;
+mod command_log_palette;
+mod compact;
 mod compass;
+mod compose;
 mod container_window;
 mod countdown;
 pub mod crossterm_bridge;
@@ -17,6 +24,7 @@ mod dashboard;
 mod dialog;
 mod encumbrance;
 mod experience;
+mod frame_pacer;
 mod frontend_impl;
 mod gs4_experience;
 mod hand;
@@ -33,16 +41,23 @@ mod inventory_window;
 mod items;
 pub mod keybind_browser;
 pub mod keybind_form;
+mod layout_gallery;
 mod list_widget;
+mod lockout_prompt;
 pub mod menu_actions;
 pub mod menu_builders;
 mod minivitals;
+mod notes;
+mod notes_editor;
+mod paste_guard;
 mod perception;
 mod performance_stats;
+mod played;
 mod players;
 mod popup_menu;
 mod progress_bar;
 mod quickbar;
+mod reminders;
 mod resize;
 mod room_window;
 mod room_window_ops;
@@ -50,21 +65,29 @@ mod runtime;
 mod scrollable_container;
 mod search;
 pub mod settings_editor;
+mod society_tasks;
 mod spacer;
 pub mod spell_color_browser;
 pub mod spell_color_form;
+mod spell_suggest;
 mod spells_window;
+mod stance;
 mod sync;
 mod tabbed_text_window;
 mod targets;
+mod task_tracker;
 mod terminal_title;
+mod text_viewer;
 mod text_window;
 pub mod textarea_bridge;
 pub mod theme_browser;
 mod theme_cache;
 pub mod theme_editor;
 mod title_position;
+mod traffic;
 pub mod uicolors_browser;
+mod watch;
+mod wealth;
 mod widget_manager;
 pub mod window_editor;
 
@@ -116,8 +139,22 @@ pub struct TuiFrontend {
     pub theme_editor: Option<theme_editor::ThemeEditor>,
     /// Active settings editor (if any)
     pub settings_editor: Option<settings_editor::SettingsEditor>,
+    /// Active long-output popup viewer (if any)
+    pub text_viewer: Option<text_viewer::TextViewer>,
+    /// Active multi-line compose popup (if any)
+    pub compose: Option<compose::ComposeEditor>,
+    /// Active notes editor popup (if any)
+    pub notes_editor: Option<notes_editor::NotesEditor>,
+    /// Active spellcheck suggestion popup (if any)
+    pub spell_suggest: Option<spell_suggest::SpellSuggest>,
+    /// Active command log palette (if any)
+    pub command_log_palette: Option<command_log_palette::CommandLogPalette>,
+    /// Active layout gallery (if any)
+    pub layout_gallery: Option<layout_gallery::LayoutGallery>,
     /// Debouncer for terminal resize events (100ms debounce)
     resize_debouncer: ResizeDebouncer,
+    /// How long `poll_events` waits for the first event (set by frame pacing)
+    poll_timeout: std::time::Duration,
     /// Theme cache to avoid HashMap lookup + clone every render
     theme_cache: ThemeCache,
     /// Cached window render order (sorted, ephemerals + overlay moved last),
@@ -129,6 +166,8 @@ pub struct TuiFrontend {
     config_sync_snapshot: ConfigSyncSnapshot,
     /// True for renders where def/theme-derived config must be re-applied.
     pub(crate) config_sync_needed: bool,
+    /// Cursor switched to a steady (non-blinking) shape for `ui.reduced_motion`
+    steady_cursor: bool,
 }
 
 /// Inputs that feed the per-widget config-application blocks in sync.rs.
@@ -164,12 +203,15 @@ impl ConfigSyncSnapshot {
 /// allocations), so the cache can never serve a stale order.
 #[derive(Default)]
 pub(crate) struct WindowOrderCache {
-    /// Sorted names with ephemeral windows, then performance_overlay, at the end
+    /// Sorted names with ephemeral windows, floating windows (in stacking
+    /// order), then performance_overlay, at the end
     pub render_order: Vec<String>,
     /// Window name -> position in render_order (z-index for selection logic)
     pub render_index: std::collections::HashMap<String, usize>,
     /// Ephemeral membership snapshot used for the validity check
     ephemeral: Vec<String>,
+    /// Floating stack snapshot used for the validity check (order matters)
+    floating: Vec<String>,
 }
 
 impl WindowOrderCache {
@@ -184,9 +226,10 @@ impl WindowOrderCache {
                 .ephemeral
                 .iter()
                 .all(|n| ui_state.ephemeral_windows.contains(n))
+            && self.floating == ui_state.floating_windows
     }
 
-    /// Rebuild if the window set or ephemeral membership changed
+    /// Rebuild if the window set, ephemeral membership, or floating stack changed
     pub(crate) fn refresh(&mut self, ui_state: &crate::data::ui_state::UiState) {
         if self.is_valid(ui_state) {
             return;
@@ -205,6 +248,16 @@ impl WindowOrderCache {
         order.retain(|n| !ui_state.ephemeral_windows.contains(n));
         order.extend(ephemeral.iter().cloned());
 
+        // Floating windows render above everything else, bottom of the stack first
+        let floating: Vec<String> = ui_state
+            .floating_windows
+            .iter()
+            .filter(|n| ui_state.windows.contains_key(*n))
+            .cloned()
+            .collect();
+        order.retain(|n| !floating.contains(n));
+        order.extend(floating);
+
         // Performance overlay renders last (on very top)
         if let Some(pos) = order.iter().position(|n| n == "performance_overlay") {
             let overlay = order.remove(pos);
@@ -218,6 +271,7 @@ impl WindowOrderCache {
             .collect();
         self.render_order = order;
         self.ephemeral = ephemeral;
+        self.floating = ui_state.floating_windows.clone();
     }
 }
 
@@ -229,7 +283,9 @@ impl TuiFrontend {
         execute!(
             stdout,
             EnterAlternateScreen,
-            crossterm::event::EnableMouseCapture
+            crossterm::event::EnableMouseCapture,
+            crossterm::event::EnableBracketedPaste,
+            crossterm::event::EnableFocusChange
         )?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
@@ -252,11 +308,19 @@ impl TuiFrontend {
             theme_browser: None,
             theme_editor: None,
             settings_editor: None,
+            text_viewer: None,
+            compose: None,
+            notes_editor: None,
+            spell_suggest: None,
+            command_log_palette: None,
+            layout_gallery: None,
             resize_debouncer: ResizeDebouncer::new(300), // 300ms debounce
+            poll_timeout: std::time::Duration::from_millis(16),
             theme_cache: ThemeCache::new(),
             window_order_cache: WindowOrderCache::default(),
             config_sync_snapshot: ConfigSyncSnapshot::default(),
             config_sync_needed: true,
+            steady_cursor: false,
         })
     }
 
@@ -265,6 +329,11 @@ impl TuiFrontend {
         self.theme_cache.update(theme_id, theme);
     }
 
+    /// Set how long the next `poll_events` may block waiting for input
+    pub fn set_poll_timeout(&mut self, timeout: std::time::Duration) {
+        self.poll_timeout = timeout;
+    }
+
     /// Get the terminal size (width, height)
     pub fn size(&self) -> (u16, u16) {
         let size = self.terminal.size().unwrap_or_default();
@@ -285,6 +354,23 @@ impl TuiFrontend {
         }
     }
 
+    /// Show the tab named `name` in every tabbed window that has one.
+    /// Returns false if none does.
+    pub fn switch_tab_all(&mut self, name: &str) -> bool {
+        let mut found = false;
+        for widget in self.widget_manager.tabbed_text_windows.values_mut() {
+            if let Some(index) = widget
+                .get_tab_names()
+                .iter()
+                .position(|tab| tab.eq_ignore_ascii_case(name))
+            {
+                widget.switch_to_tab(index);
+                found = true;
+            }
+        }
+        found
+    }
+
     /// Navigate to next tab with unread messages (searches all tabbed windows)
     /// Returns true if found, false if no unread tabs
     pub fn go_to_next_unread_tab(&mut self) -> bool {
@@ -304,12 +390,69 @@ impl TuiFrontend {
                     let active = widget.get_active_tab_index();
                     if active < tabbed.tabs.len() {
                         tabbed.active_tab_index = active;
+                        tabbed.clear_active_tab_unread();
                     }
                 }
             }
         }
     }
 
+    /// If (x, y) is on a text window's "N new lines" pill, jump that window
+    /// back to the live view. Returns whether the click was consumed.
+    pub(crate) fn jump_to_live_at(&mut self, window_name: &str, x: u16, y: u16) -> bool {
+        if let Some(text_window) = self.widget_manager.text_windows.get_mut(window_name) {
+            if text_window.new_lines_pill_contains(x, y) {
+                text_window.scroll_to_live();
+                return true;
+            }
+        } else if let Some(tabbed) = self.widget_manager.tabbed_text_windows.get_mut(window_name) {
+            if tabbed.new_lines_pill_contains(x, y) {
+                tabbed.scroll_to_live();
+                return true;
+            }
+        }
+        false
+    }
+
+    /// If (x, y) is on a tabbed window's tab bar, scroll the tabs instead of
+    /// the text. Returns whether the wheel was consumed.
+    pub(crate) fn scroll_tab_bar_at(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        window_name: &str,
+        x: u16,
+        y: u16,
+        delta: isize,
+    ) -> bool {
+        let Some(pos) = app_core
+            .ui_state
+            .get_window(window_name)
+            .map(|w| &w.position)
+        else {
+            return false;
+        };
+        let rect = ratatui::layout::Rect::new(pos.x, pos.y, pos.width, pos.height);
+        self.widget_manager
+            .tabbed_text_windows
+            .get_mut(window_name)
+            .is_some_and(|tabbed| tabbed.scroll_tab_bar(rect, x, y, delta))
+    }
+
+    /// Name of the spells-window group header at a click position, if any
+    pub(crate) fn spell_group_at(
+        &self,
+        window_name: &str,
+        x: u16,
+        y: u16,
+        area: ratatui::layout::Rect,
+    ) -> Option<String> {
+        self.widget_manager
+            .spells_windows
+            .get(window_name)?
+            .group_at(x, y, area)
+            .map(str::to_string)
+    }
+
     /// Scroll a window by a number of lines across supported widget types
     pub fn scroll_window(&mut self, window_name: &str, lines: i32) {
         // Try text window first
@@ -0,0 +1,238 @@
+//! Notes widget.
+//!
+//! Shows the character notepad, or one `# Heading` section of it, with
+//! headings in bold. `.notes` opens the editor. With input focus the
+//! arrows and PgUp/PgDn scroll it and Enter opens the editor.
+//!
+//! Reads data from AppCore.notes (see core::notes).
+
+use crate::config::BorderSides;
+use crate::core::notes::Notes as NotesFile;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget, Wrap},
+};
+
+/// Notes widget - the notepad text, wrapped
+pub struct Notes {
+    title: String,
+    section: String,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached lines for rendering
+    lines: Vec<String>,
+    /// (notes generation, section) the cache was built from
+    cache_key: Option<(u64, String)>,
+    /// First visible row
+    scroll: usize,
+    border_color: Color,
+    /// Set while the window has input focus
+    focused_border_color: Option<Color>,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Notes {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            section: String::new(),
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            lines: Vec::new(),
+            cache_key: None,
+            scroll: 0,
+            border_color: Color::White,
+            focused_border_color: None,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    /// Border color while the window has input focus (None = unfocused)
+    pub fn set_focused_border_color(&mut self, color: Option<Color>) {
+        self.focused_border_color = color;
+    }
+
+    /// Scroll by `delta` rows (negative = up). Clamped when rendered.
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll = self.scroll.saturating_add_signed(delta);
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = usize::MAX;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    /// Only show this section ("" for the whole file)
+    pub fn set_section(&mut self, section: &str) {
+        self.section = section.to_string();
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Rebuild the lines if the notes or the section changed. Returns true
+    /// if the display changed.
+    pub fn update_from_state(&mut self, notes: &NotesFile) -> bool {
+        let key = (notes.generation, self.section.clone());
+        if self.cache_key.as_ref() == Some(&key) {
+            return false;
+        }
+        self.lines = notes
+            .lines(Some(&self.section))
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        self.cache_key = Some(key);
+        true
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let border_style = match self.focused_border_color {
+                Some(color) => Style::default().fg(color).add_modifier(Modifier::BOLD),
+                None => Style::default().fg(self.border_color),
+            };
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(border_style);
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        if self.lines.iter().all(|line| line.trim().is_empty()) {
+            let empty = if self.section.trim().is_empty() {
+                "No notes (.notes)".to_string()
+            } else {
+                format!("No \"{}\" section (.notes)", self.section.trim())
+            };
+            Paragraph::new(Line::from(Span::styled(
+                empty,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .render(inner, buf);
+            return;
+        }
+        let text = Style::default().fg(self.text_color);
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let style = if line.starts_with('#') {
+                    text.add_modifier(Modifier::BOLD)
+                } else {
+                    text
+                };
+                Line::from(Span::styled(line.as_str(), style))
+            })
+            .collect();
+        // Wrapped height, estimated by width, so the last page stays full
+        let width = inner.width as usize;
+        let rows: usize = self
+            .lines
+            .iter()
+            .map(|line| line.chars().count().div_ceil(width).max(1))
+            .sum();
+        self.scroll = self.scroll.min(rows.saturating_sub(inner.height as usize));
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll.min(u16::MAX as usize) as u16, 0))
+            .render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shows_the_configured_section() {
+        let mut notes = NotesFile::default();
+        notes.set_text("# Hunt\nbounty\n# Shop\narrows\n".to_string());
+        let mut widget = Notes::new("Notes");
+        widget.set_show_border(false);
+        widget.set_section("shop");
+        assert!(widget.update_from_state(&notes));
+        assert!(!widget.update_from_state(&notes));
+
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row =
+            |buf: &Buffer, y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(&buf, 0).starts_with("arrows"));
+
+        widget.set_section("");
+        assert!(widget.update_from_state(&notes));
+        widget.render(area, &mut buf);
+        assert!(row(&buf, 0).starts_with("# Hunt"));
+
+        widget.scroll_to_bottom();
+        widget.render(area, &mut buf);
+        assert!(row(&buf, 0).starts_with("# Shop"));
+        widget.scroll_by(-1);
+        widget.render(area, &mut buf);
+        assert!(row(&buf, 0).starts_with("bounty"));
+    }
+}
@@ -0,0 +1,175 @@
+//! Notes editor popup (`.notes`).
+//!
+//! Edits the whole character notepad in a `tui_textarea::TextArea`, drawn
+//! word-wrapped like the compose popup. Text goes back to core
+//! (`AppCore::set_notes`) on Ctrl+S, on close, and a couple of seconds
+//! after typing stops, so nothing is lost if the client goes away.
+
+use super::crossterm_bridge;
+use super::textarea_bridge::GraphemeInput;
+use crate::data::input::{KeyCode, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Widget},
+};
+use std::time::{Duration, Instant};
+use tui_textarea::TextArea;
+
+/// How long after the last edit the notes are saved
+const AUTOSAVE_DELAY: Duration = Duration::from_secs(2);
+
+/// Outcome of a key press in the notes editor
+pub enum NotesEditorResult {
+    None,
+    /// Ctrl+S: save and keep editing
+    Save(String),
+    /// Esc: save and close
+    Close(String),
+}
+
+pub struct NotesEditor {
+    textarea: TextArea<'static>,
+    /// First visible wrapped row
+    scroll: usize,
+    /// When the unsaved edits started
+    dirty_since: Option<Instant>,
+    /// Last edit, for the autosave delay
+    last_edit: Instant,
+}
+
+impl NotesEditor {
+    pub fn new(text: &str) -> Self {
+        let lines: Vec<String> = text.lines().map(str::to_string).collect();
+        let mut textarea = if lines.is_empty() {
+            TextArea::default()
+        } else {
+            TextArea::new(lines)
+        };
+        textarea.set_cursor_line_style(Style::default());
+        Self {
+            textarea,
+            scroll: 0,
+            dirty_since: None,
+            last_edit: Instant::now(),
+        }
+    }
+
+    /// The notes text, with a trailing newline unless empty
+    pub fn text(&self) -> String {
+        let mut text = self.textarea.lines().join("\n");
+        if !text.trim().is_empty() {
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Ctrl+S saves, Esc saves and closes; everything else edits.
+    pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> NotesEditorResult {
+        match code {
+            KeyCode::Esc => return NotesEditorResult::Close(self.text()),
+            KeyCode::Char('s') | KeyCode::Char('S') if modifiers.ctrl => {
+                self.dirty_since = None;
+                return NotesEditorResult::Save(self.text());
+            }
+            _ => {}
+        }
+        let ct_event = crossterm::event::KeyEvent::new(
+            crossterm_bridge::to_crossterm_keycode(code),
+            crossterm_bridge::to_crossterm_modifiers(modifiers),
+        );
+        if self
+            .textarea
+            .input_graphemes(super::textarea_bridge::to_textarea_event(ct_event))
+        {
+            self.note_edit(Instant::now());
+        }
+        NotesEditorResult::None
+    }
+
+    fn note_edit(&mut self, now: Instant) {
+        self.dirty_since.get_or_insert(now);
+        self.last_edit = now;
+    }
+
+    /// The text to save once typing has paused, at most once per pause
+    pub fn autosave_due(&mut self, now: Instant) -> Option<String> {
+        self.dirty_since?;
+        if now.duration_since(self.last_edit) < AUTOSAVE_DELAY {
+            return None;
+        }
+        self.dirty_since = None;
+        Some(self.text())
+    }
+
+    pub fn render(&mut self, screen: Rect, buf: &mut Buffer, theme: &crate::theme::AppTheme) {
+        let color = crossterm_bridge::to_ratatui_color;
+        let width = screen
+            .width
+            .saturating_sub(4)
+            .clamp(20, 90)
+            .min(screen.width);
+        let height = screen
+            .height
+            .saturating_sub(4)
+            .clamp(6, 30)
+            .min(screen.height);
+        let area = Rect::new(
+            screen.x + screen.width.saturating_sub(width) / 2,
+            screen.y + screen.height.saturating_sub(height) / 2,
+            width,
+            height,
+        );
+
+        Clear.render(area, buf);
+        let status = if self.dirty_since.is_some() {
+            " Editing - Ctrl+S save, Esc close "
+        } else {
+            " Saved - Ctrl+S save, Esc close "
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(super::border_glyphs::themed(
+                ratatui::symbols::border::ROUNDED,
+            ))
+            .border_style(Style::default().fg(color(theme.window_border)))
+            .title(" Notes ")
+            .title_style(
+                Style::default()
+                    .fg(color(theme.window_title))
+                    .add_modifier(Modifier::BOLD),
+            )
+            .title_bottom(Line::from(Span::styled(
+                status,
+                Style::default().fg(color(theme.text_secondary)),
+            )))
+            .style(Style::default().bg(color(theme.window_background)));
+        let inner = block.inner(area);
+        block.render(area, buf);
+        let text_style = Style::default().fg(color(theme.text_primary));
+        super::compose::render_wrapped(&self.textarea, &mut self.scroll, inner, buf, text_style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autosaves_after_typing_pauses() {
+        let mut editor = NotesEditor::new("# Hunt\nbounty");
+        assert_eq!(editor.text(), "# Hunt\nbounty\n");
+        let start = Instant::now();
+        assert_eq!(editor.autosave_due(start + AUTOSAVE_DELAY), None);
+
+        editor.note_edit(start);
+        assert_eq!(editor.autosave_due(start + Duration::from_secs(1)), None);
+        assert!(editor.autosave_due(start + AUTOSAVE_DELAY).is_some());
+        // Saved; nothing more until the next edit
+        assert_eq!(editor.autosave_due(start + AUTOSAVE_DELAY * 2), None);
+
+        assert_eq!(NotesEditor::new("").text(), "");
+    }
+}
@@ -0,0 +1,178 @@
+//! Paste guard: bracketed pastes into the command input.
+//!
+//! Single lines are inserted like typed text. A paste with
+//! `ui.paste_confirm_lines` or more lines opens a centered popup with the
+//! line count and a preview, offering send-all, send-first-line, or cancel;
+//! the choice is resolved in core (`AppCore::resolve_paste_guard`), which
+//! queues the lines for the runtime to send.
+
+use super::crossterm_bridge;
+use crate::core::{AppCore, PasteAction};
+use crate::data::input::{KeyCode, KeyModifiers};
+use crate::data::{PasteChoice, PasteGuard};
+use anyhow::Result;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// Preview rows shown before the "... N more" line
+const PREVIEW_LINES: usize = 6;
+
+pub(crate) fn render_paste_guard(
+    guard: &PasteGuard,
+    screen: Rect,
+    buf: &mut Buffer,
+    theme: &crate::theme::AppTheme,
+) {
+    let color = crossterm_bridge::to_ratatui_color;
+    let preview_rows = guard.lines.len().min(PREVIEW_LINES);
+    let more = guard.lines.len() - preview_rows;
+    // preview + optional "more" + blank + buttons, plus borders
+    let height = (preview_rows + usize::from(more > 0) + 2 + 2) as u16;
+    let width = screen
+        .width
+        .saturating_sub(4)
+        .clamp(20, 72)
+        .min(screen.width);
+    let area = Rect::new(
+        screen.x + screen.width.saturating_sub(width) / 2,
+        screen.y + screen.height.saturating_sub(height) / 2,
+        width,
+        height.min(screen.height),
+    );
+
+    Clear.render(area, buf);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_set(super::border_glyphs::themed(
+            ratatui::symbols::border::ROUNDED,
+        ))
+        .border_style(Style::default().fg(color(theme.window_border)))
+        .title(format!(" Paste {} lines? ", guard.lines.len()))
+        .title_style(
+            Style::default()
+                .fg(color(theme.window_title))
+                .add_modifier(Modifier::BOLD),
+        )
+        .style(Style::default().bg(color(theme.window_background)));
+
+    let preview_width = width.saturating_sub(4) as usize;
+    let mut lines: Vec<Line> = guard
+        .lines
+        .iter()
+        .take(preview_rows)
+        .map(|line| {
+            let shown: String = line.chars().take(preview_width).collect();
+            Line::from(Span::styled(
+                format!(" {}", shown),
+                Style::default().fg(color(theme.text_primary)),
+            ))
+        })
+        .collect();
+    if more > 0 {
+        lines.push(Line::from(Span::styled(
+            format!(" ... and {} more", more),
+            Style::default().fg(color(theme.text_secondary)),
+        )));
+    }
+    lines.push(Line::default());
+
+    let mut buttons = Vec::new();
+    for (idx, choice) in PasteChoice::ALL.iter().enumerate() {
+        let style = if idx == guard.selected {
+            Style::default()
+                .fg(color(theme.text_selected))
+                .bg(color(theme.background_selected))
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(color(theme.text_secondary))
+        };
+        buttons.push(Span::raw(" "));
+        buttons.push(Span::styled(
+            format!("[{}]", choice.label(guard.lines.len())),
+            style,
+        ));
+    }
+    lines.push(Line::from(buttons));
+
+    Paragraph::new(lines).block(block).render(area, buf);
+}
+
+impl super::TuiFrontend {
+    /// Handle a bracketed paste. Editors and forms get the text replayed as
+    /// keystrokes (what they saw before bracketed paste was enabled); the
+    /// command input goes through the core paste guard.
+    pub(crate) fn handle_paste(
+        &mut self,
+        app_core: &mut AppCore,
+        text: String,
+    ) -> Result<Option<String>> {
+        if app_core.ui_state.boss_mode || app_core.ui_state.paste_guard.is_some() {
+            return Ok(None);
+        }
+        if app_core.ui_state.input_mode != crate::data::InputMode::Normal {
+            let mut command = None;
+            for c in text.chars() {
+                let code = match c {
+                    '\r' => continue,
+                    '\n' => KeyCode::Enter,
+                    c => KeyCode::Char(c),
+                };
+                let result = self.handle_key_event(
+                    code,
+                    KeyModifiers::NONE,
+                    app_core,
+                    super::menu_actions::handle_menu_action,
+                )?;
+                command = command.or(result);
+            }
+            return Ok(command);
+        }
+
+        match app_core.route_paste(&text) {
+            PasteAction::Insert(line) => {
+                if let Some(cmd_input) = self.widget_manager.command_inputs.get_mut("command_input")
+                {
+                    cmd_input.insert_text(&line);
+                }
+            }
+            PasteAction::Sent(count) => {
+                tracing::debug!("Pasted {} lines below the confirm threshold", count);
+            }
+            PasteAction::Confirm => {}
+        }
+        app_core.needs_render = true;
+        Ok(None)
+    }
+
+    /// Keys while the paste confirmation is open: arrows/Tab move, Enter
+    /// picks, a/f/c (or Esc) are shortcuts. Everything else is swallowed.
+    pub(crate) fn handle_paste_guard_key(&mut self, code: KeyCode, app_core: &mut AppCore) {
+        let Some(guard) = app_core.ui_state.paste_guard.as_mut() else {
+            return;
+        };
+        let choice = match code {
+            KeyCode::Left | KeyCode::Up | KeyCode::BackTab => {
+                guard.move_selection(-1);
+                None
+            }
+            KeyCode::Right | KeyCode::Down | KeyCode::Tab => {
+                guard.move_selection(1);
+                None
+            }
+            KeyCode::Enter => Some(guard.selected_choice()),
+            KeyCode::Char('a') | KeyCode::Char('A') => Some(PasteChoice::SendAll),
+            KeyCode::Char('f') | KeyCode::Char('F') => Some(PasteChoice::SendFirst),
+            KeyCode::Char('c') | KeyCode::Char('C') | KeyCode::Esc => Some(PasteChoice::Cancel),
+            _ => None,
+        };
+        if let Some(choice) = choice {
+            app_core.resolve_paste_guard(choice);
+        }
+        app_core.needs_render = true;
+    }
+}
@@ -17,6 +17,7 @@ pub struct PerceptionWindow {
     title: String,
     show_border: bool,
     border_color: Option<Color>,
+    border_style: Option<String>,
     text_color: Option<Color>,
     background_color: Option<Color>,
     entries: Vec<PerceptionEntry>,
@@ -36,6 +37,7 @@ impl PerceptionWindow {
             title,
             show_border: true,
             border_color: None,
+            border_style: None,
             text_color: None,
             background_color: None,
             entries: Vec::new(),
@@ -104,6 +106,11 @@ impl PerceptionWindow {
         self.border_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
     }
 
+    /// Set the border glyph style (see border_glyphs)
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
     /// Set the text color
     pub fn set_text_color(&mut self, color: Option<String>) {
         self.text_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
@@ -122,7 +129,12 @@ impl PerceptionWindow {
         // Create block with optional border
         let mut block = Block::default();
         if self.show_border {
-            block = block.borders(Borders::ALL).title(self.title.as_str());
+            block = block
+                .borders(Borders::ALL)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .title(self.title.as_str());
             if let Some(color) = self.border_color {
                 block = block.border_style(Style::default().fg(color));
             }
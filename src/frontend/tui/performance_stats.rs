@@ -13,7 +13,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Paragraph, Widget},
+    widgets::{Block, Paragraph, Widget},
 };
 
 #[derive(Clone)]
@@ -155,17 +155,9 @@ impl PerformanceStatsWidget {
             Block::default()
         };
 
-        if let Some(style_name) = self.border_style.as_deref() {
-            let border_type = match style_name {
-                "double" => BorderType::Double,
-                "rounded" => BorderType::Rounded,
-                "thick" => BorderType::Thick,
-                "quadrant_inside" => BorderType::QuadrantInside,
-                "quadrant_outside" => BorderType::QuadrantOutside,
-                _ => BorderType::Plain,
-            };
-            block = block.border_type(border_type);
-        }
+        block = block.border_set(super::border_glyphs::border_set(
+            self.border_style.as_deref(),
+        ));
 
         if let Some(color_hex) = &self.border_color {
             if let Some(color) = Self::parse_color(color_hex) {
@@ -199,7 +191,7 @@ impl PerformanceStatsWidget {
             lines.push(Line::from(vec![
                 Span::styled("FPS: ", Style::default().fg(label_color)),
                 Span::styled(
-                    format!("{:.1}", stats.fps()),
+                    format!("{} / {}", stats.effective_fps(), stats.target_fps()),
                     Style::default().fg(value_color),
                 ),
             ]));
@@ -253,6 +245,20 @@ impl PerformanceStatsWidget {
                     Style::default().fg(value_color),
                 ),
             ]));
+            if let Some((wire, inflated)) = stats.compression_totals() {
+                lines.push(Line::from(vec![
+                    Span::styled("Compressed: ", Style::default().fg(label_color)),
+                    Span::styled(
+                        format!(
+                            "{:.1} KB -> {:.1} KB ({:.1}x)",
+                            wire as f64 / 1024.0,
+                            inflated as f64 / 1024.0,
+                            inflated as f64 / wire as f64
+                        ),
+                        Style::default().fg(value_color),
+                    ),
+                ]));
+            }
         }
 
         if self.show_parse {
@@ -278,6 +284,31 @@ impl PerformanceStatsWidget {
                     Style::default().fg(value_color),
                 ),
             ]));
+            lines.push(Line::from(vec![
+                Span::styled("Wait: ", Style::default().fg(label_color)),
+                Span::styled(
+                    format!("{:.0}µs", stats.avg_parse_queue_wait_us()),
+                    Style::default().fg(value_color),
+                ),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Apply: ", Style::default().fg(label_color)),
+                Span::styled(
+                    format!("{:.0}µs", stats.avg_parse_apply_time_us()),
+                    Style::default().fg(value_color),
+                ),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Backlog: ", Style::default().fg(label_color)),
+                Span::styled(
+                    format!(
+                        "{} (max {})",
+                        stats.last_parsed_queue_depth(),
+                        stats.max_parsed_queue_depth()
+                    ),
+                    Style::default().fg(value_color),
+                ),
+            ]));
         }
 
         if self.show_events {
@@ -335,6 +366,19 @@ impl PerformanceStatsWidget {
                     Style::default().fg(value_color),
                 ),
             ]));
+            let interned = stats.intern_stats();
+            lines.push(Line::from(vec![
+                Span::styled("Shared: ", Style::default().fg(label_color)),
+                Span::styled(
+                    format!(
+                        "{:.1} → {:.1} KB ({} strs)",
+                        interned.bytes_unshared as f64 / 1024.0,
+                        interned.bytes_shared as f64 / 1024.0,
+                        interned.unique
+                    ),
+                    Style::default().fg(value_color),
+                ),
+            ]));
         }
 
         if self.show_lines {
@@ -0,0 +1,157 @@
+//! Played widget.
+//!
+//! Shows the session timer, time played today and (optionally) the
+//! lifetime total as label/value rows.
+//!
+//! Reads data from AppCore.played (see core::played).
+
+use crate::config::BorderSides;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Width of the row labels ("Session")
+const LABEL_WIDTH: usize = 8;
+
+/// Played widget - session timer plus daily and total played time
+pub struct Played {
+    title: String,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached rows for rendering
+    rows: Vec<(&'static str, String)>,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Played {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            rows: Vec::new(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<(&'static str, String)>) {
+        self.rows = rows;
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let lines: Vec<Line> = self
+            .rows
+            .iter()
+            .map(|(label, value)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}", label, width = LABEL_WIDTH),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(value.clone(), Style::default().fg(self.text_color)),
+                ])
+            })
+            .collect();
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_label_value_rows() {
+        let mut widget = Played::new("Played");
+        widget.set_show_border(false);
+        widget.set_rows(vec![
+            ("Session", "1:02:03".to_string()),
+            ("Today", "2:00:00".to_string()),
+        ]);
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y| {
+            (0..20)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        };
+        assert_eq!(row(0), "Session 1:02:03");
+        assert_eq!(row(1), "Today   2:00:00");
+    }
+}
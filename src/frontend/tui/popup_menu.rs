@@ -86,6 +86,7 @@ impl PopupMenu {
         // Create block with border
         let block = Block::default()
             .borders(Borders::ALL)
+            .border_set(super::border_glyphs::popup_set())
             .border_style(
                 Style::default().fg(crossterm_bridge::to_ratatui_color(theme.menu_border)),
             )
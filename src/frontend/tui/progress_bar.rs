@@ -4,11 +4,12 @@
 //! chosen by the user.
 
 use super::colors::parse_color_to_ratatui;
+use crate::frontend::common::text_width;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
-    widgets::{Block, BorderType, Borders, Clear, Widget},
+    widgets::{Block, Borders, Clear, Widget},
 };
 
 /// A progress bar widget for displaying vitals (health, mana, stamina, spirit)
@@ -206,15 +207,9 @@ impl ProgressBar {
 
             let mut block = Block::default().borders(borders);
 
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             if let Some(color) = self.border_color {
                 block = block.border_style(Style::default().fg(color));
@@ -253,7 +248,7 @@ impl ProgressBar {
             format!("{}/{}", self.current, self.max)
         };
 
-        let text_width = display_text.len() as u16;
+        let text_width = text_width::width(&display_text) as u16;
         let available_width = inner_area.width;
 
         let bar_color = self.bar_fill.unwrap_or(Color::Green);
@@ -300,20 +295,16 @@ impl ProgressBar {
             };
             let text_fg = Self::ensure_contrast(text_fg_base, contrast_bg);
 
-            for (i, c) in display_text.chars().enumerate() {
-                let x = text_start_x + i as u16;
-                if x < inner_area.x + inner_area.width && x < buf.area().width {
-                    let char_position = x - inner_area.x;
-
-                    buf[(x, y)].set_char(c);
-                    buf[(x, y)].set_fg(text_fg);
-
-                    if char_position < split_position {
-                        buf[(x, y)].set_bg(bar_color);
-                    } else if !self.transparent_background {
-                        buf[(x, y)].set_bg(bar_bg_color);
-                    }
-                }
+            // Backgrounds are already painted; the text only sets fg
+            let right = inner_area.right().min(buf.area().width);
+            if y < buf.area().height && text_start_x < right {
+                buf.set_stringn(
+                    text_start_x,
+                    y,
+                    &display_text,
+                    (right - text_start_x) as usize,
+                    Style::default().fg(text_fg),
+                );
             }
         }
     }
@@ -1,11 +1,12 @@
 //! Quickbar widget for rendering quickbar entries in a single row.
 
 use crate::data::QuickbarEntry;
+use crate::frontend::common::text_width;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType, Borders, Clear, Widget as RatatuiWidget},
+    widgets::{Block, Borders, Clear, Widget as RatatuiWidget},
 };
 
 #[derive(Clone, Debug)]
@@ -230,15 +231,9 @@ impl Quickbar {
             block = block
                 .borders(borders)
                 .border_style(Style::default().fg(border_color));
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
             if !self.title.is_empty() {
                 block = block.title(self.title.as_str());
             }
@@ -310,7 +305,7 @@ impl Quickbar {
 
         for (idx, entry) in self.entries.iter().enumerate() {
             let label = Self::entry_label(entry);
-            let label_len = label.chars().count() as u16;
+            let label_len = text_width::width(label) as u16;
             if label_len == 0 {
                 continue;
             }
@@ -392,18 +387,11 @@ impl Quickbar {
     }
 
     fn render_text(&self, buf: &mut Buffer, x: u16, y: u16, text: &str, style: Style, max_x: u16) {
-        let mut cursor = x;
-        for ch in text.chars() {
-            if cursor >= max_x {
-                break;
-            }
-            if cursor < buf.area().width && y < buf.area().height {
-                buf[(cursor, y)]
-                    .set_symbol(ch.encode_utf8(&mut [0; 4]))
-                    .set_style(style);
-            }
-            cursor += 1;
+        if x >= max_x.min(buf.area().width) || y >= buf.area().height {
+            return;
         }
+        let room = (max_x.min(buf.area().width) - x) as usize;
+        buf.set_stringn(x, y, text_width::truncate(text, room), room, style);
     }
 
     fn inner_rect(&self, area: Rect) -> Rect {
@@ -431,15 +419,9 @@ impl Quickbar {
                 borders |= Borders::RIGHT;
             }
             block = block.borders(borders);
-            if let Some(ref style) = self.border_style {
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
         }
         if !self.title.is_empty() && self.show_border {
             block = block.title(self.title.as_str());
@@ -0,0 +1,196 @@
+//! Reminders widget.
+//!
+//! Lists the upcoming `.remind` reminders, soonest first, with the time
+//! left until each.
+//!
+//! Reads data from AppCore.reminders (see core::reminders).
+
+use crate::config::BorderSides;
+use crate::core::reminders::{format_duration, Reminders as ReminderList};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Reminders widget - time left and message per reminder
+pub struct Reminders {
+    title: String,
+    show_repeat: bool,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached rows for rendering: (time left, message)
+    rows: Vec<(String, String)>,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Reminders {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_repeat: true,
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            rows: Vec::new(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_repeat(&mut self, show: bool) {
+        self.show_repeat = show;
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Rebuild the rows for `now` (Unix time). Returns true if the display
+    /// changed.
+    pub fn update_from_state(&mut self, reminders: &ReminderList, now: i64) -> bool {
+        let rows: Vec<(String, String)> = reminders
+            .upcoming()
+            .into_iter()
+            .map(|reminder| {
+                let mut message = reminder.message.clone();
+                if let Some(repeat) = reminder.repeat_label().filter(|_| self.show_repeat) {
+                    message.push_str(&format!(" ({})", repeat));
+                }
+                (format_duration(reminder.due - now), message)
+            })
+            .collect();
+        if rows == self.rows {
+            return false;
+        }
+        self.rows = rows;
+        true
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        if self.rows.is_empty() {
+            Paragraph::new(Line::from(Span::styled("No reminders (.remind)", dim)))
+                .render(inner, buf);
+            return;
+        }
+        let width = self
+            .rows
+            .iter()
+            .map(|(left, _)| left.len())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<Line> = self
+            .rows
+            .iter()
+            .map(|(left, message)| {
+                Line::from(vec![
+                    Span::styled(format!("{:>width$} ", left, width = width), dim),
+                    Span::styled(message.as_str(), Style::default().fg(self.text_color)),
+                ])
+            })
+            .collect();
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::reminders::Reminder;
+
+    #[test]
+    fn lists_reminders_soonest_first() {
+        let mut list = ReminderList::default();
+        list.add(Reminder {
+            id: 0,
+            message: "drink".into(),
+            due: 7_200,
+            every: Some(7_200),
+            daily: None,
+        });
+        list.add(Reminder {
+            id: 0,
+            message: "pawnshop".into(),
+            due: 2_700,
+            every: None,
+            daily: None,
+        });
+        let mut widget = Reminders::new("Reminders");
+        widget.set_show_border(false);
+        assert!(widget.update_from_state(&list, 0));
+        assert!(!widget.update_from_state(&list, 0));
+
+        let area = Rect::new(0, 0, 30, 2);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..30).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).starts_with("45m pawnshop"));
+        assert!(row(1).starts_with(" 2h drink (every 2h)"));
+    }
+}
@@ -14,7 +14,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Clear, Paragraph},
+    widgets::{Block, Clear, Paragraph},
 };
 use std::collections::HashMap;
 
@@ -402,8 +402,14 @@ impl RoomWindow {
         if self.show_inline_title && !self.title.is_empty() {
             let title_segment = vec![TextSegment {
                 text: self.title.clone(),
-                fg: self.title_fg_hex.clone(),
-                bg: self.title_bg_hex.clone(),
+                fg: self
+                    .title_fg_hex
+                    .as_deref()
+                    .map(crate::data::intern::intern),
+                bg: self
+                    .title_bg_hex
+                    .as_deref()
+                    .map(crate::data::intern::intern),
                 bold: true,
                 mono: false,
                 span_type: SpanType::Normal,
@@ -638,17 +644,9 @@ impl RoomWindow {
         };
 
         if self.show_border {
-            if let Some(ref style_name) = self.border_style {
-                let border_type = match style_name.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    "quadrant_inside" => BorderType::QuadrantInside,
-                    "quadrant_outside" => BorderType::QuadrantOutside,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             let border_color = self.border_color.unwrap_or(Color::White);
             block = block.border_style(Style::default().fg(border_color));
@@ -566,44 +566,21 @@ impl TuiFrontend {
 
         // Try tabbed text window
         if let Some(tabbed_window) = self.widget_manager.tabbed_text_windows.get(window_name) {
-            let border_offset = if tabbed_window.has_border() { 1 } else { 0 };
-            let tab_bar_offset: u16 = 1; // Tab bar is always 1 row
-            let separator_offset: u16 = if tabbed_window.has_tab_separator() {
-                1
-            } else {
-                0
-            };
-
-            // Calculate content area bounds accounting for tab bar position and separator
-            let (content_y_start, content_y_end) = if tabbed_window.tab_bar_at_top() {
-                // Tab bar at top: content starts after border + tab bar + separator
-                let y_start = window_rect.y + border_offset + tab_bar_offset + separator_offset;
-                let y_end = window_rect.y + window_rect.height - border_offset;
-                (y_start, y_end)
-            } else {
-                // Tab bar at bottom: content starts after border, ends before separator + tab bar
-                let y_start = window_rect.y + border_offset;
-                let y_end = window_rect.y + window_rect.height
-                    - border_offset
-                    - tab_bar_offset
-                    - separator_offset;
-                (y_start, y_end)
-            };
-
-            // Bounds check within content area
-            if mouse_col < window_rect.x + border_offset
-                || mouse_col >= window_rect.x + window_rect.width - border_offset
-                || mouse_row < content_y_start
-                || mouse_row >= content_y_end
+            // Content area bounds, wherever the tab bar and separator sit
+            let content = tabbed_window.content_rect(window_rect);
+            if mouse_col < content.x
+                || mouse_col >= content.right()
+                || mouse_row < content.y
+                || mouse_row >= content.bottom()
             {
                 return None;
             }
 
-            let visible_height = (content_y_end - content_y_start) as usize;
+            let visible_height = content.height as usize;
             let (_start_idx, visible_lines) = tabbed_window.get_visible_lines_info(visible_height);
 
-            let line_idx = (mouse_row - content_y_start) as usize;
-            let col_offset = (mouse_col - window_rect.x - border_offset) as usize;
+            let line_idx = (mouse_row - content.y) as usize;
+            let col_offset = (mouse_col - content.x) as usize;
 
             if line_idx >= visible_lines.len() {
                 return None;
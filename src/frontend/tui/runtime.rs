@@ -2,7 +2,8 @@ use anyhow::Result;
 use std::time::Instant;
 
 use super::TuiFrontend;
-use crate::frontend::Frontend;
+use crate::frontend::common::frontend::{dispatch_events, present, render_frame};
+use crate::frontend::{Frontend, FrontendEvent};
 
 /// Run the TUI frontend with the given configuration.
 /// This is the main entry point for TUI mode.
@@ -163,13 +164,14 @@ async fn async_run(
 ) -> Result<()> {
     use crate::core::AppCore;
     use crate::network::{DirectConnection, LichConnection, ServerMessage};
+    use crate::parse_worker::ParsedMessage;
     use tokio::sync::mpsc;
 
     // Create channels for network communication.
     // Server channel is bounded: if the UI stalls, the network read task
     // blocks on send() and TCP flow control takes over, instead of the
     // queue growing without bound.
-    let (server_tx, mut server_rx) =
+    let (server_tx, server_rx) =
         mpsc::channel::<ServerMessage>(crate::network::SERVER_CHANNEL_CAPACITY);
     // Command channel stays unbounded: sends happen in the synchronous UI
     // event loop (can't await) and volume is user-typed commands only.
@@ -207,6 +209,24 @@ async fn async_run(
         None
     };
 
+    // Control socket for `vellum-fe send`; the guard removes control.json
+    // on exit
+    let (_control_server, mut control_rx) =
+        match crate::control::ControlServer::start(app_core.config.character.as_deref()) {
+            Ok((server, rx)) => (Some(server), Some(rx)),
+            Err(e) => {
+                tracing::warn!("Control socket unavailable: {e:#}");
+                (None, None)
+            }
+        };
+
+    // `[command_pipe]` named pipe; stdin belongs to the terminal here
+    let mut pipe_rx = crate::command_pipe::start(
+        &app_core.config.command_pipe,
+        app_core.config.character.as_deref(),
+        false,
+    );
+
     super::colors::set_global_color_mode(app_core.config.ui.color_mode);
 
     // Initialize palette lookup for Slot mode
@@ -272,7 +292,7 @@ async fn async_run(
     app_core.init_windows(width, height);
 
     // Initial render to create widgets (needed before loading history)
-    frontend.render(&mut app_core)?;
+    render_frame(&mut frontend, &mut app_core)?;
 
     // Load command history (must be after widgets are created)
     if let Err(e) = frontend.command_input_load_history("command_input", character.as_deref()) {
@@ -303,8 +323,17 @@ async fn async_run(
         }
     }
 
-    // Spawn network connection task
+    // Spawn network connection task (--demo plays a scripted session instead)
+    let demo_script = app_core.config.demo_script.clone();
     let network_handle = match direct {
+        _ if demo_script.is_some() => tokio::spawn(async move {
+            let script = demo_script.unwrap_or_default();
+            if let Err(e) =
+                crate::network::demo::DemoConnection::start(&script, server_tx, command_rx).await
+            {
+                tracing::error!(error = ?e, "Demo session error");
+            }
+        }),
         Some(cfg) => tokio::spawn(async move {
             if let Err(e) = DirectConnection::start(cfg, server_tx, command_rx, raw_logger).await {
                 tracing::error!(error = ?e, "Network connection error");
@@ -313,6 +342,7 @@ async fn async_run(
         None => {
             let host_clone = host.clone();
             let login_key_clone = login_key.clone();
+            let proxy = app_core.config.proxy.clone();
             tokio::spawn(async move {
                 if let Err(e) = LichConnection::start(
                     &host_clone,
@@ -321,6 +351,7 @@ async fn async_run(
                     server_tx,
                     command_rx,
                     raw_logger,
+                    Some(&proxy),
                 )
                 .await
                 {
@@ -330,6 +361,16 @@ async fn async_run(
         }
     };
 
+    // Parse on a worker thread so bursts of game output don't stall input
+    // and rendering; the loop below only applies what it has parsed.
+    let mut parsed_rx = app_core.start_parse_worker(server_rx)?;
+
+    // Render at max_fps while things are happening, idle_fps otherwise
+    let mut frame_pacer = super::frame_pacer::FramePacer::new(
+        app_core.config.ui.max_fps,
+        app_core.config.ui.idle_fps,
+    );
+
     // Track time for periodic countdown updates
     let mut last_countdown_update = std::time::Instant::now();
 
@@ -386,75 +427,51 @@ async fn async_run(
         // as typed commands. Without the map poll, the mapdb load event is
         // never received and .room/.go2/.mapdb are dead on the TUI.
         app_core.poll_map();
+        // Sounds queued off the clock (timer cues, reminders) play now
+        // rather than with the next server line
+        for sound in app_core.game_state.drain_sound_queue() {
+            if let Some(ref player) = app_core.sound_player {
+                if let Err(e) = player.play_from_sounds_dir(&sound.file, sound.volume) {
+                    tracing::warn!("Failed to play sound '{}': {}", sound.file, e);
+                }
+            }
+        }
+        // Queued commands (pasted lines, automation) run like typed ones,
+        // so a dot command's `action:` result is handled locally
         for command in app_core.take_outbound() {
-            match app_core.send_command(command) {
-                Ok(out) if !out.is_empty() && !out.starts_with("action:") => {
+            match frontend.handle_command_submission(command, &mut app_core) {
+                Ok(Some(out)) => {
                     app_core
                         .perf_stats
                         .record_bytes_sent((out.len() + 1) as u64);
                     let _ = command_tx.send(out);
                 }
-                Ok(_) => {}
-                Err(e) => tracing::warn!("travel command failed: {e}"),
+                Ok(None) => {}
+                Err(e) => tracing::warn!("queued command failed: {e}"),
             }
         }
 
         // Poll for frontend events (keyboard, mouse, resize)
         let events = frontend.poll_events()?;
-        app_core
-            .perf_stats
-            .record_event_queue_depth(events.len() as u64);
+        if !events.is_empty() {
+            frame_pacer.note_activity(Instant::now());
+        }
+        // Keyboard and mouse input ends idle away mode
+        if events.iter().any(|event| {
+            matches!(
+                event,
+                FrontendEvent::Key { .. } | FrontendEvent::Mouse(_) | FrontendEvent::Paste { .. }
+            )
+        }) {
+            app_core.note_user_input();
+        }
 
         // Poll TTS callback events for auto-play
         app_core.poll_tts_events();
 
-        // Process frontend events
-        for event in events {
-            let event_start = Instant::now();
-            // Handle events that need frontend access directly
-            match &event {
-                crate::frontend::FrontendEvent::Mouse(mouse_event) => {
-                    // Phase 4.1: Delegate to TuiFrontend::handle_mouse_event
-                    let (handled, command) = frontend.handle_mouse_event(
-                        mouse_event,
-                        &mut app_core,
-                        crate::frontend::tui::menu_actions::handle_menu_action,
-                    )?;
-
-                    if let Some(cmd) = command {
-                        app_core
-                            .perf_stats
-                            .record_bytes_sent((cmd.len() + 1) as u64);
-                        let _ = command_tx.send(cmd);
-                    }
-
-                    if handled {
-                        continue;
-                    }
-                }
-                crate::frontend::FrontendEvent::Key {
-                    code: _code,
-                    modifiers: _modifiers,
-                } => {
-                    // Key events are handled in handle_event()
-                    // No early intercepts - let the 3-layer routing handle everything
-                }
-                _ => {}
-            }
-
-            if let Some(command) = handle_event(&mut app_core, &mut frontend, event)? {
-                app_core
-                    .perf_stats
-                    .record_bytes_sent((command.len() + 1) as u64);
-                let _ = command_tx.send(command);
-            }
-
-            let duration = event_start.elapsed();
-            app_core.perf_stats.record_event_process_time(duration);
-
-            // Process pending window additions after event handling (for .testline)
-            let (term_width, term_height) = frontend.size();
-            app_core.process_pending_window_additions(term_width, term_height);
+        // Process frontend events through the shared dispatch path
+        for command in dispatch_events(&mut frontend, &mut app_core, events)? {
+            let _ = command_tx.send(command);
         }
 
         // Drain commands typed on remote web clients (non-blocking). Each
@@ -465,8 +482,12 @@ async fn async_run(
             while let Ok(event) = rx.try_recv() {
                 match event {
                     crate::core::remote::RemoteEvent::Command(text) => {
-                        tracing::debug!("remote command: '{}'", text);
-                        frontend.command_input_record_external("command_input", &text);
+                        // A password reply stays out of history and the debug log
+                        if !app_core.expects_secret() {
+                            tracing::debug!("remote command: '{}'", text);
+                            frontend.command_input_record_external("command_input", &text);
+                        }
+                        app_core.note_user_input();
                         if let Some(cmd) = frontend.handle_command_submission(text, &mut app_core)? {
                             app_core
                                 .perf_stats
@@ -631,10 +652,30 @@ async fn async_run(
             }
         }
 
-        // Poll for server messages (non-blocking)
-        while let Ok(msg) = server_rx.try_recv() {
+        // Commands from `vellum-fe send` and the command pipe run like
+        // typed ones
+        for rx in [control_rx.as_mut(), pipe_rx.as_mut()]
+            .into_iter()
+            .flatten()
+        {
+            while let Ok(text) = rx.try_recv() {
+                if let Some(cmd) = frontend.handle_command_submission(text, &mut app_core)? {
+                    app_core
+                        .perf_stats
+                        .record_bytes_sent((cmd.len() + 1) as u64);
+                    let _ = command_tx.send(cmd);
+                }
+            }
+        }
+
+        // Poll for parsed server messages (non-blocking)
+        app_core
+            .perf_stats
+            .record_parsed_queue_depth(parsed_rx.len() as u64);
+        while let Ok(msg) = parsed_rx.try_recv() {
             match msg {
-                ServerMessage::Text(line) => {
+                ParsedMessage::Text(chunk) => {
+                    frame_pacer.note_activity(Instant::now());
                     // First data from the game = connection established:
                     // time the login music from here.
                     if startup_music_pending {
@@ -648,14 +689,16 @@ async fn async_run(
                     }
                     app_core
                         .perf_stats
-                        .record_bytes_received((line.len() + 1) as u64);
-                    let parse_start = Instant::now();
-                    // Process incoming server data through parser
-                    if let Err(e) = app_core.process_server_data(&line) {
+                        .record_bytes_received((chunk.bytes + 1) as u64);
+                    let apply_start = Instant::now();
+                    if let Err(e) = app_core.process_parsed_chunk(&chunk) {
                         tracing::error!("Error processing server data: {}", e);
                     }
-                    let parse_duration = parse_start.elapsed();
-                    app_core.perf_stats.record_parse(parse_duration);
+                    app_core.perf_stats.record_parse(chunk.parse_time);
+                    app_core.perf_stats.record_parse_stages(
+                        apply_start.duration_since(chunk.parsed_at),
+                        apply_start.elapsed(),
+                    );
 
                     // Adjust content-driven window sizes (e.g., Betrayer auto-resize)
                     app_core.adjust_content_driven_windows();
@@ -695,12 +738,12 @@ async fn async_run(
                     let (term_width, term_height) = frontend.size();
                     app_core.process_pending_window_additions(term_width, term_height);
                 }
-                ServerMessage::Connected => {
+                ParsedMessage::Connected => {
                     tracing::info!("Connected to game server");
                     app_core.game_state.connected = true;
                     app_core.needs_render = true;
                 }
-                ServerMessage::Disconnected => {
+                ParsedMessage::Disconnected => {
                     tracing::info!("Disconnected from game server");
                     app_core.game_state.connected = false;
                     app_core.needs_render = true;
@@ -719,6 +762,11 @@ async fn async_run(
             }
         }
 
+        // Keep redrawing while the alarm border blinks
+        if app_core.game_state.alarm.flashing(Instant::now()) {
+            app_core.needs_render = true;
+        }
+
         // Force render every second for countdown widgets
         if last_countdown_update.elapsed().as_secs() >= 1 {
             app_core.needs_render = true;
@@ -728,28 +776,21 @@ async fn async_run(
         // Sample system/process metrics (rate-limited internally)
         app_core.perf_stats.sample_sysinfo();
 
-        // Reset widget caches if layout was reloaded
-        if app_core.ui_state.needs_widget_reset {
-            frontend.widget_manager.clear();
-            app_core.ui_state.needs_widget_reset = false;
-            tracing::debug!("Widget caches cleared after layout reload");
-        }
-
-        // Reset specific widgets (e.g., when widget type changes)
-        if !app_core.ui_state.widgets_to_reset.is_empty() {
-            for name in app_core.ui_state.widgets_to_reset.drain(..) {
-                frontend.widget_manager.remove_widget_from_all_caches(&name);
-                tracing::debug!("Reset widget cache for '{}' (type change)", name);
-            }
+        // Sync + render if needed (widget cache resets happen in sync),
+        // held back while the last frame is younger than 1/max_fps
+        let now = Instant::now();
+        if app_core.needs_render && frame_pacer.frame_due(now) {
+            present(&mut frontend, &mut app_core)?;
+            frame_pacer.frame_rendered(now);
         }
 
-        // Render if needed
-        if app_core.needs_render {
-            frontend.render(&mut app_core)?;
-            app_core.needs_render = false;
-        }
-
-        // No sleep needed - event::poll() timeout already limits frame rate to ~60 FPS
+        // No sleep needed - the event poll timeout paces the loop. Rates are
+        // re-read each pass so settings edits apply immediately.
+        frame_pacer.set_rates(app_core.config.ui.max_fps, app_core.config.ui.idle_fps);
+        app_core
+            .perf_stats
+            .record_target_fps(frame_pacer.target_fps(now));
+        frontend.set_poll_timeout(frame_pacer.poll_timeout(now, app_core.needs_render));
     }
 
     // Save command history
@@ -789,36 +830,3 @@ async fn async_run(
 
     Ok(())
 }
-
-/// Handle a frontend event
-/// Returns Some(command) if a command should be sent to the server
-fn handle_event(
-    app_core: &mut crate::core::AppCore,
-    frontend: &mut TuiFrontend,
-    event: crate::frontend::FrontendEvent,
-) -> Result<Option<String>> {
-    use crate::frontend::FrontendEvent;
-
-    match event {
-        FrontendEvent::Key { code, modifiers } => {
-            // Phase 4.2: Delegate all keyboard handling to TuiFrontend::handle_key_event()
-            return frontend.handle_key_event(
-                code,
-                modifiers,
-                app_core,
-                crate::frontend::tui::menu_actions::handle_menu_action,
-            );
-        }
-        FrontendEvent::Resize { width, height } => {
-            // DISABLED: Automatic resize on terminal resize (manual .resize command only)
-            tracing::info!(
-                "Terminal resized to {}x{} (auto-resize disabled, use .resize command)",
-                width,
-                height
-            );
-        }
-        _ => {}
-    }
-
-    Ok(None)
-}
@@ -4,6 +4,7 @@
 //! makes it a handy building block for several specialized widgets.
 
 use super::progress_bar::ProgressBar;
+use crate::frontend::common::text_width;
 use crate::frontend::tui::crossterm_bridge;
 use ratatui::{
     buffer::Buffer,
@@ -244,16 +245,9 @@ impl ScrollableContainer {
             // Use Block widget for borders
             let mut block = Block::default().borders(borders);
 
-            if let Some(ref style) = self.border_style {
-                use ratatui::widgets::BorderType;
-                let border_type = match style.as_str() {
-                    "double" => BorderType::Double,
-                    "rounded" => BorderType::Rounded,
-                    "thick" => BorderType::Thick,
-                    _ => BorderType::Plain,
-                };
-                block = block.border_type(border_type);
-            }
+            block = block.border_set(super::border_glyphs::border_set(
+                self.border_style.as_deref(),
+            ));
 
             block = block.border_style(ratatui::style::Style::default().fg(border_color));
             // Only set title if label is non-empty (avoids empty title affecting layout)
@@ -301,11 +295,11 @@ impl ScrollableContainer {
                 // Format text with suffix pinned to right edge
                 let display_text = if let Some(ref suffix) = item.suffix {
                     let available_width = inner_area.width as usize;
-                    let suffix_len = suffix.chars().count();
+                    let suffix_len = text_width::width(suffix);
 
                     if available_width < suffix_len + 1 {
                         // Too narrow to show anything meaningful, just show truncated suffix
-                        suffix.chars().take(available_width).collect()
+                        text_width::truncate(suffix, available_width).to_string()
                     } else if available_width <= suffix_len + 1 {
                         // Just barely enough for suffix, truncate text completely
                         suffix.clone()
@@ -316,9 +310,9 @@ impl ScrollableContainer {
                         let text_space = available_width - reserved;
 
                         // Determine text (no separator, just text and time)
-                        let truncated_text = if source_text.chars().count() > text_space {
+                        let truncated_text = if text_width::width(source_text) > text_space {
                             // Text is too long, truncate without ellipsis
-                            source_text.chars().take(text_space).collect()
+                            text_width::truncate(source_text, text_space).to_string()
                         } else {
                             // Text fits completely
                             source_text.clone()
@@ -326,7 +320,7 @@ impl ScrollableContainer {
 
                         // Calculate padding to push suffix to right edge
                         // We want: "text<padding>suffix" where padding is at least 1 space
-                        let text_len = truncated_text.chars().count();
+                        let text_len = text_width::width(&truncated_text);
                         let padding = available_width - text_len - suffix_len;
                         format!("{}{}{}", truncated_text, " ".repeat(padding), suffix)
                     }
@@ -84,6 +84,27 @@ impl TuiFrontend {
         None
     }
 
+    /// Selected text with ANSI colors from a text or tabbed window; other
+    /// windows fall back to plain text
+    pub fn extract_selection_ansi(
+        &self,
+        window_name: &str,
+        start_line: usize,
+        start_col: usize,
+        end_line: usize,
+        end_col: usize,
+    ) -> Option<String> {
+        if let Some(text_window) = self.widget_manager.text_windows.get(window_name) {
+            return Some(
+                text_window.extract_selection_ansi(start_line, start_col, end_line, end_col),
+            );
+        }
+        if let Some(tabbed) = self.widget_manager.tabbed_text_windows.get(window_name) {
+            return Some(tabbed.extract_selection_ansi(start_line, start_col, end_line, end_col));
+        }
+        self.extract_selection_text(window_name, start_line, start_col, end_line, end_col)
+    }
+
     /// Extract selected text from any text-supporting window
     pub fn extract_selection_text(
         &self,
@@ -342,6 +342,25 @@ impl SettingsEditor {
                         config.ui.border_style = v.clone();
                     }
                 }
+                "ui.keyboard_layout" => {
+                    if let SettingValue::Enum(ref v, _) = item.value {
+                        config.ui.keyboard_layout = v.clone();
+                    }
+                }
+                "ui.emoji_width" => {
+                    if let SettingValue::Enum(ref v, _) = item.value {
+                        if let Some(width) = crate::config::EmojiWidth::from_name(v) {
+                            config.ui.emoji_width = width;
+                        }
+                    }
+                }
+                "ui.locale" => {
+                    if let SettingValue::Enum(ref v, _) = item.value {
+                        if let Some(locale) = crate::config::Locale::from_name(v) {
+                            config.ui.locale = locale;
+                        }
+                    }
+                }
                 "ui.countdown_icon" => {
                     if let SettingValue::String(ref v) = item.value {
                         config.ui.countdown_icon = v.clone();
@@ -362,6 +381,21 @@ impl SettingsEditor {
                         config.ui.selection_auto_copy = v;
                     }
                 }
+                "ui.selection_copy_ansi" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.selection_copy_ansi = v;
+                    }
+                }
+                "ui.reduced_motion" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.reduced_motion = v;
+                    }
+                }
+                "ui.screen_reader" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.screen_reader = v;
+                    }
+                }
                 "ui.drag_modifier_key" => {
                     if let SettingValue::Enum(ref v, _) = item.value {
                         config.ui.drag_modifier_key = v.clone();
@@ -372,6 +406,56 @@ impl SettingsEditor {
                         config.ui.min_command_length = v as usize;
                     }
                 }
+                "ui.history_expansion" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.history_expansion = v;
+                    }
+                }
+                "ui.history_prefix_search" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.history_prefix_search = v;
+                    }
+                }
+                "ui.hide_prompt" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.hide_prompt = v;
+                    }
+                }
+                "ui.command_echo" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.command_echo = v;
+                    }
+                }
+                "ui.command_echo_prefix" => {
+                    if let SettingValue::String(ref v) = item.value {
+                        config.ui.command_echo_prefix = v.clone();
+                    }
+                }
+                "ui.mask_secrets" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.ui.mask_secrets = v;
+                    }
+                }
+                "ui.paste_confirm_lines" => {
+                    if let SettingValue::Number(v) = item.value {
+                        config.ui.paste_confirm_lines = v.max(0) as usize;
+                    }
+                }
+                "ui.max_fps" => {
+                    if let SettingValue::Number(v) = item.value {
+                        config.ui.max_fps = v.clamp(1, 240) as u32;
+                    }
+                }
+                "ui.idle_fps" => {
+                    if let SettingValue::Number(v) = item.value {
+                        config.ui.idle_fps = v.clamp(1, 240) as u32;
+                    }
+                }
+                "spellcheck.enabled" => {
+                    if let SettingValue::Boolean(v) = item.value {
+                        config.spellcheck.enabled = v;
+                    }
+                }
 
                 // Sound settings
                 "sound.enabled" => {
@@ -1041,49 +1125,7 @@ impl SettingsEditor {
     }
 
     fn draw_border(&self, area: Rect, buf: &mut Buffer, style: Style) {
-        // Top border
-        for x in area.x..area.x + area.width {
-            if let Some(cell) = buf.cell_mut((x, area.y)) {
-                if x == area.x {
-                    cell.set_char('┌');
-                } else if x == area.x + area.width - 1 {
-                    cell.set_char('┐');
-                } else {
-                    cell.set_char('─');
-                }
-                cell.set_style(style);
-            }
-        }
-
-        // Bottom border
-        for x in area.x..area.x + area.width {
-            if let Some(cell) = buf.cell_mut((x, area.y + area.height - 1)) {
-                if x == area.x {
-                    cell.set_char('└');
-                } else if x == area.x + area.width - 1 {
-                    cell.set_char('┘');
-                } else {
-                    cell.set_char('─');
-                }
-                cell.set_style(style);
-            }
-        }
-
-        // Left border
-        for y in area.y + 1..area.y + area.height - 1 {
-            if let Some(cell) = buf.cell_mut((area.x, y)) {
-                cell.set_char('│');
-                cell.set_style(style);
-            }
-        }
-
-        // Right border
-        for y in area.y + 1..area.y + area.height - 1 {
-            if let Some(cell) = buf.cell_mut((area.x + area.width - 1, y)) {
-                cell.set_char('│');
-                cell.set_style(style);
-            }
-        }
+        super::border_glyphs::draw_box(buf, area, style);
     }
 
     fn parse_hex_color(hex: &str) -> Result<Color, ()> {
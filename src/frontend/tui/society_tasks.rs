@@ -0,0 +1,199 @@
+//! Society task checklist widget.
+//!
+//! The society status lines (rank, step) in gray, then one checklist entry
+//! per task: `[x]` in green once complete, `[6/12]` for counted tasks, and
+//! `[ ]` otherwise. Task text wraps under its mark.
+//!
+//! Reads data from GameState.society (see core::society_tasks).
+
+use crate::config::BorderSides;
+use crate::core::state::SocietyState;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget, Wrap},
+};
+
+/// Society task widget - status lines plus a task checklist
+pub struct SocietyTasks {
+    title: String,
+    show_progress: bool,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached state for rendering
+    society: SocietyState,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl SocietyTasks {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_progress: true,
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            society: SocietyState::default(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_progress(&mut self, show: bool) {
+        self.show_progress = show;
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Update from game state. Returns true if the display changed.
+    pub fn update_from_state(&mut self, society: &SocietyState) -> bool {
+        if self.society.generation == society.generation {
+            return false;
+        }
+        self.society = society.clone();
+        true
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        if !self.society.has_data() {
+            Paragraph::new(Line::from(Span::styled(
+                "(No society tasks - type SOCIETY)",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .wrap(Wrap { trim: true })
+            .render(inner, buf);
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines: Vec<Line> = self
+            .society
+            .status
+            .iter()
+            .map(|status| Line::from(Span::styled(status.clone(), dim)))
+            .collect();
+        for task in &self.society.tasks {
+            let progress = self.society.progress(task).filter(|_| self.show_progress);
+            let (mark, style) = match progress {
+                _ if task.complete => ("[x]".to_string(), Style::default().fg(Color::Green)),
+                Some((done, total)) => (
+                    format!("[{}/{}]", done, total),
+                    Style::default().fg(self.text_color),
+                ),
+                None => ("[ ]".to_string(), Style::default().fg(self.text_color)),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", mark), style),
+                Span::styled(task.text.clone(), style),
+            ]));
+        }
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_status_and_checklist() {
+        let mut society = SocietyState::default();
+        society.update(vec![
+            "Voln step 12".to_string(),
+            "You must release 12 undead souls.".to_string(),
+            "Learn symbols.".to_string(),
+        ]);
+        society.update(vec![
+            "Voln step 12".to_string(),
+            "You must release 4 more undead souls.".to_string(),
+            "Learn symbols.".to_string(),
+        ]);
+        let mut widget = SocietyTasks::new("Society");
+        widget.set_show_border(false);
+        assert!(widget.update_from_state(&society));
+        assert!(!widget.update_from_state(&society));
+
+        let area = Rect::new(0, 0, 50, 3);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String {
+            (0..50)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        };
+        assert_eq!(row(0), "Voln step 12");
+        assert_eq!(row(1), "[8/12] You must release 4 more undead souls.");
+        assert_eq!(row(2), "[ ] Learn symbols.");
+    }
+}
@@ -193,9 +193,11 @@ impl SpellColorBrowser {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_border));
 
-        // Top border
-        let top = format!("┌{}┐", "─".repeat(popup_width as usize - 2));
-        buf.set_string(popup_col, popup_row, &top, border_style);
+        super::border_glyphs::draw_box(
+            buf,
+            Rect::new(popup_col, popup_row, popup_width, popup_height),
+            border_style,
+        );
 
         // Title
         buf.set_string(
@@ -205,26 +207,6 @@ impl SpellColorBrowser {
             border_style.add_modifier(Modifier::BOLD),
         );
 
-        // Side borders
-        for i in 1..popup_height - 1 {
-            buf.set_string(popup_col, popup_row + i, "│", border_style);
-            buf.set_string(
-                popup_col + popup_width - 1,
-                popup_row + i,
-                "│",
-                border_style,
-            );
-        }
-
-        // Bottom border
-        let bottom = format!("└{}┘", "─".repeat(popup_width as usize - 2));
-        buf.set_string(
-            popup_col,
-            popup_row + popup_height - 1,
-            &bottom,
-            border_style,
-        );
-
         // Render entries
         let visible_rows = popup_height - 4;
         let visible_entries = self
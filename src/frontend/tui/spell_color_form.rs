@@ -3,6 +3,7 @@
 //! Used by the spell color browser/editor to input ranges, preview swatches,
 //! and persist the chosen palette.
 
+use super::textarea_bridge::GraphemeInput;
 use crate::config::SpellColorRange;
 use crate::frontend::tui::crossterm_bridge;
 use crossterm::event::KeyEvent;
@@ -125,16 +126,16 @@ impl SpellColorFormWidget {
         let rt_key = crate::frontend::tui::textarea_bridge::to_textarea_event(key);
         match self.focused_field {
             0 => {
-                self.spell_ids.input(rt_key);
+                self.spell_ids.input_graphemes(rt_key);
             }
             1 => {
-                self.bar_color.input(rt_key);
+                self.bar_color.input_graphemes(rt_key);
             }
             2 => {
-                self.text_color.input(rt_key);
+                self.text_color.input_graphemes(rt_key);
             }
             3 => {
-                self.bg_color.input(rt_key);
+                self.bg_color.input_graphemes(rt_key);
             }
             _ => {}
         }
@@ -321,9 +322,11 @@ impl SpellColorFormWidget {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.form_label));
 
-        // Top border
-        let top = format!("┌{}┐", "─".repeat(popup_width as usize - 2));
-        buf.set_string(popup_col, popup_row, &top, border_style);
+        super::border_glyphs::draw_box(
+            buf,
+            Rect::new(popup_col, popup_row, popup_width, popup_height),
+            border_style,
+        );
 
         // Title
         buf.set_string(
@@ -333,26 +336,6 @@ impl SpellColorFormWidget {
             border_style.add_modifier(Modifier::BOLD),
         );
 
-        // Side borders
-        for i in 1..popup_height - 1 {
-            buf.set_string(popup_col, popup_row + i, "│", border_style);
-            buf.set_string(
-                popup_col + popup_width - 1,
-                popup_row + i,
-                "│",
-                border_style,
-            );
-        }
-
-        // Bottom border
-        let bottom = format!("└{}┘", "─".repeat(popup_width as usize - 2));
-        buf.set_string(
-            popup_col,
-            popup_row + popup_height - 1,
-            &bottom,
-            border_style,
-        );
-
         // Get color values before rendering
         let bar_color_val = self.bar_color.lines()[0].to_string();
         let text_color_val = self.text_color.lines()[0].to_string();
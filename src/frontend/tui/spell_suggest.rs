@@ -0,0 +1,192 @@
+//! Spellcheck suggestion popup for the command input.
+//!
+//! The `spell_suggest` keybind picks the misspelled word under (or before)
+//! the cursor and lists corrections above the input, plus an entry that
+//! adds the word to the personal dictionary. Checking and suggestions live
+//! in core (`core::spellcheck`); this is only the picker.
+
+use super::crossterm_bridge;
+use crate::core::spellcheck::Misspelling;
+use crate::core::AppCore;
+use crate::data::input::KeyCode;
+use crate::data::InputMode;
+use crate::frontend::common::text_width;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+/// Corrections offered per word
+const MAX_SUGGESTIONS: usize = 8;
+
+pub struct SpellSuggest {
+    misspelling: Misspelling,
+    suggestions: Vec<String>,
+    /// Highlighted row; `suggestions.len()` is the "add to dictionary" row
+    selected: usize,
+}
+
+impl SpellSuggest {
+    fn new(misspelling: Misspelling, suggestions: Vec<String>) -> Self {
+        Self {
+            misspelling,
+            suggestions,
+            selected: 0,
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        self.suggestions.len() + 1
+    }
+
+    fn rows(&self) -> Vec<String> {
+        let mut rows: Vec<String> = self
+            .suggestions
+            .iter()
+            .enumerate()
+            .map(|(idx, word)| format!("{} {}", idx + 1, word))
+            .collect();
+        rows.push(format!("a Add \"{}\" to dictionary", self.misspelling.word));
+        rows
+    }
+
+    pub(crate) fn render(
+        &self,
+        input_area: Rect,
+        screen: Rect,
+        buf: &mut Buffer,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let color = crossterm_bridge::to_ratatui_color;
+        let rows = self.rows();
+        let longest = rows
+            .iter()
+            .map(|row| text_width::width(row))
+            .max()
+            .unwrap_or(0);
+        let width = ((longest + 4) as u16).max(16).min(screen.width);
+        let height = (rows.len() as u16 + 2).min(screen.height);
+        let y = if input_area.y >= screen.y + height {
+            input_area.y - height
+        } else {
+            (input_area.y + input_area.height).min(screen.bottom().saturating_sub(height))
+        };
+        let x = input_area.x.min(screen.right().saturating_sub(width));
+        let area = Rect::new(x, y, width, height);
+
+        Clear.render(area, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(super::border_glyphs::popup_set())
+            .title(format!(" {} ", self.misspelling.word))
+            .border_style(Style::default().fg(color(theme.window_border)))
+            .style(Style::default().bg(color(theme.background_secondary)));
+        let lines: Vec<Line> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, row)| {
+                let style = if idx == self.selected {
+                    Style::default()
+                        .fg(color(theme.text_selected))
+                        .bg(color(theme.background_selected))
+                        .add_modifier(Modifier::BOLD)
+                } else if idx == self.suggestions.len() {
+                    Style::default().fg(color(theme.text_secondary))
+                } else {
+                    Style::default().fg(color(theme.text_primary))
+                };
+                Line::from(Span::styled(format!(" {} ", row), style))
+            })
+            .collect();
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
+
+impl super::TuiFrontend {
+    /// Open the suggestion popup for the misspelled word under the cursor,
+    /// else the nearest one before it, else the first.
+    pub(crate) fn open_spell_suggest(&mut self, app_core: &mut AppCore) {
+        app_core.needs_render = true;
+        if !app_core.config.spellcheck.enabled {
+            app_core.add_system_message(
+                "Spellcheck is off - set enabled = true under [spellcheck] in config.toml",
+            );
+            return;
+        }
+        let Some(input) = self.widget_manager.command_inputs.get("command_input") else {
+            return;
+        };
+        let cursor = input.cursor_pos();
+        let errors = app_core.spell_errors(&input.get_input().unwrap_or_default());
+        let target = errors
+            .iter()
+            .find(|error| error.start <= cursor && cursor <= error.end)
+            .or_else(|| errors.iter().rev().find(|error| error.end <= cursor))
+            .or_else(|| errors.first())
+            .cloned();
+        let Some(misspelling) = target else {
+            app_core.add_system_message("No misspelled words");
+            return;
+        };
+        let suggestions = app_core
+            .spellchecker
+            .suggest(&misspelling.word, MAX_SUGGESTIONS);
+        self.spell_suggest = Some(SpellSuggest::new(misspelling, suggestions));
+        app_core.ui_state.input_mode = InputMode::SpellSuggest;
+    }
+
+    /// Keys while the popup is open: arrows move, Enter picks, 1-9 pick a
+    /// correction directly, `a` adds the word, Esc closes.
+    pub(crate) fn handle_spell_suggest_key(&mut self, code: KeyCode, app_core: &mut AppCore) {
+        app_core.needs_render = true;
+        let Some(popup) = self.spell_suggest.as_mut() else {
+            app_core.ui_state.input_mode = InputMode::Normal;
+            return;
+        };
+        let pick = match code {
+            KeyCode::Up => {
+                popup.selected = (popup.selected + popup.row_count() - 1) % popup.row_count();
+                None
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                popup.selected = (popup.selected + 1) % popup.row_count();
+                None
+            }
+            KeyCode::Enter => Some(popup.selected),
+            KeyCode::Char('a') => Some(popup.suggestions.len()),
+            KeyCode::Char(c @ '1'..='9') => {
+                let idx = c as usize - '1' as usize;
+                if idx < popup.suggestions.len() {
+                    Some(idx)
+                } else {
+                    None
+                }
+            }
+            KeyCode::Esc => {
+                self.spell_suggest = None;
+                app_core.ui_state.input_mode = InputMode::Normal;
+                return;
+            }
+            _ => None,
+        };
+        let Some(pick) = pick else {
+            return;
+        };
+
+        let Some(popup) = self.spell_suggest.take() else {
+            return;
+        };
+        app_core.ui_state.input_mode = InputMode::Normal;
+        match popup.suggestions.get(pick) {
+            Some(word) => {
+                if let Some(input) = self.widget_manager.command_inputs.get_mut("command_input") {
+                    input.replace_range(popup.misspelling.start, popup.misspelling.end, word);
+                }
+            }
+            None => app_core.add_to_dictionary(&popup.misspelling.word),
+        }
+    }
+}
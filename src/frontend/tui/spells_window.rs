@@ -3,24 +3,124 @@
 //! This widget behaves similarly to the inventory window but retains a separate
 //! link cache tailored to `<spell>` stream updates.
 //!
-//! Now implemented as a thin wrapper around ListWidget for DRY.
+//! Now implemented as a thin wrapper around ListWidget for DRY. With
+//! grouping on, the listing is split by circle (see `core::spell_groups`)
+//! under headers that collapse on click.
 
-use crate::data::{LinkData, SpanType};
+use crate::core::spell_groups;
+use crate::data::{ActiveEffect, LinkData, SpanType, StyledLine};
 use ratatui::{buffer::Buffer, layout::Rect};
 
+const HEADER_COLOR: &str = "#87afd7";
+
+/// What the window was last built from; a change means a rebuild
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellsView {
+    pub generation: u64,
+    pub grouping: bool,
+    pub collapsed: Vec<String>,
+    pub active_generation: u64,
+}
+
 /// Spells window widget - displays known spells with clickable links
 /// Content is completely replaced on each update (no buffer, no scrolling history)
 ///
 /// Now uses ListWidget internally for shared implementation.
 pub struct SpellsWindow {
     widget: super::list_widget::ListWidget,
+    view: Option<SpellsView>,
+    /// Line index of each group header, with the group's name
+    headers: Vec<(usize, String)>,
 }
 
 impl SpellsWindow {
     pub fn new(title: String) -> Self {
         Self {
             widget: super::list_widget::ListWidget::new(&title),
+            view: None,
+            headers: Vec::new(),
+        }
+    }
+
+    /// Whether `view` differs from what the window shows now
+    pub fn needs_rebuild(&self, view: &SpellsView) -> bool {
+        self.view.as_ref() != Some(view)
+    }
+
+    /// Replace the content with `lines`, grouped per `view`, counting the
+    /// `active` spells in each group
+    pub fn set_spells(&mut self, lines: &[StyledLine], view: SpellsView, active: &[ActiveEffect]) {
+        let saved_scroll = self.scroll_position();
+        self.clear();
+        let mut row = 0;
+        if !view.grouping {
+            for line in lines {
+                row = self.push_line(line, row);
+            }
+        } else {
+            for group in spell_groups::group(lines) {
+                if group.name.is_empty() {
+                    for line in &group.lines {
+                        row = self.push_line(line, row);
+                    }
+                    continue;
+                }
+                let collapsed = view.collapsed.contains(&group.name);
+                let active_count = group.active_count(active);
+                let mut header = format!(
+                    "{} {} ({})",
+                    if collapsed { "▸" } else { "▾" },
+                    group.name,
+                    group.lines.len()
+                );
+                if active_count > 0 {
+                    header.push_str(&format!(" · {} active", active_count));
+                }
+                let header_line = StyledLine {
+                    segments: vec![crate::data::TextSegment::styled(
+                        header,
+                        Some(HEADER_COLOR.to_string()),
+                        true,
+                    )],
+                    stream: "Spells".into(),
+                    timestamp: None,
+                };
+                self.headers.push((row, group.name.clone()));
+                row = self.push_line(&header_line, row);
+                if !collapsed {
+                    for line in &group.lines {
+                        row = self.push_line(line, row);
+                    }
+                }
+            }
+        }
+        self.restore_scroll_position(saved_scroll);
+        self.view = Some(view);
+    }
+
+    /// Append `line` as display row `row`; returns the next row
+    fn push_line(&mut self, line: &StyledLine, row: usize) -> usize {
+        for segment in &line.segments {
+            self.add_text(
+                segment.text.clone(),
+                segment.fg.as_deref().map(str::to_string),
+                segment.bg.as_deref().map(str::to_string),
+                segment.bold,
+                segment.span_type,
+                segment.link_data.clone(),
+            );
         }
+        self.finish_line();
+        row + 1
+    }
+
+    /// Name of the group header at a screen position, if any
+    pub fn group_at(&self, x: u16, y: u16, area: Rect) -> Option<&str> {
+        let (line, _) = self.widget.mouse_to_text_coords(x, y, area)?;
+        self.headers
+            .iter()
+            .find(|(index, _)| *index == line)
+            .map(|(_, name)| name.as_str())
     }
 
     /// Set highlight patterns for this window (only recompiles if changed)
@@ -36,6 +136,8 @@ impl SpellsWindow {
     /// Clear all content (called when clearStream is received)
     pub fn clear(&mut self) {
         self.widget.clear();
+        self.headers.clear();
+        self.view = None;
     }
 
     /// Add styled text to current line
@@ -67,6 +169,14 @@ impl SpellsWindow {
         self.widget.scroll_down(lines);
     }
 
+    pub fn scroll_position(&self) -> Option<usize> {
+        self.widget.scroll_position()
+    }
+
+    pub fn restore_scroll_position(&mut self, start_line: Option<usize>) {
+        self.widget.restore_scroll_position(start_line);
+    }
+
     pub fn set_border_config(
         &mut self,
         show_border: bool,
@@ -219,4 +329,61 @@ mod tests {
         assert_eq!(clicked.exist_id, "101");
         assert_eq!(clicked.text, "Fireball");
     }
+
+    #[test]
+    fn test_grouped_headers_collapse_and_count_active() {
+        let mut spells = SpellsWindow::new("Spells".to_string());
+        spells.set_border_config(false, None, None);
+        let lines: Vec<StyledLine> = [
+            "101 Spirit Warding I",
+            "107 Spirit Warding II",
+            "401 Elemental Defense I",
+        ]
+        .iter()
+        .map(|text| StyledLine {
+            segments: vec![crate::data::TextSegment::plain(*text)],
+            stream: "Spells".into(),
+            timestamp: None,
+        })
+        .collect();
+        let active = [ActiveEffect {
+            id: "107".into(),
+            text: "Spirit Warding II".into(),
+            value: 100,
+            time: "10:00".into(),
+            expires_at: None,
+            bar_color: None,
+            text_color: None,
+        }];
+        let view = SpellsView {
+            generation: 1,
+            grouping: true,
+            collapsed: vec!["Minor Elemental".into()],
+            active_generation: 1,
+        };
+        assert!(spells.needs_rebuild(&view));
+        spells.set_spells(&lines, view.clone(), &active);
+        assert!(!spells.needs_rebuild(&view));
+
+        let text: Vec<String> = spells
+            .get_lines()
+            .iter()
+            .map(|line| line.iter().map(|seg| seg.text.as_str()).collect())
+            .collect();
+        assert_eq!(
+            text,
+            [
+                "▾ Minor Spiritual (2) · 1 active",
+                "101 Spirit Warding I",
+                "107 Spirit Warding II",
+                "▸ Minor Elemental (1)",
+            ]
+        );
+
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        spells.render(area, &mut buf);
+        assert_eq!(spells.group_at(2, 3, area), Some("Minor Elemental"));
+        assert_eq!(spells.group_at(2, 1, area), None);
+    }
 }
@@ -0,0 +1,247 @@
+//! Stance widget.
+//!
+//! Shows the combat stance as an offensive-to-defensive bar and the body
+//! posture below it. Clicking the left half steps toward offensive, the
+//! right half toward defensive (see `.stance`).
+//!
+//! Reads data from GameState.stance and GameState.status.
+
+use crate::config::BorderSides;
+use crate::core::stance::StanceInfo;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Stance widget - stance bar plus posture line
+pub struct Stance {
+    title: String,
+    show_bar: bool,
+    show_posture: bool,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached state for rendering
+    stance: StanceInfo,
+    posture: Option<&'static str>,
+    border_color: Color,
+    text_color: Color,
+    bar_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Stance {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_bar: true,
+            show_posture: true,
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            stance: StanceInfo::default(),
+            posture: None,
+            border_color: Color::White,
+            text_color: Color::White,
+            bar_color: Color::Rgb(0x30, 0x50, 0xa0),
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_bar(&mut self, show: bool) {
+        self.show_bar = show;
+    }
+
+    pub fn set_show_posture(&mut self, show: bool) {
+        self.show_posture = show;
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Update from game state. Returns true if the display changed.
+    pub fn update(&mut self, stance: &StanceInfo, posture: Option<&'static str>) -> bool {
+        if self.stance == *stance && self.posture == posture {
+            return false;
+        }
+        self.stance = stance.clone();
+        self.posture = posture;
+        true
+    }
+
+    fn render_bar(&self, area: Rect, buf: &mut Buffer) {
+        let width = area.width as usize;
+        let filled = width * self.stance.percent.min(100) as usize / 100;
+        let text = format!(
+            "{} ({}%)",
+            capitalize(&self.stance.name),
+            self.stance.percent
+        );
+        let text: Vec<char> = text.chars().take(width).collect();
+        let text_start = (width - text.len()) / 2;
+
+        for col in 0..width {
+            let Some(cell) = buf.cell_mut((area.x + col as u16, area.y)) else {
+                continue;
+            };
+            let ch = col
+                .checked_sub(text_start)
+                .and_then(|i| text.get(i))
+                .copied()
+                .unwrap_or(' ');
+            cell.set_char(ch);
+            cell.set_fg(self.text_color);
+            if col < filled {
+                cell.set_bg(self.bar_color);
+            } else if let Some(bg) = self.background_color {
+                cell.set_bg(bg);
+            }
+        }
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        if self.stance.name.is_empty() && self.posture.is_none() {
+            Paragraph::new(Line::from(Span::styled(
+                "(No stance data)",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .render(inner, buf);
+            return;
+        }
+
+        let mut y = inner.y;
+        if self.show_bar && !self.stance.name.is_empty() {
+            self.render_bar(
+                Rect {
+                    y,
+                    height: 1,
+                    ..inner
+                },
+                buf,
+            );
+            y += 1;
+        }
+        if self.show_posture && y < inner.bottom() {
+            if let Some(posture) = self.posture {
+                Paragraph::new(Line::from(Span::styled(
+                    capitalize(posture),
+                    Style::default().fg(self.text_color),
+                )))
+                .centered()
+                .render(
+                    Rect {
+                        y,
+                        height: 1,
+                        ..inner
+                    },
+                    buf,
+                );
+            }
+        }
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_reports_changes_only() {
+        let mut widget = Stance::new("Stance");
+        let stance = StanceInfo {
+            name: "guarded".to_string(),
+            percent: 80,
+        };
+        assert!(widget.update(&stance, Some("standing")));
+        assert!(!widget.update(&stance, Some("standing")));
+        assert!(widget.update(&stance, Some("kneeling")));
+    }
+
+    #[test]
+    fn renders_stance_and_posture() {
+        let mut widget = Stance::new("Stance");
+        widget.set_show_border(false);
+        let stance = StanceInfo {
+            name: "defensive".to_string(),
+            percent: 100,
+        };
+        widget.update(&stance, Some("kneeling"));
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).contains("Defensive (100%)"));
+        assert!(row(1).contains("Kneeling"));
+    }
+}
@@ -12,6 +12,34 @@ fn window_def_map(
     layout.windows.iter().map(|wd| (wd.name(), wd)).collect()
 }
 
+/// Border style for a window. Screen-reader mode draws single lines so
+/// double/rounded/thick variants aren't read out as distinct symbols.
+fn border_style_for(app_core: &crate::core::AppCore, base: &crate::config::WindowBase) -> String {
+    if app_core.config.ui.screen_reader {
+        "single".to_string()
+    } else {
+        base.border_style.clone()
+    }
+}
+
+/// Window title with a frozen buffer's indicator appended: "Main [frozen, 12 held]"
+fn title_with_freeze(
+    def: Option<&crate::config::WindowDef>,
+    fallback: &str,
+    label: Option<&str>,
+) -> String {
+    let title = match def {
+        Some(def) if def.base().show_title => def.base().title.clone().unwrap_or_default(),
+        Some(_) => String::new(),
+        None => fallback.to_string(),
+    };
+    match label {
+        Some(label) if title.is_empty() => format!("[{}]", label),
+        Some(label) => format!("{} [{}]", title, label),
+        None => title,
+    }
+}
+
 fn decode_icon(icon_str: &str) -> Option<String> {
     let trimmed = icon_str.trim();
     if trimmed.is_empty() {
@@ -56,6 +84,14 @@ impl TuiFrontend {
                 // Look up the WindowDef from layout to get config
                 let window_def = window_defs.get(name.as_str()).copied();
 
+                // A widget rebuilt after a layout reload or window edit gets
+                // its old scroll position back once its lines are in
+                let restored_view = if self.widget_manager.text_windows.contains_key(name) {
+                    None
+                } else {
+                    app_core.ui_state.window_views.get(name).copied()
+                };
+
                 // Get or create TextWindow for this window
                 let text_window = self.widget_manager.text_windows.entry(name.clone()).or_insert_with(|| {
                     let mut tw =
@@ -65,7 +101,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(def.base(), theme);
                         tw.set_border_config(
                             def.base().show_border,
-                            Some(def.base().border_style.clone()),
+                            Some(border_style_for(app_core, def.base())),
                             colors.border.clone(),
                         );
                         tw.set_border_sides(def.base().border_sides.clone());
@@ -107,7 +143,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(def.base(), theme);
                         text_window.set_border_config(
                             def.base().show_border,
-                            Some(def.base().border_style.clone()),
+                            Some(border_style_for(app_core, def.base())),
                             colors.border.clone(),
                         );
                         text_window.set_border_sides(def.base().border_sides.clone());
@@ -141,19 +177,50 @@ impl TuiFrontend {
                     }
                 }
 
+                // A frozen window says so (and what it's held back) in its title
+                let freeze_label = text_content.freeze_label();
+                if self.widget_manager.freeze_labels.get(name) != freeze_label.as_ref()
+                    || (self.config_sync_needed && freeze_label.is_some())
+                {
+                    text_window.set_title(title_with_freeze(
+                        window_def,
+                        &text_content.title,
+                        freeze_label.as_deref(),
+                    ));
+                    match freeze_label {
+                        Some(label) => self
+                            .widget_manager
+                            .freeze_labels
+                            .insert(name.clone(), label),
+                        None => self.widget_manager.freeze_labels.remove(name),
+                    };
+                }
+
                 // Update width for proper wrapping
                 text_window.set_width(window.position.width);
 
                 // Get last synced generation
                 let last_synced_gen = self.widget_manager.last_synced_generation.get(name).copied().unwrap_or(0);
                 let current_gen = text_content.generation;
+                // A repeat counter bump rewrites the newest synced line in
+                // place: drop it from the widget and re-add it with the new lines
+                let tail_rewritten = self
+                    .widget_manager
+                    .last_synced_tail_revision
+                    .get(name)
+                    .copied()
+                    .unwrap_or(0)
+                    != text_content.tail_revision;
 
                 // Check if there are new lines to sync (generation changed)
-                if current_gen > last_synced_gen {
+                if current_gen > last_synced_gen || tail_rewritten {
                     // Calculate how many lines to add
                     // If generation delta > line count, we need to resync entire buffer
-                    let gen_delta = (current_gen - last_synced_gen) as usize;
+                    let gen_delta = (current_gen.saturating_sub(last_synced_gen) as usize)
+                        + usize::from(tail_rewritten);
                     let needs_full_resync = gen_delta > text_content.lines.len();
+                    // A rebuild must not yank a scrolled-back reader to the bottom
+                    let saved_scroll = text_window.scroll_position();
 
                     if needs_full_resync {
                         // Full resync - clear and add all lines
@@ -164,6 +231,8 @@ impl TuiFrontend {
                             text_content.lines.len()
                         );
                         text_window.clear();
+                    } else if tail_rewritten {
+                        text_window.pop_last_line();
                     }
 
                     // Determine how many lines to add
@@ -183,6 +252,11 @@ impl TuiFrontend {
                             // Apply link preset color to Link spans that don't have a server color
                             let fg = if segment.span_type == crate::data::SpanType::Link && segment.fg.is_none() {
                                 link_preset_color
+                            } else if segment.span_type == crate::data::SpanType::Annotation
+                                && segment.fg.is_none()
+                            {
+                                // Repeat counters render dim, like timestamps
+                                Some(ratatui::style::Color::DarkGray)
                             } else {
                                 segment.fg.as_ref().and_then(|hex| parse_hex_color(hex).ok())
                             };
@@ -205,9 +279,19 @@ impl TuiFrontend {
                         text_window.finish_line(window.position.width);
                     }
 
+                    if needs_full_resync {
+                        text_window.restore_scroll_position(saved_scroll);
+                    }
+
                     // Update last synced generation
                     self.widget_manager.last_synced_generation
                         .insert(name.clone(), current_gen);
+                    self.widget_manager
+                        .last_synced_tail_revision
+                        .insert(name.clone(), text_content.tail_revision);
+                }
+                if let Some(view) = restored_view {
+                    text_window.restore_view(view);
                 }
 
                 // Sync scroll offset from data layer to TextWindow
@@ -265,6 +349,8 @@ impl TuiFrontend {
         theme: &crate::theme::AppTheme,
     ) {
         let window_defs = window_def_map(&app_core.layout);
+        let now_server =
+            chrono::Utc::now().timestamp() + app_core.message_processor.server_time_offset;
         for (name, window) in &app_core.ui_state.windows {
             if !matches!(
                 window.content,
@@ -273,6 +359,38 @@ impl TuiFrontend {
                 continue;
             }
 
+            // The rendered prompt follows game state, so it's refreshed
+            // every frame, ahead of the config gate below
+            if let Some(cmd_input) = self.widget_manager.command_inputs.get_mut(name) {
+                let template = match window_defs.get(name.as_str()) {
+                    Some(crate::config::WindowDef::CommandInput { data, .. }) => {
+                        data.prompt_template.as_deref()
+                    }
+                    _ => None,
+                };
+                let route = app_core.route.label().unwrap_or_default();
+                cmd_input.set_prompt_text(template.map(|template| {
+                    crate::core::prompt::render_prompt(
+                        template,
+                        &app_core.game_state,
+                        now_server,
+                        &route,
+                    )
+                }));
+                // Spellcheck underlines follow the typed text
+                let misspelled = cmd_input
+                    .get_input()
+                    .map(|text| {
+                        app_core
+                            .spell_errors(&text)
+                            .into_iter()
+                            .map(|error| (error.start, error.end))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                cmd_input.set_misspelled(misspelled);
+            }
+
             // This whole body is def/theme-derived configuration (the input's
             // text state lives in ui_state); skip it while nothing changed.
             if !self.config_sync_needed && self.widget_manager.command_inputs.contains_key(name) {
@@ -320,7 +438,7 @@ impl TuiFrontend {
                     .or_else(|| color_to_hex_string(&theme.window_border));
                 cmd_input.set_border_config(
                     base.show_border,
-                    Some(base.border_style.clone()),
+                    Some(border_style_for(app_core, base)),
                     border_color,
                 );
                 cmd_input.set_border_sides(base.border_sides.clone());
@@ -359,6 +477,10 @@ impl TuiFrontend {
                     .or_else(|| color_to_hex_string(&theme.text_primary));
                 cmd_input.set_prompt_icon_color(prompt_icon_color);
             }
+            cmd_input.set_history_options(
+                app_core.config.ui.history_expansion,
+                app_core.config.ui.history_prefix_search,
+            );
         }
     }
 
@@ -393,7 +515,7 @@ impl TuiFrontend {
                     let mut inv_window =
                         inventory_window::InventoryWindow::new(text_content.title.clone());
                     // GameState widgets still need highlight patterns for item highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     inv_window.set_highlights(highlights);
                     inv_window.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.inventory_windows.insert(name.clone(), inv_window);
@@ -427,6 +549,7 @@ impl TuiFrontend {
 
                     if current_gen != last_synced_gen {
                         // Content changed - sync text lines from WindowContent to widget
+                        let saved_scroll = inv_window.scroll_position();
                         inv_window.clear();
                         tracing::debug!("Syncing inventory widget '{}' with {} lines (gen changed from {} to {})",
                             name, text_content.lines.len(), last_synced_gen, current_gen);
@@ -436,6 +559,7 @@ impl TuiFrontend {
                             }
                             inv_window.finish_line();
                         }
+                        inv_window.restore_scroll_position(saved_scroll);
                         // Update last synced generation
                         self.widget_manager.last_synced_generation
                             .insert(name.clone(), current_gen);
@@ -474,7 +598,7 @@ impl TuiFrontend {
                     let mut spells_window =
                         spells_window::SpellsWindow::new(text_content.title.clone());
                     // GameState widgets still need highlight patterns for item highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     spells_window.set_highlights(highlights);
                     spells_window.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.spells_windows.insert(name.clone(), spells_window);
@@ -487,7 +611,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(def.base(), theme);
                         spells_window.set_border_config(
                             def.base().show_border,
-                            Some(def.base().border_style.clone()),
+                            Some(border_style_for(app_core, def.base())),
                             colors.border.clone(),
                         );
                         spells_window.set_transparent_background(def.base().transparent_background);
@@ -506,37 +630,32 @@ impl TuiFrontend {
                         spells_window.set_title(text_content.title.clone());
                     }
 
-                    // Change detection: only sync if content changed (using generation)
-                    let last_synced_gen =
-                        self.widget_manager.last_synced_generation.get(name).copied().unwrap_or(0);
-                    let current_gen = text_content.generation;
+                    // Change detection: rebuild when the listing, the grouping
+                    // settings, or the active spells changed
+                    let (grouping, collapsed) = match window_def {
+                        Some(crate::config::WindowDef::Spells { data, .. }) => {
+                            (data.group_by_circle, data.collapsed_groups.clone())
+                        }
+                        _ => (true, Vec::new()),
+                    };
+                    let active = app_core.game_state.effects.get("ActiveSpells");
+                    let view = spells_window::SpellsView {
+                        generation: text_content.generation,
+                        grouping,
+                        collapsed,
+                        active_generation: active.map_or(0, |a| a.generation),
+                    };
 
-                    if current_gen != last_synced_gen {
-                        // Content changed - sync text lines from WindowContent to widget
-                        spells_window.clear();
+                    if spells_window.needs_rebuild(&view) {
                         tracing::debug!(
-                            "Syncing spells widget '{}' with {} lines (gen changed from {} to {})",
+                            "Syncing spells widget '{}' with {} lines (gen {})",
                             name,
                             text_content.lines.len(),
-                            last_synced_gen,
-                            current_gen
+                            view.generation
                         );
-                        for line in &text_content.lines {
-                            for segment in &line.segments {
-                                spells_window.add_text(
-                                    segment.text.clone(),
-                                    segment.fg.clone(),
-                                    segment.bg.clone(),
-                                    segment.bold,
-                                    segment.span_type,
-                                    segment.link_data.clone(),
-                                );
-                            }
-                            spells_window.finish_line();
-                        }
-                        // Update last synced generation
-                        self.widget_manager.last_synced_generation
-                            .insert(name.clone(), current_gen);
+                        let lines: Vec<_> = text_content.lines.iter().cloned().collect();
+                        let active = active.map(|a| a.effects.as_slice()).unwrap_or_default();
+                        spells_window.set_spells(&lines, view, active);
                     }
                 } else {
                     tracing::warn!(
@@ -598,7 +717,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(def.base(), theme);
                         progress_bar.set_border_config(
                             def.base().show_border,
-                            Some(def.base().border_style.clone()),
+                            Some(border_style_for(app_core, def.base())),
                             colors.border.clone(),
                             def.base().border_sides.clone(),
                         );
@@ -715,7 +834,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(def.base(), theme);
                         countdown_widget.set_border_config(
                             def.base().show_border,
-                            Some(def.base().border_style.clone()),
+                            Some(border_style_for(app_core, def.base())),
                             colors.border.clone(),
                         );
                         countdown_widget.set_border_sides(def.base().border_sides.clone());
@@ -730,7 +849,9 @@ impl TuiFrontend {
 
                         // Get icon from CountdownWidgetData
                         if let crate::config::WindowDef::Countdown { data, .. } = def {
-                            if let Some(icon) = data.icon {
+                            if app_core.config.ui.screen_reader {
+                                countdown_widget.set_icon('#');
+                            } else if let Some(icon) = data.icon {
                                 countdown_widget.set_icon(icon);
                             }
                             let text_color = data.color.clone().or_else(|| colors.text.clone());
@@ -781,7 +902,7 @@ impl TuiFrontend {
 
                     let mut widget = active_effects::ActiveEffects::new(&label);
                     // GameState widgets still need highlight patterns for item highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     widget.set_highlights(highlights);
                     widget.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.active_effects_windows.insert(name.clone(), widget);
@@ -822,7 +943,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(def.base(), theme);
                         widget.set_border_config(
                             def.base().show_border,
-                            Some(def.base().border_style.clone()),
+                            Some(border_style_for(app_core, def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(def.base().border_sides.clone());
@@ -909,7 +1030,7 @@ impl TuiFrontend {
                     let colors = resolve_window_colors(def.base(), theme);
                     quickbar_widget.set_border_config(
                         def.base().show_border,
-                        Some(def.base().border_style.clone()),
+                        Some(border_style_for(app_core, def.base())),
                         colors.border.clone(),
                     );
                     quickbar_widget.set_border_sides(def.base().border_sides.clone());
@@ -978,7 +1099,7 @@ impl TuiFrontend {
                     let colors = resolve_window_colors(def.base(), theme);
                     bar_widget.set_border_config(
                         def.base().show_border,
-                        Some(def.base().border_style.clone()),
+                        Some(border_style_for(app_core, def.base())),
                         colors.border.clone(),
                     );
                     bar_widget.set_border_sides(def.base().border_sides.clone());
@@ -1023,6 +1144,8 @@ impl TuiFrontend {
                 if let Some(indicator_widget) = self.widget_manager.indicator_widgets.get_mut(name) {
                     // Set active state based on indicator data
                     indicator_widget.set_active(indicator_data.active);
+                    indicator_widget.set_shape_cues(theme.shape_cues);
+                    indicator_widget.set_ascii_marker(app_core.config.ui.screen_reader);
 
                     // Apply window configuration from layout
                     if let Some(window_def) =
@@ -1031,7 +1154,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         indicator_widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         indicator_widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1089,7 +1212,7 @@ impl TuiFrontend {
                     );
                     let mut widget = targets::Targets::new(name);
                     // GameState widgets still need highlight patterns for item highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     widget.set_highlights(highlights);
                     widget.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.targets_widgets.insert(name.clone(), widget);
@@ -1145,7 +1268,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1244,7 +1367,7 @@ impl TuiFrontend {
                     );
                     let mut widget = container_window::ContainerWindow::new(display_title);
                     // GameState widgets still need highlight patterns for item highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     widget.set_highlights(highlights);
                     widget.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     // Set link color from "links" preset before first update
@@ -1323,7 +1446,7 @@ impl TuiFrontend {
                 if !self.widget_manager.players_widgets.contains_key(name) {
                     let mut widget = players::Players::new(name);
                     // Apply highlight patterns for text highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     widget.set_highlights(highlights);
                     widget.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.players_widgets.insert(name.clone(), widget);
@@ -1350,7 +1473,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1387,7 +1510,7 @@ impl TuiFrontend {
                 if !self.widget_manager.items_widgets.contains_key(name) {
                     let mut widget = items::Items::new(name);
                     // Apply highlight patterns for text highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     widget.set_highlights(highlights);
                     widget.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.items_widgets.insert(name.clone(), widget);
@@ -1411,7 +1534,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1454,6 +1577,7 @@ impl TuiFrontend {
 
                 // Update widget
                 if let Some(widget) = self.widget_manager.dashboard_widgets.get_mut(name) {
+                    widget.set_shape_cues(theme.shape_cues);
                     // Apply configuration
                     if let Some(window_def) =
                         window_defs.get(name.as_str()).copied()
@@ -1461,7 +1585,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1525,8 +1649,10 @@ impl TuiFrontend {
             if let crate::data::WindowContent::TabbedText(tabbed_content) = &window.content {
                 let window_def = window_defs.get(name.as_str()).copied();
 
-                // Ensure widget exists - create if needed
-                if !self.widget_manager.tabbed_text_windows.contains_key(name) {
+                // Ensure widget exists - create if needed. A rebuilt one gets
+                // its tabs' scroll positions and unread counts back below.
+                let rebuilt = !self.widget_manager.tabbed_text_windows.contains_key(name);
+                if rebuilt {
                     let tabs: Vec<(String, bool, bool)> = tabbed_content
                         .tabs
                         .iter()
@@ -1561,7 +1687,7 @@ impl TuiFrontend {
                             let colors = resolve_window_colors(def.base(), theme);
                             widget.set_border_config(
                                 def.base().show_border,
-                                Some(def.base().border_style.clone()),
+                                Some(border_style_for(app_core, def.base())),
                                 colors.border.clone(),
                             );
                             widget.set_border_sides(def.base().border_sides.clone());
@@ -1581,13 +1707,16 @@ impl TuiFrontend {
                                     &data.tab_bar_position,
                                 );
                                 widget.set_tab_bar_position(tab_position);
+                                widget.set_tab_bar_width(data.tab_bar_width);
                                 widget.set_tab_separator(data.tab_separator);
                                 widget.set_tab_colors(
                                     data.tab_active_color.clone(),
                                     data.tab_inactive_color.clone(),
                                     data.tab_unread_color.clone(),
                                 );
-                                if let Some(prefix) = data.tab_unread_prefix.clone() {
+                                if app_core.config.ui.screen_reader {
+                                    widget.set_unread_prefix("* ".to_string());
+                                } else if let Some(prefix) = data.tab_unread_prefix.clone() {
                                     widget.set_unread_prefix(prefix);
                                 }
                             }
@@ -1598,12 +1727,33 @@ impl TuiFrontend {
                         }
                     }
 
+                    // Tabs freeze together; the indicator goes on the window title
+                    let freeze_label = tabbed_content.freeze_label();
+                    if self.widget_manager.freeze_labels.get(name) != freeze_label.as_ref()
+                        || (self.config_sync_needed && freeze_label.is_some())
+                    {
+                        widget.set_title(title_with_freeze(
+                            window_def,
+                            name,
+                            freeze_label.as_deref(),
+                        ));
+                        match freeze_label {
+                            Some(label) => self
+                                .widget_manager
+                                .freeze_labels
+                                .insert(name.clone(), label),
+                            None => self.widget_manager.freeze_labels.remove(name),
+                        };
+                    }
+
                     // Set active tab
                     widget.switch_to_tab(tabbed_content.active_tab_index);
+                    widget.set_alert_blink(app_core.config.ui.animations_enabled());
 
                     // Sync content for each tab
                     for (i, tab_state) in tabbed_content.tabs.iter().enumerate() {
                         let ignore_activity = tab_state.definition.ignore_activity;
+                        widget.set_tab_alerting(i, tab_state.alerting);
                         if let Some(text_window) = widget.get_tab_window_mut(i) {
                             text_window
                                 .set_show_timestamps(tab_state.definition.show_timestamps);
@@ -1623,6 +1773,7 @@ impl TuiFrontend {
                                 let needs_full_resync =
                                     gen_delta > tab_state.content.lines.len();
                                 let mut lines_added = 0usize;
+                                let saved_scroll = text_window.scroll_position();
 
                                 if needs_full_resync {
                                     text_window.clear();
@@ -1665,6 +1816,16 @@ impl TuiFrontend {
                                     }
                                     text_window.finish_line(window.position.width);
                                 }
+                                if needs_full_resync {
+                                    text_window.restore_scroll_position(saved_scroll);
+                                }
+                                let restored_view = rebuilt
+                                    .then(|| app_core.ui_state.window_views.get(&tab_sync_key))
+                                    .flatten()
+                                    .copied();
+                                if let Some(view) = restored_view {
+                                    text_window.restore_view(view);
+                                }
                                 // Apply ignore flag before unread handling so unread is skipped when ignored
                                 widget.set_tab_ignore_activity(i, ignore_activity);
 
@@ -1672,11 +1833,15 @@ impl TuiFrontend {
                                     .last_synced_generation
                                     .insert(tab_sync_key, current_gen);
 
-                                if i != tabbed_content.active_tab_index
-                                    && lines_added > 0
-                                    && !ignore_activity
-                                {
-                                    widget.mark_tab_unread(i, lines_added);
+                                if i != tabbed_content.active_tab_index && !ignore_activity {
+                                    // A rebuilt tab's lines aren't new: only
+                                    // what was unread before counts
+                                    let unread = match restored_view {
+                                        Some(view) => view.unread,
+                                        None if rebuilt => 0,
+                                        None => lines_added,
+                                    };
+                                    widget.mark_tab_unread(i, unread);
                                 }
                             }
                         }
@@ -1712,7 +1877,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1766,6 +1931,7 @@ impl TuiFrontend {
 
                 // Update widget
                 if let Some(widget) = self.widget_manager.injury_doll_widgets.get_mut(name) {
+                    widget.set_shape_cues(theme.shape_cues);
                     // Update all injuries
                     for (body_part, level) in &injury_data.injuries {
                         widget.set_injury(body_part.clone(), *level);
@@ -1778,7 +1944,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         widget.set_border_sides(window_def.base().border_sides.clone());
@@ -1892,7 +2058,7 @@ impl TuiFrontend {
                 widget.set_title(title);
                 widget.set_border_config(
                     base.show_border,
-                    Some(base.border_style.clone()),
+                    Some(border_style_for(app_core, base)),
                     colors.border.clone(),
                 );
                 widget.set_border_sides(base.border_sides.clone());
@@ -1944,7 +2110,7 @@ impl TuiFrontend {
                         let colors = resolve_window_colors(window_def.base(), theme);
                         hand_widget.set_border_config(
                             window_def.base().show_border,
-                            Some(window_def.base().border_style.clone()),
+                            Some(border_style_for(app_core, window_def.base())),
                             colors.border.clone(),
                         );
                         hand_widget.set_border_sides(window_def.base().border_sides.clone());
@@ -2036,7 +2202,7 @@ impl TuiFrontend {
             if let Some(room_window) = self.widget_manager.room_windows.get_mut(window_name) {
                 // GameState widgets still need highlight patterns for item highlighting
                 if is_new {
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     room_window.set_highlights(highlights);
                     room_window.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                 }
@@ -2044,7 +2210,7 @@ impl TuiFrontend {
                 let colors = resolve_window_colors(window_def.base(), theme);
                 room_window.set_border_config(
                     window_def.base().show_border,
-                    Some(window_def.base().border_style.clone()),
+                    Some(border_style_for(app_core, window_def.base())),
                     colors.border.clone(),
                 );
                 room_window.set_border_sides(window_def.base().border_sides.clone());
@@ -2197,7 +2363,7 @@ impl TuiFrontend {
                         .unwrap_or_else(|| "Perceptions".to_string());
                     let mut perception_window = super::perception::PerceptionWindow::new(title);
                     // GameState widgets still need highlight patterns for item highlighting
-                    let highlights: Vec<_> = app_core.config.highlights.values().cloned().collect();
+                    let highlights = app_core.config.active_highlights();
                     perception_window.set_highlights(highlights);
                     perception_window.set_replace_enabled(app_core.config.highlight_settings.replace_enabled);
                     self.widget_manager.perception_windows.insert(name.clone(), perception_window);
@@ -2220,6 +2386,8 @@ impl TuiFrontend {
                 if let Some(perception_window) = self.widget_manager.perception_windows.get_mut(name) {
                     if let Some(def) = window_def {
                         let colors = resolve_window_colors(def.base(), theme);
+                        perception_window
+                            .set_border_style(Some(border_style_for(app_core, def.base())));
                         perception_window.set_show_border(def.base().show_border);
                         perception_window.set_border_color(colors.border.clone());
                         perception_window.set_background_color(colors.background.clone());
@@ -2337,6 +2505,8 @@ impl TuiFrontend {
                 // Apply theme colors
                 if let Some(def) = window_def {
                     let colors = resolve_window_colors(def.base(), theme);
+                    experience_widget
+                        .set_border_style(Some(border_style_for(app_core, def.base())));
                     if let Some(border_color) = &colors.border {
                         if let Ok(c) = parse_hex_color(border_color) {
                             experience_widget.set_border_color(c);
@@ -2406,6 +2576,10 @@ impl TuiFrontend {
                 // Apply theme colors and config toggles
                 if let Some(crate::config::WindowDef::GS4Experience { data, .. }) = window_def {
                     let colors = resolve_window_colors(window_def.unwrap().base(), theme);
+                    gs4_exp_widget.set_border_style(Some(border_style_for(
+                        app_core,
+                        window_def.unwrap().base(),
+                    )));
                     if let Some(border_color) = &colors.border {
                         if let Ok(c) = parse_hex_color(border_color) {
                             gs4_exp_widget.set_border_color(c);
@@ -2423,6 +2597,7 @@ impl TuiFrontend {
                     gs4_exp_widget.set_show_mind_bar(data.show_mind_bar);
                     gs4_exp_widget.set_show_total_exp(data.show_total_exp);
                     gs4_exp_widget.set_show_ascension_exp(data.show_ascension_exp);
+                    gs4_exp_widget.set_show_eta(data.show_eta);
                     // Apply custom bar colors (if configured)
                     if let Some(color_str) = &data.mind_bar_color {
                         if let Ok(c) = parse_hex_color(color_str) {
@@ -2438,6 +2613,14 @@ impl TuiFrontend {
 
                 // Update from game state
                 gs4_exp_widget.update_from_state(&app_core.game_state.gs4_experience);
+                // Counts down between pulses, so not tied to the generation
+                gs4_exp_widget.set_eta(
+                    app_core
+                        .game_state
+                        .gs4_experience
+                        .absorb
+                        .readout(std::time::Instant::now()),
+                );
             }
         }
     }
@@ -2510,6 +2693,7 @@ impl TuiFrontend {
                 // Apply theme colors
                 if let Some(def) = window_def {
                     let colors = resolve_window_colors(def.base(), theme);
+                    enc_widget.set_border_style(Some(border_style_for(app_core, def.base())));
                     if let Some(border_color) = &colors.border {
                         if let Ok(c) = parse_hex_color(border_color) {
                             enc_widget.set_border_color(c);
@@ -2551,6 +2735,572 @@ impl TuiFrontend {
         }
     }
 
+    /// Sync all Stance widgets from GameState.stance and posture indicators
+    pub(crate) fn sync_stance_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        let posture = crate::core::stance::posture(&app_core.game_state.status);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Stance = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let stance_widget = self
+                    .widget_manager
+                    .stance_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::stance::Stance::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::Stance { data, .. } = def {
+                        stance_widget.set_show_bar(data.show_bar);
+                        stance_widget.set_show_posture(data.show_posture);
+                    }
+                    let base = def.base();
+                    stance_widget.set_show_border(base.show_border);
+                    stance_widget.set_show_title(base.show_title);
+                    stance_widget.set_border_sides(base.border_sides.clone());
+                    stance_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            stance_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            stance_widget.set_text_color(c);
+                        }
+                    }
+                    stance_widget.set_background_color(colors.background.clone());
+                }
+
+                stance_widget.update(&app_core.game_state.stance, posture);
+            }
+        }
+    }
+
+    /// Sync all Wealth widgets from GameState.wealth
+    pub(crate) fn sync_wealth_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Wealth = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let wealth_widget = self
+                    .widget_manager
+                    .wealth_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::wealth::Wealth::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::Wealth { data, .. } = def {
+                        wealth_widget.set_show_session(data.show_session);
+                    }
+                    let base = def.base();
+                    wealth_widget.set_show_border(base.show_border);
+                    wealth_widget.set_show_title(base.show_title);
+                    wealth_widget.set_border_sides(base.border_sides.clone());
+                    wealth_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            wealth_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            wealth_widget.set_text_color(c);
+                        }
+                    }
+                    wealth_widget.set_background_color(colors.background.clone());
+                }
+
+                wealth_widget.update_from_state(&app_core.game_state.wealth);
+            }
+        }
+    }
+
+    /// Sync all TaskTracker widgets from GameState.bounty
+    pub(crate) fn sync_task_tracker_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::TaskTracker = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let tracker_widget = self
+                    .widget_manager
+                    .task_tracker_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::task_tracker::TaskTracker::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::TaskTracker { data, .. } = def {
+                        tracker_widget.set_show_progress(data.show_progress);
+                    }
+                    let base = def.base();
+                    tracker_widget.set_show_border(base.show_border);
+                    tracker_widget.set_show_title(base.show_title);
+                    tracker_widget.set_border_sides(base.border_sides.clone());
+                    tracker_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            tracker_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            tracker_widget.set_text_color(c);
+                        }
+                    }
+                    tracker_widget.set_background_color(colors.background.clone());
+                }
+
+                tracker_widget.update_from_state(&app_core.game_state.bounty);
+            }
+        }
+    }
+
+    /// Sync all SocietyTasks widgets from GameState.society
+    pub(crate) fn sync_society_tasks_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::SocietyTasks = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let society_widget = self
+                    .widget_manager
+                    .society_tasks_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::society_tasks::SocietyTasks::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::SocietyTasks { data, .. } = def {
+                        society_widget.set_show_progress(data.show_progress);
+                    }
+                    let base = def.base();
+                    society_widget.set_show_border(base.show_border);
+                    society_widget.set_show_title(base.show_title);
+                    society_widget.set_border_sides(base.border_sides.clone());
+                    society_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            society_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            society_widget.set_text_color(c);
+                        }
+                    }
+                    society_widget.set_background_color(colors.background.clone());
+                }
+
+                society_widget.update_from_state(&app_core.game_state.society);
+            }
+        }
+    }
+
+    pub(crate) fn sync_combat_stats_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::CombatStats = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let combat_widget = self
+                    .widget_manager
+                    .combat_stats_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::combat_stats::CombatStatsWidget::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::CombatStats { data, .. } = def {
+                        combat_widget.set_creatures(data.creatures);
+                    }
+                    let base = def.base();
+                    combat_widget.set_show_border(base.show_border);
+                    combat_widget.set_show_title(base.show_title);
+                    combat_widget.set_border_sides(base.border_sides.clone());
+                    combat_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            combat_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            combat_widget.set_text_color(c);
+                        }
+                    }
+                    combat_widget.set_background_color(colors.background.clone());
+                }
+
+                combat_widget.update_from_state(&app_core.game_state.combat);
+            }
+        }
+    }
+
+    /// Sync all Calendar widgets from GameState.calendar
+    pub(crate) fn sync_calendar_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        let server_time =
+            chrono::Utc::now().timestamp() + app_core.message_processor.server_time_offset;
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Calendar = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let calendar_widget = self
+                    .widget_manager
+                    .calendar_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::calendar::Calendar::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::Calendar { data, .. } = def {
+                        calendar_widget.set_show_weekday(data.show_weekday);
+                        calendar_widget.set_show_local_time(data.show_local_time);
+                        calendar_widget.set_moons(&data.moons);
+                    }
+                    let base = def.base();
+                    calendar_widget.set_show_border(base.show_border);
+                    calendar_widget.set_show_title(base.show_title);
+                    calendar_widget.set_border_sides(base.border_sides.clone());
+                    calendar_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            calendar_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            calendar_widget.set_text_color(c);
+                        }
+                    }
+                    calendar_widget.set_background_color(colors.background.clone());
+                }
+
+                calendar_widget.update_from_state(&app_core.game_state.calendar, server_time);
+            }
+        }
+    }
+
+    /// Sync all Reminders widgets from AppCore.reminders
+    pub(crate) fn sync_reminders_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        let now = chrono::Utc::now().timestamp();
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Reminders = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let reminders_widget = self
+                    .widget_manager
+                    .reminders_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::reminders::Reminders::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::Reminders { data, .. } = def {
+                        reminders_widget.set_show_repeat(data.show_repeat);
+                    }
+                    let base = def.base();
+                    reminders_widget.set_show_border(base.show_border);
+                    reminders_widget.set_show_title(base.show_title);
+                    reminders_widget.set_border_sides(base.border_sides.clone());
+                    reminders_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            reminders_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            reminders_widget.set_text_color(c);
+                        }
+                    }
+                    reminders_widget.set_background_color(colors.background.clone());
+                }
+
+                reminders_widget.update_from_state(&app_core.reminders, now);
+            }
+        }
+    }
+
+    /// Sync all Notes widgets from AppCore.notes
+    pub(crate) fn sync_notes_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Notes = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let notes_widget = self
+                    .widget_manager
+                    .notes_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::notes::Notes::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::Notes { data, .. } = def {
+                        notes_widget.set_section(&data.section);
+                    }
+                    let base = def.base();
+                    notes_widget.set_show_border(base.show_border);
+                    notes_widget.set_show_title(base.show_title);
+                    notes_widget.set_border_sides(base.border_sides.clone());
+                    notes_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            notes_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            notes_widget.set_text_color(c);
+                        }
+                    }
+                    notes_widget.set_background_color(colors.background.clone());
+                }
+
+                let focused = app_core.ui_state.input_focus.as_deref() == Some(name.as_str());
+                notes_widget.set_focused_border_color(focused.then(|| {
+                    super::crossterm_bridge::to_ratatui_color(theme.window_border_focused)
+                }));
+                notes_widget.update_from_state(&app_core.notes);
+            }
+        }
+    }
+
+    /// Sync all Watch widgets from AppCore::watch_rows
+    pub(crate) fn sync_watch_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Watch = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let watch_widget = self
+                    .widget_manager
+                    .watch_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::watch::Watch::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    let base = def.base();
+                    watch_widget.set_show_border(base.show_border);
+                    watch_widget.set_show_title(base.show_title);
+                    watch_widget.set_border_sides(base.border_sides.clone());
+                    watch_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            watch_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            watch_widget.set_text_color(c);
+                        }
+                    }
+                    watch_widget.set_background_color(colors.background.clone());
+                }
+
+                watch_widget.update_rows(app_core.watch_rows(name));
+            }
+        }
+    }
+
+    /// Sync all Traffic widgets from the network::traffic tap
+    pub(crate) fn sync_traffic_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        use crate::network::traffic;
+
+        let window_defs = window_def_map(&app_core.layout);
+        let generation = traffic::generation();
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Traffic = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let traffic_widget = self
+                    .widget_manager
+                    .traffic_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::traffic::Traffic::new(&title)
+                    });
+
+                if let Some(def) = window_def {
+                    let base = def.base();
+                    traffic_widget.set_show_border(base.show_border);
+                    traffic_widget.set_show_title(base.show_title);
+                    traffic_widget.set_border_sides(base.border_sides.clone());
+                    traffic_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            traffic_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            traffic_widget.set_text_color(c);
+                        }
+                    }
+                    traffic_widget.set_background_color(colors.background.clone());
+                    if let crate::config::WindowDef::Traffic { data, .. } = def {
+                        traffic_widget.set_hex(data.hex);
+                    }
+                }
+
+                traffic_widget.set_capturing(traffic::is_enabled());
+                if traffic_widget.generation() != generation {
+                    // More than a window ever shows, even wrapped
+                    traffic_widget.update_lines(traffic::recent(200), generation);
+                }
+            }
+        }
+    }
+
+    /// Sync all Played widgets from AppCore.played
+    pub(crate) fn sync_played_widgets(
+        &mut self,
+        app_core: &crate::core::AppCore,
+        theme: &crate::theme::AppTheme,
+    ) {
+        let window_defs = window_def_map(&app_core.layout);
+        let now = chrono::Local::now();
+        for (name, window) in app_core.ui_state.windows.iter() {
+            if let crate::data::WindowContent::Played = &window.content {
+                let window_def = window_defs.get(name.as_str()).copied();
+                let played_widget = self
+                    .widget_manager
+                    .played_widgets
+                    .entry(name.clone())
+                    .or_insert_with(|| {
+                        let title = window_def
+                            .and_then(|wd| wd.base().title.clone())
+                            .unwrap_or_else(|| name.clone());
+                        super::played::Played::new(&title)
+                    });
+
+                let mut show_total = true;
+                if let Some(def) = window_def {
+                    if let crate::config::WindowDef::Played { data, .. } = def {
+                        show_total = data.show_total;
+                    }
+                    let base = def.base();
+                    played_widget.set_show_border(base.show_border);
+                    played_widget.set_show_title(base.show_title);
+                    played_widget.set_border_sides(base.border_sides.clone());
+                    played_widget.set_border_style(Some(border_style_for(app_core, base)));
+
+                    let colors = resolve_window_colors(base, theme);
+                    if let Some(border_color) = &colors.border {
+                        if let Ok(c) = parse_hex_color(border_color) {
+                            played_widget.set_border_color(c);
+                        }
+                    }
+                    if let Some(text_color) = &colors.text {
+                        if let Ok(c) = parse_hex_color(text_color) {
+                            played_widget.set_text_color(c);
+                        }
+                    }
+                    played_widget.set_background_color(colors.background.clone());
+                }
+
+                played_widget.set_rows(app_core.played.rows(now, show_total));
+            }
+        }
+    }
+
     /// Sync MiniVitals widgets - GS4 horizontal 4-bar layout
     pub(crate) fn sync_minivitals_widgets(
         &mut self,
@@ -2612,6 +3362,7 @@ impl TuiFrontend {
                 // Apply theme colors
                 if let Some(def) = window_def {
                     let colors = resolve_window_colors(def.base(), theme);
+                    mv_widget.set_border_style(Some(border_style_for(app_core, def.base())));
                     if let Some(border_color) = &colors.border {
                         if let Ok(c) = parse_hex_color(border_color) {
                             mv_widget.set_border_color(c);
@@ -2704,6 +3455,7 @@ impl TuiFrontend {
                 // Apply theme colors
                 if let Some(def) = window_def {
                     let colors = resolve_window_colors(def.base(), theme);
+                    betrayer_widget.set_border_style(Some(border_style_for(app_core, def.base())));
                     if let Some(border_color) = &colors.border {
                         if let Ok(c) = parse_hex_color(border_color) {
                             betrayer_widget.set_border_color(c);
@@ -1,17 +1,22 @@
 //! Multi-tab wrapper around `TextWindow` for stream multiplexing.
 //!
 //! Handles unread counts, tab bar placement, and themed chrome while delegating
-//! actual text rendering to the existing `TextWindow`.
+//! actual text rendering to the existing `TextWindow`. The tab bar is a row
+//! above or below the text, or a fixed-width column of labels (with unread
+//! counts) to its left or right. Tabs that don't fit scroll out of view
+//! behind ‹ › indicators; the wheel scrolls the bar and clicking an indicator
+//! lists the hidden tabs.
 
 use super::text_window::TextWindow;
 use super::title_position::{self, TitlePosition};
 use crate::selection::SelectionState;
+use crate::frontend::common::text_width;
 use crate::theme::AppTheme;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, BorderType},
+    widgets::Block,
 };
 use std::time::Duration;
 
@@ -21,29 +26,49 @@ use super::crossterm_bridge;
 pub enum TabBarPosition {
     Top,
     Bottom,
+    Left,
+    Right,
 }
 
 impl TabBarPosition {
     pub(crate) fn from_str(value: &str) -> Self {
         match value.to_lowercase().as_str() {
             "bottom" => Self::Bottom,
+            "left" => Self::Left,
+            "right" => Self::Right,
             _ => Self::Top,
         }
     }
+
+    fn is_vertical(&self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
 }
 
+/// Between labels in a horizontal tab bar
+const DIVIDER: &str = " | ";
+
 struct TabInfo {
     name: String,
     window: TextWindow,
     has_unread: bool,
     unread_count: usize,
     ignore_activity: bool,
+    /// Background activity on a tab with `alert_flash` (mirrored from core)
+    alerting: bool,
 }
 
 pub struct TabbedTextWindow {
     tabs: Vec<TabInfo>,
     active_tab_index: usize,
     tab_bar_position: TabBarPosition,
+    /// Label column width for a left/right tab bar
+    tab_bar_width: u16,
+    /// First tab shown when they don't all fit
+    tab_scroll: usize,
+    /// Active tab last scrolled into view, so a new active tab is revealed
+    /// once and the wheel can move away from it afterwards
+    revealed_tab: Option<usize>,
     show_border: bool,
     border_style: Option<String>,
     border_color: Option<String>,
@@ -58,6 +83,9 @@ pub struct TabbedTextWindow {
     tab_unread_prefix: String,
     show_tab_separator: bool,
     title_position: TitlePosition,
+    /// Blink alerting tab labels; off under reduced motion, where they are
+    /// shown reversed instead
+    alert_blink: bool,
 }
 
 impl TabbedTextWindow {
@@ -66,6 +94,9 @@ impl TabbedTextWindow {
             tabs: Vec::new(),
             active_tab_index: 0,
             tab_bar_position,
+            tab_bar_width: 12,
+            tab_scroll: 0,
+            revealed_tab: None,
             show_border: true,
             border_style: Some("single".to_string()),
             border_color: Some("#808080".to_string()),
@@ -80,6 +111,7 @@ impl TabbedTextWindow {
             tab_unread_prefix: "* ".to_string(),
             show_tab_separator: false,
             title_position: TitlePosition::TopLeft,
+            alert_blink: true,
         }
     }
 
@@ -106,6 +138,10 @@ impl TabbedTextWindow {
         self.tab_bar_position = position;
     }
 
+    pub fn set_tab_bar_width(&mut self, width: u16) {
+        self.tab_bar_width = width.max(1);
+    }
+
     pub fn set_tab_colors(
         &mut self,
         active: Option<String>,
@@ -132,11 +168,6 @@ impl TabbedTextWindow {
         self.show_tab_separator = show;
     }
 
-    /// Check if tab separator is shown (needed for click detection)
-    pub fn has_tab_separator(&self) -> bool {
-        self.show_tab_separator
-    }
-
     pub fn set_content_align(&mut self, align: Option<String>) {
         for tab in &mut self.tabs {
             tab.window.set_content_align(align.clone());
@@ -153,6 +184,16 @@ impl TabbedTextWindow {
         }
     }
 
+    pub fn set_tab_alerting(&mut self, index: usize, alerting: bool) {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.alerting = alerting && index != self.active_tab_index;
+        }
+    }
+
+    pub fn set_alert_blink(&mut self, blink: bool) {
+        self.alert_blink = blink;
+    }
+
     /// Handle a mouse click; returns true if it activated a tab.
     pub fn handle_mouse_click(
         &mut self,
@@ -160,13 +201,13 @@ impl TabbedTextWindow {
         mouse_col: u16,
         mouse_row: u16,
     ) -> bool {
-        let tab_bar = self.tab_bar_rect(window_rect);
-        if mouse_col < tab_bar.x || mouse_col >= tab_bar.x + tab_bar.width || mouse_row != tab_bar.y
-        {
-            return false;
-        }
-
-        if let Some(idx) = self.get_tab_at_position(mouse_col, tab_bar) {
+        let tab_bar = self.tab_bar_area(window_rect);
+        let clicked = self
+            .tab_bar_layout(tab_bar, self.tab_scroll)
+            .slots
+            .into_iter()
+            .find(|(_, slot)| rect_contains(*slot, mouse_col, mouse_row));
+        if let Some((idx, _)) = clicked {
             self.switch_to_tab(idx);
             return true;
         }
@@ -174,6 +215,51 @@ impl TabbedTextWindow {
         false
     }
 
+    /// Mouse wheel over the tab bar scrolls the tabs (negative = back).
+    /// Returns false if (col, row) isn't on the bar.
+    pub fn scroll_tab_bar(
+        &mut self,
+        window_rect: Rect,
+        mouse_col: u16,
+        mouse_row: u16,
+        delta: isize,
+    ) -> bool {
+        let tab_bar = self.tab_bar_area(window_rect);
+        if !rect_contains(tab_bar, mouse_col, mouse_row) {
+            return false;
+        }
+        let max = self.max_tab_scroll(tab_bar);
+        self.tab_scroll = self.tab_scroll.saturating_add_signed(delta).min(max);
+        true
+    }
+
+    /// Whether (col, row) is on a ‹ or › overflow indicator
+    pub fn overflow_indicator_at(&self, window_rect: Rect, mouse_col: u16, mouse_row: u16) -> bool {
+        let layout = self.tab_bar_layout(self.tab_bar_area(window_rect), self.tab_scroll);
+        [layout.before, layout.after]
+            .into_iter()
+            .flatten()
+            .any(|cell| rect_contains(cell, mouse_col, mouse_row))
+    }
+
+    /// Tabs scrolled out of view: (index, name, unread count)
+    pub fn hidden_tabs(&self, window_rect: Rect) -> Vec<(usize, String, usize)> {
+        let layout = self.tab_bar_layout(self.tab_bar_area(window_rect), self.tab_scroll);
+        self.tabs
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !layout.slots.iter().any(|(shown, _)| shown == idx))
+            .map(|(idx, tab)| {
+                let unread = if tab.ignore_activity {
+                    0
+                } else {
+                    tab.unread_count
+                };
+                (idx, tab.name.clone(), unread)
+            })
+            .collect()
+    }
+
     pub fn add_tab(
         &mut self,
         name: String,
@@ -195,6 +281,7 @@ impl TabbedTextWindow {
             has_unread: false,
             unread_count: 0,
             ignore_activity,
+            alerting: false,
         });
     }
 
@@ -233,6 +320,7 @@ impl TabbedTextWindow {
             if let Some(tab) = self.tabs.get_mut(index) {
                 tab.has_unread = false;
                 tab.unread_count = 0;
+                tab.alerting = false;
             }
         }
     }
@@ -324,30 +412,7 @@ impl TabbedTextWindow {
     ) -> Option<(usize, usize)> {
         let active_tab = self.tabs.get(self.active_tab_index)?;
 
-        // Account for tab bar height, border, and separator
-        let border_offset = if self.show_border { 1 } else { 0 };
-        let tab_bar_height = 1;
-        let separator_offset: u16 = if self.show_tab_separator { 1 } else { 0 };
-
-        // Calculate inner rect for the text window
-        let inner_rect = match self.tab_bar_position {
-            TabBarPosition::Top => Rect {
-                x: window_rect.x + border_offset,
-                y: window_rect.y + border_offset + tab_bar_height + separator_offset,
-                width: window_rect.width.saturating_sub(2 * border_offset),
-                height: window_rect
-                    .height
-                    .saturating_sub(2 * border_offset + tab_bar_height + separator_offset),
-            },
-            TabBarPosition::Bottom => Rect {
-                x: window_rect.x + border_offset,
-                y: window_rect.y + border_offset,
-                width: window_rect.width.saturating_sub(2 * border_offset),
-                height: window_rect
-                    .height
-                    .saturating_sub(2 * border_offset + tab_bar_height + separator_offset),
-            },
-        };
+        let inner_rect = self.content_rect(window_rect);
 
         active_tab
             .window
@@ -371,6 +436,23 @@ impl TabbedTextWindow {
         }
     }
 
+    /// ANSI-colored selection from the active tab
+    pub fn extract_selection_ansi(
+        &self,
+        start_line: usize,
+        start_col: usize,
+        end_line: usize,
+        end_col: usize,
+    ) -> String {
+        if let Some(active_tab) = self.tabs.get(self.active_tab_index) {
+            active_tab
+                .window
+                .extract_selection_ansi(start_line, start_col, end_line, end_col)
+        } else {
+            String::new()
+        }
+    }
+
     /// Get visible lines info from the active tab (for link detection)
     /// Returns (start_line_index, visible_lines_with_segments)
     pub fn get_visible_lines_info(
@@ -384,14 +466,9 @@ impl TabbedTextWindow {
         }
     }
 
-    /// Check if this window has a border
-    pub fn has_border(&self) -> bool {
-        self.show_border
-    }
-
-    /// Check if tab bar is at the top
-    pub fn tab_bar_at_top(&self) -> bool {
-        self.tab_bar_position == TabBarPosition::Top
+    /// Where the active tab's text is drawn for a given outer window rect
+    pub fn content_rect(&self, window_rect: Rect) -> Rect {
+        self.split_inner(self.inner_area(window_rect)).2
     }
 
     /// Check if any tabs have unread messages
@@ -431,6 +508,22 @@ impl TabbedTextWindow {
         }
     }
 
+    /// Each tab's view by tab name: the active tab's scroll position and
+    /// unseen lines, or an inactive tab's unread count
+    pub fn tab_views(&self) -> Vec<(String, crate::data::WindowView)> {
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(idx, tab)| {
+                let mut view = tab.window.view();
+                if idx != self.active_tab_index {
+                    view.unread = tab.unread_count;
+                }
+                (tab.name.clone(), view)
+            })
+            .collect()
+    }
+
     /// Mark a tab as having unread content, incrementing its counter by `count`
     pub fn mark_tab_unread(&mut self, index: usize, count: usize) {
         if let Some(tab) = self.tabs.get_mut(index) {
@@ -513,6 +606,20 @@ impl TabbedTextWindow {
             .is_some_and(|tab| tab.window.is_scrolled_back())
     }
 
+    /// Whether a screen cell falls on the active tab's "N new lines" pill
+    pub fn new_lines_pill_contains(&self, x: u16, y: u16) -> bool {
+        self.tabs
+            .get(self.active_tab_index)
+            .is_some_and(|tab| tab.window.new_lines_pill_contains(x, y))
+    }
+
+    /// Jump the active tab back to the live view
+    pub fn scroll_to_live(&mut self) {
+        if let Some(tab) = self.tabs.get_mut(self.active_tab_index) {
+            tab.window.scroll_to_live();
+        }
+    }
+
     /// Check if the active tab is frozen for selection
     pub fn is_frozen_for_selection(&self) -> bool {
         self.tabs
@@ -572,72 +679,243 @@ impl TabbedTextWindow {
         }
     }
 
-    pub fn get_tab_at_position(&self, x: u16, tab_bar_rect: Rect) -> Option<usize> {
-        let mut curr_x = tab_bar_rect.x;
+    /// The area inside the border for a given outer window rect
+    fn inner_area(&self, outer: Rect) -> Rect {
+        // Match render() logic for border handling
+        if self.show_border {
+            let mut block = Block::default();
+            let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
+            let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            block = block.borders(borders).border_set(border_set);
+            block.inner(outer)
+        } else {
+            outer
+        }
+    }
 
-        for (idx, tab) in self.tabs.iter().enumerate() {
-            if curr_x >= tab_bar_rect.right() {
-                break;
+    /// Split the area inside the border into the tab bar, the optional
+    /// separator, and the content
+    fn split_inner(&self, inner: Rect) -> (Rect, Option<Rect>, Rect) {
+        let bar_width = self.tab_bar_width.min(inner.width);
+        let (tab_bar, mut content) = match self.tab_bar_position {
+            TabBarPosition::Top => (
+                Rect {
+                    height: inner.height.min(1),
+                    ..inner
+                },
+                Rect {
+                    y: inner.y + inner.height.min(1),
+                    height: inner.height.saturating_sub(1),
+                    ..inner
+                },
+            ),
+            TabBarPosition::Bottom => {
+                let content_height = inner.height.saturating_sub(1);
+                (
+                    Rect {
+                        y: inner.y + content_height,
+                        height: inner.height - content_height,
+                        ..inner
+                    },
+                    Rect {
+                        height: content_height,
+                        ..inner
+                    },
+                )
             }
-            let tab_text = if idx == self.active_tab_index {
-                tab.name.clone()
-            } else if tab.has_unread {
-                format!("{}{}", self.tab_unread_prefix, tab.name)
-            } else {
-                tab.name.clone()
-            };
-
-            let tab_width = tab_text.chars().count() as u16;
-            let divider_width = if idx < self.tabs.len() - 1 { 3 } else { 0 }; // " | "
-
-            if x >= curr_x && x < curr_x + tab_width + divider_width {
-                return Some(idx);
+            TabBarPosition::Left => (
+                Rect {
+                    width: bar_width,
+                    ..inner
+                },
+                Rect {
+                    x: inner.x + bar_width,
+                    width: inner.width - bar_width,
+                    ..inner
+                },
+            ),
+            TabBarPosition::Right => {
+                let content_width = inner.width - bar_width;
+                (
+                    Rect {
+                        x: inner.x + content_width,
+                        width: bar_width,
+                        ..inner
+                    },
+                    Rect {
+                        width: content_width,
+                        ..inner
+                    },
+                )
             }
+        };
 
-            curr_x += tab_width + divider_width;
+        let mut separator = None;
+        if self.show_tab_separator {
+            match self.tab_bar_position {
+                TabBarPosition::Top | TabBarPosition::Bottom if content.height > 0 => {
+                    let y = if self.tab_bar_position == TabBarPosition::Top {
+                        content.y
+                    } else {
+                        content.bottom() - 1
+                    };
+                    separator = Some(Rect {
+                        y,
+                        height: 1,
+                        ..content
+                    });
+                    if self.tab_bar_position == TabBarPosition::Top {
+                        content.y += 1;
+                    }
+                    content.height -= 1;
+                }
+                TabBarPosition::Left | TabBarPosition::Right if content.width > 0 => {
+                    let x = if self.tab_bar_position == TabBarPosition::Left {
+                        content.x
+                    } else {
+                        content.right() - 1
+                    };
+                    separator = Some(Rect {
+                        x,
+                        width: 1,
+                        ..content
+                    });
+                    if self.tab_bar_position == TabBarPosition::Left {
+                        content.x += 1;
+                    }
+                    content.width -= 1;
+                }
+                _ => {}
+            }
         }
+        (tab_bar, separator, content)
+    }
 
-        None
+    fn tab_bar_area(&self, window_rect: Rect) -> Rect {
+        self.split_inner(self.inner_area(window_rect)).0
     }
 
-    /// Compute the tab bar and content areas for a given outer window rect
-    fn tab_bar_rect(&self, outer: Rect) -> Rect {
-        // Match render() logic for border handling
-        let inner_area = if self.show_border {
-            let mut block = Block::default();
-            let border_type = match self
-                .border_style
-                .as_deref()
-                .unwrap_or("single")
-                .to_lowercase()
-                .as_str()
-            {
-                "double" => BorderType::Double,
-                "rounded" => BorderType::Rounded,
-                "thick" => BorderType::Thick,
-                "single" => BorderType::Plain, // closest available thin border
-                _ => BorderType::Plain,
-            };
-            let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
-            block = block.borders(borders).border_type(border_type);
-            block.inner(outer)
+    /// The label a tab shows in a horizontal bar
+    fn tab_label(&self, idx: usize) -> String {
+        let tab = &self.tabs[idx];
+        if idx != self.active_tab_index && tab.has_unread && !tab.ignore_activity {
+            format!("{}{}", self.tab_unread_prefix, tab.name)
         } else {
-            outer
-        };
+            tab.name.clone()
+        }
+    }
+
+    /// Lay out the tabs from `scroll` on: one per row in a vertical bar,
+    /// full labels with dividers in a horizontal one. Cells for the ‹ ›
+    /// indicators are taken first when tabs are hidden on that side.
+    fn tab_bar_layout(&self, bar: Rect, scroll: usize) -> TabBarLayout {
+        let mut layout = TabBarLayout::default();
+        if bar.width == 0 || bar.height == 0 || self.tabs.is_empty() {
+            return layout;
+        }
+        let scroll = scroll.min(self.tabs.len() - 1);
+
+        if self.tab_bar_position.is_vertical() {
+            let mut top = bar.y;
+            let mut bottom = bar.bottom();
+            if scroll > 0 {
+                layout.before = Some(Rect { height: 1, ..bar });
+                top += 1;
+            }
+            if self.tabs.len() - scroll > (bottom - top) as usize && bottom > top {
+                bottom -= 1;
+                layout.after = Some(Rect {
+                    y: bottom,
+                    height: 1,
+                    ..bar
+                });
+            }
+            for (row, idx) in (scroll..self.tabs.len())
+                .take((bottom - top) as usize)
+                .enumerate()
+            {
+                layout.slots.push((
+                    idx,
+                    Rect {
+                        y: top + row as u16,
+                        height: 1,
+                        ..bar
+                    },
+                ));
+            }
+            return layout;
+        }
+
+        let divider = DIVIDER.len();
+        let widths: Vec<usize> = (scroll..self.tabs.len())
+            .map(|idx| text_width::width(&self.tab_label(idx)))
+            .collect();
+        let total = widths.iter().sum::<usize>() + divider * (widths.len() - 1);
+        let mut left = bar.x;
+        let mut right = bar.right();
+        if scroll > 0 {
+            layout.before = Some(Rect { width: 1, ..bar });
+            left += 1;
+        }
+        if total > (right - left) as usize && right > left {
+            right -= 1;
+            layout.after = Some(Rect {
+                x: right,
+                width: 1,
+                ..bar
+            });
+        }
+        let mut x = left as usize;
+        for (offset, width) in widths.into_iter().enumerate() {
+            let room = (right as usize).saturating_sub(x);
+            // A label too long for the whole bar is cut short rather than
+            // leaving the bar empty
+            if room == 0 || (width > room && !layout.slots.is_empty()) {
+                break;
+            }
+            let width = width.min(room);
+            layout.slots.push((
+                scroll + offset,
+                Rect {
+                    x: x as u16,
+                    width: width as u16,
+                    ..bar
+                },
+            ));
+            x += width + divider;
+        }
+        layout
+    }
+
+    /// Furthest the bar can scroll: the first position where every
+    /// remaining tab fits
+    fn max_tab_scroll(&self, bar: Rect) -> usize {
+        (0..self.tabs.len())
+            .find(|&scroll| self.tab_bar_layout(bar, scroll).after.is_none())
+            .unwrap_or(self.tabs.len().saturating_sub(1))
+    }
 
-        match self.tab_bar_position {
-            TabBarPosition::Top => Rect {
-                x: inner_area.x,
-                y: inner_area.y,
-                width: inner_area.width,
-                height: 1,
-            },
-            TabBarPosition::Bottom => Rect {
-                x: inner_area.x,
-                y: inner_area.y + inner_area.height.saturating_sub(1),
-                width: inner_area.width,
-                height: 1,
-            },
+    /// Keep the scroll in range, and bring the active tab into view when it
+    /// changes
+    fn settle_tab_scroll(&mut self, bar: Rect) {
+        if self.tabs.is_empty() {
+            return;
+        }
+        self.tab_scroll = self.tab_scroll.min(self.max_tab_scroll(bar));
+        let active = self.active_tab_index;
+        if self.revealed_tab == Some(active) {
+            return;
+        }
+        self.revealed_tab = Some(active);
+        self.tab_scroll = self.tab_scroll.min(active);
+        while self.tab_scroll < active
+            && !self
+                .tab_bar_layout(bar, self.tab_scroll)
+                .slots
+                .iter()
+                .any(|(idx, _)| *idx == active)
+        {
+            self.tab_scroll += 1;
         }
     }
 
@@ -668,13 +946,7 @@ impl TabbedTextWindow {
             return;
         }
 
-        let border_type = match self.border_style.as_deref() {
-            Some("double") => BorderType::Double,
-            Some("rounded") => BorderType::Rounded,
-            Some("thick") => BorderType::Thick,
-            Some("single") => BorderType::Plain, // closest available thin border
-            _ => BorderType::Plain,
-        };
+        let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
 
         let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
 
@@ -709,73 +981,13 @@ impl TabbedTextWindow {
             self.show_border,
             borders,
             &self.border_sides,
-            border_type,
+            border_set,
             border_style,
             &self.title,
             self.title_position,
         );
 
-        // Split inner area for tab bar and content
-        let (tab_bar_area, mut content_area) = match self.tab_bar_position {
-            TabBarPosition::Top => {
-                let tab_bar = Rect {
-                    x: inner_area.x,
-                    y: inner_area.y,
-                    width: inner_area.width,
-                    height: 1,
-                };
-                let content = Rect {
-                    x: inner_area.x,
-                    y: inner_area.y + 1,
-                    width: inner_area.width,
-                    height: inner_area.height.saturating_sub(1),
-                };
-                (tab_bar, content)
-            }
-            TabBarPosition::Bottom => {
-                let content = Rect {
-                    x: inner_area.x,
-                    y: inner_area.y,
-                    width: inner_area.width,
-                    height: inner_area.height.saturating_sub(1),
-                };
-                let tab_bar = Rect {
-                    x: inner_area.x,
-                    y: inner_area.y + content.height,
-                    width: inner_area.width,
-                    height: 1,
-                };
-                (tab_bar, content)
-            }
-        };
-
-        // Optional separator between tab bar and content
-        let mut separator_area: Option<Rect> = None;
-        if self.show_tab_separator && content_area.height > 0 {
-            match self.tab_bar_position {
-                TabBarPosition::Top => {
-                    separator_area = Some(Rect {
-                        x: content_area.x,
-                        y: content_area.y,
-                        width: content_area.width,
-                        height: 1,
-                    });
-                    content_area.y = content_area.y.saturating_add(1);
-                    content_area.height = content_area.height.saturating_sub(1);
-                }
-                TabBarPosition::Bottom => {
-                    separator_area = Some(Rect {
-                        x: content_area.x,
-                        y: content_area
-                            .y
-                            .saturating_add(content_area.height.saturating_sub(1)),
-                        width: content_area.width,
-                        height: 1,
-                    });
-                    content_area.height = content_area.height.saturating_sub(1);
-                }
-            }
-        }
+        let (tab_bar_area, separator_area, content_area) = self.split_inner(inner_area);
 
         // Paint background for tab bar and content (use theme fallback when needed)
         let bg_color = if self.transparent_background {
@@ -786,44 +998,30 @@ impl TabbedTextWindow {
             Some(crossterm_bridge::to_ratatui_color(theme.window_background))
         };
         if let Some(bg) = bg_color {
-            // Tab bar row
-            for dx in 0..tab_bar_area.width {
-                let x = tab_bar_area.x + dx;
-                let y = tab_bar_area.y;
-                if x < buf.area().width && y < buf.area().height {
-                    buf[(x, y)].set_bg(bg);
-                }
-            }
-
-            // Content area
-            for dx in 0..content_area.width {
-                for dy in 0..content_area.height {
-                    let x = content_area.x + dx;
-                    let y = content_area.y + dy;
-                    if x < buf.area().width && y < buf.area().height {
-                        buf[(x, y)].set_bg(bg);
-                    }
-                }
-            }
-
-            if let Some(sep) = separator_area {
-                for dx in 0..sep.width {
-                    let x = sep.x + dx;
-                    let y = sep.y;
-                    if x < buf.area().width && y < buf.area().height {
-                        buf[(x, y)].set_bg(bg);
+            for rect in [Some(tab_bar_area), Some(content_area), separator_area]
+                .into_iter()
+                .flatten()
+            {
+                for dx in 0..rect.width {
+                    for dy in 0..rect.height {
+                        let x = rect.x + dx;
+                        let y = rect.y + dy;
+                        if x < buf.area().width && y < buf.area().height {
+                            buf[(x, y)].set_bg(bg);
+                        }
                     }
                 }
             }
         }
 
         // Render tab bar
+        self.settle_tab_scroll(tab_bar_area);
         self.render_tab_bar(tab_bar_area, buf);
 
         // Render separator line if enabled and space remains
         // Include scroll indicator [N] if the active tab is scrolled
         if let Some(sep) = separator_area {
-            if sep.width > 0 && sep.y < buf.area().height {
+            if sep.width > 0 && sep.height > 0 {
                 let sep_color = self
                     .border_color
                     .as_ref()
@@ -834,32 +1032,29 @@ impl TabbedTextWindow {
                             .map(|c| Self::parse_color(c))
                     })
                     .unwrap_or(Color::DarkGray);
-
-                // Check if active tab has scroll indicator
-                let scroll_indicator = self
-                    .tabs
-                    .get(self.active_tab_index)
-                    .and_then(|tab| tab.window.get_scroll_indicator())
-                    .map(|n| format!("[{}]", n));
+                let sep_style = Style::default().fg(sep_color);
+                let vertical = self.tab_bar_position.is_vertical();
+                let sep_char = if vertical { '|' } else { '-' };
 
                 // Draw separator line
                 for dx in 0..sep.width {
-                    let x = sep.x + dx;
-                    if x < buf.area().width {
-                        buf[(x, sep.y)]
-                            .set_char('-')
-                            .set_style(Style::default().fg(sep_color));
+                    for dy in 0..sep.height {
+                        let (x, y) = (sep.x + dx, sep.y + dy);
+                        if x < buf.area().width && y < buf.area().height {
+                            buf[(x, y)].set_char(sep_char).set_style(sep_style);
+                        }
                     }
                 }
 
-                // Draw scroll indicator on left side of separator if scrolled
-                if let Some(indicator) = scroll_indicator {
-                    for (i, ch) in indicator.chars().enumerate() {
-                        let x = sep.x + i as u16;
-                        if x < sep.x + sep.width && x < buf.area().width {
-                            buf[(x, sep.y)]
-                                .set_char(ch)
-                                .set_style(Style::default().fg(sep_color));
+                // Draw scroll indicator on left side of a horizontal separator
+                // (a vertical bar shows it under the tab labels instead)
+                if !vertical {
+                    if let Some(indicator) = self.scroll_indicator() {
+                        for (i, ch) in indicator.chars().enumerate() {
+                            let x = sep.x + i as u16;
+                            if x < sep.right() && x < buf.area().width {
+                                buf[(x, sep.y)].set_char(ch).set_style(sep_style);
+                            }
                         }
                     }
                 }
@@ -885,6 +1080,27 @@ impl TabbedTextWindow {
         self.render_with_focus(area, buf, false, None, "#4a4a4a", 0, &theme);
     }
 
+    /// "[N]" while the active tab is scrolled back
+    fn scroll_indicator(&self) -> Option<String> {
+        self.tabs
+            .get(self.active_tab_index)
+            .and_then(|tab| tab.window.get_scroll_indicator())
+            .map(|n| format!("[{}]", n))
+    }
+
+    /// Cut a label to `width` columns, ending in an ellipsis when shortened
+    fn truncate_label(text: &str, width: u16) -> String {
+        if text_width::width(text) as u16 <= width {
+            return text.to_string();
+        }
+        match width {
+            0 => String::new(),
+            1 => "…".to_string(),
+            2 => "…".repeat(2),
+            _ => text_width::truncate_ellipsis(text, width as usize),
+        }
+    }
+
     fn render_tab_bar(&self, area: Rect, buf: &mut Buffer) {
         let active_color = self
             .tab_active_color
@@ -902,89 +1118,142 @@ impl TabbedTextWindow {
             .map(|c| Self::parse_color(c))
             .unwrap_or(Color::White);
 
-        let mut x = area.x;
-        let divider = " | ";
-
-        for (idx, tab) in self.tabs.iter().enumerate() {
-            if x >= area.right() {
-                break;
-            }
-
-            // Determine tab text and style
-            let ignore_activity = tab.ignore_activity;
-            let (raw_text, style) = if idx == self.active_tab_index {
-                (
-                    tab.name.clone(),
-                    Style::default()
-                        .fg(active_color)
-                        .add_modifier(Modifier::BOLD),
-                )
-            } else if tab.has_unread && !ignore_activity {
-                (
-                    format!("{}{}", self.tab_unread_prefix, tab.name),
-                    Style::default().fg(unread_color),
-                )
+        // Alerting labels flip to reversed once a second (the runtime
+        // already redraws that often for countdowns)
+        let alert_on = !self.alert_blink
+            || std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs() % 2 == 0)
+                .unwrap_or(true);
+
+        let tab_style = |idx: usize, tab: &TabInfo| {
+            let style = if idx == self.active_tab_index {
+                Style::default()
+                    .fg(active_color)
+                    .add_modifier(Modifier::BOLD)
+            } else if tab.has_unread && !tab.ignore_activity {
+                Style::default().fg(unread_color)
             } else {
-                (tab.name.clone(), Style::default().fg(inactive_color))
+                Style::default().fg(inactive_color)
             };
+            if tab.alerting && idx != self.active_tab_index {
+                let style = style.fg(unread_color);
+                if alert_on {
+                    style.add_modifier(Modifier::REVERSED)
+                } else {
+                    style
+                }
+            } else {
+                style
+            }
+        };
 
-            // Compute available width for this tab (leave space for divider if needed)
-            let remaining = area.right().saturating_sub(x);
-            if remaining == 0 {
-                break;
+        let layout = self.tab_bar_layout(area, self.tab_scroll);
+        let vertical = self.tab_bar_position.is_vertical();
+        let divider_style = Style::default().fg(inactive_color);
+
+        for (n, (idx, slot)) in layout.slots.iter().enumerate() {
+            let tab = &self.tabs[*idx];
+            let style = tab_style(*idx, tab);
+            if vertical {
+                self.render_vertical_tab(*idx, *slot, buf, style);
+                continue;
+            }
+            let label = Self::truncate_label(&self.tab_label(*idx), slot.width);
+            buf.set_stringn(slot.x, slot.y, &label, slot.width as usize, style);
+            // Divider before the next visible tab
+            if let Some((_, next)) = layout.slots.get(n + 1) {
+                let width = next.x.saturating_sub(slot.right()) as usize;
+                buf.set_stringn(slot.right(), slot.y, DIVIDER, width, divider_style);
             }
-            let max_label_width = if idx < self.tabs.len() - 1 && remaining > divider.len() as u16 {
-                remaining.saturating_sub(divider.len() as u16)
+        }
+
+        // ‹ › stand in for hidden tabs, lit up when one of them has news
+        let first = layout.slots.first().map_or(0, |(idx, _)| *idx);
+        let last = layout.slots.last().map_or(0, |(idx, _)| *idx);
+        for (cell, hidden, arrow) in [
+            (layout.before, 0..first, '‹'),
+            (layout.after, last + 1..self.tabs.len(), '›'),
+        ] {
+            let Some(cell) = cell else {
+                continue;
+            };
+            let unread = self.tabs[hidden.clone()]
+                .iter()
+                .any(|tab| tab.has_unread && !tab.ignore_activity);
+            let style = if unread {
+                Style::default()
+                    .fg(unread_color)
+                    .add_modifier(Modifier::BOLD)
             } else {
-                remaining
+                divider_style
             };
-
-            // Truncate with ellipsis if needed
-            let tab_text = if raw_text.chars().count() as u16 > max_label_width {
-                if max_label_width == 0 {
-                    String::new()
-                } else if max_label_width == 1 {
-                    "…".to_string()
-                } else if max_label_width == 2 {
-                    "…".repeat(2)
-                } else {
-                    let take_len = max_label_width.saturating_sub(1) as usize;
-                    let mut truncated = String::new();
-                    for ch in raw_text.chars().take(take_len) {
-                        truncated.push(ch);
-                    }
-                    truncated.push('…');
-                    truncated
-                }
+            let text = if vertical {
+                format!("{} {} more", arrow, hidden.len())
             } else {
-                raw_text
+                arrow.to_string()
             };
+            buf.set_stringn(cell.x, cell.y, &text, cell.width as usize, style);
+        }
 
-            // Render tab text
-            for ch in tab_text.chars() {
-                if x >= area.right() {
-                    break;
-                }
-                buf[(x, area.y)].set_char(ch).set_style(style);
-                x += 1;
+        // A vertical bar with a spare row shows the scroll indicator there
+        if vertical && layout.after.is_none() {
+            let y = layout
+                .slots
+                .last()
+                .map_or(area.y, |(_, slot)| slot.bottom());
+            if let Some(indicator) = self.scroll_indicator().filter(|_| y < area.bottom()) {
+                buf.set_stringn(area.x, y, &indicator, area.width as usize, divider_style);
             }
+        }
+    }
 
-            // Render divider if not last tab
-            if idx < self.tabs.len() - 1 && x < area.right() {
-                for ch in divider.chars() {
-                    if x >= area.right() {
-                        break;
-                    }
-                    buf[(x, area.y)]
-                        .set_char(ch)
-                        .set_style(Style::default().fg(inactive_color));
-                    x += 1;
-                }
+    /// One row of a vertical bar: the name, and the unread count
+    /// right-aligned as a badge
+    fn render_vertical_tab(&self, idx: usize, slot: Rect, buf: &mut Buffer, style: Style) {
+        let tab = &self.tabs[idx];
+        let badge = if idx != self.active_tab_index
+            && tab.has_unread
+            && !tab.ignore_activity
+            && tab.unread_count > 0
+        {
+            if tab.unread_count > 99 {
+                "99+".to_string()
+            } else {
+                tab.unread_count.to_string()
             }
+        } else {
+            String::new()
+        };
+        let badge_width = text_width::width(&badge) as u16;
+        let label_width = if badge.is_empty() || slot.width <= badge_width + 1 {
+            slot.width
+        } else {
+            slot.width - badge_width - 1
+        };
+        let label = Self::truncate_label(&tab.name, label_width);
+        buf.set_stringn(slot.x, slot.y, &label, label_width as usize, style);
+        if label_width < slot.width {
+            buf.set_string(slot.right() - badge_width, slot.y, &badge, style);
         }
     }
 }
 
+/// Visible tabs and overflow indicators for one render of the tab bar
+#[derive(Default)]
+struct TabBarLayout {
+    /// (tab index, label area)
+    slots: Vec<(usize, Rect)>,
+    /// ‹ cell, when tabs are scrolled off before the first visible one
+    before: Option<Rect>,
+    /// › cell, when tabs don't fit after the last visible one
+    after: Option<Rect>,
+}
+
+fn rect_contains(rect: Rect, col: u16, row: u16) -> bool {
+    col >= rect.x && col < rect.right() && row >= rect.y && row < rect.bottom()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1037,6 +1306,76 @@ mod tests {
         assert_eq!(window.get_active_tab_index(), 1);
     }
 
+    #[test]
+    fn test_vertical_tab_bar_badges_and_clicks() {
+        let mut window = make_window();
+        window.set_tab_bar_position(TabBarPosition::from_str("left"));
+        window.set_tab_bar_width(8);
+        window.set_tab_separator(true);
+        window.mark_tab_unread(1, 12);
+
+        let rect = Rect::new(0, 0, 30, 6);
+        let mut buf = Buffer::empty(rect);
+        window.render(rect, &mut buf);
+        let row = |buf: &Buffer, y: u16| -> String {
+            (1..10).map(|x| buf[(x, y)].symbol().to_string()).collect()
+        };
+        // Fixed 8-column labels inside the border, then the separator
+        assert_eq!(row(&buf, 1), "Main    |");
+        assert_eq!(row(&buf, 2), "Thou… 12|");
+        assert_eq!(window.content_rect(rect), Rect::new(10, 1, 19, 4));
+
+        // Second row is the second tab; clicks in the content do nothing
+        assert!(!window.handle_mouse_click(rect, 12, 2));
+        assert!(window.handle_mouse_click(rect, 3, 2));
+        assert_eq!(window.get_active_tab_index(), 1);
+    }
+
+    #[test]
+    fn test_tab_overflow_scrolls_behind_indicators() {
+        let mut window = make_window();
+        for name in ["Speech", "Whispers", "Combat"] {
+            window.add_tab(name.to_string(), 100, false, false);
+        }
+        window.mark_tab_unread(4, 2);
+        let rect = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(rect);
+        let bar = |buf: &Buffer| -> String {
+            (1..19).map(|x| buf[(x, 1)].symbol().to_string()).collect()
+        };
+
+        buf.reset();
+        window.render(rect, &mut buf);
+        assert_eq!(bar(&buf), "Main | Thoughts  ›");
+        assert!(window.overflow_indicator_at(rect, 18, 1));
+        let hidden: Vec<usize> = window.hidden_tabs(rect).iter().map(|t| t.0).collect();
+        assert_eq!(hidden, [2, 3, 4]);
+        assert_eq!(window.hidden_tabs(rect)[2].2, 2);
+
+        // The wheel scrolls the bar, not past the point where the rest fits
+        assert!(window.scroll_tab_bar(rect, 5, 1, 1));
+        assert!(!window.scroll_tab_bar(rect, 5, 2, 1));
+        buf.reset();
+        window.render(rect, &mut buf);
+        assert!(bar(&buf).starts_with("‹Thoughts"));
+        assert!(window.handle_mouse_click(rect, 3, 1));
+        assert_eq!(window.get_active_tab_index(), 1);
+        buf.reset();
+        window.render(rect, &mut buf);
+        assert!(window.scroll_tab_bar(rect, 5, 1, 10));
+        buf.reset();
+        window.render(rect, &mut buf);
+        assert_eq!(bar(&buf), format!("‹* Combat{}", " ".repeat(9)));
+
+        // A newly active tab is brought into view
+        window.switch_to_tab(4);
+        buf.reset();
+        window.render(rect, &mut buf);
+        let hidden: Vec<usize> = window.hidden_tabs(rect).iter().map(|t| t.0).collect();
+        assert_eq!(hidden, [0, 1, 2]);
+        assert_eq!(bar(&buf), "‹Whispers | Combat");
+    }
+
     #[test]
     fn test_remove_tab_disallows_last() {
         let mut window = TabbedTextWindow::new("Tabs", TabBarPosition::Top);
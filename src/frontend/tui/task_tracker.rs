@@ -0,0 +1,260 @@
+//! Task tracker widget.
+//!
+//! Structured view of the bounty: the task title colored by phase, the
+//! target/area detail lines, and a "6/12 culled" progress bar for counted
+//! tasks. Falls back to the compact (or raw) bounty text when the task
+//! didn't parse.
+//!
+//! Reads data from GameState.bounty (see core::bounty_parser).
+
+use crate::config::BorderSides;
+use crate::core::bounty_parser::BountyPhase;
+use crate::core::state::BountyState;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget, Wrap},
+};
+
+/// Task tracker widget - bounty title, details and progress
+pub struct TaskTracker {
+    title: String,
+    show_progress: bool,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached state for rendering
+    bounty: BountyState,
+    border_color: Color,
+    text_color: Color,
+    bar_color: Color,
+    background_color: Option<Color>,
+}
+
+impl TaskTracker {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_progress: true,
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            bounty: BountyState::default(),
+            border_color: Color::White,
+            text_color: Color::White,
+            bar_color: Color::Rgb(0x2e, 0x6b, 0x3a),
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_progress(&mut self, show: bool) {
+        self.show_progress = show;
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Update from game state. Returns true if the display changed.
+    pub fn update_from_state(&mut self, bounty: &BountyState) -> bool {
+        if self.bounty.generation == bounty.generation {
+            return false;
+        }
+        self.bounty = bounty.clone();
+        true
+    }
+
+    fn phase_color(&self, phase: BountyPhase) -> Color {
+        match phase {
+            BountyPhase::Complete => Color::Green,
+            BountyPhase::Failed => Color::Red,
+            BountyPhase::Visit => Color::Yellow,
+            BountyPhase::None => Color::DarkGray,
+            BountyPhase::Active => self.text_color,
+        }
+    }
+
+    fn render_progress(&self, done: u32, total: u32, unit: &str, area: Rect, buf: &mut Buffer) {
+        let width = area.width as usize;
+        let filled = (width * done.min(total) as usize)
+            .checked_div(total as usize)
+            .unwrap_or(0);
+        let text = format!("{}/{} {}", done, total, unit);
+        let text: Vec<char> = text.trim_end().chars().take(width).collect();
+        let text_start = (width - text.len()) / 2;
+
+        for col in 0..width {
+            let Some(cell) = buf.cell_mut((area.x + col as u16, area.y)) else {
+                continue;
+            };
+            let ch = col
+                .checked_sub(text_start)
+                .and_then(|i| text.get(i))
+                .copied()
+                .unwrap_or(' ');
+            cell.set_char(ch);
+            cell.set_fg(self.text_color);
+            if col < filled {
+                cell.set_bg(self.bar_color);
+            } else if let Some(bg) = self.background_color {
+                cell.set_bg(bg);
+            }
+        }
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let Some(task) = &self.bounty.task else {
+            // Unrecognized bounty text: show what the compact parser kept
+            let text = if self.bounty.has_data() {
+                self.bounty.compact_lines.join(" ")
+            } else {
+                "(No bounty - type BOUNTY)".to_string()
+            };
+            Paragraph::new(Line::from(Span::styled(
+                text,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .wrap(Wrap { trim: true })
+            .render(inner, buf);
+            return;
+        };
+
+        let progress = self
+            .bounty
+            .progress()
+            .filter(|_| self.show_progress && !task.unit.is_empty());
+        let text_rows = inner.height.saturating_sub(progress.is_some() as u16);
+
+        let mut lines = Vec::with_capacity(task.lines.len());
+        if let Some(first) = task.lines.first() {
+            lines.push(Line::from(Span::styled(
+                first.clone(),
+                Style::default()
+                    .fg(self.phase_color(task.phase))
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        for line in task.lines.iter().skip(1) {
+            lines.push(Line::from(Span::styled(
+                line.clone(),
+                Style::default().fg(self.text_color),
+            )));
+        }
+        let shown = (lines.len() as u16).min(text_rows);
+        Paragraph::new(lines).render(
+            Rect {
+                height: shown,
+                ..inner
+            },
+            buf,
+        );
+
+        if let Some((done, total)) = progress {
+            let y = inner.y + shown;
+            if y < inner.bottom() {
+                self.render_progress(
+                    done,
+                    total,
+                    task.unit,
+                    Rect {
+                        y,
+                        height: 1,
+                        ..inner
+                    },
+                    buf,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_task_and_progress() {
+        let mut bounty = BountyState::default();
+        bounty.update(
+            "You have been tasked to suppress triton fanatic activity in the Atoll near Kraken's Fall. You need to kill 12 of them to complete your task.".to_string(),
+            Vec::new(),
+        );
+        bounty.update(
+            "You have been tasked to suppress triton fanatic activity in the Atoll near Kraken's Fall. You need to kill 6 more of them to complete your task.".to_string(),
+            Vec::new(),
+        );
+        let mut widget = TaskTracker::new("Bounty");
+        widget.set_show_border(false);
+        assert!(widget.update_from_state(&bounty));
+        assert!(!widget.update_from_state(&bounty));
+
+        let area = Rect::new(0, 0, 24, 4);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..24).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).starts_with("Culling Task"));
+        assert!(row(1).starts_with("6 triton fanatic"));
+        assert!(row(3).contains("6/12 culled"));
+    }
+}
@@ -51,9 +51,23 @@ impl TerminalTitleManager {
 
     /// Render the template with current game state values.
     fn render_template(&self, app_core: &AppCore) -> String {
+        // Boss key: look like any other shell tab
+        if app_core.ui_state.boss_mode {
+            return std::env::var("SHELL")
+                .ok()
+                .and_then(|shell| shell.rsplit(['/', '\\']).next().map(str::to_string))
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| "bash".to_string());
+        }
+
         let game_state = &app_core.game_state;
 
-        let character = game_state.character_name.as_deref().unwrap_or("");
+        let character = match game_state.character_name.as_deref() {
+            Some(_) if app_core.config.streamer.enabled => {
+                app_core.config.streamer.character_alias.as_str()
+            }
+            name => name.unwrap_or(""),
+        };
 
         let room = game_state.room_name.as_deref().unwrap_or("");
 
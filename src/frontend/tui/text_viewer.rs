@@ -0,0 +1,373 @@
+//! Modal popup for reading long game output (shop lists, policy text, ...).
+//!
+//! Opened with `.view last` (or the "view" link appended after long output
+//! blocks). Holds its own snapshot of the lines so the main window can keep
+//! scrolling underneath, and supports `/` search with `n`/`N` to jump
+//! between matches. `.viewlog` opens it over a log file instead, read a page
+//! at a time through `LogPager`; `h` toggles highlights there.
+
+use super::colors::parse_color_to_ratatui;
+use super::crossterm_bridge;
+use crate::core::log_viewer::LogPager;
+use crate::data::input::{KeyCode, KeyModifiers};
+use crate::data::StyledLine;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// What the viewer shows
+enum Content {
+    /// Snapshot of window lines, with their lowercased plain text (the
+    /// search haystack)
+    Lines {
+        lines: Vec<StyledLine>,
+        plain: Vec<String>,
+    },
+    /// A log file, read on demand
+    Log(LogPager),
+}
+
+/// Scrollable read-only viewer over a snapshot of styled lines or a log
+pub struct TextViewer {
+    title: String,
+    content: Content,
+    /// Index of the first visible line
+    scroll: usize,
+    /// Visible body height from the last render (for paging)
+    page_height: usize,
+    /// Some while the user is typing a search query
+    search_input: Option<String>,
+    /// Last confirmed search query
+    query: String,
+    /// Line indices matching `query`
+    matches: Vec<usize>,
+    current_match: usize,
+}
+
+impl TextViewer {
+    pub fn new(title: String, lines: Vec<StyledLine>) -> Self {
+        let plain = lines
+            .iter()
+            .map(|line| {
+                line.segments
+                    .iter()
+                    .map(|seg| seg.text.as_str())
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .collect();
+        Self::with_content(title, Content::Lines { lines, plain })
+    }
+
+    /// Viewer over a log file (`.viewlog`)
+    pub fn for_log(title: String, pager: LogPager) -> Self {
+        Self::with_content(title, Content::Log(pager))
+    }
+
+    fn with_content(title: String, content: Content) -> Self {
+        Self {
+            title,
+            content,
+            scroll: 0,
+            page_height: 20,
+            search_input: None,
+            query: String::new(),
+            matches: Vec::new(),
+            current_match: 0,
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        match &self.content {
+            Content::Lines { lines, .. } => lines.len(),
+            Content::Log(pager) => pager.len(),
+        }
+    }
+
+    fn line(&mut self, index: usize) -> Option<StyledLine> {
+        match &mut self.content {
+            Content::Lines { lines, .. } => lines.get(index).cloned(),
+            Content::Log(pager) => pager.line(index),
+        }
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.line_count().saturating_sub(self.page_height.max(1))
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.scroll = self.scroll.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: usize) {
+        self.scroll = (self.scroll + amount).min(self.max_scroll());
+    }
+
+    /// Open at the end; render clamps to the last page once it knows the height
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.line_count();
+    }
+
+    /// Run a case-insensitive search and jump to the first match
+    pub fn search(&mut self, query: &str) {
+        self.query = query.to_string();
+        let needle = query.to_lowercase();
+        self.matches = match &mut self.content {
+            _ if needle.is_empty() => Vec::new(),
+            Content::Lines { plain, .. } => plain
+                .iter()
+                .enumerate()
+                .filter(|(_, text)| text.contains(&needle))
+                .map(|(idx, _)| idx)
+                .collect(),
+            Content::Log(pager) => pager.search(&needle),
+        };
+        self.current_match = 0;
+        self.jump_to_current_match();
+    }
+
+    pub fn next_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current_match = (self.current_match + 1) % self.matches.len();
+            self.jump_to_current_match();
+        }
+    }
+
+    pub fn prev_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current_match = (self.current_match + self.matches.len() - 1) % self.matches.len();
+            self.jump_to_current_match();
+        }
+    }
+
+    fn jump_to_current_match(&mut self) {
+        if let Some(&line) = self.matches.get(self.current_match) {
+            // Keep a little context above the match
+            self.scroll = line.saturating_sub(2).min(self.max_scroll());
+        }
+    }
+
+    /// Handle a key press. Returns true when the viewer should close.
+    pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        if let Some(ref mut input) = self.search_input {
+            match code {
+                KeyCode::Esc => self.search_input = None,
+                KeyCode::Enter => {
+                    let query = std::mem::take(input);
+                    self.search_input = None;
+                    self.search(&query);
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) if !modifiers.ctrl && !modifiers.alt => input.push(c),
+                _ => {}
+            }
+            return false;
+        }
+
+        match code {
+            KeyCode::Esc | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_down(1),
+            KeyCode::PageUp => self.scroll_up(self.page_height.max(1)),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_down(self.page_height.max(1)),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::End | KeyCode::Char('G') => self.scroll = self.max_scroll(),
+            KeyCode::Char('/') => self.search_input = Some(String::new()),
+            KeyCode::Char('f') if modifiers.ctrl => self.search_input = Some(String::new()),
+            KeyCode::Char('n') => self.next_match(),
+            KeyCode::Char('N') => self.prev_match(),
+            KeyCode::Char('h') => {
+                if let Content::Log(pager) = &mut self.content {
+                    pager.toggle_highlights();
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn segment_style(seg: &crate::data::TextSegment) -> Style {
+        let mut style = Style::default();
+        if let Some(color) = seg.fg.as_deref().and_then(parse_color_to_ratatui) {
+            style = style.fg(color);
+        }
+        if let Some(color) = seg.bg.as_deref().and_then(parse_color_to_ratatui) {
+            style = style.bg(color);
+        }
+        if seg.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        style
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer, theme: &crate::theme::AppTheme) {
+        let width = area.width.saturating_sub(8).max(20).min(area.width);
+        let height = area.height.saturating_sub(4).max(6).min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(popup_area, buf);
+
+        let background = crossterm_bridge::to_ratatui_color(theme.browser_background);
+        let title = format!(" {} ({} lines) ", self.title, self.line_count());
+        let block = Block::default()
+            .title(Span::styled(
+                title,
+                Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_title)),
+            ))
+            .borders(Borders::ALL)
+            .border_set(super::border_glyphs::themed(
+                ratatui::symbols::border::DOUBLE,
+            ))
+            .border_style(
+                Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_border)),
+            )
+            .style(Style::default().bg(background));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 2 {
+            return;
+        }
+
+        // Body leaves the last row for the status/search line
+        let body = Rect {
+            height: inner.height - 1,
+            ..inner
+        };
+        self.page_height = body.height as usize;
+        self.scroll = self.scroll.min(self.max_scroll());
+
+        let highlight_line = self.matches.get(self.current_match).copied();
+        let match_style = Style::default().add_modifier(Modifier::REVERSED);
+        let shown: Vec<(usize, StyledLine)> = (self.scroll..self.scroll + body.height as usize)
+            .map_while(|idx| self.line(idx).map(|line| (idx, line)))
+            .collect();
+        let visible: Vec<Line> = shown
+            .iter()
+            .map(|(idx, line)| {
+                let spans: Vec<Span> = line
+                    .segments
+                    .iter()
+                    .map(|seg| Span::styled(seg.text.clone(), Self::segment_style(seg)))
+                    .collect();
+                let rendered = Line::from(spans);
+                if Some(*idx) == highlight_line {
+                    rendered.patch_style(match_style)
+                } else {
+                    rendered
+                }
+            })
+            .collect();
+        Paragraph::new(visible)
+            .wrap(Wrap { trim: false })
+            .render(body, buf);
+
+        let status = if let Some(ref input) = self.search_input {
+            format!("/{}_", input)
+        } else if !self.query.is_empty() {
+            if self.matches.is_empty() {
+                format!("No matches for '{}'  |  / search  Esc close", self.query)
+            } else {
+                format!(
+                    "Match {}/{} for '{}'  |  n/N next/prev  / search  Esc close",
+                    self.current_match + 1,
+                    self.matches.len(),
+                    self.query
+                )
+            }
+        } else {
+            let total = self.line_count();
+            let last = (self.scroll + body.height as usize).min(total);
+            let highlights = match &self.content {
+                Content::Log(pager) if pager.highlighting() => "  h highlights off",
+                Content::Log(_) => "  h highlights on",
+                Content::Lines { .. } => "",
+            };
+            format!(
+                "Lines {}-{} of {}  |  arrows/PgUp/PgDn scroll  / search{}  Esc close",
+                (self.scroll + 1).min(last),
+                last,
+                total,
+                highlights
+            )
+        };
+        let status_area = Rect {
+            y: inner.y + inner.height - 1,
+            height: 1,
+            ..inner
+        };
+        Paragraph::new(Span::styled(
+            status,
+            Style::default().fg(crossterm_bridge::to_ratatui_color(theme.text_secondary)),
+        ))
+        .render(status_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::TextSegment;
+
+    fn viewer(texts: &[&str]) -> TextViewer {
+        let lines = texts
+            .iter()
+            .map(|t| StyledLine {
+                segments: vec![TextSegment::plain(*t)],
+                stream: "main".into(),
+                timestamp: None,
+            })
+            .collect();
+        TextViewer::new("Test".to_string(), lines)
+    }
+
+    #[test]
+    fn test_search_is_case_insensitive_and_cycles() {
+        let mut v = viewer(&["A sword", "a shield", "Another Sword", "boots"]);
+        v.search("SWORD");
+        assert_eq!(v.matches, vec![0, 2]);
+        v.next_match();
+        assert_eq!(v.current_match, 1);
+        v.next_match();
+        assert_eq!(v.current_match, 0);
+        v.prev_match();
+        assert_eq!(v.current_match, 1);
+    }
+
+    #[test]
+    fn test_scroll_clamps_to_content() {
+        let texts: Vec<String> = (0..50).map(|i| format!("line {}", i)).collect();
+        let refs: Vec<&str> = texts.iter().map(|s| s.as_str()).collect();
+        let mut v = viewer(&refs);
+        v.page_height = 10;
+        v.scroll_down(100);
+        assert_eq!(v.scroll, 40);
+        v.scroll_up(100);
+        assert_eq!(v.scroll, 0);
+    }
+
+    #[test]
+    fn test_search_input_mode_consumes_keys() {
+        let mut v = viewer(&["alpha", "beta"]);
+        assert!(!v.handle_key(KeyCode::Char('/'), KeyModifiers::NONE));
+        v.handle_key(KeyCode::Char('q'), KeyModifiers::NONE); // typed, not quit
+        v.handle_key(KeyCode::Backspace, KeyModifiers::NONE);
+        for c in "bet".chars() {
+            v.handle_key(KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        v.handle_key(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(v.matches, vec![1]);
+        assert!(v.handle_key(KeyCode::Esc, KeyModifiers::NONE));
+    }
+}
@@ -14,7 +14,7 @@ use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Clear, Paragraph, Widget},
+    widgets::{Block, Clear, Paragraph, Widget},
 };
 use regex::Regex; // Add back Regex import for SearchState
 use std::collections::VecDeque;
@@ -102,6 +102,10 @@ pub struct TextWindow {
     frozen_for_selection: bool,
     pending_logical_lines: VecDeque<LogicalLine>,
     pending_wrapped_lines: VecDeque<WrappedLine>,
+    // Lines that arrived while scrolled back (shown in the "N new lines" pill)
+    unseen_lines: usize,
+    // Where the pill was drawn last render, for click-to-jump
+    new_lines_pill: Option<Rect>,
 }
 
 impl Clone for TextWindow {
@@ -139,6 +143,8 @@ impl Clone for TextWindow {
             frozen_for_selection: self.frozen_for_selection,
             pending_logical_lines: self.pending_logical_lines.clone(),
             pending_wrapped_lines: self.pending_wrapped_lines.clone(),
+            unseen_lines: self.unseen_lines,
+            new_lines_pill: self.new_lines_pill,
         }
     }
 }
@@ -177,6 +183,8 @@ impl TextWindow {
             frozen_for_selection: false, // Not frozen by default
             pending_logical_lines: VecDeque::new(),
             pending_wrapped_lines: VecDeque::new(),
+            unseen_lines: 0,
+            new_lines_pill: None,
         }
     }
 
@@ -315,6 +323,66 @@ impl TextWindow {
         self.scroll_position.is_some()
     }
 
+    /// Scroll state as wrapped lines from the end (None = following live),
+    /// for carrying a scrolled-back view across a buffer rebuild.
+    pub fn scroll_position(&self) -> Option<usize> {
+        self.scroll_position
+            .map(|pos| self.wrapped_lines.len().saturating_sub(pos))
+    }
+
+    /// Restore a `scroll_position()` taken before a rebuild. Only a view
+    /// that was scrolled back stays put; a live view keeps following.
+    pub fn restore_scroll_position(&mut self, lines_from_end: Option<usize>) {
+        let Some(from_end) = lines_from_end else {
+            return;
+        };
+        let total_lines = self.wrapped_lines.len();
+        let pos = total_lines.saturating_sub(from_end);
+        if pos < total_lines.saturating_sub(self.last_visible_height) {
+            self.scroll_position = Some(pos);
+        }
+    }
+
+    /// Scroll position and unseen lines, kept in `UiState::window_views`
+    /// while the widget is rebuilt
+    pub fn view(&self) -> crate::data::WindowView {
+        crate::data::WindowView {
+            scroll_from_end: self.scroll_position(),
+            unread: self.unseen_lines,
+            visible_height: self.last_visible_height,
+        }
+    }
+
+    /// Put back a `view()` once the rebuilt widget has its lines again
+    pub fn restore_view(&mut self, view: crate::data::WindowView) {
+        if view.visible_height > 0 {
+            self.last_visible_height = view.visible_height;
+        }
+        self.restore_scroll_position(view.scroll_from_end);
+        if self.scroll_position.is_some() {
+            self.unseen_lines = view.unread;
+        }
+    }
+
+    /// Lines that arrived while scrolled back
+    pub fn unseen_lines(&self) -> usize {
+        self.unseen_lines
+    }
+
+    /// Jump back to the live view (the "N new lines" pill's action)
+    pub fn scroll_to_live(&mut self) {
+        self.scroll_position = None;
+        self.scroll_offset = 0;
+        self.unseen_lines = 0;
+    }
+
+    /// Whether a screen cell falls on the "N new lines" pill
+    pub fn new_lines_pill_contains(&self, x: u16, y: u16) -> bool {
+        self.new_lines_pill.is_some_and(|rect| {
+            x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+        })
+    }
+
     pub fn set_show_timestamps(&mut self, show: bool) {
         self.show_timestamps = show;
     }
@@ -342,19 +410,19 @@ impl TextWindow {
         self.show_border
     }
 
-    /// Format current time as timestamp (e.g., "[7:08 AM]")
+    /// Format current time as timestamp (e.g., "[7:08 AM]" or "[07:08]")
     /// Format timestamp for end of line (leading space)
     fn format_timestamp_end() -> String {
         use chrono::Local;
         let now = Local::now();
-        format!(" [{}]", now.format("%l:%M %p").to_string().trim())
+        format!(" [{}]", crate::core::locale::format_time(&now))
     }
 
     /// Format timestamp for start of line (trailing space)
     fn format_timestamp_start() -> String {
         use chrono::Local;
         let now = Local::now();
-        format!("[{}] ", now.format("%l:%M %p").to_string().trim())
+        format!("[{}] ", crate::core::locale::format_time(&now))
     }
 
     /// Set the stream name for the current line being built
@@ -419,6 +487,11 @@ impl TextWindow {
         // TextWindow just displays what it's given, including blank lines.
         // Note: Highlights are now applied in core (MessageProcessor) before text reaches widgets.
 
+        // A scrolled-back view stays put; count what it's missing
+        if self.scroll_position.is_some() {
+            self.unseen_lines += 1;
+        }
+
         // Add timestamp if enabled (before storing/wrapping)
         if self.show_timestamps {
             let timestamp_style = Style::default().fg(Color::DarkGray);
@@ -539,6 +612,27 @@ impl TextWindow {
             return vec![WrappedLine { spans: line_spans }];
         }
 
+        // Conversation-view lines open with a blank Annotation indent; keep it
+        // as a hanging indent on every wrapped row
+        if let Some((indent, rest)) = spans.split_first() {
+            let indent_len = indent.0.chars().count();
+            if indent.2 == SpanType::Annotation
+                && !rest.is_empty()
+                && indent_len > 0
+                && indent_len < width
+                && indent.0.chars().all(|c| c == ' ')
+            {
+                return self
+                    .wrap_styled_spans(rest, width - indent_len)
+                    .into_iter()
+                    .map(|mut line| {
+                        line.spans.insert(0, indent.clone());
+                        line
+                    })
+                    .collect();
+            }
+        }
+
         let mut result = Vec::new();
         let mut current_line_spans: Vec<(String, Style, SpanType, Option<LinkData>)> = Vec::new();
         let mut current_line_len = 0;
@@ -1355,6 +1449,53 @@ impl TextWindow {
         result
     }
 
+    /// Like `extract_selection_text`, but each styled run is wrapped in ANSI
+    /// escapes so the colors survive a paste into a terminal or code block
+    pub fn extract_selection_ansi(
+        &self,
+        start_line: usize,
+        start_col: usize,
+        end_line: usize,
+        end_col: usize,
+    ) -> String {
+        let mut result = String::new();
+        let last_line = end_line.min(self.wrapped_lines.len().saturating_sub(1));
+        for line_idx in start_line..=last_line {
+            let Some(wrapped) = self.wrapped_lines.get(line_idx) else {
+                break;
+            };
+            let from = if line_idx == start_line { start_col } else { 0 };
+            let to = if line_idx == end_line {
+                end_col
+            } else {
+                usize::MAX
+            };
+
+            let mut col = 0;
+            for (text, style, _, _) in &wrapped.spans {
+                let len = text.chars().count();
+                let (span_from, span_to) = (from.max(col), to.min(col + len));
+                if span_from < span_to {
+                    let piece: String = text
+                        .chars()
+                        .skip(span_from - col)
+                        .take(span_to - span_from)
+                        .collect();
+                    match super::colors::style_to_sgr(*style) {
+                        Some(sgr) => result.push_str(&format!("\x1b[{}m{}\x1b[0m", sgr, piece)),
+                        None => result.push_str(&piece),
+                    }
+                }
+                col += len;
+            }
+
+            if line_idx < end_line {
+                result.push('\n');
+            }
+        }
+        result
+    }
+
     /// Get visible line information for click detection
     /// Returns (start_line_index, visible_lines)
     pub fn get_visible_lines_info(&self, visible_height: usize) -> (usize, Vec<LineSegments>) {
@@ -1442,6 +1583,25 @@ mod tests {
         assert_eq!(lines[0].segments[0].text, "Hello world");
     }
 
+    #[test]
+    fn test_extract_selection_ansi_keeps_colors() {
+        let mut window = TextWindow::new("Main", 10);
+        window.add_text(styled_text("A ", None));
+        window.add_text(StyledText {
+            fg: Some(Color::Rgb(255, 0, 0)),
+            ..styled_text("troll", None)
+        });
+        window.finish_line(80);
+        window.add_text(styled_text("flees.", None));
+        window.finish_line(80);
+
+        assert_eq!(
+            window.extract_selection_ansi(0, 1, 1, 3),
+            " \x1b[38;2;255;0;0mtroll\x1b[0m\nfle"
+        );
+        assert_eq!(window.extract_selection_text(0, 1, 1, 3), " troll\nfle");
+    }
+
     #[test]
     fn test_toggle_links_disables_link_data() {
         let mut window = TextWindow::new("Main", 10);
@@ -1472,6 +1632,84 @@ mod tests {
         assert!(window.get_scroll_indicator().is_some());
     }
 
+    #[test]
+    fn test_scrolled_back_view_stays_put_and_counts_new_lines() {
+        let mut window = TextWindow::new("Main", 50);
+        for idx in 0..10 {
+            window.add_text(styled_text(&format!("Line {}", idx), None));
+            window.finish_line(80);
+        }
+        let area = Rect::new(0, 0, 30, 5);
+        let mut buf = Buffer::empty(area);
+        let theme = crate::theme::AppTheme::default();
+        window.render_with_focus(area, &mut buf, false, None, "#000000", 0, &theme);
+
+        window.scroll_up(2);
+        let before = window.scroll_position();
+        for idx in 10..13 {
+            window.add_text(styled_text(&format!("Line {}", idx), None));
+            window.finish_line(80);
+        }
+        assert_eq!(window.unseen_lines(), 3);
+        // Same top line, now three more lines from the end
+        assert_eq!(
+            window.scroll_position(),
+            before.map(|from_end| from_end + 3)
+        );
+
+        window.render_with_focus(area, &mut buf, false, None, "#000000", 0, &theme);
+        let pill = window
+            .new_lines_pill
+            .expect("pill drawn while scrolled back");
+        assert!(window.new_lines_pill_contains(pill.x, pill.y));
+
+        // A rebuild keeps the scrolled-back view
+        let saved = window.scroll_position();
+        window.clear();
+        for idx in 0..13 {
+            window.add_text(styled_text(&format!("Line {}", idx), None));
+            window.finish_line(80);
+        }
+        window.restore_scroll_position(saved);
+        assert_eq!(window.scroll_position(), saved);
+
+        window.scroll_to_live();
+        assert!(!window.is_scrolled_back());
+        assert_eq!(window.unseen_lines(), 0);
+    }
+
+    #[test]
+    fn test_rebuilt_widget_restores_view() {
+        let mut window = TextWindow::new("Main", 50);
+        for idx in 0..10 {
+            window.add_text(styled_text(&format!("Line {}", idx), None));
+            window.finish_line(80);
+        }
+        let area = Rect::new(0, 0, 30, 5);
+        let mut buf = Buffer::empty(area);
+        let theme = crate::theme::AppTheme::default();
+        window.render_with_focus(area, &mut buf, false, None, "#000000", 0, &theme);
+        window.scroll_up(2);
+        window.add_text(styled_text("Line 10", None));
+        window.finish_line(80);
+        let view = window.view();
+        assert_eq!(view.unread, 1);
+
+        // A new widget (layout reload) refilled from the same lines
+        let mut rebuilt = TextWindow::new("Main", 50);
+        for idx in 0..11 {
+            rebuilt.add_text(styled_text(&format!("Line {}", idx), None));
+            rebuilt.finish_line(80);
+        }
+        rebuilt.restore_view(view);
+        assert_eq!(rebuilt.view(), view);
+
+        // A live view stays live
+        let mut live = TextWindow::new("Main", 50);
+        live.restore_view(crate::data::WindowView::default());
+        assert!(!live.is_scrolled_back());
+    }
+
     #[test]
     fn test_get_visible_lines_info_respects_height() {
         let mut window = TextWindow::new("Main", 10);
@@ -1532,18 +1770,11 @@ impl TextWindow {
 
         // Pre-compute border geometry so we can rewrap to the actual inner width
         let borders = crossterm_bridge::to_ratatui_borders(&self.border_sides);
-        let border_type = match self.border_style.as_deref() {
-            Some("double") => BorderType::Double,
-            Some("rounded") => BorderType::Rounded,
-            Some("thick") => BorderType::Thick,
-            Some("quadrant_inside") => BorderType::QuadrantInside,
-            Some("quadrant_outside") => BorderType::QuadrantOutside,
-            _ => BorderType::Plain,
-        };
+        let border_set = super::border_glyphs::border_set(self.border_style.as_deref());
         let inner_area = if self.show_border {
             Block::default()
                 .borders(borders)
-                .border_type(border_type)
+                .border_set(border_set)
                 .inner(area)
         } else {
             area
@@ -1558,6 +1789,10 @@ impl TextWindow {
         let total_lines = self.wrapped_lines.len();
         let visible_height = inner_area.height as usize;
         self.last_visible_height = visible_height; // Save for scroll calculations
+        if self.scroll_position.is_none() {
+            self.unseen_lines = 0;
+        }
+        self.new_lines_pill = None;
 
         let title = if let Some(pos) = self.scroll_position {
             let lines_from_end = total_lines.saturating_sub(pos);
@@ -1590,7 +1825,7 @@ impl TextWindow {
             self.show_border,
             borders,
             &self.border_sides,
-            border_type,
+            border_set,
             border_style,
             &title,
             self.title_position,
@@ -1697,6 +1932,40 @@ impl TextWindow {
         }
 
         paragraph.render(inner_area, buf);
+        self.render_new_lines_pill(inner_area, buf, theme);
+    }
+
+    /// "N new lines ↓" in the bottom-right corner while scrolled back with
+    /// unseen lines; clicking it jumps to the live view.
+    fn render_new_lines_pill(
+        &mut self,
+        inner_area: Rect,
+        buf: &mut Buffer,
+        theme: &crate::theme::AppTheme,
+    ) {
+        if self.scroll_position.is_none() || self.unseen_lines == 0 || inner_area.height == 0 {
+            return;
+        }
+        let label = format!(
+            " {} new line{} \u{2193} ",
+            self.unseen_lines,
+            if self.unseen_lines == 1 { "" } else { "s" }
+        );
+        let width = (label.chars().count() as u16).min(inner_area.width);
+        let pill = Rect::new(
+            inner_area.x + inner_area.width - width,
+            inner_area.y + inner_area.height - 1,
+            width,
+            1,
+        );
+        let style = Style::default()
+            .fg(crossterm_bridge::to_ratatui_color(theme.text_selected))
+            .bg(crossterm_bridge::to_ratatui_color(
+                theme.background_selected,
+            ))
+            .add_modifier(Modifier::BOLD);
+        buf.set_stringn(pill.x, pill.y, &label, width as usize, style);
+        self.new_lines_pill = Some(pill);
     }
 
     fn fallback_text_color(&self) -> Color {
@@ -1722,6 +1991,24 @@ impl TextWindow {
         super::colors::parse_color_to_ratatui(hex)
     }
 
+    /// Drop the newest logical line (and its wrapped rows) so it can be
+    /// re-added after being rewritten in place, e.g. a repeat counter bump
+    pub fn pop_last_line(&mut self) {
+        if self.frozen_for_selection && !self.pending_logical_lines.is_empty() {
+            if let Some(line) = self.pending_logical_lines.pop_back() {
+                for _ in 0..line.wrapped_count {
+                    self.pending_wrapped_lines.pop_back();
+                }
+            }
+            return;
+        }
+        if let Some(line) = self.logical_lines.pop_back() {
+            for _ in 0..line.wrapped_count {
+                self.wrapped_lines.pop_back();
+            }
+        }
+    }
+
     /// Clear all text from the buffer
     pub fn clear(&mut self) {
         self.logical_lines.clear();
@@ -1,10 +1,48 @@
 //! Type bridge for converting between crossterm KeyEvents
 //!
 //! Converts `crossterm::event::KeyEvent` (from custom fork with Keypad support)
-//! to `ratatui::crossterm::event::KeyEvent` (expected by tui-textarea)
+//! to `ratatui::crossterm::event::KeyEvent` (expected by tui-textarea), and
+//! feeds keys to tui-textarea a grapheme at a time (`GraphemeInput`).
 
+use crate::frontend::common::text_width;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::crossterm::event as rt_event;
+use tui_textarea::{Input, Key, TextArea};
+
+/// tui-textarea moves and deletes by char, which leaves the cursor between
+/// a letter and its combining accent or inside a multi-codepoint emoji.
+/// `input_graphemes` repeats Left/Right/Backspace/Delete over the whole
+/// grapheme so every form field edits the way the command line does.
+pub trait GraphemeInput {
+    fn input_graphemes(&mut self, input: impl Into<Input>) -> bool;
+}
+
+impl GraphemeInput for TextArea<'_> {
+    fn input_graphemes(&mut self, input: impl Into<Input>) -> bool {
+        let input: Input = input.into();
+        if input.ctrl || input.alt {
+            return self.input(input);
+        }
+        let (row, col) = self.cursor();
+        let line = self.lines().get(row).map(String::as_str).unwrap_or("");
+        let before = col - text_width::prev_grapheme(line, col);
+        let after = text_width::next_grapheme(line, col) - col;
+        // Chars the key acts on; 0 at a line edge, where one key joins or
+        // crosses lines
+        let span = match input.key {
+            Key::Left => before,
+            Key::Right => after,
+            Key::Backspace if !self.is_selecting() => before,
+            Key::Delete if !self.is_selecting() => after,
+            _ => 1,
+        };
+        let mut changed = self.input(input.clone());
+        for _ in 1..span {
+            changed |= self.input(input.clone());
+        }
+        changed
+    }
+}
 
 /// Convert a crossterm KeyEvent to a ratatui::crossterm KeyEvent for tui-textarea
 pub fn to_textarea_event(key: KeyEvent) -> rt_event::KeyEvent {
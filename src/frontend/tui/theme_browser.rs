@@ -9,7 +9,7 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Widget},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
 };
 
 /// Browser for viewing and selecting application themes
@@ -227,7 +227,9 @@ impl Widget for &ThemeBrowser {
         let block = Block::default()
             .title(" Themes ")
             .borders(Borders::ALL)
-            .border_type(BorderType::Double)
+            .border_set(super::border_glyphs::themed(
+                ratatui::symbols::border::DOUBLE,
+            ))
             .border_style(Style::default().fg(Color::Cyan));
 
         let inner = block.inner(popup_area);
@@ -20,8 +20,10 @@ pub struct ThemeCache {
 impl ThemeCache {
     /// Create a new theme cache with the dark theme as default
     pub fn new() -> Self {
+        let theme = crate::theme::ThemePresets::dark();
+        super::border_glyphs::apply_theme(&theme);
         Self {
-            cached_theme: Arc::new(crate::theme::ThemePresets::dark()),
+            cached_theme: Arc::new(theme),
             cached_theme_id: "dark".to_string(),
             version: 0,
         }
@@ -29,6 +31,7 @@ impl ThemeCache {
 
     /// Update the cached theme (call this when theme changes via command/browser)
     pub fn update(&mut self, theme_id: String, theme: AppTheme) {
+        super::border_glyphs::apply_theme(&theme);
         self.cached_theme = Arc::new(theme);
         self.cached_theme_id = theme_id;
         self.version = self.version.wrapping_add(1);
@@ -3,6 +3,7 @@
 //! Presents meta fields plus grouped color sections, supports dragging, and
 //! serializes/deserializes `ThemeData` structs for persistence.
 
+use super::textarea_bridge::GraphemeInput;
 use crate::frontend::tui::crossterm_bridge;
 use crossterm::event::KeyEvent;
 use ratatui::{
@@ -37,6 +38,11 @@ pub struct ThemeEditor {
     // Current field within section
     current_field: usize,
 
+    // Carried over from the edited theme (no color field to edit)
+    shape_cues: bool,
+    border_set: Option<String>,
+    border_sets: std::collections::HashMap<String, crate::theme::BorderGlyphs>,
+
     // Position
     popup_x: u16,
     popup_y: u16,
@@ -75,6 +81,9 @@ impl ThemeEditor {
             color_sections,
             current_section: 0,
             current_field: 0,
+            shape_cues: theme_data.shape_cues,
+            border_set: theme_data.border_set.clone(),
+            border_sets: theme_data.border_sets.clone(),
             popup_x: 0,
             popup_y: 0,
         }
@@ -97,6 +106,9 @@ impl ThemeEditor {
             color_sections,
             current_section: 0,
             current_field: 0,
+            shape_cues: theme_data.shape_cues,
+            border_set: theme_data.border_set.clone(),
+            border_sets: theme_data.border_sets.clone(),
             popup_x: 0,
             popup_y: 0,
         }
@@ -269,16 +281,16 @@ impl ThemeEditor {
         if self.current_section == 0 {
             // Meta fields (name/description)
             if self.current_field == 0 {
-                self.name.input(rt_key);
+                self.name.input_graphemes(rt_key);
             } else if self.current_field == 1 {
-                self.description.input(rt_key);
+                self.description.input_graphemes(rt_key);
             }
         } else {
             // Color fields
             let section_idx = self.current_section - 1;
             if let Some(section) = self.color_sections.get_mut(section_idx) {
                 if let Some(field) = section.fields.get_mut(self.current_field) {
-                    field.textarea.input(rt_key);
+                    field.textarea.input_graphemes(rt_key);
                 }
             }
         }
@@ -315,6 +327,9 @@ impl ThemeEditor {
     /// Collect all edited values into a ThemeData struct
     fn collect_theme_data(&self) -> ThemeData {
         let mut data = ThemeData::default();
+        data.shape_cues = self.shape_cues;
+        data.border_set = self.border_set.clone();
+        data.border_sets = self.border_sets.clone();
 
         // Set name and description
         data.name = self
@@ -658,36 +673,7 @@ impl ThemeEditor {
     ) {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.form_border));
-
-        // Top
-        buf[(x, y)].set_char('┌').set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y)].set_char('─').set_style(border_style);
-        }
-        buf[(x + width - 1, y)]
-            .set_char('┐')
-            .set_style(border_style);
-
-        // Sides
-        for row in 1..height - 1 {
-            buf[(x, y + row)].set_char('│').set_style(border_style);
-            buf[(x + width - 1, y + row)]
-                .set_char('│')
-                .set_style(border_style);
-        }
-
-        // Bottom
-        buf[(x, y + height - 1)]
-            .set_char('└')
-            .set_style(border_style);
-        for col in 1..width - 1 {
-            buf[(x + col, y + height - 1)]
-                .set_char('─')
-                .set_style(border_style);
-        }
-        buf[(x + width - 1, y + height - 1)]
-            .set_char('┘')
-            .set_style(border_style);
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, width, height), border_style);
     }
 
     fn render_text_field(
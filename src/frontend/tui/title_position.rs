@@ -1,10 +1,11 @@
 use crate::config::BorderSides;
+use crate::frontend::common::text_width;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
-    symbols,
-    widgets::{Block, BorderType, Borders, Widget},
+    symbols::border,
+    widgets::{Block, Borders, Widget},
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -48,7 +49,7 @@ pub fn render_block_with_title(
     show_border: bool,
     borders: Borders,
     border_sides: &BorderSides,
-    border_type: BorderType,
+    border_set: border::Set,
     border_style: Style,
     title: &str,
     title_position: TitlePosition,
@@ -56,7 +57,7 @@ pub fn render_block_with_title(
     // Compute inner area first so callers always get consistent geometry
     let block = Block::default()
         .borders(borders)
-        .border_type(border_type)
+        .border_set(border_set)
         .border_style(border_style);
 
     let inner = if show_border { block.inner(area) } else { area };
@@ -81,26 +82,17 @@ pub fn render_block_with_title(
         return inner;
     }
 
-    let title_len = title.chars().count() as u16;
-    let trimmed_len = title_len.min(available);
+    let trimmed = text_width::truncate(title, available as usize);
+    let trimmed_len = text_width::width(trimmed) as u16;
     let offset = TitlePosition::offset(available, trimmed_len, title_position);
     let start_x = area.x + left_pad + offset.min(available.saturating_sub(trimmed_len));
-    let title_chars: Vec<char> = title.chars().take(trimmed_len as usize).collect();
     let border_color = border_style.fg.unwrap_or(Color::White);
 
     let (title_y, line_char) = match title_position {
         TitlePosition::TopLeft | TitlePosition::TopCenter | TitlePosition::TopRight => (
             area.y,
             if border_sides.top {
-                Some(match border_type {
-                    BorderType::Rounded => symbols::border::ROUNDED.horizontal_top,
-                    BorderType::Double => symbols::border::DOUBLE.horizontal_top,
-                    BorderType::Thick => symbols::border::THICK.horizontal_top,
-                    BorderType::QuadrantInside | BorderType::QuadrantOutside => {
-                        symbols::border::QUADRANT_INSIDE.horizontal_top
-                    }
-                    _ => symbols::border::PLAIN.horizontal_top,
-                })
+                Some(border_set.horizontal_top)
             } else {
                 None
             },
@@ -108,15 +100,7 @@ pub fn render_block_with_title(
         TitlePosition::BottomLeft | TitlePosition::BottomCenter | TitlePosition::BottomRight => (
             area.y.saturating_add(area.height.saturating_sub(1)),
             if border_sides.bottom {
-                Some(match border_type {
-                    BorderType::Rounded => symbols::border::ROUNDED.horizontal_bottom,
-                    BorderType::Double => symbols::border::DOUBLE.horizontal_bottom,
-                    BorderType::Thick => symbols::border::THICK.horizontal_bottom,
-                    BorderType::QuadrantInside | BorderType::QuadrantOutside => {
-                        symbols::border::QUADRANT_INSIDE.horizontal_bottom
-                    }
-                    _ => symbols::border::PLAIN.horizontal_bottom,
-                })
+                Some(border_set.horizontal_bottom)
             } else {
                 None
             },
@@ -142,16 +126,41 @@ pub fn render_block_with_title(
         }
     }
 
-    for (idx, ch) in title_chars.into_iter().enumerate() {
-        let x = start_x + idx as u16;
-        if x < area.x + area.width {
-            buf[(x, title_y)].set_char(ch).set_style(
-                Style::default()
-                    .fg(border_color)
-                    .add_modifier(border_style.add_modifier),
-            );
-        }
-    }
+    // set_stringn keeps graphemes and wide glyphs whole
+    buf.set_stringn(
+        start_x,
+        title_y,
+        trimmed,
+        trimmed_len as usize,
+        Style::default()
+            .fg(border_color)
+            .add_modifier(border_style.add_modifier),
+    );
 
     inner
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_titles_are_measured_in_columns() {
+        let area = Rect::new(0, 0, 8, 3);
+        let mut buf = Buffer::empty(area);
+        render_block_with_title(
+            area,
+            &mut buf,
+            true,
+            Borders::ALL,
+            &BorderSides::default(),
+            border::PLAIN,
+            Style::default(),
+            "日本語の窓",
+            TitlePosition::TopLeft,
+        );
+        let top: String = (0..8).map(|x| buf[(x, 0)].symbol()).collect();
+        // Three wide glyphs fit in the six columns between the corners
+        assert_eq!(top, "┌日 本 語 ┐");
+    }
+}
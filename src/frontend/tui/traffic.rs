@@ -0,0 +1,291 @@
+//! Traffic console widget.
+//!
+//! Raw socket lines from network::traffic, newest at the bottom:
+//! `12:00:01 < <prompt time="...">&gt;</prompt>` for server lines and
+//! `12:00:02 > <c>look` for what the client sent. In hex view each line is
+//! a header followed by a hex dump. Long lines wrap under the text column.
+
+use crate::config::BorderSides;
+use crate::network::traffic::{Direction, TrafficLine};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Width of the `HH:MM:SS < ` prefix
+const PREFIX_WIDTH: usize = 11;
+
+/// Traffic widget - a tail of raw inbound/outbound lines
+pub struct Traffic {
+    title: String,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached lines, oldest first
+    lines: Vec<TrafficLine>,
+    /// Tap generation the cache was taken at
+    generation: u64,
+    hex: bool,
+    capturing: bool,
+    border_color: Color,
+    text_color: Color,
+    outbound_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Traffic {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            lines: Vec::new(),
+            generation: 0,
+            hex: false,
+            capturing: false,
+            border_color: Color::White,
+            text_color: Color::White,
+            outbound_color: Color::LightCyan,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    pub fn set_hex(&mut self, hex: bool) {
+        self.hex = hex;
+    }
+
+    pub fn set_capturing(&mut self, capturing: bool) {
+        self.capturing = capturing;
+    }
+
+    /// Tap generation of the cached lines
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn update_lines(&mut self, lines: Vec<TrafficLine>, generation: u64) {
+        self.lines = lines;
+        self.generation = generation;
+    }
+
+    /// Display rows for one line at `width` columns
+    fn rows(&self, line: &TrafficLine, width: usize) -> Vec<Line<'static>> {
+        let dim = Style::default().fg(Color::DarkGray);
+        let style = Style::default().fg(match line.direction {
+            Direction::In => self.text_color,
+            Direction::Out => self.outbound_color,
+        });
+        let prefix = format!(
+            "{} {} ",
+            line.at.format("%H:%M:%S"),
+            line.direction.marker()
+        );
+        let indent = " ".repeat(PREFIX_WIDTH);
+
+        let body: Vec<String> = if self.hex {
+            line.hex_rows()
+        } else {
+            vec![line.text()]
+        };
+        let text_width = width.saturating_sub(PREFIX_WIDTH).max(1);
+        let mut rows = Vec::new();
+        if self.hex {
+            rows.push(Line::from(vec![
+                Span::styled(prefix.clone(), dim),
+                Span::styled(format!("{} bytes", line.bytes.len()), style),
+            ]));
+        }
+        for text in body {
+            let chars: Vec<char> = text.chars().collect();
+            let pieces: Vec<String> = if chars.is_empty() {
+                vec![String::new()]
+            } else {
+                chars
+                    .chunks(text_width)
+                    .map(|c| c.iter().collect())
+                    .collect()
+            };
+            for piece in pieces {
+                let lead = if rows.is_empty() {
+                    prefix.clone()
+                } else {
+                    indent.clone()
+                };
+                rows.push(Line::from(vec![
+                    Span::styled(lead, dim),
+                    Span::styled(piece, style),
+                ]));
+            }
+        }
+        rows
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                let mut title = self.title.clone();
+                if self.hex {
+                    title.push_str(" [hex]");
+                }
+                if !self.capturing && !self.lines.is_empty() {
+                    title.push_str(" [paused]");
+                }
+                block = block.title(title);
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        if self.lines.is_empty() {
+            let message = if self.capturing {
+                "Waiting for traffic..."
+            } else {
+                "Capture off (.traffic on)"
+            };
+            Paragraph::new(Line::from(Span::styled(
+                message,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .render(inner, buf);
+            return;
+        }
+
+        // Fill from the newest line upward
+        let height = inner.height as usize;
+        let mut shown: Vec<Line> = Vec::new();
+        for line in self.lines.iter().rev() {
+            let mut rows = self.rows(line, inner.width as usize);
+            rows.extend(shown);
+            shown = rows;
+            if shown.len() >= height {
+                break;
+            }
+        }
+        let skip = shown.len().saturating_sub(height);
+        let visible: Vec<Line> = shown.into_iter().skip(skip).collect();
+        Paragraph::new(visible).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn line(seq: u64, direction: Direction, bytes: &[u8]) -> TrafficLine {
+        TrafficLine {
+            seq,
+            direction,
+            at: NaiveTime::from_hms_opt(12, 0, 1).unwrap(),
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    fn render(widget: &Traffic, width: u16, height: u16) -> Vec<String> {
+        let area = Rect::new(0, 0, width, height);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn shows_newest_lines_with_direction_and_wraps() {
+        let mut widget = Traffic::new("Traffic");
+        widget.set_show_border(false);
+        widget.update_lines(
+            vec![
+                line(1, Direction::In, b"old line\r\n"),
+                line(2, Direction::Out, b"<c>look\n"),
+                line(3, Direction::In, b"Obvious paths: north, east\r\n"),
+            ],
+            3,
+        );
+        assert_eq!(
+            render(&widget, 30, 3),
+            vec![
+                "12:00:01 > <c>look",
+                "12:00:01 < Obvious paths: nort",
+                "           h, east",
+            ]
+        );
+    }
+
+    #[test]
+    fn hex_view_dumps_bytes_under_a_header() {
+        let mut widget = Traffic::new("Traffic");
+        widget.set_show_border(false);
+        widget.set_hex(true);
+        widget.update_lines(vec![line(1, Direction::Out, b"<c>look\n")], 1);
+        let rows = render(&widget, 80, 3);
+        assert_eq!(rows[0], "12:00:01 > 8 bytes");
+        assert!(rows[1].starts_with("           3c 63 3e 6c 6f 6f 6b 0a"));
+        assert!(rows[1].ends_with("<c>look."));
+    }
+}
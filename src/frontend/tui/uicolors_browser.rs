@@ -3,6 +3,7 @@
 //! Exposes inline swatches plus an embedded mini editor for tweaking fg/bg
 //! values inline without leaving the popup.
 
+use super::textarea_bridge::GraphemeInput;
 use crate::config::ColorConfig;
 use crate::frontend::tui::crossterm_bridge;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -146,7 +147,7 @@ impl UIColorEditor {
                 } else {
                     &mut self.color_bg
                 };
-                active_field.input(rt_key);
+                active_field.input_graphemes(rt_key);
             }
         }
         None
@@ -230,41 +231,7 @@ impl UIColorEditor {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_border));
 
-        // Top border
-        if let Some(cell) = buf.cell_mut((x, y)) {
-            cell.set_char('┌').set_style(border_style);
-        }
-        for col in 1..width - 1 {
-            if let Some(cell) = buf.cell_mut((x + col, y)) {
-                cell.set_char('─').set_style(border_style);
-            }
-        }
-        if let Some(cell) = buf.cell_mut((x + width - 1, y)) {
-            cell.set_char('┐').set_style(border_style);
-        }
-
-        // Bottom border
-        if let Some(cell) = buf.cell_mut((x, y + height - 1)) {
-            cell.set_char('└').set_style(border_style);
-        }
-        for col in 1..width - 1 {
-            if let Some(cell) = buf.cell_mut((x + col, y + height - 1)) {
-                cell.set_char('─').set_style(border_style);
-            }
-        }
-        if let Some(cell) = buf.cell_mut((x + width - 1, y + height - 1)) {
-            cell.set_char('┘').set_style(border_style);
-        }
-
-        // Side borders
-        for row in 1..height - 1 {
-            if let Some(cell) = buf.cell_mut((x, y + row)) {
-                cell.set_char('│').set_style(border_style);
-            }
-            if let Some(cell) = buf.cell_mut((x + width - 1, y + row)) {
-                cell.set_char('│').set_style(border_style);
-            }
-        }
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, width, height), border_style);
 
         // Title (left-aligned)
         let title = " Edit UI Color ";
@@ -709,18 +676,7 @@ impl UIColorsBrowser {
         let border_style =
             Style::default().fg(crossterm_bridge::to_ratatui_color(theme.browser_border));
 
-        // Top border
-        if let Some(cell) = buf.cell_mut((x, y)) {
-            cell.set_char('┌').set_style(border_style);
-        }
-        for col in 1..self.width - 1 {
-            if let Some(cell) = buf.cell_mut((x + col, y)) {
-                cell.set_char('─').set_style(border_style);
-            }
-        }
-        if let Some(cell) = buf.cell_mut((x + self.width - 1, y)) {
-            cell.set_char('┐').set_style(border_style);
-        }
+        super::border_glyphs::draw_box(buf, Rect::new(x, y, self.width, self.height), border_style);
 
         // Title
         let title = " UI Colors Browser ";
@@ -735,29 +691,6 @@ impl UIColorsBrowser {
             }
         }
 
-        // Side borders
-        for row in 1..self.height - 1 {
-            if let Some(cell) = buf.cell_mut((x, y + row)) {
-                cell.set_char('│').set_style(border_style);
-            }
-            if let Some(cell) = buf.cell_mut((x + self.width - 1, y + row)) {
-                cell.set_char('│').set_style(border_style);
-            }
-        }
-
-        // Bottom border
-        if let Some(cell) = buf.cell_mut((x, y + self.height - 1)) {
-            cell.set_char('└').set_style(border_style);
-        }
-        for col in 1..self.width - 1 {
-            if let Some(cell) = buf.cell_mut((x + col, y + self.height - 1)) {
-                cell.set_char('─').set_style(border_style);
-            }
-        }
-        if let Some(cell) = buf.cell_mut((x + self.width - 1, y + self.height - 1)) {
-            cell.set_char('┘').set_style(border_style);
-        }
-
         // Render entries with display_row tracking
         let list_y = y + 1;
         let list_height = 16;
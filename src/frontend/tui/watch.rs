@@ -0,0 +1,184 @@
+//! Watch widget.
+//!
+//! Labeled rows showing the live value of state expressions (`.watch`),
+//! e.g. `HP 180` or `Busy no`. A row whose expression can't be read
+//! shows `?`; `.watch` lists the reason.
+//!
+//! Rows come from AppCore::watch_rows (see core::watch).
+
+use crate::config::BorderSides;
+use crate::core::watch::WatchRow;
+use crate::frontend::common::text_width;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Watch widget - one label and value per row
+pub struct Watch {
+    title: String,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached rows for rendering
+    rows: Vec<WatchRow>,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Watch {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            rows: Vec::new(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Replace the rows. Returns true if the display changed.
+    pub fn update_rows(&mut self, rows: Vec<WatchRow>) -> bool {
+        if rows == self.rows {
+            return false;
+        }
+        self.rows = rows;
+        true
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        if self.rows.is_empty() {
+            Paragraph::new(Line::from(Span::styled(
+                "Nothing watched (.watch add)",
+                dim,
+            )))
+            .render(inner, buf);
+            return;
+        }
+        // Labels line up in a column, but never take more than half the width
+        let width = self
+            .rows
+            .iter()
+            .map(|row| text_width::width(&row.label))
+            .max()
+            .unwrap_or(0)
+            .min(inner.width as usize / 2);
+        let lines: Vec<Line> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let label = text_width::truncate(&row.label, width);
+                let value = match &row.value {
+                    Ok(value) => Span::styled(value.as_str(), Style::default().fg(self.text_color)),
+                    Err(_) => Span::styled("?", Style::default().fg(Color::Red)),
+                };
+                Line::from(vec![
+                    Span::styled(format!("{} ", text_width::pad_right(label, width)), dim),
+                    value,
+                ])
+            })
+            .collect();
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_aligned_rows_and_unreadable_values() {
+        let mut widget = Watch::new("Watch");
+        widget.set_show_border(false);
+        let rows = vec![
+            WatchRow {
+                label: "HP".into(),
+                value: Ok("180".into()),
+            },
+            WatchRow {
+                label: "Target".into(),
+                value: Err("unknown name 'target'".into()),
+            },
+        ];
+        assert!(widget.update_rows(rows.clone()));
+        assert!(!widget.update_rows(rows));
+
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).starts_with("HP     180"));
+        assert!(row(1).starts_with("Target ?"));
+    }
+}
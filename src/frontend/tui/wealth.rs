@@ -0,0 +1,172 @@
+//! Wealth widget.
+//!
+//! Shows the running silver estimate and the session gain/loss.
+//!
+//! Reads data from GameState.wealth (see core::wealth).
+
+use crate::config::BorderSides;
+use crate::core::wealth::{format_silver, WealthState};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+/// Wealth widget - silver on hand plus session delta
+pub struct Wealth {
+    title: String,
+    show_session: bool,
+    show_title: bool,
+    show_border: bool,
+    border_sides: BorderSides,
+    border_style: Option<String>,
+    /// Cached state for rendering
+    state: WealthState,
+    border_color: Color,
+    text_color: Color,
+    background_color: Option<Color>,
+}
+
+impl Wealth {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            show_session: true,
+            show_title: true,
+            show_border: true,
+            border_sides: BorderSides::default(),
+            border_style: None,
+            state: WealthState::default(),
+            border_color: Color::White,
+            text_color: Color::White,
+            background_color: None,
+        }
+    }
+
+    pub fn set_border_color(&mut self, color: Color) {
+        self.border_color = color;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the background color (from theme)
+    pub fn set_background_color(&mut self, color: Option<String>) {
+        self.background_color = color.and_then(|c| super::colors::parse_color_to_ratatui(&c));
+    }
+
+    pub fn set_show_session(&mut self, show: bool) {
+        self.show_session = show;
+    }
+
+    pub fn set_show_title(&mut self, show: bool) {
+        self.show_title = show;
+    }
+
+    pub fn set_show_border(&mut self, show: bool) {
+        self.show_border = show;
+    }
+
+    pub fn set_border_sides(&mut self, sides: BorderSides) {
+        self.border_sides = sides;
+    }
+
+    pub fn set_border_style(&mut self, style: Option<String>) {
+        self.border_style = style;
+    }
+
+    /// Update from game state. Returns true if the display changed.
+    pub fn update_from_state(&mut self, state: &WealthState) -> bool {
+        if self.state.generation == state.generation {
+            return false;
+        }
+        self.state = state.clone();
+        true
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(bg_color) = self.background_color {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        cell.set_bg(bg_color);
+                    }
+                }
+            }
+        }
+
+        let inner = if self.show_border && self.border_sides.any() {
+            let borders = super::crossterm_bridge::to_ratatui_borders(&self.border_sides);
+            let mut block = Block::default()
+                .borders(borders)
+                .border_set(super::border_glyphs::border_set(
+                    self.border_style.as_deref(),
+                ))
+                .border_style(Style::default().fg(self.border_color));
+            if self.show_title {
+                block = block.title(self.title.as_str());
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        if inner.width == 0 || inner.height == 0 {
+            return;
+        }
+
+        let mut lines = vec![match self.state.silver {
+            Some(silver) => Line::from(Span::styled(
+                format!("Silver: {}", format_silver(silver)),
+                Style::default().fg(self.text_color),
+            )),
+            None => Line::from(Span::styled(
+                "Silver: ? (type WEALTH)",
+                Style::default().fg(Color::DarkGray),
+            )),
+        }];
+        if self.show_session {
+            let delta = self.state.session_delta;
+            let color = match delta {
+                d if d > 0 => Color::Green,
+                d if d < 0 => Color::Red,
+                _ => self.text_color,
+            };
+            let sign = if delta > 0 { "+" } else { "" };
+            lines.push(Line::from(Span::styled(
+                format!("Session: {}{}", sign, format_silver(delta)),
+                Style::default().fg(color),
+            )));
+        }
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::wealth::SilverEvent;
+
+    #[test]
+    fn renders_silver_and_session() {
+        let mut state = WealthState::default();
+        state.apply(SilverEvent::Balance(1500));
+        state.apply(SilverEvent::Gained(250));
+        let mut widget = Wealth::new("Silver");
+        widget.set_show_border(false);
+        assert!(widget.update_from_state(&state));
+        assert!(!widget.update_from_state(&state));
+
+        let area = Rect::new(0, 0, 20, 2);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+        let row = |y: u16| -> String { (0..20).map(|x| buf[(x, y)].symbol()).collect() };
+        assert!(row(0).starts_with("Silver: 1,750"));
+        assert!(row(1).starts_with("Session: +250"));
+    }
+}
@@ -4,6 +4,7 @@
 ///! - Widget caches (HashMaps of widget instances)
 ///! - Sync methods (updating widgets from AppCore state)
 ///! - Widget initialization and updates
+use crate::data::WindowView;
 use std::collections::HashMap;
 
 /// Widget manager handles all widget caches and synchronization
@@ -56,6 +57,28 @@ pub struct WidgetManager {
     pub gs4_experience_widgets: HashMap<String, super::gs4_experience::GS4Experience>,
     /// Cache of Encumbrance widgets per window name
     pub encumbrance_widgets: HashMap<String, super::encumbrance::Encumbrance>,
+    /// Cache of Stance widgets per window name
+    pub stance_widgets: HashMap<String, super::stance::Stance>,
+    /// Cache of Wealth widgets per window name
+    pub wealth_widgets: HashMap<String, super::wealth::Wealth>,
+    /// Cache of TaskTracker widgets per window name
+    pub task_tracker_widgets: HashMap<String, super::task_tracker::TaskTracker>,
+    /// Cache of SocietyTasks widgets per window name
+    pub society_tasks_widgets: HashMap<String, super::society_tasks::SocietyTasks>,
+    /// Cache of CombatStats widgets per window name
+    pub combat_stats_widgets: HashMap<String, super::combat_stats::CombatStatsWidget>,
+    /// Cache of Calendar widgets per window name
+    pub calendar_widgets: HashMap<String, super::calendar::Calendar>,
+    /// Cache of Reminders widgets per window name
+    pub reminders_widgets: HashMap<String, super::reminders::Reminders>,
+    /// Cache of Notes widgets per window name
+    pub notes_widgets: HashMap<String, super::notes::Notes>,
+    /// Cache of Watch widgets per window name
+    pub watch_widgets: HashMap<String, super::watch::Watch>,
+    /// Cache of Traffic widgets per window name
+    pub traffic_widgets: HashMap<String, super::traffic::Traffic>,
+    /// Cache of Played widgets per window name
+    pub played_widgets: HashMap<String, super::played::Played>,
     /// Cache of Quickbar widgets per window name
     pub quickbar_widgets: HashMap<String, super::quickbar::Quickbar>,
     /// Cache of HotkeyBar widgets per window name
@@ -67,6 +90,11 @@ pub struct WidgetManager {
     /// Track last synced generation per text window to know what's new
     /// Using generation instead of line count to handle buffer rotation at max_lines
     pub last_synced_generation: HashMap<String, u64>,
+    /// Last synced `tail_revision` per text window; a change means the
+    /// newest line was rewritten in place (repeat collapsing)
+    pub last_synced_tail_revision: HashMap<String, u64>,
+    /// Freeze indicator currently in each frozen text window's title
+    pub freeze_labels: HashMap<String, String>,
     /// Last synced data generation per GameState-backed widget (targets,
     /// players, items, active effects). Lets sync skip the full clear+reclone
     /// data rebuild when the underlying data hasn't changed.
@@ -101,11 +129,24 @@ impl WidgetManager {
             experience_widgets: HashMap::new(),
             gs4_experience_widgets: HashMap::new(),
             encumbrance_widgets: HashMap::new(),
+            stance_widgets: HashMap::new(),
+            wealth_widgets: HashMap::new(),
+            task_tracker_widgets: HashMap::new(),
+            society_tasks_widgets: HashMap::new(),
+            combat_stats_widgets: HashMap::new(),
+            calendar_widgets: HashMap::new(),
+            reminders_widgets: HashMap::new(),
+            notes_widgets: HashMap::new(),
+            watch_widgets: HashMap::new(),
+            traffic_widgets: HashMap::new(),
+            played_widgets: HashMap::new(),
             quickbar_widgets: HashMap::new(),
             hotkey_bar_widgets: HashMap::new(),
             minivitals_widgets: HashMap::new(),
             betrayer_widgets: HashMap::new(),
             last_synced_generation: HashMap::new(),
+            last_synced_tail_revision: HashMap::new(),
+            freeze_labels: HashMap::new(),
             widget_data_generation: HashMap::new(),
         }
     }
@@ -136,14 +177,56 @@ impl WidgetManager {
         self.experience_widgets.clear();
         self.gs4_experience_widgets.clear();
         self.encumbrance_widgets.clear();
+        self.stance_widgets.clear();
+        self.wealth_widgets.clear();
+        self.task_tracker_widgets.clear();
+        self.society_tasks_widgets.clear();
+        self.combat_stats_widgets.clear();
+        self.calendar_widgets.clear();
+        self.reminders_widgets.clear();
+        self.notes_widgets.clear();
+        self.watch_widgets.clear();
+        self.traffic_widgets.clear();
+        self.played_widgets.clear();
         self.quickbar_widgets.clear();
         self.hotkey_bar_widgets.clear();
         self.minivitals_widgets.clear();
         self.betrayer_widgets.clear();
         self.last_synced_generation.clear();
+        self.last_synced_tail_revision.clear();
+        self.freeze_labels.clear();
         self.widget_data_generation.clear();
     }
 
+    /// Scroll and unread state of the text or tabbed window `name` (tabs
+    /// keyed `name:tab`), leaving out views that are live with nothing
+    /// unread. Saved to `UiState::window_views` before a rebuild.
+    pub fn window_views(&self, name: &str) -> Vec<(String, WindowView)> {
+        let mut views = Vec::new();
+        if let Some(window) = self.text_windows.get(name) {
+            views.push((name.to_string(), window.view()));
+        }
+        if let Some(window) = self.tabbed_text_windows.get(name) {
+            views.extend(
+                window
+                    .tab_views()
+                    .into_iter()
+                    .map(|(tab, view)| (format!("{}:{}", name, tab), view)),
+            );
+        }
+        views.retain(|(_, view)| view.scroll_from_end.is_some() || view.unread > 0);
+        views
+    }
+
+    /// `window_views` for every text and tabbed window
+    pub fn all_window_views(&self) -> Vec<(String, WindowView)> {
+        self.text_windows
+            .keys()
+            .chain(self.tabbed_text_windows.keys())
+            .flat_map(|name| self.window_views(name))
+            .collect()
+    }
+
     /// Remove a widget from ALL type-specific caches by name.
     /// Call this when a widget's type changes to ensure old cached widget is cleaned up.
     pub fn remove_widget_from_all_caches(&mut self, name: &str) {
@@ -171,11 +254,24 @@ impl WidgetManager {
         self.experience_widgets.remove(name);
         self.gs4_experience_widgets.remove(name);
         self.encumbrance_widgets.remove(name);
+        self.stance_widgets.remove(name);
+        self.wealth_widgets.remove(name);
+        self.task_tracker_widgets.remove(name);
+        self.society_tasks_widgets.remove(name);
+        self.combat_stats_widgets.remove(name);
+        self.calendar_widgets.remove(name);
+        self.reminders_widgets.remove(name);
+        self.notes_widgets.remove(name);
+        self.watch_widgets.remove(name);
+        self.traffic_widgets.remove(name);
+        self.played_widgets.remove(name);
         self.quickbar_widgets.remove(name);
         self.hotkey_bar_widgets.remove(name);
         self.minivitals_widgets.remove(name);
         self.betrayer_widgets.remove(name);
         self.last_synced_generation.remove(name);
+        self.last_synced_tail_revision.remove(name);
+        self.freeze_labels.remove(name);
         self.widget_data_generation.remove(name);
     }
 }
@@ -3,8 +3,10 @@
 //! Presents a VellumFE-inspired popup that lets the user tweak geometry,
 //! borders, and stream assignments for a given window definition.
 //!
+use super::textarea_bridge::GraphemeInput;
 use crate::data::input::{KeyCode, KeyEvent as TfKeyEvent};
 use crate::config::Config;
+use crate::frontend::common::text_width;
 use crate::frontend::tui::crossterm_bridge;
 use crate::frontend::tui::textarea_bridge;
 use crate::config::{DashboardIndicatorDef, TabbedTextTab, WindowDef};
@@ -82,9 +84,11 @@ enum FieldRef {
     EntityId,
     PromptIcon,
     PromptIconColor,
+    PromptTemplate,
     CursorColor,
     CursorBg,
     ContentAlign,
+    TeeFile,
 
     // Checkboxes
     ShowTitle,
@@ -100,6 +104,7 @@ enum FieldRef {
     TabInactiveColor,
     TabUnreadColor,
     TabUnreadPrefix,
+    TabBarWidth,
     TabSeparator,
     ShowDesc,
     ShowObjs,
@@ -166,6 +171,24 @@ enum FieldRef {
     // Betrayer widget fields
     BetrayerShowItems,
     BetrayerBarColor,
+    // Stance widget fields
+    StanceShowBar,
+    StanceShowPosture,
+    // Wealth widget fields
+    WealthShowSession,
+    // Task tracker widget fields
+    TaskTrackerShowProgress,
+    TaskTrackerNotify,
+    // Society task widget fields
+    SocietyTasksShowProgress,
+    SocietyTasksNotify,
+    // Calendar widget fields
+    CalendarShowWeekday,
+    CalendarShowLocalTime,
+    // Reminders widget fields
+    RemindersShowRepeat,
+    // Notes widget fields
+    NotesSection,
     // Text widget compact mode
     TextCompact,
     // Targets widget show arms/body parts count
@@ -277,6 +300,20 @@ impl FieldRef {
             FieldRef::TextCompact => 113,
             FieldRef::TargetsShowAppendages => 114,
             FieldRef::TargetsStatusPosition => 116,
+            FieldRef::StanceShowBar => 118,
+            FieldRef::StanceShowPosture => 119,
+            FieldRef::WealthShowSession => 120,
+            FieldRef::TaskTrackerShowProgress => 121,
+            FieldRef::TaskTrackerNotify => 122,
+            FieldRef::PromptTemplate => 123,
+            FieldRef::CalendarShowWeekday => 124,
+            FieldRef::CalendarShowLocalTime => 125,
+            FieldRef::RemindersShowRepeat => 126,
+            FieldRef::NotesSection => 127,
+            FieldRef::TabBarWidth => 128,
+            FieldRef::TeeFile => 129,
+            FieldRef::SocietyTasksShowProgress => 130,
+            FieldRef::SocietyTasksNotify => 131,
         }
     }
 }
@@ -287,6 +324,8 @@ struct TabEditItem {
     streams: Vec<String>,
     show_timestamps: bool,
     ignore_activity: bool,
+    alert_flash: bool,
+    alert_sound: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -301,6 +340,7 @@ enum TabEditorFormField {
     Streams,
     Timestamps,
     IgnoreActivity,
+    AlertFlash,
 }
 
 #[derive(Clone, Debug)]
@@ -313,6 +353,7 @@ struct TabEditor {
     streams_input: TextArea<'static>,
     show_timestamps: bool,
     ignore_activity: bool,
+    alert_flash: bool,
     editing_index: Option<usize>,
     /// Click areas for mouse support: (row_index, y, x, width)
     click_areas: Vec<(usize, u16, u16, u16)>,
@@ -327,6 +368,8 @@ impl TabEditor {
                 streams: t.get_streams(),
                 show_timestamps: t.show_timestamps.unwrap_or(false),
                 ignore_activity: t.ignore_activity.unwrap_or(false),
+                alert_flash: t.alert_flash.unwrap_or(false),
+                alert_sound: t.alert_sound.clone(),
             })
             .collect();
 
@@ -336,6 +379,8 @@ impl TabEditor {
                 streams: vec!["main".to_string()],
                 show_timestamps: false,
                 ignore_activity: false,
+                alert_flash: false,
+                alert_sound: None,
             });
         }
 
@@ -345,6 +390,7 @@ impl TabEditor {
         streams_input.insert_str(items[0].streams.join(", "));
         let initial_ts = items.get(0).map(|t| t.show_timestamps).unwrap_or(false);
         let initial_ignore = items.get(0).map(|t| t.ignore_activity).unwrap_or(false);
+        let initial_flash = items.first().map(|t| t.alert_flash).unwrap_or(false);
 
         Self {
             tabs: items,
@@ -355,6 +401,7 @@ impl TabEditor {
             streams_input,
             show_timestamps: initial_ts,
             ignore_activity: initial_ignore,
+            alert_flash: initial_flash,
             editing_index: None,
             click_areas: Vec::new(),
         }
@@ -384,6 +431,8 @@ impl TabEditor {
                 show_timestamps: Some(t.show_timestamps),
                 ignore_activity: Some(t.ignore_activity),
                 timestamp_position: None,
+                alert_sound: t.alert_sound.clone(),
+                alert_flash: t.alert_flash.then_some(true),
             })
             .collect()
     }
@@ -396,6 +445,7 @@ impl TabEditor {
         self.streams_input = WindowEditor::create_textarea();
         self.show_timestamps = false;
         self.ignore_activity = false;
+        self.alert_flash = false;
     }
 
     fn start_edit(&mut self) {
@@ -409,6 +459,7 @@ impl TabEditor {
             self.streams_input.insert_str(item.streams.join(", "));
             self.show_timestamps = item.show_timestamps;
             self.ignore_activity = item.ignore_activity;
+            self.alert_flash = item.alert_flash;
         }
     }
 
@@ -435,11 +486,18 @@ impl TabEditor {
             return;
         }
 
+        // The sound has no field here; keep whatever the config had
+        let alert_sound = self
+            .editing_index
+            .and_then(|idx| self.tabs.get(idx))
+            .and_then(|t| t.alert_sound.clone());
         let item = TabEditItem {
             name,
             streams,
             show_timestamps: self.show_timestamps,
             ignore_activity: self.ignore_activity,
+            alert_flash: self.alert_flash,
+            alert_sound,
         };
 
         if let Some(idx) = self.editing_index {
@@ -1360,15 +1418,19 @@ pub struct WindowEditor {
     text_color_input: TextArea<'static>,
     prompt_icon_input: TextArea<'static>,
     prompt_icon_color_input: TextArea<'static>,
+    prompt_template_input: TextArea<'static>,
+    notes_section_input: TextArea<'static>,
     cursor_color_input: TextArea<'static>,
     cursor_bg_input: TextArea<'static>,
     content_align_input: TextArea<'static>,
+    tee_file_input: TextArea<'static>,
     tab_bar_position_input: TextArea<'static>,
     title_position_input: TextArea<'static>,
     tab_active_color_input: TextArea<'static>,
     tab_inactive_color_input: TextArea<'static>,
     tab_unread_color_input: TextArea<'static>,
     tab_unread_prefix_input: TextArea<'static>,
+    tab_bar_width_input: TextArea<'static>,
     tab_separator: bool,
     progress_id_input: TextArea<'static>,
     progress_color_input: TextArea<'static>,
@@ -1450,6 +1512,28 @@ pub struct WindowEditor {
     betrayer_show_items: bool,
     betrayer_bar_color_input: TextArea<'static>,
 
+    // Stance widget
+    stance_show_bar: bool,
+    stance_show_posture: bool,
+
+    // Wealth widget
+    wealth_show_session: bool,
+
+    // Task tracker widget
+    task_tracker_show_progress: bool,
+    task_tracker_notify: bool,
+
+    // Society task widget
+    society_tasks_show_progress: bool,
+    society_tasks_notify: bool,
+
+    // Calendar widget
+    calendar_show_weekday: bool,
+    calendar_show_local_time: bool,
+
+    // Reminders widget
+    reminders_show_repeat: bool,
+
     // Text widget compact mode
     text_compact: bool,
 
@@ -1646,6 +1730,7 @@ impl WindowEditor {
             WindowDef::CommandInput { .. } => {
                 fields.push(FieldRef::PromptIcon);
                 fields.push(FieldRef::PromptIconColor);
+                fields.push(FieldRef::PromptTemplate);
                 fields.push(FieldRef::TextColor);
                 fields.push(FieldRef::CursorColor);
                 fields.push(FieldRef::CursorBg);
@@ -1660,6 +1745,9 @@ impl WindowEditor {
                 fields.push(FieldRef::Wordwrap);
                 fields.push(FieldRef::Timestamps);
                 fields.push(FieldRef::TextCompact);
+                if !is_bounty {
+                    fields.push(FieldRef::TeeFile);
+                }
             }
             WindowDef::Inventory { .. } | WindowDef::Reserve { .. } => {
                 // No Timestamps here: timestamps are for chatter-style text
@@ -1672,12 +1760,14 @@ impl WindowEditor {
             WindowDef::Hotkeybar { .. } => {}
             WindowDef::TabbedText { .. } => {
                 fields.push(FieldRef::TabBarPosition);
+                fields.push(FieldRef::TabBarWidth);
                 fields.push(FieldRef::TabSeparator);
                 fields.push(FieldRef::TabUnreadPrefix);
                 fields.push(FieldRef::EditTabs);
                 fields.push(FieldRef::TabActiveColor);
                 fields.push(FieldRef::TabInactiveColor);
                 fields.push(FieldRef::TabUnreadColor);
+                fields.push(FieldRef::TeeFile);
             }
             WindowDef::Room { .. } => {
                 fields.push(FieldRef::ShowName);
@@ -1791,6 +1881,45 @@ impl WindowEditor {
                 fields.push(FieldRef::BetrayerShowItems);
                 fields.push(FieldRef::BetrayerBarColor);
             }
+            WindowDef::Stance { .. } => {
+                // Stance widget - bar and posture row toggles
+                fields.push(FieldRef::StanceShowBar);
+                fields.push(FieldRef::StanceShowPosture);
+            }
+            WindowDef::Wealth { .. } => {
+                fields.push(FieldRef::WealthShowSession);
+            }
+            WindowDef::TaskTracker { .. } => {
+                fields.push(FieldRef::TaskTrackerShowProgress);
+                fields.push(FieldRef::TaskTrackerNotify);
+            }
+            WindowDef::SocietyTasks { .. } => {
+                fields.push(FieldRef::SocietyTasksShowProgress);
+                fields.push(FieldRef::SocietyTasksNotify);
+            }
+            WindowDef::CombatStats { .. } => {
+                // Creature row count lives in the layout TOML (`creatures`)
+            }
+            WindowDef::Calendar { .. } => {
+                // Moons live in the layout TOML (`moons`)
+                fields.push(FieldRef::CalendarShowWeekday);
+                fields.push(FieldRef::CalendarShowLocalTime);
+            }
+            WindowDef::Reminders { .. } => {
+                fields.push(FieldRef::RemindersShowRepeat);
+            }
+            WindowDef::Notes { .. } => {
+                fields.push(FieldRef::NotesSection);
+            }
+            WindowDef::Watch { .. } => {
+                // Rows are managed with .watch (or `watches` in the layout TOML)
+            }
+            WindowDef::Traffic { .. } => {
+                // Hex view is toggled with .traffic hex
+            }
+            WindowDef::Played { .. } => {
+                // show_total is set in the layout TOML
+            }
             WindowDef::WebUi { .. } => {
                 // Page binding is set by .webui; nothing editable beyond base
             }
@@ -1926,15 +2055,25 @@ impl WindowEditor {
         let mut text_color_input = Self::create_textarea();
         let mut prompt_icon_input = Self::create_textarea();
         let mut prompt_icon_color_input = Self::create_textarea();
+        let mut prompt_template_input = Self::create_textarea();
+        let mut notes_section_input = Self::create_textarea();
+        if let crate::config::WindowDef::Notes { data, .. } = &window_def {
+            notes_section_input.insert_str(&data.section);
+        }
         let mut cursor_color_input = Self::create_textarea();
         let mut cursor_bg_input = Self::create_textarea();
         let mut tab_bar_position_input = Self::create_textarea();
         let mut title_position_input = Self::create_textarea();
         title_position_input.insert_str(&window_def.base().title_position);
+        let mut tee_file_input = Self::create_textarea();
+        if let Some(ref file) = window_def.base().tee_file {
+            tee_file_input.insert_str(file);
+        }
         let mut tab_active_color_input = Self::create_textarea();
         let mut tab_inactive_color_input = Self::create_textarea();
         let mut tab_unread_color_input = Self::create_textarea();
         let mut tab_unread_prefix_input = Self::create_textarea();
+        let mut tab_bar_width_input = Self::create_textarea();
         let mut tab_separator = false;
         let mut progress_id_input = Self::create_textarea();
         let mut progress_color_input = Self::create_textarea();
@@ -1998,6 +2137,9 @@ impl WindowEditor {
             if let Some(ref color) = data.prompt_icon_color {
                 prompt_icon_color_input.insert_str(color);
             }
+            if let Some(ref template) = data.prompt_template {
+                prompt_template_input.insert_str(template);
+            }
             if let Some(ref color) = data.cursor_color {
                 cursor_color_input.insert_str(color);
             }
@@ -2021,6 +2163,7 @@ impl WindowEditor {
             if let Some(ref prefix) = data.tab_unread_prefix {
                 tab_unread_prefix_input.insert_str(prefix);
             }
+            tab_bar_width_input.insert_str(data.tab_bar_width.to_string());
         }
 
         if let crate::config::WindowDef::Progress { data, .. } = &window_def {
@@ -2271,6 +2414,36 @@ impl WindowEditor {
         let mut betrayer_bar_color_input = Self::create_textarea();
         betrayer_bar_color_input.insert_str(&betrayer_bar_color);
 
+        // Stance widget fields
+        let (stance_show_bar, stance_show_posture) =
+            if let crate::config::WindowDef::Stance { data, .. } = &window_def {
+                (data.show_bar, data.show_posture)
+            } else {
+                (true, true)
+            };
+        let wealth_show_session = match &window_def {
+            crate::config::WindowDef::Wealth { data, .. } => data.show_session,
+            _ => true,
+        };
+        let (task_tracker_show_progress, task_tracker_notify) = match &window_def {
+            crate::config::WindowDef::TaskTracker { data, .. } => (data.show_progress, data.notify),
+            _ => (true, true),
+        };
+        let (society_tasks_show_progress, society_tasks_notify) = match &window_def {
+            crate::config::WindowDef::SocietyTasks { data, .. } => (data.show_progress, data.notify),
+            _ => (true, true),
+        };
+        let (calendar_show_weekday, calendar_show_local_time) = match &window_def {
+            crate::config::WindowDef::Calendar { data, .. } => {
+                (data.show_weekday, data.show_local_time)
+            }
+            _ => (true, false),
+        };
+        let reminders_show_repeat = match &window_def {
+            crate::config::WindowDef::Reminders { data, .. } => data.show_repeat,
+            _ => true,
+        };
+
         let mut content_align_input = Self::create_textarea();
         if let Some(ref align) = window_def.base().content_align {
             content_align_input.insert_str(align);
@@ -2309,15 +2482,19 @@ impl WindowEditor {
             text_color_input,
             prompt_icon_input,
             prompt_icon_color_input,
+            prompt_template_input,
+            notes_section_input,
             cursor_color_input,
             cursor_bg_input,
             content_align_input,
+            tee_file_input,
             tab_bar_position_input,
             title_position_input,
             tab_active_color_input,
             tab_inactive_color_input,
             tab_unread_color_input,
             tab_unread_prefix_input,
+            tab_bar_width_input,
             tab_separator,
             progress_id_input,
             progress_color_input,
@@ -2388,6 +2565,16 @@ impl WindowEditor {
             minivitals_spirit_color_input,
             betrayer_show_items,
             betrayer_bar_color_input,
+            stance_show_bar,
+            stance_show_posture,
+            wealth_show_session,
+            task_tracker_show_progress,
+            task_tracker_notify,
+            society_tasks_show_progress,
+            society_tasks_notify,
+            calendar_show_weekday,
+            calendar_show_local_time,
+            reminders_show_repeat,
             text_compact,
             targets_show_arms_count,
             targets_status_position,
@@ -2451,6 +2638,10 @@ impl WindowEditor {
             max_cols: None,
             visible: true,
             content_align: None,
+            floating: false,
+            z_index: 0,
+            tee_file: None,
+            show_when: None,
         };
 
         // Create window_def based on widget type
@@ -2464,6 +2655,9 @@ impl WindowEditor {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             },
             "room" => WindowDef::Room {
@@ -2515,6 +2709,9 @@ impl WindowEditor {
                     show_timestamps: false,
                     timestamp_position: None,
                     compact: false,
+                    collapse_repeats: false,
+                    coalesce_seconds: 0,
+                    conversation: false,
                 },
             },
         };
@@ -2551,6 +2748,8 @@ impl WindowEditor {
         let text_color_input = Self::create_textarea();
         let prompt_icon_input = Self::create_textarea();
         let prompt_icon_color_input = Self::create_textarea();
+        let prompt_template_input = Self::create_textarea();
+        let notes_section_input = Self::create_textarea();
         let cursor_color_input = Self::create_textarea();
         let cursor_bg_input = Self::create_textarea();
         let content_align_input = Self::create_textarea();
@@ -2558,10 +2757,13 @@ impl WindowEditor {
         tab_bar_position_input.insert_str("top");
         let mut title_position_input = Self::create_textarea();
         title_position_input.insert_str("top-left");
+        let tee_file_input = Self::create_textarea();
         let tab_active_color_input = Self::create_textarea();
         let tab_inactive_color_input = Self::create_textarea();
         let tab_unread_color_input = Self::create_textarea();
         let tab_unread_prefix_input = Self::create_textarea();
+        let mut tab_bar_width_input = Self::create_textarea();
+        tab_bar_width_input.insert_str("12");
         let tab_separator = false;
         let mut progress_id_input = Self::create_textarea();
         if let crate::config::WindowDef::Progress { .. } = &window_def {
@@ -2654,15 +2856,19 @@ impl WindowEditor {
             text_color_input,
             prompt_icon_input,
             prompt_icon_color_input,
+            prompt_template_input,
+            notes_section_input,
             cursor_color_input,
             cursor_bg_input,
             content_align_input,
+            tee_file_input,
             tab_bar_position_input,
             title_position_input,
             tab_active_color_input,
             tab_inactive_color_input,
             tab_unread_color_input,
             tab_unread_prefix_input,
+            tab_bar_width_input,
             tab_separator,
             progress_id_input,
             progress_color_input,
@@ -2733,6 +2939,16 @@ impl WindowEditor {
             minivitals_spirit_color_input: Self::create_textarea(),
             betrayer_show_items: true,
             betrayer_bar_color_input: Self::create_textarea(),
+            stance_show_bar: true,
+            stance_show_posture: true,
+            wealth_show_session: true,
+            task_tracker_show_progress: true,
+            task_tracker_notify: true,
+            society_tasks_show_progress: true,
+            society_tasks_notify: true,
+            calendar_show_weekday: true,
+            calendar_show_local_time: false,
+            reminders_show_repeat: true,
             text_compact,
             targets_show_arms_count,
             targets_status_position,
@@ -3187,6 +3403,16 @@ impl WindowEditor {
                     | FieldRef::MiniVitalsNumbersOnly
                     | FieldRef::MiniVitalsCurrentOnly
                     | FieldRef::BetrayerShowItems
+                    | FieldRef::StanceShowBar
+                    | FieldRef::StanceShowPosture
+                    | FieldRef::WealthShowSession
+                    | FieldRef::TaskTrackerShowProgress
+                    | FieldRef::TaskTrackerNotify
+                    | FieldRef::SocietyTasksShowProgress
+                    | FieldRef::SocietyTasksNotify
+                    | FieldRef::CalendarShowWeekday
+                    | FieldRef::CalendarShowLocalTime
+                    | FieldRef::RemindersShowRepeat
                     | FieldRef::TextCompact
                     | FieldRef::TargetsShowAppendages
                     | FieldRef::TargetsStatusPosition
@@ -3294,7 +3520,7 @@ impl WindowEditor {
 
     /// Cycle to the next/previous border style
     pub fn cycle_border_style(&mut self, reverse: bool) {
-        let options = ["single", "double", "rounded", "thick"];
+        let options = ["single", "double", "rounded", "thick", "ascii"];
         let current = &self.window_def.base().border_style;
         let len = options.len();
         let current_idx = options
@@ -3380,6 +3606,8 @@ impl WindowEditor {
             .unwrap_or("top")
         {
             "top" => "bottom",
+            "bottom" => "left",
+            "left" => "right",
             _ => "top",
         };
         let mut ta = Self::create_textarea();
@@ -3414,175 +3642,181 @@ impl WindowEditor {
         let id = self.focused_field;
         match id {
             _ if id == FieldRef::Name.legacy_field_id() => {
-                self.name_input.input(input);
+                self.name_input.input_graphemes(input);
             }
             _ if id == FieldRef::Title.legacy_field_id() => {
-                self.title_input.input(input);
+                self.title_input.input_graphemes(input);
             }
             _ if id == FieldRef::Row.legacy_field_id() => {
-                self.row_input.input(input);
+                self.row_input.input_graphemes(input);
             }
             _ if id == FieldRef::Col.legacy_field_id() => {
-                self.col_input.input(input);
+                self.col_input.input_graphemes(input);
             }
             _ if id == FieldRef::Rows.legacy_field_id() => {
-                self.rows_input.input(input);
+                self.rows_input.input_graphemes(input);
             }
             _ if id == FieldRef::Cols.legacy_field_id() => {
-                self.cols_input.input(input);
+                self.cols_input.input_graphemes(input);
             }
             _ if id == FieldRef::MinRows.legacy_field_id() => {
-                self.min_rows_input.input(input);
+                self.min_rows_input.input_graphemes(input);
             }
             _ if id == FieldRef::MinCols.legacy_field_id() => {
-                self.min_cols_input.input(input);
+                self.min_cols_input.input_graphemes(input);
             }
             _ if id == FieldRef::MaxRows.legacy_field_id() => {
-                self.max_rows_input.input(input);
+                self.max_rows_input.input_graphemes(input);
             }
             _ if id == FieldRef::MaxCols.legacy_field_id() => {
-                self.max_cols_input.input(input);
+                self.max_cols_input.input_graphemes(input);
             }
             _ if id == FieldRef::BgColor.legacy_field_id() => {
-                self.bg_color_input.input(input);
+                self.bg_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::BorderColor.legacy_field_id() => {
-                self.border_color_input.input(input);
+                self.border_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Streams.legacy_field_id() => {
-                self.streams_input.input(input);
+                self.streams_input.input_graphemes(input);
             }
             _ if id == FieldRef::TextColor.legacy_field_id() => {
-                self.text_color_input.input(input);
+                self.text_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::CursorColor.legacy_field_id() => {
-                self.cursor_color_input.input(input);
+                self.cursor_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::CursorBg.legacy_field_id() => {
-                self.cursor_bg_input.input(input);
+                self.cursor_bg_input.input_graphemes(input);
             }
             _ if id == FieldRef::ContentAlign.legacy_field_id() => {
-                self.content_align_input.input(input);
+                self.content_align_input.input_graphemes(input);
             }
             _ if id == FieldRef::TabBarPosition.legacy_field_id() => {
-                self.tab_bar_position_input.input(input);
+                self.tab_bar_position_input.input_graphemes(input);
             }
             _ if id == FieldRef::TitlePosition.legacy_field_id() => {
-                self.title_position_input.input(input);
+                self.title_position_input.input_graphemes(input);
             }
             _ if id == FieldRef::TabActiveColor.legacy_field_id() => {
-                self.tab_active_color_input.input(input);
+                self.tab_active_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::TabInactiveColor.legacy_field_id() => {
-                self.tab_inactive_color_input.input(input);
+                self.tab_inactive_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::TabUnreadColor.legacy_field_id() => {
-                self.tab_unread_color_input.input(input);
+                self.tab_unread_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::TabUnreadPrefix.legacy_field_id() => {
-                self.tab_unread_prefix_input.input(input);
+                self.tab_unread_prefix_input.input_graphemes(input);
+            }
+            _ if id == FieldRef::TabBarWidth.legacy_field_id() => {
+                self.tab_bar_width_input.input_graphemes(input);
+            }
+            _ if id == FieldRef::TeeFile.legacy_field_id() => {
+                self.tee_file_input.input_graphemes(input);
             }
             _ if id == FieldRef::ProgressId.legacy_field_id() => {
-                self.progress_id_input.input(input);
+                self.progress_id_input.input_graphemes(input);
             }
             _ if id == FieldRef::ProgressColor.legacy_field_id() => {
-                self.progress_color_input.input(input);
+                self.progress_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::CountdownId.legacy_field_id() => {
-                self.countdown_id_input.input(input);
+                self.countdown_id_input.input_graphemes(input);
             }
             _ if id == FieldRef::CountdownIcon.legacy_field_id() => {
-                self.countdown_icon_input.input(input);
+                self.countdown_icon_input.input_graphemes(input);
             }
             _ if id == FieldRef::CountdownColor.legacy_field_id() => {
-                self.countdown_color_input.input(input);
+                self.countdown_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::CountdownBgColor.legacy_field_id() => {
-                self.countdown_bg_color_input.input(input);
+                self.countdown_bg_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::HandIcon.legacy_field_id() => {
-                self.hand_icon_input.input(input);
+                self.hand_icon_input.input_graphemes(input);
             }
             _ if id == FieldRef::HandIconColor.legacy_field_id() => {
-                self.hand_icon_color_input.input(input);
+                self.hand_icon_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::HandTextColor.legacy_field_id() => {
-                self.hand_text_color_input.input(input);
+                self.hand_text_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::CompassActiveColor.legacy_field_id() => {
-                self.compass_active_color_input.input(input);
+                self.compass_active_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::CompassInactiveColor.legacy_field_id() => {
-                self.compass_inactive_color_input.input(input);
+                self.compass_inactive_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::InjuryDefaultColor.legacy_field_id() => {
-                self.injury_default_color_input.input(input);
+                self.injury_default_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Injury1Color.legacy_field_id() => {
-                self.injury1_color_input.input(input);
+                self.injury1_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Injury2Color.legacy_field_id() => {
-                self.injury2_color_input.input(input);
+                self.injury2_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Injury3Color.legacy_field_id() => {
-                self.injury3_color_input.input(input);
+                self.injury3_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Scar1Color.legacy_field_id() => {
-                self.scar1_color_input.input(input);
+                self.scar1_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Scar2Color.legacy_field_id() => {
-                self.scar2_color_input.input(input);
+                self.scar2_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::Scar3Color.legacy_field_id() => {
-                self.scar3_color_input.input(input);
+                self.scar3_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::MiniVitalsHealthColor.legacy_field_id() => {
-                self.minivitals_health_color_input.input(input);
+                self.minivitals_health_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::MiniVitalsManaColor.legacy_field_id() => {
-                self.minivitals_mana_color_input.input(input);
+                self.minivitals_mana_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::MiniVitalsStaminaColor.legacy_field_id() => {
-                self.minivitals_stamina_color_input.input(input);
+                self.minivitals_stamina_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::MiniVitalsSpiritColor.legacy_field_id() => {
-                self.minivitals_spirit_color_input.input(input);
+                self.minivitals_spirit_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::EncumColorLight.legacy_field_id() => {
-                self.encum_color_light_input.input(input);
+                self.encum_color_light_input.input_graphemes(input);
             }
             _ if id == FieldRef::EncumColorModerate.legacy_field_id() => {
-                self.encum_color_moderate_input.input(input);
+                self.encum_color_moderate_input.input_graphemes(input);
             }
             _ if id == FieldRef::EncumColorHeavy.legacy_field_id() => {
-                self.encum_color_heavy_input.input(input);
+                self.encum_color_heavy_input.input_graphemes(input);
             }
             _ if id == FieldRef::EncumColorCritical.legacy_field_id() => {
-                self.encum_color_critical_input.input(input);
+                self.encum_color_critical_input.input_graphemes(input);
             }
             _ if id == FieldRef::GS4ExpMindBarColor.legacy_field_id() => {
-                self.gs4_exp_mind_bar_color_input.input(input);
+                self.gs4_exp_mind_bar_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::GS4ExpExpBarColor.legacy_field_id() => {
-                self.gs4_exp_exp_bar_color_input.input(input);
+                self.gs4_exp_exp_bar_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::BetrayerBarColor.legacy_field_id() => {
-                self.betrayer_bar_color_input.input(input);
+                self.betrayer_bar_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::IndicatorId.legacy_field_id() => {
-                self.indicator_id_input.input(input);
+                self.indicator_id_input.input_graphemes(input);
             }
             _ if id == FieldRef::IndicatorIcon.legacy_field_id() => {
-                self.indicator_icon_input.input(input);
+                self.indicator_icon_input.input_graphemes(input);
             }
             _ if id == FieldRef::IndicatorActiveColor.legacy_field_id() => {
-                self.indicator_active_color_input.input(input);
+                self.indicator_active_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::IndicatorInactiveColor.legacy_field_id() => {
-                self.indicator_inactive_color_input.input(input);
+                self.indicator_inactive_color_input.input_graphemes(input);
             }
             _ if id == FieldRef::ActiveEffectsCategory.legacy_field_id() => {
-                self.active_effects_category_input.input(input);
+                self.active_effects_category_input.input_graphemes(input);
             }
             _ if id == FieldRef::PerceptionSortDirection.legacy_field_id() => {
                 // Dropdown field - do not accept text input (use Enter/Space to cycle)
@@ -3597,19 +3831,25 @@ impl WindowEditor {
                 // Dropdown field - do not accept text input (use Enter/Space to cycle)
             }
             _ if id == FieldRef::DashboardSpacing.legacy_field_id() => {
-                self.dashboard_spacing_input.input(input);
+                self.dashboard_spacing_input.input_graphemes(input);
             }
             _ if id == FieldRef::BufferSize.legacy_field_id() => {
-                self.buffer_size_input.input(input);
+                self.buffer_size_input.input_graphemes(input);
             }
             _ if id == FieldRef::PromptIcon.legacy_field_id() => {
-                self.prompt_icon_input.input(input);
+                self.prompt_icon_input.input_graphemes(input);
             }
             _ if id == FieldRef::PromptIconColor.legacy_field_id() => {
-                self.prompt_icon_color_input.input(input);
+                self.prompt_icon_color_input.input_graphemes(input);
+            }
+            _ if id == FieldRef::PromptTemplate.legacy_field_id() => {
+                self.prompt_template_input.input_graphemes(input);
+            }
+            _ if id == FieldRef::NotesSection.legacy_field_id() => {
+                self.notes_section_input.input_graphemes(input);
             }
             _ if id == FieldRef::EntityId.legacy_field_id() => {
-                self.entity_id_input.input(input);
+                self.entity_id_input.input_graphemes(input);
             }
             _ => {} // Checkboxes/dropdowns don't handle text input
         }
@@ -3791,6 +4031,64 @@ impl WindowEditor {
                                 self.minivitals_numbers_only = false;
                             }
                         }
+                        FieldRef::StanceShowBar => {
+                            let prev_show = self.stance_show_bar;
+                            self.stance_show_bar = !self.stance_show_bar;
+                            self.window_def
+                                .base_mut()
+                                .apply_optional_content_row(self.stance_show_bar, prev_show);
+                            self.refresh_size_inputs();
+                        }
+                        FieldRef::StanceShowPosture => {
+                            let prev_show = self.stance_show_posture;
+                            self.stance_show_posture = !self.stance_show_posture;
+                            self.window_def
+                                .base_mut()
+                                .apply_optional_content_row(self.stance_show_posture, prev_show);
+                            self.refresh_size_inputs();
+                        }
+                        FieldRef::WealthShowSession => {
+                            let prev_show = self.wealth_show_session;
+                            self.wealth_show_session = !self.wealth_show_session;
+                            self.window_def
+                                .base_mut()
+                                .apply_optional_content_row(self.wealth_show_session, prev_show);
+                            self.refresh_size_inputs();
+                        }
+                        FieldRef::TaskTrackerShowProgress => {
+                            let prev_show = self.task_tracker_show_progress;
+                            self.task_tracker_show_progress = !self.task_tracker_show_progress;
+                            self.window_def.base_mut().apply_optional_content_row(
+                                self.task_tracker_show_progress,
+                                prev_show,
+                            );
+                            self.refresh_size_inputs();
+                        }
+                        FieldRef::TaskTrackerNotify => {
+                            self.task_tracker_notify = !self.task_tracker_notify;
+                        }
+                        FieldRef::SocietyTasksShowProgress => {
+                            // Counts are inline, so no content row to add
+                            self.society_tasks_show_progress = !self.society_tasks_show_progress;
+                        }
+                        FieldRef::SocietyTasksNotify => {
+                            self.society_tasks_notify = !self.society_tasks_notify;
+                        }
+                        FieldRef::CalendarShowWeekday => {
+                            self.calendar_show_weekday = !self.calendar_show_weekday;
+                        }
+                        FieldRef::CalendarShowLocalTime => {
+                            let prev_show = self.calendar_show_local_time;
+                            self.calendar_show_local_time = !self.calendar_show_local_time;
+                            self.window_def.base_mut().apply_optional_content_row(
+                                self.calendar_show_local_time,
+                                prev_show,
+                            );
+                            self.refresh_size_inputs();
+                        }
+                        FieldRef::RemindersShowRepeat => {
+                            self.reminders_show_repeat = !self.reminders_show_repeat;
+                        }
                         FieldRef::BetrayerShowItems => {
                             let prev_show = self.betrayer_show_items;
                             self.betrayer_show_items = !self.betrayer_show_items;
@@ -3880,13 +4178,19 @@ impl WindowEditor {
                         (TabEditorFormField::Timestamps, true) => {
                             TabEditorFormField::IgnoreActivity
                         }
-                        (TabEditorFormField::IgnoreActivity, true) => TabEditorFormField::Name,
-                        (TabEditorFormField::Name, false) => TabEditorFormField::IgnoreActivity,
+                        (TabEditorFormField::IgnoreActivity, true) => {
+                            TabEditorFormField::AlertFlash
+                        }
+                        (TabEditorFormField::AlertFlash, true) => TabEditorFormField::Name,
+                        (TabEditorFormField::Name, false) => TabEditorFormField::AlertFlash,
                         (TabEditorFormField::Streams, false) => TabEditorFormField::Name,
                         (TabEditorFormField::Timestamps, false) => TabEditorFormField::Streams,
                         (TabEditorFormField::IgnoreActivity, false) => {
                             TabEditorFormField::Timestamps
                         }
+                        (TabEditorFormField::AlertFlash, false) => {
+                            TabEditorFormField::IgnoreActivity
+                        }
                     };
                 }
             }
@@ -4085,6 +4389,10 @@ impl WindowEditor {
                                 editor.ignore_activity = !editor.ignore_activity;
                                 return true;
                             }
+                            TabEditorFormField::AlertFlash => {
+                                editor.alert_flash = !editor.alert_flash;
+                                return true;
+                            }
                             _ => {}
                         }
                     }
@@ -4096,10 +4404,10 @@ impl WindowEditor {
                         let ev = textarea_bridge::to_textarea_event(key);
                         match editor.form_field {
                             TabEditorFormField::Name => {
-                                editor.name_input.input(ev);
+                                editor.name_input.input_graphemes(ev);
                             }
                             TabEditorFormField::Streams => {
-                                editor.streams_input.input(ev);
+                                editor.streams_input.input_graphemes(ev);
                             }
                             TabEditorFormField::Timestamps => {
                                 editor.show_timestamps = !editor.show_timestamps;
@@ -4107,6 +4415,9 @@ impl WindowEditor {
                             TabEditorFormField::IgnoreActivity => {
                                 editor.ignore_activity = !editor.ignore_activity;
                             }
+                            TabEditorFormField::AlertFlash => {
+                                editor.alert_flash = !editor.alert_flash;
+                            }
                         };
                         return true;
                     }
@@ -4180,13 +4491,13 @@ impl WindowEditor {
                         let ev = textarea_bridge::to_textarea_event(key);
                         match editor.form_field {
                             IndicatorFormField::Id => {
-                                editor.id_input.input(ev);
+                                editor.id_input.input_graphemes(ev);
                             }
                             IndicatorFormField::Icon => {
-                                editor.icon_input.input(ev);
+                                editor.icon_input.input_graphemes(ev);
                             }
                             IndicatorFormField::Colors => {
-                                editor.colors_input.input(ev);
+                                editor.colors_input.input_graphemes(ev);
                             }
                         };
                         return true;
@@ -4322,10 +4633,10 @@ impl WindowEditor {
                         let ev = textarea_bridge::to_textarea_event(key);
                         match editor.form_field {
                             TextReplacementsFormField::Pattern => {
-                                editor.pattern_input.input(ev);
+                                editor.pattern_input.input_graphemes(ev);
                             }
                             TextReplacementsFormField::Replace => {
-                                editor.replace_input.input(ev);
+                                editor.replace_input.input_graphemes(ev);
                             }
                         };
                         return true;
@@ -4429,7 +4740,7 @@ impl WindowEditor {
                             crossterm_bridge::to_crossterm_modifiers(key_event.modifiers);
                         let key = crossterm::event::KeyEvent::new(ct_code, ct_mods);
                         let ev = textarea_bridge::to_textarea_event(key);
-                        editor.color_input.input(ev);
+                        editor.color_input.input_graphemes(ev);
                         return true;
                     }
                 },
@@ -4477,6 +4788,17 @@ impl WindowEditor {
             .unwrap_or_else(|| "top-left".to_string());
         self.window_def.base_mut().content_align =
             Some(self.content_align_input.lines()[0].to_string()).filter(|s| !s.is_empty());
+        if matches!(
+            self.window_def,
+            crate::config::WindowDef::Text { .. } | crate::config::WindowDef::TabbedText { .. }
+        ) {
+            self.window_def.base_mut().tee_file = self
+                .tee_file_input
+                .lines()
+                .first()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty());
+        }
 
         // Update streams only for Text variant
         if let crate::config::WindowDef::Text { data, .. } = &mut self.window_def {
@@ -4548,6 +4870,13 @@ impl WindowEditor {
                 .get(0)
                 .map(|s| s.trim().to_string())
                 .filter(|s| !s.is_empty());
+            data.tab_bar_width = self
+                .tab_bar_width_input
+                .lines()
+                .first()
+                .and_then(|s| s.trim().parse::<u16>().ok())
+                .filter(|width| *width > 0)
+                .unwrap_or(data.tab_bar_width);
             data.tab_separator = self.tab_separator;
         }
 
@@ -4664,6 +4993,38 @@ impl WindowEditor {
                 .filter(|s| !s.is_empty());
         }
 
+        if let crate::config::WindowDef::Stance { data, .. } = &mut self.window_def {
+            data.show_bar = self.stance_show_bar;
+            data.show_posture = self.stance_show_posture;
+        }
+
+        if let crate::config::WindowDef::Wealth { data, .. } = &mut self.window_def {
+            data.show_session = self.wealth_show_session;
+        }
+
+        if let crate::config::WindowDef::TaskTracker { data, .. } = &mut self.window_def {
+            data.show_progress = self.task_tracker_show_progress;
+            data.notify = self.task_tracker_notify;
+        }
+
+        if let crate::config::WindowDef::SocietyTasks { data, .. } = &mut self.window_def {
+            data.show_progress = self.society_tasks_show_progress;
+            data.notify = self.society_tasks_notify;
+        }
+
+        if let crate::config::WindowDef::Calendar { data, .. } = &mut self.window_def {
+            data.show_weekday = self.calendar_show_weekday;
+            data.show_local_time = self.calendar_show_local_time;
+        }
+
+        if let crate::config::WindowDef::Reminders { data, .. } = &mut self.window_def {
+            data.show_repeat = self.reminders_show_repeat;
+        }
+
+        if let crate::config::WindowDef::Notes { data, .. } = &mut self.window_def {
+            data.section = self.notes_section_input.lines()[0].trim().to_string();
+        }
+
         if let crate::config::WindowDef::Betrayer { data, .. } = &mut self.window_def {
             data.show_items = self.betrayer_show_items;
             data.bar_color = self.betrayer_bar_color_input
@@ -4859,6 +5220,8 @@ impl WindowEditor {
             data.prompt_icon_color =
                 Some(self.prompt_icon_color_input.lines()[0].trim().to_string())
                     .filter(|s| !s.is_empty());
+            data.prompt_template = Some(self.prompt_template_input.lines()[0].trim().to_string())
+                .filter(|s| !s.is_empty());
             data.text_color =
                 Some(self.text_color_input.lines()[0].trim().to_string()).filter(|s| !s.is_empty());
             data.cursor_color = Some(self.cursor_color_input.lines()[0].trim().to_string())
@@ -5127,25 +5490,29 @@ impl WindowEditor {
             " Edit Window "
         };
 
+        let border_set = super::border_glyphs::popup_set();
         let block = Block::default()
             .borders(Borders::ALL)
+            .border_set(border_set)
             .title(title)
             .style(Style::default().bg(Color::Black).fg(crossterm_bridge::to_ratatui_color(theme.border_color)));
         block.render(popup_area, buf);
 
         // Draw combined bottom border with footer hints
         let inner_width = popup_area.width.saturating_sub(2);
-        let help = self.footer_help_text();
+        // Hints are joined with "─"; draw the joins in the border's glyph
+        let help = self
+            .footer_help_text()
+            .replace('─', border_set.horizontal_bottom);
         // Use chars().count() not len() - help contains multi-byte Unicode chars like "─"
         let pad_len = inner_width.saturating_sub(1 + help.chars().count() as u16) as usize;
-        let pad = "─".repeat(pad_len);
-        let mut interior = String::from("─");
-        interior.push_str(help);
+        let pad = border_set.horizontal_bottom.repeat(pad_len);
+        let mut interior = String::from(border_set.horizontal_bottom);
+        interior.push_str(&help);
         interior.push_str(&pad);
-        let mut footer_line = String::new();
-        footer_line.push('└');
+        let mut footer_line = String::from(border_set.bottom_left);
         footer_line.push_str(&interior.chars().take(inner_width as usize).collect::<String>());
-        footer_line.push('┘');
+        footer_line.push_str(border_set.bottom_right);
         buf.set_string(
             popup_area.x,
             popup_area.y + popup_area.height.saturating_sub(1),
@@ -5440,6 +5807,16 @@ impl WindowEditor {
                     theme,
                     matches!(editor.form_field, TabEditorFormField::IgnoreActivity),
                 );
+
+                self.render_tab_editor_checkbox(
+                    "Alert Flash",
+                    editor.alert_flash,
+                    area.x + 1,
+                    y + 4,
+                    buf,
+                    theme,
+                    matches!(editor.form_field, TabEditorFormField::AlertFlash),
+                );
             }
         }
     }
@@ -5483,8 +5860,8 @@ impl WindowEditor {
                     let enabled_marker = if ind.enabled { "[x]" } else { "[ ]" };
                     let mut line = format!("{}{} {} {}", prefix, enabled_marker, icon, ind.id);
                     let max_width = area.width.saturating_sub(2) as usize;
-                    if line.chars().count() > max_width {
-                        line = line.chars().take(max_width).collect();
+                    if text_width::width(&line) > max_width {
+                        line = text_width::truncate(&line, max_width).to_string();
                     }
                     let mut style = Style::default().fg(color);
                     if !ind.enabled {
@@ -5597,7 +5974,7 @@ impl WindowEditor {
                 None => format!("{}{}", prefix, id),
             };
             let text = self.truncate_to_width(&line, area.width.saturating_sub(2));
-            let width = text.chars().count() as u16;
+            let width = text_width::width(&text) as u16;
             buf.set_string(area.x + 1, y, text, Style::default().fg(color));
             picker.click_areas.push((idx, y, area.x + 1, width));
         }
@@ -5736,15 +6113,7 @@ impl WindowEditor {
     }
 
     fn truncate_to_width(&self, text: &str, width: u16) -> String {
-        if width == 0 {
-            return String::new();
-        }
-        let width_usize = width as usize;
-        if text.chars().count() <= width_usize {
-            text.to_string()
-        } else {
-            text.chars().take(width_usize).collect()
-        }
+        text_width::truncate(text, width as usize).to_string()
     }
 
     fn render_fields(&mut self, area: Rect, buf: &mut Buffer, theme: &EditorTheme) {
@@ -6151,6 +6520,22 @@ impl WindowEditor {
                     is_focus(FieldRef::CursorBg, self.focused_field),
                 );
                 self.field_click_areas.push((special_row, right_x, FieldRef::CursorBg));
+                special_row += 1;
+
+                // Prompt template ("{rt} {status}>") replaces the icon when set
+                self.render_textarea_compact(
+                    FieldRef::PromptTemplate.legacy_field_id(),
+                    "Prompt:",
+                    &self.prompt_template_input,
+                    left_x,
+                    special_row,
+                    24,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::PromptTemplate, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, left_x, FieldRef::PromptTemplate));
             }
             WindowDef::Text { .. } => {
                 // Bounty window is special: hide Streams and BufferSize
@@ -6261,6 +6646,20 @@ impl WindowEditor {
                         is_focus(FieldRef::TextCompact, self.focused_field),
                     );
                     self.field_click_areas.push((special_row, left_x, FieldRef::TextCompact));
+                    // Plain-text copy of the window's lines (see core::tee)
+                    self.render_textarea_compact(
+                        FieldRef::TeeFile.legacy_field_id(),
+                        "Tee File:",
+                        &self.tee_file_input,
+                        right_x,
+                        special_row,
+                        20,
+                        buf,
+                        theme,
+                        is_focus(FieldRef::TeeFile, self.focused_field),
+                    );
+                    self.field_click_areas
+                        .push((special_row, right_x, FieldRef::TeeFile));
                 }
             }
             WindowDef::Inventory { .. } | WindowDef::Reserve { .. } => {
@@ -6466,6 +6865,34 @@ impl WindowEditor {
                     is_focus(FieldRef::EditTabs, self.focused_field),
                 );
                 self.field_click_areas.push((special_row, special_left_x, FieldRef::EditTabs));
+                // Label column width for a left/right tab bar
+                self.render_textarea_compact(
+                    FieldRef::TabBarWidth.legacy_field_id(),
+                    "Tab Width:",
+                    &self.tab_bar_width_input,
+                    right_x,
+                    special_row,
+                    4,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::TabBarWidth, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, right_x, FieldRef::TabBarWidth));
+                special_row += 1;
+                self.render_textarea_compact(
+                    FieldRef::TeeFile.legacy_field_id(),
+                    "Tee File:",
+                    &self.tee_file_input,
+                    special_left_x,
+                    special_row,
+                    20,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::TeeFile, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, special_left_x, FieldRef::TeeFile));
             }
             WindowDef::Room { .. } => {
                 self.render_checkbox_compact(
@@ -7177,6 +7604,173 @@ impl WindowEditor {
                     is_focus(FieldRef::BetrayerBarColor, self.focused_field),
                 );
             }
+            WindowDef::Stance { .. } => {
+                self.render_checkbox_compact(
+                    FieldRef::StanceShowBar.legacy_field_id(),
+                    "Show Bar",
+                    self.stance_show_bar,
+                    left_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::StanceShowBar, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, left_x, FieldRef::StanceShowBar));
+                self.render_checkbox_compact(
+                    FieldRef::StanceShowPosture.legacy_field_id(),
+                    "Show Posture",
+                    self.stance_show_posture,
+                    right_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::StanceShowPosture, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, right_x, FieldRef::StanceShowPosture));
+            }
+            WindowDef::Wealth { .. } => {
+                self.render_checkbox_compact(
+                    FieldRef::WealthShowSession.legacy_field_id(),
+                    "Show Session",
+                    self.wealth_show_session,
+                    left_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::WealthShowSession, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, left_x, FieldRef::WealthShowSession));
+            }
+            WindowDef::TaskTracker { .. } => {
+                self.render_checkbox_compact(
+                    FieldRef::TaskTrackerShowProgress.legacy_field_id(),
+                    "Show Progress",
+                    self.task_tracker_show_progress,
+                    left_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::TaskTrackerShowProgress, self.focused_field),
+                );
+                self.field_click_areas.push((
+                    special_row,
+                    left_x,
+                    FieldRef::TaskTrackerShowProgress,
+                ));
+                self.render_checkbox_compact(
+                    FieldRef::TaskTrackerNotify.legacy_field_id(),
+                    "Notify on Complete",
+                    self.task_tracker_notify,
+                    right_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::TaskTrackerNotify, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, right_x, FieldRef::TaskTrackerNotify));
+            }
+            WindowDef::SocietyTasks { .. } => {
+                self.render_checkbox_compact(
+                    FieldRef::SocietyTasksShowProgress.legacy_field_id(),
+                    "Show Counts",
+                    self.society_tasks_show_progress,
+                    left_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::SocietyTasksShowProgress, self.focused_field),
+                );
+                self.field_click_areas.push((
+                    special_row,
+                    left_x,
+                    FieldRef::SocietyTasksShowProgress,
+                ));
+                self.render_checkbox_compact(
+                    FieldRef::SocietyTasksNotify.legacy_field_id(),
+                    "Notify on Complete",
+                    self.society_tasks_notify,
+                    right_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::SocietyTasksNotify, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, right_x, FieldRef::SocietyTasksNotify));
+            }
+            WindowDef::Calendar { .. } => {
+                self.render_checkbox_compact(
+                    FieldRef::CalendarShowWeekday.legacy_field_id(),
+                    "Show Weekday",
+                    self.calendar_show_weekday,
+                    left_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::CalendarShowWeekday, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, left_x, FieldRef::CalendarShowWeekday));
+                self.render_checkbox_compact(
+                    FieldRef::CalendarShowLocalTime.legacy_field_id(),
+                    "Show Local Time",
+                    self.calendar_show_local_time,
+                    right_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::CalendarShowLocalTime, self.focused_field),
+                );
+                self.field_click_areas.push((
+                    special_row,
+                    right_x,
+                    FieldRef::CalendarShowLocalTime,
+                ));
+            }
+            WindowDef::Reminders { .. } => {
+                self.render_checkbox_compact(
+                    FieldRef::RemindersShowRepeat.legacy_field_id(),
+                    "Show Repeat",
+                    self.reminders_show_repeat,
+                    left_x,
+                    special_row,
+                    column_width,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::RemindersShowRepeat, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, left_x, FieldRef::RemindersShowRepeat));
+            }
+            WindowDef::Notes { .. } => {
+                // Blank shows the whole notes file
+                self.render_textarea_compact(
+                    FieldRef::NotesSection.legacy_field_id(),
+                    "Section:",
+                    &self.notes_section_input,
+                    left_x,
+                    special_row,
+                    24,
+                    buf,
+                    theme,
+                    is_focus(FieldRef::NotesSection, self.focused_field),
+                );
+                self.field_click_areas
+                    .push((special_row, left_x, FieldRef::NotesSection));
+            }
             _ => {
                 buf.set_string(
                     left_x,
@@ -7511,6 +8105,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7546,6 +8141,10 @@ mod tests {
                 max_cols: None,
                 visible: true,
                 content_align: None,
+                floating: false,
+                z_index: 0,
+                tee_file: None,
+                show_when: None,
                 title_position: "top-left".to_string(),
             },
             data: SpacerWidgetData {},
@@ -7557,6 +8156,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7575,6 +8175,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7593,6 +8194,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7611,6 +8213,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7630,6 +8233,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7660,6 +8264,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
 
@@ -7716,6 +8321,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
         let mut editor = WindowEditor::new_window_with_layout("text_custom".to_string(), &layout);
@@ -7736,6 +8342,7 @@ mod tests {
             terminal_height: None,
             base_layout: None,
             theme: None,
+            keybind_profile: None,
             unknown_windows: Vec::new(),
         };
         let mut editor = WindowEditor::new_window_with_layout("text_custom".to_string(), &layout);
@@ -764,7 +764,7 @@ mod tests {
     fn text_delta_uses_line_seq_and_expected_shape() {
         let line = Arc::new(StyledLine {
             segments: vec![TextSegment::plain("hi")],
-            stream: "main".to_string(),
+            stream: "main".into(),
             timestamp: None,
         });
         let d = RemoteDelta::Text(RemoteLine {
@@ -985,7 +985,7 @@ mod tests {
             stream: "main".to_string(),
             line: Arc::new(StyledLine {
                 segments: vec![TextSegment::plain("x")],
-                stream: "main".to_string(),
+                stream: "main".into(),
                 timestamp: None,
             }),
         }];
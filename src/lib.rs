@@ -2,11 +2,14 @@
 
 pub mod clipboard;
 pub mod cmdlist;
+pub mod command_pipe;
 pub mod config;
+pub mod control;
 pub mod core;
 pub mod data;
 pub mod frontend;
 pub mod network;
+pub mod parse_worker;
 pub mod parser;
 pub mod performance;
 pub mod platform;
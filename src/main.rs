@@ -4,12 +4,15 @@
 
 mod clipboard;
 mod cmdlist;
+mod command_pipe;
 mod config;
+mod control;
 mod core;
 mod data;
 mod frontend;
 mod migrate;
 mod network;
+mod parse_worker;
 mod parser;
 mod performance;
 mod platform;
@@ -109,6 +112,17 @@ struct Cli {
     #[arg(long, value_name = "PORT", help = config::profiles::help::WEB_PORT)]
     web_port: Option<u16>,
 
+    /// Play a bundled scripted session instead of connecting: explore
+    /// layouts, highlights and widgets without an account. Uses the "demo"
+    /// profile unless --profile/--character is given.
+    #[arg(long, conflicts_with_all = ["direct", "launch_profile", "key"])]
+    demo: bool,
+
+    /// Script for --demo instead of the bundled session (see
+    /// defaults/demo/session.xml for the format)
+    #[arg(long, value_name = "FILE", requires = "demo")]
+    demo_script: Option<PathBuf>,
+
     /// Setup terminal palette on startup using .setpalette (use with --color-mode slot)
     #[arg(long, help = config::profiles::help::SETUP_PALETTE)]
     setup_palette: bool,
@@ -122,7 +136,9 @@ enum FrontendType {
     Tui,
     Gui,
     /// Core + web server only, no local UI — a browser (or the Android
-    /// WebView shell) at /play is the interface.
+    /// WebView shell) at /play is the interface. `web` is accepted as an
+    /// alias.
+    #[value(alias = "web")]
     Headless,
 }
 
@@ -199,6 +215,13 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+
+    /// Send a command to the instance running on --profile/--character
+    Send {
+        /// Command to run, exactly as if typed (words are joined with spaces)
+        #[arg(required = true, value_name = "COMMAND")]
+        command: Vec<String>,
+    },
 }
 
 fn main() -> Result<()> {
@@ -383,6 +406,18 @@ fn main() -> Result<()> {
 
                 return Ok(());
             }
+
+            Commands::Send { command } => {
+                if let Some(data_dir) = &cli.data_dir {
+                    std::env::set_var("VELLUM_FE_DIR", data_dir);
+                }
+                let profile = cli.profile.as_deref().or(cli.character.as_deref());
+                if let Err(e) = control::send(profile, &command.join(" ")) {
+                    eprintln!("✗ Send failed: {:#}", e);
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
         }
     }
 
@@ -410,6 +445,11 @@ fn main() -> Result<()> {
         None => None,
     };
 
+    // A demo keeps its settings and layout apart from real profiles
+    if cli.demo && cli.profile.is_none() && cli.character.is_none() {
+        cli.profile = Some("demo".to_string());
+    }
+
     // Load configuration
     // Profile (for config directory) uses --profile if specified, otherwise falls back to --character
     let profile = cli.profile.as_deref().or(cli.character.as_deref());
@@ -418,6 +458,8 @@ fn main() -> Result<()> {
     } else {
         config::Config::load_with_options(profile, cli.port)?
     };
+    // [paths]: sounds directory and environment for this session's profile
+    config.apply_profile_paths();
 
     // Apply CLI flag overrides (CLI takes precedence over config.toml)
     if let Some(port) = cli.port {
@@ -436,6 +478,16 @@ fn main() -> Result<()> {
         config.web.enabled = true;
         config.web.port = web_port;
     }
+    if cli.demo {
+        let script = match &cli.demo_script {
+            Some(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read demo script {}", path.display()))?,
+            None => network::demo::BUNDLED_SCRIPT.to_string(),
+        };
+        // Fail now rather than inside the UI
+        network::demo::DemoScript::parse(&script).context("Invalid demo script")?;
+        config.demo_script = Some(script);
+    }
     // Store setup_palette flag for frontend to use after initialization
     let setup_palette = cli.setup_palette;
 
@@ -151,6 +151,7 @@ fn process_file(path: &Path, out_dir: &Path, dry_run: bool, verbose: bool) -> Re
         terminal_height: layout.terminal_height,
         base_layout: None,
         theme: None,
+        keybind_profile: None,
         unknown_windows: Vec::new(),
     };
 
@@ -387,6 +388,8 @@ fn apply_widget_specific_fields(window: &mut WindowDef, table: &toml::value::Tab
                             show_timestamps,
                             ignore_activity,
                             timestamp_position: None,
+                            alert_sound: None,
+                            alert_flash: None,
                         })
                     })
                     .collect();
@@ -398,6 +401,8 @@ fn apply_widget_specific_fields(window: &mut WindowDef, table: &toml::value::Tab
                     show_timestamps: None,
                     ignore_activity: None,
                     timestamp_position: None,
+                    alert_sound: None,
+                    alert_flash: None,
                 }];
             }
         }
@@ -5,7 +5,7 @@
 //! decoupled from direct socket management.
 
 use anyhow::{Context, Result};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc;
 use tokio::time::{sleep, Duration as TokioDuration};
@@ -20,6 +20,12 @@ use std::sync::{mpsc as std_mpsc, Arc};
 use std::thread;
 use std::time::Duration as StdDuration;
 
+pub mod compression;
+pub mod demo;
+pub mod dial;
+pub mod proxy;
+pub mod traffic;
+
 /// Messages emitted by the TCP reader task.
 #[derive(Debug, Clone)]
 pub enum ServerMessage {
@@ -52,6 +58,39 @@ impl std::fmt::Display for AuthFailed {
 
 impl std::error::Error for AuthFailed {}
 
+/// Marker error for a failed TLS handshake with the game server (certificate
+/// rejected, server not speaking TLS on that port). Distinct from
+/// `AuthFailed`: the login worked, the transport did not, and retrying
+/// won't help until the `[connection]` TLS settings change.
+#[derive(Debug)]
+pub struct TlsFailed(pub String);
+
+impl std::fmt::Display for TlsFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for TlsFailed {}
+
+/// TLS settings for the direct game socket (`[connection] tls`)
+#[derive(Clone, Debug)]
+pub struct GameTls {
+    /// Port to connect to instead of the one in the launch ticket
+    pub port: Option<u16>,
+    /// Verify the server certificate and host name
+    pub verify: bool,
+}
+
+impl GameTls {
+    pub fn from_config(connection: &crate::config::ConnectionConfig) -> Option<Self> {
+        connection.tls.then_some(Self {
+            port: connection.tls_port,
+            verify: connection.tls_verify,
+        })
+    }
+}
+
 /// Runtime configuration for direct (non-Lich) connections.
 #[derive(Clone)]
 pub struct DirectConnectConfig {
@@ -60,6 +99,12 @@ pub struct DirectConnectConfig {
     pub character: String,
     pub game_code: String,
     pub data_dir: PathBuf,
+    /// Accept MCCP2 compression if the game server offers it
+    pub compression: bool,
+    /// Proxy for the eAccess login and the game socket
+    pub proxy: Option<crate::config::ProxyConfig>,
+    /// Wrap the game socket in TLS
+    pub tls: Option<GameTls>,
 }
 
 struct LogWriterSettings {
@@ -83,7 +128,7 @@ impl RawLogger {
             return Ok(None);
         }
 
-        let dir = config.logging.resolve_dir(config.character.as_deref())?;
+        let dir = config.logs_dir()?;
         let buffer_lines = config.logging.buffer_lines.max(1);
         let flush_interval =
             StdDuration::from_millis(config.logging.flush_interval_ms.max(1));
@@ -308,6 +353,9 @@ impl DirectConnectConfig {
             character,
             game_code,
             data_dir,
+            compression: config.connection.compression,
+            proxy: config.proxy.enabled().then(|| config.proxy.clone()),
+            tls: GameTls::from_config(&config.connection),
         }))
     }
 }
@@ -328,10 +376,11 @@ impl LichConnection {
         server_tx: mpsc::Sender<ServerMessage>,
         command_rx: mpsc::UnboundedReceiver<String>,
         raw_logger: Option<RawLogger>,
+        proxy: Option<&crate::config::ProxyConfig>,
     ) -> Result<()> {
         info!("Connecting to Lich at {}:{}...", host, port);
 
-        let mut stream = TcpStream::connect(format!("{}:{}", host, port))
+        let mut stream = proxy::connect(proxy, host, port)
             .await
             .context("Failed to connect to Lich")?;
 
@@ -346,7 +395,7 @@ impl LichConnection {
         // prepend for Stormfront frontends, so we must send <c> ourselves.
         let cmd_prefix = if login_key.is_some() { "<c>" } else { "" };
 
-        run_stream(stream, server_tx, command_rx, raw_logger, cmd_prefix).await
+        run_stream(stream, server_tx, command_rx, raw_logger, cmd_prefix, false).await
     }
 }
 
@@ -363,6 +412,9 @@ impl DirectConnection {
             character,
             game_code,
             data_dir,
+            compression,
+            proxy,
+            tls,
         } = config;
 
         info!(
@@ -371,8 +423,16 @@ impl DirectConnection {
         );
 
         let requested_character = character.clone();
+        let auth_proxy = proxy.clone();
         let ticket = tokio::task::spawn_blocking(move || {
-            eaccess::authenticate(&account, &password, &character, &game_code, &data_dir)
+            eaccess::authenticate(
+                &account,
+                &password,
+                &character,
+                &game_code,
+                &data_dir,
+                auth_proxy.as_ref(),
+            )
         })
         .await?
         .context("Failed to authenticate with eAccess")?;
@@ -395,20 +455,91 @@ impl DirectConnection {
         }
 
         let (host, port) = fix_game_host_port(&ticket.game_host, ticket.game_port);
-        info!("Connecting directly to {}:{}...", host, port);
+        let port = tls.as_ref().and_then(|tls| tls.port).unwrap_or(port);
+        info!(
+            "Connecting directly to {}:{}{}...",
+            host,
+            port,
+            if tls.is_some() { " (TLS)" } else { "" }
+        );
         let mut stream = tokio::time::timeout(
             std::time::Duration::from_secs(15),
-            TcpStream::connect(format!("{}:{}", host, port)),
+            proxy::connect(proxy.as_ref(), &host, port),
         )
         .await
         .context("Timed out connecting to game server")?
         .context("Failed to connect to game server")?;
 
-        send_direct_handshake(&mut stream, &ticket).await?;
-
         // Direct connections speak Stormfront protocol to the game itself,
         // which expects the <c> command prefix.
-        run_stream(stream, server_tx, command_rx, raw_logger, "<c>").await
+        match tls {
+            Some(tls) => {
+                let mut stream = tls_connect(stream, &host, port, &tls).await?;
+                dial::mark_tls();
+                send_direct_handshake(&mut stream, &ticket).await?;
+                run_stream(
+                    stream,
+                    server_tx,
+                    command_rx,
+                    raw_logger,
+                    "<c>",
+                    compression,
+                )
+                .await
+            }
+            None => {
+                send_direct_handshake(&mut stream, &ticket).await?;
+                run_stream(
+                    stream,
+                    server_tx,
+                    command_rx,
+                    raw_logger,
+                    "<c>",
+                    compression,
+                )
+                .await
+            }
+        }
+    }
+}
+
+/// TLS handshake over an open game socket. Failures come back as
+/// `TlsFailed` so they read differently from a rejected login.
+async fn tls_connect(
+    stream: TcpStream,
+    host: &str,
+    port: u16,
+    tls: &GameTls,
+) -> Result<tokio_native_tls::TlsStream<TcpStream>> {
+    let mut builder = native_tls::TlsConnector::builder();
+    if !tls.verify {
+        tracing::warn!("TLS certificate verification is off for {}", host);
+        builder
+            .danger_accept_invalid_certs(true)
+            .danger_accept_invalid_hostnames(true);
+    }
+    let connector = builder.build().context("Failed to build TLS connector")?;
+    let connector = tokio_native_tls::TlsConnector::from(connector);
+    let handshake = tokio::time::timeout(
+        std::time::Duration::from_secs(15),
+        connector.connect(host, stream),
+    )
+    .await;
+    let failed = |reason: String| {
+        anyhow::Error::new(TlsFailed(format!(
+            "TLS handshake with game server {}:{} failed: {}",
+            host, port, reason
+        )))
+    };
+    match handshake {
+        Ok(Ok(stream)) => {
+            info!("TLS established with {}:{}", host, port);
+            Ok(stream)
+        }
+        Ok(Err(e)) => Err(failed(e.to_string())),
+        Err(_) => Err(failed(
+            "timed out (does the server speak TLS on this port? see tls_port)".to_string(),
+        )),
     }
 }
 
@@ -427,15 +558,25 @@ impl<T> Drop for AbortOnDrop<T> {
     }
 }
 
-async fn run_stream(
-    stream: TcpStream,
+async fn run_stream<S>(
+    stream: S,
     server_tx: mpsc::Sender<ServerMessage>,
     mut command_rx: mpsc::UnboundedReceiver<String>,
     raw_logger: Option<RawLogger>,
     cmd_prefix: &'static str,
-) -> Result<()> {
+    compression: bool,
+) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Send + 'static,
+{
     let (reader, mut writer) = tokio::io::split(stream);
-    let mut reader = BufReader::new(reader);
+    // Telnet replies the reader needs sent (compression negotiation)
+    let (reply_tx, mut reply_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+    let mut reader = BufReader::new(compression::InflateReader::new(
+        reader,
+        compression,
+        reply_tx,
+    ));
 
     let _ = server_tx.send(ServerMessage::Connected).await;
 
@@ -450,6 +591,7 @@ async fn run_stream(
                     break;
                 }
                 Ok(_) => {
+                    traffic::record(traffic::Direction::In, line.as_bytes());
                     let trimmed_len = line.trim_end_matches(['\r', '\n']).len();
                     line.truncate(trimmed_len);
                     if let Some(logger) = &raw_logger {
@@ -479,17 +621,27 @@ async fn run_stream(
     // login screen until the user happened to send another command.
     let mut read_handle = AbortOnDrop(read_handle);
     let write_loop = async {
-        while let Some(cmd) = command_rx.recv().await {
-            // Build the complete message: command prefix + command + newline.
-            // The prefix is mode-dependent (see call sites): "<c>" when we talk
-            // Stormfront protocol ourselves (direct / Lich-launched), empty for
-            // Lich detachable clients where Lich prepends <c> itself.
-            let mut message = String::with_capacity(cmd_prefix.len() + cmd.len() + 1);
-            message.push_str(cmd_prefix);
-            message.push_str(&cmd);
-            message.push('\n');
-
-            if let Err(e) = writer.write_all(message.as_bytes()).await {
+        loop {
+            let message = tokio::select! {
+                cmd = command_rx.recv() => {
+                    let Some(cmd) = cmd else {
+                        break;
+                    };
+                    // Build the complete message: command prefix + command + newline.
+                    // The prefix is mode-dependent (see call sites): "<c>" when we talk
+                    // Stormfront protocol ourselves (direct / Lich-launched), empty for
+                    // Lich detachable clients where Lich prepends <c> itself.
+                    let mut message = String::with_capacity(cmd_prefix.len() + cmd.len() + 1);
+                    message.push_str(cmd_prefix);
+                    message.push_str(&cmd);
+                    message.push('\n');
+                    message.into_bytes()
+                }
+                Some(reply) = reply_rx.recv() => reply,
+            };
+
+            traffic::record(traffic::Direction::Out, &message);
+            if let Err(e) = writer.write_all(&message).await {
                 error!("Failed to write command: {}", e);
                 break;
             }
@@ -569,7 +721,7 @@ async fn send_lich_handshake(stream: &mut TcpStream, login_key: Option<&str>) ->
 }
 
 async fn send_direct_handshake(
-    stream: &mut TcpStream,
+    stream: &mut (impl AsyncWrite + Unpin),
     ticket: &eaccess::LaunchTicket,
 ) -> Result<()> {
     let key = ticket.key.trim();
@@ -614,6 +766,8 @@ mod eaccess {
     use std::net::TcpStream;
     use std::path::Path;
 
+    use crate::config::ProxyConfig;
+
     const HOST: &str = "eaccess.play.net";
     const PORT: u16 = 7910;
     const CERT_FILENAME: &str = "simu.pem";
@@ -633,20 +787,21 @@ mod eaccess {
         character: &str,
         game_code: &str,
         data_dir: &Path,
+        proxy: Option<&ProxyConfig>,
     ) -> Result<LaunchTicket> {
         let cert_path = data_dir.join(CERT_FILENAME);
-        ensure_certificate(&cert_path)?;
+        ensure_certificate(&cert_path, proxy)?;
 
         tracing::debug!("TLS handshake to eAccess starting (cert: {:?})", cert_path);
-        let mut stream = match connect_with_cert(&cert_path) {
+        let mut stream = match connect_with_cert(&cert_path, proxy) {
             Ok(stream) => {
                 tracing::debug!("TLS handshake to eAccess succeeded");
                 stream
             }
             Err(err) => {
                 tracing::warn!(error = ?err, "Handshake failed, refreshing stored cert");
-                download_certificate(&cert_path)?;
-                let stream = connect_with_cert(&cert_path)?;
+                download_certificate(&cert_path, proxy)?;
+                let stream = connect_with_cert(&cert_path, proxy)?;
                 tracing::debug!("TLS handshake succeeded after refreshing cert");
                 stream
             }
@@ -688,14 +843,14 @@ mod eaccess {
         parse_launch_response(&launch_response)
     }
 
-    fn ensure_certificate(path: &Path) -> Result<()> {
+    fn ensure_certificate(path: &Path, proxy: Option<&ProxyConfig>) -> Result<()> {
         if path.exists() {
             return Ok(());
         }
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
-        download_certificate(path)
+        download_certificate(path, proxy)
     }
 
     /// TLS handshake with the OS-native stack. The server's cert is self-signed
@@ -711,9 +866,7 @@ mod eaccess {
     /// with them, a stall becomes an error the reconnect supervisor retries.
     const AUTH_IO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
 
-    fn tls_handshake() -> Result<TlsStream<TcpStream>> {
-        use std::net::ToSocketAddrs;
-
+    fn tls_handshake(proxy: Option<&ProxyConfig>) -> Result<TlsStream<TcpStream>> {
         let connector = TlsConnector::builder()
             .use_sni(false)
             .danger_accept_invalid_certs(true)
@@ -721,32 +874,36 @@ mod eaccess {
             .build()
             .context("Failed to build TLS connector")?;
 
+        let stream = match proxy.filter(|p| p.applies(HOST)) {
+            // The proxy resolves the name; local DNS may be blocked too
+            Some(proxy) => super::proxy::connect_blocking(proxy, HOST, PORT, AUTH_IO_TIMEOUT)?,
+            None => connect_direct()?,
+        };
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(AUTH_IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(AUTH_IO_TIMEOUT))?;
+        connector
+            .connect(HOST, stream)
+            .map_err(|e| anyhow!("TLS handshake with eAccess failed: {e}"))
+    }
+
+    fn connect_direct() -> Result<TcpStream> {
+        use std::net::ToSocketAddrs;
+
         let mut last_err = None;
-        let mut stream = None;
         for addr in (HOST, PORT)
             .to_socket_addrs()
             .context("Failed to resolve eAccess host")?
         {
             match TcpStream::connect_timeout(&addr, AUTH_IO_TIMEOUT) {
-                Ok(s) => {
-                    stream = Some(s);
-                    break;
-                }
+                Ok(s) => return Ok(s),
                 Err(e) => last_err = Some(e),
             }
         }
-        let stream = stream.ok_or_else(|| {
-            anyhow!(
-                "Failed to open TLS socket: {}",
-                last_err.map(|e| e.to_string()).unwrap_or_default()
-            )
-        })?;
-        stream.set_nodelay(true)?;
-        stream.set_read_timeout(Some(AUTH_IO_TIMEOUT))?;
-        stream.set_write_timeout(Some(AUTH_IO_TIMEOUT))?;
-        connector
-            .connect(HOST, stream)
-            .map_err(|e| anyhow!("TLS handshake with eAccess failed: {e}"))
+        Err(anyhow!(
+            "Failed to open TLS socket: {}",
+            last_err.map(|e| e.to_string()).unwrap_or_default()
+        ))
     }
 
     fn peer_cert_der(stream: &TlsStream<TcpStream>) -> Result<Vec<u8>> {
@@ -769,8 +926,8 @@ mod eaccess {
         pem
     }
 
-    fn download_certificate(path: &Path) -> Result<()> {
-        let tls_stream = tls_handshake()?;
+    fn download_certificate(path: &Path, proxy: Option<&ProxyConfig>) -> Result<()> {
+        let tls_stream = tls_handshake(proxy)?;
         let der = peer_cert_der(&tls_stream)?;
         fs::write(path, der_to_pem(&der)).context("Failed to save certificate")?;
         Ok(())
@@ -796,19 +953,22 @@ mod eaccess {
             .context("Invalid base64 in PEM certificate")
     }
 
-    fn connect_with_cert(cert_path: &Path) -> Result<TlsStream<TcpStream>> {
+    fn connect_with_cert(
+        cert_path: &Path,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<TlsStream<TcpStream>> {
         let cert_data = fs::read(cert_path).context("Failed to read stored certificate")?;
         // Compare in DER form so PEM formatting differences (line width,
         // trailing newline) between OpenSSL-era and current files don't matter.
         let stored_der = pem_to_der(&cert_data).context("Invalid PEM certificate")?;
 
-        let tls_stream = tls_handshake()?;
+        let tls_stream = tls_handshake(proxy)?;
         tracing::debug!("TLS handshake with eAccess succeeded");
 
         let peer_der = peer_cert_der(&tls_stream)?;
         if peer_der != stored_der {
             tracing::warn!("Certificate mismatch - refreshing stored certificate");
-            download_certificate(cert_path)?;
+            download_certificate(cert_path, proxy)?;
         }
 
         Ok(tls_stream)
@@ -1280,4 +1440,27 @@ mod tests {
         assert!(debug_str.contains("Text"));
         assert!(debug_str.contains("data"));
     }
+
+    #[tokio::test]
+    async fn test_tls_to_plain_server_is_tls_failure() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let _ = socket.write_all(b"Welcome to the game\r\n").await;
+        });
+        let stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let tls = GameTls {
+            port: None,
+            verify: true,
+        };
+        let err = tls_connect(stream, "127.0.0.1", port, &tls)
+            .await
+            .unwrap_err();
+        assert!(err.chain().any(|c| c.is::<TlsFailed>()));
+        assert!(!err.chain().any(|c| c.is::<AuthFailed>()));
+        assert!(err
+            .to_string()
+            .starts_with("TLS handshake with game server"));
+    }
 }
@@ -0,0 +1,247 @@
+//! MCCP2 stream compression for direct connections.
+//!
+//! A server that can compress offers it with the telnet sequence `IAC WILL
+//! COMPRESS2`. With `[connection] compression` on, the client answers `IAC
+//! DO COMPRESS2`; the server then sends `IAC SB COMPRESS2 IAC SE` and
+//! everything after it is one zlib stream, until the server finishes that
+//! stream and goes back to plain text. A server that never offers is read
+//! exactly as before. Lich connections never negotiate — Lich hands the
+//! frontend plain text whatever it does upstream.
+//!
+//! `InflateReader` sits between the socket and the line reader, so the rest
+//! of the read path only ever sees plain game text.
+
+use anyhow::{anyhow, Result};
+use flate2::{Decompress, FlushDecompress, Status};
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::sync::mpsc;
+
+const IAC: u8 = 255;
+const DO: u8 = 253;
+const WILL: u8 = 251;
+const SB: u8 = 250;
+const SE: u8 = 240;
+const COMPRESS2: u8 = 86;
+
+/// Compressed bytes read off the wire, and what they inflated to
+static WIRE_BYTES: AtomicU64 = AtomicU64::new(0);
+static INFLATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Compressed bytes received and their inflated size since startup, or
+/// None if no server has compressed anything
+pub fn totals() -> Option<(u64, u64)> {
+    let wire = WIRE_BYTES.load(Ordering::Relaxed);
+    (wire > 0).then(|| (wire, INFLATED_BYTES.load(Ordering::Relaxed)))
+}
+
+/// Socket bytes in, game text (and negotiation replies) out
+pub struct Inflater {
+    compressed: bool,
+    decompress: Decompress,
+    /// Start of a telnet sequence split across reads
+    pending: Vec<u8>,
+}
+
+impl Default for Inflater {
+    fn default() -> Self {
+        Self {
+            compressed: false,
+            decompress: Decompress::new(true),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Inflater {
+    /// Process one read. Returns the game text it carried and, if the
+    /// server offered compression, the reply to send back.
+    pub fn feed(&mut self, input: &[u8]) -> Result<(Vec<u8>, Option<[u8; 3]>)> {
+        let mut buf = std::mem::take(&mut self.pending);
+        buf.extend_from_slice(input);
+        let mut out = Vec::with_capacity(buf.len());
+        let mut reply = None;
+        let mut i = 0;
+        while i < buf.len() {
+            if self.compressed {
+                let used = self.inflate(&buf[i..], &mut out)?;
+                if used == 0 && self.compressed {
+                    // zlib wants more input before it can go on
+                    self.pending = buf[i..].to_vec();
+                    break;
+                }
+                i += used;
+                continue;
+            }
+            let Some(at) = buf[i..].iter().position(|&b| b == IAC) else {
+                out.extend_from_slice(&buf[i..]);
+                break;
+            };
+            out.extend_from_slice(&buf[i..i + at]);
+            i += at;
+            let seq = &buf[i..];
+            match seq {
+                [IAC] | [IAC, WILL] | [IAC, SB] | [IAC, SB, COMPRESS2, ..] if seq.len() < 5 => {
+                    self.pending = seq.to_vec();
+                    break;
+                }
+                [IAC, WILL, COMPRESS2, ..] => {
+                    reply = Some([IAC, DO, COMPRESS2]);
+                    i += 3;
+                }
+                [IAC, SB, COMPRESS2, IAC, SE, ..] => {
+                    tracing::info!("Server started compressing (MCCP2)");
+                    self.compressed = true;
+                    i += 5;
+                }
+                // Escaped 0xFF, or a sequence we don't handle: pass through
+                _ => {
+                    out.push(IAC);
+                    i += 1;
+                }
+            }
+        }
+        Ok((out, reply))
+    }
+
+    /// Inflate from `input` into `out`; returns how much input was used
+    fn inflate(&mut self, input: &[u8], out: &mut Vec<u8>) -> Result<usize> {
+        let in_start = self.decompress.total_in();
+        let out_start = self.decompress.total_out();
+        let mut ended = false;
+        loop {
+            let used = (self.decompress.total_in() - in_start) as usize;
+            out.reserve(8192);
+            let status = self
+                .decompress
+                .decompress_vec(&input[used..], out, FlushDecompress::None)
+                .map_err(|e| anyhow!("Bad compressed data from server: {}", e))?;
+            match status {
+                Status::StreamEnd => {
+                    ended = true;
+                    break;
+                }
+                Status::BufError => break,
+                // Stop once the input is used up and zlib had room to spare
+                Status::Ok if out.len() < out.capacity() => {
+                    if self.decompress.total_in() - in_start == input.len() as u64 {
+                        break;
+                    }
+                }
+                Status::Ok => {}
+            }
+        }
+        let used = self.decompress.total_in() - in_start;
+        WIRE_BYTES.fetch_add(used, Ordering::Relaxed);
+        INFLATED_BYTES.fetch_add(self.decompress.total_out() - out_start, Ordering::Relaxed);
+        if ended {
+            tracing::info!("Server stopped compressing");
+            self.compressed = false;
+            self.decompress.reset(true);
+        }
+        Ok(used as usize)
+    }
+}
+
+/// `AsyncRead` adapter running an `Inflater` over a socket read half.
+/// Without an inflater it reads straight through.
+pub struct InflateReader<R> {
+    inner: R,
+    inflater: Option<Inflater>,
+    /// Negotiation replies for the write loop
+    replies: mpsc::UnboundedSender<Vec<u8>>,
+    ready: Vec<u8>,
+    ready_pos: usize,
+}
+
+impl<R> InflateReader<R> {
+    pub fn new(inner: R, compression: bool, replies: mpsc::UnboundedSender<Vec<u8>>) -> Self {
+        Self {
+            inner,
+            inflater: compression.then(Inflater::default),
+            replies,
+            ready: Vec::new(),
+            ready_pos: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for InflateReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let Some(inflater) = this.inflater.as_mut() else {
+            return Pin::new(&mut this.inner).poll_read(cx, buf);
+        };
+        loop {
+            if this.ready_pos < this.ready.len() {
+                let n = buf.remaining().min(this.ready.len() - this.ready_pos);
+                buf.put_slice(&this.ready[this.ready_pos..this.ready_pos + n]);
+                this.ready_pos += n;
+                return Poll::Ready(Ok(()));
+            }
+            let mut raw = [0u8; 8192];
+            let mut raw_buf = ReadBuf::new(&mut raw);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut raw_buf))?;
+            if raw_buf.filled().is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+            let (data, reply) = inflater
+                .feed(raw_buf.filled())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            if let Some(reply) = reply {
+                let _ = this.replies.send(reply.to_vec());
+            }
+            this.ready = data;
+            this.ready_pos = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn compress(text: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn negotiates_and_inflates_across_reads() {
+        let mut inflater = Inflater::default();
+        let (text, reply) = inflater.feed(b"Welcome\n\xff\xfb").unwrap();
+        assert_eq!(text, b"Welcome\n");
+        assert_eq!(reply, None);
+        let (text, reply) = inflater.feed(&[COMPRESS2]).unwrap();
+        assert!(text.is_empty());
+        assert_eq!(reply, Some([IAC, DO, COMPRESS2]));
+
+        let mut stream = vec![IAC, SB, COMPRESS2, IAC, SE];
+        stream.extend(compress(b"You swing a sword.\n"));
+        stream.extend_from_slice(b"plain again\n");
+        let (first, second) = stream.split_at(9);
+        let mut text = inflater.feed(first).unwrap().0;
+        text.extend(inflater.feed(second).unwrap().0);
+        assert_eq!(text, b"You swing a sword.\nplain again\n");
+        assert!(totals().is_some());
+    }
+
+    #[test]
+    fn passes_other_bytes_through() {
+        let mut inflater = Inflater::default();
+        let (text, reply) = inflater.feed(b"a\xff\xfb\x01b").unwrap();
+        assert_eq!(text, b"a\xff\xfb\x01b");
+        assert_eq!(reply, None);
+    }
+}
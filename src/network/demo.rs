@@ -0,0 +1,326 @@
+//! Offline demo backend for `--demo`.
+//!
+//! Stands in for the Lich/direct connection: instead of a socket it plays a
+//! scripted session of canned game XML into the same server channel, so
+//! every widget, highlight and layout behaves as it would in the game. New
+//! users can look around without an account; developers get the same
+//! session every run when checking UI changes.
+//!
+//! The script (bundled `defaults/demo/session.xml`, or `--demo-script`) is
+//! game XML plus a few `@` directives; see the header of the bundled file.
+//! The intro plays on connect, the ambient section loops while idle, and
+//! typed commands get the reply of the first `@on` section they match.
+
+use anyhow::{bail, Result};
+use tokio::sync::mpsc;
+use tokio::time::{sleep, Duration};
+
+use super::traffic::{self, Direction};
+use super::ServerMessage;
+
+/// The session `--demo` plays unless `--demo-script` names another
+pub const BUNDLED_SCRIPT: &str = include_str!("../../defaults/demo/session.xml");
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Line(String),
+    Wait(u64),
+}
+
+/// A parsed demo script
+#[derive(Debug, Default)]
+pub struct DemoScript {
+    intro: Vec<Step>,
+    ambient: Vec<Step>,
+    /// (commands, reply) in script order; the first match wins
+    replies: Vec<(Vec<String>, Vec<Step>)>,
+    fallback: Vec<Step>,
+}
+
+impl DemoScript {
+    pub fn parse(text: &str) -> Result<Self> {
+        // The section lines are being added to
+        enum Section {
+            Intro,
+            Ambient,
+            Reply(usize),
+            Fallback,
+        }
+        let mut script = Self::default();
+        let mut section = Section::Intro;
+
+        for (number, line) in text.lines().enumerate() {
+            let step = match line.strip_prefix('@') {
+                None => Step::Line(line.to_string()),
+                Some(directive) => {
+                    let (name, arg) = directive
+                        .split_once(char::is_whitespace)
+                        .map(|(name, arg)| (name, arg.trim()))
+                        .unwrap_or((directive, ""));
+                    match name {
+                        _ if name.starts_with('#') => continue,
+                        "ambient" => {
+                            section = Section::Ambient;
+                            continue;
+                        }
+                        "fallback" => {
+                            section = Section::Fallback;
+                            continue;
+                        }
+                        "on" => {
+                            let commands: Vec<String> = arg
+                                .split('|')
+                                .map(|command| command.trim().to_lowercase())
+                                .filter(|command| !command.is_empty())
+                                .collect();
+                            if commands.is_empty() {
+                                bail!("line {}: @on needs a command", number + 1);
+                            }
+                            script.replies.push((commands, Vec::new()));
+                            section = Section::Reply(script.replies.len() - 1);
+                            continue;
+                        }
+                        "wait" => match arg.parse() {
+                            Ok(ms) => Step::Wait(ms),
+                            Err(_) => bail!("line {}: bad @wait '{}'", number + 1, arg),
+                        },
+                        _ => bail!("line {}: unknown directive @{}", number + 1, name),
+                    }
+                }
+            };
+            match section {
+                Section::Intro => script.intro.push(step),
+                Section::Ambient => script.ambient.push(step),
+                Section::Reply(i) => script.replies[i].1.push(step),
+                Section::Fallback => script.fallback.push(step),
+            }
+        }
+        Ok(script)
+    }
+
+    /// The steps answering `command`: the first `@on` whose command it is
+    /// (or starts with, followed by a space), else the fallback
+    fn reply_for(&self, command: &str) -> &[Step] {
+        let command = command.trim().to_lowercase();
+        self.replies
+            .iter()
+            .find(|(commands, _)| {
+                commands.iter().any(|c| {
+                    command == *c
+                        || command
+                            .strip_prefix(c.as_str())
+                            .is_some_and(|rest| rest.starts_with(' '))
+                        // `'Hello` is say
+                        || (c == "'" && command.starts_with('\''))
+                })
+            })
+            .map(|(_, steps)| steps.as_slice())
+            .unwrap_or(&self.fallback)
+    }
+}
+
+/// Fill in `{now}` / `{now+N}` (epoch seconds) and `{command}`. The
+/// command is escaped, since the line is fed to the XML parser.
+fn expand(line: &str, command: &str) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let token = &after[1..end];
+        match token {
+            "now" => out.push_str(&now.to_string()),
+            "command" => {
+                for ch in command.chars() {
+                    match ch {
+                        '&' => out.push_str("&amp;"),
+                        '<' => out.push_str("&lt;"),
+                        '>' => out.push_str("&gt;"),
+                        _ => out.push(ch),
+                    }
+                }
+            }
+            _ => match token
+                .strip_prefix("now+")
+                .and_then(|n| n.parse::<u64>().ok())
+            {
+                Some(offset) => out.push_str(&(now + offset).to_string()),
+                None => out.push_str(&after[..=end]),
+            },
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Stub type that exposes the async `start` helper, like the real connections
+pub struct DemoConnection;
+
+impl DemoConnection {
+    /// Play `script` into `server_tx` until the user quits or the client
+    /// goes away. Commands typed during a reply or the intro wait their turn.
+    pub async fn start(
+        script: &str,
+        server_tx: mpsc::Sender<ServerMessage>,
+        mut command_rx: mpsc::UnboundedReceiver<String>,
+    ) -> Result<()> {
+        let script = DemoScript::parse(script)?;
+        tracing::info!(
+            "Demo session: {} intro lines, {} replies",
+            script.intro.len(),
+            script.replies.len()
+        );
+        let _ = server_tx.send(ServerMessage::Connected).await;
+        if !play(&script.intro, "", &server_tx).await {
+            return Ok(());
+        }
+
+        // A script whose ambient section has no lines has no ambient at all
+        let has_ambient = script
+            .ambient
+            .iter()
+            .any(|step| matches!(step, Step::Line(_)));
+        let mut ambient = script.ambient.iter().cycle();
+        // The next ambient line and the wait before it. A command arriving
+        // meanwhile restarts the wait rather than skipping the line.
+        let mut pending: Option<(u64, &String)> = None;
+        loop {
+            if pending.is_none() && has_ambient {
+                let mut wait = 0;
+                pending = loop {
+                    match ambient.next() {
+                        Some(Step::Wait(ms)) => wait += ms,
+                        Some(Step::Line(line)) => break Some((wait, line)),
+                        None => break None,
+                    }
+                };
+            }
+
+            let idle = async {
+                match pending {
+                    Some((wait, _)) => sleep(Duration::from_millis(wait)).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                _ = idle => {
+                    if let Some((_, line)) = pending.take() {
+                        if !send(&expand(line, ""), &server_tx).await {
+                            break;
+                        }
+                    }
+                }
+                command = command_rx.recv() => {
+                    let Some(command) = command else {
+                        break;
+                    };
+                    traffic::record(Direction::Out, format!("{}\n", command).as_bytes());
+                    let command = command.trim().to_string();
+                    if command.eq_ignore_ascii_case("quit") || command.eq_ignore_ascii_case("exit") {
+                        let _ = server_tx.send(ServerMessage::Disconnected).await;
+                        break;
+                    }
+                    // Client-internal requests (`_spell ...`) get no reply
+                    if command.is_empty() || command.starts_with('_') {
+                        continue;
+                    }
+                    if !play(script.reply_for(&command), &command, &server_tx).await {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Send one line; false once the client has gone away
+async fn send(line: &str, server_tx: &mpsc::Sender<ServerMessage>) -> bool {
+    traffic::record(Direction::In, line.as_bytes());
+    server_tx
+        .send(ServerMessage::Text(line.to_string()))
+        .await
+        .is_ok()
+}
+
+/// Play `steps` in order, answering `command`; false once the client has
+/// gone away
+async fn play(steps: &[Step], command: &str, server_tx: &mpsc::Sender<ServerMessage>) -> bool {
+    for step in steps {
+        match step {
+            Step::Wait(ms) => sleep(Duration::from_millis(*ms)).await,
+            Step::Line(line) => {
+                if !send(&expand(line, command), server_tx).await {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_script_parses() {
+        let script = DemoScript::parse(BUNDLED_SCRIPT).expect("bundled demo script");
+        assert!(!script.intro.is_empty());
+        assert!(!script.ambient.is_empty());
+        assert!(!script.fallback.is_empty());
+        assert_ne!(script.reply_for("look"), script.fallback.as_slice());
+    }
+
+    #[test]
+    fn replies_match_command_words() {
+        let script = DemoScript::parse(
+            "intro\n@on look|l\nroom\n@on say|'\nsaid\n@fallback\nno {command}\n",
+        )
+        .unwrap();
+        assert_eq!(script.intro, vec![Step::Line("intro".into())]);
+        assert_eq!(script.reply_for("L"), &[Step::Line("room".into())]);
+        assert_eq!(
+            script.reply_for("look at well"),
+            &[Step::Line("room".into())]
+        );
+        assert_eq!(script.reply_for("'hi"), &[Step::Line("said".into())]);
+        // "lookup" is not "look"
+        assert_eq!(
+            script.reply_for("lookup"),
+            &[Step::Line("no {command}".into())]
+        );
+        assert_eq!(expand("no {command}", "lookup"), "no lookup");
+        assert_eq!(
+            expand("no {command}", "say <b> & co"),
+            "no say &lt;b&gt; &amp; co"
+        );
+
+        assert!(DemoScript::parse("@on\n").is_err());
+        assert!(DemoScript::parse("@wait soon\n").is_err());
+        assert!(DemoScript::parse("@bogus\n").is_err());
+    }
+
+    #[test]
+    fn expands_time_placeholders() {
+        let line = expand(
+            "<roundTime value='{now+5}'/><prompt time=\"{now}\">{x}</prompt>",
+            "",
+        );
+        let numbers: Vec<u64> = line
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|n| n.parse().ok())
+            .collect();
+        assert_eq!(numbers.len(), 2);
+        assert!((numbers[0] - numbers[1]).abs_diff(5) <= 1);
+        assert!(line.ends_with("{x}</prompt>"));
+    }
+}
@@ -0,0 +1,191 @@
+//! Multi-address connect with fallback.
+//!
+//! A host name can resolve to several addresses, often both IPv6 and IPv4.
+//! Trying only the first one fails the whole connection when that family
+//! is unreachable — an IPv6 address on a network without IPv6 routing, or
+//! `localhost` resolving to `::1` while Lich only listens on `127.0.0.1`.
+//! `connect` tries them all, happy-eyeballs style (RFC 8305): families
+//! alternate, IPv6 first; a new attempt starts whenever the previous one
+//! fails or has been pending for `ATTEMPT_DELAY`; the first to connect wins
+//! and the rest are dropped.
+//!
+//! The outcome of the latest connect — chosen address, or every address
+//! that failed — is kept for `.netinfo`.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local};
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::task::JoinSet;
+
+/// How long an attempt runs alone before the next address is tried as well
+const ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+/// Give up on a single address after this long
+const ATTEMPT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// What the latest connection attempt did
+#[derive(Debug, Clone)]
+pub struct NetInfo {
+    pub host: String,
+    pub port: u16,
+    /// Address the socket connected to (the proxy's, when `proxy` is set);
+    /// None if every attempt failed
+    pub addr: Option<SocketAddr>,
+    /// Every address the host resolved to, in the order tried
+    pub resolved: Vec<SocketAddr>,
+    /// Addresses that failed, with why
+    pub failures: Vec<(SocketAddr, String)>,
+    /// `kind host:port` of the proxy the connection went through
+    pub proxy: Option<String>,
+    /// Game socket wrapped in TLS
+    pub tls: bool,
+    pub at: DateTime<Local>,
+}
+
+static LAST: Mutex<Option<NetInfo>> = Mutex::new(None);
+
+/// The latest connection attempt, if there has been one
+pub fn last() -> Option<NetInfo> {
+    LAST.lock().ok().and_then(|last| last.clone())
+}
+
+pub(crate) fn record(info: NetInfo) {
+    if let Some(addr) = info.addr {
+        tracing::info!("Connected to {}:{} at {}", info.host, info.port, addr);
+    }
+    if let Ok(mut last) = LAST.lock() {
+        *last = Some(info);
+    }
+}
+
+/// Note that the latest connection was upgraded to TLS
+pub(crate) fn mark_tls() {
+    if let Ok(mut last) = LAST.lock() {
+        if let Some(info) = last.as_mut() {
+            info.tls = true;
+        }
+    }
+}
+
+/// IPv6 first, then alternate families, keeping resolver order within each
+fn interleave(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let (v6, v4): (Vec<_>, Vec<_>) = addrs.into_iter().partition(|a| a.is_ipv6());
+    let mut v6 = v6.into_iter();
+    let mut v4 = v4.into_iter();
+    let mut ordered = Vec::new();
+    loop {
+        match (v6.next(), v4.next()) {
+            (None, None) => return ordered,
+            (a, b) => ordered.extend(a.into_iter().chain(b)),
+        }
+    }
+}
+
+/// Connect to whichever address of `host:port` answers first
+pub async fn connect(host: &str, port: u16) -> Result<TcpStream> {
+    let resolved: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .with_context(|| format!("Could not resolve {}", host))?
+        .collect();
+    if resolved.is_empty() {
+        bail!("{} did not resolve to any address", host);
+    }
+    let resolved = interleave(resolved);
+    let mut info = NetInfo {
+        host: host.to_string(),
+        port,
+        addr: None,
+        resolved: resolved.clone(),
+        failures: Vec::new(),
+        proxy: None,
+        tls: false,
+        at: Local::now(),
+    };
+
+    let mut queue = resolved.into_iter();
+    let mut attempts = JoinSet::new();
+    let start_next = |queue: &mut std::vec::IntoIter<SocketAddr>, attempts: &mut JoinSet<_>| {
+        if let Some(addr) = queue.next() {
+            tracing::debug!("Trying {}", addr);
+            attempts.spawn(async move {
+                let result =
+                    match tokio::time::timeout(ATTEMPT_TIMEOUT, TcpStream::connect(addr)).await {
+                        Ok(result) => result.map_err(|e| e.to_string()),
+                        Err(_) => Err("timed out".to_string()),
+                    };
+                (addr, result)
+            });
+        }
+    };
+    start_next(&mut queue, &mut attempts);
+
+    while !attempts.is_empty() {
+        let more = queue.len() > 0;
+        tokio::select! {
+            joined = attempts.join_next() => {
+                let Some(Ok((addr, result))) = joined else {
+                    continue;
+                };
+                match result {
+                    Ok(stream) => {
+                        info.addr = Some(addr);
+                        record(info);
+                        return Ok(stream);
+                    }
+                    Err(e) => {
+                        tracing::debug!("{} failed: {}", addr, e);
+                        info.failures.push((addr, e));
+                        start_next(&mut queue, &mut attempts);
+                    }
+                }
+            }
+            _ = tokio::time::sleep(ATTEMPT_DELAY), if more => {
+                start_next(&mut queue, &mut attempts);
+            }
+        }
+    }
+
+    let tried = info
+        .failures
+        .iter()
+        .map(|(addr, e)| format!("{}: {}", addr, e))
+        .collect::<Vec<_>>()
+        .join("; ");
+    record(info);
+    bail!("Could not connect to {}:{} (tried {})", host, port, tried)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleave_alternates_families_ipv6_first() {
+        let addrs: Vec<SocketAddr> = ["1.1.1.1:1", "2.2.2.2:1", "[::1]:1", "[::2]:1", "3.3.3.3:1"]
+            .iter()
+            .map(|a| a.parse().unwrap())
+            .collect();
+        let ordered: Vec<String> = interleave(addrs).iter().map(|a| a.to_string()).collect();
+        assert_eq!(
+            ordered,
+            ["[::1]:1", "1.1.1.1:1", "[::2]:1", "2.2.2.2:1", "3.3.3.3:1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_past_refused_addresses() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // `localhost` may resolve to ::1 first, which nothing listens on
+        let stream = connect("localhost", port).await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), listener.local_addr().unwrap());
+
+        drop(listener);
+        let err = connect("127.0.0.1", port).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("tried 127.0.0.1:{}", port)));
+    }
+}
@@ -0,0 +1,383 @@
+//! SOCKS5 and HTTP CONNECT proxies for outgoing connections.
+//!
+//! `[proxy]` routes the eAccess login, the direct game socket and the Lich
+//! connection through a proxy, for networks that only allow traffic out via
+//! one. Both protocols only set up a tunnel; once the handshake is done the
+//! socket is an ordinary TCP stream to the target. Loopback targets — a Lich
+//! running on this machine — never go through the proxy.
+//!
+//! The handshake is blocking (eAccess is a blocking TLS client anyway);
+//! `connect` runs it on the blocking pool and hands back a tokio stream.
+//! Every failure names which hop failed — the proxy itself, its
+//! authentication, or the proxy's connection to the target — since "failed
+//! to connect" alone is useless behind a restrictive network.
+
+use crate::config::{ProxyConfig, ProxyKind};
+use anyhow::{anyhow, bail, Context, Result};
+use base64::Engine as _;
+use std::io::{Read, Write};
+use std::net::{IpAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// How long the proxy gets for each connect/read/write of the handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(15);
+/// Largest HTTP CONNECT response header we'll read
+const MAX_HTTP_RESPONSE: usize = 8192;
+
+impl ProxyConfig {
+    /// Whether a connection to `host` goes through this proxy
+    pub fn applies(&self, host: &str) -> bool {
+        self.enabled() && !is_loopback(host)
+    }
+}
+
+fn is_loopback(host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Connect to `host:port`, through `proxy` when one is configured and
+/// applies to the host
+pub async fn connect(
+    proxy: Option<&ProxyConfig>,
+    host: &str,
+    port: u16,
+) -> Result<tokio::net::TcpStream> {
+    let Some(proxy) = proxy.filter(|p| p.applies(host)) else {
+        return super::dial::connect(host, port).await;
+    };
+    let via = format!("{:?} {}:{}", proxy.kind, proxy.host, proxy.port).to_lowercase();
+    let proxy = proxy.clone();
+    let target = host.to_string();
+    let stream = tokio::task::spawn_blocking(move || {
+        connect_blocking(&proxy, &target, port, HANDSHAKE_TIMEOUT)
+    })
+    .await??;
+    let addr = stream.peer_addr().ok();
+    super::dial::record(super::dial::NetInfo {
+        host: host.to_string(),
+        port,
+        addr,
+        resolved: addr.into_iter().collect(),
+        failures: Vec::new(),
+        proxy: Some(via),
+        tls: false,
+        at: chrono::Local::now(),
+    });
+    stream.set_nonblocking(true)?;
+    Ok(tokio::net::TcpStream::from_std(stream)?)
+}
+
+/// Open a tunnel to `host:port` through `proxy`. Socket timeouts are
+/// cleared again before the stream is returned.
+pub fn connect_blocking(
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+    timeout: Duration,
+) -> Result<TcpStream> {
+    let name = match proxy.kind {
+        ProxyKind::None => bail!("No proxy configured"),
+        ProxyKind::Socks5 => "SOCKS5",
+        ProxyKind::Http => "HTTP",
+    };
+    if proxy.host.is_empty() || proxy.port == 0 {
+        bail!("[proxy] needs a host and port");
+    }
+    tracing::info!(
+        "Connecting to {}:{} via {} proxy {}:{}",
+        host,
+        port,
+        name,
+        proxy.host,
+        proxy.port
+    );
+
+    let mut stream = open(&proxy.host, proxy.port, timeout).with_context(|| {
+        format!(
+            "Could not reach {} proxy at {}:{}",
+            name, proxy.host, proxy.port
+        )
+    })?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    match proxy.kind {
+        ProxyKind::Socks5 => socks5_handshake(&mut stream, proxy, host, port),
+        _ => http_handshake(&mut stream, proxy, host, port),
+    }
+    .with_context(|| format!("{} proxy {}:{} failed", name, proxy.host, proxy.port))?;
+    stream.set_read_timeout(None)?;
+    stream.set_write_timeout(None)?;
+    Ok(stream)
+}
+
+fn open(host: &str, port: u16, timeout: Duration) -> Result<TcpStream> {
+    let mut last_err = None;
+    for addr in (host, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err
+        .map(anyhow::Error::from)
+        .unwrap_or_else(|| anyhow!("{} did not resolve to any address", host)))
+}
+
+fn socks5_handshake(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> Result<()> {
+    let credentials = proxy
+        .username
+        .as_deref()
+        .map(|user| (user, proxy.password.as_deref().unwrap_or_default()));
+
+    // Greeting: offer no-auth, plus username/password when we have them
+    if credentials.is_some() {
+        stream.write_all(&[5, 2, 0, 2])?;
+    } else {
+        stream.write_all(&[5, 1, 0])?;
+    }
+    let mut choice = [0u8; 2];
+    stream
+        .read_exact(&mut choice)
+        .context("No reply to greeting")?;
+    if choice[0] != 5 {
+        bail!("Not a SOCKS5 proxy");
+    }
+    match (choice[1], credentials) {
+        (0, _) => {}
+        (2, Some((user, pass))) => {
+            if user.len() > 255 || pass.len() > 255 {
+                bail!("Proxy username and password are limited to 255 bytes");
+            }
+            let mut auth = vec![1, user.len() as u8];
+            auth.extend_from_slice(user.as_bytes());
+            auth.push(pass.len() as u8);
+            auth.extend_from_slice(pass.as_bytes());
+            stream.write_all(&auth)?;
+            let mut status = [0u8; 2];
+            stream.read_exact(&mut status)?;
+            if status[1] != 0 {
+                bail!("Proxy rejected the username or password");
+            }
+        }
+        (2, None) => bail!("Proxy requires a username and password"),
+        _ => bail!("Proxy accepts none of our authentication methods"),
+    }
+
+    // CONNECT by domain name, so the proxy does the DNS lookup
+    if host.len() > 255 {
+        bail!("Host name too long for SOCKS5: {}", host);
+    }
+    let mut request = vec![5, 1, 0, 3, host.len() as u8];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request)?;
+
+    let mut reply = [0u8; 4];
+    stream
+        .read_exact(&mut reply)
+        .context("No reply to CONNECT")?;
+    if reply[1] != 0 {
+        bail!(
+            "Proxy could not reach {}:{}: {}",
+            host,
+            port,
+            socks5_error(reply[1])
+        );
+    }
+    // Skip the bound address the proxy reports
+    let addr_len = match reply[3] {
+        1 => 4,
+        4 => 16,
+        3 => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len)?;
+            len[0] as usize
+        }
+        other => bail!("Bad address type {} in proxy reply", other),
+    };
+    let mut bound = vec![0u8; addr_len + 2];
+    stream.read_exact(&mut bound)?;
+    Ok(())
+}
+
+fn socks5_error(code: u8) -> &'static str {
+    match code {
+        1 => "general failure",
+        2 => "not allowed by proxy rules",
+        3 => "network unreachable",
+        4 => "host unreachable",
+        5 => "connection refused",
+        6 => "TTL expired",
+        7 => "command not supported",
+        8 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+fn http_handshake(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    host: &str,
+    port: u16,
+) -> Result<()> {
+    let mut request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if let Some(user) = &proxy.username {
+        let token = base64::engine::general_purpose::STANDARD.encode(format!(
+            "{}:{}",
+            user,
+            proxy.password.as_deref().unwrap_or_default()
+        ));
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes())?;
+
+    // Read byte by byte so nothing past the header is consumed
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_HTTP_RESPONSE {
+            bail!("Proxy response header too long");
+        }
+        if stream.read(&mut byte)? == 0 {
+            bail!("Proxy closed the connection during CONNECT");
+        }
+        response.push(byte[0]);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| anyhow!("Not an HTTP proxy (got '{}')", status_line))?;
+    match status {
+        200..=299 => Ok(()),
+        407 if proxy.username.is_some() => bail!("Proxy rejected the username or password"),
+        407 => bail!("Proxy requires a username and password"),
+        _ => bail!(
+            "Proxy could not reach {}:{}: {}",
+            host,
+            port,
+            status_line.trim()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// One-connection proxy on a local port: reads `expect` bytes, replies
+    /// with `reply`, then echoes one line so the tunnel can be checked
+    fn fake_proxy(
+        kind: ProxyKind,
+        steps: Vec<(usize, Vec<u8>)>,
+    ) -> (ProxyConfig, thread::JoinHandle<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = thread::spawn(move || {
+            let (mut client, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            for (expect, reply) in steps {
+                let mut buf = vec![0u8; expect];
+                client.read_exact(&mut buf).unwrap();
+                received.extend(buf);
+                client.write_all(&reply).unwrap();
+            }
+            received
+        });
+        let proxy = ProxyConfig {
+            kind,
+            host: "127.0.0.1".into(),
+            port,
+            username: None,
+            password: None,
+        };
+        (proxy, handle)
+    }
+
+    #[test]
+    fn socks5_authenticates_and_connects_by_name() {
+        let host = "game.example";
+        let connect_len = 5 + host.len() + 2;
+        let (mut proxy, server) = fake_proxy(
+            ProxyKind::Socks5,
+            vec![
+                (4, vec![5, 2]),
+                (1 + 1 + 2 + 1 + 2, vec![1, 0]),
+                (
+                    connect_len,
+                    vec![5, 0, 0, 1, 10, 0, 0, 1, 0x1f, 0x90, b'o', b'k'],
+                ),
+            ],
+        );
+        proxy.username = Some("me".into());
+        proxy.password = Some("pw".into());
+
+        let mut stream = connect_blocking(&proxy, host, 4900, HANDSHAKE_TIMEOUT).unwrap();
+        let mut tunneled = [0u8; 2];
+        stream.read_exact(&mut tunneled).unwrap();
+        assert_eq!(&tunneled, b"ok");
+
+        let received = server.join().unwrap();
+        assert_eq!(&received[..4], &[5, 2, 0, 2]);
+        assert_eq!(&received[4..11], &[1, 2, b'm', b'e', 2, b'p', b'w']);
+        assert_eq!(&received[11..16], &[5, 1, 0, 3, host.len() as u8]);
+        assert_eq!(&received[received.len() - 2..], &4900u16.to_be_bytes());
+    }
+
+    #[test]
+    fn socks5_failure_names_the_target() {
+        let (proxy, _server) = fake_proxy(
+            ProxyKind::Socks5,
+            vec![(3, vec![5, 0]), (5 + 6 + 2, vec![5, 5, 0, 1])],
+        );
+        let err = connect_blocking(&proxy, "a.test", 80, HANDSHAKE_TIMEOUT).unwrap_err();
+        assert!(format!("{:#}", err).contains("could not reach a.test:80: connection refused"));
+    }
+
+    #[test]
+    fn http_connect_reports_auth_required() {
+        let request = "CONNECT a.test:80 HTTP/1.1\r\nHost: a.test:80\r\n\r\n";
+        let (proxy, server) = fake_proxy(
+            ProxyKind::Http,
+            vec![(
+                request.len(),
+                b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n".to_vec(),
+            )],
+        );
+        let err = connect_blocking(&proxy, "a.test", 80, HANDSHAKE_TIMEOUT).unwrap_err();
+        assert!(format!("{:#}", err).contains("requires a username and password"));
+        assert_eq!(server.join().unwrap(), request.as_bytes());
+    }
+
+    #[test]
+    fn loopback_targets_skip_the_proxy() {
+        let proxy = ProxyConfig {
+            kind: ProxyKind::Socks5,
+            host: "proxy.test".into(),
+            port: 1080,
+            username: None,
+            password: None,
+        };
+        assert!(!proxy.applies("localhost"));
+        assert!(!proxy.applies("127.0.0.1"));
+        assert!(!proxy.applies("::1"));
+        assert!(proxy.applies("eaccess.play.net"));
+        assert!(!ProxyConfig::default().applies("eaccess.play.net"));
+    }
+}
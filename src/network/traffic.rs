@@ -0,0 +1,255 @@
+//! Raw traffic tap for the traffic console window (`.traffic`).
+//!
+//! While capture is on, every line read from the game socket (after
+//! decompression, before the parser) and every write to it (commands with
+//! their `<c>` prefix, telnet replies) is kept here with its direction, so
+//! direct-connect problems can be read off the wire as it was. Login
+//! handshakes are never recorded — they carry the session key.
+//!
+//! Off by default; while off `record` is a single atomic load.
+
+use chrono::{Local, NaiveTime};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Lines kept; the oldest drop off
+pub const CAPACITY: usize = 1000;
+
+/// Bytes per row in the hex view
+pub const HEX_ROW: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Server to client
+    In,
+    /// Client to server
+    Out,
+}
+
+impl Direction {
+    pub fn marker(self) -> &'static str {
+        match self {
+            Direction::In => "<",
+            Direction::Out => ">",
+        }
+    }
+}
+
+/// One socket line or write, as bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrafficLine {
+    /// Increases with every line recorded; the newest line's is the tap's
+    /// generation
+    pub seq: u64,
+    pub direction: Direction,
+    pub at: NaiveTime,
+    pub bytes: Vec<u8>,
+}
+
+impl TrafficLine {
+    /// The line as text, without its line ending. Control bytes (telnet
+    /// negotiation, stray escapes) show as `\xNN`.
+    pub fn text(&self) -> String {
+        let bytes = self.bytes.strip_suffix(b"\n").unwrap_or(&self.bytes);
+        let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+        let mut out = String::with_capacity(bytes.len());
+        for c in String::from_utf8_lossy(bytes).chars() {
+            if c.is_control() && c != '\t' {
+                out.push_str(&format!("\\x{:02x}", c as u32));
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// Hex dump rows: `3c 63 3e 6c ...  <c>l...`, `HEX_ROW` bytes each
+    pub fn hex_rows(&self) -> Vec<String> {
+        self.bytes
+            .chunks(HEX_ROW)
+            .map(|chunk| {
+                let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+                let ascii: String = chunk
+                    .iter()
+                    .map(|&b| {
+                        if b.is_ascii_graphic() || b == b' ' {
+                            b as char
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                format!(
+                    "{:<width$}  {}",
+                    hex.join(" "),
+                    ascii,
+                    width = HEX_ROW * 3 - 1
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Tap {
+    lines: VecDeque<TrafficLine>,
+    next_seq: u64,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Secrets whose next write is recorded masked (core::secrets)
+static MASKED: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+static TAP: Mutex<Tap> = Mutex::new(Tap {
+    lines: VecDeque::new(),
+    next_seq: 0,
+});
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Start or pause capture. Pausing keeps what was captured.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Drop everything captured so far
+pub fn clear() {
+    if let Ok(mut tap) = TAP.lock() {
+        tap.lines.clear();
+        // Move the generation on so windows notice
+        tap.next_seq += 1;
+    }
+}
+
+/// Record the next write containing `secret` with it masked. Does
+/// nothing while capture is off.
+pub fn mask_next(secret: &str) {
+    if !is_enabled() || secret.is_empty() {
+        return;
+    }
+    if let Ok(mut masked) = MASKED.lock() {
+        masked.push(secret.as_bytes().to_vec());
+    }
+}
+
+/// `bytes` with the first pending secret it contains masked
+fn apply_mask(bytes: &[u8]) -> Vec<u8> {
+    let Ok(mut masked) = MASKED.lock() else {
+        return bytes.to_vec();
+    };
+    let found = masked.iter().enumerate().find_map(|(i, secret)| {
+        bytes
+            .windows(secret.len())
+            .position(|window| window == secret.as_slice())
+            .map(|at| (i, at, secret.len()))
+    });
+    let Some((i, at, len)) = found else {
+        return bytes.to_vec();
+    };
+    masked.remove(i);
+    let mut out = bytes[..at].to_vec();
+    out.extend_from_slice(crate::core::secrets::MASK.as_bytes());
+    out.extend_from_slice(&bytes[at + len..]);
+    out
+}
+
+/// Note one line or write; does nothing while capture is off
+pub(crate) fn record(direction: Direction, bytes: &[u8]) {
+    if !is_enabled() {
+        return;
+    }
+    let bytes = match direction {
+        Direction::Out => apply_mask(bytes),
+        Direction::In => bytes.to_vec(),
+    };
+    if let Ok(mut tap) = TAP.lock() {
+        tap.next_seq += 1;
+        let seq = tap.next_seq;
+        tap.lines.push_back(TrafficLine {
+            seq,
+            direction,
+            at: Local::now().time(),
+            bytes,
+        });
+        while tap.lines.len() > CAPACITY {
+            tap.lines.pop_front();
+        }
+    }
+}
+
+/// Sequence number of the newest line recorded (0 before any), so a
+/// window can tell whether it needs a new `recent`
+pub fn generation() -> u64 {
+    TAP.lock().map(|tap| tap.next_seq).unwrap_or(0)
+}
+
+/// Up to the `count` newest lines, oldest first
+pub fn recent(count: usize) -> Vec<TrafficLine> {
+    TAP.lock()
+        .map(|tap| {
+            let skip = tap.lines.len().saturating_sub(count);
+            tap.lines.iter().skip(skip).cloned().collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Number of lines held
+pub fn len() -> usize {
+    TAP.lock().map(|tap| tap.lines.len()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(bytes: &[u8]) -> TrafficLine {
+        TrafficLine {
+            seq: 1,
+            direction: Direction::Out,
+            at: NaiveTime::MIN,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    #[test]
+    fn text_drops_line_ending_and_escapes_control_bytes() {
+        assert_eq!(line(b"<c>look\n").text(), "<c>look");
+        assert_eq!(line(b"You see\r\n").text(), "You see");
+        assert_eq!(line(&[0x1b, b'[', b'0', b'm']).text(), "\\x1b[0m");
+    }
+
+    #[test]
+    fn hex_rows_pad_short_rows_so_text_lines_up() {
+        let rows = line(b"<c>look at the fountain\n").hex_rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            "3c 63 3e 6c 6f 6f 6b 20 61 74 20 74 68 65 20 66  <c>look at the f"
+        );
+        assert!(rows[1].starts_with("6f 75 6e 74 61 69 6e 0a "));
+        assert!(rows[1].ends_with("  ountain."));
+        assert_eq!(rows[0].find("  <"), rows[1].find("  o"));
+    }
+
+    #[test]
+    fn records_only_while_enabled_and_keeps_the_newest() {
+        clear();
+        record(Direction::In, b"ignored");
+        set_enabled(true);
+        for n in 0..CAPACITY + 3 {
+            record(Direction::In, format!("line {}", n).as_bytes());
+        }
+        record(Direction::Out, b"<c>look\n");
+        set_enabled(false);
+        record(Direction::In, b"ignored");
+
+        assert_eq!(len(), CAPACITY);
+        let recent = recent(2);
+        assert_eq!(recent[0].text(), format!("line {}", CAPACITY + 2));
+        assert_eq!(recent[1].direction, Direction::Out);
+        assert_eq!(generation(), recent[1].seq);
+        clear();
+    }
+}
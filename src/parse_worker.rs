@@ -0,0 +1,185 @@
+//! XML parsing on a dedicated thread between the network and the UI loop.
+//!
+//! Network reads arrive on the bounded `ServerMessage` channel. The worker
+//! owns its own `XmlParser`, turns each line into `ParsedElement`s and
+//! passes the result on over a second bounded channel, so a burst of game
+//! output is parsed while the UI loop is busy rendering. When the UI falls
+//! behind, the parsed channel fills, the worker blocks on send, stops
+//! draining the network channel, and the network task in turn blocks — TCP
+//! flow control does the rest, same as before the split.
+//!
+//! Every chunk carries its parse time and the instant it was parsed, so the
+//! UI can record how long each stage took and how long chunks queued.
+
+use crate::config::EventPattern;
+use crate::network::ServerMessage;
+use crate::parser::{ParsedElement, XmlParser};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc as std_mpsc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Capacity of the worker→UI channel, in network lines
+pub const PARSED_CHANNEL_CAPACITY: usize = 1024;
+
+/// Keep each line's raw text on its chunk, for the `.inspect` inspector.
+/// Shared with the worker thread, so it's a static rather than a
+/// `ParserUpdate`: the flag takes effect on the very next chunk.
+static CAPTURE_RAW: AtomicBool = AtomicBool::new(false);
+
+pub fn set_capture_raw(enabled: bool) {
+    CAPTURE_RAW.store(enabled, Ordering::Relaxed);
+}
+
+/// One network read, parsed
+#[derive(Debug)]
+pub struct ParsedChunk {
+    /// Elements for each line of the chunk
+    pub lines: Vec<Vec<ParsedElement>>,
+    /// The chunk was a blank line from the server
+    pub blank: bool,
+    /// Raw size in bytes, for network stats
+    pub bytes: usize,
+    /// The text of each line, only while the inspector is capturing
+    pub raw: Option<Vec<String>>,
+    pub parse_time: Duration,
+    /// When parsing finished; the UI measures its queue wait from here
+    pub parsed_at: Instant,
+}
+
+impl ParsedChunk {
+    pub fn parse(parser: &mut XmlParser, data: &str) -> Self {
+        let start = Instant::now();
+        // "".lines() yields nothing, but a blank line from the server still
+        // has to reach the window (the parser returns an empty Text for it)
+        let lines = if data.is_empty() {
+            vec![parser.parse_line(data)]
+        } else {
+            data.lines().map(|line| parser.parse_line(line)).collect()
+        };
+        let parsed_at = Instant::now();
+        let raw = CAPTURE_RAW.load(Ordering::Relaxed).then(|| {
+            if data.is_empty() {
+                vec![String::new()]
+            } else {
+                data.lines().map(str::to_string).collect()
+            }
+        });
+        Self {
+            lines,
+            blank: data.is_empty(),
+            bytes: data.len(),
+            raw,
+            parse_time: parsed_at - start,
+            parsed_at,
+        }
+    }
+}
+
+/// `ServerMessage` with the text already parsed
+#[derive(Debug)]
+pub enum ParsedMessage {
+    Text(ParsedChunk),
+    Connected,
+    Disconnected,
+}
+
+/// Config changes the worker's parser has to pick up
+#[derive(Debug, Clone)]
+pub enum ParserUpdate {
+    Presets(Vec<(String, Option<String>, Option<String>)>),
+    EventPatterns(HashMap<String, EventPattern>),
+}
+
+/// Handle to the parse thread. Dropping it doesn't stop the thread; it ends
+/// when the network side or the UI side of its channels closes.
+pub struct ParseWorker {
+    updates: std_mpsc::Sender<ParserUpdate>,
+}
+
+impl ParseWorker {
+    /// Start parsing `server_rx` with `parser` on a new thread. Returns the
+    /// handle and the receiver the UI loop drains instead of `server_rx`.
+    pub fn spawn(
+        mut parser: XmlParser,
+        mut server_rx: mpsc::Receiver<ServerMessage>,
+    ) -> std::io::Result<(Self, mpsc::Receiver<ParsedMessage>)> {
+        let (parsed_tx, parsed_rx) = mpsc::channel(PARSED_CHANNEL_CAPACITY);
+        let (updates, update_rx) = std_mpsc::channel::<ParserUpdate>();
+        std::thread::Builder::new()
+            .name("xml-parser".to_string())
+            .spawn(move || {
+                while let Some(message) = server_rx.blocking_recv() {
+                    for update in update_rx.try_iter() {
+                        match update {
+                            ParserUpdate::Presets(presets) => parser.update_presets(presets),
+                            ParserUpdate::EventPatterns(patterns) => {
+                                parser.update_event_patterns(patterns)
+                            }
+                        }
+                    }
+                    let parsed = match message {
+                        ServerMessage::Text(line) => {
+                            ParsedMessage::Text(ParsedChunk::parse(&mut parser, &line))
+                        }
+                        ServerMessage::Connected => ParsedMessage::Connected,
+                        ServerMessage::Disconnected => ParsedMessage::Disconnected,
+                    };
+                    // Blocks while the UI is behind - that's the backpressure
+                    if parsed_tx.blocking_send(parsed).is_err() {
+                        break;
+                    }
+                }
+                tracing::debug!("parse worker exiting");
+            })?;
+        Ok((Self { updates }, parsed_rx))
+    }
+
+    /// Forward a parser config change; applied before the next line parses
+    pub fn update(&self, update: ParserUpdate) {
+        let _ = self.updates.send(update);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_keep_blank_lines_and_split_multi_line_reads() {
+        let mut parser = XmlParser::new();
+        let blank = ParsedChunk::parse(&mut parser, "");
+        assert!(blank.blank);
+        assert_eq!(blank.lines.len(), 1);
+
+        let chunk = ParsedChunk::parse(&mut parser, "one\ntwo");
+        assert!(!chunk.blank);
+        assert_eq!(chunk.lines.len(), 2);
+        assert_eq!(chunk.bytes, 7);
+    }
+
+    #[test]
+    fn worker_parses_in_order_and_stops_when_network_closes() {
+        let (server_tx, server_rx) = mpsc::channel(4);
+        let (_worker, mut parsed_rx) =
+            ParseWorker::spawn(XmlParser::new(), server_rx).expect("spawn");
+        server_tx
+            .blocking_send(ServerMessage::Connected)
+            .expect("send");
+        server_tx
+            .blocking_send(ServerMessage::Text("hello".to_string()))
+            .expect("send");
+        drop(server_tx);
+
+        assert!(matches!(
+            parsed_rx.blocking_recv(),
+            Some(ParsedMessage::Connected)
+        ));
+        match parsed_rx.blocking_recv() {
+            Some(ParsedMessage::Text(chunk)) => assert_eq!(chunk.bytes, 5),
+            other => panic!("expected text, got {:?}", other),
+        }
+        assert!(parsed_rx.blocking_recv().is_none());
+    }
+}
@@ -64,6 +64,28 @@ fn last_number(input: &str) -> Option<u32> {
         .find_map(|token| token.trim_matches(|c: char| !c.is_ascii_digit()).parse().ok())
 }
 
+/// Status letters the game puts in front of the `>` prompt, as words.
+/// "RK>" -> ["roundtime", "kneeling"]. Unknown letters are skipped.
+pub fn prompt_status(prompt: &str) -> Vec<&'static str> {
+    prompt
+        .chars()
+        .filter_map(|c| match c {
+            'R' => Some("roundtime"),
+            'C' => Some("casting"),
+            'H' => Some("hidden"),
+            'I' => Some("invisible"),
+            'J' => Some("joined"),
+            'K' => Some("kneeling"),
+            'S' => Some("sitting"),
+            'P' => Some("prone"),
+            'W' => Some("webbed"),
+            'B' => Some("bleeding"),
+            'D' => Some("dead"),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Top-level representation of any XML fragment we care about.
 #[derive(Debug, Clone)]
 pub enum ParsedElement {
@@ -2290,7 +2312,7 @@ impl XmlParser {
         }
     }
 
-    fn decode_entities(text: String) -> String {
+    pub(crate) fn decode_entities(text: String) -> String {
         // Fast path: most game text has no entities at all
         if !text.contains('&') {
             return text;
@@ -2959,6 +2981,13 @@ mod tests {
         assert_eq!(text, ">");
     }
 
+    #[test]
+    fn test_prompt_status_letters() {
+        assert_eq!(prompt_status("RK>"), vec!["roundtime", "kneeling"]);
+        assert_eq!(prompt_status("HJ>"), vec!["hidden", "joined"]);
+        assert!(prompt_status(">").is_empty());
+    }
+
     // ==================== RoundTime Parsing ====================
 
     #[test]
@@ -17,6 +17,12 @@ pub struct PerformanceStats {
     last_frame_time: Instant,
     max_frame_samples: usize,
     collect_frame_times: bool,
+    // Frames actually rendered in the last full second, and the rate the
+    // frame pacer is aiming for
+    frames_this_second: u32,
+    frame_rate_sample_start: Instant,
+    frames_last_second: u32,
+    target_fps: u32,
     collect_render_times: bool,
     collect_ui_times: bool,
     collect_wrap_times: bool,
@@ -39,6 +45,12 @@ pub struct PerformanceStats {
     parse_sample_start: Instant,
     chunks_parsed_last_second: u64,
     max_parse_samples: usize,
+    // Parse worker stages: time a parsed chunk waited for the UI loop, time
+    // applying it to state, and how many chunks were waiting
+    parse_queue_waits: VecDeque<Duration>,
+    parse_apply_times: VecDeque<Duration>,
+    parsed_queue_depth_last: u64,
+    parsed_queue_depth_max: u64,
 
     // General
     app_start_time: Instant,
@@ -78,6 +90,9 @@ pub struct PerformanceStats {
     elements_parsed: u64, // Total XML elements parsed
     elements_sample_start: Instant,
     elements_parsed_last_second: u64,
+
+    // Shared stream names/colors in text buffers (sampled with sysinfo)
+    intern_stats: crate::data::intern::InternStats,
 }
 
 impl Default for PerformanceStats {
@@ -95,6 +110,10 @@ impl PerformanceStats {
             last_frame_time: now,
             max_frame_samples: 60,
             collect_frame_times: true,
+            frames_this_second: 0,
+            frame_rate_sample_start: now,
+            frames_last_second: 0,
+            target_fps: 0,
             collect_render_times: true,
             collect_ui_times: true,
             collect_wrap_times: true,
@@ -115,6 +134,10 @@ impl PerformanceStats {
             parse_sample_start: now,
             chunks_parsed_last_second: 0,
             max_parse_samples: 60,
+            parse_queue_waits: VecDeque::with_capacity(60),
+            parse_apply_times: VecDeque::with_capacity(60),
+            parsed_queue_depth_last: 0,
+            parsed_queue_depth_max: 0,
 
             app_start_time: now,
 
@@ -150,11 +173,14 @@ impl PerformanceStats {
             elements_parsed: 0,
             elements_sample_start: now,
             elements_parsed_last_second: 0,
+
+            intern_stats: crate::data::intern::InternStats::default(),
         }
     }
 
     /// Record a frame render
     pub fn record_frame(&mut self) {
+        self.count_frame(Instant::now());
         if !self.collect_frame_times {
             self.last_frame_time = Instant::now();
             return;
@@ -229,6 +255,59 @@ impl PerformanceStats {
     }
 
     /// Get current FPS
+    /// Record the UI-side stages of a chunk parsed on the worker: how long it
+    /// sat in the parsed queue and how long applying it took
+    pub fn record_parse_stages(&mut self, queue_wait: Duration, apply: Duration) {
+        if !self.collect_parse {
+            return;
+        }
+        self.parse_queue_waits.push_back(queue_wait);
+        if self.parse_queue_waits.len() > self.max_parse_samples {
+            self.parse_queue_waits.pop_front();
+        }
+        self.parse_apply_times.push_back(apply);
+        if self.parse_apply_times.len() > self.max_parse_samples {
+            self.parse_apply_times.pop_front();
+        }
+    }
+
+    /// Record how many parsed chunks were waiting for the UI loop
+    pub fn record_parsed_queue_depth(&mut self, depth: u64) {
+        self.parsed_queue_depth_last = depth;
+        if depth > self.parsed_queue_depth_max {
+            self.parsed_queue_depth_max = depth;
+        }
+    }
+
+    fn count_frame(&mut self, now: Instant) {
+        self.frames_this_second += 1;
+        if now.duration_since(self.frame_rate_sample_start) >= Duration::from_secs(1) {
+            self.frames_last_second = self.frames_this_second;
+            self.frames_this_second = 0;
+            self.frame_rate_sample_start = now;
+        }
+    }
+
+    /// Record the rate the frame pacer is currently aiming for
+    pub fn record_target_fps(&mut self, fps: u32) {
+        self.target_fps = fps;
+    }
+
+    /// Frames rendered over the last full second. Unlike `fps`, which
+    /// averages recent frame intervals, this falls with the pacer's idle rate.
+    pub fn effective_fps(&self) -> u32 {
+        // No frame for a while means the last count is stale
+        if self.frame_rate_sample_start.elapsed() >= Duration::from_secs(2) {
+            return 0;
+        }
+        self.frames_last_second
+    }
+
+    /// Rate the frame pacer is aiming for (0 when nothing paces frames)
+    pub fn target_fps(&self) -> u32 {
+        self.target_fps
+    }
+
     pub fn fps(&self) -> f64 {
         if self.frame_times.is_empty() {
             return 0.0;
@@ -282,6 +361,12 @@ impl PerformanceStats {
         self.bytes_sent_last_second
     }
 
+    /// Bytes received compressed on direct connections and what they
+    /// inflated to, since startup; None until a server has compressed
+    pub fn compression_totals(&self) -> Option<(u64, u64)> {
+        crate::network::compression::totals()
+    }
+
     /// Get average parse time in microseconds
     pub fn avg_parse_time_us(&self) -> f64 {
         if self.parse_times.is_empty() {
@@ -292,6 +377,34 @@ impl PerformanceStats {
         total.as_secs_f64() * 1_000_000.0 / self.parse_times.len() as f64
     }
 
+    /// Get average time parsed chunks waited for the UI loop, in microseconds
+    pub fn avg_parse_queue_wait_us(&self) -> f64 {
+        if self.parse_queue_waits.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = self.parse_queue_waits.iter().sum();
+        total.as_secs_f64() * 1_000_000.0 / self.parse_queue_waits.len() as f64
+    }
+
+    /// Get average time applying a parsed chunk to state, in microseconds
+    pub fn avg_parse_apply_time_us(&self) -> f64 {
+        if self.parse_apply_times.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = self.parse_apply_times.iter().sum();
+        total.as_secs_f64() * 1_000_000.0 / self.parse_apply_times.len() as f64
+    }
+
+    /// Get last recorded parsed-queue depth
+    pub fn last_parsed_queue_depth(&self) -> u64 {
+        self.parsed_queue_depth_last
+    }
+
+    /// Get maximum observed parsed-queue depth
+    pub fn max_parsed_queue_depth(&self) -> u64 {
+        self.parsed_queue_depth_max
+    }
+
     /// Get chunks parsed per second
     pub fn chunks_per_sec(&self) -> u64 {
         self.chunks_parsed_last_second
@@ -405,12 +518,17 @@ impl PerformanceStats {
         }
     }
 
-    /// Sample system/process metrics (CPU/RSS) at most once per second
+    /// Sample system/process metrics (CPU/RSS) and string sharing at most
+    /// once per second
     pub fn sample_sysinfo(&mut self) {
         if Instant::now().duration_since(self.last_sys_sample) < Duration::from_secs(1) {
             return;
         }
 
+        if self.collect_memory {
+            self.intern_stats = crate::data::intern::stats();
+        }
+
         // Refresh global CPU usage plus our own process only - refreshing
         // ProcessRefreshKind::everything() enumerated every process on the
         // machine once per second
@@ -542,6 +660,12 @@ impl PerformanceStats {
         self.elements_parsed_last_second
     }
 
+    /// Stream names and colors shared across buffered lines, as of the last
+    /// sample: bytes they'd take as per-line copies vs. shared
+    pub fn intern_stats(&self) -> crate::data::intern::InternStats {
+        self.intern_stats
+    }
+
     /// Estimate memory usage in MB (very rough approximation)
     pub fn estimated_memory_mb(&self) -> f64 {
         // Rough estimate: ~200 bytes per line on average (including overhead)
@@ -701,6 +825,23 @@ mod tests {
         assert!((stats.max_frame_time_ms() - 20.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_effective_fps_counts_frames_per_second() {
+        let mut stats = PerformanceStats::new();
+        let start = stats.frame_rate_sample_start;
+
+        for i in 0..5 {
+            stats.count_frame(start + Duration::from_millis(i * 100));
+        }
+        assert_eq!(stats.frames_last_second, 0);
+        stats.count_frame(start + Duration::from_secs(1));
+        assert_eq!(stats.frames_last_second, 6);
+        assert_eq!(stats.frames_this_second, 0);
+
+        stats.record_target_fps(5);
+        assert_eq!(stats.target_fps(), 5);
+    }
+
     #[test]
     fn test_fps_calculation() {
         let mut stats = PerformanceStats::new();
@@ -758,6 +899,21 @@ mod tests {
         assert_eq!(stats.avg_parse_time_us(), 0.0);
     }
 
+    #[test]
+    fn test_parse_stage_recording() {
+        let mut stats = PerformanceStats::new();
+
+        stats.record_parse_stages(Duration::from_micros(40), Duration::from_micros(10));
+        stats.record_parse_stages(Duration::from_micros(60), Duration::from_micros(30));
+        stats.record_parsed_queue_depth(7);
+        stats.record_parsed_queue_depth(2);
+
+        assert!((stats.avg_parse_queue_wait_us() - 50.0).abs() < 0.1);
+        assert!((stats.avg_parse_apply_time_us() - 20.0).abs() < 0.1);
+        assert_eq!(stats.last_parsed_queue_depth(), 2);
+        assert_eq!(stats.max_parsed_queue_depth(), 7);
+    }
+
     // ==================== Render Time Tests ====================
 
     #[test]
@@ -19,3 +19,61 @@ pub fn open_url(target: &str) -> Result<()> {
     tracing::warn!("open_url unavailable without the `desktop` feature: {target}");
     anyhow::bail!("Opening URLs is unavailable in this build")
 }
+
+/// Show a desktop notification. Fire-and-forget: spawns the OS notifier
+/// (notify-send, osascript, a PowerShell balloon tip) without waiting on it.
+/// Title and body reach the script through environment variables, so no
+/// quoting of game text is needed.
+#[cfg(feature = "desktop")]
+pub fn notify(title: &str, body: &str) -> Result<()> {
+    use std::process::{Command, Stdio};
+
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = Command::new("osascript");
+        command.args([
+            "-e",
+            r#"display notification (system attribute "VELLUM_NOTIFY_BODY") with title (system attribute "VELLUM_NOTIFY_TITLE") sound name "Basso""#,
+        ]);
+        command
+    };
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("powershell");
+        command.args([
+            "-NoProfile",
+            "-WindowStyle",
+            "Hidden",
+            "-Command",
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             $n = New-Object System.Windows.Forms.NotifyIcon; \
+             $n.Icon = [System.Drawing.SystemIcons]::Warning; $n.Visible = $true; \
+             $n.ShowBalloonTip(10000, $env:VELLUM_NOTIFY_TITLE, $env:VELLUM_NOTIFY_BODY, 'Warning'); \
+             Start-Sleep -Seconds 10; $n.Dispose()",
+        ]);
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = {
+        let mut command = Command::new("notify-send");
+        command.args(["--urgency=critical", title, body]);
+        command
+    };
+
+    command
+        .env("VELLUM_NOTIFY_TITLE", title)
+        .env("VELLUM_NOTIFY_BODY", body)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    Ok(())
+}
+
+#[cfg(not(feature = "desktop"))]
+pub fn notify(title: &str, body: &str) -> Result<()> {
+    tracing::debug!(
+        "desktop notification unavailable without the `desktop` feature: {title}: {body}"
+    );
+    Ok(())
+}
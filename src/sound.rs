@@ -26,7 +26,14 @@ pub struct SoundPlayer {
     #[cfg(feature = "sound")]
     stream_handle: OutputStreamHandle,
     enabled: bool,
+    /// Temporary silence (boss key) that leaves `enabled` untouched
+    #[cfg_attr(not(feature = "sound"), allow(dead_code))]
+    muted: bool,
     volume: f32,
+    /// Scale for every sound from window focus (`sound.play_when`,
+    /// `sound.unfocused_volume`); 0.0 silences
+    #[cfg_attr(not(feature = "sound"), allow(dead_code))]
+    focus_gain: f32,
     #[cfg_attr(not(feature = "sound"), allow(dead_code))]
     cooldown_map: Arc<Mutex<std::collections::HashMap<String, Instant>>>,
     #[cfg_attr(not(feature = "sound"), allow(dead_code))]
@@ -54,7 +61,9 @@ impl SoundPlayer {
                 _stream: stream,
                 stream_handle,
                 enabled,
+                muted: false,
                 volume: volume.clamp(0.0, 1.0),
+                focus_gain: 1.0,
                 cooldown_map: Arc::new(Mutex::new(std::collections::HashMap::new())),
                 cooldown_duration: std::time::Duration::from_millis(cooldown_ms),
             })
@@ -64,7 +73,9 @@ impl SoundPlayer {
         {
             Ok(Self {
                 enabled,
+                muted: false,
                 volume: volume.clamp(0.0, 1.0),
+                focus_gain: 1.0,
                 cooldown_map: Arc::new(Mutex::new(std::collections::HashMap::new())),
                 cooldown_duration: std::time::Duration::from_millis(cooldown_ms),
             })
@@ -77,6 +88,18 @@ impl SoundPlayer {
         debug!("Sound player enabled: {}", enabled);
     }
 
+    /// Temporarily silence all playback without changing `enabled`
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+        debug!("Sound player muted: {}", muted);
+    }
+
+    /// Scale all playback, per-sound volumes included, for window focus
+    pub fn set_focus_gain(&mut self, gain: f32) {
+        self.focus_gain = gain.clamp(0.0, 1.0);
+        debug!("Sound player focus gain: {}", self.focus_gain);
+    }
+
     /// Set the master volume (0.0 to 1.0)
     pub fn set_volume(&mut self, volume: f32) {
         self.volume = volume.clamp(0.0, 1.0);
@@ -122,7 +145,7 @@ impl SoundPlayer {
     /// * `sound_id` - Identifier for cooldown tracking (usually the file path)
     #[cfg(feature = "sound")]
     pub fn play(&self, path: &PathBuf, volume_override: Option<f32>, sound_id: &str) -> Result<()> {
-        if !self.enabled {
+        if !self.enabled || self.muted || self.focus_gain <= 0.0 {
             return Ok(());
         }
 
@@ -152,7 +175,7 @@ impl SoundPlayer {
 
         // Calculate final volume
         let volume = volume_override.unwrap_or(self.volume);
-        let volume = volume.clamp(0.0, 1.0);
+        let volume = volume.clamp(0.0, 1.0) * self.focus_gain;
 
         // Create a sink and play
         let sink = Sink::try_new(&self.stream_handle)?;
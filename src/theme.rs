@@ -92,6 +92,30 @@ pub struct AppTheme {
 
     // Widget defaults
     pub injury_default_color: Color,
+
+    /// Accessibility: widgets that encode meaning with color alone (injury
+    /// doll levels, indicator/dashboard states) add glyph or text-style
+    /// distinctions too. Set by the high-contrast and colorblind themes.
+    pub shape_cues: bool,
+
+    /// Border glyphs for single-line borders and popups: a built-in set
+    /// ("single", "double", "rounded", "thick", "ascii") or a name from
+    /// `border_sets`. None keeps the single-line box drawing glyphs.
+    pub border_set: Option<String>,
+    /// Custom glyph sets by name, usable as `border_set` here or as a
+    /// window's `border_style`
+    pub border_sets: HashMap<String, BorderGlyphs>,
+}
+
+/// A custom border glyph set, one glyph (a single terminal cell) per part
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BorderGlyphs {
+    pub top_left: String,
+    pub top_right: String,
+    pub bottom_left: String,
+    pub bottom_right: String,
+    pub horizontal: String,
+    pub vertical: String,
 }
 
 impl AppTheme {
@@ -965,6 +989,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(96, 180, 191),
             thought_color: Color::rgb(255, 128, 128),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1053,6 +1080,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(0, 128, 128),
             thought_color: Color::rgb(200, 50, 50),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1131,6 +1161,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(129, 161, 193),
             thought_color: Color::rgb(180, 142, 173),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1209,6 +1242,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(139, 233, 253),
             thought_color: Color::rgb(255, 121, 198),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1287,6 +1323,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(42, 161, 152),
             thought_color: Color::rgb(108, 113, 196),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1365,6 +1404,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(42, 161, 152),
             thought_color: Color::rgb(108, 113, 196),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1443,6 +1485,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(102, 217, 239),
             thought_color: Color::rgb(174, 129, 255),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1521,6 +1566,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(142, 192, 124),
             thought_color: Color::rgb(211, 134, 155),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1599,6 +1647,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(128, 255, 183),
             thought_color: Color::rgb(255, 179, 64),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1677,6 +1728,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(164, 214, 255),
             thought_color: Color::rgb(203, 166, 247),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1755,6 +1809,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(15, 251, 222),
             thought_color: Color::rgb(255, 107, 159),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1833,6 +1890,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(255, 255, 255),
             thought_color: Color::rgb(255, 159, 0),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1911,6 +1971,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(88, 199, 255),
             thought_color: Color::rgb(255, 178, 92),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -1989,6 +2052,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(117, 92, 70),
             thought_color: Color::rgb(222, 141, 88),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2067,6 +2133,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(89, 148, 118),
             thought_color: Color::rgb(255, 189, 105),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2145,6 +2214,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(92, 255, 255),
             thought_color: Color::rgb(255, 95, 206),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2223,6 +2295,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(144, 202, 249),
             thought_color: Color::rgb(179, 229, 252),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2301,6 +2376,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(200, 230, 201),
             thought_color: Color::rgb(197, 225, 165),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2379,6 +2457,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(255, 204, 188),
             thought_color: Color::rgb(240, 98, 146),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2457,6 +2538,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(207, 216, 220),
             thought_color: Color::rgb(176, 190, 197),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2535,6 +2619,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(0, 255, 255),
             thought_color: Color::rgb(255, 0, 110),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2613,6 +2700,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(212, 197, 169),
             thought_color: Color::rgb(232, 213, 176),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2691,6 +2781,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(225, 190, 231),
             thought_color: Color::rgb(206, 147, 216),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2769,6 +2862,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(248, 187, 208),
             thought_color: Color::rgb(236, 64, 122),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2847,6 +2943,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(216, 222, 233),
             thought_color: Color::rgb(163, 190, 140),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -2925,6 +3024,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(255, 224, 178),
             thought_color: Color::rgb(255, 204, 128),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -3008,10 +3110,14 @@ impl ThemePresets {
             whisper_color: Color::rgb(0, 0, 139),
             thought_color: Color::rgb(139, 0, 139),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
             derive_injury_default_color(theme.window_background, theme.text_secondary);
+        theme.shape_cues = true;
         theme
     }
 
@@ -3087,10 +3193,14 @@ impl ThemePresets {
             whisper_color: Color::rgb(30, 144, 255),
             thought_color: Color::rgb(218, 112, 214),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
             derive_injury_default_color(theme.window_background, theme.text_secondary);
+        theme.shape_cues = true;
         theme
     }
 
@@ -3166,10 +3276,14 @@ impl ThemePresets {
             whisper_color: Color::rgb(168, 216, 255),
             thought_color: Color::rgb(255, 215, 0),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
             derive_injury_default_color(theme.window_background, theme.text_secondary);
+        theme.shape_cues = true;
         theme
     }
 
@@ -3244,10 +3358,14 @@ impl ThemePresets {
             whisper_color: Color::rgb(173, 216, 230),
             thought_color: Color::rgb(218, 112, 214),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
             derive_injury_default_color(theme.window_background, theme.text_secondary);
+        theme.shape_cues = true;
         theme
     }
 
@@ -3322,10 +3440,14 @@ impl ThemePresets {
             whisper_color: Color::rgb(144, 238, 144),
             thought_color: Color::rgb(255, 182, 193),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
             derive_injury_default_color(theme.window_background, theme.text_secondary);
+        theme.shape_cues = true;
         theme
     }
 
@@ -3400,10 +3522,14 @@ impl ThemePresets {
             whisper_color: Color::rgb(192, 192, 192),
             thought_color: Color::rgb(160, 160, 160),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
             derive_injury_default_color(theme.window_background, theme.text_secondary);
+        theme.shape_cues = true;
         theme
     }
 
@@ -3478,6 +3604,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(232, 196, 160),
             thought_color: Color::rgb(244, 212, 176),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -3556,6 +3685,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(90, 106, 106),
             thought_color: Color::rgb(122, 90, 122),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -3634,6 +3766,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(128, 128, 128),
             thought_color: Color::rgb(204, 204, 204),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -3713,6 +3848,9 @@ impl ThemePresets {
             whisper_color: Color::rgb(180, 180, 180),
             thought_color: Color::rgb(160, 160, 160),
             injury_default_color: Color::BLACK,
+            shape_cues: false,
+            border_set: None,
+            border_sets: HashMap::new(),
         };
 
         theme.injury_default_color =
@@ -3806,6 +3944,20 @@ mod tests {
 
     // ==================== indexed_color_to_rgb Tests ====================
 
+    #[test]
+    fn test_accessibility_themes_enable_shape_cues() {
+        let themes = ThemePresets::all();
+        for id in [
+            "high-contrast-dark",
+            "high-contrast-light",
+            "deuteranopia",
+            "protanopia",
+        ] {
+            assert!(themes[id].shape_cues, "{} should enable shape cues", id);
+        }
+        assert!(!themes["dark"].shape_cues);
+    }
+
     #[test]
     fn test_indexed_color_standard_black() {
         assert_eq!(indexed_color_to_rgb(0), (0, 0, 0));
@@ -5,9 +5,10 @@
 //! Both TUI and GUI frontends can use this module to load custom themes.
 
 use crate::frontend::common::Color;
-use crate::theme::AppTheme;
+use crate::theme::{AppTheme, BorderGlyphs};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -96,11 +97,23 @@ pub struct ThemeData {
     // Injury doll colors
     pub injury_default_color: String,
 
+    /// Widgets that encode meaning with color also add glyph/shape cues
+    #[serde(default)]
+    pub shape_cues: bool,
+
+    /// Border glyph set for single-line borders and popups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border_set: Option<String>,
+
     // Legacy/compatibility colors
     pub border_color: String,
     pub label_color: String,
     pub focused_label_color: String,
     pub text_color: String,
+
+    /// Custom border glyph sets (`[border_sets.name]` tables)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub border_sets: HashMap<String, BorderGlyphs>,
 }
 
 impl Default for ThemeData {
@@ -182,12 +195,15 @@ impl ThemeData {
             thought_color: Self::color_to_hex(&theme.thought_color),
 
             injury_default_color: Self::color_to_hex(&theme.injury_default_color),
+            shape_cues: theme.shape_cues,
+            border_set: theme.border_set.clone(),
 
             // Legacy fields - map to editor equivalents
             border_color: Self::color_to_hex(&theme.editor_border),
             label_color: Self::color_to_hex(&theme.editor_label),
             focused_label_color: Self::color_to_hex(&theme.editor_label_focused),
             text_color: Self::color_to_hex(&theme.editor_text),
+            border_sets: theme.border_sets.clone(),
         }
     }
 
@@ -330,6 +346,9 @@ impl ThemeData {
             whisper_color,
             thought_color,
             injury_default_color,
+            shape_cues: self.shape_cues,
+            border_set: self.border_set.clone(),
+            border_sets: self.border_sets.clone(),
             // Legacy fields removed - they don't exist in AppTheme
             // (ThemeData still has them for backward compatibility)
         })
@@ -472,15 +491,20 @@ impl ThemeData {
         let toml_string = toml::to_string_pretty(self)?;
 
         // Write to file
-        fs::write(&filepath, toml_string)?;
+        fs::write(&filepath, crate::config::schema::stamp(&toml_string))?;
 
         Ok(filepath)
     }
 
-    /// Load a theme from a TOML file
+    /// Load a theme from a TOML file, upgrading it first (config::schema)
     pub fn load_from_file(path: &Path) -> Result<Self> {
+        if let Err(e) =
+            crate::config::schema::upgrade_file(path, crate::config::schema::FileKind::Theme)
+        {
+            tracing::warn!("Could not upgrade {:?}: {:#}", path, e);
+        }
         let contents = fs::read_to_string(path)?;
-        let theme_data: ThemeData = toml::from_str(&contents)?;
+        let theme_data: ThemeData = crate::config::schema::parse(&contents)?;
         Ok(theme_data)
     }
 }
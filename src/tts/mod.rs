@@ -87,7 +87,7 @@ pub struct TtsManager {
 
 impl TtsManager {
     /// Create a new TTS manager
-    pub fn new(enabled: bool, rate: f32, volume: f32) -> Self {
+    pub fn new(enabled: bool, rate: f32, volume: f32, max_queue_size: usize) -> Self {
         let (event_tx, event_rx) = channel();
 
         Self {
@@ -99,7 +99,7 @@ impl TtsManager {
             enabled,
             rate,
             volume,
-            max_queue_size: 100, // Reasonable limit
+            max_queue_size: max_queue_size.max(1),
             event_tx,
             event_rx,
             // Default ranges (will be updated during initialization)
@@ -178,24 +178,53 @@ impl TtsManager {
         if !self.enabled || self.muted {
             return;
         }
+        self.insert_entry(entry);
+    }
 
-        // Prevent queue from growing unbounded
-        if self.queue.len() >= self.max_queue_size {
-            tracing::warn!(
-                "TTS queue full ({} entries), dropping oldest",
-                self.max_queue_size
-            );
-            self.queue.pop_front();
-
-            // Adjust current_index since we removed from the front
-            if let Some(current) = self.current_index {
-                if current > 0 {
-                    self.current_index = Some(current - 1);
-                } else {
-                    // Was pointing at the dropped message
-                    self.current_index = None;
-                }
+    /// Speak an interface announcement right away (focus changes, screen
+    /// read-outs), interrupting whatever is playing. The entry stays in the
+    /// queue so previous/next can replay it.
+    pub fn announce(&mut self, text: &str, source_window: &str) -> Result<()> {
+        if !self.enabled || self.muted || text.trim().is_empty() {
+            return Ok(());
+        }
+
+        self.ensure_initialized()?;
+
+        let entry = SpeechEntry {
+            text: text.to_string(),
+            source_window: source_window.to_string(),
+            priority: Priority::Critical,
+            spoken: false,
+        };
+        match self.insert_entry(entry) {
+            Some(index) => self.speak_at_index(index, true),
+            None => Ok(()),
+        }
+    }
+
+    /// Insert by priority, returning the entry's index, or None when the
+    /// queue is full of higher-priority entries and this one was dropped
+    fn insert_entry(&mut self, entry: SpeechEntry) -> Option<usize> {
+        // Prevent queue from growing unbounded: drop the lowest-priority
+        // entry, already-spoken ones first, oldest first within that
+        while self.queue.len() >= self.max_queue_size.max(1) {
+            let (victim, victim_priority) = self
+                .queue
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, e)| (e.priority, !e.spoken))
+                .map(|(i, e)| (i, e.priority))?;
+            if entry.priority < victim_priority {
+                tracing::debug!("TTS queue full of higher-priority entries, dropping new message");
+                return None;
             }
+            tracing::debug!(
+                "TTS queue full ({} entries), dropping entry at {}",
+                self.max_queue_size,
+                victim
+            );
+            self.remove_entry(victim);
         }
 
         // Insert based on priority (higher priority first)
@@ -228,6 +257,77 @@ impl TtsManager {
             insert_pos,
             self.queue.len()
         );
+        Some(insert_pos)
+    }
+
+    /// Remove one entry, keeping current_index on the same message
+    fn remove_entry(&mut self, index: usize) {
+        if self.queue.remove(index).is_none() {
+            return;
+        }
+        self.current_index = match self.current_index {
+            Some(current) if current == index => None,
+            Some(current) if current > index => Some(current - 1),
+            other => other,
+        };
+    }
+
+    /// Stop speaking and drop everything queued (`.tts stop`)
+    pub fn flush(&mut self) -> Result<()> {
+        self.stop()?;
+        self.clear_queue();
+        Ok(())
+    }
+
+    /// Cut off the current utterance and move on to the next unread one
+    /// (`.tts skip`); stops quietly when nothing unread is left
+    pub fn skip(&mut self) -> Result<()> {
+        if !self.enabled || self.muted {
+            return Ok(());
+        }
+        self.stop()?;
+        if let Some(current) = self.current_index {
+            if let Some(entry) = self.queue.get_mut(current) {
+                entry.spoken = true;
+            }
+        }
+        self.ensure_initialized()?;
+        let next = self.next_unspoken_index();
+        if let Some(index) = next {
+            self.speak_at_index(index, true)?;
+        }
+        Ok(())
+    }
+
+    /// Change the queue depth, trimming low-priority entries if it shrank
+    pub fn set_max_queue_size(&mut self, max_queue_size: usize) {
+        self.max_queue_size = max_queue_size.max(1);
+        while self.queue.len() > self.max_queue_size {
+            let Some(victim) = self
+                .queue
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, e)| (e.priority, !e.spoken))
+                .map(|(i, _)| i)
+            else {
+                break;
+            };
+            self.remove_entry(victim);
+        }
+    }
+
+    pub fn max_queue_size(&self) -> usize {
+        self.max_queue_size
+    }
+
+    /// Entries not yet spoken
+    pub fn unread_count(&self) -> usize {
+        self.queue.iter().filter(|e| !e.spoken).count()
+    }
+
+    fn next_unspoken_index(&self) -> Option<usize> {
+        let start = self.current_index.map_or(0, |current| current + 1);
+        (start..self.queue.len()).find(|&i| !self.queue[i].spoken)
     }
 
     /// Speak the next item in the queue (sequential, includes read messages)
@@ -330,14 +430,7 @@ impl TtsManager {
             return Ok(());
         }
 
-        // Find next unspoken entry
-        let next_index = if let Some(current) = self.current_index {
-            (current + 1..self.queue.len()).find(|&i| !self.queue[i].spoken)
-        } else {
-            (0..self.queue.len()).find(|&i| !self.queue[i].spoken)
-        };
-
-        if let Some(index) = next_index {
+        if let Some(index) = self.next_unspoken_index() {
             // Auto-play - don't interrupt (nothing should be playing)
             self.speak_at_index(index, false)?;
             tracing::debug!("Auto-playing next TTS entry at index {}", index);
@@ -403,6 +496,13 @@ impl TtsManager {
         }
     }
 
+    /// Set mute explicitly (stops current speech when muting)
+    pub fn set_muted(&mut self, muted: bool) {
+        if self.muted != muted {
+            self.toggle_mute();
+        }
+    }
+
     /// Clear the queue
     pub fn clear_queue(&mut self) {
         self.queue.clear();
@@ -513,3 +613,60 @@ impl TtsManager {
         self.event_rx.try_recv()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: &str, priority: Priority) -> SpeechEntry {
+        SpeechEntry {
+            text: text.to_string(),
+            source_window: "main".to_string(),
+            priority,
+            spoken: false,
+        }
+    }
+
+    fn texts(manager: &TtsManager) -> Vec<&str> {
+        manager.queue.iter().map(|e| e.text.as_str()).collect()
+    }
+
+    #[test]
+    fn full_queue_drops_lowest_priority_first() {
+        let mut manager = TtsManager::new(true, 1.0, 1.0, 3);
+        manager.enqueue(entry("thought", Priority::High));
+        manager.enqueue(entry("old", Priority::Normal));
+        manager.enqueue(entry("new", Priority::Normal));
+        manager.enqueue(entry("alert", Priority::Critical));
+        assert_eq!(texts(&manager), vec!["alert", "thought", "new"]);
+
+        // Nothing left that a normal line outranks: the new line is dropped
+        manager.enqueue(entry("critical two", Priority::Critical));
+        manager.enqueue(entry("spam", Priority::Normal));
+        manager.enqueue(entry("spam", Priority::Normal));
+        assert_eq!(texts(&manager), vec!["alert", "critical two", "thought"]);
+    }
+
+    #[test]
+    fn spoken_entries_are_dropped_before_unread_ones() {
+        let mut manager = TtsManager::new(true, 1.0, 1.0, 2);
+        manager.enqueue(entry("unread", Priority::Normal));
+        manager.enqueue(entry("heard", Priority::Normal));
+        manager.queue[1].spoken = true;
+        manager.current_index = Some(1);
+        manager.enqueue(entry("next", Priority::Normal));
+        assert_eq!(texts(&manager), vec!["unread", "next"]);
+        assert_eq!(manager.current_index, None);
+        assert_eq!(manager.unread_count(), 2);
+    }
+
+    #[test]
+    fn flush_empties_the_queue() {
+        let mut manager = TtsManager::new(true, 1.0, 1.0, 10);
+        manager.enqueue(entry("one", Priority::Normal));
+        manager.enqueue(entry("two", Priority::High));
+        manager.flush().unwrap();
+        assert_eq!(manager.queue_size(), 0);
+        assert_eq!(manager.current_index, None);
+    }
+}
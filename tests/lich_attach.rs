@@ -51,7 +51,16 @@ async fn detachable_attach_handshakes_streams_and_ends_on_close() {
     let (server_tx, mut server_rx) = mpsc::channel::<ServerMessage>(64);
     let (command_tx, command_rx) = mpsc::unbounded_channel::<String>();
     let task = tokio::spawn(async move {
-        LichConnection::start("127.0.0.1", addr.port(), None, server_tx, command_rx, None).await
+        LichConnection::start(
+            "127.0.0.1",
+            addr.port(),
+            None,
+            server_tx,
+            command_rx,
+            None,
+            None,
+        )
+        .await
     });
 
     // Session comes up and the fake's lines arrive as text.
@@ -137,7 +146,16 @@ async fn aborting_the_task_closes_the_socket_so_reattach_works() {
     let (server_tx, mut server_rx) = mpsc::channel::<ServerMessage>(64);
     let (_command_tx, command_rx) = mpsc::unbounded_channel::<String>();
     let task = tokio::spawn(async move {
-        LichConnection::start("127.0.0.1", addr.port(), None, server_tx, command_rx, None).await
+        LichConnection::start(
+            "127.0.0.1",
+            addr.port(),
+            None,
+            server_tx,
+            command_rx,
+            None,
+            None,
+        )
+        .await
     });
     assert!(matches!(recv(&mut server_rx).await, ServerMessage::Connected));
 
@@ -157,7 +175,16 @@ async fn aborting_the_task_closes_the_socket_so_reattach_works() {
     let (server_tx2, mut server_rx2) = mpsc::channel::<ServerMessage>(64);
     let (_command_tx2, command_rx2) = mpsc::unbounded_channel::<String>();
     tokio::spawn(async move {
-        LichConnection::start("127.0.0.1", addr.port(), None, server_tx2, command_rx2, None).await
+        LichConnection::start(
+            "127.0.0.1",
+            addr.port(),
+            None,
+            server_tx2,
+            command_rx2,
+            None,
+            None,
+        )
+        .await
     });
     assert!(matches!(recv(&mut server_rx2).await, ServerMessage::Connected));
     match recv(&mut server_rx2).await {
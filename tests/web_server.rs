@@ -41,7 +41,7 @@ async fn start_server(
 fn styled(text: &str, stream: &str) -> Arc<StyledLine> {
     Arc::new(StyledLine {
         segments: vec![TextSegment::plain(text)],
-        stream: stream.to_string(),
+        stream: stream.into(),
         timestamp: None,
     })
 }